@@ -6,59 +6,24 @@ use std::hint::black_box;
 use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
 use geometric_traits::{
     impls::ValuedCSR2D,
-    prelude::{LAPMOD, MatrixMut, SparseLAPJV, SparseMatrixMut, SparseValuedMatrix},
-    traits::algorithms::randomized_graphs::XorShift64,
+    prelude::{LAPMOD, SparseLAPJV, SparseValuedMatrix},
+    traits::algorithms::randomized_graphs::{
+        Feasibility, SparseCostMatrixConfig, random_sparse_cost_matrix,
+    },
 };
-use num_traits::ToPrimitive;
 
-fn target_edge_count(n: usize, density: f64) -> usize {
-    let Some(total_cells) = n.checked_mul(n).and_then(|value| value.to_f64()) else {
-        return usize::MAX;
-    };
-    (total_cells * density).floor().to_usize().unwrap_or(usize::MAX)
-}
-
-fn random_index(rng: &mut XorShift64, n: usize) -> usize {
-    let n_u64 = u64::try_from(n).expect("usize values always fit into u64");
-    let raw = rng.next().expect("XorShift64 produces infinite values") % n_u64;
-    usize::try_from(raw).expect("raw index is modulo n and always fits usize")
-}
-
-fn random_cost(rng: &mut XorShift64) -> f64 {
-    let raw = rng.next().expect("XorShift64 produces infinite values") % 999 + 1;
-    let cents = u32::try_from(raw).expect("bounded to the range 1..=999");
-    f64::from(cents) / 100.0
-}
-
-/// Generate a random n×n sparse valued matrix with the given density.
-///
-/// Guarantees at least one edge per row so that a perfect matching exists.
+/// Generate a random n×n sparse cost matrix with the given density, holding
+/// a perfect assignment so that every solver succeeds.
 fn sparse_valued_matrix(
     seed: u64,
     n: usize,
     density: f64,
 ) -> ValuedCSR2D<usize, usize, usize, f64> {
-    let mut rng = XorShift64::from(seed);
-    let mut csr: ValuedCSR2D<usize, usize, usize, f64> =
-        ValuedCSR2D::with_sparse_shaped_capacity((n, n), target_edge_count(n, density));
-
-    // Ensure every row has at least one edge (required for feasibility).
-    for row in 0..n {
-        let col = random_index(&mut rng, n);
-        let cost = random_cost(&mut rng); // 0.01 .. 9.99
-        let _ = csr.add((row, col, cost));
-    }
-
-    // Add random additional edges according to the target density.
-    let target_edges = target_edge_count(n, density);
-    for _ in 0..target_edges {
-        let row = random_index(&mut rng, n);
-        let col = random_index(&mut rng, n);
-        let cost = random_cost(&mut rng);
-        let _ = csr.add((row, col, cost)); // silently ignore duplicate keys
-    }
-
-    csr
+    let config = SparseCostMatrixConfig {
+        feasibility: Feasibility::Assignment,
+        ..SparseCostMatrixConfig::new(n, n, density)
+    };
+    random_sparse_cost_matrix(seed, &config)
 }
 
 /// Benchmark LAPMOD vs SparseLAPJV for small/medium sizes and two densities.
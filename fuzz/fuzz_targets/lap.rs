@@ -2,7 +2,10 @@
 
 use geometric_traits::{
     impls::ValuedCSR2D,
-    test_utils::{check_lap_sparse_wrapper_invariants, check_lap_square_invariants},
+    test_utils::{
+        check_lap_sparse_wrapper_invariants, check_lap_square_invariants,
+        check_sparse_hungarian_invariants,
+    },
 };
 use honggfuzz::fuzz;
 
@@ -13,6 +16,7 @@ fn main() {
         fuzz!(|csr: Csr| {
             check_lap_sparse_wrapper_invariants(&csr);
             check_lap_square_invariants(&csr);
+            check_sparse_hungarian_invariants(&csr);
         });
     }
 }
@@ -0,0 +1,293 @@
+//! Python bindings for the `geometric-traits` crate.
+//!
+//! The module exposes a CSR matrix built from the arrays of a SciPy CSR
+//! matrix, and the sparse assignment, matching, community detection and
+//! connected components algorithms operating on it.
+
+use geometric_traits::{
+    impls::{SortedVec, SymmetricCSR2D, ValuedCSR2D},
+    prelude::*,
+    traits::{LouvainConfig, algorithms::connected_components::ConnectedComponentsResult},
+};
+use pyo3::{buffer::PyBuffer, exceptions::PyValueError, prelude::*};
+
+/// The matrix type backing [`PyCsrMatrix`].
+type Csr = ValuedCSR2D<usize, usize, usize, f64>;
+
+/// Converts any displayable error into a Python `ValueError`.
+fn value_error(error: impl core::fmt::Display) -> PyErr {
+    PyValueError::new_err(error.to_string())
+}
+
+/// Reads a one-dimensional array of indices exposed through the buffer
+/// protocol, accepting 32 and 64-bit integers.
+fn read_indices(py: Python<'_>, array: &Bound<'_, PyAny>, name: &str) -> PyResult<Vec<usize>> {
+    let indices: Vec<i64> = if let Ok(buffer) = PyBuffer::<i64>::get(array) {
+        buffer.to_vec(py)?
+    } else if let Ok(buffer) = PyBuffer::<i32>::get(array) {
+        buffer.to_vec(py)?.into_iter().map(i64::from).collect()
+    } else {
+        return Err(value_error(format!("`{name}` must be an array of 32 or 64-bit integers")));
+    };
+    indices
+        .into_iter()
+        .map(|index| {
+            usize::try_from(index)
+                .map_err(|_| value_error(format!("`{name}` contains the negative index {index}")))
+        })
+        .collect()
+}
+
+/// Reads a one-dimensional array of values exposed through the buffer
+/// protocol, accepting 32 and 64-bit floats.
+fn read_values(py: Python<'_>, array: &Bound<'_, PyAny>) -> PyResult<Vec<f64>> {
+    if let Ok(buffer) = PyBuffer::<f64>::get(array) {
+        buffer.to_vec(py)
+    } else if let Ok(buffer) = PyBuffer::<f32>::get(array) {
+        Ok(buffer.to_vec(py)?.into_iter().map(f64::from).collect())
+    } else {
+        Err(value_error("`data` must be an array of 32 or 64-bit floats"))
+    }
+}
+
+/// Builds the CSR matrix from its raw arrays, sorting the columns of each
+/// row as SciPy does not guarantee sorted indices.
+fn csr_from_arrays(
+    (number_of_rows, number_of_columns): (usize, usize),
+    indptr: &[usize],
+    indices: &[usize],
+    data: &[f64],
+) -> Result<Csr, String> {
+    if indptr.len() != number_of_rows + 1 {
+        return Err(format!(
+            "`indptr` has {} entries, expected one per row plus one ({})",
+            indptr.len(),
+            number_of_rows + 1
+        ));
+    }
+    if indptr[0] != 0 || indptr.windows(2).any(|window| window[0] > window[1]) {
+        return Err("`indptr` must start from zero and be non-decreasing".to_string());
+    }
+    let number_of_values = indptr[number_of_rows];
+    if indices.len() != number_of_values || data.len() != number_of_values {
+        return Err(format!(
+            "`indices` and `data` must have {number_of_values} entries, found {} and {}",
+            indices.len(),
+            data.len()
+        ));
+    }
+
+    let mut matrix: Csr = SparseMatrixMut::with_sparse_shaped_capacity(
+        (number_of_rows, number_of_columns),
+        number_of_values,
+    );
+    let mut row_entries: Vec<(usize, f64)> = Vec::new();
+    for (row, window) in indptr.windows(2).enumerate() {
+        row_entries.clear();
+        row_entries.extend(
+            indices[window[0]..window[1]]
+                .iter()
+                .copied()
+                .zip(data[window[0]..window[1]].iter().copied()),
+        );
+        row_entries.sort_unstable_by_key(|&(column, _)| column);
+        for &(column, value) in &row_entries {
+            if column >= number_of_columns {
+                return Err(format!("Row {row} has the out of bounds column {column}"));
+            }
+            MatrixMut::add(&mut matrix, (row, column, value))
+                .map_err(|_| format!("Row {row} repeats the column {column}"))?;
+        }
+    }
+    Ok(matrix)
+}
+
+/// Splits an assignment into the row and column lists, sorted by row.
+fn split_assignment(mut assignment: Vec<(usize, usize)>) -> (Vec<usize>, Vec<usize>) {
+    assignment.sort_unstable();
+    assignment.into_iter().unzip()
+}
+
+#[pyclass(name = "CsrMatrix", module = "geometric_traits", frozen)]
+/// Sparse matrix of 64-bit floats in compressed sparse row format.
+///
+/// The matrix is built from the `indptr`, `indices` and `data` arrays of the
+/// CSR format together with its shape. Explicitly stored zeros are kept.
+struct PyCsrMatrix {
+    matrix: Csr,
+}
+
+#[pymethods]
+impl PyCsrMatrix {
+    #[new]
+    /// Builds the matrix from its CSR arrays and its shape.
+    fn new(
+        py: Python<'_>,
+        indptr: &Bound<'_, PyAny>,
+        indices: &Bound<'_, PyAny>,
+        data: &Bound<'_, PyAny>,
+        shape: (usize, usize),
+    ) -> PyResult<Self> {
+        let indptr = read_indices(py, indptr, "indptr")?;
+        let indices = read_indices(py, indices, "indices")?;
+        let data = read_values(py, data)?;
+        let matrix = csr_from_arrays(shape, &indptr, &indices, &data).map_err(value_error)?;
+        Ok(Self { matrix })
+    }
+
+    #[staticmethod]
+    /// Builds the matrix from a SciPy CSR matrix or array.
+    fn from_scipy(py: Python<'_>, matrix: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let format: String = matrix.getattr("format")?.extract()?;
+        if format != "csr" {
+            return Err(value_error(format!(
+                "Expected a matrix in the `csr` format, found `{format}`; convert it with `.tocsr()`"
+            )));
+        }
+        Self::new(
+            py,
+            &matrix.getattr("indptr")?,
+            &matrix.getattr("indices")?,
+            &matrix.getattr("data")?,
+            matrix.getattr("shape")?.extract()?,
+        )
+    }
+
+    #[getter]
+    /// The number of rows and columns of the matrix.
+    fn shape(&self) -> (usize, usize) {
+        (self.matrix.number_of_rows(), self.matrix.number_of_columns())
+    }
+
+    #[getter]
+    /// The number of stored values.
+    fn nnz(&self) -> usize {
+        self.matrix.number_of_defined_values()
+    }
+
+    fn __repr__(&self) -> String {
+        let (number_of_rows, number_of_columns) = self.shape();
+        format!("CsrMatrix(shape=({number_of_rows}, {number_of_columns}), nnz={})", self.nnz())
+    }
+}
+
+#[pyfunction]
+/// Solves the square sparse assignment problem with LAPMOD.
+///
+/// All stored costs must be positive, finite and smaller than `max_cost`.
+/// Returns the assigned rows and columns, sorted by row.
+fn lapmod(
+    py: Python<'_>,
+    matrix: &PyCsrMatrix,
+    max_cost: f64,
+) -> PyResult<(Vec<usize>, Vec<usize>)> {
+    let assignment = py.detach(|| matrix.matrix.lapmod(max_cost)).map_err(value_error)?;
+    Ok(split_assignment(assignment))
+}
+
+#[pyfunction]
+/// Solves the rectangular sparse assignment problem with the Jaqaman diagonal
+/// cost extension, where leaving a row or column unassigned costs
+/// `padding_cost`.
+///
+/// Returns the assigned rows and columns, sorted by row.
+fn jaqaman(
+    py: Python<'_>,
+    matrix: &PyCsrMatrix,
+    padding_cost: f64,
+    max_cost: f64,
+) -> PyResult<(Vec<usize>, Vec<usize>)> {
+    let assignment =
+        py.detach(|| matrix.matrix.jaqaman(padding_cost, max_cost)).map_err(value_error)?;
+    Ok(split_assignment(assignment))
+}
+
+#[pyfunction]
+/// Computes a maximum cardinality bipartite matching with Hopcroft-Karp,
+/// ignoring the stored values.
+///
+/// Returns the matched rows and columns, sorted by row.
+fn hopcroft_karp(py: Python<'_>, matrix: &PyCsrMatrix) -> PyResult<(Vec<usize>, Vec<usize>)> {
+    let assignment = py.detach(|| matrix.matrix.hopcroft_karp()).map_err(value_error)?;
+    Ok(split_assignment(assignment))
+}
+
+#[pyfunction]
+#[pyo3(signature = (
+    matrix,
+    resolution = 1.0,
+    seed = 42,
+    modularity_threshold = 1.0e-7,
+    max_levels = 100,
+    max_local_passes = 100,
+))]
+/// Detects communities with Louvain on a symmetric matrix of positive
+/// weights.
+///
+/// Returns the community of each node and the modularity of the partition.
+fn louvain(
+    py: Python<'_>,
+    matrix: &PyCsrMatrix,
+    resolution: f64,
+    seed: u64,
+    modularity_threshold: f64,
+    max_levels: usize,
+    max_local_passes: usize,
+) -> PyResult<(Vec<usize>, f64)> {
+    let config =
+        LouvainConfig { resolution, modularity_threshold, max_levels, max_local_passes, seed };
+    let result =
+        py.detach(|| Louvain::<usize>::louvain(&matrix.matrix, &config)).map_err(value_error)?;
+    Ok((result.final_partition().to_vec(), result.final_modularity()))
+}
+
+#[pyfunction]
+/// Computes the connected components of the undirected graph whose edges are
+/// the stored entries of a square matrix, in either direction.
+///
+/// Returns the number of components and the component of each node.
+fn connected_components(py: Python<'_>, matrix: &PyCsrMatrix) -> PyResult<(usize, Vec<usize>)> {
+    let (number_of_rows, number_of_columns) = matrix.shape();
+    if number_of_rows != number_of_columns {
+        return Err(value_error(format!(
+            "Expected a square matrix, found shape ({number_of_rows}, {number_of_columns})"
+        )));
+    }
+    py.detach(|| {
+        let mut edges: Vec<(usize, usize)> = SparseMatrix::sparse_coordinates(&matrix.matrix)
+            .map(|(row, column)| (row.min(column), row.max(column)))
+            .collect();
+        edges.sort_unstable();
+        edges.dedup();
+
+        let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+            .expected_number_of_symbols(number_of_rows)
+            .symbols((0..number_of_rows).enumerate())
+            .build()
+            .map_err(value_error)?;
+        let edges: SymmetricCSR2D<_> = UndiEdgesBuilder::default()
+            .expected_number_of_edges(edges.len())
+            .expected_shape(number_of_rows)
+            .edges(edges.into_iter())
+            .build()
+            .map_err(value_error)?;
+        let graph: UndiGraph<usize> = UndiGraph::from((nodes, edges));
+
+        let components: ConnectedComponentsResult<'_, _, usize> =
+            graph.connected_components().map_err(value_error)?;
+        Ok((components.number_of_components(), components.component_identifiers().collect()))
+    })
+}
+
+#[pymodule]
+#[pyo3(name = "geometric_traits")]
+/// Sparse assignment, matching and graph algorithms from `geometric-traits`.
+fn geometric_traits_python(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyCsrMatrix>()?;
+    module.add_function(wrap_pyfunction!(lapmod, module)?)?;
+    module.add_function(wrap_pyfunction!(jaqaman, module)?)?;
+    module.add_function(wrap_pyfunction!(hopcroft_karp, module)?)?;
+    module.add_function(wrap_pyfunction!(louvain, module)?)?;
+    module.add_function(wrap_pyfunction!(crate::connected_components, module)?)?;
+    Ok(())
+}
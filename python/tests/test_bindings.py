@@ -0,0 +1,87 @@
+"""Tests for the Python bindings, using only the standard library."""
+
+import unittest
+from array import array
+from types import SimpleNamespace
+
+import geometric_traits as gt
+
+
+def csr(rows, shape):
+    """Builds a CsrMatrix from a list of {column: value} rows."""
+    indptr, indices, data = [0], [], []
+    for row in rows:
+        indices.extend(row.keys())
+        data.extend(row.values())
+        indptr.append(len(indices))
+    return gt.CsrMatrix(array("q", indptr), array("i", indices), array("d", data), shape)
+
+
+class TestCsrMatrix(unittest.TestCase):
+    def test_from_scipy_like_object(self):
+        matrix = gt.CsrMatrix.from_scipy(
+            SimpleNamespace(
+                format="csr",
+                shape=(2, 3),
+                indptr=array("i", [0, 2, 3]),
+                indices=array("i", [2, 0, 1]),
+                data=array("f", [1.5, 2.5, 3.5]),
+            )
+        )
+        self.assertEqual(matrix.shape, (2, 3))
+        self.assertEqual(matrix.nnz, 3)
+        self.assertEqual(repr(matrix), "CsrMatrix(shape=(2, 3), nnz=3)")
+
+    def test_invalid_arrays_are_rejected(self):
+        with self.assertRaises(ValueError):
+            gt.CsrMatrix(array("q", [0, 1]), array("q", [0]), array("d", [1.0]), (2, 2))
+        with self.assertRaises(ValueError):
+            gt.CsrMatrix(array("q", [0, 2]), array("q", [1, 1]), array("d", [1.0, 2.0]), (1, 2))
+        with self.assertRaises(ValueError):
+            gt.CsrMatrix(array("q", [0, 1]), array("q", [5]), array("d", [1.0]), (1, 2))
+        with self.assertRaises(ValueError):
+            gt.CsrMatrix(array("q", [0, 1]), array("q", [0]), array("b", [1]), (1, 1))
+        with self.assertRaises(ValueError):
+            gt.CsrMatrix.from_scipy(SimpleNamespace(format="coo"))
+
+
+class TestAlgorithms(unittest.TestCase):
+    def test_lapmod(self):
+        matrix = csr([{0: 4.0, 1: 1.0}, {0: 2.0, 1: 8.0}], (2, 2))
+        self.assertEqual(gt.lapmod(matrix, 100.0), ([0, 1], [1, 0]))
+        with self.assertRaises(ValueError):
+            gt.lapmod(csr([{0: 1.0}, {0: 1.0}], (2, 2)), 100.0)
+
+    def test_jaqaman(self):
+        matrix = csr([{0: 1.0}, {0: 2.0, 1: 3.0}, {}], (3, 2))
+        self.assertEqual(gt.jaqaman(matrix, 10.0, 100.0), ([0, 1], [0, 1]))
+
+    def test_hopcroft_karp(self):
+        matrix = csr([{0: 1.0, 1: 1.0}, {0: 1.0}, {0: 1.0}], (3, 2))
+        rows, columns = gt.hopcroft_karp(matrix)
+        self.assertEqual(len(rows), 2)
+        self.assertEqual(sorted(columns), [0, 1])
+
+    def test_louvain(self):
+        matrix = csr(
+            [{1: 1.0, 2: 1.0}, {0: 1.0, 2: 1.0}, {0: 1.0, 1: 1.0, 3: 0.1},
+             {2: 0.1, 4: 1.0, 5: 1.0}, {3: 1.0, 5: 1.0}, {3: 1.0, 4: 1.0}],
+            (6, 6),
+        )
+        partition, modularity = gt.louvain(matrix, resolution=1.0, seed=7)
+        self.assertEqual(len(set(partition[:3])), 1)
+        self.assertEqual(len(set(partition[3:])), 1)
+        self.assertNotEqual(partition[0], partition[3])
+        self.assertGreater(modularity, 0.4)
+        with self.assertRaises(ValueError):
+            gt.louvain(csr([{1: 1.0}, {}], (2, 2)))
+
+    def test_connected_components(self):
+        matrix = csr([{1: 1.0}, {}, {}, {2: 1.0, 4: 1.0}, {}], (5, 5))
+        self.assertEqual(gt.connected_components(matrix), (2, [0, 0, 1, 1, 1]))
+        with self.assertRaises(ValueError):
+            gt.connected_components(csr([{}], (1, 2)))
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -2,10 +2,18 @@
 
 pub mod bipartite_graph_error;
 pub mod builder;
+#[cfg(feature = "alloc")]
+pub mod geometric_error;
+pub mod invariant_error;
 pub mod monopartite_graph_error;
 pub mod nodes;
+pub mod rank_select_error;
 /// Submodule defining errors related to sorted data structures.
 pub mod sorted_error;
 pub use bipartite_graph_error::BipartiteError;
+#[cfg(feature = "alloc")]
+pub use geometric_error::{ErrorContext, GeometricError};
+pub use invariant_error::InvariantError;
 pub use monopartite_graph_error::MonopartiteError;
+pub use rank_select_error::RankSelectError;
 pub use sorted_error::SortedError;
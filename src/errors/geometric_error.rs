@@ -0,0 +1,262 @@
+//! Submodule providing [`GeometricError`], the crate-level error gathering the
+//! errors of the matrices, builders and algorithms of the crate.
+//!
+//! The specific error types remain the ones returned by the operations, so
+//! that callers may still match on them. Converting them into a
+//! [`GeometricError`] with `?` lets a pipeline chaining several operations
+//! return a single error type, and [`ErrorContext::context`] records which
+//! operation of the pipeline failed.
+
+use alloc::boxed::Box;
+use core::{error::Error, fmt::Debug};
+
+use super::{InvariantError, RankSelectError, builder::edges::EdgesBuilderError};
+#[cfg(feature = "heapless")]
+use crate::traits::HeaplessSearchError;
+use crate::{
+    errors::{
+        bipartite_graph_error::BipartiteAlgorithmError,
+        monopartite_graph_error::MonopartiteAlgorithmError,
+    },
+    impls::MutabilityError,
+    traits::{
+        BlossomVError, CrouseError, DiffusionError, Edges, EffectiveResistanceError,
+        FloydWarshallError, HopcroftKarpError, KahnError, LAPError, LaplacianError, Matrix,
+        Matrix2D, MicaliVaziraniError, MinimumPathCoverError, ModularityError,
+        PairwiseDijkstraError, SpectralSparsifierError,
+    },
+};
+
+/// A type-erased error, used for the errors generic over a matrix or a graph.
+type BoxedError = Box<dyn Error + Send + Sync + 'static>;
+
+#[derive(Debug, thiserror::Error)]
+/// Crate-level error gathering the errors of the matrices, builders and
+/// algorithms of the crate.
+///
+/// The errors generic over a matrix or a graph are stored type-erased, and
+/// can be recovered with [`downcast_ref`](GeometricError::downcast_ref).
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{
+///     errors::{ErrorContext, GeometricError},
+///     impls::ValuedCSR2D,
+///     prelude::*,
+///     traits::LAPError,
+/// };
+///
+/// fn assign(costs: &ValuedCSR2D<usize, usize, usize, f64>) -> Result<usize, GeometricError> {
+///     let assignment = costs.lapmod(10.0).context("linking the frames")?;
+///     Ok(assignment.len())
+/// }
+///
+/// let mut costs: ValuedCSR2D<usize, usize, usize, f64> =
+///     SparseMatrixMut::with_sparse_shape((2, 2));
+/// MatrixMut::add(&mut costs, (0, 0, -1.0)).unwrap();
+/// MatrixMut::add(&mut costs, (1, 1, 1.0)).unwrap();
+///
+/// let error = assign(&costs).unwrap_err();
+/// assert_eq!(error.context(), Some("linking the frames"));
+/// assert!(matches!(error.root_cause(), GeometricError::LAP(LAPError::NegativeValues)));
+/// assert_eq!(error.to_string(), "linking the frames: The matrix contains negative values.");
+/// ```
+pub enum GeometricError {
+    /// Error raised while mutating a matrix.
+    #[error("{0}")]
+    Mutability(BoxedError),
+    /// Error raised while building a graph or a matrix from its edges.
+    #[error("{0}")]
+    EdgesBuilder(BoxedError),
+    /// A structural invariant of a matrix or a graph does not hold.
+    #[error(transparent)]
+    Invariant(#[from] InvariantError),
+    /// A rank or select query was made on an invalid index.
+    #[error(transparent)]
+    RankSelect(#[from] RankSelectError),
+    /// Error raised by the weighted assignment algorithms.
+    #[error(transparent)]
+    LAP(#[from] LAPError),
+    /// Error raised by the Crouse rectangular assignment algorithm.
+    #[error(transparent)]
+    Crouse(#[from] CrouseError),
+    /// Error raised by the Hopcroft-Karp matching algorithm.
+    #[error(transparent)]
+    HopcroftKarp(#[from] HopcroftKarpError),
+    /// Error raised by the Micali-Vazirani matching algorithm.
+    #[error(transparent)]
+    MicaliVazirani(#[from] MicaliVaziraniError),
+    /// Error raised by the Blossom V matching algorithm.
+    #[error(transparent)]
+    BlossomV(#[from] BlossomVError),
+    /// Error raised by the all-pairs Dijkstra search.
+    #[error(transparent)]
+    PairwiseDijkstra(#[from] PairwiseDijkstraError),
+    /// Error raised by the heat-kernel diffusion.
+    #[error(transparent)]
+    Diffusion(#[from] DiffusionError),
+    /// Error raised by the effective resistance approximation.
+    #[error(transparent)]
+    EffectiveResistance(#[from] EffectiveResistanceError),
+    /// Error raised by the Floyd-Warshall search.
+    #[error(transparent)]
+    FloydWarshall(#[from] FloydWarshallError),
+    /// Error raised by the fixed-capacity searches.
+    #[cfg(feature = "heapless")]
+    #[error(transparent)]
+    HeaplessSearch(#[from] HeaplessSearchError),
+    /// Error raised by Kahn's topological sort.
+    #[error(transparent)]
+    Kahn(#[from] KahnError),
+    /// Error raised by the construction of a graph Laplacian.
+    #[error(transparent)]
+    Laplacian(#[from] LaplacianError),
+    /// Error raised by the minimum path cover of a directed acyclic graph.
+    #[error(transparent)]
+    MinimumPathCover(#[from] MinimumPathCoverError),
+    /// Error raised by the modularity-based community detections.
+    #[error(transparent)]
+    Modularity(#[from] ModularityError),
+    /// Error raised by an algorithm on a monopartite graph.
+    #[error(transparent)]
+    MonopartiteAlgorithm(#[from] MonopartiteAlgorithmError),
+    /// Error raised by the spectral sparsification.
+    #[error(transparent)]
+    SpectralSparsifier(#[from] SpectralSparsifierError),
+    /// Error raised by an algorithm on a bipartite graph.
+    #[error(transparent)]
+    BipartiteAlgorithm(#[from] BipartiteAlgorithmError),
+    /// Error raised by the operation described by the context.
+    #[error("{context}: {error}")]
+    Context {
+        /// The operation which failed.
+        context: &'static str,
+        /// The error raised by the operation.
+        error: Box<GeometricError>,
+    },
+}
+
+impl GeometricError {
+    /// Wraps the error with the description of the operation which failed.
+    ///
+    /// # Arguments
+    ///
+    /// * `context`: The operation which failed.
+    #[inline]
+    #[must_use]
+    pub fn with_context(self, context: &'static str) -> Self {
+        Self::Context { context, error: Box::new(self) }
+    }
+
+    /// Returns the outermost context of the error, if any.
+    #[inline]
+    #[must_use]
+    pub fn context(&self) -> Option<&'static str> {
+        match self {
+            Self::Context { context, .. } => Some(context),
+            _ => None,
+        }
+    }
+
+    /// Returns the contexts of the error, from the outermost to the
+    /// innermost.
+    #[inline]
+    pub fn contexts(&self) -> impl Iterator<Item = &'static str> + '_ {
+        core::iter::successors(Some(self), |error| {
+            match error {
+                Self::Context { error, .. } => Some(error.as_ref()),
+                _ => None,
+            }
+        })
+        .filter_map(Self::context)
+    }
+
+    /// Returns the error stripped of its contexts.
+    #[inline]
+    #[must_use]
+    pub fn root_cause(&self) -> &Self {
+        match self {
+            Self::Context { error, .. } => error.root_cause(),
+            _ => self,
+        }
+    }
+
+    /// Returns the type-erased error of a matrix or a builder as the provided
+    /// type, looking through the contexts, or `None` if it is of another
+    /// type. The errors of the algorithms are matched on their variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{
+    ///     errors::GeometricError,
+    ///     impls::{CSR2D, MutabilityError},
+    ///     prelude::*,
+    /// };
+    ///
+    /// let mut matrix: CSR2D<usize, usize, usize> = SparseMatrixMut::with_sparse_shape((2, 2));
+    /// MatrixMut::add(&mut matrix, (1, 0)).unwrap();
+    /// let error: GeometricError = MatrixMut::add(&mut matrix, (0, 1)).unwrap_err().into();
+    ///
+    /// assert_eq!(
+    ///     error.with_context("loading").downcast_ref::<MutabilityError<CSR2D<usize, usize, usize>>>(),
+    ///     Some(&MutabilityError::UnorderedCoordinate((0, 1)))
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn downcast_ref<E: Error + 'static>(&self) -> Option<&E> {
+        match self.root_cause() {
+            Self::Mutability(error) | Self::EdgesBuilder(error) => error.downcast_ref(),
+            _ => None,
+        }
+    }
+}
+
+impl<M> From<MutabilityError<M>> for GeometricError
+where
+    M: Matrix2D + 'static,
+    M::Coordinates: Send + Sync,
+{
+    #[inline]
+    fn from(error: MutabilityError<M>) -> Self {
+        Self::Mutability(Box::new(error))
+    }
+}
+
+impl<E> From<EdgesBuilderError<E>> for GeometricError
+where
+    E: Edges + 'static,
+    E::EdgeId: Send + Sync,
+    <E::Matrix as Matrix>::Coordinates: Send + Sync,
+    EdgesBuilderError<E>: Debug,
+{
+    #[inline]
+    fn from(error: EdgesBuilderError<E>) -> Self {
+        Self::EdgesBuilder(Box::new(error))
+    }
+}
+
+/// Trait attaching the description of the failed operation to the error of a
+/// result, converting it into a [`GeometricError`].
+pub trait ErrorContext<T> {
+    /// Converts the error of the result into a [`GeometricError`] wrapped
+    /// with the description of the operation which failed.
+    ///
+    /// # Arguments
+    ///
+    /// * `context`: The operation which failed.
+    ///
+    /// # Errors
+    ///
+    /// Returns the converted error if the result is an error.
+    fn context(self, context: &'static str) -> Result<T, GeometricError>;
+}
+
+impl<T, E: Into<GeometricError>> ErrorContext<T> for Result<T, E> {
+    #[inline]
+    fn context(self, context: &'static str) -> Result<T, GeometricError> {
+        self.map_err(|error| error.into().with_context(context))
+    }
+}
@@ -0,0 +1,112 @@
+//! Submodule defining the error raised when a data structure violates one of
+//! its structural invariants.
+//!
+//! The mutable API of the sparse matrices and graphs maintains these
+//! invariants, but data structures assembled from external parts, such as
+//! deserialized buffers or borrowed slices, must be checked explicitly.
+
+/// Error raised when a data structure violates one of its structural
+/// invariants.
+///
+/// The row and column indices are reported as `usize`, independently of the
+/// index types of the data structure.
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InvariantError {
+    /// The offsets of a compressed sparse row matrix do not start with a zero.
+    #[error("The offsets must start with a zero.")]
+    OffsetsMustStartWithZero,
+    /// The offsets describe more rows than the matrix has.
+    #[error("The offsets describe {described_rows} rows, but the matrix has {number_of_rows}.")]
+    TooManyOffsets {
+        /// The number of rows described by the offsets.
+        described_rows: usize,
+        /// The number of rows of the matrix.
+        number_of_rows: usize,
+    },
+    /// The last offset does not match the number of column indices.
+    #[error(
+        "The last offset {last_offset} does not match the {number_of_column_indices} column indices."
+    )]
+    LastOffsetMismatch {
+        /// The last offset.
+        last_offset: usize,
+        /// The number of column indices.
+        number_of_column_indices: usize,
+    },
+    /// The offsets decrease from a row to the next one.
+    #[error("The offsets decrease after the row {row}.")]
+    DecreasingOffsets {
+        /// The row whose end offset is smaller than its start offset.
+        row: usize,
+    },
+    /// The column indices of a row are not strictly increasing.
+    #[error("The column indices of the row {row} are not strictly increasing.")]
+    UnsortedRow {
+        /// The row with unsorted or duplicated column indices.
+        row: usize,
+    },
+    /// A column index is not smaller than the number of columns.
+    #[error(
+        "The column {column} of the row {row} is out of bounds for {number_of_columns} columns."
+    )]
+    ColumnOutOfBounds {
+        /// The row storing the column index.
+        row: usize,
+        /// The out of bounds column index.
+        column: usize,
+        /// The number of columns of the matrix.
+        number_of_columns: usize,
+    },
+    /// A cached count does not match the one recomputed from the data.
+    #[error("The cached number of {counted} is {cached}, but the data holds {actual}.")]
+    CachedCountMismatch {
+        /// What is being counted.
+        counted: &'static str,
+        /// The cached count.
+        cached: usize,
+        /// The count recomputed from the data.
+        actual: usize,
+    },
+    /// The number of values does not match the number of column indices.
+    #[error("Values length mismatch: expected {expected}, got {actual}")]
+    ValuesLengthMismatch {
+        /// The number of column indices.
+        expected: usize,
+        /// The number of values.
+        actual: usize,
+    },
+    /// The matrix must be square.
+    #[error(
+        "The matrix must be square, but has {number_of_rows} rows and {number_of_columns} columns."
+    )]
+    NotSquare {
+        /// The number of rows of the matrix.
+        number_of_rows: usize,
+        /// The number of columns of the matrix.
+        number_of_columns: usize,
+    },
+    /// A stored entry of a symmetric matrix has no transposed counterpart.
+    #[error("The entry ({row}, {column}) has no symmetric counterpart.")]
+    AsymmetricEntry {
+        /// The row of the entry.
+        row: usize,
+        /// The column of the entry.
+        column: usize,
+    },
+    /// An entry is stored below the diagonal of an upper triangular matrix.
+    #[error("The entry ({row}, {column}) is below the diagonal.")]
+    EntryBelowDiagonal {
+        /// The row of the entry.
+        row: usize,
+        /// The column of the entry.
+        column: usize,
+    },
+    /// The edges refer to nodes missing from the vocabulary.
+    #[error("The edges refer to {referenced} nodes, but the vocabulary has {number_of_nodes}.")]
+    MissingNodes {
+        /// The number of nodes referred to by the edges.
+        referenced: usize,
+        /// The number of nodes of the vocabulary.
+        number_of_nodes: usize,
+    },
+}
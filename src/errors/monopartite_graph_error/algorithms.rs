@@ -2,10 +2,11 @@
 //! [`crate::traits::MonopartiteGraph`]s.
 
 use crate::traits::{
-    BiconnectedComponentsError, DiameterError, EssentialCyclesError, K4HomeomorphError,
-    K23HomeomorphError, K33HomeomorphError, MinimumCycleBasisError, ModularityError,
-    OuterplanarityError, PlanarityError, RelevantCyclesError,
-    connected_components::ConnectedComponentsError,
+    AssortativityError, BiconnectedComponentsError, DbscanError, DiameterError,
+    EssentialCyclesError, HierarchicalClusteringError, K4HomeomorphError, K23HomeomorphError,
+    K33HomeomorphError, LayoutError, MinimumCycleBasisError, ModularityError, MotifCensusError,
+    NodeCoverError, OuterplanarityError, PlanarityError, RelevantCyclesError, RichClubError,
+    RobustnessError, connected_components::ConnectedComponentsError,
 };
 
 #[derive(Debug, thiserror::Error, Clone, PartialEq, Eq)]
@@ -48,4 +49,28 @@ pub enum MonopartiteAlgorithmError {
     /// Error raised while computing modularity-based communities.
     #[error("{0}")]
     ModularityError(ModularityError),
+    /// Error raised while computing a hierarchical clustering.
+    #[error("{0}")]
+    HierarchicalClusteringError(HierarchicalClusteringError),
+    /// Error raised while computing a DBSCAN clustering.
+    #[error("{0}")]
+    DbscanError(DbscanError),
+    /// Error raised while computing an assortativity coefficient.
+    #[error("{0}")]
+    AssortativityError(AssortativityError),
+    /// Error raised while computing rich-club coefficients.
+    #[error("{0}")]
+    RichClubError(RichClubError),
+    /// Error raised while computing a motif census.
+    #[error("{0}")]
+    MotifCensusError(MotifCensusError),
+    /// Error raised while computing a robustness profile.
+    #[error("{0}")]
+    RobustnessError(RobustnessError),
+    /// Error raised while approximating a vertex cover or dominating set.
+    #[error("{0}")]
+    NodeCoverError(NodeCoverError),
+    /// Error raised while computing a force-directed layout.
+    #[error("{0}")]
+    LayoutError(LayoutError),
 }
@@ -0,0 +1,41 @@
+//! Submodule defining the error raised by the fallible rank and select
+//! queries of the sparse matrices.
+//!
+//! The panicking [`rank`](crate::traits::RankSelectSparseMatrix::rank) and
+//! [`select`](crate::traits::RankSelectSparseMatrix::select) queries are meant
+//! for indices which have already been validated, while their fallible
+//! counterparts report the invalid indices of untrusted input.
+
+/// Error raised by a rank or select query on an invalid index.
+///
+/// The indices are reported as `usize`, independently of the index types of
+/// the matrix.
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RankSelectError {
+    /// The row is not smaller than the number of rows of the matrix.
+    #[error("The row {row} is out of bounds for a matrix with {number_of_rows} rows.")]
+    RowOutOfBounds {
+        /// The requested row.
+        row: usize,
+        /// The number of rows of the matrix.
+        number_of_rows: usize,
+    },
+    /// The entry is not defined in the matrix.
+    #[error("The entry at row {row} and column {column} is not defined.")]
+    MissingEntry {
+        /// The row of the entry.
+        row: usize,
+        /// The column of the entry.
+        column: usize,
+    },
+    /// The sparse index is not smaller than the number of defined values.
+    #[error(
+        "The sparse index {sparse_index} is out of bounds for a matrix with {number_of_defined_values} defined values."
+    )]
+    SparseIndexOutOfBounds {
+        /// The requested sparse index.
+        sparse_index: usize,
+        /// The number of defined values of the matrix.
+        number_of_defined_values: usize,
+    },
+}
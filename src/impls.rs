@@ -16,6 +16,10 @@ mod bit_square_matrix;
 #[cfg(feature = "alloc")]
 pub use bit_square_matrix::BitSquareMatrix;
 #[cfg(feature = "alloc")]
+mod disjoint_set;
+#[cfg(feature = "alloc")]
+pub use disjoint_set::DisjointSet;
+#[cfg(feature = "alloc")]
 mod edge_contexts;
 #[cfg(feature = "alloc")]
 pub use edge_contexts::EdgeContexts;
@@ -55,6 +59,8 @@ pub use error::*;
 #[cfg(feature = "alloc")]
 pub mod compact_matrix;
 #[cfg(feature = "alloc")]
+pub mod coo_matrix;
+#[cfg(feature = "alloc")]
 pub mod generic_bimatrix2d;
 pub mod generic_iterators;
 #[cfg(feature = "alloc")]
@@ -74,6 +80,8 @@ mod vector;
 #[cfg(feature = "alloc")]
 pub use compact_matrix::{CompactMatrix, compactify};
 #[cfg(feature = "alloc")]
+pub use coo_matrix::COOMatrix;
+#[cfg(feature = "alloc")]
 pub use generic_bimatrix2d::GenericBiMatrix2D;
 #[cfg(feature = "alloc")]
 pub use generic_implicit_valued_matrix2d::GenericImplicitValuedMatrix2D;
@@ -0,0 +1,260 @@
+//! Submodule providing the `COOMatrix` type, a staging area collecting the
+//! entries of a sparse matrix in any order before converting them into a
+//! compressed [`CSR2D`] or [`ValuedCSR2D`].
+use alloc::vec::Vec;
+use core::{fmt::Debug, marker::PhantomData};
+
+use multi_ranged::Step;
+use num_traits::{AsPrimitive, Zero};
+
+use super::{CSR2D, MutabilityError, ValuedCSR2D};
+use crate::traits::{MatrixMut, PositiveInteger, SparseMatrixMut, TryFromUsize};
+
+/// Result of the conversion into a [`ValuedCSR2D`].
+type ValuedResult<SparseIndex, RowIndex, ColumnIndex, Value> = Result<
+    ValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value>,
+    MutabilityError<ValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value>>,
+>;
+
+/// Error of the conversion into a [`CSR2D`].
+type CSRError<SparseIndex, RowIndex, ColumnIndex> =
+    MutabilityError<CSR2D<SparseIndex, RowIndex, ColumnIndex>>;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// A coordinate list of the entries of a sparse matrix.
+///
+/// Compressed matrices such as [`CSR2D`] only accept entries sorted by row
+/// and column. A `COOMatrix` instead accepts entries in any order, possibly
+/// with repeated coordinates, and sorts them once when it is finalized.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{impls::COOMatrix, prelude::*};
+///
+/// let mut staging: COOMatrix<usize, usize, usize, f64> = COOMatrix::default();
+/// staging.push(2, 0, 1.0);
+/// staging.push(0, 1, 2.0);
+/// staging.push(2, 0, 3.0);
+///
+/// // Duplicated coordinates are merged with the provided reducer.
+/// let matrix = staging.finalize_with(|left, right| left + right).unwrap();
+/// assert_eq!(SparseMatrix::sparse_coordinates(&matrix).collect::<Vec<_>>(), vec![(0, 1), (2, 0)]);
+/// assert_eq!(matrix.sparse_values().collect::<Vec<_>>(), vec![2.0, 4.0]);
+/// ```
+pub struct COOMatrix<SparseIndex, RowIndex, ColumnIndex, Value> {
+    /// The minimal number of rows of the finalized matrix.
+    number_of_rows: RowIndex,
+    /// The minimal number of columns of the finalized matrix.
+    number_of_columns: ColumnIndex,
+    /// The entries, in insertion order.
+    entries: Vec<(RowIndex, ColumnIndex, Value)>,
+    /// The sparse index type of the finalized matrix.
+    _sparse_index: PhantomData<SparseIndex>,
+}
+
+impl<SparseIndex, RowIndex: Zero, ColumnIndex: Zero, Value> Default
+    for COOMatrix<SparseIndex, RowIndex, ColumnIndex, Value>
+{
+    #[inline]
+    fn default() -> Self {
+        Self::with_shaped_capacity((RowIndex::zero(), ColumnIndex::zero()), 0)
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value>
+    COOMatrix<SparseIndex, RowIndex, ColumnIndex, Value>
+{
+    /// Creates a new coordinate list whose finalized matrix has at least the
+    /// provided shape, with room for the provided number of entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape`: The minimal number of rows and columns of the finalized
+    ///   matrix, which grows to fit the entries as needed.
+    /// * `capacity`: The number of entries to allocate for.
+    #[inline]
+    #[must_use]
+    pub fn with_shaped_capacity(
+        (number_of_rows, number_of_columns): (RowIndex, ColumnIndex),
+        capacity: usize,
+    ) -> Self {
+        Self {
+            number_of_rows,
+            number_of_columns,
+            entries: Vec::with_capacity(capacity),
+            _sparse_index: PhantomData,
+        }
+    }
+
+    /// Creates a new coordinate list whose finalized matrix has at least the
+    /// provided shape.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape`: The minimal number of rows and columns of the finalized
+    ///   matrix, which grows to fit the entries as needed.
+    #[inline]
+    #[must_use]
+    pub fn with_shape(shape: (RowIndex, ColumnIndex)) -> Self {
+        Self::with_shaped_capacity(shape, 0)
+    }
+
+    /// Appends an entry, whatever its position relative to the previous ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `row`: The row of the entry.
+    /// * `column`: The column of the entry.
+    /// * `value`: The value of the entry.
+    #[inline]
+    pub fn push(&mut self, row: RowIndex, column: ColumnIndex, value: Value) {
+        self.entries.push((row, column, value));
+    }
+
+    /// Returns the number of entries, counting repeated coordinates.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether no entry has been pushed.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the entries, in insertion order.
+    #[inline]
+    #[must_use]
+    pub fn entries(&self) -> &[(RowIndex, ColumnIndex, Value)] {
+        &self.entries
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value> COOMatrix<SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize + Debug,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex> + Debug,
+{
+    /// Sorts the entries by their coordinates, keeping the insertion order
+    /// of the entries with repeated coordinates.
+    fn sorted_entries(self) -> Vec<(RowIndex, ColumnIndex, Value)> {
+        let mut entries = self.entries;
+        entries.sort_by_key(|&(row, column, _)| (row, column));
+        entries
+    }
+
+    /// Returns the number of entries as a sparse index, if it fits.
+    fn sparse_capacity(&self) -> Option<SparseIndex> {
+        SparseIndex::try_from_usize(self.entries.len()).ok()
+    }
+
+    /// Converts the entries into a [`ValuedCSR2D`], rejecting repeated
+    /// coordinates.
+    ///
+    /// # Errors
+    ///
+    /// * [`MutabilityError::DuplicatedEntry`] if two entries share their
+    ///   coordinates.
+    /// * [`MutabilityError::MaxedOutSparseIndex`] if the sparse index type
+    ///   cannot count the entries.
+    #[inline]
+    pub fn finalize(self) -> ValuedResult<SparseIndex, RowIndex, ColumnIndex, Value> {
+        let Some(capacity) = self.sparse_capacity() else {
+            return Err(MutabilityError::MaxedOutSparseIndex);
+        };
+        let shape = (self.number_of_rows, self.number_of_columns);
+        let mut matrix = ValuedCSR2D::with_sparse_shaped_capacity(shape, capacity);
+        matrix.extend(self.sorted_entries())?;
+        Ok(matrix)
+    }
+
+    /// Converts the entries into a [`ValuedCSR2D`], merging the values of
+    /// entries with repeated coordinates.
+    ///
+    /// # Arguments
+    ///
+    /// * `reducer`: The function merging the value accumulated so far with the
+    ///   value of the next entry with the same coordinates, in insertion order.
+    ///
+    /// # Errors
+    ///
+    /// * [`MutabilityError::MaxedOutSparseIndex`] if the sparse index type
+    ///   cannot count the entries.
+    #[inline]
+    pub fn finalize_with<F>(
+        self,
+        mut reducer: F,
+    ) -> ValuedResult<SparseIndex, RowIndex, ColumnIndex, Value>
+    where
+        F: FnMut(Value, Value) -> Value,
+    {
+        let Some(capacity) = self.sparse_capacity() else {
+            return Err(MutabilityError::MaxedOutSparseIndex);
+        };
+        let shape = (self.number_of_rows, self.number_of_columns);
+        let mut matrix = ValuedCSR2D::with_sparse_shaped_capacity(shape, capacity);
+        let mut entries = self.sorted_entries().into_iter().peekable();
+        while let Some((row, column, mut value)) = entries.next() {
+            while let Some((_, _, next)) = entries
+                .next_if(|&(next_row, next_column, _)| (next_row, next_column) == (row, column))
+            {
+                value = reducer(value, next);
+            }
+            matrix.add((row, column, value))?;
+        }
+        Ok(matrix)
+    }
+
+    /// Converts the coordinates of the entries into a [`CSR2D`], discarding
+    /// the values and merging repeated coordinates.
+    ///
+    /// # Errors
+    ///
+    /// * [`MutabilityError::MaxedOutSparseIndex`] if the sparse index type
+    ///   cannot count the entries.
+    #[inline]
+    pub fn finalize_structure(
+        self,
+    ) -> Result<
+        CSR2D<SparseIndex, RowIndex, ColumnIndex>,
+        CSRError<SparseIndex, RowIndex, ColumnIndex>,
+    > {
+        let Some(capacity) = self.sparse_capacity() else {
+            return Err(MutabilityError::MaxedOutSparseIndex);
+        };
+        let shape = (self.number_of_rows, self.number_of_columns);
+        let mut coordinates: Vec<(RowIndex, ColumnIndex)> =
+            self.entries.into_iter().map(|(row, column, _)| (row, column)).collect();
+        coordinates.sort_unstable();
+        coordinates.dedup();
+        let mut matrix = CSR2D::with_sparse_shaped_capacity(shape, capacity);
+        matrix.extend(coordinates)?;
+        Ok(matrix)
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value> Extend<(RowIndex, ColumnIndex, Value)>
+    for COOMatrix<SparseIndex, RowIndex, ColumnIndex, Value>
+{
+    #[inline]
+    fn extend<I: IntoIterator<Item = (RowIndex, ColumnIndex, Value)>>(&mut self, entries: I) {
+        self.entries.extend(entries);
+    }
+}
+
+impl<SparseIndex, RowIndex: Zero, ColumnIndex: Zero, Value>
+    FromIterator<(RowIndex, ColumnIndex, Value)>
+    for COOMatrix<SparseIndex, RowIndex, ColumnIndex, Value>
+{
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (RowIndex, ColumnIndex, Value)>>(entries: I) -> Self {
+        let mut matrix = Self::default();
+        matrix.extend(entries);
+        matrix
+    }
+}
@@ -30,7 +30,29 @@ pub mod csr2d_empty_rows_indices;
 pub use csr2d_empty_rows_indices::CSR2DEmptyRowIndices;
 pub mod csr2d_non_empty_rows_indices;
 pub use csr2d_non_empty_rows_indices::CSR2DNonEmptyRowIndices;
+#[cfg(feature = "alloc")]
+mod csr2d_slice;
+#[cfg(feature = "alloc")]
+pub use csr2d_slice::{CSR2DSlice, CSR2DSliceError, ValuedCSR2DSlice};
+#[cfg(feature = "alloc")]
+mod csc2d;
+#[cfg(feature = "alloc")]
+pub use csc2d::{CSC2D, ValuedCSC2D};
+#[cfg(feature = "alloc")]
+mod csr2d_transpose;
+#[cfg(feature = "alloc")]
+mod index_width;
+#[cfg(feature = "alloc")]
+pub use index_width::{CompactCSR2D, CompactValuedCSR2D, Compacted, IndexWidth, IndexWidthError};
 mod csr2d_values;
 pub use csr2d_values::M2DValues;
+#[cfg(feature = "heapless")]
+mod heapless_csr2d;
+#[cfg(feature = "heapless")]
+pub use heapless_csr2d::{HeaplessCSR2D, HeaplessValuedCSR2D};
 #[cfg(all(feature = "arbitrary", feature = "alloc"))]
 mod arbitrary_impl;
+#[cfg(feature = "alloc")]
+mod binary_impl;
+#[cfg(all(feature = "serde", feature = "alloc"))]
+mod serde_impl;
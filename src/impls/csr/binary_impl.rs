@@ -0,0 +1,50 @@
+//! Implementation of the binary encoding for the `CSR2D` struct.
+//!
+//! The body stores the scalar tags of the three index types, the shape, the
+//! row offsets and the column indices. The number of non-empty rows is
+//! recomputed upon decoding.
+
+use crate::{
+    impls::CSR2D,
+    io::{BinaryDecodeError, BinaryEncoding, BinaryReader, BinaryScalar, write_scalars},
+    traits::PositiveInteger,
+};
+
+/// Layout tag of the `CSR2D` body.
+const CSR2D_LAYOUT: u8 = 0x01;
+
+impl<SparseIndex, RowIndex, ColumnIndex> BinaryEncoding
+    for CSR2D<SparseIndex, RowIndex, ColumnIndex>
+where
+    SparseIndex: PositiveInteger + BinaryScalar,
+    RowIndex: PositiveInteger + BinaryScalar,
+    ColumnIndex: PositiveInteger + BinaryScalar,
+{
+    #[inline]
+    fn write_body(&self, buffer: &mut alloc::vec::Vec<u8>) {
+        buffer.extend_from_slice(&[
+            CSR2D_LAYOUT,
+            SparseIndex::TAG,
+            RowIndex::TAG,
+            ColumnIndex::TAG,
+        ]);
+        self.number_of_rows.write_le(buffer);
+        self.number_of_columns.write_le(buffer);
+        write_scalars(&self.offsets, buffer);
+        write_scalars(&self.column_indices, buffer);
+    }
+
+    #[inline]
+    fn read_body(reader: &mut BinaryReader<'_>) -> Result<Self, BinaryDecodeError> {
+        reader.expect_layout(CSR2D_LAYOUT)?;
+        reader.expect_scalar::<SparseIndex>()?;
+        reader.expect_scalar::<RowIndex>()?;
+        reader.expect_scalar::<ColumnIndex>()?;
+        let number_of_rows = reader.read_scalar()?;
+        let number_of_columns = reader.read_scalar()?;
+        let offsets = reader.read_scalars()?;
+        let column_indices = reader.read_scalars()?;
+        Self::try_from_raw_parts(number_of_rows, number_of_columns, offsets, column_indices)
+            .map_err(BinaryDecodeError::InvalidMatrix)
+    }
+}
@@ -0,0 +1,650 @@
+//! Submodule providing the `CSC2D` and `ValuedCSC2D` types, compressed
+//! sparse column matrices.
+//!
+//! A CSC matrix stores the entries column by column, so that iterating over a
+//! column is as cheap as iterating over a row of a [`CSR2D`]. The columns are
+//! stored as the rows of the transposed [`CSR2D`], which makes the conversion
+//! from a transposed CSR matrix free and the conversion from a CSR matrix a
+//! single transposition. The sparse coordinates and values are iterated in
+//! storage order, i.e. column by column. Row access, as required by
+//! [`SparseMatrix2D`], searches the row in every column and collects it, so
+//! algorithms scanning rows should keep using a CSR matrix.
+
+#[cfg(feature = "mem_dbg")]
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::iter::{Cloned, Copied, Map};
+
+use multi_ranged::Step;
+use num_traits::AsPrimitive;
+
+use super::{CSR2D, CSR2DSizedRows, CSR2DView};
+use crate::{
+    impls::ValuedCSR2D,
+    traits::{
+        Matrix, Matrix2D, PositiveInteger, SizedRowsSparseMatrix2D, SizedSparseMatrix,
+        SparseMatrix, SparseMatrix2D, SparseValuedMatrix, SparseValuedMatrix2D,
+        TransposableMatrix2D, TryFromUsize, ValuedMatrix, ValuedMatrix2D,
+    },
+};
+
+/// Function swapping the coordinates of an entry of the transposed matrix.
+type SwapCoordinates<RowIndex, ColumnIndex> =
+    fn((ColumnIndex, RowIndex)) -> (RowIndex, ColumnIndex);
+
+#[cfg_attr(feature = "mem_size", derive(mem_dbg::MemSize))]
+#[cfg_attr(feature = "mem_size", mem_size(rec))]
+#[cfg_attr(feature = "mem_dbg", derive(mem_dbg::MemDbg))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// A compressed sparse column matrix.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{impls::CSC2D, prelude::*};
+///
+/// let mut csr: CSR2D<usize, usize, usize> = SparseMatrixMut::with_sparse_shape((3, 3));
+/// MatrixMut::add(&mut csr, (0, 1)).unwrap();
+/// MatrixMut::add(&mut csr, (0, 2)).unwrap();
+/// MatrixMut::add(&mut csr, (2, 1)).unwrap();
+///
+/// let csc = CSC2D::from_csr(&csr);
+/// assert_eq!(csc.sparse_column(1).collect::<Vec<_>>(), vec![0, 2]);
+/// assert_eq!(csc.sparse_row(0).collect::<Vec<_>>(), vec![1, 2]);
+/// assert_eq!(csc.to_csr(), csr);
+/// ```
+pub struct CSC2D<SparseIndex, RowIndex, ColumnIndex> {
+    /// The columns of the matrix, stored as the rows of its transpose.
+    columns: CSR2D<SparseIndex, ColumnIndex, RowIndex>,
+}
+
+#[cfg_attr(feature = "mem_size", derive(mem_dbg::MemSize))]
+#[cfg_attr(feature = "mem_size", mem_size(rec))]
+#[cfg_attr(feature = "mem_dbg", derive(mem_dbg::MemDbg))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// A compressed sparse column matrix which stores values in addition to the
+/// row and column indices.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{impls::ValuedCSC2D, prelude::*};
+///
+/// let mut csr: ValuedCSR2D<usize, usize, usize, f64> = SparseMatrixMut::with_sparse_shape((2, 2));
+/// MatrixMut::add(&mut csr, (0, 0, 1.0)).unwrap();
+/// MatrixMut::add(&mut csr, (0, 1, 2.0)).unwrap();
+/// MatrixMut::add(&mut csr, (1, 1, 3.0)).unwrap();
+///
+/// let csc = ValuedCSC2D::from_csr(&csr);
+/// assert_eq!(csc.sparse_column_values(1).collect::<Vec<_>>(), vec![2.0, 3.0]);
+/// assert_eq!(csc.sparse_value_at(0, 1), Some(2.0));
+/// assert_eq!(csc.to_csr(), csr);
+/// ```
+pub struct ValuedCSC2D<SparseIndex, RowIndex, ColumnIndex, Value> {
+    /// The structure of the matrix.
+    csc: CSC2D<SparseIndex, RowIndex, ColumnIndex>,
+    /// The values, in storage order.
+    values: Vec<Value>,
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex> CSC2D<SparseIndex, RowIndex, ColumnIndex> {
+    /// Creates a CSC matrix whose columns are the rows of the provided CSR
+    /// matrix, without copying them.
+    ///
+    /// # Arguments
+    ///
+    /// * `columns`: The transpose of the matrix.
+    #[inline]
+    #[must_use]
+    pub fn from_columns(columns: CSR2D<SparseIndex, ColumnIndex, RowIndex>) -> Self {
+        Self { columns }
+    }
+
+    /// Returns the transpose of the matrix, whose rows are the columns of the
+    /// matrix, without copying them.
+    #[inline]
+    #[must_use]
+    pub fn into_columns(self) -> CSR2D<SparseIndex, ColumnIndex, RowIndex> {
+        self.columns
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value>
+    ValuedCSC2D<SparseIndex, RowIndex, ColumnIndex, Value>
+{
+    /// Creates a CSC matrix whose columns are the rows of the provided CSR
+    /// matrix, without copying them.
+    ///
+    /// # Arguments
+    ///
+    /// * `columns`: The transpose of the matrix.
+    #[inline]
+    #[must_use]
+    pub fn from_columns(columns: ValuedCSR2D<SparseIndex, ColumnIndex, RowIndex, Value>) -> Self {
+        let ValuedCSR2D { csr, values } = columns;
+        Self { csc: CSC2D { columns: csr }, values }
+    }
+
+    /// Returns the transpose of the matrix, whose rows are the columns of the
+    /// matrix, without copying them.
+    #[inline]
+    #[must_use]
+    pub fn into_columns(self) -> ValuedCSR2D<SparseIndex, ColumnIndex, RowIndex, Value> {
+        ValuedCSR2D { csr: self.csc.columns, values: self.values }
+    }
+
+    /// Returns the structure of the matrix, without its values.
+    #[inline]
+    #[must_use]
+    pub fn structure(&self) -> &CSC2D<SparseIndex, RowIndex, ColumnIndex> {
+        &self.csc
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex> CSC2D<SparseIndex, RowIndex, ColumnIndex>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize + TryFrom<SparseIndex>,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize + TryFrom<SparseIndex>,
+{
+    /// Returns the rows of the entries of a column, in increasing order.
+    ///
+    /// # Arguments
+    ///
+    /// * `column`: The column index.
+    #[inline]
+    pub fn sparse_column(&self, column: ColumnIndex) -> Copied<core::slice::Iter<'_, RowIndex>> {
+        self.columns.sparse_row(column)
+    }
+
+    /// Returns the rows of the entries of a column as a sorted slice.
+    ///
+    /// # Arguments
+    ///
+    /// * `column`: The column index.
+    #[inline]
+    #[must_use]
+    pub fn sparse_column_slice(&self, column: ColumnIndex) -> &[RowIndex] {
+        self.columns.sparse_row_slice(column)
+    }
+
+    /// Returns the number of entries of a column.
+    ///
+    /// # Arguments
+    ///
+    /// * `column`: The column index.
+    #[inline]
+    #[must_use]
+    pub fn number_of_defined_values_in_column(&self, column: ColumnIndex) -> RowIndex {
+        self.columns.number_of_defined_values_in_row(column)
+    }
+
+    /// Returns the storage position of the entry at the provided coordinates.
+    fn position(&self, row: RowIndex, column: ColumnIndex) -> Option<usize> {
+        let offset = self.sparse_column_slice(column).binary_search(&row).ok()?;
+        Some(self.columns.sparse_row_sparse_index_range(column).start.as_() + offset)
+    }
+
+    /// Returns the columns of the entries of a row and their storage
+    /// positions, by increasing column.
+    fn row_entries(&self, row: RowIndex) -> impl Iterator<Item = (ColumnIndex, usize)> + '_ {
+        self.columns
+            .row_indices()
+            .filter_map(move |column| Some((column, self.position(row, column)?)))
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex> CSC2D<SparseIndex, RowIndex, ColumnIndex>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize + TryFrom<SparseIndex>,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize + TryFrom<SparseIndex>,
+{
+    /// Converts the provided CSR matrix into a CSC matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `matrix`: The CSR matrix to convert.
+    ///
+    /// # Complexity
+    ///
+    /// O(R + C + E) time, as a single transposition.
+    #[inline]
+    #[must_use]
+    pub fn from_csr(matrix: &CSR2D<SparseIndex, RowIndex, ColumnIndex>) -> Self {
+        Self { columns: matrix.transpose() }
+    }
+
+    /// Converts the matrix into a CSR matrix.
+    ///
+    /// # Complexity
+    ///
+    /// O(R + C + E) time, as a single transposition.
+    #[inline]
+    #[must_use]
+    pub fn to_csr(&self) -> CSR2D<SparseIndex, RowIndex, ColumnIndex> {
+        self.columns.transpose()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value>
+    ValuedCSC2D<SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize + TryFrom<SparseIndex>,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize + TryFrom<SparseIndex>,
+    Value: Clone,
+{
+    /// Returns the rows of the entries of a column, in increasing order.
+    ///
+    /// # Arguments
+    ///
+    /// * `column`: The column index.
+    #[inline]
+    pub fn sparse_column(&self, column: ColumnIndex) -> Copied<core::slice::Iter<'_, RowIndex>> {
+        self.csc.sparse_column(column)
+    }
+
+    /// Returns the values of the entries of a column, by increasing row.
+    ///
+    /// # Arguments
+    ///
+    /// * `column`: The column index.
+    #[inline]
+    pub fn sparse_column_values(
+        &self,
+        column: ColumnIndex,
+    ) -> Cloned<core::slice::Iter<'_, Value>> {
+        self.sparse_column_entries_slice(column).1.iter().cloned()
+    }
+
+    /// Returns the rows and the values of the entries of a column as slices.
+    ///
+    /// # Arguments
+    ///
+    /// * `column`: The column index.
+    #[inline]
+    #[must_use]
+    pub fn sparse_column_entries_slice(&self, column: ColumnIndex) -> (&[RowIndex], &[Value]) {
+        let range = self.csc.columns.sparse_row_sparse_index_range(column);
+        (self.csc.sparse_column_slice(column), &self.values[range.start.as_()..range.end.as_()])
+    }
+
+    /// Returns the number of entries of a column.
+    ///
+    /// # Arguments
+    ///
+    /// * `column`: The column index.
+    #[inline]
+    #[must_use]
+    pub fn number_of_defined_values_in_column(&self, column: ColumnIndex) -> RowIndex {
+        self.csc.number_of_defined_values_in_column(column)
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value>
+    ValuedCSC2D<SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize + TryFrom<SparseIndex>,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize + TryFrom<SparseIndex>,
+    Value: Clone,
+{
+    /// Converts the provided CSR matrix into a CSC matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `matrix`: The CSR matrix to convert.
+    ///
+    /// # Complexity
+    ///
+    /// O(R + C + E) time, as a single transposition.
+    #[inline]
+    #[must_use]
+    pub fn from_csr(matrix: &ValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value>) -> Self {
+        Self::from_columns(transpose_valued(&matrix.csr, &matrix.values))
+    }
+
+    /// Converts the matrix into a CSR matrix.
+    ///
+    /// # Complexity
+    ///
+    /// O(R + C + E) time, as a single transposition.
+    #[inline]
+    #[must_use]
+    pub fn to_csr(&self) -> ValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value> {
+        transpose_valued(&self.csc.columns, &self.values)
+    }
+}
+
+/// Returns the transpose of the provided matrix and values.
+fn transpose_valued<SparseIndex, RowIndex, ColumnIndex, Value>(
+    matrix: &CSR2D<SparseIndex, RowIndex, ColumnIndex>,
+    values: &[Value],
+) -> ValuedCSR2D<SparseIndex, ColumnIndex, RowIndex, Value>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize + TryFrom<SparseIndex>,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize + TryFrom<SparseIndex>,
+    Value: Clone,
+{
+    let transposed = matrix.transpose();
+    // The transposition lists the entries of every column by increasing row,
+    // so the values are placed by visiting the entries row by row.
+    let mut next: Vec<usize> = transposed
+        .row_indices()
+        .map(|column| transposed.sparse_row_sparse_index_range(column).start.as_())
+        .collect();
+    let mut sources = vec![0; values.len()];
+    for (source, column) in matrix.column_indices.iter().enumerate() {
+        let destination = &mut next[column.as_()];
+        sources[*destination] = source;
+        *destination += 1;
+    }
+    let values = sources.into_iter().map(|source| values[source].clone()).collect();
+    ValuedCSR2D { csr: transposed, values }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex> Matrix for CSC2D<SparseIndex, RowIndex, ColumnIndex>
+where
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+{
+    type Coordinates = (RowIndex, ColumnIndex);
+
+    #[inline]
+    fn shape(&self) -> Vec<usize> {
+        vec![self.number_of_rows().as_(), self.number_of_columns().as_()]
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex> Matrix2D for CSC2D<SparseIndex, RowIndex, ColumnIndex>
+where
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+{
+    type RowIndex = RowIndex;
+    type ColumnIndex = ColumnIndex;
+
+    #[inline]
+    fn number_of_rows(&self) -> Self::RowIndex {
+        self.columns.number_of_columns()
+    }
+
+    #[inline]
+    fn number_of_columns(&self) -> Self::ColumnIndex {
+        self.columns.number_of_rows()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex> SparseMatrix for CSC2D<SparseIndex, RowIndex, ColumnIndex>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize + TryFrom<SparseIndex>,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize + TryFrom<SparseIndex>,
+{
+    type SparseIndex = SparseIndex;
+    type SparseCoordinates<'a>
+        = Map<
+        CSR2DView<'a, CSR2D<SparseIndex, ColumnIndex, RowIndex>>,
+        SwapCoordinates<RowIndex, ColumnIndex>,
+    >
+    where
+        Self: 'a;
+
+    #[inline]
+    fn sparse_coordinates(&self) -> Self::SparseCoordinates<'_> {
+        let swap: SwapCoordinates<RowIndex, ColumnIndex> = |(column, row)| (row, column);
+        self.columns.sparse_coordinates().map(swap)
+    }
+
+    #[inline]
+    fn last_sparse_coordinates(&self) -> Option<Self::Coordinates> {
+        self.columns.last_sparse_coordinates().map(|(column, row)| (row, column))
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.columns.is_empty()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex> SizedSparseMatrix
+    for CSC2D<SparseIndex, RowIndex, ColumnIndex>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize + TryFrom<SparseIndex>,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize + TryFrom<SparseIndex>,
+{
+    #[inline]
+    fn number_of_defined_values(&self) -> Self::SparseIndex {
+        self.columns.number_of_defined_values()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex> SparseMatrix2D
+    for CSC2D<SparseIndex, RowIndex, ColumnIndex>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize + TryFrom<SparseIndex>,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize + TryFrom<SparseIndex>,
+{
+    type SparseRow<'a>
+        = alloc::vec::IntoIter<ColumnIndex>
+    where
+        Self: 'a;
+    type SparseColumns<'a>
+        = CSR2DSizedRows<'a, CSR2D<SparseIndex, ColumnIndex, RowIndex>>
+    where
+        Self: 'a;
+    type SparseRows<'a>
+        = Copied<core::slice::Iter<'a, RowIndex>>
+    where
+        Self: 'a;
+
+    /// Returns the columns of the entries of a row, searching the row in
+    /// every column in O(C log R) time.
+    #[inline]
+    fn sparse_row(&self, row: Self::RowIndex) -> Self::SparseRow<'_> {
+        self.row_entries(row).map(|(column, _)| column).collect::<Vec<_>>().into_iter()
+    }
+
+    #[inline]
+    fn has_entry(&self, row: Self::RowIndex, column: Self::ColumnIndex) -> bool {
+        self.position(row, column).is_some()
+    }
+
+    #[inline]
+    fn sparse_columns(&self) -> Self::SparseColumns<'_> {
+        self.columns.sparse_rows()
+    }
+
+    #[inline]
+    fn sparse_rows(&self) -> Self::SparseRows<'_> {
+        self.columns.sparse_columns()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value> Matrix
+    for ValuedCSC2D<SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+{
+    type Coordinates = (RowIndex, ColumnIndex);
+
+    #[inline]
+    fn shape(&self) -> Vec<usize> {
+        self.csc.shape()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value> Matrix2D
+    for ValuedCSC2D<SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+{
+    type RowIndex = RowIndex;
+    type ColumnIndex = ColumnIndex;
+
+    #[inline]
+    fn number_of_rows(&self) -> Self::RowIndex {
+        self.csc.number_of_rows()
+    }
+
+    #[inline]
+    fn number_of_columns(&self) -> Self::ColumnIndex {
+        self.csc.number_of_columns()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value> SparseMatrix
+    for ValuedCSC2D<SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize + TryFrom<SparseIndex>,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize + TryFrom<SparseIndex>,
+{
+    type SparseIndex = SparseIndex;
+    type SparseCoordinates<'a>
+        = <CSC2D<SparseIndex, RowIndex, ColumnIndex> as SparseMatrix>::SparseCoordinates<'a>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn sparse_coordinates(&self) -> Self::SparseCoordinates<'_> {
+        self.csc.sparse_coordinates()
+    }
+
+    #[inline]
+    fn last_sparse_coordinates(&self) -> Option<Self::Coordinates> {
+        self.csc.last_sparse_coordinates()
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.csc.is_empty()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value> SizedSparseMatrix
+    for ValuedCSC2D<SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize + TryFrom<SparseIndex>,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize + TryFrom<SparseIndex>,
+{
+    #[inline]
+    fn number_of_defined_values(&self) -> Self::SparseIndex {
+        self.csc.number_of_defined_values()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value> SparseMatrix2D
+    for ValuedCSC2D<SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize + TryFrom<SparseIndex>,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize + TryFrom<SparseIndex>,
+{
+    type SparseRow<'a>
+        = <CSC2D<SparseIndex, RowIndex, ColumnIndex> as SparseMatrix2D>::SparseRow<'a>
+    where
+        Self: 'a;
+    type SparseColumns<'a>
+        = <CSC2D<SparseIndex, RowIndex, ColumnIndex> as SparseMatrix2D>::SparseColumns<'a>
+    where
+        Self: 'a;
+    type SparseRows<'a>
+        = <CSC2D<SparseIndex, RowIndex, ColumnIndex> as SparseMatrix2D>::SparseRows<'a>
+    where
+        Self: 'a;
+
+    /// Returns the columns of the entries of a row, searching the row in
+    /// every column in O(C log R) time.
+    #[inline]
+    fn sparse_row(&self, row: Self::RowIndex) -> Self::SparseRow<'_> {
+        self.csc.sparse_row(row)
+    }
+
+    #[inline]
+    fn has_entry(&self, row: Self::RowIndex, column: Self::ColumnIndex) -> bool {
+        self.csc.has_entry(row, column)
+    }
+
+    #[inline]
+    fn sparse_columns(&self) -> Self::SparseColumns<'_> {
+        self.csc.sparse_columns()
+    }
+
+    #[inline]
+    fn sparse_rows(&self) -> Self::SparseRows<'_> {
+        self.csc.sparse_rows()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value> ValuedMatrix
+    for ValuedCSC2D<SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    Self: Matrix,
+{
+    type Value = Value;
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value> ValuedMatrix2D
+    for ValuedCSC2D<SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    Self: Matrix2D,
+{
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value> SparseValuedMatrix
+    for ValuedCSC2D<SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    Self: SparseMatrix + ValuedMatrix<Value = Value>,
+    Value: Clone,
+{
+    type SparseValues<'a>
+        = Cloned<core::slice::Iter<'a, Value>>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn sparse_values(&self) -> Self::SparseValues<'_> {
+        self.values.iter().cloned()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value> SparseValuedMatrix2D
+    for ValuedCSC2D<SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize + TryFrom<SparseIndex>,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize + TryFrom<SparseIndex>,
+    Value: Clone,
+{
+    type SparseRowValues<'a>
+        = alloc::vec::IntoIter<Value>
+    where
+        Self: 'a;
+
+    /// Returns the values of the entries of a row, searching the row in
+    /// every column in O(C log R) time.
+    #[inline]
+    fn sparse_row_values(&self, row: Self::RowIndex) -> Self::SparseRowValues<'_> {
+        self.csc
+            .row_entries(row)
+            .map(|(_, position)| self.values[position].clone())
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    #[inline]
+    fn sparse_value_at(&self, row: Self::RowIndex, column: Self::ColumnIndex) -> Option<Self::Value>
+    where
+        Self::ColumnIndex: PartialEq,
+    {
+        Some(self.values[self.csc.position(row, column)?].clone())
+    }
+}
@@ -2,12 +2,20 @@
 #[cfg(feature = "mem_dbg")]
 use alloc::string::String;
 use alloc::vec::Vec;
-use core::{fmt::Debug, iter::repeat_n, ops::Range};
+use core::{
+    fmt::{Debug, Display},
+    iter::repeat_n,
+    ops::Range,
+};
 
 use multi_ranged::Step;
 use num_traits::{AsPrimitive, Zero};
 
+#[cfg(feature = "parallel")]
+use super::csr2d_transpose::par_fill_transposed_blocked;
+use super::csr2d_transpose::{BLOCKED_TRANSPOSE_MIN_VALUES, fill_transposed_blocked};
 use crate::{
+    errors::{InvariantError, RankSelectError},
     impls::{
         CSR2DEmptyRowIndices, CSR2DNonEmptyRowIndices, CSR2DSizedRows, CSR2DSizedRowsizes,
         MutabilityError,
@@ -49,6 +57,16 @@ impl<SparseIndex: Debug, RowIndex: Debug, ColumnIndex: Debug> Debug
     }
 }
 
+impl<SparseIndex, RowIndex, ColumnIndex> Display for CSR2D<SparseIndex, RowIndex, ColumnIndex>
+where
+    Self: SparseMatrix2D,
+{
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.dense_display(DISPLAY_LIMIT, DISPLAY_LIMIT).fmt(f)
+    }
+}
+
 impl<SparseIndex: Zero, RowIndex: Zero, ColumnIndex: Zero> Default
     for CSR2D<SparseIndex, RowIndex, ColumnIndex>
 {
@@ -64,6 +82,140 @@ impl<SparseIndex: Zero, RowIndex: Zero, ColumnIndex: Zero> Default
     }
 }
 
+impl<SparseIndex: PositiveInteger, RowIndex: PositiveInteger, ColumnIndex: PositiveInteger>
+    CSR2D<SparseIndex, RowIndex, ColumnIndex>
+{
+    /// Builds a matrix from its raw CSR parts, checking the invariants that
+    /// the mutable API maintains when the matrix is built incrementally.
+    ///
+    /// The offsets may describe fewer rows than the matrix has, in which case
+    /// the trailing rows are empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first violated invariant.
+    pub(crate) fn try_from_raw_parts(
+        number_of_rows: RowIndex,
+        number_of_columns: ColumnIndex,
+        offsets: Vec<SparseIndex>,
+        column_indices: Vec<ColumnIndex>,
+    ) -> Result<Self, InvariantError> {
+        let number_of_non_empty_rows =
+            validate_raw_parts(number_of_rows, number_of_columns, &offsets, &column_indices)?;
+
+        Ok(Self {
+            offsets,
+            number_of_columns,
+            number_of_rows,
+            column_indices,
+            number_of_non_empty_rows,
+        })
+    }
+
+    /// Returns the number of rows, the number of columns, the row offsets and
+    /// the column indices of the matrix.
+    #[cfg(feature = "sprs")]
+    pub(crate) fn into_raw_parts(
+        self,
+    ) -> (RowIndex, ColumnIndex, Vec<SparseIndex>, Vec<ColumnIndex>) {
+        (self.number_of_rows, self.number_of_columns, self.offsets, self.column_indices)
+    }
+}
+
+/// Checks the invariants of the raw CSR parts of a matrix, returning its
+/// number of non-empty rows.
+///
+/// The offsets may describe fewer rows than the matrix has, in which case the
+/// trailing rows are empty.
+///
+/// # Errors
+///
+/// Returns the first violated invariant.
+pub(super) fn validate_raw_parts<SparseIndex, RowIndex, ColumnIndex>(
+    number_of_rows: RowIndex,
+    number_of_columns: ColumnIndex,
+    offsets: &[SparseIndex],
+    column_indices: &[ColumnIndex],
+) -> Result<RowIndex, InvariantError>
+where
+    SparseIndex: PositiveInteger,
+    RowIndex: PositiveInteger,
+    ColumnIndex: PositiveInteger,
+{
+    if offsets.first().is_none_or(|first| !first.is_zero()) {
+        return Err(InvariantError::OffsetsMustStartWithZero);
+    }
+    if offsets.len() - 1 > number_of_rows.as_() {
+        return Err(InvariantError::TooManyOffsets {
+            described_rows: offsets.len() - 1,
+            number_of_rows: number_of_rows.as_(),
+        });
+    }
+    let last_offset = offsets[offsets.len() - 1].as_();
+    if last_offset != column_indices.len() {
+        return Err(InvariantError::LastOffsetMismatch {
+            last_offset,
+            number_of_column_indices: column_indices.len(),
+        });
+    }
+    if let Some(row) = offsets.windows(2).position(|window| window[0] > window[1]) {
+        return Err(InvariantError::DecreasingOffsets { row });
+    }
+
+    let mut number_of_non_empty_rows = 0_usize;
+    for (row, window) in offsets.windows(2).enumerate() {
+        let columns = &column_indices[window[0].as_()..window[1].as_()];
+        if !columns.is_empty() {
+            number_of_non_empty_rows += 1;
+        }
+        if columns.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(InvariantError::UnsortedRow { row });
+        }
+        if let Some(&column) = columns.last().filter(|&&last| last >= number_of_columns) {
+            return Err(InvariantError::ColumnOutOfBounds {
+                row,
+                column: column.as_(),
+                number_of_columns: number_of_columns.as_(),
+            });
+        }
+    }
+
+    // The non-empty rows are among the rows described by the offsets, which
+    // were checked not to outnumber the rows of the matrix.
+    Ok(RowIndex::try_from_usize(number_of_non_empty_rows)
+        .unwrap_or_else(|_| unreachable!("The number of non-empty rows fits the row index.")))
+}
+
+/// Returns the entries of a row of a CSR layout without bounds checks.
+///
+/// The rows past the last stored offset are empty.
+///
+/// # Safety
+///
+/// The offsets must be non-decreasing and no greater than the number of
+/// entries, as checked by [`validate_raw_parts`].
+#[inline]
+pub(super) unsafe fn row_entries_unchecked<'a, SparseIndex, Entry>(
+    offsets: &[SparseIndex],
+    entries: &'a [Entry],
+    row: usize,
+) -> &'a [Entry]
+where
+    SparseIndex: Copy + AsPrimitive<usize>,
+{
+    if row + 1 < offsets.len() {
+        // SAFETY: both offsets are in bounds, and the caller guarantees that
+        // they delimit a range of the entries.
+        unsafe {
+            let start = offsets.get_unchecked(row).as_();
+            let end = offsets.get_unchecked(row + 1).as_();
+            entries.get_unchecked(start..end)
+        }
+    } else {
+        &[]
+    }
+}
+
 impl<
     SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
     RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
@@ -379,6 +531,15 @@ impl<
         self.sparse_row_slice(row).iter().copied()
     }
 
+    #[inline]
+    unsafe fn sparse_row_unchecked(&self, row: Self::RowIndex) -> Self::SparseRow<'_> {
+        debug_assert!(row < self.number_of_rows(), "The row {row} is out of bounds.");
+        // SAFETY: the offsets of a CSR2D always delimit its column indices.
+        unsafe { row_entries_unchecked(&self.offsets, &self.column_indices, row.as_()) }
+            .iter()
+            .copied()
+    }
+
     #[inline]
     fn has_entry(&self, row: Self::RowIndex, column: Self::ColumnIndex) -> bool {
         self.sparse_row_slice(row).binary_search(&column).is_ok()
@@ -453,6 +614,12 @@ where
         self.offsets[row.as_()]
     }
 
+    #[inline]
+    fn rank_row_unchecked(&self, row: RowIndex) -> SparseIndex {
+        debug_assert!(row <= self.number_of_rows(), "The row {row} is out of bounds.");
+        self.offsets.get(row.as_()).copied().unwrap_or_else(|| self.number_of_defined_values())
+    }
+
     #[inline]
     fn select_row(&self, sparse_index: Self::SparseIndex) -> Self::RowIndex {
         assert!(
@@ -477,14 +644,37 @@ where
     }
 
     #[inline]
-    fn try_rank(&self, row: Self::RowIndex, column: Self::ColumnIndex) -> Option<Self::SparseIndex>
+    unsafe fn select_column_unchecked(&self, sparse_index: Self::SparseIndex) -> Self::ColumnIndex {
+        debug_assert!(
+            sparse_index < self.number_of_defined_values(),
+            "The sparse index {sparse_index} is out of bounds."
+        );
+        // SAFETY: the caller guarantees that the sparse index is smaller than
+        // the number of defined values, which is the number of column indices.
+        unsafe { *self.column_indices.get_unchecked(sparse_index.as_()) }
+    }
+
+    #[inline]
+    fn try_rank(
+        &self,
+        row: Self::RowIndex,
+        column: Self::ColumnIndex,
+    ) -> Result<Self::SparseIndex, RankSelectError>
     where
         Self::ColumnIndex: PartialEq,
     {
+        if row >= self.number_of_rows() {
+            return Err(RankSelectError::RowOutOfBounds {
+                row: row.as_(),
+                number_of_rows: self.number_of_rows().as_(),
+            });
+        }
         let start = self.rank_row(row);
         let end = self.rank_row(row + RowIndex::one());
-        let relative = self.column_indices[start.as_()..end.as_()].binary_search(&column).ok()?;
-        Some(
+        let relative = self.column_indices[start.as_()..end.as_()]
+            .binary_search(&column)
+            .map_err(|_| RankSelectError::MissingEntry { row: row.as_(), column: column.as_() })?;
+        Ok(
             start
                 + Self::SparseIndex::try_from_usize(relative).unwrap_or_else(|_| {
                     unreachable!(
@@ -631,15 +821,19 @@ impl<
     SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
     RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
     ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
-> TransposableMatrix2D<CSR2D<SparseIndex, ColumnIndex, RowIndex>>
-    for CSR2D<SparseIndex, RowIndex, ColumnIndex>
+> CSR2D<SparseIndex, RowIndex, ColumnIndex>
 where
     Self: Matrix2D<RowIndex = RowIndex, ColumnIndex = ColumnIndex>,
     CSR2D<SparseIndex, ColumnIndex, RowIndex>:
         Matrix2D<RowIndex = ColumnIndex, ColumnIndex = RowIndex>,
 {
-    #[inline]
-    fn transpose(&self) -> CSR2D<SparseIndex, ColumnIndex, RowIndex> {
+    /// Returns the transposed matrix, filling the column indices of large
+    /// matrices with the provided blocked fill.
+    #[allow(clippy::type_complexity)]
+    fn transpose_with(
+        &self,
+        fill_blocked: fn(&[SparseIndex], &[ColumnIndex], &[SparseIndex], &mut [RowIndex]),
+    ) -> CSR2D<SparseIndex, ColumnIndex, RowIndex> {
         // We initialize the transposed matrix.
         let mut transposed: CSR2D<SparseIndex, ColumnIndex, RowIndex> = CSR2D {
             offsets: vec![SparseIndex::zero(); self.number_of_columns().as_() + 1],
@@ -673,17 +867,121 @@ where
             transposed.number_of_rows
         );
 
-        // Finally, we fill the column indices.
-        let mut degree = vec![SparseIndex::zero(); self.number_of_columns.as_()];
-        for (row, column) in crate::traits::SparseMatrix::sparse_coordinates(self) {
-            let current_degree: &mut SparseIndex = &mut degree[column.as_()];
-            let index = *current_degree + transposed.offsets[column.as_()];
-            transposed.column_indices[index.as_()] = row;
-            *current_degree += SparseIndex::one();
+        // Finally, we fill the column indices, scattering the entries directly
+        // when the matrix is small enough for the scattered writes to stay in
+        // cache.
+        if self.column_indices.len() < BLOCKED_TRANSPOSE_MIN_VALUES {
+            let mut degree = vec![SparseIndex::zero(); self.number_of_columns.as_()];
+            for (row, column) in crate::traits::SparseMatrix::sparse_coordinates(self) {
+                let current_degree: &mut SparseIndex = &mut degree[column.as_()];
+                let index = *current_degree + transposed.offsets[column.as_()];
+                transposed.column_indices[index.as_()] = row;
+                *current_degree += SparseIndex::one();
+            }
+        } else {
+            fill_blocked(
+                &self.offsets,
+                &self.column_indices,
+                &transposed.offsets,
+                &mut transposed.column_indices,
+            );
+        }
+
+        // Trailing empty rows are left implicit, as in matrices built by
+        // insertion.
+        while transposed.offsets.len() > 1
+            && transposed.offsets[transposed.offsets.len() - 2] == prefix_sum
+        {
+            transposed.offsets.pop();
         }
 
         transposed
     }
+
+    #[cfg(feature = "parallel")]
+    /// Returns the transposed matrix, distributing the fill of the column
+    /// indices of large matrices over the `rayon` thread pool.
+    ///
+    /// The result is identical to the one of
+    /// [`TransposableMatrix2D::transpose`] whatever the number of threads.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{impls::CSR2D, prelude::*};
+    ///
+    /// let csr: CSR2D<usize, usize, usize> = CSR2D::from_entries([(0, 1), (1, 0), (1, 2)]).unwrap();
+    /// assert_eq!(csr.par_transpose(), csr.transpose());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn par_transpose(&self) -> CSR2D<SparseIndex, ColumnIndex, RowIndex>
+    where
+        SparseIndex: Send + Sync,
+        RowIndex: Send + Sync,
+        ColumnIndex: Send + Sync,
+    {
+        self.transpose_with(par_fill_transposed_blocked)
+    }
+}
+
+impl<
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+> TransposableMatrix2D<CSR2D<SparseIndex, ColumnIndex, RowIndex>>
+    for CSR2D<SparseIndex, RowIndex, ColumnIndex>
+where
+    Self: Matrix2D<RowIndex = RowIndex, ColumnIndex = ColumnIndex>,
+    CSR2D<SparseIndex, ColumnIndex, RowIndex>:
+        Matrix2D<RowIndex = ColumnIndex, ColumnIndex = RowIndex>,
+{
+    #[inline]
+    fn transpose(&self) -> CSR2D<SparseIndex, ColumnIndex, RowIndex> {
+        self.transpose_with(fill_transposed_blocked)
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex> MemoryFootprint
+    for CSR2D<SparseIndex, RowIndex, ColumnIndex>
+{
+    #[inline]
+    fn memory_usage(&self) -> MemoryUsage {
+        MemoryUsage::default()
+            .with_buffer(BufferUsage::of_vec("offsets", &self.offsets))
+            .with_buffer(BufferUsage::of_vec("column_indices", &self.column_indices))
+    }
+
+    #[inline]
+    fn shrink_to_fit(&mut self) {
+        self.offsets.shrink_to_fit();
+        self.column_indices.shrink_to_fit();
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex> Validate for CSR2D<SparseIndex, RowIndex, ColumnIndex>
+where
+    SparseIndex: PositiveInteger,
+    RowIndex: PositiveInteger,
+    ColumnIndex: PositiveInteger,
+{
+    #[inline]
+    fn validate(&self) -> Result<(), InvariantError> {
+        let number_of_non_empty_rows = validate_raw_parts(
+            self.number_of_rows,
+            self.number_of_columns,
+            &self.offsets,
+            &self.column_indices,
+        )?;
+        if number_of_non_empty_rows != self.number_of_non_empty_rows {
+            return Err(InvariantError::CachedCountMismatch {
+                counted: "non-empty rows",
+                cached: self.number_of_non_empty_rows.as_(),
+                actual: number_of_non_empty_rows.as_(),
+            });
+        }
+        Ok(())
+    }
 }
 
 #[cfg(all(test, feature = "alloc"))]
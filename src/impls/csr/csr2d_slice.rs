@@ -0,0 +1,1033 @@
+//! Submodule providing CSR matrices borrowing their storage from externally
+//! owned buffers.
+//!
+//! Buffers produced outside of this crate, such as memory-mapped files, Arrow
+//! arrays or buffers received over FFI, can be used by the algorithms
+//! without first being copied into the owned `Vec`s of a [`CSR2D`]. The
+//! invariants of the buffers are checked once, in linear time, when the view
+//! is built.
+
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use multi_ranged::Step;
+use num_traits::{AsPrimitive, One};
+
+use super::{
+    CSR2D,
+    csr2d::{row_entries_unchecked, validate_raw_parts},
+};
+use crate::{
+    errors::{InvariantError, RankSelectError},
+    impls::{
+        CSR2DEmptyRowIndices, CSR2DNonEmptyRowIndices, CSR2DSizedRows, CSR2DSizedRowsizes,
+        ValuedCSR2D,
+    },
+    prelude::*,
+    traits::{PositiveInteger, TryFromUsize},
+};
+
+/// Errors raised when building a view over raw CSR buffers.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum CSR2DSliceError {
+    /// The offsets and column indices do not describe a valid CSR structure.
+    #[error("Invalid CSR structure: {0}")]
+    InvalidStructure(#[from] InvariantError),
+    /// The provided values do not match the CSR structure.
+    #[error("Values length mismatch: expected {expected}, got {actual}")]
+    ValuesLengthMismatch {
+        /// Number of values required by the CSR structure.
+        expected: usize,
+        /// Number of values provided by the caller.
+        actual: usize,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// A compressed sparse row matrix borrowing its row offsets and column
+/// indices.
+pub struct CSR2DSlice<'a, SparseIndex, RowIndex, ColumnIndex> {
+    /// The row pointers.
+    offsets: &'a [SparseIndex],
+    /// The number of columns.
+    number_of_columns: ColumnIndex,
+    /// The number of rows.
+    number_of_rows: RowIndex,
+    /// The column indices.
+    column_indices: &'a [ColumnIndex],
+    /// The number of non-empty rows.
+    number_of_non_empty_rows: RowIndex,
+}
+
+impl<'a, SparseIndex: PositiveInteger, RowIndex: PositiveInteger, ColumnIndex: PositiveInteger>
+    CSR2DSlice<'a, SparseIndex, RowIndex, ColumnIndex>
+{
+    /// Builds a view over the raw CSR buffers of a matrix of the provided
+    /// shape.
+    ///
+    /// The offsets hold the position of the first column index of every row,
+    /// followed by the total number of column indices. They may describe
+    /// fewer rows than the matrix has, in which case the trailing rows are
+    /// empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `offsets` - The row offsets, starting with a zero.
+    /// * `column_indices` - The column indices, strictly increasing within
+    ///   every row.
+    /// * `(number_of_rows, number_of_columns)` - The shape of the matrix.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CSR2DSliceError::InvalidStructure`] describing the first
+    /// violated invariant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::prelude::*;
+    ///
+    /// let offsets: &[u32] = &[0, 2, 2, 3];
+    /// let columns: &[u32] = &[1, 3, 0];
+    /// let view = CSR2DSlice::from_raw_parts(offsets, columns, (4_u32, 4_u32)).unwrap();
+    ///
+    /// assert_eq!(view.number_of_defined_values(), 3);
+    /// assert_eq!(view.sparse_row(0).collect::<Vec<_>>(), vec![1, 3]);
+    /// assert!(view.has_entry(2, 0));
+    /// assert_eq!(view.number_of_empty_rows(), 2);
+    /// assert!(CSR2DSlice::from_raw_parts(offsets, &[3_u32, 1, 0], (4_u32, 4_u32)).is_err());
+    /// ```
+    #[inline]
+    pub fn from_raw_parts(
+        offsets: &'a [SparseIndex],
+        column_indices: &'a [ColumnIndex],
+        (number_of_rows, number_of_columns): (RowIndex, ColumnIndex),
+    ) -> Result<Self, CSR2DSliceError> {
+        let number_of_non_empty_rows =
+            validate_raw_parts(number_of_rows, number_of_columns, offsets, column_indices)
+                .map_err(CSR2DSliceError::InvalidStructure)?;
+        Ok(Self {
+            offsets,
+            number_of_columns,
+            number_of_rows,
+            column_indices,
+            number_of_non_empty_rows,
+        })
+    }
+
+    /// Returns the row offsets of the matrix.
+    #[inline]
+    #[must_use]
+    pub fn offsets(&self) -> &'a [SparseIndex] {
+        self.offsets
+    }
+
+    /// Returns the column indices of the matrix.
+    #[inline]
+    #[must_use]
+    pub fn column_indices(&self) -> &'a [ColumnIndex] {
+        self.column_indices
+    }
+}
+
+impl<SparseIndex: Clone, RowIndex: Copy, ColumnIndex: Copy>
+    CSR2D<SparseIndex, RowIndex, ColumnIndex>
+{
+    /// Returns a view borrowing the storage of the matrix.
+    #[inline]
+    pub fn as_slice(&self) -> CSR2DSlice<'_, SparseIndex, RowIndex, ColumnIndex> {
+        CSR2DSlice {
+            offsets: &self.offsets,
+            number_of_columns: self.number_of_columns,
+            number_of_rows: self.number_of_rows,
+            column_indices: &self.column_indices,
+            number_of_non_empty_rows: self.number_of_non_empty_rows,
+        }
+    }
+}
+
+impl<SparseIndex: Clone, RowIndex: Copy, ColumnIndex: Copy>
+    From<CSR2DSlice<'_, SparseIndex, RowIndex, ColumnIndex>>
+    for CSR2D<SparseIndex, RowIndex, ColumnIndex>
+{
+    #[inline]
+    fn from(view: CSR2DSlice<'_, SparseIndex, RowIndex, ColumnIndex>) -> Self {
+        CSR2D {
+            offsets: view.offsets.to_vec(),
+            number_of_columns: view.number_of_columns,
+            number_of_rows: view.number_of_rows,
+            column_indices: view.column_indices.to_vec(),
+            number_of_non_empty_rows: view.number_of_non_empty_rows,
+        }
+    }
+}
+
+impl<
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+> CSR2DSlice<'_, SparseIndex, RowIndex, ColumnIndex>
+{
+    /// Returns the global sparse-index range covered by a row.
+    #[inline]
+    pub fn sparse_row_sparse_index_range(&self, row: RowIndex) -> Range<SparseIndex> {
+        self.rank_row(row)..self.rank_row(row + RowIndex::one())
+    }
+
+    /// Returns the column slice stored for a sparse row.
+    #[inline]
+    pub fn sparse_row_slice(&self, row: RowIndex) -> &[ColumnIndex] {
+        let range = self.sparse_row_sparse_index_range(row);
+        &self.column_indices[range.start.as_()..range.end.as_()]
+    }
+}
+
+impl<
+    SparseIndex,
+    RowIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: PositiveInteger + AsPrimitive<usize>,
+> Matrix for CSR2DSlice<'_, SparseIndex, RowIndex, ColumnIndex>
+{
+    type Coordinates = (RowIndex, ColumnIndex);
+
+    #[inline]
+    fn shape(&self) -> Vec<usize> {
+        vec![self.number_of_rows.as_(), self.number_of_columns.as_()]
+    }
+}
+
+impl<
+    SparseIndex,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize>,
+> Matrix2D for CSR2DSlice<'_, SparseIndex, RowIndex, ColumnIndex>
+{
+    type RowIndex = RowIndex;
+    type ColumnIndex = ColumnIndex;
+
+    #[inline]
+    fn number_of_rows(&self) -> Self::RowIndex {
+        self.number_of_rows
+    }
+
+    #[inline]
+    fn number_of_columns(&self) -> Self::ColumnIndex {
+        self.number_of_columns
+    }
+}
+
+impl<
+    SparseIndex,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize>,
+> Matrix2DRef for CSR2DSlice<'_, SparseIndex, RowIndex, ColumnIndex>
+{
+    #[inline]
+    fn number_of_rows_ref(&self) -> &Self::RowIndex {
+        &self.number_of_rows
+    }
+
+    #[inline]
+    fn number_of_columns_ref(&self) -> &Self::ColumnIndex {
+        &self.number_of_columns
+    }
+}
+
+impl<
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+> SparseMatrix for CSR2DSlice<'_, SparseIndex, RowIndex, ColumnIndex>
+{
+    type SparseIndex = SparseIndex;
+    type SparseCoordinates<'b>
+        = super::CSR2DView<'b, Self>
+    where
+        Self: 'b;
+
+    #[inline]
+    fn sparse_coordinates(&self) -> Self::SparseCoordinates<'_> {
+        self.into()
+    }
+
+    #[inline]
+    fn last_sparse_coordinates(&self) -> Option<Self::Coordinates> {
+        let last_column = self.column_indices.last().copied()?;
+        Some((self.select_row(self.number_of_defined_values() - SparseIndex::one()), last_column))
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.column_indices.is_empty()
+    }
+}
+
+impl<
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+> SizedSparseMatrix for CSR2DSlice<'_, SparseIndex, RowIndex, ColumnIndex>
+{
+    #[inline]
+    fn number_of_defined_values(&self) -> Self::SparseIndex {
+        self.offsets.last().copied().unwrap_or(SparseIndex::zero())
+    }
+}
+
+impl<
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+> RankSelectSparseMatrix for CSR2DSlice<'_, SparseIndex, RowIndex, ColumnIndex>
+{
+    #[inline]
+    fn select(&self, sparse_index: Self::SparseIndex) -> Self::Coordinates {
+        (self.select_row(sparse_index), self.select_column(sparse_index))
+    }
+
+    #[inline]
+    fn rank(&self, &(row_index, column_index): &Self::Coordinates) -> Self::SparseIndex {
+        self.try_rank(row_index, column_index).unwrap_or_else(|_| {
+            panic!("The column index {column_index} is not present in the row {row_index}.")
+        })
+    }
+}
+
+impl<
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+> SparseMatrix2D for CSR2DSlice<'_, SparseIndex, RowIndex, ColumnIndex>
+{
+    type SparseRow<'b>
+        = core::iter::Copied<core::slice::Iter<'b, Self::ColumnIndex>>
+    where
+        Self: 'b;
+    type SparseColumns<'b>
+        = core::iter::Copied<core::slice::Iter<'b, Self::ColumnIndex>>
+    where
+        Self: 'b;
+    type SparseRows<'b>
+        = CSR2DSizedRows<'b, Self>
+    where
+        Self: 'b;
+
+    #[inline]
+    fn sparse_row(&self, row: Self::RowIndex) -> Self::SparseRow<'_> {
+        self.sparse_row_slice(row).iter().copied()
+    }
+
+    #[inline]
+    unsafe fn sparse_row_unchecked(&self, row: Self::RowIndex) -> Self::SparseRow<'_> {
+        debug_assert!(row < self.number_of_rows, "The row {row} is out of bounds.");
+        // SAFETY: the offsets were checked to delimit the column indices.
+        unsafe { row_entries_unchecked(self.offsets, self.column_indices, row.as_()) }
+            .iter()
+            .copied()
+    }
+
+    #[inline]
+    fn has_entry(&self, row: Self::RowIndex, column: Self::ColumnIndex) -> bool {
+        self.sparse_row_slice(row).binary_search(&column).is_ok()
+    }
+
+    #[inline]
+    fn sparse_columns(&self) -> Self::SparseColumns<'_> {
+        self.column_indices.iter().copied()
+    }
+
+    #[inline]
+    fn sparse_rows(&self) -> Self::SparseRows<'_> {
+        self.into()
+    }
+}
+
+impl<
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+> EmptyRows for CSR2DSlice<'_, SparseIndex, RowIndex, ColumnIndex>
+{
+    type EmptyRowIndices<'b>
+        = CSR2DEmptyRowIndices<'b, Self>
+    where
+        Self: 'b;
+    type NonEmptyRowIndices<'b>
+        = CSR2DNonEmptyRowIndices<'b, Self>
+    where
+        Self: 'b;
+
+    #[inline]
+    fn number_of_non_empty_rows(&self) -> Self::RowIndex {
+        self.number_of_non_empty_rows
+    }
+
+    #[inline]
+    fn number_of_empty_rows(&self) -> Self::RowIndex {
+        self.number_of_rows - self.number_of_non_empty_rows
+    }
+
+    #[inline]
+    fn empty_row_indices(&self) -> Self::EmptyRowIndices<'_> {
+        self.into()
+    }
+
+    #[inline]
+    fn non_empty_row_indices(&self) -> Self::NonEmptyRowIndices<'_> {
+        self.into()
+    }
+}
+
+impl<
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+> SizedSparseMatrix2D for CSR2DSlice<'_, SparseIndex, RowIndex, ColumnIndex>
+{
+    #[inline]
+    fn rank_row(&self, row: RowIndex) -> SparseIndex {
+        assert!(
+            row <= self.number_of_rows,
+            "The row index {row} is greater than the number of rows {}.",
+            self.number_of_rows
+        );
+        // Rows past the ones described by the offsets are empty.
+        self.offsets.get(row.as_()).copied().unwrap_or_else(|| self.number_of_defined_values())
+    }
+
+    #[inline]
+    fn rank_row_unchecked(&self, row: RowIndex) -> SparseIndex {
+        debug_assert!(row <= self.number_of_rows, "The row {row} is out of bounds.");
+        self.offsets.get(row.as_()).copied().unwrap_or_else(|| self.number_of_defined_values())
+    }
+
+    #[inline]
+    fn select_row(&self, sparse_index: Self::SparseIndex) -> Self::RowIndex {
+        assert!(
+            sparse_index < self.number_of_defined_values(),
+            "The sparse index {sparse_index} is out of bounds for a matrix with {} defined values.",
+            self.number_of_defined_values()
+        );
+        let row = self.offsets.partition_point(|&offset| offset <= sparse_index) - 1;
+        Self::RowIndex::try_from_usize(row).unwrap_or_else(|_| {
+            unreachable!("The rows described by the offsets fit the row index.")
+        })
+    }
+
+    #[inline]
+    fn select_column(&self, sparse_index: Self::SparseIndex) -> Self::ColumnIndex {
+        self.column_indices[sparse_index.as_()]
+    }
+
+    #[inline]
+    unsafe fn select_column_unchecked(&self, sparse_index: Self::SparseIndex) -> Self::ColumnIndex {
+        debug_assert!(
+            sparse_index < self.number_of_defined_values(),
+            "The sparse index {sparse_index} is out of bounds."
+        );
+        // SAFETY: the caller guarantees that the sparse index is smaller than
+        // the number of defined values, which is the number of column indices.
+        unsafe { *self.column_indices.get_unchecked(sparse_index.as_()) }
+    }
+
+    #[inline]
+    fn try_rank(
+        &self,
+        row: Self::RowIndex,
+        column: Self::ColumnIndex,
+    ) -> Result<Self::SparseIndex, RankSelectError>
+    where
+        Self::ColumnIndex: PartialEq,
+    {
+        if row >= self.number_of_rows() {
+            return Err(RankSelectError::RowOutOfBounds {
+                row: row.as_(),
+                number_of_rows: self.number_of_rows().as_(),
+            });
+        }
+        let range = self.sparse_row_sparse_index_range(row);
+        let relative = self.column_indices[range.start.as_()..range.end.as_()]
+            .binary_search(&column)
+            .map_err(|_| RankSelectError::MissingEntry { row: row.as_(), column: column.as_() })?;
+        Ok(range.start
+            + Self::SparseIndex::try_from_usize(relative).unwrap_or_else(|_| {
+                unreachable!("The positions within a row fit the sparse index.")
+            }))
+    }
+}
+
+impl<
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+> SizedRowsSparseMatrix2D for CSR2DSlice<'_, SparseIndex, RowIndex, ColumnIndex>
+{
+    type SparseRowSizes<'b>
+        = CSR2DSizedRowsizes<'b, Self>
+    where
+        Self: 'b;
+
+    #[inline]
+    fn sparse_row_sizes(&self) -> Self::SparseRowSizes<'_> {
+        self.into()
+    }
+
+    #[inline]
+    fn number_of_defined_values_in_row(&self, row: Self::RowIndex) -> Self::ColumnIndex {
+        let range = self.sparse_row_sparse_index_range(row);
+        (range.end - range.start).try_into().unwrap_or_else(|_| {
+            unreachable!(
+                "The column indices of a row are distinct, so their number fits the column index."
+            )
+        })
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex> Validate
+    for CSR2DSlice<'_, SparseIndex, RowIndex, ColumnIndex>
+where
+    SparseIndex: PositiveInteger,
+    RowIndex: PositiveInteger,
+    ColumnIndex: PositiveInteger,
+{
+    #[inline]
+    fn validate(&self) -> Result<(), InvariantError> {
+        let number_of_non_empty_rows = validate_raw_parts(
+            self.number_of_rows,
+            self.number_of_columns,
+            self.offsets,
+            self.column_indices,
+        )?;
+        if number_of_non_empty_rows != self.number_of_non_empty_rows {
+            return Err(InvariantError::CachedCountMismatch {
+                counted: "non-empty rows",
+                cached: self.number_of_non_empty_rows.as_(),
+                actual: number_of_non_empty_rows.as_(),
+            });
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// A compressed sparse row matrix borrowing its row offsets, column indices
+/// and values.
+pub struct ValuedCSR2DSlice<'a, SparseIndex, RowIndex, ColumnIndex, Value> {
+    /// The structure of the matrix.
+    csr: CSR2DSlice<'a, SparseIndex, RowIndex, ColumnIndex>,
+    /// The values, in the order of the column indices.
+    values: &'a [Value],
+}
+
+impl<
+    'a,
+    SparseIndex: PositiveInteger,
+    RowIndex: PositiveInteger,
+    ColumnIndex: PositiveInteger,
+    Value,
+> ValuedCSR2DSlice<'a, SparseIndex, RowIndex, ColumnIndex, Value>
+{
+    /// Builds a view over the raw CSR buffers of a valued matrix of the
+    /// provided shape.
+    ///
+    /// # Arguments
+    ///
+    /// * `offsets` - The row offsets, starting with a zero.
+    /// * `column_indices` - The column indices, strictly increasing within
+    ///   every row.
+    /// * `values` - The values, one per column index.
+    /// * `(number_of_rows, number_of_columns)` - The shape of the matrix.
+    ///
+    /// # Errors
+    ///
+    /// * [`CSR2DSliceError::InvalidStructure`] describing the first violated
+    ///   invariant of the offsets and column indices.
+    /// * [`CSR2DSliceError::ValuesLengthMismatch`] when there is not one value
+    ///   per column index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::prelude::*;
+    ///
+    /// let offsets: &[usize] = &[0, 2, 3];
+    /// let columns: &[usize] = &[0, 1, 1];
+    /// let costs: &[f64] = &[4.0, 1.0, 2.0];
+    /// let view = ValuedCSR2DSlice::from_raw_parts(offsets, columns, costs, (2_usize, 2)).unwrap();
+    ///
+    /// assert_eq!(view.sparse_row_values(0).collect::<Vec<_>>(), vec![4.0, 1.0]);
+    /// assert_eq!(view.lapmod(10.0).unwrap(), vec![(0, 0), (1, 1)]);
+    /// ```
+    #[inline]
+    pub fn from_raw_parts(
+        offsets: &'a [SparseIndex],
+        column_indices: &'a [ColumnIndex],
+        values: &'a [Value],
+        shape: (RowIndex, ColumnIndex),
+    ) -> Result<Self, CSR2DSliceError> {
+        let csr = CSR2DSlice::from_raw_parts(offsets, column_indices, shape)?;
+        if values.len() != column_indices.len() {
+            return Err(CSR2DSliceError::ValuesLengthMismatch {
+                expected: column_indices.len(),
+                actual: values.len(),
+            });
+        }
+        Ok(Self { csr, values })
+    }
+
+    /// Returns the view over the structure of the matrix.
+    #[inline]
+    #[must_use]
+    pub fn structure(&self) -> CSR2DSlice<'a, SparseIndex, RowIndex, ColumnIndex>
+    where
+        RowIndex: Copy,
+        ColumnIndex: Copy,
+    {
+        self.csr
+    }
+
+    /// Returns the values of the matrix, in the order of its column indices.
+    #[inline]
+    #[must_use]
+    pub fn values(&self) -> &'a [Value] {
+        self.values
+    }
+}
+
+impl<SparseIndex: Clone, RowIndex: Copy, ColumnIndex: Copy, Value>
+    ValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value>
+{
+    /// Returns a view borrowing the storage of the matrix.
+    #[inline]
+    pub fn as_slice(&self) -> ValuedCSR2DSlice<'_, SparseIndex, RowIndex, ColumnIndex, Value> {
+        ValuedCSR2DSlice { csr: self.csr.as_slice(), values: &self.values }
+    }
+}
+
+impl<SparseIndex: Clone, RowIndex: Copy, ColumnIndex: Copy, Value: Clone>
+    From<ValuedCSR2DSlice<'_, SparseIndex, RowIndex, ColumnIndex, Value>>
+    for ValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value>
+{
+    #[inline]
+    fn from(view: ValuedCSR2DSlice<'_, SparseIndex, RowIndex, ColumnIndex, Value>) -> Self {
+        ValuedCSR2D { csr: view.csr.into(), values: view.values.to_vec() }
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value> Matrix
+    for ValuedCSR2DSlice<'_, SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    RowIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: PositiveInteger + AsPrimitive<usize>,
+{
+    type Coordinates = (RowIndex, ColumnIndex);
+
+    #[inline]
+    fn shape(&self) -> Vec<usize> {
+        self.csr.shape()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value> Matrix2D
+    for ValuedCSR2DSlice<'_, SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize>,
+{
+    type RowIndex = RowIndex;
+    type ColumnIndex = ColumnIndex;
+
+    #[inline]
+    fn number_of_rows(&self) -> Self::RowIndex {
+        self.csr.number_of_rows()
+    }
+
+    #[inline]
+    fn number_of_columns(&self) -> Self::ColumnIndex {
+        self.csr.number_of_columns()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value> Matrix2DRef
+    for ValuedCSR2DSlice<'_, SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize>,
+{
+    #[inline]
+    fn number_of_rows_ref(&self) -> &Self::RowIndex {
+        self.csr.number_of_rows_ref()
+    }
+
+    #[inline]
+    fn number_of_columns_ref(&self) -> &Self::ColumnIndex {
+        self.csr.number_of_columns_ref()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value> SparseMatrix
+    for ValuedCSR2DSlice<'_, SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+{
+    type SparseIndex = SparseIndex;
+    type SparseCoordinates<'b>
+        = super::CSR2DView<'b, Self>
+    where
+        Self: 'b;
+
+    #[inline]
+    fn sparse_coordinates(&self) -> Self::SparseCoordinates<'_> {
+        self.into()
+    }
+
+    #[inline]
+    fn last_sparse_coordinates(&self) -> Option<Self::Coordinates> {
+        self.csr.last_sparse_coordinates()
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.csr.is_empty()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value> SizedSparseMatrix
+    for ValuedCSR2DSlice<'_, SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+{
+    #[inline]
+    fn number_of_defined_values(&self) -> Self::SparseIndex {
+        self.csr.number_of_defined_values()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value> RankSelectSparseMatrix
+    for ValuedCSR2DSlice<'_, SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+{
+    #[inline]
+    fn rank(&self, coordinates: &Self::Coordinates) -> Self::SparseIndex {
+        self.csr.rank(coordinates)
+    }
+
+    #[inline]
+    fn select(&self, sparse_index: Self::SparseIndex) -> Self::Coordinates {
+        self.csr.select(sparse_index)
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value> SparseMatrix2D
+    for ValuedCSR2DSlice<'_, SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+{
+    type SparseRow<'b>
+        = core::iter::Copied<core::slice::Iter<'b, Self::ColumnIndex>>
+    where
+        Self: 'b;
+    type SparseColumns<'b>
+        = core::iter::Copied<core::slice::Iter<'b, Self::ColumnIndex>>
+    where
+        Self: 'b;
+    type SparseRows<'b>
+        = CSR2DSizedRows<'b, Self>
+    where
+        Self: 'b;
+
+    #[inline]
+    fn sparse_row(&self, row: Self::RowIndex) -> Self::SparseRow<'_> {
+        self.csr.sparse_row_slice(row).iter().copied()
+    }
+
+    #[inline]
+    unsafe fn sparse_row_unchecked(&self, row: Self::RowIndex) -> Self::SparseRow<'_> {
+        // SAFETY: the caller upholds the contract of the underlying matrix.
+        unsafe { self.csr.sparse_row_unchecked(row) }
+    }
+
+    #[inline]
+    fn has_entry(&self, row: Self::RowIndex, column: Self::ColumnIndex) -> bool {
+        self.csr.has_entry(row, column)
+    }
+
+    #[inline]
+    fn sparse_columns(&self) -> Self::SparseColumns<'_> {
+        self.csr.column_indices.iter().copied()
+    }
+
+    #[inline]
+    fn sparse_rows(&self) -> Self::SparseRows<'_> {
+        self.into()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value> EmptyRows
+    for ValuedCSR2DSlice<'_, SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+{
+    type EmptyRowIndices<'b>
+        = CSR2DEmptyRowIndices<'b, Self>
+    where
+        Self: 'b;
+    type NonEmptyRowIndices<'b>
+        = CSR2DNonEmptyRowIndices<'b, Self>
+    where
+        Self: 'b;
+
+    #[inline]
+    fn number_of_non_empty_rows(&self) -> Self::RowIndex {
+        self.csr.number_of_non_empty_rows()
+    }
+
+    #[inline]
+    fn number_of_empty_rows(&self) -> Self::RowIndex {
+        self.csr.number_of_empty_rows()
+    }
+
+    #[inline]
+    fn empty_row_indices(&self) -> Self::EmptyRowIndices<'_> {
+        self.into()
+    }
+
+    #[inline]
+    fn non_empty_row_indices(&self) -> Self::NonEmptyRowIndices<'_> {
+        self.into()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value> SizedSparseMatrix2D
+    for ValuedCSR2DSlice<'_, SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+{
+    #[inline]
+    fn rank_row(&self, row: Self::RowIndex) -> Self::SparseIndex {
+        self.csr.rank_row(row)
+    }
+
+    #[inline]
+    fn rank_row_unchecked(&self, row: Self::RowIndex) -> Self::SparseIndex {
+        self.csr.rank_row_unchecked(row)
+    }
+
+    #[inline]
+    fn select_row(&self, sparse_index: Self::SparseIndex) -> Self::RowIndex {
+        self.csr.select_row(sparse_index)
+    }
+
+    #[inline]
+    fn select_column(&self, sparse_index: Self::SparseIndex) -> Self::ColumnIndex {
+        self.csr.select_column(sparse_index)
+    }
+
+    #[inline]
+    unsafe fn select_column_unchecked(&self, sparse_index: Self::SparseIndex) -> Self::ColumnIndex {
+        // SAFETY: the caller upholds the contract of the underlying matrix.
+        unsafe { self.csr.select_column_unchecked(sparse_index) }
+    }
+
+    #[inline]
+    fn try_rank(
+        &self,
+        row: Self::RowIndex,
+        column: Self::ColumnIndex,
+    ) -> Result<Self::SparseIndex, RankSelectError>
+    where
+        Self::ColumnIndex: PartialEq,
+    {
+        self.csr.try_rank(row, column)
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value> SizedRowsSparseMatrix2D
+    for ValuedCSR2DSlice<'_, SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+{
+    type SparseRowSizes<'b>
+        = CSR2DSizedRowsizes<'b, Self>
+    where
+        Self: 'b;
+
+    #[inline]
+    fn sparse_row_sizes(&self) -> Self::SparseRowSizes<'_> {
+        self.into()
+    }
+
+    #[inline]
+    fn number_of_defined_values_in_row(&self, row: Self::RowIndex) -> Self::ColumnIndex {
+        self.csr.number_of_defined_values_in_row(row)
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value> ValuedMatrix
+    for ValuedCSR2DSlice<'_, SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    Self: Matrix,
+{
+    type Value = Value;
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value> ValuedMatrix2D
+    for ValuedCSR2DSlice<'_, SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    Self: Matrix2D,
+{
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value> SparseValuedMatrix
+    for ValuedCSR2DSlice<'_, SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    Self: SparseMatrix + ValuedMatrix<Value = Value>,
+    Value: Clone,
+{
+    type SparseValues<'b>
+        = core::iter::Cloned<core::slice::Iter<'b, Value>>
+    where
+        Self: 'b;
+
+    #[inline]
+    fn max_sparse_value(&self) -> Option<Self::Value>
+    where
+        Self::Value: TotalOrd,
+    {
+        Value::total_max(self.values)
+    }
+
+    #[inline]
+    fn sparse_values(&self) -> Self::SparseValues<'_> {
+        self.values.iter().cloned()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value> SizedSparseValuedMatrix
+    for ValuedCSR2DSlice<'_, SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+    Value: Clone,
+{
+    #[inline]
+    fn select_value(&self, sparse_index: Self::SparseIndex) -> Self::Value {
+        self.values[sparse_index.as_()].clone()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value> SparseValuedMatrix2D
+    for ValuedCSR2DSlice<'_, SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    Self: SizedSparseMatrix2D + SparseValuedMatrix<Value = Value>,
+    Value: Clone,
+{
+    type SparseRowValues<'b>
+        = core::iter::Cloned<core::slice::Iter<'b, Value>>
+    where
+        Self: 'b;
+
+    #[inline]
+    fn sparse_row_values(&self, row: Self::RowIndex) -> Self::SparseRowValues<'_> {
+        let start = self.rank_row(row).as_();
+        let end = self.rank_row(row + Self::RowIndex::one()).as_();
+        self.values[start..end].iter().cloned()
+    }
+
+    #[inline]
+    unsafe fn sparse_row_values_unchecked(&self, row: Self::RowIndex) -> Self::SparseRowValues<'_> {
+        let start = self.rank_row_unchecked(row).as_();
+        let end = self.rank_row_unchecked(row + Self::RowIndex::one()).as_();
+        // SAFETY: the caller guarantees that the row is in bounds, so that its
+        // ranks and the ones of its successor delimit its values.
+        unsafe { self.values.get_unchecked(start..end) }.iter().cloned()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value> SparseValuedMatrixRef
+    for ValuedCSR2DSlice<'_, SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    Self: SparseValuedMatrix<Value = Value>,
+{
+    type SparseValuesRef<'b>
+        = core::slice::Iter<'b, Value>
+    where
+        Self: 'b,
+        Value: 'b;
+
+    #[inline]
+    fn sparse_values_ref(&self) -> Self::SparseValuesRef<'_> {
+        self.values.iter()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value> SizedSparseValuedMatrixRef
+    for ValuedCSR2DSlice<'_, SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+    Value: Clone,
+{
+    #[inline]
+    fn select_value_ref(&self, sparse_index: Self::SparseIndex) -> &Self::Value {
+        &self.values[sparse_index.as_()]
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value> SparseValuedMatrix2DRef
+    for ValuedCSR2DSlice<'_, SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    Self: SizedSparseMatrix2D
+        + SparseValuedMatrix2D<Value = Value>
+        + SparseValuedMatrixRef<Value = Value>,
+{
+    type SparseRowValuesRef<'b>
+        = core::slice::Iter<'b, Value>
+    where
+        Self: 'b,
+        Value: 'b;
+
+    #[inline]
+    fn sparse_row_values_ref(&self, row: Self::RowIndex) -> Self::SparseRowValuesRef<'_> {
+        let start = self.rank_row(row).as_();
+        let end = self.rank_row(row + Self::RowIndex::one()).as_();
+        self.values[start..end].iter()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value> Validate
+    for ValuedCSR2DSlice<'_, SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    SparseIndex: PositiveInteger,
+    RowIndex: PositiveInteger,
+    ColumnIndex: PositiveInteger,
+{
+    #[inline]
+    fn validate(&self) -> Result<(), InvariantError> {
+        self.csr.validate()?;
+        if self.values.len() != self.csr.column_indices.len() {
+            return Err(InvariantError::ValuesLengthMismatch {
+                expected: self.csr.column_indices.len(),
+                actual: self.values.len(),
+            });
+        }
+        Ok(())
+    }
+}
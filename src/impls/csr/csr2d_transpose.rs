@@ -0,0 +1,184 @@
+//! Submodule providing the cache-blocked fill of the transpose of a CSR2D
+//! matrix.
+//!
+//! Scattering every entry directly to its destination row writes at random
+//! across the whole output, which becomes memory-bound once the matrix no
+//! longer fits in cache. The blocked fill instead first buckets the entries
+//! by tiles of consecutive destination rows, writing sequentially into one
+//! staging region per tile, and then scatters every tile within its own
+//! output region, which is small enough to stay in cache. With the
+//! `parallel` feature, [`par_fill_transposed_blocked`] distributes both
+//! passes over the thread pool.
+
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use num_traits::AsPrimitive;
+
+use crate::traits::{PositiveInteger, TryFromUsize};
+
+/// Minimal number of defined values from which the transpose is blocked.
+pub(super) const BLOCKED_TRANSPOSE_MIN_VALUES: usize = 1 << 16;
+
+/// Number of defined values targeted by every tile, so that the output
+/// region of a tile fits in the cache.
+const TILE_VALUES: usize = 1 << 14;
+
+/// Maximal number of tiles, bounding the number of staging regions written
+/// concurrently by the bucketing pass.
+const MAX_TILES: usize = 1 << 10;
+
+/// Defines a function filling the row indices of the transposed matrix by
+/// tiles, running its passes through the provided task mapper over the
+/// provided number of chunks of rows, with the provided additional bounds on
+/// the index types.
+macro_rules! fill_transposed_blocked_fn {
+    (
+        $(#[$attribute:meta])*
+        $name:ident,
+        $map_tasks:ident,
+        $number_of_chunks:expr
+        $(, $bound:path)*
+    ) => {
+        /// Fills the row indices of the transposed matrix, whose offsets are
+        /// already computed, with the same result as scattering the entries
+        /// row by row.
+        ///
+        /// # Arguments
+        ///
+        /// * `offsets` - The row offsets of the matrix.
+        /// * `column_indices` - The column indices of the matrix.
+        /// * `transposed_offsets` - The row offsets of the transposed matrix.
+        /// * `transposed_column_indices` - The column indices of the
+        ///   transposed matrix, to fill.
+        $(#[$attribute])*
+        pub(super) fn $name<SparseIndex, RowIndex, ColumnIndex>(
+            offsets: &[SparseIndex],
+            column_indices: &[ColumnIndex],
+            transposed_offsets: &[SparseIndex],
+            transposed_column_indices: &mut [RowIndex],
+        ) where
+            SparseIndex: PositiveInteger + AsPrimitive<usize> $(+ $bound)*,
+            RowIndex: PositiveInteger + TryFromUsize $(+ $bound)*,
+            ColumnIndex: PositiveInteger + AsPrimitive<usize> $(+ $bound)*,
+        {
+            let number_of_values = column_indices.len();
+            let number_of_columns = transposed_offsets.len() - 1;
+            let number_of_tiles = (number_of_values / TILE_VALUES).clamp(1, MAX_TILES);
+            let tile_width = number_of_columns.div_ceil(number_of_tiles).max(1);
+            let tile_bounds: Vec<usize> = (0..=number_of_tiles)
+                .map(|tile| transposed_offsets[(tile * tile_width).min(number_of_columns)].as_())
+                .collect();
+
+            // Splitting the rows into contiguous chunks, every tile receives the
+            // entries of the chunks in order, and so by increasing row.
+            let row_chunks = row_chunks(offsets, $number_of_chunks);
+            let tile_counts: Vec<Vec<usize>> = $map_tasks(row_chunks.clone(), |rows| {
+                let mut counts = vec![0; number_of_tiles];
+                for &column in &column_indices[offsets[rows.start].as_()..offsets[rows.end].as_()] {
+                    counts[column.as_() / tile_width] += 1;
+                }
+                counts
+            });
+
+            let mut staging: Vec<(ColumnIndex, RowIndex)> =
+                vec![(ColumnIndex::zero(), RowIndex::zero()); number_of_values];
+            let mut chunk_regions: Vec<Vec<&mut [(ColumnIndex, RowIndex)]>> =
+                row_chunks.iter().map(|_| Vec::with_capacity(number_of_tiles)).collect();
+            let mut remaining = staging.as_mut_slice();
+            for tile in 0..number_of_tiles {
+                for (regions, counts) in chunk_regions.iter_mut().zip(&tile_counts) {
+                    let (region, rest) = remaining.split_at_mut(counts[tile]);
+                    regions.push(region);
+                    remaining = rest;
+                }
+            }
+            debug_assert!(remaining.is_empty());
+
+            $map_tasks(row_chunks.into_iter().zip(chunk_regions).collect(), |(rows, mut regions)| {
+                let mut cursors = vec![0; number_of_tiles];
+                for row in rows {
+                    let row_index = RowIndex::try_from_usize(row)
+                        .unwrap_or_else(|_| unreachable!("The rows of the matrix fit its row index."));
+                    for &column in &column_indices[offsets[row].as_()..offsets[row + 1].as_()] {
+                        let tile = column.as_() / tile_width;
+                        regions[tile][cursors[tile]] = (column, row_index);
+                        cursors[tile] += 1;
+                    }
+                }
+            });
+
+            let mut tiles = Vec::with_capacity(number_of_tiles);
+            let mut remaining_output = transposed_column_indices;
+            for (tile, bounds) in tile_bounds.windows(2).enumerate() {
+                let (output, rest) = remaining_output.split_at_mut(bounds[1] - bounds[0]);
+                tiles.push((tile, &staging[bounds[0]..bounds[1]], output));
+                remaining_output = rest;
+            }
+            $map_tasks(tiles, |(tile, entries, output)| {
+                // Trailing tiles may start past the last column, and are then empty.
+                let first_column = (tile * tile_width).min(number_of_columns);
+                let columns = first_column..((tile + 1) * tile_width).min(number_of_columns);
+                let tile_start = transposed_offsets[first_column].as_();
+                let mut cursors: Vec<usize> =
+                    transposed_offsets[columns].iter().map(|offset| offset.as_() - tile_start).collect();
+                for &(column, row) in entries {
+                    let cursor = &mut cursors[column.as_() - first_column];
+                    output[*cursor] = row;
+                    *cursor += 1;
+                }
+            });
+        }
+    };
+}
+
+fill_transposed_blocked_fn!(fill_transposed_blocked, map_tasks, 1);
+
+fill_transposed_blocked_fn!(
+    #[cfg(feature = "parallel")]
+    par_fill_transposed_blocked,
+    par_map_tasks,
+    rayon::current_num_threads().saturating_mul(4),
+    Send,
+    Sync
+);
+
+/// Returns the contiguous chunks of rows processed by distinct tasks,
+/// balanced by their number of defined values.
+fn row_chunks<SparseIndex: AsPrimitive<usize>>(
+    offsets: &[SparseIndex],
+    number_of_chunks: usize,
+) -> Vec<Range<usize>> {
+    let number_of_rows = offsets.len() - 1;
+    let number_of_values = offsets[number_of_rows].as_();
+    let chunk_values = number_of_values.div_ceil(number_of_chunks).max(1);
+    let mut chunks = Vec::with_capacity(number_of_chunks);
+    let mut start = 0;
+    while start < number_of_rows {
+        let target = offsets[start].as_() + chunk_values;
+        let end = start + 1 + offsets[start + 1..].partition_point(|offset| offset.as_() < target);
+        let end = end.min(number_of_rows);
+        chunks.push(start..end);
+        start = end;
+    }
+    chunks
+}
+
+/// Runs the closure on every task, and returns the results in the order of
+/// the tasks.
+fn map_tasks<T, U, F: Fn(T) -> U>(tasks: Vec<T>, f: F) -> Vec<U> {
+    tasks.into_iter().map(f).collect()
+}
+
+/// Runs the closure on every task on the thread pool, and returns the results
+/// in the order of the tasks.
+#[cfg(feature = "parallel")]
+fn par_map_tasks<T, U, F>(tasks: Vec<T>, f: F) -> Vec<U>
+where
+    T: Send,
+    U: Send,
+    F: Fn(T) -> U + Send + Sync,
+{
+    use rayon::prelude::*;
+    tasks.into_par_iter().map(f).collect()
+}
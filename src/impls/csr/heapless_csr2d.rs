@@ -0,0 +1,1016 @@
+//! Submodule providing a CSR matrix stored in fixed-capacity buffers, usable
+//! without an allocator.
+//!
+//! The [`HeaplessCSR2D`] keeps its row offsets and column indices in
+//! [`heapless`] vectors whose capacities are const-generic parameters, so
+//! that it can live on the stack or in a `static` of `#![no_std]` firmware
+//! without a global allocator. Adding an entry beyond the capacities fails
+//! with [`MutabilityError::CapacityExceeded`] instead of reallocating.
+
+use core::ops::Range;
+
+use multi_ranged::Step;
+use num_traits::{AsPrimitive, One, Zero};
+
+use crate::{
+    errors::{InvariantError, RankSelectError},
+    impls::{
+        CSR2DEmptyRowIndices, CSR2DNonEmptyRowIndices, CSR2DSizedRows, CSR2DSizedRowsizes,
+        CSR2DView, MutabilityError,
+    },
+    prelude::*,
+    traits::{PositiveInteger, TryFromUsize},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// A compressed sparse row matrix stored in fixed-capacity buffers.
+///
+/// At most `ROWS` rows may hold entries, and at most `EDGES` entries may be
+/// defined. The rows following the last row holding entries are implicitly
+/// empty, so the number of rows of the matrix may exceed `ROWS`.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::prelude::*;
+///
+/// let mut matrix: HeaplessCSR2D<u8, u8, u8, 4, 8> = SparseMatrixMut::with_sparse_shape((4, 4));
+/// MatrixMut::add(&mut matrix, (0, 1)).unwrap();
+/// MatrixMut::add(&mut matrix, (0, 3)).unwrap();
+/// MatrixMut::add(&mut matrix, (2, 0)).unwrap();
+///
+/// assert_eq!(matrix.number_of_defined_values(), 3);
+/// assert!(matrix.has_entry(0, 3));
+/// assert!(matrix.sparse_row(1).next().is_none());
+/// assert_eq!(matrix.number_of_empty_rows(), 2);
+/// ```
+pub struct HeaplessCSR2D<SparseIndex, RowIndex, ColumnIndex, const ROWS: usize, const EDGES: usize>
+{
+    /// The position past the last column index of every row holding entries
+    /// or preceding such a row.
+    row_ends: heapless::Vec<SparseIndex, ROWS>,
+    /// The number of columns.
+    number_of_columns: ColumnIndex,
+    /// The number of rows.
+    number_of_rows: RowIndex,
+    /// The column indices.
+    column_indices: heapless::Vec<ColumnIndex, EDGES>,
+    /// The number of non-empty rows.
+    number_of_non_empty_rows: RowIndex,
+}
+
+impl<SparseIndex, RowIndex: Zero, ColumnIndex: Zero, const ROWS: usize, const EDGES: usize> Default
+    for HeaplessCSR2D<SparseIndex, RowIndex, ColumnIndex, ROWS, EDGES>
+{
+    #[inline]
+    fn default() -> Self {
+        Self {
+            row_ends: heapless::Vec::new(),
+            number_of_columns: ColumnIndex::zero(),
+            number_of_rows: RowIndex::zero(),
+            column_indices: heapless::Vec::new(),
+            number_of_non_empty_rows: RowIndex::zero(),
+        }
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, const ROWS: usize, const EDGES: usize>
+    HeaplessCSR2D<SparseIndex, RowIndex, ColumnIndex, ROWS, EDGES>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize>,
+{
+    /// Returns the column indices of the matrix.
+    #[inline]
+    #[must_use]
+    pub fn column_indices(&self) -> &[ColumnIndex] {
+        &self.column_indices
+    }
+
+    /// Returns the range of the column indices of a row.
+    #[inline]
+    fn row_range(&self, row: usize) -> Range<usize> {
+        let number_of_defined_values = self.column_indices.len();
+        let start = match row.checked_sub(1) {
+            Some(previous) => {
+                self.row_ends.get(previous).map_or(number_of_defined_values, |end| end.as_())
+            }
+            None => 0,
+        };
+        let end = self.row_ends.get(row).map_or(number_of_defined_values, |end| end.as_());
+        start..end
+    }
+
+    /// Returns the column indices of a row.
+    #[inline]
+    fn row_slice(&self, row: usize) -> &[ColumnIndex] {
+        &self.column_indices[self.row_range(row)]
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, const ROWS: usize, const EDGES: usize> Matrix
+    for HeaplessCSR2D<SparseIndex, RowIndex, ColumnIndex, ROWS, EDGES>
+where
+    RowIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: PositiveInteger + AsPrimitive<usize>,
+{
+    type Coordinates = (RowIndex, ColumnIndex);
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn shape(&self) -> alloc::vec::Vec<usize> {
+        alloc::vec![self.number_of_rows.as_(), self.number_of_columns.as_()]
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, const ROWS: usize, const EDGES: usize> Matrix2D
+    for HeaplessCSR2D<SparseIndex, RowIndex, ColumnIndex, ROWS, EDGES>
+where
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize>,
+{
+    type RowIndex = RowIndex;
+    type ColumnIndex = ColumnIndex;
+
+    #[inline]
+    fn number_of_rows(&self) -> Self::RowIndex {
+        self.number_of_rows
+    }
+
+    #[inline]
+    fn number_of_columns(&self) -> Self::ColumnIndex {
+        self.number_of_columns
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, const ROWS: usize, const EDGES: usize> Matrix2DRef
+    for HeaplessCSR2D<SparseIndex, RowIndex, ColumnIndex, ROWS, EDGES>
+where
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize>,
+{
+    #[inline]
+    fn number_of_rows_ref(&self) -> &Self::RowIndex {
+        &self.number_of_rows
+    }
+
+    #[inline]
+    fn number_of_columns_ref(&self) -> &Self::ColumnIndex {
+        &self.number_of_columns
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, const ROWS: usize, const EDGES: usize> SparseMatrix
+    for HeaplessCSR2D<SparseIndex, RowIndex, ColumnIndex, ROWS, EDGES>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+{
+    type SparseIndex = SparseIndex;
+    type SparseCoordinates<'a>
+        = CSR2DView<'a, Self>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn sparse_coordinates(&self) -> Self::SparseCoordinates<'_> {
+        self.into()
+    }
+
+    #[inline]
+    fn last_sparse_coordinates(&self) -> Option<Self::Coordinates> {
+        let last_column = self.column_indices.last().copied()?;
+        Some((self.select_row(self.number_of_defined_values() - SparseIndex::one()), last_column))
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.column_indices.is_empty()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, const ROWS: usize, const EDGES: usize> SizedSparseMatrix
+    for HeaplessCSR2D<SparseIndex, RowIndex, ColumnIndex, ROWS, EDGES>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+{
+    #[inline]
+    fn number_of_defined_values(&self) -> Self::SparseIndex {
+        self.row_ends.last().copied().unwrap_or(SparseIndex::zero())
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, const ROWS: usize, const EDGES: usize>
+    RankSelectSparseMatrix for HeaplessCSR2D<SparseIndex, RowIndex, ColumnIndex, ROWS, EDGES>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+{
+    #[inline]
+    fn select(&self, sparse_index: Self::SparseIndex) -> Self::Coordinates {
+        (self.select_row(sparse_index), self.select_column(sparse_index))
+    }
+
+    #[inline]
+    fn rank(&self, &(row_index, column_index): &Self::Coordinates) -> Self::SparseIndex {
+        self.try_rank(row_index, column_index).unwrap_or_else(|_| {
+            panic!("The column index {column_index} is not present in the row {row_index}.")
+        })
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, const ROWS: usize, const EDGES: usize> SparseMatrix2D
+    for HeaplessCSR2D<SparseIndex, RowIndex, ColumnIndex, ROWS, EDGES>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+{
+    type SparseRow<'a>
+        = core::iter::Copied<core::slice::Iter<'a, Self::ColumnIndex>>
+    where
+        Self: 'a;
+    type SparseColumns<'a>
+        = core::iter::Copied<core::slice::Iter<'a, Self::ColumnIndex>>
+    where
+        Self: 'a;
+    type SparseRows<'a>
+        = CSR2DSizedRows<'a, Self>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn sparse_row(&self, row: Self::RowIndex) -> Self::SparseRow<'_> {
+        assert!(
+            row < self.number_of_rows,
+            "The row index {row} is out of bounds for a matrix with {} rows.",
+            self.number_of_rows
+        );
+        self.row_slice(row.as_()).iter().copied()
+    }
+
+    #[inline]
+    fn has_entry(&self, row: Self::RowIndex, column: Self::ColumnIndex) -> bool {
+        self.row_slice(row.as_()).binary_search(&column).is_ok()
+    }
+
+    #[inline]
+    fn sparse_columns(&self) -> Self::SparseColumns<'_> {
+        self.column_indices.iter().copied()
+    }
+
+    #[inline]
+    fn sparse_rows(&self) -> Self::SparseRows<'_> {
+        self.into()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, const ROWS: usize, const EDGES: usize> EmptyRows
+    for HeaplessCSR2D<SparseIndex, RowIndex, ColumnIndex, ROWS, EDGES>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+{
+    type EmptyRowIndices<'a>
+        = CSR2DEmptyRowIndices<'a, Self>
+    where
+        Self: 'a;
+    type NonEmptyRowIndices<'a>
+        = CSR2DNonEmptyRowIndices<'a, Self>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn number_of_non_empty_rows(&self) -> Self::RowIndex {
+        self.number_of_non_empty_rows
+    }
+
+    #[inline]
+    fn number_of_empty_rows(&self) -> Self::RowIndex {
+        self.number_of_rows - self.number_of_non_empty_rows
+    }
+
+    #[inline]
+    fn empty_row_indices(&self) -> Self::EmptyRowIndices<'_> {
+        self.into()
+    }
+
+    #[inline]
+    fn non_empty_row_indices(&self) -> Self::NonEmptyRowIndices<'_> {
+        self.into()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, const ROWS: usize, const EDGES: usize> SizedSparseMatrix2D
+    for HeaplessCSR2D<SparseIndex, RowIndex, ColumnIndex, ROWS, EDGES>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+{
+    #[inline]
+    fn rank_row(&self, row: RowIndex) -> SparseIndex {
+        assert!(
+            row <= self.number_of_rows,
+            "The row index {row} is greater than the number of rows {}.",
+            self.number_of_rows
+        );
+        match row.as_().checked_sub(1) {
+            // Rows past the ones described by the row ends are empty.
+            Some(previous) => {
+                self.row_ends
+                    .get(previous)
+                    .copied()
+                    .unwrap_or_else(|| self.number_of_defined_values())
+            }
+            None => SparseIndex::zero(),
+        }
+    }
+
+    #[inline]
+    fn select_row(&self, sparse_index: Self::SparseIndex) -> Self::RowIndex {
+        assert!(
+            sparse_index < self.number_of_defined_values(),
+            "The sparse index {sparse_index} is out of bounds for a matrix with {} defined values.",
+            self.number_of_defined_values()
+        );
+        let row = self.row_ends.partition_point(|&end| end <= sparse_index);
+        Self::RowIndex::try_from_usize(row).unwrap_or_else(|_| {
+            unreachable!("The rows described by the row ends fit the row index.")
+        })
+    }
+
+    #[inline]
+    fn select_column(&self, sparse_index: Self::SparseIndex) -> Self::ColumnIndex {
+        self.column_indices[sparse_index.as_()]
+    }
+
+    #[inline]
+    fn try_rank(
+        &self,
+        row: Self::RowIndex,
+        column: Self::ColumnIndex,
+    ) -> Result<Self::SparseIndex, RankSelectError>
+    where
+        Self::ColumnIndex: PartialEq,
+    {
+        if row >= self.number_of_rows() {
+            return Err(RankSelectError::RowOutOfBounds {
+                row: row.as_(),
+                number_of_rows: self.number_of_rows().as_(),
+            });
+        }
+        let range = self.row_range(row.as_());
+        let position = self.column_indices[range.clone()]
+            .binary_search(&column)
+            .map_err(|_| RankSelectError::MissingEntry { row: row.as_(), column: column.as_() })?;
+        Ok(Self::SparseIndex::try_from_usize(range.start + position).unwrap_or_else(|_| {
+            unreachable!("The positions within the matrix fit the sparse index.")
+        }))
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, const ROWS: usize, const EDGES: usize>
+    SizedRowsSparseMatrix2D for HeaplessCSR2D<SparseIndex, RowIndex, ColumnIndex, ROWS, EDGES>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+{
+    type SparseRowSizes<'a>
+        = CSR2DSizedRowsizes<'a, Self>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn sparse_row_sizes(&self) -> Self::SparseRowSizes<'_> {
+        self.into()
+    }
+
+    #[inline]
+    fn number_of_defined_values_in_row(&self, row: Self::RowIndex) -> Self::ColumnIndex {
+        self.sparse_row(row).len().try_into().unwrap_or_else(|_| {
+            unreachable!(
+                "The column indices of a row are distinct, so their number fits the column index."
+            )
+        })
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, const ROWS: usize, const EDGES: usize> MatrixMut
+    for HeaplessCSR2D<SparseIndex, RowIndex, ColumnIndex, ROWS, EDGES>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+{
+    type Entry = Self::Coordinates;
+    type Error = MutabilityError<Self>;
+
+    #[inline]
+    fn add(&mut self, (row, column): Self::Entry) -> Result<(), Self::Error> {
+        if column == ColumnIndex::max_value() {
+            return Err(MutabilityError::MaxedOutColumnIndex);
+        }
+        if row == RowIndex::max_value() {
+            return Err(MutabilityError::MaxedOutRowIndex);
+        }
+        let row_usize = row.as_();
+        let described_rows = self.row_ends.len();
+        if row_usize + 1 < described_rows {
+            return Err(MutabilityError::UnorderedCoordinate((row, column)));
+        }
+        if row_usize + 1 == described_rows {
+            match self.row_slice(row_usize).last() {
+                Some(&last) if last == column => {
+                    return Err(MutabilityError::DuplicatedEntry((row, column)));
+                }
+                Some(&last) if last > column => {
+                    return Err(MutabilityError::UnorderedCoordinate((row, column)));
+                }
+                _ => {}
+            }
+        }
+        let last_end = self.number_of_defined_values();
+        if last_end == SparseIndex::max_value() {
+            return Err(MutabilityError::MaxedOutSparseIndex);
+        }
+        if row_usize >= ROWS {
+            return Err(MutabilityError::CapacityExceeded("row ends"));
+        }
+        if self.column_indices.is_full() {
+            return Err(MutabilityError::CapacityExceeded("column indices"));
+        }
+
+        if row_usize + 1 > described_rows {
+            // The rows skipped since the last row holding entries are empty.
+            self.row_ends.resize(row_usize + 1, last_end).unwrap_or_else(|()| {
+                unreachable!("The row ends were checked to fit the capacity.")
+            });
+            self.number_of_non_empty_rows += RowIndex::one();
+        }
+        let end = self
+            .row_ends
+            .last_mut()
+            .unwrap_or_else(|| unreachable!("The row of the entry has a row end."));
+        *end = last_end + SparseIndex::one();
+        self.column_indices.push(column).unwrap_or_else(|_| {
+            unreachable!("The column indices were checked to fit the capacity.")
+        });
+        self.number_of_columns = self.number_of_columns.max(column + ColumnIndex::one());
+        self.number_of_rows = self.number_of_rows.max(row + RowIndex::one());
+        Ok(())
+    }
+
+    #[inline]
+    fn increase_shape(
+        &mut self,
+        (number_of_rows, number_of_columns): Self::Coordinates,
+    ) -> Result<(), Self::Error> {
+        if number_of_rows < self.number_of_rows || number_of_columns < self.number_of_columns {
+            return Err(MutabilityError::IncompatibleShape);
+        }
+        self.number_of_rows = number_of_rows;
+        self.number_of_columns = number_of_columns;
+        Ok(())
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, const ROWS: usize, const EDGES: usize> SparseMatrixMut
+    for HeaplessCSR2D<SparseIndex, RowIndex, ColumnIndex, ROWS, EDGES>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+{
+    type MinimalShape = Self::Coordinates;
+
+    /// Creates a new matrix with the given shape, the capacity being fixed
+    /// by the const parameters.
+    #[inline]
+    fn with_sparse_shaped_capacity(shape: Self::MinimalShape, _: Self::SparseIndex) -> Self {
+        Self::with_sparse_shape(shape)
+    }
+
+    #[inline]
+    fn with_sparse_shape((number_of_rows, number_of_columns): Self::MinimalShape) -> Self {
+        Self { number_of_columns, number_of_rows, ..Self::default() }
+    }
+
+    /// Creates a new empty matrix, the capacity being fixed by the const
+    /// parameters.
+    #[inline]
+    fn with_sparse_capacity(_: Self::SparseIndex) -> Self {
+        Self::default()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, const ROWS: usize, const EDGES: usize> Validate
+    for HeaplessCSR2D<SparseIndex, RowIndex, ColumnIndex, ROWS, EDGES>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize>,
+    RowIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: PositiveInteger + AsPrimitive<usize>,
+{
+    #[inline]
+    fn validate(&self) -> Result<(), InvariantError> {
+        if self.row_ends.len() > self.number_of_rows.as_() {
+            return Err(InvariantError::TooManyOffsets {
+                described_rows: self.row_ends.len(),
+                number_of_rows: self.number_of_rows.as_(),
+            });
+        }
+        if self.row_ends.last().map_or(0, |end| end.as_()) != self.column_indices.len() {
+            return Err(InvariantError::LastOffsetMismatch {
+                last_offset: self.row_ends.last().map_or(0, |end| end.as_()),
+                number_of_column_indices: self.column_indices.len(),
+            });
+        }
+        // The row ends are checked to be sorted before any row is sliced.
+        if let Some(row) = self.row_ends.windows(2).position(|window| window[0] > window[1]) {
+            return Err(InvariantError::DecreasingOffsets { row: row + 1 });
+        }
+        let mut number_of_non_empty_rows = 0_usize;
+        for row in 0..self.row_ends.len() {
+            let columns = self.row_slice(row);
+            if columns.windows(2).any(|pair| pair[0] >= pair[1]) {
+                return Err(InvariantError::UnsortedRow { row });
+            }
+            if let Some(&column) = columns.last() {
+                if column >= self.number_of_columns {
+                    return Err(InvariantError::ColumnOutOfBounds {
+                        row,
+                        column: column.as_(),
+                        number_of_columns: self.number_of_columns.as_(),
+                    });
+                }
+                number_of_non_empty_rows += 1;
+            }
+        }
+        if number_of_non_empty_rows != self.number_of_non_empty_rows.as_() {
+            return Err(InvariantError::CachedCountMismatch {
+                counted: "non-empty rows",
+                cached: self.number_of_non_empty_rows.as_(),
+                actual: number_of_non_empty_rows,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// A compressed sparse row matrix stored in fixed-capacity buffers, which
+/// stores values in addition to the row and column indices.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::prelude::*;
+///
+/// let mut matrix: HeaplessValuedCSR2D<u8, u8, u8, f32, 2, 2> =
+///     SparseMatrixMut::with_sparse_shape((2, 2));
+/// MatrixMut::add(&mut matrix, (0, 1, 0.5)).unwrap();
+/// MatrixMut::add(&mut matrix, (1, 0, 2.0)).unwrap();
+///
+/// assert_eq!(matrix.sparse_row_values(1).collect::<Vec<_>>(), vec![2.0]);
+/// assert!(MatrixMut::add(&mut matrix, (1, 1, 1.0)).is_err());
+/// ```
+pub struct HeaplessValuedCSR2D<
+    SparseIndex,
+    RowIndex,
+    ColumnIndex,
+    Value,
+    const ROWS: usize,
+    const EDGES: usize,
+> {
+    /// The underlying sparse structure.
+    csr: HeaplessCSR2D<SparseIndex, RowIndex, ColumnIndex, ROWS, EDGES>,
+    /// The values, in the order of the column indices.
+    values: heapless::Vec<Value, EDGES>,
+}
+
+impl<SparseIndex, RowIndex: Zero, ColumnIndex: Zero, Value, const ROWS: usize, const EDGES: usize>
+    Default for HeaplessValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value, ROWS, EDGES>
+{
+    #[inline]
+    fn default() -> Self {
+        Self { csr: HeaplessCSR2D::default(), values: heapless::Vec::new() }
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value, const ROWS: usize, const EDGES: usize> Matrix
+    for HeaplessValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value, ROWS, EDGES>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+{
+    type Coordinates =
+        <HeaplessCSR2D<SparseIndex, RowIndex, ColumnIndex, ROWS, EDGES> as Matrix>::Coordinates;
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn shape(&self) -> alloc::vec::Vec<usize> {
+        self.csr.shape()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value, const ROWS: usize, const EDGES: usize> Matrix2D
+    for HeaplessValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value, ROWS, EDGES>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+{
+    type RowIndex =
+        <HeaplessCSR2D<SparseIndex, RowIndex, ColumnIndex, ROWS, EDGES> as Matrix2D>::RowIndex;
+    type ColumnIndex =
+        <HeaplessCSR2D<SparseIndex, RowIndex, ColumnIndex, ROWS, EDGES> as Matrix2D>::ColumnIndex;
+
+    #[inline]
+    fn number_of_rows(&self) -> Self::RowIndex {
+        self.csr.number_of_rows()
+    }
+
+    #[inline]
+    fn number_of_columns(&self) -> Self::ColumnIndex {
+        self.csr.number_of_columns()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value, const ROWS: usize, const EDGES: usize> Matrix2DRef
+    for HeaplessValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value, ROWS, EDGES>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+{
+    #[inline]
+    fn number_of_rows_ref(&self) -> &Self::RowIndex {
+        self.csr.number_of_rows_ref()
+    }
+
+    #[inline]
+    fn number_of_columns_ref(&self) -> &Self::ColumnIndex {
+        self.csr.number_of_columns_ref()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value, const ROWS: usize, const EDGES: usize> SparseMatrix
+    for HeaplessValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value, ROWS, EDGES>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+{
+    type SparseIndex =
+        <HeaplessCSR2D<SparseIndex, RowIndex, ColumnIndex, ROWS, EDGES> as SparseMatrix>::SparseIndex;
+    type SparseCoordinates<'a>
+        = <HeaplessCSR2D<SparseIndex, RowIndex, ColumnIndex, ROWS, EDGES> as SparseMatrix>::SparseCoordinates<'a>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn sparse_coordinates(&self) -> Self::SparseCoordinates<'_> {
+        self.csr.sparse_coordinates()
+    }
+
+    #[inline]
+    fn last_sparse_coordinates(&self) -> Option<Self::Coordinates> {
+        self.csr.last_sparse_coordinates()
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.csr.is_empty()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value, const ROWS: usize, const EDGES: usize>
+    SizedSparseMatrix
+    for HeaplessValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value, ROWS, EDGES>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+{
+    #[inline]
+    fn number_of_defined_values(&self) -> Self::SparseIndex {
+        self.csr.number_of_defined_values()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value, const ROWS: usize, const EDGES: usize>
+    RankSelectSparseMatrix
+    for HeaplessValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value, ROWS, EDGES>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+{
+    #[inline]
+    fn rank(&self, coordinates: &Self::Coordinates) -> Self::SparseIndex {
+        self.csr.rank(coordinates)
+    }
+
+    #[inline]
+    fn select(&self, sparse_index: Self::SparseIndex) -> Self::Coordinates {
+        self.csr.select(sparse_index)
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value, const ROWS: usize, const EDGES: usize>
+    SparseMatrix2D for HeaplessValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value, ROWS, EDGES>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+{
+    type SparseRow<'a>
+        = <HeaplessCSR2D<SparseIndex, RowIndex, ColumnIndex, ROWS, EDGES> as SparseMatrix2D>::SparseRow<'a>
+    where
+        Self: 'a;
+    type SparseColumns<'a>
+        = <HeaplessCSR2D<SparseIndex, RowIndex, ColumnIndex, ROWS, EDGES> as SparseMatrix2D>::SparseColumns<'a>
+    where
+        Self: 'a;
+    type SparseRows<'a>
+        = <HeaplessCSR2D<SparseIndex, RowIndex, ColumnIndex, ROWS, EDGES> as SparseMatrix2D>::SparseRows<'a>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn sparse_row(&self, row: Self::RowIndex) -> Self::SparseRow<'_> {
+        self.csr.sparse_row(row)
+    }
+
+    #[inline]
+    fn has_entry(&self, row: Self::RowIndex, column: Self::ColumnIndex) -> bool {
+        self.csr.has_entry(row, column)
+    }
+
+    #[inline]
+    fn sparse_columns(&self) -> Self::SparseColumns<'_> {
+        self.csr.sparse_columns()
+    }
+
+    #[inline]
+    fn sparse_rows(&self) -> Self::SparseRows<'_> {
+        self.csr.sparse_rows()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value, const ROWS: usize, const EDGES: usize> EmptyRows
+    for HeaplessValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value, ROWS, EDGES>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+{
+    type EmptyRowIndices<'a>
+        = <HeaplessCSR2D<SparseIndex, RowIndex, ColumnIndex, ROWS, EDGES> as EmptyRows>::EmptyRowIndices<'a>
+    where
+        Self: 'a;
+    type NonEmptyRowIndices<'a>
+        = <HeaplessCSR2D<SparseIndex, RowIndex, ColumnIndex, ROWS, EDGES> as EmptyRows>::NonEmptyRowIndices<'a>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn number_of_non_empty_rows(&self) -> Self::RowIndex {
+        self.csr.number_of_non_empty_rows()
+    }
+
+    #[inline]
+    fn number_of_empty_rows(&self) -> Self::RowIndex {
+        self.csr.number_of_empty_rows()
+    }
+
+    #[inline]
+    fn empty_row_indices(&self) -> Self::EmptyRowIndices<'_> {
+        self.csr.empty_row_indices()
+    }
+
+    #[inline]
+    fn non_empty_row_indices(&self) -> Self::NonEmptyRowIndices<'_> {
+        self.csr.non_empty_row_indices()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value, const ROWS: usize, const EDGES: usize>
+    SizedSparseMatrix2D
+    for HeaplessValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value, ROWS, EDGES>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+{
+    #[inline]
+    fn rank_row(&self, row: Self::RowIndex) -> Self::SparseIndex {
+        self.csr.rank_row(row)
+    }
+
+    #[inline]
+    fn select_row(&self, sparse_index: Self::SparseIndex) -> Self::RowIndex {
+        self.csr.select_row(sparse_index)
+    }
+
+    #[inline]
+    fn select_column(&self, sparse_index: Self::SparseIndex) -> Self::ColumnIndex {
+        self.csr.select_column(sparse_index)
+    }
+
+    #[inline]
+    fn try_rank(
+        &self,
+        row: Self::RowIndex,
+        column: Self::ColumnIndex,
+    ) -> Result<Self::SparseIndex, RankSelectError>
+    where
+        Self::ColumnIndex: PartialEq,
+    {
+        self.csr.try_rank(row, column)
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value, const ROWS: usize, const EDGES: usize>
+    SizedRowsSparseMatrix2D
+    for HeaplessValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value, ROWS, EDGES>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+{
+    type SparseRowSizes<'a>
+        = <HeaplessCSR2D<SparseIndex, RowIndex, ColumnIndex, ROWS, EDGES> as SizedRowsSparseMatrix2D>::SparseRowSizes<'a>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn sparse_row_sizes(&self) -> Self::SparseRowSizes<'_> {
+        self.csr.sparse_row_sizes()
+    }
+
+    #[inline]
+    fn number_of_defined_values_in_row(&self, row: Self::RowIndex) -> Self::ColumnIndex {
+        self.csr.number_of_defined_values_in_row(row)
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value, const ROWS: usize, const EDGES: usize> MatrixMut
+    for HeaplessValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value, ROWS, EDGES>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+{
+    type Entry = (RowIndex, ColumnIndex, Value);
+    type Error = MutabilityError<Self>;
+
+    #[inline]
+    fn add(&mut self, (row, column, value): Self::Entry) -> Result<(), Self::Error> {
+        self.csr.add((row, column))?;
+        // The values share the capacity of the column indices, which just
+        // accepted the entry.
+        self.values
+            .push(value)
+            .unwrap_or_else(|_| unreachable!("The values were checked to fit the capacity."));
+        Ok(())
+    }
+
+    #[inline]
+    fn increase_shape(&mut self, shape: Self::Coordinates) -> Result<(), Self::Error> {
+        self.csr.increase_shape(shape)?;
+        Ok(())
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value, const ROWS: usize, const EDGES: usize>
+    SparseMatrixMut for HeaplessValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value, ROWS, EDGES>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+{
+    type MinimalShape = Self::Coordinates;
+
+    /// Creates a new matrix with the given shape, the capacity being fixed
+    /// by the const parameters.
+    #[inline]
+    fn with_sparse_shaped_capacity(shape: Self::MinimalShape, _: Self::SparseIndex) -> Self {
+        Self::with_sparse_shape(shape)
+    }
+
+    #[inline]
+    fn with_sparse_shape(shape: Self::MinimalShape) -> Self {
+        Self { csr: HeaplessCSR2D::with_sparse_shape(shape), values: heapless::Vec::new() }
+    }
+
+    /// Creates a new empty matrix, the capacity being fixed by the const
+    /// parameters.
+    #[inline]
+    fn with_sparse_capacity(_: Self::SparseIndex) -> Self {
+        Self::default()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value, const ROWS: usize, const EDGES: usize> ValuedMatrix
+    for HeaplessValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value, ROWS, EDGES>
+where
+    Self: Matrix,
+{
+    type Value = Value;
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value, const ROWS: usize, const EDGES: usize>
+    ValuedMatrix2D for HeaplessValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value, ROWS, EDGES>
+where
+    Self: Matrix2D,
+{
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value, const ROWS: usize, const EDGES: usize>
+    SparseValuedMatrix
+    for HeaplessValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value, ROWS, EDGES>
+where
+    Self: SparseMatrix + ValuedMatrix<Value = Value>,
+    Self::Value: Clone,
+{
+    type SparseValues<'a>
+        = core::iter::Cloned<core::slice::Iter<'a, Self::Value>>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn max_sparse_value(&self) -> Option<Self::Value>
+    where
+        Self::Value: TotalOrd,
+    {
+        Value::total_max(&self.values)
+    }
+
+    #[inline]
+    fn sparse_values(&self) -> Self::SparseValues<'_> {
+        self.values.iter().cloned()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value, const ROWS: usize, const EDGES: usize>
+    SizedSparseValuedMatrix
+    for HeaplessValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value, ROWS, EDGES>
+where
+    Self: SparseValuedMatrix<Value = Value>,
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex>,
+    Self::Value: Clone,
+{
+    #[inline]
+    fn select_value(&self, sparse_index: Self::SparseIndex) -> Self::Value {
+        self.values[sparse_index.as_()].clone()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value, const ROWS: usize, const EDGES: usize>
+    SparseValuedMatrix2D
+    for HeaplessValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value, ROWS, EDGES>
+where
+    Self: SizedSparseMatrix2D + SparseValuedMatrix<Value = Value>,
+    Self::Value: Clone,
+{
+    type SparseRowValues<'a>
+        = core::iter::Cloned<core::slice::Iter<'a, Self::Value>>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn sparse_row_values(&self, row: Self::RowIndex) -> Self::SparseRowValues<'_> {
+        let start = self.rank_row(row).as_();
+        let end = self.rank_row(row + Self::RowIndex::one()).as_();
+        self.values[start..end].iter().cloned()
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value, const ROWS: usize, const EDGES: usize> Validate
+    for HeaplessValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value, ROWS, EDGES>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize>,
+    RowIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    ColumnIndex: PositiveInteger + AsPrimitive<usize>,
+{
+    #[inline]
+    fn validate(&self) -> Result<(), InvariantError> {
+        self.csr.validate()?;
+        if self.values.len() != self.csr.column_indices.len() {
+            return Err(InvariantError::ValuesLengthMismatch {
+                expected: self.csr.column_indices.len(),
+                actual: self.values.len(),
+            });
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,418 @@
+//! Submodule providing the re-encoding of CSR matrices with narrower index
+//! types.
+//!
+//! The memory of a CSR matrix is dominated by its column indices, so storing
+//! them with the narrowest integer type fitting the shape of the matrix, such
+//! as `u32` instead of `usize`, halves the memory of most large graphs.
+
+use multi_ranged::Step;
+use num_traits::AsPrimitive;
+
+use super::{CSR2D, SquareCSR2D, SymmetricCSR2D};
+use crate::{
+    impls::ValuedCSR2D,
+    traits::{PositiveInteger, TryFromUsize},
+};
+
+/// The integer types usable as indices of a compacted matrix, by increasing
+/// width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum IndexWidth {
+    /// The `u8` type.
+    U8,
+    /// The `u16` type.
+    U16,
+    /// The `u32` type.
+    U32,
+    /// The `usize` type.
+    Usize,
+}
+
+impl IndexWidth {
+    /// Returns the narrowest width able to represent the provided value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::impls::IndexWidth;
+    ///
+    /// assert_eq!(IndexWidth::fitting(255), IndexWidth::U8);
+    /// assert_eq!(IndexWidth::fitting(256), IndexWidth::U16);
+    /// assert_eq!(IndexWidth::fitting(70_000), IndexWidth::U32);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn fitting(value: usize) -> Self {
+        if u8::try_from(value).is_ok() {
+            Self::U8
+        } else if u16::try_from(value).is_ok() {
+            Self::U16
+        } else if u32::try_from(value).is_ok() {
+            Self::U32
+        } else {
+            Self::Usize
+        }
+    }
+
+    /// Returns the number of bytes of an index of this width.
+    #[inline]
+    #[must_use]
+    pub const fn bytes(self) -> usize {
+        match self {
+            Self::U8 => 1,
+            Self::U16 => 2,
+            Self::U32 => 4,
+            Self::Usize => core::mem::size_of::<usize>(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+/// Error raised when a matrix does not fit the requested index types.
+pub enum IndexWidthError {
+    /// The number of defined values does not fit the sparse index type.
+    #[error("The {number_of_values} defined values do not fit the sparse index type")]
+    SparseIndex {
+        /// The number of defined values of the matrix.
+        number_of_values: usize,
+    },
+    /// The number of rows does not fit the row index type.
+    #[error("The {number_of_rows} rows do not fit the row index type")]
+    RowIndex {
+        /// The number of rows of the matrix.
+        number_of_rows: usize,
+    },
+    /// The number of columns does not fit the column index type.
+    #[error("The {number_of_columns} columns do not fit the column index type")]
+    ColumnIndex {
+        /// The number of columns of the matrix.
+        number_of_columns: usize,
+    },
+}
+
+/// A matrix re-encoded with the narrowest index type fitting it, as returned
+/// by the `compact` methods of the CSR matrices.
+///
+/// Every variant holds the matrix with all of its index types set to the
+/// corresponding integer type, so that generic code can be run on the
+/// compacted matrix by matching on the variant.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Compacted<M8, M16, M32, MUsize> {
+    /// The matrix indexed by `u8`.
+    U8(M8),
+    /// The matrix indexed by `u16`.
+    U16(M16),
+    /// The matrix indexed by `u32`.
+    U32(M32),
+    /// The matrix indexed by `usize`.
+    Usize(MUsize),
+}
+
+impl<M8, M16, M32, MUsize> Compacted<M8, M16, M32, MUsize> {
+    /// Returns the index width of the compacted matrix.
+    #[inline]
+    pub const fn index_width(&self) -> IndexWidth {
+        match self {
+            Self::U8(_) => IndexWidth::U8,
+            Self::U16(_) => IndexWidth::U16,
+            Self::U32(_) => IndexWidth::U32,
+            Self::Usize(_) => IndexWidth::Usize,
+        }
+    }
+}
+
+/// A [`CSR2D`] re-encoded with the narrowest index type fitting it.
+pub type CompactCSR2D = Compacted<
+    CSR2D<u8, u8, u8>,
+    CSR2D<u16, u16, u16>,
+    CSR2D<u32, u32, u32>,
+    CSR2D<usize, usize, usize>,
+>;
+
+/// A [`ValuedCSR2D`] re-encoded with the narrowest index type fitting it.
+pub type CompactValuedCSR2D<Value> = Compacted<
+    ValuedCSR2D<u8, u8, u8, Value>,
+    ValuedCSR2D<u16, u16, u16, Value>,
+    ValuedCSR2D<u32, u32, u32, Value>,
+    ValuedCSR2D<usize, usize, usize, Value>,
+>;
+
+/// Converts an index already known to fit the destination type.
+fn convert<T: TryFromUsize>(index: usize) -> T {
+    T::try_from_usize(index)
+        .unwrap_or_else(|_| unreachable!("The bounds of the matrix were checked to fit."))
+}
+
+/// Re-encodes the matrix with the narrowest index width fitting it.
+macro_rules! compact {
+    ($matrix:expr) => {
+        match $matrix.index_width() {
+            IndexWidth::U8 => {
+                Compacted::U8($matrix.try_reindex().unwrap_or_else(|_| {
+                    unreachable!("The index width was chosen to fit the matrix.")
+                }))
+            }
+            IndexWidth::U16 => {
+                Compacted::U16($matrix.try_reindex().unwrap_or_else(|_| {
+                    unreachable!("The index width was chosen to fit the matrix.")
+                }))
+            }
+            IndexWidth::U32 => {
+                Compacted::U32($matrix.try_reindex().unwrap_or_else(|_| {
+                    unreachable!("The index width was chosen to fit the matrix.")
+                }))
+            }
+            IndexWidth::Usize => {
+                Compacted::Usize($matrix.try_reindex().unwrap_or_else(|_| {
+                    unreachable!("The index width was chosen to fit the matrix.")
+                }))
+            }
+        }
+    };
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex> CSR2D<SparseIndex, RowIndex, ColumnIndex>
+where
+    SparseIndex: AsPrimitive<usize>,
+    RowIndex: AsPrimitive<usize>,
+    ColumnIndex: AsPrimitive<usize>,
+{
+    /// Returns the narrowest index width fitting the number of defined
+    /// values, of rows and of columns of the matrix.
+    #[inline]
+    pub fn index_width(&self) -> IndexWidth {
+        IndexWidth::fitting(
+            self.column_indices
+                .len()
+                .max(self.number_of_rows.as_())
+                .max(self.number_of_columns.as_()),
+        )
+    }
+
+    /// Returns the matrix re-encoded with the provided index types.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the number of defined values, of rows or of
+    /// columns does not fit the corresponding index type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{impls::IndexWidthError, prelude::*};
+    ///
+    /// let mut matrix: CSR2D<usize, usize, usize> = SparseMatrixMut::with_sparse_shape((3, 300));
+    /// MatrixMut::add(&mut matrix, (0, 2)).unwrap();
+    /// MatrixMut::add(&mut matrix, (2, 299)).unwrap();
+    ///
+    /// let narrow: CSR2D<u8, u8, u16> = matrix.try_reindex().unwrap();
+    /// assert_eq!(narrow.sparse_row(2).collect::<Vec<_>>(), vec![299]);
+    /// assert_eq!(
+    ///     matrix.try_reindex::<u8, u8, u8>(),
+    ///     Err(IndexWidthError::ColumnIndex { number_of_columns: 300 })
+    /// );
+    /// ```
+    #[inline]
+    pub fn try_reindex<NewSparseIndex, NewRowIndex, NewColumnIndex>(
+        &self,
+    ) -> Result<CSR2D<NewSparseIndex, NewRowIndex, NewColumnIndex>, IndexWidthError>
+    where
+        NewSparseIndex: TryFromUsize,
+        NewRowIndex: TryFromUsize,
+        NewColumnIndex: TryFromUsize,
+    {
+        let number_of_values = self.column_indices.len();
+        let number_of_rows = self.number_of_rows.as_();
+        let number_of_columns = self.number_of_columns.as_();
+        if NewSparseIndex::try_from_usize(number_of_values).is_err() {
+            return Err(IndexWidthError::SparseIndex { number_of_values });
+        }
+        let Ok(new_number_of_rows) = NewRowIndex::try_from_usize(number_of_rows) else {
+            return Err(IndexWidthError::RowIndex { number_of_rows });
+        };
+        let Ok(new_number_of_columns) = NewColumnIndex::try_from_usize(number_of_columns) else {
+            return Err(IndexWidthError::ColumnIndex { number_of_columns });
+        };
+        Ok(CSR2D {
+            offsets: self.offsets.iter().map(|offset| convert(offset.as_())).collect(),
+            number_of_columns: new_number_of_columns,
+            number_of_rows: new_number_of_rows,
+            column_indices: self
+                .column_indices
+                .iter()
+                .map(|column| convert(column.as_()))
+                .collect(),
+            number_of_non_empty_rows: convert(self.number_of_non_empty_rows.as_()),
+        })
+    }
+
+    /// Returns the matrix re-encoded with the narrowest index type fitting
+    /// it, as given by [`index_width`](Self::index_width).
+    ///
+    /// This only changes the types of the indices: unlike
+    /// [`compactify`](crate::impls::compactify), the rows and columns are
+    /// not renumbered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{impls::Compacted, prelude::*};
+    ///
+    /// let mut matrix: CSR2D<usize, usize, usize> = SparseMatrixMut::with_sparse_shape((1000, 1000));
+    /// MatrixMut::add(&mut matrix, (0, 999)).unwrap();
+    ///
+    /// let Compacted::U16(compact) = matrix.compact() else { unreachable!() };
+    /// assert_eq!(compact.number_of_rows(), 1000_u16);
+    /// assert!(compact.has_entry(0, 999));
+    /// ```
+    #[inline]
+    pub fn compact(&self) -> CompactCSR2D {
+        compact!(self)
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value: Clone>
+    ValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    SparseIndex: AsPrimitive<usize>,
+    RowIndex: AsPrimitive<usize>,
+    ColumnIndex: AsPrimitive<usize>,
+{
+    /// Returns the narrowest index width fitting the number of defined
+    /// values, of rows and of columns of the matrix.
+    #[inline]
+    pub fn index_width(&self) -> IndexWidth {
+        self.csr.index_width()
+    }
+
+    /// Returns the matrix re-encoded with the provided index types, keeping
+    /// its values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the number of defined values, of rows or of
+    /// columns does not fit the corresponding index type.
+    #[inline]
+    pub fn try_reindex<NewSparseIndex, NewRowIndex, NewColumnIndex>(
+        &self,
+    ) -> Result<ValuedCSR2D<NewSparseIndex, NewRowIndex, NewColumnIndex, Value>, IndexWidthError>
+    where
+        NewSparseIndex: TryFromUsize,
+        NewRowIndex: TryFromUsize,
+        NewColumnIndex: TryFromUsize,
+    {
+        Ok(ValuedCSR2D { csr: self.csr.try_reindex()?, values: self.values.clone() })
+    }
+
+    /// Returns the matrix re-encoded with the narrowest index type fitting
+    /// it, keeping its values.
+    #[inline]
+    pub fn compact(&self) -> CompactValuedCSR2D<Value> {
+        compact!(self)
+    }
+}
+
+/// Implements the re-encoding of the square and symmetric wrappers of a CSR
+/// matrix type.
+macro_rules! impl_wrapper_reindexing {
+    ($($inner:ident $(<$value:ident>)?),*) => {
+        $(
+            impl<SparseIndex, Index $(, $value: Clone)?> SquareCSR2D<$inner<SparseIndex, Index, Index $(, $value)?>>
+            where
+                SparseIndex: AsPrimitive<usize>,
+                Index: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+            {
+                /// Returns the narrowest index width fitting the number of
+                /// defined values and the order of the matrix.
+                #[inline]
+                pub fn index_width(&self) -> IndexWidth {
+                    self.matrix.index_width()
+                }
+
+                /// Returns the matrix re-encoded with the provided index
+                /// types.
+                ///
+                /// # Errors
+                ///
+                /// Returns an error when the number of defined values or the
+                /// order of the matrix does not fit the corresponding index
+                /// type.
+                #[inline]
+                pub fn try_reindex<NewSparseIndex, NewIndex>(
+                    &self,
+                ) -> Result<SquareCSR2D<$inner<NewSparseIndex, NewIndex, NewIndex $(, $value)?>>, IndexWidthError>
+                where
+                    NewSparseIndex: TryFromUsize,
+                    NewIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+                {
+                    Ok(SquareCSR2D {
+                        matrix: self.matrix.try_reindex()?,
+                        number_of_diagonal_values: convert(self.number_of_diagonal_values.as_()),
+                    })
+                }
+
+                /// Returns the matrix re-encoded with the narrowest index
+                /// type fitting it.
+                #[inline]
+                pub fn compact(
+                    &self,
+                ) -> Compacted<
+                    SquareCSR2D<$inner<u8, u8, u8 $(, $value)?>>,
+                    SquareCSR2D<$inner<u16, u16, u16 $(, $value)?>>,
+                    SquareCSR2D<$inner<u32, u32, u32 $(, $value)?>>,
+                    SquareCSR2D<$inner<usize, usize, usize $(, $value)?>>,
+                > {
+                    compact!(self)
+                }
+            }
+
+            impl<SparseIndex, Index $(, $value: Clone)?> SymmetricCSR2D<$inner<SparseIndex, Index, Index $(, $value)?>>
+            where
+                SparseIndex: AsPrimitive<usize>,
+                Index: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+            {
+                /// Returns the narrowest index width fitting the number of
+                /// defined values and the order of the matrix.
+                #[inline]
+                pub fn index_width(&self) -> IndexWidth {
+                    self.matrix.index_width()
+                }
+
+                /// Returns the matrix re-encoded with the provided index
+                /// types.
+                ///
+                /// # Errors
+                ///
+                /// Returns an error when the number of defined values or the
+                /// order of the matrix does not fit the corresponding index
+                /// type.
+                #[inline]
+                pub fn try_reindex<NewSparseIndex, NewIndex>(
+                    &self,
+                ) -> Result<SymmetricCSR2D<$inner<NewSparseIndex, NewIndex, NewIndex $(, $value)?>>, IndexWidthError>
+                where
+                    NewSparseIndex: TryFromUsize,
+                    NewIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+                {
+                    Ok(SymmetricCSR2D { matrix: self.matrix.try_reindex()? })
+                }
+
+                /// Returns the matrix re-encoded with the narrowest index
+                /// type fitting it.
+                #[inline]
+                pub fn compact(
+                    &self,
+                ) -> Compacted<
+                    SymmetricCSR2D<$inner<u8, u8, u8 $(, $value)?>>,
+                    SymmetricCSR2D<$inner<u16, u16, u16 $(, $value)?>>,
+                    SymmetricCSR2D<$inner<u32, u32, u32 $(, $value)?>>,
+                    SymmetricCSR2D<$inner<usize, usize, usize $(, $value)?>>,
+                > {
+                    compact!(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_wrapper_reindexing!(CSR2D, ValuedCSR2D<Value>);
@@ -0,0 +1,63 @@
+//! Implementation of the `serde` traits for the `CSR2D` struct.
+//!
+//! Only the row offsets, the column indices and the shape are serialized.
+//! Deserialization validates the CSR invariants before building the matrix,
+//! so corrupted input is rejected instead of producing an illegal state.
+
+use alloc::vec::Vec;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+
+use crate::{impls::CSR2D, traits::PositiveInteger};
+
+#[derive(Serialize)]
+#[serde(rename = "CSR2D")]
+struct CSR2DRef<'a, SparseIndex, RowIndex, ColumnIndex> {
+    number_of_rows: &'a RowIndex,
+    number_of_columns: &'a ColumnIndex,
+    offsets: &'a [SparseIndex],
+    column_indices: &'a [ColumnIndex],
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "CSR2D")]
+struct RawCSR2D<SparseIndex, RowIndex, ColumnIndex> {
+    number_of_rows: RowIndex,
+    number_of_columns: ColumnIndex,
+    offsets: Vec<SparseIndex>,
+    column_indices: Vec<ColumnIndex>,
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex> Serialize for CSR2D<SparseIndex, RowIndex, ColumnIndex>
+where
+    SparseIndex: Serialize,
+    RowIndex: Serialize,
+    ColumnIndex: Serialize,
+{
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        CSR2DRef {
+            number_of_rows: &self.number_of_rows,
+            number_of_columns: &self.number_of_columns,
+            offsets: &self.offsets,
+            column_indices: &self.column_indices,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, SparseIndex, RowIndex, ColumnIndex> Deserialize<'de>
+    for CSR2D<SparseIndex, RowIndex, ColumnIndex>
+where
+    SparseIndex: PositiveInteger + Deserialize<'de>,
+    RowIndex: PositiveInteger + Deserialize<'de>,
+    ColumnIndex: PositiveInteger + Deserialize<'de>,
+{
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let RawCSR2D { number_of_rows, number_of_columns, offsets, column_indices } =
+            RawCSR2D::<SparseIndex, RowIndex, ColumnIndex>::deserialize(deserializer)?;
+        Self::try_from_raw_parts(number_of_rows, number_of_columns, offsets, column_indices)
+            .map_err(D::Error::custom)
+    }
+}
@@ -2,16 +2,23 @@
 #[cfg(feature = "mem_dbg")]
 use alloc::string::String;
 use alloc::vec::Vec;
-use core::fmt::Debug;
+use core::fmt::{Debug, Display};
 
 use num_traits::{AsPrimitive, One, Zero};
 
-use crate::traits::PositiveInteger;
+use crate::traits::{PositiveInteger, TryFromUsize};
 
 #[cfg(feature = "arbitrary")]
 mod arbitrary_impl;
+mod binary_impl;
+#[cfg(feature = "serde")]
+mod serde_impl;
 
-use crate::{impls::MutabilityError, prelude::*};
+use crate::{
+    errors::{InvariantError, RankSelectError},
+    impls::MutabilityError,
+    prelude::*,
+};
 
 #[cfg_attr(feature = "mem_size", derive(mem_dbg::MemSize))]
 #[cfg_attr(feature = "mem_size", mem_size(rec))]
@@ -35,6 +42,13 @@ impl<M: Matrix2D + Debug> Debug for SquareCSR2D<M> {
     }
 }
 
+impl<M: Matrix2D + Display> Display for SquareCSR2D<M> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.matrix.fmt(f)
+    }
+}
+
 impl<M: Matrix2D> Matrix for SquareCSR2D<M>
 where
     M: Matrix2D,
@@ -109,6 +123,41 @@ impl<M: Matrix2D> SquareCSR2D<M> {
     }
 }
 
+impl<M> SquareCSR2D<M>
+where
+    M: SparseMatrix2D<ColumnIndex = <M as Matrix2D>::RowIndex>,
+{
+    /// Creates a new `SquareCSR2D` from an inner matrix, checking that it is
+    /// square and recomputing the number of diagonal values.
+    ///
+    /// # Errors
+    ///
+    /// Returns the violated invariant.
+    pub(crate) fn try_from_matrix(matrix: M) -> Result<Self, InvariantError> {
+        let number_of_diagonal_values = Self::count_diagonal_values(&matrix)?;
+        Ok(Self { matrix, number_of_diagonal_values })
+    }
+
+    /// Returns the number of diagonal values of an inner matrix, checking
+    /// that it is square.
+    fn count_diagonal_values(matrix: &M) -> Result<M::RowIndex, InvariantError> {
+        if matrix.number_of_rows() != matrix.number_of_columns() {
+            return Err(InvariantError::NotSquare {
+                number_of_rows: matrix.number_of_rows().as_(),
+                number_of_columns: matrix.number_of_columns().as_(),
+            });
+        }
+        let number_of_diagonal_values = matrix
+            .sparse_rows()
+            .zip(matrix.sparse_columns())
+            .filter(|(row, column)| row == column)
+            .count();
+        // The diagonal values are at most as many as the rows.
+        Ok(M::RowIndex::try_from_usize(number_of_diagonal_values)
+            .unwrap_or_else(|_| unreachable!("The number of diagonal values fits the row index.")))
+    }
+}
+
 impl<M: Matrix2D> AsRef<M> for SquareCSR2D<M> {
     #[inline]
     fn as_ref(&self) -> &M {
@@ -231,6 +280,12 @@ where
         self.matrix.sparse_row(row)
     }
 
+    #[inline]
+    unsafe fn sparse_row_unchecked(&self, row: Self::RowIndex) -> Self::SparseRow<'_> {
+        // SAFETY: the caller upholds the contract of the underlying matrix.
+        unsafe { self.matrix.sparse_row_unchecked(row) }
+    }
+
     #[inline]
     fn has_entry(&self, row: Self::RowIndex, column: Self::ColumnIndex) -> bool {
         self.matrix.has_entry(row, column)
@@ -313,6 +368,11 @@ where
         self.matrix.rank_row(row)
     }
 
+    #[inline]
+    fn rank_row_unchecked(&self, row: M::RowIndex) -> Self::SparseIndex {
+        self.matrix.rank_row_unchecked(row)
+    }
+
     #[inline]
     fn select_row(&self, sparse_index: Self::SparseIndex) -> Self::RowIndex {
         self.matrix.select_row(sparse_index)
@@ -324,7 +384,17 @@ where
     }
 
     #[inline]
-    fn try_rank(&self, row: Self::RowIndex, column: Self::ColumnIndex) -> Option<Self::SparseIndex>
+    unsafe fn select_column_unchecked(&self, sparse_index: Self::SparseIndex) -> Self::ColumnIndex {
+        // SAFETY: the caller upholds the contract of the underlying matrix.
+        unsafe { self.matrix.select_column_unchecked(sparse_index) }
+    }
+
+    #[inline]
+    fn try_rank(
+        &self,
+        row: Self::RowIndex,
+        column: Self::ColumnIndex,
+    ) -> Result<Self::SparseIndex, RankSelectError>
     where
         Self::ColumnIndex: PartialEq,
     {
@@ -392,6 +462,14 @@ where
     where
         Self: 'a;
 
+    #[inline]
+    fn max_sparse_value(&self) -> Option<Self::Value>
+    where
+        Self::Value: TotalOrd,
+    {
+        self.matrix.max_sparse_value()
+    }
+
     #[inline]
     fn sparse_values(&self) -> Self::SparseValues<'_> {
         self.matrix.sparse_values()
@@ -411,6 +489,12 @@ where
     fn sparse_row_values(&self, row: Self::RowIndex) -> Self::SparseRowValues<'_> {
         self.matrix.sparse_row_values(row)
     }
+
+    #[inline]
+    unsafe fn sparse_row_values_unchecked(&self, row: Self::RowIndex) -> Self::SparseRowValues<'_> {
+        // SAFETY: the caller upholds the contract of the underlying matrix.
+        unsafe { self.matrix.sparse_row_values_unchecked(row) }
+    }
 }
 
 impl<M> SizedSparseValuedMatrix for SquareCSR2D<M>
@@ -546,6 +630,65 @@ where
     }
 }
 
+#[cfg(feature = "parallel")]
+impl<SparseIndex, Idx> SquareCSR2D<CSR2D<SparseIndex, Idx, Idx>>
+where
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize + Send + Sync,
+    Idx: multi_ranged::Step
+        + PositiveInteger
+        + AsPrimitive<usize>
+        + TryFromUsize
+        + TryFrom<SparseIndex>
+        + Send
+        + Sync,
+    CSR2D<SparseIndex, Idx, Idx>: Matrix2D<RowIndex = Idx, ColumnIndex = Idx>,
+{
+    /// Returns the transposed matrix, distributing the fill of the column
+    /// indices of large matrices over the `rayon` thread pool.
+    ///
+    /// The result is identical to the one of
+    /// [`TransposableMatrix2D::transpose`] whatever the number of threads.
+    #[inline]
+    #[must_use]
+    pub fn par_transpose(&self) -> Self {
+        Self {
+            matrix: self.matrix.par_transpose(),
+            number_of_diagonal_values: self.number_of_diagonal_values,
+        }
+    }
+}
+
+impl<M: Matrix2D + MemoryFootprint> MemoryFootprint for SquareCSR2D<M> {
+    #[inline]
+    fn memory_usage(&self) -> MemoryUsage {
+        self.matrix.memory_usage()
+    }
+
+    #[inline]
+    fn shrink_to_fit(&mut self) {
+        self.matrix.shrink_to_fit();
+    }
+}
+
+impl<M> Validate for SquareCSR2D<M>
+where
+    M: SparseMatrix2D<ColumnIndex = <M as Matrix2D>::RowIndex> + Validate,
+{
+    #[inline]
+    fn validate(&self) -> Result<(), InvariantError> {
+        self.matrix.validate()?;
+        let number_of_diagonal_values = Self::count_diagonal_values(&self.matrix)?;
+        if number_of_diagonal_values != self.number_of_diagonal_values {
+            return Err(InvariantError::CachedCountMismatch {
+                counted: "diagonal values",
+                cached: self.number_of_diagonal_values.as_(),
+                actual: number_of_diagonal_values.as_(),
+            });
+        }
+        Ok(())
+    }
+}
+
 #[cfg(all(test, feature = "alloc"))]
 mod tests {
     use alloc::vec::Vec;
@@ -0,0 +1,32 @@
+//! Implementation of the binary encoding for the `SquareCSR2D` struct.
+//!
+//! The number of diagonal values is not stored: it is recomputed from the
+//! decoded inner matrix, which must be square.
+
+use alloc::vec::Vec;
+
+use super::SquareCSR2D;
+use crate::{
+    io::{BinaryDecodeError, BinaryEncoding, BinaryReader},
+    traits::{Matrix2D, SparseMatrix2D},
+};
+
+/// Layout tag of the `SquareCSR2D` body.
+const SQUARE_CSR2D_LAYOUT: u8 = 0x03;
+
+impl<M> BinaryEncoding for SquareCSR2D<M>
+where
+    M: SparseMatrix2D<ColumnIndex = <M as Matrix2D>::RowIndex> + BinaryEncoding,
+{
+    #[inline]
+    fn write_body(&self, buffer: &mut Vec<u8>) {
+        buffer.push(SQUARE_CSR2D_LAYOUT);
+        self.matrix.write_body(buffer);
+    }
+
+    #[inline]
+    fn read_body(reader: &mut BinaryReader<'_>) -> Result<Self, BinaryDecodeError> {
+        reader.expect_layout(SQUARE_CSR2D_LAYOUT)?;
+        Self::try_from_matrix(M::read_body(reader)?).map_err(BinaryDecodeError::InvalidMatrix)
+    }
+}
@@ -0,0 +1,39 @@
+//! Implementation of the `serde` traits for the `SquareCSR2D` struct.
+//!
+//! The number of diagonal values is not serialized: it is recomputed from the
+//! deserialized inner matrix, which must be square.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+
+use super::SquareCSR2D;
+use crate::traits::{Matrix2D, SparseMatrix2D};
+
+#[derive(Serialize)]
+#[serde(rename = "SquareCSR2D")]
+struct SquareCSR2DRef<'a, M> {
+    matrix: &'a M,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "SquareCSR2D")]
+struct RawSquareCSR2D<M> {
+    matrix: M,
+}
+
+impl<M: Matrix2D + Serialize> Serialize for SquareCSR2D<M> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SquareCSR2DRef { matrix: &self.matrix }.serialize(serializer)
+    }
+}
+
+impl<'de, M> Deserialize<'de> for SquareCSR2D<M>
+where
+    M: SparseMatrix2D<ColumnIndex = <M as Matrix2D>::RowIndex> + Deserialize<'de>,
+{
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let RawSquareCSR2D { matrix } = RawSquareCSR2D::<M>::deserialize(deserializer)?;
+        Self::try_from_matrix(matrix).map_err(D::Error::custom)
+    }
+}
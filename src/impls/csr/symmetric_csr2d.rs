@@ -2,12 +2,13 @@
 #[cfg(feature = "mem_dbg")]
 use alloc::string::String;
 use alloc::vec::Vec;
-use core::fmt::Debug;
+use core::fmt::{Debug, Display};
 
 use multi_ranged::Step;
 use num_traits::AsPrimitive;
 
 use crate::{
+    errors::{InvariantError, RankSelectError},
     impls::{CSR2D, SquareCSR2D, ValuedCSR2D},
     prelude::*,
     traits::{PositiveInteger, TryFromUsize},
@@ -15,6 +16,9 @@ use crate::{
 
 #[cfg(feature = "arbitrary")]
 mod arbitrary_impl;
+mod binary_impl;
+#[cfg(feature = "serde")]
+mod serde_impl;
 
 #[cfg_attr(feature = "mem_size", derive(mem_dbg::MemSize))]
 #[cfg_attr(feature = "mem_size", mem_size(rec))]
@@ -33,6 +37,13 @@ impl<M: Matrix2D + Debug> Debug for SymmetricCSR2D<M> {
     }
 }
 
+impl<M: Matrix2D + Display> Display for SymmetricCSR2D<M> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.matrix.fmt(f)
+    }
+}
+
 impl<M> Matrix for SymmetricCSR2D<M>
 where
     M: Matrix2D,
@@ -185,6 +196,12 @@ where
         self.matrix.sparse_row(row)
     }
 
+    #[inline]
+    unsafe fn sparse_row_unchecked(&self, row: Self::RowIndex) -> Self::SparseRow<'_> {
+        // SAFETY: the caller upholds the contract of the underlying matrix.
+        unsafe { self.matrix.sparse_row_unchecked(row) }
+    }
+
     #[inline]
     fn has_entry(&self, row: Self::RowIndex, column: Self::ColumnIndex) -> bool {
         self.matrix.has_entry(row, column)
@@ -263,18 +280,33 @@ where
         self.matrix.rank_row(row)
     }
 
+    #[inline]
+    fn rank_row_unchecked(&self, row: M::RowIndex) -> Self::SparseIndex {
+        self.matrix.rank_row_unchecked(row)
+    }
+
     #[inline]
     fn select_column(&self, sparse_index: Self::SparseIndex) -> Self::ColumnIndex {
         self.matrix.select_column(sparse_index)
     }
 
+    #[inline]
+    unsafe fn select_column_unchecked(&self, sparse_index: Self::SparseIndex) -> Self::ColumnIndex {
+        // SAFETY: the caller upholds the contract of the underlying matrix.
+        unsafe { self.matrix.select_column_unchecked(sparse_index) }
+    }
+
     #[inline]
     fn select_row(&self, sparse_index: Self::SparseIndex) -> Self::RowIndex {
         self.matrix.select_row(sparse_index)
     }
 
     #[inline]
-    fn try_rank(&self, row: Self::RowIndex, column: Self::ColumnIndex) -> Option<Self::SparseIndex>
+    fn try_rank(
+        &self,
+        row: Self::RowIndex,
+        column: Self::ColumnIndex,
+    ) -> Result<Self::SparseIndex, RankSelectError>
     where
         Self::ColumnIndex: PartialEq,
     {
@@ -294,6 +326,37 @@ impl<M: Matrix2D> SymmetricCSR2D<M> {
     }
 }
 
+impl<M> SymmetricCSR2D<M>
+where
+    M: SparseMatrix2D<ColumnIndex = <M as Matrix2D>::RowIndex>,
+    SquareCSR2D<M>: SparseMatrix2D<RowIndex = M::RowIndex, ColumnIndex = M::RowIndex>,
+{
+    /// Creates a new `SymmetricCSR2D` from a `SquareCSR2D`, checking that
+    /// every stored entry has its transposed counterpart.
+    ///
+    /// # Errors
+    ///
+    /// Returns the violated invariant.
+    pub(crate) fn try_from_square(matrix: SquareCSR2D<M>) -> Result<Self, InvariantError> {
+        Self::check_symmetry(&matrix)?;
+        Ok(Self { matrix })
+    }
+
+    /// Checks that every stored entry has its transposed counterpart.
+    fn check_symmetry(matrix: &SquareCSR2D<M>) -> Result<(), InvariantError> {
+        match matrix
+            .sparse_rows()
+            .zip(matrix.sparse_columns())
+            .find(|&(row, column)| !matrix.has_entry(column, row))
+        {
+            Some((row, column)) => {
+                Err(InvariantError::AsymmetricEntry { row: row.as_(), column: column.as_() })
+            }
+            None => Ok(()),
+        }
+    }
+}
+
 impl<SparseIndex, Idx, Value> SymmetricCSR2D<ValuedCSR2D<SparseIndex, Idx, Idx, Value>>
 where
     Idx: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize + TryFrom<SparseIndex>,
@@ -435,6 +498,14 @@ where
     where
         Self: 'a;
 
+    #[inline]
+    fn max_sparse_value(&self) -> Option<Self::Value>
+    where
+        Self::Value: TotalOrd,
+    {
+        self.matrix.max_sparse_value()
+    }
+
     #[inline]
     fn sparse_values(&self) -> Self::SparseValues<'_> {
         self.matrix.sparse_values()
@@ -454,6 +525,12 @@ where
     fn sparse_row_values(&self, row: Self::RowIndex) -> Self::SparseRowValues<'_> {
         self.matrix.sparse_row_values(row)
     }
+
+    #[inline]
+    unsafe fn sparse_row_values_unchecked(&self, row: Self::RowIndex) -> Self::SparseRowValues<'_> {
+        // SAFETY: the caller upholds the contract of the underlying matrix.
+        unsafe { self.matrix.sparse_row_values_unchecked(row) }
+    }
 }
 
 impl<M> SizedSparseValuedMatrix for SymmetricCSR2D<M>
@@ -613,3 +690,27 @@ where
         &self.matrix
     }
 }
+
+impl<M: Matrix2D + MemoryFootprint> MemoryFootprint for SymmetricCSR2D<M> {
+    #[inline]
+    fn memory_usage(&self) -> MemoryUsage {
+        self.matrix.memory_usage()
+    }
+
+    #[inline]
+    fn shrink_to_fit(&mut self) {
+        self.matrix.shrink_to_fit();
+    }
+}
+
+impl<M> Validate for SymmetricCSR2D<M>
+where
+    M: SparseMatrix2D<ColumnIndex = <M as Matrix2D>::RowIndex>,
+    SquareCSR2D<M>: SparseMatrix2D<RowIndex = M::RowIndex, ColumnIndex = M::RowIndex> + Validate,
+{
+    #[inline]
+    fn validate(&self) -> Result<(), InvariantError> {
+        self.matrix.validate()?;
+        Self::check_symmetry(&self.matrix)
+    }
+}
@@ -0,0 +1,36 @@
+//! Implementation of the binary encoding for the `SymmetricCSR2D` struct.
+//!
+//! The body stores the inner matrix without the `SquareCSR2D` wrapper, and
+//! decoding checks that the matrix is both square and symmetric.
+
+use alloc::vec::Vec;
+
+use super::SymmetricCSR2D;
+use crate::{
+    impls::SquareCSR2D,
+    io::{BinaryDecodeError, BinaryEncoding, BinaryReader},
+    traits::{Matrix2D, SparseMatrix2D},
+};
+
+/// Layout tag of the `SymmetricCSR2D` body.
+const SYMMETRIC_CSR2D_LAYOUT: u8 = 0x04;
+
+impl<M> BinaryEncoding for SymmetricCSR2D<M>
+where
+    M: SparseMatrix2D<ColumnIndex = <M as Matrix2D>::RowIndex> + BinaryEncoding,
+    SquareCSR2D<M>: SparseMatrix2D<RowIndex = M::RowIndex, ColumnIndex = M::RowIndex>,
+{
+    #[inline]
+    fn write_body(&self, buffer: &mut Vec<u8>) {
+        buffer.push(SYMMETRIC_CSR2D_LAYOUT);
+        self.matrix.matrix.write_body(buffer);
+    }
+
+    #[inline]
+    fn read_body(reader: &mut BinaryReader<'_>) -> Result<Self, BinaryDecodeError> {
+        reader.expect_layout(SYMMETRIC_CSR2D_LAYOUT)?;
+        let matrix = SquareCSR2D::try_from_matrix(M::read_body(reader)?)
+            .map_err(BinaryDecodeError::InvalidMatrix)?;
+        Self::try_from_square(matrix).map_err(BinaryDecodeError::InvalidMatrix)
+    }
+}
@@ -7,11 +7,16 @@ use multi_ranged::Step;
 use num_traits::{AsPrimitive, Zero};
 
 use crate::{
+    errors::InvariantError,
     impls::{CSR2D, MutabilityError, SquareCSR2D, SymmetricCSR2D, ValuedCSR2D},
     prelude::*,
     traits::{PositiveInteger, TryFromUsize},
 };
 
+mod binary_impl;
+#[cfg(feature = "serde")]
+mod serde_impl;
+
 #[cfg_attr(feature = "mem_size", derive(mem_dbg::MemSize))]
 #[cfg_attr(feature = "mem_size", mem_size(rec))]
 #[cfg_attr(feature = "mem_dbg", derive(mem_dbg::MemDbg))]
@@ -22,6 +27,13 @@ pub struct UpperTriangularCSR2D<M: Matrix2D> {
     matrix: SquareCSR2D<M>,
 }
 
+impl<M: Matrix2D + core::fmt::Display> core::fmt::Display for UpperTriangularCSR2D<M> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.matrix.fmt(f)
+    }
+}
+
 impl<M> Matrix for UpperTriangularCSR2D<M>
 where
     M: Matrix2D,
@@ -107,6 +119,33 @@ impl<M: Matrix2D> UpperTriangularCSR2D<M> {
     }
 }
 
+impl<M> UpperTriangularCSR2D<M>
+where
+    M: SparseMatrix2D<ColumnIndex = <M as Matrix2D>::RowIndex>,
+    SquareCSR2D<M>: SparseMatrix2D<RowIndex = M::RowIndex, ColumnIndex = M::RowIndex>,
+{
+    /// Creates a new `UpperTriangularCSR2D` from a `SquareCSR2D`, checking
+    /// that no entry is stored below the diagonal.
+    ///
+    /// # Errors
+    ///
+    /// Returns the violated invariant.
+    pub(crate) fn try_from_square(matrix: SquareCSR2D<M>) -> Result<Self, InvariantError> {
+        Self::check_upper_triangular(&matrix)?;
+        Ok(Self { matrix })
+    }
+
+    /// Checks that no entry is stored below the diagonal.
+    fn check_upper_triangular(matrix: &SquareCSR2D<M>) -> Result<(), InvariantError> {
+        match matrix.sparse_rows().zip(matrix.sparse_columns()).find(|(row, column)| row > column) {
+            Some((row, column)) => {
+                Err(InvariantError::EntryBelowDiagonal { row: row.as_(), column: column.as_() })
+            }
+            None => Ok(()),
+        }
+    }
+}
+
 impl<M> SparseMatrixMut for UpperTriangularCSR2D<M>
 where
     M: SparseMatrixMut<
@@ -209,6 +248,12 @@ where
         self.matrix.sparse_row(row)
     }
 
+    #[inline]
+    unsafe fn sparse_row_unchecked(&self, row: Self::RowIndex) -> Self::SparseRow<'_> {
+        // SAFETY: the caller upholds the contract of the underlying matrix.
+        unsafe { self.matrix.sparse_row_unchecked(row) }
+    }
+
     #[inline]
     fn has_entry(&self, row: Self::RowIndex, column: Self::ColumnIndex) -> bool {
         self.matrix.has_entry(row, column)
@@ -288,11 +333,22 @@ where
         self.matrix.rank_row(row)
     }
 
+    #[inline]
+    fn rank_row_unchecked(&self, row: M::RowIndex) -> Self::SparseIndex {
+        self.matrix.rank_row_unchecked(row)
+    }
+
     #[inline]
     fn select_column(&self, sparse_index: Self::SparseIndex) -> Self::ColumnIndex {
         self.matrix.select_column(sparse_index)
     }
 
+    #[inline]
+    unsafe fn select_column_unchecked(&self, sparse_index: Self::SparseIndex) -> Self::ColumnIndex {
+        // SAFETY: the caller upholds the contract of the underlying matrix.
+        unsafe { self.matrix.select_column_unchecked(sparse_index) }
+    }
+
     #[inline]
     fn select_row(&self, sparse_index: Self::SparseIndex) -> Self::RowIndex {
         self.matrix.select_row(sparse_index)
@@ -449,6 +505,30 @@ where
     }
 }
 
+impl<M: Matrix2D + MemoryFootprint> MemoryFootprint for UpperTriangularCSR2D<M> {
+    #[inline]
+    fn memory_usage(&self) -> MemoryUsage {
+        self.matrix.memory_usage()
+    }
+
+    #[inline]
+    fn shrink_to_fit(&mut self) {
+        self.matrix.shrink_to_fit();
+    }
+}
+
+impl<M> Validate for UpperTriangularCSR2D<M>
+where
+    M: SparseMatrix2D<ColumnIndex = <M as Matrix2D>::RowIndex>,
+    SquareCSR2D<M>: SparseMatrix2D<RowIndex = M::RowIndex, ColumnIndex = M::RowIndex> + Validate,
+{
+    #[inline]
+    fn validate(&self) -> Result<(), InvariantError> {
+        self.matrix.validate()?;
+        Self::check_upper_triangular(&self.matrix)
+    }
+}
+
 #[cfg(all(test, feature = "alloc"))]
 mod tests {
     use alloc::vec::Vec;
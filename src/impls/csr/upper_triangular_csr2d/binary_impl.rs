@@ -0,0 +1,36 @@
+//! Implementation of the binary encoding for the `UpperTriangularCSR2D` struct.
+//!
+//! The body stores the inner matrix without the `SquareCSR2D` wrapper, and
+//! decoding checks that the matrix is both square and upper-triangular.
+
+use alloc::vec::Vec;
+
+use super::UpperTriangularCSR2D;
+use crate::{
+    impls::SquareCSR2D,
+    io::{BinaryDecodeError, BinaryEncoding, BinaryReader},
+    traits::{Matrix2D, SparseMatrix2D},
+};
+
+/// Layout tag of the `UpperTriangularCSR2D` body.
+const UPPER_TRIANGULAR_CSR2D_LAYOUT: u8 = 0x05;
+
+impl<M> BinaryEncoding for UpperTriangularCSR2D<M>
+where
+    M: SparseMatrix2D<ColumnIndex = <M as Matrix2D>::RowIndex> + BinaryEncoding,
+    SquareCSR2D<M>: SparseMatrix2D<RowIndex = M::RowIndex, ColumnIndex = M::RowIndex>,
+{
+    #[inline]
+    fn write_body(&self, buffer: &mut Vec<u8>) {
+        buffer.push(UPPER_TRIANGULAR_CSR2D_LAYOUT);
+        self.matrix.matrix.write_body(buffer);
+    }
+
+    #[inline]
+    fn read_body(reader: &mut BinaryReader<'_>) -> Result<Self, BinaryDecodeError> {
+        reader.expect_layout(UPPER_TRIANGULAR_CSR2D_LAYOUT)?;
+        let matrix = SquareCSR2D::try_from_matrix(M::read_body(reader)?)
+            .map_err(BinaryDecodeError::InvalidMatrix)?;
+        Self::try_from_square(matrix).map_err(BinaryDecodeError::InvalidMatrix)
+    }
+}
@@ -0,0 +1,29 @@
+//! Implementation of the `serde` traits for the `UpperTriangularCSR2D` struct.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+
+use super::UpperTriangularCSR2D;
+use crate::{
+    impls::SquareCSR2D,
+    traits::{Matrix2D, SparseMatrix2D},
+};
+
+impl<M: Matrix2D + Serialize> Serialize for UpperTriangularCSR2D<M> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.matrix.serialize(serializer)
+    }
+}
+
+impl<'de, M> Deserialize<'de> for UpperTriangularCSR2D<M>
+where
+    M: SparseMatrix2D<ColumnIndex = <M as Matrix2D>::RowIndex>,
+    SquareCSR2D<M>:
+        SparseMatrix2D<RowIndex = M::RowIndex, ColumnIndex = M::RowIndex> + Deserialize<'de>,
+{
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let matrix = SquareCSR2D::<M>::deserialize(deserializer)?;
+        Self::try_from_square(matrix).map_err(D::Error::custom)
+    }
+}
@@ -0,0 +1,190 @@
+//! A disjoint-set forest over the elements `0..n`.
+//!
+//! [`DisjointSet`] maintains a partition of its elements into sets under
+//! unions, answering which set an element belongs to in amortised
+//! near-constant time thanks to path compression and union by rank. It backs
+//! Kruskal-style algorithms and incremental connectivity, where the
+//! components of a graph are updated as edges and nodes are added.
+
+use alloc::{vec, vec::Vec};
+
+use crate::traits::PositiveInteger;
+
+/// A disjoint-set forest, or union-find, over the elements `0..n`.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::impls::DisjointSet;
+///
+/// let mut sets: DisjointSet<u32> = DisjointSet::new(5);
+/// assert!(sets.union(0, 1));
+/// assert!(sets.union(3, 4));
+/// assert!(!sets.union(1, 0));
+/// assert!(sets.same_set(0, 1));
+/// assert!(!sets.same_set(1, 3));
+/// assert_eq!(sets.number_of_sets(), 3);
+/// assert_eq!(sets.set_size(4), 2);
+/// assert_eq!(sets.representatives().count(), 3);
+///
+/// let new_element = sets.push();
+/// assert_eq!(new_element, 5);
+/// assert_eq!(sets.number_of_sets(), 4);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DisjointSet<I> {
+    /// The parent of each element, roots being their own parent.
+    parents: Vec<I>,
+    /// An upper bound on the height of the tree of each root.
+    ranks: Vec<u8>,
+    /// The number of elements in the set of each root.
+    sizes: Vec<usize>,
+    /// The number of sets.
+    number_of_sets: usize,
+}
+
+impl<I: PositiveInteger> Default for DisjointSet<I> {
+    #[inline]
+    fn default() -> Self {
+        Self { parents: Vec::new(), ranks: Vec::new(), sizes: Vec::new(), number_of_sets: 0 }
+    }
+}
+
+impl<I: PositiveInteger> DisjointSet<I> {
+    /// Creates the partition of the elements `0..number_of_elements` into
+    /// singletons.
+    #[inline]
+    #[must_use]
+    pub fn new(number_of_elements: I) -> Self {
+        let number_of_elements = number_of_elements.as_();
+        let mut sets = Self {
+            parents: Vec::with_capacity(number_of_elements),
+            ranks: vec![0; number_of_elements],
+            sizes: vec![1; number_of_elements],
+            number_of_sets: number_of_elements,
+        };
+        sets.parents.extend((0..number_of_elements).map(Self::element));
+        sets
+    }
+
+    /// Converts an index below the number of elements into an element.
+    fn element(index: usize) -> I {
+        I::try_from_usize(index)
+            .unwrap_or_else(|_| unreachable!("Element indices fit in the element type."))
+    }
+
+    /// Adds a new element, in a singleton set, and returns it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new element cannot be represented by the element type.
+    #[inline]
+    pub fn push(&mut self) -> I {
+        let Ok(element) = I::try_from_usize(self.parents.len()) else {
+            panic!("The element type cannot represent {} elements.", self.parents.len() + 1);
+        };
+        self.parents.push(element);
+        self.ranks.push(0);
+        self.sizes.push(1);
+        self.number_of_sets += 1;
+        element
+    }
+
+    /// Returns the number of elements.
+    #[inline]
+    #[must_use]
+    pub fn number_of_elements(&self) -> usize {
+        self.parents.len()
+    }
+
+    /// Returns whether there are no elements.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Returns the number of sets.
+    #[inline]
+    #[must_use]
+    pub fn number_of_sets(&self) -> usize {
+        self.number_of_sets
+    }
+
+    /// Returns the representative of the set of the element, compressing the
+    /// path to it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the element is not in the forest.
+    #[inline]
+    pub fn find(&mut self, element: I) -> I {
+        let mut root = element;
+        while self.parents[root.as_()] != root {
+            root = self.parents[root.as_()];
+        }
+        let mut current = element;
+        while current != root {
+            let parent = self.parents[current.as_()];
+            self.parents[current.as_()] = root;
+            current = parent;
+        }
+        root
+    }
+
+    /// Merges the sets of the two elements, the one of lower rank under the
+    /// other, and returns whether they were distinct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either element is not in the forest.
+    #[inline]
+    pub fn union(&mut self, left: I, right: I) -> bool {
+        let (mut left, mut right) = (self.find(left), self.find(right));
+        if left == right {
+            return false;
+        }
+        if self.ranks[left.as_()] < self.ranks[right.as_()] {
+            core::mem::swap(&mut left, &mut right);
+        }
+        if self.ranks[left.as_()] == self.ranks[right.as_()] {
+            self.ranks[left.as_()] += 1;
+        }
+        self.parents[right.as_()] = left;
+        self.sizes[left.as_()] += self.sizes[right.as_()];
+        self.number_of_sets -= 1;
+        true
+    }
+
+    /// Returns whether the two elements are in the same set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either element is not in the forest.
+    #[inline]
+    pub fn same_set(&mut self, left: I, right: I) -> bool {
+        self.find(left) == self.find(right)
+    }
+
+    /// Returns the number of elements in the set of the element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the element is not in the forest.
+    #[inline]
+    pub fn set_size(&mut self, element: I) -> usize {
+        let root = self.find(element);
+        self.sizes[root.as_()]
+    }
+
+    /// Returns an iterator over the representatives of the sets, one per set,
+    /// in increasing order.
+    #[inline]
+    pub fn representatives(&self) -> impl Iterator<Item = I> + '_ {
+        self.parents
+            .iter()
+            .enumerate()
+            .filter(|&(index, parent)| parent.as_() == index)
+            .map(|(_, &root)| root)
+    }
+}
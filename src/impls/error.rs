@@ -4,6 +4,8 @@ use core::fmt::Debug;
 
 #[cfg(feature = "alloc")]
 use super::{CSR2D, SquareCSR2D, SymmetricCSR2D, UpperTriangularCSR2D, ValuedCSR2D};
+#[cfg(feature = "heapless")]
+use super::{HeaplessCSR2D, HeaplessValuedCSR2D};
 use crate::traits::Matrix2D;
 
 #[derive(Clone, PartialEq, Eq, thiserror::Error)]
@@ -48,6 +50,9 @@ pub enum MutabilityError<M: Matrix2D + ?Sized> {
     /// When a requested shape to apply is smaller than the current shape.
     #[error("Requested shape is smaller than the current shape")]
     IncompatibleShape,
+    /// When a buffer of fixed capacity is full and cannot hold the entry.
+    #[error("Capacity of the {0} buffer has been exhausted")]
+    CapacityExceeded(&'static str),
 }
 
 impl<M: Matrix2D> Debug for MutabilityError<M> {
@@ -78,6 +83,7 @@ where
             MutabilityError::MaxedOutColumnIndex => MutabilityError::MaxedOutColumnIndex,
             MutabilityError::MaxedOutSparseIndex => MutabilityError::MaxedOutSparseIndex,
             MutabilityError::IncompatibleShape => MutabilityError::IncompatibleShape,
+            MutabilityError::CapacityExceeded(buffer) => MutabilityError::CapacityExceeded(buffer),
         }
     }
 }
@@ -103,6 +109,7 @@ where
             MutabilityError::MaxedOutColumnIndex => MutabilityError::MaxedOutColumnIndex,
             MutabilityError::MaxedOutSparseIndex => MutabilityError::MaxedOutSparseIndex,
             MutabilityError::IncompatibleShape => MutabilityError::IncompatibleShape,
+            MutabilityError::CapacityExceeded(buffer) => MutabilityError::CapacityExceeded(buffer),
         }
     }
 }
@@ -128,6 +135,7 @@ where
             MutabilityError::MaxedOutColumnIndex => MutabilityError::MaxedOutColumnIndex,
             MutabilityError::MaxedOutSparseIndex => MutabilityError::MaxedOutSparseIndex,
             MutabilityError::IncompatibleShape => MutabilityError::IncompatibleShape,
+            MutabilityError::CapacityExceeded(buffer) => MutabilityError::CapacityExceeded(buffer),
         }
     }
 }
@@ -158,6 +166,40 @@ where
             MutabilityError::MaxedOutColumnIndex => MutabilityError::MaxedOutColumnIndex,
             MutabilityError::MaxedOutSparseIndex => MutabilityError::MaxedOutSparseIndex,
             MutabilityError::IncompatibleShape => MutabilityError::IncompatibleShape,
+            MutabilityError::CapacityExceeded(buffer) => MutabilityError::CapacityExceeded(buffer),
+        }
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<SparseIndex, RowIndex, ColumnIndex, Value, const ROWS: usize, const EDGES: usize>
+    From<MutabilityError<HeaplessCSR2D<SparseIndex, RowIndex, ColumnIndex, ROWS, EDGES>>>
+    for MutabilityError<HeaplessValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value, ROWS, EDGES>>
+where
+    HeaplessCSR2D<SparseIndex, RowIndex, ColumnIndex, ROWS, EDGES>:
+        Matrix2D<RowIndex = RowIndex, ColumnIndex = ColumnIndex>,
+    HeaplessValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value, ROWS, EDGES>:
+        Matrix2D<RowIndex = RowIndex, ColumnIndex = ColumnIndex>,
+{
+    #[inline]
+    fn from(
+        error: MutabilityError<HeaplessCSR2D<SparseIndex, RowIndex, ColumnIndex, ROWS, EDGES>>,
+    ) -> Self {
+        match error {
+            MutabilityError::UnorderedCoordinate(coordinates) => {
+                MutabilityError::UnorderedCoordinate(coordinates)
+            }
+            MutabilityError::DuplicatedEntry(coordinates) => {
+                MutabilityError::DuplicatedEntry(coordinates)
+            }
+            MutabilityError::OutOfBounds(coordinates, boundaries, context) => {
+                MutabilityError::OutOfBounds(coordinates, boundaries, context)
+            }
+            MutabilityError::MaxedOutRowIndex => MutabilityError::MaxedOutRowIndex,
+            MutabilityError::MaxedOutColumnIndex => MutabilityError::MaxedOutColumnIndex,
+            MutabilityError::MaxedOutSparseIndex => MutabilityError::MaxedOutSparseIndex,
+            MutabilityError::IncompatibleShape => MutabilityError::IncompatibleShape,
+            MutabilityError::CapacityExceeded(buffer) => MutabilityError::CapacityExceeded(buffer),
         }
     }
 }
@@ -251,6 +293,14 @@ mod tests {
         assert!(display.contains("smaller"));
     }
 
+    #[test]
+    fn test_mutability_error_capacity_exceeded_display() {
+        let error: MutabilityError<TestMatrix> = MutabilityError::CapacityExceeded("row ends");
+        let display = error.to_string();
+        assert!(display.contains("row ends"));
+        assert!(display.contains("exhausted"));
+    }
+
     #[test]
     fn test_error_display() {
         let mutability_error: MutabilityError<TestMatrix> =
@@ -6,7 +6,10 @@ use alloc::vec::Vec;
 
 use num_traits::AsPrimitive;
 
-use crate::prelude::*;
+use crate::{errors::InvariantError, prelude::*};
+
+#[cfg(feature = "serde")]
+mod serde_impl;
 
 #[cfg_attr(feature = "mem_size", derive(mem_dbg::MemSize))]
 #[cfg_attr(feature = "mem_size", mem_size(rec))]
@@ -299,3 +302,26 @@ where
         self.matrix.number_of_defined_diagonal_values()
     }
 }
+
+impl<M: MemoryFootprint, T: MemoryFootprint> MemoryFootprint for GenericBiMatrix2D<M, T> {
+    #[inline]
+    fn memory_usage(&self) -> MemoryUsage {
+        self.matrix.memory_usage().with_usage(self.transposed.memory_usage())
+    }
+
+    #[inline]
+    fn shrink_to_fit(&mut self) {
+        self.matrix.shrink_to_fit();
+        self.transposed.shrink_to_fit();
+    }
+}
+
+impl<M: Validate, T: Validate> Validate for GenericBiMatrix2D<M, T> {
+    /// Checks the invariants of the matrix and of its transposed version,
+    /// whose agreement is guaranteed by construction.
+    #[inline]
+    fn validate(&self) -> Result<(), InvariantError> {
+        self.matrix.validate()?;
+        self.transposed.validate()
+    }
+}
@@ -0,0 +1,28 @@
+//! Implementation of the `serde` traits for the `GenericBiMatrix2D` struct.
+//!
+//! Only the direct matrix is serialized: the transposed matrix is rebuilt
+//! upon deserialization, so the two can never disagree.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::GenericBiMatrix2D;
+use crate::traits::{Matrix2D, TransposableMatrix2D};
+
+impl<M: Serialize, T> Serialize for GenericBiMatrix2D<M, T> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.matrix.serialize(serializer)
+    }
+}
+
+impl<'de, M, T> Deserialize<'de> for GenericBiMatrix2D<M, T>
+where
+    T: Matrix2D,
+    M: TransposableMatrix2D<T, RowIndex = T::ColumnIndex, ColumnIndex = T::RowIndex>
+        + Deserialize<'de>,
+{
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self::new(M::deserialize(deserializer)?))
+    }
+}
@@ -316,3 +316,25 @@ mod tests {
         assert!(sources.is_empty());
     }
 }
+
+/// Implements [`MemoryFootprint`] for the implicit numeric vocabularies,
+/// which hold no heap buffers.
+#[cfg(feature = "alloc")]
+macro_rules! impl_empty_memory_footprint {
+    ($($number:ty),*) => {
+        $(
+            impl crate::traits::MemoryFootprint for $number {
+                #[inline]
+                fn memory_usage(&self) -> crate::traits::MemoryUsage {
+                    crate::traits::MemoryUsage::default()
+                }
+
+                #[inline]
+                fn shrink_to_fit(&mut self) {}
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "alloc")]
+impl_empty_memory_footprint!(u8, u16, u32, u64, usize);
@@ -8,11 +8,54 @@ use super::{PaddedMatrix2D, imputed_row_values::ImputedRowValues};
 use crate::{
     impls::M2DValues,
     traits::{
-        DenseMatrix, DenseMatrix2D, DenseValuedMatrix, DenseValuedMatrix2D, SparseValuedMatrix,
-        SparseValuedMatrix2D, TryFromUsize, ValuedMatrix, ValuedMatrix2D,
+        DenseMatrix, DenseMatrix2D, DenseValuedMatrix, DenseValuedMatrix2D, Matrix2D,
+        SparseValuedMatrix, SparseValuedMatrix2D, TryFromUsize, ValuedMatrix, ValuedMatrix2D,
     },
 };
 
+impl<M: SparseValuedMatrix2D, Map> PaddedMatrix2D<M, Map>
+where
+    M::RowIndex: AsPrimitive<usize> + TryFromUsize,
+    M::ColumnIndex: AsPrimitive<usize> + TryFromUsize + Step,
+    Map: Fn((M::RowIndex, M::ColumnIndex)) -> M::Value,
+{
+    /// Returns the value at the provided coordinates, imputed if it is not
+    /// defined in the underlying sparse matrix, or `None` if the coordinates
+    /// are out of the bounds of the padded matrix.
+    ///
+    /// Since the imputed values are computed on access, they are returned by
+    /// value, and the padded matrix does not implement `Index`.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinates`: The row and column of the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{
+    ///     impls::{PaddedMatrix2D, ValuedCSR2D},
+    ///     prelude::*,
+    /// };
+    ///
+    /// let mut csr: ValuedCSR2D<usize, usize, usize, f64> = SparseMatrixMut::with_sparse_shape((2, 3));
+    /// MatrixMut::add(&mut csr, (0, 1, 2.5)).unwrap();
+    /// let padded = PaddedMatrix2D::new(csr, |_: (usize, usize)| 100.0).unwrap();
+    ///
+    /// assert_eq!(padded.checked_get((0, 1)), Some(2.5));
+    /// assert_eq!(padded.checked_get((2, 2)), Some(100.0));
+    /// assert_eq!(padded.checked_get((3, 0)), None);
+    /// ```
+    #[inline]
+    pub fn checked_get(
+        &self,
+        (row_index, column_index): (M::RowIndex, M::ColumnIndex),
+    ) -> Option<M::Value> {
+        (row_index < self.number_of_rows() && column_index < self.number_of_columns())
+            .then(|| self.value((row_index, column_index)))
+    }
+}
+
 impl<M: ValuedMatrix2D, Map> ValuedMatrix for PaddedMatrix2D<M, Map>
 where
     M::RowIndex: AsPrimitive<usize> + TryFromUsize,
@@ -11,6 +11,9 @@ use core::{
 
 use crate::{errors::SortedError, prelude::*, traits::Symbol};
 
+#[cfg(feature = "serde")]
+mod serde_impl;
+
 #[cfg_attr(feature = "mem_size", derive(mem_dbg::MemSize))]
 #[cfg_attr(feature = "mem_size", mem_size(rec))]
 #[cfg_attr(feature = "mem_dbg", derive(mem_dbg::MemDbg))]
@@ -301,6 +304,18 @@ impl<V: Symbol + Ord> GrowableVocabulary for SortedVec<V> {
     }
 }
 
+impl<V> MemoryFootprint for SortedVec<V> {
+    #[inline]
+    fn memory_usage(&self) -> MemoryUsage {
+        self.vec.memory_usage()
+    }
+
+    #[inline]
+    fn shrink_to_fit(&mut self) {
+        self.vec.shrink_to_fit();
+    }
+}
+
 #[cfg(all(test, feature = "alloc"))]
 mod tests {
     use alloc::vec;
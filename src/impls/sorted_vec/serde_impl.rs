@@ -0,0 +1,28 @@
+//! Implementation of the `serde` traits for the `SortedVec` struct.
+//!
+//! A sorted vector is serialized as a plain sequence, and deserialization
+//! rejects sequences that are not sorted.
+
+use alloc::vec::Vec;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+
+use super::SortedVec;
+
+impl<V: Serialize> Serialize for SortedVec<V> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.vec.serialize(serializer)
+    }
+}
+
+impl<'de, V> Deserialize<'de> for SortedVec<V>
+where
+    V: Ord + Clone + core::fmt::Debug + Deserialize<'de>,
+{
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let vec = Vec::<V>::deserialize(deserializer)?;
+        Self::try_from(vec).map_err(D::Error::custom)
+    }
+}
@@ -3,23 +3,36 @@
 #[cfg(feature = "mem_dbg")]
 use alloc::string::String;
 use alloc::vec::Vec;
-use core::fmt::Debug;
+use core::fmt::{Debug, Display};
 
 use multi_ranged::Step;
 use num_traits::{AsPrimitive, One, Zero};
 
 use super::{CSR2D, MutabilityError};
-use crate::traits::{
-    EmptyRows, Matrix, Matrix2D, Matrix2DRef, MatrixMut, PositiveInteger, RankSelectSparseMatrix,
-    SizedRowsSparseMatrix2D, SizedSparseMatrix, SizedSparseMatrix2D, SizedSparseValuedMatrix,
-    SizedSparseValuedMatrixMut, SizedSparseValuedMatrixRef, SparseMatrix, SparseMatrix2D,
-    SparseMatrixMut, SparseValuedMatrix, SparseValuedMatrix2D, SparseValuedMatrix2DMut,
-    SparseValuedMatrix2DRef, SparseValuedMatrixMut, SparseValuedMatrixRef, TryFromUsize,
-    ValuedMatrix, ValuedMatrix2D,
+use crate::{
+    errors::{InvariantError, RankSelectError},
+    traits::{
+        BufferUsage, DISPLAY_LIMIT, DenseValuesDisplay, EmptyRows, Matrix, Matrix2D, Matrix2DRef,
+        MatrixMut, MemoryFootprint, MemoryUsage, PositiveInteger, RankSelectSparseMatrix,
+        SizedRowsSparseMatrix2D, SizedSparseMatrix, SizedSparseMatrix2D, SizedSparseValuedMatrix,
+        SizedSparseValuedMatrixMut, SizedSparseValuedMatrixRef, SparseMatrix, SparseMatrix2D,
+        SparseMatrixMut, SparseValuedMatrix, SparseValuedMatrix2D, SparseValuedMatrix2DMut,
+        SparseValuedMatrix2DRef, SparseValuedMatrixMut, SparseValuedMatrixRef, TotalOrd,
+        TryFromUsize, Validate, ValuedMatrix, ValuedMatrix2D,
+    },
 };
 
 #[cfg(feature = "arbitrary")]
 mod arbitrary_impl;
+mod binary_impl;
+#[cfg(feature = "nalgebra")]
+mod nalgebra_impl;
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(feature = "sprs")]
+mod sprs_impl;
+#[cfg(feature = "sprs")]
+pub use sprs_impl::SprsConversionError;
 
 #[cfg_attr(feature = "mem_size", derive(mem_dbg::MemSize))]
 #[cfg_attr(feature = "mem_size", mem_size(rec))]
@@ -28,8 +41,8 @@ mod arbitrary_impl;
 /// A 2D CSR matrix which stores values in addition to the row and column
 /// indices.
 pub struct ValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value> {
-    csr: CSR2D<SparseIndex, RowIndex, ColumnIndex>,
-    values: Vec<Value>,
+    pub(super) csr: CSR2D<SparseIndex, RowIndex, ColumnIndex>,
+    pub(super) values: Vec<Value>,
 }
 
 /// Errors raised when constructing a [`ValuedCSR2D`] from pre-built parts.
@@ -208,6 +221,129 @@ impl<
     }
 }
 
+impl<
+    SparseIndex: PositiveInteger + TryFromUsize + AsPrimitive<usize>,
+    RowIndex: Step + TryFromUsize + PositiveInteger + AsPrimitive<usize> + Debug,
+    ColumnIndex: Step + TryFromUsize + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex> + Debug,
+    Value,
+> TryFrom<Vec<Vec<Option<Value>>>> for ValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value>
+{
+    type Error = MutabilityError<Self>;
+
+    /// Converts dense rows of optional values, where `None` marks a missing
+    /// entry. Rows shorter than the longest one are completed with missing
+    /// entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::prelude::*;
+    ///
+    /// let matrix: ValuedCSR2D<usize, usize, usize, f64> =
+    ///     ValuedCSR2D::try_from(vec![vec![None, Some(1.5), None], vec![Some(2.0)]]).unwrap();
+    ///
+    /// assert_eq!(matrix.number_of_rows(), 2);
+    /// assert_eq!(matrix.number_of_columns(), 3);
+    /// assert_eq!(matrix.sparse_row(0).collect::<Vec<_>>(), vec![1]);
+    /// assert_eq!(matrix.sparse_row_values(1).collect::<Vec<_>>(), vec![2.0]);
+    /// ```
+    #[inline]
+    fn try_from(value: Vec<Vec<Option<Value>>>) -> Result<Self, Self::Error> {
+        let number_of_columns = value.iter().map(Vec::len).max().unwrap_or(0);
+        let number_of_values = value.iter().flatten().filter(|value| value.is_some()).count();
+        let mut valued_csr: ValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value> =
+            ValuedCSR2D::with_sparse_shaped_capacity(
+                (
+                    RowIndex::try_from_usize(value.len())
+                        .map_err(|_| MutabilityError::<Self>::MaxedOutRowIndex)?,
+                    ColumnIndex::try_from_usize(number_of_columns)
+                        .map_err(|_| MutabilityError::<Self>::MaxedOutColumnIndex)?,
+                ),
+                SparseIndex::try_from_usize(number_of_values)
+                    .map_err(|_| MutabilityError::<Self>::MaxedOutSparseIndex)?,
+            );
+        for (row, row_values) in valued_csr.row_indices().zip(value) {
+            for (column, value) in valued_csr.column_indices().zip(row_values) {
+                if let Some(value) = value {
+                    valued_csr.add((row, column, value))?;
+                }
+            }
+        }
+
+        Ok(valued_csr)
+    }
+}
+
+impl<
+    SparseIndex: PositiveInteger + TryFromUsize + AsPrimitive<usize>,
+    RowIndex: Step + TryFromUsize + PositiveInteger + AsPrimitive<usize> + Debug,
+    ColumnIndex: Step + TryFromUsize + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex> + Debug,
+    Value,
+> ValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value>
+{
+    /// Creates a new valued CSR matrix from the entries of each of its rows.
+    ///
+    /// The entries of a row may be provided in any order, and are sorted by
+    /// column. The number of rows is the number of provided rows, and the
+    /// number of columns is one past the largest provided column.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows`: The `(column, value)` entries of each row.
+    ///
+    /// # Errors
+    ///
+    /// * `MutabilityError::DuplicatedEntry` - A column is repeated in a row.
+    /// * `MutabilityError::MaxedOutRowIndex` - The number of rows cannot be
+    ///   represented by the row index.
+    /// * `MutabilityError::MaxedOutColumnIndex` - A column is the largest value
+    ///   of the column index.
+    /// * `MutabilityError::MaxedOutSparseIndex` - The number of entries cannot
+    ///   be represented by the sparse index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::prelude::*;
+    ///
+    /// let matrix: ValuedCSR2D<usize, usize, usize, i32> =
+    ///     ValuedCSR2D::try_from_rows(vec![vec![(3, 30), (1, 10)], vec![], vec![(0, 5)], vec![]])
+    ///         .unwrap();
+    ///
+    /// assert_eq!(matrix.number_of_rows(), 4);
+    /// assert_eq!(matrix.number_of_columns(), 4);
+    /// assert_eq!(matrix.sparse_row(0).collect::<Vec<_>>(), vec![1, 3]);
+    /// assert_eq!(matrix.sparse_row_values(0).collect::<Vec<_>>(), vec![10, 30]);
+    /// assert!(ValuedCSR2D::<usize, usize, usize, i32>::try_from_rows([[(1, 1), (1, 2)]]).is_err());
+    /// ```
+    #[inline]
+    pub fn try_from_rows<I, R>(rows: I) -> Result<Self, MutabilityError<Self>>
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = (ColumnIndex, Value)>,
+    {
+        let mut valued_csr = Self::default();
+        let mut number_of_rows = 0;
+        let mut entries = Vec::new();
+        for row_entries in rows {
+            let row = RowIndex::try_from_usize(number_of_rows)
+                .map_err(|_| MutabilityError::<Self>::MaxedOutRowIndex)?;
+            entries.extend(row_entries);
+            entries.sort_unstable_by_key(|&(column, _): &(ColumnIndex, Value)| column);
+            for (column, value) in entries.drain(..) {
+                valued_csr.add((row, column, value))?;
+            }
+            number_of_rows += 1;
+        }
+        let number_of_rows = RowIndex::try_from_usize(number_of_rows)
+            .map_err(|_| MutabilityError::<Self>::MaxedOutRowIndex)?;
+        let number_of_columns = valued_csr.number_of_columns();
+        valued_csr.increase_shape((number_of_rows, number_of_columns))?;
+
+        Ok(valued_csr)
+    }
+}
+
 impl<SparseIndex: Debug, RowIndex: Debug, ColumnIndex: Debug, Value: Debug> Debug
     for ValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value>
 {
@@ -217,6 +353,17 @@ impl<SparseIndex: Debug, RowIndex: Debug, ColumnIndex: Debug, Value: Debug> Debu
     }
 }
 
+impl<SparseIndex, RowIndex, ColumnIndex, Value: Display> Display
+    for ValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    Self: SparseValuedMatrix2D<Value = Value>,
+{
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.dense_values_display(DISPLAY_LIMIT, DISPLAY_LIMIT).fmt(f)
+    }
+}
+
 impl<SparseIndex: Zero, RowIndex: Zero, ColumnIndex: Zero, Value> Default
     for ValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value>
 {
@@ -307,6 +454,12 @@ where
         self.csr.sparse_row(row)
     }
 
+    #[inline]
+    unsafe fn sparse_row_unchecked(&self, row: Self::RowIndex) -> Self::SparseRow<'_> {
+        // SAFETY: the caller upholds the contract of the underlying matrix.
+        unsafe { self.csr.sparse_row_unchecked(row) }
+    }
+
     #[inline]
     fn has_entry(&self, row: Self::RowIndex, column: Self::ColumnIndex) -> bool {
         self.csr.has_entry(row, column)
@@ -393,6 +546,11 @@ where
         self.csr.rank_row(row)
     }
 
+    #[inline]
+    fn rank_row_unchecked(&self, row: Self::RowIndex) -> Self::SparseIndex {
+        self.csr.rank_row_unchecked(row)
+    }
+
     #[inline]
     fn select_row(&self, sparse_index: Self::SparseIndex) -> Self::RowIndex {
         self.csr.select_row(sparse_index)
@@ -404,7 +562,17 @@ where
     }
 
     #[inline]
-    fn try_rank(&self, row: Self::RowIndex, column: Self::ColumnIndex) -> Option<Self::SparseIndex>
+    unsafe fn select_column_unchecked(&self, sparse_index: Self::SparseIndex) -> Self::ColumnIndex {
+        // SAFETY: the caller upholds the contract of the underlying matrix.
+        unsafe { self.csr.select_column_unchecked(sparse_index) }
+    }
+
+    #[inline]
+    fn try_rank(
+        &self,
+        row: Self::RowIndex,
+        column: Self::ColumnIndex,
+    ) -> Result<Self::SparseIndex, RankSelectError>
     where
         Self::ColumnIndex: PartialEq,
     {
@@ -562,6 +730,14 @@ where
     where
         Self: 'a;
 
+    #[inline]
+    fn max_sparse_value(&self) -> Option<Self::Value>
+    where
+        Self::Value: TotalOrd,
+    {
+        Value::total_max(&self.values)
+    }
+
     #[inline]
     fn sparse_values(&self) -> Self::SparseValues<'_> {
         self.values.iter().cloned()
@@ -600,6 +776,26 @@ where
         let end = self.rank_row(row + Self::RowIndex::one()).as_();
         self.values[start..end].iter().cloned()
     }
+
+    #[inline]
+    unsafe fn sparse_row_values_unchecked(&self, row: Self::RowIndex) -> Self::SparseRowValues<'_> {
+        let start = self.rank_row_unchecked(row).as_();
+        let end = self.rank_row_unchecked(row + Self::RowIndex::one()).as_();
+        // SAFETY: the caller guarantees that the row is in bounds, so that its
+        // ranks and the ones of its successor delimit its values.
+        unsafe { self.values.get_unchecked(start..end) }.iter().cloned()
+    }
+
+    #[inline]
+    fn sparse_value_at(&self, row: Self::RowIndex, column: Self::ColumnIndex) -> Option<Self::Value>
+    where
+        Self::ColumnIndex: PartialEq,
+    {
+        // The coordinates are not validated by the callers, so that the rows
+        // out of bounds are reported as missing entries instead of panicking.
+        let sparse_index = self.try_rank(row, column).ok()?;
+        Some(self.values[sparse_index.as_()].clone())
+    }
 }
 
 impl<SparseIndex, RowIndex, ColumnIndex, Value> SparseValuedMatrixRef
@@ -722,7 +918,7 @@ where
     where
         Self::ColumnIndex: PartialEq,
     {
-        let sparse_index = self.csr.try_rank(row, column)?;
+        let sparse_index = self.csr.try_rank(row, column).ok()?;
         Some(&mut self.values[sparse_index.as_()])
     }
 
@@ -737,6 +933,42 @@ where
     }
 }
 
+impl<SparseIndex, RowIndex, ColumnIndex, Value> MemoryFootprint
+    for ValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value>
+{
+    #[inline]
+    fn memory_usage(&self) -> MemoryUsage {
+        self.csr.memory_usage().with_buffer(BufferUsage::of_vec("values", &self.values))
+    }
+
+    #[inline]
+    fn shrink_to_fit(&mut self) {
+        self.csr.shrink_to_fit();
+        self.values.shrink_to_fit();
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value> Validate
+    for ValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    CSR2D<SparseIndex, RowIndex, ColumnIndex>:
+        Validate + SizedSparseMatrix<SparseIndex = SparseIndex>,
+    SparseIndex: AsPrimitive<usize>,
+{
+    #[inline]
+    fn validate(&self) -> Result<(), InvariantError> {
+        self.csr.validate()?;
+        let expected = self.csr.number_of_defined_values().as_();
+        if self.values.len() != expected {
+            return Err(InvariantError::ValuesLengthMismatch {
+                expected,
+                actual: self.values.len(),
+            });
+        }
+        Ok(())
+    }
+}
+
 #[cfg(all(test, feature = "alloc"))]
 mod tests {
     use alloc::{string::String, vec::Vec};
@@ -1079,10 +1311,22 @@ mod tests {
         let mut matrix: TestValuedCSR2D = SparseMatrixMut::with_sparse_shape((2, 3));
         matrix.add((0, 1, 10)).unwrap();
         matrix.add((1, 2, 20)).unwrap();
-        assert_eq!(matrix.try_rank(0, 1), Some(0));
-        assert_eq!(matrix.try_rank(1, 2), Some(1));
-        assert_eq!(matrix.try_rank(0, 0), None);
-        assert_eq!(matrix.try_rank(0, 2), None);
+        assert_eq!(matrix.try_rank(0, 1), Ok(0));
+        assert_eq!(matrix.try_rank(1, 2), Ok(1));
+        assert_eq!(matrix.try_rank(0, 0), Err(RankSelectError::MissingEntry { row: 0, column: 0 }));
+        assert_eq!(matrix.try_rank(0, 2), Err(RankSelectError::MissingEntry { row: 0, column: 2 }));
+        assert_eq!(
+            matrix.try_rank(2, 0),
+            Err(RankSelectError::RowOutOfBounds { row: 2, number_of_rows: 2 })
+        );
+        assert_eq!(matrix.try_select(1), Ok((1, 2)));
+        assert_eq!(
+            matrix.try_select(2),
+            Err(RankSelectError::SparseIndexOutOfBounds {
+                sparse_index: 2,
+                number_of_defined_values: 2
+            })
+        );
     }
 
     #[test]
@@ -1179,8 +1423,8 @@ mod tests {
     #[test]
     fn test_try_rank_empty_matrix() {
         let matrix: TestValuedCSR2D = SparseMatrixMut::with_sparse_shape((3, 3));
-        assert_eq!(matrix.try_rank(0, 0), None);
-        assert_eq!(matrix.try_rank(2, 2), None);
+        assert_eq!(matrix.try_rank(0, 0), Err(RankSelectError::MissingEntry { row: 0, column: 0 }));
+        assert_eq!(matrix.try_rank(2, 2), Err(RankSelectError::MissingEntry { row: 2, column: 2 }));
     }
 
     #[test]
@@ -1191,14 +1435,14 @@ mod tests {
         matrix.add((0, 4, 3)).unwrap();
         matrix.add((1, 1, 4)).unwrap();
         matrix.add((1, 3, 5)).unwrap();
-        assert_eq!(matrix.try_rank(0, 0), Some(0));
-        assert_eq!(matrix.try_rank(0, 2), Some(1));
-        assert_eq!(matrix.try_rank(0, 4), Some(2));
-        assert_eq!(matrix.try_rank(0, 1), None);
-        assert_eq!(matrix.try_rank(0, 3), None);
-        assert_eq!(matrix.try_rank(1, 1), Some(3));
-        assert_eq!(matrix.try_rank(1, 3), Some(4));
-        assert_eq!(matrix.try_rank(1, 0), None);
+        assert_eq!(matrix.try_rank(0, 0), Ok(0));
+        assert_eq!(matrix.try_rank(0, 2), Ok(1));
+        assert_eq!(matrix.try_rank(0, 4), Ok(2));
+        assert_eq!(matrix.try_rank(0, 1), Err(RankSelectError::MissingEntry { row: 0, column: 1 }));
+        assert_eq!(matrix.try_rank(0, 3), Err(RankSelectError::MissingEntry { row: 0, column: 3 }));
+        assert_eq!(matrix.try_rank(1, 1), Ok(3));
+        assert_eq!(matrix.try_rank(1, 3), Ok(4));
+        assert_eq!(matrix.try_rank(1, 0), Err(RankSelectError::MissingEntry { row: 1, column: 0 }));
     }
 
     #[test]
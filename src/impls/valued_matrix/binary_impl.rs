@@ -0,0 +1,48 @@
+//! Implementation of the binary encoding for the `ValuedCSR2D` struct.
+//!
+//! The body stores the scalar tag of the values, the body of the underlying
+//! [`CSR2D`] and the values in storage order.
+
+use alloc::vec::Vec;
+
+use num_traits::AsPrimitive;
+
+use super::{ValuedCSR2D, ValuedCsrPartsError};
+use crate::{
+    errors::InvariantError,
+    impls::CSR2D,
+    io::{BinaryDecodeError, BinaryEncoding, BinaryReader, BinaryScalar, write_scalars},
+    traits::SizedSparseMatrix,
+};
+
+/// Layout tag of the `ValuedCSR2D` body.
+const VALUED_CSR2D_LAYOUT: u8 = 0x02;
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value> BinaryEncoding
+    for ValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    SparseIndex: AsPrimitive<usize>,
+    CSR2D<SparseIndex, RowIndex, ColumnIndex>:
+        SizedSparseMatrix<SparseIndex = SparseIndex> + BinaryEncoding,
+    Value: BinaryScalar,
+{
+    #[inline]
+    fn write_body(&self, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(&[VALUED_CSR2D_LAYOUT, Value::TAG]);
+        self.csr.write_body(buffer);
+        write_scalars(&self.values, buffer);
+    }
+
+    #[inline]
+    fn read_body(reader: &mut BinaryReader<'_>) -> Result<Self, BinaryDecodeError> {
+        reader.expect_layout(VALUED_CSR2D_LAYOUT)?;
+        reader.expect_scalar::<Value>()?;
+        let csr = CSR2D::read_body(reader)?;
+        let values = reader.read_scalars()?;
+        Self::from_parts(csr, values).map_err(
+            |ValuedCsrPartsError::ValuesLengthMismatch { expected, actual }| {
+                InvariantError::ValuesLengthMismatch { expected, actual }.into()
+            },
+        )
+    }
+}
@@ -0,0 +1,129 @@
+//! Conversions between the `ValuedCSR2D` struct and the dense `DMatrix` of the
+//! `nalgebra` crate.
+//!
+//! A dense matrix has no notion of missing entries, so the conversions take a
+//! fill value standing for the entries the sparse matrix does not store.
+
+use core::fmt::Debug;
+
+use multi_ranged::Step;
+use nalgebra::{DMatrix, Scalar};
+use num_traits::AsPrimitive;
+
+use super::{MutabilityError, ValuedCSR2D};
+use crate::traits::{
+    Matrix2D, MatrixMut, PositiveInteger, SparseMatrix, SparseMatrixMut, TryFromUsize,
+};
+
+impl<
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize + Debug,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex> + Debug,
+    Value: Scalar,
+> ValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value>
+{
+    /// Returns a dense `nalgebra` matrix holding the stored values, with the
+    /// entries missing from the sparse matrix set to `fill`.
+    ///
+    /// # Arguments
+    ///
+    /// * `fill`: The value of the entries not stored in the sparse matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::prelude::*;
+    ///
+    /// let mut matrix: ValuedCSR2D<usize, usize, usize, f64> =
+    ///     SparseMatrixMut::with_sparse_shape((2, 3));
+    /// MatrixMut::add(&mut matrix, (0, 2, 1.5)).unwrap();
+    /// MatrixMut::add(&mut matrix, (1, 0, -2.0)).unwrap();
+    ///
+    /// let dense = matrix.to_dmatrix(f64::INFINITY);
+    /// assert_eq!(dense.shape(), (2, 3));
+    /// assert_eq!(dense[(0, 2)], 1.5);
+    /// assert_eq!(dense[(1, 0)], -2.0);
+    /// assert_eq!(dense[(0, 0)], f64::INFINITY);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_dmatrix(&self, fill: Value) -> DMatrix<Value> {
+        let mut dense = DMatrix::from_element(
+            self.number_of_rows().as_(),
+            self.number_of_columns().as_(),
+            fill,
+        );
+        for ((row, column), value) in self.sparse_coordinates().zip(self.values.iter()) {
+            dense[(row.as_(), column.as_())] = value.clone();
+        }
+        dense
+    }
+
+    /// Creates a sparse matrix from a dense `nalgebra` matrix, skipping the
+    /// entries equal to `fill`.
+    ///
+    /// This is the inverse of [`to_dmatrix`](Self::to_dmatrix) for matrices
+    /// which do not store `fill` explicitly.
+    ///
+    /// # Arguments
+    ///
+    /// * `matrix`: The dense matrix to convert.
+    /// * `fill`: The value of the entries to leave out of the sparse matrix.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`MutabilityError`] if the shape or the number of stored
+    /// entries does not fit in the index types.
+    #[inline]
+    pub fn try_from_dmatrix(
+        matrix: &DMatrix<Value>,
+        fill: &Value,
+    ) -> Result<Self, MutabilityError<Self>> {
+        Self::try_from_dmatrix_entries(matrix, |value| value != fill)
+    }
+
+    /// Creates a sparse matrix from the entries of a dense `nalgebra` matrix
+    /// satisfying the provided predicate.
+    fn try_from_dmatrix_entries(
+        matrix: &DMatrix<Value>,
+        keep: impl Fn(&Value) -> bool,
+    ) -> Result<Self, MutabilityError<Self>> {
+        let (number_of_rows, number_of_columns) = matrix.shape();
+        let number_of_values = matrix.iter().filter(|value| keep(value)).count();
+        let mut valued_csr: Self = SparseMatrixMut::with_sparse_shaped_capacity(
+            (
+                RowIndex::try_from_usize(number_of_rows)
+                    .map_err(|_| MutabilityError::<Self>::MaxedOutRowIndex)?,
+                ColumnIndex::try_from_usize(number_of_columns)
+                    .map_err(|_| MutabilityError::<Self>::MaxedOutColumnIndex)?,
+            ),
+            SparseIndex::try_from_usize(number_of_values)
+                .map_err(|_| MutabilityError::<Self>::MaxedOutSparseIndex)?,
+        );
+        for (row, row_values) in valued_csr.row_indices().zip(matrix.row_iter()) {
+            for (column, value) in valued_csr.column_indices().zip(row_values.iter()) {
+                if keep(value) {
+                    valued_csr.add((row, column, value.clone()))?;
+                }
+            }
+        }
+        Ok(valued_csr)
+    }
+}
+
+impl<
+    SparseIndex: PositiveInteger + AsPrimitive<usize> + TryFromUsize,
+    RowIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFromUsize + Debug,
+    ColumnIndex: Step + PositiveInteger + AsPrimitive<usize> + TryFrom<SparseIndex> + Debug,
+    Value: Scalar,
+> TryFrom<DMatrix<Value>> for ValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value>
+{
+    type Error = MutabilityError<Self>;
+
+    /// Creates a sparse matrix storing every entry of the dense matrix, as the
+    /// conversion from nested arrays does.
+    #[inline]
+    fn try_from(matrix: DMatrix<Value>) -> Result<Self, Self::Error> {
+        Self::try_from_dmatrix_entries(&matrix, |_| true)
+    }
+}
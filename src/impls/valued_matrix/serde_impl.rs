@@ -0,0 +1,56 @@
+//! Implementation of the `serde` traits for the `ValuedCSR2D` struct.
+//!
+//! The CSR topology is validated by the deserializer of [`CSR2D`], while the
+//! number of values is checked against the number of stored entries.
+
+use alloc::vec::Vec;
+
+use num_traits::AsPrimitive;
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+
+use super::ValuedCSR2D;
+use crate::{impls::CSR2D, traits::SizedSparseMatrix};
+
+#[derive(Serialize)]
+#[serde(rename = "ValuedCSR2D")]
+struct ValuedCSR2DRef<'a, CSR, Value> {
+    csr: &'a CSR,
+    values: &'a [Value],
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "ValuedCSR2D")]
+struct RawValuedCSR2D<CSR, Value> {
+    csr: CSR,
+    values: Vec<Value>,
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value> Serialize
+    for ValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    CSR2D<SparseIndex, RowIndex, ColumnIndex>: Serialize,
+    Value: Serialize,
+{
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ValuedCSR2DRef { csr: &self.csr, values: &self.values }.serialize(serializer)
+    }
+}
+
+impl<'de, SparseIndex, RowIndex, ColumnIndex, Value> Deserialize<'de>
+    for ValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    SparseIndex: AsPrimitive<usize>,
+    CSR2D<SparseIndex, RowIndex, ColumnIndex>:
+        SizedSparseMatrix<SparseIndex = SparseIndex> + Deserialize<'de>,
+    Value: Deserialize<'de>,
+{
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let RawValuedCSR2D { csr, values } = RawValuedCSR2D::<
+            CSR2D<SparseIndex, RowIndex, ColumnIndex>,
+            Value,
+        >::deserialize(deserializer)?;
+        Self::from_parts(csr, values).map_err(D::Error::custom)
+    }
+}
@@ -0,0 +1,101 @@
+//! Conversions between the `ValuedCSR2D` struct and the `CsMatI` matrices of
+//! the `sprs` crate.
+//!
+//! When the `sprs` matrix is stored in CSR order and uses the same index types,
+//! its index pointers, column indices and values are moved as they are, without
+//! copying the entries. Matrices stored in CSC order are first converted to CSR
+//! by `sprs`, which copies them.
+
+use alloc::vec::Vec;
+
+use sprs::{CsMatI, SpIndex};
+
+use super::ValuedCSR2D;
+use crate::{
+    errors::InvariantError,
+    impls::CSR2D,
+    traits::{PositiveInteger, TryFromUsize},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, thiserror::Error)]
+/// Errors raised when converting a `sprs` matrix into a [`ValuedCSR2D`].
+pub enum SprsConversionError {
+    /// The number of rows does not fit in the row index type.
+    #[error("The number of rows {0} does not fit in the row index type")]
+    RowsOverflow(usize),
+    /// The number of columns does not fit in the column index type.
+    #[error("The number of columns {0} does not fit in the column index type")]
+    ColumnsOverflow(usize),
+    /// The `sprs` matrix violates an invariant of the CSR matrix.
+    #[error("Invalid CSR structure: {0}")]
+    InvalidStructure(#[from] InvariantError),
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value> TryFrom<CsMatI<Value, ColumnIndex, SparseIndex>>
+    for ValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value>
+where
+    SparseIndex: PositiveInteger + SpIndex,
+    RowIndex: PositiveInteger,
+    ColumnIndex: PositiveInteger + SpIndex,
+    Value: Clone + Default,
+{
+    type Error = SprsConversionError;
+
+    #[inline]
+    fn try_from(matrix: CsMatI<Value, ColumnIndex, SparseIndex>) -> Result<Self, Self::Error> {
+        let matrix = matrix.into_csr();
+        let (number_of_rows, number_of_columns) = matrix.shape();
+        let number_of_rows = RowIndex::try_from_usize(number_of_rows)
+            .map_err(|_| SprsConversionError::RowsOverflow(number_of_rows))?;
+        let number_of_columns = <ColumnIndex as TryFromUsize>::try_from_usize(number_of_columns)
+            .map_err(|_| SprsConversionError::ColumnsOverflow(number_of_columns))?;
+
+        let (mut offsets, column_indices, values) = matrix.into_raw_storage();
+        // The index pointers of a `sprs` matrix may start from a non-zero
+        // offset, which the column indices and values do not account for.
+        if let Some(&first) = offsets.first().filter(|first| !first.is_zero()) {
+            for offset in &mut offsets {
+                *offset -= first;
+            }
+        }
+
+        let csr =
+            CSR2D::try_from_raw_parts(number_of_rows, number_of_columns, offsets, column_indices)
+                .map_err(SprsConversionError::InvalidStructure)?;
+        Ok(Self { csr, values })
+    }
+}
+
+impl<SparseIndex, RowIndex, ColumnIndex, Value>
+    From<ValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value>>
+    for CsMatI<Value, ColumnIndex, SparseIndex>
+where
+    SparseIndex: PositiveInteger + SpIndex,
+    RowIndex: PositiveInteger,
+    ColumnIndex: PositiveInteger + SpIndex,
+{
+    #[inline]
+    fn from(matrix: ValuedCSR2D<SparseIndex, RowIndex, ColumnIndex, Value>) -> Self {
+        let (csr, values) = matrix.into_parts();
+        let (number_of_rows, number_of_columns, mut offsets, column_indices): (
+            RowIndex,
+            ColumnIndex,
+            Vec<SparseIndex>,
+            Vec<ColumnIndex>,
+        ) = csr.into_raw_parts();
+        // The offsets of a `CSR2D` omit the trailing empty rows, while `sprs`
+        // expects one index pointer per row plus one.
+        let last = offsets.last().copied().unwrap_or(SparseIndex::ZERO);
+        offsets.resize(number_of_rows.as_() + 1, last);
+
+        CsMatI::try_new(
+            (number_of_rows.as_(), number_of_columns.as_()),
+            offsets,
+            column_indices,
+            values,
+        )
+        .unwrap_or_else(|(.., error)| {
+            unreachable!("a valid CSR2D is always a valid sprs matrix: {error}")
+        })
+    }
+}
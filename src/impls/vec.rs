@@ -6,7 +6,7 @@ use alloc::string::String;
 use alloc::vec::Vec;
 use core::{
     iter::{Cloned, Copied},
-    ops::Range,
+    ops::{Index, Range},
 };
 
 #[cfg(feature = "arbitrary")]
@@ -134,6 +134,33 @@ impl<V> VecMatrix2D<V> {
         assert_eq!(number_of_rows * number_of_columns, data.len());
         Self { data, number_of_rows }
     }
+
+    /// Returns a reference to the value at the provided coordinates, or
+    /// `None` if they are out of the bounds of the matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `coordinates`: The row and column of the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::impls::VecMatrix2D;
+    ///
+    /// let matrix = VecMatrix2D::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(matrix.checked_get((1, 2)), Some(&6));
+    /// assert_eq!(matrix.checked_get((0, 3)), None);
+    /// assert_eq!(matrix[(1, 0)], 4);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn checked_get(&self, (row, column): (usize, usize)) -> Option<&V> {
+        let number_of_columns = self.number_of_columns();
+        if row >= self.number_of_rows || column >= number_of_columns {
+            return None;
+        }
+        self.data.get(row * number_of_columns + column)
+    }
 }
 
 impl<V> Matrix for VecMatrix2D<V> {
@@ -160,6 +187,22 @@ impl<V> Matrix2D for VecMatrix2D<V> {
     }
 }
 
+impl<V> Index<(usize, usize)> for VecMatrix2D<V> {
+    type Output = V;
+
+    #[inline]
+    fn index(&self, coordinates: (usize, usize)) -> &Self::Output {
+        let Some(value) = self.checked_get(coordinates) else {
+            panic!(
+                "The coordinates {coordinates:?} are out of the bounds of a {}x{} matrix",
+                self.number_of_rows(),
+                self.number_of_columns()
+            )
+        };
+        value
+    }
+}
+
 impl<V: Copy> ValuedMatrix for VecMatrix2D<V> {
     type Value = V;
 }
@@ -207,6 +250,52 @@ impl<V, const COLS: usize, const ROWS: usize> From<[[V; COLS]; ROWS]> for VecMat
     }
 }
 
+#[cfg(feature = "nalgebra")]
+impl<V: nalgebra::Scalar> VecMatrix2D<V> {
+    /// Returns a dense `nalgebra` matrix with the same values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::impls::VecMatrix2D;
+    ///
+    /// let matrix = VecMatrix2D::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    /// let dense = matrix.to_dmatrix();
+    /// assert_eq!(dense.shape(), (2, 3));
+    /// assert_eq!(dense[(1, 0)], 4);
+    /// assert_eq!(VecMatrix2D::from(dense), matrix);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_dmatrix(&self) -> nalgebra::DMatrix<V> {
+        nalgebra::DMatrix::from_row_slice(
+            self.number_of_rows(),
+            self.number_of_columns(),
+            &self.data,
+        )
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl<V: nalgebra::Scalar> From<VecMatrix2D<V>> for nalgebra::DMatrix<V> {
+    #[inline]
+    fn from(matrix: VecMatrix2D<V>) -> Self {
+        let number_of_columns = matrix.number_of_columns();
+        Self::from_row_iterator(matrix.number_of_rows, number_of_columns, matrix.data)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl<V: nalgebra::Scalar> From<nalgebra::DMatrix<V>> for VecMatrix2D<V> {
+    #[inline]
+    fn from(matrix: nalgebra::DMatrix<V>) -> Self {
+        let (number_of_rows, number_of_columns) = matrix.shape();
+        // The row-major layout of a matrix is the column-major layout of its
+        // transpose, which is how `nalgebra` stores its matrices.
+        Self::new(number_of_rows, number_of_columns, matrix.transpose().data.into())
+    }
+}
+
 #[cfg(feature = "arbitrary")]
 impl<'a, V> Arbitrary<'a> for VecMatrix2D<V>
 where
@@ -226,6 +315,18 @@ where
     }
 }
 
+impl<V> MemoryFootprint for Vec<V> {
+    #[inline]
+    fn memory_usage(&self) -> MemoryUsage {
+        MemoryUsage::default().with_buffer(BufferUsage::of_vec("elements", self))
+    }
+
+    #[inline]
+    fn shrink_to_fit(&mut self) {
+        Vec::shrink_to_fit(self);
+    }
+}
+
 #[cfg(all(test, feature = "alloc"))]
 mod tests {
     use alloc::{vec, vec::Vec};
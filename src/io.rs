@@ -0,0 +1,49 @@
+//! Submodule providing readers and writers for common graph interchange
+//! formats.
+//!
+//! All readers ultimately feed the one-shot edges and vocabulary builders, so
+//! the graphs they produce satisfy the same invariants as graphs constructed
+//! programmatically. The binary encoding of the CSR matrices only requires
+//! `alloc`, while the text readers and the NumPy writers require `std`.
+
+#[cfg(feature = "arrow")]
+mod arrow_table;
+mod binary;
+#[cfg(feature = "std")]
+mod delimited;
+#[cfg(feature = "std")]
+mod edge_index;
+#[cfg(feature = "std")]
+mod edge_list;
+#[cfg(feature = "std")]
+mod gexf;
+#[cfg(feature = "json")]
+mod node_link;
+#[cfg(feature = "std")]
+mod npy;
+#[cfg(feature = "std")]
+mod pajek;
+
+#[cfg(feature = "arrow")]
+pub use arrow_table::{ArrowEdgeTableReader, ArrowReadError};
+pub use binary::{
+    BINARY_FORMAT_VERSION, BINARY_MAGIC, BinaryDecodeError, BinaryEncoding, BinaryReader,
+    BinaryScalar, write_scalars,
+};
+#[cfg(feature = "std")]
+pub use delimited::{
+    DelimitedColumn, DelimitedEdgeListReader, DelimitedReadError, DelimitedRecord,
+};
+#[cfg(feature = "std")]
+pub use edge_list::{EdgeListReadError, EdgeListWriteError, read_edge_list, write_edge_list};
+#[cfg(feature = "std")]
+pub use gexf::{GexfAttributeValues, GexfWriteError, GexfWriter};
+#[cfg(feature = "json")]
+pub use node_link::{NodeLinkError, NodeLinkJson, NodeLinkSymbol};
+#[cfg(feature = "std")]
+pub use npy::{NpyScalar, write_csr_npz, write_csr_pattern_npz, write_dense_npy, write_npy_vector};
+#[cfg(feature = "std")]
+pub use pajek::{
+    PajekReadError, PajekReader, PajekWriteError, write_pajek_digraph, write_pajek_undigraph,
+    write_pajek_weighted_digraph,
+};
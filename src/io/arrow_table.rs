@@ -0,0 +1,412 @@
+//! Submodule providing a reader for edge tables stored as Arrow record batches
+//! or Parquet files.
+//!
+//! Each row of the table describes one edge. The source and target columns
+//! hold node symbols as strings, and an optional weight column holds numeric
+//! weights. As for the delimited reader, node symbols are collected into a
+//! sorted vocabulary, so the node identifiers of the resulting graph follow
+//! the lexicographic order of the symbols.
+
+use std::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use arrow_array::{
+    Array, ArrowPrimitiveType, RecordBatch,
+    cast::AsArray,
+    types::{
+        Float32Type, Float64Type, Int8Type, Int16Type, Int32Type, Int64Type, UInt8Type, UInt16Type,
+        UInt32Type, UInt64Type,
+    },
+};
+use arrow_schema::DataType;
+use num_traits::AsPrimitive;
+
+use super::edge_index::{IndexedEdges, RawEdge};
+use crate::naive_structs::{DiGraph, UndiGraph, WeightedDiGraph};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+/// Errors raised while reading an Arrow edge table.
+///
+/// Every variant referring to the content of the table carries the zero-based
+/// row index where the problem was detected, counted across all the batches.
+pub enum ArrowReadError {
+    /// A configured column does not appear in the schema of a batch.
+    #[error("Column `{column}` does not appear in the edge table")]
+    MissingColumn {
+        /// The name of the missing column.
+        column: String,
+    },
+    /// A configured column has a data type the reader cannot interpret.
+    #[error("Column `{column}` has the unsupported data type {data_type}")]
+    UnsupportedDataType {
+        /// The name of the column.
+        column: String,
+        /// The data type of the column.
+        data_type: DataType,
+    },
+    /// A configured column holds a null value.
+    #[error("Row {row} has a null value in column `{column}`")]
+    NullValue {
+        /// The row holding the null value.
+        row: usize,
+        /// The name of the column.
+        column: String,
+    },
+    /// A weight is not a finite number.
+    #[error("Row {row} has a non-finite weight")]
+    InvalidWeight {
+        /// The row holding the invalid weight.
+        row: usize,
+    },
+    /// A weighted graph was requested, but no weight column was configured.
+    #[error("A weighted graph was requested, but no weight column was configured")]
+    MissingWeightColumn,
+    /// The same edge appears more than once, and duplicates are not ignored.
+    #[error("Row {row} repeats the edge from `{source_symbol}` to `{destination_symbol}`")]
+    DuplicatedEdge {
+        /// The row of the repeated edge.
+        row: usize,
+        /// The symbol of the source node.
+        source_symbol: String,
+        /// The symbol of the destination node.
+        destination_symbol: String,
+    },
+    /// The Parquet file could not be decoded.
+    #[cfg(feature = "parquet")]
+    #[error("Failed to decode the Parquet file: {message}")]
+    Parquet {
+        /// The message of the underlying Parquet or Arrow error.
+        message: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Reader of Arrow edge tables, configured with a builder-style API.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use arrow_array::{Float64Array, RecordBatch, StringArray};
+/// use geometric_traits::{io::ArrowEdgeTableReader, prelude::*};
+///
+/// let batch = RecordBatch::try_from_iter([
+///     ("source", Arc::new(StringArray::from(vec!["alanine", "glycine"])) as _),
+///     ("target", Arc::new(StringArray::from(vec!["glycine", "serine"])) as _),
+///     ("weight", Arc::new(Float64Array::from(vec![0.5, 0.25])) as _),
+/// ])
+/// .unwrap();
+///
+/// let graph = ArrowEdgeTableReader::default()
+///     .weight_column("weight")
+///     .read_weighted_digraph(&[batch])
+///     .unwrap();
+///
+/// assert_eq!(graph.number_of_nodes(), 3);
+/// assert_eq!(graph.number_of_edges(), 2);
+/// let alanine = graph.nodes_vocabulary().invert(&"alanine".to_string()).unwrap();
+/// assert_eq!(graph.successor_weights(alanine).collect::<Vec<f64>>(), vec![0.5]);
+/// ```
+pub struct ArrowEdgeTableReader {
+    /// Whether to silently drop repeated edges.
+    ignore_duplicates: bool,
+    /// The column holding the source symbols.
+    source_column: String,
+    /// The column holding the destination symbols.
+    target_column: String,
+    /// The column holding the weights, if any.
+    weight_column: Option<String>,
+}
+
+impl Default for ArrowEdgeTableReader {
+    /// Returns a reader of the `source` and `target` columns, without weights.
+    #[inline]
+    fn default() -> Self {
+        Self {
+            ignore_duplicates: false,
+            source_column: "source".to_string(),
+            target_column: "target".to_string(),
+            weight_column: None,
+        }
+    }
+}
+
+/// Accessor to the node symbols stored in a string column.
+enum SymbolColumn<'a> {
+    Utf8(&'a arrow_array::StringArray),
+    LargeUtf8(&'a arrow_array::LargeStringArray),
+    Utf8View(&'a arrow_array::StringViewArray),
+}
+
+impl<'a> SymbolColumn<'a> {
+    fn new(batch: &'a RecordBatch, column: &str) -> Result<Self, ArrowReadError> {
+        let array = column_by_name(batch, column)?;
+        match array.data_type() {
+            DataType::Utf8 => Ok(Self::Utf8(array.as_string())),
+            DataType::LargeUtf8 => Ok(Self::LargeUtf8(array.as_string())),
+            DataType::Utf8View => Ok(Self::Utf8View(array.as_string_view())),
+            data_type => {
+                Err(ArrowReadError::UnsupportedDataType {
+                    column: column.to_string(),
+                    data_type: data_type.clone(),
+                })
+            }
+        }
+    }
+
+    /// Returns the symbol stored in the provided row, if it is not null.
+    fn symbol(&self, row: usize) -> Option<&'a str> {
+        match self {
+            Self::Utf8(array) => array.is_valid(row).then(|| array.value(row)),
+            Self::LargeUtf8(array) => array.is_valid(row).then(|| array.value(row)),
+            Self::Utf8View(array) => array.is_valid(row).then(|| array.value(row)),
+        }
+    }
+}
+
+/// Accessor to the weights stored in a numeric column, returning `None` for
+/// null values.
+type WeightColumn<'a> = Box<dyn Fn(usize) -> Option<f64> + 'a>;
+
+fn primitive_weights<T>(array: &dyn Array) -> WeightColumn<'_>
+where
+    T: ArrowPrimitiveType,
+    T::Native: AsPrimitive<f64>,
+{
+    let array = array.as_primitive::<T>();
+    Box::new(move |row| array.is_valid(row).then(|| array.value(row).as_()))
+}
+
+fn weight_column<'a>(
+    batch: &'a RecordBatch,
+    column: &str,
+) -> Result<WeightColumn<'a>, ArrowReadError> {
+    let array = column_by_name(batch, column)?;
+    Ok(match array.data_type() {
+        DataType::Float64 => primitive_weights::<Float64Type>(array),
+        DataType::Float32 => primitive_weights::<Float32Type>(array),
+        DataType::Int8 => primitive_weights::<Int8Type>(array),
+        DataType::Int16 => primitive_weights::<Int16Type>(array),
+        DataType::Int32 => primitive_weights::<Int32Type>(array),
+        DataType::Int64 => primitive_weights::<Int64Type>(array),
+        DataType::UInt8 => primitive_weights::<UInt8Type>(array),
+        DataType::UInt16 => primitive_weights::<UInt16Type>(array),
+        DataType::UInt32 => primitive_weights::<UInt32Type>(array),
+        DataType::UInt64 => primitive_weights::<UInt64Type>(array),
+        data_type => {
+            return Err(ArrowReadError::UnsupportedDataType {
+                column: column.to_string(),
+                data_type: data_type.clone(),
+            });
+        }
+    })
+}
+
+fn column_by_name<'a>(
+    batch: &'a RecordBatch,
+    column: &str,
+) -> Result<&'a dyn Array, ArrowReadError> {
+    batch
+        .column_by_name(column)
+        .map(AsRef::as_ref)
+        .ok_or_else(|| ArrowReadError::MissingColumn { column: column.to_string() })
+}
+
+impl ArrowEdgeTableReader {
+    #[must_use]
+    /// Silently drops repeated edges instead of reporting them as errors.
+    ///
+    /// For weighted graphs, the weight of the first occurrence is kept.
+    #[inline]
+    pub fn ignore_duplicates(mut self) -> Self {
+        self.ignore_duplicates = true;
+        self
+    }
+
+    #[must_use]
+    /// Sets the column holding the source symbols.
+    #[inline]
+    pub fn source_column(mut self, column: impl Into<String>) -> Self {
+        self.source_column = column.into();
+        self
+    }
+
+    #[must_use]
+    /// Sets the column holding the destination symbols.
+    #[inline]
+    pub fn target_column(mut self, column: impl Into<String>) -> Self {
+        self.target_column = column.into();
+        self
+    }
+
+    #[must_use]
+    /// Sets the column holding the edge weights.
+    ///
+    /// Floating point and integer columns are supported, and their values
+    /// are converted to `f64`.
+    #[inline]
+    pub fn weight_column(mut self, column: impl Into<String>) -> Self {
+        self.weight_column = Some(column.into());
+        self
+    }
+
+    /// Collects the edges of the batches and indexes them.
+    fn index_batches(
+        &self,
+        batches: &[RecordBatch],
+        undirected: bool,
+    ) -> Result<IndexedEdges<String>, ArrowReadError> {
+        let mut raw_edges = Vec::with_capacity(batches.iter().map(RecordBatch::num_rows).sum());
+        let mut offset = 0;
+        for batch in batches {
+            let sources = SymbolColumn::new(batch, &self.source_column)?;
+            let destinations = SymbolColumn::new(batch, &self.target_column)?;
+            let weights = self
+                .weight_column
+                .as_deref()
+                .map(|column| weight_column(batch, column).map(|weights| (column, weights)))
+                .transpose()?;
+
+            for row in 0..batch.num_rows() {
+                let position = offset + row;
+                let null_value = |column: &str| {
+                    ArrowReadError::NullValue { row: position, column: column.to_string() }
+                };
+                let source = sources.symbol(row).ok_or_else(|| null_value(&self.source_column))?;
+                let destination =
+                    destinations.symbol(row).ok_or_else(|| null_value(&self.target_column))?;
+                let weight = match &weights {
+                    Some((column, weights)) => {
+                        let weight = weights(row).ok_or_else(|| null_value(column))?;
+                        if !weight.is_finite() {
+                            return Err(ArrowReadError::InvalidWeight { row: position });
+                        }
+                        weight
+                    }
+                    None => 1.0,
+                };
+                raw_edges.push(RawEdge { position, source, destination, weight });
+            }
+            offset += batch.num_rows();
+        }
+
+        IndexedEdges::new(&raw_edges, undirected, self.ignore_duplicates).map_err(|repeated| {
+            ArrowReadError::DuplicatedEdge {
+                row: repeated.position,
+                source_symbol: repeated.source_symbol,
+                destination_symbol: repeated.destination_symbol,
+            }
+        })
+    }
+
+    /// Reads a directed graph from an edge table.
+    ///
+    /// The weight column, if configured, is validated but otherwise ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `batches` - The record batches making up the edge table.
+    ///
+    /// # Errors
+    ///
+    /// * If a configured column is missing, has an unsupported data type or
+    ///   holds a null value.
+    /// * If a weight is not finite.
+    /// * If an edge is repeated and duplicates are not ignored.
+    pub fn read_digraph(&self, batches: &[RecordBatch]) -> Result<DiGraph<String>, ArrowReadError> {
+        Ok(self.index_batches(batches, false)?.into_digraph())
+    }
+
+    /// Reads an undirected graph from an edge table.
+    ///
+    /// The edges `a - b` and `b - a` are considered the same edge. The weight
+    /// column, if configured, is validated but otherwise ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `batches` - The record batches making up the edge table.
+    ///
+    /// # Errors
+    ///
+    /// * If a configured column is missing, has an unsupported data type or
+    ///   holds a null value.
+    /// * If a weight is not finite.
+    /// * If an edge is repeated and duplicates are not ignored.
+    pub fn read_undigraph(
+        &self,
+        batches: &[RecordBatch],
+    ) -> Result<UndiGraph<String>, ArrowReadError> {
+        Ok(self.index_batches(batches, true)?.into_undigraph())
+    }
+
+    /// Reads a weighted directed graph from an edge table.
+    ///
+    /// # Arguments
+    ///
+    /// * `batches` - The record batches making up the edge table.
+    ///
+    /// # Errors
+    ///
+    /// * If no weight column was configured.
+    /// * If a configured column is missing, has an unsupported data type or
+    ///   holds a null value.
+    /// * If a weight is not finite.
+    /// * If an edge is repeated and duplicates are not ignored.
+    pub fn read_weighted_digraph(
+        &self,
+        batches: &[RecordBatch],
+    ) -> Result<WeightedDiGraph<String>, ArrowReadError> {
+        if self.weight_column.is_none() {
+            return Err(ArrowReadError::MissingWeightColumn);
+        }
+        Ok(self.index_batches(batches, false)?.into_weighted_digraph())
+    }
+
+    /// Reads the record batches of a Parquet file, decoding only the
+    /// configured columns.
+    ///
+    /// The returned batches can be passed to any of the graph readers.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The Parquet file, or any other source of Parquet data.
+    ///
+    /// # Errors
+    ///
+    /// * If a configured column does not appear in the file.
+    /// * If the file cannot be decoded.
+    #[cfg(feature = "parquet")]
+    pub fn read_parquet_batches<R: parquet::file::reader::ChunkReader + 'static>(
+        &self,
+        reader: R,
+    ) -> Result<Vec<RecordBatch>, ArrowReadError> {
+        use parquet::arrow::{ProjectionMask, arrow_reader::ParquetRecordBatchReaderBuilder};
+
+        let parquet_error =
+            |error: &dyn core::fmt::Display| ArrowReadError::Parquet { message: error.to_string() };
+        let builder = ParquetRecordBatchReaderBuilder::try_new(reader)
+            .map_err(|error| parquet_error(&error))?;
+        let roots =
+            [Some(&self.source_column), Some(&self.target_column), self.weight_column.as_ref()]
+                .into_iter()
+                .flatten()
+                .map(|column| {
+                    builder
+                        .schema()
+                        .index_of(column)
+                        .map_err(|_| ArrowReadError::MissingColumn { column: column.clone() })
+                })
+                .collect::<Result<Vec<usize>, _>>()?;
+        let mask = ProjectionMask::roots(builder.parquet_schema(), roots);
+        builder
+            .with_projection(mask)
+            .build()
+            .map_err(|error| parquet_error(&error))?
+            .map(|batch| batch.map_err(|error| parquet_error(&error)))
+            .collect()
+    }
+}
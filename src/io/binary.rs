@@ -0,0 +1,383 @@
+//! Submodule providing a compact binary encoding for the CSR family of
+//! matrices.
+//!
+//! The encoding is designed to be embedded in firmware images or shipped over
+//! IPC channels, so it only requires `alloc`. Every multi-byte number is
+//! stored in little-endian order and decoded byte-wise, so the input buffer
+//! needs no particular alignment. Pointer-sized integers are always stored on
+//! eight bytes, which makes a buffer written on a 64-bit host readable on a
+//! 32-bit target as long as the values fit.
+//!
+//! An encoded buffer is laid out as follows:
+//!
+//! | Bytes | Content                                                  |
+//! |-------|----------------------------------------------------------|
+//! | 4     | The magic bytes [`BINARY_MAGIC`].                        |
+//! | 1     | The format version, currently [`BINARY_FORMAT_VERSION`]. |
+//! | *n*   | The body, starting with the layout tag of the matrix.   |
+//! | 4     | The CRC-32 (IEEE) checksum of all the preceding bytes.   |
+//!
+//! The body of a wrapper matrix, such as a `SquareCSR2D`, is its own layout
+//! tag followed by the body of the wrapped matrix. Decoding checks the
+//! checksum before anything else, and then validates the same invariants the
+//! mutable API maintains, so a corrupted buffer is rejected instead of
+//! producing an illegal matrix.
+
+use alloc::vec::Vec;
+
+use crate::errors::InvariantError;
+
+/// The magic bytes opening every encoded buffer.
+pub const BINARY_MAGIC: [u8; 4] = *b"GTCS";
+
+/// The version of the binary format written by [`BinaryEncoding::to_bytes`].
+pub const BINARY_FORMAT_VERSION: u8 = 1;
+
+/// Number of bytes of the trailing checksum.
+const CHECKSUM_WIDTH: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, thiserror::Error)]
+/// Errors raised while decoding a binary buffer.
+pub enum BinaryDecodeError {
+    /// The buffer ended before the decoder could read all the data it needs.
+    #[error("The buffer ended unexpectedly")]
+    UnexpectedEnd,
+    /// The buffer does not start with [`BINARY_MAGIC`].
+    #[error("The buffer does not start with the expected magic bytes")]
+    InvalidMagic,
+    /// The buffer was written with a format version this crate cannot read.
+    #[error("Unsupported binary format version {found}")]
+    UnsupportedVersion {
+        /// The version found in the buffer.
+        found: u8,
+    },
+    /// The checksum stored in the buffer does not match its content.
+    #[error("Checksum mismatch: stored {stored:#010x}, computed {computed:#010x}")]
+    ChecksumMismatch {
+        /// The checksum stored at the end of the buffer.
+        stored: u32,
+        /// The checksum computed from the content of the buffer.
+        computed: u32,
+    },
+    /// The buffer encodes a different kind of matrix than the one requested.
+    #[error("Unexpected layout tag {found:#04x}, expected {expected:#04x}")]
+    UnexpectedLayout {
+        /// The layout tag of the requested matrix.
+        expected: u8,
+        /// The layout tag found in the buffer.
+        found: u8,
+    },
+    /// The buffer encodes scalars of a different type than the one requested.
+    #[error("Unexpected scalar tag {found:#04x}, expected {expected:#04x}")]
+    UnexpectedScalar {
+        /// The tag of the requested scalar type.
+        expected: u8,
+        /// The scalar tag found in the buffer.
+        found: u8,
+    },
+    /// A stored value does not fit in the requested type on this target.
+    #[error("A stored value does not fit in the requested type")]
+    ValueOverflow,
+    /// The decoded parts violate an invariant of the requested matrix.
+    #[error("Invalid matrix: {0}")]
+    InvalidMatrix(#[from] InvariantError),
+    /// The body is followed by unexpected bytes.
+    #[error("The body is followed by {0} unexpected bytes")]
+    TrailingBytes(usize),
+}
+
+/// Trait for the scalars which can be stored in a binary buffer.
+pub trait BinaryScalar: Sized {
+    /// Tag identifying the scalar type in the buffer.
+    const TAG: u8;
+    /// Number of bytes used to store one scalar.
+    const WIDTH: usize;
+
+    /// Appends the little-endian representation of the scalar to the buffer.
+    fn write_le(&self, buffer: &mut Vec<u8>);
+
+    /// Reads a scalar from exactly [`WIDTH`](Self::WIDTH) little-endian bytes.
+    ///
+    /// Returns `None` if the stored value does not fit in the type.
+    fn read_le(bytes: &[u8]) -> Option<Self>;
+}
+
+macro_rules! impl_binary_scalar {
+    ($($t:ty => $tag:literal),* $(,)?) => {
+        $(
+            impl BinaryScalar for $t {
+                const TAG: u8 = $tag;
+                const WIDTH: usize = core::mem::size_of::<$t>();
+
+                #[inline]
+                fn write_le(&self, buffer: &mut Vec<u8>) {
+                    buffer.extend_from_slice(&self.to_le_bytes());
+                }
+
+                #[inline]
+                fn read_le(bytes: &[u8]) -> Option<Self> {
+                    Some(<$t>::from_le_bytes(bytes.try_into().ok()?))
+                }
+            }
+        )*
+    };
+}
+
+impl_binary_scalar!(
+    u8 => 0x01, u16 => 0x02, u32 => 0x03, u64 => 0x04, u128 => 0x05,
+    i8 => 0x11, i16 => 0x12, i32 => 0x13, i64 => 0x14, i128 => 0x15,
+    f32 => 0x23, f64 => 0x24,
+);
+
+macro_rules! impl_pointer_sized_binary_scalar {
+    ($($t:ty => $stored:ty),* $(,)?) => {
+        $(
+            impl BinaryScalar for $t {
+                const TAG: u8 = <$stored as BinaryScalar>::TAG;
+                const WIDTH: usize = <$stored as BinaryScalar>::WIDTH;
+
+                #[inline]
+                fn write_le(&self, buffer: &mut Vec<u8>) {
+                    <$stored>::try_from(*self)
+                        .expect("pointer-sized integers fit in 64 bits")
+                        .write_le(buffer);
+                }
+
+                #[inline]
+                fn read_le(bytes: &[u8]) -> Option<Self> {
+                    <$t>::try_from(<$stored>::read_le(bytes)?).ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_pointer_sized_binary_scalar!(usize => u64, isize => i64);
+
+/// Cursor over the body of a binary buffer.
+pub struct BinaryReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> BinaryReader<'a> {
+    /// Creates a new reader over the provided bytes.
+    #[inline]
+    #[must_use]
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    /// Returns the number of bytes left to read.
+    #[inline]
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.bytes.len()
+    }
+
+    fn take(&mut self, number_of_bytes: usize) -> Result<&'a [u8], BinaryDecodeError> {
+        if number_of_bytes > self.bytes.len() {
+            return Err(BinaryDecodeError::UnexpectedEnd);
+        }
+        let (head, tail) = self.bytes.split_at(number_of_bytes);
+        self.bytes = tail;
+        Ok(head)
+    }
+
+    fn read_byte(&mut self) -> Result<u8, BinaryDecodeError> {
+        Ok(self.take(1)?[0])
+    }
+
+    /// Consumes a layout tag, checking that it matches the expected one.
+    ///
+    /// # Errors
+    ///
+    /// * [`BinaryDecodeError::UnexpectedEnd`] if the buffer is exhausted.
+    /// * [`BinaryDecodeError::UnexpectedLayout`] if the tag differs.
+    #[inline]
+    pub fn expect_layout(&mut self, expected: u8) -> Result<(), BinaryDecodeError> {
+        let found = self.read_byte()?;
+        if found != expected {
+            return Err(BinaryDecodeError::UnexpectedLayout { expected, found });
+        }
+        Ok(())
+    }
+
+    /// Consumes a scalar tag, checking that it matches the one of `T`.
+    ///
+    /// # Errors
+    ///
+    /// * [`BinaryDecodeError::UnexpectedEnd`] if the buffer is exhausted.
+    /// * [`BinaryDecodeError::UnexpectedScalar`] if the tag differs.
+    #[inline]
+    pub fn expect_scalar<T: BinaryScalar>(&mut self) -> Result<(), BinaryDecodeError> {
+        let found = self.read_byte()?;
+        if found != T::TAG {
+            return Err(BinaryDecodeError::UnexpectedScalar { expected: T::TAG, found });
+        }
+        Ok(())
+    }
+
+    /// Reads a single scalar.
+    ///
+    /// # Errors
+    ///
+    /// * [`BinaryDecodeError::UnexpectedEnd`] if the buffer is exhausted.
+    /// * [`BinaryDecodeError::ValueOverflow`] if the value does not fit in `T`.
+    #[inline]
+    pub fn read_scalar<T: BinaryScalar>(&mut self) -> Result<T, BinaryDecodeError> {
+        T::read_le(self.take(T::WIDTH)?).ok_or(BinaryDecodeError::ValueOverflow)
+    }
+
+    /// Reads a length prefix followed by as many scalars.
+    ///
+    /// The length is checked against the remaining bytes before allocating,
+    /// so a corrupted prefix cannot trigger a huge allocation.
+    ///
+    /// # Errors
+    ///
+    /// * [`BinaryDecodeError::UnexpectedEnd`] if the buffer is too short.
+    /// * [`BinaryDecodeError::ValueOverflow`] if a value does not fit in `T`.
+    #[inline]
+    pub fn read_scalars<T: BinaryScalar>(&mut self) -> Result<Vec<T>, BinaryDecodeError> {
+        let length: usize = self.read_scalar()?;
+        let number_of_bytes =
+            length.checked_mul(T::WIDTH).ok_or(BinaryDecodeError::UnexpectedEnd)?;
+        self.take(number_of_bytes)?
+            .chunks_exact(T::WIDTH)
+            .map(|chunk| T::read_le(chunk).ok_or(BinaryDecodeError::ValueOverflow))
+            .collect()
+    }
+}
+
+/// Appends a length prefix followed by the scalars to the buffer.
+#[inline]
+pub fn write_scalars<T: BinaryScalar>(scalars: &[T], buffer: &mut Vec<u8>) {
+    scalars.len().write_le(buffer);
+    for scalar in scalars {
+        scalar.write_le(buffer);
+    }
+}
+
+/// Trait for the matrices which can be encoded in the binary format.
+///
+/// Implementors only provide the encoding of their body, while the header
+/// and the checksum are handled by the provided methods.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{io::BinaryEncoding, prelude::*};
+///
+/// let mut matrix: CSR2D<u32, u16, u16> = SparseMatrixMut::with_sparse_shape((3, 4));
+/// MatrixMut::add(&mut matrix, (0, 1)).unwrap();
+/// MatrixMut::add(&mut matrix, (2, 3)).unwrap();
+///
+/// let bytes = matrix.to_bytes();
+/// let decoded = CSR2D::<u32, u16, u16>::from_bytes(&bytes).unwrap();
+/// assert_eq!(decoded, matrix);
+///
+/// // A single flipped bit is caught by the checksum.
+/// let mut corrupted = bytes.clone();
+/// corrupted[8] ^= 1;
+/// assert!(CSR2D::<u32, u16, u16>::from_bytes(&corrupted).is_err());
+/// ```
+pub trait BinaryEncoding: Sized {
+    /// Appends the body of the matrix, starting with its layout tag.
+    fn write_body(&self, buffer: &mut Vec<u8>);
+
+    /// Reads and validates the body of the matrix.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BinaryDecodeError`] if the body is malformed or describes
+    /// an illegal matrix.
+    fn read_body(reader: &mut BinaryReader<'_>) -> Result<Self, BinaryDecodeError>;
+
+    /// Encodes the matrix, including the header and the checksum.
+    #[inline]
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        buffer.extend_from_slice(&BINARY_MAGIC);
+        buffer.push(BINARY_FORMAT_VERSION);
+        self.write_body(&mut buffer);
+        let checksum = crc32(&buffer);
+        checksum.write_le(&mut buffer);
+        buffer
+    }
+
+    /// Decodes a matrix from a buffer written by [`to_bytes`](Self::to_bytes).
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BinaryDecodeError`] if the buffer is truncated, corrupted,
+    /// written by an unsupported version of the format, encodes a different
+    /// kind of matrix, or describes an illegal matrix.
+    #[inline]
+    fn from_bytes(bytes: &[u8]) -> Result<Self, BinaryDecodeError> {
+        let header_width = BINARY_MAGIC.len() + 1;
+        if bytes.len() < header_width + CHECKSUM_WIDTH {
+            return Err(BinaryDecodeError::UnexpectedEnd);
+        }
+        if bytes[..BINARY_MAGIC.len()] != BINARY_MAGIC {
+            return Err(BinaryDecodeError::InvalidMagic);
+        }
+        let (content, stored) = bytes.split_at(bytes.len() - CHECKSUM_WIDTH);
+        let stored = u32::read_le(stored).ok_or(BinaryDecodeError::UnexpectedEnd)?;
+        let computed = crc32(content);
+        if stored != computed {
+            return Err(BinaryDecodeError::ChecksumMismatch { stored, computed });
+        }
+        let version = content[BINARY_MAGIC.len()];
+        if version != BINARY_FORMAT_VERSION {
+            return Err(BinaryDecodeError::UnsupportedVersion { found: version });
+        }
+
+        let mut reader = BinaryReader::new(&content[header_width..]);
+        let matrix = Self::read_body(&mut reader)?;
+        if reader.remaining() > 0 {
+            return Err(BinaryDecodeError::TrailingBytes(reader.remaining()));
+        }
+        Ok(matrix)
+    }
+}
+
+/// Computes the CRC-32 (IEEE 802.3) checksum of the provided bytes.
+pub(super) fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = u32::MAX;
+    for &byte in bytes {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crc32_matches_reference_value() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn test_pointer_sized_scalars_use_eight_bytes() {
+        let mut buffer = Vec::new();
+        7_usize.write_le(&mut buffer);
+        assert_eq!(buffer, [7, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(usize::read_le(&buffer), Some(7));
+        assert_eq!(<usize as BinaryScalar>::TAG, <u64 as BinaryScalar>::TAG);
+    }
+
+    #[test]
+    fn test_reader_rejects_oversized_length_prefix() {
+        let mut buffer = Vec::new();
+        u64::MAX.write_le(&mut buffer);
+        let mut reader = BinaryReader::new(&buffer);
+        assert_eq!(reader.read_scalars::<u32>(), Err(BinaryDecodeError::UnexpectedEnd));
+    }
+}
@@ -0,0 +1,578 @@
+//! Submodule providing a reader for delimited (CSV, TSV, ...) edge lists.
+//!
+//! Each non-empty, non-comment line describes one edge. The source and target
+//! columns hold node symbols, and an optional weight column holds an `f64`
+//! weight. Node symbols are collected into a sorted vocabulary, so the node
+//! identifiers of the resulting graph follow the lexicographic order of the
+//! symbols rather than the order in which they appear in the file.
+
+use std::{
+    io::BufRead,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use super::edge_index::{IndexedEdges, RawEdge};
+use crate::naive_structs::{DiGraph, UndiGraph, WeightedDiGraph};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Identifies a column of a delimited file.
+pub enum DelimitedColumn {
+    /// Zero-based position of the column.
+    Index(usize),
+    /// Name of the column, as it appears in the header line.
+    Name(String),
+}
+
+impl From<usize> for DelimitedColumn {
+    #[inline]
+    fn from(index: usize) -> Self {
+        Self::Index(index)
+    }
+}
+
+impl From<&str> for DelimitedColumn {
+    #[inline]
+    fn from(name: &str) -> Self {
+        Self::Name(name.to_string())
+    }
+}
+
+impl From<String> for DelimitedColumn {
+    #[inline]
+    fn from(name: String) -> Self {
+        Self::Name(name)
+    }
+}
+
+impl core::fmt::Display for DelimitedColumn {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Index(index) => write!(f, "#{index}"),
+            Self::Name(name) => write!(f, "`{name}`"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+/// Errors raised while reading a delimited edge list.
+///
+/// Every variant referring to the content of the file carries the one-based
+/// line number where the problem was detected.
+pub enum DelimitedReadError {
+    /// The underlying reader failed.
+    #[error("I/O error of kind {kind:?} while reading line {line}")]
+    Io {
+        /// The line that was being read.
+        line: usize,
+        /// The kind of the I/O error.
+        kind: std::io::ErrorKind,
+    },
+    /// A column was requested by name, but the reader was configured without
+    /// a header line.
+    #[error("Column {column} is referenced by name, but the reader expects no header")]
+    NamedColumnWithoutHeader {
+        /// The column referenced by name.
+        column: DelimitedColumn,
+    },
+    /// The reader expected a header line, but the file contains no records.
+    #[error("Expected a header line, but the input is empty")]
+    MissingHeader,
+    /// A named column does not appear in the header line.
+    #[error("Column {column} does not appear in the header on line {line}")]
+    UnknownColumn {
+        /// The line of the header.
+        line: usize,
+        /// The column that could not be found.
+        column: DelimitedColumn,
+    },
+    /// A record has fewer fields than required by the configured columns.
+    #[error("Line {line} has no column {column}")]
+    MissingField {
+        /// The line of the truncated record.
+        line: usize,
+        /// The column that is missing from the record.
+        column: DelimitedColumn,
+    },
+    /// A quoted field is not terminated before the end of the line.
+    #[error("Line {line} contains an unterminated quoted field")]
+    UnterminatedQuote {
+        /// The line of the malformed record.
+        line: usize,
+    },
+    /// A weight could not be parsed as a finite floating point number.
+    #[error("Line {line} has an invalid weight `{value}`")]
+    InvalidWeight {
+        /// The line of the malformed record.
+        line: usize,
+        /// The raw content of the weight field.
+        value: String,
+    },
+    /// A weighted graph was requested, but no weight column was configured.
+    #[error("A weighted graph was requested, but no weight column was configured")]
+    MissingWeightColumn,
+    /// The same edge appears more than once, and duplicates are not ignored.
+    #[error("Line {line} repeats the edge from `{source_symbol}` to `{destination_symbol}`")]
+    DuplicatedEdge {
+        /// The line of the repeated edge.
+        line: usize,
+        /// The symbol of the source node.
+        source_symbol: String,
+        /// The symbol of the destination node.
+        destination_symbol: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A single edge read from a delimited file.
+pub struct DelimitedRecord {
+    /// The one-based line number of the record.
+    pub line: usize,
+    /// The symbol of the source node.
+    pub source: String,
+    /// The symbol of the destination node.
+    pub destination: String,
+    /// The weight of the edge, if a weight column was configured.
+    pub weight: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Reader of delimited edge lists, configured with a builder-style API.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{io::DelimitedEdgeListReader, prelude::*};
+///
+/// let data = "\
+/// % metabolite correlations
+/// source,target,weight
+/// alanine,glycine,0.5
+/// glycine,serine,0.25
+/// ";
+///
+/// let graph = DelimitedEdgeListReader::csv()
+///     .has_header(true)
+///     .comment_prefix("%")
+///     .source_column("source")
+///     .target_column("target")
+///     .weight_column("weight")
+///     .read_weighted_digraph(data.as_bytes())
+///     .unwrap();
+///
+/// assert_eq!(graph.number_of_nodes(), 3);
+/// assert_eq!(graph.number_of_edges(), 2);
+/// let alanine = graph.nodes_vocabulary().invert(&"alanine".to_string()).unwrap();
+/// assert_eq!(graph.successor_weights(alanine).collect::<Vec<f64>>(), vec![0.5]);
+/// ```
+pub struct DelimitedEdgeListReader {
+    /// The character separating fields.
+    delimiter: char,
+    /// The character used to quote fields, if any.
+    quote: Option<char>,
+    /// Whether the first non-comment line is a header.
+    has_header: bool,
+    /// Prefix marking comment lines, if any.
+    comment_prefix: Option<String>,
+    /// Whether to trim whitespace around unquoted fields.
+    trim: bool,
+    /// Whether to silently drop repeated edges.
+    ignore_duplicates: bool,
+    /// The column holding the source symbols.
+    source_column: DelimitedColumn,
+    /// The column holding the destination symbols.
+    target_column: DelimitedColumn,
+    /// The column holding the weights, if any.
+    weight_column: Option<DelimitedColumn>,
+}
+
+impl Default for DelimitedEdgeListReader {
+    #[inline]
+    fn default() -> Self {
+        Self::csv()
+    }
+}
+
+/// Column positions resolved against the header, if any.
+struct ResolvedColumns {
+    source: usize,
+    target: usize,
+    weight: Option<usize>,
+}
+
+impl DelimitedEdgeListReader {
+    #[must_use]
+    /// Returns a reader for comma-separated files without a header, reading
+    /// the source from the first column and the target from the second.
+    #[inline]
+    pub fn csv() -> Self {
+        Self {
+            delimiter: ',',
+            quote: Some('"'),
+            has_header: false,
+            comment_prefix: None,
+            trim: true,
+            ignore_duplicates: false,
+            source_column: DelimitedColumn::Index(0),
+            target_column: DelimitedColumn::Index(1),
+            weight_column: None,
+        }
+    }
+
+    #[must_use]
+    /// Returns a reader for tab-separated files without a header, reading
+    /// the source from the first column and the target from the second.
+    #[inline]
+    pub fn tsv() -> Self {
+        Self { delimiter: '\t', ..Self::csv() }
+    }
+
+    #[must_use]
+    /// Sets the character separating fields.
+    #[inline]
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    #[must_use]
+    /// Sets the character used to quote fields, or disables quoting.
+    ///
+    /// Within a quoted field, the quote character is escaped by doubling it.
+    #[inline]
+    pub fn quote(mut self, quote: Option<char>) -> Self {
+        self.quote = quote;
+        self
+    }
+
+    #[must_use]
+    /// Sets whether the first non-comment line is a header.
+    #[inline]
+    pub fn has_header(mut self, has_header: bool) -> Self {
+        self.has_header = has_header;
+        self
+    }
+
+    #[must_use]
+    /// Sets the prefix marking comment lines, which are skipped entirely.
+    #[inline]
+    pub fn comment_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.comment_prefix = Some(prefix.into());
+        self
+    }
+
+    #[must_use]
+    /// Sets whether whitespace around unquoted fields is trimmed.
+    #[inline]
+    pub fn trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    #[must_use]
+    /// Silently drops repeated edges instead of reporting them as errors.
+    ///
+    /// For weighted graphs, the weight of the first occurrence is kept.
+    #[inline]
+    pub fn ignore_duplicates(mut self) -> Self {
+        self.ignore_duplicates = true;
+        self
+    }
+
+    #[must_use]
+    /// Sets the column holding the source symbols.
+    #[inline]
+    pub fn source_column(mut self, column: impl Into<DelimitedColumn>) -> Self {
+        self.source_column = column.into();
+        self
+    }
+
+    #[must_use]
+    /// Sets the column holding the destination symbols.
+    #[inline]
+    pub fn target_column(mut self, column: impl Into<DelimitedColumn>) -> Self {
+        self.target_column = column.into();
+        self
+    }
+
+    #[must_use]
+    /// Sets the column holding the edge weights.
+    #[inline]
+    pub fn weight_column(mut self, column: impl Into<DelimitedColumn>) -> Self {
+        self.weight_column = Some(column.into());
+        self
+    }
+
+    /// Splits a line into its fields, honouring the quoting configuration.
+    fn split_fields(
+        &self,
+        line: &str,
+        line_number: usize,
+    ) -> Result<Vec<String>, DelimitedReadError> {
+        let mut fields = Vec::new();
+        let mut chars = line.chars().peekable();
+        loop {
+            let mut field = String::new();
+            let mut quoted = false;
+            // Leading whitespace before an opening quote is not significant.
+            if self.trim {
+                while chars.peek().is_some_and(|c| *c != self.delimiter && c.is_whitespace()) {
+                    chars.next();
+                }
+            }
+            if let Some(quote) = self.quote
+                && chars.peek() == Some(&quote)
+            {
+                quoted = true;
+                chars.next();
+                loop {
+                    match chars.next() {
+                        Some(c) if c == quote => {
+                            if chars.peek() == Some(&quote) {
+                                chars.next();
+                                field.push(quote);
+                            } else {
+                                break;
+                            }
+                        }
+                        Some(c) => field.push(c),
+                        None => {
+                            return Err(DelimitedReadError::UnterminatedQuote {
+                                line: line_number,
+                            });
+                        }
+                    }
+                }
+            }
+            let mut trailing = String::new();
+            let mut reached_delimiter = false;
+            for c in chars.by_ref() {
+                if c == self.delimiter {
+                    reached_delimiter = true;
+                    break;
+                }
+                trailing.push(c);
+            }
+            if quoted {
+                // Anything after the closing quote, besides whitespace, is kept
+                // verbatim so that malformed input is not silently altered.
+                let trailing = if self.trim { trailing.trim() } else { trailing.as_str() };
+                field.push_str(trailing);
+            } else if self.trim {
+                field.push_str(trailing.trim());
+            } else {
+                field = trailing;
+            }
+            fields.push(field);
+            if !reached_delimiter {
+                return Ok(fields);
+            }
+        }
+    }
+
+    /// Resolves a column against the header, if any.
+    fn resolve_column(
+        column: &DelimitedColumn,
+        header: Option<(usize, &[String])>,
+    ) -> Result<usize, DelimitedReadError> {
+        match (column, header) {
+            (DelimitedColumn::Index(index), _) => Ok(*index),
+            (DelimitedColumn::Name(name), Some((line, header))) => {
+                header.iter().position(|field| field == name).ok_or_else(|| {
+                    DelimitedReadError::UnknownColumn { line, column: column.clone() }
+                })
+            }
+            (DelimitedColumn::Name(_), None) => {
+                Err(DelimitedReadError::NamedColumnWithoutHeader { column: column.clone() })
+            }
+        }
+    }
+
+    fn resolve_columns(
+        &self,
+        header: Option<(usize, &[String])>,
+    ) -> Result<ResolvedColumns, DelimitedReadError> {
+        Ok(ResolvedColumns {
+            source: Self::resolve_column(&self.source_column, header)?,
+            target: Self::resolve_column(&self.target_column, header)?,
+            weight: self
+                .weight_column
+                .as_ref()
+                .map(|column| Self::resolve_column(column, header))
+                .transpose()?,
+        })
+    }
+
+    /// Reads all the records of a delimited edge list.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The source of the delimited data.
+    ///
+    /// # Errors
+    ///
+    /// * If the underlying reader fails.
+    /// * If the header or a named column cannot be found.
+    /// * If a record lacks one of the configured columns.
+    /// * If a weight cannot be parsed as a finite `f64`.
+    pub fn read_records<R: BufRead>(
+        &self,
+        reader: R,
+    ) -> Result<Vec<DelimitedRecord>, DelimitedReadError> {
+        let mut columns = if self.has_header { None } else { Some(self.resolve_columns(None)?) };
+        let mut records = Vec::new();
+
+        for (line_index, line) in reader.lines().enumerate() {
+            let line_number = line_index + 1;
+            let line = line.map_err(|error| {
+                DelimitedReadError::Io { line: line_number, kind: error.kind() }
+            })?;
+            let content = line.strip_suffix('\r').unwrap_or(&line);
+            if content.trim().is_empty()
+                || self.comment_prefix.as_deref().is_some_and(|prefix| content.starts_with(prefix))
+            {
+                continue;
+            }
+
+            let fields = self.split_fields(content, line_number)?;
+            let Some(resolved) = columns.as_ref() else {
+                columns = Some(self.resolve_columns(Some((line_number, &fields)))?);
+                continue;
+            };
+
+            let field = |index: usize, column: &DelimitedColumn| {
+                fields.get(index).ok_or_else(|| {
+                    DelimitedReadError::MissingField { line: line_number, column: column.clone() }
+                })
+            };
+
+            let source = field(resolved.source, &self.source_column)?.clone();
+            let destination = field(resolved.target, &self.target_column)?.clone();
+            let weight = match (resolved.weight, self.weight_column.as_ref()) {
+                (Some(index), Some(column)) => {
+                    let raw = field(index, column)?;
+                    match raw.parse::<f64>() {
+                        Ok(weight) if weight.is_finite() => Some(weight),
+                        _ => {
+                            return Err(DelimitedReadError::InvalidWeight {
+                                line: line_number,
+                                value: raw.clone(),
+                            });
+                        }
+                    }
+                }
+                _ => None,
+            };
+
+            records.push(DelimitedRecord { line: line_number, source, destination, weight });
+        }
+
+        if columns.is_none() {
+            return Err(DelimitedReadError::MissingHeader);
+        }
+
+        Ok(records)
+    }
+
+    /// Indexes the records, reporting repeated edges unless they are ignored.
+    fn index_records(
+        &self,
+        records: &[DelimitedRecord],
+        undirected: bool,
+    ) -> Result<IndexedEdges<String>, DelimitedReadError> {
+        let raw_edges: Vec<RawEdge<'_, str>> = records
+            .iter()
+            .map(|record| {
+                RawEdge {
+                    position: record.line,
+                    source: record.source.as_str(),
+                    destination: record.destination.as_str(),
+                    weight: record.weight.unwrap_or(1.0),
+                }
+            })
+            .collect();
+        IndexedEdges::new(&raw_edges, undirected, self.ignore_duplicates).map_err(|repeated| {
+            DelimitedReadError::DuplicatedEdge {
+                line: repeated.position,
+                source_symbol: repeated.source_symbol,
+                destination_symbol: repeated.destination_symbol,
+            }
+        })
+    }
+
+    /// Reads a directed graph from a delimited edge list.
+    ///
+    /// The weight column, if configured, is validated but otherwise ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The source of the delimited data.
+    ///
+    /// # Errors
+    ///
+    /// * If the records cannot be read, see
+    ///   [`read_records`](Self::read_records).
+    /// * If an edge is repeated and duplicates are not ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{io::DelimitedEdgeListReader, prelude::*};
+    ///
+    /// let graph = DelimitedEdgeListReader::tsv().read_digraph("b\ta\na\tc\n".as_bytes()).unwrap();
+    ///
+    /// assert_eq!(graph.number_of_nodes(), 3);
+    /// assert!(graph.has_successor(1, 0));
+    /// assert!(graph.has_successor(0, 2));
+    /// ```
+    pub fn read_digraph<R: BufRead>(
+        &self,
+        reader: R,
+    ) -> Result<DiGraph<String>, DelimitedReadError> {
+        Ok(self.index_records(&self.read_records(reader)?, false)?.into_digraph())
+    }
+
+    /// Reads an undirected graph from a delimited edge list.
+    ///
+    /// The edges `a - b` and `b - a` are considered the same edge. The weight
+    /// column, if configured, is validated but otherwise ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The source of the delimited data.
+    ///
+    /// # Errors
+    ///
+    /// * If the records cannot be read, see
+    ///   [`read_records`](Self::read_records).
+    /// * If an edge is repeated and duplicates are not ignored.
+    pub fn read_undigraph<R: BufRead>(
+        &self,
+        reader: R,
+    ) -> Result<UndiGraph<String>, DelimitedReadError> {
+        Ok(self.index_records(&self.read_records(reader)?, true)?.into_undigraph())
+    }
+
+    /// Reads a weighted directed graph from a delimited edge list.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The source of the delimited data.
+    ///
+    /// # Errors
+    ///
+    /// * If no weight column was configured.
+    /// * If the records cannot be read, see
+    ///   [`read_records`](Self::read_records).
+    /// * If an edge is repeated and duplicates are not ignored.
+    pub fn read_weighted_digraph<R: BufRead>(
+        &self,
+        reader: R,
+    ) -> Result<WeightedDiGraph<String>, DelimitedReadError> {
+        if self.weight_column.is_none() {
+            return Err(DelimitedReadError::MissingWeightColumn);
+        }
+        Ok(self.index_records(&self.read_records(reader)?, false)?.into_weighted_digraph())
+    }
+}
@@ -0,0 +1,161 @@
+//! Submodule providing the indexing shared by the edge-list readers.
+//!
+//! Readers collect raw edges holding node symbols, and this submodule turns
+//! them into a sorted vocabulary and sorted, deduplicated edges which the
+//! one-shot edges builders always accept.
+
+use std::{borrow::ToOwned, vec::Vec};
+
+use crate::{
+    impls::{SortedVec, ValuedCSR2D},
+    naive_structs::{
+        DiEdgesBuilder, DiGraph, GenericEdgesBuilder, UndiEdgesBuilder, UndiGraph, WeightedDiGraph,
+    },
+    traits::EdgesBuilder,
+};
+
+/// An edge read from an edge list, before its symbols are indexed.
+pub(super) struct RawEdge<'a, S: ?Sized> {
+    /// The position of the edge in the input, reported in errors.
+    pub(super) position: usize,
+    /// The symbol of the source node.
+    pub(super) source: &'a S,
+    /// The symbol of the destination node.
+    pub(super) destination: &'a S,
+    /// The weight of the edge.
+    pub(super) weight: f64,
+}
+
+/// An edge which appears more than once in an edge list.
+pub(super) struct RepeatedEdge<Symbol> {
+    /// The position of the second occurrence of the edge.
+    pub(super) position: usize,
+    /// The symbol of the source node.
+    pub(super) source_symbol: Symbol,
+    /// The symbol of the destination node.
+    pub(super) destination_symbol: Symbol,
+}
+
+/// Sorted node vocabulary together with the sorted, deduplicated edges.
+pub(super) struct IndexedEdges<Symbol> {
+    nodes: SortedVec<Symbol>,
+    edges: Vec<(usize, usize, f64)>,
+}
+
+impl<Symbol: crate::traits::Symbol + Ord> IndexedEdges<Symbol> {
+    /// Builds the sorted node vocabulary and the sorted, deduplicated edges.
+    ///
+    /// When `undirected` is set, each edge is normalized so that its source
+    /// identifier is not greater than its destination identifier. Repeated
+    /// edges keep their first occurrence when `ignore_duplicates` is set, and
+    /// are reported otherwise.
+    pub(super) fn new<S: ?Sized + Ord + ToOwned<Owned = Symbol>>(
+        raw_edges: &[RawEdge<'_, S>],
+        undirected: bool,
+        ignore_duplicates: bool,
+    ) -> Result<Self, RepeatedEdge<Symbol>> {
+        Self::with_nodes(core::iter::empty(), raw_edges, undirected, ignore_duplicates)
+    }
+
+    /// Builds the sorted node vocabulary and the sorted, deduplicated edges,
+    /// as [`new`](Self::new) does, additionally including the provided nodes
+    /// in the vocabulary even if no edge touches them.
+    pub(super) fn with_nodes<'a, S: ?Sized + Ord + ToOwned<Owned = Symbol> + 'a>(
+        nodes: impl IntoIterator<Item = &'a S>,
+        raw_edges: &[RawEdge<'a, S>],
+        undirected: bool,
+        ignore_duplicates: bool,
+    ) -> Result<Self, RepeatedEdge<Symbol>> {
+        let mut symbols: Vec<&S> = nodes
+            .into_iter()
+            .chain(raw_edges.iter().flat_map(|edge| [edge.source, edge.destination]))
+            .collect();
+        symbols.sort_unstable();
+        symbols.dedup();
+
+        let index_of = |symbol: &S| {
+            symbols.binary_search(&symbol).unwrap_or_else(|_| {
+                unreachable!("Every edge symbol was inserted in the vocabulary.")
+            })
+        };
+
+        let mut edges: Vec<(usize, usize, usize, f64)> = raw_edges
+            .iter()
+            .map(|edge| {
+                let mut source = index_of(edge.source);
+                let mut destination = index_of(edge.destination);
+                if undirected && source > destination {
+                    core::mem::swap(&mut source, &mut destination);
+                }
+                (source, destination, edge.position, edge.weight)
+            })
+            .collect();
+        // Sorting by position as well keeps the first occurrence of each edge
+        // first, and makes the keys distinct so that the order does not
+        // depend on how the sort is carried out.
+        let edge_key = |&(source, destination, position, _): &(usize, usize, usize, f64)| {
+            (source, destination, position)
+        };
+        #[cfg(not(feature = "parallel"))]
+        edges.sort_unstable_by_key(edge_key);
+        #[cfg(feature = "parallel")]
+        rayon::slice::ParallelSliceMut::par_sort_unstable_by_key(edges.as_mut_slice(), edge_key);
+
+        let mut deduplicated: Vec<(usize, usize, f64)> = Vec::with_capacity(edges.len());
+        for (source, destination, position, weight) in edges {
+            if deduplicated.last().is_some_and(|&(last_source, last_destination, _)| {
+                (last_source, last_destination) == (source, destination)
+            }) {
+                if ignore_duplicates {
+                    continue;
+                }
+                return Err(RepeatedEdge {
+                    position,
+                    source_symbol: symbols[source].to_owned(),
+                    destination_symbol: symbols[destination].to_owned(),
+                });
+            }
+            deduplicated.push((source, destination, weight));
+        }
+
+        let nodes = SortedVec::try_from(symbols.into_iter().map(S::to_owned).collect::<Vec<_>>())
+            .unwrap_or_else(|_| unreachable!("The symbols were sorted above."));
+
+        Ok(Self { nodes, edges: deduplicated })
+    }
+
+    /// Returns the directed graph described by the edges, ignoring weights.
+    pub(super) fn into_digraph(self) -> DiGraph<Symbol> {
+        let edges = DiEdgesBuilder::default()
+            .expected_number_of_edges(self.edges.len())
+            .expected_shape(self.nodes.len())
+            .edges(self.edges.into_iter().map(|(source, destination, _)| (source, destination)))
+            .build()
+            .expect("Sorted and deduplicated edges are always accepted by the builder.");
+        DiGraph::from((self.nodes, edges))
+    }
+
+    /// Returns the undirected graph described by the edges, ignoring weights.
+    ///
+    /// The edges must have been indexed as undirected.
+    pub(super) fn into_undigraph(self) -> UndiGraph<Symbol> {
+        let edges = UndiEdgesBuilder::default()
+            .expected_number_of_edges(self.edges.len())
+            .expected_shape(self.nodes.len())
+            .edges(self.edges.into_iter().map(|(source, destination, _)| (source, destination)))
+            .build()
+            .expect("Sorted and deduplicated edges are always accepted by the builder.");
+        UndiGraph::from((self.nodes, edges))
+    }
+
+    /// Returns the weighted directed graph described by the edges.
+    pub(super) fn into_weighted_digraph(self) -> WeightedDiGraph<Symbol> {
+        let edges = GenericEdgesBuilder::<_, ValuedCSR2D<usize, usize, usize, f64>>::default()
+            .expected_number_of_edges(self.edges.len())
+            .expected_shape((self.nodes.len(), self.nodes.len()))
+            .edges(self.edges.into_iter())
+            .build()
+            .expect("Sorted and deduplicated edges are always accepted by the builder.");
+        WeightedDiGraph::from((self.nodes, edges))
+    }
+}
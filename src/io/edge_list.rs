@@ -0,0 +1,234 @@
+//! Submodule providing a symbol-preserving, tab-separated edge-list format.
+//!
+//! Each edge is written on its own line as the symbol of its source, the
+//! symbol of its destination and its weight, separated by tabs. Nodes without
+//! any edge are written on a line of their own holding only their symbol, so
+//! that the vocabulary survives the round-trip. With `<TAB>` standing for the
+//! tab character:
+//!
+//! ```text
+//! alanine<TAB>glycine<TAB>0.5
+//! glycine<TAB>serine<TAB>0.25
+//! taurine
+//! ```
+//!
+//! Symbols are written verbatim and weights in their shortest form which
+//! parses back to the same value, so reading a written graph yields the same
+//! vocabulary and the same weighted edges.
+
+use std::{
+    fmt::Display,
+    io::{BufRead, Write},
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use super::edge_index::{IndexedEdges, RawEdge};
+use crate::{
+    naive_structs::WeightedDiGraph,
+    traits::{MonopartiteGraph, MonoplexGraph, Symbol, WeightedMonoplexGraph},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+/// Errors raised while reading a tab-separated edge list.
+///
+/// Every variant referring to the content of the file carries the one-based
+/// line number where the problem was detected.
+pub enum EdgeListReadError {
+    /// The underlying reader failed.
+    #[error("I/O error of kind {kind:?} while reading line {line}")]
+    Io {
+        /// The line that was being read.
+        line: usize,
+        /// The kind of the I/O error.
+        kind: std::io::ErrorKind,
+    },
+    /// A line holds neither a single node nor an edge with its weight.
+    #[error("Line {line} has {fields} tab-separated fields, expected 1 or 3")]
+    InvalidFieldCount {
+        /// The malformed line.
+        line: usize,
+        /// The number of tab-separated fields of the line.
+        fields: usize,
+    },
+    /// A weight could not be parsed as a floating point number.
+    #[error("Line {line} has an invalid weight `{value}`")]
+    InvalidWeight {
+        /// The line of the malformed weight.
+        line: usize,
+        /// The raw weight.
+        value: String,
+    },
+    /// The same edge appears more than once.
+    #[error("Line {line} repeats the edge from `{source_symbol}` to `{destination_symbol}`")]
+    DuplicatedEdge {
+        /// The line of the repeated edge.
+        line: usize,
+        /// The symbol of the source node.
+        source_symbol: String,
+        /// The symbol of the destination node.
+        destination_symbol: String,
+    },
+}
+
+#[derive(Debug, thiserror::Error)]
+/// Errors raised while writing a tab-separated edge list.
+pub enum EdgeListWriteError {
+    /// A symbol is empty or contains a tab or a line break, which the format
+    /// cannot represent.
+    #[error("The symbol `{symbol}` of node {node} cannot be written in a tab-separated edge list")]
+    UnrepresentableSymbol {
+        /// The identifier of the node.
+        node: usize,
+        /// The symbol of the node.
+        symbol: String,
+    },
+    /// The writer raised an error.
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+/// Writes a weighted directed graph as a tab-separated edge list.
+///
+/// Edges are written sorted by source and destination identifier, followed by
+/// the nodes without any incoming or outgoing edge.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to write.
+/// * `writer` - The destination of the edge list.
+///
+/// # Errors
+///
+/// * If a symbol is empty or contains a tab or a line break.
+/// * If the writer raises an error.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{
+///     io::{read_edge_list, write_edge_list},
+///     prelude::*,
+/// };
+///
+/// let graph =
+///     read_edge_list("glycine\tserine\t0.25\nalanine\tglycine\t0.5\ntaurine\n".as_bytes())
+///         .unwrap();
+///
+/// let mut edge_list = Vec::new();
+/// write_edge_list(&graph, &mut edge_list).unwrap();
+/// assert_eq!(
+///     String::from_utf8(edge_list.clone()).unwrap(),
+///     "alanine\tglycine\t0.5\nglycine\tserine\t0.25\ntaurine\n"
+/// );
+/// assert_eq!(read_edge_list(edge_list.as_slice()).unwrap(), graph);
+/// ```
+pub fn write_edge_list<S, W>(
+    graph: &WeightedDiGraph<S>,
+    mut writer: W,
+) -> Result<(), EdgeListWriteError>
+where
+    S: Symbol + Ord + Display,
+    W: Write,
+{
+    let symbols = graph
+        .nodes_vocabulary()
+        .iter()
+        .enumerate()
+        .map(|(node, symbol)| {
+            let symbol = symbol.to_string();
+            if symbol.is_empty() || symbol.contains(['\t', '\n', '\r']) {
+                return Err(EdgeListWriteError::UnrepresentableSymbol { node, symbol });
+            }
+            Ok(symbol)
+        })
+        .collect::<Result<Vec<String>, _>>()?;
+
+    let mut isolated = vec![true; symbols.len()];
+    for source in graph.node_ids() {
+        for (destination, weight) in graph.successors(source).zip(graph.successor_weights(source)) {
+            isolated[source] = false;
+            isolated[destination] = false;
+            writeln!(writer, "{}\t{}\t{weight}", symbols[source], symbols[destination])?;
+        }
+    }
+    for (symbol, _) in symbols.iter().zip(isolated).filter(|&(_, isolated)| isolated) {
+        writeln!(writer, "{symbol}")?;
+    }
+    Ok(())
+}
+
+/// Reads a weighted directed graph from a tab-separated edge list.
+///
+/// Empty lines are skipped. The node vocabulary holds every symbol found in
+/// the file, sorted, so reading the output of [`write_edge_list`] yields the
+/// graph that was written.
+///
+/// # Arguments
+///
+/// * `reader` - The source of the edge list.
+///
+/// # Errors
+///
+/// * If the reader raises an error.
+/// * If a line has neither one nor three tab-separated fields.
+/// * If a weight cannot be parsed.
+/// * If an edge appears more than once.
+pub fn read_edge_list<R: BufRead>(reader: R) -> Result<WeightedDiGraph<String>, EdgeListReadError> {
+    let mut nodes: Vec<String> = Vec::new();
+    let mut edges: Vec<(usize, String, String, f64)> = Vec::new();
+    for (line_index, line) in reader.lines().enumerate() {
+        let line_number = line_index + 1;
+        let line =
+            line.map_err(|error| EdgeListReadError::Io { line: line_number, kind: error.kind() })?;
+        if line.is_empty() {
+            continue;
+        }
+        let fields: Vec<&str> = line.split('\t').collect();
+        match fields.as_slice() {
+            [node] => nodes.push((*node).to_string()),
+            [source, destination, weight] => {
+                let weight = weight.parse::<f64>().map_err(|_| {
+                    EdgeListReadError::InvalidWeight {
+                        line: line_number,
+                        value: (*weight).to_string(),
+                    }
+                })?;
+                edges.push((
+                    line_number,
+                    (*source).to_string(),
+                    (*destination).to_string(),
+                    weight,
+                ));
+            }
+            _ => {
+                return Err(EdgeListReadError::InvalidFieldCount {
+                    line: line_number,
+                    fields: fields.len(),
+                });
+            }
+        }
+    }
+
+    let raw_edges: Vec<RawEdge<'_, str>> = edges
+        .iter()
+        .map(|(line, source, destination, weight)| {
+            RawEdge {
+                position: *line,
+                source: source.as_str(),
+                destination: destination.as_str(),
+                weight: *weight,
+            }
+        })
+        .collect();
+    IndexedEdges::with_nodes(nodes.iter().map(String::as_str), &raw_edges, false, false)
+        .map(IndexedEdges::into_weighted_digraph)
+        .map_err(|repeated| {
+            EdgeListReadError::DuplicatedEdge {
+                line: repeated.position,
+                source_symbol: repeated.source_symbol,
+                destination_symbol: repeated.destination_symbol,
+            }
+        })
+}
@@ -0,0 +1,473 @@
+//! Submodule providing a writer for the GEXF format used by Gephi.
+//!
+//! The writer emits GEXF 1.3 documents. Nodes are identified by their
+//! numeric identifier and labelled with their symbol, so the labels shown by
+//! Gephi are the symbols of the vocabulary. The crate has no attribute
+//! storage nor temporal graph type, so node attributes and the time intervals
+//! of nodes and edges are provided to the writer as columns indexed by node
+//! identifier or by edge position.
+//!
+//! Edges are written in the order of the rows of the underlying matrix, that
+//! is sorted by source and then by destination identifier. Undirected graphs
+//! list each edge once, with the source not greater than the destination, and
+//! the edge intervals follow this same order.
+
+use std::{
+    fmt::Display,
+    format,
+    io::Write,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::{
+    impls::SortedVec,
+    naive_structs::{DiGraph, UndiGraph, WeightedDiGraph},
+    traits::{MonopartiteGraph, MonoplexGraph, Symbol, WeightedMonoplexGraph},
+};
+
+#[derive(Debug, thiserror::Error)]
+/// Errors raised while writing a GEXF document.
+pub enum GexfWriteError {
+    /// A node attribute does not provide exactly one value per node.
+    #[error("Attribute `{title}` has {found} values, but the graph has {expected} nodes")]
+    AttributeLength {
+        /// The title of the attribute.
+        title: String,
+        /// The number of nodes of the graph.
+        expected: usize,
+        /// The number of values of the attribute.
+        found: usize,
+    },
+    /// The intervals do not provide exactly one interval per node or edge.
+    #[error("Expected {expected} {element} intervals, found {found}")]
+    IntervalLength {
+        /// Either `node` or `edge`.
+        element: &'static str,
+        /// The number of nodes or edges of the graph.
+        expected: usize,
+        /// The number of intervals.
+        found: usize,
+    },
+    /// An interval has a non-finite bound, or starts after it ends.
+    #[error("The {element} interval at position {position} is not a finite, ordered interval")]
+    InvalidInterval {
+        /// Either `node` or `edge`.
+        element: &'static str,
+        /// The position of the interval.
+        position: usize,
+    },
+    /// The writer raised an error.
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// The values of a node attribute, one per node identifier.
+pub enum GexfAttributeValues {
+    /// Integer values, declared with the `long` type.
+    Integer(Vec<i64>),
+    /// Floating point values, declared with the `double` type.
+    Double(Vec<f64>),
+    /// Boolean values, declared with the `boolean` type.
+    Boolean(Vec<bool>),
+    /// Textual values, declared with the `string` type.
+    String(Vec<String>),
+}
+
+impl GexfAttributeValues {
+    /// Returns the number of values.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Integer(values) => values.len(),
+            Self::Double(values) => values.len(),
+            Self::Boolean(values) => values.len(),
+            Self::String(values) => values.len(),
+        }
+    }
+
+    /// Returns whether there are no values.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the GEXF name of the type of the values.
+    fn gexf_type(&self) -> &'static str {
+        match self {
+            Self::Integer(_) => "long",
+            Self::Double(_) => "double",
+            Self::Boolean(_) => "boolean",
+            Self::String(_) => "string",
+        }
+    }
+
+    /// Returns the value at the provided position, escaped for XML.
+    fn value(&self, position: usize) -> String {
+        match self {
+            Self::Integer(values) => values[position].to_string(),
+            Self::Double(values) => xsd_double(values[position]),
+            Self::Boolean(values) => values[position].to_string(),
+            Self::String(values) => escape_xml(&values[position]),
+        }
+    }
+}
+
+impl From<Vec<i64>> for GexfAttributeValues {
+    #[inline]
+    fn from(values: Vec<i64>) -> Self {
+        Self::Integer(values)
+    }
+}
+
+impl From<Vec<f64>> for GexfAttributeValues {
+    #[inline]
+    fn from(values: Vec<f64>) -> Self {
+        Self::Double(values)
+    }
+}
+
+impl From<Vec<bool>> for GexfAttributeValues {
+    #[inline]
+    fn from(values: Vec<bool>) -> Self {
+        Self::Boolean(values)
+    }
+}
+
+impl From<Vec<String>> for GexfAttributeValues {
+    #[inline]
+    fn from(values: Vec<String>) -> Self {
+        Self::String(values)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+/// Writer of GEXF documents, configured with a builder-style API.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{io::GexfWriter, prelude::*};
+///
+/// let nodes: SortedVec<String> = GenericVocabularyBuilder::default()
+///     .expected_number_of_symbols(2)
+///     .symbols(["alanine".to_string(), "glycine".to_string()].into_iter().enumerate())
+///     .build()
+///     .unwrap();
+/// let edges: SquareCSR2D<CSR2D<usize, usize, usize>> = DiEdgesBuilder::default()
+///     .expected_number_of_edges(1)
+///     .expected_shape(2)
+///     .edges([(0, 1)].into_iter())
+///     .build()
+///     .unwrap();
+/// let graph: DiGraph<String> = DiGraph::from((nodes, edges));
+///
+/// let mut document = Vec::new();
+/// GexfWriter::default()
+///     .node_attribute("mass", vec![89.09, 75.07])
+///     .node_intervals(vec![(0.0, 2.0), (1.0, 2.0)])
+///     .write_digraph(&graph, &mut document)
+///     .unwrap();
+/// let document = String::from_utf8(document).unwrap();
+/// assert!(
+///     document
+///         .contains(r#"<graph defaultedgetype="directed" mode="dynamic" timeformat="double">"#)
+/// );
+/// assert!(document.contains(r#"<node id="0" label="alanine" start="0" end="2">"#));
+/// assert!(document.contains(r#"<attvalue for="0" value="75.07"/>"#));
+/// assert!(document.contains(r#"<edge id="0" source="0" target="1"/>"#));
+/// ```
+pub struct GexfWriter {
+    /// The node attributes, with their titles.
+    node_attributes: Vec<(String, GexfAttributeValues)>,
+    /// The time interval of each node, if any.
+    node_intervals: Option<Vec<(f64, f64)>>,
+    /// The time interval of each edge, if any.
+    edge_intervals: Option<Vec<(f64, f64)>>,
+}
+
+impl GexfWriter {
+    #[must_use]
+    /// Adds a node attribute, holding one value per node identifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `title`: The title of the attribute, as shown by Gephi.
+    /// * `values`: The values of the attribute.
+    #[inline]
+    pub fn node_attribute(
+        mut self,
+        title: impl Into<String>,
+        values: impl Into<GexfAttributeValues>,
+    ) -> Self {
+        self.node_attributes.push((title.into(), values.into()));
+        self
+    }
+
+    #[must_use]
+    /// Sets the `(start, end)` time interval of each node, indexed by node
+    /// identifier, and makes the document dynamic.
+    #[inline]
+    pub fn node_intervals(mut self, intervals: Vec<(f64, f64)>) -> Self {
+        self.node_intervals = Some(intervals);
+        self
+    }
+
+    #[must_use]
+    /// Sets the `(start, end)` time interval of each edge, in the order the
+    /// edges are written, and makes the document dynamic.
+    #[inline]
+    pub fn edge_intervals(mut self, intervals: Vec<(f64, f64)>) -> Self {
+        self.edge_intervals = Some(intervals);
+        self
+    }
+
+    /// Checks that the attributes and intervals match the size of the graph.
+    fn validate(
+        &self,
+        number_of_nodes: usize,
+        number_of_edges: usize,
+    ) -> Result<(), GexfWriteError> {
+        for (title, values) in &self.node_attributes {
+            if values.len() != number_of_nodes {
+                return Err(GexfWriteError::AttributeLength {
+                    title: title.clone(),
+                    expected: number_of_nodes,
+                    found: values.len(),
+                });
+            }
+        }
+        for (element, intervals, expected) in [
+            ("node", &self.node_intervals, number_of_nodes),
+            ("edge", &self.edge_intervals, number_of_edges),
+        ] {
+            let Some(intervals) = intervals else {
+                continue;
+            };
+            if intervals.len() != expected {
+                return Err(GexfWriteError::IntervalLength {
+                    element,
+                    expected,
+                    found: intervals.len(),
+                });
+            }
+            if let Some(position) = intervals
+                .iter()
+                .position(|&(start, end)| !(start.is_finite() && end.is_finite() && start <= end))
+            {
+                return Err(GexfWriteError::InvalidInterval { element, position });
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the document, given the edges as triples of source,
+    /// destination and optional weight.
+    fn write<W, S, I>(
+        &self,
+        mut writer: W,
+        directed: bool,
+        nodes: &SortedVec<S>,
+        number_of_edges: usize,
+        edges: I,
+    ) -> Result<(), GexfWriteError>
+    where
+        W: Write,
+        S: Display,
+        I: Iterator<Item = (usize, usize, Option<f64>)>,
+    {
+        self.validate(nodes.len(), number_of_edges)?;
+        let dynamic = self.node_intervals.is_some() || self.edge_intervals.is_some();
+
+        writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(writer, r#"<gexf xmlns="http://gexf.net/1.3" version="1.3">"#)?;
+        writeln!(
+            writer,
+            r#"  <graph defaultedgetype="{}" mode="{}"{}>"#,
+            if directed { "directed" } else { "undirected" },
+            if dynamic { "dynamic" } else { "static" },
+            if dynamic { r#" timeformat="double""# } else { "" }
+        )?;
+
+        if !self.node_attributes.is_empty() {
+            writeln!(writer, r#"    <attributes class="node" mode="static">"#)?;
+            for (id, (title, values)) in self.node_attributes.iter().enumerate() {
+                writeln!(
+                    writer,
+                    r#"      <attribute id="{id}" title="{}" type="{}"/>"#,
+                    escape_xml(title),
+                    values.gexf_type()
+                )?;
+            }
+            writeln!(writer, "    </attributes>")?;
+        }
+
+        writeln!(writer, "    <nodes>")?;
+        for (node, symbol) in nodes.iter().enumerate() {
+            let interval = self.node_intervals.as_ref().map(|intervals| intervals[node]);
+            write!(
+                writer,
+                r#"      <node id="{node}" label="{}"{}"#,
+                escape_xml(&symbol.to_string()),
+                interval_attributes(interval)
+            )?;
+            if self.node_attributes.is_empty() {
+                writeln!(writer, "/>")?;
+                continue;
+            }
+            writeln!(writer, ">")?;
+            writeln!(writer, "        <attvalues>")?;
+            for (id, (_, values)) in self.node_attributes.iter().enumerate() {
+                writeln!(
+                    writer,
+                    r#"          <attvalue for="{id}" value="{}"/>"#,
+                    values.value(node)
+                )?;
+            }
+            writeln!(writer, "        </attvalues>")?;
+            writeln!(writer, "      </node>")?;
+        }
+        writeln!(writer, "    </nodes>")?;
+
+        writeln!(writer, "    <edges>")?;
+        for (id, (source, destination, weight)) in edges.enumerate() {
+            let interval = self.edge_intervals.as_ref().map(|intervals| intervals[id]);
+            writeln!(
+                writer,
+                r#"      <edge id="{id}" source="{source}" target="{destination}"{}{}/>"#,
+                weight.map_or_else(String::new, |weight| {
+                    format!(r#" weight="{}""#, xsd_double(weight))
+                }),
+                interval_attributes(interval)
+            )?;
+        }
+        writeln!(writer, "    </edges>")?;
+        writeln!(writer, "  </graph>")?;
+        writeln!(writer, "</gexf>")?;
+        Ok(())
+    }
+
+    /// Writes a directed graph as a GEXF document.
+    ///
+    /// # Arguments
+    ///
+    /// * `graph`: The graph to write.
+    /// * `writer`: The destination of the document.
+    ///
+    /// # Errors
+    ///
+    /// * If an attribute or the intervals do not match the size of the graph,
+    ///   or if an interval is invalid.
+    /// * If the writer raises an error.
+    pub fn write_digraph<S: Symbol + Ord + Display, W: Write>(
+        &self,
+        graph: &DiGraph<S>,
+        writer: W,
+    ) -> Result<(), GexfWriteError> {
+        self.write(
+            writer,
+            true,
+            graph.nodes_vocabulary(),
+            graph.number_of_edges(),
+            graph.node_ids().flat_map(|source| {
+                graph.successors(source).map(move |destination| (source, destination, None))
+            }),
+        )
+    }
+
+    /// Writes an undirected graph as a GEXF document, listing each edge once.
+    ///
+    /// # Arguments
+    ///
+    /// * `graph`: The graph to write.
+    /// * `writer`: The destination of the document.
+    ///
+    /// # Errors
+    ///
+    /// * If an attribute or the intervals do not match the size of the graph,
+    ///   or if an interval is invalid.
+    /// * If the writer raises an error.
+    pub fn write_undigraph<S: Symbol + Ord + Display, W: Write>(
+        &self,
+        graph: &UndiGraph<S>,
+        writer: W,
+    ) -> Result<(), GexfWriteError> {
+        let edges = || {
+            graph.node_ids().flat_map(move |source| {
+                graph
+                    .successors(source)
+                    .filter(move |&destination| destination >= source)
+                    .map(move |destination| (source, destination, None))
+            })
+        };
+        self.write(writer, false, graph.nodes_vocabulary(), edges().count(), edges())
+    }
+
+    /// Writes a weighted directed graph as a GEXF document.
+    ///
+    /// # Arguments
+    ///
+    /// * `graph`: The graph to write.
+    /// * `writer`: The destination of the document.
+    ///
+    /// # Errors
+    ///
+    /// * If an attribute or the intervals do not match the size of the graph,
+    ///   or if an interval is invalid.
+    /// * If the writer raises an error.
+    pub fn write_weighted_digraph<S: Symbol + Ord + Display, W: Write>(
+        &self,
+        graph: &WeightedDiGraph<S>,
+        writer: W,
+    ) -> Result<(), GexfWriteError> {
+        self.write(
+            writer,
+            true,
+            graph.nodes_vocabulary(),
+            graph.number_of_edges(),
+            graph.node_ids().flat_map(|source| {
+                graph
+                    .successors(source)
+                    .zip(graph.successor_weights(source))
+                    .map(move |(destination, weight)| (source, destination, Some(weight)))
+            }),
+        )
+    }
+}
+
+/// Returns the `start` and `end` XML attributes of an optional interval.
+fn interval_attributes(interval: Option<(f64, f64)>) -> String {
+    interval.map_or_else(String::new, |(start, end)| {
+        format!(r#" start="{}" end="{}""#, xsd_double(start), xsd_double(end))
+    })
+}
+
+/// Formats a double following the lexical space of the XML Schema `double`.
+fn xsd_double(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value.is_infinite() {
+        if value > 0.0 { "INF" } else { "-INF" }.to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+/// Escapes the characters which cannot appear verbatim in XML attributes.
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(character),
+        }
+    }
+    escaped
+}
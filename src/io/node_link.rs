@@ -0,0 +1,784 @@
+//! Submodule providing support for the JSON node-link format.
+//!
+//! The node-link format is the one produced by `networkx.node_link_data` and
+//! consumed by D3 force layouts:
+//!
+//! ```json
+//! {
+//!   "directed": true,
+//!   "multigraph": false,
+//!   "graph": {},
+//!   "nodes": [{"id": "alanine"}, {"id": "glycine"}],
+//!   "links": [{"source": "alanine", "target": "glycine", "weight": 0.5}]
+//! }
+//! ```
+//!
+//! Links refer to nodes by their symbol. Undirected graphs list each edge
+//! once, and only weighted graphs write the `weight` key. Writing streams the
+//! nodes and the links straight from the graph, without materializing the
+//! document in memory. Reading accepts the `edges` key written by recent
+//! `networkx` releases in place of `links`, ignores unknown keys, and adds the
+//! endpoints of the links to the nodes when they are not listed.
+//!
+//! Node symbols are strings or integers, as described by [`NodeLinkSymbol`].
+
+use std::{
+    format,
+    io::{self, Read, Write},
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use super::edge_index::{IndexedEdges, RawEdge};
+use crate::{
+    impls::SortedVec,
+    naive_structs::{DiGraph, UndiGraph, WeightedDiGraph},
+    traits::{MonopartiteGraph, MonoplexGraph, Symbol, WeightedMonoplexGraph},
+};
+
+/// Maximal nesting depth of the values of a document, beyond which it is
+/// rejected instead of exhausting the stack.
+const MAX_DEPTH: usize = 128;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+/// Errors raised while reading or writing a node-link document.
+///
+/// The variants referring to a link carry its zero-based position in the
+/// `links` array.
+pub enum NodeLinkError {
+    /// The document is not valid JSON, does not follow the node-link schema,
+    /// or could not be read or written.
+    #[error("Invalid node-link document: {message}")]
+    Json {
+        /// The description of the problem.
+        message: String,
+    },
+    /// The `directed` key of the document does not match the graph type.
+    #[error(
+        "Expected a {} graph, found an {} one",
+        if *expected { "directed" } else { "undirected" },
+        if *expected { "undirected" } else { "directed" }
+    )]
+    DirectedMismatch {
+        /// Whether the requested graph type is directed.
+        expected: bool,
+    },
+    /// The document describes a multigraph, which no graph type supports.
+    #[error("Multigraphs are not supported")]
+    Multigraph,
+    /// A link of a weighted graph has no weight.
+    #[error("Link {link} has no weight")]
+    MissingWeight {
+        /// The position of the link.
+        link: usize,
+    },
+    /// A link has a weight which is not a finite number.
+    #[error("Link {link} has a non-finite weight")]
+    InvalidWeight {
+        /// The position of the link.
+        link: usize,
+    },
+    /// A link repeats an earlier one.
+    #[error("Link {link} repeats an earlier link")]
+    DuplicatedEdge {
+        /// The position of the repeated link.
+        link: usize,
+    },
+}
+
+impl NodeLinkError {
+    /// Returns the error reporting a failure of the reader or the writer.
+    fn io(error: &io::Error) -> Self {
+        Self::Json { message: error.to_string() }
+    }
+}
+
+/// Trait for the node symbols which can be written to and read from a
+/// node-link document.
+///
+/// Strings are written as JSON strings, and integers as JSON numbers.
+pub trait NodeLinkSymbol: Symbol + Ord {
+    /// Writes the symbol as a JSON value.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer`: The destination of the value.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error raised by the writer.
+    fn write_json<W: Write>(&self, writer: &mut W) -> io::Result<()>;
+
+    /// Returns the symbol described by a JSON string, or `None` if the
+    /// symbols are not strings.
+    ///
+    /// # Arguments
+    ///
+    /// * `string`: The unescaped content of the string.
+    #[inline]
+    #[must_use]
+    fn from_json_string(string: String) -> Option<Self> {
+        let _ = string;
+        None
+    }
+
+    /// Returns the symbol described by a JSON number, or `None` if the
+    /// symbols are not numbers or the number is out of their range.
+    ///
+    /// # Arguments
+    ///
+    /// * `literal`: The number as it appears in the document.
+    #[inline]
+    #[must_use]
+    fn from_json_number(literal: &str) -> Option<Self> {
+        let _ = literal;
+        None
+    }
+}
+
+impl NodeLinkSymbol for String {
+    #[inline]
+    fn write_json<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_json_string(self, writer)
+    }
+
+    #[inline]
+    fn from_json_string(string: String) -> Option<Self> {
+        Some(string)
+    }
+}
+
+macro_rules! impl_node_link_symbol {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl NodeLinkSymbol for $t {
+                #[inline]
+                fn write_json<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+                    write!(writer, "{self}")
+                }
+
+                #[inline]
+                fn from_json_number(literal: &str) -> Option<Self> {
+                    literal.parse().ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_node_link_symbol!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Writes the string as a JSON string, escaping the quotes, the backslashes
+/// and the control characters.
+fn write_json_string<W: Write>(string: &str, writer: &mut W) -> io::Result<()> {
+    writer.write_all(b"\"")?;
+    let mut unescaped = 0;
+    for (position, character) in string.char_indices() {
+        let escaped = match character {
+            '"' => "\\\"",
+            '\\' => "\\\\",
+            '\n' => "\\n",
+            '\r' => "\\r",
+            '\t' => "\\t",
+            '\u{8}' => "\\b",
+            '\u{c}' => "\\f",
+            '\0'..='\u{1f}' => "",
+            _ => continue,
+        };
+        writer.write_all(&string.as_bytes()[unescaped..position])?;
+        if escaped.is_empty() {
+            write!(writer, "\\u{:04x}", u32::from(character))?;
+        } else {
+            writer.write_all(escaped.as_bytes())?;
+        }
+        unescaped = position + character.len_utf8();
+    }
+    writer.write_all(&string.as_bytes()[unescaped..])?;
+    writer.write_all(b"\"")
+}
+
+/// Trait for the graphs which can be converted to and from the node-link
+/// format.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{io::NodeLinkJson, prelude::*};
+///
+/// let document = r#"{
+///     "directed": false,
+///     "nodes": [{"id": 1}, {"id": 2}, {"id": 3}, {"id": 4}],
+///     "links": [{"source": 2, "target": 1}, {"source": 2, "target": 3}]
+/// }"#;
+/// let graph = UndiGraph::<u32>::from_node_link_json(document.as_bytes()).unwrap();
+/// assert_eq!(graph.number_of_nodes(), 4);
+/// assert!(graph.has_successor(0, 1));
+///
+/// let mut written = Vec::new();
+/// graph.to_node_link_json(&mut written).unwrap();
+/// assert_eq!(
+///     String::from_utf8(written).unwrap(),
+///     concat!(
+///         r#"{"directed":false,"multigraph":false,"graph":{},"#,
+///         r#""nodes":[{"id":1},{"id":2},{"id":3},{"id":4}],"#,
+///         r#""links":[{"source":1,"target":2},{"source":2,"target":3}]}"#
+///     )
+/// );
+/// ```
+pub trait NodeLinkJson: Sized {
+    /// Writes the graph as a node-link document.
+    ///
+    /// The document is streamed to the writer, which should be buffered when
+    /// it is backed by a file or a socket.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer`: The destination of the document.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NodeLinkError::Json`] if the writer raises an error.
+    fn to_node_link_json<W: Write>(&self, writer: W) -> Result<(), NodeLinkError>;
+
+    /// Reads a graph from a node-link document.
+    ///
+    /// The reader should be buffered when it is backed by a file or a socket.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader`: The source of the document.
+    ///
+    /// # Errors
+    ///
+    /// * [`NodeLinkError::Json`] if the document is malformed, or if a node
+    ///   identifier is not a valid symbol.
+    /// * [`NodeLinkError::DirectedMismatch`] if the document declares the other
+    ///   directedness.
+    /// * [`NodeLinkError::Multigraph`] if the document declares a multigraph.
+    /// * [`NodeLinkError::MissingWeight`] or [`NodeLinkError::InvalidWeight`]
+    ///   if a link of a weighted graph has no finite weight.
+    /// * [`NodeLinkError::DuplicatedEdge`] if a link is repeated.
+    fn from_node_link_json<R: Read>(reader: R) -> Result<Self, NodeLinkError>;
+}
+
+/// Streams the node-link document of a graph, given the function returning
+/// the links as triples of source, destination and optional weight.
+fn write_node_link<W, S, I>(
+    mut writer: W,
+    directed: bool,
+    nodes: &SortedVec<S>,
+    links: I,
+) -> io::Result<()>
+where
+    W: Write,
+    S: NodeLinkSymbol,
+    I: Iterator<Item = (usize, usize, Option<f64>)>,
+{
+    write!(writer, r#"{{"directed":{directed},"multigraph":false,"graph":{{}},"nodes":["#)?;
+    for (position, id) in nodes.iter().enumerate() {
+        if position > 0 {
+            writer.write_all(b",")?;
+        }
+        writer.write_all(br#"{"id":"#)?;
+        id.write_json(&mut writer)?;
+        writer.write_all(b"}")?;
+    }
+    writer.write_all(br#"],"links":["#)?;
+    for (position, (source, destination, weight)) in links.enumerate() {
+        if position > 0 {
+            writer.write_all(b",")?;
+        }
+        writer.write_all(br#"{"source":"#)?;
+        nodes[source].write_json(&mut writer)?;
+        writer.write_all(br#","target":"#)?;
+        nodes[destination].write_json(&mut writer)?;
+        match weight {
+            // The shortest representation which reads back to the same
+            // weight, always with a fractional part or an exponent.
+            Some(weight) if weight.is_finite() => write!(writer, r#","weight":{weight:?}"#)?,
+            Some(_) => writer.write_all(br#","weight":null"#)?,
+            None => {}
+        }
+        writer.write_all(b"}")?;
+    }
+    writer.write_all(b"]}")
+}
+
+/// A link of a node-link document.
+struct RawLink<S> {
+    source: S,
+    target: S,
+    weight: Option<f64>,
+}
+
+/// The parts of a node-link document used to build the graph.
+struct RawNodeLink<S> {
+    directed: Option<bool>,
+    multigraph: bool,
+    nodes: Vec<S>,
+    links: Vec<RawLink<S>>,
+}
+
+/// Recursive descent parser of the JSON values of a document.
+struct JsonParser<'a> {
+    /// The document.
+    text: &'a str,
+    /// The byte offset of the next character to parse.
+    position: usize,
+    /// The nesting depth of the value being parsed.
+    depth: usize,
+}
+
+impl<'a> JsonParser<'a> {
+    /// Returns the error describing a problem at the current position.
+    fn error(&self, problem: &str) -> NodeLinkError {
+        NodeLinkError::Json { message: format!("{problem} at byte {}", self.position) }
+    }
+
+    /// Skips the whitespace, returning the next byte if any.
+    fn peek(&mut self) -> Option<u8> {
+        let bytes = self.text.as_bytes();
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = bytes.get(self.position) {
+            self.position += 1;
+        }
+        bytes.get(self.position).copied()
+    }
+
+    /// Skips the whitespace and consumes the provided byte.
+    fn expect(&mut self, expected: u8) -> Result<(), NodeLinkError> {
+        if self.peek() == Some(expected) {
+            self.position += 1;
+            Ok(())
+        } else {
+            Err(self.error(&format!("Expected `{}`", char::from(expected))))
+        }
+    }
+
+    /// Consumes the provided keyword, such as `true`.
+    fn keyword(&mut self, keyword: &str) -> Result<(), NodeLinkError> {
+        if self.text[self.position..].starts_with(keyword) {
+            self.position += keyword.len();
+            Ok(())
+        } else {
+            Err(self.error("Expected a JSON value"))
+        }
+    }
+
+    /// Parses a string, returning its unescaped content.
+    fn string(&mut self) -> Result<String, NodeLinkError> {
+        self.expect(b'"')?;
+        let mut string = String::new();
+        loop {
+            let rest = &self.text[self.position..];
+            let Some(end) = rest.find(['"', '\\']) else {
+                return Err(self.error("Unterminated string"));
+            };
+            if rest[..end].chars().any(|character| character < ' ') {
+                return Err(self.error("Control character in string"));
+            }
+            string.push_str(&rest[..end]);
+            self.position += end + 1;
+            if rest.as_bytes()[end] == b'"' {
+                return Ok(string);
+            }
+            let escape = self.text.as_bytes().get(self.position).copied();
+            self.position += 1;
+            string.push(match escape {
+                Some(b'"') => '"',
+                Some(b'\\') => '\\',
+                Some(b'/') => '/',
+                Some(b'b') => '\u{8}',
+                Some(b'f') => '\u{c}',
+                Some(b'n') => '\n',
+                Some(b'r') => '\r',
+                Some(b't') => '\t',
+                Some(b'u') => self.unicode_escape()?,
+                _ => return Err(self.error("Invalid escape sequence")),
+            });
+        }
+    }
+
+    /// Parses the four hexadecimal digits of a `\u` escape, and the low
+    /// surrogate following a high one.
+    fn unicode_escape(&mut self) -> Result<char, NodeLinkError> {
+        let code_unit = self.hexadecimal_code_unit()?;
+        let code_point = if (0xD800..0xDC00).contains(&code_unit) {
+            if !self.text[self.position..].starts_with("\\u") {
+                return Err(self.error("Unpaired surrogate"));
+            }
+            self.position += 2;
+            let low = self.hexadecimal_code_unit()?;
+            if !(0xDC00..0xE000).contains(&low) {
+                return Err(self.error("Unpaired surrogate"));
+            }
+            0x10000 + ((code_unit - 0xD800) << 10) + (low - 0xDC00)
+        } else {
+            code_unit
+        };
+        char::from_u32(code_point).ok_or_else(|| self.error("Unpaired surrogate"))
+    }
+
+    /// Parses four hexadecimal digits.
+    fn hexadecimal_code_unit(&mut self) -> Result<u32, NodeLinkError> {
+        let digits = self
+            .text
+            .get(self.position..self.position + 4)
+            .filter(|digits| digits.bytes().all(|digit| digit.is_ascii_hexdigit()))
+            .ok_or_else(|| self.error("Invalid unicode escape"))?;
+        self.position += 4;
+        u32::from_str_radix(digits, 16).map_err(|_| self.error("Invalid unicode escape"))
+    }
+
+    /// Parses a number, returning it as it appears in the document.
+    fn number(&mut self) -> Result<&'a str, NodeLinkError> {
+        let bytes = self.text.as_bytes();
+        let start = self.position;
+        let digits = |position: &mut usize| {
+            let first = *position;
+            while bytes.get(*position).is_some_and(u8::is_ascii_digit) {
+                *position += 1;
+            }
+            *position > first
+        };
+        let mut end = start;
+        if bytes.get(end) == Some(&b'-') {
+            end += 1;
+        }
+        let integer_start = end;
+        if !digits(&mut end) || (bytes[integer_start] == b'0' && end > integer_start + 1) {
+            return Err(self.error("Invalid number"));
+        }
+        if bytes.get(end) == Some(&b'.') {
+            end += 1;
+            if !digits(&mut end) {
+                return Err(self.error("Invalid number"));
+            }
+        }
+        if let Some(b'e' | b'E') = bytes.get(end) {
+            end += 1;
+            if let Some(b'+' | b'-') = bytes.get(end) {
+                end += 1;
+            }
+            if !digits(&mut end) {
+                return Err(self.error("Invalid number"));
+            }
+        }
+        self.position = end;
+        Ok(&self.text[start..end])
+    }
+
+    /// Parses a boolean.
+    fn boolean(&mut self) -> Result<bool, NodeLinkError> {
+        match self.peek() {
+            Some(b't') => self.keyword("true").map(|()| true),
+            Some(b'f') => self.keyword("false").map(|()| false),
+            _ => Err(self.error("Expected a boolean")),
+        }
+    }
+
+    /// Parses `null` or the value parsed by the closure.
+    fn nullable<T>(
+        &mut self,
+        parse: impl FnOnce(&mut Self) -> Result<T, NodeLinkError>,
+    ) -> Result<Option<T>, NodeLinkError> {
+        if self.peek() == Some(b'n') {
+            self.keyword("null").map(|()| None)
+        } else {
+            parse(self).map(Some)
+        }
+    }
+
+    /// Parses a finite or infinite number as a weight.
+    fn weight(&mut self) -> Result<f64, NodeLinkError> {
+        self.peek();
+        let literal = self.number()?;
+        literal.parse().map_err(|_| self.error("Invalid number"))
+    }
+
+    /// Parses a node identifier.
+    fn symbol<S: NodeLinkSymbol>(&mut self) -> Result<S, NodeLinkError> {
+        match self.peek() {
+            Some(b'"') => {
+                let string = self.string()?;
+                S::from_json_string(string)
+                    .ok_or_else(|| self.error("Node identifiers should be numbers"))
+            }
+            Some(b'-' | b'0'..=b'9') => {
+                let literal = self.number()?;
+                S::from_json_number(literal)
+                    .ok_or_else(|| self.error(&format!("Invalid node identifier {literal}")))
+            }
+            _ => Err(self.error("Expected a node identifier")),
+        }
+    }
+
+    /// Parses an object, calling the closure to parse the value of each of
+    /// its keys.
+    fn object(
+        &mut self,
+        mut member: impl FnMut(&mut Self, String) -> Result<(), NodeLinkError>,
+    ) -> Result<(), NodeLinkError> {
+        self.nested(|parser| {
+            parser.expect(b'{')?;
+            if parser.peek() == Some(b'}') {
+                parser.position += 1;
+                return Ok(());
+            }
+            loop {
+                let key = parser.string()?;
+                parser.expect(b':')?;
+                member(parser, key)?;
+                match parser.peek() {
+                    Some(b',') => parser.position += 1,
+                    Some(b'}') => {
+                        parser.position += 1;
+                        return Ok(());
+                    }
+                    _ => return Err(parser.error("Expected `,` or `}`")),
+                }
+            }
+        })
+    }
+
+    /// Parses an array, calling the closure to parse each of its elements.
+    fn array(
+        &mut self,
+        mut element: impl FnMut(&mut Self) -> Result<(), NodeLinkError>,
+    ) -> Result<(), NodeLinkError> {
+        self.nested(|parser| {
+            parser.expect(b'[')?;
+            if parser.peek() == Some(b']') {
+                parser.position += 1;
+                return Ok(());
+            }
+            loop {
+                element(parser)?;
+                match parser.peek() {
+                    Some(b',') => parser.position += 1,
+                    Some(b']') => {
+                        parser.position += 1;
+                        return Ok(());
+                    }
+                    _ => return Err(parser.error("Expected `,` or `]`")),
+                }
+            }
+        })
+    }
+
+    /// Runs the closure one nesting level deeper.
+    fn nested(
+        &mut self,
+        parse: impl FnOnce(&mut Self) -> Result<(), NodeLinkError>,
+    ) -> Result<(), NodeLinkError> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error("Too deeply nested document"));
+        }
+        self.depth += 1;
+        parse(self)?;
+        self.depth -= 1;
+        Ok(())
+    }
+
+    /// Parses and discards any value.
+    fn skip(&mut self) -> Result<(), NodeLinkError> {
+        match self.peek() {
+            Some(b'{') => self.object(|parser, _| parser.skip()),
+            Some(b'[') => self.array(Self::skip),
+            Some(b'"') => self.string().map(drop),
+            Some(b'-' | b'0'..=b'9') => self.number().map(drop),
+            Some(b't' | b'f') => self.boolean().map(drop),
+            _ => self.keyword("null"),
+        }
+    }
+}
+
+/// Stores the value of a key, rejecting the keys which appear twice.
+fn set_once<T>(
+    parser: &JsonParser<'_>,
+    slot: &mut Option<T>,
+    key: &str,
+    value: T,
+) -> Result<(), NodeLinkError> {
+    if slot.replace(value).is_some() {
+        return Err(parser.error(&format!("Duplicate key `{key}`")));
+    }
+    Ok(())
+}
+
+/// Parses a node-link document, ignoring the keys it does not use.
+fn parse_node_link<S: NodeLinkSymbol>(text: &str) -> Result<RawNodeLink<S>, NodeLinkError> {
+    let mut parser = JsonParser { text, position: 0, depth: 0 };
+    let mut directed = None;
+    let mut multigraph = None;
+    let mut nodes = None;
+    let mut links = None;
+    parser.object(|parser, key| {
+        match key.as_str() {
+            "directed" => {
+                let value = parser.nullable(JsonParser::boolean)?;
+                set_once(parser, &mut directed, &key, value)
+            }
+            "multigraph" => {
+                let value = parser.boolean()?;
+                set_once(parser, &mut multigraph, &key, value)
+            }
+            "nodes" => {
+                let mut symbols = Vec::new();
+                parser.array(|parser| {
+                    let mut id = None;
+                    parser.object(|parser, key| {
+                        if key == "id" {
+                            let symbol = parser.symbol()?;
+                            set_once(parser, &mut id, &key, symbol)
+                        } else {
+                            parser.skip()
+                        }
+                    })?;
+                    symbols.push(id.ok_or_else(|| parser.error("Missing key `id`"))?);
+                    Ok(())
+                })?;
+                set_once(parser, &mut nodes, &key, symbols)
+            }
+            // Recent `networkx` releases write the links under `edges`.
+            "links" | "edges" => {
+                let mut raw_links = Vec::new();
+                parser.array(|parser| {
+                    let (mut source, mut target, mut weight) = (None, None, None);
+                    parser.object(|parser, key| {
+                        match key.as_str() {
+                            "source" => {
+                                let symbol = parser.symbol()?;
+                                set_once(parser, &mut source, &key, symbol)
+                            }
+                            "target" => {
+                                let symbol = parser.symbol()?;
+                                set_once(parser, &mut target, &key, symbol)
+                            }
+                            "weight" => {
+                                let value = parser.nullable(JsonParser::weight)?;
+                                set_once(parser, &mut weight, &key, value)
+                            }
+                            _ => parser.skip(),
+                        }
+                    })?;
+                    raw_links.push(RawLink {
+                        source: source.ok_or_else(|| parser.error("Missing key `source`"))?,
+                        target: target.ok_or_else(|| parser.error("Missing key `target`"))?,
+                        weight: weight.flatten(),
+                    });
+                    Ok(())
+                })?;
+                set_once(parser, &mut links, "links", raw_links)
+            }
+            _ => parser.skip(),
+        }
+    })?;
+    if parser.peek().is_some() {
+        return Err(parser.error("Trailing characters"));
+    }
+
+    Ok(RawNodeLink {
+        directed: directed.flatten(),
+        multigraph: multigraph.unwrap_or(false),
+        nodes: nodes.ok_or_else(|| parser.error("Missing key `nodes`"))?,
+        links: links.unwrap_or_default(),
+    })
+}
+
+/// Reads a node-link document and indexes its nodes and links.
+fn read_node_link<R, S>(
+    mut reader: R,
+    directed: bool,
+    weighted: bool,
+) -> Result<IndexedEdges<S>, NodeLinkError>
+where
+    R: Read,
+    S: NodeLinkSymbol,
+{
+    let mut text = String::new();
+    reader.read_to_string(&mut text).map_err(|error| NodeLinkError::io(&error))?;
+    let document: RawNodeLink<S> = parse_node_link(&text)?;
+    if document.directed.is_some_and(|found| found != directed) {
+        return Err(NodeLinkError::DirectedMismatch { expected: directed });
+    }
+    if document.multigraph {
+        return Err(NodeLinkError::Multigraph);
+    }
+
+    let raw_edges = document
+        .links
+        .iter()
+        .enumerate()
+        .map(|(link, raw_link)| {
+            let weight = match raw_link.weight {
+                Some(weight) if !weight.is_finite() => {
+                    return Err(NodeLinkError::InvalidWeight { link });
+                }
+                Some(weight) => weight,
+                None if weighted => return Err(NodeLinkError::MissingWeight { link }),
+                None => 1.0,
+            };
+            Ok(RawEdge {
+                position: link,
+                source: &raw_link.source,
+                destination: &raw_link.target,
+                weight,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    IndexedEdges::with_nodes(document.nodes.iter(), &raw_edges, !directed, false)
+        .map_err(|repeated| NodeLinkError::DuplicatedEdge { link: repeated.position })
+}
+
+impl<S: NodeLinkSymbol> NodeLinkJson for DiGraph<S> {
+    #[inline]
+    fn to_node_link_json<W: Write>(&self, writer: W) -> Result<(), NodeLinkError> {
+        let links = self.node_ids().flat_map(move |source| {
+            self.successors(source).map(move |destination| (source, destination, None))
+        });
+        write_node_link(writer, true, self.nodes_vocabulary(), links)
+            .map_err(|error| NodeLinkError::io(&error))
+    }
+
+    #[inline]
+    fn from_node_link_json<R: Read>(reader: R) -> Result<Self, NodeLinkError> {
+        Ok(read_node_link(reader, true, false)?.into_digraph())
+    }
+}
+
+impl<S: NodeLinkSymbol> NodeLinkJson for UndiGraph<S> {
+    #[inline]
+    fn to_node_link_json<W: Write>(&self, writer: W) -> Result<(), NodeLinkError> {
+        let links = self.node_ids().flat_map(move |source| {
+            self.successors(source)
+                .filter(move |&destination| destination >= source)
+                .map(move |destination| (source, destination, None))
+        });
+        write_node_link(writer, false, self.nodes_vocabulary(), links)
+            .map_err(|error| NodeLinkError::io(&error))
+    }
+
+    #[inline]
+    fn from_node_link_json<R: Read>(reader: R) -> Result<Self, NodeLinkError> {
+        Ok(read_node_link(reader, false, false)?.into_undigraph())
+    }
+}
+
+impl<S: NodeLinkSymbol> NodeLinkJson for WeightedDiGraph<S> {
+    #[inline]
+    fn to_node_link_json<W: Write>(&self, writer: W) -> Result<(), NodeLinkError> {
+        let links = self.node_ids().flat_map(move |source| {
+            self.successors(source)
+                .zip(self.successor_weights(source))
+                .map(move |(destination, weight)| (source, destination, Some(weight)))
+        });
+        write_node_link(writer, true, self.nodes_vocabulary(), links)
+            .map_err(|error| NodeLinkError::io(&error))
+    }
+
+    #[inline]
+    fn from_node_link_json<R: Read>(reader: R) -> Result<Self, NodeLinkError> {
+        Ok(read_node_link(reader, true, true)?.into_weighted_digraph())
+    }
+}
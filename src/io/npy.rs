@@ -0,0 +1,364 @@
+//! Submodule providing writers for the NumPy `.npy` and `.npz` formats.
+//!
+//! The `.npy` writers store one-dimensional arrays, such as the partitions
+//! returned by the community detection algorithms or the scores of the
+//! centrality measures, and dense two-dimensional matrices in row-major
+//! order. The `.npz` writers store a CSR matrix as an uncompressed archive
+//! following the layout of `scipy.sparse.save_npz`, so the file can be loaded
+//! back with `scipy.sparse.load_npz`, or inspected with `numpy.load`.
+//!
+//! Every array is written with the version 1.0 header of the format, and
+//! pointer-sized integers are stored on eight bytes, as in the binary
+//! encoding.
+
+use std::{
+    format,
+    io::{self, Write},
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use num_traits::AsPrimitive;
+
+use super::binary::{BinaryScalar, crc32};
+use crate::traits::{DenseValuedMatrix2D, SizedSparseMatrix2D, SparseValuedMatrix2D};
+
+/// The magic bytes opening every `.npy` array.
+const NPY_MAGIC: &[u8; 6] = b"\x93NUMPY";
+
+/// The `.npy` headers are padded so that the data starts at a multiple of
+/// this alignment.
+const NPY_HEADER_ALIGNMENT: usize = 64;
+
+/// Trait for the scalars which can be stored in a `.npy` array.
+pub trait NpyScalar: BinaryScalar {
+    /// The NumPy type descriptor of the scalar, such as `<f8`.
+    const DESCR: &'static str;
+}
+
+macro_rules! impl_npy_scalar {
+    ($($t:ty => $descr:literal),* $(,)?) => {
+        $(
+            impl NpyScalar for $t {
+                const DESCR: &'static str = $descr;
+            }
+        )*
+    };
+}
+
+impl_npy_scalar!(
+    u8 => "|u1", u16 => "<u2", u32 => "<u4", u64 => "<u8", usize => "<u8",
+    i8 => "|i1", i16 => "<i2", i32 => "<i4", i64 => "<i8", isize => "<i8",
+    f32 => "<f4", f64 => "<f8",
+);
+
+/// Writes the `.npy` header describing an array with the provided type
+/// descriptor and shape.
+fn write_npy_header<W: Write>(descr: &str, shape: &[usize], writer: &mut W) -> io::Result<()> {
+    let shape = if let [length] = shape {
+        format!("({length},)")
+    } else {
+        let dimensions: Vec<String> = shape.iter().map(ToString::to_string).collect();
+        format!("({})", dimensions.join(", "))
+    };
+    let mut header = format!("{{'descr': '{descr}', 'fortran_order': False, 'shape': {shape}, }}");
+    // The magic bytes, the two version bytes and the two length bytes precede
+    // the header, which is terminated by a newline.
+    let preamble = NPY_MAGIC.len() + 4;
+    let padding = (NPY_HEADER_ALIGNMENT - (preamble + header.len() + 1) % NPY_HEADER_ALIGNMENT)
+        % NPY_HEADER_ALIGNMENT;
+    header.extend(core::iter::repeat_n(' ', padding));
+    header.push('\n');
+    let header_length = u16::try_from(header.len())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "The .npy header is too long"))?;
+
+    writer.write_all(NPY_MAGIC)?;
+    writer.write_all(&[1, 0])?;
+    writer.write_all(&header_length.to_le_bytes())?;
+    writer.write_all(header.as_bytes())
+}
+
+/// Writes a `.npy` array with the provided shape, taking its values in
+/// row-major order from the provided rows.
+fn write_npy_rows<T, R, W>(shape: &[usize], rows: R, writer: &mut W) -> io::Result<()>
+where
+    T: NpyScalar,
+    R: IntoIterator,
+    R::Item: IntoIterator<Item = T>,
+    W: Write,
+{
+    write_npy_header(T::DESCR, shape, writer)?;
+    let mut buffer = Vec::new();
+    for row in rows {
+        buffer.clear();
+        for value in row {
+            value.write_le(&mut buffer);
+        }
+        writer.write_all(&buffer)?;
+    }
+    Ok(())
+}
+
+/// Writes a one-dimensional `.npy` array.
+///
+/// This is the natural format for per-node results, such as the partitions
+/// returned by the community detection algorithms or the scores of the
+/// centrality measures.
+///
+/// # Arguments
+///
+/// * `values`: The values of the array.
+/// * `writer`: The destination of the array.
+///
+/// # Errors
+///
+/// Returns any error raised by the writer.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::io::write_npy_vector;
+///
+/// let mut bytes = Vec::new();
+/// write_npy_vector(&[0_usize, 1, 1, 0], &mut bytes).unwrap();
+/// assert!(bytes.starts_with(b"\x93NUMPY"));
+/// // The data starts after a header padded to a multiple of 64 bytes.
+/// assert_eq!(bytes.len(), 128 + 4 * 8);
+/// ```
+#[inline]
+pub fn write_npy_vector<T: NpyScalar + Copy, W: Write>(
+    values: &[T],
+    mut writer: W,
+) -> io::Result<()> {
+    write_npy_rows(&[values.len()], [values.iter().copied()], &mut writer)
+}
+
+/// Writes a dense matrix as a two-dimensional `.npy` array in row-major
+/// order.
+///
+/// # Arguments
+///
+/// * `matrix`: The matrix to write.
+/// * `writer`: The destination of the array.
+///
+/// # Errors
+///
+/// Returns any error raised by the writer.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{impls::VecMatrix2D, io::write_dense_npy};
+///
+/// let matrix = VecMatrix2D::new(2, 3, vec![1.0_f32, 2.0, 3.0, 4.0, 5.0, 6.0]);
+/// let mut bytes = Vec::new();
+/// write_dense_npy(&matrix, &mut bytes).unwrap();
+/// assert_eq!(bytes.len(), 128 + 6 * 4);
+/// assert_eq!(&bytes[128..132], &1.0_f32.to_le_bytes());
+/// ```
+#[inline]
+pub fn write_dense_npy<M, W>(matrix: &M, mut writer: W) -> io::Result<()>
+where
+    M: DenseValuedMatrix2D,
+    M::Value: NpyScalar,
+    W: Write,
+{
+    write_npy_rows(
+        &[matrix.number_of_rows().as_(), matrix.number_of_columns().as_()],
+        matrix.row_indices().map(|row| matrix.row_values(row)),
+        &mut writer,
+    )
+}
+
+/// Writes a sparse matrix as a `.npz` archive following the layout of
+/// `scipy.sparse.save_npz`.
+///
+/// The archive holds the `indptr` (offsets), `indices` and `data` (values)
+/// arrays of the CSR representation, together with the `shape` and `format`
+/// entries `scipy` expects. The indices keep the integer types of the matrix.
+///
+/// # Arguments
+///
+/// * `matrix`: The matrix to write.
+/// * `writer`: The destination of the archive.
+///
+/// # Errors
+///
+/// * Returns any error raised by the writer.
+/// * Returns an error of kind [`io::ErrorKind::InvalidInput`] if an array
+///   exceeds the 4 GiB limit of the archive format without the ZIP64
+///   extensions.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{io::write_csr_npz, prelude::*};
+///
+/// let mut matrix: ValuedCSR2D<u32, u32, u32, f64> = SparseMatrixMut::with_sparse_shape((2, 3));
+/// MatrixMut::add(&mut matrix, (0, 2, 1.5)).unwrap();
+/// MatrixMut::add(&mut matrix, (1, 0, -2.0)).unwrap();
+///
+/// let mut bytes = Vec::new();
+/// write_csr_npz(&matrix, &mut bytes).unwrap();
+/// assert!(bytes.starts_with(b"PK\x03\x04"));
+/// ```
+#[inline]
+pub fn write_csr_npz<M, W>(matrix: &M, writer: W) -> io::Result<()>
+where
+    M: SizedSparseMatrix2D + SparseValuedMatrix2D,
+    M::SparseIndex: NpyScalar,
+    M::ColumnIndex: NpyScalar,
+    M::Value: NpyScalar,
+    W: Write,
+{
+    let mut data = Vec::new();
+    write_npy_rows(
+        &[matrix.number_of_defined_values().as_()],
+        matrix.row_indices().map(|row| matrix.sparse_row_values(row)),
+        &mut data,
+    )?;
+    write_scipy_npz(matrix, &data, writer)
+}
+
+/// Writes the sparsity pattern of a matrix as a `.npz` archive following the
+/// layout of `scipy.sparse.save_npz`, with a boolean `data` array.
+///
+/// This is the variant of [`write_csr_npz`] for matrices without values,
+/// such as the adjacency matrices of unweighted graphs.
+///
+/// # Arguments
+///
+/// * `matrix`: The matrix to write.
+/// * `writer`: The destination of the archive.
+///
+/// # Errors
+///
+/// * Returns any error raised by the writer.
+/// * Returns an error of kind [`io::ErrorKind::InvalidInput`] if an array
+///   exceeds the 4 GiB limit of the archive format without the ZIP64
+///   extensions.
+#[inline]
+pub fn write_csr_pattern_npz<M, W>(matrix: &M, writer: W) -> io::Result<()>
+where
+    M: SizedSparseMatrix2D,
+    M::SparseIndex: NpyScalar,
+    M::ColumnIndex: NpyScalar,
+    W: Write,
+{
+    let number_of_values: usize = matrix.number_of_defined_values().as_();
+    let mut data = Vec::new();
+    write_npy_header("|b1", &[number_of_values], &mut data)?;
+    data.extend(core::iter::repeat_n(1_u8, number_of_values));
+    write_scipy_npz(matrix, &data, writer)
+}
+
+/// Writes the `.npz` archive of a CSR matrix, given its already encoded
+/// `data` array.
+fn write_scipy_npz<M, W>(matrix: &M, data: &[u8], writer: W) -> io::Result<()>
+where
+    M: SizedSparseMatrix2D,
+    M::SparseIndex: NpyScalar,
+    M::ColumnIndex: NpyScalar,
+    W: Write,
+{
+    let number_of_rows: usize = matrix.number_of_rows().as_();
+    let number_of_values: usize = matrix.number_of_defined_values().as_();
+
+    let mut indices = Vec::new();
+    write_npy_rows(
+        &[number_of_values],
+        matrix.row_indices().map(|row| matrix.sparse_row(row)),
+        &mut indices,
+    )?;
+
+    let mut indptr = Vec::new();
+    write_npy_rows(
+        &[number_of_rows + 1],
+        [matrix
+            .row_indices()
+            .map(|row| matrix.rank_row(row))
+            .chain(core::iter::once(matrix.number_of_defined_values()))],
+        &mut indptr,
+    )?;
+
+    // `scipy` stores the format as a zero-dimensional byte string.
+    let mut format = Vec::new();
+    write_npy_header("|S3", &[], &mut format)?;
+    format.extend_from_slice(b"csr");
+
+    let mut shape = Vec::new();
+    write_npy_vector(&[number_of_rows, matrix.number_of_columns().as_()], &mut shape)?;
+
+    write_stored_zip(
+        &[
+            ("indices.npy", &indices),
+            ("indptr.npy", &indptr),
+            ("format.npy", &format),
+            ("shape.npy", &shape),
+            ("data.npy", data),
+        ],
+        writer,
+    )
+}
+
+/// Writes an uncompressed ZIP archive holding the provided members.
+fn write_stored_zip<W: Write>(members: &[(&str, &[u8])], mut writer: W) -> io::Result<()> {
+    /// Version 2.0 of the specification, the minimum readers must support.
+    const VERSION: u16 = 20;
+    /// The DOS date of 1980-01-01, the earliest representable date.
+    const DOS_DATE: u16 = 0x0021;
+
+    let too_large = || {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "The .npz archive exceeds the 4 GiB limit of the ZIP format",
+        )
+    };
+    let to_u32 = |value: usize| u32::try_from(value).map_err(|_| too_large());
+    let to_u16 = |value: usize| u16::try_from(value).map_err(|_| too_large());
+
+    let mut central_directory = Vec::new();
+    let mut offset = 0;
+    for (name, content) in members {
+        let mut header = Vec::new();
+        header.extend_from_slice(&VERSION.to_le_bytes());
+        // Flags, compression method and modification time.
+        header.extend_from_slice(&[0; 6]);
+        header.extend_from_slice(&DOS_DATE.to_le_bytes());
+        header.extend_from_slice(&crc32(content).to_le_bytes());
+        // The compressed and uncompressed sizes coincide for stored members.
+        header.extend_from_slice(&to_u32(content.len())?.to_le_bytes());
+        header.extend_from_slice(&to_u32(content.len())?.to_le_bytes());
+        header.extend_from_slice(&to_u16(name.len())?.to_le_bytes());
+        // Length of the extra field.
+        header.extend_from_slice(&[0; 2]);
+
+        writer.write_all(&0x0403_4b50_u32.to_le_bytes())?;
+        writer.write_all(&header)?;
+        writer.write_all(name.as_bytes())?;
+        writer.write_all(content)?;
+
+        central_directory.extend_from_slice(&0x0201_4b50_u32.to_le_bytes());
+        central_directory.extend_from_slice(&VERSION.to_le_bytes());
+        central_directory.extend_from_slice(&header);
+        // Lengths of the comment, disk number, internal and external
+        // attributes.
+        central_directory.extend_from_slice(&[0; 10]);
+        central_directory.extend_from_slice(&to_u32(offset)?.to_le_bytes());
+        central_directory.extend_from_slice(name.as_bytes());
+
+        offset += 4 + header.len() + name.len() + content.len();
+    }
+
+    let number_of_members = to_u16(members.len())?;
+    writer.write_all(&central_directory)?;
+    writer.write_all(&0x0605_4b50_u32.to_le_bytes())?;
+    // Number of this disk and of the disk holding the central directory.
+    writer.write_all(&[0; 4])?;
+    writer.write_all(&number_of_members.to_le_bytes())?;
+    writer.write_all(&number_of_members.to_le_bytes())?;
+    writer.write_all(&to_u32(central_directory.len())?.to_le_bytes())?;
+    writer.write_all(&to_u32(offset)?.to_le_bytes())?;
+    // Length of the archive comment.
+    writer.write_all(&[0; 2])
+}
@@ -0,0 +1,623 @@
+//! Submodule providing a reader and writers for the Pajek `.net` format.
+//!
+//! A Pajek network lists its vertices, numbered from one, followed by the
+//! arcs (directed) and edges (undirected) connecting them:
+//!
+//! ```text
+//! *Vertices 3
+//! 1 "alanine"
+//! 2 "glycine"
+//! 3 "serine"
+//! *Arcs
+//! 1 2 0.5
+//! *Edges
+//! 2 3 0.25
+//! ```
+//!
+//! The labels of the vertices are the symbols of the node vocabulary, so they
+//! round-trip exactly, while the vertex numbers are only used to refer to the
+//! vertices within the file. Vertices without a line of their own are
+//! labelled with their number, as Pajek does. Coordinates and other vertex
+//! properties following the label are ignored, and so are the `*Network`
+//! line and the lines starting with `%`. Arcs and edges may also be given in
+//! the `*Arcslist` and `*Edgeslist` form, where each line lists a vertex
+//! followed by its neighbours. Missing weights default to one.
+
+use std::{
+    fmt::Display,
+    io::{BufRead, Write},
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use super::edge_index::{IndexedEdges, RawEdge};
+use crate::{
+    impls::SortedVec,
+    naive_structs::{DiGraph, UndiGraph, WeightedDiGraph},
+    traits::{MonopartiteGraph, MonoplexGraph, Symbol, WeightedMonoplexGraph},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+/// Errors raised while reading a Pajek network.
+///
+/// Every variant referring to the content of the file carries the one-based
+/// line number where the problem was detected.
+pub enum PajekReadError {
+    /// The underlying reader failed.
+    #[error("I/O error of kind {kind:?} while reading line {line}")]
+    Io {
+        /// The line that was being read.
+        line: usize,
+        /// The kind of the I/O error.
+        kind: std::io::ErrorKind,
+    },
+    /// The network does not start with a `*Vertices` line.
+    #[error("Line {line} precedes the `*Vertices` line")]
+    MissingVertices {
+        /// The first line which is not a comment.
+        line: usize,
+    },
+    /// The `*Vertices` line does not hold a valid number of vertices.
+    #[error("Line {line} does not declare a valid number of vertices")]
+    InvalidVertexCount {
+        /// The line of the `*Vertices` section.
+        line: usize,
+    },
+    /// A section other than vertices, arcs and edges was found.
+    #[error("Line {line} opens the unsupported section `{section}`")]
+    UnsupportedSection {
+        /// The line of the section.
+        line: usize,
+        /// The name of the section, including the leading `*`.
+        section: String,
+    },
+    /// A vertex number is not between one and the number of vertices.
+    #[error("Line {line} refers to the invalid vertex `{vertex}`")]
+    InvalidVertex {
+        /// The line referring to the vertex.
+        line: usize,
+        /// The raw vertex number.
+        vertex: String,
+    },
+    /// A vertex is described by more than one line.
+    #[error("Line {line} describes the vertex {vertex} again")]
+    RepeatedVertex {
+        /// The line of the repeated description.
+        line: usize,
+        /// The vertex number.
+        vertex: usize,
+    },
+    /// Two vertices have the same label.
+    #[error("Line {line} repeats the label `{label}`")]
+    DuplicatedLabel {
+        /// The line of the vertex with the repeated label, or of the
+        /// `*Vertices` line for the vertices labelled with their number.
+        line: usize,
+        /// The repeated label.
+        label: String,
+    },
+    /// A quoted label is not terminated before the end of the line.
+    #[error("Line {line} contains an unterminated label")]
+    UnterminatedLabel {
+        /// The line of the malformed label.
+        line: usize,
+    },
+    /// An arc or edge line does not hold two vertices.
+    #[error("Line {line} does not describe an arc or an edge")]
+    MissingVertex {
+        /// The line of the malformed arc or edge.
+        line: usize,
+    },
+    /// A weight could not be parsed as a finite floating point number.
+    #[error("Line {line} has an invalid weight `{value}`")]
+    InvalidWeight {
+        /// The line of the malformed weight.
+        line: usize,
+        /// The raw weight.
+        value: String,
+    },
+    /// An arc was found while reading an undirected graph.
+    #[error("Line {line} holds an arc, but an undirected graph was requested")]
+    ArcInUndirectedGraph {
+        /// The line of the arc.
+        line: usize,
+    },
+    /// The same arc or edge appears more than once, and duplicates are not
+    /// ignored.
+    #[error("Line {line} repeats the edge from `{source_symbol}` to `{destination_symbol}`")]
+    DuplicatedEdge {
+        /// The line of the repeated arc or edge.
+        line: usize,
+        /// The label of the source vertex.
+        source_symbol: String,
+        /// The label of the destination vertex.
+        destination_symbol: String,
+    },
+}
+
+#[derive(Debug, thiserror::Error)]
+/// Errors raised while writing a Pajek network.
+pub enum PajekWriteError {
+    /// A label contains a double quote or a line break, which Pajek labels
+    /// cannot represent.
+    #[error("The label `{label}` of node {node} cannot be written as a Pajek label")]
+    UnrepresentableLabel {
+        /// The identifier of the node.
+        node: usize,
+        /// The label of the node.
+        label: String,
+    },
+    /// The writer raised an error.
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+/// The section of the network being read.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Section {
+    Vertices,
+    Arcs { list: bool },
+    Edges { list: bool },
+}
+
+/// An arc or edge read from the network, referring to vertex numbers.
+struct PajekLink {
+    line: usize,
+    source: usize,
+    destination: usize,
+    weight: f64,
+    directed: bool,
+}
+
+/// The vertex labels and the links of a network.
+struct PajekNetwork {
+    labels: Vec<String>,
+    links: Vec<PajekLink>,
+}
+
+/// Splits a line into whitespace-separated tokens, keeping quoted labels
+/// whole and without their quotes.
+fn tokenize(line: &str, line_number: usize) -> Result<Vec<&str>, PajekReadError> {
+    let mut tokens = Vec::new();
+    let mut rest = line.trim_start();
+    while !rest.is_empty() {
+        if let Some(quoted) = rest.strip_prefix('"') {
+            let end =
+                quoted.find('"').ok_or(PajekReadError::UnterminatedLabel { line: line_number })?;
+            tokens.push(&quoted[..end]);
+            rest = quoted[end + 1..].trim_start();
+        } else {
+            let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            tokens.push(&rest[..end]);
+            rest = rest[end..].trim_start();
+        }
+    }
+    Ok(tokens)
+}
+
+/// Parses a one-based vertex number, returning the zero-based position.
+fn parse_vertex(
+    token: &str,
+    number_of_vertices: usize,
+    line: usize,
+) -> Result<usize, PajekReadError> {
+    match token.parse::<usize>() {
+        Ok(vertex) if (1..=number_of_vertices).contains(&vertex) => Ok(vertex - 1),
+        _ => Err(PajekReadError::InvalidVertex { line, vertex: token.to_string() }),
+    }
+}
+
+/// Parses an arc or edge line, or a line of an arcs or edges list.
+fn parse_links(
+    tokens: &[&str],
+    list: bool,
+    directed: bool,
+    number_of_vertices: usize,
+    line: usize,
+    links: &mut Vec<PajekLink>,
+) -> Result<(), PajekReadError> {
+    if tokens.len() < 2 {
+        return Err(PajekReadError::MissingVertex { line });
+    }
+    let source = parse_vertex(tokens[0], number_of_vertices, line)?;
+    if list {
+        for token in &tokens[1..] {
+            let destination = parse_vertex(token, number_of_vertices, line)?;
+            links.push(PajekLink { line, source, destination, weight: 1.0, directed });
+        }
+        return Ok(());
+    }
+    let destination = parse_vertex(tokens[1], number_of_vertices, line)?;
+    let weight = match tokens.get(2) {
+        None => 1.0,
+        Some(raw) => {
+            match raw.parse::<f64>() {
+                Ok(weight) if weight.is_finite() => weight,
+                _ => return Err(PajekReadError::InvalidWeight { line, value: (*raw).to_string() }),
+            }
+        }
+    };
+    links.push(PajekLink { line, source, destination, weight, directed });
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// Reader of Pajek networks, configured with a builder-style API.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{io::PajekReader, prelude::*};
+///
+/// let network = "\
+/// *Vertices 3
+/// 1 \"alanine\" 0.1 0.2 0.5
+/// 2 \"glycine\"
+/// 3 \"L-serine\"
+/// *Arcs
+/// 1 2 0.5
+/// 3 1 2
+/// ";
+///
+/// let graph = PajekReader::default().read_weighted_digraph(network.as_bytes()).unwrap();
+/// assert_eq!(graph.number_of_nodes(), 3);
+/// let serine = graph.nodes_vocabulary().invert(&"L-serine".to_string()).unwrap();
+/// assert_eq!(graph.successor_weights(serine).collect::<Vec<f64>>(), vec![2.0]);
+/// ```
+pub struct PajekReader {
+    /// Whether to silently drop repeated arcs and edges.
+    ignore_duplicates: bool,
+}
+
+impl PajekReader {
+    #[must_use]
+    /// Silently drops repeated arcs and edges instead of reporting them as
+    /// errors.
+    ///
+    /// For weighted graphs, the weight of the first occurrence is kept.
+    #[inline]
+    pub fn ignore_duplicates(mut self) -> Self {
+        self.ignore_duplicates = true;
+        self
+    }
+
+    /// Reads the labels and the links of a network.
+    fn read_network<R: BufRead>(reader: R) -> Result<PajekNetwork, PajekReadError> {
+        let mut labels: Vec<Option<(String, usize)>> = Vec::new();
+        let mut vertices_line = 0;
+        let mut section = None;
+        let mut links = Vec::new();
+
+        for (line_index, line) in reader.lines().enumerate() {
+            let line_number = line_index + 1;
+            let line =
+                line.map_err(|error| PajekReadError::Io { line: line_number, kind: error.kind() })?;
+            let content = line.trim();
+            if content.is_empty() || content.starts_with('%') {
+                continue;
+            }
+
+            if content.starts_with('*') {
+                let tokens = tokenize(content, line_number)?;
+                let keyword = tokens[0].to_ascii_lowercase();
+                if section.is_none() && keyword != "*vertices" {
+                    if keyword == "*network" {
+                        continue;
+                    }
+                    return Err(PajekReadError::MissingVertices { line: line_number });
+                }
+                section = Some(match keyword.as_str() {
+                    "*vertices" if section.is_none() => {
+                        let number_of_vertices = tokens
+                            .get(1)
+                            .and_then(|count| count.parse::<usize>().ok())
+                            .ok_or(PajekReadError::InvalidVertexCount { line: line_number })?;
+                        labels = vec![None; number_of_vertices];
+                        vertices_line = line_number;
+                        Section::Vertices
+                    }
+                    "*arcs" => Section::Arcs { list: false },
+                    "*arcslist" => Section::Arcs { list: true },
+                    "*edges" => Section::Edges { list: false },
+                    "*edgeslist" => Section::Edges { list: true },
+                    _ => {
+                        return Err(PajekReadError::UnsupportedSection {
+                            line: line_number,
+                            section: tokens[0].to_string(),
+                        });
+                    }
+                });
+                continue;
+            }
+
+            let tokens = tokenize(content, line_number)?;
+            match section {
+                None => return Err(PajekReadError::MissingVertices { line: line_number }),
+                Some(Section::Vertices) => {
+                    let vertex = parse_vertex(tokens[0], labels.len(), line_number)?;
+                    if labels[vertex].is_some() {
+                        return Err(PajekReadError::RepeatedVertex {
+                            line: line_number,
+                            vertex: vertex + 1,
+                        });
+                    }
+                    let label =
+                        tokens.get(1).map_or_else(|| tokens[0].to_string(), ToString::to_string);
+                    labels[vertex] = Some((label, line_number));
+                }
+                Some(Section::Arcs { list }) => {
+                    parse_links(&tokens, list, true, labels.len(), line_number, &mut links)?;
+                }
+                Some(Section::Edges { list }) => {
+                    parse_links(&tokens, list, false, labels.len(), line_number, &mut links)?;
+                }
+            }
+        }
+
+        let labels: Vec<(String, usize)> = labels
+            .into_iter()
+            .enumerate()
+            .map(|(vertex, label)| {
+                label.unwrap_or_else(|| ((vertex + 1).to_string(), vertices_line))
+            })
+            .collect();
+
+        let mut sorted: Vec<(&str, usize)> =
+            labels.iter().map(|(label, line)| (label.as_str(), *line)).collect();
+        sorted.sort_unstable();
+        if let Some(window) = sorted.windows(2).find(|window| window[0].0 == window[1].0) {
+            let (label, line) = window[1];
+            return Err(PajekReadError::DuplicatedLabel { line, label: label.to_string() });
+        }
+        let labels = labels.into_iter().map(|(label, _)| label).collect();
+
+        Ok(PajekNetwork { labels, links })
+    }
+
+    /// Indexes the links of a network, reporting repeated edges unless they
+    /// are ignored.
+    fn index_network(
+        self,
+        network: &PajekNetwork,
+        undirected: bool,
+    ) -> Result<IndexedEdges<String>, PajekReadError> {
+        let mut raw_edges: Vec<RawEdge<'_, str>> = Vec::with_capacity(network.links.len());
+        for link in &network.links {
+            if undirected && link.directed {
+                return Err(PajekReadError::ArcInUndirectedGraph { line: link.line });
+            }
+            let source = network.labels[link.source].as_str();
+            let destination = network.labels[link.destination].as_str();
+            raw_edges.push(RawEdge {
+                position: link.line,
+                source,
+                destination,
+                weight: link.weight,
+            });
+            // In a directed graph, an edge stands for the arcs in both
+            // directions.
+            if !undirected && !link.directed && link.source != link.destination {
+                raw_edges.push(RawEdge {
+                    position: link.line,
+                    source: destination,
+                    destination: source,
+                    weight: link.weight,
+                });
+            }
+        }
+        IndexedEdges::with_nodes(
+            network.labels.iter().map(String::as_str),
+            &raw_edges,
+            undirected,
+            self.ignore_duplicates,
+        )
+        .map_err(|repeated| {
+            PajekReadError::DuplicatedEdge {
+                line: repeated.position,
+                source_symbol: repeated.source_symbol,
+                destination_symbol: repeated.destination_symbol,
+            }
+        })
+    }
+
+    /// Reads a directed graph from a Pajek network.
+    ///
+    /// Each edge is read as the two arcs in opposite directions, and weights
+    /// are validated but otherwise ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The source of the network.
+    ///
+    /// # Errors
+    ///
+    /// * If the network is malformed.
+    /// * If two vertices have the same label.
+    /// * If an arc is repeated and duplicates are not ignored.
+    pub fn read_digraph<R: BufRead>(self, reader: R) -> Result<DiGraph<String>, PajekReadError> {
+        Ok(self.index_network(&Self::read_network(reader)?, false)?.into_digraph())
+    }
+
+    /// Reads an undirected graph from a Pajek network.
+    ///
+    /// Weights are validated but otherwise ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The source of the network.
+    ///
+    /// # Errors
+    ///
+    /// * If the network is malformed or contains arcs.
+    /// * If two vertices have the same label.
+    /// * If an edge is repeated and duplicates are not ignored.
+    pub fn read_undigraph<R: BufRead>(
+        self,
+        reader: R,
+    ) -> Result<UndiGraph<String>, PajekReadError> {
+        Ok(self.index_network(&Self::read_network(reader)?, true)?.into_undigraph())
+    }
+
+    /// Reads a weighted directed graph from a Pajek network.
+    ///
+    /// Each edge is read as the two arcs in opposite directions, both with the
+    /// weight of the edge.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The source of the network.
+    ///
+    /// # Errors
+    ///
+    /// * If the network is malformed.
+    /// * If two vertices have the same label.
+    /// * If an arc is repeated and duplicates are not ignored.
+    pub fn read_weighted_digraph<R: BufRead>(
+        self,
+        reader: R,
+    ) -> Result<WeightedDiGraph<String>, PajekReadError> {
+        Ok(self.index_network(&Self::read_network(reader)?, false)?.into_weighted_digraph())
+    }
+}
+
+/// Writes a Pajek network, given the links as triples of source, destination
+/// and optional weight.
+fn write_pajek<W, S, I>(
+    mut writer: W,
+    directed: bool,
+    nodes: &SortedVec<S>,
+    links: I,
+) -> Result<(), PajekWriteError>
+where
+    W: Write,
+    S: Display,
+    I: Iterator<Item = (usize, usize, Option<f64>)>,
+{
+    writeln!(writer, "*Vertices {}", nodes.len())?;
+    for (node, symbol) in nodes.iter().enumerate() {
+        let label = symbol.to_string();
+        if label.contains(['"', '\n', '\r']) {
+            return Err(PajekWriteError::UnrepresentableLabel { node, label });
+        }
+        writeln!(writer, "{} \"{label}\"", node + 1)?;
+    }
+    writeln!(writer, "{}", if directed { "*Arcs" } else { "*Edges" })?;
+    for (source, destination, weight) in links {
+        match weight {
+            Some(weight) => writeln!(writer, "{} {} {weight}", source + 1, destination + 1)?,
+            None => writeln!(writer, "{} {}", source + 1, destination + 1)?,
+        }
+    }
+    Ok(())
+}
+
+/// Writes a directed graph as a Pajek network.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to write.
+/// * `writer` - The destination of the network.
+///
+/// # Errors
+///
+/// * If a label contains a double quote or a line break.
+/// * If the writer raises an error.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{
+///     io::{PajekReader, write_pajek_digraph},
+///     prelude::*,
+/// };
+///
+/// let graph = PajekReader::default()
+///     .read_digraph("*Vertices 2\n1 \"glycine\"\n2 \"alanine\"\n*Arcs\n1 2\n".as_bytes())
+///     .unwrap();
+///
+/// let mut network = Vec::new();
+/// write_pajek_digraph(&graph, &mut network).unwrap();
+/// assert_eq!(
+///     String::from_utf8(network).unwrap(),
+///     "*Vertices 2\n1 \"alanine\"\n2 \"glycine\"\n*Arcs\n2 1\n"
+/// );
+/// ```
+pub fn write_pajek_digraph<S, W>(graph: &DiGraph<S>, writer: W) -> Result<(), PajekWriteError>
+where
+    S: Symbol + Ord + Display,
+    W: Write,
+{
+    write_pajek(
+        writer,
+        true,
+        graph.nodes_vocabulary(),
+        graph.node_ids().flat_map(|source| {
+            graph.successors(source).map(move |destination| (source, destination, None))
+        }),
+    )
+}
+
+/// Writes an undirected graph as a Pajek network, listing each edge once.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to write.
+/// * `writer` - The destination of the network.
+///
+/// # Errors
+///
+/// * If a label contains a double quote or a line break.
+/// * If the writer raises an error.
+pub fn write_pajek_undigraph<S, W>(graph: &UndiGraph<S>, writer: W) -> Result<(), PajekWriteError>
+where
+    S: Symbol + Ord + Display,
+    W: Write,
+{
+    write_pajek(
+        writer,
+        false,
+        graph.nodes_vocabulary(),
+        graph.node_ids().flat_map(|source| {
+            graph
+                .successors(source)
+                .filter(move |&destination| destination >= source)
+                .map(move |destination| (source, destination, None))
+        }),
+    )
+}
+
+/// Writes a weighted directed graph as a Pajek network.
+///
+/// Weights are written in their shortest form which parses back to the same
+/// value, so they round-trip exactly.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to write.
+/// * `writer` - The destination of the network.
+///
+/// # Errors
+///
+/// * If a label contains a double quote or a line break.
+/// * If the writer raises an error.
+pub fn write_pajek_weighted_digraph<S, W>(
+    graph: &WeightedDiGraph<S>,
+    writer: W,
+) -> Result<(), PajekWriteError>
+where
+    S: Symbol + Ord + Display,
+    W: Write,
+{
+    write_pajek(
+        writer,
+        true,
+        graph.nodes_vocabulary(),
+        graph.node_ids().flat_map(|source| {
+            graph
+                .successors(source)
+                .zip(graph.successor_weights(source))
+                .map(move |(destination, weight)| (source, destination, Some(weight)))
+        }),
+    )
+}
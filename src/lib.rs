@@ -13,10 +13,16 @@ extern crate mem_dbg_crate as mem_dbg;
 
 pub mod errors;
 pub mod impls;
+#[cfg(feature = "alloc")]
+pub mod io;
 pub mod naive_structs;
+#[cfg(feature = "alloc")]
+pub mod spatial;
 #[cfg(feature = "arbitrary")]
 pub mod test_utils;
 pub mod traits;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 /// Prelude module for the graph crate.
 pub mod prelude {
@@ -18,6 +18,8 @@ pub mod undirected_edges_builder;
 pub use directory_tree::DirectoryTree;
 pub use generic_bigraph::GenericBiGraph;
 pub use generic_edges_builder::GenericEdgesBuilder;
+#[cfg(feature = "std")]
+pub use generic_graph::AdjacencyMapError;
 pub use generic_graph::GenericGraph;
 pub use generic_monoplex_bipartite_graph_builder::GenericMonoplexBipartiteGraphBuilder;
 pub use generic_vocabulary_builder::GenericVocabularyBuilder;
@@ -6,11 +6,21 @@ use alloc::{string::String, vec::Vec};
 use num_traits::AsPrimitive;
 
 use super::generic_monoplex_bipartite_graph_builder::MonoplexBipartiteGraphBuilderError;
-use crate::traits::{
-    BidirectionalVocabulary, BipartiteGraph, Edges, Graph, MonoplexGraph, PositiveInteger,
-    TryFromUsize, Vocabulary,
+#[cfg(feature = "alloc")]
+use crate::traits::{MemoryFootprint, MemoryUsage};
+use crate::{
+    errors::InvariantError,
+    traits::{
+        BidirectionalVocabulary, BipartiteGraph, Edges, Graph, Matrix2D, MonoplexGraph,
+        PositiveInteger, TryFromUsize, Validate, Vocabulary,
+    },
 };
 
+#[cfg(feature = "std")]
+mod adjacency_map;
+#[cfg(feature = "serde")]
+mod serde_impl;
+
 #[cfg_attr(feature = "mem_size", derive(mem_dbg::MemSize))]
 #[cfg_attr(feature = "mem_size", mem_size(rec))]
 #[cfg_attr(feature = "mem_dbg", derive(mem_dbg::MemDbg))]
@@ -70,6 +80,29 @@ where
     }
 }
 
+/// Checks that the rows of the edges only refer to left nodes and their
+/// columns to right nodes.
+fn check_nodes<LeftNodes, RightNodes, E>(
+    left_nodes: &LeftNodes,
+    right_nodes: &RightNodes,
+    edges: &E,
+) -> Result<(), InvariantError>
+where
+    LeftNodes: Vocabulary,
+    RightNodes: Vocabulary,
+    E: Edges,
+{
+    for (referenced, number_of_nodes) in [
+        (edges.matrix().number_of_rows().as_(), left_nodes.len()),
+        (edges.matrix().number_of_columns().as_(), right_nodes.len()),
+    ] {
+        if referenced > number_of_nodes {
+            return Err(InvariantError::MissingNodes { referenced, number_of_nodes });
+        }
+    }
+    Ok(())
+}
+
 impl<LeftNodes, RightNodes, E> Graph for GenericBiGraph<LeftNodes, RightNodes, E>
 where
     LeftNodes: Vocabulary,
@@ -127,3 +160,39 @@ where
         &self.edges
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<LeftNodes, RightNodes, E> MemoryFootprint for GenericBiGraph<LeftNodes, RightNodes, E>
+where
+    LeftNodes: MemoryFootprint,
+    RightNodes: MemoryFootprint,
+    E: MemoryFootprint,
+{
+    #[inline]
+    fn memory_usage(&self) -> MemoryUsage {
+        self.left_nodes
+            .memory_usage()
+            .with_usage(self.right_nodes.memory_usage())
+            .with_usage(self.edges.memory_usage())
+    }
+
+    #[inline]
+    fn shrink_to_fit(&mut self) {
+        self.left_nodes.shrink_to_fit();
+        self.right_nodes.shrink_to_fit();
+        self.edges.shrink_to_fit();
+    }
+}
+
+impl<LeftNodes, RightNodes, E> Validate for GenericBiGraph<LeftNodes, RightNodes, E>
+where
+    LeftNodes: Vocabulary,
+    RightNodes: Vocabulary,
+    E: Edges + Validate,
+{
+    #[inline]
+    fn validate(&self) -> Result<(), InvariantError> {
+        self.edges.validate()?;
+        check_nodes(&self.left_nodes, &self.right_nodes, &self.edges)
+    }
+}
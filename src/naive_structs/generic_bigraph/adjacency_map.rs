@@ -0,0 +1,81 @@
+//! Submodule providing conversions between weighted bipartite graphs and
+//! nested `HashMap` adjacency representations, mapping each left symbol to
+//! its right symbols and edge weights.
+
+use core::hash::BuildHasher;
+use std::{collections::HashMap, vec::Vec};
+
+use crate::{
+    naive_structs::{
+        AdjacencyMapError, WeightedBiGraph,
+        generic_graph::{sorted_vocabulary, symbol_of, weighted_edges},
+    },
+    traits::{BipartiteGraph, MonoplexGraph, Symbol, WeightedMonoplexGraph},
+};
+
+impl<L, R, V, H> TryFrom<HashMap<L, Vec<(R, V)>, H>> for WeightedBiGraph<L, R>
+where
+    L: Symbol + Ord,
+    R: Symbol + Ord,
+    V: Into<f64>,
+    H: BuildHasher,
+{
+    type Error = AdjacencyMapError<L, R>;
+
+    /// Builds the graph from an adjacency map, where every key becomes a left
+    /// node and every destination a right node, both sorted by symbol.
+    fn try_from(adjacency: HashMap<L, Vec<(R, V)>, H>) -> Result<Self, Self::Error> {
+        let left_nodes = sorted_vocabulary(adjacency.keys().cloned().collect());
+        let right_nodes = sorted_vocabulary(
+            adjacency
+                .values()
+                .flat_map(|destinations| destinations.iter().map(|(destination, _)| destination))
+                .cloned()
+                .collect(),
+        );
+        let edges = weighted_edges(adjacency, &left_nodes, &right_nodes)?;
+        Ok(WeightedBiGraph::try_from((left_nodes, right_nodes, edges)).unwrap_or_else(|_| {
+            unreachable!("Building a bipartite graph from its parts never fails.")
+        }))
+    }
+}
+
+impl<L: Symbol + Ord, R: Symbol + Ord> WeightedBiGraph<L, R> {
+    /// Returns the adjacency map of the graph, mapping every left node symbol
+    /// to its right successors and edge weights, sorted by successor symbol.
+    ///
+    /// Left nodes without successors are kept with an empty adjacency list,
+    /// while right nodes without predecessors do not appear in the map, so
+    /// that converting it back drops them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// use geometric_traits::prelude::*;
+    ///
+    /// let adjacency: HashMap<&str, Vec<(u8, f64)>> =
+    ///     HashMap::from([("a", vec![(1, 1.5), (2, 2.0)]), ("b", vec![(2, 0.5)]), ("c", vec![])]);
+    /// let graph = WeightedBiGraph::try_from(adjacency.clone()).unwrap();
+    /// assert_eq!(graph.number_of_left_nodes(), 3);
+    /// assert_eq!(graph.number_of_right_nodes(), 2);
+    /// assert_eq!(graph.number_of_edges(), 3);
+    /// assert_eq!(graph.to_adjacency_map(), adjacency);
+    /// ```
+    #[must_use]
+    pub fn to_adjacency_map(&self) -> HashMap<L, Vec<(R, f64)>> {
+        let left_nodes = self.left_nodes_vocabulary();
+        let right_nodes = self.right_nodes_vocabulary();
+        self.left_node_ids()
+            .map(|source| {
+                let successors = self
+                    .successors(source)
+                    .zip(self.successor_weights(source))
+                    .map(|(destination, weight)| (symbol_of(right_nodes, destination), weight))
+                    .collect();
+                (symbol_of(left_nodes, source), successors)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,58 @@
+//! Implementation of the `serde` traits for the `GenericBiGraph` struct.
+//!
+//! Both vocabularies and the edges are validated by their own deserializers;
+//! here we additionally check that the edges only refer to nodes defined in
+//! the corresponding vocabularies.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+
+use super::{GenericBiGraph, check_nodes};
+use crate::traits::{Edges, Vocabulary};
+
+#[derive(Serialize)]
+#[serde(rename = "GenericBiGraph")]
+struct GenericBiGraphRef<'a, LeftNodes, RightNodes, E> {
+    left_nodes: &'a LeftNodes,
+    right_nodes: &'a RightNodes,
+    edges: &'a E,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "GenericBiGraph")]
+struct RawGenericBiGraph<LeftNodes, RightNodes, E> {
+    left_nodes: LeftNodes,
+    right_nodes: RightNodes,
+    edges: E,
+}
+
+impl<LeftNodes, RightNodes, E> Serialize for GenericBiGraph<LeftNodes, RightNodes, E>
+where
+    LeftNodes: Serialize,
+    RightNodes: Serialize,
+    E: Serialize,
+{
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        GenericBiGraphRef {
+            left_nodes: &self.left_nodes,
+            right_nodes: &self.right_nodes,
+            edges: &self.edges,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, LeftNodes, RightNodes, E> Deserialize<'de> for GenericBiGraph<LeftNodes, RightNodes, E>
+where
+    LeftNodes: Vocabulary + Deserialize<'de>,
+    RightNodes: Vocabulary + Deserialize<'de>,
+    E: Edges + Deserialize<'de>,
+{
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let RawGenericBiGraph { left_nodes, right_nodes, edges } =
+            RawGenericBiGraph::<LeftNodes, RightNodes, E>::deserialize(deserializer)?;
+        check_nodes(&left_nodes, &right_nodes, &edges).map_err(D::Error::custom)?;
+        Ok(Self { left_nodes, right_nodes, edges })
+    }
+}
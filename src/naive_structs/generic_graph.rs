@@ -5,13 +5,28 @@ use alloc::{string::String, vec::Vec};
 
 use num_traits::AsPrimitive;
 
-use crate::traits::{
-    BidirectionalVocabulary, Edges, Graph, MonopartiteGraph, MonoplexGraph, PositiveInteger,
-    TryFromUsize,
+#[cfg(feature = "alloc")]
+use crate::traits::{MemoryFootprint, MemoryUsage};
+use crate::{
+    errors::InvariantError,
+    traits::{
+        AdjacencyDisplay, BidirectionalVocabulary, DISPLAY_LIMIT, Edges, Graph, Matrix2D,
+        MonopartiteGraph, MonoplexGraph, MonoplexMonopartiteGraph, PositiveInteger, TryFromUsize,
+        Validate, Vocabulary,
+    },
 };
 
+#[cfg(feature = "std")]
+mod adjacency_map;
 #[cfg(feature = "arbitrary")]
 mod arbitrary_impl;
+#[cfg(feature = "serde")]
+mod serde_impl;
+
+#[cfg(feature = "std")]
+pub use adjacency_map::AdjacencyMapError;
+#[cfg(feature = "std")]
+pub(crate) use adjacency_map::{sorted_vocabulary, symbol_of, weighted_edges};
 
 #[cfg_attr(feature = "mem_size", derive(mem_dbg::MemSize))]
 #[cfg_attr(feature = "mem_size", mem_size(rec))]
@@ -50,6 +65,17 @@ where
     }
 }
 
+impl<Nodes, Edges> core::fmt::Display for GenericGraph<Nodes, Edges>
+where
+    Self: MonoplexMonopartiteGraph,
+    <Self as MonopartiteGraph>::NodeSymbol: core::fmt::Display,
+{
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.adjacency_display(DISPLAY_LIMIT).fmt(f)
+    }
+}
+
 impl<Nodes, Edges> From<(Nodes, Edges)> for GenericGraph<Nodes, Edges> {
     #[inline]
     fn from((nodes, edges): (Nodes, Edges)) -> Self {
@@ -57,6 +83,19 @@ impl<Nodes, Edges> From<(Nodes, Edges)> for GenericGraph<Nodes, Edges> {
     }
 }
 
+/// Checks that the edges only refer to nodes of the vocabulary.
+fn check_nodes<Nodes: Vocabulary, E: Edges>(
+    nodes: &Nodes,
+    edges: &E,
+) -> Result<(), InvariantError> {
+    let referenced =
+        edges.matrix().number_of_rows().as_().max(edges.matrix().number_of_columns().as_());
+    if referenced > nodes.len() {
+        return Err(InvariantError::MissingNodes { referenced, number_of_nodes: nodes.len() });
+    }
+    Ok(())
+}
+
 impl<Nodes, E> Graph for GenericGraph<Nodes, E>
 where
     Nodes: BidirectionalVocabulary,
@@ -104,3 +143,33 @@ where
         &self.edges
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<Nodes, E> MemoryFootprint for GenericGraph<Nodes, E>
+where
+    Nodes: MemoryFootprint,
+    E: MemoryFootprint,
+{
+    #[inline]
+    fn memory_usage(&self) -> MemoryUsage {
+        self.nodes.memory_usage().with_usage(self.edges.memory_usage())
+    }
+
+    #[inline]
+    fn shrink_to_fit(&mut self) {
+        self.nodes.shrink_to_fit();
+        self.edges.shrink_to_fit();
+    }
+}
+
+impl<Nodes, E> Validate for GenericGraph<Nodes, E>
+where
+    Nodes: Vocabulary,
+    E: Edges + Validate,
+{
+    #[inline]
+    fn validate(&self) -> Result<(), InvariantError> {
+        self.edges.validate()?;
+        check_nodes(&self.nodes, &self.edges)
+    }
+}
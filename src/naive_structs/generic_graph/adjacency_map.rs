@@ -0,0 +1,155 @@
+//! Submodule providing conversions between weighted directed graphs and
+//! nested `HashMap` adjacency representations, mapping each source symbol to
+//! its destination symbols and edge weights.
+//!
+//! The crate has no weighted undirected graph type, whose adjacency map would
+//! have to list each edge from both endpoints; the weighted bipartite graphs
+//! are converted in the `generic_bigraph` module with the helpers below.
+
+use core::hash::BuildHasher;
+use std::{collections::HashMap, vec::Vec};
+
+use crate::{
+    impls::{SortedVec, ValuedCSR2D},
+    naive_structs::{GenericEdgesBuilder, WeightedDiGraph},
+    traits::{
+        EdgesBuilder, MonopartiteGraph, MonoplexGraph, Symbol, Vocabulary, WeightedMonoplexGraph,
+    },
+};
+
+#[derive(Debug, Clone, thiserror::Error, PartialEq, Eq)]
+/// Errors raised while building a graph from an adjacency map.
+pub enum AdjacencyMapError<S, D = S> {
+    #[error("The edge from {source_symbol:?} to {destination_symbol:?} is repeated")]
+    /// The adjacency list of a source lists the same destination more than
+    /// once.
+    DuplicatedEdge {
+        /// The symbol of the source node.
+        source_symbol: S,
+        /// The symbol of the destination node.
+        destination_symbol: D,
+    },
+}
+
+impl<S, V, H> TryFrom<HashMap<S, Vec<(S, V)>, H>> for WeightedDiGraph<S>
+where
+    S: Symbol + Ord,
+    V: Into<f64>,
+    H: BuildHasher,
+{
+    type Error = AdjacencyMapError<S>;
+
+    /// Builds the graph from an adjacency map, where every key and every
+    /// destination becomes a node of the vocabulary, sorted by symbol.
+    fn try_from(adjacency: HashMap<S, Vec<(S, V)>, H>) -> Result<Self, Self::Error> {
+        let nodes = sorted_vocabulary(
+            adjacency
+                .iter()
+                .flat_map(|(source, destinations)| {
+                    core::iter::once(source)
+                        .chain(destinations.iter().map(|(destination, _)| destination))
+                })
+                .cloned()
+                .collect(),
+        );
+        let edges = weighted_edges(adjacency, &nodes, &nodes)?;
+        Ok(WeightedDiGraph::from((nodes, edges)))
+    }
+}
+
+impl<S: Symbol + Ord> WeightedDiGraph<S> {
+    /// Returns the adjacency map of the graph, mapping every node symbol to
+    /// its successors and edge weights, sorted by successor symbol.
+    ///
+    /// Nodes without successors are kept with an empty adjacency list, so
+    /// that converting the map back yields the same graph.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// use geometric_traits::prelude::*;
+    ///
+    /// let adjacency: HashMap<&str, Vec<(&str, f64)>> =
+    ///     HashMap::from([("a", vec![("b", 1.5), ("c", 2.0)]), ("b", vec![("c", 0.5)])]);
+    /// let graph = WeightedDiGraph::try_from(adjacency.clone()).unwrap();
+    /// assert_eq!(graph.number_of_nodes(), 3);
+    /// assert_eq!(graph.number_of_edges(), 3);
+    ///
+    /// let mut expected = adjacency;
+    /// expected.insert("c", Vec::new());
+    /// assert_eq!(graph.to_adjacency_map(), expected);
+    /// ```
+    #[must_use]
+    pub fn to_adjacency_map(&self) -> HashMap<S, Vec<(S, f64)>> {
+        let nodes = self.nodes_vocabulary();
+        self.node_ids()
+            .map(|source| {
+                let successors = self
+                    .successors(source)
+                    .zip(self.successor_weights(source))
+                    .map(|(destination, weight)| (symbol_of(nodes, destination), weight))
+                    .collect();
+                (symbol_of(nodes, source), successors)
+            })
+            .collect()
+    }
+}
+
+/// Returns the sorted vocabulary of the symbols, which may be repeated.
+pub(crate) fn sorted_vocabulary<S: Symbol + Ord>(mut symbols: Vec<S>) -> SortedVec<S> {
+    symbols.sort_unstable();
+    symbols.dedup();
+    SortedVec::try_from(symbols).unwrap_or_else(|_| unreachable!("The symbols were sorted above."))
+}
+
+/// Returns the weighted edges of the adjacency map, between the sources and
+/// destinations vocabularies, which must hold all of its symbols.
+pub(crate) fn weighted_edges<S, D, V, H>(
+    adjacency: HashMap<S, Vec<(D, V)>, H>,
+    sources: &SortedVec<S>,
+    destinations: &SortedVec<D>,
+) -> Result<ValuedCSR2D<usize, usize, usize, f64>, AdjacencyMapError<S, D>>
+where
+    S: Symbol + Ord,
+    D: Symbol + Ord,
+    V: Into<f64>,
+    H: BuildHasher,
+{
+    let mut edges: Vec<(usize, usize, f64)> = Vec::new();
+    for (source, successors) in adjacency {
+        let source_id = index_of(sources, &source);
+        edges.extend(successors.into_iter().map(|(destination, weight)| {
+            (source_id, index_of(destinations, &destination), weight.into())
+        }));
+    }
+    edges.sort_unstable_by_key(|&(source, destination, _)| (source, destination));
+    if let Some(window) =
+        edges.windows(2).find(|window| (window[0].0, window[0].1) == (window[1].0, window[1].1))
+    {
+        return Err(AdjacencyMapError::DuplicatedEdge {
+            source_symbol: sources[window[0].0].clone(),
+            destination_symbol: destinations[window[0].1].clone(),
+        });
+    }
+
+    Ok(GenericEdgesBuilder::<_, ValuedCSR2D<usize, usize, usize, f64>>::default()
+        .expected_number_of_edges(edges.len())
+        .expected_shape((sources.len(), destinations.len()))
+        .edges(edges.into_iter())
+        .build()
+        .expect("Sorted and deduplicated edges are always accepted by the builder."))
+}
+
+/// Returns the identifier of a symbol of the vocabulary.
+fn index_of<S: Ord>(nodes: &SortedVec<S>, symbol: &S) -> usize {
+    nodes
+        .binary_search(symbol)
+        .unwrap_or_else(|_| unreachable!("Every adjacency symbol was inserted in the vocabulary."))
+}
+
+/// Returns the symbol of a node identifier of the vocabulary.
+pub(crate) fn symbol_of<S: Symbol>(nodes: &SortedVec<S>, id: usize) -> S {
+    nodes.convert(&id).unwrap_or_else(|| unreachable!("Node identifiers come from the graph."))
+}
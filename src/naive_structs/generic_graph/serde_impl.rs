@@ -0,0 +1,45 @@
+//! Implementation of the `serde` traits for the `GenericGraph` struct.
+//!
+//! Both the vocabulary and the edges are validated by their own
+//! deserializers; here we additionally check that the edges only refer to
+//! nodes defined in the vocabulary.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+
+use super::{GenericGraph, check_nodes};
+use crate::traits::{Edges, Vocabulary};
+
+#[derive(Serialize)]
+#[serde(rename = "GenericGraph")]
+struct GenericGraphRef<'a, Nodes, E> {
+    nodes: &'a Nodes,
+    edges: &'a E,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "GenericGraph")]
+struct RawGenericGraph<Nodes, E> {
+    nodes: Nodes,
+    edges: E,
+}
+
+impl<Nodes: Serialize, E: Serialize> Serialize for GenericGraph<Nodes, E> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        GenericGraphRef { nodes: &self.nodes, edges: &self.edges }.serialize(serializer)
+    }
+}
+
+impl<'de, Nodes, E> Deserialize<'de> for GenericGraph<Nodes, E>
+where
+    Nodes: Vocabulary + Deserialize<'de>,
+    E: Edges + Deserialize<'de>,
+{
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let RawGenericGraph { nodes, edges } =
+            RawGenericGraph::<Nodes, E>::deserialize(deserializer)?;
+        check_nodes(&nodes, &edges).map_err(D::Error::custom)?;
+        Ok(Self { nodes, edges })
+    }
+}
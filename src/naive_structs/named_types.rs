@@ -50,6 +50,10 @@ pub type WeightedBiGraph<LeftNodeSymbol, RightNodeSymbol> = GenericBiGraph<
     ValuedCSR2D<usize, usize, usize, f64>,
 >;
 
+/// Type alias for a generic weighted directed graph.
+pub type WeightedDiGraph<NodeSymbol> =
+    GenericGraph<SortedVec<NodeSymbol>, ValuedCSR2D<usize, usize, usize, f64>>;
+
 /// Type alias for a generic undirected graph.
 pub type UndiGraph<NodeSymbol> =
     GenericGraph<SortedVec<NodeSymbol>, SymmetricCSR2D<CSR2D<usize, usize, usize>>>;
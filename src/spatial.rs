@@ -0,0 +1,32 @@
+//! Submodule providing spatial indices over point sets, answering nearest
+//! neighbour and radius queries without comparing the query with every point.
+//!
+//! All indices report their results as [`Neighbor`] values, holding the
+//! position of the point in the input and its distance from the query, sorted
+//! by increasing distance and then by increasing position.
+
+mod dbscan;
+mod distance_matrix;
+mod epsilon_graph;
+mod fuzzy_simplicial_set;
+mod gated_cost_builder;
+mod kd_tree;
+mod knn_graph;
+mod metric;
+mod neighbor;
+mod spectral_alignment;
+mod tracker;
+mod vp_tree;
+
+pub use dbscan::dbscan;
+pub use distance_matrix::{CosineDistance, DistanceMatrix};
+pub use epsilon_graph::epsilon_graph;
+pub use fuzzy_simplicial_set::{FuzzySimplicialSetConfig, fuzzy_simplicial_set};
+pub use gated_cost_builder::GatedCostBuilder;
+pub use kd_tree::KdTree;
+pub use knn_graph::{KnnSymmetrize, knn_graph, mutual_knn_graph};
+pub use metric::{Angular, Chebyshev, Euclidean, Manhattan, Metric};
+pub use neighbor::Neighbor;
+pub use spectral_alignment::{Peak, SpectralAligner, SpectralAlignment};
+pub use tracker::{Detection, Track, TrackJunction, Tracker, Tracking};
+pub use vp_tree::VpTree;
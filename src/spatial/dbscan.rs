@@ -0,0 +1,53 @@
+//! Submodule providing the DBSCAN clustering of a point set.
+
+use super::{Metric, epsilon_graph};
+use crate::traits::{Dbscan, DbscanError, DbscanResult, PositiveInteger};
+
+/// Returns the DBSCAN clustering of the points, grouping the points with at
+/// least `min_points` points within distance `epsilon`, themselves included,
+/// together with their neighbours.
+///
+/// This runs [`Dbscan`] on the [`epsilon_graph`] of the points, whose
+/// neighbourhoods are retrieved through a [`VpTree`](super::VpTree): cluster
+/// `i` of the result is the `i`-th cluster found by scanning the points by
+/// position, and points outside of any cluster are labelled as noise.
+///
+/// # Arguments
+///
+/// * `points` - The points to cluster.
+/// * `epsilon` - The radius of the neighbourhoods, boundary included.
+/// * `min_points` - The minimal size of the neighbourhood of a core point.
+/// * `metric` - The metric comparing the points.
+///
+/// # Errors
+///
+/// Returns an error when the number of clusters cannot fit into `Marker`.
+///
+/// # Complexity
+///
+/// O(N log N) expected distance evaluations to build the index, plus one
+/// radius query per point.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::spatial::{Manhattan, dbscan};
+///
+/// let points = [[0.0, 0.0], [0.0, 1.0], [1.0, 0.0], [5.0, 5.0], [9.0, 9.0], [9.0, 10.0]];
+/// let result = dbscan::<u8, _, _>(&points, 1.0, 2, &Manhattan).unwrap();
+///
+/// assert_eq!(result.labels(), &[Some(0), Some(0), Some(0), None, Some(1), Some(1)]);
+/// ```
+#[inline]
+pub fn dbscan<Marker, P, M>(
+    points: &[P],
+    epsilon: f64,
+    min_points: usize,
+    metric: &M,
+) -> Result<DbscanResult<Marker>, DbscanError>
+where
+    Marker: PositiveInteger,
+    M: Metric<P> + ?Sized,
+{
+    Dbscan::<Marker>::dbscan(&epsilon_graph(points, epsilon, metric), min_points)
+}
@@ -0,0 +1,168 @@
+//! Submodule providing the `DistanceMatrix` trait, computing the distances
+//! between two collections of points as a sparse matrix.
+
+use alloc::vec::Vec;
+
+use super::{Angular, Chebyshev, Euclidean, Manhattan, Metric, metric::cosine};
+use crate::{
+    impls::ValuedCSR2D,
+    traits::{MatrixMut, SparseMatrixMut},
+};
+
+/// Dissimilarity between points of type `P`, computing the matrices of
+/// distances between two collections of points.
+///
+/// The rows of the matrices are the points of the left collection and their
+/// columns the points of the right one, so that they are ready to be used as
+/// cost matrices by the assignment solvers, such as
+/// [`Jaqaman`](crate::traits::Jaqaman) linking the features of two frames.
+/// Note that the assignment solvers reject null costs, which arise from
+/// coincident points.
+///
+/// The trait is implemented by the provided metrics, by [`CosineDistance`],
+/// which is a dissimilarity but not a metric, and by any closure `Fn(&P, &P)
+/// -> f64`.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{
+///     prelude::*,
+///     spatial::{DistanceMatrix, Manhattan},
+/// };
+///
+/// let left = [[0.0, 0.0], [5.0, 5.0]];
+/// let right = [[1.0, 0.0], [5.0, 4.0], [9.0, 9.0]];
+///
+/// let dense = Manhattan.distance_matrix(&left, &right);
+/// assert_eq!(dense.number_of_defined_values(), 6);
+///
+/// let sparse = Manhattan.thresholded_distance_matrix(&left, &right, 2.0);
+/// assert_eq!(SparseMatrix::sparse_coordinates(&sparse).collect::<Vec<_>>(), vec![(0, 0), (1, 1)]);
+/// assert_eq!(sparse.sparse_values().collect::<Vec<_>>(), vec![1.0, 1.0]);
+/// ```
+pub trait DistanceMatrix<P> {
+    /// Returns the dissimilarity between the two points.
+    fn dissimilarity(&self, left: &P, right: &P) -> f64;
+
+    /// Returns the dense matrix of the distances between every point of
+    /// `left` and every point of `right`.
+    ///
+    /// # Complexity
+    ///
+    /// O(L·R) distance evaluations.
+    #[must_use]
+    fn distance_matrix(&self, left: &[P], right: &[P]) -> ValuedCSR2D<usize, usize, usize, f64> {
+        collect_distances(left, right, |left, right| Some(self.dissimilarity(left, right)))
+    }
+
+    /// Returns the sparse matrix of the distances between the points of
+    /// `left` and of `right` which are at most `threshold`, the other pairs
+    /// being absent from the matrix.
+    ///
+    /// # Complexity
+    ///
+    /// O(L·R) distance evaluations.
+    #[must_use]
+    fn thresholded_distance_matrix(
+        &self,
+        left: &[P],
+        right: &[P],
+        threshold: f64,
+    ) -> ValuedCSR2D<usize, usize, usize, f64> {
+        collect_distances(left, right, |left, right| {
+            let distance = self.dissimilarity(left, right);
+            (distance <= threshold).then_some(distance)
+        })
+    }
+}
+
+impl<P, F> DistanceMatrix<P> for F
+where
+    F: Fn(&P, &P) -> f64,
+{
+    #[inline]
+    fn dissimilarity(&self, left: &P, right: &P) -> f64 {
+        self(left, right)
+    }
+}
+
+/// Implements `DistanceMatrix` for metrics over coordinate vectors.
+macro_rules! impl_distance_matrix_for_metric {
+    ($($metric:ty),* $(,)?) => {
+        $(
+            impl<P: AsRef<[f64]>> DistanceMatrix<P> for $metric {
+                #[inline]
+                fn dissimilarity(&self, left: &P, right: &P) -> f64 {
+                    Metric::<P>::distance(self, left, right)
+                }
+            }
+        )*
+    };
+}
+
+impl_distance_matrix_for_metric!(Euclidean, Manhattan, Chebyshev, Angular);
+
+/// Cosine distance between coordinate vectors, one minus the cosine of the
+/// angle between them, which ranges from zero for vectors pointing the same
+/// way to two for opposite vectors.
+///
+/// The cosine distance violates the triangle inequality, so it is not a
+/// [`Metric`] and cannot back a spatial index: the [`Angular`] distance orders
+/// the pairs in the same way and is a metric. A null vector is at distance
+/// zero from itself and one from any other vector.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::spatial::{CosineDistance, DistanceMatrix};
+///
+/// assert!(CosineDistance.dissimilarity(&[1.0, 0.0], &[2.0, 0.0]).abs() < f64::EPSILON);
+/// assert!((CosineDistance.dissimilarity(&[1.0, 0.0], &[0.0, 3.0]) - 1.0).abs() < f64::EPSILON);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CosineDistance;
+
+impl<P: AsRef<[f64]>> DistanceMatrix<P> for CosineDistance {
+    #[inline]
+    fn dissimilarity(&self, left: &P, right: &P) -> f64 {
+        match cosine(left.as_ref(), right.as_ref()) {
+            Ok(cosine) => 1.0 - cosine,
+            Err(both_null) => {
+                if both_null {
+                    0.0
+                } else {
+                    1.0
+                }
+            }
+        }
+    }
+}
+
+/// Returns the matrix of the distances provided for the pairs of points of
+/// `left` and `right`.
+fn collect_distances<P, F>(
+    left: &[P],
+    right: &[P],
+    distance: F,
+) -> ValuedCSR2D<usize, usize, usize, f64>
+where
+    F: Fn(&P, &P) -> Option<f64>,
+{
+    let distance = &distance;
+    let entries: Vec<(usize, usize, f64)> = left
+        .iter()
+        .enumerate()
+        .flat_map(|(row, left)| {
+            right.iter().enumerate().filter_map(move |(column, right)| {
+                distance(left, right).map(|distance| (row, column, distance))
+            })
+        })
+        .collect();
+    let mut matrix: ValuedCSR2D<usize, usize, usize, f64> =
+        SparseMatrixMut::with_sparse_shaped_capacity((left.len(), right.len()), entries.len());
+    for entry in entries {
+        MatrixMut::add(&mut matrix, entry).expect("Entries are sorted by row and column.");
+    }
+    matrix
+}
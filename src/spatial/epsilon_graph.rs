@@ -0,0 +1,76 @@
+//! Submodule providing the construction of the epsilon-neighbourhood graph of
+//! a point set.
+
+use alloc::vec::Vec;
+
+use super::{Metric, VpTree};
+use crate::impls::{SymmetricCSR2D, ValuedCSR2D};
+
+/// Returns the epsilon-neighbourhood graph of the points, connecting every
+/// pair of distinct points within distance `epsilon` of each other, boundary
+/// included, with their distance as edge weight.
+///
+/// Node `i` of the graph is the point at position `i` of `points`. The
+/// neighbourhoods are retrieved through a [`VpTree`], so only a fraction of
+/// the pairs is compared when the graph is sparse. Coincident points are
+/// connected by edges of null weight, and no self-loop is added. The graph
+/// feeds density-based clustering, community detection and connected
+/// components on geometric data.
+///
+/// # Arguments
+///
+/// * `points` - The points to connect.
+/// * `epsilon` - The largest distance between connected points. A negative or
+///   NaN value yields a graph without edges.
+/// * `metric` - The metric comparing the points.
+///
+/// # Complexity
+///
+/// O(N log N) expected distance evaluations to build the index, plus one
+/// radius query per point.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{
+///     prelude::*,
+///     spatial::{Euclidean, epsilon_graph},
+/// };
+///
+/// let points = [[0.0, 0.0], [0.0, 1.0], [1.0, 1.0], [5.0, 5.0]];
+/// let graph = epsilon_graph(&points, 1.0, &Euclidean);
+///
+/// assert_eq!(
+///     SparseMatrix::sparse_coordinates(&graph).collect::<Vec<_>>(),
+///     vec![(0, 1), (1, 0), (1, 2), (2, 1)]
+/// );
+/// assert!(graph.sparse_values().all(|distance| (distance - 1.0).abs() < f64::EPSILON));
+/// ```
+#[must_use]
+pub fn epsilon_graph<P, M>(
+    points: &[P],
+    epsilon: f64,
+    metric: &M,
+) -> SymmetricCSR2D<ValuedCSR2D<usize, usize, usize, f64>>
+where
+    M: Metric<P> + ?Sized,
+{
+    let mut edges: Vec<(usize, usize, f64)> = Vec::new();
+    if epsilon >= 0.0 {
+        let tree = VpTree::new(0..points.len(), |left: &usize, right: &usize| {
+            metric.distance(&points[*left], &points[*right])
+        });
+        for source in 0..points.len() {
+            let row_start = edges.len();
+            edges.extend(
+                tree.within_radius(&source, epsilon)
+                    .into_iter()
+                    .filter(|neighbor| neighbor.index() > source)
+                    .map(|neighbor| (source, neighbor.index(), neighbor.distance())),
+            );
+            edges[row_start..].sort_unstable_by_key(|&(_, destination, _)| destination);
+        }
+    }
+    SymmetricCSR2D::from_sorted_upper_triangular_entries(points.len(), edges)
+        .expect("Edges are sorted, distinct and upper triangular.")
+}
@@ -0,0 +1,183 @@
+//! Submodule providing the fuzzy simplicial set of UMAP, turning the distances
+//! of a k-nearest-neighbour graph into symmetric membership strengths.
+
+use alloc::vec::Vec;
+
+use crate::{
+    impls::{SymmetricCSR2D, ValuedCSR2D},
+    traits::{Matrix2D, SparseMatrix2D, SparseValuedMatrix2D},
+};
+
+/// Number of bisection steps of the search for the bandwidth of a point.
+const BANDWIDTH_STEPS: usize = 64;
+/// Tolerance on the sum of the memberships of a point to its target.
+const BANDWIDTH_TOLERANCE: f64 = 1.0e-5;
+/// Smallest bandwidth of a point, relative to its mean neighbour distance.
+const MIN_BANDWIDTH_SCALE: f64 = 1.0e-3;
+
+/// Configuration of [`fuzzy_simplicial_set`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FuzzySimplicialSetConfig {
+    /// Number of nearest neighbours assumed to be fully connected to each
+    /// point, usually one. Fractional values interpolate between the
+    /// distances of two consecutive neighbours.
+    pub local_connectivity: f64,
+    /// Interpolation between the fuzzy union, for one, and the fuzzy
+    /// intersection, for zero, of the memberships in both directions.
+    pub set_op_mix_ratio: f64,
+}
+
+impl Default for FuzzySimplicialSetConfig {
+    #[inline]
+    fn default() -> Self {
+        Self { local_connectivity: 1.0, set_op_mix_ratio: 1.0 }
+    }
+}
+
+/// Returns the fuzzy simplicial set of UMAP of the directed k-nearest-neighbour
+/// graph, a symmetric graph whose edge weights are membership strengths in
+/// `(0, 1]`.
+///
+/// Each point `i` is calibrated to its own neighbourhood: `ρ_i` is its
+/// distance to its `local_connectivity`-th nearest neighbour, and the
+/// bandwidth `σ_i` is found by bisection so that the memberships
+/// `exp(-max(0, d_ij - ρ_i) / σ_i)` of its `k` neighbours sum to
+/// `log2(k + 1)`, the point counting among its own neighbours as in UMAP. The
+/// nearest neighbours are thus always fully connected, and dense and sparse
+/// regions contribute alike. The memberships `a` and `b` of both directions of
+/// an edge are then combined by the probabilistic union `a + b - ab`, or its
+/// interpolation with the intersection `ab` by the mix ratio. Edges of null
+/// weight are dropped.
+///
+/// The graph is usually built by [`knn_graph`](super::knn_graph), and its
+/// rows are the neighbourhoods with their distances. Self-loops are ignored.
+///
+/// # Arguments
+///
+/// * `graph` - The directed k-nearest-neighbour graph, weighted by distance.
+/// * `config` - The local connectivity and the mix ratio.
+///
+/// # Panics
+///
+/// Panics if the graph is not square, if the local connectivity is negative
+/// or not finite, or if the mix ratio is not within `[0, 1]`.
+///
+/// # Complexity
+///
+/// O(E log E) time and O(E) space.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{
+///     prelude::*,
+///     spatial::{Euclidean, FuzzySimplicialSetConfig, fuzzy_simplicial_set, knn_graph},
+/// };
+///
+/// let points = [[0.0], [1.0], [3.0], [7.0]];
+/// let graph = knn_graph(&points, 2, &Euclidean);
+/// let memberships = fuzzy_simplicial_set(&graph, &FuzzySimplicialSetConfig::default());
+///
+/// // Every point is fully connected to its nearest neighbour.
+/// assert_eq!(memberships.sparse_value_at(0, 1), Some(1.0));
+/// assert_eq!(memberships.sparse_value_at(2, 3), Some(1.0));
+/// assert!(memberships.sparse_values().all(|weight| weight > 0.0 && weight <= 1.0));
+/// ```
+#[must_use]
+pub fn fuzzy_simplicial_set(
+    graph: &ValuedCSR2D<usize, usize, usize, f64>,
+    config: &FuzzySimplicialSetConfig,
+) -> SymmetricCSR2D<ValuedCSR2D<usize, usize, usize, f64>> {
+    let order = graph.number_of_rows();
+    assert_eq!(order, graph.number_of_columns(), "The k-nearest-neighbour graph must be square.");
+    assert!(
+        config.local_connectivity.is_finite() && config.local_connectivity >= 0.0,
+        "The local connectivity must be finite and non-negative."
+    );
+    assert!((0.0..=1.0).contains(&config.set_op_mix_ratio), "The mix ratio must be within [0, 1].");
+
+    // The memberships of both directions of an edge end up next to each other.
+    let mut memberships: Vec<(usize, usize, f64)> = Vec::new();
+    for row in graph.row_indices() {
+        let neighborhood: Vec<(usize, f64)> = graph
+            .sparse_row(row)
+            .zip(graph.sparse_row_values(row))
+            .filter(|&(column, _)| column != row)
+            .collect();
+        let distances: Vec<f64> = neighborhood.iter().map(|&(_, distance)| distance).collect();
+        let (rho, sigma) = calibrate(&distances, config.local_connectivity);
+        memberships.extend(neighborhood.into_iter().map(|(column, distance)| {
+            (row.min(column), row.max(column), membership(distance, rho, sigma))
+        }));
+    }
+    memberships.sort_by_key(|&(row, column, _)| (row, column));
+
+    let mix_ratio = config.set_op_mix_ratio;
+    let edges = memberships
+        .chunk_by(|left, right| (left.0, left.1) == (right.0, right.1))
+        .filter_map(|pair| {
+            let (row, column, forward) = pair[0];
+            let backward = pair.get(1).map_or(0.0, |&(_, _, membership)| membership);
+            let product = forward * backward;
+            let weight = mix_ratio * (forward + backward - product) + (1.0 - mix_ratio) * product;
+            (weight > 0.0).then_some((row, column, weight))
+        })
+        .collect::<Vec<_>>();
+    SymmetricCSR2D::from_sorted_upper_triangular_entries(order, edges)
+        .expect("Edges are sorted, distinct and upper triangular.")
+}
+
+/// Returns the membership of a neighbour at the given distance.
+fn membership(distance: f64, rho: f64, sigma: f64) -> f64 {
+    let excess = distance - rho;
+    if excess <= 0.0 { 1.0 } else { (-excess / sigma).exp() }
+}
+
+/// Returns the distance `ρ` to the nearest neighbours and the bandwidth `σ`
+/// of a point from the distances to its neighbours.
+#[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn calibrate(distances: &[f64], local_connectivity: f64) -> (f64, f64) {
+    if distances.is_empty() {
+        return (0.0, 1.0);
+    }
+    let mut positive: Vec<f64> =
+        distances.iter().copied().filter(|&distance| distance > 0.0).collect();
+    positive.sort_by(f64::total_cmp);
+
+    // The distance to the nearest neighbours, interpolated between the
+    // neighbours around a fractional local connectivity.
+    let rho = if positive.len() as f64 >= local_connectivity {
+        let index = local_connectivity.floor() as usize;
+        let interpolation = local_connectivity - local_connectivity.floor();
+        if index > 0 {
+            let lower = positive[index - 1];
+            lower + interpolation * (positive.get(index).map_or(lower, |&upper| upper) - lower)
+        } else {
+            interpolation * positive.first().copied().unwrap_or(0.0)
+        }
+    } else {
+        positive.last().copied().unwrap_or(0.0)
+    };
+
+    let target = ((distances.len() + 1) as f64).log2();
+    let total = |sigma: f64| -> f64 {
+        distances.iter().map(|&distance| membership(distance, rho, sigma)).sum()
+    };
+    let (mut low, mut high, mut sigma) = (0.0, f64::INFINITY, 1.0);
+    for _ in 0..BANDWIDTH_STEPS {
+        let sum = total(sigma);
+        if (sum - target).abs() < BANDWIDTH_TOLERANCE {
+            break;
+        }
+        if sum > target {
+            high = sigma;
+            sigma = f64::midpoint(low, high);
+        } else {
+            low = sigma;
+            sigma = if high.is_infinite() { sigma * 2.0 } else { f64::midpoint(low, high) };
+        }
+    }
+
+    let mean = distances.iter().sum::<f64>() / distances.len() as f64;
+    (rho, sigma.max(MIN_BANDWIDTH_SCALE * mean).max(f64::MIN_POSITIVE))
+}
@@ -0,0 +1,125 @@
+//! Submodule providing the `GatedCostBuilder`, building the sparse cost
+//! matrix linking two point sets under a gate.
+
+use alloc::vec::Vec;
+
+use crate::{
+    impls::ValuedCSR2D,
+    traits::{MatrixMut, SparseMatrixMut},
+};
+
+/// Builder of the sparse cost matrix linking the points of two sets, such as
+/// the particles or features detected in two consecutive frames.
+///
+/// A gate decides which pairs may be linked, for instance those within a
+/// maximal displacement and a maximal time difference, and only these pairs
+/// are costed and stored: the others are absent from the rectangular matrix,
+/// whose rows are the points of the left set and columns the points of the
+/// right one. The matrix is thus ready for the
+/// [`Jaqaman`](crate::traits::Jaqaman) solver, which leaves points without
+/// acceptable link unmatched.
+///
+/// Pairs whose cost is not finite are treated as gated out. Note that the
+/// assignment solvers also reject null and negative costs.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{prelude::*, spatial::GatedCostBuilder};
+///
+/// // Features detected in two frames, as (position, intensity).
+/// let first = [(0.0, 10.0), (4.0, 3.0), (9.0, 7.0)];
+/// let second = [(0.5, 11.0), (8.0, 7.5), (20.0, 1.0)];
+///
+/// let costs = GatedCostBuilder::new(&first, &second, |left: &(f64, f64), right: &(f64, f64)| {
+///     (left.0 - right.0).powi(2)
+/// })
+/// .with_gate(|left: &(f64, f64), right: &(f64, f64)| (left.0 - right.0).abs() <= 2.0)
+/// .build();
+///
+/// assert_eq!(SparseMatrix::sparse_coordinates(&costs).collect::<Vec<_>>(), vec![(0, 0), (2, 1)]);
+/// let links = costs.jaqaman(10.0, 100.0).unwrap();
+/// assert_eq!(links, vec![(0, 0), (2, 1)]);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct GatedCostBuilder<'points, L, R, C, G = fn(&L, &R) -> bool> {
+    /// The points of the rows.
+    left: &'points [L],
+    /// The points of the columns.
+    right: &'points [R],
+    /// The cost of linking two points.
+    cost: C,
+    /// The predicate deciding whether two points may be linked.
+    gate: G,
+}
+
+impl<'points, L, R, C> GatedCostBuilder<'points, L, R, C>
+where
+    C: Fn(&L, &R) -> f64,
+{
+    /// Creates a builder linking the two point sets with the provided cost,
+    /// without gate.
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - The points of the rows of the cost matrix.
+    /// * `right` - The points of the columns of the cost matrix.
+    /// * `cost` - The cost of linking a left point to a right point.
+    #[inline]
+    #[must_use]
+    pub fn new(left: &'points [L], right: &'points [R], cost: C) -> Self {
+        Self { left, right, cost, gate: |_, _| true }
+    }
+}
+
+impl<'points, L, R, C, G> GatedCostBuilder<'points, L, R, C, G>
+where
+    C: Fn(&L, &R) -> f64,
+    G: Fn(&L, &R) -> bool,
+{
+    /// Sets the predicate deciding whether a left point may be linked to a
+    /// right point, replacing the previous one.
+    ///
+    /// The gate is checked before the cost, which is only evaluated for the
+    /// pairs passing it.
+    #[inline]
+    #[must_use]
+    pub fn with_gate<G2>(self, gate: G2) -> GatedCostBuilder<'points, L, R, C, G2>
+    where
+        G2: Fn(&L, &R) -> bool,
+    {
+        GatedCostBuilder { left: self.left, right: self.right, cost: self.cost, gate }
+    }
+
+    /// Returns the sparse cost matrix of the pairs passing the gate with a
+    /// finite cost.
+    ///
+    /// # Complexity
+    ///
+    /// O(L·R) gate evaluations, and one cost evaluation per pair passing the
+    /// gate.
+    #[must_use]
+    pub fn build(&self) -> ValuedCSR2D<usize, usize, usize, f64> {
+        let mut entries: Vec<(usize, usize, f64)> = Vec::new();
+        for (row, left) in self.left.iter().enumerate() {
+            for (column, right) in self.right.iter().enumerate() {
+                if !(self.gate)(left, right) {
+                    continue;
+                }
+                let cost = (self.cost)(left, right);
+                if cost.is_finite() {
+                    entries.push((row, column, cost));
+                }
+            }
+        }
+        let mut costs: ValuedCSR2D<usize, usize, usize, f64> =
+            SparseMatrixMut::with_sparse_shaped_capacity(
+                (self.left.len(), self.right.len()),
+                entries.len(),
+            );
+        for entry in entries {
+            MatrixMut::add(&mut costs, entry).expect("Entries are sorted by row and column.");
+        }
+        costs
+    }
+}
@@ -0,0 +1,265 @@
+//! Submodule providing the `KdTree` spatial index over points of a
+//! low-dimensional Euclidean space.
+
+use alloc::vec::Vec;
+
+use super::{Neighbor, neighbor::NearestNeighbors};
+
+/// Balanced k-d tree over points of `D` dimensions, each carrying a value of
+/// type `V`.
+///
+/// The tree is stored implicitly in a permutation of the points: every range
+/// of the permutation is split at its median along the dimension of largest
+/// spread, so that the tree has logarithmic depth whatever the distribution
+/// of the points. Distances are Euclidean, so coordinates living on different
+/// scales, such as mass-over-charge ratios and retention times, should be
+/// rescaled beforehand.
+///
+/// # Reference
+///
+/// Bentley, J. L. (1975). [Multidimensional binary search trees used for associative searching](https://doi.org/10.1145/361002.361007).
+/// Friedman, J. H., Bentley, J. L., & Finkel, R. A. (1977). [An Algorithm for Finding Best Matches in Logarithmic Expected Time](https://doi.org/10.1145/355744.355745).
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::spatial::KdTree;
+///
+/// // Features identified by their mass-over-charge ratio and retention time.
+/// let tree: KdTree<2, &str> = KdTree::new([
+///     ([180.06, 2.1], "glucose"),
+///     ([180.06, 2.4], "fructose"),
+///     ([342.12, 3.0], "sucrose"),
+///     ([151.06, 5.2], "paracetamol"),
+/// ]);
+///
+/// let nearest = tree.nearest_neighbors(&[180.0, 2.2], 2);
+/// assert_eq!(
+///     nearest.iter().map(|neighbor| *tree.value(neighbor.index())).collect::<Vec<_>>(),
+///     ["glucose", "fructose"]
+/// );
+///
+/// let close = tree.within_radius(&[180.06, 2.0], 0.5);
+/// assert_eq!(close.len(), 2);
+/// assert!((close[0].distance() - 0.1).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone)]
+pub struct KdTree<const D: usize, V> {
+    /// The points, in input order.
+    points: Vec<[f64; D]>,
+    /// The values carried by the points, in input order.
+    values: Vec<V>,
+    /// The permutation of the points laying out the tree: the root of the
+    /// range `lo..hi` is at position `lo + (hi - lo) / 2`.
+    order: Vec<usize>,
+    /// The splitting dimension of the node at every position of `order`.
+    split_dimensions: Vec<usize>,
+}
+
+impl<const D: usize, V> KdTree<D, V> {
+    /// Builds the k-d tree over the provided points and their values.
+    ///
+    /// # Arguments
+    ///
+    /// * `entries` - The points and their values. Neighbours are reported by
+    ///   their position in this sequence.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any coordinate is NaN, or at compile time if `D` is zero.
+    ///
+    /// # Complexity
+    ///
+    /// O(D·N log N) expected time and O(N) additional space.
+    #[must_use]
+    pub fn new<I>(entries: I) -> Self
+    where
+        I: IntoIterator<Item = ([f64; D], V)>,
+    {
+        const { assert!(D > 0, "A k-d tree needs at least one dimension.") };
+        let (points, values): (Vec<[f64; D]>, Vec<V>) = entries.into_iter().unzip();
+        assert!(
+            points.iter().flatten().all(|coordinate| !coordinate.is_nan()),
+            "The coordinates of the points must not be NaN."
+        );
+
+        let mut order: Vec<usize> = (0..points.len()).collect();
+        let mut split_dimensions = vec![0; points.len()];
+        let mut ranges = vec![(0, points.len())];
+        while let Some((lo, hi)) = ranges.pop() {
+            if hi - lo <= 1 {
+                continue;
+            }
+            let dimension = widest_dimension(&points, &order[lo..hi]);
+            let median = lo + (hi - lo) / 2;
+            order[lo..hi].select_nth_unstable_by(median - lo, |&left, &right| {
+                points[left][dimension].total_cmp(&points[right][dimension]).then(left.cmp(&right))
+            });
+            split_dimensions[median] = dimension;
+            ranges.push((lo, median));
+            ranges.push((median + 1, hi));
+        }
+
+        Self { points, values, order, split_dimensions }
+    }
+
+    /// Returns the number of points in the tree.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Returns whether the tree holds no point.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Returns the point at the provided position of the input.
+    #[inline]
+    #[must_use]
+    pub fn point(&self, index: usize) -> &[f64; D] {
+        &self.points[index]
+    }
+
+    /// Returns the value of the point at the provided position of the input.
+    #[inline]
+    #[must_use]
+    pub fn value(&self, index: usize) -> &V {
+        &self.values[index]
+    }
+
+    /// Returns the permutation of the points laying out the tree: the root of
+    /// the range `lo..hi` is at position `lo + (hi - lo) / 2`, and its
+    /// subtrees are the ranges on either side of it.
+    #[inline]
+    #[must_use]
+    pub(crate) fn permutation(&self) -> &[usize] {
+        &self.order
+    }
+
+    /// Returns the `k` points closest to `query`, sorted by increasing
+    /// distance and then by increasing position, or all the points if there
+    /// are fewer than `k`.
+    ///
+    /// # Complexity
+    ///
+    /// O(log N + k log k) expected time for well-distributed points, and
+    /// O(N log k) in the worst case.
+    #[must_use]
+    pub fn nearest_neighbors(&self, query: &[f64; D], k: usize) -> Vec<Neighbor> {
+        let mut nearest = NearestNeighbors::new(k);
+        if k > 0 {
+            self.nearest_in(0, self.len(), query, &mut nearest);
+        }
+        nearest.into_sorted_vec()
+    }
+
+    /// Returns the points within distance `radius` of `query`, boundary
+    /// included, sorted by increasing distance and then by increasing
+    /// position.
+    ///
+    /// # Complexity
+    ///
+    /// O(log N + M log M) expected time for well-distributed points, where M
+    /// is the number of returned points.
+    #[must_use]
+    pub fn within_radius(&self, query: &[f64; D], radius: f64) -> Vec<Neighbor> {
+        let mut neighbors = Vec::new();
+        if radius >= 0.0 {
+            self.within_radius_in(0, self.len(), query, radius, &mut neighbors);
+        }
+        neighbors.sort_unstable_by(Neighbor::query_order);
+        neighbors
+    }
+
+    /// Offers the points of the range `lo..hi` to the nearest neighbours
+    /// found so far.
+    fn nearest_in(&self, lo: usize, hi: usize, query: &[f64; D], nearest: &mut NearestNeighbors) {
+        if lo >= hi {
+            return;
+        }
+        let median = lo + (hi - lo) / 2;
+        let index = self.order[median];
+        nearest.offer(Neighbor::new(index, distance(query, &self.points[index])));
+
+        let dimension = self.split_dimensions[median];
+        let offset = query[dimension] - self.points[index][dimension];
+        let (near, far) = if offset < 0.0 {
+            ((lo, median), (median + 1, hi))
+        } else {
+            ((median + 1, hi), (lo, median))
+        };
+        self.nearest_in(near.0, near.1, query, nearest);
+        if offset.abs() <= nearest.bound() {
+            self.nearest_in(far.0, far.1, query, nearest);
+        }
+    }
+
+    /// Collects the points of the range `lo..hi` within the radius.
+    fn within_radius_in(
+        &self,
+        lo: usize,
+        hi: usize,
+        query: &[f64; D],
+        radius: f64,
+        neighbors: &mut Vec<Neighbor>,
+    ) {
+        if lo >= hi {
+            return;
+        }
+        let median = lo + (hi - lo) / 2;
+        let index = self.order[median];
+        let distance = distance(query, &self.points[index]);
+        if distance <= radius {
+            neighbors.push(Neighbor::new(index, distance));
+        }
+
+        let dimension = self.split_dimensions[median];
+        let offset = query[dimension] - self.points[index][dimension];
+        if offset <= 0.0 || offset <= radius {
+            self.within_radius_in(lo, median, query, radius, neighbors);
+        }
+        if offset >= 0.0 || -offset <= radius {
+            self.within_radius_in(median + 1, hi, query, radius, neighbors);
+        }
+    }
+}
+
+impl<const D: usize, V> FromIterator<([f64; D], V)> for KdTree<D, V> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = ([f64; D], V)>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+/// Returns the Euclidean distance between two points.
+fn distance<const D: usize>(left: &[f64; D], right: &[f64; D]) -> f64 {
+    left.iter().zip(right).map(|(left, right)| (left - right) * (left - right)).sum::<f64>().sqrt()
+}
+
+/// Returns the dimension along which the provided points are the most
+/// spread.
+fn widest_dimension<const D: usize>(points: &[[f64; D]], indices: &[usize]) -> usize {
+    let mut minimum = [f64::INFINITY; D];
+    let mut maximum = [f64::NEG_INFINITY; D];
+    for &index in indices {
+        for ((minimum, maximum), &coordinate) in
+            minimum.iter_mut().zip(maximum.iter_mut()).zip(&points[index])
+        {
+            *minimum = minimum.min(coordinate);
+            *maximum = maximum.max(coordinate);
+        }
+    }
+    let mut widest = 0;
+    let mut widest_spread = f64::NEG_INFINITY;
+    for (dimension, (minimum, maximum)) in minimum.iter().zip(&maximum).enumerate() {
+        if maximum - minimum > widest_spread {
+            widest = dimension;
+            widest_spread = maximum - minimum;
+        }
+    }
+    widest
+}
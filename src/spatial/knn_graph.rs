@@ -0,0 +1,258 @@
+//! Submodule providing the construction of the k-nearest-neighbour graphs of
+//! a point set.
+
+use alloc::vec::Vec;
+
+use super::{Metric, VpTree};
+use crate::{
+    impls::{SymmetricCSR2D, ValuedCSR2D},
+    traits::{Matrix2D, MatrixMut, SparseMatrix, SparseMatrixMut, SparseValuedMatrix},
+};
+
+/// Policy turning the directed k-nearest-neighbour graph into an undirected
+/// one, see [`KnnSymmetrize::symmetrize`].
+///
+/// Mutual neighbourhoods suit clustering, as they do not connect outliers to
+/// dense regions, while the union keeps every point connected to its
+/// neighbours, as needed by graph embeddings, and the average suits
+/// affinities, as in spectral clustering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KnnSymmetrize {
+    /// Keeps the pairs of points that are neighbours of each other, with the
+    /// smaller of their two weights.
+    Mutual,
+    /// Keeps the pairs of points of which at least one is a neighbour of the
+    /// other, with the smaller of their weights when both are.
+    Union,
+    /// Keeps the pairs of points of which at least one is a neighbour of the
+    /// other, with the average of their two weights, a missing edge counting
+    /// as a null weight.
+    Average,
+}
+
+impl KnnSymmetrize {
+    /// Returns the undirected graph obtained from the directed
+    /// k-nearest-neighbour graph under this policy.
+    ///
+    /// The directed graph is usually built by [`knn_graph`], but any square
+    /// weighted graph is accepted, self-loops being kept as they are. With
+    /// the distances of a metric, both directions of an edge have the same
+    /// weight, so that the mutual graph is that of [`mutual_knn_graph`].
+    ///
+    /// # Arguments
+    ///
+    /// * `graph` - The directed graph to symmetrize.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the graph is not square.
+    ///
+    /// # Complexity
+    ///
+    /// O(E log E) time and O(E) space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{
+    ///     prelude::*,
+    ///     spatial::{Euclidean, KnnSymmetrize, knn_graph},
+    /// };
+    ///
+    /// let points = [[0.0], [1.0], [3.0], [7.0]];
+    /// let graph = knn_graph(&points, 1, &Euclidean);
+    ///
+    /// let union = KnnSymmetrize::Union.symmetrize(&graph);
+    /// assert_eq!(
+    ///     SparseMatrix::sparse_coordinates(&union).collect::<Vec<_>>(),
+    ///     vec![(0, 1), (1, 0), (1, 2), (2, 1), (2, 3), (3, 2)]
+    /// );
+    /// let average = KnnSymmetrize::Average.symmetrize(&graph);
+    /// assert_eq!(average.sparse_values().collect::<Vec<_>>(), vec![1.0, 1.0, 1.0, 1.0, 2.0, 2.0]);
+    /// ```
+    #[must_use]
+    pub fn symmetrize(
+        self,
+        graph: &ValuedCSR2D<usize, usize, usize, f64>,
+    ) -> SymmetricCSR2D<ValuedCSR2D<usize, usize, usize, f64>> {
+        let order = graph.number_of_rows();
+        assert_eq!(order, graph.number_of_columns(), "The graph to symmetrize must be square.");
+
+        // Both directions of an edge end up next to each other.
+        let mut entries: Vec<(usize, usize, f64)> = SparseMatrix::sparse_coordinates(graph)
+            .zip(graph.sparse_values())
+            .map(|((row, column), weight)| (row.min(column), row.max(column), weight))
+            .collect();
+        entries.sort_by_key(|&(row, column, _)| (row, column));
+
+        let edges = entries
+            .chunk_by(|left, right| (left.0, left.1) == (right.0, right.1))
+            .filter_map(|pair| {
+                let (row, column, weight) = pair[0];
+                let reverse = pair.get(1).map(|&(_, _, weight)| weight);
+                let weight = match (self, reverse) {
+                    (Self::Mutual, None) if row != column => return None,
+                    (Self::Mutual | Self::Union, Some(reverse)) => weight.min(reverse),
+                    (Self::Average, reverse) if row != column => {
+                        f64::midpoint(weight, reverse.unwrap_or(0.0))
+                    }
+                    _ => weight,
+                };
+                Some((row, column, weight))
+            })
+            .collect::<Vec<_>>();
+        SymmetricCSR2D::from_sorted_upper_triangular_entries(order, edges)
+            .expect("Edges are sorted, distinct and upper triangular.")
+    }
+}
+
+/// Returns the directed k-nearest-neighbour graph of the points, with an edge
+/// from every point to each of its `k` nearest other points, weighted by their
+/// distance.
+///
+/// Node `i` of the graph is the point at position `i` of `points`, and its row
+/// lists its neighbours by increasing position. Ties in distance are broken
+/// towards the smaller position, so that every point has exactly `k`
+/// neighbours, or all other points if there are at most `k` of them. A point
+/// is never its own neighbour, but coincident points are neighbours at null
+/// distance. The neighbourhoods are retrieved through a [`VpTree`].
+///
+/// See [`mutual_knn_graph`] for the symmetric graph keeping only the mutual
+/// neighbours, and [`KnnSymmetrize`] for the other symmetrization policies.
+///
+/// # Arguments
+///
+/// * `points` - The points to connect.
+/// * `k` - The number of neighbours of every point.
+/// * `metric` - The metric comparing the points.
+///
+/// # Complexity
+///
+/// O(N log N) expected distance evaluations to build the index, plus one
+/// nearest-neighbour query per point.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{
+///     prelude::*,
+///     spatial::{Euclidean, knn_graph},
+/// };
+///
+/// let points = [[0.0], [1.0], [3.0], [7.0]];
+/// let graph = knn_graph(&points, 1, &Euclidean);
+///
+/// assert_eq!(
+///     SparseMatrix::sparse_coordinates(&graph).collect::<Vec<_>>(),
+///     vec![(0, 1), (1, 0), (2, 1), (3, 2)]
+/// );
+/// assert_eq!(graph.sparse_values().collect::<Vec<_>>(), vec![1.0, 1.0, 2.0, 4.0]);
+/// ```
+#[must_use]
+pub fn knn_graph<P, M>(points: &[P], k: usize, metric: &M) -> ValuedCSR2D<usize, usize, usize, f64>
+where
+    M: Metric<P> + ?Sized,
+{
+    let neighborhoods = nearest_neighborhoods(points, k, metric);
+    let mut graph: ValuedCSR2D<usize, usize, usize, f64> =
+        SparseMatrixMut::with_sparse_shaped_capacity(
+            (points.len(), points.len()),
+            neighborhoods.iter().map(Vec::len).sum(),
+        );
+    for (source, neighborhood) in neighborhoods.into_iter().enumerate() {
+        for (destination, distance) in neighborhood {
+            MatrixMut::add(&mut graph, (source, destination, distance))
+                .expect("Neighbourhoods are sorted by position.");
+        }
+    }
+    graph
+}
+
+/// Returns the mutual k-nearest-neighbour graph of the points, connecting two
+/// points when each is among the `k` nearest neighbours of the other, with
+/// their distance as edge weight.
+///
+/// The neighbourhoods are those of [`knn_graph`], whose edges are kept only
+/// when their reverse edge also exists. Unlike the k-nearest-neighbour graph,
+/// the mutual one does not connect outliers to dense regions, so its degrees
+/// are at most `k` and its connected components follow the clusters of the
+/// points.
+///
+/// # Arguments
+///
+/// * `points` - The points to connect.
+/// * `k` - The number of neighbours of every point.
+/// * `metric` - The metric comparing the points.
+///
+/// # Complexity
+///
+/// O(N log N) expected distance evaluations to build the index, plus one
+/// nearest-neighbour query per point.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{
+///     prelude::*,
+///     spatial::{Euclidean, mutual_knn_graph},
+/// };
+///
+/// let points = [[0.0], [1.0], [3.0], [7.0]];
+/// let graph = mutual_knn_graph(&points, 1, &Euclidean);
+///
+/// // Point 1 is the nearest neighbour of point 2, but not the other way.
+/// assert_eq!(SparseMatrix::sparse_coordinates(&graph).collect::<Vec<_>>(), vec![(0, 1), (1, 0)]);
+/// ```
+#[must_use]
+pub fn mutual_knn_graph<P, M>(
+    points: &[P],
+    k: usize,
+    metric: &M,
+) -> SymmetricCSR2D<ValuedCSR2D<usize, usize, usize, f64>>
+where
+    M: Metric<P> + ?Sized,
+{
+    let neighborhoods = nearest_neighborhoods(points, k, metric);
+    let is_neighbor = |source: usize, destination: usize| {
+        neighborhoods[source].binary_search_by_key(&destination, |&(neighbor, _)| neighbor).is_ok()
+    };
+    let edges: Vec<(usize, usize, f64)> = neighborhoods
+        .iter()
+        .enumerate()
+        .flat_map(|(source, neighborhood)| {
+            neighborhood
+                .iter()
+                .filter(move |&&(destination, _)| destination > source)
+                .map(move |&(destination, distance)| (source, destination, distance))
+        })
+        .filter(|&(source, destination, _)| is_neighbor(destination, source))
+        .collect();
+    SymmetricCSR2D::from_sorted_upper_triangular_entries(points.len(), edges)
+        .expect("Edges are sorted, distinct and upper triangular.")
+}
+
+/// Returns the `k` nearest other points of every point with their distances,
+/// sorted by position.
+fn nearest_neighborhoods<P, M>(points: &[P], k: usize, metric: &M) -> Vec<Vec<(usize, f64)>>
+where
+    M: Metric<P> + ?Sized,
+{
+    let tree = VpTree::new(0..points.len(), |left: &usize, right: &usize| {
+        metric.distance(&points[*left], &points[*right])
+    });
+    (0..points.len())
+        .map(|source| {
+            // The point itself is usually its own nearest neighbour, but
+            // coincident points with a smaller position come before it.
+            let mut neighborhood: Vec<(usize, f64)> = tree
+                .nearest_neighbors(&source, k.saturating_add(1))
+                .into_iter()
+                .filter(|neighbor| neighbor.index() != source)
+                .map(|neighbor| (neighbor.index(), neighbor.distance()))
+                .take(k)
+                .collect();
+            neighborhood.sort_unstable_by_key(|&(neighbor, _)| neighbor);
+            neighborhood
+        })
+        .collect()
+}
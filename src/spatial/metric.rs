@@ -0,0 +1,124 @@
+//! Submodule providing the `Metric` trait, measuring the distance between
+//! two points, and the usual metrics over coordinate vectors.
+
+/// Distance between two points of type `P`.
+///
+/// Spatial indices such as [`VpTree`](super::VpTree) rely on the distance
+/// being a metric: it must be non-negative, null between identical points,
+/// symmetric, and satisfy the triangle inequality `d(a, c) <= d(a, b) + d(b,
+/// c)`. Queries may silently miss points otherwise.
+///
+/// The trait is implemented by any closure `Fn(&P, &P) -> f64`, so that custom
+/// distances, for instance between mass spectra, need no dedicated type.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::spatial::{Chebyshev, Euclidean, Manhattan, Metric};
+///
+/// let (left, right) = ([0.0, 0.0], [3.0, 4.0]);
+/// assert!((Euclidean.distance(&left, &right) - 5.0).abs() < f64::EPSILON);
+/// assert!((Manhattan.distance(&left, &right) - 7.0).abs() < f64::EPSILON);
+/// assert!((Chebyshev.distance(&left, &right) - 4.0).abs() < f64::EPSILON);
+///
+/// let absolute_difference = |left: &f64, right: &f64| (left - right).abs();
+/// assert!((absolute_difference.distance(&1.5, &-1.0) - 2.5).abs() < f64::EPSILON);
+/// ```
+pub trait Metric<P: ?Sized> {
+    /// Returns the distance between the two points.
+    fn distance(&self, left: &P, right: &P) -> f64;
+}
+
+impl<P: ?Sized, F> Metric<P> for F
+where
+    F: Fn(&P, &P) -> f64,
+{
+    #[inline]
+    fn distance(&self, left: &P, right: &P) -> f64 {
+        self(left, right)
+    }
+}
+
+/// Euclidean distance between coordinate vectors, the square root of the sum
+/// of the squared coordinate differences.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Euclidean;
+
+impl<P: AsRef<[f64]> + ?Sized> Metric<P> for Euclidean {
+    #[inline]
+    fn distance(&self, left: &P, right: &P) -> f64 {
+        left.as_ref()
+            .iter()
+            .zip(right.as_ref())
+            .map(|(left, right)| (left - right) * (left - right))
+            .sum::<f64>()
+            .sqrt()
+    }
+}
+
+/// Manhattan distance between coordinate vectors, the sum of the absolute
+/// coordinate differences.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Manhattan;
+
+impl<P: AsRef<[f64]> + ?Sized> Metric<P> for Manhattan {
+    #[inline]
+    fn distance(&self, left: &P, right: &P) -> f64 {
+        left.as_ref().iter().zip(right.as_ref()).map(|(left, right)| (left - right).abs()).sum()
+    }
+}
+
+/// Chebyshev distance between coordinate vectors, the largest absolute
+/// coordinate difference.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Chebyshev;
+
+impl<P: AsRef<[f64]> + ?Sized> Metric<P> for Chebyshev {
+    #[inline]
+    fn distance(&self, left: &P, right: &P) -> f64 {
+        left.as_ref()
+            .iter()
+            .zip(right.as_ref())
+            .map(|(left, right)| (left - right).abs())
+            .fold(0.0, f64::max)
+    }
+}
+
+/// Angular distance between coordinate vectors, the angle between them
+/// divided by π, which ranges from zero for vectors pointing the same way to
+/// one for opposite vectors.
+///
+/// Unlike the cosine distance `1 - cos θ`, which violates the triangle
+/// inequality, the angular distance is a metric over directions, so it can
+/// index vectors compared by cosine similarity, such as spectra. A null
+/// vector is at distance zero from itself and one half from any other vector.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Angular;
+
+impl<P: AsRef<[f64]> + ?Sized> Metric<P> for Angular {
+    #[inline]
+    fn distance(&self, left: &P, right: &P) -> f64 {
+        match cosine(left.as_ref(), right.as_ref()) {
+            Ok(cosine) => cosine.acos() / core::f64::consts::PI,
+            Err(both_null) => {
+                if both_null {
+                    0.0
+                } else {
+                    0.5
+                }
+            }
+        }
+    }
+}
+
+/// Returns the cosine of the angle between two vectors, clamped to `[-1, 1]`,
+/// or whether both vectors are null if either of them is.
+pub(super) fn cosine(left: &[f64], right: &[f64]) -> Result<f64, bool> {
+    let dot: f64 = left.iter().zip(right).map(|(left, right)| left * right).sum();
+    let left_norm = left.iter().map(|value| value * value).sum::<f64>().sqrt();
+    let right_norm = right.iter().map(|value| value * value).sum::<f64>().sqrt();
+    match (left_norm > 0.0, right_norm > 0.0) {
+        (true, true) => Ok((dot / (left_norm * right_norm)).clamp(-1.0, 1.0)),
+        (left_is_non_null, right_is_non_null) => Err(!left_is_non_null && !right_is_non_null),
+    }
+}
@@ -0,0 +1,109 @@
+//! Submodule providing the result type shared by the queries of the spatial
+//! indices.
+
+use alloc::{collections::BinaryHeap, vec::Vec};
+use core::cmp::Ordering;
+
+/// A point returned by a spatial query, identified by its position in the
+/// input of the index, together with its distance from the query.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Neighbor {
+    /// The position of the point in the input of the index.
+    index: usize,
+    /// The distance of the point from the query.
+    distance: f64,
+}
+
+impl Neighbor {
+    /// Creates a new neighbour.
+    #[inline]
+    #[must_use]
+    pub const fn new(index: usize, distance: f64) -> Self {
+        Self { index, distance }
+    }
+
+    /// Returns the position of the point in the input of the index.
+    #[inline]
+    #[must_use]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the distance of the point from the query.
+    #[inline]
+    #[must_use]
+    pub const fn distance(&self) -> f64 {
+        self.distance
+    }
+
+    /// Compares neighbours by increasing distance and then by increasing
+    /// index, which is the order in which queries report them.
+    pub(crate) fn query_order(&self, other: &Self) -> Ordering {
+        self.distance.total_cmp(&other.distance).then(self.index.cmp(&other.index))
+    }
+}
+
+/// Collector of the `k` nearest neighbours offered during a query.
+pub(crate) struct NearestNeighbors {
+    /// The number of neighbours to keep.
+    k: usize,
+    /// The best neighbours offered so far, the worst of them on top.
+    heap: BinaryHeap<QueryOrdered>,
+}
+
+impl NearestNeighbors {
+    /// Creates a collector keeping the `k` best neighbours.
+    pub(crate) fn new(k: usize) -> Self {
+        Self { k, heap: BinaryHeap::new() }
+    }
+
+    /// Offers a neighbour, which is kept if it is among the `k` best so far.
+    pub(crate) fn offer(&mut self, neighbor: Neighbor) {
+        let candidate = QueryOrdered(neighbor);
+        if self.heap.len() < self.k {
+            self.heap.push(candidate);
+        } else if self.heap.peek().is_some_and(|worst| candidate < *worst) {
+            self.heap.pop();
+            self.heap.push(candidate);
+        }
+    }
+
+    /// Returns the distance beyond which offered neighbours are discarded,
+    /// which is infinite until `k` neighbours have been collected.
+    pub(crate) fn bound(&self) -> f64 {
+        if self.heap.len() < self.k {
+            f64::INFINITY
+        } else {
+            self.heap.peek().map_or(f64::NEG_INFINITY, |worst| worst.0.distance)
+        }
+    }
+
+    /// Returns the collected neighbours in query order.
+    pub(crate) fn into_sorted_vec(self) -> Vec<Neighbor> {
+        self.heap.into_sorted_vec().into_iter().map(|QueryOrdered(neighbor)| neighbor).collect()
+    }
+}
+
+/// Neighbour ordered as reported by the queries, so that the top of a
+/// max-heap is the worst neighbour found so far.
+struct QueryOrdered(Neighbor);
+
+impl PartialEq for QueryOrdered {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for QueryOrdered {}
+
+impl PartialOrd for QueryOrdered {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for QueryOrdered {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.query_order(&other.0)
+    }
+}
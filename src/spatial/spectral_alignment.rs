@@ -0,0 +1,175 @@
+//! Submodule providing the `SpectralAligner`, matching the peaks of two mass
+//! spectra with the sparse linear assignment solver.
+
+use alloc::vec::Vec;
+
+use super::GatedCostBuilder;
+use crate::traits::{Jaqaman, LAPError};
+
+/// A peak of a mass spectrum, as its mass-to-charge ratio and its intensity.
+pub type Peak = (f64, f64);
+
+/// Aligner of the peaks of two mass spectra, matching each peak with at most
+/// one peak of the other spectrum.
+///
+/// Two peaks may only be matched when their mass-to-charge ratios differ by at
+/// most the tolerance. Each peak is weighted by its mass-to-charge ratio and
+/// its intensity raised to configurable powers, and the matching maximizes,
+/// with [`Jaqaman`], the sum over the matched pairs of one plus the product
+/// of their weights, normalized by the largest such product. Peaks with a
+/// null or non-finite weight are never matched.
+///
+/// The alignment is scored with the cosine similarity of the weights of the
+/// matched pairs, as in the Hungarian variant of the spectral cosine
+/// similarity:
+///
+/// ```text
+/// score = Σ_matched w_left · w_right / (‖w_left‖ · ‖w_right‖)
+/// ```
+///
+/// where the norms run over all the peaks of each spectrum.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::spatial::SpectralAligner;
+///
+/// let left = [(100.0, 10.0), (150.0, 20.0), (200.0, 5.0)];
+/// let right = [(100.01, 8.0), (150.02, 25.0), (180.0, 3.0)];
+///
+/// let alignment = SpectralAligner::new(0.05).align(&left, &right).unwrap();
+/// assert_eq!(alignment.matches(), [(0, 0), (1, 1)]);
+/// assert!(alignment.score() > 0.95);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpectralAligner {
+    /// The largest mass-to-charge difference between two matched peaks.
+    tolerance: f64,
+    /// The power of the mass-to-charge ratio in the weight of a peak.
+    mz_power: f64,
+    /// The power of the intensity in the weight of a peak.
+    intensity_power: f64,
+}
+
+impl SpectralAligner {
+    /// Creates an aligner matching peaks within the provided mass-to-charge
+    /// tolerance, weighting each peak by its intensity.
+    ///
+    /// # Arguments
+    ///
+    /// * `tolerance` - The largest mass-to-charge difference between two
+    ///   matched peaks.
+    #[inline]
+    #[must_use]
+    pub fn new(tolerance: f64) -> Self {
+        Self { tolerance, mz_power: 0.0, intensity_power: 1.0 }
+    }
+
+    /// Sets the powers of the mass-to-charge ratio and of the intensity in the
+    /// weight of a peak, which default to zero and one.
+    #[inline]
+    #[must_use]
+    pub fn with_powers(mut self, mz_power: f64, intensity_power: f64) -> Self {
+        self.mz_power = mz_power;
+        self.intensity_power = intensity_power;
+        self
+    }
+
+    /// Returns the weight of the peak used for matching and scoring.
+    #[inline]
+    fn weight(&self, (mz, intensity): Peak) -> f64 {
+        let weight = mz.powf(self.mz_power) * intensity.powf(self.intensity_power);
+        if weight.is_finite() && weight > 0.0 { weight } else { 0.0 }
+    }
+
+    /// Aligns the peaks of the two spectra.
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - The peaks of the first spectrum.
+    /// * `right` - The peaks of the second spectrum.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LAPError`] if the assignment solver fails.
+    ///
+    /// # Complexity
+    ///
+    /// O(L·R) gate evaluations, plus one assignment problem over the pairs of
+    /// peaks within the tolerance.
+    #[inline]
+    pub fn align(&self, left: &[Peak], right: &[Peak]) -> Result<SpectralAlignment, LAPError> {
+        let left_weights: Vec<f64> = left.iter().map(|&peak| self.weight(peak)).collect();
+        let right_weights: Vec<f64> = right.iter().map(|&peak| self.weight(peak)).collect();
+        let largest_product =
+            left_weights.iter().fold(0.0_f64, |largest, &weight| largest.max(weight))
+                * right_weights.iter().fold(0.0_f64, |largest, &weight| largest.max(weight));
+
+        let left_indices: Vec<usize> = (0..left.len()).collect();
+        let right_indices: Vec<usize> = (0..right.len()).collect();
+        let costs = GatedCostBuilder::new(&left_indices, &right_indices, |&row, &column| {
+            let product = left_weights[row] * right_weights[column];
+            if product > 0.0 {
+                // The solver requires positive costs, so the pairs of largest
+                // product are raised to a negligible positive value.
+                (1.0 - product / largest_product).max(f64::EPSILON)
+            } else {
+                f64::INFINITY
+            }
+        })
+        .with_gate(|&row, &column| (left[row].0 - right[column].0).abs() <= self.tolerance)
+        .build();
+
+        // Leaving a peak unmatched costs one, more than any pair.
+        let mut matches = costs.jaqaman(2.0, 4.0)?;
+        matches.sort_unstable();
+
+        let norms = left_weights.iter().map(|weight| weight * weight).sum::<f64>().sqrt()
+            * right_weights.iter().map(|weight| weight * weight).sum::<f64>().sqrt();
+        let score = if norms > 0.0 {
+            matches
+                .iter()
+                .map(|&(row, column)| left_weights[row] * right_weights[column])
+                .sum::<f64>()
+                / norms
+        } else {
+            0.0
+        };
+        Ok(SpectralAlignment { matches, score })
+    }
+}
+
+/// The alignment of the peaks of two mass spectra.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpectralAlignment {
+    /// The matched pairs of peaks, by increasing peak of the first spectrum.
+    matches: Vec<(usize, usize)>,
+    /// The cosine similarity of the weights of the matched pairs.
+    score: f64,
+}
+
+impl SpectralAlignment {
+    /// Returns the matched pairs of peaks, as the positions of the peaks in
+    /// the first and second spectrum, by increasing position in the first
+    /// spectrum.
+    #[inline]
+    #[must_use]
+    pub fn matches(&self) -> &[(usize, usize)] {
+        &self.matches
+    }
+
+    /// Returns the number of matched pairs of peaks.
+    #[inline]
+    #[must_use]
+    pub fn number_of_matches(&self) -> usize {
+        self.matches.len()
+    }
+
+    /// Returns the cosine similarity of the weights of the matched pairs,
+    /// between zero and one.
+    #[inline]
+    #[must_use]
+    pub fn score(&self) -> f64 {
+        self.score
+    }
+}
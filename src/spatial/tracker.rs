@@ -0,0 +1,576 @@
+//! Submodule providing the `Tracker`, linking the detections of a sequence of
+//! frames into tracks with the two-stage assignment approach of Jaqaman et
+//! al.
+
+use alloc::{boxed::Box, vec::Vec};
+
+use super::GatedCostBuilder;
+use crate::{
+    impls::ValuedCSR2D,
+    traits::{
+        Jaqaman, LAPError, Matrix2D, MatrixMut, SizedSparseMatrix, SparseMatrix, SparseMatrix2D,
+        SparseMatrixMut, SparseValuedMatrix, SparseValuedMatrix2D,
+    },
+};
+
+/// A detection, identified by its frame and its position within the frame.
+pub type Detection = (usize, usize);
+
+/// Cost of linking two detections.
+type LinkCost<'costs, P> = Box<dyn Fn(&P, &P) -> f64 + 'costs>;
+
+/// Cost of closing a gap between two detections, given their frame
+/// difference.
+type GapClosingCost<'costs, P> = Box<dyn Fn(&P, &P, usize) -> f64 + 'costs>;
+
+/// Multi-object tracker linking the detections of a sequence of frames into
+/// tracks.
+///
+/// Tracks are built in two stages, each solved as a sparse linear assignment
+/// problem with [`Jaqaman`], so that every detection may also remain
+/// unlinked:
+///
+/// 1. the detections of every pair of consecutive frames are linked, and the
+///    resulting chains form the track segments;
+/// 2. the end of every segment may then be linked to the start of a later
+///    segment, closing the gap left by missed detections; with merging enabled,
+///    it may instead join a detection in the middle of another segment, and
+///    with splitting enabled, the start of a segment may branch off a detection
+///    in the middle of another segment.
+///
+/// Links whose cost is not finite, is negative, or is at least the maximal
+/// cost of their stage are never made: leaving two detections unlinked costs
+/// twice the maximal cost, which the solver weighs against the links. Costs
+/// are reported as returned by the cost functions, which may return zero.
+///
+/// # Reference
+///
+/// Jaqaman, K., Loerke, D., Mettlen, M., Kuwata, H., Grinstein, S., Schmid, S.
+/// L., & Danuser, G. (2008). [Robust single-particle tracking in live-cell time-lapse sequences](https://doi.org/10.1038/nmeth.1237).
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::spatial::Tracker;
+///
+/// // Two particles moving right, the first one missed in frame 2.
+/// let frames: Vec<Vec<[f64; 2]>> = vec![
+///     vec![[0.0, 0.0], [0.0, 10.0]],
+///     vec![[1.0, 0.0], [1.0, 10.0]],
+///     vec![[2.0, 10.0]],
+///     vec![[3.0, 0.0], [3.0, 10.0]],
+/// ];
+/// let squared_displacement = |left: &[f64; 2], right: &[f64; 2]| {
+///     (left[0] - right[0]).powi(2) + (left[1] - right[1]).powi(2)
+/// };
+///
+/// let tracking = Tracker::new(squared_displacement, 4.0)
+///     .with_gap_closing(1, |left: &[f64; 2], right: &[f64; 2], frames: usize| {
+///         squared_displacement(left, right) / frames as f64
+///     })
+///     .track(&frames)
+///     .unwrap();
+///
+/// assert_eq!(tracking.tracks().len(), 2);
+/// assert_eq!(tracking.tracks()[0].detections(), [(0, 0), (1, 0), (3, 0)]);
+/// assert_eq!(tracking.tracks()[0].link_costs(), [1.0, 2.0]);
+/// assert_eq!(tracking.tracks()[1].detections(), [(0, 1), (1, 1), (2, 0), (3, 1)]);
+/// ```
+pub struct Tracker<'costs, P> {
+    /// The cost of linking detections of consecutive frames.
+    link_cost: LinkCost<'costs, P>,
+    /// The cost from which links between consecutive frames are not made.
+    max_link_cost: f64,
+    /// The largest number of consecutive frames a gap may span, and the cost
+    /// of closing a gap.
+    gap_closing: Option<(usize, GapClosingCost<'costs, P>)>,
+    /// The cost of a segment end joining a detection of another segment.
+    merge_cost: Option<LinkCost<'costs, P>>,
+    /// The cost of a segment start branching off a detection of another
+    /// segment.
+    split_cost: Option<LinkCost<'costs, P>>,
+    /// The cost from which gap closings, merges and splits are not made.
+    max_closing_cost: Option<f64>,
+}
+
+impl<'costs, P> Tracker<'costs, P> {
+    /// Creates a tracker linking detections of consecutive frames, without
+    /// gap closing, merging nor splitting.
+    ///
+    /// # Arguments
+    ///
+    /// * `link_cost` - The cost of linking a detection to a detection of the
+    ///   next frame.
+    /// * `max_link_cost` - The cost from which such links are not made.
+    #[must_use]
+    pub fn new<F>(link_cost: F, max_link_cost: f64) -> Self
+    where
+        F: Fn(&P, &P) -> f64 + 'costs,
+    {
+        Self {
+            link_cost: Box::new(link_cost),
+            max_link_cost,
+            gap_closing: None,
+            merge_cost: None,
+            split_cost: None,
+            max_closing_cost: None,
+        }
+    }
+
+    /// Enables gap closing, linking the end of a segment to the start of a
+    /// segment up to `maximum_gap` frames later than the next frame.
+    ///
+    /// The cost function receives the end detection, the start detection,
+    /// and their frame difference, between 1 and `maximum_gap + 1`.
+    #[must_use]
+    pub fn with_gap_closing<F>(mut self, maximum_gap: usize, cost: F) -> Self
+    where
+        F: Fn(&P, &P, usize) -> f64 + 'costs,
+    {
+        self.gap_closing = Some((maximum_gap, Box::new(cost)));
+        self
+    }
+
+    /// Enables merging, linking the end of a segment to a detection of the
+    /// next frame in the middle of another segment.
+    ///
+    /// The cost function receives the end detection and the detection it
+    /// joins.
+    #[must_use]
+    pub fn with_merging<F>(mut self, cost: F) -> Self
+    where
+        F: Fn(&P, &P) -> f64 + 'costs,
+    {
+        self.merge_cost = Some(Box::new(cost));
+        self
+    }
+
+    /// Enables splitting, linking the start of a segment to a detection of
+    /// the previous frame in the middle of another segment.
+    ///
+    /// The cost function receives the detection branched off and the start
+    /// detection.
+    #[must_use]
+    pub fn with_splitting<F>(mut self, cost: F) -> Self
+    where
+        F: Fn(&P, &P) -> f64 + 'costs,
+    {
+        self.split_cost = Some(Box::new(cost));
+        self
+    }
+
+    /// Sets the cost from which gap closings, merges and splits are not
+    /// made, which defaults to the maximal link cost.
+    #[must_use]
+    pub fn with_max_closing_cost(mut self, max_closing_cost: f64) -> Self {
+        self.max_closing_cost = Some(max_closing_cost);
+        self
+    }
+
+    /// Links the detections of the frames into tracks.
+    ///
+    /// # Arguments
+    ///
+    /// * `frames` - The detections of every frame, in temporal order.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LAPError`] if a maximal cost is not finite and positive.
+    ///
+    /// # Complexity
+    ///
+    /// One link cost evaluation per pair of detections of consecutive frames,
+    /// plus one assignment problem per pair of consecutive frames and one
+    /// over all segments.
+    pub fn track<F: AsRef<[P]>>(&self, frames: &[F]) -> Result<Tracking, LAPError> {
+        let segments = self.link_frames(frames)?;
+        let links =
+            if self.gap_closing.is_some() || self.merge_cost.is_some() || self.split_cost.is_some()
+            {
+                self.link_segments(frames, &segments)?
+            } else {
+                SegmentLinks::default()
+            };
+        Ok(assemble(segments, &links))
+    }
+
+    /// Links the detections of consecutive frames, returning the segments.
+    fn link_frames<F: AsRef<[P]>>(&self, frames: &[F]) -> Result<Vec<Track>, LAPError> {
+        // The successor of every detection, with the cost of the link.
+        let mut successors: Vec<Vec<Option<(usize, f64)>>> =
+            frames.iter().map(|frame| vec![None; frame.as_ref().len()]).collect();
+        let mut has_predecessor: Vec<Vec<bool>> =
+            frames.iter().map(|frame| vec![false; frame.as_ref().len()]).collect();
+        for (frame, pair) in frames.windows(2).enumerate() {
+            let costs = GatedCostBuilder::new(pair[0].as_ref(), pair[1].as_ref(), |left, right| {
+                // Inadmissible links are dropped by the builder as infinite.
+                admissible((self.link_cost)(left, right), self.max_link_cost)
+                    .unwrap_or(f64::INFINITY)
+            })
+            .build();
+            for (row, column, cost) in solve(&costs, self.max_link_cost)? {
+                successors[frame][row] = Some((column, cost));
+                has_predecessor[frame + 1][column] = true;
+            }
+        }
+
+        let mut segments = Vec::new();
+        for (frame, starts) in has_predecessor.iter().enumerate() {
+            for index in (0..starts.len()).filter(|&index| !starts[index]) {
+                let mut segment =
+                    Track { detections: vec![(frame, index)], link_costs: Vec::new() };
+                let (mut current_frame, mut current_index) = (frame, index);
+                while let Some((next_index, cost)) = successors[current_frame][current_index] {
+                    current_frame += 1;
+                    current_index = next_index;
+                    segment.detections.push((current_frame, current_index));
+                    segment.link_costs.push(cost);
+                }
+                segments.push(segment);
+            }
+        }
+        Ok(segments)
+    }
+
+    /// Links the ends of the segments to the starts of later segments, or to
+    /// the middle of other segments.
+    #[allow(clippy::too_many_lines)]
+    fn link_segments<F: AsRef<[P]>>(
+        &self,
+        frames: &[F],
+        segments: &[Track],
+    ) -> Result<SegmentLinks, LAPError> {
+        let max_closing_cost = self.max_closing_cost.unwrap_or(self.max_link_cost);
+        let point = |(frame, index): Detection| &frames[frame].as_ref()[index];
+        let first = |segment: &Track| segment.detections[0];
+        let last = |segment: &Track| segment.detections[segment.detections.len() - 1];
+
+        // The segments starting at every frame, and the detections which have
+        // a predecessor or a successor in their segment, by frame.
+        let mut starts_by_frame: Vec<Vec<usize>> = vec![Vec::new(); frames.len()];
+        let mut middles_with_predecessor: Vec<Vec<(usize, Detection)>> =
+            vec![Vec::new(); frames.len()];
+        let mut middles_with_successor: Vec<(usize, Detection)> = Vec::new();
+        for (segment_index, segment) in segments.iter().enumerate() {
+            starts_by_frame[first(segment).0].push(segment_index);
+            let length = segment.detections.len();
+            if self.merge_cost.is_some() {
+                for &detection in &segment.detections[1..] {
+                    middles_with_predecessor[detection.0].push((segment_index, detection));
+                }
+            }
+            if self.split_cost.is_some() {
+                for &detection in &segment.detections[..length - 1] {
+                    middles_with_successor.push((segment_index, detection));
+                }
+            }
+        }
+        // The merge targets are numbered after the segment starts.
+        let mut merge_targets: Vec<(usize, Detection)> = Vec::new();
+        let mut merge_columns: Vec<Vec<usize>> = vec![Vec::new(); frames.len()];
+        for (frame, middles) in middles_with_predecessor.into_iter().enumerate() {
+            for middle in middles {
+                merge_columns[frame].push(segments.len() + merge_targets.len());
+                merge_targets.push(middle);
+            }
+        }
+
+        let number_of_rows = segments.len() + middles_with_successor.len();
+        let number_of_columns = segments.len() + merge_targets.len();
+        let mut entries: Vec<(usize, usize, f64)> = Vec::new();
+        for (row, segment) in segments.iter().enumerate() {
+            let row_start = entries.len();
+            let end = last(segment);
+            if let Some((maximum_gap, gap_closing_cost)) = &self.gap_closing {
+                let later_frames = end.0 + 1..frames.len().min(end.0 + maximum_gap + 2);
+                for start_frame in later_frames {
+                    for &column in &starts_by_frame[start_frame] {
+                        let cost = gap_closing_cost(
+                            point(end),
+                            point(first(&segments[column])),
+                            start_frame - end.0,
+                        );
+                        if let Some(cost) = admissible(cost, max_closing_cost) {
+                            entries.push((row, column, cost));
+                        }
+                    }
+                }
+            }
+            if let Some(merge_cost) = &self.merge_cost {
+                let next_frame_columns =
+                    merge_columns.get(end.0 + 1).map_or(&[][..], Vec::as_slice);
+                for &column in next_frame_columns {
+                    let target = merge_targets[column - segments.len()].1;
+                    if let Some(cost) =
+                        admissible(merge_cost(point(end), point(target)), max_closing_cost)
+                    {
+                        entries.push((row, column, cost));
+                    }
+                }
+            }
+            entries[row_start..].sort_unstable_by_key(|&(_, column, _)| column);
+        }
+        if let Some(split_cost) = &self.split_cost {
+            for (offset, &(_, middle)) in middles_with_successor.iter().enumerate() {
+                let row = segments.len() + offset;
+                let row_start = entries.len();
+                for &column in &starts_by_frame[middle.0 + 1] {
+                    let start = first(&segments[column]);
+                    if let Some(cost) =
+                        admissible(split_cost(point(middle), point(start)), max_closing_cost)
+                    {
+                        entries.push((row, column, cost));
+                    }
+                }
+                entries[row_start..].sort_unstable_by_key(|&(_, column, _)| column);
+            }
+        }
+
+        let mut costs: ValuedCSR2D<usize, usize, usize, f64> =
+            SparseMatrixMut::with_sparse_shaped_capacity(
+                (number_of_rows, number_of_columns),
+                entries.len(),
+            );
+        for entry in entries {
+            MatrixMut::add(&mut costs, entry).expect("Entries are sorted by row and column.");
+        }
+
+        let mut links = SegmentLinks::default();
+        for (row, column, cost) in solve(&costs, max_closing_cost)? {
+            match (row < segments.len(), column < segments.len()) {
+                (true, true) => links.gap_closings.push((row, column, cost)),
+                (true, false) => {
+                    let (segment, detection) = merge_targets[column - segments.len()];
+                    links.merges.push((row, segment, detection, cost));
+                }
+                (false, true) => {
+                    let (segment, detection) = middles_with_successor[row - segments.len()];
+                    links.splits.push((column, segment, detection, cost));
+                }
+                (false, false) => unreachable!("Middle detections are never linked together."),
+            }
+        }
+        Ok(links)
+    }
+}
+
+/// A track, the sequence of detections of an object over the frames.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Track {
+    /// The detections of the track, by increasing frame.
+    detections: Vec<Detection>,
+    /// The cost of the link between every pair of consecutive detections.
+    link_costs: Vec<f64>,
+}
+
+impl Track {
+    /// Returns the detections of the track, by increasing frame, which may
+    /// skip frames where a gap was closed.
+    #[inline]
+    #[must_use]
+    pub fn detections(&self) -> &[Detection] {
+        &self.detections
+    }
+
+    /// Returns the cost of the link between every pair of consecutive
+    /// detections of the track.
+    #[inline]
+    #[must_use]
+    pub fn link_costs(&self) -> &[f64] {
+        &self.link_costs
+    }
+
+    /// Returns the total cost of the links of the track.
+    #[inline]
+    #[must_use]
+    pub fn cost(&self) -> f64 {
+        self.link_costs.iter().sum()
+    }
+}
+
+/// A junction between two tracks, where a track ends by merging into another
+/// or starts by splitting from another.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrackJunction {
+    /// The track which merges or splits.
+    track: usize,
+    /// The track which is merged into or split from.
+    other_track: usize,
+    /// The detection of the other track at the junction.
+    detection: Detection,
+    /// The cost of the junction link.
+    cost: f64,
+}
+
+impl TrackJunction {
+    /// Returns the track which merges or splits.
+    #[inline]
+    #[must_use]
+    pub fn track(&self) -> usize {
+        self.track
+    }
+
+    /// Returns the track which is merged into or split from.
+    #[inline]
+    #[must_use]
+    pub fn other_track(&self) -> usize {
+        self.other_track
+    }
+
+    /// Returns the detection of the other track at the junction.
+    #[inline]
+    #[must_use]
+    pub fn detection(&self) -> Detection {
+        self.detection
+    }
+
+    /// Returns the cost of the junction link.
+    #[inline]
+    #[must_use]
+    pub fn cost(&self) -> f64 {
+        self.cost
+    }
+}
+
+/// The tracks computed by a [`Tracker`], with their merges and splits.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tracking {
+    /// The tracks, sorted by their first detection.
+    tracks: Vec<Track>,
+    /// The tracks ending by merging into another track.
+    merges: Vec<TrackJunction>,
+    /// The tracks starting by splitting from another track.
+    splits: Vec<TrackJunction>,
+}
+
+impl Tracking {
+    /// Returns the tracks, sorted by their first detection. Every detection
+    /// belongs to exactly one track.
+    #[inline]
+    #[must_use]
+    pub fn tracks(&self) -> &[Track] {
+        &self.tracks
+    }
+
+    /// Returns the tracks ending by merging into another track, sorted by
+    /// track.
+    #[inline]
+    #[must_use]
+    pub fn merges(&self) -> &[TrackJunction] {
+        &self.merges
+    }
+
+    /// Returns the tracks starting by splitting from another track, sorted by
+    /// track.
+    #[inline]
+    #[must_use]
+    pub fn splits(&self) -> &[TrackJunction] {
+        &self.splits
+    }
+}
+
+/// The links of the second stage, between segments.
+#[derive(Default)]
+struct SegmentLinks {
+    /// The gap closings, as (ending segment, starting segment, cost).
+    gap_closings: Vec<(usize, usize, f64)>,
+    /// The merges, as (ending segment, joined segment, joined detection,
+    /// cost).
+    merges: Vec<(usize, usize, Detection, f64)>,
+    /// The splits, as (starting segment, branched segment, branched
+    /// detection, cost).
+    splits: Vec<(usize, usize, Detection, f64)>,
+}
+
+/// Returns the cost if the link it prices may be made.
+fn admissible(cost: f64, max_cost: f64) -> Option<f64> {
+    (cost.is_finite() && cost >= 0.0 && cost < max_cost).then_some(cost)
+}
+
+/// Solves the assignment problem over the costs, where leaving a row and a
+/// column unassigned costs twice the maximal cost, and returns the
+/// assignments with their costs.
+fn solve(
+    costs: &ValuedCSR2D<usize, usize, usize, f64>,
+    max_cost: f64,
+) -> Result<Vec<(usize, usize, f64)>, LAPError> {
+    // The solver requires positive costs, so null costs are raised to a
+    // negligible positive value.
+    let floor = max_cost * f64::EPSILON;
+    let mut positive_costs: ValuedCSR2D<usize, usize, usize, f64> =
+        SparseMatrixMut::with_sparse_shaped_capacity(
+            (costs.number_of_rows(), costs.number_of_columns()),
+            costs.number_of_defined_values(),
+        );
+    for ((row, column), cost) in SparseMatrix::sparse_coordinates(costs).zip(costs.sparse_values())
+    {
+        MatrixMut::add(&mut positive_costs, (row, column, cost.max(floor)))
+            .expect("Entries are sorted by row and column.");
+    }
+    let assignments = positive_costs.jaqaman(2.0 * max_cost, 4.0 * max_cost)?;
+    Ok(assignments
+        .into_iter()
+        .map(|(row, column)| {
+            let cost = costs
+                .sparse_row(row)
+                .zip(costs.sparse_row_values(row))
+                .find_map(|(entry_column, cost)| (entry_column == column).then_some(cost))
+                .expect("Assignments are entries of the cost matrix.");
+            (row, column, cost)
+        })
+        .collect())
+}
+
+/// Assembles the segments linked by gap closing into tracks, and maps the
+/// merges and splits between segments to the tracks.
+fn assemble(segments: Vec<Track>, links: &SegmentLinks) -> Tracking {
+    let mut next: Vec<Option<(usize, f64)>> = vec![None; segments.len()];
+    let mut has_previous = vec![false; segments.len()];
+    for &(end, start, cost) in &links.gap_closings {
+        next[end] = Some((start, cost));
+        has_previous[start] = true;
+    }
+
+    let mut segments: Vec<Option<Track>> = segments.into_iter().map(Some).collect();
+    let mut track_of_segment = vec![0; segments.len()];
+    let mut tracks: Vec<Track> = Vec::new();
+    for first_segment in (0..segments.len()).filter(|&segment| !has_previous[segment]) {
+        let mut track = segments[first_segment].take().expect("Segments start one track.");
+        track_of_segment[first_segment] = tracks.len();
+        let mut current = first_segment;
+        while let Some((following, cost)) = next[current] {
+            let segment = segments[following].take().expect("Segments join one track.");
+            track.link_costs.push(cost);
+            track.detections.extend(segment.detections);
+            track.link_costs.extend(segment.link_costs);
+            track_of_segment[following] = tracks.len();
+            current = following;
+        }
+        tracks.push(track);
+    }
+
+    // Sort the tracks by their first detection, renumbering the segments.
+    let mut order: Vec<usize> = (0..tracks.len()).collect();
+    order.sort_unstable_by_key(|&track| tracks[track].detections[0]);
+    let mut rank = vec![0; tracks.len()];
+    for (position, &track) in order.iter().enumerate() {
+        rank[track] = position;
+    }
+    let mut tracks: Vec<Option<Track>> = tracks.into_iter().map(Some).collect();
+    let tracks: Vec<Track> =
+        order.iter().map(|&track| tracks[track].take().expect("Tracks are unique.")).collect();
+    let junction = |&(segment, other_segment, detection, cost): &(usize, usize, Detection, f64)| {
+        TrackJunction {
+            track: rank[track_of_segment[segment]],
+            other_track: rank[track_of_segment[other_segment]],
+            detection,
+            cost,
+        }
+    };
+    let mut merges: Vec<TrackJunction> = links.merges.iter().map(junction).collect();
+    let mut splits: Vec<TrackJunction> = links.splits.iter().map(junction).collect();
+    merges.sort_unstable_by_key(TrackJunction::track);
+    splits.sort_unstable_by_key(TrackJunction::track);
+
+    Tracking { tracks, merges, splits }
+}
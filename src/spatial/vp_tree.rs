@@ -0,0 +1,236 @@
+//! Submodule providing the `VpTree` spatial index over points of an arbitrary
+//! metric space.
+
+use alloc::vec::Vec;
+
+use super::{Metric, Neighbor, neighbor::NearestNeighbors};
+
+/// Vantage-point tree over points of type `P` compared by a [`Metric`].
+///
+/// Where a [`KdTree`](super::KdTree) needs Euclidean coordinates, a
+/// vantage-point tree only evaluates distances, so it indexes any metric
+/// space, such as vectors under the [`Angular`](super::Angular) distance or
+/// spectra under a custom distance. Every node picks a vantage point and
+/// splits the remaining points at the median of their distances from it, and
+/// queries use the triangle inequality to skip the half that cannot contain
+/// close enough points.
+///
+/// The tree is stored implicitly in a permutation of the points: the vantage
+/// point of the range `lo..hi` is at position `lo`, followed by the closer
+/// half of the remaining points and then by the farther half.
+///
+/// # Reference
+///
+/// Yianilos, P. N. (1993). [Data structures and algorithms for nearest neighbor search in general metric spaces](https://dl.acm.org/doi/10.5555/313559.313789).
+/// Uhlmann, J. K. (1991). [Satisfying general proximity/similarity queries with metric trees](https://doi.org/10.1016/0020-0190(91)90074-R).
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::spatial::{Angular, VpTree};
+///
+/// // Intensity vectors compared by the angle between them.
+/// let tree = VpTree::new(
+///     vec![vec![1.0, 0.0, 0.0], vec![0.9, 0.1, 0.0], vec![0.0, 1.0, 0.0], vec![0.0, 0.0, 2.0]],
+///     Angular,
+/// );
+///
+/// let nearest = tree.nearest_neighbors(&vec![2.0, 0.1, 0.0], 2);
+/// assert_eq!(nearest.iter().map(|neighbor| neighbor.index()).collect::<Vec<_>>(), [0, 1]);
+///
+/// let close = tree.within_radius(&vec![0.0, 0.0, 1.0], 0.1);
+/// assert_eq!(close.len(), 1);
+/// assert_eq!(close[0].index(), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct VpTree<P, M> {
+    /// The points, in input order.
+    points: Vec<P>,
+    /// The metric comparing the points.
+    metric: M,
+    /// The permutation of the points laying out the tree.
+    order: Vec<usize>,
+    /// The median distance from the vantage point at every position of
+    /// `order`, separating the closer half of its range from the farther one.
+    radii: Vec<f64>,
+}
+
+impl<P, M: Metric<P>> VpTree<P, M> {
+    /// Builds the vantage-point tree over the provided points.
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - The points to index. Neighbours are reported by their
+    ///   position in this sequence.
+    /// * `metric` - The metric comparing the points.
+    ///
+    /// # Complexity
+    ///
+    /// O(N log N) expected distance evaluations and O(N) additional space.
+    #[must_use]
+    pub fn new<I>(points: I, metric: M) -> Self
+    where
+        I: IntoIterator<Item = P>,
+    {
+        let points: Vec<P> = points.into_iter().collect();
+        let mut order: Vec<usize> = (0..points.len()).collect();
+        let mut radii = vec![0.0; points.len()];
+        let mut distances: Vec<(f64, usize)> = Vec::with_capacity(points.len());
+        let mut ranges = vec![(0, points.len())];
+        while let Some((lo, hi)) = ranges.pop() {
+            if hi - lo <= 1 {
+                continue;
+            }
+            let vantage = &points[order[lo]];
+            distances.clear();
+            distances.extend(
+                order[lo + 1..hi]
+                    .iter()
+                    .map(|&index| (metric.distance(vantage, &points[index]), index)),
+            );
+            let median = distances.len() / 2;
+            distances.select_nth_unstable_by(median, |left, right| {
+                left.0.total_cmp(&right.0).then(left.1.cmp(&right.1))
+            });
+            radii[lo] = distances[median].0;
+            for (position, &(_, index)) in order[lo + 1..hi].iter_mut().zip(&distances) {
+                *position = index;
+            }
+            let split = lo + 1 + median;
+            ranges.push((lo + 1, split));
+            ranges.push((split, hi));
+        }
+
+        Self { points, metric, order, radii }
+    }
+
+    /// Returns the number of points in the tree.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Returns whether the tree holds no point.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Returns the point at the provided position of the input.
+    #[inline]
+    #[must_use]
+    pub fn point(&self, index: usize) -> &P {
+        &self.points[index]
+    }
+
+    /// Returns the metric comparing the points.
+    #[inline]
+    #[must_use]
+    pub fn metric(&self) -> &M {
+        &self.metric
+    }
+
+    /// Returns the `k` points closest to `query`, sorted by increasing
+    /// distance and then by increasing position, or all the points if there
+    /// are fewer than `k`.
+    ///
+    /// # Complexity
+    ///
+    /// O(log N + k log k) expected distance evaluations on low-dimensional
+    /// data, degrading to O(N) as the intrinsic dimension grows.
+    #[must_use]
+    pub fn nearest_neighbors(&self, query: &P, k: usize) -> Vec<Neighbor> {
+        let mut nearest = NearestNeighbors::new(k);
+        if k > 0 {
+            self.nearest_in(0, self.len(), query, &mut nearest);
+        }
+        nearest.into_sorted_vec()
+    }
+
+    /// Returns the points within distance `radius` of `query`, boundary
+    /// included, sorted by increasing distance and then by increasing
+    /// position.
+    ///
+    /// # Complexity
+    ///
+    /// O(log N + M log M) expected distance evaluations on low-dimensional
+    /// data, where M is the number of returned points.
+    #[must_use]
+    pub fn within_radius(&self, query: &P, radius: f64) -> Vec<Neighbor> {
+        let mut neighbors = Vec::new();
+        if radius >= 0.0 {
+            self.within_radius_in(0, self.len(), query, radius, &mut neighbors);
+        }
+        neighbors.sort_unstable_by(Neighbor::query_order);
+        neighbors
+    }
+
+    /// Offers the points of the range `lo..hi` to the nearest neighbours
+    /// found so far.
+    fn nearest_in(&self, lo: usize, hi: usize, query: &P, nearest: &mut NearestNeighbors) {
+        if lo >= hi {
+            return;
+        }
+        let index = self.order[lo];
+        let distance = self.metric.distance(query, &self.points[index]);
+        nearest.offer(Neighbor::new(index, distance));
+
+        let radius = self.radii[lo];
+        let split = lo + 1 + (hi - lo - 1) / 2;
+        // The closer half lies within the radius of the vantage point and the
+        // farther half beyond it, so each half can only hold points closer
+        // than the bound if the query ball crosses the radius.
+        if distance <= radius {
+            self.nearest_in(lo + 1, split, query, nearest);
+            let bound = nearest.bound();
+            if at_most_up_to_rounding(radius, distance + bound, distance + radius + bound) {
+                self.nearest_in(split, hi, query, nearest);
+            }
+        } else {
+            self.nearest_in(split, hi, query, nearest);
+            let bound = nearest.bound();
+            if at_most_up_to_rounding(distance - bound, radius, distance + radius + bound) {
+                self.nearest_in(lo + 1, split, query, nearest);
+            }
+        }
+    }
+
+    /// Collects the points of the range `lo..hi` within the radius.
+    fn within_radius_in(
+        &self,
+        lo: usize,
+        hi: usize,
+        query: &P,
+        radius: f64,
+        neighbors: &mut Vec<Neighbor>,
+    ) {
+        if lo >= hi {
+            return;
+        }
+        let index = self.order[lo];
+        let distance = self.metric.distance(query, &self.points[index]);
+        if distance <= radius {
+            neighbors.push(Neighbor::new(index, distance));
+        }
+
+        let split = lo + 1 + (hi - lo - 1) / 2;
+        let scale = distance + radius + self.radii[lo];
+        if at_most_up_to_rounding(distance - radius, self.radii[lo], scale) {
+            self.within_radius_in(lo + 1, split, query, radius, neighbors);
+        }
+        if at_most_up_to_rounding(self.radii[lo], distance + radius, scale) {
+            self.within_radius_in(split, hi, query, radius, neighbors);
+        }
+    }
+}
+
+/// Returns whether `lower <= upper` may hold for the exact distances, given
+/// that the computed ones, of magnitude up to `scale`, carry rounding errors.
+///
+/// Pruning with the triangle inequality on rounded distances would otherwise
+/// miss points lying exactly at the query distance.
+fn at_most_up_to_rounding(lower: f64, upper: f64, scale: f64) -> bool {
+    lower <= upper + 8.0 * f64::EPSILON * scale
+}
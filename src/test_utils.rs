@@ -2114,25 +2114,7 @@ pub fn validate_lap_assignment(
 #[must_use]
 #[inline]
 pub fn lap_values_are_numerically_stable(csr: &ValuedCSR2D<u16, u8, u8, f64>) -> bool {
-    let mut minimum_value = f64::INFINITY;
-    let mut maximum_value = 0.0_f64;
-
-    for row in csr.row_indices() {
-        for value in csr.sparse_row_values(row) {
-            if value > 0.0 && value.is_finite() {
-                minimum_value = minimum_value.min(value);
-                maximum_value = maximum_value.max(value);
-            }
-        }
-    }
-
-    if minimum_value.is_infinite() {
-        return true;
-    }
-
-    minimum_value >= f64::MIN_POSITIVE
-        && maximum_value <= 1e150
-        && (maximum_value / minimum_value) <= 1e12
+    csr.preflight(f64::MAX).is_numerically_stable()
 }
 
 /// Compute the total cost of an assignment.
@@ -2392,6 +2374,61 @@ pub fn check_lap_square_invariants(csr: &ValuedCSR2D<u16, u8, u8, f64>) {
     );
 }
 
+/// Check that `sparse_hungarian` cross-validates the objectives of
+/// `sparse_lapjv` with the same padding, and of `lapmod` on square matrices.
+///
+/// # Panics
+///
+/// Panics if the solvers disagree on a numerically stable matrix.
+#[inline]
+pub fn check_sparse_hungarian_invariants(csr: &ValuedCSR2D<u16, u8, u8, f64>) {
+    let maximum_value = csr.max_sparse_value().unwrap_or(1000.0);
+    let padding_value = maximum_value * 4.2;
+    let maximal_cost = padding_value * 2.0;
+    if !padding_value.is_finite() || !maximal_cost.is_finite() || padding_value <= 0.0 {
+        return;
+    }
+
+    let Ok(hungarian_assignment) = csr.sparse_hungarian(padding_value, maximal_cost) else {
+        return;
+    };
+    validate_lap_assignment(csr, &hungarian_assignment, "SparseHungarian");
+    if !lap_values_are_numerically_stable(csr) {
+        return;
+    }
+    let hungarian_cost = lap_assignment_cost(csr, &hungarian_assignment);
+
+    let lapjv_assignment = csr.sparse_lapjv(padding_value, maximal_cost).unwrap_or_else(|error| {
+        panic!("SparseLAPJV failed with {error:?} where SparseHungarian succeeded: {csr:?}")
+    });
+    let lapjv_cost = lap_assignment_cost(csr, &lapjv_assignment);
+    let denom = hungarian_cost.abs().max(lapjv_cost.abs()).max(1e-30);
+    assert!(
+        (hungarian_cost - lapjv_cost).abs() / denom < 1e-9,
+        "SparseHungarian/SparseLAPJV objective mismatch ({hungarian_cost} vs \
+         {lapjv_cost}): {csr:?}"
+    );
+
+    if csr.number_of_rows() != csr.number_of_columns() {
+        return;
+    }
+    let Ok(lapmod_assignment) = csr.lapmod(maximum_value * 2.1) else {
+        return;
+    };
+    assert_eq!(
+        hungarian_assignment.len(),
+        lapmod_assignment.len(),
+        "SparseHungarian/LAPMOD cardinality mismatch: {csr:?}"
+    );
+    let lapmod_cost = lap_assignment_cost(csr, &lapmod_assignment);
+    let denom = hungarian_cost.abs().max(lapmod_cost.abs()).max(1e-30);
+    assert!(
+        (hungarian_cost - lapmod_cost).abs() / denom < 1e-9,
+        "SparseHungarian/LAPMOD objective mismatch ({hungarian_cost} vs \
+         {lapmod_cost}): {csr:?}"
+    );
+}
+
 // ============================================================================
 // Louvain/Leiden invariants (from fuzz/fuzz_targets/{louvain,leiden}.rs)
 // ============================================================================
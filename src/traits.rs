@@ -10,6 +10,10 @@ pub mod edge;
 pub mod edges;
 pub mod from_directed_monopartite_edges;
 pub mod graph;
+#[cfg(feature = "simd")]
+mod lanes;
+#[cfg(feature = "alloc")]
+pub mod memory_footprint;
 pub mod monopartite_graph;
 pub mod monoplex_bipartite_graph;
 pub mod monoplex_graph;
@@ -17,6 +21,7 @@ pub mod monoplex_monopartite_graph;
 pub mod node_typed_bipartite_graph;
 pub mod node_typed_monopartite_graph;
 pub mod numeric;
+pub mod pretty_print;
 pub mod similarity;
 pub mod total_ord;
 pub mod transposed_directed_graph;
@@ -26,6 +31,7 @@ pub mod transposed_monoplex_monopartite_graph;
 pub mod transposed_weighted_graph;
 pub mod undirected_bipartite_graph;
 pub mod undirected_monopartite_graph;
+pub mod validate;
 pub mod vocabulary;
 pub mod weighted_monoplex_graph;
 
@@ -39,6 +45,8 @@ pub use edge::*;
 pub use edges::*;
 pub use from_directed_monopartite_edges::*;
 pub use graph::*;
+#[cfg(feature = "alloc")]
+pub use memory_footprint::*;
 pub use monopartite_graph::*;
 pub use monoplex_bipartite_graph::*;
 pub use monoplex_graph::*;
@@ -46,6 +54,7 @@ pub use monoplex_monopartite_graph::*;
 pub use node_typed_bipartite_graph::*;
 pub use node_typed_monopartite_graph::*;
 pub use numeric::*;
+pub use pretty_print::*;
 pub use similarity::*;
 pub use total_ord::*;
 pub use transposed_directed_graph::*;
@@ -55,6 +64,7 @@ pub use transposed_monoplex_monopartite_graph::*;
 pub use transposed_weighted_graph::*;
 pub use undirected_bipartite_graph::*;
 pub use undirected_monopartite_graph::*;
+pub use validate::*;
 pub use vocabulary::*;
 pub use weighted_monoplex_graph::*;
 pub mod transmute_from;
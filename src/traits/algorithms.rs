@@ -51,7 +51,10 @@ pub use tree_detection::TreeDetection;
 #[cfg(feature = "alloc")]
 pub mod planarity_detection;
 #[cfg(feature = "alloc")]
-pub use planarity_detection::{PlanarityDetection, PlanarityError};
+pub use planarity_detection::{
+    KuratowskiKind, KuratowskiSubgraph, PlanarEmbedding, PlanarityCertificate, PlanarityDetection,
+    PlanarityError,
+};
 #[cfg(feature = "alloc")]
 pub mod outerplanarity_detection;
 #[cfg(feature = "alloc")]
@@ -112,18 +115,42 @@ pub mod wu_palmer;
 pub use wu_palmer::{WuPalmer, WuPalmerResult};
 pub mod randomized_graphs;
 #[cfg(all(feature = "alloc", any(feature = "std", feature = "hashbrown")))]
-pub use randomized_graphs::RandomizedDAG;
+pub use randomized_graphs::{DirectedRewire, RandomizedDAG, Rewire, RewireError};
 mod assignment;
 pub use assignment::*;
 #[cfg(feature = "alloc")]
+mod metrics;
+#[cfg(feature = "alloc")]
 mod weighted_assignment;
 #[cfg(feature = "alloc")]
 pub use weighted_assignment::*;
 #[cfg(feature = "alloc")]
+mod link_prediction;
+#[cfg(feature = "alloc")]
+pub use link_prediction::{LinkPrediction, LinkPredictionScore};
+#[cfg(feature = "alloc")]
+mod neighborhood_similarity;
+#[cfg(feature = "alloc")]
+pub use neighborhood_similarity::{
+    CosineSimilarity, DiceSimilarity, JaccardSimilarity, MinHashSignatures, OverlapSimilarity,
+};
+#[cfg(feature = "alloc")]
+mod personalized_pagerank;
+#[cfg(feature = "alloc")]
+pub use personalized_pagerank::{PersonalizedPageRank, PersonalizedPageRankSimilarity};
+#[cfg(feature = "alloc")]
+mod random_spanning_tree;
+#[cfg(feature = "alloc")]
+pub use random_spanning_tree::{RandomSpanningTree, RandomSpanningTreeError};
+#[cfg(feature = "alloc")]
 mod kahn;
 #[cfg(feature = "alloc")]
 pub use kahn::*;
 #[cfg(feature = "alloc")]
+mod minimum_path_cover;
+#[cfg(feature = "alloc")]
+pub use minimum_path_cover::*;
+#[cfg(feature = "alloc")]
 mod johnson;
 #[cfg(feature = "alloc")]
 pub use johnson::*;
@@ -136,9 +163,51 @@ mod pairwise_bfs;
 #[cfg(feature = "alloc")]
 pub use pairwise_bfs::*;
 #[cfg(feature = "alloc")]
+mod ego_graph;
+#[cfg(feature = "alloc")]
+pub use ego_graph::{EgoGraph, EgoGraphResult};
+#[cfg(feature = "alloc")]
+mod a_star;
+#[cfg(feature = "alloc")]
+pub use a_star::{AStar, AStarError};
+#[cfg(feature = "alloc")]
+mod all_pairs_shortest_paths;
+#[cfg(feature = "alloc")]
+pub use all_pairs_shortest_paths::{AllPairsShortestPaths, AllPairsShortestPathsError};
+#[cfg(feature = "alloc")]
+mod dijkstra;
+#[cfg(feature = "alloc")]
+pub use dijkstra::{Dijkstra, DijkstraError, ShortestPaths};
+#[cfg(feature = "alloc")]
 mod pairwise_dijkstra;
 #[cfg(feature = "alloc")]
 pub use pairwise_dijkstra::*;
+#[cfg(feature = "heapless")]
+mod heapless_search;
+#[cfg(feature = "heapless")]
+pub use heapless_search::*;
+#[cfg(feature = "alloc")]
+mod independent_set;
+#[cfg(feature = "alloc")]
+pub use independent_set::{IndependentSet, IndependentSetResult};
+#[cfg(feature = "alloc")]
+mod node_cover;
+#[cfg(feature = "alloc")]
+pub use node_cover::{DominatingSet, NodeCover, NodeCoverError, VertexCover};
+#[cfg(feature = "alloc")]
+mod prize_collecting_steiner_tree;
+#[cfg(feature = "alloc")]
+pub use prize_collecting_steiner_tree::{
+    PrizeCollectingSteinerTree, PrizeCollectingSteinerTreeError, PrizeCollectingSubgraph,
+};
+#[cfg(feature = "alloc")]
+mod steiner_tree;
+#[cfg(feature = "alloc")]
+pub use steiner_tree::{SteinerSubgraph, SteinerTree, SteinerTreeError};
+#[cfg(feature = "alloc")]
+mod traveling_salesman;
+#[cfg(feature = "alloc")]
+pub use traveling_salesman::{Tour, TravelingSalesman, TravelingSalesmanError};
 #[cfg(feature = "alloc")]
 mod tarjan;
 #[cfg(feature = "alloc")]
@@ -156,6 +225,22 @@ mod leiden;
 #[cfg(feature = "alloc")]
 pub use leiden::*;
 #[cfg(feature = "alloc")]
+mod quotient;
+#[cfg(feature = "alloc")]
+pub use quotient::{Quotient, QuotientGraph};
+#[cfg(feature = "alloc")]
+mod fit_sbm;
+#[cfg(feature = "alloc")]
+pub use fit_sbm::{FitSbm, SbmConfig, SbmResult};
+#[cfg(feature = "alloc")]
+mod hierarchical_clustering;
+#[cfg(feature = "alloc")]
+pub use hierarchical_clustering::*;
+#[cfg(feature = "alloc")]
+mod dbscan;
+#[cfg(feature = "alloc")]
+pub use dbscan::*;
+#[cfg(feature = "alloc")]
 mod jacobi;
 #[cfg(feature = "alloc")]
 pub use jacobi::*;
@@ -207,7 +292,8 @@ pub use modular_product::{
 pub mod maximum_clique;
 #[cfg(feature = "alloc")]
 pub use maximum_clique::{
-    MaximumClique, OwnedPartitionLabels, PartitionInfo, PartitionedMaximumClique,
+    GraphMaximumClique, MaximumClique, OwnedPartitionLabels, PartitionInfo,
+    PartitionedMaximumClique,
 };
 #[cfg(feature = "alloc")]
 pub mod delta_y_exchange;
@@ -235,6 +321,62 @@ pub use vf2::{
     PreparedVf2Builder, PreparedVf2Graph, Vf2, Vf2Builder, Vf2Match, Vf2Mode,
 };
 #[cfg(feature = "alloc")]
+mod graph_isomorphism;
+#[cfg(feature = "alloc")]
+pub use graph_isomorphism::GraphIsomorphism;
+#[cfg(feature = "alloc")]
+mod graph_edit_distance;
+#[cfg(feature = "alloc")]
+pub use graph_edit_distance::{ApproximateGraphEditDistance, GraphEditCosts, GraphEditMapping};
+#[cfg(feature = "alloc")]
+mod laplacian;
+#[cfg(feature = "alloc")]
+pub use laplacian::{Laplacian, LaplacianError};
+#[cfg(feature = "alloc")]
+mod diffusion;
+#[cfg(feature = "alloc")]
+pub use diffusion::{Diffusion, DiffusionConfig, DiffusionError, DiffusionLaplacian};
+#[cfg(feature = "alloc")]
+mod effective_resistance;
+#[cfg(feature = "alloc")]
+pub use effective_resistance::{
+    EffectiveResistance, EffectiveResistanceConfig, EffectiveResistanceError, EffectiveResistances,
+};
+#[cfg(feature = "alloc")]
+mod spectral_sparsifier;
+#[cfg(feature = "alloc")]
+pub use spectral_sparsifier::{SpectralSparsifier, SpectralSparsifierError};
+#[cfg(feature = "alloc")]
+mod centrality;
+#[cfg(feature = "alloc")]
+pub use centrality::Centrality;
+#[cfg(feature = "alloc")]
+mod edge_betweenness;
+#[cfg(feature = "alloc")]
+pub use edge_betweenness::EdgeBetweenness;
+#[cfg(feature = "alloc")]
+mod assortativity;
+#[cfg(feature = "alloc")]
+pub use assortativity::{Assortativity, AssortativityError};
+#[cfg(feature = "alloc")]
+mod rich_club;
+#[cfg(feature = "alloc")]
+pub use rich_club::{RichClub, RichClubConfig, RichClubError};
+#[cfg(feature = "alloc")]
+mod motif_census;
+#[cfg(feature = "alloc")]
+pub use motif_census::{
+    MotifCensus, MotifCensusError, MotifCounts, Triad, TriadCensus, UndirectedMotif,
+};
+#[cfg(feature = "alloc")]
+mod robustness;
+#[cfg(feature = "alloc")]
+pub use robustness::{RemovalOrder, Robustness, RobustnessError};
+#[cfg(feature = "alloc")]
+mod layout;
+#[cfg(feature = "alloc")]
+pub use layout::{ForceModel, Layout, LayoutConfig, LayoutError};
+#[cfg(feature = "alloc")]
 pub mod clique_ranking;
 #[cfg(feature = "alloc")]
 pub mod mces;
@@ -242,7 +384,7 @@ pub mod mces;
 pub mod node_ordering;
 #[cfg(feature = "alloc")]
 pub use canon::{
-    BacktrackableOrderedPartition, CanonSplittingHeuristic, CanonicalLabeling,
+    BacktrackableOrderedPartition, CanonSplittingHeuristic, CanonicalForm, CanonicalLabeling,
     CanonicalLabelingOptions, CanonicalLabelingResult, CanonicalSearchStats,
     LabeledSimpleGraphCertificate, OrderedPartitionCells, PartitionBacktrackPoint, PartitionCellId,
     PartitionCellView, refine_partition_to_labeled_equitable,
@@ -270,4 +412,4 @@ pub use node_ordering::{
     TraversalSeedStrategy, TriangleCountScorer, apply_node_order_to_graph,
 };
 #[cfg(feature = "alloc")]
-pub use weisfeiler_lehman::WeisfeilerLehmanColoring;
+pub use weisfeiler_lehman::{WeisfeilerLehmanColoring, WlHash};
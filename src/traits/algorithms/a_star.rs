@@ -0,0 +1,265 @@
+//! Submodule providing the `AStar` trait, computing a shortest path between
+//! two nodes of a sparse valued matrix with non-negative weights, guided by a
+//! user-supplied estimate of the remaining distance.
+//!
+//! The search pops nodes by increasing `g + h`, where `g` is the length of the
+//! best known path from the source and `h` the heuristic estimate to the
+//! destination, and stops as soon as the destination is popped. A node whose
+//! path is shortened after it was popped is pushed again, so that the path is
+//! optimal for every admissible heuristic, one never overestimating the
+//! remaining distance, and not only for consistent ones.
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+
+use num_traits::AsPrimitive;
+
+use super::dijkstra::{DijkstraError, DijkstraVisitor, dijkstra_search};
+use crate::traits::{Finite, Number, PositiveInteger, SparseValuedMatrix2D, TotalOrd};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+/// Errors that can occur while executing the A* search.
+pub enum AStarError {
+    /// The input matrix is not square.
+    #[error("The matrix must be square, but has {rows} rows and {columns} columns.")]
+    NonSquareMatrix {
+        /// Number of rows.
+        rows: usize,
+        /// Number of columns.
+        columns: usize,
+    },
+    /// An endpoint of the path is not a node of the graph.
+    #[error("The node {node_id} is not a node of the graph, which has {number_of_nodes} nodes.")]
+    UnknownNode {
+        /// Node identifier.
+        node_id: usize,
+        /// Number of nodes of the graph.
+        number_of_nodes: usize,
+    },
+    /// An edge weight reached by the search is not finite.
+    #[error("Found a non-finite weight on ({source_id}, {destination_id}).")]
+    NonFiniteWeight {
+        /// Source node identifier.
+        source_id: usize,
+        /// Destination node identifier.
+        destination_id: usize,
+    },
+    /// An edge weight reached by the search is negative.
+    #[error("Found a negative weight on ({source_id}, {destination_id}).")]
+    NegativeWeight {
+        /// Source node identifier.
+        source_id: usize,
+        /// Destination node identifier.
+        destination_id: usize,
+    },
+    /// The heuristic estimate of a node is not finite or is negative.
+    #[error("The heuristic returned an invalid estimate for node {node_id}.")]
+    InvalidHeuristic {
+        /// Node identifier.
+        node_id: usize,
+    },
+    /// A tentative path distance overflowed or otherwise became non-finite.
+    #[error("Found a non-finite tentative distance to {destination_id} via {via_id}.")]
+    NonFiniteDistance {
+        /// Destination node identifier.
+        destination_id: usize,
+        /// Intermediate node identifier whose outgoing edge caused the issue.
+        via_id: usize,
+    },
+}
+
+impl From<DijkstraError> for AStarError {
+    #[inline]
+    fn from(error: DijkstraError) -> Self {
+        match error {
+            DijkstraError::NonSquareMatrix { rows, columns } => {
+                Self::NonSquareMatrix { rows, columns }
+            }
+            DijkstraError::UnknownSource { source_id, number_of_nodes } => {
+                Self::UnknownNode { node_id: source_id, number_of_nodes }
+            }
+            DijkstraError::NonFiniteWeight { source_id, destination_id } => {
+                Self::NonFiniteWeight { source_id, destination_id }
+            }
+            DijkstraError::NegativeWeight { source_id, destination_id } => {
+                Self::NegativeWeight { source_id, destination_id }
+            }
+            DijkstraError::NonFiniteDistance { destination_id, via_id } => {
+                Self::NonFiniteDistance { destination_id, via_id }
+            }
+            DijkstraError::ZeroWeight { .. } => {
+                unreachable!("The search accepts zero weights.")
+            }
+        }
+    }
+}
+
+/// Trait providing the A* search for a shortest path between two nodes of a
+/// non-negative weighted graph.
+///
+/// Missing entries in the sparse matrix are interpreted as absent edges. Only
+/// the weights of the edges leaving popped nodes are validated, and the
+/// heuristic is only evaluated on reached nodes.
+///
+/// # Reference
+///
+/// Hart, P. E., Nilsson, N. J., & Raphael, B. (1968). [A formal basis for the heuristic determination of minimum cost paths](https://doi.org/10.1109/TSSC.1968.300136).
+/// *IEEE Transactions on Systems Science and Cybernetics*, 4(2), 100-107.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{impls::ValuedCSR2D, prelude::*};
+///
+/// // Nodes on a line, whose distance to node 3 is a perfect heuristic.
+/// let csr: ValuedCSR2D<usize, usize, usize, f64> =
+///     GenericEdgesBuilder::<_, ValuedCSR2D<usize, usize, usize, f64>>::default()
+///         .expected_number_of_edges(4)
+///         .expected_shape((4, 4))
+///         .edges(vec![(0, 1, 1.0), (0, 3, 5.0), (1, 2, 1.0), (2, 3, 1.0)].into_iter())
+///         .build()
+///         .unwrap();
+///
+/// let (distance, path) = csr.a_star(0, 3, |node| (3 - node) as f64).unwrap().unwrap();
+/// assert_eq!(distance, 3.0);
+/// assert_eq!(path, vec![0, 1, 2, 3]);
+/// assert_eq!(csr.a_star(3, 0, |_| 0.0).unwrap(), None);
+/// ```
+pub trait AStar: SparseValuedMatrix2D + Sized
+where
+    Self::Value: Number + Finite + TotalOrd,
+    Self::RowIndex: PositiveInteger,
+    Self::ColumnIndex: AsPrimitive<usize> + Into<Self::RowIndex>,
+{
+    /// Returns the length and the nodes, both endpoints included, of a
+    /// shortest path from the source to the destination, or `None` if the
+    /// destination is not reachable.
+    ///
+    /// The path is only guaranteed to be shortest when the heuristic is
+    /// admissible. The null heuristic reduces the search to Dijkstra's
+    /// algorithm stopped at the destination.
+    ///
+    /// # Arguments
+    ///
+    /// * `source_id` - The node the path starts from.
+    /// * `destination_id` - The node the path ends at.
+    /// * `heuristic` - The estimate of the distance from a node to the
+    ///   destination, which must be finite and non-negative.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the matrix is not square, if an endpoint is not one
+    /// of its nodes, if a weight reached by the search is not finite or is
+    /// negative, if the heuristic returns a non-finite or negative estimate,
+    /// or if a tentative path distance becomes non-finite.
+    ///
+    /// # Complexity
+    ///
+    /// O((V + E) log V) time and O(V) space for consistent heuristics, whose
+    /// estimate never drops along an edge by more than its weight.
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    fn a_star<H>(
+        &self,
+        source_id: Self::RowIndex,
+        destination_id: Self::RowIndex,
+        heuristic: H,
+    ) -> Result<Option<(Self::Value, Vec<Self::RowIndex>)>, AStarError>
+    where
+        H: Fn(Self::RowIndex) -> Self::Value,
+    {
+        let rows = self.number_of_rows().as_();
+        let columns = self.number_of_columns().as_();
+        if rows != columns {
+            return Err(AStarError::NonSquareMatrix { rows, columns });
+        }
+        for node_id in [source_id, destination_id] {
+            if node_id.as_() >= rows {
+                return Err(AStarError::UnknownNode {
+                    node_id: node_id.as_(),
+                    number_of_nodes: rows,
+                });
+            }
+        }
+
+        let mut visitor = AStarVisitor {
+            heuristic,
+            destination_id,
+            estimates: vec![None; rows],
+            predecessors: vec![None; rows],
+            distance: None,
+        };
+        let mut distances = vec![None; rows];
+        dijkstra_search(self, source_id, &mut distances, &mut BinaryHeap::new(), &mut visitor)?;
+        let Some(distance) = visitor.distance else {
+            return Ok(None);
+        };
+
+        let mut path = vec![destination_id];
+        let mut current = destination_id.as_();
+        while let Some(predecessor) = visitor.predecessors[current] {
+            path.push(predecessor);
+            current = predecessor.as_();
+        }
+        path.reverse();
+        Ok(Some((distance, path)))
+    }
+}
+
+impl<M> AStar for M
+where
+    M: SparseValuedMatrix2D + Sized,
+    M::Value: Number + Finite + TotalOrd,
+    M::RowIndex: PositiveInteger,
+    M::ColumnIndex: AsPrimitive<usize> + Into<M::RowIndex>,
+{
+}
+
+/// Visitor guiding the search by the heuristic and stopping it at the
+/// destination.
+struct AStarVisitor<H, V, I> {
+    /// The estimate of the distance from a node to the destination.
+    heuristic: H,
+    /// The node the path ends at.
+    destination_id: I,
+    /// The validated estimate of every node the heuristic was evaluated on.
+    estimates: Vec<Option<V>>,
+    /// The predecessor of every reached node other than the source.
+    predecessors: Vec<Option<I>>,
+    /// The distance of the destination, once popped.
+    distance: Option<V>,
+}
+
+impl<H, V, I> DijkstraVisitor<V, I> for AStarVisitor<H, V, I>
+where
+    H: Fn(I) -> V,
+    V: Number + Finite,
+    I: PositiveInteger,
+{
+    type Error = AStarError;
+
+    #[inline]
+    fn estimate(&mut self, node_id: I) -> Result<V, AStarError> {
+        let node = node_id.as_();
+        if let Some(estimate) = self.estimates[node] {
+            return Ok(estimate);
+        }
+        let estimate = (self.heuristic)(node_id);
+        if !estimate.is_finite() || estimate < V::zero() {
+            return Err(AStarError::InvalidHeuristic { node_id: node });
+        }
+        self.estimates[node] = Some(estimate);
+        Ok(estimate)
+    }
+
+    #[inline]
+    fn settle(&mut self, node_id: I, distance: V) -> bool {
+        if node_id == self.destination_id {
+            self.distance = Some(distance);
+        }
+        self.distance.is_some()
+    }
+
+    #[inline]
+    fn improve(&mut self, destination_id: I, node_id: I) {
+        self.predecessors[destination_id.as_()] = Some(node_id);
+    }
+}
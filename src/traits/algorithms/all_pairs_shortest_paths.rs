@@ -0,0 +1,335 @@
+//! Submodule providing the `AllPairsShortestPaths` trait, computing the
+//! all-pairs shortest-path distances of sparse valued matrices whose weights
+//! may be negative.
+//!
+//! Small or dense matrices are handled by [`FloydWarshall`], in O(V³) time.
+//! Larger sparse matrices are handled by Johnson's reweighting: a
+//! Bellman-Ford search from a virtual source linked to every node by a null
+//! edge computes potentials `h` such that every reweighted edge
+//! `w(u, v) + h(u) - h(v)` is non-negative, or detects a negative cycle, and
+//! [`PairwiseDijkstra`] over the reweighted matrix then yields the distances,
+//! shifted back by `h(v) - h(u)`, in O(V · E log V) time.
+use alloc::{vec, vec::Vec};
+
+use num_traits::{AsPrimitive, Zero};
+
+use super::{
+    floyd_warshall::{FloydWarshall, FloydWarshallError},
+    pairwise_dijkstra::{PairwiseDijkstra, PairwiseDijkstraError},
+};
+use crate::{
+    impls::{ValuedCSR2D, VecMatrix2D},
+    traits::{
+        DenseValuedMatrix, Finite, MatrixMut, Number, PositiveInteger, SparseMatrixMut,
+        SparseValuedMatrix2D, TotalOrd,
+    },
+};
+
+/// Number of nodes up to which Floyd-Warshall is always preferred.
+const SMALL_ORDER: usize = 32;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+/// Errors that can occur while computing all-pairs shortest paths.
+pub enum AllPairsShortestPathsError {
+    /// The input matrix is not square.
+    #[error("The matrix must be square, but has {rows} rows and {columns} columns.")]
+    NonSquareMatrix {
+        /// Number of rows.
+        rows: usize,
+        /// Number of columns.
+        columns: usize,
+    },
+    /// An input edge weight is not finite.
+    #[error("Found a non-finite weight on ({source_id}, {destination_id}).")]
+    NonFiniteWeight {
+        /// Source node identifier.
+        source_id: usize,
+        /// Destination node identifier.
+        destination_id: usize,
+    },
+    /// A tentative path distance overflowed or otherwise became non-finite.
+    #[error("Found a non-finite tentative distance for ({source_id}, {destination_id}).")]
+    NonFiniteDistance {
+        /// Source node identifier.
+        source_id: usize,
+        /// Destination node identifier.
+        destination_id: usize,
+    },
+    /// The graph contains a negative cycle.
+    #[error("Found a negative cycle reaching node {node_id}.")]
+    NegativeCycle {
+        /// Node identifier whose distance kept decreasing along the cycle.
+        node_id: usize,
+    },
+}
+
+impl From<FloydWarshallError> for AllPairsShortestPathsError {
+    #[inline]
+    fn from(error: FloydWarshallError) -> Self {
+        match error {
+            FloydWarshallError::NonSquareMatrix { rows, columns } => {
+                Self::NonSquareMatrix { rows, columns }
+            }
+            FloydWarshallError::NonFiniteWeight { source_id, destination_id } => {
+                Self::NonFiniteWeight { source_id, destination_id }
+            }
+            FloydWarshallError::NonFiniteDistance { source_id, destination_id, .. } => {
+                Self::NonFiniteDistance { source_id, destination_id }
+            }
+            FloydWarshallError::NegativeCycle { node_id } => Self::NegativeCycle { node_id },
+        }
+    }
+}
+
+/// Trait providing all-pairs shortest-path distances for weighted graphs
+/// whose weights may be negative, choosing between Floyd-Warshall and
+/// Johnson's reweighting by the density of the graph.
+///
+/// Missing entries in the sparse matrix are interpreted as absent edges. The
+/// result is returned as a dense matrix whose entries are `Option<Value>`:
+/// - `Some(distance)` when a path exists;
+/// - `None` when the destination is unreachable.
+///
+/// # Reference
+///
+/// Johnson, D. B. (1977). [Efficient algorithms for shortest paths in sparse networks](https://doi.org/10.1145/321992.321993).
+/// *Journal of the ACM*, 24(1), 1-13.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{impls::ValuedCSR2D, prelude::*};
+///
+/// let csr: ValuedCSR2D<usize, usize, usize, f64> =
+///     GenericEdgesBuilder::<_, ValuedCSR2D<usize, usize, usize, f64>>::default()
+///         .expected_number_of_edges(4)
+///         .expected_shape((4, 4))
+///         .edges(vec![(0, 1, 4.0), (0, 2, 1.0), (1, 3, 1.0), (2, 1, -2.0)].into_iter())
+///         .build()
+///         .unwrap();
+///
+/// let distances = csr.johnson_all_pairs_shortest_paths().unwrap();
+/// assert_eq!(distances.value((0, 3)), Some(0.0));
+/// assert_eq!(distances.value((3, 0)), None);
+/// assert_eq!(distances, csr.all_pairs_shortest_paths().unwrap());
+/// ```
+pub trait AllPairsShortestPaths: SparseValuedMatrix2D + Sized
+where
+    Self::Value: Number + Finite + TotalOrd,
+    Self::RowIndex: PositiveInteger,
+    Self::ColumnIndex: AsPrimitive<usize> + Into<Self::RowIndex>,
+{
+    /// Computes all-pairs shortest-path distances, with Floyd-Warshall for
+    /// matrices of at most 32 nodes or whose `E log2 V` reaches `V²`, and
+    /// with Johnson's reweighting otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the matrix is not square, if an input weight is not
+    /// finite, if a tentative path distance becomes non-finite, or if a
+    /// negative cycle is detected.
+    ///
+    /// # Complexity
+    ///
+    /// O(min(V³, V · E log V)) time and O(V²) space.
+    #[inline]
+    fn all_pairs_shortest_paths(
+        &self,
+    ) -> Result<VecMatrix2D<Option<Self::Value>>, AllPairsShortestPathsError> {
+        let order = self.number_of_rows().as_();
+        let edges: usize = self.row_indices().map(|row| self.sparse_row(row).count()).sum();
+        let log_order = usize::try_from(order.max(1).ilog2())
+            .unwrap_or_else(|_| unreachable!("Logarithms of counts fit in a usize."));
+        if order <= SMALL_ORDER || edges.saturating_mul(log_order.max(1)) >= order * order {
+            Ok(self.floyd_warshall()?)
+        } else {
+            self.johnson_all_pairs_shortest_paths()
+        }
+    }
+
+    /// Computes all-pairs shortest-path distances with Johnson's reweighting.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as
+    /// [`AllPairsShortestPaths::all_pairs_shortest_paths`].
+    ///
+    /// # Complexity
+    ///
+    /// O(V · E log V) time and O(V² + E) space.
+    #[inline]
+    fn johnson_all_pairs_shortest_paths(
+        &self,
+    ) -> Result<VecMatrix2D<Option<Self::Value>>, AllPairsShortestPathsError> {
+        johnson_with(self, PairwiseDijkstra::pairwise_dijkstra)
+    }
+
+    #[cfg(feature = "parallel")]
+    /// Computes all-pairs shortest-path distances with Johnson's reweighting,
+    /// running the searches from the different sources on the `rayon` thread
+    /// pool.
+    ///
+    /// The distances, and the error reported if any, are identical to the
+    /// ones of [`AllPairsShortestPaths::johnson_all_pairs_shortest_paths`]
+    /// whatever the number of threads.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as
+    /// [`AllPairsShortestPaths::all_pairs_shortest_paths`].
+    #[inline]
+    fn par_johnson_all_pairs_shortest_paths(
+        &self,
+    ) -> Result<VecMatrix2D<Option<Self::Value>>, AllPairsShortestPathsError>
+    where
+        Self::Value: Send + Sync,
+    {
+        johnson_with(self, PairwiseDijkstra::par_pairwise_dijkstra)
+    }
+}
+
+impl<M> AllPairsShortestPaths for M
+where
+    M: SparseValuedMatrix2D + Sized,
+    M::Value: Number + Finite + TotalOrd,
+    M::RowIndex: PositiveInteger,
+    M::ColumnIndex: AsPrimitive<usize> + Into<M::RowIndex>,
+{
+}
+
+/// Type of the non-negative reweighted matrix searched by Johnson's
+/// algorithm.
+type Reweighted<V> = ValuedCSR2D<usize, usize, usize, V>;
+
+/// Computes all-pairs shortest-path distances with Johnson's reweighting,
+/// searching the reweighted matrix with the provided pairwise Dijkstra.
+fn johnson_with<M>(
+    matrix: &M,
+    pairwise_dijkstra: impl FnOnce(
+        &Reweighted<M::Value>,
+    ) -> Result<VecMatrix2D<Option<M::Value>>, PairwiseDijkstraError>,
+) -> Result<VecMatrix2D<Option<M::Value>>, AllPairsShortestPathsError>
+where
+    M: SparseValuedMatrix2D,
+    M::Value: Number + Finite + TotalOrd,
+    M::RowIndex: PositiveInteger,
+    M::ColumnIndex: AsPrimitive<usize> + Into<M::RowIndex>,
+{
+    let rows = matrix.number_of_rows().as_();
+    let columns = matrix.number_of_columns().as_();
+    if rows != columns {
+        return Err(AllPairsShortestPathsError::NonSquareMatrix { rows, columns });
+    }
+
+    let order = rows;
+    let potentials = bellman_ford_potentials(matrix)?;
+    let zero = M::Value::zero();
+    let mut reweighted: Reweighted<M::Value> = SparseMatrixMut::with_sparse_shape((order, order));
+    for (row_id, entries) in matrix.rows_with_values() {
+        let source = row_id.as_();
+        for (column_id, weight) in entries {
+            let destination = column_id.as_();
+            let mut weight = weight + potentials[source] - potentials[destination];
+            if !weight.is_finite() {
+                return Err(AllPairsShortestPathsError::NonFiniteDistance {
+                    source_id: source,
+                    destination_id: destination,
+                });
+            }
+            // Rounding may leave reweighted edges slightly negative.
+            if weight < zero {
+                weight = zero;
+            }
+            MatrixMut::add(&mut reweighted, (source, destination, weight)).unwrap_or_else(|_| {
+                unreachable!("The entries follow the sorted rows of the matrix.")
+            });
+        }
+    }
+
+    let reweighted_distances = pairwise_dijkstra(&reweighted).map_err(|error| {
+        match error {
+            PairwiseDijkstraError::NonFiniteDistance { source_id, destination_id, .. } => {
+                AllPairsShortestPathsError::NonFiniteDistance { source_id, destination_id }
+            }
+            PairwiseDijkstraError::NonSquareMatrix { .. }
+            | PairwiseDijkstraError::NonFiniteWeight { .. }
+            | PairwiseDijkstraError::NegativeWeight { .. } => {
+                unreachable!("The reweighted matrix is square, with finite non-negative weights.")
+            }
+        }
+    })?;
+
+    let mut all_distances = Vec::with_capacity(order * order);
+    for source in 0..order {
+        for destination in 0..order {
+            let distance = reweighted_distances
+                .value((source, destination))
+                .map(|reweighted| reweighted - potentials[source] + potentials[destination]);
+            if distance.is_some_and(|distance| !distance.is_finite()) {
+                return Err(AllPairsShortestPathsError::NonFiniteDistance {
+                    source_id: source,
+                    destination_id: destination,
+                });
+            }
+            all_distances.push(distance);
+        }
+    }
+
+    Ok(VecMatrix2D::new(order, order, all_distances))
+}
+
+/// Returns the Bellman-Ford distances from a virtual source linked to every
+/// node by a null edge, which make every reweighted edge non-negative.
+fn bellman_ford_potentials<M>(matrix: &M) -> Result<Vec<M::Value>, AllPairsShortestPathsError>
+where
+    M: SparseValuedMatrix2D,
+    M::Value: Number + Finite,
+    M::RowIndex: AsPrimitive<usize>,
+    M::ColumnIndex: AsPrimitive<usize>,
+{
+    let order = matrix.number_of_rows().as_();
+    let mut potentials = vec![M::Value::zero(); order];
+    for (row_id, entries) in matrix.rows_with_values() {
+        for (column_id, weight) in entries {
+            if !weight.is_finite() {
+                return Err(AllPairsShortestPathsError::NonFiniteWeight {
+                    source_id: row_id.as_(),
+                    destination_id: column_id.as_(),
+                });
+            }
+        }
+    }
+
+    // Shortest paths from the virtual source take its edge and at most
+    // V - 1 edges of the graph, so that the potentials settle within V - 1
+    // passes, and still decrease during the V-th one only along a negative
+    // cycle.
+    let mut last_updated = None;
+    for _ in 0..order {
+        last_updated = None;
+        for (row_id, entries) in matrix.rows_with_values() {
+            let source = row_id.as_();
+            for (column_id, weight) in entries {
+                let destination = column_id.as_();
+                let candidate = potentials[source] + weight;
+                if !candidate.is_finite() {
+                    return Err(AllPairsShortestPathsError::NonFiniteDistance {
+                        source_id: source,
+                        destination_id: destination,
+                    });
+                }
+                if candidate < potentials[destination] {
+                    potentials[destination] = candidate;
+                    last_updated = Some(destination);
+                }
+            }
+        }
+        if last_updated.is_none() {
+            break;
+        }
+    }
+    match last_updated {
+        Some(node_id) => Err(AllPairsShortestPathsError::NegativeCycle { node_id }),
+        None => Ok(potentials),
+    }
+}
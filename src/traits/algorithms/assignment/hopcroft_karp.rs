@@ -1,8 +1,9 @@
 //! Submodule providing the Hopcroft-Karp algorithm for the assignment problem.
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 
 mod partial_assignment;
 
+use num_traits::AsPrimitive;
 use partial_assignment::PartialAssignment;
 
 use crate::traits::SparseMatrix2D;
@@ -17,6 +18,17 @@ pub enum HopcroftKarpError {
     InsufficientDistanceType,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A set of rows violating Hall's condition: the rows are adjacent to fewer
+/// columns than there are rows, so no matching can assign all of them.
+pub struct HallViolator<RowIndex, ColumnIndex> {
+    /// The rows of the violating set, sorted by increasing index.
+    pub rows: Vec<RowIndex>,
+    /// The columns adjacent to at least one of the rows, sorted by
+    /// increasing index.
+    pub neighborhood: Vec<ColumnIndex>,
+}
+
 /// Trait providing the Hopcroft-Karp algorithm for the assignment problem.
 pub trait HopcroftKarp: SparseMatrix2D {
     #[allow(clippy::type_complexity)]
@@ -69,6 +81,86 @@ pub trait HopcroftKarp: SparseMatrix2D {
 
         Ok(partial_assignment.into())
     }
+
+    #[allow(clippy::type_complexity)]
+    /// Returns a set of rows whose neighborhood is smaller than the set, if
+    /// the rows cannot all be assigned.
+    ///
+    /// By Hall's theorem, such a set exists exactly when a maximum matching
+    /// leaves a row unassigned, which is why the LAP solvers fail with
+    /// [`LAPError::InfeasibleAssignment`](crate::traits::LAPError::InfeasibleAssignment).
+    /// The set is grown from the first unassigned row of the Hopcroft-Karp
+    /// matching along the alternating paths of its residual graph: every
+    /// reached column is assigned to a reached row, so the neighborhood has
+    /// exactly one column less than the set.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`HopcroftKarp::hopcroft_karp`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{
+    ///     impls::ValuedCSR2D,
+    ///     prelude::*,
+    ///     traits::{HallViolator, LAPError},
+    /// };
+    ///
+    /// // Rows 0 and 2 can only be assigned to column 1.
+    /// let mut csr: ValuedCSR2D<usize, usize, usize, f64> = SparseMatrixMut::with_sparse_shape((3, 3));
+    /// for entry in [(0, 1, 1.0), (1, 0, 2.0), (1, 1, 3.0), (1, 2, 4.0), (2, 1, 5.0)] {
+    ///     MatrixMut::add(&mut csr, entry).unwrap();
+    /// }
+    /// assert_eq!(csr.lapmod(100.0), Err(LAPError::InfeasibleAssignment));
+    ///
+    /// let violator = csr.hall_violator().unwrap().unwrap();
+    /// assert_eq!(violator, HallViolator { rows: vec![0, 2], neighborhood: vec![1] });
+    /// ```
+    #[inline]
+    fn hall_violator(
+        &self,
+    ) -> Result<Option<HallViolator<Self::RowIndex, Self::ColumnIndex>>, HopcroftKarpError> {
+        let mut assigned_columns = vec![None; self.number_of_rows().as_()];
+        let mut assigned_rows = vec![None; self.number_of_columns().as_()];
+        for (row, column) in self.hopcroft_karp()? {
+            assigned_columns[row.as_()] = Some(column);
+            assigned_rows[column.as_()] = Some(row);
+        }
+
+        let Some(unassigned_row) =
+            self.row_indices().find(|row| assigned_columns[row.as_()].is_none())
+        else {
+            return Ok(None);
+        };
+
+        let mut reached_rows = vec![false; assigned_columns.len()];
+        let mut reached_columns = vec![false; assigned_rows.len()];
+        reached_rows[unassigned_row.as_()] = true;
+        let mut frontier = vec![unassigned_row];
+        while let Some(row) = frontier.pop() {
+            for column in self.sparse_row(row) {
+                if core::mem::replace(&mut reached_columns[column.as_()], true) {
+                    continue;
+                }
+                // A reached column is always assigned, as otherwise the
+                // alternating path leading to it would augment the matching.
+                if let Some(assigned_row) = assigned_rows[column.as_()] {
+                    if !core::mem::replace(&mut reached_rows[assigned_row.as_()], true) {
+                        frontier.push(assigned_row);
+                    }
+                }
+            }
+        }
+
+        Ok(Some(HallViolator {
+            rows: self.row_indices().filter(|row| reached_rows[row.as_()]).collect(),
+            neighborhood: self
+                .column_indices()
+                .filter(|column| reached_columns[column.as_()])
+                .collect(),
+        }))
+    }
 }
 
 impl<M> HopcroftKarp for M where M: SparseMatrix2D {}
@@ -0,0 +1,262 @@
+//! Submodule providing the `Assortativity` trait, measuring whether the
+//! edges of an undirected graph preferentially join similar nodes.
+//!
+//! Following Newman, the degree assortativity is the Pearson correlation of
+//! the degrees at the two ends of the edges, and the attribute assortativity
+//! compares the fraction of edges joining nodes of the same category with
+//! the fraction expected if the edges were placed at random. Both are read
+//! from a single pass over the edges, each undirected edge contributing its
+//! two orientations, so that the statistics are symmetric. Self-loops are
+//! ignored, both as edges and in the degrees.
+
+use alloc::{vec, vec::Vec};
+
+use num_traits::AsPrimitive;
+
+use crate::traits::{MonopartiteGraph, UndirectedMonopartiteMonoplexGraph};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+/// Error enumeration for the attribute assortativity.
+pub enum AssortativityError {
+    /// The number of labels differs from the number of nodes.
+    #[error("Expected one label for each of the {expected} nodes, but received {actual} labels.")]
+    LabelCountMismatch {
+        /// The number of nodes of the graph.
+        expected: usize,
+        /// The number of provided labels.
+        actual: usize,
+    },
+}
+
+impl From<AssortativityError>
+    for crate::errors::monopartite_graph_error::algorithms::MonopartiteAlgorithmError
+{
+    #[inline]
+    fn from(error: AssortativityError) -> Self {
+        Self::AssortativityError(error)
+    }
+}
+
+impl<G: MonopartiteGraph> From<AssortativityError> for crate::errors::MonopartiteError<G> {
+    #[inline]
+    fn from(error: AssortativityError) -> Self {
+        Self::AlgorithmError(error.into())
+    }
+}
+
+/// Trait providing the assortativity coefficients and the mixing matrix of
+/// undirected graphs.
+pub trait Assortativity: UndirectedMonopartiteMonoplexGraph
+where
+    Self::NodeId: AsPrimitive<usize>,
+{
+    /// Returns the degree assortativity coefficient, in [-1, 1].
+    ///
+    /// Positive values indicate that high-degree nodes tend to be connected
+    /// to high-degree nodes, negative values that they tend to be connected
+    /// to low-degree nodes, as the hub of a star. The coefficient is
+    /// undefined, and `None` is returned, when the graph has no edge or when
+    /// all the edges join nodes of equal degrees, e.g. in regular graphs.
+    ///
+    /// # Complexity
+    ///
+    /// O(V + E) time and O(V) space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{
+    ///     impls::{CSR2D, SortedVec, SymmetricCSR2D},
+    ///     prelude::*,
+    ///     traits::{Assortativity, EdgesBuilder, VocabularyBuilder},
+    /// };
+    ///
+    /// let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+    ///     .expected_number_of_symbols(4)
+    ///     .symbols((0..4).enumerate())
+    ///     .build()
+    ///     .unwrap();
+    /// // A star, whose hub is only connected to leaves.
+    /// let edges: SymmetricCSR2D<CSR2D<usize, usize, usize>> = UndiEdgesBuilder::default()
+    ///     .expected_number_of_edges(3)
+    ///     .expected_shape(4)
+    ///     .edges(vec![(0, 1), (0, 2), (0, 3)].into_iter())
+    ///     .build()
+    ///     .unwrap();
+    /// let star: UndiGraph<usize> = UndiGraph::from((nodes, edges));
+    ///
+    /// assert!((star.degree_assortativity().unwrap() + 1.0).abs() < 1e-12);
+    /// ```
+    #[inline]
+    fn degree_assortativity(&self) -> Option<f64> {
+        let degrees: Vec<f64> = self
+            .node_ids()
+            .map(|node| {
+                let degree = self.neighbors(node).filter(|&neighbor| neighbor != node).count();
+                usize_to_f64(degree)
+            })
+            .collect();
+
+        let mut ends = 0_usize;
+        let (mut sum, mut sum_of_squares, mut sum_of_products) = (0.0, 0.0, 0.0);
+        for node in self.node_ids() {
+            let degree = degrees[node.as_()];
+            for neighbor in self.neighbors(node).filter(|&neighbor| neighbor != node) {
+                ends += 1;
+                sum += degree;
+                sum_of_squares += degree * degree;
+                sum_of_products += degree * degrees[neighbor.as_()];
+            }
+        }
+        if ends == 0 {
+            return None;
+        }
+        let ends = usize_to_f64(ends);
+        let mean = sum / ends;
+        let variance = sum_of_squares / ends - mean * mean;
+        let covariance = sum_of_products / ends - mean * mean;
+        if variance <= f64::EPSILON * sum_of_squares / ends {
+            return None;
+        }
+        Some((covariance / variance).clamp(-1.0, 1.0))
+    }
+
+    /// Returns the mixing matrix of the provided node categories, whose
+    /// entry (i, j) is the fraction of the edge ends leaving a node of
+    /// category i towards a node of category j.
+    ///
+    /// The matrix is symmetric, has one row per category up to the largest
+    /// provided label, and sums to one unless the graph has no edge, in
+    /// which case it is zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `labels`: The category of each node, indexed by node id.
+    ///
+    /// # Errors
+    ///
+    /// * [`AssortativityError::LabelCountMismatch`] if the number of labels
+    ///   differs from the number of nodes.
+    ///
+    /// # Complexity
+    ///
+    /// O(V + E + K²) time and O(K²) space, with K the number of categories.
+    #[inline]
+    fn attribute_mixing_matrix<Label>(
+        &self,
+        labels: &[Label],
+    ) -> Result<Vec<Vec<f64>>, AssortativityError>
+    where
+        Label: AsPrimitive<usize>,
+    {
+        let number_of_nodes = self.number_of_nodes().as_();
+        if labels.len() != number_of_nodes {
+            return Err(AssortativityError::LabelCountMismatch {
+                expected: number_of_nodes,
+                actual: labels.len(),
+            });
+        }
+        let number_of_categories = labels.iter().map(|label| label.as_() + 1).max().unwrap_or(0);
+        let mut counts = vec![vec![0_usize; number_of_categories]; number_of_categories];
+        let mut ends = 0_usize;
+        for node in self.node_ids() {
+            let label = labels[node.as_()].as_();
+            for neighbor in self.neighbors(node).filter(|&neighbor| neighbor != node) {
+                counts[label][labels[neighbor.as_()].as_()] += 1;
+                ends += 1;
+            }
+        }
+        let total = usize_to_f64(ends.max(1));
+        Ok(counts
+            .into_iter()
+            .map(|row| row.into_iter().map(|count| usize_to_f64(count) / total).collect())
+            .collect())
+    }
+
+    /// Returns the attribute assortativity coefficient of the provided node
+    /// categories, in [-1, 1].
+    ///
+    /// The coefficient is one when every edge joins nodes of the same
+    /// category, zero when the categories are mixed as at random, and
+    /// negative when edges preferentially join distinct categories. It is
+    /// undefined, and `None` is returned, when the graph has no edge or when
+    /// all the edge ends belong to a single category.
+    ///
+    /// # Arguments
+    ///
+    /// * `labels`: The category of each node, indexed by node id.
+    ///
+    /// # Errors
+    ///
+    /// * [`AssortativityError::LabelCountMismatch`] if the number of labels
+    ///   differs from the number of nodes.
+    ///
+    /// # Complexity
+    ///
+    /// O(V + E + K²) time and O(K²) space, with K the number of categories.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{
+    ///     impls::{CSR2D, SortedVec, SymmetricCSR2D},
+    ///     prelude::*,
+    ///     traits::{Assortativity, EdgesBuilder, VocabularyBuilder},
+    /// };
+    ///
+    /// let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+    ///     .expected_number_of_symbols(4)
+    ///     .symbols((0..4).enumerate())
+    ///     .build()
+    ///     .unwrap();
+    /// let edges: SymmetricCSR2D<CSR2D<usize, usize, usize>> = UndiEdgesBuilder::default()
+    ///     .expected_number_of_edges(2)
+    ///     .expected_shape(4)
+    ///     .edges(vec![(0, 1), (2, 3)].into_iter())
+    ///     .build()
+    ///     .unwrap();
+    /// let graph: UndiGraph<usize> = UndiGraph::from((nodes, edges));
+    ///
+    /// // Every edge joins nodes of the same category.
+    /// assert_eq!(graph.attribute_assortativity(&[0, 0, 1, 1]).unwrap(), Some(1.0));
+    /// // Every edge joins nodes of distinct categories.
+    /// assert_eq!(graph.attribute_assortativity(&[0, 1, 0, 1]).unwrap(), Some(-1.0));
+    /// ```
+    #[inline]
+    fn attribute_assortativity<Label>(
+        &self,
+        labels: &[Label],
+    ) -> Result<Option<f64>, AssortativityError>
+    where
+        Label: AsPrimitive<usize>,
+    {
+        let mixing = self.attribute_mixing_matrix(labels)?;
+        let trace: f64 = mixing.iter().enumerate().map(|(category, row)| row[category]).sum();
+        // As the matrix is symmetric, the row and column sums coincide.
+        let expected: f64 = mixing
+            .iter()
+            .map(|row| {
+                let fraction: f64 = row.iter().sum();
+                fraction * fraction
+            })
+            .sum();
+        if expected < f64::EPSILON || 1.0 - expected <= f64::EPSILON {
+            return Ok(None);
+        }
+        Ok(Some((trace - expected) / (1.0 - expected)))
+    }
+}
+
+impl<G> Assortativity for G
+where
+    G: UndirectedMonopartiteMonoplexGraph,
+    G::NodeId: AsPrimitive<usize>,
+{
+}
+
+/// Converts a count into a floating point value.
+#[allow(clippy::cast_precision_loss)]
+#[inline]
+fn usize_to_f64(value: usize) -> f64 {
+    value as f64
+}
@@ -1,6 +1,8 @@
 //! Public canonization API plus lower-level building blocks for
 //! individualization-refinement over dense vertex identifiers.
 
+use alloc::{vec, vec::Vec};
+
 use num_traits::AsPrimitive;
 
 use crate::traits::MonoplexMonopartiteGraph;
@@ -16,7 +18,7 @@ pub use partition::{
 pub(crate) use refine::RefinementTrace;
 pub use refine::refine_partition_to_labeled_equitable;
 pub use search::{
-    CanonSplittingHeuristic, CanonicalLabelingOptions, CanonicalLabelingResult,
+    CanonSplittingHeuristic, CanonicalForm, CanonicalLabelingOptions, CanonicalLabelingResult,
     CanonicalSearchStats, LabeledSimpleGraphCertificate,
 };
 
@@ -68,6 +70,77 @@ pub trait CanonicalLabeling: MonoplexMonopartiteGraph {
             options,
         )
     }
+
+    /// Computes the canonical form of the graph, ignoring any vertex and
+    /// edge labels.
+    ///
+    /// The returned permutation maps each node to its position in the
+    /// canonical ordering, so that relabeling any two isomorphic graphs with
+    /// their permutations yields the same edges. This makes canonical forms
+    /// suitable as keys when deduplicating graphs, e.g. in a `HashSet`.
+    ///
+    /// The graph must be simple and undirected, i.e. symmetric and without
+    /// self-loops.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{
+    ///     impls::{CSR2D, SortedVec, SymmetricCSR2D},
+    ///     prelude::*,
+    ///     traits::{CanonicalLabeling, EdgesBuilder, VocabularyBuilder},
+    /// };
+    ///
+    /// fn build_graph(node_count: usize, mut edges: Vec<(usize, usize)>) -> UndiGraph<usize> {
+    ///     let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+    ///         .expected_number_of_symbols(node_count)
+    ///         .symbols((0..node_count).enumerate())
+    ///         .build()
+    ///         .unwrap();
+    ///     edges.sort_unstable();
+    ///     let edges: SymmetricCSR2D<CSR2D<usize, usize, usize>> = UndiEdgesBuilder::default()
+    ///         .expected_number_of_edges(edges.len())
+    ///         .expected_shape(node_count)
+    ///         .edges(edges.into_iter())
+    ///         .build()
+    ///         .unwrap();
+    ///     UndiGraph::from((nodes, edges))
+    /// }
+    ///
+    /// // The same path on three nodes, centered on different nodes.
+    /// let left = build_graph(3, vec![(0, 1), (1, 2)]).canonical_form();
+    /// let right = build_graph(3, vec![(0, 2), (1, 2)]).canonical_form();
+    /// assert_eq!(left, right);
+    /// assert_eq!(left.permutation[1], right.permutation[2]);
+    ///
+    /// let triangle = build_graph(3, vec![(0, 1), (0, 2), (1, 2)]).canonical_form();
+    /// assert_ne!(left, triangle);
+    /// ```
+    #[inline]
+    fn canonical_form(&self) -> CanonicalForm
+    where
+        Self: Sized,
+        Self::NodeId: AsPrimitive<usize>,
+    {
+        let order = self.canonical_labeling(|_| 0_u8, |_, _| 0_u8).order;
+        let mut permutation = vec![0; order.len()];
+        for (position, node) in order.into_iter().enumerate() {
+            permutation[node] = position;
+        }
+
+        let mut edges = Vec::new();
+        for node in self.node_ids() {
+            for successor in self.successors(node) {
+                if node.as_() < successor.as_() {
+                    let (left, right) = (permutation[node.as_()], permutation[successor.as_()]);
+                    edges.push((left.min(right), left.max(right)));
+                }
+            }
+        }
+        edges.sort_unstable();
+
+        CanonicalForm { permutation, edges }
+    }
 }
 
 impl<G: ?Sized + MonoplexMonopartiteGraph> CanonicalLabeling for G {}
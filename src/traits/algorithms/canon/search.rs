@@ -79,6 +79,62 @@ pub struct CanonicalLabelingResult<VertexLabel, EdgeLabel> {
     pub stats: CanonicalSearchStats,
 }
 
+/// Canonical form of a simple undirected graph, ignoring its labels.
+///
+/// Two canonical forms compare equal, and hash identically, if and only if
+/// their graphs are isomorphic: equality, ordering and hashing only consider
+/// the number of nodes and the relabeled edges, never the permutation.
+#[derive(Clone, Debug)]
+pub struct CanonicalForm {
+    /// Canonical position of each original dense vertex identifier.
+    pub permutation: Vec<usize>,
+    /// Edges relabeled by `permutation`, each as a `(smaller, larger)` pair,
+    /// in lexicographic order.
+    pub edges: Vec<(usize, usize)>,
+}
+
+impl CanonicalForm {
+    /// Returns the number of nodes of the graph.
+    #[inline]
+    #[must_use]
+    pub fn number_of_nodes(&self) -> usize {
+        self.permutation.len()
+    }
+}
+
+impl PartialEq for CanonicalForm {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.number_of_nodes() == other.number_of_nodes() && self.edges == other.edges
+    }
+}
+
+impl Eq for CanonicalForm {}
+
+impl PartialOrd for CanonicalForm {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CanonicalForm {
+    #[inline]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.number_of_nodes()
+            .cmp(&other.number_of_nodes())
+            .then_with(|| self.edges.cmp(&other.edges))
+    }
+}
+
+impl core::hash::Hash for CanonicalForm {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.number_of_nodes().hash(state);
+        self.edges.hash(state);
+    }
+}
+
 /// Search statistics for the current individualization-refinement canonizer.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct CanonicalSearchStats {
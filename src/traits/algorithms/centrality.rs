@@ -0,0 +1,316 @@
+//! Submodule providing the `Centrality` trait, computing the shortest path
+//! betweenness and closeness centralities of the nodes of sparse valued
+//! matrices, either weighted by their values or counting hops.
+//!
+//! These are the directed and weighted counterparts of the
+//! [`BetweennessCentralityScorer`](super::node_ordering::BetweennessCentralityScorer)
+//! and [`ClosenessCentralityScorer`](super::node_ordering::ClosenessCentralityScorer)
+//! of undirected graphs, and share their searches: the breadth-first ones
+//! for hop counts and the [`Dijkstra`] ones for weighted distances.
+//!
+//! The rows of the matrix are the outgoing edges of the nodes, and the
+//! centralities are computed on the directed graph they describe: undirected
+//! graphs are given by symmetric matrices.
+
+use alloc::{
+    collections::{BinaryHeap, VecDeque},
+    vec,
+    vec::Vec,
+};
+
+use num_traits::{AsPrimitive, ToPrimitive, Zero};
+
+use super::{
+    Dijkstra, DijkstraError,
+    node_ordering::{BrandesWorkspace, breadth_first_closeness, closeness},
+};
+use crate::traits::{
+    Finite, Number, PositiveInteger, SparseValuedMatrix2D, TotalOrd, TryFromUsize,
+};
+
+/// Trait providing the betweenness and closeness centralities of the nodes
+/// of sparse valued matrices, as vectors indexed by node identifier.
+///
+/// Missing entries in the sparse matrix are interpreted as absent edges and
+/// self-loops do not change the centralities. The weighted centralities
+/// measure the length of a path by the sum of its weights, which must be
+/// finite and non-negative, and positive outside self-loops for the
+/// betweenness, while the unweighted ones count its edges and ignore the
+/// weights.
+///
+/// # References
+///
+/// Brandes, U. (2001). [A faster algorithm for betweenness centrality](https://doi.org/10.1080/0022250X.2001.9990249).
+/// *Journal of Mathematical Sociology*, 25(2), 163-177.
+///
+/// Wasserman, S., & Faust, K. (1994). [Social Network Analysis: Methods and Applications](https://doi.org/10.1017/CBO9780511815478).
+/// Cambridge University Press.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{impls::ValuedCSR2D, prelude::*, traits::Centrality};
+///
+/// // The undirected path 0 - 1 - 2, and the edge (0, 2), heavier than the
+/// // path.
+/// let edges = vec![(0, 1, 1.0), (0, 2, 3.0), (1, 0, 1.0), (1, 2, 1.5), (2, 0, 3.0), (2, 1, 1.5)];
+/// let csr: ValuedCSR2D<usize, usize, usize, f64> =
+///     GenericEdgesBuilder::<_, ValuedCSR2D<usize, usize, usize, f64>>::default()
+///         .expected_number_of_edges(edges.len())
+///         .expected_shape((3, 3))
+///         .edges(edges.into_iter())
+///         .build()
+///         .unwrap();
+///
+/// // Both directions of the path between 0 and 2 cross the node 1.
+/// assert_eq!(csr.betweenness_centrality(false).unwrap(), vec![0.0, 2.0, 0.0]);
+/// assert_eq!(csr.unweighted_betweenness_centrality(false).unwrap(), vec![0.0; 3]);
+/// assert_eq!(csr.closeness_centrality(true).unwrap(), vec![2.0 / 3.5, 0.8, 0.5]);
+/// assert_eq!(csr.unweighted_closeness_centrality(true).unwrap(), vec![1.0; 3]);
+/// ```
+pub trait Centrality: SparseValuedMatrix2D + Sized
+where
+    Self::Value: Number + Finite + TotalOrd + ToPrimitive,
+    Self::RowIndex: PositiveInteger,
+    Self::ColumnIndex: AsPrimitive<usize> + Into<Self::RowIndex>,
+{
+    /// Returns the betweenness centrality of each node, weighting the paths
+    /// by the values of the matrix.
+    ///
+    /// The betweenness of a node sums, over the ordered pairs of other nodes
+    /// it connects, the fraction of the shortest paths from the first node
+    /// to the second one which cross it. The betweenness of an undirected
+    /// graph given by a symmetric matrix thus counts each unordered pair
+    /// twice, and is halved by `NetworkX`. Normalized values are divided by
+    /// the number (n − 1)(n − 2) of ordered pairs of other nodes, which makes
+    /// them match the normalized `NetworkX` values on undirected graphs too.
+    ///
+    /// Shortest paths are compared by exact equality of their lengths, so
+    /// that floating point weights whose sums differ by rounding errors
+    /// yield distinct paths.
+    ///
+    /// # Arguments
+    ///
+    /// * `normalized`: Whether to normalize the betweenness values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the matrix is not square, if a weight is zero
+    /// outside self-loops, if a weight reached by the searches is not finite
+    /// or is negative, or if a tentative path distance becomes non-finite.
+    ///
+    /// # Complexity
+    ///
+    /// O(V · (V + E) log V) time and O(V + E) space.
+    #[inline]
+    fn betweenness_centrality(&self, normalized: bool) -> Result<Vec<f64>, DijkstraError> {
+        let number_of_nodes = validate_shape(self)?;
+        reject_zero_weights(self)?;
+        let mut workspace = BrandesWorkspace::new(number_of_nodes);
+        let mut distances = vec![None; number_of_nodes];
+        let mut heap = BinaryHeap::new();
+        let mut scores = vec![0.0; number_of_nodes];
+        for source in 0..number_of_nodes {
+            workspace.dijkstra_search(self, row_id::<Self>(source), &mut distances, &mut heap)?;
+            workspace.accumulate(source, false, |node, value| scores[node] += value);
+        }
+        Ok(normalize_betweenness(scores, normalized))
+    }
+
+    /// Returns the betweenness centrality of each node, counting the edges
+    /// of the paths.
+    ///
+    /// The values are those of [`Centrality::betweenness_centrality`] on the
+    /// matrix with unit weights.
+    ///
+    /// # Arguments
+    ///
+    /// * `normalized`: Whether to normalize the betweenness values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the matrix is not square.
+    ///
+    /// # Complexity
+    ///
+    /// O(V · (V + E)) time and O(V + E) space.
+    #[inline]
+    fn unweighted_betweenness_centrality(
+        &self,
+        normalized: bool,
+    ) -> Result<Vec<f64>, DijkstraError> {
+        let number_of_nodes = validate_shape(self)?;
+        let mut workspace = BrandesWorkspace::new(number_of_nodes);
+        let mut scores = vec![0.0; number_of_nodes];
+        for source in 0..number_of_nodes {
+            workspace.breadth_first_search(source, |node| successors(self, node));
+            workspace.accumulate(source, false, |node, value| scores[node] += value);
+        }
+        Ok(normalize_betweenness(scores, normalized))
+    }
+
+    /// Returns the closeness centrality of each node, weighting the paths by
+    /// the values of the matrix.
+    ///
+    /// The closeness of a node is the inverse of the average distance from
+    /// it to the r − 1 other nodes it reaches, or zero if it reaches none.
+    /// With the Wasserman-Faust improvement, it is further scaled by the
+    /// fraction (r − 1)/(n − 1) of the other nodes it reaches, so that nodes
+    /// of small components are not deemed central. The distances are the
+    /// outgoing ones, whereas `NetworkX` uses the incoming ones on directed
+    /// graphs: both agree on undirected graphs given by symmetric matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `wf_improved`: Whether to apply the Wasserman-Faust improvement.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the matrix is not square, if a weight reached by
+    /// the searches is not finite or is negative, or if a tentative path
+    /// distance becomes non-finite.
+    ///
+    /// # Complexity
+    ///
+    /// O(V · (V + E) log V) time and O(V + E) space.
+    #[inline]
+    fn closeness_centrality(&self, wf_improved: bool) -> Result<Vec<f64>, DijkstraError> {
+        let number_of_nodes = validate_shape(self)?;
+        (0..number_of_nodes)
+            .map(|source| {
+                let paths = self.dijkstra(row_id::<Self>(source))?;
+                let (reachable_others, total_shortest_path_distance) = paths
+                    .distances()
+                    .iter()
+                    .flatten()
+                    .fold((0, 0.0), |(count, total), distance| {
+                        (count + 1, total + distance.to_f64().unwrap_or(f64::INFINITY))
+                    });
+                Ok(closeness(
+                    reachable_others - 1,
+                    total_shortest_path_distance,
+                    number_of_nodes,
+                    wf_improved,
+                ))
+            })
+            .collect()
+    }
+
+    /// Returns the closeness centrality of each node, counting the edges of
+    /// the paths.
+    ///
+    /// The values are those of [`Centrality::closeness_centrality`] on the
+    /// matrix with unit weights.
+    ///
+    /// # Arguments
+    ///
+    /// * `wf_improved`: Whether to apply the Wasserman-Faust improvement.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the matrix is not square.
+    ///
+    /// # Complexity
+    ///
+    /// O(V · (V + E)) time and O(V + E) space.
+    #[inline]
+    fn unweighted_closeness_centrality(
+        &self,
+        wf_improved: bool,
+    ) -> Result<Vec<f64>, DijkstraError> {
+        let number_of_nodes = validate_shape(self)?;
+        let mut distance = vec![usize::MAX; number_of_nodes];
+        let mut queue = VecDeque::with_capacity(number_of_nodes);
+        Ok((0..number_of_nodes)
+            .map(|source| {
+                breadth_first_closeness(
+                    source,
+                    |node| successors(self, node),
+                    &mut distance,
+                    &mut queue,
+                    wf_improved,
+                )
+            })
+            .collect())
+    }
+}
+
+impl<M> Centrality for M
+where
+    M: SparseValuedMatrix2D + Sized,
+    M::Value: Number + Finite + TotalOrd + ToPrimitive,
+    M::RowIndex: PositiveInteger,
+    M::ColumnIndex: AsPrimitive<usize> + Into<M::RowIndex>,
+{
+}
+
+/// Returns the number of nodes of the matrix, checking that it is square.
+fn validate_shape<M>(matrix: &M) -> Result<usize, DijkstraError>
+where
+    M: SparseValuedMatrix2D,
+{
+    let rows = matrix.number_of_rows().as_();
+    let columns = matrix.number_of_columns().as_();
+    if rows == columns { Ok(rows) } else { Err(DijkstraError::NonSquareMatrix { rows, columns }) }
+}
+
+/// Checks that the weights of the edges other than self-loops are not zero,
+/// as the searches of Brandes' algorithm need to settle the predecessors of
+/// a node before it.
+fn reject_zero_weights<M>(matrix: &M) -> Result<(), DijkstraError>
+where
+    M: SparseValuedMatrix2D,
+    M::Value: Number,
+    M::ColumnIndex: AsPrimitive<usize>,
+{
+    for row_id in matrix.row_indices() {
+        let source_id = row_id.as_();
+        for (column_id, weight) in matrix.sparse_row(row_id).zip(matrix.sparse_row_values(row_id)) {
+            let destination_id = column_id.as_();
+            if destination_id != source_id && weight.is_zero() {
+                return Err(DijkstraError::ZeroWeight { source_id, destination_id });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Scales the betweenness values by the number of ordered pairs of other
+/// nodes, if normalized.
+fn normalize_betweenness(mut scores: Vec<f64>, normalized: bool) -> Vec<f64> {
+    let number_of_nodes = scores.len();
+    if normalized && number_of_nodes > 2 {
+        let scale = 1.0 / (usize_to_f64(number_of_nodes - 1) * usize_to_f64(number_of_nodes - 2));
+        for score in &mut scores {
+            *score *= scale;
+        }
+    }
+    scores
+}
+
+/// Returns the indices of the destinations of the edges leaving the node.
+fn successors<M>(matrix: &M, node: usize) -> impl Iterator<Item = usize> + '_
+where
+    M: SparseValuedMatrix2D,
+    M::RowIndex: TryFromUsize,
+    M::ColumnIndex: AsPrimitive<usize>,
+{
+    matrix.sparse_row(row_id::<M>(node)).map(AsPrimitive::as_)
+}
+
+/// Converts the dense node index back to a row identifier.
+fn row_id<M>(index: usize) -> M::RowIndex
+where
+    M: SparseValuedMatrix2D,
+    M::RowIndex: TryFromUsize,
+{
+    M::RowIndex::try_from_usize(index)
+        .unwrap_or_else(|_| unreachable!("Node indices are row identifiers."))
+}
+
+/// Converts the count to `f64`.
+#[allow(clippy::cast_precision_loss)]
+#[inline]
+fn usize_to_f64(value: usize) -> f64 {
+    value as f64
+}
@@ -1,9 +1,12 @@
 //! Submodule providing the `ConnectedComponents` trait and its primary methods.
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 
 use num_traits::{AsPrimitive, One, Zero};
 
-use crate::traits::{MonopartiteGraph, PositiveInteger, UndirectedMonopartiteMonoplexGraph};
+use crate::{
+    impls::DisjointSet,
+    traits::{MonopartiteGraph, PositiveInteger, UndirectedMonopartiteMonoplexGraph},
+};
 
 /// Connected components object.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -77,6 +80,52 @@ where
             move |(symbol, &component)| (component == component_identifier).then_some(symbol),
         )
     }
+
+    /// Returns the connected components as a disjoint-set forest over the
+    /// node ids, which can then be updated incrementally as edges and nodes
+    /// are added to the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{
+    ///     impls::{SortedVec, SymmetricCSR2D},
+    ///     prelude::*,
+    ///     traits::{
+    ///         EdgesBuilder, VocabularyBuilder,
+    ///         algorithms::connected_components::ConnectedComponentsResult,
+    ///     },
+    /// };
+    ///
+    /// let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+    ///     .expected_number_of_symbols(4)
+    ///     .symbols((0..4).enumerate())
+    ///     .build()
+    ///     .unwrap();
+    /// let edges: SymmetricCSR2D<_> = UndiEdgesBuilder::default()
+    ///     .expected_number_of_edges(2)
+    ///     .expected_shape(nodes.len())
+    ///     .edges(vec![(0, 1), (2, 3)].into_iter())
+    ///     .build()
+    ///     .unwrap();
+    /// let graph: UndiGraph<usize> = UndiGraph::from((nodes, edges));
+    ///
+    /// let cc: ConnectedComponentsResult<_, usize> = graph.connected_components().unwrap();
+    /// let mut sets = cc.to_disjoint_set();
+    /// assert_eq!(sets.number_of_sets(), 2);
+    /// assert!(sets.union(1, 2));
+    /// assert_eq!(sets.number_of_sets(), 1);
+    /// ```
+    #[inline]
+    pub fn to_disjoint_set(&self) -> DisjointSet<G::NodeId> {
+        let mut sets = DisjointSet::new(self.graph.number_of_nodes());
+        let mut first_nodes: Vec<Option<G::NodeId>> = vec![None; self.number_of_components.as_()];
+        for (node, component) in self.graph.node_ids().zip(self.component_identifiers()) {
+            let first_node = first_nodes[component.as_()].get_or_insert(node);
+            sets.union(*first_node, node);
+        }
+        sets
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
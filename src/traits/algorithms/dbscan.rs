@@ -0,0 +1,194 @@
+//! Submodule providing the `Dbscan` trait, clustering the nodes of a
+//! neighbourhood graph by density.
+
+use alloc::{collections::VecDeque, vec::Vec};
+
+use num_traits::AsPrimitive;
+
+use crate::traits::{MonopartiteGraph, PositiveInteger, SparseMatrix2D};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+/// Error enumeration for the DBSCAN clustering algorithm.
+pub enum DbscanError {
+    /// The matrix is not square.
+    #[error("The neighbourhood matrix must be square, but received shape ({rows}, {columns}).")]
+    NonSquareMatrix {
+        /// Number of rows in the matrix.
+        rows: usize,
+        /// Number of columns in the matrix.
+        columns: usize,
+    },
+    /// The selected cluster marker type is too small.
+    #[error("The selected cluster marker type is too small for this clustering.")]
+    TooManyClusters,
+}
+
+impl From<DbscanError>
+    for crate::errors::monopartite_graph_error::algorithms::MonopartiteAlgorithmError
+{
+    #[inline]
+    fn from(error: DbscanError) -> Self {
+        Self::DbscanError(error)
+    }
+}
+
+impl<G: MonopartiteGraph> From<DbscanError> for crate::errors::MonopartiteError<G> {
+    #[inline]
+    fn from(error: DbscanError) -> Self {
+        Self::AlgorithmError(error.into())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Result of the DBSCAN clustering algorithm.
+pub struct DbscanResult<Marker> {
+    /// Cluster of every node, or `None` for noise.
+    labels: Vec<Option<Marker>>,
+    /// Whether every node is a core node.
+    core: Vec<bool>,
+    /// Number of clusters.
+    number_of_clusters: usize,
+}
+
+impl<Marker> DbscanResult<Marker> {
+    /// Returns the cluster of every node, or `None` for the noise nodes.
+    #[must_use]
+    #[inline]
+    pub fn labels(&self) -> &[Option<Marker>] {
+        &self.labels
+    }
+
+    /// Returns whether every node is a core node, with at least the minimal
+    /// number of nodes in its neighbourhood.
+    #[must_use]
+    #[inline]
+    pub fn core_nodes(&self) -> &[bool] {
+        &self.core
+    }
+
+    /// Returns the number of clusters.
+    #[must_use]
+    #[inline]
+    pub fn number_of_clusters(&self) -> usize {
+        self.number_of_clusters
+    }
+
+    /// Returns the number of noise nodes, which belong to no cluster.
+    #[must_use]
+    #[inline]
+    pub fn number_of_noise_nodes(&self) -> usize {
+        self.labels.iter().filter(|label| label.is_none()).count()
+    }
+}
+
+/// Trait providing the DBSCAN density-based clustering over a precomputed
+/// neighbourhood graph.
+///
+/// The graph is expected to be a square matrix whose row `i` lists the
+/// neighbours of node `i`, such as the
+/// [`epsilon_graph`](crate::spatial::epsilon_graph) of a point set, and is
+/// usually symmetric. Values, if any, are ignored, as are self-loops: the
+/// neighbourhood of a node always contains the node itself.
+pub trait Dbscan<Marker: PositiveInteger = usize>: SparseMatrix2D + Sized
+where
+    Self::RowIndex: AsPrimitive<usize>,
+    Self::ColumnIndex: AsPrimitive<usize>,
+{
+    /// Executes DBSCAN with the provided minimal neighbourhood size.
+    ///
+    /// Core nodes have at least `min_points` nodes in their neighbourhood,
+    /// themselves included. Clusters are the groups of core nodes connected
+    /// through core neighbours, together with the non-core neighbours of
+    /// their core nodes, the border nodes. The other nodes are noise.
+    ///
+    /// The result is deterministic given the node order: clusters are numbered
+    /// by order of their first core node, and a border node neighbouring
+    /// several clusters joins the first of them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when:
+    /// - the matrix is not square;
+    /// - the number of clusters cannot fit into `Marker`.
+    ///
+    /// # Complexity
+    ///
+    /// O(V + E) time and space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{
+    ///     prelude::*,
+    ///     spatial::{Euclidean, epsilon_graph},
+    ///     traits::Dbscan,
+    /// };
+    ///
+    /// let points = [[0.0], [1.0], [2.0], [10.0], [11.0], [12.0], [30.0]];
+    /// let graph = epsilon_graph(&points, 1.0, &Euclidean);
+    ///
+    /// let result = Dbscan::<u8>::dbscan(&graph, 3).unwrap();
+    /// assert_eq!(result.labels(), &[Some(0), Some(0), Some(0), Some(1), Some(1), Some(1), None]);
+    /// assert_eq!(result.number_of_clusters(), 2);
+    /// assert_eq!(result.number_of_noise_nodes(), 1);
+    /// ```
+    #[inline]
+    fn dbscan(&self, min_points: usize) -> Result<DbscanResult<Marker>, DbscanError> {
+        let rows = self.number_of_rows().as_();
+        let columns = self.number_of_columns().as_();
+        if rows != columns {
+            return Err(DbscanError::NonSquareMatrix { rows, columns });
+        }
+
+        let neighborhoods: Vec<Vec<usize>> = self
+            .row_indices()
+            .map(|row| {
+                let node = row.as_();
+                self.sparse_row(row)
+                    .map(AsPrimitive::as_)
+                    .filter(|&neighbor| neighbor != node)
+                    .collect()
+            })
+            .collect();
+        let core: Vec<bool> = neighborhoods
+            .iter()
+            .map(|neighborhood| neighborhood.len().saturating_add(1) >= min_points)
+            .collect();
+
+        let mut labels: Vec<Option<Marker>> = vec![None; rows];
+        let mut number_of_clusters = 0;
+        let mut frontier: VecDeque<usize> = VecDeque::new();
+        for seed in 0..rows {
+            if !core[seed] || labels[seed].is_some() {
+                continue;
+            }
+            let marker = Marker::try_from_usize(number_of_clusters)
+                .map_err(|_| DbscanError::TooManyClusters)?;
+            number_of_clusters += 1;
+            labels[seed] = Some(marker);
+            frontier.push_back(seed);
+            while let Some(node) = frontier.pop_front() {
+                for &neighbor in &neighborhoods[node] {
+                    if labels[neighbor].is_some() {
+                        continue;
+                    }
+                    labels[neighbor] = Some(marker);
+                    if core[neighbor] {
+                        frontier.push_back(neighbor);
+                    }
+                }
+            }
+        }
+
+        Ok(DbscanResult { labels, core, number_of_clusters })
+    }
+}
+
+impl<G, Marker> Dbscan<Marker> for G
+where
+    G: SparseMatrix2D + Sized,
+    Marker: PositiveInteger,
+    G::RowIndex: AsPrimitive<usize>,
+    G::ColumnIndex: AsPrimitive<usize>,
+{
+}
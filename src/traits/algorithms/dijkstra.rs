@@ -0,0 +1,453 @@
+//! Submodule providing the `Dijkstra` trait, computing the single-source
+//! shortest paths of sparse valued matrices with non-negative weights.
+//!
+//! The search keeps its frontier in a binary heap with lazy deletion: a node
+//! whose tentative distance is lowered is pushed again, and the outdated
+//! entries are skipped when popped. Besides the distances, the search records
+//! the predecessor of every reached node on its shortest path, from which the
+//! paths themselves are rebuilt.
+//!
+//! The search loop itself is shared, through [`dijkstra_search`], with the
+//! other algorithms built upon single-source searches, which follow its
+//! progress through the hooks of a [`DijkstraVisitor`].
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+use core::cmp::Ordering;
+
+use num_traits::{AsPrimitive, Zero};
+
+use crate::traits::{Finite, Number, PositiveInteger, SparseValuedMatrix2D, TotalOrd};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+/// Errors that can occur while executing the Dijkstra algorithm.
+pub enum DijkstraError {
+    /// The input matrix is not square.
+    #[error("The matrix must be square, but has {rows} rows and {columns} columns.")]
+    NonSquareMatrix {
+        /// Number of rows.
+        rows: usize,
+        /// Number of columns.
+        columns: usize,
+    },
+    /// The source is not a node of the graph.
+    #[error(
+        "The source {source_id} is not a node of the graph, which has {number_of_nodes} nodes."
+    )]
+    UnknownSource {
+        /// Source node identifier.
+        source_id: usize,
+        /// Number of nodes of the graph.
+        number_of_nodes: usize,
+    },
+    /// An edge weight reached by the search is not finite.
+    #[error("Found a non-finite weight on ({source_id}, {destination_id}).")]
+    NonFiniteWeight {
+        /// Source node identifier.
+        source_id: usize,
+        /// Destination node identifier.
+        destination_id: usize,
+    },
+    /// An edge weight reached by the search is negative.
+    #[error("Found a negative weight on ({source_id}, {destination_id}).")]
+    NegativeWeight {
+        /// Source node identifier.
+        source_id: usize,
+        /// Destination node identifier.
+        destination_id: usize,
+    },
+    /// An edge weight is zero, where the algorithm requires positive weights.
+    #[error("Found a zero weight on ({source_id}, {destination_id}).")]
+    ZeroWeight {
+        /// Source node identifier.
+        source_id: usize,
+        /// Destination node identifier.
+        destination_id: usize,
+    },
+    /// A tentative path distance overflowed or otherwise became non-finite.
+    #[error("Found a non-finite tentative distance to {destination_id} via {via_id}.")]
+    NonFiniteDistance {
+        /// Destination node identifier.
+        destination_id: usize,
+        /// Intermediate node identifier whose outgoing edge caused the issue.
+        via_id: usize,
+    },
+}
+
+/// Shortest paths from a source node, as returned by
+/// [`Dijkstra::dijkstra`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShortestPaths<V, I> {
+    /// The source of the paths.
+    source: I,
+    /// The distance from the source to every node, if reached.
+    distances: Vec<Option<V>>,
+    /// The node preceding every reached node on its shortest path, `None` for
+    /// the source and the unreached nodes.
+    predecessors: Vec<Option<I>>,
+}
+
+impl<V: Copy, I: PositiveInteger> ShortestPaths<V, I> {
+    /// Returns the source of the paths.
+    #[inline]
+    #[must_use]
+    pub fn source(&self) -> I {
+        self.source
+    }
+
+    /// Returns the distance from the source to every node, or `None` for the
+    /// nodes it does not reach.
+    #[inline]
+    #[must_use]
+    pub fn distances(&self) -> &[Option<V>] {
+        &self.distances
+    }
+
+    /// Returns the node preceding every node on its shortest path from the
+    /// source, or `None` for the source and the nodes it does not reach.
+    #[inline]
+    #[must_use]
+    pub fn predecessors(&self) -> &[Option<I>] {
+        &self.predecessors
+    }
+
+    /// Returns the distance from the source to the node, or `None` if the
+    /// node is not reached.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node is not a node of the graph.
+    #[inline]
+    #[must_use]
+    pub fn distance(&self, node: I) -> Option<V> {
+        self.distances[node.as_()]
+    }
+
+    /// Returns the node preceding the node on its shortest path from the
+    /// source, or `None` for the source and the nodes it does not reach.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node is not a node of the graph.
+    #[inline]
+    #[must_use]
+    pub fn predecessor(&self, node: I) -> Option<I> {
+        self.predecessors[node.as_()]
+    }
+
+    /// Returns the nodes of the shortest path from the source to the node,
+    /// both included, or `None` if the node is not reached.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node is not a node of the graph.
+    ///
+    /// # Complexity
+    ///
+    /// O(L) time for a path of L nodes.
+    #[must_use]
+    pub fn path_to(&self, node: I) -> Option<Vec<I>> {
+        self.distances[node.as_()]?;
+        let mut path = vec![node];
+        let mut current = node;
+        while let Some(predecessor) = self.predecessors[current.as_()] {
+            path.push(predecessor);
+            current = predecessor;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Trait providing the single-source shortest paths of non-negative weighted
+/// graphs via Dijkstra's algorithm.
+///
+/// Missing entries in the sparse matrix are interpreted as absent edges. Only
+/// the weights of the edges leaving reached nodes are validated.
+///
+/// # Complexity
+///
+/// O((V + E) log V) time and O(V + E) space.
+///
+/// # Reference
+///
+/// Dijkstra, E. W. (1959). [A note on two problems in connexion with graphs](https://doi.org/10.1007/BF01386390).
+/// *Numerische Mathematik*, 1(1), 269-271.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{impls::ValuedCSR2D, prelude::*};
+///
+/// let csr: ValuedCSR2D<usize, usize, usize, f64> =
+///     GenericEdgesBuilder::<_, ValuedCSR2D<usize, usize, usize, f64>>::default()
+///         .expected_number_of_edges(4)
+///         .expected_shape((4, 4))
+///         .edges(vec![(0, 1, 2.0), (0, 3, 10.0), (1, 2, 3.0), (2, 3, 4.0)].into_iter())
+///         .build()
+///         .unwrap();
+///
+/// let paths = csr.dijkstra(0).unwrap();
+/// assert_eq!(paths.distances(), &[Some(0.0), Some(2.0), Some(5.0), Some(9.0)]);
+/// assert_eq!(paths.predecessors(), &[None, Some(0), Some(1), Some(2)]);
+/// assert_eq!(paths.path_to(3), Some(vec![0, 1, 2, 3]));
+/// assert_eq!(csr.dijkstra(3).unwrap().path_to(0), None);
+/// ```
+pub trait Dijkstra: SparseValuedMatrix2D + Sized
+where
+    Self::Value: Number + Finite + TotalOrd,
+    Self::RowIndex: PositiveInteger,
+    Self::ColumnIndex: AsPrimitive<usize> + Into<Self::RowIndex>,
+{
+    /// Returns the shortest paths from the source to every node.
+    ///
+    /// Among shortest paths of equal length, the recorded one is the first
+    /// found, popping nodes by increasing distance and then by increasing
+    /// identifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `source_id` - The node the search starts from.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the matrix is not square, if the source is not one
+    /// of its nodes, if a weight reached by the search is not finite or is
+    /// negative, or if a tentative path distance becomes non-finite.
+    #[inline]
+    fn dijkstra(
+        &self,
+        source_id: Self::RowIndex,
+    ) -> Result<ShortestPaths<Self::Value, Self::RowIndex>, DijkstraError> {
+        let rows = self.number_of_rows().as_();
+        let columns = self.number_of_columns().as_();
+        if rows != columns {
+            return Err(DijkstraError::NonSquareMatrix { rows, columns });
+        }
+        let source = source_id.as_();
+        if source >= rows {
+            return Err(DijkstraError::UnknownSource { source_id: source, number_of_nodes: rows });
+        }
+
+        let mut distances = vec![None; rows];
+        let mut predecessors = PredecessorVisitor { predecessors: vec![None; rows] };
+        dijkstra_search(
+            self,
+            source_id,
+            &mut distances,
+            &mut BinaryHeap::new(),
+            &mut predecessors,
+        )?;
+        let PredecessorVisitor { predecessors } = predecessors;
+
+        Ok(ShortestPaths { source: source_id, distances, predecessors })
+    }
+}
+
+impl<M> Dijkstra for M
+where
+    M: SparseValuedMatrix2D + Sized,
+    M::Value: Number + Finite + TotalOrd,
+    M::RowIndex: PositiveInteger,
+    M::ColumnIndex: AsPrimitive<usize> + Into<M::RowIndex>,
+{
+}
+
+/// Entry of the Dijkstra heap, popping the smallest distance first and then
+/// the smallest node.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct QueueEntry<V, I> {
+    /// Tentative distance of the node when the entry was pushed.
+    pub(crate) distance: V,
+    /// Identifier of the reached node.
+    pub(crate) node_id: I,
+}
+
+impl<V, I> PartialEq for QueueEntry<V, I>
+where
+    V: TotalOrd,
+    I: PositiveInteger,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.distance.total_cmp(&other.distance).is_eq() && self.node_id == other.node_id
+    }
+}
+
+impl<V, I> Eq for QueueEntry<V, I>
+where
+    V: TotalOrd,
+    I: PositiveInteger,
+{
+}
+
+impl<V, I> PartialOrd for QueueEntry<V, I>
+where
+    V: TotalOrd,
+    I: PositiveInteger,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<V, I> Ord for QueueEntry<V, I>
+where
+    V: TotalOrd,
+    I: PositiveInteger,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.distance.total_cmp(&self.distance).then_with(|| other.node_id.cmp(&self.node_id))
+    }
+}
+
+/// Hooks through which [`dijkstra_search`] reports its progress.
+///
+/// Every hook does nothing by default, so that a visitor only implements the
+/// events it follows.
+pub(crate) trait DijkstraVisitor<V: Zero, I> {
+    /// Error of the hooks, into which the errors of the search are converted.
+    type Error: From<DijkstraError>;
+
+    /// Returns the estimate of the remaining distance from the node, which
+    /// is added to its distance to prioritize it. Null estimates, the
+    /// default, yield Dijkstra's algorithm.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the estimate is invalid.
+    #[inline]
+    fn estimate(&mut self, _node_id: I) -> Result<V, Self::Error> {
+        Ok(V::zero())
+    }
+
+    /// Called when the node is popped at its distance, and returns whether
+    /// the search stops there.
+    #[inline]
+    fn settle(&mut self, _node_id: I, _distance: V) -> bool {
+        false
+    }
+
+    /// Called when an outdated heap entry is skipped.
+    #[inline]
+    fn skip(&mut self) {}
+
+    /// Called when the distance of the destination is lowered through the
+    /// edge from the node.
+    #[inline]
+    fn improve(&mut self, _destination_id: I, _node_id: I) {}
+
+    /// Called when the edge from the node yields another path to the
+    /// destination as short as its current one.
+    #[inline]
+    fn tie(&mut self, _destination_id: I, _node_id: I) {}
+}
+
+/// Visitor recording the predecessor of every node on its shortest path.
+struct PredecessorVisitor<I> {
+    /// The predecessor of every reached node other than the source.
+    predecessors: Vec<Option<I>>,
+}
+
+impl<V: Zero, I: PositiveInteger> DijkstraVisitor<V, I> for PredecessorVisitor<I> {
+    type Error = DijkstraError;
+
+    #[inline]
+    fn improve(&mut self, destination_id: I, node_id: I) {
+        self.predecessors[destination_id.as_()] = Some(node_id);
+    }
+}
+
+/// Runs a single-source search from the source, popping the nodes by
+/// increasing distance plus estimate, and fills the distance of every node it
+/// reaches.
+///
+/// The distances are expected to be `None` for every node, and the heap is
+/// cleared before the search starts. The weights of the edges leaving popped
+/// nodes are validated as the search goes.
+///
+/// # Errors
+///
+/// Returns an error if a weight reached by the search is not finite or is
+/// negative, if a tentative path distance or priority becomes non-finite, or
+/// if a hook of the visitor fails.
+pub(crate) fn dijkstra_search<M, Visitor>(
+    matrix: &M,
+    source_id: M::RowIndex,
+    distances: &mut [Option<M::Value>],
+    heap: &mut BinaryHeap<QueueEntry<M::Value, M::RowIndex>>,
+    visitor: &mut Visitor,
+) -> Result<(), Visitor::Error>
+where
+    M: SparseValuedMatrix2D,
+    M::Value: Number + Finite + TotalOrd,
+    M::RowIndex: PositiveInteger,
+    M::ColumnIndex: AsPrimitive<usize> + Into<M::RowIndex>,
+    Visitor: DijkstraVisitor<M::Value, M::RowIndex>,
+{
+    let zero = M::Value::zero();
+    distances[source_id.as_()] = Some(zero);
+    heap.clear();
+    heap.push(QueueEntry { distance: visitor.estimate(source_id)?, node_id: source_id });
+
+    while let Some(entry) = heap.pop() {
+        let node_id = entry.node_id;
+        let node = node_id.as_();
+        let distance =
+            distances[node].unwrap_or_else(|| unreachable!("Pushed nodes have a distance."));
+        if entry.distance.total_cmp(&(distance + visitor.estimate(node_id)?)).is_gt() {
+            visitor.skip();
+            continue;
+        }
+        if visitor.settle(node_id, distance) {
+            return Ok(());
+        }
+        for (destination_id, weight) in
+            matrix.sparse_row(node_id).zip(matrix.sparse_row_values(node_id))
+        {
+            let destination = destination_id.as_();
+            if !weight.is_finite() {
+                return Err(DijkstraError::NonFiniteWeight {
+                    source_id: node,
+                    destination_id: destination,
+                }
+                .into());
+            }
+            if weight < zero {
+                return Err(DijkstraError::NegativeWeight {
+                    source_id: node,
+                    destination_id: destination,
+                }
+                .into());
+            }
+            let candidate = distance + weight;
+            if !candidate.is_finite() {
+                return Err(DijkstraError::NonFiniteDistance {
+                    destination_id: destination,
+                    via_id: node,
+                }
+                .into());
+            }
+            let destination_id: M::RowIndex = destination_id.into();
+            match distances[destination].map(|current| candidate.total_cmp(&current)) {
+                Some(Ordering::Greater) => {}
+                Some(Ordering::Equal) => visitor.tie(destination_id, node_id),
+                Some(Ordering::Less) | None => {
+                    let priority = candidate + visitor.estimate(destination_id)?;
+                    if !priority.is_finite() {
+                        return Err(DijkstraError::NonFiniteDistance {
+                            destination_id: destination,
+                            via_id: node,
+                        }
+                        .into());
+                    }
+                    distances[destination] = Some(candidate);
+                    visitor.improve(destination_id, node_id);
+                    heap.push(QueueEntry { distance: priority, node_id: destination_id });
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
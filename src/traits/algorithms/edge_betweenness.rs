@@ -0,0 +1,344 @@
+//! Submodule providing the `EdgeBetweenness` trait, computing the shortest
+//! path betweenness of the edges of an undirected graph and the divisive
+//! Girvan-Newman clustering built upon it.
+//!
+//! The betweenness of an edge is the number of shortest paths between pairs
+//! of nodes that cross it, each path being weighted by the inverse of the
+//! number of shortest paths between its endpoints. It is accumulated with
+//! Brandes' dependency recursion, crediting each predecessor edge with its
+//! share of the dependency of the node it leads to. The Girvan-Newman
+//! algorithm repeatedly removes the edge of largest betweenness, so that the
+//! edges bridging communities, which carry most shortest paths, are removed
+//! first, and records in a [`Dendrogram`] how the connected components split.
+
+use alloc::{vec, vec::Vec};
+
+use num_traits::AsPrimitive;
+
+use super::node_ordering::BrandesWorkspace;
+use crate::{
+    impls::DisjointSet,
+    traits::{Dendrogram, DendrogramMerge, TryFromUsize, UndirectedMonopartiteMonoplexGraph},
+};
+
+/// Scale at which the betweenness values are rounded, so that the edges
+/// whose values only differ by rounding errors tie.
+const SCORE_SCALE: f64 = 1.0e12;
+
+/// Trait providing the edge betweenness and the Girvan-Newman clustering of
+/// undirected graphs.
+pub trait EdgeBetweenness: UndirectedMonopartiteMonoplexGraph
+where
+    Self::NodeId: AsPrimitive<usize> + TryFromUsize,
+{
+    /// Returns the betweenness of each edge, as `(source, destination,
+    /// betweenness)` triples with `source < destination`, sorted.
+    ///
+    /// This matches the unweighted `NetworkX` edge betweenness contract on
+    /// undirected graphs. Unnormalized values count the shortest paths
+    /// between unordered pairs of nodes; normalized values are divided by
+    /// the number n(n − 1)/2 of such pairs, as in `NetworkX`. Self-loops, which
+    /// lie on no shortest path, are omitted.
+    ///
+    /// # Arguments
+    ///
+    /// * `normalized`: Whether to normalize the betweenness values.
+    ///
+    /// # Complexity
+    ///
+    /// O(V · E) time and O(V + E) space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{
+    ///     impls::{CSR2D, SortedVec, SymmetricCSR2D},
+    ///     prelude::*,
+    ///     traits::{EdgeBetweenness, EdgesBuilder, VocabularyBuilder},
+    /// };
+    ///
+    /// let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+    ///     .expected_number_of_symbols(4)
+    ///     .symbols((0..4).enumerate())
+    ///     .build()
+    ///     .unwrap();
+    /// let edges: SymmetricCSR2D<CSR2D<usize, usize, usize>> = UndiEdgesBuilder::default()
+    ///     .expected_number_of_edges(3)
+    ///     .expected_shape(4)
+    ///     .edges(vec![(0, 1), (1, 2), (2, 3)].into_iter())
+    ///     .build()
+    ///     .unwrap();
+    /// let path: UndiGraph<usize> = UndiGraph::from((nodes, edges));
+    ///
+    /// // The middle edge of the path is crossed by the paths of four pairs.
+    /// assert_eq!(path.edge_betweenness(false), vec![(0, 1, 3.0), (1, 2, 4.0), (2, 3, 3.0)]);
+    /// ```
+    #[inline]
+    fn edge_betweenness(&self, normalized: bool) -> Vec<(Self::NodeId, Self::NodeId, f64)> {
+        let graph = EdgeGraph::new(self);
+        let number_of_nodes = graph.neighbors.len();
+        let mut scores = vec![0.0; graph.edges.len()];
+        let mut workspace = BrandesWorkspace::new(number_of_nodes);
+        for source in 0..number_of_nodes {
+            graph.accumulate(&mut workspace, source, &mut scores);
+        }
+
+        let scale = if normalized {
+            if number_of_nodes <= 1 {
+                1.0
+            } else {
+                1.0 / (usize_to_f64(number_of_nodes) * usize_to_f64(number_of_nodes - 1))
+            }
+        } else {
+            0.5
+        };
+        graph
+            .edges
+            .iter()
+            .zip(scores)
+            .map(|(&(source, destination), score)| {
+                (node_id::<Self>(source), node_id::<Self>(destination), round(score * scale))
+            })
+            .collect()
+    }
+
+    /// Returns the dendrogram of the Girvan-Newman divisive clustering.
+    ///
+    /// The edge of largest betweenness is removed until no edge is left,
+    /// ties being broken towards the smallest edge, and every removal that
+    /// disconnects a component splits it in two clusters. The dendrogram
+    /// lists these splits in reverse order, as the merges of an
+    /// agglomerative clustering whose height is the number of edges of the
+    /// graph before the removal splitting the clusters. Cutting the
+    /// dendrogram at height h thus returns the connected components once h
+    /// edges are left, and cutting it with [`DendrogramCut::Clusters`]
+    /// returns the partition reached when the graph first had the given
+    /// number of connected components. Clusters of distinct connected
+    /// components of the graph are never merged.
+    ///
+    /// [`DendrogramCut::Clusters`]: crate::traits::DendrogramCut::Clusters
+    ///
+    /// # Complexity
+    ///
+    /// O(V · E²) time, as the betweenness of the edges of the split
+    /// component is recomputed after each removal, and O(V + E) space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{
+    ///     impls::{CSR2D, SortedVec, SymmetricCSR2D},
+    ///     prelude::*,
+    ///     traits::{DendrogramCut, EdgeBetweenness, EdgesBuilder, VocabularyBuilder},
+    /// };
+    ///
+    /// let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+    ///     .expected_number_of_symbols(6)
+    ///     .symbols((0..6).enumerate())
+    ///     .build()
+    ///     .unwrap();
+    /// // Two triangles joined by the edge (2, 3).
+    /// let edges: SymmetricCSR2D<CSR2D<usize, usize, usize>> = UndiEdgesBuilder::default()
+    ///     .expected_number_of_edges(7)
+    ///     .expected_shape(6)
+    ///     .edges(vec![(0, 1), (0, 2), (1, 2), (2, 3), (3, 4), (3, 5), (4, 5)].into_iter())
+    ///     .build()
+    ///     .unwrap();
+    /// let graph: UndiGraph<usize> = UndiGraph::from((nodes, edges));
+    ///
+    /// let dendrogram = graph.girvan_newman();
+    /// assert_eq!(dendrogram.merges().len(), 5);
+    /// assert_eq!(
+    ///     dendrogram.cut::<usize>(DendrogramCut::Clusters(2)).unwrap(),
+    ///     vec![0, 0, 0, 1, 1, 1]
+    /// );
+    /// ```
+    #[inline]
+    fn girvan_newman(&self) -> Dendrogram {
+        let mut graph = EdgeGraph::new(self);
+        let number_of_nodes = graph.neighbors.len();
+        let mut scores = vec![0.0; graph.edges.len()];
+        let mut workspace = BrandesWorkspace::new(number_of_nodes);
+        for source in 0..number_of_nodes {
+            graph.accumulate(&mut workspace, source, &mut scores);
+        }
+
+        // Each split is stored as a node of each part, the number of edges
+        // before the removal and the size of the split component.
+        let mut splits: Vec<(usize, usize, usize, usize)> = Vec::new();
+        let mut is_removed = vec![false; graph.edges.len()];
+        let mut in_component = vec![false; number_of_nodes];
+        for number_of_edges in (1..=graph.edges.len()).rev() {
+            let mut removed_edge = None;
+            let mut largest_score = f64::NEG_INFINITY;
+            for (edge, &score) in scores.iter().enumerate() {
+                let score = round(score);
+                if !is_removed[edge] && score > largest_score {
+                    removed_edge = Some(edge);
+                    largest_score = score;
+                }
+            }
+            let Some(removed_edge) = removed_edge else { break };
+            is_removed[removed_edge] = true;
+            let (source, destination) = graph.edges[removed_edge];
+            graph.remove_edge(source, destination);
+
+            // Recomputes the betweenness within the component of the removed
+            // edge, or within both parts if the removal split it.
+            let source_component = graph.component(source);
+            let is_split = !source_component.contains(&destination);
+            let mut component = source_component;
+            if is_split {
+                let destination_component = graph.component(destination);
+                splits.push((
+                    source,
+                    destination,
+                    number_of_edges,
+                    component.len() + destination_component.len(),
+                ));
+                component.extend(destination_component);
+            }
+            for &node in &component {
+                in_component[node] = true;
+            }
+            for (edge, score) in scores.iter_mut().enumerate() {
+                if in_component[graph.edges[edge].0] {
+                    *score = 0.0;
+                }
+            }
+            for &node in &component {
+                graph.accumulate(&mut workspace, node, &mut scores);
+                in_component[node] = false;
+            }
+        }
+
+        // Replays the splits backwards as merges, identifying the clusters
+        // as in `SciPy` linkage matrices.
+        let mut sets: DisjointSet<usize> = DisjointSet::new(number_of_nodes);
+        let mut clusters: Vec<usize> = (0..number_of_nodes).collect();
+        let merges = splits
+            .into_iter()
+            .rev()
+            .enumerate()
+            .map(|(index, (source, destination, number_of_edges, size))| {
+                let left = clusters[sets.find(source)];
+                let right = clusters[sets.find(destination)];
+                sets.union(source, destination);
+                clusters[sets.find(source)] = number_of_nodes + index;
+                DendrogramMerge::new(left, right, usize_to_f64(number_of_edges), size)
+            })
+            .collect();
+        Dendrogram::new(number_of_nodes, merges)
+    }
+}
+
+impl<G> EdgeBetweenness for G
+where
+    G: UndirectedMonopartiteMonoplexGraph,
+    G::NodeId: AsPrimitive<usize> + TryFromUsize,
+{
+}
+
+/// Converts the count to `f64`.
+fn usize_to_f64(value: usize) -> f64 {
+    value.as_()
+}
+
+/// Rounds the betweenness value to [`SCORE_SCALE`].
+fn round(score: f64) -> f64 {
+    (score * SCORE_SCALE).round() / SCORE_SCALE
+}
+
+/// Converts the dense node index back to a node identifier.
+fn node_id<G>(index: usize) -> G::NodeId
+where
+    G: UndirectedMonopartiteMonoplexGraph + ?Sized,
+    G::NodeId: TryFromUsize,
+{
+    G::NodeId::try_from_usize(index)
+        .unwrap_or_else(|_| unreachable!("Node indices are node identifiers."))
+}
+
+/// Adjacency lists of the graph whose entries carry the index of the edge.
+struct EdgeGraph {
+    /// The neighbours of each node with the index of the connecting edge,
+    /// sorted by neighbour.
+    neighbors: Vec<Vec<(usize, usize)>>,
+    /// The edges as `(source, destination)` with `source < destination`,
+    /// sorted.
+    edges: Vec<(usize, usize)>,
+}
+
+impl EdgeGraph {
+    fn new<G>(graph: &G) -> Self
+    where
+        G: UndirectedMonopartiteMonoplexGraph + ?Sized,
+        G::NodeId: AsPrimitive<usize>,
+    {
+        let number_of_nodes = graph.number_of_nodes().as_();
+        debug_assert!(graph.node_ids().enumerate().all(|(index, node)| node.as_() == index));
+        let mut neighbors = vec![Vec::new(); number_of_nodes];
+        let mut edges = Vec::new();
+        for node in graph.node_ids() {
+            let source = node.as_();
+            for neighbor in graph.neighbors(node) {
+                let destination = neighbor.as_();
+                if source < destination {
+                    neighbors[source].push((destination, edges.len()));
+                    neighbors[destination].push((source, edges.len()));
+                    edges.push((source, destination));
+                }
+            }
+        }
+        for node_neighbors in &mut neighbors {
+            node_neighbors.sort_unstable();
+        }
+        Self { neighbors, edges }
+    }
+
+    /// Removes the edge between the two nodes.
+    fn remove_edge(&mut self, source: usize, destination: usize) {
+        self.neighbors[source].retain(|&(neighbor, _)| neighbor != destination);
+        self.neighbors[destination].retain(|&(neighbor, _)| neighbor != source);
+    }
+
+    /// Returns the nodes of the connected component of the node.
+    fn component(&self, node: usize) -> Vec<usize> {
+        let mut visited = vec![false; self.neighbors.len()];
+        visited[node] = true;
+        let mut component = vec![node];
+        let mut position = 0;
+        while let Some(&node) = component.get(position) {
+            position += 1;
+            for &(neighbor, _) in &self.neighbors[node] {
+                if !core::mem::replace(&mut visited[neighbor], true) {
+                    component.push(neighbor);
+                }
+            }
+        }
+        component
+    }
+
+    /// Runs the breadth-first search of Brandes' algorithm from the source
+    /// and adds the dependencies of the source on each edge to its score.
+    fn accumulate(&self, workspace: &mut BrandesWorkspace, source: usize, scores: &mut [f64]) {
+        workspace.breadth_first_search(source, |node| {
+            self.neighbors[node].iter().map(|&(neighbor, _)| neighbor)
+        });
+        workspace.accumulate_with_edges(
+            source,
+            false,
+            |_, _| {},
+            |predecessor, node, dependency| scores[self.edge(predecessor, node)] += dependency,
+        );
+    }
+
+    /// Returns the index of the edge between the two adjacent nodes.
+    fn edge(&self, source: usize, destination: usize) -> usize {
+        let neighbors = &self.neighbors[source];
+        let position = neighbors
+            .binary_search_by_key(&destination, |&(neighbor, _)| neighbor)
+            .unwrap_or_else(|_| unreachable!("The predecessors are neighbours."));
+        neighbors[position].1
+    }
+}
@@ -0,0 +1,323 @@
+//! Submodule providing the `EffectiveResistance` trait, approximating the
+//! effective resistances between the nodes of a weighted undirected graph
+//! with the random projection of Spielman and Srivastava.
+//!
+//! With B the signed edge-node incidence matrix, W the diagonal matrix of the
+//! edge weights and L = BᵀWB the Laplacian, the effective resistance between
+//! u and v is ‖W^{1/2} B L⁺ (e_u − e_v)‖². Projecting the columns of
+//! W^{1/2} B L⁺ onto k random ±1/√k directions preserves these squared
+//! distances in expectation, with a relative standard deviation of at most
+//! √(2/k), as in the Johnson–Lindenstrauss lemma. Each projection costs a
+//! single Laplacian solve, computed with the conjugate gradient method.
+
+use alloc::{vec, vec::Vec};
+
+use num_traits::{AsPrimitive, ToPrimitive};
+use rand::{Rng, SeedableRng, rngs::SmallRng};
+
+use super::laplacian::sparse_matrix_vector_product;
+use crate::{
+    impls::{DisjointSet, ValuedCSR2D},
+    traits::{Laplacian, LaplacianError, Matrix2D, SparseMatrix2D, SparseValuedMatrix2D},
+};
+
+/// Configuration of the effective resistance approximation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EffectiveResistanceConfig {
+    /// Number k of random projections, trading time for accuracy.
+    /// Default: `64`.
+    pub sketch_size: usize,
+    /// Relative residual at which each conjugate gradient solve stops.
+    /// Default: `1e-8`.
+    pub tolerance: f64,
+    /// Maximum number of conjugate gradient iterations per solve.
+    /// Default: `1000`.
+    pub max_iterations: usize,
+    /// Random seed of the projections. Default: `42`.
+    pub seed: u64,
+}
+
+impl Default for EffectiveResistanceConfig {
+    #[inline]
+    fn default() -> Self {
+        Self { sketch_size: 64, tolerance: 1e-8, max_iterations: 1000, seed: 42 }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+/// Error enumeration for the effective resistance approximation.
+pub enum EffectiveResistanceError {
+    /// The Laplacian of the matrix could not be built.
+    #[error(transparent)]
+    Laplacian(#[from] LaplacianError),
+    /// The number of random projections is zero.
+    #[error("The sketch size must be positive.")]
+    ZeroSketchSize,
+    /// The tolerance of the solver is not positive or not finite.
+    #[error("The tolerance must be finite and positive, but received {0}.")]
+    InvalidTolerance(f64),
+    /// A Laplacian solve did not reach the tolerance.
+    #[error("The conjugate gradient did not converge within {iterations} iterations.")]
+    DidNotConverge {
+        /// Maximum number of iterations of the solve.
+        iterations: usize,
+    },
+}
+
+/// Approximate effective resistances of a weighted undirected graph, stored
+/// as a low-dimensional embedding of its nodes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EffectiveResistances {
+    /// Number of random projections.
+    sketch_size: usize,
+    /// Coordinates of each node in the projection, node-major.
+    embedding: Vec<f64>,
+    /// Connected component of each node.
+    components: Vec<usize>,
+    /// Edges of the graph as `(source, destination, weight)`, with
+    /// `source < destination`, sorted.
+    edges: Vec<(usize, usize, f64)>,
+}
+
+impl EffectiveResistances {
+    /// Returns the number of nodes of the graph.
+    #[inline]
+    #[must_use]
+    pub fn number_of_nodes(&self) -> usize {
+        self.components.len()
+    }
+
+    /// Returns the number of random projections.
+    #[inline]
+    #[must_use]
+    pub fn sketch_size(&self) -> usize {
+        self.sketch_size
+    }
+
+    /// Returns the approximate effective resistance between two nodes.
+    ///
+    /// The resistance of a node with itself is zero, and the one between
+    /// nodes of distinct connected components is infinite.
+    ///
+    /// # Arguments
+    ///
+    /// * `source`: The first node.
+    /// * `destination`: The second node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either node is not a node of the graph.
+    #[inline]
+    #[must_use]
+    pub fn resistance(&self, source: usize, destination: usize) -> f64 {
+        if self.components[source] != self.components[destination] {
+            return f64::INFINITY;
+        }
+        let source = &self.embedding[source * self.sketch_size..][..self.sketch_size];
+        let destination = &self.embedding[destination * self.sketch_size..][..self.sketch_size];
+        source.iter().zip(destination).map(|(left, right)| (left - right) * (left - right)).sum()
+    }
+
+    /// Returns the edges of the graph as `(source, destination, weight)`
+    /// triples, with `source < destination`, sorted. Self-loops are omitted.
+    #[inline]
+    #[must_use]
+    pub fn edges(&self) -> &[(usize, usize, f64)] {
+        &self.edges
+    }
+
+    /// Returns the importance of each edge, in the order of [`Self::edges`],
+    /// as `(source, destination, importance)` triples.
+    ///
+    /// The importance of an edge is its weight times the effective
+    /// resistance between its endpoints, i.e. its leverage score: it lies in
+    /// (0, 1], equals one on bridges, and the importances sum to the number
+    /// of nodes minus the number of connected components. Edges of high
+    /// importance are the ones a spectral sparsifier must keep.
+    #[inline]
+    pub fn edge_importances(&self) -> impl Iterator<Item = (usize, usize, f64)> + '_ {
+        self.edges.iter().map(|&(source, destination, weight)| {
+            (source, destination, weight * self.resistance(source, destination))
+        })
+    }
+}
+
+/// Trait providing the approximate effective resistances of the weighted
+/// undirected graph represented by a square and symmetric sparse matrix of
+/// finite and non-negative weights.
+pub trait EffectiveResistance: Laplacian
+where
+    Self::RowIndex: AsPrimitive<usize>,
+    Self::ColumnIndex: AsPrimitive<usize>,
+    Self::Value: ToPrimitive,
+{
+    /// Returns the approximate effective resistances between the nodes of
+    /// the graph.
+    ///
+    /// # Arguments
+    ///
+    /// * `config`: The number of projections, the solver settings and the seed.
+    ///
+    /// # Errors
+    ///
+    /// * [`EffectiveResistanceError::Laplacian`] if the matrix is not a valid
+    ///   weighted adjacency matrix.
+    /// * [`EffectiveResistanceError::ZeroSketchSize`] if the number of
+    ///   projections is zero.
+    /// * [`EffectiveResistanceError::InvalidTolerance`] if the tolerance is not
+    ///   finite and positive.
+    /// * [`EffectiveResistanceError::DidNotConverge`] if a Laplacian solve did
+    ///   not reach the tolerance within the maximum number of iterations.
+    ///
+    /// # Complexity
+    ///
+    /// O(k · I · (V + E)) time, with I the number of conjugate gradient
+    /// iterations per solve, and O(k · V + E) space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{
+    ///     impls::{SymmetricCSR2D, ValuedCSR2D},
+    ///     prelude::*,
+    ///     traits::{EffectiveResistance, EffectiveResistanceConfig},
+    /// };
+    ///
+    /// // A triangle with unit weights and a pendant node.
+    /// let adjacency: SymmetricCSR2D<ValuedCSR2D<usize, usize, usize, f64>> =
+    ///     SymmetricCSR2D::from_sorted_upper_triangular_entries(
+    ///         4,
+    ///         vec![(0, 1, 1.0), (0, 2, 1.0), (1, 2, 1.0), (2, 3, 1.0)],
+    ///     )
+    ///     .unwrap();
+    ///
+    /// let config = EffectiveResistanceConfig { sketch_size: 400, ..Default::default() };
+    /// let resistances = adjacency.effective_resistances(&config).unwrap();
+    ///
+    /// // Two paths of resistance 1 and 2 in parallel, then a bridge.
+    /// assert!((resistances.resistance(0, 1) - 2.0 / 3.0).abs() < 0.2);
+    /// assert!((resistances.resistance(2, 3) - 1.0).abs() < 0.3);
+    /// assert_eq!(resistances.resistance(3, 3), 0.0);
+    /// ```
+    #[inline]
+    fn effective_resistances(
+        &self,
+        config: &EffectiveResistanceConfig,
+    ) -> Result<EffectiveResistances, EffectiveResistanceError> {
+        if config.sketch_size == 0 {
+            return Err(EffectiveResistanceError::ZeroSketchSize);
+        }
+        if !config.tolerance.is_finite() || config.tolerance <= 0.0 {
+            return Err(EffectiveResistanceError::InvalidTolerance(config.tolerance));
+        }
+        let laplacian = self.laplacian()?;
+        let number_of_nodes = laplacian.number_of_rows();
+        let edges: Vec<(usize, usize, f64)> = laplacian
+            .row_indices()
+            .flat_map(|row| {
+                laplacian
+                    .sparse_row(row)
+                    .zip(laplacian.sparse_row_values(row))
+                    .filter(move |&(column, _)| column > row)
+                    .map(move |(column, value)| (row, column, -value))
+            })
+            .collect();
+
+        let sketch_size = config.sketch_size;
+        let scale = 1.0 / as_f64(sketch_size).sqrt();
+        let scaled_weights: Vec<f64> =
+            edges.iter().map(|&(_, _, weight)| scale * weight.sqrt()).collect();
+        let mut rng = SmallRng::seed_from_u64(config.seed);
+        let mut embedding = vec![0.0; number_of_nodes * sketch_size];
+        let mut projection = vec![0.0; number_of_nodes];
+        for coordinate in 0..sketch_size {
+            // Projects the rows of W^{1/2} B onto a random direction, which
+            // sums to zero on every connected component.
+            projection.fill(0.0);
+            for (&(source, destination, _), &scaled_weight) in edges.iter().zip(&scaled_weights) {
+                let value = if rng.gen_bool(0.5) { scaled_weight } else { -scaled_weight };
+                projection[source] += value;
+                projection[destination] -= value;
+            }
+            let solution = conjugate_gradient(&laplacian, &projection, config)?;
+            for (node, value) in solution.into_iter().enumerate() {
+                embedding[node * sketch_size + coordinate] = value;
+            }
+        }
+
+        Ok(EffectiveResistances {
+            sketch_size,
+            embedding,
+            components: connected_components(number_of_nodes, &edges),
+            edges,
+        })
+    }
+}
+
+impl<M> EffectiveResistance for M
+where
+    M: Laplacian,
+    M::RowIndex: AsPrimitive<usize>,
+    M::ColumnIndex: AsPrimitive<usize>,
+    M::Value: ToPrimitive,
+{
+}
+
+/// Converts the count to `f64`.
+fn as_f64(count: usize) -> f64 {
+    count.as_()
+}
+
+/// Returns the dot product of the two vectors.
+fn dot(left: &[f64], right: &[f64]) -> f64 {
+    left.iter().zip(right).map(|(left, right)| left * right).sum()
+}
+
+/// Solves L x = b with the conjugate gradient method, for a right-hand side
+/// summing to zero on every connected component.
+fn conjugate_gradient(
+    laplacian: &ValuedCSR2D<usize, usize, usize, f64>,
+    right_hand_side: &[f64],
+    config: &EffectiveResistanceConfig,
+) -> Result<Vec<f64>, EffectiveResistanceError> {
+    let threshold = config.tolerance * config.tolerance * dot(right_hand_side, right_hand_side);
+    let mut solution = vec![0.0; right_hand_side.len()];
+    let mut residual = right_hand_side.to_vec();
+    let mut direction = residual.clone();
+    let mut product = vec![0.0; right_hand_side.len()];
+    let mut residual_norm = dot(&residual, &residual);
+    for _ in 0..config.max_iterations {
+        if residual_norm <= threshold {
+            return Ok(solution);
+        }
+        sparse_matrix_vector_product(laplacian, &direction, &mut product);
+        let step = residual_norm / dot(&direction, &product);
+        for ((value, residual), (direction, product)) in
+            solution.iter_mut().zip(&mut residual).zip(direction.iter().zip(&product))
+        {
+            *value += step * direction;
+            *residual -= step * product;
+        }
+        let next_residual_norm = dot(&residual, &residual);
+        let ratio = next_residual_norm / residual_norm;
+        for (direction, residual) in direction.iter_mut().zip(&residual) {
+            *direction = residual + ratio * *direction;
+        }
+        residual_norm = next_residual_norm;
+    }
+    if residual_norm <= threshold {
+        Ok(solution)
+    } else {
+        Err(EffectiveResistanceError::DidNotConverge { iterations: config.max_iterations })
+    }
+}
+
+/// Returns the connected component of each node, labeled by the
+/// representative of its set.
+fn connected_components(number_of_nodes: usize, edges: &[(usize, usize, f64)]) -> Vec<usize> {
+    let mut sets: DisjointSet<usize> = DisjointSet::new(number_of_nodes);
+    for &(source, destination, _) in edges {
+        sets.union(source, destination);
+    }
+    (0..number_of_nodes).map(|node| sets.find(node)).collect()
+}
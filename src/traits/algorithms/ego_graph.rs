@@ -0,0 +1,175 @@
+//! Submodule providing the `EgoGraph` trait, which extracts the subgraph
+//! induced by the nodes within a given number of hops from a center node.
+use alloc::{collections::VecDeque, vec::Vec};
+
+use num_traits::AsPrimitive;
+
+use super::pairwise_bfs::breadth_first_distances;
+use crate::{
+    impls::{CSR2D, SquareCSR2D},
+    traits::{Edges, MonoplexMonopartiteGraph},
+};
+
+/// Result of extracting an ego graph.
+///
+/// Contains the induced subgraph, whose nodes are renumbered from zero in the
+/// increasing order of the original nodes, with the original node and the
+/// distance from the center of each of them.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EgoGraphResult<M, NodeId> {
+    graph: M,
+    node_map: Vec<NodeId>,
+    distances: Vec<usize>,
+}
+
+impl<M, NodeId: Copy + Ord> EgoGraphResult<M, NodeId> {
+    /// Returns a reference to the induced subgraph.
+    #[inline]
+    pub fn graph(&self) -> &M {
+        &self.graph
+    }
+
+    /// Consumes the result and returns the induced subgraph.
+    #[inline]
+    pub fn into_graph(self) -> M {
+        self.graph
+    }
+
+    /// Returns the mapping from the subgraph nodes to the original nodes,
+    /// which is sorted.
+    #[inline]
+    pub fn node_map(&self) -> &[NodeId] {
+        &self.node_map
+    }
+
+    /// Returns the number of nodes in the subgraph.
+    #[inline]
+    pub fn number_of_nodes(&self) -> usize {
+        self.node_map.len()
+    }
+
+    /// Returns the original node corresponding to a node of the subgraph.
+    #[inline]
+    pub fn original_node(&self, node: usize) -> NodeId {
+        self.node_map[node]
+    }
+
+    /// Returns the node of the subgraph corresponding to an original node,
+    /// or `None` if the original node is farther than the radius.
+    ///
+    /// # Complexity
+    ///
+    /// O(log N) time for a subgraph of N nodes.
+    #[inline]
+    pub fn subgraph_node(&self, original_node: NodeId) -> Option<usize> {
+        self.node_map.binary_search(&original_node).ok()
+    }
+
+    /// Returns the number of hops from the center to a node of the subgraph.
+    #[inline]
+    pub fn distance(&self, node: usize) -> usize {
+        self.distances[node]
+    }
+}
+
+/// Trait providing the ego graphs of monoplex monopartite graphs.
+pub trait EgoGraph: MonoplexMonopartiteGraph {
+    /// Returns the subgraph induced by the nodes reachable from the center
+    /// in at most `radius` hops, the center included.
+    ///
+    /// Hops follow the edges from their source to their destination, so that
+    /// the ego graph of an undirected graph holds its nodes within the radius
+    /// in either direction. The subgraph keeps every edge of the graph
+    /// between its nodes, self-loops included.
+    ///
+    /// # Arguments
+    ///
+    /// * `center`: The node around which the subgraph is extracted.
+    /// * `radius`: The maximum number of hops from the center.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the center is not a node of the graph.
+    ///
+    /// # Complexity
+    ///
+    /// O(V + E') time and O(V) space, where E' is the number of edges leaving
+    /// the nodes of the subgraph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{
+    ///     impls::{SortedVec, SymmetricCSR2D},
+    ///     prelude::*,
+    ///     traits::{EgoGraph, VocabularyBuilder},
+    /// };
+    ///
+    /// let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+    ///     .expected_number_of_symbols(5)
+    ///     .symbols((0..5).enumerate())
+    ///     .build()
+    ///     .unwrap();
+    /// let edges: SymmetricCSR2D<_> = UndiEdgesBuilder::default()
+    ///     .expected_number_of_edges(4)
+    ///     .expected_shape(5)
+    ///     .edges(vec![(0, 1), (1, 2), (2, 3), (3, 4)].into_iter())
+    ///     .build()
+    ///     .unwrap();
+    /// let path: UndiGraph<usize> = UndiGraph::from((nodes, edges));
+    ///
+    /// let ego = path.ego_graph(2, 1);
+    /// assert_eq!(ego.node_map(), &[1, 2, 3]);
+    /// assert_eq!(ego.distance(0), 1);
+    /// assert_eq!(ego.subgraph_node(3), Some(2));
+    /// assert_eq!(ego.subgraph_node(4), None);
+    /// assert_eq!(
+    ///     SparseMatrix::sparse_coordinates(ego.graph()).collect::<Vec<_>>(),
+    ///     vec![(0, 1), (1, 0), (1, 2), (2, 1)]
+    /// );
+    /// ```
+    #[inline]
+    fn ego_graph(
+        &self,
+        center: Self::NodeId,
+        radius: usize,
+    ) -> EgoGraphResult<SquareCSR2D<CSR2D<usize, usize, usize>>, Self::NodeId> {
+        let number_of_nodes: usize = self.number_of_nodes().as_();
+        assert!(
+            center.as_() < number_of_nodes,
+            "The center must be a node of the graph, which has {number_of_nodes} nodes."
+        );
+
+        let mut all_distances = vec![None; number_of_nodes];
+        let mut queue = VecDeque::new();
+        breadth_first_distances(
+            self.edges().matrix(),
+            center,
+            radius,
+            &mut all_distances,
+            &mut queue,
+        );
+
+        let (node_map, distances): (Vec<Self::NodeId>, Vec<usize>) = self
+            .node_ids()
+            .filter_map(|node| all_distances[node.as_()].map(|distance| (node, distance)))
+            .unzip();
+
+        // The subgraph nodes follow the original ones, so that the edges are
+        // generated sorted.
+        let mut edges: Vec<(usize, usize)> = Vec::new();
+        for (source, &node) in node_map.iter().enumerate() {
+            edges.extend(self.successors(node).filter_map(|successor| {
+                node_map.binary_search(&successor).ok().map(|destination| (source, destination))
+            }));
+        }
+
+        let graph = crate::traits::algorithms::randomized_graphs::builder_utils::build_directed(
+            node_map.len(),
+            edges,
+        );
+        EgoGraphResult { graph, node_map, distances }
+    }
+}
+
+impl<G: MonoplexMonopartiteGraph> EgoGraph for G {}
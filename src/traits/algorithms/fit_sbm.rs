@@ -0,0 +1,641 @@
+//! Submodule providing the `FitSbm` trait, inferring a degree-corrected
+//! stochastic block model from an observed weighted undirected graph.
+//!
+//! The blocks maximize the profile log-likelihood of Karrer and Newman,
+//! `L = Σ_rs m_rs ln(m_rs / (κ_r κ_s))`, where `m_rs` is the weight between
+//! the blocks `r` and `s`, counted in both directions, and `κ_r` the sum of
+//! the degrees of the block `r`. Starting from one block per node, the blocks
+//! are merged agglomeratively, each round merging the pairs with the largest
+//! likelihood gains until half of the blocks are left, and the last pairs one
+//! at a time, down to the requested number of blocks. The nodes are then moved
+//! one at a time to the block that most increases the likelihood until no move
+//! improves it.
+
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+
+use num_traits::{AsPrimitive, ToPrimitive};
+use rand::{SeedableRng, rngs::SmallRng, seq::SliceRandom};
+
+use super::modularity::{
+    ModularityError, WeightedUndirectedGraph, marker_partition, mix_seed, modularity,
+    renumber_partition,
+};
+use crate::traits::{Finite, Number, PositiveInteger, SparseValuedMatrix2D};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Configuration options for fitting a stochastic block model.
+pub struct SbmConfig {
+    /// Number of blocks of the fitted model.
+    pub number_of_blocks: usize,
+    /// Maximum number of node-moving passes after the agglomeration.
+    pub max_refinement_passes: usize,
+    /// Random seed used for node-order shuffling.
+    pub seed: u64,
+}
+
+impl Default for SbmConfig {
+    #[inline]
+    fn default() -> Self {
+        Self { number_of_blocks: 2, max_refinement_passes: 100, seed: 42 }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Result of fitting a degree-corrected stochastic block model.
+pub struct SbmResult<Marker> {
+    /// Block identifier for each node.
+    partition: Vec<Marker>,
+    /// Weights between the blocks, in row-major order.
+    block_weights: Vec<f64>,
+    /// Sum of the degrees of each block.
+    block_degrees: Vec<f64>,
+    /// Degree of each node divided by the degree of its block.
+    degree_propensities: Vec<f64>,
+    /// Profile log-likelihood of the partition.
+    log_likelihood: f64,
+    /// Modularity of the partition.
+    modularity: f64,
+    /// Number of node moves performed by the refinement.
+    moved_nodes: usize,
+}
+
+impl<Marker> SbmResult<Marker> {
+    /// Returns the block of each node.
+    #[must_use]
+    #[inline]
+    pub fn partition(&self) -> &[Marker] {
+        &self.partition
+    }
+
+    /// Returns the number of blocks.
+    #[must_use]
+    #[inline]
+    pub fn number_of_blocks(&self) -> usize {
+        self.block_degrees.len()
+    }
+
+    /// Returns the total weight of the edges between the two blocks, counted
+    /// in both directions, so that the weight of the edges within a block is
+    /// counted twice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either block is out of range.
+    #[must_use]
+    #[inline]
+    pub fn block_weight(&self, left: usize, right: usize) -> f64 {
+        assert!(left < self.number_of_blocks() && right < self.number_of_blocks());
+        self.block_weights[left * self.number_of_blocks() + right]
+    }
+
+    /// Returns the sum of the degrees of the nodes of each block.
+    #[must_use]
+    #[inline]
+    pub fn block_degrees(&self) -> &[f64] {
+        &self.block_degrees
+    }
+
+    /// Returns the degree of each node divided by the degree of its block,
+    /// which sum to one over the nodes of each block with edges.
+    #[must_use]
+    #[inline]
+    pub fn degree_propensities(&self) -> &[f64] {
+        &self.degree_propensities
+    }
+
+    /// Returns the maximized profile log-likelihood of the model.
+    #[must_use]
+    #[inline]
+    pub fn log_likelihood(&self) -> f64 {
+        self.log_likelihood
+    }
+
+    /// Returns the modularity of the partition, at resolution one.
+    #[must_use]
+    #[inline]
+    pub fn modularity(&self) -> f64 {
+        self.modularity
+    }
+
+    /// Returns the number of node moves performed by the refinement.
+    #[must_use]
+    #[inline]
+    pub fn moved_nodes(&self) -> usize {
+        self.moved_nodes
+    }
+}
+
+impl<Marker: AsPrimitive<usize>> SbmResult<Marker> {
+    /// Returns the expected weight between the two nodes under the fitted
+    /// model, `θ_u θ_v m_rs` for the nodes `u` and `v` of the blocks `r` and
+    /// `s`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either node is out of range.
+    #[must_use]
+    #[inline]
+    pub fn expected_weight(&self, left: usize, right: usize) -> f64 {
+        self.degree_propensities[left]
+            * self.degree_propensities[right]
+            * self.block_weight(self.partition[left].as_(), self.partition[right].as_())
+    }
+}
+
+/// Trait providing the inference of degree-corrected stochastic block models.
+///
+/// The graph is expected to be represented by a weighted, square matrix with
+/// symmetric entries (undirected weighted graph).
+pub trait FitSbm<Marker: AsPrimitive<usize> + PositiveInteger = usize>:
+    SparseValuedMatrix2D + Sized
+where
+    Self::RowIndex: AsPrimitive<usize>,
+    Self::ColumnIndex: AsPrimitive<usize>,
+    Self::Value: Number + ToPrimitive + Finite,
+{
+    /// Fits a degree-corrected stochastic block model with the provided
+    /// configuration.
+    ///
+    /// Unlike modularity maximization, the number of blocks is fixed and the
+    /// blocks need not be assortative: bipartite or core-periphery structures
+    /// are found as well as communities. The degree correction prevents the
+    /// blocks from merely separating the nodes by degree.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when:
+    /// - the number of blocks is zero or larger than the number of nodes;
+    /// - the maximum number of refinement passes is zero;
+    /// - the matrix is not square or not symmetric;
+    /// - at least one weight is non-finite or non-positive;
+    /// - the number of blocks cannot fit into `Marker`.
+    ///
+    /// # Complexity
+    ///
+    /// O((log(V) + K) · Σ d²) time for the agglomeration, where d are the
+    /// numbers of neighbouring blocks, plus O(P · V · K · min(D, K)) time for
+    /// the refinement, where P is the number of passes, K the number of
+    /// blocks and D the maximal degree, and O(V + E + K²) space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{impls::ValuedCSR2D, prelude::*, traits::SbmConfig};
+    ///
+    /// // Two triangles joined by a single edge.
+    /// let mut edges = Vec::new();
+    /// for (source, destination) in [(0, 1), (0, 2), (1, 2), (3, 4), (3, 5), (4, 5), (2, 3)] {
+    ///     edges.push((source, destination, 1.0));
+    ///     edges.push((destination, source, 1.0));
+    /// }
+    /// edges.sort_unstable_by_key(|&(source, destination, _)| (source, destination));
+    /// let graph: ValuedCSR2D<usize, usize, usize, f64> =
+    ///     GenericEdgesBuilder::<_, ValuedCSR2D<usize, usize, usize, f64>>::default()
+    ///         .expected_number_of_edges(edges.len())
+    ///         .expected_shape((6, 6))
+    ///         .edges(edges.into_iter())
+    ///         .build()
+    ///         .unwrap();
+    ///
+    /// let result = FitSbm::<usize>::fit_sbm(&graph, &SbmConfig::default()).unwrap();
+    /// assert_eq!(result.partition(), &[0, 0, 0, 1, 1, 1]);
+    /// assert_eq!(result.block_weight(0, 1), 1.0);
+    /// assert_eq!(result.block_weight(0, 0), 6.0);
+    /// ```
+    #[inline]
+    fn fit_sbm(&self, config: &SbmConfig) -> Result<SbmResult<Marker>, ModularityError> {
+        if config.max_refinement_passes == 0 {
+            return Err(ModularityError::InvalidMaxRefinementPasses);
+        }
+        let graph = WeightedUndirectedGraph::from_matrix(self)?;
+        let number_of_nodes = graph.number_of_nodes();
+        let number_of_blocks = config.number_of_blocks;
+        if number_of_blocks == 0 || number_of_blocks > number_of_nodes {
+            return Err(ModularityError::InvalidNumberOfBlocks {
+                number_of_blocks,
+                number_of_nodes,
+            });
+        }
+
+        let mut partition = agglomerate(&graph, number_of_blocks);
+        renumber_partition(&mut partition);
+        let mut blocks = BlockModel::new(&graph, &partition, number_of_blocks);
+        let moved_nodes = blocks.refine(&graph, &mut partition, config);
+
+        let degree_propensities = graph
+            .degree
+            .iter()
+            .zip(&partition)
+            .map(|(&degree, &block)| {
+                let block_degree = blocks.degrees[block];
+                if block_degree > 0.0 { degree / block_degree } else { 0.0 }
+            })
+            .collect();
+        Ok(SbmResult {
+            partition: marker_partition::<Marker>(&partition)?,
+            log_likelihood: blocks.log_likelihood(),
+            modularity: modularity(&graph, &partition, 1.0),
+            block_weights: blocks.weights,
+            block_degrees: blocks.degrees,
+            degree_propensities,
+            moved_nodes,
+        })
+    }
+}
+
+impl<G, Marker> FitSbm<Marker> for G
+where
+    G: SparseValuedMatrix2D + Sized,
+    Marker: AsPrimitive<usize> + PositiveInteger,
+    G::RowIndex: AsPrimitive<usize>,
+    G::ColumnIndex: AsPrimitive<usize>,
+    G::Value: Number + ToPrimitive + Finite,
+{
+}
+
+/// Returns `x ln x`, extended by continuity to zero.
+fn entropy_term(value: f64) -> f64 {
+    if value > 0.0 { value * value.ln() } else { 0.0 }
+}
+
+/// Sparse block statistics of the agglomeration, indexed by the node that
+/// started each block.
+struct Agglomeration {
+    /// Nodes of each block, empty once the block is merged into another.
+    members: Vec<Vec<usize>>,
+    /// Sum of the degrees of each block.
+    degrees: Vec<f64>,
+    /// Weight within each block, counted in both directions.
+    internal: Vec<f64>,
+    /// Weight from each block to the other blocks it is linked to.
+    links: Vec<BTreeMap<usize, f64>>,
+}
+
+impl Agglomeration {
+    fn new(graph: &WeightedUndirectedGraph) -> Self {
+        let number_of_nodes = graph.number_of_nodes();
+        let mut internal = vec![0.0; number_of_nodes];
+        let mut links: Vec<BTreeMap<usize, f64>> = vec![BTreeMap::new(); number_of_nodes];
+        for (source, neighbors) in graph.adjacency.iter().enumerate() {
+            for &(destination, weight) in neighbors {
+                if source == destination {
+                    internal[source] += weight;
+                } else {
+                    links[source].insert(destination, weight);
+                }
+            }
+        }
+        Self {
+            members: (0..number_of_nodes).map(|node| vec![node]).collect(),
+            degrees: graph.degree.clone(),
+            internal,
+            links,
+        }
+    }
+
+    /// Returns the change of the log-likelihood when merging the two blocks.
+    fn merge_gain(&self, left: usize, right: usize) -> f64 {
+        let between = self.links[left].get(&right).copied().unwrap_or(0.0);
+        let mut gain = entropy_term(self.internal[left] + self.internal[right] + 2.0 * between)
+            - entropy_term(self.internal[left])
+            - entropy_term(self.internal[right])
+            - 2.0 * entropy_term(between);
+        // Only the blocks linked to both change the sum of their terms.
+        let (smaller, larger) = if self.links[left].len() <= self.links[right].len() {
+            (left, right)
+        } else {
+            (right, left)
+        };
+        for (&other, &weight) in &self.links[smaller] {
+            if other == larger {
+                continue;
+            }
+            if let Some(&other_weight) = self.links[larger].get(&other) {
+                gain += 2.0
+                    * (entropy_term(weight + other_weight)
+                        - entropy_term(weight)
+                        - entropy_term(other_weight));
+            }
+        }
+        gain - 2.0
+            * (entropy_term(self.degrees[left] + self.degrees[right])
+                - entropy_term(self.degrees[left])
+                - entropy_term(self.degrees[right]))
+    }
+
+    /// Returns the best merge of the block, among the blocks it is linked to
+    /// and the blocks linked to its most strongly linked block, which are the
+    /// candidates for disassortative structures. Blocks without links are
+    /// merged with the lightest blocks, which costs the least likelihood.
+    fn best_merge(&self, block: usize, lightest: &[usize]) -> Option<(f64, usize)> {
+        let Some((&strongest, _)) =
+            self.links[block].iter().max_by(|left, right| left.1.total_cmp(right.1))
+        else {
+            return lightest
+                .iter()
+                .copied()
+                .find(|&other| other != block)
+                .map(|other| (self.merge_gain(block, other), other));
+        };
+        self.links[block]
+            .keys()
+            .chain(self.links[strongest].keys())
+            .copied()
+            .filter(|&other| other != block)
+            .map(|other| (self.merge_gain(block, other), other))
+            .max_by(|left, right| left.0.total_cmp(&right.0).then(right.1.cmp(&left.1)))
+    }
+
+    /// Merges the removed block into the kept block.
+    fn merge(&mut self, kept: usize, removed: usize) {
+        let between = self.links[kept].remove(&removed).unwrap_or(0.0);
+        self.internal[kept] += self.internal[removed] + 2.0 * between;
+        self.degrees[kept] += self.degrees[removed];
+        for (other, weight) in core::mem::take(&mut self.links[removed]) {
+            if other == kept {
+                continue;
+            }
+            self.links[other].remove(&removed);
+            *self.links[other].entry(kept).or_insert(0.0) += weight;
+            *self.links[kept].entry(other).or_insert(0.0) += weight;
+        }
+        let removed_members = core::mem::take(&mut self.members[removed]);
+        self.members[kept].extend(removed_members);
+    }
+}
+
+/// Merges the blocks of the nodes, starting from one block per node, down to
+/// the requested number of blocks, and returns the block of each node.
+fn agglomerate(graph: &WeightedUndirectedGraph, number_of_blocks: usize) -> Vec<usize> {
+    let number_of_nodes = graph.number_of_nodes();
+    let mut agglomeration = Agglomeration::new(graph);
+    let mut alive: Vec<usize> = (0..number_of_nodes).collect();
+    let mut is_merged = vec![false; number_of_nodes];
+    while alive.len() > number_of_blocks {
+        let mut lightest: Vec<usize> = alive.clone();
+        lightest.sort_by(|&left, &right| {
+            agglomeration.degrees[left].total_cmp(&agglomeration.degrees[right])
+        });
+        lightest.truncate(2);
+
+        let mut candidates: Vec<(f64, usize, usize)> = alive
+            .iter()
+            .filter_map(|&block| {
+                agglomeration
+                    .best_merge(block, &lightest)
+                    .map(|(gain, other)| (gain, block.min(other), block.max(other)))
+            })
+            .collect();
+        candidates.sort_by(|left, right| {
+            right.0.total_cmp(&left.0).then((left.1, left.2).cmp(&(right.1, right.2)))
+        });
+
+        // The gains of a round go stale as its merges change the blocks, so
+        // the last merges are taken one at a time with fresh gains.
+        let number_of_merges = if alive.len() <= 2 * number_of_blocks {
+            1
+        } else {
+            alive.len() - number_of_blocks.max(alive.len().div_ceil(2))
+        };
+        let mut merges = 0;
+        for (_, kept, removed) in candidates {
+            if merges == number_of_merges {
+                break;
+            }
+            if is_merged[kept] || is_merged[removed] {
+                continue;
+            }
+            is_merged[kept] = true;
+            is_merged[removed] = true;
+            agglomeration.merge(kept, removed);
+            merges += 1;
+        }
+        alive.retain(|&block| !agglomeration.members[block].is_empty());
+        for &block in &alive {
+            is_merged[block] = false;
+        }
+    }
+
+    let mut partition = vec![0; number_of_nodes];
+    for (block, members) in agglomeration.members.iter().enumerate() {
+        for &node in members {
+            partition[node] = block;
+        }
+    }
+    partition
+}
+
+/// Weights from a node to the blocks of its neighbours.
+struct NodeLinks {
+    /// Weight from the node to each block.
+    weights: Vec<f64>,
+    /// Blocks with a positive weight from the node.
+    touched: Vec<usize>,
+    /// Weight of the self-loops of the node.
+    self_loop: f64,
+}
+
+impl NodeLinks {
+    fn new(number_of_blocks: usize) -> Self {
+        Self { weights: vec![0.0; number_of_blocks], touched: Vec::new(), self_loop: 0.0 }
+    }
+
+    /// Gathers the weights from the node to the blocks of its neighbours.
+    fn gather(&mut self, graph: &WeightedUndirectedGraph, partition: &[usize], node: usize) {
+        for &block in &self.touched {
+            self.weights[block] = 0.0;
+        }
+        self.touched.clear();
+        self.self_loop = 0.0;
+        for &(neighbor, weight) in &graph.adjacency[node] {
+            if neighbor == node {
+                self.self_loop += weight;
+                continue;
+            }
+            let block = partition[neighbor];
+            if self.weights[block] == 0.0 {
+                self.touched.push(block);
+            }
+            self.weights[block] += weight;
+        }
+    }
+}
+
+/// Dense block statistics of a partition into a fixed number of blocks.
+struct BlockModel {
+    /// Number of blocks.
+    number_of_blocks: usize,
+    /// Weights between the blocks, in row-major order.
+    weights: Vec<f64>,
+    /// Sum of the degrees of each block.
+    degrees: Vec<f64>,
+    /// Number of nodes of each block.
+    sizes: Vec<usize>,
+}
+
+impl BlockModel {
+    fn new(graph: &WeightedUndirectedGraph, partition: &[usize], number_of_blocks: usize) -> Self {
+        let mut weights = vec![0.0; number_of_blocks * number_of_blocks];
+        let mut degrees = vec![0.0; number_of_blocks];
+        let mut sizes = vec![0; number_of_blocks];
+        for (source, neighbors) in graph.adjacency.iter().enumerate() {
+            let source_block = partition[source];
+            degrees[source_block] += graph.degree[source];
+            sizes[source_block] += 1;
+            for &(destination, weight) in neighbors {
+                weights[source_block * number_of_blocks + partition[destination]] += weight;
+            }
+        }
+        Self { number_of_blocks, weights, degrees, sizes }
+    }
+
+    fn weight(&self, left: usize, right: usize) -> f64 {
+        self.weights[left * self.number_of_blocks + right]
+    }
+
+    /// Adds the weight between the two blocks, in both directions.
+    fn add_weight(&mut self, left: usize, right: usize, weight: f64) {
+        self.weights[left * self.number_of_blocks + right] += weight;
+        if left != right {
+            self.weights[right * self.number_of_blocks + left] += weight;
+        }
+    }
+
+    fn log_likelihood(&self) -> f64 {
+        self.weights.iter().copied().map(entropy_term).sum::<f64>()
+            - 2.0 * self.degrees.iter().copied().map(entropy_term).sum::<f64>()
+    }
+
+    /// Returns the change of the log-likelihood when moving a node of the
+    /// given degree from the source block to the destination block, given
+    /// the change of the terms of the source block towards the other blocks.
+    fn move_gain(
+        &self,
+        links: &NodeLinks,
+        node_degree: f64,
+        source: usize,
+        destination: usize,
+        leaving: f64,
+    ) -> f64 {
+        let to_source = links.weights[source];
+        let to_destination = links.weights[destination];
+        let between = self.weight(source, destination);
+        let mut gain =
+            entropy_term(self.weight(source, source) - 2.0 * to_source - links.self_loop)
+                - entropy_term(self.weight(source, source))
+                + entropy_term(
+                    self.weight(destination, destination) + 2.0 * to_destination + links.self_loop,
+                )
+                - entropy_term(self.weight(destination, destination))
+                + 2.0
+                    * (entropy_term(between + to_source - to_destination) - entropy_term(between));
+        let mut third_blocks = leaving;
+        for &block in &links.touched {
+            if block == source {
+                continue;
+            }
+            if block == destination {
+                let weight = self.weight(source, block);
+                third_blocks -= entropy_term(weight - links.weights[block]) - entropy_term(weight);
+                continue;
+            }
+            let weight = self.weight(destination, block);
+            third_blocks += entropy_term(weight + links.weights[block]) - entropy_term(weight);
+        }
+        gain += 2.0 * third_blocks;
+        gain - 2.0
+            * (entropy_term(self.degrees[source] - node_degree)
+                - entropy_term(self.degrees[source])
+                + entropy_term(self.degrees[destination] + node_degree)
+                - entropy_term(self.degrees[destination]))
+    }
+
+    /// Updates the statistics for moving a node of the given degree from the
+    /// source block to the destination block.
+    fn move_node(
+        &mut self,
+        links: &NodeLinks,
+        node_degree: f64,
+        source: usize,
+        destination: usize,
+    ) {
+        for &block in &links.touched {
+            if block != source && block != destination {
+                let weight = links.weights[block];
+                self.add_weight(source, block, -weight);
+                self.add_weight(destination, block, weight);
+            }
+        }
+        let to_source = links.weights[source];
+        let to_destination = links.weights[destination];
+        self.add_weight(source, source, -2.0 * to_source - links.self_loop);
+        self.add_weight(destination, destination, 2.0 * to_destination + links.self_loop);
+        self.add_weight(source, destination, to_source - to_destination);
+        self.degrees[source] -= node_degree;
+        self.degrees[destination] += node_degree;
+        self.sizes[source] -= 1;
+        self.sizes[destination] += 1;
+    }
+
+    /// Moves the nodes to the blocks most increasing the likelihood until no
+    /// move improves it, and returns the number of moves.
+    fn refine(
+        &mut self,
+        graph: &WeightedUndirectedGraph,
+        partition: &mut [usize],
+        config: &SbmConfig,
+    ) -> usize {
+        let tolerance = 1.0e-9 * graph.total_weight.max(1.0);
+        let mut order: Vec<usize> = (0..graph.number_of_nodes()).collect();
+        let mut links = NodeLinks::new(self.number_of_blocks);
+        let mut moved_nodes = 0;
+
+        for pass_index in 0..config.max_refinement_passes {
+            let mut rng = SmallRng::seed_from_u64(mix_seed(config.seed, 0, pass_index));
+            order.shuffle(&mut rng);
+            let mut moved_in_pass = 0;
+
+            for &node in &order {
+                let node_degree = graph.degree[node];
+                let source = partition[node];
+                if node_degree <= 0.0 || self.sizes[source] == 1 {
+                    continue;
+                }
+                links.gather(graph, partition, node);
+
+                // The change of the terms of the source block towards the
+                // third blocks does not depend on the destination.
+                let leaving: f64 = links
+                    .touched
+                    .iter()
+                    .filter(|&&block| block != source)
+                    .map(|&block| {
+                        let weight = self.weight(source, block);
+                        entropy_term(weight - links.weights[block]) - entropy_term(weight)
+                    })
+                    .sum();
+                let mut best_block = source;
+                let mut best_gain = tolerance;
+                for destination in (0..self.number_of_blocks).filter(|&block| block != source) {
+                    let gain = self.move_gain(&links, node_degree, source, destination, leaving);
+                    if gain > best_gain {
+                        best_gain = gain;
+                        best_block = destination;
+                    }
+                }
+
+                if best_block != source {
+                    self.move_node(&links, node_degree, source, best_block);
+                    partition[node] = best_block;
+                    moved_in_pass += 1;
+                }
+            }
+
+            moved_nodes += moved_in_pass;
+            if moved_in_pass == 0 {
+                break;
+            }
+        }
+        moved_nodes
+    }
+}
@@ -114,9 +114,9 @@ where
             distances[node_id * order + node_id] = Some(Self::Value::zero());
         }
 
-        for row_id in self.row_indices() {
+        for (row_id, entries) in self.rows_with_values() {
             let source_id = row_id.as_();
-            for (column_id, weight) in self.sparse_row(row_id).zip(self.sparse_row_values(row_id)) {
+            for (column_id, weight) in entries {
                 let destination_id = column_id.as_();
                 if !weight.is_finite() {
                     return Err(FloydWarshallError::NonFiniteWeight { source_id, destination_id });
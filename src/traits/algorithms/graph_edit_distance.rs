@@ -0,0 +1,270 @@
+//! Submodule providing the `ApproximateGraphEditDistance` trait, which
+//! bounds the graph edit distance between two small undirected graphs with
+//! the bipartite approximation of Riesen and Bunke (2009).
+//!
+//! Each node of the first graph is either substituted by a node of the second
+//! graph or deleted, and each remaining node of the second graph is inserted.
+//! The cost of each of these node operations is estimated from the node
+//! costs and from the degrees of the nodes, charging half the edge cost for
+//! each incident edge that has to be deleted or inserted, since every edge is
+//! shared by two nodes. The resulting square cost matrix, of side equal to the
+//! total number of nodes, is solved with [`LAPMOD`], and the returned cost is
+//! the exact cost of the edit path induced by the optimal node mapping, which
+//! is an upper bound of the graph edit distance.
+use alloc::{vec, vec::Vec};
+
+use num_traits::AsPrimitive;
+
+use crate::{
+    impls::ValuedCSR2D,
+    traits::{LAPError, LAPMOD, MatrixMut, SparseMatrixMut, UndirectedMonopartiteMonoplexGraph},
+};
+
+/// Costs of the node and edge operations of the graph edit distance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GraphEditCosts {
+    /// Cost of inserting or deleting a node.
+    pub node_insertion_deletion: f64,
+    /// Cost of inserting or deleting an edge.
+    pub edge_insertion_deletion: f64,
+}
+
+impl Default for GraphEditCosts {
+    #[inline]
+    fn default() -> Self {
+        Self { node_insertion_deletion: 1.0, edge_insertion_deletion: 1.0 }
+    }
+}
+
+/// Result of the approximate graph edit distance.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GraphEditMapping {
+    /// Cost of the edit path induced by `mapping`, which is an upper bound of
+    /// the graph edit distance.
+    pub cost: f64,
+    /// Node of the second graph substituting each node of the first graph,
+    /// both as dense node identifiers, or `None` if the node is deleted.
+    ///
+    /// The nodes of the second graph that no node maps to are inserted.
+    pub mapping: Vec<Option<usize>>,
+}
+
+/// Offset added to every entry of the cost matrix, as LAPMOD requires
+/// strictly positive costs. Every perfect matching of the square matrix
+/// selects the same number of entries, so the offset does not change the
+/// optimal assignment.
+const COST_OFFSET: f64 = 1.0;
+
+/// Returns the sorted edges `(smaller, larger)` of the graph, as dense node
+/// identifiers.
+fn sorted_edges<G>(graph: &G) -> Vec<(usize, usize)>
+where
+    G: UndirectedMonopartiteMonoplexGraph,
+{
+    let mut edges: Vec<(usize, usize)> = graph
+        .node_ids()
+        .flat_map(|node| {
+            graph
+                .neighbors(node)
+                .filter(move |&neighbor| node.as_() < neighbor.as_())
+                .map(move |neighbor| (node.as_(), neighbor.as_()))
+        })
+        .collect();
+    edges.sort_unstable();
+    edges
+}
+
+/// Converts a number of operations into a cost.
+#[inline]
+fn as_cost(count: usize) -> f64 {
+    count.as_()
+}
+
+#[inline]
+fn validate_cost(cost: f64) -> Result<f64, LAPError> {
+    if !cost.is_finite() {
+        Err(LAPError::NonFiniteValues)
+    } else if cost < 0.0 {
+        Err(LAPError::NegativeValues)
+    } else {
+        Ok(cost)
+    }
+}
+
+/// Trait providing the approximate graph edit distance between undirected
+/// graphs.
+pub trait ApproximateGraphEditDistance: UndirectedMonopartiteMonoplexGraph {
+    /// Returns an upper bound of the graph edit distance to `other` with unit
+    /// node and edge insertion and deletion costs and free node substitutions,
+    /// together with the node mapping achieving it.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: The graph to compare against.
+    ///
+    /// # Errors
+    ///
+    /// See [`approximate_graph_edit_distance_with_costs`](Self::approximate_graph_edit_distance_with_costs).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{
+    ///     impls::{CSR2D, SortedVec, SymmetricCSR2D},
+    ///     prelude::*,
+    ///     traits::{ApproximateGraphEditDistance, EdgesBuilder, VocabularyBuilder},
+    /// };
+    ///
+    /// fn build_graph(node_count: usize, edges: Vec<(usize, usize)>) -> UndiGraph<usize> {
+    ///     let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+    ///         .expected_number_of_symbols(node_count)
+    ///         .symbols((0..node_count).enumerate())
+    ///         .build()
+    ///         .unwrap();
+    ///     let edges: SymmetricCSR2D<CSR2D<usize, usize, usize>> = UndiEdgesBuilder::default()
+    ///         .expected_number_of_edges(edges.len())
+    ///         .expected_shape(node_count)
+    ///         .edges(edges.into_iter())
+    ///         .build()
+    ///         .unwrap();
+    ///     UndiGraph::from((nodes, edges))
+    /// }
+    ///
+    /// // A triangle becomes a path on four nodes by deleting an edge, then
+    /// // inserting a node and the edge attaching it.
+    /// let triangle = build_graph(3, vec![(0, 1), (0, 2), (1, 2)]);
+    /// let path = build_graph(4, vec![(0, 1), (1, 2), (2, 3)]);
+    ///
+    /// let distance = triangle.approximate_graph_edit_distance(&path).unwrap();
+    /// assert_eq!(distance.cost, 3.0);
+    /// assert_eq!(distance.mapping.iter().flatten().count(), 3);
+    /// ```
+    #[inline]
+    fn approximate_graph_edit_distance<Other>(
+        &self,
+        other: &Other,
+    ) -> Result<GraphEditMapping, LAPError>
+    where
+        Self: Sized,
+        Other: UndirectedMonopartiteMonoplexGraph,
+    {
+        self.approximate_graph_edit_distance_with_costs(other, GraphEditCosts::default(), |_, _| {
+            0.0
+        })
+    }
+
+    /// Returns an upper bound of the graph edit distance to `other` with the
+    /// provided costs, together with the node mapping achieving it.
+    ///
+    /// Edges are unlabeled, so substituting an edge is free.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: The graph to compare against.
+    /// * `costs`: The node and edge insertion and deletion costs.
+    /// * `node_substitution_cost`: The cost of substituting a node of this
+    ///   graph with a node of `other`, e.g. zero for nodes with equal labels.
+    ///
+    /// # Errors
+    ///
+    /// * [`LAPError::NonFiniteValues`] if a cost is not finite.
+    /// * [`LAPError::NegativeValues`] if a cost is negative.
+    /// * Any other [`LAPError`] returned by [`LAPMOD`] on the cost matrix, e.g.
+    ///   [`LAPError::MaximalCostNotFinite`] if the costs are so large that no
+    ///   finite upper bound of the matrix entries exists.
+    ///
+    /// # Complexity
+    ///
+    /// O((V₁ + V₂)²) space for the cost matrix, plus the time LAPMOD takes to
+    /// solve it, where V₁ and V₂ are the numbers of nodes of the two graphs.
+    #[inline]
+    fn approximate_graph_edit_distance_with_costs<Other, F>(
+        &self,
+        other: &Other,
+        costs: GraphEditCosts,
+        mut node_substitution_cost: F,
+    ) -> Result<GraphEditMapping, LAPError>
+    where
+        Self: Sized,
+        Other: UndirectedMonopartiteMonoplexGraph,
+        F: FnMut(Self::NodeId, Other::NodeId) -> f64,
+    {
+        let node_cost = validate_cost(costs.node_insertion_deletion)?;
+        let edge_cost = validate_cost(costs.edge_insertion_deletion)?;
+        let left_nodes: Vec<Self::NodeId> = self.node_ids().collect();
+        let right_nodes: Vec<Other::NodeId> = other.node_ids().collect();
+        let (n, m) = (left_nodes.len(), right_nodes.len());
+        let indel_cost =
+            |degree: usize| node_cost + edge_cost * as_cost(degree) / 2.0 + COST_OFFSET;
+        let left_degrees: Vec<usize> =
+            left_nodes.iter().map(|&node| self.degree(node).as_()).collect();
+        let right_degrees: Vec<usize> =
+            right_nodes.iter().map(|&node| other.degree(node).as_()).collect();
+
+        // The square cost matrix of side n + m has four blocks:
+        //
+        //               right nodes (0..m)       deletions (m..m+n)
+        //             ┌───────────────────────┬───────────────────────┐
+        // left nodes  │  substitutions        │  Diag(deletion)       │
+        // (0..n)      │                       │                       │
+        //             ├───────────────────────┼───────────────────────┤
+        // insertions  │  Diag(insertion)      │  0, matching the      │
+        // (n..n+m)    │                       │  unused diagonals     │
+        //             └───────────────────────┴───────────────────────┘
+        let mut matrix: ValuedCSR2D<usize, usize, usize, f64> =
+            SparseMatrixMut::with_sparse_shaped_capacity((n + m, n + m), n * m + n + m + m * n);
+        let mut maximum_entry = COST_OFFSET;
+        let mut add = |matrix: &mut ValuedCSR2D<usize, usize, usize, f64>,
+                       (row, column, value): (usize, usize, f64)| {
+            maximum_entry = maximum_entry.max(value);
+            matrix.add((row, column, value)).map_err(|_| LAPError::ExpandedMatrixBuildFailed)
+        };
+        for (row, &left_node) in left_nodes.iter().enumerate() {
+            for (column, &right_node) in right_nodes.iter().enumerate() {
+                let substitution = validate_cost(node_substitution_cost(left_node, right_node))?;
+                let degree_difference = left_degrees[row].abs_diff(right_degrees[column]);
+                let value =
+                    substitution + edge_cost * as_cost(degree_difference) / 2.0 + COST_OFFSET;
+                add(&mut matrix, (row, column, value))?;
+            }
+            add(&mut matrix, (row, m + row, indel_cost(left_degrees[row])))?;
+        }
+        for (column, &degree) in right_degrees.iter().enumerate() {
+            add(&mut matrix, (n + column, column, indel_cost(degree)))?;
+            for row in 0..n {
+                add(&mut matrix, (n + column, m + row, COST_OFFSET))?;
+            }
+        }
+
+        let mut mapping = vec![None; n];
+        for (row, column) in matrix.lapmod(2.0 * maximum_entry)? {
+            if row < n && column < m {
+                mapping[row] = Some(column);
+            }
+        }
+
+        // The cost of the edit path induced by the mapping.
+        let mut cost = node_cost * as_cost(n + m - 2 * mapping.iter().flatten().count());
+        for (row, column) in mapping.iter().enumerate() {
+            if let Some(column) = *column {
+                cost += node_substitution_cost(left_nodes[row], right_nodes[column]);
+            }
+        }
+        let left_edges = sorted_edges(self);
+        let right_edges = sorted_edges(other);
+        let preserved_edges = left_edges
+            .iter()
+            .filter(|&&(left, right)| {
+                let (Some(left), Some(right)) = (mapping[left], mapping[right]) else {
+                    return false;
+                };
+                right_edges.binary_search(&(left.min(right), left.max(right))).is_ok()
+            })
+            .count();
+        cost += edge_cost * as_cost(left_edges.len() + right_edges.len() - 2 * preserved_edges);
+
+        Ok(GraphEditMapping { cost, mapping })
+    }
+}
+
+impl<G: UndirectedMonopartiteMonoplexGraph> ApproximateGraphEditDistance for G {}
@@ -0,0 +1,102 @@
+//! Submodule providing the `GraphIsomorphism` trait, which checks whether two
+//! graphs are identical up to a permutation of their node identifiers.
+//!
+//! Non-isomorphic graphs are usually told apart by cheap invariants, so the
+//! check first compares the number of nodes and edges and the sorted sequences
+//! of the per-node degrees and triangle counts, and only runs the
+//! [`Vf2`] search when all of them agree.
+use alloc::{vec, vec::Vec};
+
+use num_traits::AsPrimitive;
+
+use crate::traits::{MonoplexMonopartiteGraph, Vf2, Vf2Mode};
+
+/// Returns the sorted sequence of the out-degree, in-degree and number of
+/// triangles of each node, which is identical for isomorphic graphs.
+///
+/// The triangles of a node are the edges between two of its distinct
+/// successors, so that each undirected triangle is counted twice.
+fn node_invariants<G: MonoplexMonopartiteGraph>(graph: &G) -> Vec<(usize, usize, usize)> {
+    let mut invariants: Vec<(usize, usize, usize)> = vec![(0, 0, 0); graph.number_of_nodes().as_()];
+    for node in graph.node_ids() {
+        for successor in graph.successors(node) {
+            invariants[node.as_()].0 += 1;
+            invariants[successor.as_()].1 += 1;
+            if successor == node {
+                continue;
+            }
+            invariants[node.as_()].2 += graph
+                .successors(successor)
+                .filter(|&other| {
+                    other != node && other != successor && graph.has_successor(node, other)
+                })
+                .count();
+        }
+    }
+    invariants.sort_unstable();
+    invariants
+}
+
+/// Trait providing the isomorphism check of monoplex monopartite graphs.
+pub trait GraphIsomorphism: MonoplexMonopartiteGraph {
+    /// Returns whether the two graphs are isomorphic, i.e. whether a
+    /// bijection between their nodes maps the edges of one graph exactly
+    /// onto the edges of the other one.
+    ///
+    /// Node symbols and edge weights are ignored. The check is meant for
+    /// graphs of up to a few thousand nodes: the invariant-based rejection
+    /// costs O(V log V + Σ deg²), while the [`Vf2`] search, only run when
+    /// the invariants agree, may take exponential time on highly regular
+    /// graphs.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: The graph to compare against.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{
+    ///     impls::{CSR2D, SortedVec, SymmetricCSR2D},
+    ///     prelude::*,
+    ///     traits::{EdgesBuilder, GraphIsomorphism, VocabularyBuilder},
+    /// };
+    ///
+    /// fn build_graph(node_count: usize, mut edges: Vec<(usize, usize)>) -> UndiGraph<usize> {
+    ///     let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+    ///         .expected_number_of_symbols(node_count)
+    ///         .symbols((0..node_count).enumerate())
+    ///         .build()
+    ///         .unwrap();
+    ///     edges.sort_unstable();
+    ///     let edges: SymmetricCSR2D<CSR2D<usize, usize, usize>> = UndiEdgesBuilder::default()
+    ///         .expected_number_of_edges(edges.len())
+    ///         .expected_shape(node_count)
+    ///         .edges(edges.into_iter())
+    ///         .build()
+    ///         .unwrap();
+    ///     UndiGraph::from((nodes, edges))
+    /// }
+    ///
+    /// // A triangle with a pendant node, labeled in two different ways.
+    /// let left = build_graph(4, vec![(0, 1), (0, 2), (1, 2), (2, 3)]);
+    /// let right = build_graph(4, vec![(0, 3), (1, 2), (1, 3), (2, 3)]);
+    /// let cycle = build_graph(4, vec![(0, 1), (0, 3), (1, 2), (2, 3)]);
+    ///
+    /// assert!(left.is_isomorphic(&right));
+    /// assert!(!left.is_isomorphic(&cycle));
+    /// ```
+    #[inline]
+    fn is_isomorphic<Other>(&self, other: &Other) -> bool
+    where
+        Self: Sized,
+        Other: MonoplexMonopartiteGraph,
+    {
+        self.number_of_nodes().as_() == other.number_of_nodes().as_()
+            && self.number_of_edges().as_() == other.number_of_edges().as_()
+            && node_invariants(self) == node_invariants(other)
+            && self.vf2(other).with_mode(Vf2Mode::Isomorphism).has_match()
+    }
+}
+
+impl<G: MonoplexMonopartiteGraph> GraphIsomorphism for G {}
@@ -0,0 +1,289 @@
+//! Submodule providing single-source breadth-first and Dijkstra searches
+//! whose working memory is bounded by a const-generic capacity, so that they
+//! run without an allocator.
+//!
+//! The distances and the frontier of the searches are kept in [`heapless`]
+//! buffers of `N` slots, where `N` must be at least the number of nodes of
+//! the graph. The Dijkstra search selects the closest unsettled node with a
+//! linear scan rather than a binary heap, whose lazy deletions would need
+//! room for one entry per edge: for the tiny graphs fitting in the buffers,
+//! the quadratic scan is also the faster of the two.
+
+use num_traits::{AsPrimitive, Zero};
+
+use crate::traits::{
+    Finite, Number, PositiveInteger, SparseMatrix2D, SparseValuedMatrix2D, TotalOrd, TryFromUsize,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+/// Errors that can occur while executing the fixed-capacity searches.
+pub enum HeaplessSearchError {
+    /// The input matrix is not square.
+    #[error("The matrix must be square, but has {rows} rows and {columns} columns.")]
+    NonSquareMatrix {
+        /// Number of rows.
+        rows: usize,
+        /// Number of columns.
+        columns: usize,
+    },
+    /// The graph has more nodes than the buffers of the search can hold.
+    #[error("The graph has {number_of_nodes} nodes, more than the capacity of {capacity}.")]
+    CapacityExceeded {
+        /// Number of nodes of the graph.
+        number_of_nodes: usize,
+        /// Number of slots of the buffers.
+        capacity: usize,
+    },
+    /// The source node is not a node of the graph.
+    #[error("The source {source_id} is not one of the {number_of_nodes} nodes of the graph.")]
+    SourceOutOfBounds {
+        /// Source node identifier.
+        source_id: usize,
+        /// Number of nodes of the graph.
+        number_of_nodes: usize,
+    },
+    /// An input edge weight is not finite.
+    #[error("Found a non-finite weight on ({source_id}, {destination_id}).")]
+    NonFiniteWeight {
+        /// Source node identifier.
+        source_id: usize,
+        /// Destination node identifier.
+        destination_id: usize,
+    },
+    /// An input edge weight is negative.
+    #[error("Found a negative weight on ({source_id}, {destination_id}).")]
+    NegativeWeight {
+        /// Source node identifier.
+        source_id: usize,
+        /// Destination node identifier.
+        destination_id: usize,
+    },
+    /// A tentative path distance overflowed or otherwise became non-finite.
+    #[error("Found a non-finite tentative distance to {destination_id} via {via_id}.")]
+    NonFiniteDistance {
+        /// Destination node identifier.
+        destination_id: usize,
+        /// Intermediate node identifier whose outgoing edge caused the issue.
+        via_id: usize,
+    },
+}
+
+/// Returns the number of nodes of the graph after checking that it is square,
+/// that it fits the capacity and that it contains the source.
+fn checked_number_of_nodes<M: SparseMatrix2D + ?Sized, const N: usize>(
+    matrix: &M,
+    source: usize,
+) -> Result<usize, HeaplessSearchError> {
+    let rows = matrix.number_of_rows().as_();
+    let columns = matrix.number_of_columns().as_();
+    if rows != columns {
+        return Err(HeaplessSearchError::NonSquareMatrix { rows, columns });
+    }
+    if rows > N {
+        return Err(HeaplessSearchError::CapacityExceeded { number_of_nodes: rows, capacity: N });
+    }
+    if source >= rows {
+        return Err(HeaplessSearchError::SourceOutOfBounds {
+            source_id: source,
+            number_of_nodes: rows,
+        });
+    }
+    Ok(rows)
+}
+
+/// Trait providing a breadth-first search whose working memory is bounded by
+/// a const-generic capacity.
+///
+/// # Complexity
+///
+/// O(V + E) time and O(N) space, allocated on the stack.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::prelude::*;
+///
+/// let mut graph: HeaplessCSR2D<u8, u8, u8, 4, 8> = SparseMatrixMut::with_sparse_shape((4, 4));
+/// for edge in [(0, 1), (1, 2), (2, 0)] {
+///     MatrixMut::add(&mut graph, edge).unwrap();
+/// }
+///
+/// let distances = graph.heapless_bfs::<4>(0).unwrap();
+/// assert_eq!(distances.as_slice(), &[Some(0), Some(1), Some(2), None]);
+/// assert!(graph.heapless_bfs::<3>(0).is_err());
+/// ```
+pub trait HeaplessBFS: SparseMatrix2D
+where
+    Self::RowIndex: PositiveInteger,
+    Self::ColumnIndex: Into<Self::RowIndex>,
+{
+    /// Returns the number of hops from the source to every node, or `None`
+    /// for the nodes it does not reach.
+    ///
+    /// # Arguments
+    ///
+    /// * `source_id` - The node the search starts from.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the matrix is not square, if it has more than `N`
+    /// nodes or if the source is not one of its nodes.
+    #[inline]
+    fn heapless_bfs<const N: usize>(
+        &self,
+        source_id: Self::RowIndex,
+    ) -> Result<heapless::Vec<Option<usize>, N>, HeaplessSearchError> {
+        let number_of_nodes = checked_number_of_nodes::<Self, N>(self, source_id.as_())?;
+        let mut distances: heapless::Vec<Option<usize>, N> = heapless::Vec::new();
+        distances
+            .resize(number_of_nodes, None)
+            .unwrap_or_else(|()| unreachable!("The nodes were checked to fit the capacity."));
+        let mut frontier: heapless::Deque<Self::RowIndex, N> = heapless::Deque::new();
+
+        distances[source_id.as_()] = Some(0);
+        frontier
+            .push_back(source_id)
+            .unwrap_or_else(|_| unreachable!("The nodes were checked to fit the capacity."));
+        while let Some(node_id) = frontier.pop_front() {
+            let next_distance = distances[node_id.as_()].map_or(0, |distance| distance + 1);
+            for destination_id in self.sparse_row(node_id) {
+                let destination = &mut distances[destination_id.as_()];
+                if destination.is_none() {
+                    *destination = Some(next_distance);
+                    // Every node enters the frontier at most once.
+                    frontier.push_back(destination_id.into()).unwrap_or_else(|_| {
+                        unreachable!("The nodes were checked to fit the capacity.")
+                    });
+                }
+            }
+        }
+
+        Ok(distances)
+    }
+}
+
+impl<M> HeaplessBFS for M
+where
+    M: SparseMatrix2D,
+    M::RowIndex: PositiveInteger,
+    M::ColumnIndex: Into<M::RowIndex>,
+{
+}
+
+/// Trait providing a Dijkstra search for non-negative weights, whose working
+/// memory is bounded by a const-generic capacity.
+///
+/// # Complexity
+///
+/// O(V² + E) time and O(N) space, allocated on the stack.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::prelude::*;
+///
+/// let mut graph: HeaplessValuedCSR2D<u8, u8, u8, f64, 3, 3> =
+///     SparseMatrixMut::with_sparse_shape((3, 3));
+/// for edge in [(0, 1, 1.0), (0, 2, 5.0), (1, 2, 1.0)] {
+///     MatrixMut::add(&mut graph, edge).unwrap();
+/// }
+///
+/// let distances = graph.heapless_dijkstra::<3>(0).unwrap();
+/// assert_eq!(distances.as_slice(), &[Some(0.0), Some(1.0), Some(2.0)]);
+/// ```
+pub trait HeaplessDijkstra: SparseValuedMatrix2D
+where
+    Self::Value: Number + Finite + TotalOrd,
+    Self::RowIndex: PositiveInteger,
+    Self::ColumnIndex: Into<Self::RowIndex>,
+{
+    /// Returns the shortest-path distance from the source to every node, or
+    /// `None` for the nodes it does not reach.
+    ///
+    /// # Arguments
+    ///
+    /// * `source_id` - The node the search starts from.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the matrix is not square, if it has more than `N`
+    /// nodes, if the source is not one of its nodes, if a weight reached by
+    /// the search is not finite or is negative, or if a tentative path
+    /// distance becomes non-finite.
+    #[inline]
+    fn heapless_dijkstra<const N: usize>(
+        &self,
+        source_id: Self::RowIndex,
+    ) -> Result<heapless::Vec<Option<Self::Value>, N>, HeaplessSearchError> {
+        let number_of_nodes = checked_number_of_nodes::<Self, N>(self, source_id.as_())?;
+        let zero = Self::Value::zero();
+        let mut distances: heapless::Vec<Option<Self::Value>, N> = heapless::Vec::new();
+        let mut settled: heapless::Vec<bool, N> = heapless::Vec::new();
+        distances
+            .resize(number_of_nodes, None)
+            .and_then(|()| settled.resize(number_of_nodes, false))
+            .unwrap_or_else(|()| unreachable!("The nodes were checked to fit the capacity."));
+
+        distances[source_id.as_()] = Some(zero);
+        loop {
+            let mut closest: Option<(usize, Self::Value)> = None;
+            for (node, (distance, &is_settled)) in distances.iter().zip(settled.iter()).enumerate()
+            {
+                let Some(distance) = *distance else {
+                    continue;
+                };
+                if !is_settled && closest.is_none_or(|(_, best)| distance.total_cmp(&best).is_lt())
+                {
+                    closest = Some((node, distance));
+                }
+            }
+            let Some((node, distance)) = closest else {
+                break;
+            };
+            settled[node] = true;
+
+            let node_id = Self::RowIndex::try_from_usize(node)
+                .unwrap_or_else(|_| unreachable!("The nodes are rows of the matrix."));
+            for (destination_id, weight) in
+                self.sparse_row(node_id).zip(self.sparse_row_values(node_id))
+            {
+                let destination = destination_id.as_();
+                if !weight.is_finite() {
+                    return Err(HeaplessSearchError::NonFiniteWeight {
+                        source_id: node,
+                        destination_id: destination,
+                    });
+                }
+                if weight < zero {
+                    return Err(HeaplessSearchError::NegativeWeight {
+                        source_id: node,
+                        destination_id: destination,
+                    });
+                }
+                let candidate = distance + weight;
+                if !candidate.is_finite() {
+                    return Err(HeaplessSearchError::NonFiniteDistance {
+                        destination_id: destination,
+                        via_id: node,
+                    });
+                }
+                if distances[destination]
+                    .is_none_or(|current| candidate.total_cmp(&current).is_lt())
+                {
+                    distances[destination] = Some(candidate);
+                }
+            }
+        }
+
+        Ok(distances)
+    }
+}
+
+impl<M> HeaplessDijkstra for M
+where
+    M: SparseValuedMatrix2D,
+    M::Value: Number + Finite + TotalOrd,
+    M::RowIndex: PositiveInteger,
+    M::ColumnIndex: Into<M::RowIndex>,
+{
+}
@@ -0,0 +1,497 @@
+//! Submodule providing the `HierarchicalClustering` trait, computing the
+//! single, complete or average linkage dendrogram of the items of a sparse
+//! distance matrix.
+
+use alloc::{
+    collections::{BTreeMap, BinaryHeap},
+    vec::Vec,
+};
+use core::cmp::{Ordering, Reverse};
+
+use num_traits::{AsPrimitive, ToPrimitive};
+
+use crate::{
+    impls::DisjointSet,
+    traits::{Finite, MonopartiteGraph, PositiveInteger, SparseValuedMatrix2D},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Rule computing the distance between two clusters from the distances
+/// between their items.
+pub enum Linkage {
+    /// Distance between the closest items of the clusters.
+    Single,
+    /// Distance between the farthest items of the clusters.
+    Complete,
+    /// Mean distance between the items of the clusters (UPGMA).
+    Average,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Rule selecting the flat clustering extracted from a [`Dendrogram`].
+pub enum DendrogramCut {
+    /// Keeps the merges whose height is at most the provided height.
+    Height(f64),
+    /// Keeps the first merges, until the provided number of clusters is
+    /// reached.
+    Clusters(usize),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+/// Error enumeration for the hierarchical clustering algorithm.
+pub enum HierarchicalClusteringError {
+    /// The matrix is not square.
+    #[error("The distance matrix must be square, but received shape ({rows}, {columns}).")]
+    NonSquareMatrix {
+        /// Number of rows in the matrix.
+        rows: usize,
+        /// Number of columns in the matrix.
+        columns: usize,
+    },
+    /// The distance cannot be represented as `f64`.
+    #[error(
+        "Found a distance on ({source_id}, {destination_id}) that cannot be represented as f64."
+    )]
+    UnrepresentableDistance {
+        /// Source item identifier.
+        source_id: usize,
+        /// Destination item identifier.
+        destination_id: usize,
+    },
+    /// The distance is not finite.
+    #[error("Found a non-finite distance on ({source_id}, {destination_id}).")]
+    NonFiniteDistance {
+        /// Source item identifier.
+        source_id: usize,
+        /// Destination item identifier.
+        destination_id: usize,
+    },
+    /// The distance is negative.
+    #[error("Found a negative distance on ({source_id}, {destination_id}).")]
+    NegativeDistance {
+        /// Source item identifier.
+        source_id: usize,
+        /// Destination item identifier.
+        destination_id: usize,
+    },
+    /// The matrix is not symmetric.
+    #[error(
+        "The distance matrix is not symmetric: entry ({source_id}, {destination_id}) has no matching reverse entry."
+    )]
+    NonSymmetricDistance {
+        /// Source item identifier.
+        source_id: usize,
+        /// Destination item identifier.
+        destination_id: usize,
+    },
+    /// The selected cluster marker type is too small.
+    #[error("The selected cluster marker type is too small for this partition.")]
+    TooManyClusters,
+}
+
+impl From<HierarchicalClusteringError>
+    for crate::errors::monopartite_graph_error::algorithms::MonopartiteAlgorithmError
+{
+    #[inline]
+    fn from(error: HierarchicalClusteringError) -> Self {
+        Self::HierarchicalClusteringError(error)
+    }
+}
+
+impl<G: MonopartiteGraph> From<HierarchicalClusteringError> for crate::errors::MonopartiteError<G> {
+    #[inline]
+    fn from(error: HierarchicalClusteringError) -> Self {
+        Self::AlgorithmError(error.into())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Merge of two clusters of a [`Dendrogram`].
+///
+/// Clusters are identified as in `SciPy` linkage matrices: the items are the
+/// clusters `0..n`, and the `i`-th merge creates the cluster `n + i`.
+pub struct DendrogramMerge {
+    /// The smaller identifier of the merged clusters.
+    left: usize,
+    /// The larger identifier of the merged clusters.
+    right: usize,
+    /// The linkage distance between the merged clusters.
+    height: f64,
+    /// The number of items of the merged cluster.
+    size: usize,
+}
+
+impl DendrogramMerge {
+    /// Creates the merge of the two provided clusters.
+    pub(crate) fn new(left: usize, right: usize, height: f64, size: usize) -> Self {
+        Self { left: left.min(right), right: left.max(right), height, size }
+    }
+
+    /// Returns the smaller identifier of the merged clusters.
+    #[must_use]
+    #[inline]
+    pub fn left(&self) -> usize {
+        self.left
+    }
+
+    /// Returns the larger identifier of the merged clusters.
+    #[must_use]
+    #[inline]
+    pub fn right(&self) -> usize {
+        self.right
+    }
+
+    /// Returns the linkage distance at which the clusters are merged.
+    #[must_use]
+    #[inline]
+    pub fn height(&self) -> f64 {
+        self.height
+    }
+
+    /// Returns the number of items of the merged cluster.
+    #[must_use]
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Result of the hierarchical clustering algorithm: the sequence of merges of
+/// the clusters, by non-decreasing height.
+///
+/// Items without a finite linkage distance to each other are never merged,
+/// so the dendrogram is a forest with one tree per group of items that the
+/// linkage can connect, and it has fewer than `n - 1` merges when there are
+/// several of them.
+pub struct Dendrogram {
+    /// The number of clustered items.
+    number_of_items: usize,
+    /// The merges, by non-decreasing height.
+    merges: Vec<DendrogramMerge>,
+}
+
+impl Dendrogram {
+    /// Creates the dendrogram of the provided merges, by non-decreasing
+    /// height.
+    pub(crate) fn new(number_of_items: usize, merges: Vec<DendrogramMerge>) -> Self {
+        debug_assert!(merges.windows(2).all(|pair| pair[0].height <= pair[1].height));
+        Self { number_of_items, merges }
+    }
+
+    /// Returns the number of clustered items, the leaves of the dendrogram.
+    #[must_use]
+    #[inline]
+    pub fn number_of_items(&self) -> usize {
+        self.number_of_items
+    }
+
+    /// Returns the merges, by non-decreasing height.
+    #[must_use]
+    #[inline]
+    pub fn merges(&self) -> &[DendrogramMerge] {
+        &self.merges
+    }
+
+    /// Returns the flat clustering of the items obtained by cutting the
+    /// dendrogram.
+    ///
+    /// Clusters are numbered by order of their first item, as the partitions
+    /// of the [`Louvain`](crate::traits::Louvain) levels. Asking for fewer
+    /// clusters than the number of trees of the dendrogram returns one
+    /// cluster per tree.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the number of clusters cannot fit into `Marker`.
+    ///
+    /// # Complexity
+    ///
+    /// O(n α(n)) time and O(n) space.
+    #[inline]
+    pub fn cut<Marker: PositiveInteger>(
+        &self,
+        cut: DendrogramCut,
+    ) -> Result<Vec<Marker>, HierarchicalClusteringError> {
+        let number_of_merges = match cut {
+            DendrogramCut::Height(height) => {
+                self.merges.partition_point(|merge| merge.height <= height)
+            }
+            DendrogramCut::Clusters(number_of_clusters) => {
+                self.number_of_items.saturating_sub(number_of_clusters).min(self.merges.len())
+            }
+        };
+
+        // Each cluster is identified in the forest by one of its items.
+        let mut sets: DisjointSet<usize> = DisjointSet::new(self.number_of_items);
+        let mut representatives: Vec<usize> = (0..self.number_of_items).collect();
+        for merge in &self.merges[..number_of_merges] {
+            let (left, right) = (representatives[merge.left], representatives[merge.right]);
+            sets.union(left, right);
+            representatives.push(left);
+        }
+
+        let mut labels = vec![usize::MAX; self.number_of_items];
+        let mut number_of_clusters = 0;
+        (0..self.number_of_items)
+            .map(|item| {
+                let root = sets.find(item);
+                if labels[root] == usize::MAX {
+                    labels[root] = number_of_clusters;
+                    number_of_clusters += 1;
+                }
+                Marker::try_from_usize(labels[root])
+                    .map_err(|_| HierarchicalClusteringError::TooManyClusters)
+            })
+            .collect()
+    }
+}
+
+/// Trait providing agglomerative hierarchical clustering over a sparse
+/// distance matrix.
+///
+/// The matrix is expected to be square and symmetric, with the finite and
+/// non-negative distances between the items. Absent entries are infinite
+/// distances: with single linkage, clusters are merged when any of their
+/// items are at a finite distance, while complete and average linkage only
+/// merge clusters whose items are all at a finite distance from each other.
+/// Diagonal entries are ignored.
+pub trait HierarchicalClustering: SparseValuedMatrix2D + Sized
+where
+    Self::RowIndex: AsPrimitive<usize>,
+    Self::ColumnIndex: AsPrimitive<usize>,
+    Self::Value: ToPrimitive + Finite,
+{
+    /// Returns the dendrogram of the items under the provided linkage.
+    ///
+    /// Clusters are merged greedily by increasing linkage distance, drawn
+    /// from a heap, with ties broken towards the smaller cluster identifiers
+    /// so that the result is deterministic. The distances of a merged cluster
+    /// to its neighbours are derived from those of its two parts through the
+    /// Lance-Williams formulas.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when:
+    /// - the matrix is not square or not symmetric;
+    /// - at least one distance is non-finite or negative.
+    ///
+    /// # Complexity
+    ///
+    /// O(n E log E) time in the worst case and O(E) space, where n is the
+    /// number of items and E the number of entries; single linkage on sparse
+    /// matrices is usually much faster.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{
+    ///     impls::{SymmetricCSR2D, ValuedCSR2D},
+    ///     prelude::*,
+    ///     traits::{DendrogramCut, HierarchicalClustering, Linkage},
+    /// };
+    ///
+    /// let distances: SymmetricCSR2D<ValuedCSR2D<usize, usize, usize, f64>> =
+    ///     SymmetricCSR2D::from_sorted_upper_triangular_entries(
+    ///         4,
+    ///         vec![(0, 1, 1.0), (1, 2, 5.0), (2, 3, 2.0)],
+    ///     )
+    ///     .unwrap();
+    ///
+    /// let dendrogram = distances.hierarchical_clustering(Linkage::Single).unwrap();
+    /// assert_eq!(dendrogram.merges().len(), 3);
+    /// assert_eq!(dendrogram.cut::<usize>(DendrogramCut::Clusters(2)).unwrap(), vec![0, 0, 1, 1]);
+    /// assert_eq!(dendrogram.cut::<u8>(DendrogramCut::Height(1.5)).unwrap(), vec![0, 0, 1, 2]);
+    ///
+    /// // Items 0 and 3 are at an unknown distance, so complete linkage
+    /// // never merges their clusters.
+    /// let dendrogram = distances.hierarchical_clustering(Linkage::Complete).unwrap();
+    /// assert_eq!(dendrogram.merges().len(), 2);
+    /// ```
+    #[inline]
+    fn hierarchical_clustering(
+        &self,
+        linkage: Linkage,
+    ) -> Result<Dendrogram, HierarchicalClusteringError> {
+        let mut neighbors = distance_lists(self)?;
+        let number_of_items = neighbors.len();
+        let mut sizes = vec![1usize; number_of_items];
+
+        let mut heap: BinaryHeap<Reverse<Candidate>> = neighbors
+            .iter()
+            .enumerate()
+            .flat_map(|(left, distances)| {
+                distances
+                    .range(left + 1..)
+                    .map(move |(&right, &distance)| Reverse(Candidate { distance, left, right }))
+            })
+            .collect();
+
+        let mut merges: Vec<DendrogramMerge> = Vec::new();
+        while let Some(Reverse(Candidate { distance, left, right })) = heap.pop() {
+            // Candidates are created once per pair of clusters, and are stale
+            // only when one of the clusters was merged since.
+            if sizes[left] == 0 || sizes[right] == 0 {
+                continue;
+            }
+            let merged = neighbors.len();
+            let (left_size, right_size) = (sizes[left], sizes[right]);
+            let mut left_distances = core::mem::take(&mut neighbors[left]);
+            let mut right_distances = core::mem::take(&mut neighbors[right]);
+            left_distances.remove(&right);
+            right_distances.remove(&left);
+
+            let mut merged_distances = BTreeMap::new();
+            for (&other, &left_distance) in &left_distances {
+                let right_distance = right_distances.remove(&other);
+                let distance = match (linkage, right_distance) {
+                    (Linkage::Single, None) => Some(left_distance),
+                    (Linkage::Single, Some(right_distance)) => {
+                        Some(left_distance.min(right_distance))
+                    }
+                    (Linkage::Complete, Some(right_distance)) => {
+                        Some(left_distance.max(right_distance))
+                    }
+                    (Linkage::Average, Some(right_distance)) => {
+                        Some(weighted_mean(left_distance, left_size, right_distance, right_size))
+                    }
+                    (Linkage::Complete | Linkage::Average, None) => None,
+                };
+                neighbors[other].remove(&left);
+                if let Some(distance) = distance {
+                    merged_distances.insert(other, distance);
+                }
+            }
+            for (other, right_distance) in right_distances {
+                neighbors[other].remove(&right);
+                if linkage == Linkage::Single {
+                    merged_distances.insert(other, right_distance);
+                }
+            }
+            for (&other, &distance) in &merged_distances {
+                neighbors[other].insert(merged, distance);
+                heap.push(Reverse(Candidate { distance, left: other, right: merged }));
+            }
+
+            // The reducible linkages never decrease the height, but the means
+            // may lose a rounding error.
+            let height = merges.last().map_or(distance, |last| distance.max(last.height));
+            merges.push(DendrogramMerge { left, right, height, size: left_size + right_size });
+            neighbors.push(merged_distances);
+            sizes[left] = 0;
+            sizes[right] = 0;
+            sizes.push(left_size + right_size);
+        }
+
+        Ok(Dendrogram { number_of_items, merges })
+    }
+}
+
+impl<G> HierarchicalClustering for G
+where
+    G: SparseValuedMatrix2D + Sized,
+    G::RowIndex: AsPrimitive<usize>,
+    G::ColumnIndex: AsPrimitive<usize>,
+    G::Value: ToPrimitive + Finite,
+{
+}
+
+/// Pair of clusters to merge, ordered by distance and then by identifiers.
+#[derive(Debug, Clone, Copy)]
+struct Candidate {
+    distance: f64,
+    left: usize,
+    right: usize,
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance
+            .total_cmp(&other.distance)
+            .then(self.left.cmp(&other.left))
+            .then(self.right.cmp(&other.right))
+    }
+}
+
+/// Returns the validated off-diagonal distances of every item.
+fn distance_lists<M>(matrix: &M) -> Result<Vec<BTreeMap<usize, f64>>, HierarchicalClusteringError>
+where
+    M: SparseValuedMatrix2D,
+    M::RowIndex: AsPrimitive<usize>,
+    M::ColumnIndex: AsPrimitive<usize>,
+    M::Value: ToPrimitive + Finite,
+{
+    let rows = matrix.number_of_rows().as_();
+    let columns = matrix.number_of_columns().as_();
+    if rows != columns {
+        return Err(HierarchicalClusteringError::NonSquareMatrix { rows, columns });
+    }
+
+    let mut neighbors: Vec<BTreeMap<usize, f64>> = vec![BTreeMap::new(); rows];
+    for (row_id, entries) in matrix.rows_with_values() {
+        let source_id = row_id.as_();
+        for (column_id, distance) in entries {
+            let destination_id = column_id.as_();
+            if source_id == destination_id {
+                continue;
+            }
+            if !distance.is_finite() {
+                return Err(HierarchicalClusteringError::NonFiniteDistance {
+                    source_id,
+                    destination_id,
+                });
+            }
+            let distance =
+                distance.to_f64().ok_or(HierarchicalClusteringError::UnrepresentableDistance {
+                    source_id,
+                    destination_id,
+                })?;
+            if !distance.is_finite() {
+                return Err(HierarchicalClusteringError::NonFiniteDistance {
+                    source_id,
+                    destination_id,
+                });
+            }
+            if distance < 0.0 {
+                return Err(HierarchicalClusteringError::NegativeDistance {
+                    source_id,
+                    destination_id,
+                });
+            }
+            neighbors[source_id].insert(destination_id, distance);
+        }
+    }
+
+    for (source_id, distances) in neighbors.iter().enumerate() {
+        for (&destination_id, distance) in distances {
+            if neighbors[destination_id].get(&source_id) != Some(distance) {
+                return Err(HierarchicalClusteringError::NonSymmetricDistance {
+                    source_id,
+                    destination_id,
+                });
+            }
+        }
+    }
+
+    Ok(neighbors)
+}
+
+/// Returns the mean of two cluster distances weighted by the cluster sizes.
+#[allow(clippy::cast_precision_loss)]
+fn weighted_mean(left: f64, left_size: usize, right: f64, right_size: usize) -> f64 {
+    (left * left_size as f64 + right * right_size as f64) / (left_size + right_size) as f64
+}
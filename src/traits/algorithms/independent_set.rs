@@ -0,0 +1,334 @@
+//! Submodule providing the `IndependentSet` trait, approximating the maximum
+//! independent sets of undirected graphs.
+//!
+//! The initial set is built greedily, by repeatedly taking a node of
+//! smallest degree among the remaining ones and removing it along with its
+//! neighbours. It is then improved by the (1, 2)-swaps of the local search of
+//! Andrade, Resende and Werneck: a node of the set is replaced by two of its
+//! neighbours which are not adjacent to each other nor to any other node of
+//! the set. Both phases walk the adjacency lists of the graph itself, so that
+//! its dense complement, in which the independent sets are cliques, is never
+//! built.
+
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+use core::cmp::Reverse;
+
+use num_traits::AsPrimitive;
+
+use crate::traits::UndirectedMonopartiteMonoplexGraph;
+
+/// Independent set returned by the [`IndependentSet`] heuristics, with
+/// bounds on the size of a maximum independent set.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndependentSetResult<I> {
+    /// The nodes of the set, in increasing order.
+    nodes: Vec<I>,
+    /// The Caro-Wei lower bound on the independence number.
+    lower_bound: f64,
+    /// An upper bound on the independence number.
+    upper_bound: usize,
+}
+
+impl<I> IndependentSetResult<I> {
+    /// Returns the nodes of the set, in increasing order.
+    #[inline]
+    #[must_use]
+    pub fn nodes(&self) -> &[I] {
+        &self.nodes
+    }
+
+    /// Returns the number of nodes of the set.
+    #[inline]
+    #[must_use]
+    pub fn size(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns the Caro-Wei lower bound Σ 1 / (d(v) + 1) on the size of a
+    /// maximum independent set, summed over the nodes without self-loops
+    /// with their degree among them, which the size of the set never falls
+    /// below.
+    #[inline]
+    #[must_use]
+    pub fn lower_bound(&self) -> f64 {
+        self.lower_bound
+    }
+
+    /// Returns an upper bound on the size of a maximum independent set: the
+    /// number of nodes without self-loops minus the size of a maximal
+    /// matching among them, each of its edges excluding one of its
+    /// endpoints.
+    #[inline]
+    #[must_use]
+    pub fn upper_bound(&self) -> usize {
+        self.upper_bound
+    }
+}
+
+/// Trait providing heuristics for the maximum independent set of undirected
+/// graphs.
+///
+/// Nodes with a self-loop, being adjacent to themselves, belong to no
+/// independent set.
+///
+/// # References
+///
+/// Halldórsson, M. M., & Radhakrishnan, J. (1997). [Greed is good: Approximating independent sets in sparse and bounded-degree graphs](https://doi.org/10.1007/BF02523693).
+/// *Algorithmica*, 18(1), 145-163.
+///
+/// Andrade, D. V., Resende, M. G. C., & Werneck, R. F. (2012). [Fast local search for the maximum independent set problem](https://doi.org/10.1007/s10732-012-9196-4).
+/// *Journal of Heuristics*, 18(4), 525-547.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{
+///     impls::{CSR2D, SortedVec, SymmetricCSR2D},
+///     prelude::*,
+///     traits::{EdgesBuilder, IndependentSet, VocabularyBuilder},
+/// };
+///
+/// let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+///     .expected_number_of_symbols(6)
+///     .symbols((0..6).enumerate())
+///     .build()
+///     .unwrap();
+/// // A path of six nodes, whose every other node is independent.
+/// let edges: SymmetricCSR2D<CSR2D<usize, usize, usize>> = UndiEdgesBuilder::default()
+///     .expected_number_of_edges(5)
+///     .expected_shape(6)
+///     .edges(vec![(0, 1), (1, 2), (2, 3), (3, 4), (4, 5)].into_iter())
+///     .build()
+///     .unwrap();
+/// let path: UndiGraph<usize> = UndiGraph::from((nodes, edges));
+///
+/// let independent_set = path.independent_set();
+/// assert_eq!(independent_set.nodes(), &[0, 2, 4]);
+/// assert_eq!(independent_set.size(), 3);
+/// assert_eq!(independent_set.upper_bound(), 3);
+/// assert!(independent_set.lower_bound() <= 3.0);
+/// ```
+pub trait IndependentSet: UndirectedMonopartiteMonoplexGraph
+where
+    Self::NodeId: AsPrimitive<usize>,
+{
+    /// Returns the maximal independent set built by the minimum-degree
+    /// greedy heuristic, ties going to the smallest node.
+    ///
+    /// The set is at least as large as the Caro-Wei bound, and within a
+    /// factor (Δ + 2) / 3 of the maximum for graphs of maximum degree Δ.
+    ///
+    /// # Complexity
+    ///
+    /// O((V + E) log V) time and O(V) space.
+    #[inline]
+    fn greedy_independent_set(&self) -> IndependentSetResult<Self::NodeId> {
+        let graph = AdjacencyLists::new(self);
+        let in_set = graph.greedy();
+        graph.result(self, &in_set)
+    }
+
+    /// Returns the greedy independent set of
+    /// [`IndependentSet::greedy_independent_set`] improved by (1, 2)-swaps
+    /// until none is left, which keeps it maximal.
+    ///
+    /// # Complexity
+    ///
+    /// O((V + E) log V) time for the greedy set and O(E) time per improving
+    /// swap, of which there are fewer than V, and O(V) space.
+    #[inline]
+    fn independent_set(&self) -> IndependentSetResult<Self::NodeId> {
+        let graph = AdjacencyLists::new(self);
+        let mut in_set = graph.greedy();
+        graph.local_search(&mut in_set);
+        graph.result(self, &in_set)
+    }
+}
+
+impl<G> IndependentSet for G
+where
+    G: UndirectedMonopartiteMonoplexGraph,
+    G::NodeId: AsPrimitive<usize>,
+{
+}
+
+/// Adjacency lists of the nodes without self-loops, restricted to them.
+struct AdjacencyLists {
+    /// The sorted neighbours of each node, empty for excluded nodes.
+    neighbors: Vec<Vec<usize>>,
+    /// Whether each node has a self-loop, which excludes it.
+    is_excluded: Vec<bool>,
+}
+
+impl AdjacencyLists {
+    fn new<G>(graph: &G) -> Self
+    where
+        G: UndirectedMonopartiteMonoplexGraph + ?Sized,
+        G::NodeId: AsPrimitive<usize>,
+    {
+        let is_excluded: Vec<bool> =
+            graph.node_ids().map(|node| graph.neighbors(node).any(|other| other == node)).collect();
+        let neighbors = graph
+            .node_ids()
+            .map(|node| {
+                if is_excluded[node.as_()] {
+                    return Vec::new();
+                }
+                graph
+                    .neighbors(node)
+                    .map(AsPrimitive::as_)
+                    .filter(|&neighbor: &usize| !is_excluded[neighbor])
+                    .collect()
+            })
+            .collect();
+        Self { neighbors, is_excluded }
+    }
+
+    /// Returns the membership of the nodes to the minimum-degree greedy set.
+    fn greedy(&self) -> Vec<bool> {
+        let number_of_nodes = self.neighbors.len();
+        let mut degrees: Vec<usize> = self.neighbors.iter().map(Vec::len).collect();
+        let mut is_removed = self.is_excluded.clone();
+        let mut in_set = vec![false; number_of_nodes];
+        let mut heap: BinaryHeap<Reverse<(usize, usize)>> = (0..number_of_nodes)
+            .filter(|&node| !is_removed[node])
+            .map(|node| Reverse((degrees[node], node)))
+            .collect();
+        while let Some(Reverse((degree, node))) = heap.pop() {
+            if is_removed[node] || degree != degrees[node] {
+                continue;
+            }
+            in_set[node] = true;
+            is_removed[node] = true;
+            for &neighbor in &self.neighbors[node] {
+                if core::mem::replace(&mut is_removed[neighbor], true) {
+                    continue;
+                }
+                for &second in &self.neighbors[neighbor] {
+                    if !is_removed[second] {
+                        degrees[second] -= 1;
+                        heap.push(Reverse((degrees[second], second)));
+                    }
+                }
+            }
+        }
+        in_set
+    }
+
+    /// Applies (1, 2)-swaps to the maximal independent set until none is
+    /// left.
+    fn local_search(&self, in_set: &mut [bool]) {
+        let number_of_nodes = self.neighbors.len();
+        // The number of neighbours of each node in the set.
+        let mut tightness = vec![0_usize; number_of_nodes];
+        for node in (0..number_of_nodes).filter(|&node| in_set[node]) {
+            for &neighbor in &self.neighbors[node] {
+                tightness[neighbor] += 1;
+            }
+        }
+        let mut is_marked = vec![false; number_of_nodes];
+        let mut improved = true;
+        while improved {
+            improved = false;
+            for node in 0..number_of_nodes {
+                if !in_set[node] {
+                    continue;
+                }
+                // The neighbours whose only neighbour in the set is the node.
+                let candidates: Vec<usize> = self.neighbors[node]
+                    .iter()
+                    .copied()
+                    .filter(|&neighbor| tightness[neighbor] == 1)
+                    .collect();
+                let Some((first, second)) = self.non_adjacent_pair(&candidates, &mut is_marked)
+                else {
+                    continue;
+                };
+
+                in_set[node] = false;
+                for &neighbor in &self.neighbors[node] {
+                    tightness[neighbor] -= 1;
+                }
+                // The two nodes are inserted, followed by the candidates
+                // they leave free, so that the set stays maximal.
+                for inserted in [first, second].into_iter().chain(candidates) {
+                    if in_set[inserted] || tightness[inserted] > 0 {
+                        continue;
+                    }
+                    in_set[inserted] = true;
+                    for &neighbor in &self.neighbors[inserted] {
+                        tightness[neighbor] += 1;
+                    }
+                }
+                improved = true;
+            }
+        }
+    }
+
+    /// Returns two of the candidates which are not adjacent, if any.
+    fn non_adjacent_pair(
+        &self,
+        candidates: &[usize],
+        is_marked: &mut [bool],
+    ) -> Option<(usize, usize)> {
+        if candidates.len() < 2 {
+            return None;
+        }
+        for &first in candidates {
+            for &neighbor in &self.neighbors[first] {
+                is_marked[neighbor] = true;
+            }
+            let second =
+                candidates.iter().copied().find(|&second| second != first && !is_marked[second]);
+            for &neighbor in &self.neighbors[first] {
+                is_marked[neighbor] = false;
+            }
+            if let Some(second) = second {
+                return Some((first, second));
+            }
+        }
+        None
+    }
+
+    /// Builds the result of the independent set, with its bounds.
+    fn result<G>(&self, graph: &G, in_set: &[bool]) -> IndependentSetResult<G::NodeId>
+    where
+        G: UndirectedMonopartiteMonoplexGraph + ?Sized,
+        G::NodeId: AsPrimitive<usize>,
+    {
+        let lower_bound = self
+            .neighbors
+            .iter()
+            .zip(&self.is_excluded)
+            .filter(|&(_, &is_excluded)| !is_excluded)
+            .map(|(neighbors, _)| 1.0 / usize_to_f64(neighbors.len() + 1))
+            .sum();
+
+        // A greedy maximal matching, each of whose edges excludes one of its
+        // endpoints from any independent set.
+        let mut is_matched = self.is_excluded.clone();
+        let mut upper_bound = 0;
+        for node in 0..self.neighbors.len() {
+            if is_matched[node] {
+                continue;
+            }
+            upper_bound += 1;
+            if let Some(&neighbor) =
+                self.neighbors[node].iter().find(|&&neighbor| !is_matched[neighbor])
+            {
+                is_matched[node] = true;
+                is_matched[neighbor] = true;
+            }
+        }
+
+        let nodes = graph.node_ids().filter(|node| in_set[node.as_()]).collect();
+        IndependentSetResult { nodes, lower_bound, upper_bound }
+    }
+}
+
+/// Converts a count into a floating point value.
+#[allow(clippy::cast_precision_loss)]
+#[inline]
+fn usize_to_f64(value: usize) -> f64 {
+    value as f64
+}
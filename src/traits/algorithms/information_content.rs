@@ -8,8 +8,12 @@ use core::ops::Index;
 pub use error::InformationContentError;
 use num_traits::AsPrimitive;
 
-use crate::traits::{
-    Kahn, MonoplexMonopartiteGraph, RootNodes, SingletonNodes, SinkNodes, edges::Edges,
+use crate::{
+    impls::ValuedCSR2D,
+    traits::{
+        Kahn, MatrixMut, MonoplexMonopartiteGraph, RootNodes, SingletonNodes, SinkNodes,
+        SparseMatrixMut, edges::Edges,
+    },
 };
 
 /// Result of information content computation.
@@ -34,6 +38,115 @@ impl<G: ?Sized + MonoplexMonopartiteGraph> InformationContentResult<'_, G> {
     pub(crate) fn root_nodes(&self) -> &[G::NodeId] {
         &self.root_nodes
     }
+
+    /// Returns the information content of every node, indexed by node.
+    pub(crate) fn information_contents(&self) -> &[f64] {
+        &self.information_contents
+    }
+
+    /// Computes the Resnik score of every pair of distinct nodes sharing at
+    /// least one common ancestor, and collects in a square sparse matrix the
+    /// pairs whose score is at least `threshold`.
+    ///
+    /// The descendants of every node, itself included, are computed once in
+    /// reverse topological order and inverted into ancestor sets. The Resnik
+    /// score of a pair is then the largest information content among the
+    /// ancestors of the left node which are also ancestors of the right one,
+    /// and never less than zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - The minimum score of the collected pairs.
+    /// * `score` - Maps the left node, the right node and their Resnik score to
+    ///   the score of the pair.
+    pub(crate) fn thresholded_common_ancestors<F>(
+        &self,
+        threshold: f64,
+        score: F,
+    ) -> ValuedCSR2D<usize, usize, usize, f64>
+    where
+        F: Fn(usize, usize, f64) -> f64,
+    {
+        let number_of_nodes: usize = self.graph.number_of_nodes().as_();
+        let topological_ordering = self
+            .graph
+            .edges()
+            .matrix()
+            .kahn()
+            .expect("The graph was checked to be a DAG upon creation.");
+        let mut sorted_nodes: Vec<G::NodeId> = self.graph.node_ids().collect();
+        sorted_nodes.sort_unstable_by(|&a, &b| {
+            topological_ordering[b.as_()].as_().cmp(&topological_ordering[a.as_()].as_())
+        });
+
+        // Successors are processed before their predecessors, so that the
+        // descendants of a node are the union of those of its successors.
+        let mut descendants: Vec<Vec<usize>> = vec![Vec::new(); number_of_nodes];
+        let mut last_visit = vec![usize::MAX; number_of_nodes];
+        for &node_id in &sorted_nodes {
+            let node = node_id.as_();
+            let mut node_descendants = vec![node];
+            last_visit[node] = node;
+            for successor in self.graph.successors(node_id) {
+                for &descendant in &descendants[successor.as_()] {
+                    if last_visit[descendant] != node {
+                        last_visit[descendant] = node;
+                        node_descendants.push(descendant);
+                    }
+                }
+            }
+            node_descendants.sort_unstable();
+            descendants[node] = node_descendants;
+        }
+
+        let mut ancestors: Vec<Vec<usize>> = vec![Vec::new(); number_of_nodes];
+        for (ancestor, ancestor_descendants) in descendants.iter().enumerate() {
+            for &descendant in ancestor_descendants {
+                ancestors[descendant].push(ancestor);
+            }
+        }
+
+        let mut best = vec![0.0; number_of_nodes];
+        let mut is_touched = vec![false; number_of_nodes];
+        let mut touched: Vec<usize> = Vec::new();
+        let mut entries: Vec<(usize, usize, f64)> = Vec::new();
+        for (left, left_ancestors) in ancestors.iter().enumerate() {
+            for &ancestor in left_ancestors {
+                let information_content = self.information_contents[ancestor];
+                for &right in &descendants[ancestor] {
+                    if right == left {
+                        continue;
+                    }
+                    if !is_touched[right] {
+                        is_touched[right] = true;
+                        touched.push(right);
+                        best[right] = information_content;
+                    } else if information_content > best[right] {
+                        best[right] = information_content;
+                    }
+                }
+            }
+            touched.sort_unstable();
+            for right in touched.drain(..) {
+                let similarity = score(left, right, best[right].max(0.0));
+                is_touched[right] = false;
+                if similarity >= threshold {
+                    entries.push((left, right, similarity));
+                }
+            }
+        }
+
+        let mut similarities: ValuedCSR2D<usize, usize, usize, f64> =
+            SparseMatrixMut::with_sparse_shaped_capacity(
+                (number_of_nodes, number_of_nodes),
+                entries.len(),
+            );
+        for entry in entries {
+            MatrixMut::add(&mut similarities, entry)
+                .expect("Entries are generated sorted by row and column.");
+        }
+        similarities
+    }
 }
 
 impl<G: ?Sized + MonoplexMonopartiteGraph> Index<G::NodeId> for InformationContentResult<'_, G> {
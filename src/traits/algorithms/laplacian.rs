@@ -0,0 +1,300 @@
+//! Submodule providing the `Laplacian` trait, building the combinatorial and
+//! the symmetric normalized Laplacians of the weighted undirected graph
+//! represented by a symmetric sparse matrix.
+//!
+//! With A the weighted adjacency matrix and D the diagonal matrix of the
+//! weighted degrees, i.e. of the row sums of A, the combinatorial Laplacian
+//! is L = D − A and the normalized Laplacian is D^{-1/2} L D^{-1/2}, which
+//! equals I − D^{-1/2} A D^{-1/2} on the nodes of positive degree. As in
+//! NetworkX, isolated nodes have a null row in both Laplacians, and
+//! self-loops count once in the degree of their node.
+
+use alloc::vec::Vec;
+
+use num_traits::{AsPrimitive, ToPrimitive};
+
+use crate::{
+    impls::ValuedCSR2D,
+    traits::{MatrixMut, SparseMatrix2D, SparseMatrixMut, SparseValuedMatrix2D},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+/// Error enumeration for the construction of Laplacians.
+pub enum LaplacianError {
+    /// The matrix is not square.
+    #[error("The adjacency matrix must be square, but received shape ({rows}, {columns}).")]
+    NonSquareMatrix {
+        /// Number of rows in the matrix.
+        rows: usize,
+        /// Number of columns in the matrix.
+        columns: usize,
+    },
+    /// The weight cannot be represented as `f64`.
+    #[error("Found a weight on ({source_id}, {destination_id}) that cannot be represented as f64.")]
+    UnrepresentableWeight {
+        /// Source node identifier.
+        source_id: usize,
+        /// Destination node identifier.
+        destination_id: usize,
+    },
+    /// The weight is not finite.
+    #[error("Found a non-finite weight on ({source_id}, {destination_id}).")]
+    NonFiniteWeight {
+        /// Source node identifier.
+        source_id: usize,
+        /// Destination node identifier.
+        destination_id: usize,
+    },
+    /// The weight is negative.
+    #[error("Found a negative weight on ({source_id}, {destination_id}).")]
+    NegativeWeight {
+        /// Source node identifier.
+        source_id: usize,
+        /// Destination node identifier.
+        destination_id: usize,
+    },
+    /// The matrix is not symmetric.
+    #[error(
+        "The adjacency matrix is not symmetric: entry ({source_id}, {destination_id}) has no matching reverse entry."
+    )]
+    NonSymmetricWeight {
+        /// Source node identifier.
+        source_id: usize,
+        /// Destination node identifier.
+        destination_id: usize,
+    },
+}
+
+/// The sparse matrix type of the Laplacians.
+type LaplacianMatrix = ValuedCSR2D<usize, usize, usize, f64>;
+
+/// Trait providing the Laplacians of the weighted undirected graph
+/// represented by a square and symmetric sparse matrix of finite and
+/// non-negative weights.
+pub trait Laplacian: SparseValuedMatrix2D + Sized
+where
+    Self::RowIndex: AsPrimitive<usize>,
+    Self::ColumnIndex: AsPrimitive<usize>,
+    Self::Value: ToPrimitive,
+{
+    /// Returns the combinatorial Laplacian D − A.
+    ///
+    /// Each row holds the weighted degree of its node on the diagonal and the
+    /// opposite of the weights of its edges elsewhere, so that its entries sum
+    /// to zero. Null diagonal entries and null weights are not stored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when:
+    /// - the matrix is not square or not symmetric;
+    /// - at least one weight cannot be represented as `f64`, is non-finite or
+    ///   is negative.
+    ///
+    /// # Complexity
+    ///
+    /// O(V + E log Δ) time and O(V + E) space, where Δ is the largest number
+    /// of entries in a row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{
+    ///     impls::{SymmetricCSR2D, ValuedCSR2D},
+    ///     prelude::*,
+    ///     traits::Laplacian,
+    /// };
+    ///
+    /// let adjacency: SymmetricCSR2D<ValuedCSR2D<usize, usize, usize, f64>> =
+    ///     SymmetricCSR2D::from_sorted_upper_triangular_entries(3, vec![(0, 1, 2.0), (1, 2, 1.0)])
+    ///         .unwrap();
+    ///
+    /// let laplacian = adjacency.laplacian().unwrap();
+    /// assert_eq!(laplacian.sparse_row_values(0).collect::<Vec<_>>(), vec![2.0, -2.0]);
+    /// assert_eq!(laplacian.sparse_row_values(1).collect::<Vec<_>>(), vec![-2.0, 3.0, -1.0]);
+    /// assert_eq!(laplacian.sparse_row_values(2).collect::<Vec<_>>(), vec![-1.0, 1.0]);
+    /// ```
+    #[inline]
+    fn laplacian(&self) -> Result<LaplacianMatrix, LaplacianError> {
+        let rows = weighted_rows(self)?;
+        let degrees = weighted_degrees(&rows);
+        Ok(build_laplacian(
+            &rows,
+            |row, column, weight| {
+                if row == column { degrees[row] - weight } else { -weight }
+            },
+        ))
+    }
+
+    /// Returns the symmetric normalized Laplacian D^{-1/2} (D − A) D^{-1/2}.
+    ///
+    /// Its diagonal entries are one, or one minus the normalized weight of
+    /// the self-loop, on the nodes of positive degree, and its off-diagonal
+    /// entries are −w / √(d_i d_j). The rows of isolated nodes are empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when:
+    /// - the matrix is not square or not symmetric;
+    /// - at least one weight cannot be represented as `f64`, is non-finite or
+    ///   is negative.
+    ///
+    /// # Complexity
+    ///
+    /// O(V + E log Δ) time and O(V + E) space, where Δ is the largest number
+    /// of entries in a row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{
+    ///     impls::{SymmetricCSR2D, ValuedCSR2D},
+    ///     prelude::*,
+    ///     traits::Laplacian,
+    /// };
+    ///
+    /// let adjacency: SymmetricCSR2D<ValuedCSR2D<usize, usize, usize, f64>> =
+    ///     SymmetricCSR2D::from_sorted_upper_triangular_entries(3, vec![(0, 1, 1.0), (1, 2, 1.0)])
+    ///         .unwrap();
+    ///
+    /// let laplacian = adjacency.normalized_laplacian().unwrap();
+    /// let off_diagonal = -1.0 / 2.0_f64.sqrt();
+    /// assert_eq!(laplacian.sparse_row_values(0).collect::<Vec<_>>(), vec![1.0, off_diagonal]);
+    /// assert_eq!(
+    ///     laplacian.sparse_row_values(1).collect::<Vec<_>>(),
+    ///     vec![off_diagonal, 1.0, off_diagonal]
+    /// );
+    /// ```
+    #[inline]
+    fn normalized_laplacian(&self) -> Result<LaplacianMatrix, LaplacianError> {
+        let rows = weighted_rows(self)?;
+        let degrees = weighted_degrees(&rows);
+        let inverse_square_roots: Vec<f64> = degrees
+            .iter()
+            .map(|&degree| if degree > 0.0 { 1.0 / degree.sqrt() } else { 0.0 })
+            .collect();
+        Ok(build_laplacian(&rows, |row, column, weight| {
+            if row != column {
+                -weight * inverse_square_roots[row] * inverse_square_roots[column]
+            } else if degrees[row] > 0.0 {
+                1.0 - weight / degrees[row]
+            } else {
+                0.0
+            }
+        }))
+    }
+}
+
+impl<M> Laplacian for M
+where
+    M: SparseValuedMatrix2D,
+    M::RowIndex: AsPrimitive<usize>,
+    M::ColumnIndex: AsPrimitive<usize>,
+    M::Value: ToPrimitive,
+{
+}
+
+/// Returns the positive weights of each row, sorted by column, after checking
+/// that the matrix is a valid weighted adjacency matrix.
+fn weighted_rows<M>(matrix: &M) -> Result<Vec<Vec<(usize, f64)>>, LaplacianError>
+where
+    M: SparseValuedMatrix2D,
+    M::RowIndex: AsPrimitive<usize>,
+    M::ColumnIndex: AsPrimitive<usize>,
+    M::Value: ToPrimitive,
+{
+    let rows = matrix.number_of_rows().as_();
+    let columns = matrix.number_of_columns().as_();
+    if rows != columns {
+        return Err(LaplacianError::NonSquareMatrix { rows, columns });
+    }
+
+    let mut weighted_rows: Vec<Vec<(usize, f64)>> = Vec::with_capacity(rows);
+    for (row_id, entries) in matrix.rows_with_values() {
+        let source_id = row_id.as_();
+        let mut weights = Vec::new();
+        for (column_id, weight) in entries {
+            let destination_id = column_id.as_();
+            let weight = weight
+                .to_f64()
+                .ok_or(LaplacianError::UnrepresentableWeight { source_id, destination_id })?;
+            if !weight.is_finite() {
+                return Err(LaplacianError::NonFiniteWeight { source_id, destination_id });
+            }
+            if weight < 0.0 {
+                return Err(LaplacianError::NegativeWeight { source_id, destination_id });
+            }
+            if weight > 0.0 {
+                weights.push((destination_id, weight));
+            }
+        }
+        weights.sort_unstable_by_key(|&(destination_id, _)| destination_id);
+        weighted_rows.push(weights);
+    }
+
+    for (source_id, weights) in weighted_rows.iter().enumerate() {
+        for &(destination_id, weight) in weights {
+            let reverse = weighted_rows[destination_id]
+                .binary_search_by_key(&source_id, |&(column, _)| column)
+                .map(|position| weighted_rows[destination_id][position].1);
+            if reverse != Ok(weight) {
+                return Err(LaplacianError::NonSymmetricWeight { source_id, destination_id });
+            }
+        }
+    }
+
+    Ok(weighted_rows)
+}
+
+/// Returns the weighted degree of each node.
+fn weighted_degrees(rows: &[Vec<(usize, f64)>]) -> Vec<f64> {
+    rows.iter().map(|weights| weights.iter().map(|&(_, weight)| weight).sum()).collect()
+}
+
+/// Builds the Laplacian whose entry at `(row, column)` is provided by `entry`
+/// from the weight of the edge, zero on the diagonal of nodes without
+/// self-loop, skipping the null entries.
+fn build_laplacian<F>(rows: &[Vec<(usize, f64)>], mut entry: F) -> LaplacianMatrix
+where
+    F: FnMut(usize, usize, f64) -> f64,
+{
+    let number_of_entries = rows.iter().map(|weights| weights.len() + 1).sum();
+    let mut laplacian: LaplacianMatrix =
+        SparseMatrixMut::with_sparse_shaped_capacity((rows.len(), rows.len()), number_of_entries);
+    for (row, weights) in rows.iter().enumerate() {
+        let diagonal_position = weights.partition_point(|&(column, _)| column < row);
+        let has_self_loop =
+            weights.get(diagonal_position).is_some_and(|&(column, _)| column == row);
+        let mut add = |column: usize, weight: f64| {
+            let value = entry(row, column, weight);
+            if value != 0.0 {
+                MatrixMut::add(&mut laplacian, (row, column, value))
+                    .expect("Entries are sorted by row and column.");
+            }
+        };
+        for &(column, weight) in &weights[..diagonal_position] {
+            add(column, weight);
+        }
+        if !has_self_loop {
+            add(row, 0.0);
+        }
+        for &(column, weight) in &weights[diagonal_position..] {
+            add(column, weight);
+        }
+    }
+    laplacian
+}
+
+/// Writes the product of the Laplacian with the vector into `product`.
+pub(crate) fn sparse_matrix_vector_product(
+    matrix: &LaplacianMatrix,
+    vector: &[f64],
+    product: &mut [f64],
+) {
+    for (row, value) in product.iter_mut().enumerate() {
+        *value = matrix
+            .sparse_row(row)
+            .zip(matrix.sparse_row_values(row))
+            .map(|(column, weight)| weight * vector[column])
+            .sum();
+    }
+}
@@ -0,0 +1,410 @@
+//! Submodule providing the `Layout` trait, placing the nodes of an undirected
+//! graph in the plane by force-directed simulation.
+//!
+//! Edges pull their endpoints together while all pairs of nodes push each
+//! other apart, and the nodes move along their net force by a step bounded
+//! by a temperature cooling down linearly over the iterations. The repulsions
+//! between all pairs of nodes are approximated as in Barnes–Hut: at every
+//! iteration the positions are indexed by a [`KdTree`], and a subtree whose
+//! extent is small compared with its distance from a node acts on that node
+//! as a single body at its centre of mass, so that an iteration takes
+//! O(V log V + E) expected time instead of O(V² + E).
+
+use alloc::{vec, vec::Vec};
+
+use num_traits::AsPrimitive;
+
+use super::randomized_graphs::XorShift64;
+use crate::{
+    spatial::KdTree,
+    traits::{MonopartiteGraph, UndirectedMonopartiteMonoplexGraph},
+};
+
+/// Forces moving the nodes in [`Layout::layout`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ForceModel {
+    /// The forces of Fruchterman and Reingold: edges attract their endpoints
+    /// by `d² / k` and all pairs of nodes repel each other by `k² / d`, with
+    /// an ideal edge length `k` of one.
+    FruchtermanReingold,
+    /// The forces of ForceAtlas2: edges attract their endpoints by `d`, pairs
+    /// of nodes of degrees `a` and `b` repel each other by
+    /// `scaling · (a + 1)(b + 1) / d`, and every node of degree `a` is pulled
+    /// towards the origin by `gravity · (a + 1)`, keeping disconnected
+    /// components together.
+    ForceAtlas2 {
+        /// Strength of the repulsion, spreading the layout out.
+        scaling: f64,
+        /// Strength of the pull towards the origin.
+        gravity: f64,
+    },
+}
+
+/// Configuration of [`Layout::layout`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LayoutConfig {
+    /// The forces moving the nodes.
+    pub model: ForceModel,
+    /// Number of iterations of the simulation.
+    pub iterations: usize,
+    /// Opening angle of the Barnes–Hut approximation: a subtree acts as a
+    /// single body on nodes farther than its extent divided by `theta`. Zero
+    /// computes the repulsions exactly.
+    pub theta: f64,
+    /// Random seed of the initial positions.
+    pub seed: u64,
+}
+
+impl Default for LayoutConfig {
+    #[inline]
+    fn default() -> Self {
+        Self { model: ForceModel::FruchtermanReingold, iterations: 300, theta: 0.8, seed: 42 }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+/// Error enumeration for force-directed layouts.
+pub enum LayoutError {
+    /// The opening angle is negative or not finite.
+    #[error("The Barnes-Hut opening angle must be finite and non-negative.")]
+    InvalidTheta,
+    /// The scaling of ForceAtlas2 is not positive, or its gravity is negative,
+    /// or either is not finite.
+    #[error(
+        "The ForceAtlas2 scaling must be finite and positive, and its gravity finite and non-negative."
+    )]
+    InvalidForceAtlas2Parameters,
+}
+
+impl From<LayoutError>
+    for crate::errors::monopartite_graph_error::algorithms::MonopartiteAlgorithmError
+{
+    #[inline]
+    fn from(error: LayoutError) -> Self {
+        Self::LayoutError(error)
+    }
+}
+
+impl<G: MonopartiteGraph> From<LayoutError> for crate::errors::MonopartiteError<G> {
+    #[inline]
+    fn from(error: LayoutError) -> Self {
+        Self::AlgorithmError(error.into())
+    }
+}
+
+/// Trait providing force-directed layouts of undirected graphs.
+pub trait Layout: UndirectedMonopartiteMonoplexGraph
+where
+    Self::NodeId: AsPrimitive<usize>,
+{
+    /// Returns the coordinates in the plane of every node of the graph.
+    ///
+    /// The nodes start at positions drawn uniformly from the seed in a
+    /// square of area V, so that the layout is deterministic, and move for
+    /// the configured number of iterations. The step of a node is bounded by
+    /// a temperature starting at a tenth of the side of the square and
+    /// decreasing linearly to zero. Self-loops are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `config`: The forces, the number of iterations, the opening angle and
+    ///   the seed.
+    ///
+    /// # Errors
+    ///
+    /// * [`LayoutError::InvalidTheta`] if the opening angle is negative or not
+    ///   finite.
+    /// * [`LayoutError::InvalidForceAtlas2Parameters`] if the scaling of
+    ///   ForceAtlas2 is not positive or its gravity is negative.
+    ///
+    /// # Complexity
+    ///
+    /// O(I · (V log V + E)) expected time for I iterations with a positive
+    /// opening angle, O(I · (V² + E)) with a null one, and O(V + E) space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{
+    ///     impls::{CSR2D, SortedVec, SymmetricCSR2D},
+    ///     prelude::*,
+    ///     traits::{EdgesBuilder, Layout, LayoutConfig, VocabularyBuilder},
+    /// };
+    ///
+    /// let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+    ///     .expected_number_of_symbols(4)
+    ///     .symbols((0..4).enumerate())
+    ///     .build()
+    ///     .unwrap();
+    /// let edges: SymmetricCSR2D<CSR2D<usize, usize, usize>> = UndiEdgesBuilder::default()
+    ///     .expected_number_of_edges(3)
+    ///     .expected_shape(4)
+    ///     .edges(vec![(0, 1), (1, 2), (2, 3)].into_iter())
+    ///     .build()
+    ///     .unwrap();
+    /// let path: UndiGraph<usize> = UndiGraph::from((nodes, edges));
+    ///
+    /// let positions = path.layout(&LayoutConfig::default()).unwrap();
+    /// assert_eq!(positions.len(), 4);
+    /// assert_eq!(positions, path.layout(&LayoutConfig::default()).unwrap());
+    /// // The ends of the path lie farther apart than consecutive nodes.
+    /// let distance = |a: [f64; 2], b: [f64; 2]| (a[0] - b[0]).hypot(a[1] - b[1]);
+    /// assert!(distance(positions[0], positions[3]) > distance(positions[0], positions[1]));
+    /// ```
+    #[inline]
+    fn layout(&self, config: &LayoutConfig) -> Result<Vec<[f64; 2]>, LayoutError> {
+        if !config.theta.is_finite() || config.theta < 0.0 {
+            return Err(LayoutError::InvalidTheta);
+        }
+        if let ForceModel::ForceAtlas2 { scaling, gravity } = config.model {
+            if !scaling.is_finite() || scaling <= 0.0 || !gravity.is_finite() || gravity < 0.0 {
+                return Err(LayoutError::InvalidForceAtlas2Parameters);
+            }
+        }
+
+        let number_of_nodes = self.number_of_nodes().as_();
+        let mut edges: Vec<(usize, usize)> = Vec::new();
+        let mut degrees = vec![0usize; number_of_nodes];
+        for node in self.node_ids() {
+            for neighbor in self.neighbors(node) {
+                if neighbor != node {
+                    degrees[node.as_()] += 1;
+                }
+                if neighbor > node {
+                    edges.push((node.as_(), neighbor.as_()));
+                }
+            }
+        }
+
+        let side = usize_to_f64(number_of_nodes).sqrt();
+        let mut rng = XorShift64::from(XorShift64::normalize_seed(config.seed));
+        let mut unit = || {
+            let raw = rng.next().unwrap_or_else(|| unreachable!("The generator never ends."));
+            u64_to_f64(raw >> 11) / u64_to_f64(1 << 53) - 0.5
+        };
+        let mut positions: Vec<[f64; 2]> =
+            (0..number_of_nodes).map(|_| [side * unit(), side * unit()]).collect();
+
+        let simulation = Simulation::new(config, &degrees, edges);
+        for iteration in 0..config.iterations {
+            let temperature =
+                side / 10.0 * (1.0 - usize_to_f64(iteration) / usize_to_f64(config.iterations));
+            let forces = simulation.forces(&positions);
+            for (position, force) in positions.iter_mut().zip(forces) {
+                let norm = force[0].hypot(force[1]);
+                if norm > 0.0 {
+                    let step = norm.min(temperature) / norm;
+                    position[0] += force[0] * step;
+                    position[1] += force[1] * step;
+                }
+            }
+        }
+        Ok(positions)
+    }
+}
+
+impl<G> Layout for G
+where
+    G: UndirectedMonopartiteMonoplexGraph,
+    G::NodeId: AsPrimitive<usize>,
+{
+}
+
+/// Forces of the simulation, independent of the positions of the nodes.
+struct Simulation {
+    /// The forces moving the nodes.
+    model: ForceModel,
+    /// The opening angle of the Barnes–Hut approximation.
+    theta: f64,
+    /// The edges, each listed once, without self-loops.
+    edges: Vec<(usize, usize)>,
+    /// The mass of each node, scaling its repulsion and gravity.
+    masses: Vec<f64>,
+    /// The factor of the repulsion between two nodes of unit mass.
+    repulsion: f64,
+}
+
+/// Summary of a subtree of the k-d tree, acting as a single body on the nodes
+/// far from it.
+#[derive(Clone, Copy, Default)]
+struct Body {
+    /// The total mass of the subtree.
+    mass: f64,
+    /// The centre of mass of the subtree.
+    center: [f64; 2],
+    /// The diagonal of the bounding box of the subtree.
+    extent: f64,
+}
+
+impl Simulation {
+    /// Returns the forces of the model over the graph.
+    fn new(config: &LayoutConfig, degrees: &[usize], edges: Vec<(usize, usize)>) -> Self {
+        let (masses, repulsion) = match config.model {
+            ForceModel::FruchtermanReingold => (vec![1.0; degrees.len()], 1.0),
+            ForceModel::ForceAtlas2 { scaling, .. } => {
+                (degrees.iter().map(|&degree| usize_to_f64(degree + 1)).collect(), scaling)
+            }
+        };
+        Self { model: config.model, theta: config.theta, edges, masses, repulsion }
+    }
+
+    /// Returns the net force on every node at the provided positions.
+    fn forces(&self, positions: &[[f64; 2]]) -> Vec<[f64; 2]> {
+        let tree: KdTree<2, ()> = positions.iter().map(|&position| (position, ())).collect();
+        let mut bodies = vec![Body::default(); positions.len()];
+        self.summarize(tree.permutation(), positions, 0, positions.len(), &mut bodies);
+
+        let mut forces: Vec<[f64; 2]> = (0..positions.len())
+            .map(|node| {
+                let mut force = [0.0; 2];
+                self.repel(
+                    tree.permutation(),
+                    positions,
+                    &bodies,
+                    node,
+                    0,
+                    positions.len(),
+                    &mut force,
+                );
+                force
+            })
+            .collect();
+
+        for &(source, destination) in &self.edges {
+            let delta = [
+                positions[destination][0] - positions[source][0],
+                positions[destination][1] - positions[source][1],
+            ];
+            let factor = match self.model {
+                ForceModel::FruchtermanReingold => delta[0].hypot(delta[1]),
+                ForceModel::ForceAtlas2 { .. } => 1.0,
+            };
+            for axis in 0..2 {
+                forces[source][axis] += delta[axis] * factor;
+                forces[destination][axis] -= delta[axis] * factor;
+            }
+        }
+
+        if let ForceModel::ForceAtlas2 { gravity, .. } = self.model {
+            for ((force, position), mass) in forces.iter_mut().zip(positions).zip(&self.masses) {
+                let distance = position[0].hypot(position[1]);
+                if distance > 0.0 {
+                    force[0] -= gravity * mass * position[0] / distance;
+                    force[1] -= gravity * mass * position[1] / distance;
+                }
+            }
+        }
+        forces
+    }
+
+    /// Fills the bodies of the subtrees of the range `lo..hi` of the
+    /// permutation of the k-d tree, each stored at the position of its root,
+    /// and returns the body of the range with the corners of its bounding box.
+    fn summarize(
+        &self,
+        permutation: &[usize],
+        positions: &[[f64; 2]],
+        lo: usize,
+        hi: usize,
+        bodies: &mut [Body],
+    ) -> Option<(Body, [f64; 2], [f64; 2])> {
+        if lo >= hi {
+            return None;
+        }
+        let median = lo + (hi - lo) / 2;
+        let node = permutation[median];
+        let mass = self.masses[node];
+        let mut total = mass;
+        let mut moment = [positions[node][0] * mass, positions[node][1] * mass];
+        let (mut minimum, mut maximum) = (positions[node], positions[node]);
+        for (child_lo, child_hi) in [(lo, median), (median + 1, hi)] {
+            if let Some((child, child_minimum, child_maximum)) =
+                self.summarize(permutation, positions, child_lo, child_hi, bodies)
+            {
+                total += child.mass;
+                for axis in 0..2 {
+                    moment[axis] += child.center[axis] * child.mass;
+                    minimum[axis] = minimum[axis].min(child_minimum[axis]);
+                    maximum[axis] = maximum[axis].max(child_maximum[axis]);
+                }
+            }
+        }
+        let body = Body {
+            mass: total,
+            center: [moment[0] / total, moment[1] / total],
+            extent: (maximum[0] - minimum[0]).hypot(maximum[1] - minimum[1]),
+        };
+        bodies[median] = body;
+        Some((body, minimum, maximum))
+    }
+
+    /// Adds to the force the repulsion of the nodes of the range `lo..hi` of
+    /// the permutation of the k-d tree on the provided node.
+    #[allow(clippy::too_many_arguments)]
+    fn repel(
+        &self,
+        permutation: &[usize],
+        positions: &[[f64; 2]],
+        bodies: &[Body],
+        node: usize,
+        lo: usize,
+        hi: usize,
+        force: &mut [f64; 2],
+    ) {
+        if lo >= hi {
+            return;
+        }
+        let median = lo + (hi - lo) / 2;
+        let body = bodies[median];
+        if hi - lo > 1
+            && self.push(positions[node], node, body.center, body.mass, body.extent, force)
+        {
+            return;
+        }
+        let other = permutation[median];
+        if other != node {
+            self.push(positions[node], node, positions[other], self.masses[other], 0.0, force);
+        }
+        self.repel(permutation, positions, bodies, node, lo, median, force);
+        self.repel(permutation, positions, bodies, node, median + 1, hi, force);
+    }
+
+    /// Adds to the force the repulsion on the node of a body of the provided
+    /// mass and extent, returning whether the body is far enough to act as a
+    /// single one. Coincident bodies exert no force.
+    fn push(
+        &self,
+        position: [f64; 2],
+        node: usize,
+        center: [f64; 2],
+        mass: f64,
+        extent: f64,
+        force: &mut [f64; 2],
+    ) -> bool {
+        let delta = [position[0] - center[0], position[1] - center[1]];
+        let squared_distance = delta[0] * delta[0] + delta[1] * delta[1];
+        if extent > 0.0 && extent * extent >= self.theta * self.theta * squared_distance {
+            return false;
+        }
+        if squared_distance > 0.0 {
+            let factor = self.repulsion * self.masses[node] * mass / squared_distance;
+            force[0] += delta[0] * factor;
+            force[1] += delta[1] * factor;
+        }
+        true
+    }
+}
+
+/// Converts a count into a floating point value.
+#[allow(clippy::cast_precision_loss)]
+#[inline]
+fn usize_to_f64(value: usize) -> f64 {
+    value as f64
+}
+
+/// Converts a random integer into a floating point value.
+#[allow(clippy::cast_precision_loss)]
+#[inline]
+fn u64_to_f64(value: u64) -> f64 {
+    value as f64
+}
@@ -7,9 +7,10 @@ use num_traits::{AsPrimitive, ToPrimitive};
 use rand::{Rng, SeedableRng, rngs::SmallRng, seq::SliceRandom};
 
 use super::modularity::{
-    LocalMovingConfig, ModularityError, WeightedUndirectedGraph, approx_eq, local_moving,
-    marker_partition, mix_seed, modularity, project_partition, regroup_members, renumber_partition,
-    split_disconnected_communities, validate_common_config, validate_leiden_config,
+    LocalMovingConfig, LocalMovingCounters, ModularityError, WeightedUndirectedGraph, approx_eq,
+    local_moving, marker_partition, mix_seed, modularity, project_partition, regroup_members,
+    renumber_partition, split_disconnected_communities, validate_common_config,
+    validate_leiden_config,
 };
 use crate::traits::{Finite, Number, PositiveInteger, SparseValuedMatrix2D};
 
@@ -171,6 +172,7 @@ where
                     seed: config.seed,
                 },
                 level_index,
+                &mut LocalMovingCounters::default(),
             );
             renumber_partition(&mut local_partition);
 
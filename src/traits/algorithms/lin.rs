@@ -3,6 +3,7 @@
 use core::f64;
 
 use crate::{
+    impls::ValuedCSR2D,
     prelude::{information_content::InformationContentError, resnik::ResnikResult},
     traits::{
         MonoplexMonopartiteGraph, Resnik, ScalarSimilarity,
@@ -89,6 +90,69 @@ impl<'graph, G: ?Sized + MonoplexMonopartiteGraph> AsRef<InformationContentResul
     }
 }
 
+impl<G: ?Sized + MonoplexMonopartiteGraph> LinResult<'_, G> {
+    /// Returns the Lin similarities of all pairs of distinct nodes which are
+    /// at least `threshold`, as a square sparse matrix indexed by node.
+    ///
+    /// The ancestor sets of the nodes are computed once, as in
+    /// [`ResnikResult::pairwise_similarities`], and only pairs of nodes
+    /// sharing a common ancestor are compared. The returned matrix is
+    /// symmetric, and its values match those of `similarity`.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - The minimum similarity of the emitted pairs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{
+    ///     impls::{SortedVec, SquareCSR2D},
+    ///     prelude::*,
+    ///     traits::{EdgesBuilder, ScalarSimilarity, VocabularyBuilder},
+    /// };
+    ///
+    /// let edges: Vec<(usize, usize)> = vec![(0, 1), (0, 2), (1, 3), (1, 4)];
+    /// let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+    ///     .expected_number_of_symbols(5)
+    ///     .symbols((0..5).enumerate())
+    ///     .build()
+    ///     .unwrap();
+    /// let edges: SquareCSR2D<_> = DiEdgesBuilder::default()
+    ///     .expected_number_of_edges(edges.len())
+    ///     .expected_shape(nodes.len())
+    ///     .edges(edges.into_iter())
+    ///     .build()
+    ///     .unwrap();
+    /// let graph: DiGraph<usize> = DiGraph::from((nodes, edges));
+    ///
+    /// let lin = graph.lin(&[1, 1, 1, 1, 1]).unwrap();
+    /// let similarities = lin.pairwise_similarities(0.3);
+    /// for ((left, right), value) in
+    ///     SparseMatrix::sparse_coordinates(&similarities).zip(similarities.sparse_values())
+    /// {
+    ///     assert!((value - lin.similarity(&left, &right)).abs() < 1e-12);
+    /// }
+    /// assert!(
+    ///     SparseMatrix::sparse_coordinates(&similarities)
+    ///         .zip(similarities.sparse_values())
+    ///         .any(|((left, right), _)| (left, right) == (3, 4))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn pairwise_similarities(&self, threshold: f64) -> ValuedCSR2D<usize, usize, usize, f64> {
+        let information_content: &InformationContentResult<G> = self.as_ref();
+        information_content.thresholded_common_ancestors(threshold, |left, right, resnik| {
+            let mut denominator = information_content.information_contents()[left]
+                + information_content.information_contents()[right];
+            if denominator < f64::EPSILON {
+                denominator = f64::EPSILON;
+            }
+            (2.0 * resnik) / denominator
+        })
+    }
+}
+
 impl<G> ScalarSimilarity<G::NodeId, G::NodeId> for LinResult<'_, G>
 where
     G: MonoplexMonopartiteGraph,
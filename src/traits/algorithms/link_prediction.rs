@@ -0,0 +1,235 @@
+//! Submodule providing the `LinkPrediction` trait and its blanket
+//! implementation for undirected graphs, which scores node pairs by their
+//! shared neighbourhood to suggest missing edges.
+use alloc::vec::Vec;
+
+use num_traits::AsPrimitive;
+
+use crate::{impls::SortedIterator, traits::UndirectedMonopartiteMonoplexGraph};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+/// Neighbourhood-based scores used to rank candidate links.
+///
+/// Each score sums a contribution over the common neighbours `w` of the two
+/// nodes, where `deg(w)` is the number of neighbours of `w` other than
+/// itself.
+pub enum LinkPredictionScore {
+    /// Number of common neighbours, each contributing one.
+    CommonNeighbors,
+    /// Adamic–Adar index, each common neighbour contributing `1 / ln deg(w)`,
+    /// so that rare shared neighbours weigh more than hubs.
+    #[default]
+    AdamicAdar,
+    /// Resource allocation index, each common neighbour contributing
+    /// `1 / deg(w)`.
+    ResourceAllocation,
+}
+
+/// Trait providing neighbourhood-based link prediction scores.
+///
+/// # Reference
+///
+/// Liben-Nowell, D., & Kleinberg, J. (2007). [The link-prediction problem for social networks](https://doi.org/10.1002/asi.20591).
+/// Adamic, L. A., & Adar, E. (2003). [Friends and neighbors on the Web](https://doi.org/10.1016/S0378-8733(03)00009-1).
+/// Zhou, T., Lü, L., & Zhang, Y.-C. (2009). [Predicting missing links via local information](https://doi.org/10.1140/epjb/e2009-00335-8).
+pub trait LinkPrediction: UndirectedMonopartiteMonoplexGraph
+where
+    Self::NodeId: AsPrimitive<usize>,
+{
+    /// Returns the provided candidate pairs with their score, sorted by
+    /// decreasing score.
+    ///
+    /// Pairs with equal scores keep the order in which they were provided.
+    /// Pairs without common neighbours score zero. Both nodes of a pair are
+    /// expected to be distinct.
+    ///
+    /// # Arguments
+    ///
+    /// * `score` - The score to compute.
+    /// * `candidates` - The node pairs to score.
+    ///
+    /// # Complexity
+    ///
+    /// O(V + E) time to compute the degrees, then O(deg(u) + deg(v)) per
+    /// candidate pair and O(P log P) to sort the P pairs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{
+    ///     impls::{SortedVec, SymmetricCSR2D},
+    ///     prelude::*,
+    ///     traits::{EdgesBuilder, LinkPrediction, LinkPredictionScore, VocabularyBuilder},
+    /// };
+    ///
+    /// let edges: Vec<(usize, usize)> = vec![(0, 1), (0, 2), (1, 3), (2, 3), (3, 4)];
+    /// let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+    ///     .expected_number_of_symbols(5)
+    ///     .symbols((0..5).enumerate())
+    ///     .build()
+    ///     .unwrap();
+    /// let edges: SymmetricCSR2D<_> = UndiEdgesBuilder::default()
+    ///     .expected_number_of_edges(edges.len())
+    ///     .expected_shape(nodes.len())
+    ///     .edges(edges.into_iter())
+    ///     .build()
+    ///     .unwrap();
+    /// let graph: UndiGraph<usize> = UndiGraph::from((nodes, edges));
+    ///
+    /// let scores = graph.score_links(LinkPredictionScore::CommonNeighbors, [(1, 4), (0, 3)]);
+    /// assert_eq!(scores, vec![(0, 3, 2.0), (1, 4, 1.0)]);
+    /// ```
+    #[inline]
+    fn score_links<I>(
+        &self,
+        score: LinkPredictionScore,
+        candidates: I,
+    ) -> Vec<(Self::NodeId, Self::NodeId, f64)>
+    where
+        I: IntoIterator<Item = (Self::NodeId, Self::NodeId)>,
+    {
+        let contributions = neighbor_contributions(self, score);
+        let mut scored: Vec<(Self::NodeId, Self::NodeId, f64)> = candidates
+            .into_iter()
+            .map(|(source, destination)| {
+                let value = UndirectedMonopartiteMonoplexGraph::neighbors(self, source)
+                    .sorted_intersection(UndirectedMonopartiteMonoplexGraph::neighbors(
+                        self,
+                        destination,
+                    ))
+                    .filter(|&common| common != source && common != destination)
+                    .map(|common| contributions[common.as_()])
+                    .sum();
+                (source, destination, value)
+            })
+            .collect();
+        scored.sort_by(|left, right| right.2.total_cmp(&left.2));
+        scored
+    }
+
+    /// Returns up to `budget` pairs of non-adjacent distinct nodes with the
+    /// highest scores, sorted by decreasing score.
+    ///
+    /// Only pairs sharing at least one neighbour are considered, since all
+    /// others score zero. Every pair is reported once, as `(u, v)` with
+    /// `u < v`, and pairs with equal scores are sorted by `(u, v)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `score` - The score to compute.
+    /// * `budget` - The maximum number of returned pairs.
+    ///
+    /// # Complexity
+    ///
+    /// O(V + Σ deg(w)²) time, where the sum runs over the nodes, and O(V + P)
+    /// space, where P is the number of pairs sharing a neighbour.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{
+    ///     impls::{SortedVec, SymmetricCSR2D},
+    ///     prelude::*,
+    ///     traits::{EdgesBuilder, LinkPrediction, LinkPredictionScore, VocabularyBuilder},
+    /// };
+    ///
+    /// let edges: Vec<(usize, usize)> = vec![(0, 1), (0, 2), (1, 3), (2, 3), (3, 4)];
+    /// let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+    ///     .expected_number_of_symbols(5)
+    ///     .symbols((0..5).enumerate())
+    ///     .build()
+    ///     .unwrap();
+    /// let edges: SymmetricCSR2D<_> = UndiEdgesBuilder::default()
+    ///     .expected_number_of_edges(edges.len())
+    ///     .expected_shape(nodes.len())
+    ///     .edges(edges.into_iter())
+    ///     .build()
+    ///     .unwrap();
+    /// let graph: UndiGraph<usize> = UndiGraph::from((nodes, edges));
+    ///
+    /// // Both (0, 3) and (1, 2) share two neighbours, (1, 4) and (2, 4) one.
+    /// let predicted = graph.predict_links(LinkPredictionScore::CommonNeighbors, 2);
+    /// assert_eq!(predicted, vec![(0, 3, 2.0), (1, 2, 2.0)]);
+    /// ```
+    #[inline]
+    fn predict_links(
+        &self,
+        score: LinkPredictionScore,
+        budget: usize,
+    ) -> Vec<(Self::NodeId, Self::NodeId, f64)> {
+        let number_of_nodes: usize = self.number_of_nodes().as_();
+        let contributions = neighbor_contributions(self, score);
+        let mut is_neighbor = vec![false; number_of_nodes];
+        let mut accumulated = vec![0.0; number_of_nodes];
+        let mut is_touched = vec![false; number_of_nodes];
+        let mut touched: Vec<Self::NodeId> = Vec::new();
+        let mut predicted: Vec<(Self::NodeId, Self::NodeId, f64)> = Vec::new();
+
+        for source in self.node_ids() {
+            for neighbor in UndirectedMonopartiteMonoplexGraph::neighbors(self, source) {
+                is_neighbor[neighbor.as_()] = true;
+            }
+            for common in UndirectedMonopartiteMonoplexGraph::neighbors(self, source)
+                .filter(|&common| common != source)
+            {
+                for destination in UndirectedMonopartiteMonoplexGraph::neighbors(self, common) {
+                    if destination <= source
+                        || destination == common
+                        || is_neighbor[destination.as_()]
+                    {
+                        continue;
+                    }
+                    if !is_touched[destination.as_()] {
+                        is_touched[destination.as_()] = true;
+                        touched.push(destination);
+                    }
+                    accumulated[destination.as_()] += contributions[common.as_()];
+                }
+            }
+            touched.sort_unstable();
+            for destination in touched.drain(..) {
+                predicted.push((source, destination, accumulated[destination.as_()]));
+                accumulated[destination.as_()] = 0.0;
+                is_touched[destination.as_()] = false;
+            }
+            for neighbor in UndirectedMonopartiteMonoplexGraph::neighbors(self, source) {
+                is_neighbor[neighbor.as_()] = false;
+            }
+        }
+
+        predicted.sort_by(|left, right| right.2.total_cmp(&left.2));
+        predicted.truncate(budget);
+        predicted
+    }
+}
+
+impl<G> LinkPrediction for G
+where
+    G: UndirectedMonopartiteMonoplexGraph,
+    G::NodeId: AsPrimitive<usize>,
+{
+}
+
+/// Returns the contribution of every node to the score of the pairs of which
+/// it is a common neighbour.
+#[allow(clippy::cast_precision_loss)]
+fn neighbor_contributions<G>(graph: &G, score: LinkPredictionScore) -> Vec<f64>
+where
+    G: UndirectedMonopartiteMonoplexGraph + ?Sized,
+    G::NodeId: AsPrimitive<usize>,
+{
+    graph
+        .node_ids()
+        .map(|node| {
+            let degree = UndirectedMonopartiteMonoplexGraph::neighbors(graph, node)
+                .filter(|&neighbor| neighbor != node)
+                .count() as f64;
+            match score {
+                LinkPredictionScore::CommonNeighbors => 1.0,
+                // Common neighbours have at least two neighbours.
+                LinkPredictionScore::AdamicAdar => 1.0 / degree.ln(),
+                LinkPredictionScore::ResourceAllocation => 1.0 / degree,
+            }
+        })
+        .collect()
+}
@@ -6,10 +6,14 @@ use alloc::vec::Vec;
 use num_traits::{AsPrimitive, ToPrimitive};
 
 use super::modularity::{
-    LocalMovingConfig, ModularityError, WeightedUndirectedGraph, local_moving, marker_partition,
-    modularity, project_partition, regroup_members, renumber_partition, validate_common_config,
+    LocalMovingConfig, LocalMovingCounters, ModularityError, WeightedUndirectedGraph, local_moving,
+    marker_partition, modularity, project_partition, regroup_members, renumber_partition,
+    validate_common_config,
+};
+use crate::{
+    impls::ValuedCSR2D,
+    traits::{Finite, Number, PositiveInteger, SparseValuedMatrix2D},
 };
-use crate::traits::{Finite, Number, PositiveInteger, SparseValuedMatrix2D};
 
 #[derive(Debug, Clone, PartialEq)]
 /// Configuration options for the Louvain community detection algorithm.
@@ -50,6 +54,8 @@ pub struct LouvainLevel<Marker> {
     modularity: f64,
     /// Number of node moves performed at this level.
     moved_nodes: usize,
+    /// Weighted graph of the communities at this level, if kept.
+    graph: Option<ValuedCSR2D<usize, usize, usize, f64>>,
 }
 
 impl<Marker> LouvainLevel<Marker> {
@@ -73,12 +79,40 @@ impl<Marker> LouvainLevel<Marker> {
     pub fn moved_nodes(&self) -> usize {
         self.moved_nodes
     }
+
+    /// Returns the weighted graph of the communities at this level, when the
+    /// levels were computed by [`Louvain::louvain_with_level_graphs`].
+    ///
+    /// Its nodes are the communities of [`LouvainLevel::partition`], and its
+    /// entries sum the weights between them, an edge within a community
+    /// counting twice on its diagonal, as in
+    /// [`Quotient::quotient`](super::Quotient::quotient).
+    #[must_use]
+    #[inline]
+    pub fn graph(&self) -> Option<&ValuedCSR2D<usize, usize, usize, f64>> {
+        self.graph.as_ref()
+    }
+}
+
+#[cfg(feature = "metrics")]
+/// Counts of the operations performed by one run of [`Louvain::louvain`],
+/// summed over all the levels.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct LouvainMetrics {
+    /// Number of local-moving passes over the nodes of a level.
+    pub local_passes: usize,
+    /// Number of nodes whose best community was evaluated.
+    pub visited_nodes: usize,
+    /// Number of nodes moved to another community.
+    pub moved_nodes: usize,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 /// Result of the Louvain community detection algorithm.
 pub struct LouvainResult<Marker> {
     levels: Vec<LouvainLevel<Marker>>,
+    #[cfg(feature = "metrics")]
+    metrics: LouvainMetrics,
 }
 
 impl<Marker> LouvainResult<Marker> {
@@ -102,6 +136,14 @@ impl<Marker> LouvainResult<Marker> {
     pub fn levels(&self) -> &[LouvainLevel<Marker>] {
         &self.levels
     }
+
+    #[cfg(feature = "metrics")]
+    /// Returns the counts of the operations performed by the algorithm.
+    #[must_use]
+    #[inline]
+    pub fn metrics(&self) -> LouvainMetrics {
+        self.metrics
+    }
 }
 
 /// Trait providing the Louvain community detection algorithm.
@@ -162,58 +204,118 @@ where
     /// ```
     #[inline]
     fn louvain(&self, config: &LouvainConfig) -> Result<LouvainResult<Marker>, ModularityError> {
-        validate_common_config(
-            config.resolution,
-            config.modularity_threshold,
-            config.max_levels,
-            config.max_local_passes,
-        )?;
-
-        let mut graph = WeightedUndirectedGraph::from_matrix(self)?;
-
-        let original_number_of_nodes = self.number_of_rows().as_();
-        let mut current_members: Vec<Vec<usize>> =
-            (0..original_number_of_nodes).map(|node_id| vec![node_id]).collect();
-
-        let mut levels: Vec<LouvainLevel<Marker>> = Vec::new();
-        let mut previous_modularity: Option<f64> = None;
-
-        for level_index in 0..config.max_levels {
-            let (mut partition, moved_nodes) = local_moving(
-                &graph,
-                LocalMovingConfig {
-                    resolution: config.resolution,
-                    max_local_passes: config.max_local_passes,
-                    seed: config.seed,
-                },
-                level_index,
-            );
-            let number_of_communities = renumber_partition(&mut partition);
-            let modularity = modularity(&graph, &partition, config.resolution);
-
-            let original_partition =
-                project_partition(&current_members, &partition, original_number_of_nodes);
-            let marker_partition = marker_partition::<Marker>(&original_partition)?;
-
-            levels.push(LouvainLevel { partition: marker_partition, modularity, moved_nodes });
-
-            if let Some(previous) = previous_modularity {
-                if modularity - previous < config.modularity_threshold {
-                    break;
-                }
-            }
-            previous_modularity = Some(modularity);
+        louvain_levels(self, config, false)
+    }
 
-            if number_of_communities == graph.number_of_nodes() {
+    /// Executes the Louvain algorithm like [`Louvain::louvain`], also keeping
+    /// the weighted graph of the communities of each level, available from
+    /// [`LouvainLevel::graph`].
+    ///
+    /// The partitions and modularities are the same as those returned by
+    /// [`Louvain::louvain`] with the same configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Louvain::louvain`].
+    ///
+    /// # Complexity
+    ///
+    /// The same as [`Louvain::louvain`], plus O(L * E) space for the graphs
+    /// of the levels.
+    #[inline]
+    fn louvain_with_level_graphs(
+        &self,
+        config: &LouvainConfig,
+    ) -> Result<LouvainResult<Marker>, ModularityError> {
+        louvain_levels(self, config, true)
+    }
+}
+
+/// Runs the Louvain levels on the provided matrix, storing the weighted graph
+/// of the communities of each level when `keep_level_graphs` is set.
+fn louvain_levels<G, Marker>(
+    matrix: &G,
+    config: &LouvainConfig,
+    keep_level_graphs: bool,
+) -> Result<LouvainResult<Marker>, ModularityError>
+where
+    G: SparseValuedMatrix2D,
+    Marker: AsPrimitive<usize> + PositiveInteger,
+    G::RowIndex: AsPrimitive<usize>,
+    G::ColumnIndex: AsPrimitive<usize>,
+    G::Value: Number + ToPrimitive + Finite,
+{
+    validate_common_config(
+        config.resolution,
+        config.modularity_threshold,
+        config.max_levels,
+        config.max_local_passes,
+    )?;
+
+    let mut graph = WeightedUndirectedGraph::from_matrix(matrix)?;
+
+    let original_number_of_nodes = matrix.number_of_rows().as_();
+    let mut current_members: Vec<Vec<usize>> =
+        (0..original_number_of_nodes).map(|node_id| vec![node_id]).collect();
+
+    let mut levels: Vec<LouvainLevel<Marker>> = Vec::new();
+    let mut previous_modularity: Option<f64> = None;
+    let mut counters = LocalMovingCounters::default();
+
+    for level_index in 0..config.max_levels {
+        let (mut partition, moved_nodes) = local_moving(
+            &graph,
+            LocalMovingConfig {
+                resolution: config.resolution,
+                max_local_passes: config.max_local_passes,
+                seed: config.seed,
+            },
+            level_index,
+            &mut counters,
+        );
+        let number_of_communities = renumber_partition(&mut partition);
+        let modularity = modularity(&graph, &partition, config.resolution);
+
+        let original_partition =
+            project_partition(&current_members, &partition, original_number_of_nodes);
+        let marker_partition = marker_partition::<Marker>(&original_partition)?;
+
+        let level_graph =
+            keep_level_graphs.then(|| graph.induced(&partition, number_of_communities));
+        levels.push(LouvainLevel {
+            partition: marker_partition,
+            modularity,
+            moved_nodes,
+            graph: level_graph.as_ref().map(WeightedUndirectedGraph::to_matrix),
+        });
+
+        if let Some(previous) = previous_modularity {
+            if modularity - previous < config.modularity_threshold {
                 break;
             }
+        }
+        previous_modularity = Some(modularity);
 
-            graph = graph.induced(&partition, number_of_communities);
-            current_members = regroup_members(current_members, &partition, number_of_communities);
+        if number_of_communities == graph.number_of_nodes() {
+            break;
         }
 
-        Ok(LouvainResult { levels })
+        graph = level_graph.unwrap_or_else(|| graph.induced(&partition, number_of_communities));
+        current_members = regroup_members(current_members, &partition, number_of_communities);
     }
+
+    #[cfg(feature = "metrics")]
+    let metrics = LouvainMetrics {
+        local_passes: counters.passes.count(),
+        visited_nodes: counters.visited_nodes.count(),
+        moved_nodes: levels.iter().map(LouvainLevel::moved_nodes).sum(),
+    };
+
+    Ok(LouvainResult {
+        levels,
+        #[cfg(feature = "metrics")]
+        metrics,
+    })
 }
 
 impl<G, Marker> Louvain<Marker> for G
@@ -232,8 +334,12 @@ mod tests {
 
     #[test]
     fn test_louvain_level_moved_nodes_getter() {
-        let level =
-            LouvainLevel { partition: vec![0usize, 0usize], modularity: 0.5, moved_nodes: 7 };
+        let level = LouvainLevel {
+            partition: vec![0usize, 0usize],
+            modularity: 0.5,
+            moved_nodes: 7,
+            graph: None,
+        };
         assert_eq!(level.moved_nodes(), 7);
     }
 }
@@ -1,9 +1,10 @@
 //! Exact maximum clique search on [`BitSquareMatrix`].
 //!
-//! The public API is split into two traits:
+//! The public API is split into three traits:
 //! - [`MaximumClique`] for the generic bit-parallel solver.
 //! - [`PartitionedMaximumClique`] for the MCES-oriented partition-driven
 //!   solver.
+//! - [`GraphMaximumClique`] running the generic solver on undirected graphs.
 //!
 //! This keeps the generic clique implementation clean while letting the
 //! partitioned MCES path use a dedicated search state modeled after RDKit's
@@ -14,6 +15,7 @@ mod partitioned;
 
 use alloc::vec::Vec;
 
+use num_traits::AsPrimitive;
 pub(crate) use partitioned::partial_u32_best_size_with_budget;
 pub use partitioned::{
     OwnedPartitionLabels, PartitionInfo, PartitionSide, all_best_search,
@@ -21,7 +23,10 @@ pub use partitioned::{
     partial_search_u32_with_bounds, partial_search_with_bounds,
 };
 
-use crate::{impls::BitSquareMatrix, traits::SquareMatrix};
+use crate::{
+    impls::BitSquareMatrix,
+    traits::{SquareMatrix, UndirectedMonopartiteMonoplexGraph},
+};
 
 /// Trait for finding maximum cliques in an undirected graph.
 pub trait MaximumClique {
@@ -175,6 +180,83 @@ impl MaximumClique for BitSquareMatrix {
     }
 }
 
+/// Trait for finding a maximum clique of undirected graphs, by running the
+/// branch-and-bound search of [`MaximumClique`] with greedy coloring bounds
+/// on their adjacency bit matrix. Self-loops are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{
+///     impls::{CSR2D, SortedVec, SymmetricCSR2D},
+///     prelude::*,
+///     traits::{EdgesBuilder, GraphMaximumClique, VocabularyBuilder},
+/// };
+///
+/// let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+///     .expected_number_of_symbols(5)
+///     .symbols((0..5).enumerate())
+///     .build()
+///     .unwrap();
+/// // Two triangles sharing the node 2, the second one extended to a K4.
+/// let edges: SymmetricCSR2D<CSR2D<usize, usize, usize>> = UndiEdgesBuilder::default()
+///     .expected_number_of_edges(8)
+///     .expected_shape(5)
+///     .edges(vec![(0, 1), (0, 2), (1, 2), (1, 3), (1, 4), (2, 3), (2, 4), (3, 4)].into_iter())
+///     .build()
+///     .unwrap();
+/// let graph: UndiGraph<usize> = UndiGraph::from((nodes, edges));
+///
+/// assert_eq!(graph.maximum_clique_of_graph(), vec![1, 2, 3, 4]);
+/// assert_eq!(graph.clique_number(), 4);
+/// ```
+pub trait GraphMaximumClique: UndirectedMonopartiteMonoplexGraph
+where
+    Self::NodeId: AsPrimitive<usize>,
+{
+    /// Returns the sorted node ids of one maximum clique of the graph, which
+    /// is empty only for graphs without nodes.
+    ///
+    /// # Complexity
+    ///
+    /// Exponential time in the worst case, and O(V² + E) space for the
+    /// adjacency bit matrix, which keeps graphs of a few thousand nodes
+    /// practical when their cliques are pruned by the coloring bounds.
+    #[inline]
+    #[must_use]
+    fn maximum_clique_of_graph(&self) -> Vec<Self::NodeId> {
+        let node_ids: Vec<Self::NodeId> = self.node_ids().collect();
+        let adjacency = BitSquareMatrix::from_symmetric_edges(
+            node_ids.len(),
+            node_ids.iter().flat_map(|&node| {
+                self.neighbors(node)
+                    .filter(move |&neighbor| neighbor > node)
+                    .map(move |neighbor| (node.as_(), neighbor.as_()))
+            }),
+        );
+        adjacency.maximum_clique().into_iter().map(|node| node_ids[node]).collect()
+    }
+
+    /// Returns the clique number of the graph, the number of nodes of its
+    /// maximum cliques.
+    ///
+    /// # Complexity
+    ///
+    /// Same as [`GraphMaximumClique::maximum_clique_of_graph`].
+    #[inline]
+    #[must_use]
+    fn clique_number(&self) -> usize {
+        self.maximum_clique_of_graph().len()
+    }
+}
+
+impl<G> GraphMaximumClique for G
+where
+    G: UndirectedMonopartiteMonoplexGraph,
+    G::NodeId: AsPrimitive<usize>,
+{
+}
+
 impl PartitionedMaximumClique for BitSquareMatrix {
     fn maximum_clique_with_partition(&self, partition: &PartitionInfo<'_>) -> Vec<usize> {
         self.maximum_clique_with_partition_where(partition, |_| true)
@@ -0,0 +1,51 @@
+//! Submodule providing the operation counters of the hot loops of the
+//! algorithms, reported with the `metrics` feature.
+//!
+//! The algorithms always increment their counters, but a [`Counter`] holds
+//! no state without the feature, so that the increments compile to nothing
+//! and the default builds pay no cost. With the feature, the counts are
+//! returned next to the results, so that a regression making a solver do
+//! far more work than before, such as an augmenting path search revisiting
+//! the same columns over and over, is detected from the counts rather than
+//! from wall-clock timeouts.
+
+/// A counter of operations, holding no state without the `metrics` feature.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub(crate) struct Counter {
+    /// The number of counted operations.
+    #[cfg(feature = "metrics")]
+    count: usize,
+}
+
+impl Counter {
+    /// Counts one more operation.
+    #[inline]
+    #[cfg_attr(not(feature = "metrics"), allow(clippy::unused_self))]
+    pub(crate) fn increment(&mut self) {
+        #[cfg(feature = "metrics")]
+        {
+            self.count += 1;
+        }
+    }
+
+    /// Adds the operations counted by another counter, such as the one of
+    /// another thread.
+    #[cfg(feature = "parallel")]
+    #[inline]
+    #[cfg_attr(not(feature = "metrics"), allow(clippy::unused_self))]
+    pub(crate) fn merge(&mut self, other: Self) {
+        #[cfg(feature = "metrics")]
+        {
+            self.count += other.count;
+        }
+        #[cfg(not(feature = "metrics"))]
+        let _ = other;
+    }
+
+    /// Returns the number of counted operations.
+    #[cfg(feature = "metrics")]
+    #[inline]
+    pub(crate) fn count(self) -> usize {
+        self.count
+    }
+}
@@ -14,8 +14,11 @@ use alloc::{
 };
 use core::cmp::Reverse;
 
-use crate::traits::{
-    ConnectedComponents, MonopartiteGraph, PositiveInteger, UndirectedMonopartiteMonoplexGraph,
+use crate::{
+    impls::DisjointSet,
+    traits::{
+        ConnectedComponents, MonopartiteGraph, PositiveInteger, UndirectedMonopartiteMonoplexGraph,
+    },
 };
 
 /// Result returned by [`MinimumCycleBasis::minimum_cycle_basis`].
@@ -158,45 +161,6 @@ struct OrthogonalCycle<NodeId: PositiveInteger> {
     edge_bits: Vec<u64>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-struct UnionFind {
-    parents: Vec<usize>,
-    ranks: Vec<u8>,
-}
-
-impl UnionFind {
-    fn new(size: usize) -> Self {
-        Self { parents: (0..size).collect(), ranks: vec![0; size] }
-    }
-
-    fn find(&mut self, node: usize) -> usize {
-        if self.parents[node] != node {
-            let parent = self.parents[node];
-            self.parents[node] = self.find(parent);
-        }
-        self.parents[node]
-    }
-
-    fn union(&mut self, left: usize, right: usize) -> bool {
-        let left_root = self.find(left);
-        let right_root = self.find(right);
-        if left_root == right_root {
-            return false;
-        }
-
-        match self.ranks[left_root].cmp(&self.ranks[right_root]) {
-            core::cmp::Ordering::Less => self.parents[left_root] = right_root,
-            core::cmp::Ordering::Greater => self.parents[right_root] = left_root,
-            core::cmp::Ordering::Equal => {
-                self.parents[right_root] = left_root;
-                self.ranks[left_root] += 1;
-            }
-        }
-
-        true
-    }
-}
-
 /// Trait providing an exact minimum cycle basis for undirected simple graphs.
 pub trait MinimumCycleBasis: UndirectedMonopartiteMonoplexGraph {
     /// Computes the minimum cycle basis of the graph.
@@ -354,7 +318,7 @@ fn minimum_cycle_basis_for_component<NodeId: PositiveInteger>(
 fn kruskal_spanning_tree_flags<NodeId: PositiveInteger>(
     component: &LocalGraph<NodeId>,
 ) -> Vec<bool> {
-    let mut union_find = UnionFind::new(component.order());
+    let mut union_find: DisjointSet<usize> = DisjointSet::new(component.order());
     let mut tree_edges = vec![false; component.edge_count()];
 
     for (edge_id, [left, right]) in component.edges.iter().copied().enumerate() {
@@ -0,0 +1,93 @@
+//! Submodule providing the `MinimumPathCover` trait and its blanket
+//! implementation for sparse square matrices, which decomposes a directed
+//! acyclic graph into the minimum number of vertex-disjoint paths.
+//!
+//! Following Fulkerson's reduction, each node is split into an outgoing copy,
+//! the row, and an incoming copy, the column, so that the adjacency matrix is
+//! itself the split bipartite graph. Each edge of a maximum matching of this
+//! graph, computed with [`HopcroftKarp`], chains two nodes into the same
+//! path, and every node left without a matched predecessor starts a new path:
+//! the number of paths is the number of nodes minus the size of the matching.
+use alloc::{vec, vec::Vec};
+
+use num_traits::AsPrimitive;
+
+use crate::traits::{
+    HopcroftKarp, HopcroftKarpError, Kahn, KahnError, SparseMatrix2D, SquareMatrix,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+/// Error enumeration for the minimum path cover.
+pub enum MinimumPathCoverError {
+    /// The graph is not acyclic.
+    #[error(transparent)]
+    Kahn(#[from] KahnError),
+    /// The maximum matching of the split graph could not be computed.
+    #[error(transparent)]
+    HopcroftKarp(#[from] HopcroftKarpError),
+}
+
+/// Trait providing the minimum vertex-disjoint path cover of a directed
+/// acyclic graph.
+pub trait MinimumPathCover: SquareMatrix + SparseMatrix2D + Sized {
+    /// Returns a minimum set of vertex-disjoint paths covering every node of
+    /// the graph, each path listing its nodes from source to sink.
+    ///
+    /// Isolated nodes form single-node paths. The paths are sorted by their
+    /// first node.
+    ///
+    /// # Errors
+    ///
+    /// * [`MinimumPathCoverError::Kahn`] if the graph contains a cycle,
+    ///   including a self-loop.
+    /// * [`MinimumPathCoverError::HopcroftKarp`] if the maximum matching of the
+    ///   split graph could not be computed.
+    ///
+    /// # Complexity
+    ///
+    /// O(E√V) time, dominated by the Hopcroft-Karp matching, and O(V) space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{impls::SquareCSR2D, prelude::*, traits::EdgesBuilder};
+    ///
+    /// // Two chains, 0 -> 1 -> 3 and 2 -> 4, joined by the edge 2 -> 3.
+    /// let edges: SquareCSR2D<_> = DiEdgesBuilder::default()
+    ///     .expected_number_of_edges(4)
+    ///     .expected_shape(5)
+    ///     .edges(vec![(0, 1), (1, 3), (2, 3), (2, 4)].into_iter())
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let paths: Vec<Vec<usize>> = edges.minimum_path_cover().unwrap();
+    /// assert_eq!(paths, vec![vec![0, 1, 3], vec![2, 4]]);
+    /// ```
+    #[inline]
+    fn minimum_path_cover(&self) -> Result<Vec<Vec<Self::Index>>, MinimumPathCoverError> {
+        self.kahn()?;
+
+        let mut successors = vec![None; self.order().as_()];
+        let mut has_predecessor = vec![false; self.order().as_()];
+        for (node, successor) in self.hopcroft_karp()? {
+            successors[node.as_()] = Some(successor);
+            has_predecessor[successor.as_()] = true;
+        }
+
+        Ok(self
+            .row_indices()
+            .filter(|node| !has_predecessor[node.as_()])
+            .map(|first_node| {
+                let mut path = vec![first_node];
+                let mut node = first_node;
+                while let Some(successor) = successors[node.as_()] {
+                    path.push(successor);
+                    node = successor;
+                }
+                path
+            })
+            .collect())
+    }
+}
+
+impl<G: SquareMatrix + SparseMatrix2D> MinimumPathCover for G {}
@@ -8,7 +8,13 @@ use alloc::{
 use num_traits::{AsPrimitive, ToPrimitive};
 use rand::{SeedableRng, rngs::SmallRng, seq::SliceRandom};
 
-use crate::traits::{Finite, MonopartiteGraph, PositiveInteger, SparseValuedMatrix2D};
+use super::metrics::Counter;
+use crate::{
+    impls::ValuedCSR2D,
+    traits::{
+        Finite, MatrixMut, MonopartiteGraph, PositiveInteger, SparseMatrixMut, SparseValuedMatrix2D,
+    },
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
 /// Error enumeration for modularity-based community detection algorithms.
@@ -75,6 +81,26 @@ pub enum ModularityError {
         /// Destination node identifier.
         destination_id: usize,
     },
+    /// The number of blocks must be between one and the number of nodes.
+    #[error(
+        "The number of blocks must be between one and the number of nodes ({number_of_nodes}), but received {number_of_blocks}."
+    )]
+    InvalidNumberOfBlocks {
+        /// Requested number of blocks.
+        number_of_blocks: usize,
+        /// Number of nodes in the graph.
+        number_of_nodes: usize,
+    },
+    /// The partition does not assign a community to every node.
+    #[error(
+        "The partition must assign a community to each of the {expected} nodes, but has {found} entries."
+    )]
+    PartitionLengthMismatch {
+        /// Number of nodes in the graph.
+        expected: usize,
+        /// Number of entries in the partition.
+        found: usize,
+    },
     /// The selected community marker type is too small.
     #[error("The selected community marker type is too small for this partition.")]
     TooManyCommunities,
@@ -103,6 +129,15 @@ pub(crate) struct LocalMovingConfig {
     pub(crate) seed: u64,
 }
 
+/// Counters of the operations performed by the local-moving phases.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct LocalMovingCounters {
+    /// Passes over the nodes of a level.
+    pub(crate) passes: Counter,
+    /// Nodes whose best community was evaluated.
+    pub(crate) visited_nodes: Counter,
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct WeightedUndirectedGraph {
     pub(crate) adjacency: Vec<Vec<(usize, f64)>>,
@@ -131,11 +166,9 @@ impl WeightedUndirectedGraph {
         let mut adjacency: Vec<Vec<(usize, f64)>> = vec![Vec::new(); rows];
         let mut degree = vec![0.0; rows];
 
-        for row_id in matrix.row_indices() {
+        for (row_id, entries) in matrix.rows_with_values() {
             let source = row_id.as_();
-            for (column_id, weight) in
-                matrix.sparse_row(row_id).zip(matrix.sparse_row_values(row_id))
-            {
+            for (column_id, weight) in entries {
                 let destination = column_id.as_();
                 if !weight.is_finite() {
                     return Err(ModularityError::NonFiniteWeight {
@@ -205,6 +238,23 @@ impl WeightedUndirectedGraph {
         let total_weight = degree.iter().sum();
         Self { adjacency, degree, total_weight }
     }
+
+    /// Returns the weighted adjacency matrix of the graph.
+    pub(crate) fn to_matrix(&self) -> ValuedCSR2D<usize, usize, usize, f64> {
+        let number_of_nodes = self.number_of_nodes();
+        let mut matrix: ValuedCSR2D<usize, usize, usize, f64> =
+            SparseMatrixMut::with_sparse_shaped_capacity(
+                (number_of_nodes, number_of_nodes),
+                self.adjacency.iter().map(Vec::len).sum(),
+            );
+        for (source, neighbors) in self.adjacency.iter().enumerate() {
+            for &(destination, weight) in neighbors {
+                MatrixMut::add(&mut matrix, (source, destination, weight))
+                    .expect("Adjacency lists are sorted by destination.");
+            }
+        }
+        matrix
+    }
 }
 
 pub(crate) fn validate_common_config(
@@ -245,6 +295,7 @@ pub(crate) fn local_moving(
     graph: &WeightedUndirectedGraph,
     config: LocalMovingConfig,
     level_index: usize,
+    counters: &mut LocalMovingCounters,
 ) -> (Vec<usize>, usize) {
     let number_of_nodes = graph.number_of_nodes();
     let mut partition: Vec<usize> = (0..number_of_nodes).collect();
@@ -262,6 +313,7 @@ pub(crate) fn local_moving(
     for pass_index in 0..config.max_local_passes {
         let mut rng = SmallRng::seed_from_u64(mix_seed(config.seed, level_index, pass_index));
         order.shuffle(&mut rng);
+        counters.passes.increment();
 
         let mut moved_in_pass = 0usize;
 
@@ -271,6 +323,7 @@ pub(crate) fn local_moving(
             if node_degree <= 0.0 {
                 continue;
             }
+            counters.visited_nodes.increment();
 
             let source_community = partition[node];
             touched_communities.clear();
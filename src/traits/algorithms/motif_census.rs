@@ -0,0 +1,642 @@
+//! Submodule providing the `MotifCensus` and `TriadCensus` traits, counting
+//! the small subgraphs of a graph by isomorphism class.
+//!
+//! The undirected census counts the connected induced subgraphs of three or
+//! four nodes, enumerated exactly once each by the ESU algorithm of
+//! Wernicke: every subgraph is grown from its smallest node by adding, one
+//! at a time, nodes larger than it that are adjacent to the subgraph but not
+//! to its previous nodes. Pruning every branch of the enumeration tree at
+//! random, with a probability depending on its depth, yields the RAND-ESU
+//! sampling, whose counts divided by the product of the probabilities are
+//! unbiased estimates of the exact counts. The directed census counts all
+//! the triads, connected or not, in the sixteen classes of Holland and
+//! Leinhardt with the algorithm of Batagelj and Mrvar, which only visits the
+//! triads holding an edge. Self-loops are ignored by both censuses.
+
+use alloc::{vec, vec::Vec};
+
+use num_traits::AsPrimitive;
+
+use super::randomized_graphs::XorShift64;
+use crate::traits::{
+    MonopartiteGraph, MonoplexMonopartiteGraph, UndirectedMonopartiteMonoplexGraph,
+};
+
+/// Isomorphism classes of the connected undirected graphs with three or four
+/// nodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum UndirectedMotif {
+    /// Three nodes joined by two edges.
+    OpenTriad,
+    /// Three pairwise adjacent nodes.
+    Triangle,
+    /// Four nodes joined by a path of three edges.
+    Path,
+    /// One node adjacent to three otherwise isolated nodes.
+    Star,
+    /// Four nodes joined by a cycle of four edges.
+    Cycle,
+    /// A triangle with a pendant node.
+    Paw,
+    /// Four nodes joined by five edges, i.e. two triangles sharing an edge.
+    Diamond,
+    /// Four pairwise adjacent nodes.
+    Clique,
+}
+
+impl UndirectedMotif {
+    /// The motifs with three nodes.
+    pub const THREE_NODES: [Self; 2] = [Self::OpenTriad, Self::Triangle];
+    /// The motifs with four nodes.
+    pub const FOUR_NODES: [Self; 6] =
+        [Self::Path, Self::Star, Self::Cycle, Self::Paw, Self::Diamond, Self::Clique];
+}
+
+/// Isomorphism classes of the directed graphs with three nodes, named by the
+/// numbers of mutual, asymmetric and null dyads they hold, followed by a
+/// letter distinguishing the classes with equal numbers: Down, Up, Cyclic
+/// or Transitive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Triad {
+    /// No edge.
+    T003,
+    /// A single edge.
+    T012,
+    /// A single mutual edge.
+    T102,
+    /// Two edges leaving the same node, A ← B → C.
+    T021D,
+    /// Two edges reaching the same node, A → B ← C.
+    T021U,
+    /// A path of two edges, A → B → C.
+    T021C,
+    /// A mutual edge and an edge reaching it, A ↔ B ← C.
+    T111D,
+    /// A mutual edge and an edge leaving it, A ↔ B → C.
+    T111U,
+    /// A transitive triple, A → B ← C with A → C.
+    T030T,
+    /// A directed cycle, A → B → C → A.
+    T030C,
+    /// Two mutual edges, A ↔ B ↔ C.
+    T201,
+    /// A ← B → C with A ↔ C.
+    T120D,
+    /// A → B ← C with A ↔ C.
+    T120U,
+    /// A → B → C with A ↔ C.
+    T120C,
+    /// A → B ↔ C with A ↔ C.
+    T210,
+    /// Three mutual edges.
+    T300,
+}
+
+impl Triad {
+    /// The sixteen triads, in the order of Holland and Leinhardt.
+    pub const ALL: [Self; 16] = [
+        Self::T003,
+        Self::T012,
+        Self::T102,
+        Self::T021D,
+        Self::T021U,
+        Self::T021C,
+        Self::T111D,
+        Self::T111U,
+        Self::T030T,
+        Self::T030C,
+        Self::T201,
+        Self::T120D,
+        Self::T120U,
+        Self::T120C,
+        Self::T210,
+        Self::T300,
+    ];
+
+    /// Returns the triad of the code, whose bits tell which of the edges
+    /// v → u, u → v, v → w, w → v, u → w and w → u are present.
+    fn from_code(code: usize) -> Self {
+        /// Position in [`Triad::ALL`] of the triad of each code.
+        const TRIADS_OF_CODES: [u8; 64] = [
+            0, 1, 1, 2, 1, 3, 5, 7, 1, 5, 4, 6, 2, 7, 6, 10, 1, 5, 3, 7, 4, 8, 8, 12, 5, 9, 8, 13,
+            6, 13, 11, 14, 1, 4, 5, 6, 5, 8, 9, 13, 3, 8, 8, 11, 7, 12, 13, 14, 2, 6, 7, 10, 6, 11,
+            13, 14, 7, 13, 12, 14, 10, 14, 14, 15,
+        ];
+        Self::ALL[usize::from(TRIADS_OF_CODES[code])]
+    }
+}
+
+/// Counts of the classes of a motif census.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MotifCounts<M> {
+    /// Each class with its count, in the order of the classes.
+    counts: Vec<(M, f64)>,
+    /// Whether the counts are estimated by sampling.
+    sampled: bool,
+}
+
+impl<M: Copy + Eq> MotifCounts<M> {
+    /// Returns the count of the provided class, zero if the class is not
+    /// part of the census.
+    ///
+    /// Exact counts are integers, sampled counts are estimates.
+    #[inline]
+    #[must_use]
+    pub fn count(&self, motif: M) -> f64 {
+        self.counts.iter().find(|(class, _)| *class == motif).map_or(0.0, |&(_, count)| count)
+    }
+
+    /// Returns an iterator over the classes of the census and their counts.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (M, f64)> + '_ {
+        self.counts.iter().copied()
+    }
+
+    /// Returns the sum of the counts of all the classes.
+    #[inline]
+    #[must_use]
+    pub fn total(&self) -> f64 {
+        self.counts.iter().map(|&(_, count)| count).sum()
+    }
+
+    /// Returns whether the counts are estimated by sampling.
+    #[inline]
+    #[must_use]
+    pub fn is_sampled(&self) -> bool {
+        self.sampled
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+/// Error enumeration for the motif census.
+pub enum MotifCensusError {
+    /// The census only supports subgraphs of three or four nodes.
+    #[error("The motif census supports subgraphs of 3 or 4 nodes, but received {0}.")]
+    UnsupportedSize(usize),
+    /// The number of sampling probabilities differs from the size of the
+    /// subgraphs.
+    #[error(
+        "Expected one sampling probability for each of the {expected} levels, but received {actual}."
+    )]
+    ProbabilityCountMismatch {
+        /// The size of the subgraphs.
+        expected: usize,
+        /// The number of provided probabilities.
+        actual: usize,
+    },
+    /// A sampling probability is not in (0, 1].
+    #[error("The sampling probability of level {level} is not in (0, 1].")]
+    InvalidProbability {
+        /// The level of the invalid probability.
+        level: usize,
+    },
+}
+
+impl From<MotifCensusError>
+    for crate::errors::monopartite_graph_error::algorithms::MonopartiteAlgorithmError
+{
+    #[inline]
+    fn from(error: MotifCensusError) -> Self {
+        Self::MotifCensusError(error)
+    }
+}
+
+impl<G: MonopartiteGraph> From<MotifCensusError> for crate::errors::MonopartiteError<G> {
+    #[inline]
+    fn from(error: MotifCensusError) -> Self {
+        Self::AlgorithmError(error.into())
+    }
+}
+
+/// Trait providing the census of the connected induced subgraphs of three or
+/// four nodes of undirected graphs.
+pub trait MotifCensus: UndirectedMonopartiteMonoplexGraph
+where
+    Self::NodeId: AsPrimitive<usize>,
+{
+    /// Returns the number of connected induced subgraphs of each class with
+    /// the provided number of nodes.
+    ///
+    /// # Arguments
+    ///
+    /// * `size`: The number of nodes of the subgraphs, 3 or 4.
+    ///
+    /// # Errors
+    ///
+    /// * [`MotifCensusError::UnsupportedSize`] if the size is not 3 or 4.
+    ///
+    /// # Complexity
+    ///
+    /// O(V + E + S · k²) time, with S the number of connected induced
+    /// subgraphs of k nodes, and O(V + E) space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{
+    ///     impls::{CSR2D, SortedVec, SymmetricCSR2D},
+    ///     prelude::*,
+    ///     traits::{EdgesBuilder, MotifCensus, UndirectedMotif, VocabularyBuilder},
+    /// };
+    ///
+    /// let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+    ///     .expected_number_of_symbols(4)
+    ///     .symbols((0..4).enumerate())
+    ///     .build()
+    ///     .unwrap();
+    /// // A triangle with a pendant node.
+    /// let edges: SymmetricCSR2D<CSR2D<usize, usize, usize>> = UndiEdgesBuilder::default()
+    ///     .expected_number_of_edges(4)
+    ///     .expected_shape(4)
+    ///     .edges(vec![(0, 1), (0, 2), (1, 2), (2, 3)].into_iter())
+    ///     .build()
+    ///     .unwrap();
+    /// let graph: UndiGraph<usize> = UndiGraph::from((nodes, edges));
+    ///
+    /// let census = graph.motif_census(3).unwrap();
+    /// assert_eq!(census.count(UndirectedMotif::Triangle), 1.0);
+    /// assert_eq!(census.count(UndirectedMotif::OpenTriad), 2.0);
+    /// let census = graph.motif_census(4).unwrap();
+    /// assert_eq!(census.count(UndirectedMotif::Paw), 1.0);
+    /// assert_eq!(census.total(), 1.0);
+    /// ```
+    #[inline]
+    fn motif_census(&self, size: usize) -> Result<MotifCounts<UndirectedMotif>, MotifCensusError> {
+        let motifs = motifs_of_size(size)?;
+        let mut search = EnumerationSearch::new(undirected_adjacency(self), size, None);
+        search.enumerate();
+        Ok(search.into_counts(motifs, 1.0))
+    }
+
+    /// Returns estimates of the number of connected induced subgraphs of
+    /// each class with the provided number of nodes, enumerating a random
+    /// part of them.
+    ///
+    /// The enumeration of every subgraph grows it one node at a time, and
+    /// the i-th probability is the one of pursuing the enumeration after
+    /// adding the i-th node, the first one being the probability of starting
+    /// from each node. The number of subgraphs visited, and so the time, is
+    /// about the product of the probabilities times the one of the exact
+    /// census, and the estimates are unbiased. Lowering the last
+    /// probabilities rather than the first ones visits a more uniform
+    /// sample of the subgraphs, and so reduces the variance.
+    ///
+    /// # Arguments
+    ///
+    /// * `size`: The number of nodes of the subgraphs, 3 or 4.
+    /// * `probabilities`: The probability, in (0, 1], of pursuing the
+    ///   enumeration at each of its `size` levels.
+    /// * `seed`: The random seed of the sampling.
+    ///
+    /// # Errors
+    ///
+    /// * [`MotifCensusError::UnsupportedSize`] if the size is not 3 or 4.
+    /// * [`MotifCensusError::ProbabilityCountMismatch`] if the number of
+    ///   probabilities differs from the size.
+    /// * [`MotifCensusError::InvalidProbability`] if a probability is not in
+    ///   (0, 1].
+    #[inline]
+    fn sampled_motif_census(
+        &self,
+        size: usize,
+        probabilities: &[f64],
+        seed: u64,
+    ) -> Result<MotifCounts<UndirectedMotif>, MotifCensusError> {
+        let motifs = motifs_of_size(size)?;
+        if probabilities.len() != size {
+            return Err(MotifCensusError::ProbabilityCountMismatch {
+                expected: size,
+                actual: probabilities.len(),
+            });
+        }
+        if let Some(level) =
+            probabilities.iter().position(|&probability| !(probability > 0.0 && probability <= 1.0))
+        {
+            return Err(MotifCensusError::InvalidProbability { level });
+        }
+        let rng = XorShift64::from(XorShift64::normalize_seed(seed));
+        let mut search =
+            EnumerationSearch::new(undirected_adjacency(self), size, Some((probabilities, rng)));
+        search.enumerate();
+        let mut counts = search.into_counts(motifs, probabilities.iter().product());
+        counts.sampled = true;
+        Ok(counts)
+    }
+}
+
+impl<G> MotifCensus for G
+where
+    G: UndirectedMonopartiteMonoplexGraph,
+    G::NodeId: AsPrimitive<usize>,
+{
+}
+
+/// Trait providing the triad census of directed graphs.
+pub trait TriadCensus: MonoplexMonopartiteGraph
+where
+    Self::NodeId: AsPrimitive<usize>,
+{
+    /// Returns the number of triads of each of the sixteen classes, in the
+    /// order of [`Triad::ALL`].
+    ///
+    /// The edges of undirected graphs are mutual, so that only the classes
+    /// 003, 102, 201 and 300 occur.
+    ///
+    /// # Complexity
+    ///
+    /// O(V + E · Δ) time, with Δ the largest number of nodes adjacent to a
+    /// node, and O(V + E) space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{
+    ///     impls::{SortedVec, SquareCSR2D},
+    ///     prelude::*,
+    ///     traits::{EdgesBuilder, Triad, TriadCensus, VocabularyBuilder},
+    /// };
+    ///
+    /// let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+    ///     .expected_number_of_symbols(4)
+    ///     .symbols((0..4).enumerate())
+    ///     .build()
+    ///     .unwrap();
+    /// // A directed cycle of three nodes and an isolated node.
+    /// let edges: SquareCSR2D<_> = DiEdgesBuilder::default()
+    ///     .expected_number_of_edges(3)
+    ///     .expected_shape(4)
+    ///     .edges(vec![(0, 1), (1, 2), (2, 0)].into_iter())
+    ///     .build()
+    ///     .unwrap();
+    /// let graph: DiGraph<usize> = DiGraph::from((nodes, edges));
+    ///
+    /// let census = graph.triad_census();
+    /// assert_eq!(census.count(Triad::T030C), 1.0);
+    /// assert_eq!(census.count(Triad::T012), 3.0);
+    /// assert_eq!(census.total(), 4.0);
+    /// ```
+    #[inline]
+    fn triad_census(&self) -> MotifCounts<Triad> {
+        let number_of_nodes = self.number_of_nodes().as_();
+        // The nodes adjacent to each node, with whether the edges towards
+        // and from them are present.
+        let mut adjacency: Vec<Vec<(usize, bool, bool)>> = vec![Vec::new(); number_of_nodes];
+        for node in self.node_ids() {
+            let source = node.as_();
+            for successor in self.successors(node) {
+                let destination = successor.as_();
+                if destination != source {
+                    adjacency[source].push((destination, true, false));
+                    adjacency[destination].push((source, false, true));
+                }
+            }
+        }
+        for neighbors in &mut adjacency {
+            neighbors.sort_unstable_by_key(|&(neighbor, _, _)| neighbor);
+            neighbors.dedup_by(|next, kept| {
+                if next.0 == kept.0 {
+                    kept.1 |= next.1;
+                    kept.2 |= next.2;
+                    true
+                } else {
+                    false
+                }
+            });
+        }
+        let edge_between = |source: usize, destination: usize| {
+            adjacency[source]
+                .binary_search_by_key(&destination, |&(neighbor, _, _)| neighbor)
+                .is_ok_and(|position| adjacency[source][position].1)
+        };
+        let adjacent = |left: usize, right: usize| {
+            adjacency[left].binary_search_by_key(&right, |&(neighbor, _, _)| neighbor).is_ok()
+        };
+
+        let mut counts = [0_u64; 16];
+        let mut union: Vec<usize> = Vec::new();
+        for v in 0..number_of_nodes {
+            for &(u, v_to_u, u_to_v) in &adjacency[v] {
+                if u <= v {
+                    continue;
+                }
+                union.clear();
+                union.extend(
+                    adjacency[u]
+                        .iter()
+                        .chain(&adjacency[v])
+                        .map(|&(neighbor, _, _)| neighbor)
+                        .filter(|&neighbor| neighbor != u && neighbor != v),
+                );
+                union.sort_unstable();
+                union.dedup();
+
+                // The triads with a single adjacent pair.
+                let dyad = if v_to_u && u_to_v { 2 } else { 1 };
+                counts[dyad] += usize_to_u64(number_of_nodes - union.len() - 2);
+
+                // Every connected triad is counted from its smallest adjacent
+                // pair, or from its smallest pair when all pairs are adjacent.
+                for &w in &union {
+                    if u < w || (v < w && w < u && !adjacent(v, w)) {
+                        let code = usize::from(v_to_u)
+                            | usize::from(u_to_v) << 1
+                            | usize::from(edge_between(v, w)) << 2
+                            | usize::from(edge_between(w, v)) << 3
+                            | usize::from(edge_between(u, w)) << 4
+                            | usize::from(edge_between(w, u)) << 5;
+                        counts[Triad::from_code(code) as usize] += 1;
+                    }
+                }
+            }
+        }
+        let number_of_nodes = usize_to_u64(number_of_nodes);
+        let triads = if number_of_nodes < 3 {
+            0
+        } else {
+            number_of_nodes * (number_of_nodes - 1) / 2 * (number_of_nodes - 2) / 3
+        };
+        counts[0] = triads - counts[1..].iter().sum::<u64>();
+
+        MotifCounts {
+            counts: Triad::ALL
+                .iter()
+                .zip(counts)
+                .map(|(&triad, count)| (triad, u64_to_f64(count)))
+                .collect(),
+            sampled: false,
+        }
+    }
+}
+
+impl<G> TriadCensus for G
+where
+    G: MonoplexMonopartiteGraph,
+    G::NodeId: AsPrimitive<usize>,
+{
+}
+
+/// Returns the motifs of the provided number of nodes.
+fn motifs_of_size(size: usize) -> Result<&'static [UndirectedMotif], MotifCensusError> {
+    match size {
+        3 => Ok(&UndirectedMotif::THREE_NODES),
+        4 => Ok(&UndirectedMotif::FOUR_NODES),
+        _ => Err(MotifCensusError::UnsupportedSize(size)),
+    }
+}
+
+/// Returns the sorted neighbors of each node of an undirected graph,
+/// ignoring the self-loops.
+fn undirected_adjacency<G>(graph: &G) -> Vec<Vec<usize>>
+where
+    G: UndirectedMonopartiteMonoplexGraph + ?Sized,
+    G::NodeId: AsPrimitive<usize>,
+{
+    graph
+        .node_ids()
+        .map(|node| {
+            let mut neighbors: Vec<usize> = graph
+                .neighbors(node)
+                .filter(|&neighbor| neighbor != node)
+                .map(AsPrimitive::as_)
+                .collect();
+            neighbors.sort_unstable();
+            neighbors.dedup();
+            neighbors
+        })
+        .collect()
+}
+
+/// State of the ESU enumeration of the connected induced subgraphs.
+struct EnumerationSearch<'probabilities> {
+    /// The sorted neighbors of each node.
+    adjacency: Vec<Vec<usize>>,
+    /// The number of nodes of the enumerated subgraphs.
+    size: usize,
+    /// The probabilities of pursuing the enumeration at each level, with
+    /// the random generator drawing the pruned branches.
+    sampling: Option<(&'probabilities [f64], XorShift64)>,
+    /// The number of subgraphs found with each number of edges and each
+    /// largest degree, indexed by `edges * size + largest_degree`.
+    found: Vec<u64>,
+}
+
+impl<'probabilities> EnumerationSearch<'probabilities> {
+    /// Creates the search over the provided adjacency lists.
+    fn new(
+        adjacency: Vec<Vec<usize>>,
+        size: usize,
+        sampling: Option<(&'probabilities [f64], XorShift64)>,
+    ) -> Self {
+        let number_of_pairs = size * (size - 1) / 2;
+        Self { adjacency, size, sampling, found: vec![0; (number_of_pairs + 1) * size] }
+    }
+
+    /// Returns whether the enumeration is pursued at the provided level.
+    fn pursue(&mut self, level: usize) -> bool {
+        let Some((probabilities, rng)) = &mut self.sampling else {
+            return true;
+        };
+        let raw = rng.next().unwrap_or_else(|| unreachable!("The generator never ends."));
+        // The 53 most significant bits give a uniform value in [0, 1).
+        u64_to_f64(raw >> 11) / u64_to_f64(1 << 53) < probabilities[level]
+    }
+
+    /// Enumerates the subgraphs grown from every node.
+    fn enumerate(&mut self) {
+        let mut subgraph = Vec::with_capacity(self.size);
+        for root in 0..self.adjacency.len() {
+            if !self.pursue(0) {
+                continue;
+            }
+            let extension: Vec<usize> =
+                self.adjacency[root].iter().copied().filter(|&neighbor| neighbor > root).collect();
+            subgraph.push(root);
+            self.extend(&mut subgraph, extension, root);
+            subgraph.pop();
+        }
+    }
+
+    /// Grows the subgraph with every node of the extension in turn, adding
+    /// to the extension the neighbors of the new node that are larger than
+    /// the root and neither in nor adjacent to the subgraph.
+    fn extend(&mut self, subgraph: &mut Vec<usize>, mut extension: Vec<usize>, root: usize) {
+        if subgraph.len() == self.size {
+            self.record(subgraph);
+            return;
+        }
+        while let Some(node) = extension.pop() {
+            if !self.pursue(subgraph.len()) {
+                continue;
+            }
+            let mut next_extension = extension.clone();
+            for &neighbor in &self.adjacency[node] {
+                if neighbor > root
+                    && !subgraph.contains(&neighbor)
+                    && !subgraph.iter().any(|&member| self.adjacent(member, neighbor))
+                {
+                    next_extension.push(neighbor);
+                }
+            }
+            subgraph.push(node);
+            self.extend(subgraph, next_extension, root);
+            subgraph.pop();
+        }
+    }
+
+    /// Returns whether the two nodes are adjacent.
+    fn adjacent(&self, left: usize, right: usize) -> bool {
+        self.adjacency[left].binary_search(&right).is_ok()
+    }
+
+    /// Counts the subgraph by its number of edges and its largest degree.
+    fn record(&mut self, subgraph: &[usize]) {
+        let mut edges = 0;
+        let mut largest_degree = 0;
+        for &member in subgraph {
+            let degree = subgraph.iter().filter(|&&other| self.adjacent(member, other)).count();
+            edges += degree;
+            largest_degree = largest_degree.max(degree);
+        }
+        self.found[edges / 2 * self.size + largest_degree] += 1;
+    }
+
+    /// Returns the counts of the motifs, dividing the numbers of subgraphs
+    /// found by the provided probability of finding each of them.
+    fn into_counts(
+        self,
+        motifs: &[UndirectedMotif],
+        probability: f64,
+    ) -> MotifCounts<UndirectedMotif> {
+        let size = self.size;
+        let found = |edges: usize, largest_degree: usize| self.found[edges * size + largest_degree];
+        let counts = motifs
+            .iter()
+            .map(|&motif| {
+                let count = match motif {
+                    UndirectedMotif::OpenTriad => found(2, 2),
+                    // Triangles and paths are told apart by their number of
+                    // nodes, which is the same for all the subgraphs found.
+                    UndirectedMotif::Triangle | UndirectedMotif::Path => found(3, 2),
+                    UndirectedMotif::Star => found(3, 3),
+                    UndirectedMotif::Cycle => found(4, 2),
+                    UndirectedMotif::Paw => found(4, 3),
+                    UndirectedMotif::Diamond => found(5, 3),
+                    UndirectedMotif::Clique => found(6, 3),
+                };
+                (motif, u64_to_f64(count) / probability)
+            })
+            .collect();
+        MotifCounts { counts, sampled: false }
+    }
+}
+
+/// Converts a count into an unsigned integer.
+fn usize_to_u64(value: usize) -> u64 {
+    u64::try_from(value).unwrap_or_else(|_| unreachable!("Counts fit in 64 bits."))
+}
+
+/// Converts a count into a floating point value.
+#[allow(clippy::cast_precision_loss)]
+fn u64_to_f64(value: u64) -> f64 {
+    value as f64
+}
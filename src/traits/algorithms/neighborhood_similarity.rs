@@ -0,0 +1,102 @@
+//! Submodule providing similarities between the sparse rows of a matrix,
+//! such as the neighbourhoods of the nodes of a graph, together with batched
+//! all-pairs variants emitting sparse similarity matrices, exact or
+//! approximated by `MinHash` signatures.
+
+use alloc::vec::Vec;
+
+use crate::{
+    impls::ValuedCSR2D,
+    traits::{MatrixMut, SparseBiMatrix2D, SparseMatrixMut},
+};
+
+mod cosine;
+mod dice;
+mod jaccard;
+mod minhash;
+mod overlap;
+pub use cosine::CosineSimilarity;
+pub use dice::DiceSimilarity;
+pub use jaccard::JaccardSimilarity;
+pub use minhash::MinHashSignatures;
+pub use overlap::OverlapSimilarity;
+
+/// Returns the rows other than `item` sharing at least one column with it,
+/// sorted and without duplicates.
+fn rows_sharing_a_column<M>(matrix: &M, item: M::RowIndex) -> Vec<M::RowIndex>
+where
+    M: SparseBiMatrix2D + ?Sized,
+{
+    let mut candidates: Vec<M::RowIndex> = matrix
+        .sparse_row(item)
+        .flat_map(|column| matrix.sparse_column(column))
+        .filter(|&candidate| candidate != item)
+        .collect();
+    candidates.sort_unstable();
+    candidates.dedup();
+    candidates
+}
+
+/// Computes the dot products of every pair of distinct weighted rows sharing
+/// at least one column, as in the product `A·Aᵀ`, and collects in a square
+/// sparse matrix the pairs whose score is at least `threshold`.
+///
+/// # Arguments
+///
+/// * `rows` - The `(column, weight)` entries of every row, sorted by column.
+/// * `number_of_columns` - The number of columns of the matrix.
+/// * `threshold` - The minimum score of the collected pairs.
+/// * `score` - Maps the left row, the right row and their dot product to the
+///   score of the pair.
+fn thresholded_pairwise<F>(
+    rows: &[Vec<(usize, f64)>],
+    number_of_columns: usize,
+    threshold: f64,
+    score: F,
+) -> ValuedCSR2D<usize, usize, usize, f64>
+where
+    F: Fn(usize, usize, f64) -> f64,
+{
+    let mut rows_by_column: Vec<Vec<(usize, f64)>> = vec![Vec::new(); number_of_columns];
+    for (row, entries) in rows.iter().enumerate() {
+        for &(column, weight) in entries {
+            rows_by_column[column].push((row, weight));
+        }
+    }
+
+    let mut dot_products = vec![0.0; rows.len()];
+    let mut is_touched = vec![false; rows.len()];
+    let mut touched: Vec<usize> = Vec::new();
+    let mut entries: Vec<(usize, usize, f64)> = Vec::new();
+
+    for (left, left_entries) in rows.iter().enumerate() {
+        for &(column, left_weight) in left_entries {
+            for &(right, right_weight) in &rows_by_column[column] {
+                if right != left {
+                    if !is_touched[right] {
+                        is_touched[right] = true;
+                        touched.push(right);
+                    }
+                    dot_products[right] += left_weight * right_weight;
+                }
+            }
+        }
+        touched.sort_unstable();
+        for right in touched.drain(..) {
+            let similarity = score(left, right, dot_products[right]);
+            dot_products[right] = 0.0;
+            is_touched[right] = false;
+            if similarity >= threshold {
+                entries.push((left, right, similarity));
+            }
+        }
+    }
+
+    let mut similarities: ValuedCSR2D<usize, usize, usize, f64> =
+        SparseMatrixMut::with_sparse_shaped_capacity((rows.len(), rows.len()), entries.len());
+    for entry in entries {
+        MatrixMut::add(&mut similarities, entry)
+            .expect("Entries are generated sorted by row and column.");
+    }
+    similarities
+}
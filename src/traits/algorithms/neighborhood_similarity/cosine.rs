@@ -0,0 +1,204 @@
+//! Submodule providing the cosine similarity between the sparse weighted rows
+//! of a matrix.
+
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use num_traits::AsPrimitive;
+
+use super::{rows_sharing_a_column, thresholded_pairwise};
+use crate::{
+    impls::ValuedCSR2D,
+    traits::{
+        ScalarSimilarity, SparseBiMatrix2D, SparseValuedMatrix2D, TopKSimilarity,
+        similarity::top_k_by_score,
+    },
+};
+
+/// Cosine similarity between the sparse weighted rows of a matrix.
+///
+/// The similarity of two rows is the dot product of their values divided by
+/// the product of their Euclidean norms, so that rows pointing in the same
+/// direction have similarity one whatever their scale. Columns missing from a
+/// row count as zeros, and rows with null norm have similarity zero with
+/// every row, themselves included.
+///
+/// The norms of the rows are computed once upon creation.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{
+///     impls::ValuedCSR2D,
+///     prelude::*,
+///     traits::{CosineSimilarity, MatrixMut, ScalarSimilarity, SparseMatrixMut},
+/// };
+///
+/// // Three spectra over four features.
+/// let mut spectra: ValuedCSR2D<usize, usize, usize, f64> =
+///     SparseMatrixMut::with_sparse_shaped_capacity((3, 4), 6);
+/// for entry in [(0, 0, 1.0), (0, 1, 2.0), (1, 0, 2.0), (1, 1, 4.0), (2, 2, 3.0), (2, 3, 4.0)] {
+///     MatrixMut::add(&mut spectra, entry).unwrap();
+/// }
+///
+/// let cosine = CosineSimilarity::new(&spectra);
+/// // The first two spectra are proportional, the third shares no feature.
+/// assert!((cosine.similarity(&0, &1) - 1.0).abs() < 1e-12);
+/// assert!(cosine.similarity(&0, &2).abs() < f64::EPSILON);
+///
+/// let similarities = cosine.pairwise_similarities(0.5);
+/// assert_eq!(
+///     SparseMatrix::sparse_coordinates(&similarities).collect::<Vec<_>>(),
+///     vec![(0, 1), (1, 0)]
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct CosineSimilarity<'matrix, M: ?Sized> {
+    /// The matrix whose rows are compared.
+    matrix: &'matrix M,
+    /// The Euclidean norm of every row.
+    norms: Vec<f64>,
+}
+
+impl<'matrix, M> CosineSimilarity<'matrix, M>
+where
+    M: SparseValuedMatrix2D + ?Sized,
+    M::RowIndex: AsPrimitive<usize>,
+    M::ColumnIndex: AsPrimitive<usize>,
+    M::Value: AsPrimitive<f64>,
+{
+    /// Creates the cosine similarity between the rows of the matrix.
+    #[must_use]
+    pub fn new(matrix: &'matrix M) -> Self {
+        let norms = matrix
+            .row_indices()
+            .map(|row| {
+                matrix
+                    .sparse_row_values(row)
+                    .map(|value| {
+                        let value: f64 = value.as_();
+                        value * value
+                    })
+                    .sum::<f64>()
+                    .sqrt()
+            })
+            .collect();
+        Self { matrix, norms }
+    }
+
+    /// Returns the cosine similarities of all pairs of distinct rows which
+    /// are at least `threshold`, as a square sparse matrix indexed by row.
+    ///
+    /// The rows are normalized to unit norm and multiplied as in `A·Aᵀ`,
+    /// comparing only pairs of rows sharing at least one column, so pairs
+    /// without shared columns are never emitted, whatever the threshold. The
+    /// returned matrix is symmetric.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - The minimum similarity of the emitted pairs.
+    ///
+    /// # Complexity
+    ///
+    /// O(R + C + Σ deg(c)²) time, where the sum runs over the columns and
+    /// `deg(c)` is the number of rows holding column `c`.
+    #[must_use]
+    pub fn pairwise_similarities(&self, threshold: f64) -> ValuedCSR2D<usize, usize, usize, f64> {
+        let rows: Vec<Vec<(usize, f64)>> = self
+            .matrix
+            .row_indices()
+            .zip(self.norms.iter())
+            .map(|(row, &norm)| {
+                if norm > 0.0 {
+                    self.matrix
+                        .sparse_row(row)
+                        .zip(self.matrix.sparse_row_values(row))
+                        .map(|(column, value)| (column.as_(), value.as_() / norm))
+                        .collect()
+                } else {
+                    Vec::new()
+                }
+            })
+            .collect();
+        thresholded_pairwise(
+            &rows,
+            self.matrix.number_of_columns().as_(),
+            threshold,
+            |_, _, dot_product| dot_product,
+        )
+    }
+}
+
+impl<M> ScalarSimilarity<M::RowIndex, M::RowIndex> for CosineSimilarity<'_, M>
+where
+    M: SparseValuedMatrix2D + ?Sized,
+    M::RowIndex: AsPrimitive<usize>,
+    M::Value: AsPrimitive<f64>,
+{
+    type Similarity = f64;
+
+    #[inline]
+    fn similarity(&self, left: &M::RowIndex, right: &M::RowIndex) -> Self::Similarity {
+        let norms = self.norms[left.as_()] * self.norms[right.as_()];
+        if norms <= 0.0 {
+            return 0.0;
+        }
+
+        // Merge the two sorted rows, multiplying the values of shared columns.
+        let mut left_entries =
+            self.matrix.sparse_row(*left).zip(self.matrix.sparse_row_values(*left)).peekable();
+        let mut right_entries =
+            self.matrix.sparse_row(*right).zip(self.matrix.sparse_row_values(*right)).peekable();
+        let mut dot_product = 0.0;
+        while let (Some((left_column, _)), Some((right_column, _))) =
+            (left_entries.peek(), right_entries.peek())
+        {
+            match left_column.cmp(right_column) {
+                Ordering::Less => {
+                    left_entries.next();
+                }
+                Ordering::Greater => {
+                    right_entries.next();
+                }
+                Ordering::Equal => {
+                    let (_, left_value) = left_entries.next().unwrap();
+                    let (_, right_value) = right_entries.next().unwrap();
+                    let left_value: f64 = left_value.as_();
+                    let right_value: f64 = right_value.as_();
+                    dot_product += left_value * right_value;
+                }
+            }
+        }
+        dot_product / norms
+    }
+}
+
+impl<M> TopKSimilarity<M::RowIndex> for CosineSimilarity<'_, M>
+where
+    M: SparseValuedMatrix2D + SparseBiMatrix2D + ?Sized,
+    M::RowIndex: AsPrimitive<usize>,
+    M::Value: AsPrimitive<f64>,
+{
+    /// Returns up to `k` rows other than `item` with the highest cosine
+    /// similarity, sorted by decreasing similarity and then by increasing row.
+    ///
+    /// Only the rows sharing a column with `item` are scored. Rows whose
+    /// shared values cancel out to a null or negative similarity are never
+    /// returned.
+    ///
+    /// # Complexity
+    ///
+    /// O(Σ deg(c) + K·D + K log K) time, where the sum runs over the columns
+    /// of `item`, K is the number of candidate rows and D bounds their size.
+    #[inline]
+    fn top_k_similar(&self, item: &M::RowIndex, k: usize) -> Vec<(M::RowIndex, f64)> {
+        if self.norms[item.as_()] <= 0.0 {
+            return Vec::new();
+        }
+        let scored = rows_sharing_a_column(self.matrix, *item)
+            .into_iter()
+            .map(|candidate| (candidate, self.similarity(item, &candidate)))
+            .collect();
+        top_k_by_score(scored, k)
+    }
+}
@@ -0,0 +1,177 @@
+//! Submodule providing the Jaccard similarity between the sparse rows of a
+//! matrix.
+
+use alloc::vec::Vec;
+
+use num_traits::AsPrimitive;
+
+use super::{rows_sharing_a_column, thresholded_pairwise};
+use crate::{
+    impls::{SortedIterator, ValuedCSR2D},
+    traits::{
+        ScalarSimilarity, SizedRowsSparseMatrix2D, SparseBiMatrix2D, TopKSimilarity,
+        similarity::top_k_by_score, tanimoto_similarity,
+    },
+};
+
+/// Jaccard similarity between the sparse rows of a matrix.
+///
+/// The similarity of two rows is the number of columns they share divided by
+/// the number of columns held by either of them, `|N(u) ∩ N(v)| / |N(u) ∪
+/// N(v)|`. On the adjacency matrix of a graph, this is the neighbourhood
+/// overlap of two nodes. Two empty rows have similarity one.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{
+///     impls::{SortedVec, SymmetricCSR2D},
+///     prelude::*,
+///     traits::{EdgesBuilder, JaccardSimilarity, ScalarSimilarity, VocabularyBuilder},
+/// };
+///
+/// let edges: Vec<(usize, usize)> = vec![(0, 1), (0, 2), (1, 3), (2, 3), (3, 4)];
+/// let edges: SymmetricCSR2D<_> = UndiEdgesBuilder::default()
+///     .expected_number_of_edges(edges.len())
+///     .expected_shape(5)
+///     .edges(edges.into_iter())
+///     .build()
+///     .unwrap();
+///
+/// let jaccard = JaccardSimilarity::new(&edges);
+/// // Nodes 0 and 3 share both neighbours of node 0 out of three in total.
+/// assert!((jaccard.similarity(&0, &3) - 2.0 / 3.0).abs() < f64::EPSILON);
+/// assert!((jaccard.similarity(&1, &2) - 1.0).abs() < f64::EPSILON);
+/// assert!(jaccard.similarity(&0, &4).abs() < f64::EPSILON);
+///
+/// // Pairs of distinct nodes with similarity at least 0.6.
+/// let similarities = jaccard.pairwise_similarities(0.6);
+/// assert_eq!(
+///     SparseMatrix::sparse_coordinates(&similarities).collect::<Vec<_>>(),
+///     vec![(0, 3), (1, 2), (2, 1), (3, 0)]
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct JaccardSimilarity<'matrix, M: ?Sized> {
+    /// The matrix whose rows are compared.
+    matrix: &'matrix M,
+}
+
+impl<'matrix, M> JaccardSimilarity<'matrix, M>
+where
+    M: SizedRowsSparseMatrix2D + ?Sized,
+    M::RowIndex: AsPrimitive<usize>,
+    M::ColumnIndex: AsPrimitive<usize>,
+{
+    /// Creates the Jaccard similarity between the rows of the matrix.
+    #[inline]
+    #[must_use]
+    pub fn new(matrix: &'matrix M) -> Self {
+        Self { matrix }
+    }
+
+    /// Returns the Jaccard similarities of all pairs of distinct rows which
+    /// are at least `threshold`, as a square sparse matrix indexed by row.
+    ///
+    /// Only pairs of rows sharing at least one column are compared, so pairs
+    /// with null similarity are never emitted, whatever the threshold. The
+    /// returned matrix is symmetric.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - The minimum similarity of the emitted pairs.
+    ///
+    /// # Complexity
+    ///
+    /// O(R + C + Σ deg(c)²) time, where the sum runs over the columns and
+    /// `deg(c)` is the number of rows holding column `c`.
+    #[allow(clippy::cast_precision_loss)]
+    #[must_use]
+    pub fn pairwise_similarities(&self, threshold: f64) -> ValuedCSR2D<usize, usize, usize, f64> {
+        let rows: Vec<Vec<(usize, f64)>> = self
+            .matrix
+            .row_indices()
+            .map(|row| self.matrix.sparse_row(row).map(|column| (column.as_(), 1.0)).collect())
+            .collect();
+        // With unit weights, the dot products count the shared columns.
+        thresholded_pairwise(
+            &rows,
+            self.matrix.number_of_columns().as_(),
+            threshold,
+            |left, right, shared| {
+                let union = (rows[left].len() + rows[right].len()) as f64 - shared;
+                shared / union
+            },
+        )
+    }
+}
+
+impl<M> ScalarSimilarity<M::RowIndex, M::RowIndex> for JaccardSimilarity<'_, M>
+where
+    M: SizedRowsSparseMatrix2D + ?Sized,
+    M::ColumnIndex: AsPrimitive<usize>,
+{
+    type Similarity = f64;
+
+    #[inline]
+    fn similarity(&self, left: &M::RowIndex, right: &M::RowIndex) -> Self::Similarity {
+        let shared = self
+            .matrix
+            .sparse_row(*left)
+            .sorted_intersection(self.matrix.sparse_row(*right))
+            .count();
+        tanimoto_similarity(
+            shared,
+            self.matrix.number_of_defined_values_in_row(*left).as_(),
+            self.matrix.number_of_defined_values_in_row(*right).as_(),
+        )
+    }
+}
+
+impl<M> TopKSimilarity<M::RowIndex> for JaccardSimilarity<'_, M>
+where
+    M: SizedRowsSparseMatrix2D + SparseBiMatrix2D + ?Sized,
+    M::ColumnIndex: AsPrimitive<usize>,
+{
+    /// Returns up to `k` rows other than `item` with the highest Jaccard
+    /// similarity, sorted by decreasing similarity and then by increasing row.
+    ///
+    /// Only the rows sharing a column with `item` are scored, which on the
+    /// adjacency matrix of a graph are the neighbours of its neighbours.
+    ///
+    /// # Complexity
+    ///
+    /// O(Σ deg(c) + K·D + K log K) time, where the sum runs over the columns
+    /// of `item`, K is the number of candidate rows and D bounds their size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{
+    ///     impls::SymmetricCSR2D,
+    ///     prelude::*,
+    ///     traits::{EdgesBuilder, JaccardSimilarity, TopKSimilarity},
+    /// };
+    ///
+    /// let edges: Vec<(usize, usize)> = vec![(0, 1), (0, 2), (1, 3), (2, 3), (3, 4)];
+    /// let edges: SymmetricCSR2D<_> = UndiEdgesBuilder::default()
+    ///     .expected_number_of_edges(edges.len())
+    ///     .expected_shape(5)
+    ///     .edges(edges.into_iter())
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let jaccard = JaccardSimilarity::new(&edges);
+    /// // Node 0 shares no neighbour with node 4, which is never scored.
+    /// assert_eq!(jaccard.top_k_similar(&0, 2), vec![(3, 2.0 / 3.0)]);
+    /// assert_eq!(jaccard.top_k_similar(&1, 2), vec![(2, 1.0), (4, 0.5)]);
+    /// ```
+    #[inline]
+    fn top_k_similar(&self, item: &M::RowIndex, k: usize) -> Vec<(M::RowIndex, f64)> {
+        let scored = rows_sharing_a_column(self.matrix, *item)
+            .into_iter()
+            .map(|candidate| (candidate, self.similarity(item, &candidate)))
+            .collect();
+        top_k_by_score(scored, k)
+    }
+}
@@ -0,0 +1,253 @@
+//! Submodule providing `MinHash` signatures of the sparse rows of a matrix,
+//! which estimate their Jaccard similarity, together with locality-sensitive
+//! hashing to retrieve the candidate pairs of similar rows.
+
+use alloc::vec::Vec;
+
+use num_traits::AsPrimitive;
+
+use crate::{
+    impls::ValuedCSR2D,
+    traits::{MatrixMut, ScalarSimilarity, SparseMatrix2D, SparseMatrixMut},
+};
+
+const SPLITMIX_PHI: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// `MinHash` signatures of the sparse rows of a matrix.
+///
+/// Every signature holds, for each of its seeded hash functions, the minimum
+/// hash of the columns of the row. The probability that two rows agree on a
+/// hash function is their Jaccard similarity, so the fraction of agreeing
+/// positions estimates it with a standard error of at most `1 / (2 √L)` for
+/// signatures of length `L`. Two empty rows have similarity one, as in
+/// [`JaccardSimilarity`](super::JaccardSimilarity).
+///
+/// Candidate pairs are retrieved by locality-sensitive hashing: signatures
+/// are cut into `b` bands of `r` positions, and rows agreeing on a whole band
+/// become candidates. A pair with similarity `s` is retrieved with
+/// probability `1 - (1 - s^r)^b`, which rises sharply around `(1 / b)^(1 /
+/// r)`.
+///
+/// # Reference
+///
+/// Broder, A. Z. (1997). [On the resemblance and containment of documents](https://doi.org/10.1109/SEQUEN.1997.666900).
+/// Leskovec, J., Rajaraman, A., & Ullman, J. D. (2014). *Mining of Massive
+/// Datasets*, chapter 3. Cambridge University Press.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{
+///     impls::{CSR2D, SquareCSR2D},
+///     prelude::*,
+///     traits::{EdgesBuilder, MinHashSignatures, ScalarSimilarity},
+/// };
+///
+/// // Rows 0 and 1 hold the same 40 columns, row 2 half of them.
+/// let mut edges: Vec<(usize, usize)> = Vec::new();
+/// for column in 0..40 {
+///     edges.push((0, column));
+///     edges.push((1, column));
+///     if column % 2 == 0 {
+///         edges.push((2, column));
+///     }
+/// }
+/// edges.sort_unstable();
+/// let matrix: SquareCSR2D<CSR2D<usize, usize, usize>> = DiEdgesBuilder::default()
+///     .expected_number_of_edges(edges.len())
+///     .expected_shape(40)
+///     .edges(edges.into_iter())
+///     .build()
+///     .unwrap();
+///
+/// let signatures = MinHashSignatures::new(&matrix, 128, 42);
+/// assert!((signatures.similarity(&0, &1) - 1.0).abs() < f64::EPSILON);
+/// assert!((signatures.similarity(&0, &2) - 0.5).abs() < 0.2);
+///
+/// // With 32 bands of 4 positions, rows 0 and 1 always collide.
+/// let similarities = signatures.approximate_pairwise_similarities(32, 0.9);
+/// assert_eq!(
+///     SparseMatrix::sparse_coordinates(&similarities).collect::<Vec<_>>(),
+///     vec![(0, 1), (1, 0)]
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinHashSignatures {
+    /// The signatures of all rows, stored contiguously row after row.
+    signatures: Vec<u64>,
+    /// The number of hash functions of every signature.
+    signature_length: usize,
+    /// Whether each row holds at least one column.
+    is_non_empty: Vec<bool>,
+}
+
+impl MinHashSignatures {
+    /// Computes the `MinHash` signatures of the rows of the matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `matrix` - The matrix whose rows are hashed.
+    /// * `signature_length` - The number of hash functions per signature.
+    /// * `seed` - The seed of the hash functions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `signature_length` is zero.
+    ///
+    /// # Complexity
+    ///
+    /// O(R·L + Z·L) time and O(R·L) space, where Z is the number of defined
+    /// values of the matrix and L the signature length.
+    #[must_use]
+    pub fn new<M>(matrix: &M, signature_length: usize, seed: u64) -> Self
+    where
+        M: SparseMatrix2D + ?Sized,
+        M::ColumnIndex: AsPrimitive<usize>,
+    {
+        assert!(signature_length > 0, "The signature length must be positive.");
+        let mut state = seed;
+        let hash_seeds: Vec<u64> =
+            (0..signature_length).map(|_| splitmix64_next(&mut state)).collect();
+
+        let number_of_rows = matrix.number_of_rows().as_();
+        let mut signatures = vec![u64::MAX; number_of_rows * signature_length];
+        let mut is_non_empty = vec![false; number_of_rows];
+        for (row_index, row) in matrix.row_indices().enumerate() {
+            let signature =
+                &mut signatures[row_index * signature_length..(row_index + 1) * signature_length];
+            for column in matrix.sparse_row(row) {
+                is_non_empty[row_index] = true;
+                let column = column.as_() as u64;
+                for (minimum, &hash_seed) in signature.iter_mut().zip(&hash_seeds) {
+                    let hash = stafford_mix13(column ^ hash_seed);
+                    if hash < *minimum {
+                        *minimum = hash;
+                    }
+                }
+            }
+        }
+        Self { signatures, signature_length, is_non_empty }
+    }
+
+    /// Returns the number of hash functions of every signature.
+    #[inline]
+    #[must_use]
+    pub fn signature_length(&self) -> usize {
+        self.signature_length
+    }
+
+    /// Returns the signature of the provided row.
+    #[inline]
+    #[must_use]
+    pub fn signature(&self, row: usize) -> &[u64] {
+        &self.signatures[row * self.signature_length..(row + 1) * self.signature_length]
+    }
+
+    /// Returns the estimated Jaccard similarities of the candidate pairs of
+    /// distinct rows which are at least `threshold`, as a square sparse
+    /// matrix indexed by row.
+    ///
+    /// The signatures are cut into `bands` bands, and pairs of non-empty rows
+    /// agreeing on at least one whole band are candidates. Pairs similar
+    /// enough may therefore be missed, with a probability decreasing as the
+    /// number of bands grows. The returned matrix is symmetric.
+    ///
+    /// # Arguments
+    ///
+    /// * `bands` - The number of bands the signatures are cut into.
+    /// * `threshold` - The minimum estimated similarity of the emitted pairs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bands` is zero or does not divide the signature length.
+    ///
+    /// # Complexity
+    ///
+    /// O(b·R log R) time to bucket the bands of the R rows, plus O(L) time
+    /// per candidate pair.
+    #[must_use]
+    pub fn approximate_pairwise_similarities(
+        &self,
+        bands: usize,
+        threshold: f64,
+    ) -> ValuedCSR2D<usize, usize, usize, f64> {
+        assert!(
+            bands > 0 && self.signature_length % bands == 0,
+            "The number of bands ({bands}) must divide the signature length ({}).",
+            self.signature_length
+        );
+        let rows_per_band = self.signature_length / bands;
+        let number_of_rows = self.is_non_empty.len();
+
+        let mut candidates: Vec<(usize, usize)> = Vec::new();
+        let mut bucketed: Vec<usize> = Vec::with_capacity(number_of_rows);
+        for band in 0..bands {
+            let band_of = |row: usize| {
+                let start = row * self.signature_length + band * rows_per_band;
+                &self.signatures[start..start + rows_per_band]
+            };
+            bucketed.clear();
+            bucketed.extend((0..number_of_rows).filter(|&row| self.is_non_empty[row]));
+            bucketed.sort_unstable_by(|&left, &right| band_of(left).cmp(band_of(right)));
+            for bucket in bucketed.chunk_by(|&left, &right| band_of(left) == band_of(right)) {
+                for (position, &left) in bucket.iter().enumerate() {
+                    for &right in &bucket[position + 1..] {
+                        candidates.push((left.min(right), left.max(right)));
+                    }
+                }
+            }
+        }
+        candidates.sort_unstable();
+        candidates.dedup();
+
+        let mut entries: Vec<(usize, usize, f64)> = Vec::new();
+        for (left, right) in candidates {
+            let similarity = self.similarity(&left, &right);
+            if similarity >= threshold {
+                entries.push((left, right, similarity));
+                entries.push((right, left, similarity));
+            }
+        }
+        entries.sort_unstable_by_key(|&(left, right, _)| (left, right));
+
+        let mut similarities: ValuedCSR2D<usize, usize, usize, f64> =
+            SparseMatrixMut::with_sparse_shaped_capacity(
+                (number_of_rows, number_of_rows),
+                entries.len(),
+            );
+        for entry in entries {
+            MatrixMut::add(&mut similarities, entry)
+                .expect("Entries are sorted by row and column.");
+        }
+        similarities
+    }
+}
+
+impl ScalarSimilarity<usize, usize> for MinHashSignatures {
+    type Similarity = f64;
+
+    #[inline]
+    #[allow(clippy::cast_precision_loss)]
+    fn similarity(&self, left: &usize, right: &usize) -> Self::Similarity {
+        let agreeing = self
+            .signature(*left)
+            .iter()
+            .zip(self.signature(*right))
+            .filter(|(left_hash, right_hash)| left_hash == right_hash)
+            .count();
+        agreeing as f64 / self.signature_length as f64
+    }
+}
+
+#[inline]
+fn stafford_mix13(mut value: u64) -> u64 {
+    value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    value ^ (value >> 31)
+}
+
+#[inline]
+fn splitmix64_next(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(SPLITMIX_PHI);
+    stafford_mix13(*state)
+}
@@ -0,0 +1,148 @@
+//! Submodule providing the overlap similarity between the sparse rows of a
+//! matrix.
+
+use alloc::vec::Vec;
+
+use num_traits::AsPrimitive;
+
+use super::{rows_sharing_a_column, thresholded_pairwise};
+use crate::{
+    impls::{SortedIterator, ValuedCSR2D},
+    traits::{
+        ScalarSimilarity, SizedRowsSparseMatrix2D, SparseBiMatrix2D, TopKSimilarity,
+        overlap_similarity, similarity::top_k_by_score,
+    },
+};
+
+/// Overlap (Szymkiewicz–Simpson) similarity between the sparse rows of a
+/// matrix.
+///
+/// The similarity of two rows is the number of columns they share divided by
+/// the size of the smaller row, `|N(u) ∩ N(v)| / min(|N(u)|, |N(v)|)`, so
+/// that a row contained in another has similarity one with it whatever
+/// their difference in size. Two empty rows have similarity one, and an empty
+/// row has similarity zero with any other row.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{
+///     impls::SymmetricCSR2D,
+///     prelude::*,
+///     traits::{EdgesBuilder, OverlapSimilarity, ScalarSimilarity},
+/// };
+///
+/// let edges: Vec<(usize, usize)> = vec![(0, 1), (0, 2), (1, 3), (2, 3), (3, 4)];
+/// let edges: SymmetricCSR2D<_> = UndiEdgesBuilder::default()
+///     .expected_number_of_edges(edges.len())
+///     .expected_shape(5)
+///     .edges(edges.into_iter())
+///     .build()
+///     .unwrap();
+///
+/// let overlap = OverlapSimilarity::new(&edges);
+/// // The neighbours of node 0 are all neighbours of node 3.
+/// assert!((overlap.similarity(&0, &3) - 1.0).abs() < f64::EPSILON);
+/// // Node 4 shares its only neighbour with node 1.
+/// assert!((overlap.similarity(&1, &4) - 1.0).abs() < f64::EPSILON);
+///
+/// let similarities = overlap.pairwise_similarities(1.0);
+/// assert_eq!(
+///     SparseMatrix::sparse_coordinates(&similarities).collect::<Vec<_>>(),
+///     vec![(0, 3), (1, 2), (1, 4), (2, 1), (2, 4), (3, 0), (4, 1), (4, 2)]
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct OverlapSimilarity<'matrix, M: ?Sized> {
+    /// The matrix whose rows are compared.
+    matrix: &'matrix M,
+}
+
+impl<'matrix, M> OverlapSimilarity<'matrix, M>
+where
+    M: SizedRowsSparseMatrix2D + ?Sized,
+    M::RowIndex: AsPrimitive<usize>,
+    M::ColumnIndex: AsPrimitive<usize>,
+{
+    /// Creates the overlap similarity between the rows of the matrix.
+    #[inline]
+    #[must_use]
+    pub fn new(matrix: &'matrix M) -> Self {
+        Self { matrix }
+    }
+
+    /// Returns the overlap similarities of all pairs of distinct rows which
+    /// are at least `threshold`, as a square sparse matrix indexed by row.
+    ///
+    /// Only pairs of rows sharing at least one column are compared, so pairs
+    /// with null similarity are never emitted, whatever the threshold. The
+    /// returned matrix is symmetric.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - The minimum similarity of the emitted pairs.
+    ///
+    /// # Complexity
+    ///
+    /// O(R + C + Σ deg(c)²) time, where the sum runs over the columns and
+    /// `deg(c)` is the number of rows holding column `c`.
+    #[allow(clippy::cast_precision_loss)]
+    #[must_use]
+    pub fn pairwise_similarities(&self, threshold: f64) -> ValuedCSR2D<usize, usize, usize, f64> {
+        let rows: Vec<Vec<(usize, f64)>> = self
+            .matrix
+            .row_indices()
+            .map(|row| self.matrix.sparse_row(row).map(|column| (column.as_(), 1.0)).collect())
+            .collect();
+        // With unit weights, the dot products count the shared columns, and
+        // rows sharing a column are never empty.
+        thresholded_pairwise(
+            &rows,
+            self.matrix.number_of_columns().as_(),
+            threshold,
+            |left, right, shared| shared / rows[left].len().min(rows[right].len()) as f64,
+        )
+    }
+}
+
+impl<M> ScalarSimilarity<M::RowIndex, M::RowIndex> for OverlapSimilarity<'_, M>
+where
+    M: SizedRowsSparseMatrix2D + ?Sized,
+    M::ColumnIndex: AsPrimitive<usize>,
+{
+    type Similarity = f64;
+
+    #[inline]
+    fn similarity(&self, left: &M::RowIndex, right: &M::RowIndex) -> Self::Similarity {
+        let shared = self
+            .matrix
+            .sparse_row(*left)
+            .sorted_intersection(self.matrix.sparse_row(*right))
+            .count();
+        overlap_similarity(
+            shared,
+            self.matrix.number_of_defined_values_in_row(*left).as_(),
+            self.matrix.number_of_defined_values_in_row(*right).as_(),
+        )
+    }
+}
+
+impl<M> TopKSimilarity<M::RowIndex> for OverlapSimilarity<'_, M>
+where
+    M: SizedRowsSparseMatrix2D + SparseBiMatrix2D + ?Sized,
+    M::ColumnIndex: AsPrimitive<usize>,
+{
+    /// Returns up to `k` rows other than `item` with the highest overlap
+    /// similarity, sorted by decreasing similarity and then by increasing row.
+    ///
+    /// Only the rows sharing a column with `item` are scored, which on the
+    /// adjacency matrix of a graph are the neighbours of its neighbours.
+    #[inline]
+    fn top_k_similar(&self, item: &M::RowIndex, k: usize) -> Vec<(M::RowIndex, f64)> {
+        let scored = rows_sharing_a_column(self.matrix, *item)
+            .into_iter()
+            .map(|candidate| (candidate, self.similarity(item, &candidate)))
+            .collect();
+        top_k_by_score(scored, k)
+    }
+}
@@ -0,0 +1,484 @@
+//! Submodule providing the `VertexCover` and `DominatingSet` traits,
+//! approximating minimum-weight sets of nodes covering an undirected graph.
+//!
+//! A vertex cover holds an endpoint of every edge, and a dominating set holds
+//! every node or one of its neighbours. Both problems are NP-hard, and their
+//! approximations come with a lower bound on the optimal weight, certified by
+//! a feasible solution of the dual of their linear relaxation, so that the
+//! quality of each returned set can be assessed a posteriori.
+//!
+//! The vertex cover is computed by the local-ratio algorithm of Bar-Yehuda
+//! and Even, which pays for every uncovered edge with the residual weight of
+//! its cheaper endpoint, and the dominating set by the greedy algorithm of
+//! Chvátal, which repeatedly picks the node dominating new nodes at the
+//! smallest weight per node. Both sets are then made minimal, by dropping the
+//! heaviest nodes that have become redundant.
+
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+use core::cmp::Ordering;
+
+use num_traits::AsPrimitive;
+
+use crate::traits::{MonopartiteGraph, UndirectedMonopartiteMonoplexGraph};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+/// Error enumeration for the vertex cover and dominating set approximations.
+pub enum NodeCoverError {
+    /// The number of weights differs from the number of nodes.
+    #[error("Expected {expected} node weights, one per node, but found {found}.")]
+    WeightsLengthMismatch {
+        /// The number of nodes.
+        expected: usize,
+        /// The number of weights.
+        found: usize,
+    },
+    /// A node weight is negative or not finite.
+    #[error("The weight of the node {node_id} is negative or not finite.")]
+    InvalidWeight {
+        /// The node with the invalid weight.
+        node_id: usize,
+    },
+}
+
+impl From<NodeCoverError>
+    for crate::errors::monopartite_graph_error::algorithms::MonopartiteAlgorithmError
+{
+    #[inline]
+    fn from(error: NodeCoverError) -> Self {
+        Self::NodeCoverError(error)
+    }
+}
+
+impl<G: MonopartiteGraph> From<NodeCoverError> for crate::errors::MonopartiteError<G> {
+    #[inline]
+    fn from(error: NodeCoverError) -> Self {
+        Self::AlgorithmError(error.into())
+    }
+}
+
+/// Set of nodes returned by the [`VertexCover`] and [`DominatingSet`]
+/// approximations, with the metadata bounding its distance to the optimum.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NodeCover<I> {
+    /// The nodes of the set, in increasing order.
+    nodes: Vec<I>,
+    /// The total weight of the nodes.
+    weight: f64,
+    /// A lower bound on the weight of an optimal set.
+    lower_bound: f64,
+    /// The worst-case approximation factor of the algorithm on the graph.
+    guarantee: f64,
+}
+
+impl<I> NodeCover<I> {
+    /// Returns the nodes of the set, in increasing order.
+    #[inline]
+    #[must_use]
+    pub fn nodes(&self) -> &[I] {
+        &self.nodes
+    }
+
+    /// Returns the total weight of the nodes of the set.
+    #[inline]
+    #[must_use]
+    pub fn weight(&self) -> f64 {
+        self.weight
+    }
+
+    /// Returns a lower bound on the weight of an optimal set, given by a
+    /// feasible solution of the dual linear relaxation.
+    #[inline]
+    #[must_use]
+    pub fn lower_bound(&self) -> f64 {
+        self.lower_bound
+    }
+
+    /// Returns the ratio between the weight of the set and the lower bound,
+    /// which bounds the ratio between the weight of the set and the optimal
+    /// one, or one if the set has no weight.
+    #[inline]
+    #[must_use]
+    pub fn approximation_ratio(&self) -> f64 {
+        if self.weight <= 0.0 { 1.0 } else { self.weight / self.lower_bound }
+    }
+
+    /// Returns the worst-case approximation factor of the algorithm on the
+    /// graph, which the approximation ratio never exceeds.
+    #[inline]
+    #[must_use]
+    pub fn guarantee(&self) -> f64 {
+        self.guarantee
+    }
+}
+
+/// Trait providing a 2-approximation of the minimum-weight vertex cover of
+/// undirected graphs.
+///
+/// A node with a self-loop belongs to every vertex cover.
+///
+/// # References
+///
+/// Bar-Yehuda, R., & Even, S. (1981). [A linear-time approximation algorithm for the weighted vertex cover problem](https://doi.org/10.1016/0196-6774(81)90020-1).
+/// *Journal of Algorithms*, 2(2), 198-203.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{
+///     impls::{CSR2D, SortedVec, SymmetricCSR2D},
+///     prelude::*,
+///     traits::{EdgesBuilder, VertexCover, VocabularyBuilder},
+/// };
+///
+/// let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+///     .expected_number_of_symbols(5)
+///     .symbols((0..5).enumerate())
+///     .build()
+///     .unwrap();
+/// // A star, whose hub covers every edge.
+/// let edges: SymmetricCSR2D<CSR2D<usize, usize, usize>> = UndiEdgesBuilder::default()
+///     .expected_number_of_edges(4)
+///     .expected_shape(5)
+///     .edges(vec![(0, 1), (0, 2), (0, 3), (0, 4)].into_iter())
+///     .build()
+///     .unwrap();
+/// let star: UndiGraph<usize> = UndiGraph::from((nodes, edges));
+///
+/// let cover = star.vertex_cover();
+/// assert_eq!(cover.nodes(), &[0]);
+/// assert_eq!(cover.weight(), 1.0);
+///
+/// // A heavy hub is cheaper to replace by the leaves.
+/// let cover = star.weighted_vertex_cover(&[10.0, 1.0, 1.0, 1.0, 1.0]).unwrap();
+/// assert_eq!(cover.nodes(), &[1, 2, 3, 4]);
+/// assert!(cover.approximation_ratio() <= cover.guarantee());
+/// ```
+pub trait VertexCover: UndirectedMonopartiteMonoplexGraph
+where
+    Self::NodeId: AsPrimitive<usize>,
+{
+    /// Returns a vertex cover with at most twice as many nodes as a minimum
+    /// one.
+    ///
+    /// # Complexity
+    ///
+    /// O(V log V + E) time and O(V) space.
+    #[inline]
+    fn vertex_cover(&self) -> NodeCover<Self::NodeId> {
+        let weights = vec![1.0; self.number_of_nodes().as_()];
+        self.weighted_vertex_cover(&weights)
+            .unwrap_or_else(|_| unreachable!("Unit weights are valid."))
+    }
+
+    /// Returns a vertex cover weighing at most twice as much as a
+    /// minimum-weight one.
+    ///
+    /// The lower bound of the returned cover is the total price paid for the
+    /// edges, which the optimal weight cannot be below.
+    ///
+    /// # Arguments
+    ///
+    /// * `weights`: The non-negative weight of each node.
+    ///
+    /// # Errors
+    ///
+    /// * [`NodeCoverError::WeightsLengthMismatch`] if there is not one weight
+    ///   per node.
+    /// * [`NodeCoverError::InvalidWeight`] if a weight is negative or not
+    ///   finite.
+    ///
+    /// # Complexity
+    ///
+    /// O(V log V + E) time and O(V) space.
+    #[inline]
+    fn weighted_vertex_cover(
+        &self,
+        weights: &[f64],
+    ) -> Result<NodeCover<Self::NodeId>, NodeCoverError> {
+        validate_weights(self.number_of_nodes().as_(), weights)?;
+        let node_ids: Vec<Self::NodeId> = self.node_ids().collect();
+        let mut residuals = weights.to_vec();
+        let mut in_cover = vec![false; node_ids.len()];
+        let mut lower_bound = 0.0;
+        for &node in &node_ids {
+            for neighbor in self.neighbors(node) {
+                let (left, right) = (node.as_(), neighbor.as_());
+                if right < left || in_cover[left] || in_cover[right] {
+                    continue;
+                }
+                // A self-loop is paid for by its node alone.
+                let price = residuals[left].min(residuals[right]);
+                residuals[left] -= price;
+                if right != left {
+                    residuals[right] -= price;
+                }
+                lower_bound += price;
+                in_cover[left] = residuals[left] == 0.0;
+                in_cover[right] = residuals[right] == 0.0;
+            }
+        }
+
+        // A node is redundant when all its neighbours are in the cover.
+        for node in by_decreasing_weight(weights, &in_cover) {
+            if self.neighbors(node_ids[node]).all(|neighbor| {
+                let neighbor = neighbor.as_();
+                neighbor != node && in_cover[neighbor]
+            }) {
+                in_cover[node] = false;
+            }
+        }
+        Ok(node_cover(&node_ids, weights, &in_cover, lower_bound, 2.0))
+    }
+}
+
+impl<G> VertexCover for G
+where
+    G: UndirectedMonopartiteMonoplexGraph,
+    G::NodeId: AsPrimitive<usize>,
+{
+}
+
+/// Trait providing a greedy approximation of the minimum-weight dominating
+/// set of undirected graphs.
+///
+/// The approximation factor is the harmonic number H(Δ + 1) of the largest
+/// number of nodes dominated by a single node, which is at most
+/// ln(Δ + 1) + 1 for the maximum degree Δ.
+///
+/// # References
+///
+/// Chvátal, V. (1979). [A greedy heuristic for the set-covering problem](https://doi.org/10.1287/moor.4.3.233).
+/// *Mathematics of Operations Research*, 4(3), 233-235.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{
+///     impls::{CSR2D, SortedVec, SymmetricCSR2D},
+///     prelude::*,
+///     traits::{DominatingSet, EdgesBuilder, VocabularyBuilder},
+/// };
+///
+/// let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+///     .expected_number_of_symbols(7)
+///     .symbols((0..7).enumerate())
+///     .build()
+///     .unwrap();
+/// // A path of six nodes, dominated by its second and fifth nodes, and an
+/// // isolated node, which dominates itself.
+/// let edges: SymmetricCSR2D<CSR2D<usize, usize, usize>> = UndiEdgesBuilder::default()
+///     .expected_number_of_edges(5)
+///     .expected_shape(7)
+///     .edges(vec![(0, 1), (1, 2), (2, 3), (3, 4), (4, 5)].into_iter())
+///     .build()
+///     .unwrap();
+/// let graph: UndiGraph<usize> = UndiGraph::from((nodes, edges));
+///
+/// let dominating_set = graph.dominating_set();
+/// assert_eq!(dominating_set.nodes(), &[1, 4, 6]);
+/// assert_eq!(dominating_set.weight(), 3.0);
+/// assert!(dominating_set.approximation_ratio() <= dominating_set.guarantee());
+/// ```
+pub trait DominatingSet: UndirectedMonopartiteMonoplexGraph
+where
+    Self::NodeId: AsPrimitive<usize>,
+{
+    /// Returns a dominating set with at most H(Δ + 1) times as many nodes as
+    /// a minimum one.
+    ///
+    /// # Complexity
+    ///
+    /// O((V + E) log V) time and O(V) space.
+    #[inline]
+    fn dominating_set(&self) -> NodeCover<Self::NodeId> {
+        let weights = vec![1.0; self.number_of_nodes().as_()];
+        self.weighted_dominating_set(&weights)
+            .unwrap_or_else(|_| unreachable!("Unit weights are valid."))
+    }
+
+    /// Returns a dominating set weighing at most H(Δ + 1) times as much as a
+    /// minimum-weight one.
+    ///
+    /// The lower bound of the returned set is the larger of two bounds: the
+    /// one charging each node the smallest weight per dominated node among
+    /// the nodes dominating it, and the weight of the greedy set before it
+    /// is made minimal divided by the approximation factor.
+    ///
+    /// # Arguments
+    ///
+    /// * `weights`: The non-negative weight of each node.
+    ///
+    /// # Errors
+    ///
+    /// * [`NodeCoverError::WeightsLengthMismatch`] if there is not one weight
+    ///   per node.
+    /// * [`NodeCoverError::InvalidWeight`] if a weight is negative or not
+    ///   finite.
+    ///
+    /// # Complexity
+    ///
+    /// O((V + E) log V) time and O(V) space.
+    #[inline]
+    fn weighted_dominating_set(
+        &self,
+        weights: &[f64],
+    ) -> Result<NodeCover<Self::NodeId>, NodeCoverError> {
+        validate_weights(self.number_of_nodes().as_(), weights)?;
+        let node_ids: Vec<Self::NodeId> = self.node_ids().collect();
+        // The nodes dominated by a node are itself and its other neighbours.
+        let closed_neighborhood = |node: usize| {
+            core::iter::once(node).chain(
+                self.neighbors(node_ids[node])
+                    .map(AsPrimitive::as_)
+                    .filter(move |&neighbor| neighbor != node),
+            )
+        };
+        let closed_degrees: Vec<usize> =
+            (0..node_ids.len()).map(|node| closed_neighborhood(node).count()).collect();
+        let packing_bound: f64 = (0..node_ids.len())
+            .map(|node| {
+                closed_neighborhood(node)
+                    .map(|dominator| weights[dominator] / usize_to_f64(closed_degrees[dominator]))
+                    .fold(f64::INFINITY, f64::min)
+            })
+            .sum();
+        let guarantee = (1..=closed_degrees.iter().copied().max().unwrap_or(1))
+            .map(|count| 1.0 / usize_to_f64(count))
+            .sum();
+
+        // The number of undominated nodes each node would dominate, whose
+        // ratios in the heap can only be outdated by being too optimistic.
+        let mut gains = closed_degrees.clone();
+        let mut heap: BinaryHeap<Candidate> = (0..node_ids.len())
+            .map(|node| Candidate { cost: weights[node] / usize_to_f64(gains[node]), node })
+            .collect();
+        let mut dominators = vec![0_usize; node_ids.len()];
+        let mut in_set = vec![false; node_ids.len()];
+        let mut undominated = node_ids.len();
+        while undominated > 0 {
+            let Some(Candidate { cost, node }) = heap.pop() else {
+                unreachable!("Every node dominates itself.");
+            };
+            if gains[node] == 0 {
+                continue;
+            }
+            let current_cost = weights[node] / usize_to_f64(gains[node]);
+            if current_cost > cost {
+                heap.push(Candidate { cost: current_cost, node });
+                continue;
+            }
+            in_set[node] = true;
+            for dominated in closed_neighborhood(node) {
+                dominators[dominated] += 1;
+                if dominators[dominated] == 1 {
+                    undominated -= 1;
+                    for dominator in closed_neighborhood(dominated) {
+                        gains[dominator] -= 1;
+                    }
+                }
+            }
+        }
+
+        let greedy_weight: f64 =
+            (0..node_ids.len()).filter(|&node| in_set[node]).map(|node| weights[node]).sum();
+        let lower_bound = packing_bound.max(greedy_weight / guarantee);
+
+        // A node is redundant when all the nodes it dominates are dominated
+        // by another node of the set.
+        for node in by_decreasing_weight(weights, &in_set) {
+            if closed_neighborhood(node).all(|dominated| dominators[dominated] > 1) {
+                in_set[node] = false;
+                for dominated in closed_neighborhood(node) {
+                    dominators[dominated] -= 1;
+                }
+            }
+        }
+        Ok(node_cover(&node_ids, weights, &in_set, lower_bound, guarantee))
+    }
+}
+
+impl<G> DominatingSet for G
+where
+    G: UndirectedMonopartiteMonoplexGraph,
+    G::NodeId: AsPrimitive<usize>,
+{
+}
+
+/// Node of the greedy dominating set heap, popped by increasing weight per
+/// newly dominated node and then by increasing node.
+#[derive(Debug, Clone, Copy)]
+struct Candidate {
+    /// The weight per newly dominated node, possibly outdated.
+    cost: f64,
+    /// The node.
+    node: usize,
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost.total_cmp(&self.cost).then_with(|| other.node.cmp(&self.node))
+    }
+}
+
+/// Checks that there is one non-negative and finite weight per node.
+fn validate_weights(number_of_nodes: usize, weights: &[f64]) -> Result<(), NodeCoverError> {
+    if weights.len() != number_of_nodes {
+        return Err(NodeCoverError::WeightsLengthMismatch {
+            expected: number_of_nodes,
+            found: weights.len(),
+        });
+    }
+    match weights.iter().position(|weight| !weight.is_finite() || *weight < 0.0) {
+        Some(node_id) => Err(NodeCoverError::InvalidWeight { node_id }),
+        None => Ok(()),
+    }
+}
+
+/// Returns the selected nodes by decreasing weight, ties going to the
+/// largest node.
+fn by_decreasing_weight(weights: &[f64], selected: &[bool]) -> Vec<usize> {
+    let mut nodes: Vec<usize> = (0..selected.len()).filter(|&node| selected[node]).collect();
+    nodes.sort_unstable_by(|&left, &right| {
+        weights[right].total_cmp(&weights[left]).then_with(|| right.cmp(&left))
+    });
+    nodes
+}
+
+/// Builds the node cover of the selected nodes.
+fn node_cover<I: Copy>(
+    node_ids: &[I],
+    weights: &[f64],
+    selected: &[bool],
+    lower_bound: f64,
+    guarantee: f64,
+) -> NodeCover<I> {
+    let nodes: Vec<usize> = (0..selected.len()).filter(|&node| selected[node]).collect();
+    let weight = nodes.iter().map(|&node| weights[node]).sum();
+    NodeCover {
+        nodes: nodes.into_iter().map(|node| node_ids[node]).collect(),
+        weight,
+        lower_bound,
+        guarantee,
+    }
+}
+
+/// Converts a count into a floating point value.
+#[allow(clippy::cast_precision_loss)]
+#[inline]
+fn usize_to_f64(value: usize) -> f64 {
+    value as f64
+}
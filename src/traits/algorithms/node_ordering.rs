@@ -30,8 +30,10 @@ mod triangles;
 use alloc::vec::Vec;
 
 pub use application::{NodeOrderApplicableGraph, PermutableVocabulary, apply_node_order_to_graph};
+pub(crate) use betweenness_centrality::BrandesWorkspace;
 pub use betweenness_centrality::{BetweennessCentralityScorer, BetweennessCentralityScorerBuilder};
 pub use closeness_centrality::{ClosenessCentralityScorer, ClosenessCentralityScorerBuilder};
+pub(crate) use closeness_centrality::{breadth_first_closeness, closeness};
 pub use degeneracy::{CoreNumberScorer, DegeneracySorter};
 pub use degree::{DegreeScorer, SecondOrderDegreeScorer};
 pub use dsatur::DsaturSorter;
@@ -54,7 +56,7 @@ pub use traversal::{
 };
 pub use triangles::TriangleCountScorer;
 
-use crate::traits::{MonopartiteGraph, TotalOrd};
+use crate::traits::{MonopartiteGraph, TotalOrd, TryFromUsize, UndirectedMonopartiteMonoplexGraph};
 
 /// Trait for algorithms that return a complete node ordering for a graph.
 pub trait NodeSorter<G>
@@ -269,6 +271,16 @@ pub(super) const LOCAL_CLUSTERING_SCORE_SCALE: f64 = 1.0e12;
 pub(super) const SQUARE_CLUSTERING_SCORE_SCALE: f64 = 1.0e12;
 pub(super) const POWER_ITERATION_EIGENVECTOR_SCORE_SCALE: f64 = 1.0e12;
 
+/// Returns the indices of the neighbors of the node of the graph.
+pub(super) fn neighbor_indices<G>(graph: &G, node_index: usize) -> impl Iterator<Item = usize> + '_
+where
+    G: UndirectedMonopartiteMonoplexGraph,
+{
+    let node = G::NodeId::try_from_usize(node_index)
+        .unwrap_or_else(|_| unreachable!("Node indices are node identifiers."));
+    graph.neighbors(node).map(AsPrimitive::as_)
+}
+
 #[inline]
 pub(super) fn usize_to_f64(value: usize) -> f64 {
     cast::<usize, f64>(value).expect("graph sizes and degrees must fit into f64 for PageRank")
@@ -1,16 +1,24 @@
-use alloc::{collections::VecDeque, vec::Vec};
+use alloc::{
+    collections::{BinaryHeap, VecDeque},
+    vec::Vec,
+};
 
-use num_traits::AsPrimitive;
+use num_traits::{AsPrimitive, Zero};
 
-use super::{BETWEENNESS_SCORE_SCALE, NodeScorer, usize_to_f64};
-use crate::traits::UndirectedMonopartiteMonoplexGraph;
+use super::{BETWEENNESS_SCORE_SCALE, NodeScorer, neighbor_indices, usize_to_f64};
+use crate::traits::{
+    Finite, Number, PositiveInteger, SparseValuedMatrix2D, TotalOrd,
+    UndirectedMonopartiteMonoplexGraph,
+    algorithms::dijkstra::{DijkstraError, DijkstraVisitor, QueueEntry, dijkstra_search},
+};
 
 /// Betweenness centrality scorer.
 ///
 /// This matches the exact unweighted `NetworkX` betweenness-centrality
 /// contract on undirected simple graphs, including the `normalized` and
 /// `endpoints` parameter behavior. Weighted edges and source sampling are not
-/// part of this scorer.
+/// part of this scorer, whose weighted and directed counterpart is
+/// [`Centrality`](crate::traits::Centrality).
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct BetweennessCentralityScorer {
     normalized: bool,
@@ -98,68 +106,79 @@ where
         if n == 0 {
             return Vec::new();
         }
-
-        let nodes: Vec<G::NodeId> = graph.node_ids().collect();
-        debug_assert_eq!(nodes.len(), n);
-        debug_assert!(nodes.iter().enumerate().all(|(i, node)| (*node).as_() == i));
+        debug_assert!(graph.node_ids().enumerate().all(|(i, node)| node.as_() == i));
 
         let mut scores = vec![0.0; n];
-        let mut stack = Vec::with_capacity(n);
-        let mut predecessors = vec![Vec::<usize>::new(); n];
-        let mut sigma = vec![0.0; n];
-        let mut distance = vec![usize::MAX; n];
-        let mut queue = VecDeque::with_capacity(n);
-        let mut delta = vec![0.0; n];
-
+        let mut workspace = BrandesWorkspace::new(n);
         for source_index in 0..n {
-            stack.clear();
-            queue.clear();
-            sigma.fill(0.0);
-            distance.fill(usize::MAX);
-            delta.fill(0.0);
-            for node_predecessors in &mut predecessors {
-                node_predecessors.clear();
-            }
+            workspace.breadth_first_search(source_index, |node_index| {
+                neighbor_indices(graph, node_index)
+            });
+            workspace.accumulate(source_index, self.endpoints, |node_index, value| {
+                scores[node_index] += value;
+            });
+        }
 
-            sigma[source_index] = 1.0;
-            distance[source_index] = 0;
-            queue.push_back(source_index);
-
-            while let Some(node_index) = queue.pop_front() {
-                stack.push(node_index);
-                let node = nodes[node_index];
-                let node_distance = distance[node_index];
-                let node_sigma = sigma[node_index];
-
-                for neighbor in graph.neighbors(node) {
-                    let neighbor_index = neighbor.as_();
-                    if distance[neighbor_index] == usize::MAX {
-                        queue.push_back(neighbor_index);
-                        distance[neighbor_index] = node_distance + 1;
-                    }
-                    if distance[neighbor_index] == node_distance + 1 {
-                        sigma[neighbor_index] += node_sigma;
-                        predecessors[neighbor_index].push(node_index);
-                    }
-                }
-            }
+        self.scale_scores(&mut scores);
+        scores
+    }
+}
 
-            if self.endpoints {
-                scores[source_index] += usize_to_f64(stack.len().saturating_sub(1));
-            }
+impl BetweennessCentralityScorer {
+    #[cfg(feature = "parallel")]
+    /// Scores the nodes of the graph, running the breadth-first searches from
+    /// the different sources on the `rayon` thread pool.
+    ///
+    /// The scores are identical to the ones of
+    /// [`NodeScorer::score_nodes`] whatever the number of threads.
+    #[must_use]
+    pub fn par_score_nodes<G>(&self, graph: &G) -> Vec<f64>
+    where
+        G: UndirectedMonopartiteMonoplexGraph + Sync,
+    {
+        use rayon::prelude::*;
 
-            while let Some(node_index) = stack.pop() {
-                let coefficient = (1.0 + delta[node_index]) / sigma[node_index];
-                for &predecessor_index in &predecessors[node_index] {
-                    delta[predecessor_index] += sigma[predecessor_index] * coefficient;
-                }
-                if node_index != source_index {
-                    scores[node_index] +=
-                        if self.endpoints { delta[node_index] + 1.0 } else { delta[node_index] };
-                }
+        let n = graph.number_of_nodes().as_();
+        if n == 0 {
+            return Vec::new();
+        }
+        debug_assert!(graph.node_ids().enumerate().all(|(i, node)| node.as_() == i));
+
+        // Every source adds at most one value to each score, so adding the
+        // contributions of the sources in order yields the sequential sums.
+        let mut scores = vec![0.0; n];
+        let block_size = rayon::current_num_threads().saturating_mul(8).max(1);
+        for block_start in (0..n).step_by(block_size) {
+            let contributions: Vec<Vec<(usize, f64)>> = (block_start
+                ..n.min(block_start + block_size))
+                .into_par_iter()
+                .map_init(
+                    || BrandesWorkspace::new(n),
+                    |workspace, source_index| {
+                        let mut contribution = Vec::new();
+                        workspace.breadth_first_search(source_index, |node_index| {
+                            neighbor_indices(graph, node_index)
+                        });
+                        workspace.accumulate(source_index, self.endpoints, |node_index, value| {
+                            contribution.push((node_index, value));
+                        });
+                        contribution
+                    },
+                )
+                .collect();
+            for (node_index, value) in contributions.into_iter().flatten() {
+                scores[node_index] += value;
             }
         }
 
+        self.scale_scores(&mut scores);
+        scores
+    }
+
+    /// Scales the summed dependencies of the nodes into their rounded
+    /// betweenness scores.
+    fn scale_scores(self, scores: &mut [f64]) {
+        let n = scores.len();
         let scale = if self.normalized {
             if self.endpoints {
                 if n < 2 { None } else { Some(1.0 / (usize_to_f64(n) * usize_to_f64(n - 1))) }
@@ -173,16 +192,185 @@ where
         };
 
         if let Some(scale) = scale {
-            for score in &mut scores {
+            for score in scores {
                 *score *= scale;
                 *score = (*score * BETWEENNESS_SCORE_SCALE).round() / BETWEENNESS_SCORE_SCALE;
             }
         } else {
-            for score in &mut scores {
+            for score in scores {
                 *score = (*score * BETWEENNESS_SCORE_SCALE).round() / BETWEENNESS_SCORE_SCALE;
             }
         }
+    }
+}
+
+/// Buffers of the single-source shortest-path searches of Brandes' algorithm.
+///
+/// A search, either breadth-first or weighted, records the shortest-path
+/// directed acyclic graph from its source, from which
+/// [`BrandesWorkspace::accumulate`] derives the dependencies of the source.
+pub(crate) struct BrandesWorkspace {
+    stack: Vec<usize>,
+    predecessors: Vec<Vec<usize>>,
+    sigma: Vec<f64>,
+    distance: Vec<usize>,
+    queue: VecDeque<usize>,
+    delta: Vec<f64>,
+}
+
+impl BrandesWorkspace {
+    pub(crate) fn new(n: usize) -> Self {
+        Self {
+            stack: Vec::with_capacity(n),
+            predecessors: vec![Vec::new(); n],
+            sigma: vec![0.0; n],
+            distance: vec![usize::MAX; n],
+            queue: VecDeque::with_capacity(n),
+            delta: vec![0.0; n],
+        }
+    }
 
-        scores
+    /// Clears the state of the previous search and starts one from the
+    /// source.
+    fn reset(&mut self, source_index: usize) {
+        self.stack.clear();
+        self.queue.clear();
+        self.sigma.fill(0.0);
+        self.distance.fill(usize::MAX);
+        self.delta.fill(0.0);
+        for node_predecessors in &mut self.predecessors {
+            node_predecessors.clear();
+        }
+        self.sigma[source_index] = 1.0;
+    }
+
+    /// Runs a breadth-first search from the source, following the provided
+    /// successors of every node.
+    pub(crate) fn breadth_first_search<S, I>(&mut self, source_index: usize, successors: S)
+    where
+        S: Fn(usize) -> I,
+        I: IntoIterator<Item = usize>,
+    {
+        self.reset(source_index);
+        let Self { stack, predecessors, sigma, distance, queue, .. } = self;
+        distance[source_index] = 0;
+        queue.push_back(source_index);
+
+        while let Some(node_index) = queue.pop_front() {
+            stack.push(node_index);
+            let node_distance = distance[node_index];
+            let node_sigma = sigma[node_index];
+
+            for neighbor_index in successors(node_index) {
+                if distance[neighbor_index] == usize::MAX {
+                    queue.push_back(neighbor_index);
+                    distance[neighbor_index] = node_distance + 1;
+                }
+                if distance[neighbor_index] == node_distance + 1 {
+                    sigma[neighbor_index] += node_sigma;
+                    predecessors[neighbor_index].push(node_index);
+                }
+            }
+        }
+    }
+
+    /// Runs Dijkstra's algorithm from the source over the rows of the
+    /// matrix, using the provided buffers.
+    ///
+    /// The weights of the edges other than self-loops are expected to be
+    /// positive, since zero weights would not settle the predecessors of a
+    /// node before it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of the search, for non-finite or negative weights
+    /// and non-finite distances.
+    pub(crate) fn dijkstra_search<M>(
+        &mut self,
+        matrix: &M,
+        source_id: M::RowIndex,
+        distances: &mut [Option<M::Value>],
+        heap: &mut BinaryHeap<QueueEntry<M::Value, M::RowIndex>>,
+    ) -> Result<(), DijkstraError>
+    where
+        M: SparseValuedMatrix2D,
+        M::Value: Number + Finite + TotalOrd,
+        M::RowIndex: PositiveInteger,
+        M::ColumnIndex: AsPrimitive<usize> + Into<M::RowIndex>,
+    {
+        self.reset(source_id.as_());
+        distances.fill(None);
+        dijkstra_search(matrix, source_id, distances, heap, self)
+    }
+
+    /// Reports the contribution of the source of the last search to the
+    /// unscaled score of every node, at most once per node.
+    pub(crate) fn accumulate(
+        &mut self,
+        source_index: usize,
+        endpoints: bool,
+        add: impl FnMut(usize, f64),
+    ) {
+        self.accumulate_with_edges(source_index, endpoints, add, |_, _, _| {});
+    }
+
+    /// Reports the contribution of the source of the last search to the
+    /// unscaled score of every node, as [`BrandesWorkspace::accumulate`], and
+    /// the dependency credited to every edge of the shortest-path directed
+    /// acyclic graph, as `(predecessor, node, dependency)`.
+    pub(crate) fn accumulate_with_edges(
+        &mut self,
+        source_index: usize,
+        endpoints: bool,
+        mut add: impl FnMut(usize, f64),
+        mut add_edge: impl FnMut(usize, usize, f64),
+    ) {
+        let Self { stack, predecessors, sigma, delta, .. } = self;
+        if endpoints {
+            add(source_index, usize_to_f64(stack.len().saturating_sub(1)));
+        }
+
+        while let Some(node_index) = stack.pop() {
+            let coefficient = (1.0 + delta[node_index]) / sigma[node_index];
+            for &predecessor_index in &predecessors[node_index] {
+                let dependency = sigma[predecessor_index] * coefficient;
+                add_edge(predecessor_index, node_index, dependency);
+                delta[predecessor_index] += dependency;
+            }
+            if node_index != source_index {
+                add(
+                    node_index,
+                    if endpoints { delta[node_index] + 1.0 } else { delta[node_index] },
+                );
+            }
+        }
+    }
+}
+
+impl<V: Zero, I: PositiveInteger> DijkstraVisitor<V, I> for BrandesWorkspace {
+    type Error = DijkstraError;
+
+    #[inline]
+    fn settle(&mut self, node_id: I, _distance: V) -> bool {
+        self.stack.push(node_id.as_());
+        false
+    }
+
+    #[inline]
+    fn improve(&mut self, destination_id: I, node_id: I) {
+        let (destination, node) = (destination_id.as_(), node_id.as_());
+        self.sigma[destination] = self.sigma[node];
+        self.predecessors[destination].clear();
+        self.predecessors[destination].push(node);
+    }
+
+    #[inline]
+    fn tie(&mut self, destination_id: I, node_id: I) {
+        let (destination, node) = (destination_id.as_(), node_id.as_());
+        // A self-loop of null weight does not yield another path.
+        if destination != node {
+            self.sigma[destination] += self.sigma[node];
+            self.predecessors[destination].push(node);
+        }
     }
 }
@@ -2,14 +2,15 @@ use alloc::{collections::VecDeque, vec::Vec};
 
 use num_traits::AsPrimitive;
 
-use super::{CLOSENESS_SCORE_SCALE, NodeScorer, usize_to_f64};
+use super::{CLOSENESS_SCORE_SCALE, NodeScorer, neighbor_indices, usize_to_f64};
 use crate::traits::UndirectedMonopartiteMonoplexGraph;
 
 /// Closeness centrality scorer.
 ///
 /// This matches the exact unweighted `NetworkX` closeness-centrality contract
 /// on undirected simple graphs for the supported `wf_improved` parameter.
-/// Weighted distances are not part of this scorer.
+/// Weighted distances are not part of this scorer, whose weighted and
+/// directed counterpart is [`Centrality`](crate::traits::Centrality).
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct ClosenessCentralityScorer {
     wf_improved: bool,
@@ -86,54 +87,132 @@ where
         if n == 0 {
             return Vec::new();
         }
-
-        let nodes: Vec<G::NodeId> = graph.node_ids().collect();
-        debug_assert_eq!(nodes.len(), n);
-        debug_assert!(nodes.iter().enumerate().all(|(i, node)| (*node).as_() == i));
+        debug_assert!(graph.node_ids().enumerate().all(|(i, node)| node.as_() == i));
 
         let mut scores = vec![0.0; n];
         let mut distance = vec![usize::MAX; n];
         let mut queue = VecDeque::with_capacity(n);
+        for (source_index, score) in scores.iter_mut().enumerate() {
+            *score = self.source_score(graph, source_index, &mut distance, &mut queue);
+        }
 
-        for source_index in 0..n {
-            distance.fill(usize::MAX);
-            queue.clear();
-            distance[source_index] = 0;
-            queue.push_back(source_index);
-
-            let mut total_shortest_path_distance = 0usize;
-            let mut reachable_nodes = 1usize;
-
-            while let Some(node_index) = queue.pop_front() {
-                let node = nodes[node_index];
-                let node_distance = distance[node_index];
-
-                for neighbor in graph.neighbors(node) {
-                    let neighbor_index = neighbor.as_();
-                    if distance[neighbor_index] != usize::MAX {
-                        continue;
-                    }
-
-                    let neighbor_distance = node_distance + 1;
-                    distance[neighbor_index] = neighbor_distance;
-                    total_shortest_path_distance += neighbor_distance;
-                    reachable_nodes += 1;
-                    queue.push_back(neighbor_index);
-                }
-            }
+        scores
+    }
+}
 
-            if total_shortest_path_distance > 0 && n > 1 {
-                let reachable_minus_source = reachable_nodes - 1;
-                let mut score = usize_to_f64(reachable_minus_source)
-                    / usize_to_f64(total_shortest_path_distance);
-                if self.wf_improved {
-                    score *= usize_to_f64(reachable_minus_source) / usize_to_f64(n - 1);
-                }
-                scores[source_index] =
-                    (score * CLOSENESS_SCORE_SCALE).round() / CLOSENESS_SCORE_SCALE;
-            }
+impl ClosenessCentralityScorer {
+    #[cfg(feature = "parallel")]
+    /// Scores the nodes of the graph, running the breadth-first searches from
+    /// the different sources on the `rayon` thread pool.
+    ///
+    /// The scores are identical to the ones of
+    /// [`NodeScorer::score_nodes`] whatever the number of threads.
+    #[must_use]
+    pub fn par_score_nodes<G>(&self, graph: &G) -> Vec<f64>
+    where
+        G: UndirectedMonopartiteMonoplexGraph + Sync,
+    {
+        use rayon::prelude::*;
+
+        let n = graph.number_of_nodes().as_();
+        if n == 0 {
+            return Vec::new();
         }
+        debug_assert!(graph.node_ids().enumerate().all(|(i, node)| node.as_() == i));
+
+        let mut scores = vec![0.0; n];
+        scores.par_iter_mut().enumerate().for_each_init(
+            || (vec![usize::MAX; n], VecDeque::with_capacity(n)),
+            |(distance, queue), (source_index, score)| {
+                *score = self.source_score(graph, source_index, distance, queue);
+            },
+        );
 
         scores
     }
+
+    /// Returns the closeness of the source, using the provided buffers for
+    /// its breadth-first search.
+    fn source_score<G>(
+        self,
+        graph: &G,
+        source_index: usize,
+        distance: &mut [usize],
+        queue: &mut VecDeque<usize>,
+    ) -> f64
+    where
+        G: UndirectedMonopartiteMonoplexGraph,
+    {
+        let score = breadth_first_closeness(
+            source_index,
+            |node_index| neighbor_indices(graph, node_index),
+            distance,
+            queue,
+            self.wf_improved,
+        );
+        (score * CLOSENESS_SCORE_SCALE).round() / CLOSENESS_SCORE_SCALE
+    }
+}
+
+/// Returns the closeness of the source, following the provided successors of
+/// every node in a breadth-first search that uses the provided buffers.
+pub(crate) fn breadth_first_closeness<S, I>(
+    source_index: usize,
+    successors: S,
+    distance: &mut [usize],
+    queue: &mut VecDeque<usize>,
+    wf_improved: bool,
+) -> f64
+where
+    S: Fn(usize) -> I,
+    I: IntoIterator<Item = usize>,
+{
+    distance.fill(usize::MAX);
+    queue.clear();
+    distance[source_index] = 0;
+    queue.push_back(source_index);
+
+    let mut total_shortest_path_distance = 0usize;
+    let mut reachable_nodes = 1usize;
+
+    while let Some(node_index) = queue.pop_front() {
+        let node_distance = distance[node_index];
+
+        for neighbor_index in successors(node_index) {
+            if distance[neighbor_index] != usize::MAX {
+                continue;
+            }
+
+            let neighbor_distance = node_distance + 1;
+            distance[neighbor_index] = neighbor_distance;
+            total_shortest_path_distance += neighbor_distance;
+            reachable_nodes += 1;
+            queue.push_back(neighbor_index);
+        }
+    }
+
+    closeness(
+        reachable_nodes - 1,
+        usize_to_f64(total_shortest_path_distance),
+        distance.len(),
+        wf_improved,
+    )
+}
+
+/// Returns the closeness of a node reaching the provided number of other
+/// nodes, among the provided number of nodes, at the provided total distance.
+pub(crate) fn closeness(
+    reachable_others: usize,
+    total_shortest_path_distance: f64,
+    number_of_nodes: usize,
+    wf_improved: bool,
+) -> f64 {
+    if reachable_others == 0 || total_shortest_path_distance <= 0.0 {
+        return 0.0;
+    }
+    let mut score = usize_to_f64(reachable_others) / total_shortest_path_distance;
+    if wf_improved {
+        score *= usize_to_f64(reachable_others) / usize_to_f64(number_of_nodes - 1);
+    }
+    score
 }
@@ -62,33 +62,44 @@ where
 
         let mut all_distances = vec![None; order * order];
         let mut queue = VecDeque::with_capacity(order);
+        for (source_id, distances) in self.row_indices().zip(all_distances.chunks_mut(order)) {
+            breadth_first_distances(self, source_id, usize::MAX, distances, &mut queue);
+        }
 
-        for source_id in self.row_indices() {
-            let source = source_id.as_();
-            let source_offset = source * order;
-
-            let distances = &mut all_distances[source_offset..source_offset + order];
-            distances.fill(None);
-            distances[source] = Some(0);
+        VecMatrix2D::new(order, order, all_distances)
+    }
 
-            queue.clear();
-            queue.push_back(source_id);
+    #[cfg(feature = "parallel")]
+    /// Computes all-pairs shortest-path distances in the unweighted case,
+    /// running the searches from the different sources on the `rayon` thread
+    /// pool.
+    ///
+    /// The distances are identical to the ones of
+    /// [`PairwiseBFS::pairwise_bfs`] whatever the number of threads.
+    #[inline]
+    fn par_pairwise_bfs(&self) -> VecMatrix2D<Option<usize>>
+    where
+        Self: Sync,
+    {
+        use rayon::prelude::*;
 
-            while let Some(node_id) = queue.pop_front() {
-                let node = node_id.as_();
-                let distance =
-                    distances[node].expect("BFS queue only contains already-reached nodes");
+        use crate::traits::TryFromUsize;
 
-                for destination_id in self.sparse_row(node_id) {
-                    let destination = destination_id.as_();
-                    if distances[destination].is_none() {
-                        distances[destination] = Some(distance + 1);
-                        queue.push_back(destination_id);
-                    }
-                }
-            }
+        let order = self.order().as_();
+        if order == 0 {
+            return VecMatrix2D::new(0, 0, Vec::new());
         }
 
+        let mut all_distances = vec![None; order * order];
+        all_distances.par_chunks_mut(order).enumerate().for_each_init(
+            || VecDeque::with_capacity(order),
+            |queue, (source, distances)| {
+                let source_id = Self::RowIndex::try_from_usize(source)
+                    .unwrap_or_else(|_| unreachable!("Sources are rows of the matrix."));
+                breadth_first_distances(self, source_id, usize::MAX, distances, queue);
+            },
+        );
+
         VecMatrix2D::new(order, order, all_distances)
     }
 }
@@ -101,3 +112,38 @@ where
     M::ColumnIndex: AsPrimitive<usize>,
 {
 }
+
+/// Fills the distances from the source node to every node within the radius,
+/// through the provided queue.
+pub(super) fn breadth_first_distances<M>(
+    matrix: &M,
+    source_id: M::RowIndex,
+    radius: usize,
+    distances: &mut [Option<usize>],
+    queue: &mut VecDeque<M::RowIndex>,
+) where
+    M: SquareMatrix + SparseMatrix2D,
+    M::Index: AsPrimitive<usize>,
+    M::RowIndex: AsPrimitive<usize>,
+    M::ColumnIndex: AsPrimitive<usize>,
+{
+    distances[source_id.as_()] = Some(0);
+    queue.clear();
+    queue.push_back(source_id);
+
+    while let Some(node_id) = queue.pop_front() {
+        let node = node_id.as_();
+        let distance = distances[node].expect("BFS queue only contains already-reached nodes");
+        if distance == radius {
+            continue;
+        }
+
+        for destination_id in matrix.sparse_row(node_id) {
+            let destination = destination_id.as_();
+            if distances[destination].is_none() {
+                distances[destination] = Some(distance + 1);
+                queue.push_back(destination_id);
+            }
+        }
+    }
+}
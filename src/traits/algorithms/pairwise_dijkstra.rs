@@ -5,10 +5,13 @@
 //! and returns the resulting all-pairs shortest-path distances for the
 //! non-negative weighted case.
 use alloc::collections::BinaryHeap;
-use core::cmp::Ordering;
 
 use num_traits::{AsPrimitive, Zero};
 
+use super::{
+    dijkstra::{DijkstraError, DijkstraVisitor, QueueEntry, dijkstra_search},
+    metrics::Counter,
+};
 use crate::{
     impls::VecMatrix2D,
     traits::{Finite, Number, PositiveInteger, SparseValuedMatrix2D, TotalOrd},
@@ -55,49 +58,48 @@ pub enum PairwiseDijkstraError {
     },
 }
 
-#[derive(Debug, Clone, Copy)]
-struct QueueEntry<V, I> {
-    distance: V,
-    node_id: I,
-}
-
-impl<V, I> PartialEq for QueueEntry<V, I>
-where
-    V: TotalOrd,
-    I: PositiveInteger,
-{
-    #[inline]
-    fn eq(&self, other: &Self) -> bool {
-        self.distance.total_cmp(&other.distance).is_eq() && self.node_id == other.node_id
-    }
+#[cfg(feature = "metrics")]
+/// Counts of the operations performed by one run of
+/// [`PairwiseDijkstra::pairwise_dijkstra`], summed over all the sources.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DijkstraMetrics {
+    /// Number of nodes settled at their final distance.
+    pub settled_nodes: usize,
+    /// Number of heap entries skipped because a shorter distance to their
+    /// node was found after they were pushed.
+    pub stale_entries: usize,
+    /// Number of tentative distances lowered through an edge.
+    pub relaxations: usize,
 }
 
-impl<V, I> Eq for QueueEntry<V, I>
-where
-    V: TotalOrd,
-    I: PositiveInteger,
-{
+/// Counters of the operations performed by the Dijkstra searches.
+#[derive(Debug, Clone, Copy, Default)]
+struct DijkstraCounters {
+    /// Nodes settled at their final distance.
+    settled_nodes: Counter,
+    /// Heap entries skipped as stale.
+    stale_entries: Counter,
+    /// Tentative distances lowered through an edge.
+    relaxations: Counter,
 }
 
-impl<V, I> PartialOrd for QueueEntry<V, I>
-where
-    V: TotalOrd,
-    I: PositiveInteger,
-{
-    #[inline]
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+impl DijkstraCounters {
+    #[cfg(feature = "parallel")]
+    /// Adds the operations counted by the searches of other sources.
+    fn merge(&mut self, other: Self) {
+        self.settled_nodes.merge(other.settled_nodes);
+        self.stale_entries.merge(other.stale_entries);
+        self.relaxations.merge(other.relaxations);
     }
-}
 
-impl<V, I> Ord for QueueEntry<V, I>
-where
-    V: TotalOrd,
-    I: PositiveInteger,
-{
-    #[inline]
-    fn cmp(&self, other: &Self) -> Ordering {
-        other.distance.total_cmp(&self.distance).then_with(|| other.node_id.cmp(&self.node_id))
+    #[cfg(feature = "metrics")]
+    /// Returns the counts of the counted operations.
+    fn metrics(self) -> DijkstraMetrics {
+        DijkstraMetrics {
+            settled_nodes: self.settled_nodes.count(),
+            stale_entries: self.stale_entries.count(),
+            relaxations: self.relaxations.count(),
+        }
     }
 }
 
@@ -150,91 +152,198 @@ where
     /// becomes non-finite.
     #[inline]
     fn pairwise_dijkstra(&self) -> Result<VecMatrix2D<Option<Self::Value>>, PairwiseDijkstraError> {
-        let rows = self.number_of_rows().as_();
-        let columns = self.number_of_columns().as_();
+        pairwise_dijkstra_with_counters(self).map(|(distances, _)| distances)
+    }
 
-        if rows != columns {
-            return Err(PairwiseDijkstraError::NonSquareMatrix { rows, columns });
-        }
+    #[cfg(feature = "parallel")]
+    /// Computes all-pairs shortest-path distances in the non-negative weighted
+    /// case, running the searches from the different sources on the `rayon`
+    /// thread pool.
+    ///
+    /// The distances, and the error reported if any, are identical to the
+    /// ones of [`PairwiseDijkstra::pairwise_dijkstra`] whatever the number of
+    /// threads.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`PairwiseDijkstra::pairwise_dijkstra`].
+    #[inline]
+    fn par_pairwise_dijkstra(
+        &self,
+    ) -> Result<VecMatrix2D<Option<Self::Value>>, PairwiseDijkstraError>
+    where
+        Self: Sync,
+        Self::Value: Send + Sync,
+    {
+        par_pairwise_dijkstra_with_counters(self).map(|(distances, _)| distances)
+    }
 
-        let order = rows;
-        let mut all_distances = vec![None; order * order];
-        if order == 0 {
-            return Ok(VecMatrix2D::new(0, 0, all_distances));
-        }
+    #[cfg(feature = "metrics")]
+    /// Computes all-pairs shortest-path distances in the non-negative weighted
+    /// case, together with the counts of the operations performed.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`PairwiseDijkstra::pairwise_dijkstra`].
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    fn pairwise_dijkstra_with_metrics(
+        &self,
+    ) -> Result<(VecMatrix2D<Option<Self::Value>>, DijkstraMetrics), PairwiseDijkstraError> {
+        pairwise_dijkstra_with_counters(self)
+            .map(|(distances, counters)| (distances, counters.metrics()))
+    }
+}
 
-        let zero = Self::Value::zero();
-        let mut heap = BinaryHeap::new();
-
-        for source_id in self.row_indices() {
-            let source = source_id.as_();
-            let source_offset = source * order;
-            let distances = &mut all_distances[source_offset..source_offset + order];
-            distances.fill(None);
-            distances[source] = Some(zero);
-
-            heap.clear();
-            heap.push(QueueEntry { distance: zero, node_id: source_id });
-
-            while let Some(entry) = heap.pop() {
-                let node_id = entry.node_id;
-                let node = node_id.as_();
-                let best_distance =
-                    distances[node].expect("Dijkstra heap only contains already-reached nodes");
-                if entry.distance.total_cmp(&best_distance).is_gt() {
-                    continue;
-                }
+impl<M> PairwiseDijkstra for M
+where
+    M: SparseValuedMatrix2D + Sized,
+    M::Value: Number + Finite + TotalOrd,
+    M::RowIndex: PositiveInteger,
+    M::ColumnIndex: AsPrimitive<usize> + Into<M::RowIndex>,
+{
+}
 
-                for (destination_id, weight) in
-                    self.sparse_row(node_id).zip(self.sparse_row_values(node_id))
-                {
-                    let destination = destination_id.as_();
-                    if !weight.is_finite() {
-                        return Err(PairwiseDijkstraError::NonFiniteWeight {
-                            source_id: node,
-                            destination_id: destination,
-                        });
-                    }
-                    if weight < zero {
-                        return Err(PairwiseDijkstraError::NegativeWeight {
-                            source_id: node,
-                            destination_id: destination,
-                        });
-                    }
-
-                    let candidate = entry.distance + weight;
-                    if !candidate.is_finite() {
-                        return Err(PairwiseDijkstraError::NonFiniteDistance {
-                            source_id: source,
-                            destination_id: destination,
-                            via_id: node,
-                        });
-                    }
-
-                    let should_update = match distances[destination] {
-                        Some(current) => candidate.total_cmp(&current).is_lt(),
-                        None => true,
-                    };
-                    if should_update {
-                        distances[destination] = Some(candidate);
-                        heap.push(QueueEntry {
-                            distance: candidate,
-                            node_id: destination_id.into(),
-                        });
-                    }
-                }
-            }
-        }
+/// Checks that the matrix is square, returning its order.
+fn pairwise_order<M: PairwiseDijkstra>(matrix: &M) -> Result<usize, PairwiseDijkstraError>
+where
+    M::Value: Number + Finite + TotalOrd,
+    M::RowIndex: PositiveInteger,
+    M::ColumnIndex: AsPrimitive<usize> + Into<M::RowIndex>,
+{
+    let rows = matrix.number_of_rows().as_();
+    let columns = matrix.number_of_columns().as_();
+    if rows != columns {
+        return Err(PairwiseDijkstraError::NonSquareMatrix { rows, columns });
+    }
+    Ok(rows)
+}
 
-        Ok(VecMatrix2D::new(order, order, all_distances))
+/// Computes the distances from every source, returning them with the
+/// counters of the operations performed.
+#[allow(clippy::type_complexity)]
+fn pairwise_dijkstra_with_counters<M: PairwiseDijkstra>(
+    matrix: &M,
+) -> Result<(VecMatrix2D<Option<M::Value>>, DijkstraCounters), PairwiseDijkstraError>
+where
+    M::Value: Number + Finite + TotalOrd,
+    M::RowIndex: PositiveInteger,
+    M::ColumnIndex: AsPrimitive<usize> + Into<M::RowIndex>,
+{
+    let order = pairwise_order(matrix)?;
+    let mut all_distances = vec![None; order * order];
+    let mut counters = DijkstraCounters::default();
+    if order == 0 {
+        return Ok((VecMatrix2D::new(0, 0, all_distances), counters));
     }
+
+    let mut heap = BinaryHeap::new();
+    for (source_id, distances) in matrix.row_indices().zip(all_distances.chunks_mut(order)) {
+        dijkstra_distances(matrix, source_id, distances, &mut heap, &mut counters)?;
+    }
+
+    Ok((VecMatrix2D::new(order, order, all_distances), counters))
 }
 
-impl<M> PairwiseDijkstra for M
+/// Computes the distances from every source on the `rayon` thread pool,
+/// returning them with the counters of the operations performed.
+#[cfg(feature = "parallel")]
+#[allow(clippy::type_complexity)]
+fn par_pairwise_dijkstra_with_counters<M: PairwiseDijkstra + Sync>(
+    matrix: &M,
+) -> Result<(VecMatrix2D<Option<M::Value>>, DijkstraCounters), PairwiseDijkstraError>
 where
-    M: SparseValuedMatrix2D + Sized,
+    M::Value: Number + Finite + TotalOrd + Send + Sync,
+    M::RowIndex: PositiveInteger,
+    M::ColumnIndex: AsPrimitive<usize> + Into<M::RowIndex>,
+{
+    use alloc::vec::Vec;
+
+    use rayon::prelude::*;
+
+    use crate::traits::TryFromUsize;
+
+    let order = pairwise_order(matrix)?;
+    let mut all_distances = vec![None; order * order];
+    let mut counters = DijkstraCounters::default();
+    if order == 0 {
+        return Ok((VecMatrix2D::new(0, 0, all_distances), counters));
+    }
+
+    let outcomes: Vec<Result<DijkstraCounters, PairwiseDijkstraError>> = all_distances
+        .par_chunks_mut(order)
+        .enumerate()
+        .map_init(BinaryHeap::new, |heap, (source, distances)| {
+            let source_id = M::RowIndex::try_from_usize(source)
+                .unwrap_or_else(|_| unreachable!("Sources are rows of the matrix."));
+            let mut source_counters = DijkstraCounters::default();
+            dijkstra_distances(matrix, source_id, distances, heap, &mut source_counters)?;
+            Ok(source_counters)
+        })
+        .collect();
+    // The first error by source is the one the sequential searches report.
+    for outcome in outcomes {
+        counters.merge(outcome?);
+    }
+
+    Ok((VecMatrix2D::new(order, order, all_distances), counters))
+}
+
+impl<V: Zero, I> DijkstraVisitor<V, I> for DijkstraCounters {
+    type Error = DijkstraError;
+
+    #[inline]
+    fn settle(&mut self, _node_id: I, _distance: V) -> bool {
+        self.settled_nodes.increment();
+        false
+    }
+
+    #[inline]
+    fn skip(&mut self) {
+        self.stale_entries.increment();
+    }
+
+    #[inline]
+    fn improve(&mut self, _destination_id: I, _node_id: I) {
+        self.relaxations.increment();
+    }
+}
+
+/// Fills the distances from the source node to every node, through the
+/// provided heap.
+fn dijkstra_distances<M>(
+    matrix: &M,
+    source_id: M::RowIndex,
+    distances: &mut [Option<M::Value>],
+    heap: &mut BinaryHeap<QueueEntry<M::Value, M::RowIndex>>,
+    counters: &mut DijkstraCounters,
+) -> Result<(), PairwiseDijkstraError>
+where
+    M: SparseValuedMatrix2D,
     M::Value: Number + Finite + TotalOrd,
     M::RowIndex: PositiveInteger,
     M::ColumnIndex: AsPrimitive<usize> + Into<M::RowIndex>,
 {
+    dijkstra_search(matrix, source_id, distances, heap, counters).map_err(|error| {
+        match error {
+            DijkstraError::NonFiniteWeight { source_id, destination_id } => {
+                PairwiseDijkstraError::NonFiniteWeight { source_id, destination_id }
+            }
+            DijkstraError::NegativeWeight { source_id, destination_id } => {
+                PairwiseDijkstraError::NegativeWeight { source_id, destination_id }
+            }
+            DijkstraError::NonFiniteDistance { destination_id, via_id } => {
+                PairwiseDijkstraError::NonFiniteDistance {
+                    source_id: source_id.as_(),
+                    destination_id,
+                    via_id,
+                }
+            }
+            DijkstraError::NonSquareMatrix { .. }
+            | DijkstraError::UnknownSource { .. }
+            | DijkstraError::ZeroWeight { .. } => {
+                unreachable!("The search does not check the shape of the matrix or zero weights.")
+            }
+        }
+    })
 }
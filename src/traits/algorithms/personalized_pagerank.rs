@@ -0,0 +1,332 @@
+//! Submodule providing the `PersonalizedPageRank` trait, which approximates
+//! the personalized PageRank vector of a source node with local pushes, and
+//! the `PersonalizedPageRankSimilarity` built upon it.
+use alloc::{collections::VecDeque, vec::Vec};
+
+use num_traits::AsPrimitive;
+
+use crate::traits::{
+    ScalarSimilarity, TopKSimilarity, UndirectedMonopartiteMonoplexGraph,
+    similarity::top_k_by_score,
+};
+
+/// Trait providing the approximate personalized PageRank of a source node.
+///
+/// The personalized PageRank of `v` from the source `s` is the probability
+/// that a random walk starting from `s`, which at every step moves to a
+/// uniformly random neighbour with probability `alpha` and otherwise restarts
+/// from `s`, is found at `v`.
+///
+/// # Reference
+///
+/// Andersen, R., Chung, F., & Lang, K. (2006). [Local Graph Partitioning using PageRank Vectors](https://doi.org/10.1109/FOCS.2006.44).
+pub trait PersonalizedPageRank: UndirectedMonopartiteMonoplexGraph
+where
+    Self::NodeId: AsPrimitive<usize>,
+{
+    /// Returns the non-zero entries of the approximate personalized PageRank
+    /// vector of `source`, sorted by node.
+    ///
+    /// The residual mass of a node is pushed to its neighbours whenever it is
+    /// at least `epsilon` times its degree, so that the approximation never
+    /// overestimates the exact score of a node `v` and underestimates it by
+    /// at most `epsilon · deg(v)`. Nodes without neighbours keep all the mass
+    /// they receive.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The node from which the random walks restart.
+    /// * `alpha` - The probability of moving to a neighbour, in `[0, 1)`.
+    /// * `epsilon` - The residual tolerance per unit of degree, positive.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alpha` is not in `[0, 1)` or `epsilon` is not positive.
+    ///
+    /// # Complexity
+    ///
+    /// O(V) time to allocate the dense vectors, plus O(1 / (epsilon · (1 -
+    /// alpha))) time for the pushes, independently of the size of the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{
+    ///     impls::{SortedVec, SymmetricCSR2D},
+    ///     prelude::*,
+    ///     traits::{EdgesBuilder, PersonalizedPageRank, VocabularyBuilder},
+    /// };
+    ///
+    /// let edges: Vec<(usize, usize)> = vec![(0, 1), (1, 2), (3, 4)];
+    /// let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+    ///     .expected_number_of_symbols(5)
+    ///     .symbols((0..5).enumerate())
+    ///     .build()
+    ///     .unwrap();
+    /// let edges: SymmetricCSR2D<_> = UndiEdgesBuilder::default()
+    ///     .expected_number_of_edges(edges.len())
+    ///     .expected_shape(nodes.len())
+    ///     .edges(edges.into_iter())
+    ///     .build()
+    ///     .unwrap();
+    /// let graph: UndiGraph<usize> = UndiGraph::from((nodes, edges));
+    ///
+    /// let scores = graph.approximate_personalized_pagerank(0, 0.85, 1e-6);
+    /// // The walks never leave the component of the source.
+    /// assert_eq!(scores.iter().map(|&(node, _)| node).collect::<Vec<_>>(), vec![0, 1, 2]);
+    /// assert!((scores.iter().map(|&(_, score)| score).sum::<f64>() - 1.0).abs() < 1e-5);
+    /// ```
+    #[allow(clippy::cast_precision_loss)]
+    #[inline]
+    fn approximate_personalized_pagerank(
+        &self,
+        source: Self::NodeId,
+        alpha: f64,
+        epsilon: f64,
+    ) -> Vec<(Self::NodeId, f64)> {
+        assert!((0.0..1.0).contains(&alpha), "alpha must be in [0, 1), got {alpha}");
+        assert!(epsilon > 0.0, "epsilon must be positive, got {epsilon}");
+
+        let number_of_nodes: usize = self.number_of_nodes().as_();
+        let mut scores = vec![0.0; number_of_nodes];
+        let mut residuals = vec![0.0; number_of_nodes];
+        let mut is_queued = vec![false; number_of_nodes];
+        let mut touched: Vec<Self::NodeId> = vec![source];
+        let mut queue: VecDeque<Self::NodeId> = VecDeque::from([source]);
+        residuals[source.as_()] = 1.0;
+        is_queued[source.as_()] = true;
+
+        while let Some(node) = queue.pop_front() {
+            is_queued[node.as_()] = false;
+            let residual = core::mem::take(&mut residuals[node.as_()]);
+            let degree: usize = self.degree(node).as_();
+            if degree == 0 {
+                scores[node.as_()] += residual;
+                continue;
+            }
+            scores[node.as_()] += (1.0 - alpha) * residual;
+            let share = alpha * residual / degree as f64;
+            for neighbor in UndirectedMonopartiteMonoplexGraph::neighbors(self, node) {
+                let index = neighbor.as_();
+                if residuals[index] == 0.0 && scores[index] == 0.0 {
+                    touched.push(neighbor);
+                }
+                residuals[index] += share;
+                let neighbor_degree: usize = self.degree(neighbor).as_();
+                if !is_queued[index] && residuals[index] >= epsilon * neighbor_degree as f64 {
+                    is_queued[index] = true;
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        touched.sort_unstable();
+        touched.dedup();
+        touched
+            .into_iter()
+            .filter(|node| scores[node.as_()] > 0.0)
+            .map(|node| (node, scores[node.as_()]))
+            .collect()
+    }
+}
+
+impl<G> PersonalizedPageRank for G
+where
+    G: UndirectedMonopartiteMonoplexGraph,
+    G::NodeId: AsPrimitive<usize>,
+{
+}
+
+/// Node similarity given by the approximate personalized PageRank.
+///
+/// The similarity of `u` to `v` is the personalized PageRank of `v` from the
+/// source `u`, as computed by
+/// [`PersonalizedPageRank::approximate_personalized_pagerank`]. As this score
+/// is not symmetric, the similarity can optionally be symmetrized as the mean
+/// of the scores in both directions.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{
+///     impls::{SortedVec, SymmetricCSR2D},
+///     prelude::*,
+///     traits::{
+///         EdgesBuilder, PersonalizedPageRankSimilarity, ScalarSimilarity, VocabularyBuilder,
+///     },
+/// };
+///
+/// let edges: Vec<(usize, usize)> = vec![(0, 1), (0, 2), (0, 3), (3, 4)];
+/// let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+///     .expected_number_of_symbols(5)
+///     .symbols((0..5).enumerate())
+///     .build()
+///     .unwrap();
+/// let edges: SymmetricCSR2D<_> = UndiEdgesBuilder::default()
+///     .expected_number_of_edges(edges.len())
+///     .expected_shape(nodes.len())
+///     .edges(edges.into_iter())
+///     .build()
+///     .unwrap();
+/// let graph: UndiGraph<usize> = UndiGraph::from((nodes, edges));
+///
+/// let similarity = PersonalizedPageRankSimilarity::new(&graph);
+/// // The hub receives more of the walks of a leaf than the other way round.
+/// assert!(similarity.similarity(&1, &0) > similarity.similarity(&0, &1));
+/// // Closer nodes are more similar.
+/// assert!(similarity.similarity(&1, &3) > similarity.similarity(&1, &4));
+///
+/// let symmetric = similarity.symmetric(true);
+/// assert!((symmetric.similarity(&1, &0) - symmetric.similarity(&0, &1)).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct PersonalizedPageRankSimilarity<'graph, G: ?Sized> {
+    /// The graph whose nodes are compared.
+    graph: &'graph G,
+    /// The probability of moving to a neighbour.
+    alpha: f64,
+    /// The residual tolerance per unit of degree.
+    epsilon: f64,
+    /// Whether the scores in both directions are averaged.
+    symmetric: bool,
+}
+
+impl<'graph, G> PersonalizedPageRankSimilarity<'graph, G>
+where
+    G: UndirectedMonopartiteMonoplexGraph + ?Sized,
+{
+    /// Creates the personalized PageRank similarity between the nodes of the
+    /// graph, with `alpha = 0.85`, `epsilon = 1e-6` and no symmetrization.
+    #[inline]
+    #[must_use]
+    pub fn new(graph: &'graph G) -> Self {
+        Self { graph, alpha: 0.85, epsilon: 1.0e-6, symmetric: false }
+    }
+
+    /// Sets the probability of moving to a neighbour.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alpha` is not in `[0, 1)`.
+    #[inline]
+    #[must_use]
+    pub fn alpha(mut self, alpha: f64) -> Self {
+        assert!((0.0..1.0).contains(&alpha), "alpha must be in [0, 1), got {alpha}");
+        self.alpha = alpha;
+        self
+    }
+
+    /// Sets the residual tolerance per unit of degree.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `epsilon` is not positive.
+    #[inline]
+    #[must_use]
+    pub fn epsilon(mut self, epsilon: f64) -> Self {
+        assert!(epsilon > 0.0, "epsilon must be positive, got {epsilon}");
+        self.epsilon = epsilon;
+        self
+    }
+
+    /// Sets whether the similarity is the mean of the scores in both
+    /// directions.
+    #[inline]
+    #[must_use]
+    pub fn symmetric(mut self, symmetric: bool) -> Self {
+        self.symmetric = symmetric;
+        self
+    }
+}
+
+impl<G> PersonalizedPageRankSimilarity<'_, G>
+where
+    G: UndirectedMonopartiteMonoplexGraph,
+    G::NodeId: AsPrimitive<usize>,
+{
+    /// Returns the approximate personalized PageRank of `destination` from
+    /// `source`.
+    fn directed_score(&self, source: G::NodeId, destination: G::NodeId) -> f64 {
+        let scores = self.graph.approximate_personalized_pagerank(source, self.alpha, self.epsilon);
+        scores
+            .binary_search_by(|(node, _)| node.cmp(&destination))
+            .map_or(0.0, |position| scores[position].1)
+    }
+}
+
+impl<G> ScalarSimilarity<G::NodeId, G::NodeId> for PersonalizedPageRankSimilarity<'_, G>
+where
+    G: UndirectedMonopartiteMonoplexGraph,
+    G::NodeId: AsPrimitive<usize>,
+{
+    type Similarity = f64;
+
+    #[inline]
+    fn similarity(&self, left: &G::NodeId, right: &G::NodeId) -> Self::Similarity {
+        let forward = self.directed_score(*left, *right);
+        if self.symmetric {
+            f64::midpoint(forward, self.directed_score(*right, *left))
+        } else {
+            forward
+        }
+    }
+}
+
+impl<G> TopKSimilarity<G::NodeId> for PersonalizedPageRankSimilarity<'_, G>
+where
+    G: UndirectedMonopartiteMonoplexGraph,
+    G::NodeId: AsPrimitive<usize>,
+{
+    /// Returns up to `k` nodes other than `item` with the highest similarity,
+    /// sorted by decreasing similarity and then by increasing node.
+    ///
+    /// The candidates are the nodes reached by the push from `item`, whose
+    /// scores are read directly from the pushed vector. When symmetrized, the
+    /// push is also run from every candidate to score it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{
+    ///     impls::{SortedVec, SymmetricCSR2D},
+    ///     prelude::*,
+    ///     traits::{EdgesBuilder, PersonalizedPageRankSimilarity, TopKSimilarity, VocabularyBuilder},
+    /// };
+    ///
+    /// let edges: Vec<(usize, usize)> = vec![(0, 1), (0, 2), (0, 3), (3, 4), (5, 6)];
+    /// let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+    ///     .expected_number_of_symbols(7)
+    ///     .symbols((0..7).enumerate())
+    ///     .build()
+    ///     .unwrap();
+    /// let edges: SymmetricCSR2D<_> = UndiEdgesBuilder::default()
+    ///     .expected_number_of_edges(edges.len())
+    ///     .expected_shape(nodes.len())
+    ///     .edges(edges.into_iter())
+    ///     .build()
+    ///     .unwrap();
+    /// let graph: UndiGraph<usize> = UndiGraph::from((nodes, edges));
+    ///
+    /// let similarity = PersonalizedPageRankSimilarity::new(&graph);
+    /// let top = similarity.top_k_similar(&1, 10);
+    /// // Nodes of the other component are never reached.
+    /// assert_eq!(top.iter().map(|&(node, _)| node).collect::<Vec<_>>(), vec![0, 3, 2, 4]);
+    /// ```
+    #[inline]
+    fn top_k_similar(&self, item: &G::NodeId, k: usize) -> Vec<(G::NodeId, f64)> {
+        let scored = self
+            .graph
+            .approximate_personalized_pagerank(*item, self.alpha, self.epsilon)
+            .into_iter()
+            .filter(|(node, _)| node != item)
+            .map(|(node, score)| {
+                if self.symmetric {
+                    (node, f64::midpoint(score, self.directed_score(node, *item)))
+                } else {
+                    (node, score)
+                }
+            })
+            .collect();
+        top_k_by_score(scored, k)
+    }
+}
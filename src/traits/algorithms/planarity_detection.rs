@@ -3,10 +3,12 @@
 //! The current implementation builds a local simple-graph view, performs DFS
 //! preprocessing, and then runs the crate's internal edge-addition embedding
 //! engine. The public contract is intentionally limited to simple undirected
-//! graphs, so self-loops and parallel edges are rejected.
+//! graphs, so self-loops and parallel edges are rejected. Planar graphs are
+//! certified by the rotation system of the completed embedding, and
+//! non-planar ones by a Kuratowski subgraph.
 #![cfg_attr(test, allow(clippy::pedantic))]
 
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 
 use num_traits::AsPrimitive;
 
@@ -119,10 +121,232 @@ pub trait PlanarityDetection: UndirectedMonopartiteMonoplexGraph {
     {
         Ok(is_planar_simple_undirected_graph(self)?)
     }
+
+    /// Returns a certificate of the planarity of the graph: a combinatorial
+    /// embedding if the graph is planar, or a Kuratowski subgraph otherwise.
+    ///
+    /// The embedding is the one completed by the edge-addition engine. The
+    /// Kuratowski subgraph is extracted by deleting, one at a time, every
+    /// edge whose removal leaves the graph non-planar: the remaining edges
+    /// form a minimal non-planar subgraph, which by Kuratowski's theorem is
+    /// a subdivision of `K_5` or `K_{3,3}`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`PlanarityDetection::is_planar`].
+    ///
+    /// # Complexity
+    ///
+    /// O(V + E) time for planar graphs, and O(E · (V + E)) time for
+    /// non-planar ones.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geometric_traits::{
+    ///     impls::{CSR2D, SortedVec, SymmetricCSR2D},
+    ///     prelude::*,
+    ///     traits::{
+    ///         EdgesBuilder, KuratowskiKind, PlanarityCertificate, PlanarityDetection,
+    ///         VocabularyBuilder,
+    ///     },
+    /// };
+    ///
+    /// fn build_undigraph(node_count: usize, edges: &[(usize, usize)]) -> UndiGraph<usize> {
+    ///     let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+    ///         .expected_number_of_symbols(node_count)
+    ///         .symbols((0..node_count).enumerate())
+    ///         .build()
+    ///         .unwrap();
+    ///     let matrix: SymmetricCSR2D<CSR2D<usize, usize, usize>> = UndiEdgesBuilder::default()
+    ///         .expected_number_of_edges(edges.len())
+    ///         .expected_shape(node_count)
+    ///         .edges(edges.iter().copied())
+    ///         .build()
+    ///         .unwrap();
+    ///     UndiGraph::from((nodes, matrix))
+    /// }
+    ///
+    /// // A square with one diagonal has three faces.
+    /// let planar = build_undigraph(4, &[(0, 1), (0, 2), (0, 3), (1, 2), (2, 3)]);
+    /// let PlanarityCertificate::Planar(embedding) = planar.planarity_certificate()? else {
+    ///     panic!("the graph is planar");
+    /// };
+    /// assert_eq!(embedding.rotation(0).len(), 3);
+    /// assert_eq!(embedding.faces().len(), 3);
+    ///
+    /// // A K_{3,3} with a pendant node, which is not part of the witness.
+    /// let nonplanar = build_undigraph(
+    ///     7,
+    ///     &[(0, 3), (0, 4), (0, 5), (1, 3), (1, 4), (1, 5), (2, 3), (2, 4), (2, 5), (5, 6)],
+    /// );
+    /// let PlanarityCertificate::NonPlanar(witness) = nonplanar.planarity_certificate()? else {
+    ///     panic!("the graph is not planar");
+    /// };
+    /// assert_eq!(witness.kind(), KuratowskiKind::K33);
+    /// assert_eq!(witness.edges().len(), 9);
+    /// assert_eq!(witness.branch_nodes(), vec![0, 1, 2, 3, 4, 5]);
+    /// # Ok::<(), geometric_traits::errors::MonopartiteError<UndiGraph<usize>>>(())
+    /// ```
+    #[inline]
+    fn planarity_certificate(
+        &self,
+    ) -> Result<PlanarityCertificate, crate::errors::MonopartiteError<Self>>
+    where
+        Self: Sized,
+        Self::NodeId: AsPrimitive<usize>,
+    {
+        Ok(planarity_certificate_simple_undirected_graph(self)?)
+    }
 }
 
 impl<G: ?Sized + UndirectedMonopartiteMonoplexGraph> PlanarityDetection for G {}
 
+/// Certificate of the planarity of a graph, as returned by
+/// [`PlanarityDetection::planarity_certificate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlanarityCertificate {
+    /// The graph is planar, with the provided embedding.
+    Planar(PlanarEmbedding),
+    /// The graph is not planar, and contains the provided Kuratowski
+    /// subgraph.
+    NonPlanar(KuratowskiSubgraph),
+}
+
+/// Combinatorial embedding of a planar graph, given by the clockwise order
+/// of the neighbours around every node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlanarEmbedding {
+    /// The neighbours of every node, in clockwise order.
+    rotations: Vec<Vec<usize>>,
+}
+
+impl PlanarEmbedding {
+    /// Returns the number of nodes of the embedded graph.
+    #[inline]
+    #[must_use]
+    pub fn number_of_nodes(&self) -> usize {
+        self.rotations.len()
+    }
+
+    /// Returns the neighbours of the node in clockwise order, starting from
+    /// an arbitrary one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node is not a node of the graph.
+    #[inline]
+    #[must_use]
+    pub fn rotation(&self, node: usize) -> &[usize] {
+        &self.rotations[node]
+    }
+
+    /// Returns the faces of the embedding, each as the cyclic sequence of the
+    /// nodes met along its boundary.
+    ///
+    /// Every edge is walked once in each direction, so that a connected graph
+    /// with at least one edge has `E - V + 2` faces, and nodes or edges met
+    /// twice along a boundary appear twice in its face. Isolated nodes belong
+    /// to no face.
+    ///
+    /// # Complexity
+    ///
+    /// O(V + E log Δ) time and O(V + E) space, where Δ is the largest degree.
+    #[must_use]
+    pub fn faces(&self) -> Vec<Vec<usize>> {
+        // The position of every neighbour in the rotation of a node.
+        let positions: Vec<Vec<(usize, usize)>> = self
+            .rotations
+            .iter()
+            .map(|rotation| {
+                let mut positions: Vec<(usize, usize)> =
+                    rotation.iter().enumerate().map(|(position, &node)| (node, position)).collect();
+                positions.sort_unstable();
+                positions
+            })
+            .collect();
+        let position = |node: usize, neighbor: usize| {
+            let index = positions[node]
+                .binary_search_by_key(&neighbor, |&(neighbor, _)| neighbor)
+                .unwrap_or_else(|_| unreachable!("The rotations of both endpoints list an edge."));
+            positions[node][index].1
+        };
+
+        let mut walked: Vec<Vec<bool>> =
+            self.rotations.iter().map(|rotation| vec![false; rotation.len()]).collect();
+        let mut faces = Vec::new();
+        for (start, rotation) in self.rotations.iter().enumerate() {
+            for start_position in 0..rotation.len() {
+                if walked[start][start_position] {
+                    continue;
+                }
+                let mut face = Vec::new();
+                let (mut node, mut node_position) = (start, start_position);
+                while !walked[node][node_position] {
+                    walked[node][node_position] = true;
+                    face.push(node);
+                    let next = self.rotations[node][node_position];
+                    let rotation = &self.rotations[next];
+                    node_position = (position(next, node) + 1) % rotation.len();
+                    node = next;
+                }
+                faces.push(face);
+            }
+        }
+        faces
+    }
+}
+
+/// Kind of a Kuratowski subgraph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KuratowskiKind {
+    /// A subdivision of the complete graph on five nodes.
+    K5,
+    /// A subdivision of the complete bipartite graph on three and three
+    /// nodes.
+    K33,
+}
+
+/// Subdivision of `K_5` or `K_{3,3}` contained in a non-planar graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KuratowskiSubgraph {
+    /// The kind of the subdivided graph.
+    kind: KuratowskiKind,
+    /// The edges of the subdivision, sorted, with the smaller endpoint first.
+    edges: Vec<(usize, usize)>,
+}
+
+impl KuratowskiSubgraph {
+    /// Returns whether the subgraph subdivides `K_5` or `K_{3,3}`.
+    #[inline]
+    #[must_use]
+    pub fn kind(&self) -> KuratowskiKind {
+        self.kind
+    }
+
+    /// Returns the edges of the subdivision, sorted, with the smaller endpoint
+    /// first.
+    #[inline]
+    #[must_use]
+    pub fn edges(&self) -> &[(usize, usize)] {
+        &self.edges
+    }
+
+    /// Returns the sorted nodes of the subdivided graph, the others being the
+    /// inner nodes of the paths subdividing its edges.
+    #[must_use]
+    pub fn branch_nodes(&self) -> Vec<usize> {
+        let mut endpoints: Vec<usize> =
+            self.edges.iter().flat_map(|&(left, right)| [left, right]).collect();
+        endpoints.sort_unstable();
+        endpoints
+            .chunk_by(|left, right| left == right)
+            .filter(|occurrences| occurrences.len() >= 3)
+            .map(|occurrences| occurrences[0])
+            .collect()
+    }
+}
+
 pub(crate) fn is_planar_simple_undirected_graph<G>(graph: &G) -> Result<bool, PlanarityError>
 where
     G: UndirectedMonopartiteMonoplexGraph,
@@ -133,6 +357,52 @@ where
     Ok(run_planarity_engine(&preprocessing))
 }
 
+pub(crate) fn planarity_certificate_simple_undirected_graph<G>(
+    graph: &G,
+) -> Result<PlanarityCertificate, PlanarityError>
+where
+    G: UndirectedMonopartiteMonoplexGraph,
+    G::NodeId: AsPrimitive<usize>,
+{
+    let simple_graph = preprocessing::LocalSimpleGraph::try_from_undirected_graph(graph)?;
+    let preprocessing = simple_graph.into_preprocessing();
+    if let EmbeddingRunOutcome::Embedded(embedding) =
+        run_embedding_engine(&preprocessing, EmbeddingRunMode::Planarity)
+    {
+        return Ok(PlanarityCertificate::Planar(PlanarEmbedding {
+            rotations: embedding.into_rotation_system(),
+        }));
+    }
+
+    // The arcs come in pairs, the first one leading to the larger endpoint.
+    let node_count = preprocessing.vertices.len();
+    let mut edges: Vec<[usize; 2]> =
+        preprocessing.arcs.iter().step_by(2).map(|arc| [arc.source, arc.target]).collect();
+    let mut index = 0;
+    while index < edges.len() {
+        let mut candidate = edges.clone();
+        candidate.remove(index);
+        let candidate_preprocessing =
+            preprocessing::LocalSimpleGraph::from_edges(node_count, &candidate)
+                .unwrap_or_else(|_| unreachable!("A subgraph of a simple graph is simple."))
+                .into_preprocessing();
+        if run_planarity_engine(&candidate_preprocessing) {
+            index += 1;
+        } else {
+            edges = candidate;
+        }
+    }
+
+    let mut edges: Vec<(usize, usize)> =
+        edges.into_iter().map(|[left, right]| (left, right)).collect();
+    edges.sort_unstable();
+    let mut witness = KuratowskiSubgraph { kind: KuratowskiKind::K33, edges };
+    if witness.branch_nodes().len() == 5 {
+        witness.kind = KuratowskiKind::K5;
+    }
+    Ok(PlanarityCertificate::NonPlanar(witness))
+}
+
 pub(crate) fn is_outerplanar_simple_undirected_graph<G>(graph: &G) -> Result<bool, PlanarityError>
 where
     G: UndirectedMonopartiteMonoplexGraph,
@@ -5771,6 +6041,43 @@ pub(crate) mod embedding {
 
             visited_primaries.into_iter().all(core::convert::identity)
         }
+
+        /// Returns the clockwise neighbours of every original vertex in the
+        /// completed embedding.
+        ///
+        /// Every bicomp is first oriented consistently from its root copy,
+        /// and the rotation of a vertex is then the concatenation of the arc
+        /// lists of its primary slot and of the root copies still standing
+        /// for it, each bicomp keeping a contiguous range of the rotation.
+        pub(crate) fn into_rotation_system(mut self) -> Vec<Vec<usize>> {
+            self.orient_embedding_from_active_bicomps(false);
+
+            let original_vertex = |slot: usize| {
+                let primary_slot = match self.slots[slot].kind {
+                    EmbeddingSlotKind::Primary { .. } => slot,
+                    EmbeddingSlotKind::RootCopy { parent_primary_slot, .. } => parent_primary_slot,
+                };
+                match self.slots[primary_slot].kind {
+                    EmbeddingSlotKind::Primary { original_vertex } => original_vertex,
+                    EmbeddingSlotKind::RootCopy { .. } => {
+                        panic!("root copies must stand for primary slots")
+                    }
+                }
+            };
+
+            let mut rotations = vec![Vec::new(); self.primary_slot_by_original_vertex.len()];
+            for slot in 0..self.slots.len() {
+                let vertex = original_vertex(slot);
+                let mut current_arc = self.slots[slot].first_arc;
+                while let Some(arc) = current_arc {
+                    current_arc = self.arcs[arc].next;
+                    if self.arcs[arc].embedded {
+                        rotations[vertex].push(original_vertex(self.arcs[arc].target_slot));
+                    }
+                }
+            }
+            rotations
+        }
     }
 
     #[cfg(test)]
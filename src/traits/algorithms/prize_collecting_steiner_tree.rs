@@ -0,0 +1,499 @@
+//! Submodule providing the `PrizeCollectingSteinerTree` trait, selecting a
+//! connected subgraph of a weighted undirected graph whose node prizes
+//! outweigh its edge costs.
+//!
+//! The heuristic follows the unrooted Goemans-Williamson primal-dual growth
+//! of Johnson, Minkoff and Phillips. Every node starts as an active cluster
+//! whose budget is its prize. Active clusters grow moats around themselves at
+//! unit rate, spending their budget: an edge whose cost is covered by the
+//! moats around its endpoints merges their clusters and enters the forest,
+//! and a cluster whose budget runs out stops growing. Each tree of the
+//! resulting forest is then strongly pruned, removing every subtree whose
+//! prizes do not pay for the edges reaching it, around the best possible
+//! root, and the tree of largest net worth is returned.
+use alloc::{vec, vec::Vec};
+
+use num_traits::{AsPrimitive, ToPrimitive};
+
+use crate::{
+    impls::DisjointSet,
+    traits::{PositiveInteger, SparseValuedMatrix2D, TryFromUsize},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+/// Errors that can occur while computing a prize-collecting Steiner tree.
+pub enum PrizeCollectingSteinerTreeError {
+    /// The input matrix is not square.
+    #[error("The matrix must be square, but has {rows} rows and {columns} columns.")]
+    NonSquareMatrix {
+        /// Number of rows.
+        rows: usize,
+        /// Number of columns.
+        columns: usize,
+    },
+    /// The number of prizes differs from the number of nodes.
+    #[error("Expected {expected} prizes, one per node, but found {found}.")]
+    PrizesLengthMismatch {
+        /// Number of nodes of the graph.
+        expected: usize,
+        /// Number of provided prizes.
+        found: usize,
+    },
+    /// A prize is not finite or is negative.
+    #[error("The prize of node {node_id} must be finite and non-negative.")]
+    InvalidPrize {
+        /// Node identifier.
+        node_id: usize,
+    },
+    /// A cost cannot be represented as `f64`.
+    #[error("The cost on ({source_id}, {destination_id}) cannot be represented as f64.")]
+    UnrepresentableWeight {
+        /// Source node identifier.
+        source_id: usize,
+        /// Destination node identifier.
+        destination_id: usize,
+    },
+    /// A cost is not finite.
+    #[error("Found a non-finite cost on ({source_id}, {destination_id}).")]
+    NonFiniteWeight {
+        /// Source node identifier.
+        source_id: usize,
+        /// Destination node identifier.
+        destination_id: usize,
+    },
+    /// A cost is negative.
+    #[error("Found a negative cost on ({source_id}, {destination_id}).")]
+    NegativeWeight {
+        /// Source node identifier.
+        source_id: usize,
+        /// Destination node identifier.
+        destination_id: usize,
+    },
+    /// The matrix is not symmetric.
+    #[error(
+        "The adjacency matrix is not symmetric: entry ({source_id}, {destination_id}) has no matching reverse entry."
+    )]
+    NonSymmetricWeight {
+        /// Source node identifier.
+        source_id: usize,
+        /// Destination node identifier.
+        destination_id: usize,
+    },
+}
+
+/// Connected subgraph selected by
+/// [`PrizeCollectingSteinerTree::prize_collecting_steiner_tree`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrizeCollectingSubgraph<I> {
+    /// The sorted selected nodes.
+    nodes: Vec<I>,
+    /// The edges of the tree spanning the nodes, each with its smaller
+    /// endpoint first, sorted.
+    edges: Vec<(I, I, f64)>,
+    /// The sum of the prizes of the nodes.
+    prize: f64,
+    /// The sum of the costs of the edges.
+    cost: f64,
+}
+
+impl<I: PositiveInteger> PrizeCollectingSubgraph<I> {
+    /// Returns the sorted selected nodes, empty when no node is worth
+    /// selecting.
+    #[inline]
+    #[must_use]
+    pub fn nodes(&self) -> &[I] {
+        &self.nodes
+    }
+
+    /// Returns the edges of the tree spanning the selected nodes, each as
+    /// `(u, v, cost)` with `u < v`, sorted by endpoints.
+    #[inline]
+    #[must_use]
+    pub fn edges(&self) -> &[(I, I, f64)] {
+        &self.edges
+    }
+
+    /// Returns the sum of the prizes of the selected nodes.
+    #[inline]
+    #[must_use]
+    pub fn prize(&self) -> f64 {
+        self.prize
+    }
+
+    /// Returns the sum of the costs of the selected edges.
+    #[inline]
+    #[must_use]
+    pub fn cost(&self) -> f64 {
+        self.cost
+    }
+
+    /// Returns the net worth of the subgraph, its prize minus its cost.
+    #[inline]
+    #[must_use]
+    pub fn net_worth(&self) -> f64 {
+        self.prize - self.cost
+    }
+}
+
+/// Trait providing a heuristic prize-collecting Steiner tree, or
+/// maximum-weight connected subgraph, of the weighted undirected graph
+/// represented by a square and symmetric sparse matrix of finite and
+/// non-negative edge costs, given finite and non-negative node prizes.
+///
+/// # Reference
+///
+/// Johnson, D. S., Minkoff, M., & Phillips, S. (2000). [The prize collecting Steiner tree problem: theory and practice](https://dl.acm.org/doi/10.5555/338219.338637).
+/// *Proceedings of the 11th Annual ACM-SIAM Symposium on Discrete Algorithms*,
+/// 760-769.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{
+///     impls::{SymmetricCSR2D, ValuedCSR2D},
+///     prelude::*,
+///     traits::PrizeCollectingSteinerTree,
+/// };
+///
+/// // The prizes of 0 and 2 pay for the path through the free node 1, while
+/// // the expensive edge to 3 is not worth its prize.
+/// let graph: SymmetricCSR2D<ValuedCSR2D<usize, usize, usize, f64>> =
+///     SymmetricCSR2D::from_sorted_upper_triangular_entries(
+///         4,
+///         vec![(0, 1, 1.0), (1, 2, 1.0), (2, 3, 5.0)],
+///     )
+///     .unwrap();
+///
+/// let subgraph = graph.prize_collecting_steiner_tree(&[3.0, 0.0, 3.0, 1.0]).unwrap();
+/// assert_eq!(subgraph.nodes(), &[0, 1, 2]);
+/// assert_eq!(subgraph.edges(), &[(0, 1, 1.0), (1, 2, 1.0)]);
+/// assert_eq!(subgraph.net_worth(), 4.0);
+/// ```
+pub trait PrizeCollectingSteinerTree: SparseValuedMatrix2D + Sized
+where
+    Self::RowIndex: PositiveInteger,
+    Self::ColumnIndex: AsPrimitive<usize>,
+    Self::Value: ToPrimitive,
+{
+    /// Returns a connected subgraph, spanned by a tree, of large net worth:
+    /// the sum of the prizes of its nodes minus the sum of the costs of its
+    /// edges.
+    ///
+    /// The net worth of the subgraph is never below the largest prize of a
+    /// single node, and the subgraph is empty only when all the prizes are
+    /// null.
+    ///
+    /// # Arguments
+    ///
+    /// * `prizes` - The prize of every node.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when:
+    /// - the matrix is not square or not symmetric;
+    /// - the number of prizes differs from the number of nodes;
+    /// - a prize is not finite or is negative;
+    /// - a cost cannot be represented as `f64`, is not finite or is negative.
+    ///
+    /// # Complexity
+    ///
+    /// O(V (V + E)) time and O(V + E) space.
+    #[inline]
+    fn prize_collecting_steiner_tree(
+        &self,
+        prizes: &[f64],
+    ) -> Result<PrizeCollectingSubgraph<Self::RowIndex>, PrizeCollectingSteinerTreeError> {
+        let costs = cost_rows(self)?;
+        if prizes.len() != costs.len() {
+            return Err(PrizeCollectingSteinerTreeError::PrizesLengthMismatch {
+                expected: costs.len(),
+                found: prizes.len(),
+            });
+        }
+        if let Some(node_id) = prizes.iter().position(|prize| !prize.is_finite() || *prize < 0.0) {
+            return Err(PrizeCollectingSteinerTreeError::InvalidPrize { node_id });
+        }
+
+        let forest = grow_forest(&costs, prizes);
+        let (root, worth) = best_root(&forest, prizes);
+        let mut nodes = Vec::new();
+        let mut edges = Vec::new();
+        if worth > 0.0 {
+            collect_pruned_tree(&forest, prizes, root, &mut nodes, &mut edges);
+        }
+        nodes.sort_unstable();
+        edges.sort_unstable_by_key(|&(source, destination, _)| (source, destination));
+        let prize = nodes.iter().map(|&node| prizes[node]).sum();
+        let cost = edges.iter().map(|&(_, _, cost)| cost).sum();
+        let node_id = |node: usize| {
+            Self::RowIndex::try_from_usize(node)
+                .unwrap_or_else(|_| unreachable!("Node indices come from row identifiers."))
+        };
+        Ok(PrizeCollectingSubgraph {
+            nodes: nodes.into_iter().map(node_id).collect(),
+            edges: edges.into_iter().map(|(u, v, cost)| (node_id(u), node_id(v), cost)).collect(),
+            prize,
+            cost,
+        })
+    }
+}
+
+impl<M> PrizeCollectingSteinerTree for M
+where
+    M: SparseValuedMatrix2D + Sized,
+    M::RowIndex: PositiveInteger,
+    M::ColumnIndex: AsPrimitive<usize>,
+    M::Value: ToPrimitive,
+{
+}
+
+/// Adjacency lists of `(neighbour, cost)` pairs, indexed by node.
+type CostRows = Vec<Vec<(usize, f64)>>;
+
+/// Returns the rows of the matrix as sorted `(column, cost)` pairs, after
+/// checking that the matrix is square and symmetric and that its costs are
+/// finite and non-negative.
+fn cost_rows<M>(matrix: &M) -> Result<CostRows, PrizeCollectingSteinerTreeError>
+where
+    M: SparseValuedMatrix2D,
+    M::RowIndex: AsPrimitive<usize>,
+    M::ColumnIndex: AsPrimitive<usize>,
+    M::Value: ToPrimitive,
+{
+    let rows = matrix.number_of_rows().as_();
+    let columns = matrix.number_of_columns().as_();
+    if rows != columns {
+        return Err(PrizeCollectingSteinerTreeError::NonSquareMatrix { rows, columns });
+    }
+
+    let mut cost_rows = Vec::with_capacity(rows);
+    for (row_id, entries) in matrix.rows_with_values() {
+        let source_id = row_id.as_();
+        let mut costs = Vec::new();
+        for (column_id, cost) in entries {
+            let destination_id = column_id.as_();
+            let cost =
+                cost.to_f64().ok_or(PrizeCollectingSteinerTreeError::UnrepresentableWeight {
+                    source_id,
+                    destination_id,
+                })?;
+            if !cost.is_finite() {
+                return Err(PrizeCollectingSteinerTreeError::NonFiniteWeight {
+                    source_id,
+                    destination_id,
+                });
+            }
+            if cost < 0.0 {
+                return Err(PrizeCollectingSteinerTreeError::NegativeWeight {
+                    source_id,
+                    destination_id,
+                });
+            }
+            costs.push((destination_id, cost));
+        }
+        costs.sort_unstable_by_key(|&(destination_id, _)| destination_id);
+        cost_rows.push(costs);
+    }
+
+    for (source_id, costs) in cost_rows.iter().enumerate() {
+        for &(destination_id, cost) in costs {
+            let reverse = cost_rows[destination_id]
+                .binary_search_by_key(&source_id, |&(column, _)| column)
+                .map(|position| cost_rows[destination_id][position].1);
+            if reverse.is_ok_and(|reverse| reverse.to_bits() == cost.to_bits()) {
+                continue;
+            }
+            return Err(PrizeCollectingSteinerTreeError::NonSymmetricWeight {
+                source_id,
+                destination_id,
+            });
+        }
+    }
+    Ok(cost_rows)
+}
+
+/// Clusters of the moat growth.
+struct Clusters {
+    /// The partition of the nodes into clusters.
+    sets: DisjointSet<usize>,
+    /// Whether the cluster of each root still grows.
+    active: Vec<bool>,
+    /// The prize left to spend by the cluster of each root.
+    budgets: Vec<f64>,
+}
+
+/// Next event of the moat growth.
+enum Event {
+    /// The edge of the given index becomes tight.
+    Edge(usize),
+    /// The cluster of the given root runs out of budget.
+    Deactivation(usize),
+}
+
+/// Returns the adjacency lists of the forest of tight edges grown by the
+/// Goemans-Williamson moats.
+fn grow_forest(costs: &[Vec<(usize, f64)>], prizes: &[f64]) -> CostRows {
+    let order = costs.len();
+    let edges: Vec<(usize, usize, f64)> = costs
+        .iter()
+        .enumerate()
+        .flat_map(|(source, row)| {
+            row.iter()
+                .filter(move |&&(destination, _)| source < destination)
+                .map(move |&(destination, cost)| (source, destination, cost))
+        })
+        .collect();
+    let mut clusters = Clusters {
+        sets: DisjointSet::new(order),
+        active: vec![true; order],
+        budgets: prizes.to_vec(),
+    };
+    // The total width of the moats around each node.
+    let mut covered = vec![0.0; order];
+    let mut forest = vec![Vec::new(); order];
+
+    loop {
+        let mut next: Option<(f64, Event)> = None;
+        for root in 0..order {
+            if clusters.sets.find(root) == root
+                && clusters.active[root]
+                && next.as_ref().is_none_or(|(time, _)| clusters.budgets[root] < *time)
+            {
+                next = Some((clusters.budgets[root], Event::Deactivation(root)));
+            }
+        }
+        for (index, &(source, destination, cost)) in edges.iter().enumerate() {
+            let (left, right) = (clusters.sets.find(source), clusters.sets.find(destination));
+            let rate = usize::from(clusters.active[left]) + usize::from(clusters.active[right]);
+            if left == right || rate == 0 {
+                continue;
+            }
+            let slack = (cost - covered[source] - covered[destination]).max(0.0);
+            let time = if rate == 2 { slack / 2.0 } else { slack };
+            if next.as_ref().is_none_or(|(best, _)| time < *best) {
+                next = Some((time, Event::Edge(index)));
+            }
+        }
+        let Some((time, event)) = next else {
+            return forest;
+        };
+
+        for (node, width) in covered.iter_mut().enumerate() {
+            let root = clusters.sets.find(node);
+            if clusters.active[root] {
+                *width += time;
+            }
+        }
+        for root in 0..order {
+            if clusters.sets.find(root) == root && clusters.active[root] {
+                clusters.budgets[root] = (clusters.budgets[root] - time).max(0.0);
+            }
+        }
+        match event {
+            Event::Deactivation(root) => {
+                clusters.active[root] = false;
+                clusters.budgets[root] = 0.0;
+            }
+            Event::Edge(index) => {
+                let (source, destination, cost) = edges[index];
+                let (left, right) = (clusters.sets.find(source), clusters.sets.find(destination));
+                clusters.sets.union(left, right);
+                let root = clusters.sets.find(left);
+                clusters.budgets[root] = clusters.budgets[left] + clusters.budgets[right];
+                clusters.active[root] = true;
+                forest[source].push((destination, cost));
+                forest[destination].push((source, cost));
+            }
+        }
+    }
+}
+
+/// Returns the nodes of the forest in depth-first preorder from the root,
+/// with the parent of each of them.
+fn preorder(forest: &[Vec<(usize, f64)>], root: usize) -> Vec<(usize, Option<(usize, f64)>)> {
+    let mut order = Vec::new();
+    let mut stack = vec![(root, None)];
+    while let Some((node, parent)) = stack.pop() {
+        order.push((node, parent));
+        for &(neighbour, cost) in &forest[node] {
+            if parent.is_none_or(|(parent, _)| parent != neighbour) {
+                stack.push((neighbour, Some((node, cost))));
+            }
+        }
+    }
+    order
+}
+
+/// Returns the root of the forest whose strongly pruned tree has the largest
+/// net worth, together with that worth.
+///
+/// Rooted at a node, the pruned tree keeps a child subtree whenever its own
+/// pruned worth exceeds the cost of the edge reaching it. Rerooting the
+/// worths of every tree yields the best root in linear time.
+fn best_root(forest: &[Vec<(usize, f64)>], prizes: &[f64]) -> (usize, f64) {
+    let order = forest.len();
+    let mut visited = vec![false; order];
+    // The worth of the subtree below each node, and its contribution to the
+    // parent once the cost of the edge is paid.
+    let mut below = vec![0.0; order];
+    let mut total = vec![0.0; order];
+    let mut best = (0, 0.0);
+    for start in 0..order {
+        if visited[start] {
+            continue;
+        }
+        let tree = preorder(forest, start);
+        for &(node, _) in &tree {
+            visited[node] = true;
+        }
+        for &(node, parent) in tree.iter().rev() {
+            below[node] += prizes[node];
+            if let Some((parent, cost)) = parent {
+                below[parent] += (below[node] - cost).max(0.0);
+            }
+        }
+        for &(node, parent) in &tree {
+            total[node] = below[node];
+            if let Some((parent, cost)) = parent {
+                // The worth of the parent side once this subtree is removed.
+                let above = total[parent] - (below[node] - cost).max(0.0);
+                total[node] += (above - cost).max(0.0);
+            }
+            if total[node] > best.1 {
+                best = (node, total[node]);
+            }
+        }
+    }
+    best
+}
+
+/// Appends the nodes and edges of the tree strongly pruned around the root.
+fn collect_pruned_tree(
+    forest: &[Vec<(usize, f64)>],
+    prizes: &[f64],
+    root: usize,
+    nodes: &mut Vec<usize>,
+    edges: &mut Vec<(usize, usize, f64)>,
+) {
+    let tree = preorder(forest, root);
+    let mut below = vec![0.0; forest.len()];
+    for &(node, parent) in tree.iter().rev() {
+        below[node] += prizes[node];
+        if let Some((parent, cost)) = parent {
+            below[parent] += (below[node] - cost).max(0.0);
+        }
+    }
+    let mut kept = vec![false; forest.len()];
+    kept[root] = true;
+    for &(node, parent) in &tree {
+        let Some((parent, cost)) = parent else {
+            nodes.push(node);
+            continue;
+        };
+        if kept[parent] && below[node] > cost {
+            kept[node] = true;
+            nodes.push(node);
+            edges.push((parent.min(node), parent.max(node), cost));
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//! Submodule providing the `Quotient` trait, which condenses a weighted
+//! undirected graph along a partition of its nodes.
+
+use alloc::vec::Vec;
+
+use num_traits::{AsPrimitive, ToPrimitive};
+
+use super::modularity::{ModularityError, WeightedUndirectedGraph};
+use crate::{
+    impls::ValuedCSR2D,
+    traits::{Finite, Number, SparseValuedMatrix2D},
+};
+
+/// The weighted adjacency matrix of a quotient graph, with the community of
+/// each of its super-nodes.
+pub type QuotientGraph<Marker> = (ValuedCSR2D<usize, usize, usize, f64>, Vec<Marker>);
+
+/// Trait providing the quotient of a weighted undirected graph by a partition
+/// of its nodes, as aggregated between the levels of
+/// [`Louvain`](super::Louvain).
+///
+/// The graph is expected to be represented by a weighted, square matrix with
+/// symmetric entries (undirected weighted graph).
+pub trait Quotient: SparseValuedMatrix2D + Sized
+where
+    Self::RowIndex: AsPrimitive<usize>,
+    Self::ColumnIndex: AsPrimitive<usize>,
+    Self::Value: Number + ToPrimitive + Finite,
+{
+    /// Collapses each community of the partition into a super-node.
+    ///
+    /// The super-nodes follow the increasing order of the communities, which
+    /// the returned mapping lists, so that a partition numbering its
+    /// communities from zero is mapped onto itself. The weight between two
+    /// super-nodes is the sum of the weights between their communities, and
+    /// the self-loop of a super-node sums the entries within its community,
+    /// in which an edge counts in both directions and a self-loop once. The
+    /// total weight of the matrix, and hence the modularity of any coarser
+    /// partition, is preserved.
+    ///
+    /// # Arguments
+    ///
+    /// * `partition`: The community of each node.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when:
+    /// - the matrix is not square or not symmetric;
+    /// - at least one weight is non-finite or non-positive;
+    /// - the partition does not have one entry per node.
+    ///
+    /// # Complexity
+    ///
+    /// O(V log V + E log E) time and O(V + E) space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{impls::ValuedCSR2D, prelude::*, traits::Quotient};
+    ///
+    /// let edges: ValuedCSR2D<usize, usize, usize, f64> =
+    ///     GenericEdgesBuilder::<_, ValuedCSR2D<usize, usize, usize, f64>>::default()
+    ///         .expected_number_of_edges(6)
+    ///         .expected_shape((4, 4))
+    ///         .edges(
+    ///             vec![(0, 1, 1.0), (1, 0, 1.0), (1, 2, 0.5), (2, 1, 0.5), (2, 3, 2.0), (3, 2, 2.0)]
+    ///                 .into_iter(),
+    ///         )
+    ///         .build()
+    ///         .unwrap();
+    ///
+    /// let (quotient, communities) = edges.quotient(&[7_u8, 7, 3, 3]).unwrap();
+    /// assert_eq!(communities, vec![3, 7]);
+    /// assert_eq!(quotient.sparse_row_values(0).collect::<Vec<_>>(), vec![4.0, 0.5]);
+    /// assert_eq!(quotient.sparse_row_values(1).collect::<Vec<_>>(), vec![0.5, 2.0]);
+    /// ```
+    #[inline]
+    fn quotient<Marker>(
+        &self,
+        partition: &[Marker],
+    ) -> Result<QuotientGraph<Marker>, ModularityError>
+    where
+        Marker: Ord + Copy,
+    {
+        let graph = WeightedUndirectedGraph::from_matrix(self)?;
+        if partition.len() != graph.number_of_nodes() {
+            return Err(ModularityError::PartitionLengthMismatch {
+                expected: graph.number_of_nodes(),
+                found: partition.len(),
+            });
+        }
+
+        let mut communities = partition.to_vec();
+        communities.sort_unstable();
+        communities.dedup();
+        let super_nodes: Vec<usize> = partition
+            .iter()
+            .map(|community| {
+                communities.binary_search(community).expect("Every community is listed.")
+            })
+            .collect();
+
+        let quotient = graph.induced(&super_nodes, communities.len()).to_matrix();
+        Ok((quotient, communities))
+    }
+}
+
+impl<G> Quotient for G
+where
+    G: SparseValuedMatrix2D + Sized,
+    G::RowIndex: AsPrimitive<usize>,
+    G::ColumnIndex: AsPrimitive<usize>,
+    G::Value: Number + ToPrimitive + Finite,
+{
+}
@@ -0,0 +1,183 @@
+//! Submodule providing the `RandomSpanningTree` trait and its blanket
+//! implementation for undirected graphs, which samples uniformly random
+//! spanning trees with Wilson's algorithm.
+use alloc::vec::Vec;
+
+use num_traits::AsPrimitive;
+
+use super::randomized_graphs::{XorShift64, builder_utils::build_directed};
+use crate::{
+    impls::{CSR2D, SquareCSR2D},
+    traits::UndirectedMonopartiteMonoplexGraph,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+/// Error enumeration for random spanning tree sampling.
+pub enum RandomSpanningTreeError {
+    /// The graph is not connected, so it has no spanning tree.
+    #[error("The graph is not connected, so it has no spanning tree.")]
+    DisconnectedGraph,
+}
+
+/// Trait providing Wilson's algorithm for sampling uniformly random spanning
+/// trees of connected undirected graphs.
+pub trait RandomSpanningTree: UndirectedMonopartiteMonoplexGraph
+where
+    Self::NodeId: AsPrimitive<usize>,
+{
+    /// Returns a spanning tree drawn uniformly at random among all the
+    /// spanning trees of the graph.
+    ///
+    /// The tree is returned as a square matrix over the node identifiers of
+    /// the graph, holding every tree edge in both directions. Self-loops of
+    /// the graph are never part of the tree.
+    ///
+    /// The tree is built from loop-erased random walks (Wilson, 1996): each
+    /// node not yet in the tree starts a random walk which stops upon hitting
+    /// the tree, and the walk with its cycles erased is grafted onto it.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The seed of the random walks.
+    ///
+    /// # Errors
+    ///
+    /// * If the graph is not connected.
+    ///
+    /// # Complexity
+    ///
+    /// O(V + E) space and expected time proportional to the mean hitting time
+    /// of the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{
+    ///     impls::{SortedVec, SymmetricCSR2D},
+    ///     prelude::*,
+    ///     traits::{EdgesBuilder, VocabularyBuilder},
+    /// };
+    ///
+    /// let nodes: Vec<usize> = vec![0, 1, 2, 3];
+    /// let edges: Vec<(usize, usize)> = vec![(0, 1), (0, 2), (1, 2), (2, 3)];
+    /// let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+    ///     .expected_number_of_symbols(nodes.len())
+    ///     .symbols(nodes.into_iter().enumerate())
+    ///     .build()
+    ///     .unwrap();
+    /// let edges: SymmetricCSR2D<_> = UndiEdgesBuilder::default()
+    ///     .expected_number_of_edges(edges.len())
+    ///     .expected_shape(nodes.len())
+    ///     .edges(edges.into_iter())
+    ///     .build()
+    ///     .unwrap();
+    /// let graph: UndiGraph<usize> = UndiGraph::from((nodes, edges));
+    ///
+    /// let tree = graph.random_spanning_tree(42).unwrap();
+    /// // A spanning tree of 4 nodes has 3 edges, stored in both directions.
+    /// assert_eq!(tree.number_of_defined_values(), 6);
+    /// // The bridge (2, 3) belongs to every spanning tree.
+    /// assert!(tree.has_entry(2, 3));
+    /// ```
+    #[allow(clippy::cast_possible_truncation)]
+    #[inline]
+    fn random_spanning_tree(
+        &self,
+        seed: u64,
+    ) -> Result<SquareCSR2D<CSR2D<usize, usize, usize>>, RandomSpanningTreeError> {
+        let number_of_nodes: usize = self.number_of_nodes().as_();
+
+        // Adjacency lists without self-loops, for constant-time random
+        // neighbour access.
+        let mut offsets = Vec::with_capacity(number_of_nodes + 1);
+        let mut neighbors = Vec::new();
+        offsets.push(0);
+        for node in self.node_ids() {
+            let node_index: usize = node.as_();
+            neighbors.extend(
+                UndirectedMonopartiteMonoplexGraph::neighbors(self, node)
+                    .map(AsPrimitive::<usize>::as_)
+                    .filter(|&neighbor| neighbor != node_index),
+            );
+            offsets.push(neighbors.len());
+        }
+
+        // Walks from a node of another component would never hit the tree.
+        if !is_connected(&offsets, &neighbors) {
+            return Err(RandomSpanningTreeError::DisconnectedGraph);
+        }
+
+        let mut rng = XorShift64::from(XorShift64::normalize_seed(scramble_seed(seed)));
+        let mut in_tree = vec![false; number_of_nodes];
+        let mut next = vec![usize::MAX; number_of_nodes];
+        if let Some(root) = in_tree.first_mut() {
+            *root = true;
+        }
+
+        for start in 1..number_of_nodes {
+            // Random walk until the tree is hit, remembering only the last
+            // exit of every node, which implicitly erases the loops.
+            let mut node = start;
+            while !in_tree[node] {
+                let degree = (offsets[node + 1] - offsets[node]) as u64;
+                let choice = (rng.next().unwrap() % degree) as usize;
+                next[node] = neighbors[offsets[node] + choice];
+                node = next[node];
+            }
+            // Graft the loop-erased path onto the tree.
+            let mut node = start;
+            while !in_tree[node] {
+                in_tree[node] = true;
+                node = next[node];
+            }
+        }
+
+        let mut edges: Vec<(usize, usize)> = next
+            .iter()
+            .enumerate()
+            .skip(1)
+            .flat_map(|(node, &parent)| [(node, parent), (parent, node)])
+            .collect();
+        edges.sort_unstable();
+        Ok(build_directed(number_of_nodes, edges))
+    }
+}
+
+impl<G> RandomSpanningTree for G
+where
+    G: UndirectedMonopartiteMonoplexGraph,
+    G::NodeId: AsPrimitive<usize>,
+{
+}
+
+/// Scrambles the seed with the `SplitMix64` finalizer, since the first
+/// outputs of `XorShift64` for nearby seeds are strongly correlated and would
+/// bias the first steps of the walks.
+fn scramble_seed(seed: u64) -> u64 {
+    let mut value = seed;
+    value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    value ^ (value >> 31)
+}
+
+/// Returns whether the graph described by the adjacency lists is connected.
+fn is_connected(offsets: &[usize], neighbors: &[usize]) -> bool {
+    let number_of_nodes = offsets.len() - 1;
+    if number_of_nodes == 0 {
+        return true;
+    }
+    let mut visited = vec![false; number_of_nodes];
+    let mut stack = vec![0];
+    visited[0] = true;
+    let mut number_of_visited = 1;
+    while let Some(node) = stack.pop() {
+        for &neighbor in &neighbors[offsets[node]..offsets[node + 1]] {
+            if !visited[neighbor] {
+                visited[neighbor] = true;
+                number_of_visited += 1;
+                stack.push(neighbor);
+            }
+        }
+    }
+    number_of_visited == number_of_nodes
+}
@@ -13,6 +13,11 @@ mod randomized_dag;
 #[cfg(all(feature = "alloc", any(feature = "std", feature = "hashbrown")))]
 pub use randomized_dag::RandomizedDAG;
 
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "hashbrown")))]
+mod rewire;
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "hashbrown")))]
+pub use rewire::{DirectedRewire, Rewire, RewireError};
+
 // ── Deterministic graph families ────────────────────────────────────────────
 
 #[cfg(feature = "alloc")]
@@ -140,14 +145,29 @@ pub use stochastic_block_model::stochastic_block_model;
 #[cfg(all(feature = "alloc", any(feature = "std", feature = "hashbrown")))]
 mod configuration_model;
 #[cfg(all(feature = "alloc", any(feature = "std", feature = "hashbrown")))]
-pub use configuration_model::configuration_model;
+pub use configuration_model::{
+    ConfigurationModelError, configuration_model, configuration_multigraph,
+    simple_configuration_model,
+};
 
 #[cfg(feature = "alloc")]
 mod chung_lu;
 #[cfg(feature = "alloc")]
 pub use chung_lu::chung_lu;
 
+#[cfg(feature = "alloc")]
+mod random_bipartite;
+#[cfg(feature = "alloc")]
+pub use random_bipartite::random_bipartite_graph;
+
+#[cfg(feature = "alloc")]
+mod random_cost_matrix;
+#[cfg(feature = "alloc")]
+pub use random_cost_matrix::{
+    CostDistribution, Feasibility, SparseCostMatrixConfig, random_sparse_cost_matrix,
+};
+
 #[cfg(feature = "alloc")]
 mod random_geometric;
 #[cfg(feature = "alloc")]
-pub use random_geometric::random_geometric_graph;
+pub use random_geometric::{random_geometric_graph, random_geometric_graph_with_positions};
@@ -1,8 +1,9 @@
-//! Internal helper for building undirected graphs from edge lists.
+//! Internal helpers for sampling edge lists and building graphs from them.
 #![cfg(feature = "alloc")]
 
 use alloc::vec::Vec;
 
+use super::XorShift64;
 use crate::{
     impls::{CSR2D, SquareCSR2D, SymmetricCSR2D, UpperTriangularCSR2D},
     naive_structs::GenericUndirectedMonopartiteEdgesBuilder,
@@ -64,3 +65,60 @@ pub(crate) fn build_directed(
         .build()
         .unwrap()
 }
+
+/// Decodes the linear index of a pair of distinct vertices into the pair
+/// `(col, row)`, with `col < row`, under the enumeration `(0, 1)`, `(0, 2)`,
+/// `(1, 2)`, `(0, 3)`, ...
+///
+/// The row is the largest integer such that `row * (row - 1) / 2 <= index`.
+#[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+#[inline]
+pub(crate) fn pair_from_index(index: usize) -> (usize, usize) {
+    #[allow(clippy::manual_midpoint)]
+    let mut row = ((1.0 + (1.0 + 8.0 * index as f64).sqrt()) / 2.0).floor() as usize;
+    // The floating-point estimate may be off by one in either direction.
+    while row * (row - 1) / 2 > index {
+        row -= 1;
+    }
+    while (row + 1) * row / 2 <= index {
+        row += 1;
+    }
+    (index - row * (row - 1) / 2, row)
+}
+
+/// Samples each pair of distinct vertices among `n` independently with
+/// probability `p`, returning the pairs `(col, row)` with `col < row` in the
+/// order of [`pair_from_index`].
+///
+/// Pairs are visited with geometric skipping, so the running time is linear
+/// in the number of sampled pairs.
+///
+/// # Arguments
+/// * `rng` — the generator the skips are drawn from
+/// * `n` — number of vertices, at least two
+/// * `p` — probability of each pair, in `(0, 1)`
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    clippy::cast_sign_loss
+)]
+pub(crate) fn geometric_pairs(rng: &mut XorShift64, n: usize, p: f64) -> Vec<(usize, usize)> {
+    let total_pairs = n * (n - 1) / 2;
+    let ln_1_minus_p = (1.0 - p).ln();
+
+    let mut pairs = Vec::new();
+    let mut pos: isize = -1;
+    loop {
+        let uniform = (rng.next().unwrap() as f64) / (u64::MAX as f64);
+        // Avoid log(0)
+        let u_clamped = if uniform <= 0.0 { f64::MIN_POSITIVE } else { uniform };
+        let skip = (u_clamped.ln() / ln_1_minus_p).floor() as isize;
+        pos = pos.saturating_add(skip).saturating_add(1);
+        if pos >= total_pairs as isize {
+            break;
+        }
+        pairs.push(pair_from_index(pos as usize));
+    }
+    pairs
+}
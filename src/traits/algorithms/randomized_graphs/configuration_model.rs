@@ -11,15 +11,93 @@ use hashbrown::HashSet;
 use super::{XorShift64, builder_utils::build_symmetric};
 use crate::impls::{CSR2D, SymmetricCSR2D};
 
+/// Error type for simple configuration model generation.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ConfigurationModelError {
+    /// The total number of stubs must be even.
+    #[error("the sum of degrees must be even (got {sum})")]
+    OddDegreeSum {
+        /// The sum of the requested degrees.
+        sum: usize,
+    },
+    /// No simple graph has the requested degree sequence.
+    #[error("the degree sequence is not realizable by a simple graph")]
+    NotGraphical,
+    /// Rejection sampling failed to produce a simple graph in the retry budget.
+    #[error("failed to generate a simple graph with the degree sequence after {attempts} attempts")]
+    GenerationAttemptsExceeded {
+        /// Number of rejection-sampling attempts that were tried.
+        attempts: usize,
+    },
+}
+
+/// Shuffles the stubs of the degree sequence and returns them paired, with
+/// the smaller endpoint first, in pairing order.
+#[allow(clippy::cast_possible_truncation)]
+fn paired_stubs(seed: u64, degrees: &[usize], total_stubs: usize) -> Vec<(usize, usize)> {
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(seed));
+
+    // Create stubs: vertex i appears degrees[i] times.
+    let mut stubs: Vec<usize> = Vec::with_capacity(total_stubs);
+    for (i, &deg) in degrees.iter().enumerate() {
+        for _ in 0..deg {
+            stubs.push(i);
+        }
+    }
+
+    // Fisher-Yates shuffle.
+    for i in (1..total_stubs).rev() {
+        let j = (rng.next().unwrap() as usize) % (i + 1);
+        stubs.swap(i, j);
+    }
+
+    stubs.chunks_exact(2).map(|pair| (pair[0].min(pair[1]), pair[0].max(pair[1]))).collect()
+}
+
+/// Returns whether the degree sequence is realizable by a simple graph,
+/// according to the Erdős–Gallai theorem.
+fn is_graphical(degrees: &[usize]) -> bool {
+    let n = degrees.len();
+    let mut sorted = degrees.to_vec();
+    sorted.sort_unstable_by(|a, b| b.cmp(a));
+    if sorted.first().is_some_and(|&max| max >= n) {
+        return false;
+    }
+
+    // suffix[i] is the sum of the degrees from position i onwards.
+    let mut suffix = alloc::vec![0usize; n + 1];
+    for i in (0..n).rev() {
+        suffix[i] = suffix[i + 1] + sorted[i];
+    }
+
+    // Number of degrees which are at least k, non-increasing in k.
+    let mut at_least_k = n;
+    for k in 1..=n {
+        while at_least_k > 0 && sorted[at_least_k - 1] < k {
+            at_least_k -= 1;
+        }
+        // The degrees after position k are capped at k: those at least k
+        // contribute k each, and the others their own value.
+        let boundary = at_least_k.max(k);
+        let tail = k * (boundary - k) + suffix[boundary];
+        if suffix[0] - suffix[k] > k * (k - 1) + tail {
+            return false;
+        }
+    }
+    true
+}
+
 /// Generates a graph from the given degree sequence using the configuration
 /// model.
 ///
 /// Creates stubs according to the degree sequence, shuffles, and pairs them.
-/// Self-loops and multi-edges are silently removed to produce a simple graph.
+/// Self-loops and multi-edges are silently removed to produce a simple graph,
+/// so vertices may end up with a lower degree than requested. See
+/// [`simple_configuration_model`] to match the degree sequence exactly, and
+/// [`configuration_multigraph`] to keep the self-loops and multi-edges.
 ///
 /// # Panics
 /// Panics if the sum of degrees is odd.
-#[allow(clippy::cast_possible_truncation, clippy::many_single_char_names)]
 #[must_use]
 pub fn configuration_model(
     seed: u64,
@@ -33,36 +111,78 @@ pub fn configuration_model(
         return build_symmetric(n, Vec::new());
     }
 
-    let mut rng = XorShift64::from(XorShift64::normalize_seed(seed));
-
-    // Create stubs: vertex i appears degrees[i] times.
-    let mut stubs: Vec<usize> = Vec::with_capacity(total_stubs);
-    for (i, &deg) in degrees.iter().enumerate() {
-        for _ in 0..deg {
-            stubs.push(i);
+    // Skip self-loops and multi-edges.
+    let mut edge_set = HashSet::with_capacity(total_stubs / 2);
+    for (u, v) in paired_stubs(seed, degrees, total_stubs) {
+        if u != v {
+            edge_set.insert((u, v));
         }
     }
 
-    // Fisher-Yates shuffle.
-    for i in (1..total_stubs).rev() {
-        let j = (rng.next().unwrap() as usize) % (i + 1);
-        stubs.swap(i, j);
+    let mut edges: Vec<(usize, usize)> = edge_set.into_iter().collect();
+    edges.sort_unstable();
+    build_symmetric(n, edges)
+}
+
+/// Generates a random multigraph from the given degree sequence using the
+/// configuration model, keeping self-loops and multi-edges.
+///
+/// Returns the sorted edges `(u, v)` with `u <= v`, one per pair of stubs, so
+/// a multi-edge appears once per parallel edge and a self-loop counts twice
+/// towards the degree of its vertex. Each vertex therefore has exactly the
+/// requested degree. Removing the self-loops and the repeated edges yields
+/// the graph that [`configuration_model`] builds from the same seed.
+///
+/// # Panics
+/// Panics if the sum of degrees is odd.
+#[must_use]
+pub fn configuration_multigraph(seed: u64, degrees: &[usize]) -> Vec<(usize, usize)> {
+    let total_stubs: usize = degrees.iter().sum();
+    assert!(total_stubs % 2 == 0, "sum of degrees must be even");
+
+    let mut edges = paired_stubs(seed, degrees, total_stubs);
+    edges.sort_unstable();
+    edges
+}
+
+/// Generates a simple graph with exactly the given degree sequence using the
+/// configuration model with rejection sampling.
+///
+/// Pairings containing a self-loop or a multi-edge are rejected and redrawn,
+/// so the graph is sampled uniformly among the simple graphs with the degree
+/// sequence. The probability of drawing a simple pairing drops quickly as the
+/// degrees grow, so heavy-tailed sequences may exhaust the retry budget.
+///
+/// # Errors
+/// Returns [`ConfigurationModelError::OddDegreeSum`] if the sum of degrees is
+/// odd, [`ConfigurationModelError::NotGraphical`] if no simple graph has the
+/// degree sequence, or [`ConfigurationModelError::GenerationAttemptsExceeded`]
+/// if rejection sampling fails within the retry budget.
+pub fn simple_configuration_model(
+    seed: u64,
+    degrees: &[usize],
+) -> Result<SymmetricCSR2D<CSR2D<usize, usize, usize>>, ConfigurationModelError> {
+    let n = degrees.len();
+    let total_stubs: usize = degrees.iter().sum();
+    if total_stubs % 2 != 0 {
+        return Err(ConfigurationModelError::OddDegreeSum { sum: total_stubs });
+    }
+    if !is_graphical(degrees) {
+        return Err(ConfigurationModelError::NotGraphical);
+    }
+    if total_stubs == 0 {
+        return Ok(build_symmetric(n, Vec::new()));
     }
 
-    // Pair consecutive stubs, skip self-loops and multi-edges.
-    let num_pairs = total_stubs / 2;
-    let mut edge_set = HashSet::with_capacity(num_pairs);
-    for pair_idx in 0..num_pairs {
-        let a = stubs[2 * pair_idx];
-        let b = stubs[2 * pair_idx + 1];
-        if a == b {
-            continue; // self-loop
+    let max_attempts = 1000usize;
+    for attempt in 0u64..u64::try_from(max_attempts).expect("attempt budget fits in u64") {
+        let current_seed = seed.wrapping_add(attempt.wrapping_mul(0x9E37_79B9));
+        let mut edges = paired_stubs(current_seed, degrees, total_stubs);
+        edges.sort_unstable();
+        if edges.iter().all(|&(u, v)| u != v) && edges.windows(2).all(|pair| pair[0] != pair[1]) {
+            return Ok(build_symmetric(n, edges));
         }
-        let (u, v) = if a < b { (a, b) } else { (b, a) };
-        edge_set.insert((u, v));
     }
 
-    let mut edges: Vec<(usize, usize)> = edge_set.into_iter().collect();
-    edges.sort_unstable();
-    build_symmetric(n, edges)
+    Err(ConfigurationModelError::GenerationAttemptsExceeded { attempts: max_attempts })
 }
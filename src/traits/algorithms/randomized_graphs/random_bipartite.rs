@@ -0,0 +1,60 @@
+//! Generator for random bipartite graphs.
+#![cfg(feature = "alloc")]
+
+use alloc::vec::Vec;
+
+use super::{XorShift64, builder_utils::build_symmetric};
+use crate::impls::{CSR2D, SymmetricCSR2D};
+
+/// Generates a random bipartite graph: each of the `m · n` possible edges
+/// between the left and right vertices exists independently with
+/// probability `p`.
+///
+/// Left vertices 0..m, right vertices m..m+n, as in
+/// [`complete_bipartite_graph`](super::complete_bipartite_graph).
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    clippy::cast_sign_loss
+)]
+#[must_use]
+pub fn random_bipartite_graph(
+    seed: u64,
+    m: usize,
+    n: usize,
+    p: f64,
+) -> SymmetricCSR2D<CSR2D<usize, usize, usize>> {
+    let total = m + n;
+    if m == 0 || n == 0 || p <= 0.0 || p.is_nan() {
+        return build_symmetric(total, Vec::new());
+    }
+
+    // Complete bipartite graph when p >= 1.0
+    if p >= 1.0 {
+        return super::complete_bipartite_graph(m, n);
+    }
+
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(seed));
+    let total_pairs = m * n;
+    let ln_1_minus_p = (1.0 - p).ln();
+
+    // Geometric skipping over the pairs in row-major order, which are
+    // therefore generated already sorted.
+    let mut edges = Vec::new();
+    let mut pos: isize = -1;
+    loop {
+        let uniform = (rng.next().unwrap() as f64) / (u64::MAX as f64);
+        // Avoid log(0)
+        let u_clamped = if uniform <= 0.0 { f64::MIN_POSITIVE } else { uniform };
+        let skip = (u_clamped.ln() / ln_1_minus_p).floor() as isize;
+        pos = pos.saturating_add(skip).saturating_add(1);
+        if pos >= total_pairs as isize {
+            break;
+        }
+        let k = pos as usize;
+        edges.push((k / n, m + k % n));
+    }
+
+    build_symmetric(total, edges)
+}
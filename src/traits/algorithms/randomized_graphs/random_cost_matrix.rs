@@ -0,0 +1,218 @@
+//! Generator for random sparse cost matrices, such as the ones solved by the
+//! linear assignment algorithms.
+#![cfg(feature = "alloc")]
+
+use alloc::vec::Vec;
+
+use super::XorShift64;
+use crate::{
+    impls::ValuedCSR2D,
+    traits::{MatrixMut, SparseMatrixMut},
+};
+
+/// Distribution of the costs of a random sparse cost matrix.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CostDistribution {
+    /// Costs drawn uniformly from the half-open interval `[low, high)`.
+    Uniform {
+        /// The inclusive lower bound of the costs.
+        low: f64,
+        /// The exclusive upper bound of the costs.
+        high: f64,
+    },
+    /// Costs drawn uniformly from `1 / scale, 2 / scale, ..., levels / scale`,
+    /// which are all positive and include ties.
+    Grid {
+        /// The number of distinct costs.
+        levels: u64,
+        /// The divisor of the integer costs.
+        scale: f64,
+    },
+}
+
+impl Default for CostDistribution {
+    /// Costs from `0.01` to `9.99` in steps of `0.01`.
+    #[inline]
+    fn default() -> Self {
+        Self::Grid { levels: 999, scale: 100.0 }
+    }
+}
+
+impl CostDistribution {
+    /// Draws a cost from the distribution.
+    #[allow(clippy::cast_precision_loss)]
+    fn sample(self, rng: &mut XorShift64) -> f64 {
+        let raw = rng.next().unwrap();
+        match self {
+            Self::Uniform { low, high } => {
+                // The 53 most significant bits give a uniform value in [0, 1).
+                let unit = (raw >> 11) as f64 / (1u64 << 53) as f64;
+                low + (high - low) * unit
+            }
+            Self::Grid { levels, scale } => (raw % levels + 1) as f64 / scale,
+        }
+    }
+}
+
+/// Entries which a random sparse cost matrix is guaranteed to hold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Feasibility {
+    /// No entry is guaranteed.
+    None,
+    /// Every row holds at least one entry.
+    #[default]
+    Rows,
+    /// Every row and every column hold at least one entry.
+    RowsAndColumns,
+    /// The entries contain a random matching covering every row of a tall
+    /// or square matrix, or every column of a wide one, so that the
+    /// assignment problem always has a solution of full size.
+    Assignment,
+}
+
+/// Configuration of [`random_sparse_cost_matrix`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SparseCostMatrixConfig {
+    /// The number of rows of the matrix.
+    pub number_of_rows: usize,
+    /// The number of columns of the matrix.
+    pub number_of_columns: usize,
+    /// The fraction of cells drawn at random, clamped to `[0, 1]`. Cells are
+    /// drawn with replacement, so the realized density of the random entries
+    /// is slightly lower at high densities.
+    pub density: f64,
+    /// The distribution of the costs.
+    pub costs: CostDistribution,
+    /// The entries the matrix is guaranteed to hold.
+    pub feasibility: Feasibility,
+}
+
+impl SparseCostMatrixConfig {
+    /// Returns the configuration of a matrix with the provided shape and
+    /// density, with the default costs and at least one entry per row.
+    #[inline]
+    #[must_use]
+    pub fn new(number_of_rows: usize, number_of_columns: usize, density: f64) -> Self {
+        Self {
+            number_of_rows,
+            number_of_columns,
+            density,
+            costs: CostDistribution::default(),
+            feasibility: Feasibility::default(),
+        }
+    }
+}
+
+/// Returns a random index in `0..n`, which must be positive.
+#[allow(clippy::cast_possible_truncation)]
+fn random_index(rng: &mut XorShift64, n: usize) -> usize {
+    (rng.next().unwrap() % n as u64) as usize
+}
+
+/// Returns a random permutation of `0..n`.
+fn random_permutation(rng: &mut XorShift64, n: usize) -> Vec<usize> {
+    let mut permutation: Vec<usize> = (0..n).collect();
+    for i in (1..n).rev() {
+        permutation.swap(i, random_index(rng, i + 1));
+    }
+    permutation
+}
+
+/// Generates a random sparse cost matrix.
+///
+/// The entries guaranteed by the [`Feasibility`] option are drawn first,
+/// followed by `density · rows · columns` cells drawn uniformly at random.
+/// Repeated cells are merged, and each entry then receives a cost drawn from
+/// the [`CostDistribution`]. The same seed always yields the same matrix.
+///
+/// # Panics
+/// Panics if a uniform distribution has non-finite bounds or `low >= high`,
+/// or if a grid distribution has no levels or a non-positive scale.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{
+///     prelude::*,
+///     traits::algorithms::randomized_graphs::{
+///         CostDistribution, Feasibility, SparseCostMatrixConfig, random_sparse_cost_matrix,
+///     },
+/// };
+///
+/// let config = SparseCostMatrixConfig {
+///     costs: CostDistribution::Uniform { low: 1.0, high: 2.0 },
+///     feasibility: Feasibility::Assignment,
+///     ..SparseCostMatrixConfig::new(30, 50, 0.1)
+/// };
+/// let costs = random_sparse_cost_matrix(42, &config);
+/// assert_eq!(costs.number_of_rows(), 30);
+/// assert_eq!(costs.number_of_columns(), 50);
+/// assert_eq!(costs.jaqaman(10.0, 100.0).unwrap().len(), 30);
+/// ```
+#[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss, clippy::cast_sign_loss)]
+#[must_use]
+pub fn random_sparse_cost_matrix(
+    seed: u64,
+    config: &SparseCostMatrixConfig,
+) -> ValuedCSR2D<usize, usize, usize, f64> {
+    match config.costs {
+        CostDistribution::Uniform { low, high } => {
+            assert!(
+                low.is_finite() && high.is_finite() && low < high,
+                "uniform costs require finite bounds with low < high"
+            );
+        }
+        CostDistribution::Grid { levels, scale } => {
+            assert!(levels > 0, "grid costs require at least one level");
+            assert!(scale > 0.0 && scale.is_finite(), "grid costs require a positive scale");
+        }
+    }
+
+    let rows = config.number_of_rows;
+    let columns = config.number_of_columns;
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(seed));
+    let mut entries: Vec<(usize, usize)> = Vec::new();
+
+    if rows > 0 && columns > 0 {
+        match config.feasibility {
+            Feasibility::None => {}
+            Feasibility::Rows => {
+                entries.extend((0..rows).map(|row| (row, random_index(&mut rng, columns))));
+            }
+            Feasibility::RowsAndColumns => {
+                entries.extend((0..rows).map(|row| (row, random_index(&mut rng, columns))));
+                entries.extend((0..columns).map(|column| (random_index(&mut rng, rows), column)));
+            }
+            Feasibility::Assignment => {
+                if rows <= columns {
+                    let permutation = random_permutation(&mut rng, columns);
+                    entries.extend((0..rows).map(|row| (row, permutation[row])));
+                } else {
+                    let permutation = random_permutation(&mut rng, rows);
+                    entries.extend((0..columns).map(|column| (permutation[column], column)));
+                }
+            }
+        }
+
+        let cells = rows as f64 * columns as f64;
+        let density = if config.density.is_nan() { 0.0 } else { config.density.clamp(0.0, 1.0) };
+        let number_of_random_entries = (cells * density).floor() as usize;
+        entries.reserve(number_of_random_entries);
+        for _ in 0..number_of_random_entries {
+            let row = random_index(&mut rng, rows);
+            entries.push((row, random_index(&mut rng, columns)));
+        }
+    }
+
+    entries.sort_unstable();
+    entries.dedup();
+
+    let mut matrix: ValuedCSR2D<usize, usize, usize, f64> =
+        SparseMatrixMut::with_sparse_shaped_capacity((rows, columns), entries.len());
+    for (row, column) in entries {
+        let cost = config.costs.sample(&mut rng);
+        MatrixMut::add(&mut matrix, (row, column, cost))
+            .expect("Sorted and deduplicated entries are always accepted by the matrix.");
+    }
+    matrix
+}
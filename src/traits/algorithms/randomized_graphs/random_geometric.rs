@@ -8,20 +8,41 @@ use crate::impls::{CSR2D, SymmetricCSR2D};
 
 /// Generates a random geometric graph by placing `n` points uniformly in the
 /// unit square and connecting pairs within Euclidean distance `radius`.
-#[allow(clippy::cast_precision_loss)]
+///
+/// See [`random_geometric_graph_with_positions`] to also retrieve the
+/// coordinates of the points.
 #[must_use]
 pub fn random_geometric_graph(
     seed: u64,
     n: usize,
     radius: f64,
 ) -> SymmetricCSR2D<CSR2D<usize, usize, usize>> {
-    if n <= 1 || radius <= 0.0 {
-        return build_symmetric(n, Vec::new());
-    }
+    random_geometric_graph_with_positions(seed, n, radius).0
+}
 
+/// Generates a random geometric graph by placing `n` points uniformly in the
+/// unit square and connecting pairs within Euclidean distance `radius`,
+/// returning the graph together with the `(x, y)` coordinates of its
+/// vertices.
+///
+/// Points are bucketed in a grid of cells at least `radius` wide, so only
+/// pairs in neighbouring cells are compared and sparse graphs are generated
+/// in time close to linear in the number of points and edges.
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::type_complexity
+)]
+#[must_use]
+pub fn random_geometric_graph_with_positions(
+    seed: u64,
+    n: usize,
+    radius: f64,
+) -> (SymmetricCSR2D<CSR2D<usize, usize, usize>>, Vec<(f64, f64)>) {
     let mut rng = XorShift64::from(XorShift64::normalize_seed(seed));
 
-    // Generate positions in [0, 1) x [0, 1).
+    // Generate positions in [0, 1] x [0, 1].
     let mut positions: Vec<(f64, f64)> = Vec::with_capacity(n);
     for _ in 0..n {
         let x = (rng.next().unwrap() as f64) / (u64::MAX as f64);
@@ -29,18 +50,55 @@ pub fn random_geometric_graph(
         positions.push((x, y));
     }
 
+    if n <= 1 || radius <= 0.0 || radius.is_nan() {
+        return (build_symmetric(n, Vec::new()), positions);
+    }
+
+    // Cells per side: each cell is at least `radius` wide, and there are no
+    // more cells than points.
+    let cells = (1.0 / radius).floor().clamp(1.0, (n as f64).sqrt().ceil()) as usize;
+    let cell_of = |coordinate: f64| ((coordinate * cells as f64) as usize).min(cells - 1);
+
+    // Counting sort of the points by cell, in row-major cell order.
+    let point_cells: Vec<usize> =
+        positions.iter().map(|&(x, y)| cell_of(y) * cells + cell_of(x)).collect();
+    let mut cell_offsets = vec![0usize; cells * cells + 1];
+    for &cell in &point_cells {
+        cell_offsets[cell + 1] += 1;
+    }
+    for cell in 0..cells * cells {
+        cell_offsets[cell + 1] += cell_offsets[cell];
+    }
+    let mut next_slot = cell_offsets.clone();
+    let mut bucketed = vec![0usize; n];
+    for (point, &cell) in point_cells.iter().enumerate() {
+        bucketed[next_slot[cell]] = point;
+        next_slot[cell] += 1;
+    }
+
     let r_sq = radius * radius;
     let mut edges = Vec::new();
 
     for (i, &(xi, yi)) in positions.iter().enumerate() {
-        for (j, &(xj, yj)) in positions.iter().enumerate().skip(i + 1) {
-            let dx = xi - xj;
-            let dy = yi - yj;
-            if dx * dx + dy * dy <= r_sq {
-                edges.push((i, j));
+        let (row, column) = (point_cells[i] / cells, point_cells[i] % cells);
+        for neighbour_row in row.saturating_sub(1)..=(row + 1).min(cells - 1) {
+            for neighbour_column in column.saturating_sub(1)..=(column + 1).min(cells - 1) {
+                let cell = neighbour_row * cells + neighbour_column;
+                for &j in &bucketed[cell_offsets[cell]..cell_offsets[cell + 1]] {
+                    if j <= i {
+                        continue;
+                    }
+                    let (xj, yj) = positions[j];
+                    let dx = xi - xj;
+                    let dy = yi - yj;
+                    if dx * dx + dy * dy <= r_sq {
+                        edges.push((i, j));
+                    }
+                }
             }
         }
     }
 
-    build_symmetric(n, edges)
+    edges.sort_unstable();
+    (build_symmetric(n, edges), positions)
 }
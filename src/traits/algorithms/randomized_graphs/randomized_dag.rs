@@ -8,7 +8,7 @@ use std::collections::HashSet;
 #[cfg(all(feature = "hashbrown", not(feature = "std")))]
 use hashbrown::HashSet;
 
-use super::XorShift64;
+use super::{XorShift64, builder_utils::geometric_pairs};
 use crate::traits::{GrowableEdges, MonoplexGraph, MonoplexMonopartiteGraph, SparseMatrixMut};
 
 /// Trait providing randomized dag method
@@ -19,6 +19,41 @@ pub trait RandomizedDAG: MonoplexGraph {
     /// - `seed`: the random seed the dag is generated from
     /// - `nodes`: number of the nodes the dag is generated from
     fn randomized_dag(seed: u64, nodes: usize) -> Self;
+
+    /// returns a randomized dag drawn from the G(n, p) model under a random
+    /// topological order
+    ///
+    /// The nodes are first arranged in a uniformly random order; each of the
+    /// `nodes * (nodes - 1) / 2` pairs is then connected independently with
+    /// probability `probability`, always from the earlier to the later node
+    /// of the order, so that the graph is acyclic by construction. Pairs are
+    /// visited with geometric skipping, so the running time is linear in the
+    /// number of nodes and generated edges.
+    ///
+    /// # Arguments
+    /// - `seed`: the random seed the dag is generated from
+    /// - `nodes`: number of the nodes the dag is generated from
+    /// - `probability`: the probability of each forward edge
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{
+    ///     impls::{CSR2D, SquareCSR2D},
+    ///     prelude::*,
+    ///     traits::CycleDetection,
+    /// };
+    ///
+    /// type Dag = GenericGraph<usize, SquareCSR2D<CSR2D<usize, usize, usize>>>;
+    ///
+    /// let dag = Dag::randomized_gnp_dag(42, 20, 0.3);
+    /// assert_eq!(dag.number_of_nodes(), 20);
+    /// assert!(!dag.has_cycle());
+    ///
+    /// let tournament = Dag::randomized_gnp_dag(42, 20, 1.0);
+    /// assert_eq!(tournament.number_of_edges(), 20 * 19 / 2);
+    /// ```
+    fn randomized_gnp_dag(seed: u64, nodes: usize, probability: f64) -> Self;
 }
 
 impl<G> RandomizedDAG for G
@@ -64,4 +99,42 @@ where
         }
         G::from((nodes, edges))
     }
+
+    #[allow(clippy::cast_possible_truncation)]
+    #[inline]
+    fn randomized_gnp_dag(seed: u64, nodes: usize, probability: f64) -> Self {
+        if nodes <= 1 || probability <= 0.0 || probability.is_nan() {
+            let edges = G::MonoplexMonopartiteEdges::with_shaped_capacity(nodes, 0);
+            return G::from((nodes, edges));
+        }
+
+        let mut xorshift = XorShift64::from(XorShift64::normalize_seed(seed));
+
+        // Fisher-Yates shuffle of the topological order.
+        let mut order: Vec<usize> = (0..nodes).collect();
+        for i in (1..nodes).rev() {
+            let j = (xorshift.next().unwrap() as usize) % (i + 1);
+            order.swap(i, j);
+        }
+
+        let mut edge_tuples: Vec<(usize, usize)> = if probability >= 1.0 {
+            let mut edge_tuples = Vec::with_capacity(nodes * (nodes - 1) / 2);
+            for (position, &src) in order.iter().enumerate() {
+                edge_tuples.extend(order[position + 1..].iter().map(|&dst| (src, dst)));
+            }
+            edge_tuples
+        } else {
+            geometric_pairs(&mut xorshift, nodes, probability)
+                .into_iter()
+                .map(|(col, row)| (order[col], order[row]))
+                .collect()
+        };
+        edge_tuples.sort_unstable();
+
+        let mut edges = G::MonoplexMonopartiteEdges::with_shaped_capacity(nodes, edge_tuples.len());
+        for (src, dst) in edge_tuples {
+            edges.add((src, dst)).unwrap();
+        }
+        G::from((nodes, edges))
+    }
 }
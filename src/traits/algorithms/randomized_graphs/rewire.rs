@@ -0,0 +1,251 @@
+//! Degree-preserving rewiring of existing graphs by double-edge swaps.
+//!
+//! A double-edge swap picks two edges `(a, b)` and `(c, d)` and replaces them
+//! with `(a, d)` and `(c, b)`, which leaves the degree of every node
+//! unchanged (the in- and out-degrees for directed graphs). Repeating enough
+//! swaps yields a uniformly random graph with the same degrees, the usual
+//! null model against which motif counts or modularities are compared.
+#![cfg(all(feature = "alloc", any(feature = "std", feature = "hashbrown")))]
+
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::collections::HashSet;
+
+#[cfg(all(feature = "hashbrown", not(feature = "std")))]
+use hashbrown::HashSet;
+use num_traits::AsPrimitive;
+
+use super::{
+    XorShift64,
+    builder_utils::{build_directed, build_symmetric},
+};
+use crate::{
+    impls::{CSR2D, SquareCSR2D, SymmetricCSR2D},
+    traits::{MonoplexMonopartiteGraph, UndirectedMonopartiteMonoplexGraph},
+};
+
+/// Number of swap attempts allowed for each requested swap.
+const ATTEMPTS_PER_SWAP: usize = 100;
+
+/// Error type for degree-preserving rewiring.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum RewireError {
+    /// Swaps were requested on a graph with fewer than two swappable edges.
+    #[error("at least two edges that are not self-loops are needed to swap (got {edges})")]
+    TooFewEdges {
+        /// Number of edges of the graph that are not self-loops.
+        edges: usize,
+    },
+    /// Too many swaps were rejected to reach the requested number of swaps.
+    #[error("performed only {performed} of the {requested} requested swaps in {attempts} attempts")]
+    SwapAttemptsExceeded {
+        /// Number of requested swaps.
+        requested: usize,
+        /// Number of swaps performed before giving up.
+        performed: usize,
+        /// Number of swap attempts that were tried.
+        attempts: usize,
+    },
+}
+
+/// Trait providing degree-preserving rewiring of undirected graphs.
+pub trait Rewire: UndirectedMonopartiteMonoplexGraph
+where
+    Self::NodeId: AsPrimitive<usize>,
+{
+    /// Returns a copy of the graph rewired by the given number of successful
+    /// double-edge swaps, each replacing the edges `{a, b}` and `{c, d}` with
+    /// `{a, d}` and `{c, b}`.
+    ///
+    /// Swaps that would create a self-loop or an edge already in the graph are
+    /// rejected and retried, so that the rewired graph is simple and every
+    /// node keeps its degree. Self-loops of the original graph are left in
+    /// place. About ten swaps per edge are customary to forget the original
+    /// structure.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_of_swaps`: The number of successful swaps to perform.
+    /// * `seed`: The random seed of the swaps.
+    ///
+    /// # Errors
+    ///
+    /// * [`RewireError::TooFewEdges`] if swaps are requested on a graph with
+    ///   fewer than two edges that are not self-loops.
+    /// * [`RewireError::SwapAttemptsExceeded`] if the swaps are not performed
+    ///   within 100 attempts per requested swap, e.g. for complete graphs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{
+    ///     impls::{CSR2D, SortedVec, SymmetricCSR2D},
+    ///     prelude::*,
+    ///     traits::{EdgesBuilder, Rewire, VocabularyBuilder},
+    /// };
+    ///
+    /// let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+    ///     .expected_number_of_symbols(6)
+    ///     .symbols((0..6).enumerate())
+    ///     .build()
+    ///     .unwrap();
+    /// let edges: SymmetricCSR2D<CSR2D<usize, usize, usize>> = UndiEdgesBuilder::default()
+    ///     .expected_number_of_edges(6)
+    ///     .expected_shape(6)
+    ///     .edges(vec![(0, 1), (0, 5), (1, 2), (2, 3), (3, 4), (4, 5)].into_iter())
+    ///     .build()
+    ///     .unwrap();
+    /// let cycle: UndiGraph<usize> = UndiGraph::from((nodes, edges));
+    ///
+    /// let rewired = cycle.rewire(10, 42).unwrap();
+    /// for node in 0..6 {
+    ///     assert_eq!(rewired.sparse_row(node).count(), 2);
+    /// }
+    /// ```
+    #[inline]
+    fn rewire(
+        &self,
+        number_of_swaps: usize,
+        seed: u64,
+    ) -> Result<SymmetricCSR2D<CSR2D<usize, usize, usize>>, RewireError> {
+        let mut loops = Vec::new();
+        let mut edges = Vec::new();
+        for node in self.node_ids() {
+            for neighbor in self.neighbors(node) {
+                if node == neighbor {
+                    loops.push((node.as_(), node.as_()));
+                } else if node < neighbor {
+                    edges.push((node.as_(), neighbor.as_()));
+                }
+            }
+        }
+        double_edge_swaps(&mut edges, number_of_swaps, seed, false)?;
+        edges.extend(loops);
+        edges.sort_unstable();
+        Ok(build_symmetric(self.number_of_nodes().as_(), edges))
+    }
+}
+
+impl<G> Rewire for G
+where
+    G: UndirectedMonopartiteMonoplexGraph,
+    G::NodeId: AsPrimitive<usize>,
+{
+}
+
+/// Trait providing degree-preserving rewiring of directed graphs.
+pub trait DirectedRewire: MonoplexMonopartiteGraph
+where
+    Self::NodeId: AsPrimitive<usize>,
+{
+    /// Returns a copy of the graph rewired by the given number of successful
+    /// double-edge swaps, each replacing the edges `a → b` and `c → d` with
+    /// `a → d` and `c → b`.
+    ///
+    /// Swaps that would create a self-loop or an edge already in the graph are
+    /// rejected and retried, so that the rewired graph is simple and every
+    /// node keeps both its in-degree and its out-degree. Self-loops of the
+    /// original graph are left in place.
+    ///
+    /// # Arguments
+    ///
+    /// * `number_of_swaps`: The number of successful swaps to perform.
+    /// * `seed`: The random seed of the swaps.
+    ///
+    /// # Errors
+    ///
+    /// * [`RewireError::TooFewEdges`] if swaps are requested on a graph with
+    ///   fewer than two edges that are not self-loops.
+    /// * [`RewireError::SwapAttemptsExceeded`] if the swaps are not performed
+    ///   within 100 attempts per requested swap.
+    #[inline]
+    fn rewire_directed(
+        &self,
+        number_of_swaps: usize,
+        seed: u64,
+    ) -> Result<SquareCSR2D<CSR2D<usize, usize, usize>>, RewireError> {
+        let mut loops = Vec::new();
+        let mut edges = Vec::new();
+        for node in self.node_ids() {
+            for successor in self.successors(node) {
+                if node == successor {
+                    loops.push((node.as_(), node.as_()));
+                } else {
+                    edges.push((node.as_(), successor.as_()));
+                }
+            }
+        }
+        double_edge_swaps(&mut edges, number_of_swaps, seed, true)?;
+        edges.extend(loops);
+        edges.sort_unstable();
+        Ok(build_directed(self.number_of_nodes().as_(), edges))
+    }
+}
+
+impl<G> DirectedRewire for G
+where
+    G: MonoplexMonopartiteGraph,
+    G::NodeId: AsPrimitive<usize>,
+{
+}
+
+/// Performs the requested number of double-edge swaps on the loopless edges,
+/// which are stored with the smaller endpoint first when undirected.
+#[allow(clippy::cast_possible_truncation)]
+fn double_edge_swaps(
+    edges: &mut [(usize, usize)],
+    number_of_swaps: usize,
+    seed: u64,
+    directed: bool,
+) -> Result<(), RewireError> {
+    if number_of_swaps == 0 {
+        return Ok(());
+    }
+    if edges.len() < 2 {
+        return Err(RewireError::TooFewEdges { edges: edges.len() });
+    }
+    let normalize = |(source, destination): (usize, usize)| {
+        if directed || source < destination { (source, destination) } else { (destination, source) }
+    };
+
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(seed));
+    let mut edge_set: HashSet<(usize, usize)> = edges.iter().copied().collect();
+    let max_attempts = number_of_swaps.saturating_mul(ATTEMPTS_PER_SWAP);
+    let mut performed = 0;
+    let mut attempts = 0;
+    while performed < number_of_swaps {
+        if attempts == max_attempts {
+            return Err(RewireError::SwapAttemptsExceeded {
+                requested: number_of_swaps,
+                performed,
+                attempts,
+            });
+        }
+        attempts += 1;
+
+        let first = (rng.next().unwrap() as usize) % edges.len();
+        let second = (rng.next().unwrap() as usize) % edges.len();
+        if first == second {
+            continue;
+        }
+        let (a, b) = edges[first];
+        let (mut c, mut d) = edges[second];
+        // Undirected edges have no orientation: both pairings are possible.
+        if !directed && rng.next().unwrap() & 1 == 1 {
+            core::mem::swap(&mut c, &mut d);
+        }
+        let (left, right) = (normalize((a, d)), normalize((c, b)));
+        if a == d || c == b || edge_set.contains(&left) || edge_set.contains(&right) {
+            continue;
+        }
+
+        edge_set.remove(&edges[first]);
+        edge_set.remove(&edges[second]);
+        edge_set.insert(left);
+        edge_set.insert(right);
+        edges[first] = left;
+        edges[second] = right;
+        performed += 1;
+    }
+    Ok(())
+}
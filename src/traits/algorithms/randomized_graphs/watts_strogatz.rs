@@ -9,7 +9,8 @@ use crate::impls::{CSR2D, SymmetricCSR2D};
 /// Generates a Watts-Strogatz small-world graph.
 ///
 /// Starts with a ring lattice of `n` vertices each connected to `k` nearest
-/// neighbors, then rewires each edge with probability `beta`.
+/// neighbors, then rewires each edge with probability `beta`. The same seed
+/// always yields the same graph.
 ///
 /// # Panics
 /// Panics if `k` is odd, `k < 2`, or `n <= k`.
@@ -27,19 +28,20 @@ pub fn watts_strogatz(
 
     let mut rng = XorShift64::from(XorShift64::normalize_seed(seed));
 
-    // Use a flat boolean adjacency matrix for O(1) edge lookup.
-    let mut adj = alloc::vec![false; n * n];
+    // Neighbor lists keep the memory linear in the number of edges, as
+    // vertices only hold about `k` neighbors.
+    let mut adj: Vec<Vec<usize>> = alloc::vec![Vec::with_capacity(k); n];
 
-    let set_edge = |adj: &mut Vec<bool>, u: usize, v: usize| {
-        adj[u * n + v] = true;
-        adj[v * n + u] = true;
+    let set_edge = |adj: &mut [Vec<usize>], u: usize, v: usize| {
+        adj[u].push(v);
+        adj[v].push(u);
     };
 
-    let has_edge = |adj: &[bool], u: usize, v: usize| -> bool { adj[u * n + v] };
+    let has_edge = |adj: &[Vec<usize>], u: usize, v: usize| -> bool { adj[u].contains(&v) };
 
-    let remove_edge = |adj: &mut Vec<bool>, u: usize, v: usize| {
-        adj[u * n + v] = false;
-        adj[v * n + u] = false;
+    let remove_edge = |adj: &mut [Vec<usize>], u: usize, v: usize| {
+        adj[u].retain(|&w| w != v);
+        adj[v].retain(|&w| w != u);
     };
 
     // Build ring lattice: connect each vertex to k/2 neighbors on each side.
@@ -76,15 +78,13 @@ pub fn watts_strogatz(
         }
     }
 
-    // Collect edges from adjacency matrix.
-    let mut edges = Vec::new();
-    for u in 0..n {
-        for v in (u + 1)..n {
-            if has_edge(&adj, u, v) {
-                edges.push((u, v));
-            }
-        }
-    }
+    // Collect the upper-triangular edges from the neighbor lists.
+    let mut edges: Vec<(usize, usize)> = adj
+        .iter()
+        .enumerate()
+        .flat_map(|(u, neighbors)| neighbors.iter().filter(move |&&v| v > u).map(move |&v| (u, v)))
+        .collect();
+    edges.sort_unstable();
 
     build_symmetric(n, edges)
 }
@@ -4,6 +4,7 @@ use alloc::vec::Vec;
 use num_traits::AsPrimitive;
 
 use crate::{
+    impls::ValuedCSR2D,
     prelude::information_content::InformationContentError,
     traits::{
         InformationContent, MonoplexMonopartiteGraph, ScalarSimilarity,
@@ -35,6 +36,67 @@ impl<'graph, G: ?Sized + MonoplexMonopartiteGraph> From<InformationContentResult
     }
 }
 
+impl<G: ?Sized + MonoplexMonopartiteGraph> ResnikResult<'_, G> {
+    /// Returns the Resnik similarities of all pairs of distinct nodes which
+    /// are at least `threshold`, as a square sparse matrix indexed by node.
+    ///
+    /// The ancestor sets of the nodes are computed once, instead of searching
+    /// the graph from every root for each pair. Only pairs of nodes sharing a
+    /// common ancestor are compared, so other pairs are never emitted,
+    /// whatever the threshold. The returned matrix is symmetric, and its
+    /// values match those of `similarity`.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - The minimum similarity of the emitted pairs.
+    ///
+    /// # Complexity
+    ///
+    /// O(V log V + Σ |D(v)|·(deg(v) + |A(v)|)) time, where `D(v)` and `A(v)`
+    /// are the descendants and ancestors of node `v`, and O(Σ |D(v)|) space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{
+    ///     impls::{SortedVec, SquareCSR2D},
+    ///     prelude::*,
+    ///     traits::{EdgesBuilder, ScalarSimilarity, VocabularyBuilder},
+    /// };
+    ///
+    /// let edges: Vec<(usize, usize)> = vec![(0, 1), (0, 2), (1, 3), (1, 4)];
+    /// let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+    ///     .expected_number_of_symbols(5)
+    ///     .symbols((0..5).enumerate())
+    ///     .build()
+    ///     .unwrap();
+    /// let edges: SquareCSR2D<_> = DiEdgesBuilder::default()
+    ///     .expected_number_of_edges(edges.len())
+    ///     .expected_shape(nodes.len())
+    ///     .edges(edges.into_iter())
+    ///     .build()
+    ///     .unwrap();
+    /// let graph: DiGraph<usize> = DiGraph::from((nodes, edges));
+    ///
+    /// let resnik = graph.resnik(&[1, 1, 1, 1, 1]).unwrap();
+    /// // Only the pairs below node 1 share an ancestor more informative than the root.
+    /// let similarities = resnik.pairwise_similarities(0.1);
+    /// assert_eq!(
+    ///     SparseMatrix::sparse_coordinates(&similarities).collect::<Vec<_>>(),
+    ///     vec![(1, 3), (1, 4), (3, 1), (3, 4), (4, 1), (4, 3)]
+    /// );
+    /// for ((left, right), value) in
+    ///     SparseMatrix::sparse_coordinates(&similarities).zip(similarities.sparse_values())
+    /// {
+    ///     assert!((value - resnik.similarity(&left, &right)).abs() < 1e-12);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn pairwise_similarities(&self, threshold: f64) -> ValuedCSR2D<usize, usize, usize, f64> {
+        self.0.thresholded_common_ancestors(threshold, |_, _, resnik| resnik)
+    }
+}
+
 impl<G> ScalarSimilarity<G::NodeId, G::NodeId> for ResnikResult<'_, G>
 where
     G: MonoplexMonopartiteGraph,
@@ -0,0 +1,289 @@
+//! Submodule providing the `RichClub` trait, measuring how densely the hubs
+//! of an undirected graph are connected to each other.
+//!
+//! The rich-club coefficient φ(k) is the density of the subgraph induced by
+//! the nodes of degree larger than k: with N the number of such nodes and E
+//! the number of edges among them, φ(k) = 2E / (N (N − 1)). As high-degree
+//! nodes are more likely to be connected by chance alone, the coefficient is
+//! normalized by its mean over random graphs drawn from the configuration
+//! model with the same degree sequence: a normalized coefficient larger than
+//! one reveals an actual rich club. Self-loops are ignored, both as edges and
+//! in the degrees.
+
+use alloc::{vec, vec::Vec};
+
+use num_traits::AsPrimitive;
+
+use crate::traits::{MonopartiteGraph, UndirectedMonopartiteMonoplexGraph};
+
+/// Configuration of the normalized rich-club coefficients.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RichClubConfig {
+    /// Number of random graphs averaged by the null model. Default: `10`.
+    pub samples: usize,
+    /// Random seed of the null model. Default: `42`.
+    pub seed: u64,
+}
+
+impl Default for RichClubConfig {
+    #[inline]
+    fn default() -> Self {
+        Self { samples: 10, seed: 42 }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+/// Error enumeration for the normalized rich-club coefficients.
+pub enum RichClubError {
+    /// The number of random graphs of the null model is zero.
+    #[error("The number of null model samples must be positive.")]
+    ZeroSamples,
+}
+
+impl From<RichClubError>
+    for crate::errors::monopartite_graph_error::algorithms::MonopartiteAlgorithmError
+{
+    #[inline]
+    fn from(error: RichClubError) -> Self {
+        Self::RichClubError(error)
+    }
+}
+
+impl<G: MonopartiteGraph> From<RichClubError> for crate::errors::MonopartiteError<G> {
+    #[inline]
+    fn from(error: RichClubError) -> Self {
+        Self::AlgorithmError(error.into())
+    }
+}
+
+/// Trait providing the rich-club coefficients of undirected graphs.
+pub trait RichClub: UndirectedMonopartiteMonoplexGraph
+where
+    Self::NodeId: AsPrimitive<usize>,
+{
+    /// Returns the rich-club coefficient φ(k), the density of the subgraph
+    /// induced by the nodes of degree larger than `k`.
+    ///
+    /// The coefficient is undefined, and `None` is returned, when fewer than
+    /// two nodes have a degree larger than `k`.
+    ///
+    /// # Arguments
+    ///
+    /// * `k`: The degree the nodes of the club must exceed.
+    ///
+    /// # Complexity
+    ///
+    /// O(V + E) time and O(V) space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{
+    ///     impls::{CSR2D, SortedVec, SymmetricCSR2D},
+    ///     prelude::*,
+    ///     traits::{EdgesBuilder, RichClub, VocabularyBuilder},
+    /// };
+    ///
+    /// let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+    ///     .expected_number_of_symbols(5)
+    ///     .symbols((0..5).enumerate())
+    ///     .build()
+    ///     .unwrap();
+    /// // A triangle of hubs, two of which hold a leaf.
+    /// let edges: SymmetricCSR2D<CSR2D<usize, usize, usize>> = UndiEdgesBuilder::default()
+    ///     .expected_number_of_edges(5)
+    ///     .expected_shape(5)
+    ///     .edges(vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 4)].into_iter())
+    ///     .build()
+    ///     .unwrap();
+    /// let graph: UndiGraph<usize> = UndiGraph::from((nodes, edges));
+    ///
+    /// assert_eq!(graph.rich_club(0), Some(0.5));
+    /// assert_eq!(graph.rich_club(1), Some(1.0));
+    /// assert_eq!(graph.rich_club(2), Some(1.0));
+    /// assert_eq!(graph.rich_club(3), None);
+    /// ```
+    #[inline]
+    fn rich_club(&self, k: usize) -> Option<f64> {
+        let degrees = loopless_degrees(self);
+        let mut members = 0_usize;
+        let mut edges = 0_usize;
+        for node in self.node_ids() {
+            if degrees[node.as_()] <= k {
+                continue;
+            }
+            members += 1;
+            edges += self
+                .neighbors(node)
+                .filter(|&neighbor| neighbor != node && degrees[neighbor.as_()] > k)
+                .count();
+        }
+        // Every edge of the club was counted from both of its endpoints.
+        density(edges / 2, members)
+    }
+
+    /// Returns the rich-club curve, whose entry k is the coefficient φ(k)
+    /// returned by [`RichClub::rich_club`].
+    ///
+    /// The curve stops before the largest degree, from which no node is left
+    /// in the club, and its last entries are `None` when fewer than two
+    /// nodes have the largest degrees.
+    ///
+    /// # Complexity
+    ///
+    /// O(V + E) time and O(V) space.
+    #[inline]
+    fn rich_club_curve(&self) -> Vec<Option<f64>> {
+        let degrees = loopless_degrees(self);
+        rich_club_curve_of(
+            &degrees,
+            loopless_edges(self)
+                .map(|(source, destination)| degrees[source].min(degrees[destination])),
+        )
+    }
+
+    #[cfg(any(feature = "std", feature = "hashbrown"))]
+    /// Returns the normalized rich-club curve, whose entry k is the ratio of
+    /// the coefficient φ(k) to its mean over random graphs with the same
+    /// degree sequence.
+    ///
+    /// The random graphs are drawn from the degree sequence of the graph by
+    /// the [`configuration_model`](crate::traits::algorithms::randomized_graphs::configuration_model),
+    /// and the clubs of every random graph gather the nodes whose degree in
+    /// the graph is larger than k. As the model erases the self-loops and
+    /// the repeated edges its pairings produce, which mostly join hubs, the
+    /// random graphs slightly underestimate the density of the clubs of the
+    /// largest degrees. Rejecting these pairings instead, as the
+    /// [`simple_configuration_model`](crate::traits::algorithms::randomized_graphs::simple_configuration_model)
+    /// does, almost never succeeds beyond the sparsest degree sequences.
+    /// An entry is `None` where the coefficient is undefined, or where no
+    /// random graph has an edge among the nodes of degree larger than k.
+    ///
+    /// # Arguments
+    ///
+    /// * `config`: The number of random graphs and their seed.
+    ///
+    /// # Errors
+    ///
+    /// * [`RichClubError::ZeroSamples`] if no random graph is requested.
+    ///
+    /// # Complexity
+    ///
+    /// O(S · (V + E) log E) time and O(V + E) space, with S the number of
+    /// random graphs.
+    #[inline]
+    fn normalized_rich_club_curve(
+        &self,
+        config: &RichClubConfig,
+    ) -> Result<Vec<Option<f64>>, RichClubError> {
+        use crate::traits::{
+            SparseMatrix,
+            algorithms::randomized_graphs::{XorShift64, configuration_model},
+        };
+
+        if config.samples == 0 {
+            return Err(RichClubError::ZeroSamples);
+        }
+        let degrees = loopless_degrees(self);
+        let curve = self.rich_club_curve();
+        let mut null_curve = vec![0.0; curve.len()];
+        let mut rng = XorShift64::from(XorShift64::normalize_seed(config.seed));
+        for _ in 0..config.samples {
+            let seed = rng.next().unwrap_or_else(|| unreachable!("The generator never ends."));
+            let random = configuration_model(seed, &degrees);
+            let random_curve = rich_club_curve_of(
+                &degrees,
+                SparseMatrix::sparse_coordinates(&random)
+                    .filter(|&(source, destination)| source < destination)
+                    .map(|(source, destination)| degrees[source].min(degrees[destination])),
+            );
+            for (total, coefficient) in null_curve.iter_mut().zip(random_curve) {
+                *total += coefficient.unwrap_or(0.0);
+            }
+        }
+        Ok(curve
+            .into_iter()
+            .zip(null_curve)
+            .map(|(coefficient, total)| {
+                let coefficient = coefficient?;
+                (total > 0.0).then(|| coefficient * usize_to_f64(config.samples) / total)
+            })
+            .collect())
+    }
+}
+
+impl<G> RichClub for G
+where
+    G: UndirectedMonopartiteMonoplexGraph,
+    G::NodeId: AsPrimitive<usize>,
+{
+}
+
+/// Returns the degree of each node, ignoring its self-loops.
+fn loopless_degrees<G>(graph: &G) -> Vec<usize>
+where
+    G: UndirectedMonopartiteMonoplexGraph + ?Sized,
+    G::NodeId: AsPrimitive<usize>,
+{
+    graph
+        .node_ids()
+        .map(|node| graph.neighbors(node).filter(|&neighbor| neighbor != node).count())
+        .collect()
+}
+
+/// Returns the edges of the graph, once each with the smaller endpoint
+/// first, ignoring the self-loops.
+fn loopless_edges<G>(graph: &G) -> impl Iterator<Item = (usize, usize)> + '_
+where
+    G: UndirectedMonopartiteMonoplexGraph + ?Sized,
+    G::NodeId: AsPrimitive<usize>,
+{
+    graph.node_ids().flat_map(move |node| {
+        graph
+            .neighbors(node)
+            .filter(move |&neighbor| node < neighbor)
+            .map(move |neighbor| (node.as_(), neighbor.as_()))
+    })
+}
+
+/// Returns the rich-club curve of a graph from its degrees and from the
+/// smaller degree of the endpoints of each of its edges, as an edge belongs
+/// to the clubs of all the degrees below it.
+fn rich_club_curve_of(
+    degrees: &[usize],
+    edge_degrees: impl Iterator<Item = usize>,
+) -> Vec<Option<f64>> {
+    let maximal_degree = degrees.iter().copied().max().unwrap_or(0);
+    let mut members = vec![0_usize; maximal_degree + 1];
+    for &degree in degrees {
+        members[degree] += 1;
+    }
+    let mut edges = vec![0_usize; maximal_degree + 1];
+    for degree in edge_degrees {
+        edges[degree] += 1;
+    }
+    // Suffix sums from the largest degree, so that the entry k counts the
+    // nodes and edges whose degrees are larger than k.
+    let mut curve = vec![None; maximal_degree];
+    let (mut club_members, mut club_edges) = (members[maximal_degree], edges[maximal_degree]);
+    for k in (0..maximal_degree).rev() {
+        curve[k] = density(club_edges, club_members);
+        club_members += members[k];
+        club_edges += edges[k];
+    }
+    curve
+}
+
+/// Returns the density of a simple graph with the provided number of edges
+/// and nodes, if it has at least two nodes.
+fn density(edges: usize, nodes: usize) -> Option<f64> {
+    (nodes >= 2)
+        .then(|| 2.0 * usize_to_f64(edges) / (usize_to_f64(nodes) * usize_to_f64(nodes - 1)))
+}
+
+/// Converts a count into a floating point value.
+#[allow(clippy::cast_precision_loss)]
+#[inline]
+fn usize_to_f64(value: usize) -> f64 {
+    value as f64
+}
@@ -0,0 +1,267 @@
+//! Submodule providing the `Robustness` trait, simulating the removal of the
+//! nodes of an undirected graph and tracking how its largest connected
+//! component shrinks.
+//!
+//! The nodes are removed one at a time, in a random order to model failures
+//! or in decreasing order of degree or betweenness to model targeted
+//! attacks, and the robustness profile records the fraction of the nodes of
+//! the graph left in the largest connected component after each removal.
+//! Rather than recomputing the components after every removal, the profile
+//! is filled backwards: the removed nodes are added back in reverse order to
+//! a union-find forest, whose largest component can only grow, so that the
+//! whole profile costs a single pass over the edges.
+
+use alloc::{vec, vec::Vec};
+use core::cmp::Reverse;
+
+use num_traits::AsPrimitive;
+
+use super::{
+    node_ordering::{BetweennessCentralityScorer, DescendingScoreSorter, NodeSorter},
+    randomized_graphs::XorShift64,
+};
+use crate::{
+    impls::DisjointSet,
+    traits::{MonopartiteGraph, UndirectedMonopartiteMonoplexGraph},
+};
+
+/// Order in which the nodes are removed by
+/// [`Robustness::robustness_profile`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RemovalOrder {
+    /// All the nodes, in a uniformly random order drawn from the seed.
+    Random {
+        /// Random seed of the order.
+        seed: u64,
+    },
+    /// All the nodes, by decreasing degree in the original graph, ties being
+    /// broken towards the smallest node.
+    Degree,
+    /// All the nodes, by decreasing betweenness in the original graph as
+    /// scored by [`BetweennessCentralityScorer`], ties being broken towards
+    /// the smallest node.
+    Betweenness,
+    /// The provided distinct nodes, in the provided order.
+    Custom(Vec<usize>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+/// Error enumeration for the robustness profile.
+pub enum RobustnessError {
+    /// A node of the custom removal order is not a node of the graph.
+    #[error(
+        "The removal order contains the node {node}, but the graph has {number_of_nodes} nodes."
+    )]
+    UnknownNode {
+        /// The unknown node.
+        node: usize,
+        /// The number of nodes of the graph.
+        number_of_nodes: usize,
+    },
+    /// A node appears more than once in the custom removal order.
+    #[error("The removal order contains the node {0} more than once.")]
+    DuplicateNode(usize),
+}
+
+impl From<RobustnessError>
+    for crate::errors::monopartite_graph_error::algorithms::MonopartiteAlgorithmError
+{
+    #[inline]
+    fn from(error: RobustnessError) -> Self {
+        Self::RobustnessError(error)
+    }
+}
+
+impl<G: MonopartiteGraph> From<RobustnessError> for crate::errors::MonopartiteError<G> {
+    #[inline]
+    fn from(error: RobustnessError) -> Self {
+        Self::AlgorithmError(error.into())
+    }
+}
+
+/// Trait providing the robustness profile of undirected graphs under node
+/// removals.
+pub trait Robustness: UndirectedMonopartiteMonoplexGraph + Sized
+where
+    Self::NodeId: AsPrimitive<usize>,
+{
+    /// Returns the fraction of the nodes of the graph in its largest
+    /// connected component after each node removal.
+    ///
+    /// The entry i of the profile is the size of the largest connected
+    /// component once the first i nodes of the order are removed, divided by
+    /// the number of nodes of the original graph: the profile thus starts
+    /// with the fraction of the intact graph and has one more entry than the
+    /// number of removed nodes, the last one being zero when all the nodes
+    /// are removed. The mean of the entries after each removal is the
+    /// robustness measure R of Schneider et al. The profile of a graph
+    /// without nodes is `[0.0]`.
+    ///
+    /// The degree and betweenness orders are computed once on the original
+    /// graph, not recomputed after each removal.
+    ///
+    /// # Arguments
+    ///
+    /// * `order`: The order in which the nodes are removed.
+    ///
+    /// # Errors
+    ///
+    /// * [`RobustnessError::UnknownNode`] if a node of a custom order is not a
+    ///   node of the graph.
+    /// * [`RobustnessError::DuplicateNode`] if a node appears more than once in
+    ///   a custom order.
+    ///
+    /// # Complexity
+    ///
+    /// O((V + E) · α(V)) time and O(V + E) space, plus the O(V · E) time of
+    /// the betweenness for the betweenness order and the O(V log V) time of
+    /// sorting for the degree order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{
+    ///     impls::{CSR2D, SortedVec, SymmetricCSR2D},
+    ///     prelude::*,
+    ///     traits::{EdgesBuilder, RemovalOrder, Robustness, VocabularyBuilder},
+    /// };
+    ///
+    /// let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+    ///     .expected_number_of_symbols(5)
+    ///     .symbols((0..5).enumerate())
+    ///     .build()
+    ///     .unwrap();
+    /// // A star, which falls apart as soon as its hub is removed.
+    /// let edges: SymmetricCSR2D<CSR2D<usize, usize, usize>> = UndiEdgesBuilder::default()
+    ///     .expected_number_of_edges(4)
+    ///     .expected_shape(5)
+    ///     .edges(vec![(0, 1), (0, 2), (0, 3), (0, 4)].into_iter())
+    ///     .build()
+    ///     .unwrap();
+    /// let star: UndiGraph<usize> = UndiGraph::from((nodes, edges));
+    ///
+    /// assert_eq!(
+    ///     star.robustness_profile(&RemovalOrder::Degree).unwrap(),
+    ///     vec![1.0, 0.2, 0.2, 0.2, 0.2, 0.0]
+    /// );
+    /// assert_eq!(
+    ///     star.robustness_profile(&RemovalOrder::Custom(vec![4, 3])).unwrap(),
+    ///     vec![1.0, 0.8, 0.6]
+    /// );
+    /// ```
+    #[inline]
+    fn robustness_profile(&self, order: &RemovalOrder) -> Result<Vec<f64>, RobustnessError> {
+        let number_of_nodes = self.number_of_nodes().as_();
+        let removals = removal_order(self, order)?;
+        let mut is_removed = vec![false; number_of_nodes];
+        for &node in &removals {
+            is_removed[node] = true;
+        }
+
+        // The removed nodes stay singletons of the forest until added back.
+        let node_ids: Vec<Self::NodeId> = self.node_ids().collect();
+        let mut forest: DisjointSet<usize> = DisjointSet::new(number_of_nodes);
+        let mut largest = usize::from(removals.len() < number_of_nodes);
+        for &node in &node_ids {
+            if is_removed[node.as_()] {
+                continue;
+            }
+            for neighbor in self.neighbors(node) {
+                if neighbor < node && !is_removed[neighbor.as_()] {
+                    forest.union(node.as_(), neighbor.as_());
+                    largest = largest.max(forest.set_size(node.as_()));
+                }
+            }
+        }
+
+        let fraction = |size: usize| {
+            if number_of_nodes == 0 {
+                0.0
+            } else {
+                usize_to_f64(size) / usize_to_f64(number_of_nodes)
+            }
+        };
+        let mut profile = vec![0.0; removals.len() + 1];
+        profile[removals.len()] = fraction(largest);
+        for (position, &node) in removals.iter().enumerate().rev() {
+            is_removed[node] = false;
+            largest = largest.max(1);
+            for neighbor in self.neighbors(node_ids[node]) {
+                if !is_removed[neighbor.as_()] {
+                    forest.union(node, neighbor.as_());
+                    largest = largest.max(forest.set_size(node));
+                }
+            }
+            profile[position] = fraction(largest);
+        }
+        Ok(profile)
+    }
+}
+
+impl<G> Robustness for G
+where
+    G: UndirectedMonopartiteMonoplexGraph,
+    G::NodeId: AsPrimitive<usize>,
+{
+}
+
+/// Returns the nodes of the graph in the removal order.
+fn removal_order<G>(graph: &G, order: &RemovalOrder) -> Result<Vec<usize>, RobustnessError>
+where
+    G: UndirectedMonopartiteMonoplexGraph,
+    G::NodeId: AsPrimitive<usize>,
+{
+    let number_of_nodes = graph.number_of_nodes().as_();
+    Ok(match order {
+        RemovalOrder::Random { seed } => {
+            let mut rng = XorShift64::from(XorShift64::normalize_seed(*seed));
+            let mut nodes: Vec<usize> = (0..number_of_nodes).collect();
+            for i in (1..number_of_nodes).rev() {
+                let raw = rng.next().unwrap_or_else(|| unreachable!("The generator never ends."));
+                let bound = u64::try_from(i + 1)
+                    .unwrap_or_else(|_| unreachable!("Node counts fit in 64 bits."));
+                let j = usize::try_from(raw % bound)
+                    .unwrap_or_else(|_| unreachable!("The index is at most a node."));
+                nodes.swap(i, j);
+            }
+            nodes
+        }
+        RemovalOrder::Degree => {
+            let mut nodes: Vec<(usize, usize)> = graph
+                .node_ids()
+                .map(|node| {
+                    let degree = graph.neighbors(node).filter(|&neighbor| neighbor != node).count();
+                    (node.as_(), degree)
+                })
+                .collect();
+            nodes.sort_by_key(|&(_, degree)| Reverse(degree));
+            nodes.into_iter().map(|(node, _)| node).collect()
+        }
+        RemovalOrder::Betweenness => {
+            DescendingScoreSorter::new(BetweennessCentralityScorer::new(false, false))
+                .sort_nodes(graph)
+                .into_iter()
+                .map(AsPrimitive::as_)
+                .collect()
+        }
+        RemovalOrder::Custom(nodes) => {
+            let mut is_listed = vec![false; number_of_nodes];
+            for &node in nodes {
+                if node >= number_of_nodes {
+                    return Err(RobustnessError::UnknownNode { node, number_of_nodes });
+                }
+                if core::mem::replace(&mut is_listed[node], true) {
+                    return Err(RobustnessError::DuplicateNode(node));
+                }
+            }
+            nodes.clone()
+        }
+    })
+}
+
+/// Converts a count into a floating point value.
+#[allow(clippy::cast_precision_loss)]
+#[inline]
+fn usize_to_f64(value: usize) -> f64 {
+    value as f64
+}
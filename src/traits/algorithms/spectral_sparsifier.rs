@@ -0,0 +1,145 @@
+//! Submodule providing the `SpectralSparsifier` trait, reducing a weighted
+//! undirected graph to a spectrally similar graph with fewer edges.
+//!
+//! Following Spielman and Srivastava, every edge is sampled with a
+//! probability proportional to its importance, i.e. to its weight times the
+//! effective resistance between its endpoints, and the sampled edges are
+//! reweighted by the inverse of their probability, so that the Laplacian of
+//! the sparsifier equals the original one in expectation. As in the
+//! `sparsify` routine of Laplacians.jl, the edges are sampled independently
+//! with probability min(1, C · ln V · importance / ε²), so that bridges and
+//! other critical edges are always kept with their original weight. The
+//! effective resistances are approximated with [`EffectiveResistance`].
+
+use alloc::vec::Vec;
+
+use num_traits::{AsPrimitive, ToPrimitive};
+use rand::{Rng, RngCore, SeedableRng, rngs::SmallRng};
+
+use crate::{
+    impls::{SymmetricCSR2D, ValuedCSR2D},
+    traits::{EffectiveResistance, EffectiveResistanceConfig, EffectiveResistanceError},
+};
+
+/// Oversampling constant C of the sampling probabilities.
+const SAMPLING_CONSTANT: f64 = 4.0;
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+/// Error enumeration for the spectral sparsification.
+pub enum SpectralSparsifierError {
+    /// The effective resistances could not be approximated.
+    #[error(transparent)]
+    EffectiveResistance(#[from] EffectiveResistanceError),
+    /// The approximation parameter is not in (0, 1].
+    #[error("The approximation parameter must be in (0, 1], but received {0}.")]
+    InvalidEpsilon(f64),
+}
+
+/// Trait providing the spectral sparsification of the weighted undirected
+/// graph represented by a square and symmetric sparse matrix of finite and
+/// non-negative weights.
+pub trait SpectralSparsifier: EffectiveResistance
+where
+    Self::RowIndex: AsPrimitive<usize>,
+    Self::ColumnIndex: AsPrimitive<usize>,
+    Self::Value: ToPrimitive,
+{
+    /// Returns a reweighted subgraph whose Laplacian quadratic form, and thus
+    /// the weight of every cut, approximates the one of the graph.
+    ///
+    /// With high probability, xᵀL̃x lies within a factor 1 ± ε of xᵀLx for
+    /// every vector x, and the sparsifier has O(V log V / ε²) edges, however
+    /// dense the graph. Self-loops, which do not contribute to the
+    /// Laplacian, are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `epsilon`: The approximation parameter, in (0, 1]. Smaller values keep
+    ///   more edges.
+    /// * `seed`: The random seed of the resistance sketch and of the edge
+    ///   sampling.
+    ///
+    /// # Errors
+    ///
+    /// * [`SpectralSparsifierError::InvalidEpsilon`] if `epsilon` is not in (0,
+    ///   1].
+    /// * [`SpectralSparsifierError::EffectiveResistance`] if the matrix is not
+    ///   a valid weighted adjacency matrix or the resistances could not be
+    ///   approximated.
+    ///
+    /// # Complexity
+    ///
+    /// Dominated by the approximation of the effective resistances, see
+    /// [`EffectiveResistance::effective_resistances`], followed by O(V + E)
+    /// time for the sampling.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{
+    ///     impls::{SymmetricCSR2D, ValuedCSR2D},
+    ///     prelude::*,
+    ///     traits::SpectralSparsifier,
+    /// };
+    ///
+    /// // The complete graph on 100 nodes with unit weights.
+    /// let entries: Vec<(usize, usize, f64)> = (0..100)
+    ///     .flat_map(|source| (source + 1..100).map(move |destination| (source, destination, 1.0)))
+    ///     .collect();
+    /// let graph: SymmetricCSR2D<ValuedCSR2D<usize, usize, usize, f64>> =
+    ///     SymmetricCSR2D::from_sorted_upper_triangular_entries(100, entries).unwrap();
+    ///
+    /// let sparsifier = graph.sparsify(1.0, 42).unwrap();
+    /// assert!(sparsifier.number_of_defined_values() < graph.number_of_defined_values() / 2);
+    /// ```
+    #[inline]
+    fn sparsify(
+        &self,
+        epsilon: f64,
+        seed: u64,
+    ) -> Result<SymmetricCSR2D<ValuedCSR2D<usize, usize, usize, f64>>, SpectralSparsifierError>
+    {
+        if !(epsilon > 0.0 && epsilon <= 1.0) {
+            return Err(SpectralSparsifierError::InvalidEpsilon(epsilon));
+        }
+        let mut rng = SmallRng::seed_from_u64(seed);
+        let resistances = self.effective_resistances(&EffectiveResistanceConfig {
+            seed: rng.next_u64(),
+            ..EffectiveResistanceConfig::default()
+        })?;
+        let number_of_nodes = resistances.number_of_nodes();
+        let oversampling = if number_of_nodes > 1 {
+            let number_of_nodes: f64 = number_of_nodes.as_();
+            SAMPLING_CONSTANT * number_of_nodes.ln() / (epsilon * epsilon)
+        } else {
+            0.0
+        };
+
+        let entries: Vec<(usize, usize, f64)> = resistances
+            .edges()
+            .iter()
+            .zip(resistances.edge_importances())
+            .filter_map(|(&(source, destination, weight), (_, _, importance))| {
+                let probability = oversampling * importance;
+                if probability >= 1.0 {
+                    Some((source, destination, weight))
+                } else if rng.gen_bool(probability) {
+                    Some((source, destination, weight / probability))
+                } else {
+                    None
+                }
+            })
+            .collect();
+        Ok(SymmetricCSR2D::from_sorted_upper_triangular_entries(number_of_nodes, entries)
+            .expect("The edges of the graph are sorted and upper triangular."))
+    }
+}
+
+impl<M> SpectralSparsifier for M
+where
+    M: EffectiveResistance,
+    M::RowIndex: AsPrimitive<usize>,
+    M::ColumnIndex: AsPrimitive<usize>,
+    M::Value: ToPrimitive,
+{
+}
@@ -0,0 +1,384 @@
+//! Submodule providing the `SteinerTree` trait, connecting a set of terminal
+//! nodes of a weighted undirected graph through a tree of small total weight.
+//!
+//! The heuristic of Kou, Markowsky and Berman runs one Dijkstra search from
+//! every terminal to build the shortest-path metric closure over the
+//! terminals, takes a minimum spanning tree of that closure, expands each of
+//! its edges into the corresponding shortest path, takes a minimum spanning
+//! tree of the union of these paths and finally prunes the leaves that are
+//! not terminals. The total weight of the result is at most `2 (1 - 1/L)`
+//! times the optimum, where `L` is the number of leaves of an optimal tree.
+use alloc::{collections::BinaryHeap, vec, vec::Vec};
+
+use num_traits::{AsPrimitive, Zero};
+
+use super::dijkstra::{Dijkstra, DijkstraError, QueueEntry, ShortestPaths};
+use crate::traits::{
+    Finite, Number, PositiveInteger, SparseValuedMatrix2D, TotalOrd, TryFromUsize,
+};
+
+/// Adjacency lists of `(neighbour, weight)` pairs, indexed by node.
+type WeightedRows<V> = Vec<Vec<(usize, V)>>;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+/// Errors that can occur while computing a Steiner tree.
+pub enum SteinerTreeError {
+    /// The input matrix is not square.
+    #[error("The matrix must be square, but has {rows} rows and {columns} columns.")]
+    NonSquareMatrix {
+        /// Number of rows.
+        rows: usize,
+        /// Number of columns.
+        columns: usize,
+    },
+    /// The matrix is not symmetric.
+    #[error(
+        "The adjacency matrix is not symmetric: entry ({source_id}, {destination_id}) has no matching reverse entry."
+    )]
+    NonSymmetricWeight {
+        /// Source node identifier.
+        source_id: usize,
+        /// Destination node identifier.
+        destination_id: usize,
+    },
+    /// A terminal is not a node of the graph.
+    #[error(
+        "The terminal {terminal_id} is not a node of the graph, which has {number_of_nodes} nodes."
+    )]
+    UnknownTerminal {
+        /// Terminal node identifier.
+        terminal_id: usize,
+        /// Number of nodes of the graph.
+        number_of_nodes: usize,
+    },
+    /// Two terminals lie in different connected components.
+    #[error("The terminals {source_id} and {destination_id} are not connected.")]
+    DisconnectedTerminals {
+        /// First terminal node identifier.
+        source_id: usize,
+        /// Second terminal node identifier.
+        destination_id: usize,
+    },
+    /// The shortest-path searches failed on an invalid weight.
+    #[error(transparent)]
+    Dijkstra(#[from] DijkstraError),
+}
+
+/// Tree connecting terminal nodes, as returned by
+/// [`SteinerTree::steiner_tree`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SteinerSubgraph<V, I> {
+    /// The edges of the tree, each with its smaller endpoint first, sorted.
+    edges: Vec<(I, I, V)>,
+    /// The sum of the weights of the edges.
+    total_weight: V,
+}
+
+impl<V: Copy, I: PositiveInteger> SteinerSubgraph<V, I> {
+    /// Returns the edges of the tree, each as `(u, v, weight)` with `u < v`,
+    /// sorted by endpoints.
+    #[inline]
+    #[must_use]
+    pub fn edges(&self) -> &[(I, I, V)] {
+        &self.edges
+    }
+
+    /// Returns the sum of the weights of the edges of the tree.
+    #[inline]
+    #[must_use]
+    pub fn total_weight(&self) -> V {
+        self.total_weight
+    }
+
+    /// Returns the sorted nodes of the tree, terminals and Steiner nodes
+    /// alike.
+    ///
+    /// An edgeless tree, connecting at most one terminal, has no nodes.
+    #[must_use]
+    pub fn nodes(&self) -> Vec<I> {
+        let mut nodes: Vec<I> = self.edges.iter().flat_map(|&(u, v, _)| [u, v]).collect();
+        nodes.sort_unstable();
+        nodes.dedup();
+        nodes
+    }
+}
+
+/// Trait providing a 2-approximate Steiner tree of the weighted undirected
+/// graph represented by a square and symmetric sparse matrix of finite and
+/// non-negative weights.
+///
+/// # Reference
+///
+/// Kou, L., Markowsky, G., & Berman, L. (1981). [A fast algorithm for Steiner trees](https://doi.org/10.1007/BF00288961).
+/// *Acta Informatica*, 15(2), 141-145.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{
+///     impls::{SymmetricCSR2D, ValuedCSR2D},
+///     prelude::*,
+///     traits::SteinerTree,
+/// };
+///
+/// // A star through the hub 4 is cheaper than the outer ring.
+/// let graph: SymmetricCSR2D<ValuedCSR2D<usize, usize, usize, f64>> =
+///     SymmetricCSR2D::from_sorted_upper_triangular_entries(
+///         5,
+///         vec![(0, 1, 3.0), (0, 2, 3.0), (0, 4, 1.0), (1, 2, 3.0), (1, 4, 1.0), (2, 4, 1.0)],
+///     )
+///     .unwrap();
+///
+/// let tree = graph.steiner_tree(&[0, 1, 2]).unwrap();
+/// assert_eq!(tree.edges(), &[(0, 4, 1.0), (1, 4, 1.0), (2, 4, 1.0)]);
+/// assert_eq!(tree.total_weight(), 3.0);
+/// assert_eq!(tree.nodes(), vec![0, 1, 2, 4]);
+/// ```
+pub trait SteinerTree: SparseValuedMatrix2D + Sized
+where
+    Self::Value: Number + Finite + TotalOrd,
+    Self::RowIndex: PositiveInteger,
+    Self::ColumnIndex: AsPrimitive<usize> + Into<Self::RowIndex>,
+{
+    /// Returns a tree connecting the terminals whose total weight is at most
+    /// twice the one of a minimum Steiner tree.
+    ///
+    /// Repeated terminals are ignored, and at most one distinct terminal
+    /// yields an edgeless tree.
+    ///
+    /// # Arguments
+    ///
+    /// * `terminals` - The nodes the tree must connect.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when:
+    /// - the matrix is not square or not symmetric;
+    /// - a terminal is not a node of the graph;
+    /// - two terminals are not connected;
+    /// - a weight reached from a terminal is not finite or is negative, or a
+    ///   path distance becomes non-finite.
+    ///
+    /// # Complexity
+    ///
+    /// O(T (V + E) log V + T²) time and O(T V + E) space, for T terminals.
+    #[inline]
+    fn steiner_tree(
+        &self,
+        terminals: &[Self::RowIndex],
+    ) -> Result<SteinerSubgraph<Self::Value, Self::RowIndex>, SteinerTreeError> {
+        let rows = weighted_rows(self)?;
+        let order = rows.len();
+        let mut terminals = terminals.to_vec();
+        terminals.sort_unstable();
+        terminals.dedup();
+        if let Some(&terminal) = terminals.iter().find(|terminal| terminal.as_() >= order) {
+            return Err(SteinerTreeError::UnknownTerminal {
+                terminal_id: terminal.as_(),
+                number_of_nodes: order,
+            });
+        }
+
+        let searches = terminals
+            .iter()
+            .map(|&terminal| self.dijkstra(terminal))
+            .collect::<Result<Vec<_>, _>>()?;
+        let closure_tree = closure_spanning_tree(&terminals, &searches)?;
+
+        // Union of the shortest paths behind the edges of the closure tree.
+        let mut in_union = vec![false; order];
+        let mut union_adjacency: WeightedRows<Self::Value> = vec![Vec::new(); order];
+        for (search, destination) in closure_tree {
+            let path = searches[search].path_to(terminals[destination]).unwrap_or_else(|| {
+                unreachable!("The closure tree only joins connected terminals.")
+            });
+            for step in path.windows(2) {
+                let (source, destination) = (step[0].as_(), step[1].as_());
+                let weight = edge_weight(&rows, source, destination);
+                if !union_adjacency[source].iter().any(|&(node, _)| node == destination) {
+                    union_adjacency[source].push((destination, weight));
+                    union_adjacency[destination].push((source, weight));
+                }
+                in_union[source] = true;
+                in_union[destination] = true;
+            }
+        }
+
+        let mut tree_adjacency = union_spanning_tree(&union_adjacency, &in_union);
+        let mut is_terminal = vec![false; order];
+        for terminal in &terminals {
+            is_terminal[terminal.as_()] = true;
+        }
+        prune_steiner_leaves(&mut tree_adjacency, &is_terminal);
+
+        let mut edges = Vec::new();
+        let mut total_weight = Self::Value::zero();
+        for (source, neighbours) in tree_adjacency.iter().enumerate() {
+            for &destination in neighbours.iter().filter(|&&destination| source < destination) {
+                let weight = edge_weight(&rows, source, destination);
+                total_weight += weight;
+                edges.push((node_id::<Self>(source), node_id::<Self>(destination), weight));
+            }
+        }
+        edges.sort_unstable_by_key(|&(source, destination, _)| (source, destination));
+        Ok(SteinerSubgraph { edges, total_weight })
+    }
+}
+
+impl<M> SteinerTree for M
+where
+    M: SparseValuedMatrix2D + Sized,
+    M::Value: Number + Finite + TotalOrd,
+    M::RowIndex: PositiveInteger,
+    M::ColumnIndex: AsPrimitive<usize> + Into<M::RowIndex>,
+{
+}
+
+/// Returns the row identifier of a node index.
+fn node_id<M: SparseValuedMatrix2D>(node: usize) -> M::RowIndex
+where
+    M::RowIndex: PositiveInteger,
+{
+    M::RowIndex::try_from_usize(node)
+        .unwrap_or_else(|_| unreachable!("Node indices come from row identifiers."))
+}
+
+/// Returns the rows of the matrix as sorted `(column, weight)` pairs, after
+/// checking that the matrix is square and symmetric.
+fn weighted_rows<M>(matrix: &M) -> Result<WeightedRows<M::Value>, SteinerTreeError>
+where
+    M: SparseValuedMatrix2D,
+    M::Value: Number,
+    M::RowIndex: AsPrimitive<usize>,
+    M::ColumnIndex: AsPrimitive<usize>,
+{
+    let rows = matrix.number_of_rows().as_();
+    let columns = matrix.number_of_columns().as_();
+    if rows != columns {
+        return Err(SteinerTreeError::NonSquareMatrix { rows, columns });
+    }
+
+    let weighted_rows: WeightedRows<M::Value> = matrix
+        .rows_with_values()
+        .map(|(_, entries)| entries.map(|(column_id, weight)| (column_id.as_(), weight)).collect())
+        .collect();
+    for (source_id, weights) in weighted_rows.iter().enumerate() {
+        for &(destination_id, weight) in weights {
+            let reverse = weighted_rows[destination_id]
+                .binary_search_by_key(&source_id, |&(column, _)| column)
+                .map(|position| weighted_rows[destination_id][position].1);
+            if reverse != Ok(weight) {
+                return Err(SteinerTreeError::NonSymmetricWeight { source_id, destination_id });
+            }
+        }
+    }
+    Ok(weighted_rows)
+}
+
+/// Returns the weight of an edge of the matrix.
+fn edge_weight<V: Copy>(rows: &[Vec<(usize, V)>], source: usize, destination: usize) -> V {
+    let position = rows[source]
+        .binary_search_by_key(&destination, |&(column, _)| column)
+        .unwrap_or_else(|_| unreachable!("Shortest paths only follow edges of the matrix."));
+    rows[source][position].1
+}
+
+/// Returns the edges of a minimum spanning tree of the shortest-path metric
+/// closure over the terminals, each as the index of the search from one
+/// endpoint and the index of the other endpoint, with Prim's algorithm.
+fn closure_spanning_tree<V, I>(
+    terminals: &[I],
+    searches: &[ShortestPaths<V, I>],
+) -> Result<Vec<(usize, usize)>, SteinerTreeError>
+where
+    V: Copy + TotalOrd,
+    I: PositiveInteger,
+{
+    let mut in_tree = vec![false; terminals.len()];
+    // The cheapest known connection of each terminal to the tree, as the
+    // distance and the index of the search it comes from.
+    let mut best: Vec<Option<(V, usize)>> = vec![None; terminals.len()];
+    let mut edges = Vec::with_capacity(terminals.len().saturating_sub(1));
+    let mut current = 0;
+    for _ in 0..terminals.len() {
+        in_tree[current] = true;
+        for (other, terminal) in terminals.iter().enumerate() {
+            if in_tree[other] {
+                continue;
+            }
+            let Some(distance) = searches[current].distance(*terminal) else {
+                return Err(SteinerTreeError::DisconnectedTerminals {
+                    source_id: terminals[current].as_(),
+                    destination_id: terminal.as_(),
+                });
+            };
+            if best[other].is_none_or(|(known, _)| distance.total_cmp(&known).is_lt()) {
+                best[other] = Some((distance, current));
+            }
+        }
+        let next = (0..terminals.len()).filter(|&other| !in_tree[other]).min_by(|&left, &right| {
+            let (left_distance, _) = best[left].unwrap_or_else(|| unreachable!());
+            let (right_distance, _) = best[right].unwrap_or_else(|| unreachable!());
+            left_distance.total_cmp(&right_distance)
+        });
+        let Some(next) = next else {
+            break;
+        };
+        let (_, search) = best[next].unwrap_or_else(|| unreachable!());
+        edges.push((search, next));
+        current = next;
+    }
+    Ok(edges)
+}
+
+/// Returns the adjacency lists of a minimum spanning tree of the connected
+/// union of shortest paths, with Prim's algorithm.
+fn union_spanning_tree<V>(adjacency: &[Vec<(usize, V)>], in_union: &[bool]) -> Vec<Vec<usize>>
+where
+    V: Number + TotalOrd,
+{
+    let mut tree_adjacency = vec![Vec::new(); adjacency.len()];
+    let Some(root) = in_union.iter().position(|&node| node) else {
+        return tree_adjacency;
+    };
+    let mut in_tree = vec![false; adjacency.len()];
+    let mut best: Vec<Option<(V, usize)>> = vec![None; adjacency.len()];
+    let mut heap = BinaryHeap::new();
+    heap.push(QueueEntry { distance: V::zero(), node_id: root });
+    while let Some(QueueEntry { distance, node_id: node }) = heap.pop() {
+        if in_tree[node] || best[node].is_some_and(|(known, _)| distance.total_cmp(&known).is_gt())
+        {
+            continue;
+        }
+        in_tree[node] = true;
+        if let Some((_, parent)) = best[node] {
+            tree_adjacency[parent].push(node);
+            tree_adjacency[node].push(parent);
+        }
+        for &(neighbour, weight) in &adjacency[node] {
+            if !in_tree[neighbour]
+                && best[neighbour].is_none_or(|(known, _)| weight.total_cmp(&known).is_lt())
+            {
+                best[neighbour] = Some((weight, node));
+                heap.push(QueueEntry { distance: weight, node_id: neighbour });
+            }
+        }
+    }
+    tree_adjacency
+}
+
+/// Repeatedly removes the leaves of the tree which are not terminals.
+fn prune_steiner_leaves(tree_adjacency: &mut [Vec<usize>], is_terminal: &[bool]) {
+    let mut leaves: Vec<usize> = (0..tree_adjacency.len())
+        .filter(|&node| tree_adjacency[node].len() == 1 && !is_terminal[node])
+        .collect();
+    while let Some(leaf) = leaves.pop() {
+        let Some(neighbour) = tree_adjacency[leaf].pop() else {
+            continue;
+        };
+        tree_adjacency[neighbour].retain(|&node| node != leaf);
+        if tree_adjacency[neighbour].len() == 1 && !is_terminal[neighbour] {
+            leaves.push(neighbour);
+        }
+    }
+}
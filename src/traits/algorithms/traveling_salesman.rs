@@ -0,0 +1,288 @@
+//! Submodule providing the `TravelingSalesman` trait, computing short tours
+//! through every node of a complete weighted graph given as a dense
+//! symmetric distance matrix.
+//!
+//! The tour is first built greedily, by repeatedly travelling to the nearest
+//! unvisited node, and then improved by 2-opt moves: whenever replacing two
+//! edges `(a, b)` and `(c, d)` of the tour by `(a, c)` and `(b, d)` shortens
+//! it, the path between `b` and `c` is reversed. The improvement stops at a
+//! tour that no 2-opt move shortens.
+use alloc::{vec, vec::Vec};
+
+use num_traits::{AsPrimitive, ToPrimitive};
+
+use crate::traits::{DenseValuedMatrix2D, Finite, Number};
+
+/// Relative decrease below which a 2-opt move is not applied, so that the
+/// improvement terminates despite rounding errors.
+const IMPROVEMENT_TOLERANCE: f64 = 1e-12;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+/// Errors that can occur while computing a tour.
+pub enum TravelingSalesmanError {
+    /// The distance matrix is not square.
+    #[error("The distance matrix must be square, but has {rows} rows and {columns} columns.")]
+    NonSquareMatrix {
+        /// Number of rows.
+        rows: usize,
+        /// Number of columns.
+        columns: usize,
+    },
+    /// An off-diagonal distance is not finite or cannot be represented as
+    /// `f64`.
+    #[error("Found a non-finite distance at ({row}, {column}).")]
+    NonFiniteValue {
+        /// Row index.
+        row: usize,
+        /// Column index.
+        column: usize,
+    },
+    /// An off-diagonal distance is negative.
+    #[error("Found a negative distance at ({row}, {column}).")]
+    NegativeDistance {
+        /// Row index.
+        row: usize,
+        /// Column index.
+        column: usize,
+    },
+    /// The distance matrix is not symmetric.
+    #[error("The distance matrix is not symmetric at ({row}, {column}).")]
+    NonSymmetricMatrix {
+        /// Row index.
+        row: usize,
+        /// Column index.
+        column: usize,
+    },
+}
+
+/// Closed tour through every node, as returned by the
+/// [`TravelingSalesman`] methods.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tour {
+    /// The nodes in visiting order, starting from node 0.
+    nodes: Vec<usize>,
+    /// The length of the tour, including the edge back to the first node.
+    length: f64,
+}
+
+impl Tour {
+    /// Returns the nodes in visiting order, starting from node 0. The tour
+    /// closes with the edge from the last node back to the first one.
+    #[inline]
+    #[must_use]
+    pub fn nodes(&self) -> &[usize] {
+        &self.nodes
+    }
+
+    /// Returns the length of the tour, including the edge back to the first
+    /// node, or zero for tours of at most one node.
+    #[inline]
+    #[must_use]
+    pub fn length(&self) -> f64 {
+        self.length
+    }
+}
+
+/// Trait providing traveling salesman heuristics over the complete graph
+/// whose edge weights are the off-diagonal entries of a dense, square and
+/// symmetric matrix of finite and non-negative distances.
+///
+/// The diagonal is ignored. Orderings of samples by similarity are obtained
+/// by running the heuristics over their distance matrix.
+///
+/// # References
+///
+/// Rosenkrantz, D. J., Stearns, R. E., & Lewis, P. M. (1977). [An analysis of several heuristics for the traveling salesman problem](https://doi.org/10.1137/0206041).
+/// *SIAM Journal on Computing*, 6(3), 563-581.
+///
+/// Croes, G. A. (1958). [A method for solving traveling-salesman problems](https://doi.org/10.1287/opre.6.6.791).
+/// *Operations Research*, 6(6), 791-812.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{impls::VecMatrix2D, prelude::*};
+///
+/// // Four corners of a unit square, whose shortest tour is its perimeter.
+/// let diagonal = 2.0_f64.sqrt();
+/// let distances = VecMatrix2D::new(
+///     4,
+///     4,
+///     vec![
+///         0.0, diagonal, 1.0, 1.0, //
+///         diagonal, 0.0, 1.0, 1.0, //
+///         1.0, 1.0, 0.0, diagonal, //
+///         1.0, 1.0, diagonal, 0.0,
+///     ],
+/// );
+///
+/// let tour = distances.traveling_salesman_tour().unwrap();
+/// assert_eq!(tour.nodes(), &[0, 2, 1, 3]);
+/// assert!((tour.length() - 4.0).abs() < 1e-12);
+/// ```
+pub trait TravelingSalesman: DenseValuedMatrix2D + Sized
+where
+    Self::Value: Number + ToPrimitive + Finite,
+    Self::RowIndex: AsPrimitive<usize>,
+    Self::ColumnIndex: AsPrimitive<usize>,
+{
+    /// Returns the tour built by the nearest-neighbour heuristic from node
+    /// 0, ties going to the smallest node.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the matrix is not square or not symmetric, or if
+    /// an off-diagonal distance is not finite or is negative.
+    ///
+    /// # Complexity
+    ///
+    /// O(n²) time and space.
+    #[inline]
+    fn nearest_neighbor_tour(&self) -> Result<Tour, TravelingSalesmanError> {
+        let (distances, order) = read_distances(self)?;
+        let nodes = nearest_neighbor_order(&distances, order);
+        let length = tour_length(&distances, order, &nodes);
+        Ok(Tour { nodes, length })
+    }
+
+    /// Returns the nearest-neighbour tour improved by 2-opt moves until none
+    /// of them shortens it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the matrix is not square or not symmetric, or if
+    /// an off-diagonal distance is not finite or is negative.
+    ///
+    /// # Complexity
+    ///
+    /// O(n²) time per improving pass and O(n²) space. The number of passes
+    /// is small in practice, although not polynomially bounded.
+    #[inline]
+    fn traveling_salesman_tour(&self) -> Result<Tour, TravelingSalesmanError> {
+        let (distances, order) = read_distances(self)?;
+        let mut nodes = nearest_neighbor_order(&distances, order);
+        two_opt(&distances, order, &mut nodes);
+        let length = tour_length(&distances, order, &nodes);
+        Ok(Tour { nodes, length })
+    }
+}
+
+impl<M: DenseValuedMatrix2D> TravelingSalesman for M
+where
+    M::Value: Number + ToPrimitive + Finite,
+    M::RowIndex: AsPrimitive<usize>,
+    M::ColumnIndex: AsPrimitive<usize>,
+{
+}
+
+/// Returns the validated row-major distances with their order, the diagonal
+/// set to zero and the symmetric entries averaged.
+fn read_distances<M>(matrix: &M) -> Result<(Vec<f64>, usize), TravelingSalesmanError>
+where
+    M: DenseValuedMatrix2D,
+    M::Value: Number + ToPrimitive + Finite,
+    M::RowIndex: AsPrimitive<usize>,
+    M::ColumnIndex: AsPrimitive<usize>,
+{
+    let rows: usize = matrix.number_of_rows().as_();
+    let columns: usize = matrix.number_of_columns().as_();
+    if rows != columns {
+        return Err(TravelingSalesmanError::NonSquareMatrix { rows, columns });
+    }
+    let order = rows;
+
+    let mut distances = Vec::with_capacity(order * order);
+    for row_id in matrix.row_indices() {
+        let row = row_id.as_();
+        for (column_id, value) in matrix.column_indices().zip(matrix.row_values(row_id)) {
+            let column = column_id.as_();
+            if row == column {
+                distances.push(0.0);
+                continue;
+            }
+            let distance = value
+                .to_f64()
+                .filter(|distance| value.is_finite() && distance.is_finite())
+                .ok_or(TravelingSalesmanError::NonFiniteValue { row, column })?;
+            if distance < 0.0 {
+                return Err(TravelingSalesmanError::NegativeDistance { row, column });
+            }
+            distances.push(distance);
+        }
+    }
+
+    for row in 0..order {
+        for column in (row + 1)..order {
+            let upper = distances[row * order + column];
+            let lower = distances[column * order + row];
+            let scale = upper.max(lower).max(1.0);
+            if (upper - lower).abs() > 16.0 * f64::EPSILON * scale {
+                return Err(TravelingSalesmanError::NonSymmetricMatrix { row, column });
+            }
+            let average = f64::midpoint(upper, lower);
+            distances[row * order + column] = average;
+            distances[column * order + row] = average;
+        }
+    }
+    Ok((distances, order))
+}
+
+/// Returns the nodes in the order visited by the nearest-neighbour heuristic
+/// from node 0.
+fn nearest_neighbor_order(distances: &[f64], order: usize) -> Vec<usize> {
+    let mut nodes = Vec::with_capacity(order);
+    if order == 0 {
+        return nodes;
+    }
+    let mut visited = vec![false; order];
+    let mut current = 0;
+    visited[current] = true;
+    nodes.push(current);
+    for _ in 1..order {
+        let row = &distances[current * order..(current + 1) * order];
+        let next = (0..order)
+            .filter(|&node| !visited[node])
+            .min_by(|&left, &right| row[left].total_cmp(&row[right]))
+            .unwrap_or_else(|| unreachable!("Some node is left to visit."));
+        visited[next] = true;
+        nodes.push(next);
+        current = next;
+    }
+    nodes
+}
+
+/// Applies improving 2-opt moves to the tour until none is left.
+fn two_opt(distances: &[f64], order: usize, nodes: &mut [usize]) {
+    let distance = |left: usize, right: usize| distances[left * order + right];
+    let mut improved = true;
+    while improved {
+        improved = false;
+        for first in 0..order.saturating_sub(2) {
+            // The edges (a, b) and (c, d) of the tour, which must not be
+            // adjacent.
+            let last = if first == 0 { order - 1 } else { order };
+            for second in (first + 2)..last {
+                let (a, b) = (nodes[first], nodes[first + 1]);
+                let (c, d) = (nodes[second], nodes[(second + 1) % order]);
+                let removed = distance(a, b) + distance(c, d);
+                let added = distance(a, c) + distance(b, d);
+                if added < removed - IMPROVEMENT_TOLERANCE * removed {
+                    nodes[first + 1..=second].reverse();
+                    improved = true;
+                }
+            }
+        }
+    }
+}
+
+/// Returns the length of the closed tour.
+fn tour_length(distances: &[f64], order: usize, nodes: &[usize]) -> f64 {
+    if nodes.len() < 2 {
+        return 0.0;
+    }
+    nodes
+        .iter()
+        .zip(nodes.iter().cycle().skip(1))
+        .map(|(&source, &destination)| distances[source * order + destination])
+        .sum()
+}
@@ -7,8 +7,10 @@ pub use lapjv::{LAPJV, SparseLAPJV};
 
 #[cfg(feature = "alloc")]
 mod lapmod;
+#[cfg(feature = "metrics")]
+pub use lapmod::LapmodMetrics;
 #[cfg(feature = "alloc")]
-pub use lapmod::{Jaqaman, LAPMOD};
+pub use lapmod::{Jaqaman, LAPMOD, LapmodState};
 
 #[cfg(feature = "alloc")]
 pub mod crouse;
@@ -20,6 +22,18 @@ mod hungarian;
 #[cfg(feature = "alloc")]
 pub use hungarian::{Hungarian, SparseHungarian};
 
+#[cfg(feature = "alloc")]
+mod mutual_nearest_neighbors;
+#[cfg(feature = "alloc")]
+pub use mutual_nearest_neighbors::MutualNearestNeighbors;
+
+#[cfg(feature = "alloc")]
+mod lap_preflight;
+#[cfg(feature = "alloc")]
+pub use lap_preflight::{
+    LapPreflight, MAXIMUM_STABLE_DYNAMIC_RANGE, MAXIMUM_STABLE_VALUE, PreflightReport,
+};
+
 #[cfg(feature = "alloc")]
 mod lap_error;
 #[cfg(feature = "alloc")]
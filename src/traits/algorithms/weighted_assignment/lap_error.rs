@@ -58,8 +58,21 @@ pub enum LAPError {
     #[error("Internal index conversion failed while processing the sparse wrapper.")]
     IndexConversionFailed,
     /// The sparse structure has no perfect matching.
+    ///
+    /// The rows which cannot all be assigned are returned by
+    /// [`HopcroftKarp::hall_violator`](crate::traits::HopcroftKarp::hall_violator).
     #[error("The sparse structure has no perfect matching (infeasible assignment).")]
     InfeasibleAssignment,
+    /// The warm-start state does not hold one dual variable per column.
+    #[error(
+        "The warm-start state holds {actual} column dual variables, but the matrix has {expected} columns."
+    )]
+    WarmStartStateMismatch {
+        /// The number of columns of the matrix.
+        expected: usize,
+        /// The number of column dual variables of the state.
+        actual: usize,
+    },
 }
 
 /// Validates the common `padding_cost`/`max_cost` contract for sparse wrappers.
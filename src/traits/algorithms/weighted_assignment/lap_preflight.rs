@@ -0,0 +1,209 @@
+//! Submodule providing the [`LapPreflight`] diagnostics of the inputs of the
+//! LAP algorithms.
+//!
+//! The solvers reject invalid inputs with a single [`LAPError`], and only
+//! after part of the work has been done. The preflight report collects in one
+//! scan all the properties of a cost matrix the solvers depend on, so that
+//! callers can fail fast and tell which rows, columns or values are at fault.
+use alloc::{vec, vec::Vec};
+
+use num_traits::{AsPrimitive, ToPrimitive};
+
+use super::{LAPError, lap_error::validate_lap_entry_costs};
+use crate::traits::{Finite, HopcroftKarp, Number, SparseValuedMatrix2D, ValueStatistics};
+
+/// Largest magnitude of the values considered numerically stable.
+pub const MAXIMUM_STABLE_VALUE: f64 = 1e150;
+
+/// Largest ratio between the largest and the smallest strictly positive
+/// values considered numerically stable.
+pub const MAXIMUM_STABLE_DYNAMIC_RANGE: f64 = 1e12;
+
+#[derive(Debug, Clone, PartialEq)]
+/// Report of the properties of a cost matrix which determine whether the LAP
+/// algorithms can solve it.
+pub struct PreflightReport<RowIndex, ColumnIndex> {
+    /// The number of rows of the matrix.
+    pub number_of_rows: usize,
+    /// The number of columns of the matrix.
+    pub number_of_columns: usize,
+    /// The distribution statistics of the defined values.
+    pub statistics: ValueStatistics,
+    /// The error raised by the provided maximal cost or by the value type,
+    /// if any.
+    pub cost_error: Option<LAPError>,
+    /// The number of finite values which are not strictly smaller than the
+    /// maximal cost.
+    pub number_of_values_exceeding_max_cost: usize,
+    /// The rows without any defined value.
+    pub empty_rows: Vec<RowIndex>,
+    /// The columns without any defined value.
+    pub empty_columns: Vec<ColumnIndex>,
+    /// The size of a maximum matching of the sparse structure, or `None` if
+    /// the Hopcroft-Karp algorithm could not compute it.
+    pub maximum_matching_size: Option<usize>,
+}
+
+impl<RowIndex, ColumnIndex> PreflightReport<RowIndex, ColumnIndex> {
+    /// Returns whether the matrix is square.
+    #[must_use]
+    #[inline]
+    pub fn is_square(&self) -> bool {
+        self.number_of_rows == self.number_of_columns
+    }
+
+    /// Returns the ratio between the largest and the smallest strictly
+    /// positive finite values.
+    #[must_use]
+    #[inline]
+    pub fn dynamic_range(&self) -> Option<f64> {
+        self.statistics.positive_dynamic_range()
+    }
+
+    /// Returns whether the sparse structure of a square matrix admits a
+    /// perfect matching, i.e. whether an assignment of every row exists.
+    #[must_use]
+    #[inline]
+    pub fn has_perfect_matching(&self) -> bool {
+        self.is_square() && self.maximum_matching_size == Some(self.number_of_rows)
+    }
+
+    /// Returns whether the strictly positive values span a range where the
+    /// floating-point arithmetic of the solvers remains accurate.
+    ///
+    /// The smallest positive value must be normal, the largest value must not
+    /// exceed [`MAXIMUM_STABLE_VALUE`] and their ratio must not exceed
+    /// [`MAXIMUM_STABLE_DYNAMIC_RANGE`]. Matrices without positive values are
+    /// considered stable.
+    #[must_use]
+    #[inline]
+    pub fn is_numerically_stable(&self) -> bool {
+        let (Some(minimum_positive), Some(maximum), Some(dynamic_range)) =
+            (self.statistics.minimum_positive, self.statistics.maximum, self.dynamic_range())
+        else {
+            return true;
+        };
+        minimum_positive >= f64::MIN_POSITIVE
+            && maximum <= MAXIMUM_STABLE_VALUE
+            && dynamic_range <= MAXIMUM_STABLE_DYNAMIC_RANGE
+    }
+
+    /// Returns the error [`LAPMOD`](super::LAPMOD) would fail with, if any.
+    ///
+    /// The checks follow the validation order of the solver, except that all
+    /// the values are checked for one property before the next one.
+    ///
+    /// # Errors
+    ///
+    /// * The error of the maximal cost or value type, see
+    ///   [`cost_error`](PreflightReport::cost_error).
+    /// * [`LAPError::NonSquareMatrix`] if the matrix is not square.
+    /// * [`LAPError::NonFiniteValues`], [`LAPError::ZeroValues`],
+    ///   [`LAPError::NegativeValues`] or [`LAPError::ValueTooLarge`] if any
+    ///   value is respectively non-finite, zero, negative or not smaller than
+    ///   the maximal cost.
+    /// * [`LAPError::InfeasibleAssignment`] if the sparse structure has no
+    ///   perfect matching.
+    #[inline]
+    pub fn check(&self) -> Result<(), LAPError> {
+        if let Some(error) = &self.cost_error {
+            return Err(error.clone());
+        }
+        if !self.is_square() {
+            return Err(LAPError::NonSquareMatrix);
+        }
+        if self.statistics.number_of_non_finite_values > 0 {
+            return Err(LAPError::NonFiniteValues);
+        }
+        if self.statistics.number_of_zero_values > 0 {
+            return Err(LAPError::ZeroValues);
+        }
+        if self.statistics.number_of_negative_values > 0 {
+            return Err(LAPError::NegativeValues);
+        }
+        if self.number_of_values_exceeding_max_cost > 0 {
+            return Err(LAPError::ValueTooLarge);
+        }
+        if !self.has_perfect_matching() {
+            return Err(LAPError::InfeasibleAssignment);
+        }
+        Ok(())
+    }
+}
+
+/// Trait providing the preflight diagnostics of the inputs of the LAP
+/// algorithms.
+pub trait LapPreflight: SparseValuedMatrix2D + Sized
+where
+    Self::Value: Number + Finite + ToPrimitive,
+{
+    /// Returns the report of the properties of the matrix which determine
+    /// whether the LAP algorithms can solve it with the provided maximal cost.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_cost`: The upper bound on the edge costs that would be provided
+    ///   to the solver.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{
+    ///     impls::ValuedCSR2D,
+    ///     prelude::*,
+    ///     traits::{LAPError, LapPreflight},
+    /// };
+    ///
+    /// let mut csr: ValuedCSR2D<usize, usize, usize, f64> = SparseMatrixMut::with_sparse_shape((3, 3));
+    /// for entry in [(0, 0, 1.0), (0, 1, 2.0), (1, 0, 3.0), (1, 1, 4.0), (2, 0, 5.0)] {
+    ///     MatrixMut::add(&mut csr, entry).unwrap();
+    /// }
+    ///
+    /// let report = csr.preflight(1000.0);
+    /// assert_eq!(report.empty_columns, vec![2]);
+    /// assert_eq!(report.maximum_matching_size, Some(2));
+    /// assert_eq!(report.dynamic_range(), Some(5.0));
+    /// assert_eq!(report.check(), Err(LAPError::InfeasibleAssignment));
+    /// assert_eq!(csr.lapmod(1000.0), Err(LAPError::InfeasibleAssignment));
+    ///
+    /// assert_eq!(csr.preflight(4.0).check(), Err(LAPError::ValueTooLarge));
+    /// ```
+    #[inline]
+    fn preflight(
+        &self,
+        max_cost: Self::Value,
+    ) -> PreflightReport<Self::RowIndex, Self::ColumnIndex> {
+        let mut empty_rows = Vec::new();
+        let mut has_column_values = vec![false; self.number_of_columns().as_()];
+        let mut number_of_values_exceeding_max_cost = 0;
+        for (row, entries) in self.rows_with_values() {
+            let mut is_empty = true;
+            for (column, value) in entries {
+                is_empty = false;
+                has_column_values[column.as_()] = true;
+                if value.is_finite() && value >= max_cost {
+                    number_of_values_exceeding_max_cost += 1;
+                }
+            }
+            if is_empty {
+                empty_rows.push(row);
+            }
+        }
+
+        PreflightReport {
+            number_of_rows: self.number_of_rows().as_(),
+            number_of_columns: self.number_of_columns().as_(),
+            statistics: self.value_statistics(),
+            cost_error: validate_lap_entry_costs(max_cost).err(),
+            number_of_values_exceeding_max_cost,
+            empty_rows,
+            empty_columns: self
+                .column_indices()
+                .filter(|column| !has_column_values[column.as_()])
+                .collect(),
+            maximum_matching_size: self.hopcroft_karp().ok().map(|matching| matching.len()),
+        }
+    }
+}
+
+impl<M: SparseValuedMatrix2D> LapPreflight for M where M::Value: Number + Finite + ToPrimitive {}
@@ -74,6 +74,54 @@ where
 
         Ok(inner.into_assignments())
     }
+
+    #[cfg(feature = "parallel")]
+    #[allow(clippy::type_complexity)]
+    /// Computes the weighted assignment using the LAPJV algorithm,
+    /// distributing the column reduction and the augmenting row reductions
+    /// over the `rayon` thread pool.
+    ///
+    /// The assignment is identical to the one of [`LAPJV::lapjv`] whatever
+    /// the number of threads. The column reduction, which validates and
+    /// scans every value of the matrix, is split over blocks of rows. The
+    /// augmenting row reductions compute the two smallest reduced costs of
+    /// every unassigned row in parallel, and then commit the assignments
+    /// sequentially, computing again the reduced costs of the rows whose
+    /// minimum columns were updated in the meantime. The reduction transfer
+    /// and the augmentation remain sequential.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_cost`: The upper bound for the cost of the assignment.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`LAPJV::lapjv`].
+    #[inline]
+    fn par_lapjv(
+        &self,
+        max_cost: Self::Value,
+    ) -> Result<Vec<(Self::RowIndex, Self::ColumnIndex)>, LAPError>
+    where
+        Self: Sync,
+        Self::Value: Send + Sync,
+        Self::RowIndex: Send + Sync,
+        Self::ColumnIndex: Send + Sync,
+        <Self::ColumnIndex as TryFrom<usize>>::Error: Debug,
+    {
+        validate_lap_entry_costs(max_cost)?;
+
+        let mut inner = Inner::new(self, max_cost)?;
+        inner.par_column_reduction()?;
+        inner.reduction_transfer();
+
+        inner.par_augmenting_row_reduction();
+        inner.par_augmenting_row_reduction();
+
+        inner.augmentation();
+
+        Ok(inner.into_assignments())
+    }
 }
 
 impl<M: DenseValuedMatrix2D> LAPJV for M
@@ -145,6 +193,51 @@ where
     {
         sparse_padded_lap_impl!(self, padding_cost, max_cost, lapjv)
     }
+
+    #[cfg(feature = "parallel")]
+    #[allow(clippy::type_complexity)]
+    /// Computes the weighted assignment using the LAPJV algorithm on the
+    /// padded matrix, distributing its phases over the `rayon` thread pool
+    /// as [`LAPJV::par_lapjv`] does.
+    ///
+    /// The assignment is identical to the one of
+    /// [`SparseLAPJV::sparse_lapjv`] whatever the number of threads.
+    ///
+    /// # Arguments
+    ///
+    /// * `padding_cost`: The cost of padding the matrix to make it square.
+    /// * `max_cost`: The upper bound for the cost of the assignment.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`SparseLAPJV::sparse_lapjv`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{impls::ValuedCSR2D, prelude::*};
+    ///
+    /// let csr: ValuedCSR2D<u8, u8, u8, f64> =
+    ///     ValuedCSR2D::try_from([[1.0, 0.5, 10.0], [0.5, 10.0, 20.0], [10.0, 20.0, 0.5]])
+    ///         .expect("Failed to create CSR matrix");
+    ///
+    /// assert_eq!(csr.par_sparse_lapjv(900.0, 1000.0), csr.sparse_lapjv(900.0, 1000.0));
+    /// ```
+    #[inline]
+    fn par_sparse_lapjv(
+        &self,
+        padding_cost: Self::Value,
+        max_cost: Self::Value,
+    ) -> Result<Vec<(Self::RowIndex, Self::ColumnIndex)>, LAPError>
+    where
+        Self: Sync,
+        Self::Value: Finite + TotalOrd + Send + Sync,
+        Self::RowIndex: Send + Sync,
+        Self::ColumnIndex: Send + Sync,
+        <<Self as crate::traits::Matrix2D>::ColumnIndex as TryFrom<usize>>::Error: Debug,
+    {
+        sparse_padded_lap_impl!(self, padding_cost, max_cost, par_lapjv)
+    }
 }
 
 impl<M: SparseValuedMatrix2D> SparseLAPJV for M
@@ -5,6 +5,8 @@ use core::fmt::Debug;
 
 use num_traits::AsPrimitive;
 
+#[cfg(feature = "parallel")]
+use crate::traits::algorithms::metrics::Counter;
 use crate::traits::{AssignmentState, DenseValuedMatrix2D, Finite, Number, TotalOrd, TryFromUsize};
 
 /// Finds the minimum distance among the columns in `to_scan[lower_bound..]`,
@@ -190,6 +192,80 @@ pub(crate) fn augmenting_row_reduction_impl<R, C, V>(
     unassigned_rows.truncate(updated_number_of_unassigned_rows);
 }
 
+/// Runs [`augmenting_row_reduction_impl`], computing the first and second
+/// minimum reduced costs of the unassigned rows over the `rayon` thread pool
+/// before committing the assignments sequentially.
+///
+/// The reduction only ever lowers column dual variables, which raises the
+/// reduced costs of their columns, so the minima of a row computed upfront
+/// still hold when its turn comes unless the dual of one of its two minimum
+/// columns was lowered in the meantime. Those rows, and the rows displaced
+/// during the reduction, have their minima computed again, so that the
+/// result is the one of the sequential reduction. Returns the counter of the
+/// rows processed by the reduction.
+///
+/// # Arguments
+///
+/// * `unassigned_rows`: The current list of unassigned rows (modified).
+/// * `assigned_rows`: Column → row assignment (indexed by column, modified).
+/// * `assigned_columns`: Row → column assignment (indexed by row, modified).
+/// * `column_costs`: Current column dual variables (modified).
+/// * `number_of_rows`: Total number of rows (used for iteration-count guard).
+/// * `first_and_second_min`: Closure `(row, &col_costs) -> ((best_col,
+///   best_val), (Option<second_col>, second_val))`.
+#[cfg(feature = "parallel")]
+#[allow(clippy::type_complexity)]
+pub(crate) fn par_augmenting_row_reduction_impl<R, C, V>(
+    unassigned_rows: &mut Vec<R>,
+    assigned_rows: &mut [AssignmentState<R>],
+    assigned_columns: &mut [AssignmentState<C>],
+    column_costs: &mut Vec<V>,
+    number_of_rows: usize,
+    first_and_second_min: impl Fn(R, &[V]) -> ((C, V), (Option<C>, V)) + Sync,
+) -> Counter
+where
+    R: Copy + AsPrimitive<usize> + Send + Sync,
+    C: Copy + AsPrimitive<usize> + Send + Sync,
+    V: Number + TotalOrd + Send + Sync,
+{
+    use rayon::prelude::*;
+
+    let mut minima = vec![None; number_of_rows];
+    let computed: Vec<_> =
+        unassigned_rows.par_iter().map(|&row| first_and_second_min(row, column_costs)).collect();
+    for (row, minimum) in unassigned_rows.iter().zip(computed) {
+        minima[row.as_()] = Some(minimum);
+    }
+
+    let mut row_reductions = Counter::default();
+    let mut lowered = vec![false; column_costs.len()];
+    let mut previous_first_column: Option<usize> = None;
+    augmenting_row_reduction_impl(
+        unassigned_rows,
+        assigned_rows,
+        assigned_columns,
+        column_costs,
+        number_of_rows,
+        |row, col_costs| {
+            row_reductions.increment();
+            // Only the dual of the first minimum column of the previous row
+            // may have been lowered since the previous call.
+            if let Some(column) = previous_first_column.take() {
+                lowered[column] = true;
+            }
+            let minimum = minima[row.as_()]
+                .take()
+                .filter(|((first, _), (second, _))| {
+                    !lowered[first.as_()] && second.is_none_or(|second| !lowered[second.as_()])
+                })
+                .unwrap_or_else(|| first_and_second_min(row, col_costs));
+            previous_first_column = Some(minimum.0.0.as_());
+            minimum
+        },
+    );
+    row_reductions
+}
+
 /// Shared Dijkstra-style frontier scan for dense LAP solvers (LAPJV and
 /// Hungarian).
 ///
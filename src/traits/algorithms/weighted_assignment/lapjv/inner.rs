@@ -5,6 +5,8 @@ use core::fmt::Debug;
 
 use num_traits::{AsPrimitive, Bounded};
 
+#[cfg(feature = "parallel")]
+use super::common::par_augmenting_row_reduction_impl;
 use super::{
     LAPError,
     common::{
@@ -109,6 +111,14 @@ where
             "We expected all rows to be assigned",
         );
 
+        self.resolve_column_conflicts();
+
+        Ok(())
+    }
+
+    /// Reverse-scans the columns to resolve the conflicts of the column
+    /// reduction: a column is won by the last row that achieved its minimum.
+    fn resolve_column_conflicts(&mut self) {
         for column_index in self.matrix.column_indices().rev() {
             let AssignmentState::Assigned(assigned_row) = self.assigned_rows[column_index.as_()]
             else {
@@ -132,6 +142,61 @@ where
                 }
             }
         }
+    }
+
+    /// Column reduction distributed over blocks of rows, keeping the first
+    /// cheapest row of each column within a block and merging the blocks in
+    /// row order, which yields the same result as the sequential one.
+    #[cfg(feature = "parallel")]
+    #[allow(clippy::type_complexity)]
+    #[inline]
+    pub(super) fn par_column_reduction(&mut self) -> Result<(), LAPError>
+    where
+        M: Sync,
+        M::Value: Send + Sync,
+        M::RowIndex: Send + Sync,
+    {
+        use rayon::prelude::*;
+
+        let matrix = self.matrix;
+        let max_cost = self.max_cost;
+        let number_of_columns = self.column_costs.len();
+        let rows: Vec<M::RowIndex> = matrix.row_indices().collect();
+        let block_size = rows.len().div_ceil(rayon::current_num_threads()).max(1);
+        let blocks: Vec<Result<Vec<Option<(M::Value, M::RowIndex)>>, LAPError>> = rows
+            .par_chunks(block_size)
+            .map(|block| {
+                let mut cheapest: Vec<Option<(M::Value, M::RowIndex)>> =
+                    vec![None; number_of_columns];
+                for &row_index in block {
+                    for (column_index, value) in
+                        matrix.column_indices().zip(matrix.row_values(row_index))
+                    {
+                        validate_lap_value_against_max(value, max_cost)?;
+                        let entry = &mut cheapest[column_index.as_()];
+                        if entry.is_none_or(|(minimum, _)| value < minimum) {
+                            *entry = Some((value, row_index));
+                        }
+                    }
+                }
+                Ok(cheapest)
+            })
+            .collect();
+
+        // The first invalid value in row order is the one reported by the
+        // sequential scan.
+        for block in blocks {
+            for (column_index, cheapest) in block?.into_iter().enumerate() {
+                if let Some((value, row_index)) =
+                    cheapest.filter(|&(value, _)| value < self.column_costs[column_index])
+                {
+                    self.assigned_rows[column_index] = AssignmentState::Assigned(row_index);
+                    self.column_costs[column_index] = value;
+                }
+            }
+        }
+
+        self.resolve_column_conflicts();
 
         Ok(())
     }
@@ -187,38 +252,74 @@ where
             &mut self.assigned_columns,
             &mut self.column_costs,
             number_of_rows,
-            |row, col_costs| {
-                let mut iterator = matrix.column_indices().zip(matrix.row_values(row)).map(
-                    |(column_index, cost)| (column_index, cost - col_costs[column_index.as_()]),
-                );
-
-                let (mut first_minimum_index, mut first_minimum_reduced_cost) =
-                    iterator.next().expect("We expected the iterator to have at least one element");
-
-                let mut second_minimum_column_index: Option<M::ColumnIndex> = None;
-                let mut second_minimum_reduced_cost = max_cost;
-                for (column_index, reduced_cost) in iterator {
-                    if reduced_cost < second_minimum_reduced_cost {
-                        if reduced_cost >= first_minimum_reduced_cost {
-                            second_minimum_column_index = Some(column_index);
-                            second_minimum_reduced_cost = reduced_cost;
-                        } else {
-                            second_minimum_column_index = Some(first_minimum_index);
-                            second_minimum_reduced_cost = first_minimum_reduced_cost;
-                            first_minimum_index = column_index;
-                            first_minimum_reduced_cost = reduced_cost;
-                        }
-                    }
-                }
+            |row, col_costs| Self::first_and_second_min(matrix, max_cost, row, col_costs),
+        );
+    }
 
-                (
-                    (first_minimum_index, first_minimum_reduced_cost),
-                    (second_minimum_column_index, second_minimum_reduced_cost),
-                )
-            },
+    /// Augmenting row reduction with the minimum reduced costs of the
+    /// unassigned rows computed over the `rayon` thread pool, yielding the
+    /// same result as the sequential one.
+    #[cfg(feature = "parallel")]
+    #[inline]
+    pub(super) fn par_augmenting_row_reduction(&mut self)
+    where
+        M: Sync,
+        M::Value: Send + Sync,
+        M::RowIndex: Send + Sync,
+        M::ColumnIndex: Send + Sync,
+    {
+        let matrix = self.matrix;
+        let max_cost = self.max_cost;
+        par_augmenting_row_reduction_impl(
+            &mut self.unassigned_rows,
+            &mut self.assigned_rows,
+            &mut self.assigned_columns,
+            &mut self.column_costs,
+            matrix.number_of_rows().as_(),
+            |row, col_costs| Self::first_and_second_min(matrix, max_cost, row, col_costs),
         );
     }
 
+    /// Returns the first and second minimum reduced costs of the row, with
+    /// their columns, the second one being `max_cost` without a column when
+    /// the row has no other reduced cost below it.
+    #[allow(clippy::type_complexity)]
+    fn first_and_second_min(
+        matrix: &M,
+        max_cost: M::Value,
+        row: M::RowIndex,
+        col_costs: &[M::Value],
+    ) -> ((M::ColumnIndex, M::Value), (Option<M::ColumnIndex>, M::Value)) {
+        let mut iterator = matrix
+            .column_indices()
+            .zip(matrix.row_values(row))
+            .map(|(column_index, cost)| (column_index, cost - col_costs[column_index.as_()]));
+
+        let (mut first_minimum_index, mut first_minimum_reduced_cost) =
+            iterator.next().expect("We expected the iterator to have at least one element");
+
+        let mut second_minimum_column_index: Option<M::ColumnIndex> = None;
+        let mut second_minimum_reduced_cost = max_cost;
+        for (column_index, reduced_cost) in iterator {
+            if reduced_cost < second_minimum_reduced_cost {
+                if reduced_cost >= first_minimum_reduced_cost {
+                    second_minimum_column_index = Some(column_index);
+                    second_minimum_reduced_cost = reduced_cost;
+                } else {
+                    second_minimum_column_index = Some(first_minimum_index);
+                    second_minimum_reduced_cost = first_minimum_reduced_cost;
+                    first_minimum_index = column_index;
+                    first_minimum_reduced_cost = reduced_cost;
+                }
+            }
+        }
+
+        (
+            (first_minimum_index, first_minimum_reduced_cost),
+            (second_minimum_column_index, second_minimum_reduced_cost),
+        )
+    }
+
     #[inline]
     pub(super) fn augmentation(&mut self) {
         if self.unassigned_rows.is_empty() {
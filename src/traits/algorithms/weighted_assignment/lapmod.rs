@@ -21,7 +21,7 @@ mod inner;
 
 use core::fmt::Debug;
 
-use inner::LapmodInner;
+use inner::{LapmodCounters, LapmodInner};
 use num_traits::{AsPrimitive, One};
 
 use super::{
@@ -100,31 +100,302 @@ where
         <Self::ColumnIndex as TryFrom<usize>>::Error: Debug,
         <Self::RowIndex as TryFrom<usize>>::Error: Debug,
     {
-        validate_lap_entry_costs(max_cost)?;
+        lapmod_with_counters(
+            self,
+            max_cost,
+            LapmodInner::column_reduction_sparse,
+            LapmodInner::augmenting_row_reduction_sparse,
+        )
+        .map(|(assignment, _, _)| assignment)
+    }
 
-        let n_rows = self.number_of_rows().as_();
-        let n_cols = self.number_of_columns().as_();
+    #[cfg(feature = "parallel")]
+    #[allow(clippy::type_complexity)]
+    /// Computes the optimal weighted assignment using the LAPMOD algorithm,
+    /// distributing the column reduction and the augmenting row reductions
+    /// over the `rayon` thread pool.
+    ///
+    /// The assignment is identical to the one of [`LAPMOD::lapmod`]
+    /// whatever the number of threads. The column reduction, which
+    /// validates and scans every entry of the matrix, is split over blocks
+    /// of rows. The augmenting row reductions compute the two smallest
+    /// reduced costs of every unassigned row in parallel, and then commit
+    /// the assignments sequentially, computing again the reduced costs of
+    /// the rows whose minimum columns were updated in the meantime. The
+    /// reduction transfer and the augmentation remain sequential, as every
+    /// step updates the column duals read by the next one.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_cost`: An upper bound on all edge costs.  Must be positive and
+    ///   finite.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`LAPMOD::lapmod`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{impls::ValuedCSR2D, prelude::*};
+    ///
+    /// let csr: ValuedCSR2D<u8, u8, u8, f64> =
+    ///     ValuedCSR2D::try_from([[1.0, 2.0, 3.0], [4.0, 1.0, 6.0], [7.0, 8.0, 1.0]])
+    ///         .expect("Failed to create CSR matrix");
+    ///
+    /// assert_eq!(csr.par_lapmod(1000.0), csr.lapmod(1000.0));
+    /// ```
+    #[inline]
+    fn par_lapmod(
+        &self,
+        max_cost: Self::Value,
+    ) -> Result<Vec<(Self::RowIndex, Self::ColumnIndex)>, LAPError>
+    where
+        Self: Sync,
+        Self::Value: Send + Sync,
+        Self::RowIndex: Send + Sync,
+        Self::ColumnIndex: Send + Sync,
+        <Self::ColumnIndex as TryFrom<usize>>::Error: Debug,
+        <Self::RowIndex as TryFrom<usize>>::Error: Debug,
+    {
+        lapmod_with_counters(
+            self,
+            max_cost,
+            LapmodInner::par_column_reduction_sparse,
+            LapmodInner::par_augmenting_row_reduction_sparse,
+        )
+        .map(|(assignment, _, _)| assignment)
+    }
 
-        if n_rows != n_cols {
-            return Err(LAPError::NonSquareMatrix);
-        }
+    #[allow(clippy::type_complexity)]
+    /// Computes the optimal weighted assignment using the LAPMOD algorithm,
+    /// starting from the dual variables of a previous run.
+    ///
+    /// When many assignment problems with the same columns are solved in a
+    /// row, and the costs change only slightly between them, the column
+    /// duals of the previous solution are close to optimal ones for the
+    /// next problem. Most rows are then assigned right away to a column of
+    /// smallest reduced cost, and only the rows competing for the same
+    /// column are left to the augmentation. The returned state holds the
+    /// duals of the new solution, to be passed to the next call; starting
+    /// from [`LapmodState::default`] solves the problem as
+    /// [`LAPMOD::lapmod`].
+    ///
+    /// The assignment is optimal whatever the state, but among several
+    /// optimal assignments the one returned may depend on the state.
+    ///
+    /// # Arguments
+    ///
+    /// * `state`: The state returned by the previous call, or an empty state.
+    /// * `max_cost`: An upper bound on all edge costs.  Must be positive and
+    ///   finite.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`LAPMOD::lapmod`], and
+    /// [`LAPError::WarmStartStateMismatch`] if the state is not empty and
+    /// holds a number of column duals other than the number of columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{impls::ValuedCSR2D, prelude::*};
+    ///
+    /// let first: ValuedCSR2D<u8, u8, u8, f64> =
+    ///     ValuedCSR2D::try_from([[1.0, 2.0, 3.0], [4.0, 1.0, 6.0], [7.0, 8.0, 1.0]])
+    ///         .expect("Failed to create CSR matrix");
+    /// let second: ValuedCSR2D<u8, u8, u8, f64> =
+    ///     ValuedCSR2D::try_from([[1.1, 2.0, 3.0], [4.0, 1.2, 6.0], [7.0, 8.0, 0.9]])
+    ///         .expect("Failed to create CSR matrix");
+    ///
+    /// let (assignment, state) =
+    ///     first.lapmod_warm_start(&LapmodState::default(), 1000.0).expect("LAPMOD failed");
+    /// assert_eq!(assignment, first.lapmod(1000.0).unwrap());
+    /// let (assignment, _) = second.lapmod_warm_start(&state, 1000.0).expect("LAPMOD failed");
+    /// assert_eq!(assignment, vec![(0, 0), (1, 1), (2, 2)]);
+    /// ```
+    #[inline]
+    fn lapmod_warm_start(
+        &self,
+        state: &LapmodState<Self::Value>,
+        max_cost: Self::Value,
+    ) -> Result<(Vec<(Self::RowIndex, Self::ColumnIndex)>, LapmodState<Self::Value>), LAPError>
+    where
+        <Self::ColumnIndex as TryFrom<usize>>::Error: Debug,
+        <Self::RowIndex as TryFrom<usize>>::Error: Debug,
+    {
+        let result = if state.column_costs.is_empty() {
+            lapmod_with_counters(
+                self,
+                max_cost,
+                LapmodInner::column_reduction_sparse,
+                LapmodInner::augmenting_row_reduction_sparse,
+            )
+        } else {
+            let number_of_columns = self.number_of_columns().as_();
+            if state.column_costs.len() != number_of_columns {
+                return Err(LAPError::WarmStartStateMismatch {
+                    expected: number_of_columns,
+                    actual: state.column_costs.len(),
+                });
+            }
+            lapmod_with_counters(
+                self,
+                max_cost,
+                |inner| inner.warm_column_reduction_sparse(&state.column_costs),
+                LapmodInner::augmenting_row_reduction_sparse,
+            )
+        };
+        result.map(|(assignment, column_costs, _)| (assignment, LapmodState { column_costs }))
+    }
 
-        if n_rows == 0 {
-            return Ok(Vec::new());
-        }
+    #[cfg(feature = "metrics")]
+    #[allow(clippy::type_complexity)]
+    /// Computes the optimal weighted assignment using the LAPMOD algorithm,
+    /// together with the counts of the operations it performed.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_cost`: An upper bound on all edge costs.  Must be positive and
+    ///   finite.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`LAPMOD::lapmod`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{impls::ValuedCSR2D, prelude::*};
+    ///
+    /// let csr: ValuedCSR2D<u8, u8, u8, f64> =
+    ///     ValuedCSR2D::try_from([[1.0, 2.0], [1.0, 3.0]]).expect("Failed to create CSR matrix");
+    ///
+    /// let (assignment, metrics) = csr.lapmod_with_metrics(1000.0).expect("LAPMOD failed");
+    /// assert_eq!(assignment, csr.lapmod(1000.0).unwrap());
+    /// assert!(metrics.row_reductions > 0);
+    /// ```
+    #[inline]
+    fn lapmod_with_metrics(
+        &self,
+        max_cost: Self::Value,
+    ) -> Result<(Vec<(Self::RowIndex, Self::ColumnIndex)>, LapmodMetrics), LAPError>
+    where
+        <Self::ColumnIndex as TryFrom<usize>>::Error: Debug,
+        <Self::RowIndex as TryFrom<usize>>::Error: Debug,
+    {
+        lapmod_with_counters(
+            self,
+            max_cost,
+            LapmodInner::column_reduction_sparse,
+            LapmodInner::augmenting_row_reduction_sparse,
+        )
+        .map(|(assignment, _, counters)| (assignment, counters.metrics()))
+    }
+}
+
+/// Dual variables of the columns of a LAPMOD solution, from which
+/// [`LAPMOD::lapmod_warm_start`] solves a similar problem.
+///
+/// The default state is empty, and starts the solver from scratch.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LapmodState<V> {
+    /// Dual variable of each column.
+    column_costs: Vec<V>,
+}
+
+impl<V> Default for LapmodState<V> {
+    #[inline]
+    fn default() -> Self {
+        Self { column_costs: Vec::new() }
+    }
+}
+
+impl<V> LapmodState<V> {
+    /// Returns the dual variable of each column, empty for the default
+    /// state.
+    #[inline]
+    #[must_use]
+    pub fn column_duals(&self) -> &[V] {
+        &self.column_costs
+    }
+}
+
+/// Runs the phases of LAPMOD, with the provided column reduction and
+/// augmenting row reduction, returning the assignment, the column duals and
+/// the counters of the operations performed.
+#[allow(clippy::type_complexity)]
+fn lapmod_with_counters<'matrix, M: LAPMOD>(
+    matrix: &'matrix M,
+    max_cost: M::Value,
+    column_reduction: impl FnOnce(&mut LapmodInner<'matrix, M>) -> Result<(), LAPError>,
+    augmenting_row_reduction: impl Fn(&mut LapmodInner<'matrix, M>),
+) -> Result<(Vec<(M::RowIndex, M::ColumnIndex)>, Vec<M::Value>, LapmodCounters), LAPError>
+where
+    M::Value: Number + Finite + TotalOrd,
+    M::RowIndex: TryFromUsize,
+    M::ColumnIndex: TryFromUsize,
+    <M::ColumnIndex as TryFrom<usize>>::Error: Debug,
+    <M::RowIndex as TryFrom<usize>>::Error: Debug,
+{
+    validate_lap_entry_costs(max_cost)?;
 
-        let mut inner = LapmodInner::new(self, max_cost)?;
+    let n_rows = matrix.number_of_rows().as_();
+    let n_cols = matrix.number_of_columns().as_();
+
+    if n_rows != n_cols {
+        return Err(LAPError::NonSquareMatrix);
+    }
+
+    if n_rows == 0 {
+        return Ok((Vec::new(), Vec::new(), LapmodCounters::default()));
+    }
 
-        inner.column_reduction_sparse()?;
-        inner.reduction_transfer_sparse();
+    let mut inner = LapmodInner::new(matrix, max_cost)?;
 
-        // Two passes of augmenting row reduction (same as LAPJV).
-        inner.augmenting_row_reduction_sparse();
-        inner.augmenting_row_reduction_sparse();
+    column_reduction(&mut inner)?;
+    inner.reduction_transfer_sparse();
 
-        inner.augmentation_sparse()?;
+    // Two passes of augmenting row reduction (same as LAPJV).
+    augmenting_row_reduction(&mut inner);
+    augmenting_row_reduction(&mut inner);
 
-        Ok(inner.into_assignments())
+    inner.augmentation_sparse()?;
+
+    Ok(inner.into_assignments())
+}
+
+#[cfg(feature = "metrics")]
+/// Counts of the operations performed by one run of [`LAPMOD::lapmod`].
+///
+/// The augmentations and the scanned columns grow with the number of rows
+/// left unassigned by the row reductions and with the length of their
+/// augmenting paths, which is where a degenerate cost structure makes the
+/// solver slow down.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct LapmodMetrics {
+    /// Number of rows processed by the augmenting row reductions.
+    pub row_reductions: usize,
+    /// Number of augmenting paths found by the augmentation phase.
+    pub augmentations: usize,
+    /// Number of columns whose assigned row was scanned by the shortest
+    /// augmenting path searches.
+    pub scanned_columns: usize,
+    /// Number of column distances lowered by the shortest augmenting path
+    /// searches.
+    pub relaxations: usize,
+}
+
+#[cfg(feature = "metrics")]
+impl LapmodCounters {
+    /// Returns the counts of the counted operations.
+    fn metrics(self) -> LapmodMetrics {
+        LapmodMetrics {
+            row_reductions: self.row_reductions.count(),
+            augmentations: self.augmentations.count(),
+            scanned_columns: self.scanned_columns.count(),
+            relaxations: self.relaxations.count(),
+        }
     }
 }
 
@@ -6,16 +6,35 @@ use core::fmt::Debug;
 use num_traits::{AsPrimitive, Bounded};
 
 use super::LAPError;
+#[cfg(feature = "parallel")]
+use crate::traits::algorithms::weighted_assignment::lapjv::common::par_augmenting_row_reduction_impl;
 use crate::traits::{
     AssignmentState, Finite, Number, SparseValuedMatrix2D, TotalOrd, TryFromUsize,
-    algorithms::weighted_assignment::{
-        lap_error::validate_lap_value_against_max,
-        lapjv::common::{
-            assignments_from_assigned_rows, augmentation_backtrack, augmenting_row_reduction_impl,
+    algorithms::{
+        metrics::Counter,
+        weighted_assignment::{
+            lap_error::validate_lap_value_against_max,
+            lapjv::common::{
+                assignments_from_assigned_rows, augmentation_backtrack,
+                augmenting_row_reduction_impl,
+            },
         },
     },
 };
 
+/// Counters of the operations performed by the phases of LAPMOD.
+#[derive(Debug, Clone, Copy, Default)]
+pub(super) struct LapmodCounters {
+    /// Rows processed by the augmenting row reductions.
+    pub(super) row_reductions: Counter,
+    /// Augmenting paths found by the augmentation phase.
+    pub(super) augmentations: Counter,
+    /// Columns whose row was scanned by the path searches.
+    pub(super) scanned_columns: Counter,
+    /// Column distances lowered by the path searches.
+    pub(super) relaxations: Counter,
+}
+
 /// Support struct for computing the weighted assignment using the LAPMOD
 /// algorithm operating directly on a sparse valued matrix.
 pub(super) struct LapmodInner<'matrix, M: SparseValuedMatrix2D + ?Sized> {
@@ -25,12 +44,15 @@ pub(super) struct LapmodInner<'matrix, M: SparseValuedMatrix2D + ?Sized> {
     column_costs: Vec<M::Value>,
     /// Rows not yet augmented.
     unassigned_rows: Vec<M::RowIndex>,
-    /// Sentinel: `distances[j] == max_cost` means column `j` not yet reached.
+    /// The maximum cost of the matrix, bounding the second minimum reduced
+    /// cost of the augmenting row reduction.
     max_cost: M::Value,
     /// For each column `j`, which row is currently assigned to it.
     assigned_rows: Vec<AssignmentState<M::RowIndex>>,
     /// For each row `i`, which column it is currently assigned to.
     assigned_columns: Vec<AssignmentState<M::ColumnIndex>>,
+    /// Counters of the operations performed so far.
+    counters: LapmodCounters,
 }
 
 impl<M: SparseValuedMatrix2D + ?Sized> LapmodInner<'_, M>
@@ -40,8 +62,15 @@ where
     <M::ColumnIndex as TryFrom<usize>>::Error: Debug,
 {
     #[inline]
-    pub(super) fn into_assignments(self) -> Vec<(M::RowIndex, M::ColumnIndex)> {
-        assignments_from_assigned_rows(self.assigned_rows, self.matrix.number_of_rows().as_())
+    #[allow(clippy::type_complexity)]
+    pub(super) fn into_assignments(
+        self,
+    ) -> (Vec<(M::RowIndex, M::ColumnIndex)>, Vec<M::Value>, LapmodCounters) {
+        (
+            assignments_from_assigned_rows(self.assigned_rows, self.matrix.number_of_rows().as_()),
+            self.column_costs,
+            self.counters,
+        )
     }
 }
 
@@ -63,10 +92,33 @@ where
             max_cost,
             assigned_rows: vec![AssignmentState::Unassigned; n],
             assigned_columns: vec![AssignmentState::Unassigned; n],
+            counters: LapmodCounters::default(),
         })
     }
 }
 
+impl<M: SparseValuedMatrix2D + ?Sized> LapmodInner<'_, M> {
+    /// Returns the columns and costs of a row, skipping the bounds checks of
+    /// the row accessors.
+    ///
+    /// Every row visited by the algorithm comes from
+    /// [`row_indices`](crate::traits::Matrix2D::row_indices), either directly
+    /// or through the unassigned rows, the row assignments and the
+    /// predecessors, which only ever store such rows.
+    ///
+    /// # Safety
+    ///
+    /// The row must be smaller than the number of rows of the matrix.
+    #[inline]
+    unsafe fn sparse_row_entries(
+        matrix: &M,
+        row: M::RowIndex,
+    ) -> core::iter::Zip<M::SparseRow<'_>, M::SparseRowValues<'_>> {
+        // SAFETY: the caller guarantees that the row is in bounds.
+        unsafe { matrix.sparse_row_unchecked(row).zip(matrix.sparse_row_values_unchecked(row)) }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Algorithm phases
 // ---------------------------------------------------------------------------
@@ -90,14 +142,16 @@ where
     pub(super) fn column_reduction_sparse(&mut self) -> Result<(), LAPError> {
         // Check that every row has at least one sparse entry.
         for row in self.matrix.row_indices() {
-            if self.matrix.sparse_row(row).next().is_none() {
+            // SAFETY: the row comes from the row indices of the matrix.
+            if unsafe { self.matrix.sparse_row_unchecked(row) }.next().is_none() {
                 return Err(LAPError::InfeasibleAssignment);
             }
         }
 
         // Update column_costs and assigned_rows from sparse entries.
         for row in self.matrix.row_indices() {
-            for (col, cost) in self.matrix.sparse_row(row).zip(self.matrix.sparse_row_values(row)) {
+            // SAFETY: the row comes from the row indices of the matrix.
+            for (col, cost) in unsafe { Self::sparse_row_entries(self.matrix, row) } {
                 validate_lap_value_against_max(cost, self.max_cost)?;
 
                 if cost < self.column_costs[col.as_()] {
@@ -107,7 +161,13 @@ where
             }
         }
 
-        // Reverse-scan columns to resolve conflicts.
+        self.resolve_column_conflicts();
+        Ok(())
+    }
+
+    /// Reverse-scans the columns to resolve the conflicts of the column
+    /// reduction: a column is won by the last row that achieved its minimum.
+    fn resolve_column_conflicts(&mut self) {
         for col in self.matrix.column_indices().rev() {
             let AssignmentState::Assigned(row) = self.assigned_rows[col.as_()] else {
                 // Column has no sparse entries or was displaced — skip.
@@ -128,7 +188,124 @@ where
                 }
             }
         }
+    }
+
+    #[cfg(feature = "parallel")]
+    #[allow(clippy::type_complexity)]
+    /// Phase 1 distributed over the rows.
+    ///
+    /// Every task finds, within a contiguous block of rows, the cheapest row
+    /// of each column, keeping the first one on ties. Merging the blocks in
+    /// row order with a strict comparison then yields the same column costs
+    /// and row assignments as the sequential scan, and so the same result.
+    #[inline]
+    pub(super) fn par_column_reduction_sparse(&mut self) -> Result<(), LAPError>
+    where
+        M: Sync,
+        M::Value: Send + Sync,
+        M::RowIndex: TryFromUsize + Send + Sync,
+    {
+        use rayon::prelude::*;
+
+        let matrix = self.matrix;
+        let max_cost = self.max_cost;
+        let number_of_rows = matrix.number_of_rows().as_();
+        let number_of_columns = self.column_costs.len();
+        let row_at = |row: usize| {
+            M::RowIndex::try_from_usize(row)
+                .unwrap_or_else(|_| unreachable!("The rows of the matrix fit its row index."))
+        };
+
+        // SAFETY: the rows are smaller than the number of rows of the matrix.
+        if (0..number_of_rows)
+            .into_par_iter()
+            .any(|row| unsafe { matrix.sparse_row_unchecked(row_at(row)) }.next().is_none())
+        {
+            return Err(LAPError::InfeasibleAssignment);
+        }
+
+        let block_size = number_of_rows.div_ceil(rayon::current_num_threads()).max(1);
+        let blocks: Vec<Result<Vec<Option<(M::Value, M::RowIndex)>>, LAPError>> = (0
+            ..number_of_rows.div_ceil(block_size))
+            .into_par_iter()
+            .map(|block| {
+                let mut cheapest: Vec<Option<(M::Value, M::RowIndex)>> =
+                    vec![None; number_of_columns];
+                let rows = block * block_size..((block + 1) * block_size).min(number_of_rows);
+                for row in rows.map(row_at) {
+                    // SAFETY: the rows are smaller than the number of rows of
+                    // the matrix.
+                    for (col, cost) in unsafe { Self::sparse_row_entries(matrix, row) } {
+                        validate_lap_value_against_max(cost, max_cost)?;
+                        let entry = &mut cheapest[col.as_()];
+                        if entry.is_none_or(|(minimum, _)| cost < minimum) {
+                            *entry = Some((cost, row));
+                        }
+                    }
+                }
+                Ok(cheapest)
+            })
+            .collect();
+
+        // The first invalid cost in row order is the one reported by the
+        // sequential scan.
+        for block in blocks {
+            for (col, cheapest) in block?.into_iter().enumerate() {
+                if let Some((cost, row)) =
+                    cheapest.filter(|&(cost, _)| cost < self.column_costs[col])
+                {
+                    self.assigned_rows[col] = AssignmentState::Assigned(row);
+                    self.column_costs[col] = cost;
+                }
+            }
+        }
+
+        self.resolve_column_conflicts();
+        Ok(())
+    }
+
+    /// Phase 1 replaced by the column dual variables of a previous run.
+    ///
+    /// Every row is assigned to the first column of smallest reduced cost,
+    /// unless an earlier row already holds it, in which case the row is left
+    /// for the augmentation. Any column duals keep each assigned row on a
+    /// column of smallest reduced cost, which is the invariant the later
+    /// phases rely on: the closer the duals are to optimal ones, the fewer
+    /// rows are left unassigned.
+    ///
+    /// Returns `Err(InfeasibleAssignment)` as soon as a row with no entries is
+    /// found.
+    #[inline]
+    pub(super) fn warm_column_reduction_sparse(
+        &mut self,
+        column_costs: &[M::Value],
+    ) -> Result<(), LAPError> {
+        for row in self.matrix.row_indices() {
+            // SAFETY: the row comes from the row indices of the matrix.
+            if unsafe { self.matrix.sparse_row_unchecked(row) }.next().is_none() {
+                return Err(LAPError::InfeasibleAssignment);
+            }
+        }
 
+        self.column_costs.copy_from_slice(column_costs);
+        for row in self.matrix.row_indices() {
+            let mut cheapest: Option<(M::ColumnIndex, M::Value)> = None;
+            // SAFETY: the row comes from the row indices of the matrix.
+            for (col, cost) in unsafe { Self::sparse_row_entries(self.matrix, row) } {
+                validate_lap_value_against_max(cost, self.max_cost)?;
+                let reduced = cost - self.column_costs[col.as_()];
+                if cheapest.is_none_or(|(_, minimum)| reduced < minimum) {
+                    cheapest = Some((col, reduced));
+                }
+            }
+            let Some((col, _)) = cheapest else {
+                unreachable!("Every row has at least one sparse entry");
+            };
+            if self.assigned_rows[col.as_()].is_unassigned() {
+                self.assigned_rows[col.as_()] = AssignmentState::Assigned(row);
+                self.assigned_columns[row.as_()] = AssignmentState::Assigned(col);
+            }
+        }
         Ok(())
     }
 
@@ -136,8 +313,9 @@ where
     ///
     /// - Unassigned rows are pushed onto `unassigned_rows`.
     /// - Conflict rows are resolved (the last-won column is kept).
-    /// - Assigned rows contribute the minimum reduced cost of their other
-    ///   sparse neighbours to lower the dual variable of the assigned column.
+    /// - Assigned rows lower the dual variable of the assigned column until its
+    ///   reduced cost equals the minimum reduced cost of their other sparse
+    ///   neighbours.
     #[inline]
     pub(super) fn reduction_transfer_sparse(&mut self) {
         for row in self.matrix.row_indices() {
@@ -149,12 +327,12 @@ where
                     self.assigned_columns[row.as_()] = AssignmentState::Assigned(col);
                 }
                 AssignmentState::Assigned(col) => {
-                    let min_reduced = self
-                        .matrix
-                        .sparse_row(row)
-                        .zip(self.matrix.sparse_row_values(row))
+                    let mut assigned_cost = None;
+                    // SAFETY: the row comes from the row indices of the matrix.
+                    let min_reduced = unsafe { Self::sparse_row_entries(self.matrix, row) }
                         .filter_map(|(c, cost)| {
                             if c.as_() == col.as_() {
+                                assigned_cost = Some(cost);
                                 None
                             } else {
                                 Some(cost - self.column_costs[c.as_()])
@@ -162,7 +340,18 @@ where
                         })
                         .min_by(TotalOrd::total_cmp)
                         .unwrap_or(self.max_cost);
-                    self.column_costs[col.as_()] -= min_reduced;
+                    let Some(assigned_cost) = assigned_cost else {
+                        unreachable!("Row assigned to column must have an entry for that column");
+                    };
+                    // After the column reduction the dual variable equals
+                    // the assigned cost, but not after a warm start, where
+                    // the `max_cost` bound of a row without other entries may
+                    // exceed its reduced cost: the dual is then only ever
+                    // lowered, so that the other rows keep their minima.
+                    let column_cost = assigned_cost - min_reduced;
+                    if column_cost < self.column_costs[col.as_()] {
+                        self.column_costs[col.as_()] = column_cost;
+                    }
                 }
             }
         }
@@ -174,6 +363,9 @@ where
         let matrix = self.matrix;
         let max_cost = self.max_cost;
         let number_of_rows = matrix.number_of_rows().as_();
+        let row_reductions = &mut self.counters.row_reductions;
+        #[cfg(feature = "simd")]
+        let mut reduced_costs: Vec<M::Value> = Vec::new();
         augmenting_row_reduction_impl(
             &mut self.unassigned_rows,
             &mut self.assigned_rows,
@@ -181,37 +373,106 @@ where
             &mut self.column_costs,
             number_of_rows,
             |row, col_costs| {
-                let mut iter = matrix
-                    .sparse_row(row)
-                    .zip(matrix.sparse_row_values(row))
-                    .map(|(col, cost)| (col, cost - col_costs[col.as_()]));
-
-                let (mut first_col, mut first_val) = iter
-                    .next()
-                    .expect("Every row must have at least one sparse entry after column_reduction");
-
-                let mut second_col: Option<M::ColumnIndex> = None;
-                let mut second_val = max_cost;
-
-                for (col, val) in iter {
-                    if val < second_val {
-                        if val >= first_val {
-                            second_col = Some(col);
-                            second_val = val;
-                        } else {
-                            second_col = Some(first_col);
-                            second_val = first_val;
-                            first_col = col;
-                            first_val = val;
+                row_reductions.increment();
+                #[cfg(feature = "simd")]
+                {
+                    reduced_costs.clear();
+                    reduced_costs.extend(
+                        // SAFETY: the unassigned rows are rows of the matrix.
+                        unsafe { Self::sparse_row_entries(matrix, row) }
+                            .map(|(col, cost)| cost - col_costs[col.as_()]),
+                    );
+                    // The scalar scan below may only differ when the second
+                    // minimum is not smaller than `max_cost`, so the lanes
+                    // are used whenever it is.
+                    if let Some((first, second)) = crate::traits::lanes::two_smallest_positions(
+                        &reduced_costs,
+                        M::Value::max_value(),
+                    ) {
+                        if reduced_costs[second] < max_cost {
+                            let column_at = |position: usize| {
+                                // SAFETY: the unassigned rows are rows of the matrix.
+                                unsafe { matrix.sparse_row_unchecked(row) }
+                                    .nth(position)
+                                    .unwrap_or_else(|| {
+                                        unreachable!("The positions are within the row.")
+                                    })
+                            };
+                            return (
+                                (column_at(first), reduced_costs[first]),
+                                (Some(column_at(second)), reduced_costs[second]),
+                            );
                         }
                     }
                 }
 
-                ((first_col, first_val), (second_col, second_val))
+                Self::first_and_second_min_sparse(matrix, max_cost, row, col_costs)
             },
         );
     }
 
+    /// Phase 3 with the minimum reduced costs of the unassigned rows computed
+    /// over the `rayon` thread pool, yielding the same result.
+    #[cfg(feature = "parallel")]
+    #[inline]
+    pub(super) fn par_augmenting_row_reduction_sparse(&mut self)
+    where
+        M: Sync,
+        M::Value: Send + Sync,
+        M::RowIndex: Send + Sync,
+        M::ColumnIndex: Send + Sync,
+    {
+        let matrix = self.matrix;
+        let max_cost = self.max_cost;
+        let row_reductions = par_augmenting_row_reduction_impl(
+            &mut self.unassigned_rows,
+            &mut self.assigned_rows,
+            &mut self.assigned_columns,
+            &mut self.column_costs,
+            matrix.number_of_rows().as_(),
+            |row, col_costs| Self::first_and_second_min_sparse(matrix, max_cost, row, col_costs),
+        );
+        self.counters.row_reductions.merge(row_reductions);
+    }
+
+    /// Returns the first and second minimum reduced costs of the row, with
+    /// their columns, the second one being `max_cost` without a column when
+    /// the row has no other reduced cost below it.
+    #[allow(clippy::type_complexity)]
+    fn first_and_second_min_sparse(
+        matrix: &M,
+        max_cost: M::Value,
+        row: M::RowIndex,
+        col_costs: &[M::Value],
+    ) -> ((M::ColumnIndex, M::Value), (Option<M::ColumnIndex>, M::Value)) {
+        // SAFETY: the unassigned rows are rows of the matrix.
+        let mut iter = unsafe { Self::sparse_row_entries(matrix, row) }
+            .map(|(col, cost)| (col, cost - col_costs[col.as_()]));
+
+        let (mut first_col, mut first_val) = iter
+            .next()
+            .expect("Every row must have at least one sparse entry after column_reduction");
+
+        let mut second_col: Option<M::ColumnIndex> = None;
+        let mut second_val = max_cost;
+
+        // The first minimum is compared on its own: after a warm start it may
+        // exceed `max_cost`, which only bounds the second one.
+        for (col, val) in iter {
+            if val < first_val {
+                second_col = Some(first_col);
+                second_val = first_val;
+                first_col = col;
+                first_val = val;
+            } else if val < second_val {
+                second_col = Some(col);
+                second_val = val;
+            }
+        }
+
+        ((first_col, first_val), (second_col, second_val))
+    }
+
     // -----------------------------------------------------------------------
     // Augmentation (Dijkstra-style path search over sparse structure)
     // -----------------------------------------------------------------------
@@ -301,20 +562,20 @@ where
 
             ready[n_ready] = col;
             n_ready += 1;
+            self.counters.scanned_columns.increment();
 
             let minimum_distance = distances[col.as_()];
 
             // Compute h = cost(row,col) - column_cost[col] - d[col].
-            let initial_reduced = self
-                .matrix
-                .sparse_row(row)
-                .zip(self.matrix.sparse_row_values(row))
+            // SAFETY: the rows assigned to columns are rows of the matrix.
+            let initial_reduced = unsafe { Self::sparse_row_entries(self.matrix, row) }
                 .find(|&(c, _)| c.as_() == col.as_())
                 .map(|(_, cost)| cost - self.column_costs[col.as_()] - minimum_distance)
                 .expect("Row assigned to column must have an entry for that column in sparse_row");
 
+            // SAFETY: the rows assigned to columns are rows of the matrix.
             for (neighbour_col, neighbour_cost) in
-                self.matrix.sparse_row(row).zip(self.matrix.sparse_row_values(row))
+                unsafe { Self::sparse_row_entries(self.matrix, row) }
             {
                 let nc_usize = neighbour_col.as_();
                 if done[nc_usize] {
@@ -322,9 +583,13 @@ where
                 }
 
                 let new_dist = neighbour_cost - self.column_costs[nc_usize] - initial_reduced;
-                if new_dist < distances[nc_usize] {
+                // The distance of a column not yet added to `todo` is stale:
+                // warm-start duals may make any reduced distance exceed
+                // `max_cost`, so it cannot serve as the "not reached" value.
+                if !added[nc_usize] || new_dist < distances[nc_usize] {
                     distances[nc_usize] = new_dist;
                     predecessors[nc_usize] = row;
+                    self.counters.relaxations.increment();
 
                     if new_dist <= minimum_distance {
                         if self.assigned_rows[nc_usize].is_unassigned() {
@@ -380,15 +645,15 @@ where
         distances.fill(self.max_cost);
 
         // Seed TODO with sparse neighbours of start_row.
-        for (col, cost) in
-            self.matrix.sparse_row(start_row).zip(self.matrix.sparse_row_values(start_row))
-        {
+        // SAFETY: the start row is one of the unassigned rows of the matrix.
+        for (col, cost) in unsafe { Self::sparse_row_entries(self.matrix, start_row) } {
             let col_usize = col.as_();
             let dist = cost - self.column_costs[col_usize];
 
-            if dist < distances[col_usize] {
+            if !added[col_usize] || dist < distances[col_usize] {
                 distances[col_usize] = dist;
                 predecessors[col_usize] = start_row;
+                self.counters.relaxations.increment();
             }
             if !added[col_usize] {
                 debug_assert!(n_todo < todo.len(), "todo worklist overflow during seeding");
@@ -480,6 +745,7 @@ where
                 &mut self.assigned_columns,
                 unassigned_row,
             );
+            self.counters.augmentations.increment();
         }
 
         Ok(())
@@ -0,0 +1,82 @@
+//! Mutual nearest neighbours of the rows and columns of a cost matrix.
+//!
+//! A row and a column are mutual nearest neighbours when each is the
+//! minimum-cost partner of the other. Unlike a full assignment, the pairs are
+//! found in a single pass over the defined entries, and they are never
+//! challenged by a better global assignment: they form a conservative set of
+//! confident links, such as the anchors between two datasets.
+use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
+
+use num_traits::AsPrimitive;
+
+use crate::traits::{SparseValuedMatrix2D, TotalOrd};
+
+/// Trait providing the mutual nearest neighbours of the rows and columns of a
+/// sparse cost matrix.
+pub trait MutualNearestNeighbors: SparseValuedMatrix2D + Sized
+where
+    Self::Value: TotalOrd + Copy,
+{
+    /// Returns the pairs of rows and columns that are each other's
+    /// minimum-cost partner, sorted by row.
+    ///
+    /// Only the defined entries of the matrix are considered, so that empty
+    /// rows and columns have no partner. Ties are resolved deterministically:
+    /// the partner of a row is the smallest column among its minimum-cost
+    /// entries, and the partner of a column the smallest row among its
+    /// minimum-cost entries. Costs are compared with their total order, in
+    /// which a NaN is larger than any other value.
+    ///
+    /// Every row and every column appear in at most one pair, so the pairs
+    /// form a matching, which is usually only part of any optimal assignment.
+    ///
+    /// # Complexity
+    ///
+    /// O(R + C + E) time and O(C) space, where E is the number of defined
+    /// entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{impls::ValuedCSR2D, prelude::*};
+    ///
+    /// let costs: ValuedCSR2D<usize, usize, usize, f64> =
+    ///     ValuedCSR2D::try_from([[1.0, 2.0, 9.0], [1.5, 4.0, 3.0], [8.0, 1.8, 9.0]]).unwrap();
+    ///
+    /// // Row 1 prefers column 0, which prefers row 0, while rows 0 and 2 are
+    /// // the mutual nearest neighbours of columns 0 and 1.
+    /// assert_eq!(costs.mutual_nearest_neighbors(), vec![(0, 0), (2, 1)]);
+    /// ```
+    #[inline]
+    fn mutual_nearest_neighbors(&self) -> Vec<(Self::RowIndex, Self::ColumnIndex)> {
+        let mut best_rows: Vec<Option<(Self::RowIndex, Self::Value)>> =
+            vec![None; self.number_of_columns().as_()];
+        let mut best_columns: Vec<(Self::RowIndex, Self::ColumnIndex)> = Vec::new();
+        for (row, entries) in self.rows_with_values() {
+            let mut best_column: Option<(Self::ColumnIndex, Self::Value)> = None;
+            for (column, cost) in entries {
+                // Rows are scanned by increasing index, so ties keep the
+                // smallest row of every column, and likewise for columns.
+                let best_row = &mut best_rows[column.as_()];
+                if best_row.is_none_or(|(_, best)| cost.total_cmp(&best) == Ordering::Less) {
+                    *best_row = Some((row, cost));
+                }
+                if best_column.is_none_or(|(_, best)| cost.total_cmp(&best) == Ordering::Less) {
+                    best_column = Some((column, cost));
+                }
+            }
+            if let Some((column, _)) = best_column {
+                best_columns.push((row, column));
+            }
+        }
+        best_columns
+            .into_iter()
+            .filter(|&(row, column)| {
+                best_rows[column.as_()].is_some_and(|(best_row, _)| best_row == row)
+            })
+            .collect()
+    }
+}
+
+impl<M: SparseValuedMatrix2D> MutualNearestNeighbors for M where M::Value: TotalOrd + Copy {}
@@ -146,3 +146,163 @@ pub trait WeisfeilerLehmanColoring: MonoplexMonopartiteGraph {
 }
 
 impl<G> WeisfeilerLehmanColoring for G where G: MonoplexMonopartiteGraph {}
+
+/// Label hashed into the initial hash of every node when no initial labels
+/// are provided.
+const UNIFORM_INITIAL_LABEL: u64 = 0;
+
+#[inline]
+fn stafford_mix13(mut value: u64) -> u64 {
+    value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    value ^ (value >> 31)
+}
+
+/// Folds a sequence of hashes into `state`, so that the result depends on
+/// both the order and the length of the sequence.
+#[inline]
+fn fold_hashes(state: u64, hashes: &[u64]) -> u64 {
+    hashes.iter().fold(stafford_mix13(state ^ hashes.len() as u64), |state, &hash| {
+        stafford_mix13(state.rotate_left(23) ^ hash)
+    })
+}
+
+/// Weisfeiler-Lehman graph hashing for dense node ids.
+///
+/// Unlike [`WeisfeilerLehmanColoring`], whose dense colors are only
+/// meaningful within a single graph, the hashes are computed from the labels
+/// and the structure alone, so they can be compared across graphs: isomorphic
+/// graphs always receive equal hashes, while non-isomorphic graphs collide
+/// only when Weisfeiler-Lehman refinement cannot tell them apart or, with
+/// negligible probability, on a 64-bit hash collision. This makes the hashes
+/// suitable for approximate deduplication and for bucketing similar graphs.
+///
+/// At each iteration, the hash of each node is replaced by the hash of its
+/// current hash and of the sorted multiset of the hashes of its outgoing
+/// neighbors.
+pub trait WlHash: MonoplexMonopartiteGraph {
+    /// Returns the hash of each node after `iterations` refinement rounds,
+    /// starting from a uniform initial label.
+    ///
+    /// The hash of a node summarizes its neighborhood up to distance
+    /// `iterations`.
+    #[must_use]
+    #[inline]
+    fn wl_node_hashes(&self, iterations: usize) -> Vec<u64> {
+        self.wl_node_hashes_with_labels(
+            &vec![UNIFORM_INITIAL_LABEL; self.number_of_nodes().as_()],
+            iterations,
+        )
+    }
+
+    /// Returns the hash of each node after `iterations` refinement rounds,
+    /// starting from caller-provided initial labels.
+    ///
+    /// `initial_labels` must contain exactly one entry per dense node id.
+    #[must_use]
+    #[inline]
+    fn wl_node_hashes_with_labels(&self, initial_labels: &[u64], iterations: usize) -> Vec<u64> {
+        let mut hashes = initial_wl_hashes(self, initial_labels);
+        for _ in 0..iterations {
+            hashes = refine_wl_hashes(self, &hashes);
+        }
+        hashes
+    }
+
+    /// Returns the hash of the whole graph after `iterations` refinement
+    /// rounds, starting from a uniform initial label.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{
+    ///     impls::{CSR2D, SortedVec, SymmetricCSR2D},
+    ///     prelude::*,
+    ///     traits::{EdgesBuilder, VocabularyBuilder, WlHash},
+    /// };
+    ///
+    /// fn build_graph(node_count: usize, edges: Vec<(usize, usize)>) -> UndiGraph<usize> {
+    ///     let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+    ///         .expected_number_of_symbols(node_count)
+    ///         .symbols((0..node_count).enumerate())
+    ///         .build()
+    ///         .unwrap();
+    ///     let edges: SymmetricCSR2D<CSR2D<usize, usize, usize>> = UndiEdgesBuilder::default()
+    ///         .expected_number_of_edges(edges.len())
+    ///         .expected_shape(node_count)
+    ///         .edges(edges.into_iter())
+    ///         .build()
+    ///         .unwrap();
+    ///     UndiGraph::from((nodes, edges))
+    /// }
+    ///
+    /// // The same path on four nodes, labeled in two different ways.
+    /// let path = build_graph(4, vec![(0, 1), (1, 2), (2, 3)]);
+    /// let relabeled_path = build_graph(4, vec![(0, 2), (1, 3), (2, 3)]);
+    /// let star = build_graph(4, vec![(0, 1), (0, 2), (0, 3)]);
+    ///
+    /// assert_eq!(path.wl_hash(3), relabeled_path.wl_hash(3));
+    /// assert_ne!(path.wl_hash(3), star.wl_hash(3));
+    /// ```
+    #[must_use]
+    #[inline]
+    fn wl_hash(&self, iterations: usize) -> u64 {
+        self.wl_hash_with_labels(
+            &vec![UNIFORM_INITIAL_LABEL; self.number_of_nodes().as_()],
+            iterations,
+        )
+    }
+
+    /// Returns the hash of the whole graph after `iterations` refinement
+    /// rounds, starting from caller-provided initial labels.
+    ///
+    /// The graph hash combines the sorted multisets of node hashes of every
+    /// round, including the initial one, so that graphs whose refinement
+    /// diverges at any round receive different hashes.
+    ///
+    /// `initial_labels` must contain exactly one entry per dense node id.
+    #[must_use]
+    #[inline]
+    fn wl_hash_with_labels(&self, initial_labels: &[u64], iterations: usize) -> u64 {
+        let mut hashes = initial_wl_hashes(self, initial_labels);
+        let mut sorted_hashes = hashes.clone();
+        sorted_hashes.sort_unstable();
+        let mut graph_hash = fold_hashes(0, &sorted_hashes);
+        for _ in 0..iterations {
+            hashes = refine_wl_hashes(self, &hashes);
+            sorted_hashes.copy_from_slice(&hashes);
+            sorted_hashes.sort_unstable();
+            graph_hash = fold_hashes(graph_hash, &sorted_hashes);
+        }
+        graph_hash
+    }
+}
+
+fn initial_wl_hashes<G>(graph: &G, initial_labels: &[u64]) -> Vec<u64>
+where
+    G: MonoplexMonopartiteGraph + ?Sized,
+{
+    assert!(
+        initial_labels.len() == graph.number_of_nodes().as_(),
+        "initial labels must contain exactly one entry per dense node id"
+    );
+    initial_labels.iter().map(|&label| stafford_mix13(label)).collect()
+}
+
+fn refine_wl_hashes<G>(graph: &G, hashes: &[u64]) -> Vec<u64>
+where
+    G: MonoplexMonopartiteGraph + ?Sized,
+{
+    let mut neighborhood = Vec::new();
+    graph
+        .node_ids()
+        .map(|node| {
+            neighborhood.clear();
+            neighborhood.extend(graph.successors(node).map(|neighbor| hashes[neighbor.as_()]));
+            neighborhood.sort_unstable();
+            fold_hashes(hashes[node.as_()], &neighborhood)
+        })
+        .collect()
+}
+
+impl<G> WlHash for G where G: MonoplexMonopartiteGraph {}
@@ -0,0 +1,82 @@
+//! Submodule providing the slice reductions accelerated by the `simd` feature.
+//!
+//! The reductions keep one independent accumulator per lane of a fixed-width
+//! block, so that the loops carry no dependency between consecutive values
+//! and the compiler lowers them to vector instructions on stable Rust,
+//! without `unsafe` code nor target-specific intrinsics. The lanes are merged
+//! so that the results are exactly the ones of the scalar scans.
+
+/// Number of lanes of the accumulators, enough to fill the widest common
+/// vector registers with 64-bit values.
+const LANES: usize = 8;
+
+/// Returns the largest key of the values of the slice, or `None` when the
+/// slice is empty.
+#[inline]
+pub(crate) fn max_key<T: Copy, K: Copy + Ord>(values: &[T], key: impl Fn(T) -> K) -> Option<K> {
+    let &first = values.first()?;
+    let mut lanes = [key(first); LANES];
+    let mut chunks = values.chunks_exact(LANES);
+    for chunk in &mut chunks {
+        for (lane, &value) in lanes.iter_mut().zip(chunk) {
+            *lane = (*lane).max(key(value));
+        }
+    }
+    for (lane, &value) in lanes.iter_mut().zip(chunks.remainder()) {
+        *lane = (*lane).max(key(value));
+    }
+    lanes.into_iter().max()
+}
+
+/// Returns the positions of the smallest value of the slice and of the
+/// smallest of the other values, each the earliest one on ties.
+///
+/// Values that are not smaller than `sentinel` are never selected, and
+/// `None` is returned when fewer than two values are smaller than it. The
+/// values must be comparable, which excludes NaNs.
+#[cfg(feature = "alloc")]
+#[inline]
+pub(crate) fn two_smallest_positions<V: Copy + PartialOrd>(
+    values: &[V],
+    sentinel: V,
+) -> Option<(usize, usize)> {
+    let mut firsts = [(sentinel, usize::MAX); LANES];
+    let mut seconds = [(sentinel, usize::MAX); LANES];
+    let mut update = |lane: usize, value: V, position: usize| {
+        // Every lane sees its values by increasing position, so that strict
+        // comparisons keep the earliest value on ties.
+        if value < firsts[lane].0 {
+            seconds[lane] = firsts[lane];
+            firsts[lane] = (value, position);
+        } else if value < seconds[lane].0 {
+            seconds[lane] = (value, position);
+        }
+    };
+    let mut chunks = values.chunks_exact(LANES);
+    let mut offset = 0;
+    for chunk in &mut chunks {
+        for (lane, &value) in chunk.iter().enumerate() {
+            update(lane, value, offset + lane);
+        }
+        offset += LANES;
+    }
+    for (lane, &value) in chunks.remainder().iter().enumerate() {
+        update(lane, value, offset + lane);
+    }
+
+    // The smallest value is the first of some lane, and the smallest of the
+    // others either the second of that lane or the first of another one.
+    let precedes = |left: (V, usize), right: (V, usize)| {
+        left.0 < right.0 || (left.0 == right.0 && left.1 < right.1)
+    };
+    let smallest = |excluded: usize| {
+        firsts
+            .into_iter()
+            .chain(seconds)
+            .filter(|&(_, position)| position != usize::MAX && position != excluded)
+            .reduce(|best, candidate| if precedes(candidate, best) { candidate } else { best })
+    };
+    let (_, first) = smallest(usize::MAX)?;
+    let (_, second) = smallest(first)?;
+    Some((first, second))
+}
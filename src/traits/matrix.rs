@@ -26,6 +26,7 @@ pub use valued_matrix2d::*;
 
 use super::Coordinates;
 use crate::{
+    errors::RankSelectError,
     impls::ImplicitValuedSparseIterator,
     traits::{PositiveInteger, TotalOrd},
 };
@@ -194,6 +195,53 @@ pub trait RankSelectSparseMatrix: SizedSparseMatrix {
     ///
     /// Panics if the sparse index is out of bounds.
     fn select(&self, sparse_index: Self::SparseIndex) -> Self::Coordinates;
+
+    /// Returns the coordinates associated to the provided sparse index.
+    ///
+    /// This is the non-panicking alternative to
+    /// [`select`](RankSelectSparseMatrix::select), for sparse indices which
+    /// have not been validated.
+    ///
+    /// # Arguments
+    ///
+    /// * `sparse_index`: The sparse index of the coordinates to get.
+    ///
+    /// # Errors
+    ///
+    /// * `RankSelectError::SparseIndexOutOfBounds` - The sparse index is not
+    ///   smaller than the number of defined values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{errors::RankSelectError, prelude::*};
+    ///
+    /// let mut matrix: CSR2D<usize, usize, usize> = SparseMatrixMut::with_sparse_shape((2, 2));
+    /// MatrixMut::add(&mut matrix, (1, 0)).unwrap();
+    ///
+    /// assert_eq!(matrix.try_select(0), Ok((1, 0)));
+    /// assert_eq!(
+    ///     matrix.try_select(1),
+    ///     Err(RankSelectError::SparseIndexOutOfBounds {
+    ///         sparse_index: 1,
+    ///         number_of_defined_values: 1
+    ///     })
+    /// );
+    /// ```
+    #[inline]
+    fn try_select(
+        &self,
+        sparse_index: Self::SparseIndex,
+    ) -> Result<Self::Coordinates, RankSelectError> {
+        let number_of_defined_values = self.number_of_defined_values();
+        if sparse_index >= number_of_defined_values {
+            return Err(RankSelectError::SparseIndexOutOfBounds {
+                sparse_index: sparse_index.as_(),
+                number_of_defined_values: number_of_defined_values.as_(),
+            });
+        }
+        Ok(self.select(sparse_index))
+    }
 }
 
 impl<M: RankSelectSparseMatrix> RankSelectSparseMatrix for &M {
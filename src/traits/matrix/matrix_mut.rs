@@ -1,7 +1,69 @@
 //! Submodule defining the `MatrixMut` trait.
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 use super::{Matrix, SparseMatrix};
 
+/// Policy on the entries repeating the coordinates of a previous entry.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum DuplicatePolicy {
+    /// Repeated coordinates are rejected by the matrix, as in
+    /// [`MatrixMut::from_entries`].
+    #[default]
+    Error,
+    /// Only the first entry with the given coordinates is kept.
+    KeepFirst,
+    /// Only the last entry with the given coordinates is kept.
+    KeepLast,
+}
+
+/// Guarantees of the entries provided to
+/// [`MatrixMut::from_entries_with_policy`].
+///
+/// The default policy presumes sorted entries and rejects repeated
+/// coordinates, as [`MatrixMut::from_entries`] does.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct InputPolicy {
+    /// Whether the entries should be sorted by their coordinates before
+    /// being added. The sort is stable, so that the first and last entries
+    /// with repeated coordinates remain the ones of the input.
+    pub sort: bool,
+    /// How repeated coordinates should be handled. Repeated coordinates are
+    /// only detected between consecutive entries, as in sorted input.
+    pub duplicates: DuplicatePolicy,
+}
+
+/// Trait defining an entry of a matrix, from which its coordinates can be
+/// read.
+pub trait MatrixEntry {
+    /// Type of the coordinates of the entry.
+    type Coordinates: Ord + Copy;
+
+    /// Returns the coordinates of the entry.
+    fn coordinates(&self) -> Self::Coordinates;
+}
+
+impl<RowIndex: Ord + Copy, ColumnIndex: Ord + Copy> MatrixEntry for (RowIndex, ColumnIndex) {
+    type Coordinates = Self;
+
+    #[inline]
+    fn coordinates(&self) -> Self::Coordinates {
+        *self
+    }
+}
+
+impl<RowIndex: Ord + Copy, ColumnIndex: Ord + Copy, Value> MatrixEntry
+    for (RowIndex, ColumnIndex, Value)
+{
+    type Coordinates = (RowIndex, ColumnIndex);
+
+    #[inline]
+    fn coordinates(&self) -> Self::Coordinates {
+        (self.0, self.1)
+    }
+}
+
 /// Trait defining a mutable matrix.
 pub trait MatrixMut: Matrix + Default {
     /// Type of the entry of the matrix.
@@ -45,6 +107,68 @@ pub trait MatrixMut: Matrix + Default {
         Ok(matrix)
     }
 
+    #[cfg(feature = "alloc")]
+    /// Creates a new matrix with the given entries, sorting them and handling
+    /// their repeated coordinates as stated by the provided policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `entries` - The entries to add to the matrix.
+    /// * `policy` - The guarantees of the entries.
+    ///
+    /// # Errors
+    ///
+    /// * If the entries are not sorted and the policy does not sort them.
+    /// * If the entry is out of bounds.
+    /// * If the entry is already defined and the policy rejects duplicates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{
+    ///     prelude::*,
+    ///     traits::{DuplicatePolicy, InputPolicy},
+    /// };
+    ///
+    /// let entries = vec![(1, 0, 1.0), (0, 2, 2.0), (1, 0, 3.0)];
+    /// assert!(ValuedCSR2D::<usize, usize, usize, f64>::from_entries(entries.clone()).is_err());
+    ///
+    /// let policy = InputPolicy { sort: true, duplicates: DuplicatePolicy::KeepLast };
+    /// let matrix: ValuedCSR2D<usize, usize, usize, f64> =
+    ///     MatrixMut::from_entries_with_policy(entries, policy).unwrap();
+    /// assert_eq!(SparseMatrix::sparse_coordinates(&matrix).collect::<Vec<_>>(), vec![(0, 2), (1, 0)]);
+    /// assert_eq!(matrix.sparse_values().collect::<Vec<_>>(), vec![2.0, 3.0]);
+    /// ```
+    #[inline]
+    fn from_entries_with_policy<I: IntoIterator<Item = Self::Entry>>(
+        entries: I,
+        policy: InputPolicy,
+    ) -> Result<Self, Self::Error>
+    where
+        Self::Entry: MatrixEntry,
+    {
+        if !policy.sort && policy.duplicates == DuplicatePolicy::Error {
+            return Self::from_entries(entries);
+        }
+        let mut entries: Vec<Self::Entry> = entries.into_iter().collect();
+        if policy.sort {
+            entries.sort_by_key(MatrixEntry::coordinates);
+        }
+        match policy.duplicates {
+            DuplicatePolicy::Error => {}
+            // The entries removed by `dedup_by` are the later ones.
+            DuplicatePolicy::KeepFirst => {
+                entries.dedup_by(|later, earlier| later.coordinates() == earlier.coordinates());
+            }
+            DuplicatePolicy::KeepLast => {
+                entries.reverse();
+                entries.dedup_by(|earlier, later| earlier.coordinates() == later.coordinates());
+                entries.reverse();
+            }
+        }
+        Self::from_entries(entries)
+    }
+
     /// Extends the matrix with the given entries.
     ///
     /// # Arguments
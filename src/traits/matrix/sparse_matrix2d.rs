@@ -1,9 +1,12 @@
 //! Submodule defining traits characterizing a sparse matrix.
 
+use num_traits::AsPrimitive;
+
 use super::{
     BiMatrix2D, Matrix2D, RankSelectSparseMatrix, SizedSparseMatrix, SparseMatrix,
     SymmetricMatrix2D,
 };
+use crate::errors::RankSelectError;
 
 /// Trait defining a sparse bi-dimensional matrix.
 pub trait SparseMatrix2D: Matrix2D + SparseMatrix {
@@ -31,6 +34,24 @@ pub trait SparseMatrix2D: Matrix2D + SparseMatrix {
     /// * `row`: The row index.
     fn sparse_row(&self, row: Self::RowIndex) -> Self::SparseRow<'_>;
 
+    /// Returns an iterator over the sorted sparse columns of a row, without
+    /// checking that the row is within the bounds of the matrix.
+    ///
+    /// The default implementation calls
+    /// [`sparse_row`](SparseMatrix2D::sparse_row).
+    ///
+    /// # Arguments
+    ///
+    /// * `row`: The row index.
+    ///
+    /// # Safety
+    ///
+    /// The row must be smaller than the number of rows of the matrix.
+    #[inline]
+    unsafe fn sparse_row_unchecked(&self, row: Self::RowIndex) -> Self::SparseRow<'_> {
+        self.sparse_row(row)
+    }
+
     /// Returns whether the provided row has an entry for the provided column.
     ///
     /// # Arguments
@@ -68,6 +89,12 @@ impl<M: SparseMatrix2D> SparseMatrix2D for &M {
         (*self).sparse_row(row)
     }
 
+    #[inline]
+    unsafe fn sparse_row_unchecked(&self, row: Self::RowIndex) -> Self::SparseRow<'_> {
+        // SAFETY: the caller upholds the contract of the referenced matrix.
+        unsafe { (*self).sparse_row_unchecked(row) }
+    }
+
     #[inline]
     fn has_entry(&self, row: Self::RowIndex, column: Self::ColumnIndex) -> bool {
         (*self).has_entry(row, column)
@@ -163,6 +190,21 @@ pub trait SizedSparseMatrix2D: SizedRowsSparseMatrix2D + RankSelectSparseMatrix
     /// Returns the rank of a row.
     fn rank_row(&self, row: Self::RowIndex) -> Self::SparseIndex;
 
+    /// Returns the rank of a row, checking that the row is within the bounds
+    /// of the matrix only in debug builds.
+    ///
+    /// The rank returned for a row greater than the number of rows of the
+    /// matrix is unspecified. The default implementation calls
+    /// [`rank_row`](SizedSparseMatrix2D::rank_row).
+    ///
+    /// # Arguments
+    ///
+    /// * `row`: The row index.
+    #[inline]
+    fn rank_row_unchecked(&self, row: Self::RowIndex) -> Self::SparseIndex {
+        self.rank_row(row)
+    }
+
     /// Returns the row associated with a given sparse index.
     ///
     /// # Arguments
@@ -177,22 +219,77 @@ pub trait SizedSparseMatrix2D: SizedRowsSparseMatrix2D + RankSelectSparseMatrix
     /// * `sparse_index`: The sparse index of the column to get.
     fn select_column(&self, sparse_index: Self::SparseIndex) -> Self::ColumnIndex;
 
-    /// Returns the sparse index of the entry at the given row and column,
-    /// or `None` if the entry does not exist.
+    /// Returns the column associated with a given sparse index, without
+    /// checking that the sparse index is within the bounds of the matrix.
+    ///
+    /// The default implementation calls
+    /// [`select_column`](SizedSparseMatrix2D::select_column).
+    ///
+    /// # Arguments
+    ///
+    /// * `sparse_index`: The sparse index of the column to get.
+    ///
+    /// # Safety
+    ///
+    /// The sparse index must be smaller than the number of defined values of
+    /// the matrix.
+    #[inline]
+    unsafe fn select_column_unchecked(&self, sparse_index: Self::SparseIndex) -> Self::ColumnIndex {
+        self.select_column(sparse_index)
+    }
+
+    /// Returns the sparse index of the entry at the given row and column.
     ///
-    /// This is a non-panicking alternative to
-    /// [`rank`](super::RankSelectSparseMatrix::rank).
+    /// This is the non-panicking alternative to
+    /// [`rank`](super::RankSelectSparseMatrix::rank), for coordinates which
+    /// have not been validated.
     ///
     /// # Arguments
     ///
     /// * `row`: The row index.
     /// * `column`: The column index.
+    ///
+    /// # Errors
+    ///
+    /// * `RankSelectError::RowOutOfBounds` - The row is not smaller than the
+    ///   number of rows.
+    /// * `RankSelectError::MissingEntry` - The entry is not defined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{errors::RankSelectError, prelude::*};
+    ///
+    /// let mut matrix: CSR2D<usize, usize, usize> = SparseMatrixMut::with_sparse_shape((2, 3));
+    /// MatrixMut::add(&mut matrix, (0, 2)).unwrap();
+    ///
+    /// assert_eq!(matrix.try_rank(0, 2), Ok(0));
+    /// assert_eq!(matrix.try_rank(0, 1), Err(RankSelectError::MissingEntry { row: 0, column: 1 }));
+    /// assert_eq!(
+    ///     matrix.try_rank(2, 0),
+    ///     Err(RankSelectError::RowOutOfBounds { row: 2, number_of_rows: 2 })
+    /// );
+    /// ```
     #[inline]
-    fn try_rank(&self, row: Self::RowIndex, column: Self::ColumnIndex) -> Option<Self::SparseIndex>
+    fn try_rank(
+        &self,
+        row: Self::RowIndex,
+        column: Self::ColumnIndex,
+    ) -> Result<Self::SparseIndex, RankSelectError>
     where
         Self::ColumnIndex: PartialEq,
     {
-        if self.has_entry(row, column) { Some(self.rank(&(row, column))) } else { None }
+        if row >= self.number_of_rows() {
+            return Err(RankSelectError::RowOutOfBounds {
+                row: row.as_(),
+                number_of_rows: self.number_of_rows().as_(),
+            });
+        }
+        if self.has_entry(row, column) {
+            Ok(self.rank(&(row, column)))
+        } else {
+            Err(RankSelectError::MissingEntry { row: row.as_(), column: column.as_() })
+        }
     }
 
     /// Returns the sparse index of the entry at the given row and column, or
@@ -210,7 +307,83 @@ pub trait SizedSparseMatrix2D: SizedRowsSparseMatrix2D + RankSelectSparseMatrix
     where
         Self::ColumnIndex: PartialEq,
     {
-        self.try_rank(row, column)
+        self.try_rank(row, column).ok()
+    }
+
+    /// Returns the sparse index of the entry at the given row and column, or
+    /// `None` if the entry does not exist.
+    ///
+    /// The CSR matrices find the entry by binary search over the sorted row,
+    /// in O(log d) for a row of d entries, so that the returned index can be
+    /// used to read or update the value of the entry in place.
+    ///
+    /// # Arguments
+    ///
+    /// * `row`: The row index.
+    /// * `column`: The column index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{impls::ValuedCSR2D, prelude::*};
+    ///
+    /// let mut matrix: ValuedCSR2D<usize, usize, usize, f64> =
+    ///     SparseMatrixMut::with_sparse_shape((2, 3));
+    /// for entry in [(0, 1, 1.0), (1, 0, 2.0), (1, 2, 3.0)] {
+    ///     MatrixMut::add(&mut matrix, entry).unwrap();
+    /// }
+    ///
+    /// let rank = matrix.entry_rank(1, 2).unwrap();
+    /// assert_eq!(rank, 2);
+    /// *matrix.select_value_mut(rank) += 1.0;
+    /// assert_eq!(matrix.select_value(rank), 4.0);
+    /// assert_eq!(matrix.entry_rank(1, 1), None);
+    /// ```
+    #[inline]
+    fn entry_rank(
+        &self,
+        row: Self::RowIndex,
+        column: Self::ColumnIndex,
+    ) -> Option<Self::SparseIndex>
+    where
+        Self::ColumnIndex: PartialEq,
+    {
+        self.try_rank(row, column).ok()
+    }
+
+    /// Returns the position of the column among the columns of the row, or
+    /// `None` if the entry does not exist.
+    ///
+    /// The position indexes the items of
+    /// [`sparse_row`](SparseMatrix2D::sparse_row) and of the row values of
+    /// the valued matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `row`: The row index.
+    /// * `column`: The column index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{impls::CSR2D, prelude::*};
+    ///
+    /// let mut matrix: CSR2D<usize, usize, usize> = SparseMatrixMut::with_sparse_shape((2, 4));
+    /// for entry in [(0, 1), (1, 0), (1, 2), (1, 3)] {
+    ///     MatrixMut::add(&mut matrix, entry).unwrap();
+    /// }
+    ///
+    /// assert_eq!(matrix.neighbor_position(1, 2), Some(1));
+    /// assert_eq!(matrix.sparse_row(1).nth(1), Some(2));
+    /// assert_eq!(matrix.neighbor_position(0, 2), None);
+    /// ```
+    #[inline]
+    fn neighbor_position(&self, row: Self::RowIndex, column: Self::ColumnIndex) -> Option<usize>
+    where
+        Self::ColumnIndex: PartialEq,
+    {
+        let rank = self.entry_rank(row, column)?;
+        Some((rank - self.rank_row(row)).as_())
     }
 }
 
@@ -6,10 +6,14 @@ mod dense_valued_matrix2d;
 mod sparse_valued_matrix2d;
 mod sparse_valued_matrix2d_mut;
 mod sparse_valued_matrix2d_ref;
+#[cfg(feature = "alloc")]
+mod value_statistics;
 pub use dense_valued_matrix2d::*;
 pub use sparse_valued_matrix2d::*;
 pub use sparse_valued_matrix2d_mut::*;
 pub use sparse_valued_matrix2d_ref::*;
+#[cfg(feature = "alloc")]
+pub use value_statistics::*;
 
 /// Trait defining a bi-dimensional matrix.
 pub trait ValuedMatrix2D: Matrix2D + ValuedMatrix {}
@@ -2,6 +2,11 @@
 //!
 //! A sparse valued matrix is a matrix where not all the values are defined.
 
+#[cfg(feature = "alloc")]
+use num_traits::ToPrimitive;
+
+#[cfg(feature = "alloc")]
+use super::ValueStatistics;
 use super::ValuedMatrix2D;
 use crate::traits::{
     ImplicitValuedMatrix, SparseMatrix2D, SparseValuedMatrix, TotalOrd, ValuedMatrix,
@@ -103,6 +108,24 @@ pub trait SparseValuedMatrix2D: SparseMatrix2D + ValuedMatrix2D + SparseValuedMa
     /// * `row`: The row.
     fn sparse_row_values(&self, row: Self::RowIndex) -> Self::SparseRowValues<'_>;
 
+    /// Returns an iterator over the values of a row, without checking that
+    /// the row is within the bounds of the matrix.
+    ///
+    /// The default implementation calls
+    /// [`sparse_row_values`](SparseValuedMatrix2D::sparse_row_values).
+    ///
+    /// # Arguments
+    ///
+    /// * `row`: The row index.
+    ///
+    /// # Safety
+    ///
+    /// The row must be smaller than the number of rows of the matrix.
+    #[inline]
+    unsafe fn sparse_row_values_unchecked(&self, row: Self::RowIndex) -> Self::SparseRowValues<'_> {
+        self.sparse_row_values(row)
+    }
+
     #[inline]
     /// Returns the value at the given row and column, if present.
     fn sparse_value_at(&self, row: Self::RowIndex, column: Self::ColumnIndex) -> Option<Self::Value>
@@ -142,6 +165,75 @@ pub trait SparseValuedMatrix2D: SparseMatrix2D + ValuedMatrix2D + SparseValuedMa
     {
         self.row_indices().map(move |row| self.sparse_row_min_value(row))
     }
+
+    #[inline]
+    /// Returns an iterator over the rows of the matrix, each paired with an
+    /// iterator over its sparse columns and their values.
+    ///
+    /// Both the rows and the entries of a row can be iterated from either
+    /// end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{impls::ValuedCSR2D, prelude::*};
+    ///
+    /// let mut matrix: ValuedCSR2D<usize, usize, usize, f64> =
+    ///     SparseMatrixMut::with_sparse_shape((3, 3));
+    /// for entry in [(0, 1, 1.5), (0, 2, 2.5), (2, 0, 3.5)] {
+    ///     MatrixMut::add(&mut matrix, entry).unwrap();
+    /// }
+    ///
+    /// let rows: Vec<(usize, Vec<(usize, f64)>)> =
+    ///     matrix.rows_with_values().map(|(row, entries)| (row, entries.collect())).collect();
+    /// assert_eq!(rows, vec![(0, vec![(1, 1.5), (2, 2.5)]), (1, vec![]), (2, vec![(0, 3.5)])]);
+    ///
+    /// let (last_row, entries) = matrix.rows_with_values().next_back().unwrap();
+    /// assert_eq!((last_row, entries.rev().collect::<Vec<_>>()), (2, vec![(0, 3.5)]));
+    /// ```
+    fn rows_with_values(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = (Self::RowIndex, SparseRowEntries<'_, Self>)> {
+        self.row_indices().map(move |row| (row, SparseRowEntries::new(self, row)))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    /// Returns the distribution statistics of the defined values of the
+    /// matrix, computed in a single pass over its rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::{impls::ValuedCSR2D, prelude::*};
+    ///
+    /// let mut matrix: ValuedCSR2D<usize, usize, usize, f64> =
+    ///     SparseMatrixMut::with_sparse_shape((2, 3));
+    /// for entry in [(0, 0, 1.0), (0, 2, 4.0), (1, 1, 0.0), (1, 2, 3.0)] {
+    ///     MatrixMut::add(&mut matrix, entry).unwrap();
+    /// }
+    ///
+    /// let statistics = matrix.value_statistics();
+    /// assert_eq!(statistics.number_of_values, 4);
+    /// assert_eq!((statistics.minimum, statistics.maximum), (Some(0.0), Some(4.0)));
+    /// assert_eq!(statistics.mean, Some(2.0));
+    /// assert_eq!(statistics.variance, Some(2.5));
+    /// assert_eq!(statistics.median(), Some(2.0));
+    /// assert_eq!(statistics.positive_dynamic_range(), Some(4.0));
+    ///
+    /// // The magnitudes 1, 3 and 4 fall in the bins [1, 2), [2, 4) and [4, 8).
+    /// let bins: Vec<(i32, usize)> =
+    ///     statistics.histogram.iter().map(|bin| (bin.exponent, bin.count)).collect();
+    /// assert_eq!(bins, vec![(0, 1), (1, 1), (2, 1)]);
+    /// ```
+    fn value_statistics(&self) -> ValueStatistics
+    where
+        Self::Value: ToPrimitive,
+    {
+        ValueStatistics::from_values(
+            self.row_indices().flat_map(move |row| self.sparse_row_values(row)),
+        )
+    }
 }
 
 impl<M: SparseValuedMatrix2D> SparseValuedMatrix2D for &M {
@@ -155,6 +247,12 @@ impl<M: SparseValuedMatrix2D> SparseValuedMatrix2D for &M {
         (*self).sparse_row_values(row)
     }
 
+    #[inline]
+    unsafe fn sparse_row_values_unchecked(&self, row: Self::RowIndex) -> Self::SparseRowValues<'_> {
+        // SAFETY: the caller upholds the contract of the referenced matrix.
+        unsafe { (*self).sparse_row_values_unchecked(row) }
+    }
+
     #[inline]
     fn sparse_value_at(&self, row: Self::RowIndex, column: Self::ColumnIndex) -> Option<Self::Value>
     where
@@ -164,6 +262,55 @@ impl<M: SparseValuedMatrix2D> SparseValuedMatrix2D for &M {
     }
 }
 
+/// Iterator over the sparse columns of a row paired with their values.
+pub struct SparseRowEntries<'matrix, M: SparseValuedMatrix2D + ?Sized + 'matrix> {
+    columns: M::SparseRow<'matrix>,
+    values: M::SparseRowValues<'matrix>,
+}
+
+impl<M: SparseValuedMatrix2D + ?Sized> Clone for SparseRowEntries<'_, M> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { columns: self.columns.clone(), values: self.values.clone() }
+    }
+}
+
+impl<'matrix, M: SparseValuedMatrix2D + ?Sized> SparseRowEntries<'matrix, M> {
+    /// Creates a new iterator over the entries of a row.
+    ///
+    /// # Arguments
+    ///
+    /// * `matrix`: The matrix.
+    /// * `row`: The row.
+    #[inline]
+    pub fn new(matrix: &'matrix M, row: M::RowIndex) -> Self {
+        Self { columns: matrix.sparse_row(row), values: matrix.sparse_row_values(row) }
+    }
+}
+
+impl<M: SparseValuedMatrix2D + ?Sized> Iterator for SparseRowEntries<'_, M> {
+    type Item = (M::ColumnIndex, M::Value);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        Some((self.columns.next()?, self.values.next()?))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.columns.size_hint()
+    }
+}
+
+impl<M: SparseValuedMatrix2D + ?Sized> DoubleEndedIterator for SparseRowEntries<'_, M> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // The columns and the values of a row have the same length, so that
+        // their last items are paired.
+        Some((self.columns.next_back()?, self.values.next_back()?))
+    }
+}
+
 /// Iterator over the values of a row.
 pub struct ImplicitValuedSparseRowIterator<'matrix, M: SparseMatrix2D> {
     iter: M::SparseRow<'matrix>,
@@ -0,0 +1,193 @@
+//! Submodule providing the [`ValueStatistics`] summary of the values of a
+//! sparse valued matrix.
+
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use num_traits::ToPrimitive;
+
+/// Probabilities of the quantiles reported in [`ValueStatistics::quantiles`].
+pub const QUANTILE_PROBABILITIES: [f64; 5] = [0.05, 0.25, 0.5, 0.75, 0.95];
+
+/// Exponent of the histogram bin collecting the subnormal magnitudes.
+const SUBNORMAL_EXPONENT: i32 = f64::MIN_EXP - 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// A bin of the base-two logarithmic histogram of [`ValueStatistics`].
+///
+/// The bin counts the values whose magnitude lies in
+/// `[2^exponent, 2^(exponent + 1))`. Subnormal magnitudes are all collected
+/// in the lowest bin, whose lower bound is zero.
+pub struct LogHistogramBin {
+    /// The base-two exponent of the lower bound of the bin.
+    pub exponent: i32,
+    /// The number of values falling in the bin.
+    pub count: usize,
+}
+
+impl LogHistogramBin {
+    /// Returns the inclusive lower bound of the magnitudes in the bin.
+    #[must_use]
+    #[inline]
+    pub fn lower_bound(&self) -> f64 {
+        power_of_two(self.exponent)
+    }
+
+    /// Returns the exclusive upper bound of the magnitudes in the bin.
+    #[must_use]
+    #[inline]
+    pub fn upper_bound(&self) -> f64 {
+        power_of_two(self.exponent + 1)
+    }
+}
+
+/// Returns `2^exponent`, saturating to zero and infinity outside of the
+/// range of the normal numbers.
+fn power_of_two(exponent: i32) -> f64 {
+    if exponent < f64::MIN_EXP - 1 {
+        0.0
+    } else if exponent >= f64::MAX_EXP {
+        f64::INFINITY
+    } else {
+        let biased = u64::try_from(exponent + f64::MAX_EXP - 1).unwrap_or_default();
+        f64::from_bits(biased << (f64::MANTISSA_DIGITS - 1))
+    }
+}
+
+/// Returns the exponent of the histogram bin of a finite non-zero magnitude.
+fn bin_exponent(magnitude: f64) -> i32 {
+    let biased = magnitude.to_bits() >> (f64::MANTISSA_DIGITS - 1);
+    if biased == 0 {
+        SUBNORMAL_EXPONENT
+    } else {
+        i32::try_from(biased).unwrap_or_default() - (f64::MAX_EXP - 1)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Summary of the distribution of the defined values of a sparse matrix.
+///
+/// The moments, extrema, quantiles and histogram only account for the values
+/// which convert to a finite `f64`; the other ones are only counted in
+/// [`number_of_non_finite_values`](ValueStatistics::number_of_non_finite_values).
+pub struct ValueStatistics {
+    /// The number of defined values, including the non-finite ones.
+    pub number_of_values: usize,
+    /// The number of values which are not finite once converted to `f64`.
+    pub number_of_non_finite_values: usize,
+    /// The number of finite values equal to zero.
+    pub number_of_zero_values: usize,
+    /// The number of finite values smaller than zero.
+    pub number_of_negative_values: usize,
+    /// The minimal finite value.
+    pub minimum: Option<f64>,
+    /// The maximal finite value.
+    pub maximum: Option<f64>,
+    /// The minimal strictly positive finite value.
+    pub minimum_positive: Option<f64>,
+    /// The mean of the finite values.
+    pub mean: Option<f64>,
+    /// The population variance of the finite values.
+    pub variance: Option<f64>,
+    /// The quantiles of the finite values at [`QUANTILE_PROBABILITIES`],
+    /// linearly interpolated between the closest ranks.
+    pub quantiles: Option<[f64; QUANTILE_PROBABILITIES.len()]>,
+    /// The base-two logarithmic histogram of the magnitudes of the finite
+    /// non-zero values, sorted by increasing exponent and without empty bins.
+    pub histogram: Vec<LogHistogramBin>,
+}
+
+impl ValueStatistics {
+    /// Computes the statistics of the provided values in a single pass.
+    ///
+    /// # Arguments
+    ///
+    /// * `values`: The values to summarize.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn from_values<V: ToPrimitive>(values: impl IntoIterator<Item = V>) -> Self {
+        let mut number_of_values = 0;
+        let mut number_of_zero_values = 0;
+        let mut number_of_negative_values = 0;
+        let mut minimum_positive: Option<f64> = None;
+        let mut mean = 0.0;
+        let mut sum_of_squared_deviations = 0.0;
+        let mut finite_values = Vec::new();
+        let mut histogram: BTreeMap<i32, usize> = BTreeMap::new();
+
+        for value in values {
+            number_of_values += 1;
+            let Some(value) = value.to_f64().filter(|value| value.is_finite()) else {
+                continue;
+            };
+            if value == 0.0 {
+                number_of_zero_values += 1;
+            } else {
+                if value < 0.0 {
+                    number_of_negative_values += 1;
+                } else if minimum_positive.is_none_or(|minimum| value < minimum) {
+                    minimum_positive = Some(value);
+                }
+                *histogram.entry(bin_exponent(value.abs())).or_default() += 1;
+            }
+            // Welford's update, which avoids the cancellation of the naive
+            // sum of squares.
+            finite_values.push(value);
+            let delta = value - mean;
+            mean += delta / finite_values.len() as f64;
+            sum_of_squared_deviations += delta * (value - mean);
+        }
+
+        let number_of_non_finite_values = number_of_values - finite_values.len();
+        finite_values.sort_unstable_by(f64::total_cmp);
+        let has_values = !finite_values.is_empty();
+
+        Self {
+            number_of_values,
+            number_of_non_finite_values,
+            number_of_zero_values,
+            number_of_negative_values,
+            minimum: finite_values.first().copied(),
+            maximum: finite_values.last().copied(),
+            minimum_positive,
+            mean: has_values.then_some(mean),
+            variance: has_values.then(|| sum_of_squared_deviations / finite_values.len() as f64),
+            quantiles: has_values.then(|| {
+                QUANTILE_PROBABILITIES.map(|probability| quantile(&finite_values, probability))
+            }),
+            histogram: histogram
+                .into_iter()
+                .map(|(exponent, count)| LogHistogramBin { exponent, count })
+                .collect(),
+        }
+    }
+
+    /// Returns the median of the finite values.
+    #[must_use]
+    #[inline]
+    pub fn median(&self) -> Option<f64> {
+        self.quantiles.map(|quantiles| quantiles[2])
+    }
+
+    /// Returns the ratio between the maximal and the minimal strictly
+    /// positive finite values.
+    ///
+    /// Returns `None` when there is no strictly positive finite value.
+    #[must_use]
+    #[inline]
+    pub fn positive_dynamic_range(&self) -> Option<f64> {
+        Some(self.maximum? / self.minimum_positive?)
+    }
+}
+
+/// Returns the quantile of a sorted non-empty slice, linearly interpolated
+/// between the closest ranks.
+#[allow(clippy::cast_precision_loss)]
+fn quantile(sorted: &[f64], probability: f64) -> f64 {
+    let position = probability * (sorted.len() - 1) as f64;
+    // The position is non-negative and at most `sorted.len() - 1`.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let lower = position as usize;
+    let upper = (lower + 1).min(sorted.len() - 1);
+    let fraction = position - lower as f64;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * fraction
+}
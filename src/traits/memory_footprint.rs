@@ -0,0 +1,134 @@
+//! Submodule providing the [`MemoryFootprint`] trait, reporting and
+//! reclaiming the heap memory held by the buffers of a data structure.
+//!
+//! Builders reserve capacity from estimates of the final size, such as
+//! [`SparseMatrixMut::with_sparse_shaped_capacity`](crate::traits::SparseMatrixMut::with_sparse_shaped_capacity),
+//! and overestimates are kept allocated for the lifetime of the structure.
+//! The report tells apart the bytes in use from the allocated ones, so that
+//! the spare capacity can be measured and released with
+//! [`MemoryFootprint::shrink_to_fit`].
+
+use alloc::vec::Vec;
+
+/// The bytes held by one heap buffer of a data structure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BufferUsage {
+    /// The name of the buffer within its data structure.
+    pub name: &'static str,
+    /// The number of bytes holding elements of the buffer.
+    pub used_bytes: usize,
+    /// The number of bytes allocated by the buffer.
+    pub allocated_bytes: usize,
+}
+
+impl BufferUsage {
+    /// Returns the usage of the provided vector.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the buffer within its data structure.
+    /// * `buffer` - The vector backing the buffer.
+    #[inline]
+    #[must_use]
+    pub fn of_vec<T>(name: &'static str, buffer: &Vec<T>) -> Self {
+        Self {
+            name,
+            used_bytes: buffer.len() * core::mem::size_of::<T>(),
+            allocated_bytes: buffer.capacity() * core::mem::size_of::<T>(),
+        }
+    }
+
+    /// Returns the number of allocated bytes not holding elements.
+    #[inline]
+    #[must_use]
+    pub fn spare_bytes(&self) -> usize {
+        self.allocated_bytes - self.used_bytes
+    }
+}
+
+/// The bytes held by the heap buffers of a data structure.
+///
+/// Only the buffers owned directly by the data structure are reported: the
+/// heap memory owned by its elements, such as the contents of `String`
+/// symbols, is not.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct MemoryUsage {
+    /// The usage of every buffer.
+    buffers: Vec<BufferUsage>,
+}
+
+impl MemoryUsage {
+    /// Returns the usage of every buffer.
+    #[inline]
+    #[must_use]
+    pub fn buffers(&self) -> &[BufferUsage] {
+        &self.buffers
+    }
+
+    /// Returns the total number of bytes holding elements.
+    #[inline]
+    #[must_use]
+    pub fn used_bytes(&self) -> usize {
+        self.buffers.iter().map(|buffer| buffer.used_bytes).sum()
+    }
+
+    /// Returns the total number of allocated bytes.
+    #[inline]
+    #[must_use]
+    pub fn allocated_bytes(&self) -> usize {
+        self.buffers.iter().map(|buffer| buffer.allocated_bytes).sum()
+    }
+
+    /// Returns the total number of allocated bytes not holding elements,
+    /// which [`MemoryFootprint::shrink_to_fit`] releases.
+    #[inline]
+    #[must_use]
+    pub fn spare_bytes(&self) -> usize {
+        self.buffers.iter().map(BufferUsage::spare_bytes).sum()
+    }
+
+    /// Appends the usage of another buffer.
+    #[inline]
+    #[must_use]
+    pub fn with_buffer(mut self, buffer: BufferUsage) -> Self {
+        self.buffers.push(buffer);
+        self
+    }
+
+    /// Appends the usage of the buffers of another data structure.
+    #[inline]
+    #[must_use]
+    pub fn with_usage(mut self, other: MemoryUsage) -> Self {
+        self.buffers.extend(other.buffers);
+        self
+    }
+}
+
+/// Trait for data structures reporting and releasing the heap memory of
+/// their buffers.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::prelude::*;
+///
+/// let mut matrix: CSR2D<usize, usize, usize> =
+///     SparseMatrixMut::with_sparse_shaped_capacity((3, 3), 100);
+/// MatrixMut::add(&mut matrix, (0, 1)).unwrap();
+/// MatrixMut::add(&mut matrix, (2, 0)).unwrap();
+///
+/// let usage = matrix.memory_usage();
+/// assert_eq!(usage.buffers().len(), 2);
+/// assert!(usage.spare_bytes() > 0);
+///
+/// matrix.shrink_to_fit();
+/// assert_eq!(matrix.memory_usage().spare_bytes(), 0);
+/// assert_eq!(matrix.memory_usage().used_bytes(), usage.used_bytes());
+/// ```
+pub trait MemoryFootprint {
+    /// Returns the bytes used and allocated by every heap buffer.
+    fn memory_usage(&self) -> MemoryUsage;
+
+    /// Releases the spare capacity of every heap buffer.
+    fn shrink_to_fit(&mut self);
+}
@@ -0,0 +1,366 @@
+//! Submodule providing human-readable renderings of small matrices and
+//! graphs, meant for debugging.
+//!
+//! Sparse matrices are rendered as dense grids whose cells are aligned to
+//! the widest one, with `·` marking the missing entries, and graphs as one
+//! adjacency list per node, using the node symbols. Both are truncated to
+//! the requested number of rows, columns or nodes, the omitted ones being
+//! marked by `…` and `⋮`, so that printing a large structure by mistake
+//! stays readable.
+
+use core::fmt::{self, Display, Formatter, Write};
+
+use num_traits::AsPrimitive;
+
+use super::{
+    Matrix2D, MonoplexMonopartiteGraph, SparseMatrix2D, SparseValuedMatrix2D, TryFromUsize,
+    Vocabulary,
+};
+
+/// The number of rows, columns or nodes shown by the [`Display`]
+/// implementations of the matrices and graphs of the crate.
+pub const DISPLAY_LIMIT: usize = 16;
+
+/// Marker of a missing entry.
+const MISSING: &str = "·";
+/// Marker of the omitted columns or successors.
+const OMITTED_COLUMNS: &str = "…";
+/// Marker of the omitted rows or nodes.
+const OMITTED_ROWS: &str = "⋮";
+
+/// Writer counting the characters written into it.
+struct CharacterCounter(usize);
+
+impl Write for CharacterCounter {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// Returns the number of characters of the rendering of the value.
+fn rendered_width<V: Display>(value: &V) -> usize {
+    let mut counter = CharacterCounter(0);
+    // Writing into the counter never fails, unless the value itself fails.
+    let _ = write!(counter, "{value}");
+    counter.0
+}
+
+/// Writes the cell right-aligned to the provided width.
+fn write_cell<V: Display>(f: &mut Formatter<'_>, cell: &V, width: usize) -> fmt::Result {
+    for _ in rendered_width(cell)..width {
+        f.write_char(' ')?;
+    }
+    write!(f, "{cell}")
+}
+
+/// Writes the rows yielded by the closure, as `(column, value)` pairs sorted
+/// by column, as a grid aligned to its widest cell.
+fn write_grid<I, V>(
+    f: &mut Formatter<'_>,
+    (number_of_rows, number_of_columns): (usize, usize),
+    (max_rows, max_columns): (usize, usize),
+    row: impl Fn(usize) -> I,
+) -> fmt::Result
+where
+    I: Iterator<Item = (usize, V)>,
+    V: Display,
+{
+    let shown_rows = number_of_rows.min(max_rows);
+    let shown_columns = number_of_columns.min(max_columns);
+    let visible =
+        |row_index: usize| row(row_index).take_while(|&(column, _)| column < shown_columns);
+
+    let width = (0..shown_rows)
+        .flat_map(|row_index| visible(row_index).map(|(_, value)| rendered_width(&value)))
+        .fold(MISSING.chars().count(), usize::max);
+
+    for row_index in 0..shown_rows {
+        if row_index > 0 {
+            f.write_char('\n')?;
+        }
+        let mut entries = visible(row_index).peekable();
+        for column in 0..shown_columns {
+            if column > 0 {
+                f.write_char(' ')?;
+            }
+            match entries.next_if(|&(entry_column, _)| entry_column == column) {
+                Some((_, value)) => write_cell(f, &value, width)?,
+                None => write_cell(f, &MISSING, width)?,
+            }
+        }
+        if shown_columns < number_of_columns {
+            write!(f, " {OMITTED_COLUMNS}")?;
+        }
+    }
+    if shown_rows < number_of_rows {
+        if shown_rows > 0 {
+            f.write_char('\n')?;
+        }
+        f.write_str(OMITTED_ROWS)?;
+    }
+    Ok(())
+}
+
+/// Returns the row index of the provided position.
+fn row_index<M: Matrix2D + ?Sized>(row: usize) -> M::RowIndex {
+    M::RowIndex::try_from_usize(row)
+        .unwrap_or_else(|_| unreachable!("The shown rows are rows of the matrix."))
+}
+
+/// Adapter rendering a sparse matrix as a dense grid, with `1` for the
+/// defined entries and `·` for the missing ones.
+///
+/// It is returned by [`DenseDisplay::dense_display`].
+#[derive(Debug, Clone, Copy)]
+pub struct DenseGrid<'a, M: ?Sized> {
+    /// The rendered matrix.
+    matrix: &'a M,
+    /// The maximal number of rendered rows.
+    max_rows: usize,
+    /// The maximal number of rendered columns.
+    max_columns: usize,
+}
+
+impl<M: SparseMatrix2D + ?Sized> Display for DenseGrid<'_, M> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_grid(
+            f,
+            (self.matrix.number_of_rows().as_(), self.matrix.number_of_columns().as_()),
+            (self.max_rows, self.max_columns),
+            |row| self.matrix.sparse_row(row_index::<M>(row)).map(|column| (column.as_(), 1_u8)),
+        )
+    }
+}
+
+/// Adapter rendering a sparse valued matrix as a dense grid of its values,
+/// with `·` for the missing entries.
+///
+/// It is returned by [`DenseValuesDisplay::dense_values_display`].
+#[derive(Debug, Clone, Copy)]
+pub struct DenseValuesGrid<'a, M: ?Sized> {
+    /// The rendered matrix.
+    matrix: &'a M,
+    /// The maximal number of rendered rows.
+    max_rows: usize,
+    /// The maximal number of rendered columns.
+    max_columns: usize,
+}
+
+impl<M: SparseValuedMatrix2D + ?Sized> Display for DenseValuesGrid<'_, M>
+where
+    M::Value: Display,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_grid(
+            f,
+            (self.matrix.number_of_rows().as_(), self.matrix.number_of_columns().as_()),
+            (self.max_rows, self.max_columns),
+            |row| {
+                let row = row_index::<M>(row);
+                self.matrix
+                    .sparse_row(row)
+                    .map(AsPrimitive::as_)
+                    .zip(self.matrix.sparse_row_values(row))
+            },
+        )
+    }
+}
+
+/// Adapter rendering a graph as the adjacency lists of its nodes, using the
+/// node symbols.
+///
+/// It is returned by [`AdjacencyDisplay::adjacency_display`].
+#[derive(Debug, Clone, Copy)]
+pub struct AdjacencyLists<'a, G: ?Sized> {
+    /// The rendered graph.
+    graph: &'a G,
+    /// The maximal number of rendered nodes and successors per node.
+    max_nodes: usize,
+}
+
+impl<G: MonoplexMonopartiteGraph + ?Sized> Display for AdjacencyLists<'_, G>
+where
+    G::NodeSymbol: Display,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let nodes = self.graph.nodes_vocabulary();
+        let symbol = |node_id: &G::NodeId| {
+            nodes
+                .convert(node_id)
+                .unwrap_or_else(|| unreachable!("The node identifiers of the graph have a symbol."))
+        };
+        let number_of_nodes = self.graph.number_of_nodes().as_();
+        for (position, node_id) in self.graph.node_ids().take(self.max_nodes).enumerate() {
+            if position > 0 {
+                f.write_char('\n')?;
+            }
+            write!(f, "{}:", symbol(&node_id))?;
+            let mut successors = self.graph.successors(node_id);
+            for (position, successor_id) in successors.by_ref().take(self.max_nodes).enumerate() {
+                let separator = if position > 0 { ", " } else { " " };
+                write!(f, "{separator}{}", symbol(&successor_id))?;
+            }
+            if successors.next().is_some() {
+                write!(f, ", {OMITTED_COLUMNS}")?;
+            }
+        }
+        if self.max_nodes < number_of_nodes {
+            if self.max_nodes > 0 {
+                f.write_char('\n')?;
+            }
+            f.write_str(OMITTED_ROWS)?;
+        }
+        Ok(())
+    }
+}
+
+/// Trait rendering a sparse matrix as a dense grid of its defined entries.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::prelude::*;
+///
+/// let matrix: CSR2D<usize, usize, usize> =
+///     CSR2D::from_entries(vec![(0, 1), (1, 0), (1, 2)]).unwrap();
+/// assert_eq!(matrix.to_dense_string(2, 3), "· 1 ·\n1 · 1");
+/// assert_eq!(matrix.to_dense_string(1, 2), "· 1 …\n⋮");
+/// ```
+pub trait DenseDisplay: SparseMatrix2D {
+    /// Returns an adapter rendering at most the provided number of rows and
+    /// columns of the matrix, with `1` for the defined entries and `·` for
+    /// the missing ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_rows`: The maximal number of rendered rows.
+    /// * `max_columns`: The maximal number of rendered columns.
+    #[inline]
+    fn dense_display(&self, max_rows: usize, max_columns: usize) -> DenseGrid<'_, Self> {
+        DenseGrid { matrix: self, max_rows, max_columns }
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Renders at most the provided number of rows and columns of the
+    /// matrix, with `1` for the defined entries and `·` for the missing ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_rows`: The maximal number of rendered rows.
+    /// * `max_columns`: The maximal number of rendered columns.
+    #[inline]
+    fn to_dense_string(&self, max_rows: usize, max_columns: usize) -> alloc::string::String {
+        alloc::string::ToString::to_string(&self.dense_display(max_rows, max_columns))
+    }
+}
+
+impl<M: SparseMatrix2D + ?Sized> DenseDisplay for M {}
+
+/// Trait rendering a sparse valued matrix as a dense grid of its values.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{impls::ValuedCSR2D, prelude::*};
+///
+/// let mut matrix: ValuedCSR2D<usize, usize, usize, f64> =
+///     SparseMatrixMut::with_sparse_shape((2, 3));
+/// for entry in [(0, 1, 1.5), (1, 0, 2.0), (1, 2, 10.0)] {
+///     MatrixMut::add(&mut matrix, entry).unwrap();
+/// }
+/// assert_eq!(matrix.to_dense_values_string(2, 3), "  · 1.5   ·\n  2   ·  10");
+/// ```
+pub trait DenseValuesDisplay: SparseValuedMatrix2D
+where
+    Self::Value: Display,
+{
+    /// Returns an adapter rendering at most the provided number of rows and
+    /// columns of the values of the matrix, with `·` for the missing entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_rows`: The maximal number of rendered rows.
+    /// * `max_columns`: The maximal number of rendered columns.
+    #[inline]
+    fn dense_values_display(
+        &self,
+        max_rows: usize,
+        max_columns: usize,
+    ) -> DenseValuesGrid<'_, Self> {
+        DenseValuesGrid { matrix: self, max_rows, max_columns }
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Renders at most the provided number of rows and columns of the values
+    /// of the matrix, with `·` for the missing entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_rows`: The maximal number of rendered rows.
+    /// * `max_columns`: The maximal number of rendered columns.
+    #[inline]
+    fn to_dense_values_string(&self, max_rows: usize, max_columns: usize) -> alloc::string::String {
+        alloc::string::ToString::to_string(&self.dense_values_display(max_rows, max_columns))
+    }
+}
+
+impl<M: SparseValuedMatrix2D + ?Sized> DenseValuesDisplay for M where M::Value: Display {}
+
+/// Trait rendering a graph as the adjacency lists of its nodes.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::{impls::SortedVec, prelude::*, traits::EdgesBuilder};
+///
+/// let nodes: SortedVec<&str> = GenericVocabularyBuilder::default()
+///     .expected_number_of_symbols(3)
+///     .symbols(["a", "b", "c"].into_iter().enumerate())
+///     .build()
+///     .unwrap();
+/// let edges: SquareCSR2D<CSR2D<usize, usize, usize>> = DiEdgesBuilder::default()
+///     .expected_number_of_edges(3)
+///     .expected_shape(3)
+///     .edges(vec![(0, 1), (0, 2), (1, 2)].into_iter())
+///     .build()
+///     .unwrap();
+/// let graph: GenericGraph<SortedVec<&str>, SquareCSR2D<CSR2D<usize, usize, usize>>> =
+///     GenericGraph::from((nodes, edges));
+///
+/// assert_eq!(graph.to_adjacency_string(3), "a: b, c\nb: c\nc:");
+/// assert_eq!(graph.to_adjacency_string(1), "a: b, …\n⋮");
+/// ```
+pub trait AdjacencyDisplay: MonoplexMonopartiteGraph
+where
+    Self::NodeSymbol: Display,
+{
+    /// Returns an adapter rendering the adjacency lists of at most the
+    /// provided number of nodes, each truncated to as many successors.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_nodes`: The maximal number of rendered nodes and successors.
+    #[inline]
+    fn adjacency_display(&self, max_nodes: usize) -> AdjacencyLists<'_, Self> {
+        AdjacencyLists { graph: self, max_nodes }
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Renders the adjacency lists of at most the provided number of nodes,
+    /// each truncated to as many successors.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_nodes`: The maximal number of rendered nodes and successors.
+    #[inline]
+    fn to_adjacency_string(&self, max_nodes: usize) -> alloc::string::String {
+        alloc::string::ToString::to_string(&self.adjacency_display(max_nodes))
+    }
+}
+
+impl<G: MonoplexMonopartiteGraph + ?Sized> AdjacencyDisplay for G where G::NodeSymbol: Display {}
@@ -1,5 +1,8 @@
 //! Trait for calculating similarity between two items.
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 /// Trait for calculating similarity between two items.
 pub trait ScalarSimilarity<L, R> {
     /// The type of the similarity score.
@@ -8,3 +11,40 @@ pub trait ScalarSimilarity<L, R> {
     /// Calculate the similarity between two items.
     fn similarity(&self, left: &L, right: &R) -> Self::Similarity;
 }
+
+#[cfg(feature = "alloc")]
+/// Trait for similarities able to retrieve the items most similar to a given
+/// one without scoring every other item.
+///
+/// Implementations only score the candidates which may have a non-null
+/// similarity with the queried item, such as the neighbours of its
+/// neighbours, so items with null similarity are never returned.
+pub trait TopKSimilarity<N>: ScalarSimilarity<N, N> {
+    /// Returns up to `k` items other than `item` with the highest similarity,
+    /// sorted by decreasing similarity and then by increasing item.
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - The item whose most similar items are retrieved.
+    /// * `k` - The maximum number of returned items.
+    fn top_k_similar(&self, item: &N, k: usize) -> Vec<(N, Self::Similarity)>;
+}
+
+#[cfg(feature = "alloc")]
+/// Keeps the `k` scored items with the highest positive scores, sorted by
+/// decreasing score and then by increasing item.
+pub(crate) fn top_k_by_score<N: Ord>(mut scored: Vec<(N, f64)>, k: usize) -> Vec<(N, f64)> {
+    scored.retain(|(_, score)| *score > 0.0);
+    let order = |left: &(N, f64), right: &(N, f64)| {
+        right.1.total_cmp(&left.1).then_with(|| left.0.cmp(&right.0))
+    };
+    if k < scored.len() {
+        if k == 0 {
+            return Vec::new();
+        }
+        scored.select_nth_unstable_by(k - 1, order);
+        scored.truncate(k);
+    }
+    scored.sort_unstable_by(order);
+    scored
+}
@@ -23,22 +23,60 @@ use core::cmp::Ordering;
 pub trait TotalOrd {
     /// Compare two values.
     fn total_cmp(&self, other: &Self) -> Ordering;
-}
 
-impl TotalOrd for f32 {
+    /// Returns the largest value of the slice according to
+    /// [`total_cmp`](Self::total_cmp), or `None` when the slice is empty.
+    ///
+    /// With the `simd` feature, the implementations for the primitive
+    /// numbers reduce the slice in vectorized lanes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geometric_traits::traits::TotalOrd;
+    ///
+    /// assert_eq!(f64::total_max(&[1.0, f64::NAN, 3.0]).map(f64::is_nan), Some(true));
+    /// assert_eq!(i32::total_max(&[4, -2, 7, 1]), Some(7));
+    /// assert_eq!(u8::total_max(&[]), None);
+    /// ```
     #[inline]
-    fn total_cmp(&self, other: &Self) -> Ordering {
-        self.total_cmp(other)
+    fn total_max(values: &[Self]) -> Option<Self>
+    where
+        Self: Sized + Clone,
+    {
+        values.iter().max_by(|left, right| left.total_cmp(right)).cloned()
     }
 }
 
-impl TotalOrd for f64 {
-    #[inline]
-    fn total_cmp(&self, other: &Self) -> Ordering {
-        self.total_cmp(other)
-    }
+/// Implements [`TotalOrd`] for a float type, whose total order is the one of
+/// the signed integer keys of its bit patterns.
+macro_rules! impl_total_ord_float {
+    ($($float:ty => $signed:ty, $unsigned:ty),*) => {
+        $(
+            impl TotalOrd for $float {
+                #[inline]
+                fn total_cmp(&self, other: &Self) -> Ordering {
+                    self.total_cmp(other)
+                }
+
+                #[cfg(feature = "simd")]
+                #[inline]
+                #[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+                fn total_max(values: &[Self]) -> Option<Self> {
+                    // Flipping the magnitude bits of the negative values
+                    // makes the keys ordered as the floats, as in
+                    // `total_cmp`, and the flip is its own inverse.
+                    let flip = |bits: $signed| bits ^ ((((bits >> (<$signed>::BITS - 1)) as $unsigned) >> 1) as $signed);
+                    super::lanes::max_key(values, |value: $float| flip(value.to_bits() as $signed))
+                        .map(|key| <$float>::from_bits(flip(key) as $unsigned))
+                }
+            }
+        )*
+    };
 }
 
+impl_total_ord_float!(f32 => i32, u32, f64 => i64, u64);
+
 macro_rules! impl_total_ord {
     ($($t:ty),*) => {
         $(
@@ -47,6 +85,12 @@ macro_rules! impl_total_ord {
                 fn total_cmp(&self, other: &Self) -> Ordering {
                     self.cmp(other)
                 }
+
+                #[cfg(feature = "simd")]
+                #[inline]
+                fn total_max(values: &[Self]) -> Option<Self> {
+                    super::lanes::max_key(values, |value| value)
+                }
             }
         )*
     };
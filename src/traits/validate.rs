@@ -0,0 +1,29 @@
+//! Submodule providing the [`Validate`] trait, checking the structural
+//! invariants of a data structure.
+//!
+//! The mutable API of the sparse matrices and graphs maintains their
+//! invariants, so that checking them is only needed for data structures
+//! assembled from external parts, such as deserialized or borrowed buffers.
+//! The same checks run when such data structures are built by the crate.
+
+use crate::errors::InvariantError;
+
+/// Trait for data structures able to check their structural invariants.
+///
+/// # Examples
+///
+/// ```
+/// use geometric_traits::prelude::*;
+///
+/// let matrix: CSR2D<usize, usize, usize> =
+///     CSR2D::from_entries(vec![(0, 1), (1, 0), (1, 2)]).unwrap();
+/// assert_eq!(matrix.validate(), Ok(()));
+/// ```
+pub trait Validate {
+    /// Checks the structural invariants of the data structure.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first violated invariant.
+    fn validate(&self) -> Result<(), InvariantError>;
+}
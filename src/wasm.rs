@@ -0,0 +1,302 @@
+//! Submodule providing thin `wasm-bindgen` wrappers, so that graphs can be
+//! built and analysed from JavaScript.
+//!
+//! The wrappers exchange plain typed arrays with JavaScript: matrices and
+//! graphs are built from coordinate arrays, assignments are returned as the
+//! column assigned to each row, and partitions as the community of each node.
+//! Node identifiers are `u32`, as JavaScript has no native 64-bit integers.
+//!
+//! ```js
+//! import { SparseMatrix, lapmod, louvain } from "geometric-traits";
+//!
+//! const costs = new SparseMatrix(2, 2, [0, 0, 1, 1], [0, 1, 0, 1], [4, 1, 2, 8]);
+//! const columns = lapmod(costs, 100); // Int32Array [1, 0]
+//!
+//! const graph = SparseMatrix.undirected(4, [0, 2, 1], [1, 3, 2], [1, 1, 0.1]);
+//! const { partition, modularity } = louvain(graph, 1.0, 42n);
+//! ```
+
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    impls::ValuedCSR2D,
+    traits::{
+        Jaqaman, LAPMOD, Louvain, LouvainConfig, Matrix2D, MatrixMut, SizedSparseMatrix,
+        SparseMatrixMut,
+    },
+};
+
+/// The matrix type backing [`WasmSparseMatrix`].
+type Csr = ValuedCSR2D<usize, usize, usize, f64>;
+
+/// Sparse matrix of `f64` values, used both as a cost matrix and as the
+/// weighted adjacency matrix of a graph.
+#[wasm_bindgen(js_name = SparseMatrix)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct WasmSparseMatrix {
+    matrix: Csr,
+}
+
+/// Converts a JavaScript index into a matrix index.
+fn to_index(index: u32) -> usize {
+    usize::try_from(index).unwrap_or_else(|_| unreachable!("wasm32 indices fit in usize"))
+}
+
+/// Converts a matrix index into a JavaScript index.
+fn to_u32(index: usize) -> u32 {
+    u32::try_from(index).unwrap_or_else(|_| unreachable!("indices of u32-sized matrices fit"))
+}
+
+impl WasmSparseMatrix {
+    /// Builds the matrix from coordinate arrays, in any order.
+    ///
+    /// # Errors
+    ///
+    /// * If the arrays have different lengths.
+    /// * If a coordinate is out of bounds or repeated.
+    pub fn try_from_coordinates(
+        number_of_rows: u32,
+        number_of_columns: u32,
+        rows: &[u32],
+        columns: &[u32],
+        values: &[f64],
+    ) -> Result<Self, String> {
+        if i32::try_from(number_of_columns).is_err() {
+            return Err(format!("The number of columns {number_of_columns} exceeds i32::MAX"));
+        }
+        if rows.len() != columns.len() || rows.len() != values.len() {
+            return Err(format!(
+                "The rows, columns and values have {}, {} and {} entries",
+                rows.len(),
+                columns.len(),
+                values.len()
+            ));
+        }
+        let mut entries: Vec<(u32, u32, f64)> = rows
+            .iter()
+            .zip(columns)
+            .zip(values)
+            .map(|((&row, &column), &value)| (row, column, value))
+            .collect();
+        entries.sort_unstable_by_key(|&(row, column, _)| (row, column));
+
+        let mut matrix: Csr = SparseMatrixMut::with_sparse_shaped_capacity(
+            (to_index(number_of_rows), to_index(number_of_columns)),
+            entries.len(),
+        );
+        for (row, column, value) in entries {
+            if row >= number_of_rows || column >= number_of_columns {
+                return Err(format!("The coordinate ({row}, {column}) is out of bounds"));
+            }
+            MatrixMut::add(&mut matrix, (to_index(row), to_index(column), value))
+                .map_err(|_| format!("The coordinate ({row}, {column}) is repeated"))?;
+        }
+        Ok(Self { matrix })
+    }
+
+    /// Builds the symmetric adjacency matrix of an undirected weighted graph.
+    ///
+    /// # Errors
+    ///
+    /// * If the arrays have different lengths.
+    /// * If a node is out of bounds, or an edge is repeated in either
+    ///   direction.
+    pub fn try_undirected(
+        number_of_nodes: u32,
+        sources: &[u32],
+        destinations: &[u32],
+        weights: &[f64],
+    ) -> Result<Self, String> {
+        if sources.len() != destinations.len() || sources.len() != weights.len() {
+            return Err(format!(
+                "The sources, destinations and weights have {}, {} and {} entries",
+                sources.len(),
+                destinations.len(),
+                weights.len()
+            ));
+        }
+        let mut rows = Vec::with_capacity(2 * sources.len());
+        let mut columns = Vec::with_capacity(2 * sources.len());
+        let mut values = Vec::with_capacity(2 * sources.len());
+        for ((&source, &destination), &weight) in sources.iter().zip(destinations).zip(weights) {
+            rows.push(source);
+            columns.push(destination);
+            values.push(weight);
+            if source != destination {
+                rows.push(destination);
+                columns.push(source);
+                values.push(weight);
+            }
+        }
+        Self::try_from_coordinates(number_of_nodes, number_of_nodes, &rows, &columns, &values)
+    }
+
+    /// Returns the column assigned to each row, or `-1` for the unassigned
+    /// rows.
+    fn assignment_by_row(&self, assignment: Vec<(usize, usize)>) -> Vec<i32> {
+        let mut by_row = vec![-1; self.matrix.number_of_rows()];
+        for (row, column) in assignment {
+            by_row[row] = i32::try_from(column).unwrap_or_else(|_| {
+                unreachable!("the number of columns was checked to fit in i32")
+            });
+        }
+        by_row
+    }
+
+    /// Solves the square assignment problem with LAPMOD.
+    ///
+    /// # Errors
+    ///
+    /// If LAPMOD rejects the matrix or finds no perfect assignment.
+    pub fn try_lapmod(&self, max_cost: f64) -> Result<Vec<i32>, String> {
+        let assignment = self.matrix.lapmod(max_cost).map_err(|error| error.to_string())?;
+        Ok(self.assignment_by_row(assignment))
+    }
+
+    /// Solves the rectangular assignment problem with the Jaqaman diagonal
+    /// cost extension.
+    ///
+    /// # Errors
+    ///
+    /// If the costs are rejected or the expanded problem cannot be solved.
+    pub fn try_jaqaman(&self, padding_cost: f64, max_cost: f64) -> Result<Vec<i32>, String> {
+        let assignment =
+            self.matrix.jaqaman(padding_cost, max_cost).map_err(|error| error.to_string())?;
+        Ok(self.assignment_by_row(assignment))
+    }
+
+    /// Detects communities with Louvain.
+    ///
+    /// # Errors
+    ///
+    /// If the matrix is not symmetric, holds non-positive weights, or the
+    /// configuration is invalid.
+    pub fn try_louvain(&self, resolution: f64, seed: u64) -> Result<WasmPartition, String> {
+        let config = LouvainConfig { resolution, seed, ..LouvainConfig::default() };
+        let result =
+            Louvain::<usize>::louvain(&self.matrix, &config).map_err(|error| error.to_string())?;
+        Ok(WasmPartition {
+            partition: result.final_partition().iter().copied().map(to_u32).collect(),
+            modularity: result.final_modularity(),
+        })
+    }
+}
+
+#[wasm_bindgen(js_class = SparseMatrix)]
+impl WasmSparseMatrix {
+    /// Builds the matrix from coordinate arrays, in any order.
+    ///
+    /// # Errors
+    ///
+    /// * If the arrays have different lengths.
+    /// * If a coordinate is out of bounds or repeated.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        number_of_rows: u32,
+        number_of_columns: u32,
+        rows: &[u32],
+        columns: &[u32],
+        values: &[f64],
+    ) -> Result<WasmSparseMatrix, JsError> {
+        Self::try_from_coordinates(number_of_rows, number_of_columns, rows, columns, values)
+            .map_err(|error| JsError::new(&error))
+    }
+
+    /// Builds the symmetric adjacency matrix of an undirected weighted graph.
+    ///
+    /// # Errors
+    ///
+    /// * If the arrays have different lengths.
+    /// * If a node is out of bounds, or an edge is repeated in either
+    ///   direction.
+    pub fn undirected(
+        number_of_nodes: u32,
+        sources: &[u32],
+        destinations: &[u32],
+        weights: &[f64],
+    ) -> Result<WasmSparseMatrix, JsError> {
+        Self::try_undirected(number_of_nodes, sources, destinations, weights)
+            .map_err(|error| JsError::new(&error))
+    }
+
+    /// Returns the number of rows.
+    #[must_use]
+    #[wasm_bindgen(getter = numberOfRows)]
+    pub fn number_of_rows(&self) -> u32 {
+        to_u32(self.matrix.number_of_rows())
+    }
+
+    /// Returns the number of columns.
+    #[must_use]
+    #[wasm_bindgen(getter = numberOfColumns)]
+    pub fn number_of_columns(&self) -> u32 {
+        to_u32(self.matrix.number_of_columns())
+    }
+
+    /// Returns the number of stored values.
+    #[must_use]
+    #[wasm_bindgen(getter = numberOfValues)]
+    pub fn number_of_values(&self) -> u32 {
+        to_u32(self.matrix.number_of_defined_values())
+    }
+}
+
+/// Community of each node, together with the modularity of the partition.
+#[wasm_bindgen(js_name = Partition, getter_with_clone)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct WasmPartition {
+    /// The community of each node.
+    pub partition: Vec<u32>,
+    /// The modularity of the partition.
+    pub modularity: f64,
+}
+
+/// Solves the square assignment problem with LAPMOD, returning the column
+/// assigned to each row.
+///
+/// # Errors
+///
+/// If LAPMOD rejects the matrix or finds no perfect assignment.
+#[wasm_bindgen]
+pub fn lapmod(matrix: &WasmSparseMatrix, max_cost: f64) -> Result<Box<[i32]>, JsError> {
+    matrix.try_lapmod(max_cost).map(Vec::into_boxed_slice).map_err(|error| JsError::new(&error))
+}
+
+/// Solves the rectangular assignment problem with the Jaqaman diagonal cost
+/// extension, returning the column assigned to each row or `-1`.
+///
+/// # Errors
+///
+/// If the costs are rejected or the expanded problem cannot be solved.
+#[wasm_bindgen]
+pub fn jaqaman(
+    matrix: &WasmSparseMatrix,
+    padding_cost: f64,
+    max_cost: f64,
+) -> Result<Box<[i32]>, JsError> {
+    matrix
+        .try_jaqaman(padding_cost, max_cost)
+        .map(Vec::into_boxed_slice)
+        .map_err(|error| JsError::new(&error))
+}
+
+/// Detects communities with Louvain on a symmetric adjacency matrix.
+///
+/// # Errors
+///
+/// If the matrix is not symmetric, holds non-positive weights, or the
+/// configuration is invalid.
+#[wasm_bindgen]
+pub fn louvain(
+    matrix: &WasmSparseMatrix,
+    resolution: f64,
+    seed: u64,
+) -> Result<WasmPartition, JsError> {
+    matrix.try_louvain(resolution, seed).map_err(|error| JsError::new(&error))
+}
@@ -23,6 +23,22 @@ pub fn build_square_csr(
         .unwrap()
 }
 
+/// Draw a random directed acyclic graph as a square CSR matrix, with each
+/// forward edge of a random topological order kept with the probability.
+pub fn random_dag(
+    seed: u64,
+    node_count: usize,
+    probability: f64,
+) -> SquareCSR2D<CSR2D<usize, usize, usize>> {
+    GenericGraph::<usize, SquareCSR2D<CSR2D<usize, usize, usize>>>::randomized_gnp_dag(
+        seed,
+        node_count,
+        probability,
+    )
+    .edges()
+    .clone()
+}
+
 /// Return an absolute path under `tests/fixtures`.
 pub fn fixture_path(relative_path: &str) -> PathBuf {
     Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(relative_path)
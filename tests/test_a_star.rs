@@ -0,0 +1,132 @@
+//! Tests for the A* point-to-point shortest-path search.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::ValuedCSR2D,
+    prelude::*,
+    traits::{AStar, AStarError, EdgesBuilder, algorithms::randomized_graphs::XorShift64},
+};
+
+type TestValCSR = ValuedCSR2D<usize, usize, usize, f64>;
+type TestIntValCSR = ValuedCSR2D<usize, usize, usize, i32>;
+
+fn build_matrix(
+    rows: usize,
+    columns: usize,
+    edges: impl IntoIterator<Item = (usize, usize, f64)>,
+) -> TestValCSR {
+    let mut edges: Vec<(usize, usize, f64)> = edges.into_iter().collect();
+    edges.sort_unstable_by_key(|left| (left.0, left.1));
+    GenericEdgesBuilder::<_, TestValCSR>::default()
+        .expected_number_of_edges(edges.len())
+        .expected_shape((rows, columns))
+        .edges(edges.into_iter())
+        .build()
+        .unwrap()
+}
+
+/// Returns a random directed graph with integer weights, some of them null.
+fn random_graph(seed: u64, order: usize, number_of_edges: usize) -> TestIntValCSR {
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(seed));
+    let mut edges: Vec<(usize, usize, i32)> = (0..number_of_edges)
+        .map(|_| {
+            let source = usize::try_from(rng.next().unwrap() % order as u64).unwrap();
+            let destination = usize::try_from(rng.next().unwrap() % order as u64).unwrap();
+            let weight = i32::try_from(rng.next().unwrap() % 10).unwrap();
+            (source, destination, weight)
+        })
+        .collect();
+    edges.sort_unstable_by_key(|&(source, destination, _)| (source, destination));
+    edges.dedup_by_key(|&mut (source, destination, _)| (source, destination));
+    GenericEdgesBuilder::<_, TestIntValCSR>::default()
+        .expected_number_of_edges(edges.len())
+        .expected_shape((order, order))
+        .edges(edges.into_iter())
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_a_star_matches_dijkstra_with_admissible_heuristics() {
+    let order = 20;
+    for seed in 0..20 {
+        let graph = random_graph(seed, order, 50);
+        let all_pairs = graph.pairwise_dijkstra().unwrap();
+        let mut rng = XorShift64::from(XorShift64::normalize_seed(seed + 1000));
+        // Random fractions of the exact remaining distance, which are
+        // admissible but in general not consistent.
+        let fractions: Vec<u64> = (0..order).map(|_| rng.next().unwrap() % 4).collect();
+        for source in 0..order {
+            for destination in 0..order {
+                let exact = |node: usize| all_pairs.value((node, destination)).unwrap_or(0);
+                let fraction =
+                    |node: usize| exact(node) * i32::try_from(fractions[node]).unwrap() / 3;
+                let expected = all_pairs.value((source, destination));
+                for result in [
+                    graph.a_star(source, destination, |_| 0).unwrap(),
+                    graph.a_star(source, destination, exact).unwrap(),
+                    graph.a_star(source, destination, fraction).unwrap(),
+                ] {
+                    assert_eq!(result.as_ref().map(|(distance, _)| *distance), expected);
+                    if let Some((distance, path)) = result {
+                        assert_eq!(path.first(), Some(&source));
+                        assert_eq!(path.last(), Some(&destination));
+                        let length: i32 = path
+                            .windows(2)
+                            .map(|edge| graph.sparse_value_at(edge[0], edge[1]).unwrap())
+                            .sum();
+                        assert_eq!(length, distance, "seed {seed}: {source} -> {destination}");
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_a_star_stops_at_the_destination() {
+    // With the exact heuristic, the invalid weight behind the destination is
+    // never reached, while the null heuristic explores it first.
+    let graph = build_matrix(4, 4, [(0, 1, 1.0), (0, 2, 0.5), (1, 3, 1.0), (2, 2, f64::NAN)]);
+    let exact = [2.0, 1.0, 10.0, 0.0];
+    assert_eq!(graph.a_star(0, 3, |node| exact[node]).unwrap(), Some((2.0, vec![0, 1, 3])));
+    assert_eq!(
+        graph.a_star(0, 3, |_| 0.0),
+        Err(AStarError::NonFiniteWeight { source_id: 2, destination_id: 2 })
+    );
+    assert_eq!(graph.a_star(1, 1, |_| 0.0).unwrap(), Some((0.0, vec![1])));
+    assert_eq!(graph.a_star(3, 0, |_| 0.0).unwrap(), None);
+}
+
+#[test]
+fn test_a_star_errors() {
+    let rectangular = build_matrix(2, 3, [(0, 1, 1.0)]);
+    assert_eq!(
+        rectangular.a_star(0, 1, |_| 0.0),
+        Err(AStarError::NonSquareMatrix { rows: 2, columns: 3 })
+    );
+
+    let graph = build_matrix(3, 3, [(0, 1, 1.0), (1, 2, -1.0)]);
+    assert_eq!(
+        graph.a_star(0, 3, |_| 0.0),
+        Err(AStarError::UnknownNode { node_id: 3, number_of_nodes: 3 })
+    );
+    assert_eq!(
+        graph.a_star(0, 2, |_| 0.0),
+        Err(AStarError::NegativeWeight { source_id: 1, destination_id: 2 })
+    );
+    assert_eq!(
+        graph.a_star(0, 1, |node| if node == 1 { -1.0 } else { 0.0 }),
+        Err(AStarError::InvalidHeuristic { node_id: 1 })
+    );
+    assert_eq!(
+        graph.a_star(0, 1, |_| f64::INFINITY),
+        Err(AStarError::InvalidHeuristic { node_id: 0 })
+    );
+
+    let overflowing = build_matrix(3, 3, [(0, 1, f64::MAX), (1, 2, f64::MAX)]);
+    assert_eq!(
+        overflowing.a_star(0, 2, |_| 0.0),
+        Err(AStarError::NonFiniteDistance { destination_id: 2, via_id: 1 })
+    );
+}
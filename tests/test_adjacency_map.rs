@@ -0,0 +1,73 @@
+//! Tests for the conversions between weighted directed and bipartite graphs
+//! and nested `HashMap` adjacency representations.
+#![cfg(feature = "std")]
+
+use std::collections::HashMap;
+
+use geometric_traits::prelude::*;
+
+#[test]
+fn test_round_trip_keeps_isolated_nodes() {
+    let adjacency: HashMap<String, Vec<(String, f64)>> = HashMap::from([
+        ("b".to_string(), vec![("a".to_string(), 2.0), ("b".to_string(), 0.5)]),
+        ("a".to_string(), vec![("c".to_string(), 1.0)]),
+        ("d".to_string(), Vec::new()),
+    ]);
+    let graph = WeightedDiGraph::try_from(adjacency).unwrap();
+    assert_eq!(graph.number_of_nodes(), 4);
+    assert_eq!(graph.number_of_edges(), 3);
+    assert_eq!(graph.successor_weights(1).collect::<Vec<f64>>(), vec![2.0, 0.5]);
+
+    let map = graph.to_adjacency_map();
+    assert_eq!(map["b"], vec![("a".to_string(), 2.0), ("b".to_string(), 0.5)]);
+    assert_eq!(map["c"], Vec::<(String, f64)>::new());
+    assert_eq!(map["d"], Vec::<(String, f64)>::new());
+    assert_eq!(WeightedDiGraph::try_from(map).unwrap(), graph);
+}
+
+#[test]
+fn test_integer_weights() {
+    let adjacency: HashMap<u32, Vec<(u32, u8)>> = HashMap::from([(7, vec![(3, 4)])]);
+    let graph = WeightedDiGraph::try_from(adjacency).unwrap();
+    assert_eq!(graph.to_adjacency_map(), HashMap::from([(3, Vec::new()), (7, vec![(3, 4.0)])]));
+}
+
+#[test]
+fn test_duplicated_edge() {
+    let adjacency: HashMap<&str, Vec<(&str, f64)>> =
+        HashMap::from([("a", vec![("b", 1.0), ("c", 1.0), ("b", 3.0)])]);
+    assert_eq!(
+        WeightedDiGraph::try_from(adjacency),
+        Err(AdjacencyMapError::DuplicatedEdge { source_symbol: "a", destination_symbol: "b" })
+    );
+}
+
+#[test]
+fn test_bipartite_round_trip() {
+    let adjacency: HashMap<String, Vec<(char, f32)>> = HashMap::from([
+        ("y".to_string(), vec![('b', 2.0), ('a', 0.5)]),
+        ("x".to_string(), vec![('c', 1.0)]),
+        ("z".to_string(), Vec::new()),
+    ]);
+    let graph = WeightedBiGraph::try_from(adjacency).unwrap();
+    assert_eq!(graph.number_of_left_nodes(), 3);
+    assert_eq!(graph.number_of_right_nodes(), 3);
+    assert_eq!(graph.number_of_edges(), 3);
+    assert_eq!(graph.successors(1).collect::<Vec<usize>>(), vec![0, 1]);
+    assert_eq!(graph.successor_weights(1).collect::<Vec<f64>>(), vec![0.5, 2.0]);
+
+    let map = graph.to_adjacency_map();
+    assert_eq!(map["y"], vec![('a', 0.5), ('b', 2.0)]);
+    assert_eq!(map["z"], Vec::<(char, f64)>::new());
+    assert_eq!(WeightedBiGraph::try_from(map).unwrap(), graph);
+}
+
+#[test]
+fn test_bipartite_duplicated_edge() {
+    let adjacency: HashMap<u8, Vec<(&str, f64)>> =
+        HashMap::from([(1, vec![("b", 1.0)]), (2, vec![("a", 1.0), ("a", 3.0)])]);
+    assert_eq!(
+        WeightedBiGraph::try_from(adjacency),
+        Err(AdjacencyMapError::DuplicatedEdge { source_symbol: 2, destination_symbol: "a" })
+    );
+}
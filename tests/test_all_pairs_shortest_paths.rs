@@ -0,0 +1,124 @@
+//! Tests for the all-pairs shortest paths with Johnson's reweighting.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::ValuedCSR2D,
+    prelude::*,
+    traits::{
+        AllPairsShortestPaths, AllPairsShortestPathsError, EdgesBuilder,
+        algorithms::randomized_graphs::XorShift64,
+    },
+};
+
+type TestValCSR = ValuedCSR2D<usize, usize, usize, f64>;
+type TestIntValCSR = ValuedCSR2D<usize, usize, usize, i64>;
+
+fn build_matrix(
+    rows: usize,
+    columns: usize,
+    edges: impl IntoIterator<Item = (usize, usize, f64)>,
+) -> TestValCSR {
+    let mut edges: Vec<(usize, usize, f64)> = edges.into_iter().collect();
+    edges.sort_unstable_by_key(|left| (left.0, left.1));
+    GenericEdgesBuilder::<_, TestValCSR>::default()
+        .expected_number_of_edges(edges.len())
+        .expected_shape((rows, columns))
+        .edges(edges.into_iter())
+        .build()
+        .unwrap()
+}
+
+/// Returns a random directed graph whose integer weights may be negative,
+/// without negative cycles: the weight of every edge `(u, v)` is a
+/// non-negative cost shifted by the difference of the random potentials of
+/// its endpoints.
+fn random_graph(seed: u64, order: usize, number_of_edges: usize) -> TestIntValCSR {
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(seed));
+    let mut random = |bound: u64| usize::try_from(rng.next().unwrap() % bound).unwrap();
+    let potentials: Vec<i64> =
+        (0..order).map(|_| i64::try_from(random(20)).unwrap() - 10).collect();
+    let mut edges: Vec<(usize, usize, i64)> = (0..number_of_edges)
+        .map(|_| {
+            let source = random(order as u64);
+            let destination = random(order as u64);
+            let cost = i64::try_from(random(10)).unwrap();
+            (source, destination, cost + potentials[destination] - potentials[source])
+        })
+        .collect();
+    edges.sort_unstable_by_key(|&(source, destination, _)| (source, destination));
+    edges.dedup_by_key(|&mut (source, destination, _)| (source, destination));
+    GenericEdgesBuilder::<_, TestIntValCSR>::default()
+        .expected_number_of_edges(edges.len())
+        .expected_shape((order, order))
+        .edges(edges.into_iter())
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_johnson_matches_floyd_warshall() {
+    for seed in 0..30 {
+        for (order, number_of_edges) in [(10, 20), (40, 80), (70, 300)] {
+            let graph = random_graph(seed, order, number_of_edges);
+            let expected = graph.floyd_warshall().unwrap();
+            assert_eq!(
+                graph.johnson_all_pairs_shortest_paths().unwrap(),
+                expected,
+                "seed {seed}, order {order}"
+            );
+            assert_eq!(graph.all_pairs_shortest_paths().unwrap(), expected);
+        }
+    }
+}
+
+#[test]
+fn test_johnson_negative_weights_and_self_loops() {
+    let graph = build_matrix(
+        5,
+        5,
+        [(0, 1, 3.0), (1, 2, -2.0), (2, 3, 1.0), (0, 3, 2.5), (3, 3, 4.0), (4, 0, -1.0)],
+    );
+    let distances = graph.johnson_all_pairs_shortest_paths().unwrap();
+    assert_eq!(distances.value((0, 3)), Some(2.0));
+    assert_eq!(distances.value((4, 2)), Some(0.0));
+    assert_eq!(distances.value((3, 3)), Some(0.0));
+    assert_eq!(distances.value((1, 0)), None);
+    assert_eq!(distances, graph.floyd_warshall().unwrap());
+
+    let empty = build_matrix(0, 0, []);
+    assert_eq!(empty.johnson_all_pairs_shortest_paths().unwrap().number_of_rows(), 0);
+}
+
+#[test]
+fn test_johnson_errors() {
+    let rectangular = build_matrix(2, 3, [(0, 1, 1.0)]);
+    assert_eq!(
+        rectangular.johnson_all_pairs_shortest_paths(),
+        Err(AllPairsShortestPathsError::NonSquareMatrix { rows: 2, columns: 3 })
+    );
+    assert_eq!(
+        rectangular.all_pairs_shortest_paths(),
+        Err(AllPairsShortestPathsError::NonSquareMatrix { rows: 2, columns: 3 })
+    );
+
+    let cycle = build_matrix(4, 4, [(0, 1, 1.0), (1, 2, -3.0), (2, 0, 1.0), (2, 3, 1.0)]);
+    assert!(matches!(
+        cycle.johnson_all_pairs_shortest_paths(),
+        Err(AllPairsShortestPathsError::NegativeCycle { .. })
+    ));
+    assert!(matches!(
+        cycle.all_pairs_shortest_paths(),
+        Err(AllPairsShortestPathsError::NegativeCycle { .. })
+    ));
+    let negative_loop = build_matrix(2, 2, [(0, 1, 1.0), (1, 1, -0.5)]);
+    assert!(matches!(
+        negative_loop.johnson_all_pairs_shortest_paths(),
+        Err(AllPairsShortestPathsError::NegativeCycle { .. })
+    ));
+
+    let infinite = build_matrix(2, 2, [(0, 1, f64::NEG_INFINITY)]);
+    assert_eq!(
+        infinite.johnson_all_pairs_shortest_paths(),
+        Err(AllPairsShortestPathsError::NonFiniteWeight { source_id: 0, destination_id: 1 })
+    );
+}
@@ -0,0 +1,173 @@
+//! Tests for the Arrow and Parquet edge-table reader.
+#![cfg(feature = "arrow")]
+
+use std::sync::Arc;
+
+use arrow_array::{
+    ArrayRef, Float64Array, Int32Array, LargeStringArray, RecordBatch, StringArray, StringViewArray,
+};
+use geometric_traits::{
+    io::{ArrowEdgeTableReader, ArrowReadError},
+    prelude::*,
+};
+
+fn node(graph: &impl MonopartiteGraph<NodeSymbol = String, NodeId = usize>, symbol: &str) -> usize {
+    graph.nodes_vocabulary().invert(&symbol.to_string()).unwrap()
+}
+
+fn strings(values: &[&str]) -> ArrayRef {
+    Arc::new(StringArray::from(values.to_vec()))
+}
+
+fn edge_batch(sources: &[&str], targets: &[&str]) -> RecordBatch {
+    RecordBatch::try_from_iter([("source", strings(sources)), ("target", strings(targets))])
+        .unwrap()
+}
+
+#[test]
+fn test_read_digraph_across_batches() {
+    let batches = [edge_batch(&["c", "a"], &["a", "b"]), edge_batch(&["b"], &["c"])];
+    let graph = ArrowEdgeTableReader::default().read_digraph(&batches).unwrap();
+
+    assert_eq!(graph.number_of_nodes(), 3);
+    assert_eq!(graph.number_of_edges(), 3);
+    assert!(graph.has_successor(node(&graph, "c"), node(&graph, "a")));
+    assert!(graph.has_successor(node(&graph, "a"), node(&graph, "b")));
+    assert!(graph.has_successor(node(&graph, "b"), node(&graph, "c")));
+    assert!(!graph.has_successor(node(&graph, "a"), node(&graph, "c")));
+}
+
+#[test]
+fn test_read_undigraph_merges_reversed_edges() {
+    let batches = [edge_batch(&["a", "b", "b"], &["b", "a", "c"])];
+    assert_eq!(
+        ArrowEdgeTableReader::default().read_undigraph(&batches),
+        Err(ArrowReadError::DuplicatedEdge {
+            row: 1,
+            source_symbol: "a".to_string(),
+            destination_symbol: "b".to_string(),
+        })
+    );
+
+    let graph =
+        ArrowEdgeTableReader::default().ignore_duplicates().read_undigraph(&batches).unwrap();
+    assert_eq!(graph.number_of_nodes(), 3);
+    assert!(graph.has_successor(node(&graph, "b"), node(&graph, "a")));
+    assert!(graph.has_successor(node(&graph, "c"), node(&graph, "b")));
+    assert!(!graph.has_successor(node(&graph, "a"), node(&graph, "c")));
+}
+
+#[test]
+fn test_read_weighted_digraph_with_custom_columns() {
+    let batch = RecordBatch::try_from_iter([
+        ("from", Arc::new(LargeStringArray::from(vec!["x", "y"])) as ArrayRef),
+        ("to", Arc::new(StringViewArray::from(vec!["y", "z"])) as ArrayRef),
+        ("score", Arc::new(Int32Array::from(vec![3, 7])) as ArrayRef),
+    ])
+    .unwrap();
+    let graph = ArrowEdgeTableReader::default()
+        .source_column("from")
+        .target_column("to")
+        .weight_column("score")
+        .read_weighted_digraph(&[batch])
+        .unwrap();
+
+    assert_eq!(graph.number_of_edges(), 2);
+    assert_eq!(graph.successor_weights(node(&graph, "x")).collect::<Vec<f64>>(), vec![3.0]);
+    assert_eq!(graph.successor_weights(node(&graph, "y")).collect::<Vec<f64>>(), vec![7.0]);
+}
+
+#[test]
+fn test_duplicates_keep_the_first_weight() {
+    let batch = RecordBatch::try_from_iter([
+        ("source", strings(&["a", "a"])),
+        ("target", strings(&["b", "b"])),
+        ("weight", Arc::new(Float64Array::from(vec![2.0, 5.0])) as ArrayRef),
+    ])
+    .unwrap();
+    let graph = ArrowEdgeTableReader::default()
+        .weight_column("weight")
+        .ignore_duplicates()
+        .read_weighted_digraph(&[batch])
+        .unwrap();
+    assert_eq!(graph.successor_weights(node(&graph, "a")).collect::<Vec<f64>>(), vec![2.0]);
+}
+
+#[test]
+fn test_errors_report_rows_and_columns() {
+    let batches = [edge_batch(&["a"], &["b"])];
+    assert_eq!(
+        ArrowEdgeTableReader::default().target_column("destination").read_digraph(&batches),
+        Err(ArrowReadError::MissingColumn { column: "destination".to_string() })
+    );
+    assert_eq!(
+        ArrowEdgeTableReader::default().read_weighted_digraph(&batches),
+        Err(ArrowReadError::MissingWeightColumn)
+    );
+
+    let batch = RecordBatch::try_from_iter([
+        ("source", strings(&["a", "b"])),
+        ("target", Arc::new(StringArray::from(vec![Some("b"), None])) as ArrayRef),
+    ])
+    .unwrap();
+    assert_eq!(
+        ArrowEdgeTableReader::default().read_digraph(&[edge_batch(&["x"], &["y"]), batch]),
+        Err(ArrowReadError::NullValue { row: 2, column: "target".to_string() })
+    );
+
+    let batch = RecordBatch::try_from_iter([
+        ("source", Arc::new(Int32Array::from(vec![1])) as ArrayRef),
+        ("target", strings(&["b"])),
+    ])
+    .unwrap();
+    assert!(matches!(
+        ArrowEdgeTableReader::default().read_digraph(&[batch]),
+        Err(ArrowReadError::UnsupportedDataType { column, .. }) if column == "source"
+    ));
+
+    let batch = RecordBatch::try_from_iter([
+        ("source", strings(&["a"])),
+        ("target", strings(&["b"])),
+        ("weight", Arc::new(Float64Array::from(vec![f64::NAN])) as ArrayRef),
+    ])
+    .unwrap();
+    assert_eq!(
+        ArrowEdgeTableReader::default().weight_column("weight").read_weighted_digraph(&[batch]),
+        Err(ArrowReadError::InvalidWeight { row: 0 })
+    );
+}
+
+#[cfg(feature = "parquet")]
+#[test]
+fn test_read_parquet_projects_configured_columns() {
+    use parquet::arrow::ArrowWriter;
+
+    let batch = RecordBatch::try_from_iter([
+        ("source", strings(&["a", "b"])),
+        ("comment", strings(&["ignored", "ignored"])),
+        ("target", strings(&["b", "c"])),
+        ("weight", Arc::new(Float64Array::from(vec![0.5, 1.5])) as ArrayRef),
+    ])
+    .unwrap();
+    let path = std::env::temp_dir()
+        .join(format!("geometric_traits_arrow_reader_{}.parquet", std::process::id()));
+    let mut writer =
+        ArrowWriter::try_new(std::fs::File::create(&path).unwrap(), batch.schema(), None).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+
+    let reader = ArrowEdgeTableReader::default().weight_column("weight");
+    let batches = reader.read_parquet_batches(std::fs::File::open(&path).unwrap()).unwrap();
+    assert!(batches.iter().all(|batch| batch.column_by_name("comment").is_none()));
+    let graph = reader.read_weighted_digraph(&batches).unwrap();
+    assert_eq!(graph.number_of_edges(), 2);
+    assert_eq!(graph.successor_weights(node(&graph, "b")).collect::<Vec<f64>>(), vec![1.5]);
+
+    assert_eq!(
+        ArrowEdgeTableReader::default()
+            .source_column("missing")
+            .read_parquet_batches(std::fs::File::open(&path).unwrap()),
+        Err(ArrowReadError::MissingColumn { column: "missing".to_string() })
+    );
+    std::fs::remove_file(&path).unwrap();
+}
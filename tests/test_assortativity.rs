@@ -0,0 +1,143 @@
+//! Tests for the degree and attribute assortativity of undirected graphs.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::{CSR2D, SortedVec, SymmetricCSR2D},
+    prelude::*,
+    traits::{
+        Assortativity, AssortativityError, EdgesBuilder, VocabularyBuilder,
+        algorithms::randomized_graphs::{XorShift64, erdos_renyi_gnp},
+    },
+};
+
+fn undirected(order: usize, edges: &[(usize, usize)]) -> UndiGraph<usize> {
+    let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+        .expected_number_of_symbols(order)
+        .symbols((0..order).enumerate())
+        .build()
+        .unwrap();
+    let mut edges: Vec<(usize, usize)> =
+        edges.iter().map(|&(left, right)| (left.min(right), left.max(right))).collect();
+    edges.sort_unstable();
+    let edges: SymmetricCSR2D<CSR2D<usize, usize, usize>> = UndiEdgesBuilder::default()
+        .expected_number_of_edges(edges.len())
+        .expected_shape(order)
+        .edges(edges.into_iter())
+        .build()
+        .unwrap();
+    UndiGraph::from((nodes, edges))
+}
+
+/// Returns the Pearson correlation of the provided pairs.
+#[allow(clippy::cast_precision_loss)]
+fn pearson(pairs: &[(f64, f64)]) -> f64 {
+    let count = pairs.len() as f64;
+    let (mean_x, mean_y) = pairs
+        .iter()
+        .fold((0.0, 0.0), |(sum_x, sum_y), &(x, y)| (sum_x + x / count, sum_y + y / count));
+    let (mut covariance, mut variance_x, mut variance_y) = (0.0, 0.0, 0.0);
+    for &(x, y) in pairs {
+        covariance += (x - mean_x) * (y - mean_y);
+        variance_x += (x - mean_x).powi(2);
+        variance_y += (y - mean_y).powi(2);
+    }
+    covariance / (variance_x * variance_y).sqrt()
+}
+
+#[test]
+fn test_degree_assortativity_matches_the_pearson_correlation() {
+    for seed in 1..=10 {
+        let matrix = erdos_renyi_gnp(seed, 30, 0.15);
+        let edges: Vec<(usize, usize)> = SparseMatrix::sparse_coordinates(&matrix)
+            .filter(|&(left, right)| left < right)
+            .collect();
+        let graph = undirected(30, &edges);
+        let mut degrees = [0.0; 30];
+        for &(left, right) in &edges {
+            degrees[left] += 1.0;
+            degrees[right] += 1.0;
+        }
+        let pairs: Vec<(f64, f64)> = edges
+            .iter()
+            .flat_map(|&(left, right)| {
+                [(degrees[left], degrees[right]), (degrees[right], degrees[left])]
+            })
+            .collect();
+        let coefficient = graph.degree_assortativity().unwrap();
+        assert!((coefficient - pearson(&pairs)).abs() < 1e-12, "{coefficient}");
+    }
+}
+
+#[test]
+fn test_degree_assortativity_of_special_graphs() {
+    // Stars are perfectly disassortative.
+    let star = undirected(6, &[(0, 1), (0, 2), (0, 3), (0, 4), (0, 5)]);
+    assert!((star.degree_assortativity().unwrap() + 1.0).abs() < 1e-12);
+
+    // Two disjoint stars of distinct sizes joined hub to hub.
+    let hubs = undirected(7, &[(0, 1), (0, 2), (0, 3), (3, 4), (3, 5), (3, 6)]);
+    assert!(hubs.degree_assortativity().unwrap() < 0.0);
+
+    // Regular graphs, graphs without edges and self-loops only.
+    let cycle = undirected(5, &[(0, 1), (1, 2), (2, 3), (3, 4), (0, 4)]);
+    assert_eq!(cycle.degree_assortativity(), None);
+    assert_eq!(undirected(3, &[]).degree_assortativity(), None);
+    assert_eq!(undirected(2, &[(0, 0), (1, 1)]).degree_assortativity(), None);
+
+    // Self-loops are ignored.
+    let with_loop = undirected(6, &[(0, 0), (0, 1), (0, 2), (0, 3), (0, 4), (0, 5)]);
+    assert!((with_loop.degree_assortativity().unwrap() + 1.0).abs() < 1e-12);
+}
+
+#[test]
+#[allow(clippy::cast_precision_loss)]
+fn test_attribute_assortativity() {
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(5));
+    for seed in 1..=10 {
+        let matrix = erdos_renyi_gnp(seed, 25, 0.2);
+        let edges: Vec<(usize, usize)> = SparseMatrix::sparse_coordinates(&matrix)
+            .filter(|&(left, right)| left < right)
+            .collect();
+        let graph = undirected(25, &edges);
+        let labels: Vec<u8> =
+            (0..25).map(|_| u8::try_from(rng.next().unwrap() % 3).unwrap()).collect();
+
+        // Newman's coefficient from the fractions of edges.
+        let total = 2.0 * edges.len() as f64;
+        let mut mixing = [[0.0; 3]; 3];
+        for &(left, right) in &edges {
+            let (left, right) = (usize::from(labels[left]), usize::from(labels[right]));
+            mixing[left][right] += 1.0 / total;
+            mixing[right][left] += 1.0 / total;
+        }
+        let trace: f64 = (0..3).map(|category| mixing[category][category]).sum();
+        let expected: f64 = mixing.iter().map(|row| row.iter().sum::<f64>().powi(2)).sum();
+        let coefficient = graph.attribute_assortativity(&labels).unwrap().unwrap();
+        assert!(((trace - expected) / (1.0 - expected) - coefficient).abs() < 1e-12);
+
+        let matrix = graph.attribute_mixing_matrix(&labels).unwrap();
+        assert_eq!(matrix.len(), 3);
+        for (row, expected_row) in matrix.iter().zip(mixing) {
+            for (&value, expected) in row.iter().zip(expected_row) {
+                assert!((value - expected).abs() < 1e-12);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_attribute_assortativity_edge_cases() {
+    let graph = undirected(4, &[(0, 1), (2, 3)]);
+    assert_eq!(graph.attribute_assortativity(&[0, 0, 1, 1]).unwrap(), Some(1.0));
+    assert_eq!(graph.attribute_assortativity(&[0, 1, 0, 1]).unwrap(), Some(-1.0));
+    assert_eq!(graph.attribute_assortativity(&[2, 2, 2, 2]).unwrap(), None);
+    assert_eq!(
+        graph.attribute_assortativity(&[0, 1, 0]),
+        Err(AssortativityError::LabelCountMismatch { expected: 4, actual: 3 })
+    );
+    assert_eq!(undirected(2, &[]).attribute_assortativity(&[0, 1]).unwrap(), None);
+    assert_eq!(
+        undirected(2, &[]).attribute_mixing_matrix(&[0, 1]).unwrap(),
+        vec![vec![0.0, 0.0], vec![0.0, 0.0]]
+    );
+}
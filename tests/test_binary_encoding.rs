@@ -0,0 +1,191 @@
+//! Tests for the binary encoding of the CSR family of matrices.
+#![cfg(feature = "alloc")]
+
+use geometric_traits::{
+    errors::InvariantError,
+    impls::{CSR2D, SquareCSR2D, SymmetricCSR2D, UpperTriangularCSR2D, ValuedCSR2D},
+    io::{BINARY_FORMAT_VERSION, BINARY_MAGIC, BinaryDecodeError, BinaryEncoding},
+    prelude::*,
+};
+
+type TestCSR2D = CSR2D<usize, usize, usize>;
+
+fn round_trip<T: BinaryEncoding + PartialEq + core::fmt::Debug>(value: &T) {
+    let bytes = value.to_bytes();
+    assert_eq!(bytes[..4], BINARY_MAGIC);
+    assert_eq!(bytes[4], BINARY_FORMAT_VERSION);
+    assert_eq!(&T::from_bytes(&bytes).unwrap(), value);
+}
+
+/// Recomputes the trailing CRC-32 after a deliberate edit of the buffer, so
+/// that the structural validation is exercised rather than the checksum.
+fn reseal(bytes: &mut Vec<u8>) {
+    bytes.truncate(bytes.len() - 4);
+    let mut crc = u32::MAX;
+    for &byte in bytes.iter() {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = (crc >> 1) ^ (0xEDB8_8320 & (crc & 1).wrapping_neg());
+        }
+    }
+    bytes.extend_from_slice(&(!crc).to_le_bytes());
+}
+
+fn sample_csr() -> TestCSR2D {
+    let mut csr: TestCSR2D = SparseMatrixMut::with_sparse_shape((4, 5));
+    MatrixMut::add(&mut csr, (0, 1)).unwrap();
+    MatrixMut::add(&mut csr, (0, 4)).unwrap();
+    MatrixMut::add(&mut csr, (2, 0)).unwrap();
+    csr
+}
+
+#[test]
+fn test_csr2d_round_trip() {
+    round_trip(&sample_csr());
+    round_trip(&TestCSR2D::default());
+
+    let mut narrow: CSR2D<u16, u8, u8> = SparseMatrixMut::with_sparse_shape((3, 3));
+    MatrixMut::add(&mut narrow, (1, 2)).unwrap();
+    round_trip(&narrow);
+    let restored = CSR2D::<u16, u8, u8>::from_bytes(&narrow.to_bytes()).unwrap();
+    assert_eq!(restored.number_of_non_empty_rows(), 1);
+}
+
+#[test]
+fn test_pointer_sized_indices_are_portable() {
+    let bytes = sample_csr().to_bytes();
+    let widened = CSR2D::<u64, u64, u64>::from_bytes(&bytes).unwrap();
+    assert_eq!(widened.sparse_row(0).collect::<Vec<_>>(), vec![1, 4]);
+}
+
+#[test]
+fn test_valued_and_wrapped_matrices_round_trip() {
+    let valued: ValuedCSR2D<u32, u16, u16, f64> =
+        ValuedCSR2D::try_from([[1.0, 0.5], [0.25, 2.0]]).unwrap();
+    round_trip(&valued);
+
+    let mut square: SquareCSR2D<TestCSR2D> = SparseMatrixMut::with_sparse_shape(3);
+    MatrixMut::add(&mut square, (0, 0)).unwrap();
+    MatrixMut::add(&mut square, (0, 2)).unwrap();
+    MatrixMut::add(&mut square, (2, 2)).unwrap();
+    round_trip(&square);
+    let restored = SquareCSR2D::<TestCSR2D>::from_bytes(&square.to_bytes()).unwrap();
+    assert_eq!(restored.number_of_defined_diagonal_values(), 2);
+
+    let mut upper: UpperTriangularCSR2D<TestCSR2D> = SparseMatrixMut::with_sparse_shape(3);
+    MatrixMut::add(&mut upper, (0, 1)).unwrap();
+    MatrixMut::add(&mut upper, (1, 2)).unwrap();
+    round_trip(&upper);
+
+    let symmetric: SymmetricCSR2D<TestCSR2D> = UndiEdgesBuilder::default()
+        .expected_number_of_edges(2)
+        .expected_shape(3)
+        .edges([(0, 1), (1, 2)].into_iter())
+        .build()
+        .unwrap();
+    round_trip(&symmetric);
+}
+
+#[test]
+fn test_header_and_checksum_are_validated() {
+    let bytes = sample_csr().to_bytes();
+
+    assert_eq!(TestCSR2D::from_bytes(&bytes[..6]), Err(BinaryDecodeError::UnexpectedEnd));
+
+    let mut wrong_magic = bytes.clone();
+    wrong_magic[0] = b'X';
+    assert_eq!(TestCSR2D::from_bytes(&wrong_magic), Err(BinaryDecodeError::InvalidMagic));
+
+    let mut flipped = bytes.clone();
+    flipped[10] ^= 0x40;
+    assert!(matches!(
+        TestCSR2D::from_bytes(&flipped),
+        Err(BinaryDecodeError::ChecksumMismatch { .. })
+    ));
+
+    let mut future = bytes.clone();
+    future[4] = BINARY_FORMAT_VERSION + 1;
+    reseal(&mut future);
+    assert_eq!(
+        TestCSR2D::from_bytes(&future),
+        Err(BinaryDecodeError::UnsupportedVersion { found: BINARY_FORMAT_VERSION + 1 })
+    );
+
+    let mut trailing = bytes;
+    trailing.insert(trailing.len() - 4, 0);
+    reseal(&mut trailing);
+    assert_eq!(TestCSR2D::from_bytes(&trailing), Err(BinaryDecodeError::TrailingBytes(1)));
+}
+
+#[test]
+fn test_type_mismatches_are_rejected() {
+    let bytes = sample_csr().to_bytes();
+    assert!(matches!(
+        CSR2D::<u32, usize, usize>::from_bytes(&bytes),
+        Err(BinaryDecodeError::UnexpectedScalar { .. })
+    ));
+    assert!(matches!(
+        SquareCSR2D::<TestCSR2D>::from_bytes(&bytes),
+        Err(BinaryDecodeError::UnexpectedLayout { .. })
+    ));
+
+    let valued: ValuedCSR2D<usize, usize, usize, f32> =
+        ValuedCSR2D::try_from([[1.0, 0.0], [0.0, 2.0]]).unwrap();
+    assert!(matches!(
+        ValuedCSR2D::<usize, usize, usize, u32>::from_bytes(&valued.to_bytes()),
+        Err(BinaryDecodeError::UnexpectedScalar { .. })
+    ));
+}
+
+#[test]
+fn test_illegal_matrices_are_rejected() {
+    // The body of a 1x3 `CSR2D<u8, u8, u8>`: layout and scalar tags, shape,
+    // offsets and column indices, each sequence prefixed by its length.
+    let body = |column_indices: &[u8]| {
+        let mut bytes = BINARY_MAGIC.to_vec();
+        bytes.extend_from_slice(&[BINARY_FORMAT_VERSION, 0x01, 0x01, 0x01, 0x01]);
+        bytes.extend_from_slice(&[1, 3]);
+        bytes.extend_from_slice(&2_u64.to_le_bytes());
+        bytes.extend_from_slice(&[0, u8::try_from(column_indices.len()).unwrap()]);
+        bytes.extend_from_slice(&(column_indices.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(column_indices);
+        bytes.extend_from_slice(&[0; 4]);
+        reseal(&mut bytes);
+        bytes
+    };
+
+    assert!(CSR2D::<u8, u8, u8>::from_bytes(&body(&[0, 2])).is_ok());
+    for (column_indices, error) in [
+        ([2, 0], InvariantError::UnsortedRow { row: 0 }),
+        ([1, 1], InvariantError::UnsortedRow { row: 0 }),
+        ([0, 3], InvariantError::ColumnOutOfBounds { row: 0, column: 3, number_of_columns: 3 }),
+    ] {
+        assert_eq!(
+            CSR2D::<u8, u8, u8>::from_bytes(&body(&column_indices)),
+            Err(BinaryDecodeError::InvalidMatrix(error))
+        );
+    }
+
+    let mut lower: SquareCSR2D<TestCSR2D> = SparseMatrixMut::with_sparse_shape(2);
+    MatrixMut::add(&mut lower, (1, 0)).unwrap();
+    let mut as_upper = lower.to_bytes();
+    // Relabel the `SquareCSR2D` body as an `UpperTriangularCSR2D` body.
+    as_upper[5] = 0x05;
+    reseal(&mut as_upper);
+    assert_eq!(
+        UpperTriangularCSR2D::<TestCSR2D>::from_bytes(&as_upper),
+        Err(BinaryDecodeError::InvalidMatrix(InvariantError::EntryBelowDiagonal {
+            row: 1,
+            column: 0
+        }))
+    );
+    as_upper[5] = 0x04;
+    reseal(&mut as_upper);
+    assert_eq!(
+        SymmetricCSR2D::<TestCSR2D>::from_bytes(&as_upper),
+        Err(BinaryDecodeError::InvalidMatrix(InvariantError::AsymmetricEntry {
+            row: 1,
+            column: 0
+        }))
+    );
+}
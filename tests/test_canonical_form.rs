@@ -0,0 +1,89 @@
+//! Tests for the canonical form of unlabeled simple undirected graphs.
+#![cfg(feature = "std")]
+
+use std::collections::HashSet;
+
+use geometric_traits::{
+    impls::{CSR2D, SortedVec, SymmetricCSR2D},
+    prelude::*,
+    traits::{
+        CanonicalLabeling, EdgesBuilder, VocabularyBuilder,
+        algorithms::randomized_graphs::erdos_renyi_gnp,
+    },
+};
+
+fn undirected(order: usize, edges: &[(usize, usize)]) -> UndiGraph<usize> {
+    let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+        .expected_number_of_symbols(order)
+        .symbols((0..order).enumerate())
+        .build()
+        .unwrap();
+    let mut edges: Vec<(usize, usize)> =
+        edges.iter().map(|&(left, right)| (left.min(right), left.max(right))).collect();
+    edges.sort_unstable();
+    let edges: SymmetricCSR2D<CSR2D<usize, usize, usize>> = UndiEdgesBuilder::default()
+        .expected_number_of_edges(edges.len())
+        .expected_shape(order)
+        .edges(edges.into_iter())
+        .build()
+        .unwrap();
+    UndiGraph::from((nodes, edges))
+}
+
+/// Relabels the node `index` of a graph of order 19 as `5 * index + 2`.
+fn permute(index: usize) -> usize {
+    (5 * index + 2) % 19
+}
+
+#[test]
+fn test_canonical_form_is_invariant_under_relabeling() {
+    for seed in 1..=10 {
+        let matrix = erdos_renyi_gnp(seed, 19, 0.25);
+        let edges: Vec<(usize, usize)> = SparseMatrix::sparse_coordinates(&matrix)
+            .filter(|&(left, right)| left < right)
+            .collect();
+        let permuted: Vec<(usize, usize)> =
+            edges.iter().map(|&(left, right)| (permute(left), permute(right))).collect();
+
+        let graph = undirected(19, &edges);
+        let form = graph.canonical_form();
+        assert_eq!(form, undirected(19, &permuted).canonical_form());
+
+        // The permutation relabels the graph into its canonical edges.
+        let mut sorted = form.permutation.clone();
+        sorted.sort_unstable();
+        assert_eq!(sorted, (0..19).collect::<Vec<_>>());
+        let mut relabeled: Vec<(usize, usize)> = edges
+            .iter()
+            .map(|&(left, right)| {
+                let (left, right) = (form.permutation[left], form.permutation[right]);
+                (left.min(right), left.max(right))
+            })
+            .collect();
+        relabeled.sort_unstable();
+        assert_eq!(relabeled, form.edges);
+        assert_eq!(form.number_of_nodes(), graph.number_of_nodes());
+    }
+}
+
+#[test]
+fn test_canonical_forms_deduplicate_graphs() {
+    let forms: HashSet<_> = [
+        // Three labelings of the path on four nodes.
+        undirected(4, &[(0, 1), (1, 2), (2, 3)]),
+        undirected(4, &[(3, 1), (1, 0), (0, 2)]),
+        undirected(4, &[(2, 0), (0, 3), (3, 1)]),
+        // Two labelings of the star on four nodes.
+        undirected(4, &[(0, 1), (0, 2), (0, 3)]),
+        undirected(4, &[(2, 0), (2, 1), (2, 3)]),
+        // A triangle with an isolated node, and the empty graphs.
+        undirected(4, &[(0, 1), (1, 2), (0, 2)]),
+        undirected(4, &[]),
+        undirected(3, &[]),
+        undirected(0, &[]),
+    ]
+    .iter()
+    .map(CanonicalLabeling::canonical_form)
+    .collect();
+    assert_eq!(forms.len(), 6);
+}
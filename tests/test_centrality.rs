@@ -0,0 +1,240 @@
+//! Tests for the weighted and unweighted betweenness and closeness
+//! centralities.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::ValuedCSR2D,
+    prelude::*,
+    traits::{Centrality, DijkstraError, algorithms::randomized_graphs::XorShift64},
+};
+
+type TestValCSR = ValuedCSR2D<usize, usize, usize, f64>;
+
+/// Builds a square matrix from the sorted and distinct edges.
+fn build(order: usize, edges: &[(usize, usize, f64)]) -> TestValCSR {
+    GenericEdgesBuilder::<_, TestValCSR>::default()
+        .expected_number_of_edges(edges.len())
+        .expected_shape((order, order))
+        .edges(edges.iter().copied())
+        .build()
+        .unwrap()
+}
+
+/// Returns random directed edges with integer weights, including some
+/// self-loops, made symmetric if requested.
+fn random_edges(seed: u64, order: usize, symmetric: bool) -> Vec<(usize, usize, f64)> {
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(seed));
+    let mut random = |bound: usize| usize::try_from(rng.next().unwrap()).unwrap() % bound;
+    let mut edges = Vec::new();
+    for _ in 0..2 * order {
+        let (u, v) = (random(order), random(order));
+        let weight = f64::from(u32::try_from(1 + random(4)).unwrap());
+        edges.push((u, v, weight));
+        if symmetric {
+            edges.push((v, u, weight));
+        }
+    }
+    edges.sort_unstable_by_key(|&(u, v, _)| (u, v));
+    edges.dedup_by_key(|&mut (u, v, _)| (u, v));
+    if symmetric {
+        // Keeps the weight of the first copy of each unordered pair.
+        let weights: Vec<((usize, usize), f64)> =
+            edges.iter().map(|&(u, v, weight)| ((u.min(v), u.max(v)), weight)).collect();
+        for edge in &mut edges {
+            let key = (edge.0.min(edge.1), edge.0.max(edge.1));
+            edge.2 = weights.iter().find(|&&(pair, _)| pair == key).unwrap().1;
+        }
+    }
+    edges
+}
+
+/// Returns the distances and numbers of shortest paths between all pairs,
+/// by Floyd-Warshall and dynamic programming over the nodes sorted by
+/// distance.
+#[allow(clippy::float_cmp)]
+fn all_pairs(order: usize, edges: &[(usize, usize, f64)]) -> (Vec<Vec<f64>>, Vec<Vec<f64>>) {
+    let mut distances = vec![vec![f64::INFINITY; order]; order];
+    for (node, row) in distances.iter_mut().enumerate() {
+        row[node] = 0.0;
+    }
+    for &(u, v, weight) in edges {
+        if u != v {
+            distances[u][v] = distances[u][v].min(weight);
+        }
+    }
+    for via in 0..order {
+        for u in 0..order {
+            for v in 0..order {
+                let candidate = distances[u][via] + distances[via][v];
+                if candidate < distances[u][v] {
+                    distances[u][v] = candidate;
+                }
+            }
+        }
+    }
+    let mut counts = vec![vec![0.0; order]; order];
+    for source in 0..order {
+        let mut nodes: Vec<usize> =
+            (0..order).filter(|&node| distances[source][node].is_finite()).collect();
+        nodes.sort_by(|&left, &right| distances[source][left].total_cmp(&distances[source][right]));
+        counts[source][source] = 1.0;
+        for &node in &nodes[1..] {
+            counts[source][node] = edges
+                .iter()
+                .filter(|&&(u, v, weight)| {
+                    v == node && u != v && distances[source][u] + weight == distances[source][node]
+                })
+                .map(|&(u, _, _)| counts[source][u])
+                .sum();
+        }
+    }
+    (distances, counts)
+}
+
+/// Returns the unnormalized betweenness by its definition.
+#[allow(clippy::float_cmp)]
+fn brute_force_betweenness(distances: &[Vec<f64>], counts: &[Vec<f64>]) -> Vec<f64> {
+    let order = distances.len();
+    (0..order)
+        .map(|node| {
+            let mut score = 0.0;
+            for source in (0..order).filter(|&source| source != node) {
+                for destination in (0..order).filter(|&other| other != node && other != source) {
+                    if distances[source][destination].is_finite()
+                        && distances[source][node] + distances[node][destination]
+                            == distances[source][destination]
+                    {
+                        score += counts[source][node] * counts[node][destination]
+                            / counts[source][destination];
+                    }
+                }
+            }
+            score
+        })
+        .collect()
+}
+
+/// Returns the closeness with the Wasserman-Faust improvement by its
+/// definition.
+fn brute_force_closeness(distances: &[Vec<f64>]) -> Vec<f64> {
+    let order = distances.len();
+    distances
+        .iter()
+        .map(|row| {
+            let reached: Vec<f64> =
+                row.iter().copied().filter(|&distance| distance < f64::INFINITY).collect();
+            let total: f64 = reached.iter().sum();
+            if total <= 0.0 {
+                return 0.0;
+            }
+            let others = f64::from(u32::try_from(reached.len() - 1).unwrap());
+            others / total * others / f64::from(u32::try_from(order - 1).unwrap())
+        })
+        .collect()
+}
+
+/// Asserts that the two vectors are equal up to rounding errors.
+fn assert_close(left: &[f64], right: &[f64], context: &str) {
+    assert_eq!(left.len(), right.len());
+    for (left, right) in left.iter().zip(right) {
+        assert!((left - right).abs() < 1e-9, "{context}: {left} != {right}");
+    }
+}
+
+#[test]
+fn test_centralities_against_brute_force() {
+    let order = 9;
+    for seed in 0..60 {
+        let edges = random_edges(seed, order, seed % 2 == 0);
+        let matrix = build(order, &edges);
+        let (distances, counts) = all_pairs(order, &edges);
+        let context = format!("seed {seed}");
+
+        let betweenness = brute_force_betweenness(&distances, &counts);
+        assert_close(&matrix.betweenness_centrality(false).unwrap(), &betweenness, &context);
+        let scale = f64::from(u32::try_from((order - 1) * (order - 2)).unwrap());
+        let normalized: Vec<f64> = betweenness.iter().map(|score| score / scale).collect();
+        assert_close(&matrix.betweenness_centrality(true).unwrap(), &normalized, &context);
+        assert_close(
+            &matrix.closeness_centrality(true).unwrap(),
+            &brute_force_closeness(&distances),
+            &context,
+        );
+
+        let unit_edges: Vec<(usize, usize, f64)> =
+            edges.iter().map(|&(u, v, _)| (u, v, 1.0)).collect();
+        let (distances, counts) = all_pairs(order, &unit_edges);
+        assert_close(
+            &matrix.unweighted_betweenness_centrality(false).unwrap(),
+            &brute_force_betweenness(&distances, &counts),
+            &context,
+        );
+        assert_close(
+            &matrix.unweighted_closeness_centrality(true).unwrap(),
+            &brute_force_closeness(&distances),
+            &context,
+        );
+    }
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_centralities_of_small_graphs() {
+    let empty = build(0, &[]);
+    assert!(empty.betweenness_centrality(true).unwrap().is_empty());
+    assert!(empty.unweighted_closeness_centrality(true).unwrap().is_empty());
+
+    // A directed path, whose closeness only counts the nodes ahead.
+    let path = build(4, &[(0, 1, 2.0), (1, 2, 2.0), (2, 3, 2.0)]);
+    assert_eq!(path.betweenness_centrality(false).unwrap(), vec![0.0, 2.0, 2.0, 0.0]);
+    assert_eq!(path.closeness_centrality(false).unwrap(), vec![0.25, 1.0 / 3.0, 0.5, 0.0]);
+    assert_eq!(
+        path.unweighted_closeness_centrality(false).unwrap(),
+        vec![0.5, 2.0 / 3.0, 1.0, 0.0]
+    );
+    assert_eq!(
+        path.unweighted_closeness_centrality(true).unwrap(),
+        vec![0.5, 2.0 / 3.0 * 2.0 / 3.0, 1.0 / 3.0, 0.0]
+    );
+
+    // Two shortest paths between 0 and 3 split the betweenness.
+    let square: ValuedCSR2D<usize, usize, usize, u32> =
+        GenericEdgesBuilder::<_, ValuedCSR2D<usize, usize, usize, u32>>::default()
+            .expected_number_of_edges(4)
+            .expected_shape((4, 4))
+            .edges(vec![(0, 1, 1), (0, 2, 2), (1, 3, 2), (2, 3, 1)].into_iter())
+            .build()
+            .unwrap();
+    assert_eq!(square.betweenness_centrality(false).unwrap(), vec![0.0, 0.5, 0.5, 0.0]);
+}
+
+#[test]
+fn test_centrality_errors() {
+    let zero = build(2, &[(0, 1, 0.0)]);
+    assert_eq!(
+        zero.betweenness_centrality(false),
+        Err(DijkstraError::ZeroWeight { source_id: 0, destination_id: 1 })
+    );
+    // The unweighted centralities ignore the weights.
+    assert_eq!(zero.unweighted_betweenness_centrality(false), Ok(vec![0.0, 0.0]));
+    let infinite = build(2, &[(0, 1, 1.0), (1, 0, f64::INFINITY)]);
+    assert_eq!(
+        infinite.closeness_centrality(true),
+        Err(DijkstraError::NonFiniteWeight { source_id: 1, destination_id: 0 })
+    );
+    let overflow = build(3, &[(0, 1, f64::MAX), (1, 2, f64::MAX)]);
+    assert_eq!(
+        overflow.closeness_centrality(true),
+        Err(DijkstraError::NonFiniteDistance { destination_id: 2, via_id: 1 })
+    );
+    let rectangular = GenericEdgesBuilder::<_, TestValCSR>::default()
+        .expected_number_of_edges(1)
+        .expected_shape((2, 3))
+        .edges(vec![(0, 1, 1.0)].into_iter())
+        .build()
+        .unwrap();
+    assert_eq!(
+        rectangular.unweighted_closeness_centrality(true),
+        Err(DijkstraError::NonSquareMatrix { rows: 2, columns: 3 })
+    );
+}
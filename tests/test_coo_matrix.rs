@@ -0,0 +1,82 @@
+//! Tests for the coordinate list staging of sparse matrices.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::{COOMatrix, MutabilityError},
+    prelude::*,
+    traits::{DuplicatePolicy, InputPolicy, algorithms::randomized_graphs::XorShift64},
+};
+
+type TestCOO = COOMatrix<usize, usize, usize, u64>;
+
+#[test]
+fn test_finalize_sorts_the_entries() {
+    let mut staging = TestCOO::with_shape((4, 5));
+    assert!(staging.is_empty());
+    staging.push(3, 1, 10);
+    staging.push(0, 4, 20);
+    staging.push(3, 0, 30);
+    staging.push(1, 2, 40);
+    assert_eq!(staging.len(), 4);
+    assert_eq!(staging.entries()[0], (3, 1, 10));
+
+    let matrix = staging.finalize().unwrap();
+    assert_eq!(matrix.number_of_rows(), 4);
+    assert_eq!(matrix.number_of_columns(), 5);
+    assert_eq!(
+        SparseMatrix::sparse_coordinates(&matrix).collect::<Vec<_>>(),
+        vec![(0, 4), (1, 2), (3, 0), (3, 1)]
+    );
+    assert_eq!(matrix.sparse_values().collect::<Vec<_>>(), vec![20, 40, 30, 10]);
+
+    // The shape grows to fit the entries.
+    let grown: TestCOO = [(6, 7, 1)].into_iter().collect();
+    let grown = grown.finalize().unwrap();
+    assert_eq!((grown.number_of_rows(), grown.number_of_columns()), (7, 8));
+    assert_eq!(TestCOO::default().finalize().unwrap().number_of_defined_values(), 0);
+}
+
+#[test]
+fn test_duplicated_entries() {
+    let staging: TestCOO = [(1, 1, 1), (0, 0, 2), (1, 1, 3), (1, 1, 4)].into_iter().collect();
+    assert_eq!(staging.clone().finalize(), Err(MutabilityError::DuplicatedEntry((1, 1))));
+
+    // The reducer sees the values in insertion order.
+    let matrix = staging.clone().finalize_with(|left, right| 10 * left + right).unwrap();
+    assert_eq!(matrix.sparse_values().collect::<Vec<_>>(), vec![2, 134]);
+    let first = staging.clone().finalize_with(|left, _| left).unwrap();
+    assert_eq!(first.sparse_values().collect::<Vec<_>>(), vec![2, 1]);
+
+    let structure = staging.finalize_structure().unwrap();
+    assert_eq!(
+        SparseMatrix::sparse_coordinates(&structure).collect::<Vec<_>>(),
+        vec![(0, 0), (1, 1)]
+    );
+}
+
+#[test]
+fn test_finalize_matches_the_policy_construction() {
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(11));
+    let mut staging = TestCOO::with_shaped_capacity((20, 20), 300);
+    let mut entries = Vec::new();
+    for _ in 0..300 {
+        let row = usize::try_from(rng.next().unwrap() % 20).unwrap();
+        let column = usize::try_from(rng.next().unwrap() % 20).unwrap();
+        let value = rng.next().unwrap() % 100;
+        staging.push(row, column, value);
+        entries.push((row, column, value));
+    }
+    let matrix = staging.finalize_with(|_, right| right).unwrap();
+    let expected: ValuedCSR2D<usize, usize, usize, u64> = MatrixMut::from_entries_with_policy(
+        entries,
+        InputPolicy { sort: true, duplicates: DuplicatePolicy::KeepLast },
+    )
+    .unwrap();
+    assert_eq!(matrix, expected);
+}
+
+#[test]
+fn test_sparse_index_overflow() {
+    let staging: COOMatrix<u8, usize, usize, ()> = (0..300).map(|row| (row, 0, ())).collect();
+    assert_eq!(staging.finalize(), Err(MutabilityError::MaxedOutSparseIndex));
+}
@@ -7,6 +7,7 @@
 #![cfg(feature = "std")]
 
 use geometric_traits::{
+    errors::RankSelectError,
     impls::{
         CSR2D, GenericBiMatrix2D, SquareCSR2D, SubsetSquareMatrix, UpperTriangularCSR2D,
         VecMatrix2D,
@@ -117,9 +118,9 @@ fn test_bimatrix_graph_has_nodes_and_edges() {
 #[test]
 fn test_bimatrix_try_rank_default_impl() {
     let bm = build_bimatrix(3, vec![(0, 1), (1, 2)]);
-    assert_eq!(bm.try_rank(0, 1), Some(0));
-    assert_eq!(bm.try_rank(1, 2), Some(1));
-    assert_eq!(bm.try_rank(2, 2), None);
+    assert_eq!(bm.try_rank(0, 1), Ok(0));
+    assert_eq!(bm.try_rank(1, 2), Ok(1));
+    assert_eq!(bm.try_rank(2, 2), Err(RankSelectError::MissingEntry { row: 2, column: 2 }));
 }
 
 // ============================================================================
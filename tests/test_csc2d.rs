@@ -0,0 +1,115 @@
+//! Tests for the compressed sparse column matrices.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::{CSC2D, ValuedCSC2D},
+    prelude::*,
+    traits::algorithms::randomized_graphs::XorShift64,
+};
+
+type TestCSR = CSR2D<usize, usize, usize>;
+type TestValuedCSR = ValuedCSR2D<usize, usize, usize, u64>;
+
+/// Returns a random rectangular valued matrix.
+fn random_valued(seed: u64, rows: usize, columns: usize) -> TestValuedCSR {
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(seed));
+    let mut matrix: TestValuedCSR = SparseMatrixMut::with_sparse_shape((rows, columns));
+    for row in 0..rows {
+        for column in 0..columns {
+            if rng.next().unwrap() % 4 == 0 {
+                MatrixMut::add(&mut matrix, (row, column, rng.next().unwrap() % 1000)).unwrap();
+            }
+        }
+    }
+    matrix
+}
+
+#[test]
+fn test_csc_matches_the_csr() {
+    for seed in 1..=10 {
+        let csr = random_valued(seed, 7, 11);
+        let csc = ValuedCSC2D::from_csr(&csr);
+        assert_eq!(csc.number_of_rows(), 7);
+        assert_eq!(csc.number_of_columns(), 11);
+        assert_eq!(csc.shape(), vec![7, 11]);
+        assert_eq!(csc.number_of_defined_values(), csr.number_of_defined_values());
+        assert_eq!(csc.to_csr(), csr);
+
+        for row in csr.row_indices() {
+            assert_eq!(
+                csc.sparse_row(row).collect::<Vec<_>>(),
+                csr.sparse_row(row).collect::<Vec<_>>()
+            );
+            assert_eq!(
+                csc.sparse_row_values(row).collect::<Vec<_>>(),
+                csr.sparse_row_values(row).collect::<Vec<_>>()
+            );
+            for column in 0..11 {
+                assert_eq!(csc.has_entry(row, column), csr.has_entry(row, column));
+                assert_eq!(csc.sparse_value_at(row, column), csr.sparse_value_at(row, column));
+            }
+        }
+
+        // Columns are the rows of the transpose, in storage order.
+        let mut coordinates: Vec<(usize, usize)> = SparseMatrix::sparse_coordinates(&csr).collect();
+        coordinates.sort_unstable_by_key(|&(row, column)| (column, row));
+        assert_eq!(SparseMatrix::sparse_coordinates(&csc).collect::<Vec<_>>(), coordinates);
+        assert_eq!(csc.last_sparse_coordinates(), coordinates.last().copied());
+        assert_eq!(csc.sparse_rows().zip(csc.sparse_columns()).collect::<Vec<_>>(), coordinates);
+        for column in 0..11 {
+            let (rows, values) = csc.sparse_column_entries_slice(column);
+            assert_eq!(csc.sparse_column(column).collect::<Vec<_>>(), rows);
+            assert_eq!(csc.sparse_column_values(column).collect::<Vec<_>>(), values);
+            assert_eq!(csc.number_of_defined_values_in_column(column), rows.len());
+            for (&row, &value) in rows.iter().zip(values) {
+                assert_eq!(csr.sparse_value_at(row, column), Some(value));
+            }
+        }
+        let mut values: Vec<(usize, usize, u64)> = csr
+            .row_indices()
+            .flat_map(|row| {
+                csr.sparse_row(row).zip(csr.sparse_row_values(row)).map(move |(c, v)| (row, c, v))
+            })
+            .collect();
+        values.sort_unstable_by_key(|&(row, column, _)| (column, row));
+        assert_eq!(
+            csc.sparse_values().collect::<Vec<_>>(),
+            values.into_iter().map(|(_, _, value)| value).collect::<Vec<_>>()
+        );
+    }
+}
+
+#[test]
+fn test_csc_structure_conversions() {
+    let csr: TestCSR = random_valued(3, 5, 4).into_parts().0;
+    let csc = CSC2D::from_csr(&csr);
+    assert_eq!(csc.to_csr(), csr);
+    for column in 0..4 {
+        let rows: Vec<usize> =
+            csr.row_indices().filter(|&row| csr.has_entry(row, column)).collect();
+        assert_eq!(csc.sparse_column_slice(column), rows.as_slice());
+    }
+
+    // Wrapping a transposed matrix does not copy it.
+    let transposed = csr.transpose();
+    let wrapped = CSC2D::from_columns(transposed.clone());
+    assert_eq!(wrapped, csc);
+    assert_eq!(wrapped.into_columns(), transposed);
+
+    let valued = random_valued(4, 3, 3);
+    let valued_csc = ValuedCSC2D::from_csr(&valued);
+    assert_eq!(valued_csc.structure(), &CSC2D::from_csr(&valued.clone().into_parts().0));
+    let columns = valued_csc.clone().into_columns();
+    assert_eq!(ValuedCSC2D::from_columns(columns), valued_csc);
+}
+
+#[test]
+fn test_empty_csc() {
+    let csr: TestCSR = SparseMatrixMut::with_sparse_shape((3, 2));
+    let csc = CSC2D::from_csr(&csr);
+    assert!(csc.is_empty());
+    assert_eq!((csc.number_of_rows(), csc.number_of_columns()), (3, 2));
+    assert_eq!(csc.last_sparse_coordinates(), None);
+    assert_eq!(csc.sparse_row(1).count(), 0);
+    assert_eq!(csc.to_csr(), csr);
+}
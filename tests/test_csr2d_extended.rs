@@ -7,6 +7,7 @@ use geometric_traits::{
     traits::{
         EmptyRows, Matrix, Matrix2D, RankSelectSparseMatrix, SizedRowsSparseMatrix2D,
         SizedSparseMatrix, SizedSparseMatrix2D, SparseMatrix, SparseMatrix2D, TransposableMatrix2D,
+        algorithms::randomized_graphs::XorShift64,
     },
 };
 
@@ -243,6 +244,43 @@ fn test_csr2d_transpose_empty_non_empty_row_invariants_fully_populated() {
     );
 }
 
+#[test]
+fn test_csr2d_transpose_of_large_matrices() {
+    // Above 2^16 defined values, the transpose buckets the entries by tiles of
+    // rows, which must give the same result as the direct scatter.
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(3));
+    for (number_of_rows, number_of_columns, number_of_values) in
+        [(30000, 3, 70000), (500, 500, 100000), (120, 300000, 80000), (70000, 70000, 70000)]
+    {
+        let mut entries = std::collections::BTreeSet::new();
+        while entries.len() < number_of_values {
+            let row = usize::try_from(rng.next().unwrap()).unwrap() % number_of_rows;
+            let column = usize::try_from(rng.next().unwrap()).unwrap() % number_of_columns;
+            entries.insert((row, column));
+        }
+        let mut csr: TestCSR2D = SparseMatrixMut::with_sparse_shaped_capacity(
+            (number_of_rows, number_of_columns),
+            number_of_values,
+        );
+        for &entry in &entries {
+            MatrixMut::add(&mut csr, entry).unwrap();
+        }
+
+        let transposed: TestCSR2D = csr.transpose();
+        let mut expected: Vec<(usize, usize)> =
+            entries.iter().map(|&(row, column)| (column, row)).collect();
+        expected.sort_unstable();
+        assert_eq!(transposed.number_of_rows(), number_of_columns);
+        assert_eq!(transposed.number_of_columns(), number_of_rows);
+        assert_eq!(transposed.sparse_coordinates().collect::<Vec<_>>(), expected);
+        assert_eq!(
+            transposed.number_of_non_empty_rows(),
+            expected.iter().map(|&(row, _)| row).collect::<std::collections::BTreeSet<_>>().len()
+        );
+        assert!(transposed.transpose() == csr);
+    }
+}
+
 // ============================================================================
 // Sparse rows/columns iterators
 // ============================================================================
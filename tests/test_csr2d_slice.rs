@@ -0,0 +1,136 @@
+//! Tests for the CSR matrices borrowing their storage from external buffers.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    errors::{InvariantError, RankSelectError},
+    impls::{CSR2D, CSR2DSlice, CSR2DSliceError, ValuedCSR2D, ValuedCSR2DSlice},
+    prelude::*,
+    traits::algorithms::randomized_graphs::{
+        Feasibility, SparseCostMatrixConfig, random_sparse_cost_matrix,
+    },
+};
+
+type TestCSR2D = CSR2D<usize, usize, usize>;
+
+/// The coordinates, row sizes, empty rows and rows of every sparse index.
+type Traversals = (Vec<(usize, usize)>, Vec<usize>, Vec<usize>, Vec<usize>);
+
+fn collect_traits<M>(matrix: &M) -> Traversals
+where
+    M: SizedSparseMatrix2D<RowIndex = usize, ColumnIndex = usize, SparseIndex = usize> + EmptyRows,
+{
+    (
+        SparseMatrix::sparse_coordinates(matrix).collect(),
+        matrix.sparse_row_sizes().collect(),
+        matrix.empty_row_indices().collect(),
+        (0..matrix.number_of_defined_values()).map(|index| matrix.select_row(index)).collect(),
+    )
+}
+
+#[test]
+fn test_csr2d_slice_matches_owned_matrix() {
+    let owned: TestCSR2D =
+        CSR2D::from_entries(vec![(0, 1), (0, 4), (2, 0), (2, 3), (5, 2)]).unwrap();
+    let offsets = [0, 2, 2, 4, 4, 4, 5];
+    let columns = [1, 4, 0, 3, 2];
+    let view = CSR2DSlice::from_raw_parts(&offsets[..], &columns[..], (6, 5)).unwrap();
+
+    assert_eq!(view, owned.as_slice());
+    assert_eq!(view.shape(), owned.shape());
+    assert_eq!(view.number_of_non_empty_rows(), owned.number_of_non_empty_rows());
+    assert_eq!(view.last_sparse_coordinates(), owned.last_sparse_coordinates());
+    assert_eq!(collect_traits(&view), collect_traits(&owned));
+    for (row, column) in SparseMatrix::sparse_coordinates(&owned) {
+        assert!(view.has_entry(row, column));
+        assert_eq!(view.rank(&(row, column)), owned.rank(&(row, column)));
+    }
+    assert!(!view.has_entry(1, 1));
+    assert_eq!(view.try_rank(0, 2), Err(RankSelectError::MissingEntry { row: 0, column: 2 }));
+    assert!(TestCSR2D::from(view) == owned);
+}
+
+#[test]
+fn test_csr2d_slice_with_implicit_trailing_rows() {
+    let offsets: [u16; 3] = [0, 1, 3];
+    let columns: [u8; 3] = [2, 0, 1];
+    let view = CSR2DSlice::from_raw_parts(&offsets[..], &columns[..], (5_u8, 3_u8)).unwrap();
+    assert_eq!(view.number_of_rows(), 5);
+    assert_eq!(view.number_of_empty_rows(), 3);
+    assert_eq!(view.empty_row_indices().collect::<Vec<_>>(), vec![2, 3, 4]);
+    assert_eq!(view.sparse_row(4).count(), 0);
+    assert_eq!(view.rank_row(5), 3);
+    assert_eq!(view.last_sparse_coordinates(), Some((1, 1)));
+}
+
+#[test]
+fn test_csr2d_slice_validation_errors() {
+    let cases: [(&[usize], &[usize], InvariantError); 6] = [
+        (&[], &[], InvariantError::OffsetsMustStartWithZero),
+        (&[1, 2], &[0, 1], InvariantError::OffsetsMustStartWithZero),
+        (
+            &[0, 1, 1, 1, 1],
+            &[0],
+            InvariantError::TooManyOffsets { described_rows: 4, number_of_rows: 3 },
+        ),
+        (
+            &[0, 1, 2],
+            &[0],
+            InvariantError::LastOffsetMismatch { last_offset: 2, number_of_column_indices: 1 },
+        ),
+        (&[0, 2, 1, 2], &[0, 1], InvariantError::DecreasingOffsets { row: 1 }),
+        (&[0, 2], &[1, 1], InvariantError::UnsortedRow { row: 0 }),
+    ];
+    for (offsets, columns, error) in cases {
+        assert_eq!(
+            CSR2DSlice::from_raw_parts(offsets, columns, (3_usize, 3_usize)),
+            Err(CSR2DSliceError::InvalidStructure(error))
+        );
+    }
+    assert_eq!(
+        CSR2DSlice::from_raw_parts(&[0_usize, 1][..], &[3_usize][..], (3_usize, 3_usize)),
+        Err(CSR2DSliceError::InvalidStructure(InvariantError::ColumnOutOfBounds {
+            row: 0,
+            column: 3,
+            number_of_columns: 3
+        }))
+    );
+    assert_eq!(
+        ValuedCSR2DSlice::from_raw_parts(
+            &[0_usize, 1][..],
+            &[0_usize][..],
+            &[1.0, 2.0][..],
+            (1_usize, 1_usize)
+        ),
+        Err(CSR2DSliceError::ValuesLengthMismatch { expected: 1, actual: 2 })
+    );
+}
+
+#[test]
+fn test_valued_csr2d_slice_runs_algorithms_without_copies() {
+    for seed in 1..=10 {
+        let mut config = SparseCostMatrixConfig::new(30, 30, 0.3);
+        config.feasibility = Feasibility::Assignment;
+        let owned = random_sparse_cost_matrix(seed, &config);
+        let borrowed = owned.as_slice();
+        let structure = borrowed.structure();
+        let view = ValuedCSR2DSlice::from_raw_parts(
+            structure.offsets(),
+            structure.column_indices(),
+            borrowed.values(),
+            (owned.number_of_rows(), owned.number_of_columns()),
+        )
+        .unwrap();
+
+        assert_eq!(view, borrowed);
+        assert_eq!(view.max_sparse_value(), owned.max_sparse_value());
+        for row in owned.row_indices() {
+            assert_eq!(
+                view.sparse_row_values(row).collect::<Vec<_>>(),
+                owned.sparse_row_values(row).collect::<Vec<_>>()
+            );
+        }
+        let maximum = owned.max_sparse_value().unwrap();
+        assert_eq!(view.lapmod(maximum * 2.1), owned.lapmod(maximum * 2.1), "seed {seed}");
+        assert!(ValuedCSR2D::from(view) == owned);
+    }
+}
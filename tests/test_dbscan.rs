@@ -0,0 +1,140 @@
+//! Tests for the `Dbscan` trait and the DBSCAN clustering of point sets.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::{SymmetricCSR2D, ValuedCSR2D},
+    prelude::*,
+    spatial::{Euclidean, Metric, dbscan},
+    traits::{Dbscan, DbscanError, algorithms::randomized_graphs::XorShift64},
+};
+
+type Graph = SymmetricCSR2D<ValuedCSR2D<usize, usize, usize, f64>>;
+
+fn graph(order: usize, edges: Vec<(usize, usize, f64)>) -> Graph {
+    SymmetricCSR2D::from_sorted_upper_triangular_entries(order, edges).unwrap()
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn random_points(seed: u64, number_of_points: usize) -> Vec<[f64; 2]> {
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(seed));
+    let mut uniform = move || (rng.next().unwrap() % 10000) as f64 / 1000.0;
+    (0..number_of_points).map(|_| [uniform(), uniform()]).collect()
+}
+
+/// Textbook DBSCAN comparing every pair of points.
+fn naive_dbscan(points: &[[f64; 2]], epsilon: f64, min_points: usize) -> Vec<Option<usize>> {
+    let neighborhoods: Vec<Vec<usize>> = points
+        .iter()
+        .map(|point| {
+            (0..points.len())
+                .filter(|&other| {
+                    Metric::<[f64; 2]>::distance(&Euclidean, point, &points[other]) <= epsilon
+                })
+                .collect()
+        })
+        .collect();
+    let core: Vec<bool> =
+        neighborhoods.iter().map(|neighborhood| neighborhood.len() >= min_points).collect();
+    let mut labels = vec![None; points.len()];
+    let mut number_of_clusters = 0;
+    for seed in 0..points.len() {
+        if !core[seed] || labels[seed].is_some() {
+            continue;
+        }
+        labels[seed] = Some(number_of_clusters);
+        let mut queue = std::collections::VecDeque::from([seed]);
+        while let Some(point) = queue.pop_front() {
+            for &neighbor in &neighborhoods[point] {
+                if labels[neighbor].is_none() {
+                    labels[neighbor] = Some(number_of_clusters);
+                    if core[neighbor] {
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+        }
+        number_of_clusters += 1;
+    }
+    labels
+}
+
+#[test]
+fn test_dbscan_matches_naive_dbscan() {
+    for seed in 1..=10 {
+        let points = random_points(seed, 150);
+        for (epsilon, min_points) in [(0.5, 3), (0.8, 5), (1.2, 8), (0.3, 1)] {
+            let result = dbscan::<usize, _, _>(&points, epsilon, min_points, &Euclidean).unwrap();
+            let expected = naive_dbscan(&points, epsilon, min_points);
+            assert_eq!(result.labels(), expected.as_slice(), "seed {seed}, epsilon {epsilon}");
+            assert_eq!(
+                result.number_of_clusters(),
+                expected.iter().flatten().max().map_or(0, |&label| label + 1)
+            );
+            assert_eq!(
+                result.number_of_noise_nodes(),
+                expected.iter().filter(|label| label.is_none()).count()
+            );
+        }
+    }
+}
+
+#[test]
+fn test_dbscan_core_border_and_noise_nodes() {
+    // For a minimal neighbourhood of four nodes, nodes 0 and 4 are core, node
+    // 3 is a border node of both their clusters, node 7 only neighbours a
+    // border node and node 8 is isolated.
+    let graph = graph(
+        9,
+        vec![
+            (0, 1, 1.0),
+            (0, 2, 1.0),
+            (0, 3, 1.0),
+            (1, 7, 1.0),
+            (3, 4, 1.0),
+            (4, 5, 1.0),
+            (4, 6, 1.0),
+        ],
+    );
+    let result = Dbscan::<u8>::dbscan(&graph, 4).unwrap();
+    assert_eq!(result.core_nodes(), &[true, false, false, false, true, false, false, false, false]);
+    assert_eq!(
+        result.labels(),
+        &[Some(0), Some(0), Some(0), Some(0), Some(1), Some(1), Some(1), None, None]
+    );
+    assert_eq!(result.number_of_clusters(), 2);
+    assert_eq!(result.number_of_noise_nodes(), 2);
+
+    // Every node is core when the neighbourhoods always suffice.
+    for min_points in [0, 1] {
+        let result = Dbscan::<usize>::dbscan(&graph, min_points).unwrap();
+        assert!(result.core_nodes().iter().all(|&core| core));
+        assert_eq!(result.number_of_clusters(), 2);
+        assert_eq!(result.labels()[8], Some(1));
+    }
+}
+
+#[test]
+fn test_dbscan_ignores_self_loops_and_values() {
+    let graph = graph(3, vec![(0, 0, 5.0), (1, 1, 5.0), (1, 2, 0.0)]);
+    let result = Dbscan::<usize>::dbscan(&graph, 2).unwrap();
+    assert_eq!(result.labels(), &[None, Some(0), Some(0)]);
+}
+
+#[test]
+fn test_dbscan_errors() {
+    let mut rectangular: ValuedCSR2D<usize, usize, usize, f64> =
+        SparseMatrixMut::with_sparse_shaped_capacity((2, 3), 1);
+    MatrixMut::add(&mut rectangular, (0, 1, 1.0)).unwrap();
+    assert_eq!(
+        Dbscan::<usize>::dbscan(&rectangular, 1),
+        Err(DbscanError::NonSquareMatrix { rows: 2, columns: 3 })
+    );
+
+    let isolated = graph(300, Vec::new());
+    assert_eq!(Dbscan::<u8>::dbscan(&isolated, 1), Err(DbscanError::TooManyClusters));
+    assert_eq!(Dbscan::<u16>::dbscan(&isolated, 1).unwrap().number_of_clusters(), 300);
+    assert_eq!(Dbscan::<u8>::dbscan(&isolated, 2).unwrap().number_of_noise_nodes(), 300);
+
+    let points: Vec<[f64; 2]> = (0..300).map(|index| [f64::from(index) * 10.0, 0.0]).collect();
+    assert_eq!(dbscan::<u8, _, _>(&points, 1.0, 1, &Euclidean), Err(DbscanError::TooManyClusters));
+}
@@ -0,0 +1,194 @@
+//! Tests for the delimited edge-list reader.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    io::{DelimitedColumn, DelimitedEdgeListReader, DelimitedReadError},
+    prelude::*,
+};
+
+fn node(graph: &impl MonopartiteGraph<NodeSymbol = String, NodeId = usize>, symbol: &str) -> usize {
+    graph.nodes_vocabulary().invert(&symbol.to_string()).unwrap()
+}
+
+#[test]
+fn test_read_digraph_from_csv_with_header_and_comments() {
+    let data = "\
+# exported by a pipeline
+source,target
+c,a
+
+a,b
+# trailing comment
+b,c
+";
+    let graph = DelimitedEdgeListReader::csv()
+        .has_header(true)
+        .comment_prefix("#")
+        .source_column("source")
+        .target_column("target")
+        .read_digraph(data.as_bytes())
+        .unwrap();
+
+    assert_eq!(graph.number_of_nodes(), 3);
+    assert_eq!(graph.number_of_edges(), 3);
+    assert!(graph.has_successor(node(&graph, "c"), node(&graph, "a")));
+    assert!(graph.has_successor(node(&graph, "a"), node(&graph, "b")));
+    assert!(graph.has_successor(node(&graph, "b"), node(&graph, "c")));
+    assert!(!graph.has_successor(node(&graph, "a"), node(&graph, "c")));
+}
+
+#[test]
+fn test_read_records_with_column_indices_and_custom_delimiter() {
+    let data = "x;1.5;ignored;y\nz;2;ignored;x\r\n";
+    let records = DelimitedEdgeListReader::csv()
+        .delimiter(';')
+        .source_column(3)
+        .target_column(0)
+        .weight_column(1)
+        .read_records(data.as_bytes())
+        .unwrap();
+
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].line, 1);
+    assert_eq!(records[0].source, "y");
+    assert_eq!(records[0].destination, "x");
+    assert_eq!(records[0].weight, Some(1.5));
+    assert_eq!(records[1].source, "x");
+    assert_eq!(records[1].weight, Some(2.0));
+}
+
+#[test]
+fn test_read_records_handles_quoted_fields() {
+    let data = "\"a, b\",\"say \"\"hi\"\"\"\n  c  , d \n";
+    let records = DelimitedEdgeListReader::csv().read_records(data.as_bytes()).unwrap();
+    assert_eq!(records[0].source, "a, b");
+    assert_eq!(records[0].destination, "say \"hi\"");
+    assert_eq!(records[1].source, "c");
+    assert_eq!(records[1].destination, "d");
+
+    let untrimmed =
+        DelimitedEdgeListReader::csv().trim(false).read_records("  c  , d \n".as_bytes()).unwrap();
+    assert_eq!(untrimmed[0].source, "  c  ");
+    assert_eq!(untrimmed[0].destination, " d ");
+
+    let unquoted =
+        DelimitedEdgeListReader::csv().quote(None).read_records("\"a\",b\n".as_bytes()).unwrap();
+    assert_eq!(unquoted[0].source, "\"a\"");
+}
+
+#[test]
+fn test_read_weighted_digraph_from_tsv() {
+    let data = "from\tto\tscore\nalanine\tglycine\t0.5\nglycine\tserine\t0.25\n";
+    let graph = DelimitedEdgeListReader::tsv()
+        .has_header(true)
+        .source_column("from")
+        .target_column("to")
+        .weight_column("score")
+        .read_weighted_digraph(data.as_bytes())
+        .unwrap();
+
+    let glycine = node(&graph, "glycine");
+    assert_eq!(graph.successor_weights(glycine).collect::<Vec<f64>>(), vec![0.25]);
+    assert_eq!(graph.successors(glycine).collect::<Vec<usize>>(), vec![node(&graph, "serine")]);
+}
+
+#[test]
+fn test_read_undigraph_merges_reversed_edges_when_ignoring_duplicates() {
+    let data = "a,b\nb,a\nb,c\n";
+    let error = DelimitedEdgeListReader::csv().read_undigraph(data.as_bytes()).unwrap_err();
+    assert_eq!(
+        error,
+        DelimitedReadError::DuplicatedEdge {
+            line: 2,
+            source_symbol: "a".to_string(),
+            destination_symbol: "b".to_string(),
+        }
+    );
+
+    let graph =
+        DelimitedEdgeListReader::csv().ignore_duplicates().read_undigraph(data.as_bytes()).unwrap();
+    assert_eq!(graph.number_of_nodes(), 3);
+    assert!(graph.has_successor(node(&graph, "b"), node(&graph, "a")));
+    assert!(graph.has_successor(node(&graph, "a"), node(&graph, "b")));
+    assert!(graph.has_successor(node(&graph, "c"), node(&graph, "b")));
+}
+
+#[test]
+fn test_ignore_duplicates_keeps_first_weight() {
+    let data = "a,b,1\na,b,2\n";
+    let graph = DelimitedEdgeListReader::csv()
+        .weight_column(2)
+        .ignore_duplicates()
+        .read_weighted_digraph(data.as_bytes())
+        .unwrap();
+    assert_eq!(graph.successor_weights(0).collect::<Vec<f64>>(), vec![1.0]);
+}
+
+#[test]
+fn test_errors_carry_line_numbers() {
+    let reader = DelimitedEdgeListReader::csv().weight_column(2);
+
+    assert_eq!(
+        reader.read_records("a,b,1\n\nc\n".as_bytes()).unwrap_err(),
+        DelimitedReadError::MissingField { line: 3, column: DelimitedColumn::Index(1) }
+    );
+    assert_eq!(
+        reader.read_records("a,b,1\na,c,heavy\n".as_bytes()).unwrap_err(),
+        DelimitedReadError::InvalidWeight { line: 2, value: "heavy".to_string() }
+    );
+    assert_eq!(
+        reader.read_records("a,b,NaN\n".as_bytes()).unwrap_err(),
+        DelimitedReadError::InvalidWeight { line: 1, value: "NaN".to_string() }
+    );
+    assert_eq!(
+        reader.read_records("a,b,1\n\"a,b,1\n".as_bytes()).unwrap_err(),
+        DelimitedReadError::UnterminatedQuote { line: 2 }
+    );
+}
+
+#[test]
+fn test_header_errors() {
+    assert_eq!(
+        DelimitedEdgeListReader::csv().source_column("source").read_records("a,b\n".as_bytes()),
+        Err(DelimitedReadError::NamedColumnWithoutHeader {
+            column: DelimitedColumn::Name("source".to_string())
+        })
+    );
+    assert_eq!(
+        DelimitedEdgeListReader::csv()
+            .has_header(true)
+            .comment_prefix("%")
+            .read_records("% nothing here\n".as_bytes()),
+        Err(DelimitedReadError::MissingHeader)
+    );
+    assert_eq!(
+        DelimitedEdgeListReader::csv()
+            .has_header(true)
+            .comment_prefix("%")
+            .target_column("dst")
+            .read_records("%\nsrc,destination\na,b\n".as_bytes()),
+        Err(DelimitedReadError::UnknownColumn {
+            line: 2,
+            column: DelimitedColumn::Name("dst".to_string())
+        })
+    );
+    assert_eq!(
+        DelimitedEdgeListReader::csv().read_weighted_digraph("a,b\n".as_bytes()).unwrap_err(),
+        DelimitedReadError::MissingWeightColumn
+    );
+}
+
+#[test]
+fn test_error_display_mentions_line() {
+    let error = DelimitedReadError::InvalidWeight { line: 7, value: "x".to_string() };
+    assert!(error.to_string().contains("Line 7"));
+    assert_eq!(DelimitedColumn::from(3).to_string(), "#3");
+    assert_eq!(DelimitedColumn::from("w".to_string()).to_string(), "`w`");
+}
+
+#[test]
+fn test_empty_input_produces_empty_graph() {
+    let graph = DelimitedEdgeListReader::default().read_digraph("".as_bytes()).unwrap();
+    assert_eq!(graph.number_of_nodes(), 0);
+    assert_eq!(graph.number_of_edges(), 0);
+}
@@ -0,0 +1,147 @@
+//! Tests for the heat-kernel diffusion of signals over weighted undirected
+//! graphs.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::{PaddedMatrix2D, SymmetricCSR2D, ValuedCSR2D},
+    prelude::*,
+    traits::{
+        Diffusion, DiffusionConfig, DiffusionError, DiffusionLaplacian, EdgesBuilder, Laplacian,
+        LaplacianError, algorithms::randomized_graphs::XorShift64,
+    },
+};
+
+type Weights = SymmetricCSR2D<ValuedCSR2D<usize, usize, usize, f64>>;
+type TestValuedCSR = ValuedCSR2D<usize, usize, usize, f64>;
+type TestPadded = PaddedMatrix2D<TestValuedCSR, Box<dyn Fn((usize, usize)) -> f64>>;
+
+#[allow(clippy::cast_precision_loss)]
+fn random_weights(seed: u64, order: usize) -> Weights {
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(seed));
+    let mut entries = Vec::new();
+    for source in 0..order {
+        for destination in source + 1..order {
+            if rng.next().unwrap() % 100 < 40 {
+                entries.push((source, destination, (1 + rng.next().unwrap() % 500) as f64 / 100.0));
+            }
+        }
+    }
+    SymmetricCSR2D::from_sorted_upper_triangular_entries(order, entries).unwrap()
+}
+
+/// Returns the dense version of the sparse Laplacian, as a padded matrix.
+fn dense(laplacian: &TestValuedCSR) -> TestPadded {
+    let order = laplacian.number_of_rows();
+    let mut values = vec![0.0; order * order];
+    for row in laplacian.row_indices() {
+        for (column, value) in laplacian.sparse_row(row).zip(laplacian.sparse_row_values(row)) {
+            values[row * order + column] = value;
+        }
+    }
+    let inner = GenericEdgesBuilder::<_, TestValuedCSR>::default()
+        .expected_number_of_edges(0)
+        .expected_shape((order, order))
+        .edges(core::iter::empty())
+        .build()
+        .unwrap();
+    PaddedMatrix2D::new(
+        inner,
+        Box::new(move |(row, column): (usize, usize)| values[row * order + column])
+            as Box<dyn Fn((usize, usize)) -> f64>,
+    )
+    .unwrap()
+}
+
+/// Returns exp(−tL)·x from the eigendecomposition of the Laplacian.
+fn spectral_diffusion(laplacian: &TestValuedCSR, signal: &[f64], time: f64) -> Vec<f64> {
+    let config = JacobiConfig { tolerance: 1e-26, ..JacobiConfig::default() };
+    let decomposition = dense(laplacian).jacobi(&config).unwrap();
+    let mut diffused = vec![0.0; signal.len()];
+    for (k, eigenvalue) in decomposition.eigenvalues().iter().enumerate() {
+        let eigenvector = decomposition.eigenvector(k);
+        let projection: f64 =
+            eigenvector.iter().zip(signal).map(|(left, right)| left * right).sum();
+        for (value, component) in diffused.iter_mut().zip(eigenvector) {
+            *value += (-time * eigenvalue).exp() * projection * component;
+        }
+    }
+    diffused
+}
+
+#[test]
+#[allow(clippy::cast_precision_loss)]
+fn test_diffusion_matches_the_spectral_heat_kernel() {
+    for seed in 1..=10 {
+        let matrix = random_weights(seed, 8);
+        let signal: Vec<f64> = (0..8_u64).map(|node| ((node * 7 + seed) % 5) as f64).collect();
+        for laplacian in [DiffusionLaplacian::Combinatorial, DiffusionLaplacian::Normalized] {
+            let dense_laplacian = match laplacian {
+                DiffusionLaplacian::Combinatorial => matrix.laplacian().unwrap(),
+                DiffusionLaplacian::Normalized => matrix.normalized_laplacian().unwrap(),
+            };
+            for time in [0.0, 0.1, 1.0, 7.5] {
+                let config = DiffusionConfig { time, laplacian, ..DiffusionConfig::default() };
+                let diffused = matrix.diffuse(&signal, &config).unwrap();
+                let expected = spectral_diffusion(&dense_laplacian, &signal, time);
+                for (value, expected) in diffused.iter().zip(&expected) {
+                    assert!((value - expected).abs() < 1e-9, "{value} != {expected}");
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_combinatorial_diffusion_conserves_the_signal_per_component() {
+    // Two components, a triangle and an edge, and an isolated node.
+    let matrix: Weights = SymmetricCSR2D::from_sorted_upper_triangular_entries(
+        6,
+        vec![(0, 1, 1.0), (0, 2, 2.0), (1, 2, 0.5), (3, 4, 3.0)],
+    )
+    .unwrap();
+    let signal = [6.0, 0.0, 0.0, 2.0, 0.0, 5.0];
+    let config = DiffusionConfig { time: 40.0, ..DiffusionConfig::default() };
+    let diffused = matrix.diffuse(&signal, &config).unwrap();
+    for (value, expected) in diffused.iter().zip([2.0, 2.0, 2.0, 1.0, 1.0, 5.0]) {
+        assert!((value - expected).abs() < 1e-9, "{value} != {expected}");
+    }
+
+    // A lower truncation order still conserves the total.
+    let config = DiffusionConfig { time: 0.3, order: 2, ..DiffusionConfig::default() };
+    let diffused = matrix.diffuse(&signal, &config).unwrap();
+    assert!((diffused.iter().sum::<f64>() - 13.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_invalid_diffusions_are_rejected() {
+    let matrix = random_weights(3, 4);
+    let signal = [1.0, 2.0, 3.0, 4.0];
+    let config = DiffusionConfig::default();
+
+    assert_eq!(
+        matrix.diffuse(&signal[..3], &config),
+        Err(DiffusionError::SignalLengthMismatch { expected: 4, found: 3 })
+    );
+    assert_eq!(
+        matrix.diffuse(&[1.0, f64::NAN, 0.0, 0.0], &config),
+        Err(DiffusionError::NonFiniteSignal)
+    );
+    assert_eq!(
+        matrix.diffuse(&signal, &DiffusionConfig { time: -1.0, ..config }),
+        Err(DiffusionError::InvalidTime(-1.0))
+    );
+    assert_eq!(
+        matrix.diffuse(&signal, &DiffusionConfig { order: 0, ..config }),
+        Err(DiffusionError::ZeroOrder)
+    );
+
+    let mut directed: TestValuedCSR = SparseMatrixMut::with_sparse_shape((2, 2));
+    MatrixMut::add(&mut directed, (0, 1, 1.0)).unwrap();
+    assert_eq!(
+        directed.diffuse(&[1.0, 0.0], &config),
+        Err(DiffusionError::Laplacian(LaplacianError::NonSymmetricWeight {
+            source_id: 0,
+            destination_id: 1
+        }))
+    );
+}
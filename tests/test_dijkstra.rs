@@ -0,0 +1,164 @@
+//! Tests for the Dijkstra single-source shortest-path algorithm.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::ValuedCSR2D,
+    prelude::*,
+    traits::{
+        DenseValuedMatrix, Dijkstra, DijkstraError, EdgesBuilder,
+        algorithms::randomized_graphs::XorShift64,
+    },
+};
+
+type TestValCSR = ValuedCSR2D<usize, usize, usize, f64>;
+type TestIntValCSR = ValuedCSR2D<usize, usize, usize, i32>;
+
+fn build_matrix(
+    rows: usize,
+    columns: usize,
+    edges: impl IntoIterator<Item = (usize, usize, f64)>,
+) -> TestValCSR {
+    let mut edges: Vec<(usize, usize, f64)> = edges.into_iter().collect();
+    edges.sort_unstable_by_key(|left| (left.0, left.1));
+    GenericEdgesBuilder::<_, TestValCSR>::default()
+        .expected_number_of_edges(edges.len())
+        .expected_shape((rows, columns))
+        .edges(edges.into_iter())
+        .build()
+        .unwrap()
+}
+
+fn build_int_matrix(
+    rows: usize,
+    columns: usize,
+    edges: impl IntoIterator<Item = (usize, usize, i32)>,
+) -> TestIntValCSR {
+    let mut edges: Vec<(usize, usize, i32)> = edges.into_iter().collect();
+    edges.sort_unstable_by_key(|left| (left.0, left.1));
+    GenericEdgesBuilder::<_, TestIntValCSR>::default()
+        .expected_number_of_edges(edges.len())
+        .expected_shape((rows, columns))
+        .edges(edges.into_iter())
+        .build()
+        .unwrap()
+}
+
+/// Returns a random directed graph with integer weights, some of them null.
+fn random_graph(seed: u64, order: usize, number_of_edges: usize) -> TestIntValCSR {
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(seed));
+    let mut edges: Vec<(usize, usize, i32)> = (0..number_of_edges)
+        .map(|_| {
+            let source = usize::try_from(rng.next().unwrap() % order as u64).unwrap();
+            let destination = usize::try_from(rng.next().unwrap() % order as u64).unwrap();
+            let weight = i32::try_from(rng.next().unwrap() % 10).unwrap();
+            (source, destination, weight)
+        })
+        .collect();
+    edges.sort_unstable_by_key(|&(source, destination, _)| (source, destination));
+    edges.dedup_by_key(|&mut (source, destination, _)| (source, destination));
+    build_int_matrix(order, order, edges)
+}
+
+#[test]
+fn test_dijkstra_matches_pairwise_dijkstra() {
+    for seed in 0..20 {
+        let graph = random_graph(seed, 25, 60);
+        let all_pairs = graph.pairwise_dijkstra().unwrap();
+        for source in 0..25 {
+            let paths = graph.dijkstra(source).unwrap();
+            assert_eq!(paths.source(), source);
+            for destination in 0..25 {
+                assert_eq!(
+                    paths.distance(destination),
+                    all_pairs.value((source, destination)),
+                    "seed {seed}: {source} -> {destination}"
+                );
+
+                // The predecessors lie on shortest paths.
+                match paths.predecessor(destination) {
+                    Some(predecessor) => {
+                        let weight = graph.sparse_value_at(predecessor, destination).unwrap();
+                        assert_eq!(
+                            paths.distance(predecessor).unwrap() + weight,
+                            paths.distance(destination).unwrap()
+                        );
+                    }
+                    None => {
+                        assert!(destination == source || paths.distance(destination).is_none());
+                    }
+                }
+
+                // The rebuilt paths follow edges and add up to the distance.
+                if let Some(path) = paths.path_to(destination) {
+                    assert_eq!(path.first(), Some(&source));
+                    assert_eq!(path.last(), Some(&destination));
+                    let length: i32 = path
+                        .windows(2)
+                        .map(|edge| graph.sparse_value_at(edge[0], edge[1]).unwrap())
+                        .sum();
+                    assert_eq!(Some(length), paths.distance(destination));
+                } else {
+                    assert_eq!(paths.distance(destination), None);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_dijkstra_paths_and_ties() {
+    // Two shortest paths to 3, the first one found going through 1.
+    let graph = build_matrix(
+        5,
+        5,
+        [(0, 1, 1.0), (0, 2, 1.0), (1, 3, 1.0), (2, 3, 1.0), (3, 3, 0.0), (4, 0, 1.0)],
+    );
+    let paths = graph.dijkstra(0).unwrap();
+    assert_eq!(paths.distances(), &[Some(0.0), Some(1.0), Some(1.0), Some(2.0), None]);
+    assert_eq!(paths.predecessors(), &[None, Some(0), Some(0), Some(1), None]);
+    assert_eq!(paths.path_to(0), Some(vec![0]));
+    assert_eq!(paths.path_to(3), Some(vec![0, 1, 3]));
+    assert_eq!(paths.path_to(4), None);
+
+    let from_four = graph.dijkstra(4).unwrap();
+    assert_eq!(from_four.path_to(3), Some(vec![4, 0, 1, 3]));
+}
+
+#[test]
+fn test_dijkstra_errors() {
+    let rectangular: TestValCSR = build_matrix(2, 3, [(0, 1, 1.0)]);
+    assert_eq!(
+        rectangular.dijkstra(0),
+        Err(DijkstraError::NonSquareMatrix { rows: 2, columns: 3 })
+    );
+
+    let graph: TestValCSR = build_matrix(3, 3, [(0, 1, 1.0), (2, 0, -1.0)]);
+    assert_eq!(
+        graph.dijkstra(3),
+        Err(DijkstraError::UnknownSource { source_id: 3, number_of_nodes: 3 })
+    );
+    // The negative weight is not reached from the first node.
+    assert!(graph.dijkstra(0).is_ok());
+    assert_eq!(
+        graph.dijkstra(2),
+        Err(DijkstraError::NegativeWeight { source_id: 2, destination_id: 0 })
+    );
+
+    let infinite: TestValCSR = build_matrix(2, 2, [(0, 1, f64::INFINITY)]);
+    assert_eq!(
+        infinite.dijkstra(0),
+        Err(DijkstraError::NonFiniteWeight { source_id: 0, destination_id: 1 })
+    );
+
+    let overflowing: TestValCSR = build_matrix(3, 3, [(0, 1, f64::MAX), (1, 2, f64::MAX)]);
+    assert_eq!(
+        overflowing.dijkstra(0),
+        Err(DijkstraError::NonFiniteDistance { destination_id: 2, via_id: 1 })
+    );
+
+    let empty: TestIntValCSR = build_int_matrix(0, 0, []);
+    assert_eq!(
+        empty.dijkstra(0),
+        Err(DijkstraError::UnknownSource { source_id: 0, number_of_nodes: 0 })
+    );
+}
@@ -0,0 +1,126 @@
+//! Tests for the [`DisjointSet`] union-find.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::{CSR2D, DisjointSet, SortedVec, SymmetricCSR2D},
+    prelude::{connected_components::ConnectedComponentsResult, *},
+    traits::{ConnectedComponents, VocabularyBuilder, algorithms::randomized_graphs::XorShift64},
+};
+
+/// Helper to build an undirected graph.
+fn build_undi_graph(node_count: usize, edges: Vec<(usize, usize)>) -> UndiGraph<usize> {
+    let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+        .expected_number_of_symbols(node_count)
+        .symbols((0..node_count).enumerate())
+        .build()
+        .unwrap();
+    let edge_count = edges.len();
+    let edges: SymmetricCSR2D<CSR2D<usize, usize, usize>> = UndiEdgesBuilder::default()
+        .expected_number_of_edges(edge_count)
+        .expected_shape(node_count)
+        .edges(edges.into_iter())
+        .build()
+        .unwrap();
+    UndiGraph::from((nodes, edges))
+}
+
+#[test]
+fn test_disjoint_set_against_labels() {
+    for seed in 0..50 {
+        let mut rng = XorShift64::from(XorShift64::normalize_seed(seed));
+        let mut random = |bound: usize| usize::try_from(rng.next().unwrap()).unwrap() % bound;
+        let order = 1 + random(30);
+        let mut sets: DisjointSet<u16> = DisjointSet::new(u16::try_from(order).unwrap());
+        // The naive partition, labelling each element with its set.
+        let mut labels: Vec<usize> = (0..order).collect();
+
+        for _ in 0..2 * order {
+            if random(10) == 0 {
+                let element = sets.push();
+                assert_eq!(usize::from(element), labels.len());
+                labels.push(labels.len());
+            }
+            let left = random(labels.len());
+            let right = random(labels.len());
+            let (left_label, right_label) = (labels[left], labels[right]);
+            let merged = sets.union(u16::try_from(left).unwrap(), u16::try_from(right).unwrap());
+            assert_eq!(merged, left_label != right_label, "seed {seed}");
+            for label in &mut labels {
+                if *label == right_label {
+                    *label = left_label;
+                }
+            }
+
+            let mut distinct = labels.clone();
+            distinct.sort_unstable();
+            distinct.dedup();
+            assert_eq!(sets.number_of_sets(), distinct.len());
+            assert_eq!(sets.representatives().count(), distinct.len());
+            assert_eq!(sets.number_of_elements(), labels.len());
+        }
+
+        for left in 0..labels.len() {
+            let left_id = u16::try_from(left).unwrap();
+            let size = labels.iter().filter(|&&label| label == labels[left]).count();
+            assert_eq!(sets.set_size(left_id), size);
+            for right in 0..labels.len() {
+                let right_id = u16::try_from(right).unwrap();
+                assert_eq!(sets.same_set(left_id, right_id), labels[left] == labels[right]);
+            }
+        }
+        for representative in sets.representatives().collect::<Vec<_>>() {
+            assert_eq!(sets.find(representative), representative);
+        }
+    }
+}
+
+#[test]
+fn test_empty_disjoint_set() {
+    let mut sets: DisjointSet<usize> = DisjointSet::default();
+    assert!(sets.is_empty());
+    assert_eq!(sets, DisjointSet::new(0));
+    assert_eq!(sets.number_of_sets(), 0);
+    assert_eq!(sets.representatives().count(), 0);
+    assert_eq!(sets.push(), 0);
+    assert!(!sets.is_empty());
+    assert_eq!(sets.find(0), 0);
+    assert_eq!(sets.set_size(0), 1);
+}
+
+#[test]
+#[should_panic(expected = "cannot represent 257 elements")]
+fn test_disjoint_set_push_overflow() {
+    let mut sets: DisjointSet<u8> = DisjointSet::new(u8::MAX);
+    sets.push();
+    sets.push();
+}
+
+#[test]
+fn test_incremental_connected_components() {
+    let graph = build_undi_graph(6, vec![(0, 1), (1, 2), (4, 5)]);
+    let cc: ConnectedComponentsResult<'_, _, usize> = graph.connected_components().unwrap();
+    let mut sets = cc.to_disjoint_set();
+    assert_eq!(sets.number_of_elements(), 6);
+    assert_eq!(sets.number_of_sets(), cc.number_of_components());
+    for left in 0..6 {
+        assert_eq!(
+            sets.set_size(left),
+            cc.node_ids_of_component(cc.component_of_node(left)).count()
+        );
+        for right in 0..6 {
+            assert_eq!(
+                sets.same_set(left, right),
+                cc.component_of_node(left) == cc.component_of_node(right)
+            );
+        }
+    }
+
+    // Adding the edge (2, 3) and a new node joined to 5.
+    assert!(sets.union(2, 3));
+    let node = sets.push();
+    assert!(sets.union(node, 5));
+    assert!(!sets.union(0, 3));
+    assert_eq!(sets.number_of_sets(), 2);
+    assert_eq!(sets.set_size(0), 4);
+    assert_eq!(sets.set_size(node), 3);
+}
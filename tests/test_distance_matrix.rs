@@ -0,0 +1,109 @@
+//! Tests for the `DistanceMatrix` trait and the cost matrices it builds.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::ValuedCSR2D,
+    prelude::*,
+    spatial::{Angular, Chebyshev, CosineDistance, DistanceMatrix, Euclidean, Manhattan, Metric},
+    traits::algorithms::randomized_graphs::XorShift64,
+};
+
+#[allow(clippy::cast_precision_loss)]
+fn random_points(seed: u64, n: usize, resolution: u64) -> Vec<[f64; 3]> {
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(seed));
+    (0..n)
+        .map(|_| core::array::from_fn(|_| (rng.next().unwrap() % resolution) as f64 / 10.0))
+        .collect()
+}
+
+fn entries(matrix: &ValuedCSR2D<usize, usize, usize, f64>) -> Vec<(usize, usize, f64)> {
+    SparseMatrix::sparse_coordinates(matrix)
+        .zip(matrix.sparse_values())
+        .map(|((row, column), distance)| (row, column, distance))
+        .collect()
+}
+
+fn check_distance_matrices<D: DistanceMatrix<[f64; 3]>>(distance: &D, threshold: f64) {
+    let left = random_points(1, 40, 100);
+    let right = random_points(2, 25, 100);
+    let all_pairs: Vec<(usize, usize, f64)> = (0..left.len())
+        .flat_map(|row| (0..right.len()).map(move |column| (row, column)))
+        .map(|(row, column)| (row, column, distance.dissimilarity(&left[row], &right[column])))
+        .collect();
+
+    let dense = distance.distance_matrix(&left, &right);
+    assert_eq!(dense.number_of_rows(), left.len());
+    assert_eq!(dense.number_of_columns(), right.len());
+    assert_eq!(entries(&dense), all_pairs);
+
+    let sparse = distance.thresholded_distance_matrix(&left, &right, threshold);
+    assert_eq!(sparse.number_of_rows(), left.len());
+    assert_eq!(sparse.number_of_columns(), right.len());
+    let expected: Vec<(usize, usize, f64)> =
+        all_pairs.into_iter().filter(|&(_, _, distance)| distance <= threshold).collect();
+    assert!(!expected.is_empty());
+    assert_eq!(entries(&sparse), expected);
+}
+
+#[test]
+fn test_distance_matrices_of_the_provided_distances() {
+    check_distance_matrices(&Euclidean, 3.0);
+    check_distance_matrices(&Manhattan, 5.0);
+    check_distance_matrices(&Chebyshev, 2.0);
+    check_distance_matrices(&Angular, 0.1);
+    check_distance_matrices(&CosineDistance, 0.05);
+    check_distance_matrices(&|left: &[f64; 3], right: &[f64; 3]| (left[0] - right[0]).abs(), 0.5);
+}
+
+#[test]
+fn test_cosine_distance_against_angular_distance() {
+    let points = random_points(3, 30, 1000);
+    for left in &points {
+        for right in &points {
+            // The cosine distance is a monotone function of the angle.
+            let angle = Angular.distance(left, right) * core::f64::consts::PI;
+            let cosine_distance = CosineDistance.dissimilarity(left, right);
+            assert!((cosine_distance - (1.0 - angle.cos())).abs() < 1e-9);
+        }
+    }
+    let null = [0.0, 0.0];
+    assert!(CosineDistance.dissimilarity(&null, &null).abs() < f64::EPSILON);
+    assert!((CosineDistance.dissimilarity(&null, &[1.0, 1.0]) - 1.0).abs() < f64::EPSILON);
+    assert!((CosineDistance.dissimilarity(&[1.0, 1.0], &[-2.0, -2.0]) - 2.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_thresholded_distance_matrix_links_frames_with_jaqaman() {
+    // The second frame moves every feature by a small offset and reverses
+    // their order, and adds a feature far from all the others.
+    let first = random_points(4, 30, 10000);
+    let mut second: Vec<[f64; 3]> =
+        first.iter().rev().map(|[x, y, z]| [x + 0.05, y - 0.02, z + 0.01]).collect();
+    second.push([5000.0, 5000.0, 5000.0]);
+
+    let costs = Euclidean.thresholded_distance_matrix(&first, &second, 1.0);
+    let mut links = costs.jaqaman(10.0, 100.0).unwrap();
+    links.sort_unstable();
+    let expected: Vec<(usize, usize)> =
+        (0..first.len()).map(|row| (row, first.len() - 1 - row)).collect();
+    assert_eq!(links, expected);
+}
+
+#[test]
+fn test_distance_matrices_of_empty_collections() {
+    let points = random_points(5, 3, 10);
+    let none: Vec<[f64; 3]> = Vec::new();
+    let matrix = Euclidean.distance_matrix(&points, &none);
+    assert_eq!(matrix.number_of_rows(), 3);
+    assert_eq!(matrix.number_of_columns(), 0);
+    assert_eq!(matrix.number_of_defined_values(), 0);
+    let matrix = Euclidean.thresholded_distance_matrix(&none, &points, f64::INFINITY);
+    assert_eq!(matrix.number_of_rows(), 0);
+    assert_eq!(matrix.number_of_columns(), 3);
+    assert_eq!(
+        Euclidean
+            .thresholded_distance_matrix(&points, &points, f64::NAN)
+            .number_of_defined_values(),
+        0
+    );
+}
@@ -0,0 +1,176 @@
+//! Tests for the edge betweenness and the Girvan-Newman clustering.
+#![cfg(feature = "std")]
+
+use std::collections::VecDeque;
+
+use geometric_traits::{
+    impls::{CSR2D, SortedVec, SymmetricCSR2D},
+    prelude::*,
+    traits::{
+        DendrogramCut, EdgeBetweenness, EdgesBuilder, VocabularyBuilder,
+        algorithms::randomized_graphs::erdos_renyi_gnp,
+    },
+};
+
+fn undirected(order: usize, edges: &[(usize, usize)]) -> UndiGraph<usize> {
+    let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+        .expected_number_of_symbols(order)
+        .symbols((0..order).enumerate())
+        .build()
+        .unwrap();
+    let mut edges: Vec<(usize, usize)> =
+        edges.iter().map(|&(left, right)| (left.min(right), left.max(right))).collect();
+    edges.sort_unstable();
+    let edges: SymmetricCSR2D<CSR2D<usize, usize, usize>> = UndiEdgesBuilder::default()
+        .expected_number_of_edges(edges.len())
+        .expected_shape(order)
+        .edges(edges.into_iter())
+        .build()
+        .unwrap();
+    UndiGraph::from((nodes, edges))
+}
+
+/// Returns the distances and the numbers of shortest paths from the source.
+fn shortest_paths(order: usize, edges: &[(usize, usize)], source: usize) -> (Vec<usize>, Vec<f64>) {
+    let mut distances = vec![usize::MAX; order];
+    let mut counts = vec![0.0; order];
+    distances[source] = 0;
+    counts[source] = 1.0;
+    let mut queue = VecDeque::from([source]);
+    while let Some(node) = queue.pop_front() {
+        for &(left, right) in edges {
+            let neighbor = if left == node {
+                right
+            } else if right == node {
+                left
+            } else {
+                continue;
+            };
+            if distances[neighbor] == usize::MAX {
+                distances[neighbor] = distances[node] + 1;
+                queue.push_back(neighbor);
+            }
+            if distances[neighbor] == distances[node] + 1 {
+                counts[neighbor] += counts[node];
+            }
+        }
+    }
+    (distances, counts)
+}
+
+/// Returns the edge betweenness from the shortest paths between all pairs.
+fn brute_force_edge_betweenness(order: usize, edges: &[(usize, usize)]) -> Vec<f64> {
+    let paths: Vec<(Vec<usize>, Vec<f64>)> =
+        (0..order).map(|source| shortest_paths(order, edges, source)).collect();
+    edges
+        .iter()
+        .map(|&(left, right)| {
+            let mut betweenness = 0.0;
+            for source in 0..order {
+                for target in source + 1..order {
+                    let (distances, counts) = &paths[source];
+                    if distances[target] == usize::MAX {
+                        continue;
+                    }
+                    for (near, far) in [(left, right), (right, left)] {
+                        let (far_distances, far_counts) = &paths[far];
+                        if distances[near] != usize::MAX
+                            && distances[near] + 1 + far_distances[target] == distances[target]
+                        {
+                            betweenness += counts[near] * far_counts[target] / counts[target];
+                        }
+                    }
+                }
+            }
+            betweenness
+        })
+        .collect()
+}
+
+#[test]
+fn test_edge_betweenness_matches_the_brute_force() {
+    for seed in 1..=10 {
+        let matrix = erdos_renyi_gnp(seed, 12, 0.3);
+        let edges: Vec<(usize, usize)> = SparseMatrix::sparse_coordinates(&matrix)
+            .filter(|&(left, right)| left < right)
+            .collect();
+        let graph = undirected(12, &edges);
+        let betweenness = graph.edge_betweenness(false);
+        let expected = brute_force_edge_betweenness(12, &edges);
+        assert_eq!(betweenness.len(), edges.len());
+        for ((source, destination, value), (&edge, expected)) in
+            betweenness.into_iter().zip(edges.iter().zip(expected))
+        {
+            assert_eq!((source, destination), edge);
+            assert!((value - expected).abs() < 1e-9, "{value} != {expected}");
+        }
+    }
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_normalized_edge_betweenness() {
+    // The values of the path graph P4 in NetworkX.
+    let path = undirected(4, &[(0, 1), (1, 2), (2, 3)]);
+    let betweenness = path.edge_betweenness(true);
+    assert_eq!(betweenness[0], (0, 1, 0.5));
+    assert!((betweenness[1].2 - 2.0 / 3.0).abs() < 1e-12);
+    assert_eq!(betweenness[2], (2, 3, 0.5));
+
+    // Self-loops are omitted.
+    let with_loop = undirected(2, &[(0, 0), (0, 1)]);
+    assert_eq!(with_loop.edge_betweenness(true), vec![(0, 1, 1.0)]);
+    assert!(undirected(1, &[]).edge_betweenness(true).is_empty());
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_girvan_newman_separates_bridged_cliques() {
+    // Four cliques of four nodes joined in a ring by single edges.
+    let mut edges = Vec::new();
+    for clique in 0..4 {
+        let offset = 4 * clique;
+        for left in 0..4 {
+            for right in left + 1..4 {
+                edges.push((offset + left, offset + right));
+            }
+        }
+        edges.push((offset + 3, (offset + 4) % 16));
+    }
+    let graph = undirected(16, &edges);
+    let dendrogram = graph.girvan_newman();
+    assert_eq!(dendrogram.number_of_items(), 16);
+    assert_eq!(dendrogram.merges().len(), 15);
+    assert!(dendrogram.merges().windows(2).all(|pair| pair[0].height() < pair[1].height()));
+    assert_eq!(dendrogram.merges().last().unwrap().size(), 16);
+
+    // The four ring edges are removed first, leaving 24 edges.
+    assert_eq!(
+        dendrogram.cut::<usize>(DendrogramCut::Clusters(4)).unwrap(),
+        (0..16).map(|node| node / 4).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        dendrogram.cut::<usize>(DendrogramCut::Height(24.0)).unwrap().into_iter().max(),
+        Some(3)
+    );
+    assert_eq!(dendrogram.cut::<usize>(DendrogramCut::Clusters(1)).unwrap(), vec![0; 16]);
+}
+
+#[test]
+fn test_girvan_newman_of_disconnected_graphs() {
+    // A path, an edge and an isolated node.
+    let graph = undirected(6, &[(0, 1), (1, 2), (3, 4)]);
+    let dendrogram = graph.girvan_newman();
+    assert_eq!(dendrogram.merges().len(), 3);
+    assert_eq!(
+        dendrogram.cut::<usize>(DendrogramCut::Clusters(1)).unwrap(),
+        vec![0, 0, 0, 1, 1, 2]
+    );
+    assert_eq!(
+        dendrogram.cut::<usize>(DendrogramCut::Clusters(6)).unwrap(),
+        vec![0, 1, 2, 3, 4, 5]
+    );
+
+    let empty = undirected(0, &[]);
+    assert!(empty.girvan_newman().merges().is_empty());
+}
@@ -0,0 +1,80 @@
+//! Tests for the symbol-preserving, tab-separated edge-list format.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    io::{EdgeListReadError, EdgeListWriteError, read_edge_list, write_edge_list},
+    prelude::*,
+};
+
+#[test]
+fn test_round_trip_preserves_symbols_weights_and_isolated_nodes() {
+    let adjacency: std::collections::HashMap<String, Vec<(String, f64)>> = [
+        ("node with spaces", vec![("Ünïcode", 0.1), ("node with spaces", -2.5)]),
+        ("Ünïcode", vec![("x", f64::INFINITY)]),
+        ("x", vec![("node with spaces", 1e-300)]),
+        ("isolated", vec![]),
+    ]
+    .into_iter()
+    .map(|(source, destinations)| {
+        (
+            source.to_string(),
+            destinations
+                .into_iter()
+                .map(|(destination, weight)| (destination.to_string(), weight))
+                .collect(),
+        )
+    })
+    .collect();
+    let graph = WeightedDiGraph::try_from(adjacency).unwrap();
+
+    let mut edge_list = Vec::new();
+    write_edge_list(&graph, &mut edge_list).unwrap();
+    let read = read_edge_list(edge_list.as_slice()).unwrap();
+    assert_eq!(read, graph);
+    assert_eq!(read.to_adjacency_map(), graph.to_adjacency_map());
+}
+
+#[test]
+fn test_write_non_string_symbols() {
+    let adjacency: std::collections::HashMap<u32, Vec<(u32, f64)>> =
+        std::collections::HashMap::from([(10, vec![(9, 2.0)]), (9, vec![(1, 0.5)]), (4, vec![])]);
+    let graph = WeightedDiGraph::try_from(adjacency).unwrap();
+    let mut edge_list = Vec::new();
+    write_edge_list(&graph, &mut edge_list).unwrap();
+    assert_eq!(String::from_utf8(edge_list).unwrap(), "9\t1\t0.5\n10\t9\t2\n4\n");
+}
+
+#[test]
+fn test_unrepresentable_symbols() {
+    for symbol in ["", "tab\there", "line\nbreak"] {
+        let graph = WeightedDiGraph::try_from(std::collections::HashMap::from([(
+            symbol.to_string(),
+            Vec::<(String, f64)>::new(),
+        )]))
+        .unwrap();
+        assert!(matches!(
+            write_edge_list(&graph, Vec::new()),
+            Err(EdgeListWriteError::UnrepresentableSymbol { node: 0, .. })
+        ));
+    }
+}
+
+#[test]
+fn test_read_errors() {
+    assert_eq!(
+        read_edge_list("a\tb\t1\n\na\tb\n".as_bytes()),
+        Err(EdgeListReadError::InvalidFieldCount { line: 3, fields: 2 })
+    );
+    assert_eq!(
+        read_edge_list("a\tb\theavy\n".as_bytes()),
+        Err(EdgeListReadError::InvalidWeight { line: 1, value: "heavy".to_string() })
+    );
+    assert_eq!(
+        read_edge_list("a\tb\t1\nb\ta\t1\na\tb\t2\n".as_bytes()),
+        Err(EdgeListReadError::DuplicatedEdge {
+            line: 3,
+            source_symbol: "a".to_string(),
+            destination_symbol: "b".to_string(),
+        })
+    );
+}
@@ -0,0 +1,171 @@
+//! Tests for the approximate effective resistances of weighted undirected
+//! graphs.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::{PaddedMatrix2D, SymmetricCSR2D, ValuedCSR2D},
+    prelude::*,
+    traits::{
+        EdgesBuilder, EffectiveResistance, EffectiveResistanceConfig, EffectiveResistanceError,
+        Laplacian, algorithms::randomized_graphs::XorShift64,
+    },
+};
+
+type Weights = SymmetricCSR2D<ValuedCSR2D<usize, usize, usize, f64>>;
+type TestValuedCSR = ValuedCSR2D<usize, usize, usize, f64>;
+type TestPadded = PaddedMatrix2D<TestValuedCSR, Box<dyn Fn((usize, usize)) -> f64>>;
+
+/// Returns a random connected weighted graph, made of a weighted path and of
+/// random chords.
+#[allow(clippy::cast_precision_loss)]
+fn random_connected_weights(seed: u64, order: usize) -> Weights {
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(seed));
+    let mut entries = Vec::new();
+    for source in 0..order {
+        for destination in source + 1..order {
+            if destination == source + 1 || rng.next().unwrap() % 100 < 30 {
+                entries.push((source, destination, (1 + rng.next().unwrap() % 400) as f64 / 100.0));
+            }
+        }
+    }
+    SymmetricCSR2D::from_sorted_upper_triangular_entries(order, entries).unwrap()
+}
+
+/// Returns the dense version of the sparse Laplacian, as a padded matrix.
+fn dense(laplacian: &TestValuedCSR) -> TestPadded {
+    let order = laplacian.number_of_rows();
+    let mut values = vec![0.0; order * order];
+    for row in laplacian.row_indices() {
+        for (column, value) in laplacian.sparse_row(row).zip(laplacian.sparse_row_values(row)) {
+            values[row * order + column] = value;
+        }
+    }
+    let inner = GenericEdgesBuilder::<_, TestValuedCSR>::default()
+        .expected_number_of_edges(0)
+        .expected_shape((order, order))
+        .edges(core::iter::empty())
+        .build()
+        .unwrap();
+    PaddedMatrix2D::new(
+        inner,
+        Box::new(move |(row, column): (usize, usize)| values[row * order + column])
+            as Box<dyn Fn((usize, usize)) -> f64>,
+    )
+    .unwrap()
+}
+
+/// Returns the exact effective resistances of a connected graph, from the
+/// pseudo-inverse of its Laplacian.
+fn exact_resistances(matrix: &Weights) -> Vec<Vec<f64>> {
+    let laplacian = matrix.laplacian().unwrap();
+    let order = laplacian.number_of_rows();
+    let decomposition = dense(&laplacian).jacobi(&JacobiConfig::default()).unwrap();
+    let mut pseudo_inverse = vec![vec![0.0; order]; order];
+    for (k, &eigenvalue) in decomposition.eigenvalues().iter().enumerate() {
+        if eigenvalue < 1e-9 {
+            continue;
+        }
+        let eigenvector = decomposition.eigenvector(k);
+        for (row, values) in pseudo_inverse.iter_mut().enumerate() {
+            for (column, value) in values.iter_mut().enumerate() {
+                *value += eigenvector[row] * eigenvector[column] / eigenvalue;
+            }
+        }
+    }
+    (0..order)
+        .map(|row| {
+            (0..order)
+                .map(|column| {
+                    pseudo_inverse[row][row] + pseudo_inverse[column][column]
+                        - 2.0 * pseudo_inverse[row][column]
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+#[allow(clippy::cast_precision_loss)]
+fn test_resistances_approximate_the_exact_ones() {
+    let config = EffectiveResistanceConfig { sketch_size: 2000, ..Default::default() };
+    for seed in 1..=5 {
+        let matrix = random_connected_weights(seed, 8);
+        let exact = exact_resistances(&matrix);
+        let resistances =
+            matrix.effective_resistances(&EffectiveResistanceConfig { seed, ..config }).unwrap();
+        assert_eq!(resistances.number_of_nodes(), 8);
+        assert_eq!(resistances.sketch_size(), 2000);
+        for (source, row) in exact.iter().enumerate() {
+            for (destination, &expected) in row.iter().enumerate() {
+                let approximation = resistances.resistance(source, destination);
+                assert!(
+                    (approximation - expected).abs() <= 0.2 * expected + 1e-9,
+                    "{approximation} != {expected}"
+                );
+            }
+        }
+
+        // By Foster's theorem, the importances sum to the number of nodes
+        // minus one.
+        let total: f64 = resistances.edge_importances().map(|(_, _, importance)| importance).sum();
+        assert!((total - 7.0).abs() < 0.5, "{total}");
+    }
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_resistances_of_bridges_and_components() {
+    // A path of two edges, a separate unit edge and an isolated node.
+    let matrix: Weights = SymmetricCSR2D::from_sorted_upper_triangular_entries(
+        6,
+        vec![(0, 1, 2.0), (1, 2, 4.0), (3, 4, 1.0)],
+    )
+    .unwrap();
+    let config = EffectiveResistanceConfig { sketch_size: 1000, ..Default::default() };
+    let resistances = matrix.effective_resistances(&config).unwrap();
+    assert!((resistances.resistance(0, 1) - 0.5).abs() < 0.1);
+    assert!((resistances.resistance(0, 2) - 0.75).abs() < 0.15);
+    assert!((resistances.resistance(3, 4) - 1.0).abs() < 0.2);
+    assert_eq!(resistances.resistance(0, 3), f64::INFINITY);
+    assert_eq!(resistances.resistance(5, 2), f64::INFINITY);
+    assert_eq!(resistances.resistance(5, 5), 0.0);
+    assert_eq!(resistances.edges(), &[(0, 1, 2.0), (1, 2, 4.0), (3, 4, 1.0)]);
+
+    // Bridges have unit importance.
+    for (_, _, importance) in resistances.edge_importances() {
+        assert!((importance - 1.0).abs() < 0.2, "{importance}");
+    }
+
+    // The projections are deterministic for a given seed.
+    assert_eq!(resistances, matrix.effective_resistances(&config).unwrap());
+    assert_ne!(
+        resistances,
+        matrix.effective_resistances(&EffectiveResistanceConfig { seed: 7, ..config }).unwrap()
+    );
+}
+
+#[test]
+fn test_invalid_configurations_are_rejected() {
+    let matrix = random_connected_weights(1, 5);
+    assert_eq!(
+        matrix.effective_resistances(&EffectiveResistanceConfig {
+            sketch_size: 0,
+            ..Default::default()
+        }),
+        Err(EffectiveResistanceError::ZeroSketchSize)
+    );
+    assert_eq!(
+        matrix.effective_resistances(&EffectiveResistanceConfig {
+            tolerance: -1.0,
+            ..Default::default()
+        }),
+        Err(EffectiveResistanceError::InvalidTolerance(-1.0))
+    );
+    assert_eq!(
+        matrix.effective_resistances(&EffectiveResistanceConfig {
+            max_iterations: 1,
+            ..Default::default()
+        }),
+        Err(EffectiveResistanceError::DidNotConverge { iterations: 1 })
+    );
+}
@@ -0,0 +1,132 @@
+//! Tests for the extraction of ego graphs.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::{CSR2D, SortedVec, SquareCSR2D, SymmetricCSR2D},
+    prelude::*,
+    traits::{EgoGraph, VocabularyBuilder, algorithms::randomized_graphs::XorShift64},
+};
+
+/// Helper to build a directed graph.
+fn build_digraph(node_count: usize, edges: Vec<(usize, usize)>) -> DiGraph<usize> {
+    let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+        .expected_number_of_symbols(node_count)
+        .symbols((0..node_count).enumerate())
+        .build()
+        .unwrap();
+    let edge_count = edges.len();
+    let edges: SquareCSR2D<CSR2D<usize, usize, usize>> = DiEdgesBuilder::default()
+        .expected_number_of_edges(edge_count)
+        .expected_shape(node_count)
+        .edges(edges.into_iter())
+        .build()
+        .unwrap();
+    DiGraph::from((nodes, edges))
+}
+
+/// Helper to build an undirected graph.
+fn build_undi_graph(node_count: usize, edges: Vec<(usize, usize)>) -> UndiGraph<usize> {
+    let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+        .expected_number_of_symbols(node_count)
+        .symbols((0..node_count).enumerate())
+        .build()
+        .unwrap();
+    let edge_count = edges.len();
+    let edges: SymmetricCSR2D<CSR2D<usize, usize, usize>> = UndiEdgesBuilder::default()
+        .expected_number_of_edges(edge_count)
+        .expected_shape(node_count)
+        .edges(edges.into_iter())
+        .build()
+        .unwrap();
+    UndiGraph::from((nodes, edges))
+}
+
+#[test]
+fn test_ego_graph_against_pairwise_bfs() {
+    let order = 30;
+    for seed in 0..20 {
+        let mut rng = XorShift64::from(XorShift64::normalize_seed(seed));
+        let mut edges = Vec::new();
+        for u in 0..order {
+            for v in 0..order {
+                if rng.next().unwrap() % 100 < 6 {
+                    edges.push((u, v));
+                }
+            }
+        }
+        let graph = build_digraph(order, edges.clone());
+        let all_distances = graph.edges().matrix().pairwise_bfs();
+
+        for center in [0, 7, 29] {
+            for radius in 0..4 {
+                let ego = graph.ego_graph(center, radius);
+                let expected: Vec<usize> = (0..order)
+                    .filter(|&node| {
+                        all_distances
+                            .value((center, node))
+                            .is_some_and(|distance| distance <= radius)
+                    })
+                    .collect();
+                assert_eq!(ego.node_map(), expected.as_slice(), "seed {seed}");
+                assert_eq!(ego.number_of_nodes(), expected.len());
+                for (node, &original) in expected.iter().enumerate() {
+                    assert_eq!(ego.original_node(node), original);
+                    assert_eq!(ego.subgraph_node(original), Some(node));
+                    assert_eq!(Some(ego.distance(node)), all_distances.value((center, original)));
+                }
+
+                let expected_edges: Vec<(usize, usize)> = edges
+                    .iter()
+                    .filter_map(|&(u, v)| Some((ego.subgraph_node(u)?, ego.subgraph_node(v)?)))
+                    .collect();
+                assert_eq!(
+                    SparseMatrix::sparse_coordinates(ego.graph()).collect::<Vec<_>>(),
+                    expected_edges
+                );
+                assert_eq!(ego.graph().order(), expected.len());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_ego_graph_of_undirected_graph() {
+    // A star whose leaves 1 and 2 are joined, with a tail 4 - 5 - 6.
+    let graph = build_undi_graph(7, vec![(0, 1), (0, 2), (0, 3), (1, 2), (3, 4), (4, 5), (5, 6)]);
+
+    let ego = graph.ego_graph(0, 0);
+    assert_eq!(ego.node_map(), &[0]);
+    assert_eq!(ego.graph().number_of_defined_values(), 0);
+
+    let ego = graph.ego_graph(0, 2);
+    assert_eq!(ego.node_map(), &[0, 1, 2, 3, 4]);
+    assert_eq!((0..5).map(|node| ego.distance(node)).collect::<Vec<_>>(), vec![0, 1, 1, 1, 2]);
+    // The edge between the two leaves is kept, the one leaving the radius is
+    // not.
+    assert!(ego.graph().sparse_row(1).any(|node| node == 2));
+    assert_eq!(ego.graph().sparse_row(4).collect::<Vec<_>>(), vec![3]);
+    assert_eq!(ego.clone().into_graph(), *ego.graph());
+
+    // A radius beyond the eccentricity yields the component of the center.
+    let ego = graph.ego_graph(6, usize::MAX);
+    assert_eq!(ego.number_of_nodes(), 7);
+    assert_eq!(ego.distance(0), 4);
+}
+
+#[test]
+fn test_ego_graph_follows_edge_direction() {
+    let graph = build_digraph(4, vec![(0, 1), (1, 2), (2, 2), (3, 0)]);
+    let ego = graph.ego_graph(1, 3);
+    assert_eq!(ego.node_map(), &[1, 2]);
+    assert_eq!(
+        SparseMatrix::sparse_coordinates(ego.graph()).collect::<Vec<_>>(),
+        vec![(0, 1), (1, 1)]
+    );
+}
+
+#[test]
+#[should_panic(expected = "The center must be a node of the graph")]
+fn test_ego_graph_panics_on_unknown_center() {
+    let graph = build_digraph(2, vec![(0, 1)]);
+    let _ = graph.ego_graph(2, 1);
+}
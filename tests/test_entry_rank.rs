@@ -0,0 +1,66 @@
+//! Tests for the `entry_rank` and `neighbor_position` lookups.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::{CSR2D, SquareCSR2D, ValuedCSR2D},
+    prelude::*,
+    traits::{
+        SizedSparseValuedMatrixMut,
+        algorithms::randomized_graphs::{
+            SparseCostMatrixConfig, erdos_renyi_gnp, random_sparse_cost_matrix,
+        },
+    },
+};
+
+fn assert_lookups_match_row_scan<M>(matrix: &M)
+where
+    M: SizedSparseMatrix2D<RowIndex = usize, ColumnIndex = usize, SparseIndex = usize>,
+{
+    for row in 0..matrix.number_of_rows() {
+        let columns: Vec<usize> = matrix.sparse_row(row).collect();
+        for column in 0..matrix.number_of_columns() {
+            let position = columns.iter().position(|&sparse_column| sparse_column == column);
+            assert_eq!(matrix.neighbor_position(row, column), position);
+            assert_eq!(
+                matrix.entry_rank(row, column),
+                position.map(|position| matrix.rank_row(row) + position)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_entry_rank_matches_row_scan() {
+    for seed in 1..=5 {
+        assert_lookups_match_row_scan(&erdos_renyi_gnp(seed, 20, 0.2));
+        assert_lookups_match_row_scan(&random_sparse_cost_matrix(
+            seed,
+            &SparseCostMatrixConfig::new(15, 25, 0.3),
+        ));
+    }
+
+    let mut rectangular: CSR2D<usize, usize, usize> = SparseMatrixMut::with_sparse_shape((4, 3));
+    for entry in [(0, 2), (2, 0), (2, 1)] {
+        MatrixMut::add(&mut rectangular, entry).unwrap();
+    }
+    assert_lookups_match_row_scan(&rectangular);
+    assert_lookups_match_row_scan(&SquareCSR2D::<CSR2D<usize, usize, usize>>::default());
+}
+
+#[test]
+fn test_entry_rank_updates_values_in_place() {
+    let mut matrix: ValuedCSR2D<usize, usize, usize, i32> =
+        SparseMatrixMut::with_sparse_shape((3, 3));
+    for entry in [(0, 0, 1), (0, 2, 2), (1, 1, 3), (2, 0, 4), (2, 2, 5)] {
+        MatrixMut::add(&mut matrix, entry).unwrap();
+    }
+
+    for (row, column) in [(0, 2), (2, 0), (2, 2)] {
+        let rank = matrix.entry_rank(row, column).unwrap();
+        *matrix.select_value_mut(rank) *= 10;
+    }
+    assert_eq!(matrix.sparse_row_values(2).collect::<Vec<_>>(), vec![40, 50]);
+    assert_eq!(matrix.sparse_row_values(0).collect::<Vec<_>>(), vec![1, 20]);
+    assert_eq!(matrix.neighbor_position(2, 2), Some(1));
+    assert_eq!(matrix.entry_rank(1, 0), None);
+}
@@ -0,0 +1,104 @@
+//! Tests for the construction of epsilon-neighbourhood graphs.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::{SymmetricCSR2D, ValuedCSR2D},
+    prelude::*,
+    spatial::{Angular, Euclidean, Manhattan, Metric, epsilon_graph},
+    traits::algorithms::randomized_graphs::{XorShift64, random_geometric_graph_with_positions},
+};
+
+type Graph = SymmetricCSR2D<ValuedCSR2D<usize, usize, usize, f64>>;
+
+#[allow(clippy::cast_precision_loss)]
+fn random_points(seed: u64, n: usize, dimensions: usize, resolution: u64) -> Vec<Vec<f64>> {
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(seed));
+    (0..n)
+        .map(|_| {
+            (0..dimensions).map(|_| (rng.next().unwrap() % resolution) as f64 / 10.0).collect()
+        })
+        .collect()
+}
+
+fn weighted_edges(graph: &Graph) -> Vec<(usize, usize, f64)> {
+    SparseMatrix::sparse_coordinates(graph)
+        .zip(graph.sparse_values())
+        .map(|((row, column), distance)| (row, column, distance))
+        .collect()
+}
+
+fn brute_force<P, M: Metric<P>>(
+    points: &[P],
+    epsilon: f64,
+    metric: &M,
+) -> Vec<(usize, usize, f64)> {
+    let mut edges = Vec::new();
+    for (row, left) in points.iter().enumerate() {
+        for (column, right) in points.iter().enumerate() {
+            if row == column {
+                continue;
+            }
+            // Distances are computed from the smaller endpoint, as the graph
+            // does, so that both directions carry the same weight.
+            let distance = if row < column {
+                metric.distance(left, right)
+            } else {
+                metric.distance(right, left)
+            };
+            if distance <= epsilon {
+                edges.push((row, column, distance));
+            }
+        }
+    }
+    edges
+}
+
+#[test]
+fn test_epsilon_graph_matches_brute_force() {
+    // A coarse resolution produces coincident points and distances equal to
+    // epsilon.
+    let points = random_points(1, 300, 2, 60);
+    for epsilon in [0.0, 0.3, 1.0, 2.5] {
+        let graph = epsilon_graph(&points, epsilon, &Euclidean);
+        assert_eq!(weighted_edges(&graph), brute_force(&points, epsilon, &Euclidean));
+        let graph = epsilon_graph(&points, epsilon, &Manhattan);
+        assert_eq!(weighted_edges(&graph), brute_force(&points, epsilon, &Manhattan));
+    }
+    let points = random_points(2, 200, 6, 1000);
+    let graph = epsilon_graph(&points, 0.2, &Angular);
+    assert_eq!(weighted_edges(&graph), brute_force(&points, 0.2, &Angular));
+}
+
+#[test]
+fn test_epsilon_graph_matches_random_geometric_graph() {
+    let radius = 0.08;
+    let (expected, positions) = random_geometric_graph_with_positions(3, 500, radius);
+    let points: Vec<[f64; 2]> = positions.into_iter().map(|(x, y)| [x, y]).collect();
+    let graph = epsilon_graph(&points, radius, &Euclidean);
+    assert_eq!(
+        SparseMatrix::sparse_coordinates(&graph).collect::<Vec<_>>(),
+        SparseMatrix::sparse_coordinates(&expected).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_epsilon_graph_degenerate_inputs() {
+    let empty: [[f64; 2]; 0] = [];
+    let graph = epsilon_graph(&empty, 1.0, &Euclidean);
+    assert_eq!(graph.order(), 0);
+
+    let points = [[0.0], [0.0], [3.0]];
+    let graph = epsilon_graph(&points, -1.0, &Euclidean);
+    assert_eq!(graph.order(), 3);
+    assert_eq!(graph.number_of_defined_values(), 0);
+    let graph = epsilon_graph(&points, f64::NAN, &Euclidean);
+    assert_eq!(graph.number_of_defined_values(), 0);
+
+    // Coincident points are connected with a null weight and no self-loop.
+    let graph = epsilon_graph(&points, 0.0, &Euclidean);
+    assert_eq!(weighted_edges(&graph), vec![(0, 1, 0.0), (1, 0, 0.0)]);
+    let graph = epsilon_graph(&points, 3.0, &|left: &[f64; 1], right: &[f64; 1]| {
+        (left[0] - right[0]).abs()
+    });
+    assert_eq!(graph.number_of_defined_values(), 6);
+}
@@ -0,0 +1,210 @@
+//! Tests for the degree-corrected stochastic block model fitting.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::ValuedCSR2D,
+    prelude::*,
+    traits::{ModularityError, SbmConfig, algorithms::randomized_graphs::stochastic_block_model},
+};
+
+type WeightedMatrix = ValuedCSR2D<usize, usize, usize, f64>;
+
+fn build_undirected_weighted_graph(
+    node_count: usize,
+    undirected_edges: &[(usize, usize, f64)],
+) -> WeightedMatrix {
+    let mut edges = Vec::with_capacity(undirected_edges.len() * 2);
+    for &(source, destination, weight) in undirected_edges {
+        edges.push((source, destination, weight));
+        if source != destination {
+            edges.push((destination, source, weight));
+        }
+    }
+    edges.sort_unstable_by_key(|&(source, destination, _)| (source, destination));
+    GenericEdgesBuilder::<_, WeightedMatrix>::default()
+        .expected_number_of_edges(edges.len())
+        .expected_shape((node_count, node_count))
+        .edges(edges.into_iter())
+        .build()
+        .unwrap()
+}
+
+fn planted(seed: u64, sizes: &[usize], p_intra: f64, p_inter: f64) -> WeightedMatrix {
+    let matrix = stochastic_block_model(seed, sizes, p_intra, p_inter);
+    let edges: Vec<(usize, usize, f64)> = SparseMatrix::sparse_coordinates(&matrix)
+        .filter(|&(left, right)| left < right)
+        .map(|(left, right)| (left, right, 1.0))
+        .collect();
+    build_undirected_weighted_graph(sizes.iter().sum(), &edges)
+}
+
+/// Returns the profile log-likelihood of the partition, from scratch.
+fn log_likelihood(graph: &WeightedMatrix, partition: &[usize], number_of_blocks: usize) -> f64 {
+    let mut weights = vec![0.0; number_of_blocks * number_of_blocks];
+    let mut degrees = vec![0.0; number_of_blocks];
+    for ((source, destination), &weight) in graph.sparse_entries() {
+        let (left, right) = (partition[source], partition[destination]);
+        weights[left * number_of_blocks + right] += weight;
+        degrees[left] += weight;
+    }
+    let mut likelihood = 0.0;
+    for left in 0..number_of_blocks {
+        for right in 0..number_of_blocks {
+            let weight = weights[left * number_of_blocks + right];
+            if weight > 0.0 {
+                likelihood += weight * (weight / (degrees[left] * degrees[right])).ln();
+            }
+        }
+    }
+    likelihood
+}
+
+/// Returns whether the two partitions group the nodes in the same way.
+fn same_grouping(left: &[usize], right: &[usize]) -> bool {
+    left.len() == right.len()
+        && (0..left.len()).all(|first| {
+            (0..left.len())
+                .all(|second| (left[first] == left[second]) == (right[first] == right[second]))
+        })
+}
+
+#[test]
+fn test_fit_sbm_recovers_planted_partitions() {
+    let sizes = [15, 20, 25];
+    let planted_partition: Vec<usize> =
+        sizes.iter().enumerate().flat_map(|(block, &size)| vec![block; size]).collect();
+    for seed in 1..=4 {
+        let graph = planted(seed, &sizes, 0.5, 0.02);
+        let config = SbmConfig { number_of_blocks: 3, seed, ..SbmConfig::default() };
+        let result = FitSbm::<usize>::fit_sbm(&graph, &config).unwrap();
+        assert_eq!(result.number_of_blocks(), 3);
+        assert!(same_grouping(result.partition(), &planted_partition), "seed {seed}");
+        assert!(result.modularity() > 0.4);
+    }
+
+    // A bipartite graph, which modularity cannot find, is split by its sides.
+    let mut edges = Vec::new();
+    for left in 0..6 {
+        for right in 6..12 {
+            if (left + right) % 3 != 0 {
+                edges.push((left, right, 1.0));
+            }
+        }
+    }
+    let graph = build_undirected_weighted_graph(12, &edges);
+    let result = FitSbm::<usize>::fit_sbm(&graph, &SbmConfig::default()).unwrap();
+    let bipartition: Vec<usize> = (0..12).map(|node| node / 6).collect();
+    assert!(same_grouping(result.partition(), &bipartition));
+    assert!(result.modularity() < 0.0);
+    assert!(result.block_weight(0, 0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn test_fit_sbm_is_a_local_optimum() {
+    for seed in 1..=4 {
+        let graph = planted(seed, &[10, 10, 10, 10], 0.3, 0.08);
+        let number_of_blocks = 4;
+        let config = SbmConfig { number_of_blocks, seed, ..SbmConfig::default() };
+        let result = FitSbm::<usize>::fit_sbm(&graph, &config).unwrap();
+        let partition = result.partition().to_vec();
+        let likelihood = log_likelihood(&graph, &partition, number_of_blocks);
+        assert!((result.log_likelihood() - likelihood).abs() < 1e-9 * likelihood.abs());
+
+        // No single node move, keeping every block non-empty, improves the fit.
+        for node in 0..partition.len() {
+            if partition.iter().filter(|&&block| block == partition[node]).count() == 1 {
+                continue;
+            }
+            for block in 0..number_of_blocks {
+                let mut moved = partition.clone();
+                moved[node] = block;
+                assert!(
+                    log_likelihood(&graph, &moved, number_of_blocks)
+                        <= likelihood + 1e-9 * likelihood.abs(),
+                    "seed {seed}: moving {node} to {block}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_fit_sbm_block_statistics() {
+    // Two weighted triangles, one with a self-loop, and an isolated node.
+    let graph = build_undirected_weighted_graph(
+        7,
+        &[
+            (0, 0, 1.0),
+            (0, 1, 2.0),
+            (0, 2, 2.0),
+            (1, 2, 2.0),
+            (2, 3, 0.5),
+            (3, 4, 1.0),
+            (3, 5, 1.0),
+            (4, 5, 1.0),
+        ],
+    );
+    let result = FitSbm::<u8>::fit_sbm(&graph, &SbmConfig::default()).unwrap();
+    let partition: Vec<usize> =
+        result.partition().iter().map(|&block| usize::from(block)).collect();
+    assert!(same_grouping(&partition[..6], &[0, 0, 0, 1, 1, 1]));
+
+    let first = partition[0];
+    let second = partition[3];
+    assert_eq!(result.block_weight(first, first), 13.0);
+    assert_eq!(result.block_weight(first, second), 0.5);
+    assert_eq!(result.block_weight(second, first), 0.5);
+    assert_eq!(result.block_weight(second, second), 6.0);
+    assert_eq!(result.block_degrees()[first] + result.block_degrees()[second], 20.0);
+    assert_eq!(result.degree_propensities()[6], 0.0);
+    for block in [first, second] {
+        let total: f64 = (0..7)
+            .filter(|&node| partition[node] == block && node != 6)
+            .map(|node| result.degree_propensities()[node])
+            .sum();
+        assert!((total - 1.0).abs() < 1e-12);
+    }
+
+    // The expected weights add up to the total weight of the graph.
+    let expected: f64 = (0..7)
+        .flat_map(|left| (0..7).map(move |right| (left, right)))
+        .map(|(left, right)| result.expected_weight(left, right))
+        .sum();
+    assert!((expected - 20.0).abs() < 1e-9);
+
+    // As many blocks as nodes leaves every node in a block of its own.
+    let config = SbmConfig { number_of_blocks: 7, ..SbmConfig::default() };
+    let result = FitSbm::<usize>::fit_sbm(&graph, &config).unwrap();
+    let mut blocks = result.partition().to_vec();
+    blocks.sort_unstable();
+    assert_eq!(blocks, (0..7).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_fit_sbm_errors() {
+    let graph = build_undirected_weighted_graph(3, &[(0, 1, 1.0), (1, 2, 1.0)]);
+    for number_of_blocks in [0, 4] {
+        let config = SbmConfig { number_of_blocks, ..SbmConfig::default() };
+        assert_eq!(
+            FitSbm::<usize>::fit_sbm(&graph, &config),
+            Err(ModularityError::InvalidNumberOfBlocks { number_of_blocks, number_of_nodes: 3 })
+        );
+    }
+    let config = SbmConfig { max_refinement_passes: 0, ..SbmConfig::default() };
+    assert_eq!(
+        FitSbm::<usize>::fit_sbm(&graph, &config),
+        Err(ModularityError::InvalidMaxRefinementPasses)
+    );
+
+    let asymmetric: WeightedMatrix = GenericEdgesBuilder::<_, WeightedMatrix>::default()
+        .expected_number_of_edges(1)
+        .expected_shape((2, 2))
+        .edges(vec![(0, 1, 1.0)].into_iter())
+        .build()
+        .unwrap();
+    assert_eq!(
+        FitSbm::<usize>::fit_sbm(&asymmetric, &SbmConfig::default()),
+        Err(ModularityError::NonSymmetricEdge { source_id: 0, destination_id: 1 })
+    );
+}
@@ -0,0 +1,110 @@
+//! Tests for the construction of matrices from entries under an explicit
+//! sorting and duplicates policy.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::{CSR2D, SquareCSR2D, UpperTriangularCSR2D, ValuedCSR2D},
+    prelude::*,
+    traits::{DuplicatePolicy, InputPolicy},
+};
+
+type TestCSR = CSR2D<usize, usize, usize>;
+type TestValuedCSR = ValuedCSR2D<usize, usize, usize, i32>;
+
+const SORT_AND_DEDUP: InputPolicy =
+    InputPolicy { sort: true, duplicates: DuplicatePolicy::KeepFirst };
+
+#[test]
+fn test_default_policy_matches_from_entries() {
+    let sorted = vec![(0, 1), (1, 0), (1, 2)];
+    assert_eq!(
+        TestCSR::from_entries_with_policy(sorted.clone(), InputPolicy::default()).unwrap(),
+        TestCSR::from_entries(sorted).unwrap()
+    );
+    assert_eq!(
+        TestCSR::from_entries_with_policy(vec![(1, 0), (0, 1)], InputPolicy::default()),
+        Err(MutabilityError::UnorderedCoordinate((0, 1)))
+    );
+    assert_eq!(
+        TestCSR::from_entries_with_policy(vec![(0, 1), (0, 1)], InputPolicy::default()),
+        Err(MutabilityError::DuplicatedEntry((0, 1)))
+    );
+}
+
+#[test]
+fn test_sorting_policy_across_matrices() {
+    let entries = vec![(2, 2), (0, 1), (1, 2), (0, 0)];
+    let policy = InputPolicy { sort: true, ..InputPolicy::default() };
+
+    let csr = TestCSR::from_entries_with_policy(entries.clone(), policy).unwrap();
+    assert_eq!(
+        SparseMatrix::sparse_coordinates(&csr).collect::<Vec<_>>(),
+        vec![(0, 0), (0, 1), (1, 2), (2, 2)]
+    );
+
+    let square = SquareCSR2D::<TestCSR>::from_entries_with_policy(entries.clone(), policy).unwrap();
+    assert_eq!(square.order(), 3);
+    assert_eq!(square.number_of_defined_diagonal_values(), 2);
+
+    let upper =
+        UpperTriangularCSR2D::<TestCSR>::from_entries_with_policy(entries.clone(), policy).unwrap();
+    assert_eq!(upper.number_of_defined_values(), 4);
+    assert!(
+        UpperTriangularCSR2D::<TestCSR>::from_entries_with_policy(vec![(1, 2), (1, 0)], policy)
+            .is_err()
+    );
+
+    // Sorting does not remove repeated coordinates.
+    assert_eq!(
+        TestCSR::from_entries_with_policy(vec![(1, 1), (0, 0), (1, 1)], policy),
+        Err(MutabilityError::DuplicatedEntry((1, 1)))
+    );
+}
+
+#[test]
+fn test_duplicate_policies_on_valued_entries() {
+    let entries = vec![(1, 1, 10), (0, 0, 1), (1, 1, 20), (0, 0, 2), (1, 1, 30)];
+
+    let first = TestValuedCSR::from_entries_with_policy(entries.clone(), SORT_AND_DEDUP).unwrap();
+    assert_eq!(first.sparse_values().collect::<Vec<_>>(), vec![1, 10]);
+
+    let last = TestValuedCSR::from_entries_with_policy(
+        entries.clone(),
+        InputPolicy { sort: true, duplicates: DuplicatePolicy::KeepLast },
+    )
+    .unwrap();
+    assert_eq!(last.sparse_values().collect::<Vec<_>>(), vec![2, 30]);
+    assert_eq!(SparseMatrix::sparse_coordinates(&last).collect::<Vec<_>>(), vec![(0, 0), (1, 1)]);
+
+    // Without sorting, only consecutive duplicates are merged.
+    let consecutive = vec![(0, 0, 1), (0, 0, 2), (0, 3, 3), (1, 1, 4), (1, 1, 5)];
+    let last = TestValuedCSR::from_entries_with_policy(
+        consecutive,
+        InputPolicy { sort: false, duplicates: DuplicatePolicy::KeepLast },
+    )
+    .unwrap();
+    assert_eq!(last.sparse_values().collect::<Vec<_>>(), vec![2, 3, 5]);
+    assert!(
+        TestValuedCSR::from_entries_with_policy(
+            vec![(0, 0, 1), (0, 1, 2), (0, 0, 3)],
+            InputPolicy { sort: false, duplicates: DuplicatePolicy::KeepFirst }
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn test_duplicate_policies_on_structural_entries() {
+    let entries = vec![(0, 2), (0, 1), (0, 2), (2, 0), (0, 1)];
+    let expected = vec![(0, 1), (0, 2), (2, 0)];
+    for duplicates in [DuplicatePolicy::KeepFirst, DuplicatePolicy::KeepLast] {
+        let policy = InputPolicy { sort: true, duplicates };
+        let square =
+            SquareCSR2D::<TestCSR>::from_entries_with_policy(entries.clone(), policy).unwrap();
+        assert_eq!(SparseMatrix::sparse_coordinates(&square).collect::<Vec<_>>(), expected);
+    }
+    assert_eq!(
+        TestCSR::from_entries_with_policy(Vec::new(), SORT_AND_DEDUP).unwrap(),
+        TestCSR::default()
+    );
+}
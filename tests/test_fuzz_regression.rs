@@ -20,9 +20,9 @@ use geometric_traits::{
         check_lap_sparse_wrapper_invariants, check_lap_square_invariants, check_leiden_invariants,
         check_louvain_invariants, check_padded_diagonal_invariants,
         check_padded_matrix2d_invariants, check_pairwise_bfs_matches_unit_floyd_warshall,
-        check_pairwise_dijkstra_matches_floyd_warshall, check_sparse_matrix_invariants,
-        check_structured_blossom_v_invariants, check_valued_matrix_invariants,
-        check_vf2_invariants, from_bytes, replay_dir,
+        check_pairwise_dijkstra_matches_floyd_warshall, check_sparse_hungarian_invariants,
+        check_sparse_matrix_invariants, check_structured_blossom_v_invariants,
+        check_valued_matrix_invariants, check_vf2_invariants, from_bytes, replay_dir,
     },
     traits::MonopartiteGraph,
 };
@@ -438,6 +438,7 @@ fn test_arbitrary_lap_invariants() {
     for_each_instance::<TestValuedCSR, _>(|csr| {
         check_lap_sparse_wrapper_invariants(csr);
         check_lap_square_invariants(csr);
+        check_sparse_hungarian_invariants(csr);
     });
 }
 
@@ -446,6 +447,7 @@ fn test_replay_lap_corpus() {
     for instance in replay_shared_fixture::<TestValuedCSR>() {
         check_lap_sparse_wrapper_invariants(&instance);
         check_lap_square_invariants(&instance);
+        check_sparse_hungarian_invariants(&instance);
     }
 }
 
@@ -0,0 +1,143 @@
+//! Tests for the fuzzy simplicial set of k-nearest-neighbour graphs.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::ValuedCSR2D,
+    prelude::*,
+    spatial::{Euclidean, FuzzySimplicialSetConfig, Metric, fuzzy_simplicial_set, knn_graph},
+    traits::algorithms::randomized_graphs::XorShift64,
+};
+
+type WeightedMatrix = ValuedCSR2D<usize, usize, usize, f64>;
+
+#[allow(clippy::cast_precision_loss)]
+fn random_points(seed: u64, n: usize) -> Vec<[f64; 3]> {
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(seed));
+    (0..n).map(|_| core::array::from_fn(|_| (rng.next().unwrap() % 1000) as f64 / 100.0)).collect()
+}
+
+fn weighted_edges<M>(graph: &M) -> Vec<(usize, usize, f64)>
+where
+    M: SparseValuedMatrix2D<RowIndex = usize, ColumnIndex = usize, Value = f64>,
+{
+    SparseMatrix::sparse_coordinates(graph)
+        .zip(graph.sparse_values())
+        .map(|((row, column), weight)| (row, column, weight))
+        .collect()
+}
+
+/// Returns a directed graph linking every node to the next `k` nodes of a
+/// cycle, so that no edge is reciprocated.
+#[allow(clippy::cast_precision_loss)]
+fn forward_cycle(order: usize, k: usize) -> WeightedMatrix {
+    let mut edges: Vec<(usize, usize, f64)> = (0..order)
+        .flat_map(|source| {
+            (1..=k).map(move |step| {
+                (source, (source + step) % order, (step * step) as f64 + source as f64 / 10.0)
+            })
+        })
+        .collect();
+    edges.sort_unstable_by_key(|&(source, destination, _)| (source, destination));
+    GenericEdgesBuilder::<_, WeightedMatrix>::default()
+        .expected_number_of_edges(edges.len())
+        .expected_shape((order, order))
+        .edges(edges.into_iter())
+        .build()
+        .unwrap()
+}
+
+#[test]
+#[allow(clippy::cast_precision_loss)]
+fn test_fuzzy_simplicial_set_calibrates_each_neighbourhood() {
+    for k in [2, 3, 5] {
+        let order = 20;
+        let memberships =
+            fuzzy_simplicial_set(&forward_cycle(order, k), &FuzzySimplicialSetConfig::default());
+        assert_eq!(memberships.number_of_defined_values(), 2 * order * k);
+        for source in 0..order {
+            // The nearest neighbour is fully connected and the memberships of
+            // the neighbourhood sum to log2(k + 1).
+            let total: f64 = (1..=k)
+                .map(|step| memberships.sparse_value_at(source, (source + step) % order).unwrap())
+                .sum();
+            assert_eq!(memberships.sparse_value_at(source, (source + 1) % order), Some(1.0));
+            assert!((total - ((k + 1) as f64).log2()).abs() < 1e-4, "k = {k}: {total}");
+        }
+    }
+}
+
+#[test]
+fn test_fuzzy_simplicial_set_mixes_union_and_intersection() {
+    let points = random_points(5, 120);
+    let graph = knn_graph(&points, 6, &Euclidean);
+    let mixed = |set_op_mix_ratio: f64| {
+        let config = FuzzySimplicialSetConfig { set_op_mix_ratio, ..Default::default() };
+        fuzzy_simplicial_set(&graph, &config)
+    };
+    let union = mixed(1.0);
+    let intersection = mixed(0.0);
+    let half = mixed(0.5);
+
+    assert!(union.sparse_values().all(|weight| weight > 0.0 && weight <= 1.0));
+    assert!(intersection.row_indices().all(|row| {
+        intersection
+            .sparse_row(row)
+            .all(|column| graph.has_entry(row, column) && graph.has_entry(column, row))
+    }));
+    assert_eq!(SparseMatrix::sparse_coordinates(&half).collect::<Vec<_>>(), {
+        SparseMatrix::sparse_coordinates(&union).collect::<Vec<_>>()
+    });
+    for (row, column, weight) in weighted_edges(&half) {
+        let union_weight = union.sparse_value_at(row, column).unwrap();
+        let intersection_weight = intersection.sparse_value_at(row, column).unwrap_or(0.0);
+        assert!((weight - f64::midpoint(union_weight, intersection_weight)).abs() < 1e-12);
+        // The union of the memberships exceeds their intersection.
+        assert!(union_weight >= intersection_weight);
+    }
+
+    // Every point is fully connected to its nearest neighbour.
+    for source in 0..points.len() {
+        let nearest = (0..points.len())
+            .filter(|&other| other != source)
+            .min_by(|&left, &right| {
+                Euclidean
+                    .distance(&points[source], &points[left])
+                    .total_cmp(&Euclidean.distance(&points[source], &points[right]))
+            })
+            .unwrap();
+        assert!((union.sparse_value_at(source, nearest).unwrap() - 1.0).abs() < f64::EPSILON);
+    }
+}
+
+#[test]
+fn test_fuzzy_simplicial_set_local_connectivity() {
+    // Without local connectivity, even the nearest neighbour is not fully
+    // connected, and larger local connectivities connect more neighbours.
+    let graph = forward_cycle(10, 4);
+    let connected = |local_connectivity: f64| {
+        let config = FuzzySimplicialSetConfig { local_connectivity, ..Default::default() };
+        let memberships = fuzzy_simplicial_set(&graph, &config);
+        weighted_edges(&memberships)
+            .into_iter()
+            .filter(|&(_, _, weight)| (weight - 1.0).abs() < f64::EPSILON)
+            .count()
+    };
+    assert_eq!(connected(0.0), 0);
+    assert_eq!(connected(1.0), 20);
+    assert_eq!(connected(2.0), 40);
+    assert_eq!(connected(1.5), 20);
+
+    // Points without neighbours are isolated.
+    let empty = knn_graph(&random_points(1, 5), 0, &Euclidean);
+    let memberships = fuzzy_simplicial_set(&empty, &FuzzySimplicialSetConfig::default());
+    assert_eq!(memberships.order(), 5);
+    assert_eq!(memberships.number_of_defined_values(), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_fuzzy_simplicial_set_rejects_invalid_mix_ratio() {
+    let graph = forward_cycle(5, 2);
+    let config = FuzzySimplicialSetConfig { set_op_mix_ratio: 1.5, ..Default::default() };
+    let _ = fuzzy_simplicial_set(&graph, &config);
+}
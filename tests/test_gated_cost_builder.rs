@@ -0,0 +1,116 @@
+//! Tests for the `GatedCostBuilder` of sparse linking cost matrices.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::ValuedCSR2D, prelude::*, spatial::GatedCostBuilder,
+    traits::algorithms::randomized_graphs::XorShift64,
+};
+
+/// A feature detected in a frame, with its position and time.
+#[derive(Debug, Clone, Copy)]
+struct Feature {
+    position: [f64; 2],
+    time: f64,
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn random_features(seed: u64, n: usize) -> Vec<Feature> {
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(seed));
+    let mut uniform = move || (rng.next().unwrap() % 10000) as f64 / 100.0;
+    (0..n).map(|_| Feature { position: [uniform(), uniform()], time: uniform() / 10.0 }).collect()
+}
+
+fn squared_displacement(left: &Feature, right: &Feature) -> f64 {
+    left.position.iter().zip(&right.position).map(|(left, right)| (left - right).powi(2)).sum()
+}
+
+fn gate(left: &Feature, right: &Feature) -> bool {
+    squared_displacement(left, right) <= 100.0 && (left.time - right.time).abs() <= 2.0
+}
+
+fn entries(matrix: &ValuedCSR2D<usize, usize, usize, f64>) -> Vec<(usize, usize, f64)> {
+    SparseMatrix::sparse_coordinates(matrix)
+        .zip(matrix.sparse_values())
+        .map(|((row, column), cost)| (row, column, cost))
+        .collect()
+}
+
+#[test]
+fn test_gated_cost_builder_keeps_exactly_the_gated_pairs() {
+    let first = random_features(1, 60);
+    let second = random_features(2, 45);
+    let costs =
+        GatedCostBuilder::new(&first, &second, squared_displacement).with_gate(gate).build();
+    assert_eq!(costs.number_of_rows(), first.len());
+    assert_eq!(costs.number_of_columns(), second.len());
+
+    let mut expected = Vec::new();
+    for (row, left) in first.iter().enumerate() {
+        for (column, right) in second.iter().enumerate() {
+            if gate(left, right) {
+                expected.push((row, column, squared_displacement(left, right)));
+            }
+        }
+    }
+    assert!(!expected.is_empty());
+    assert!(expected.len() < first.len() * second.len());
+    assert_eq!(entries(&costs), expected);
+
+    // Without gate, every pair is costed.
+    let dense = GatedCostBuilder::new(&first, &second, squared_displacement).build();
+    assert_eq!(dense.number_of_defined_values(), first.len() * second.len());
+}
+
+#[test]
+fn test_gated_cost_builder_evaluates_costs_only_behind_the_gate() {
+    let first = random_features(3, 20);
+    let second = random_features(4, 20);
+    let costs = GatedCostBuilder::new(&first, &second, |left: &Feature, right: &Feature| {
+        assert!(gate(left, right), "The cost was evaluated for a gated out pair.");
+        1.0
+    })
+    .with_gate(gate)
+    .build();
+    assert!(costs.sparse_values().all(|cost| (cost - 1.0).abs() < f64::EPSILON));
+}
+
+#[test]
+fn test_gated_cost_builder_drops_non_finite_costs() {
+    let first = [0.0, 1.0, 2.0];
+    let second = [0.0, 1.0];
+    let costs = GatedCostBuilder::new(&first, &second, |left: &f64, right: &f64| {
+        match left - right {
+            difference if difference > 1.5 => f64::INFINITY,
+            difference if difference < -0.5 => f64::NAN,
+            difference => difference.abs() + 1.0,
+        }
+    })
+    .build();
+    assert_eq!(entries(&costs), vec![(0, 0, 1.0), (1, 0, 2.0), (1, 1, 1.0), (2, 1, 2.0)]);
+}
+
+#[test]
+fn test_gated_costs_link_frames_with_jaqaman() {
+    // The second frame shifts every feature slightly, drops one feature and
+    // adds one far away.
+    let first = random_features(5, 40);
+    let mut second: Vec<Feature> = first
+        .iter()
+        .skip(1)
+        .map(|feature| {
+            Feature {
+                position: [feature.position[0] + 0.01, feature.position[1] - 0.01],
+                time: feature.time + 0.1,
+            }
+        })
+        .collect();
+    second.push(Feature { position: [1000.0, 1000.0], time: 0.0 });
+
+    let costs = GatedCostBuilder::new(&first, &second, squared_displacement)
+        .with_gate(|left: &Feature, right: &Feature| squared_displacement(left, right) <= 0.01)
+        .build();
+    let mut links = costs.jaqaman(1.0, 10.0).unwrap();
+    links.sort_unstable();
+    let expected: Vec<(usize, usize)> = (1..first.len()).map(|row| (row, row - 1)).collect();
+    assert_eq!(links, expected);
+}
@@ -0,0 +1,90 @@
+//! Tests for the conversions and contexts of the crate-level `GeometricError`.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    errors::{ErrorContext, GeometricError, InvariantError, builder::edges::EdgesBuilderError},
+    impls::{CSR2D, ValuedCSR2D},
+    prelude::*,
+    traits::{LAPError, LouvainConfig, ModularityError},
+};
+
+type TestCSR = CSR2D<usize, usize, usize>;
+type TestValuedCSR = ValuedCSR2D<usize, usize, usize, f64>;
+
+fn build_costs(edges: Vec<(usize, usize, f64)>) -> Result<TestValuedCSR, GeometricError> {
+    let number_of_edges = edges.len();
+    GenericEdgesBuilder::<_, TestValuedCSR>::default()
+        .expected_number_of_edges(number_of_edges)
+        .expected_shape((2, 2))
+        .edges(edges.into_iter())
+        .build()
+        .context("building the costs")
+}
+
+fn assign(edges: Vec<(usize, usize, f64)>) -> Result<Vec<(usize, usize)>, GeometricError> {
+    let costs = build_costs(edges)?;
+    costs.validate().context("validating the costs")?;
+    costs.lapmod(100.0).context("assigning")
+}
+
+#[test]
+fn test_geometric_error_records_the_failed_operation() {
+    assert_eq!(assign(vec![(0, 0, 1.0), (1, 1, 2.0)]).unwrap(), vec![(0, 0), (1, 1)]);
+
+    let error = assign(vec![(0, 0, 1.0), (1, 1, -2.0)]).unwrap_err();
+    assert_eq!(error.context(), Some("assigning"));
+    assert!(matches!(error.root_cause(), GeometricError::LAP(LAPError::NegativeValues)));
+
+    let error = assign(vec![(1, 1, 1.0), (0, 0, 2.0)]).unwrap_err();
+    assert_eq!(error.context(), Some("building the costs"));
+    assert!(matches!(error.root_cause(), GeometricError::EdgesBuilder(_)));
+    assert!(matches!(
+        error.downcast_ref::<EdgesBuilderError<TestValuedCSR>>(),
+        Some(EdgesBuilderError::MatrixError(_))
+    ));
+    assert!(error.downcast_ref::<EdgesBuilderError<TestCSR>>().is_none());
+    assert!(error.to_string().starts_with("building the costs: "));
+}
+
+#[test]
+fn test_geometric_error_nests_contexts() {
+    let error: GeometricError = InvariantError::OffsetsMustStartWithZero.into();
+    assert_eq!(error.context(), None);
+    assert_eq!(error.contexts().count(), 0);
+    assert!(error.downcast_ref::<InvariantError>().is_none());
+
+    let error = error.with_context("loading the graph").with_context("running the pipeline");
+    assert_eq!(error.context(), Some("running the pipeline"));
+    assert_eq!(
+        error.contexts().collect::<Vec<_>>(),
+        vec!["running the pipeline", "loading the graph"]
+    );
+    assert!(matches!(
+        error.root_cause(),
+        GeometricError::Invariant(InvariantError::OffsetsMustStartWithZero)
+    ));
+    assert_eq!(
+        error.to_string(),
+        format!(
+            "running the pipeline: loading the graph: {}",
+            InvariantError::OffsetsMustStartWithZero
+        )
+    );
+}
+
+#[test]
+fn test_geometric_error_converts_algorithm_and_matrix_errors() {
+    let config = LouvainConfig { resolution: -1.0, ..LouvainConfig::default() };
+    let error: GeometricError =
+        Louvain::<usize>::louvain(&TestValuedCSR::default(), &config).unwrap_err().into();
+    assert!(matches!(error, GeometricError::Modularity(ModularityError::InvalidResolution)));
+
+    let mut matrix = TestCSR::with_sparse_shape((2, 2));
+    MatrixMut::add(&mut matrix, (0, 1)).unwrap();
+    let error: GeometricError = MatrixMut::add(&mut matrix, (0, 1)).unwrap_err().into();
+    assert!(matches!(error, GeometricError::Mutability(_)));
+    assert_eq!(
+        error.downcast_ref::<MutabilityError<TestCSR>>(),
+        Some(&MutabilityError::DuplicatedEntry((0, 1)))
+    );
+}
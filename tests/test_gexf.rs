@@ -0,0 +1,96 @@
+//! Tests for the GEXF writer.
+#![cfg(feature = "std")]
+
+use geometric_traits::io::{
+    DelimitedEdgeListReader, GexfAttributeValues, GexfWriteError, GexfWriter,
+};
+
+fn write<F>(write: F) -> String
+where
+    F: FnOnce(&mut Vec<u8>) -> Result<(), GexfWriteError>,
+{
+    let mut document = Vec::new();
+    write(&mut document).unwrap();
+    String::from_utf8(document).unwrap()
+}
+
+#[test]
+fn test_static_weighted_digraph() {
+    let graph = DelimitedEdgeListReader::csv()
+        .weight_column(2)
+        .read_weighted_digraph("b,a,0.5\na,c,2\n".as_bytes())
+        .unwrap();
+    let document = write(|bytes| GexfWriter::default().write_weighted_digraph(&graph, bytes));
+
+    assert_eq!(
+        document,
+        concat!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+            "<gexf xmlns=\"http://gexf.net/1.3\" version=\"1.3\">\n",
+            "  <graph defaultedgetype=\"directed\" mode=\"static\">\n",
+            "    <nodes>\n",
+            "      <node id=\"0\" label=\"a\"/>\n",
+            "      <node id=\"1\" label=\"b\"/>\n",
+            "      <node id=\"2\" label=\"c\"/>\n",
+            "    </nodes>\n",
+            "    <edges>\n",
+            "      <edge id=\"0\" source=\"0\" target=\"2\" weight=\"2\"/>\n",
+            "      <edge id=\"1\" source=\"1\" target=\"0\" weight=\"0.5\"/>\n",
+            "    </edges>\n",
+            "  </graph>\n",
+            "</gexf>\n",
+        )
+    );
+}
+
+#[test]
+fn test_dynamic_undigraph_with_attributes() {
+    let graph =
+        DelimitedEdgeListReader::csv().read_undigraph("x,y\ny,\"<z & w>\"\n".as_bytes()).unwrap();
+    let document = write(|bytes| {
+        GexfWriter::default()
+            .node_attribute("degree", vec![1_i64, 1, 2])
+            .node_attribute("class", vec!["a".to_string(), "b\"".to_string(), "c".to_string()])
+            .node_attribute("seed", vec![true, false, false])
+            .edge_intervals(vec![(0.0, 1.5), (1.0, 255.0)])
+            .write_undigraph(&graph, bytes)
+    });
+
+    assert!(
+        document
+            .contains(r#"<graph defaultedgetype="undirected" mode="dynamic" timeformat="double">"#)
+    );
+    assert!(document.contains(r#"<attribute id="0" title="degree" type="long"/>"#));
+    assert!(document.contains(r#"<attribute id="1" title="class" type="string"/>"#));
+    assert!(document.contains(r#"<attribute id="2" title="seed" type="boolean"/>"#));
+    assert!(document.contains(r#"<node id="0" label="&lt;z &amp; w&gt;">"#));
+    assert!(document.contains(r#"<attvalue for="1" value="b&quot;"/>"#));
+    assert!(document.contains(r#"<edge id="0" source="0" target="2" start="0" end="1.5"/>"#));
+    assert!(document.contains(r#"<edge id="1" source="1" target="2" start="1" end="255"/>"#));
+    assert_eq!(document.matches("<edge ").count(), 2);
+    assert!(!document.contains("<node id=\"1\" label=\"x\" start"));
+}
+
+#[test]
+fn test_mismatched_columns_are_rejected() {
+    let graph = DelimitedEdgeListReader::csv().read_digraph("a,b\n".as_bytes()).unwrap();
+    let write = |writer: GexfWriter| writer.write_digraph(&graph, Vec::new());
+
+    assert!(matches!(
+        write(GexfWriter::default().node_attribute("mass", vec![1.0])),
+        Err(GexfWriteError::AttributeLength { expected: 2, found: 1, .. })
+    ));
+    assert!(matches!(
+        write(GexfWriter::default().edge_intervals(Vec::new())),
+        Err(GexfWriteError::IntervalLength { element: "edge", expected: 1, found: 0 })
+    ));
+    assert!(matches!(
+        write(GexfWriter::default().node_intervals(vec![(0.0, 1.0), (2.0, 1.0)])),
+        Err(GexfWriteError::InvalidInterval { element: "node", position: 1 })
+    ));
+    assert!(matches!(
+        write(GexfWriter::default().node_intervals(vec![(f64::NAN, 1.0), (0.0, 1.0)])),
+        Err(GexfWriteError::InvalidInterval { element: "node", position: 0 })
+    ));
+    assert_eq!(GexfAttributeValues::from(vec![1_i64, 2]).len(), 2);
+}
@@ -0,0 +1,186 @@
+//! Tests for the approximate graph edit distance between undirected graphs.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::{CSR2D, SortedVec, SymmetricCSR2D},
+    prelude::*,
+    traits::{
+        ApproximateGraphEditDistance, EdgesBuilder, GraphEditCosts, LAPError, VocabularyBuilder,
+        algorithms::randomized_graphs::erdos_renyi_gnp,
+    },
+};
+
+fn undirected(order: usize, edges: &[(usize, usize)]) -> UndiGraph<usize> {
+    let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+        .expected_number_of_symbols(order)
+        .symbols((0..order).enumerate())
+        .build()
+        .unwrap();
+    let mut edges: Vec<(usize, usize)> =
+        edges.iter().map(|&(left, right)| (left.min(right), left.max(right))).collect();
+    edges.sort_unstable();
+    let edges: SymmetricCSR2D<CSR2D<usize, usize, usize>> = UndiEdgesBuilder::default()
+        .expected_number_of_edges(edges.len())
+        .expected_shape(order)
+        .edges(edges.into_iter())
+        .build()
+        .unwrap();
+    UndiGraph::from((nodes, edges))
+}
+
+fn random_edges(seed: u64, order: usize, probability: f64) -> Vec<(usize, usize)> {
+    SparseMatrix::sparse_coordinates(&erdos_renyi_gnp(seed, order, probability))
+        .filter(|&(left, right)| left < right)
+        .collect()
+}
+
+/// Returns the number of node and edge insertions and deletions of the edit
+/// path induced by `mapping`, with free node substitutions.
+fn edit_path_cost(
+    left: &[(usize, usize)],
+    left_order: usize,
+    right: &[(usize, usize)],
+    right_order: usize,
+    mapping: &[Option<usize>],
+) -> usize {
+    let mapped = mapping.iter().flatten().count();
+    let preserved = left
+        .iter()
+        .filter(|&&(source, destination)| {
+            match (mapping[source], mapping[destination]) {
+                (Some(source), Some(destination)) => {
+                    right.contains(&(source.min(destination), source.max(destination)))
+                }
+                _ => false,
+            }
+        })
+        .count();
+    left_order + right_order - 2 * mapped + left.len() + right.len() - 2 * preserved
+}
+
+/// Returns the exact graph edit distance with unit costs, by enumerating
+/// every partial injective mapping.
+fn brute_force_graph_edit_distance(
+    left: &[(usize, usize)],
+    left_order: usize,
+    right: &[(usize, usize)],
+    right_order: usize,
+) -> usize {
+    fn search(
+        mapping: &mut Vec<Option<usize>>,
+        used: &mut Vec<bool>,
+        evaluate: &dyn Fn(&[Option<usize>]) -> usize,
+        left_order: usize,
+    ) -> usize {
+        if mapping.len() == left_order {
+            return evaluate(mapping);
+        }
+        mapping.push(None);
+        let mut best = search(mapping, used, evaluate, left_order);
+        mapping.pop();
+        for column in 0..used.len() {
+            if !used[column] {
+                used[column] = true;
+                mapping.push(Some(column));
+                best = best.min(search(mapping, used, evaluate, left_order));
+                mapping.pop();
+                used[column] = false;
+            }
+        }
+        best
+    }
+    let evaluate =
+        |mapping: &[Option<usize>]| edit_path_cost(left, left_order, right, right_order, mapping);
+    search(&mut Vec::new(), &mut vec![false; right_order], &evaluate, left_order)
+}
+
+#[test]
+#[allow(clippy::cast_precision_loss)]
+#[allow(clippy::float_cmp)]
+fn test_approximation_bounds_the_exact_distance() {
+    let mut number_of_exact = 0;
+    for (index, seed) in (1..=30).enumerate() {
+        let (left_order, right_order) = (3 + index % 3, 3 + (index / 3) % 3);
+        let left = random_edges(seed, left_order, 0.5);
+        let right = random_edges(seed + 100, right_order, 0.5);
+        let distance = undirected(left_order, &left)
+            .approximate_graph_edit_distance(&undirected(right_order, &right))
+            .unwrap();
+
+        assert_eq!(distance.mapping.len(), left_order);
+        let mut targets: Vec<usize> = distance.mapping.iter().flatten().copied().collect();
+        targets.sort_unstable();
+        targets.dedup();
+        assert_eq!(targets.len(), distance.mapping.iter().flatten().count());
+        let path_cost = edit_path_cost(&left, left_order, &right, right_order, &distance.mapping);
+        assert_eq!(distance.cost, path_cost as f64);
+
+        let exact = brute_force_graph_edit_distance(&left, left_order, &right, right_order);
+        assert!(path_cost >= exact);
+        if path_cost == exact {
+            number_of_exact += 1;
+        }
+    }
+    assert!(number_of_exact > 15);
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_isomorphic_graphs_are_at_distance_zero() {
+    let edges = random_edges(7, 12, 0.3);
+    let permuted: Vec<(usize, usize)> =
+        edges.iter().map(|&(left, right)| ((5 * left + 1) % 12, (5 * right + 1) % 12)).collect();
+    let distance =
+        undirected(12, &edges).approximate_graph_edit_distance(&undirected(12, &permuted)).unwrap();
+    assert_eq!(distance.cost, 0.0);
+    assert!(distance.mapping.iter().all(Option::is_some));
+
+    let empty = undirected(0, &[]);
+    let distance = empty.approximate_graph_edit_distance(&undirected(2, &[(0, 1)])).unwrap();
+    assert_eq!((distance.cost, distance.mapping), (3.0, Vec::new()));
+    let distance = undirected(2, &[]).approximate_graph_edit_distance(&empty).unwrap();
+    assert_eq!((distance.cost, distance.mapping), (2.0, vec![None, None]));
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_costs_and_node_substitutions_are_applied() {
+    let labels = ['C', 'C', 'O'];
+    let other_labels = ['O', 'C', 'C'];
+    let left = undirected(3, &[(0, 1), (1, 2)]);
+    let right = undirected(3, &[(0, 1), (1, 2)]);
+
+    // The paths carry their labels in opposite directions, so that only the
+    // reversed alignment substitutes nodes with equal labels.
+    let substitution = |left: usize, right: usize| {
+        if labels[left] == other_labels[right] { 0.0 } else { 5.0 }
+    };
+    let distance = left
+        .approximate_graph_edit_distance_with_costs(&right, GraphEditCosts::default(), substitution)
+        .unwrap();
+    assert_eq!(distance.cost, 0.0);
+    assert_eq!(distance.mapping, vec![Some(2), Some(1), Some(0)]);
+
+    // When substitutions are expensive, deleting and inserting is cheaper.
+    let costs = GraphEditCosts { node_insertion_deletion: 0.5, edge_insertion_deletion: 0.25 };
+    let distance = undirected(1, &[])
+        .approximate_graph_edit_distance_with_costs(&undirected(1, &[]), costs, |_, _| 2.0)
+        .unwrap();
+    assert_eq!((distance.cost, distance.mapping), (1.0, vec![None]));
+
+    for (costs, substitution, error) in [
+        (GraphEditCosts { node_insertion_deletion: -1.0, ..costs }, 0.0, LAPError::NegativeValues),
+        (
+            GraphEditCosts { edge_insertion_deletion: f64::NAN, ..costs },
+            0.0,
+            LAPError::NonFiniteValues,
+        ),
+        (costs, f64::INFINITY, LAPError::NonFiniteValues),
+        (costs, -0.5, LAPError::NegativeValues),
+    ] {
+        assert_eq!(
+            left.approximate_graph_edit_distance_with_costs(&right, costs, |_, _| substitution),
+            Err(error)
+        );
+    }
+}
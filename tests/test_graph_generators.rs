@@ -1,4 +1,4 @@
-//! Tests for undirected graph generator families.
+//! Tests for graph generator families.
 #![cfg(feature = "std")]
 
 use geometric_traits::{
@@ -21,6 +21,27 @@ fn same_graph(left: &UndirectedGraph, right: &UndirectedGraph) -> bool {
         && (0..left.order()).all(|row| left.sparse_row(row).eq(right.sparse_row(row)))
 }
 
+#[allow(clippy::cast_precision_loss)]
+fn average_clustering(g: &UndirectedGraph) -> f64 {
+    let total: f64 = (0..g.order())
+        .map(|v| {
+            let neighbors: Vec<usize> = g.sparse_row(v).collect();
+            let degree = neighbors.len();
+            if degree < 2 {
+                return 0.0;
+            }
+            let links = neighbors
+                .iter()
+                .enumerate()
+                .flat_map(|(i, &a)| neighbors[i + 1..].iter().map(move |&b| (a, b)))
+                .filter(|&(a, b)| g.sparse_row(a).any(|w| w == b))
+                .count();
+            2.0 * links as f64 / (degree * (degree - 1)) as f64
+        })
+        .sum();
+    total / g.order() as f64
+}
+
 fn windmill_matching_size(num_cliques: usize, clique_size: usize) -> usize {
     num_cliques * ((clique_size - 1) / 2) + usize::from(clique_size % 2 == 0)
 }
@@ -497,6 +518,28 @@ fn test_barabasi_albert_small() {
     assert_eq!(g.order(), 3);
 }
 
+#[test]
+fn test_barabasi_albert_edge_count_and_minimum_degree() {
+    // The initial clique on m + 1 vertices, then m edges per added vertex.
+    let (n, m) = (200, 3);
+    let g = barabasi_albert(7, n, m);
+    assert_eq!(edge_count(&g), m * (m + 1) / 2 + (n - m - 1) * m);
+    assert!((0..n).all(|v| g.sparse_row(v).count() >= m));
+    assert!(same_graph(&g, &barabasi_albert(7, n, m)));
+}
+
+#[test]
+fn test_barabasi_albert_heavy_tailed_degrees() {
+    // Preferential attachment grows hubs of degree about m * sqrt(n), far
+    // above the 2m average, which uniform random graphs never reach.
+    let (n, m) = (2000, 2);
+    for seed in 1..=5 {
+        let g = barabasi_albert(seed, n, m);
+        let max_degree = (0..n).map(|v| g.sparse_row(v).count()).max().unwrap();
+        assert!(max_degree >= 10 * 2 * m, "seed={seed} max degree {max_degree}");
+    }
+}
+
 #[test]
 fn test_watts_strogatz_no_rewiring() {
     // beta = 0 → pure ring lattice
@@ -515,6 +558,39 @@ fn test_watts_strogatz_deterministic() {
     );
 }
 
+#[test]
+fn test_watts_strogatz_ring_lattice_clustering() {
+    // The clustering of a ring lattice is 3(k - 2) / (4(k - 1)).
+    let g = watts_strogatz(42, 100, 6, 0.0);
+    assert!((0..100).all(|v| g.sparse_row(v).count() == 6));
+    assert!((average_clustering(&g) - 0.6).abs() < 1e-12);
+}
+
+#[test]
+fn test_watts_strogatz_rewiring_keeps_edges_and_lowers_clustering() {
+    let (n, k) = (500, 6);
+    let lattice = watts_strogatz(3, n, k, 0.0);
+    let small_world = watts_strogatz(3, n, k, 0.1);
+    let random = watts_strogatz(3, n, k, 1.0);
+    for g in [&small_world, &random] {
+        assert_eq!(edge_count(g), n * k / 2);
+    }
+    assert!(same_graph(&small_world, &watts_strogatz(3, n, k, 0.1)));
+    assert!(!same_graph(&small_world, &watts_strogatz(4, n, k, 0.1)));
+    assert!(average_clustering(&small_world) > 0.4);
+    assert!(average_clustering(&small_world) < average_clustering(&lattice));
+    assert!(average_clustering(&random) < 0.1);
+}
+
+#[test]
+fn test_watts_strogatz_large_sparse() {
+    // Memory grows with the number of edges, not with the square of `n`.
+    let n = 200_000;
+    let g = watts_strogatz(11, n, 4, 0.2);
+    assert_eq!(g.order(), n);
+    assert_eq!(edge_count(&g), 2 * n);
+}
+
 #[test]
 fn test_random_regular_graph_basic() {
     let g = random_regular_graph(42, 10, 4).expect("10-vertex 4-regular graph should exist");
@@ -558,6 +634,95 @@ fn test_configuration_model_basic() {
     assert!(edge_count(&g) <= 5);
 }
 
+#[test]
+fn test_configuration_multigraph_matches_degrees() {
+    let degrees = [3, 1, 4, 2, 2, 6, 2];
+    for seed in 1..=10 {
+        let edges = configuration_multigraph(seed, &degrees);
+        assert_eq!(edges.len(), 10);
+        assert!(edges.windows(2).all(|pair| pair[0] <= pair[1]));
+        let mut realized = [0; 7];
+        for &(u, v) in &edges {
+            assert!(u <= v);
+            realized[u] += 1;
+            realized[v] += 1;
+        }
+        assert_eq!(realized, degrees);
+
+        // Erasing the self-loops and multi-edges yields the configuration model.
+        let mut simple: Vec<(usize, usize)> = edges.into_iter().filter(|&(u, v)| u != v).collect();
+        simple.dedup();
+        let g = configuration_model(seed, &degrees);
+        assert_eq!(edge_count(&g), simple.len());
+        assert!(simple.iter().all(|&(u, v)| g.sparse_row(u).any(|w| w == v)));
+    }
+}
+
+#[test]
+fn test_simple_configuration_model_matches_degrees() {
+    let degrees = [3, 1, 4, 2, 2, 3, 1];
+    for seed in 1..=10 {
+        let g = simple_configuration_model(seed, &degrees).unwrap();
+        assert_eq!(g.order(), 7);
+        for (v, &degree) in degrees.iter().enumerate() {
+            assert_eq!(g.sparse_row(v).count(), degree);
+            assert!(g.sparse_row(v).all(|w| w != v));
+        }
+        assert!(same_graph(&g, &simple_configuration_model(seed, &degrees).unwrap()));
+    }
+    assert_eq!(simple_configuration_model(42, &[0, 0]).unwrap().order(), 2);
+}
+
+#[test]
+fn test_simple_configuration_model_errors() {
+    assert_eq!(
+        simple_configuration_model(42, &[1, 2]),
+        Err(ConfigurationModelError::OddDegreeSum { sum: 3 })
+    );
+    // Degrees at least n, and sequences failing the Erdős–Gallai inequalities.
+    for degrees in [&[2, 2][..], &[3, 3, 1, 1], &[3, 3, 3, 1], &[4, 4, 1, 1, 1, 1]] {
+        assert_eq!(
+            simple_configuration_model(42, degrees),
+            Err(ConfigurationModelError::NotGraphical),
+            "{degrees:?}"
+        );
+    }
+    // Graphical sequences are accepted: a star, a cycle and a complete graph.
+    for degrees in [&[4, 1, 1, 1, 1][..], &[2, 2, 2, 2, 2], &[3, 3, 3, 3]] {
+        assert!(simple_configuration_model(42, degrees).is_ok(), "{degrees:?}");
+    }
+}
+
+#[test]
+fn test_simple_configuration_model_graphical_exhaustive() {
+    // Compare against the degree sequences of every graph on 5 vertices.
+    let pairs: Vec<(usize, usize)> =
+        (0..5).flat_map(|u| ((u + 1)..5).map(move |v| (u, v))).collect();
+    let mut realizable = std::collections::HashSet::new();
+    for mask in 0u32..(1 << pairs.len()) {
+        let mut degrees = vec![0usize; 5];
+        for (bit, &(u, v)) in pairs.iter().enumerate() {
+            if mask & (1 << bit) != 0 {
+                degrees[u] += 1;
+                degrees[v] += 1;
+            }
+        }
+        realizable.insert(degrees);
+    }
+    for code in 0..5usize.pow(5) {
+        let degrees: Vec<usize> = (0..5).map(|i| code / 5usize.pow(i) % 5).collect();
+        if degrees.iter().sum::<usize>() % 2 != 0 {
+            continue;
+        }
+        let result = simple_configuration_model(42, &degrees);
+        if realizable.contains(&degrees) {
+            assert!(!matches!(result, Err(ConfigurationModelError::NotGraphical)), "{degrees:?}");
+        } else {
+            assert_eq!(result, Err(ConfigurationModelError::NotGraphical), "{degrees:?}");
+        }
+    }
+}
+
 #[test]
 fn test_chung_lu_deterministic() {
     let g1 = chung_lu(42, &[3.0, 3.0, 3.0, 3.0, 3.0]);
@@ -568,6 +733,22 @@ fn test_chung_lu_deterministic() {
     );
 }
 
+#[test]
+fn test_random_bipartite_graph() {
+    let (m, n) = (30, 50);
+    let g = random_bipartite_graph(42, m, n, 0.1);
+    assert_eq!(g.order(), m + n);
+    // 150 expected edges out of 1500 pairs.
+    assert!((100..=200).contains(&edge_count(&g)), "{}", edge_count(&g));
+    assert!((0..m).all(|u| g.sparse_row(u).all(|v| v >= m)));
+    assert!((m..m + n).all(|v| g.sparse_row(v).all(|u| u < m)));
+    assert!(same_graph(&g, &random_bipartite_graph(42, m, n, 0.1)));
+
+    assert_eq!(edge_count(&random_bipartite_graph(42, m, n, 0.0)), 0);
+    assert!(same_graph(&random_bipartite_graph(42, m, n, 1.0), &complete_bipartite_graph(m, n)));
+    assert_eq!(random_bipartite_graph(42, 0, 4, 0.5).order(), 4);
+}
+
 #[test]
 fn test_random_geometric_graph_deterministic() {
     let g1 = random_geometric_graph(42, 20, 0.3);
@@ -585,6 +766,40 @@ fn test_random_geometric_graph_zero_radius() {
     assert_eq!(edge_count(&g), 0);
 }
 
+#[test]
+fn test_random_geometric_graph_with_positions_matches_distances() {
+    for (n, radius) in [(300, 0.05), (200, 0.3), (50, 1.5)] {
+        let (g, positions) = random_geometric_graph_with_positions(7, n, radius);
+        assert_eq!(positions.len(), n);
+        assert!(
+            positions.iter().all(|&(x, y)| (0.0..=1.0).contains(&x) && (0.0..=1.0).contains(&y))
+        );
+        assert!(same_graph(&g, &random_geometric_graph(7, n, radius)));
+        for (i, &(xi, yi)) in positions.iter().enumerate() {
+            let expected: Vec<usize> = positions
+                .iter()
+                .enumerate()
+                .filter(|&(j, &(xj, yj))| {
+                    j != i && (xi - xj) * (xi - xj) + (yi - yj) * (yi - yj) <= radius * radius
+                })
+                .map(|(j, _)| j)
+                .collect();
+            assert_eq!(g.sparse_row(i).collect::<Vec<_>>(), expected, "n={n}, r={radius}, i={i}");
+        }
+    }
+}
+
+#[test]
+fn test_random_geometric_graph_large_sparse() {
+    let n = 200_000;
+    let (g, positions) = random_geometric_graph_with_positions(11, n, 0.002);
+    assert_eq!(g.order(), n);
+    assert_eq!(positions.len(), n);
+    // Expected average degree is about n * pi * r^2 = 2.5.
+    let edges = edge_count(&g);
+    assert!((200_000..=300_000).contains(&edges), "edges={edges}");
+}
+
 // ============================================================================
 // Cross-checks: matching algorithm agrees across generators
 // ============================================================================
@@ -0,0 +1,118 @@
+//! Tests for the isomorphism check of graphs.
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::random_dag;
+use geometric_traits::{
+    impls::{CSR2D, SortedVec, SquareCSR2D, SymmetricCSR2D},
+    prelude::*,
+    traits::{
+        EdgesBuilder, GraphIsomorphism, VocabularyBuilder,
+        algorithms::randomized_graphs::{cycle_graph, erdos_renyi_gnp},
+    },
+};
+
+fn nodes(order: usize) -> SortedVec<usize> {
+    GenericVocabularyBuilder::default()
+        .expected_number_of_symbols(order)
+        .symbols((0..order).enumerate())
+        .build()
+        .unwrap()
+}
+
+fn undirected(order: usize, edges: &[(usize, usize)]) -> UndiGraph<usize> {
+    let mut edges: Vec<(usize, usize)> =
+        edges.iter().map(|&(left, right)| (left.min(right), left.max(right))).collect();
+    edges.sort_unstable();
+    let edges: SymmetricCSR2D<CSR2D<usize, usize, usize>> = UndiEdgesBuilder::default()
+        .expected_number_of_edges(edges.len())
+        .expected_shape(order)
+        .edges(edges.into_iter())
+        .build()
+        .unwrap();
+    UndiGraph::from((nodes(order), edges))
+}
+
+fn directed(order: usize, edges: &[(usize, usize)]) -> DiGraph<usize> {
+    let mut edges = edges.to_vec();
+    edges.sort_unstable();
+    let edges: SquareCSR2D<CSR2D<usize, usize, usize>> = DiEdgesBuilder::default()
+        .expected_number_of_edges(edges.len())
+        .expected_shape(order)
+        .edges(edges.into_iter())
+        .build()
+        .unwrap();
+    DiGraph::from((nodes(order), edges))
+}
+
+/// Relabels the node `index` of a graph of order 23 as `7 * index + 3`.
+fn permute(index: usize) -> usize {
+    (7 * index + 3) % 23
+}
+
+#[test]
+fn test_permuted_graphs_are_isomorphic() {
+    for seed in 1..=10 {
+        let matrix = erdos_renyi_gnp(seed, 23, 0.2);
+        let edges: Vec<(usize, usize)> = SparseMatrix::sparse_coordinates(&matrix)
+            .filter(|&(left, right)| left < right)
+            .collect();
+        let graph = undirected(23, &edges);
+        let permuted: Vec<(usize, usize)> =
+            edges.iter().map(|&(left, right)| (permute(left), permute(right))).collect();
+        assert!(graph.is_isomorphic(&undirected(23, &permuted)));
+
+        // Removing an edge breaks the isomorphism.
+        assert!(!graph.is_isomorphic(&undirected(23, &permuted[1..])));
+
+        let dag = random_dag(seed, 23, 0.2);
+        let arcs: Vec<(usize, usize)> = SparseMatrix::sparse_coordinates(&dag).collect();
+        let permuted: Vec<(usize, usize)> = arcs
+            .iter()
+            .map(|&(source, destination)| (permute(source), permute(destination)))
+            .collect();
+        let digraph = directed(23, &arcs);
+        assert!(digraph.is_isomorphic(&directed(23, &permuted)));
+    }
+}
+
+#[test]
+fn test_graphs_with_equal_invariants_are_told_apart() {
+    // Both graphs are 2-regular without triangles.
+    let eight_cycle = undirected(8, &(0..8).map(|node| (node, (node + 1) % 8)).collect::<Vec<_>>());
+    let two_squares =
+        undirected(8, &[(0, 1), (1, 2), (2, 3), (0, 3), (4, 5), (5, 6), (6, 7), (4, 7)]);
+    assert!(!eight_cycle.is_isomorphic(&two_squares));
+    assert!(eight_cycle.is_isomorphic(&eight_cycle));
+
+    // Both graphs are 3-regular, but only the prism has triangles.
+    let prism =
+        undirected(6, &[(0, 1), (1, 2), (0, 2), (3, 4), (4, 5), (3, 5), (0, 3), (1, 4), (2, 5)]);
+    let utility =
+        undirected(6, &[(0, 3), (0, 4), (0, 5), (1, 3), (1, 4), (1, 5), (2, 3), (2, 4), (2, 5)]);
+    assert!(!prism.is_isomorphic(&utility));
+
+    let hexagon = cycle_graph(6);
+    let hexagon = UndiGraph::from((nodes(6), hexagon));
+    assert!(!hexagon.is_isomorphic(&prism));
+    assert!(undirected(0, &[]).is_isomorphic(&undirected(0, &[])));
+    assert!(!undirected(2, &[]).is_isomorphic(&undirected(3, &[])));
+}
+
+#[test]
+fn test_directed_isomorphism_respects_orientation() {
+    let out_star = directed(4, &[(0, 1), (0, 2), (0, 3)]);
+    let in_star = directed(4, &[(1, 0), (2, 0), (3, 0)]);
+    assert!(!out_star.is_isomorphic(&in_star));
+    assert!(out_star.is_isomorphic(&directed(4, &[(2, 0), (2, 1), (2, 3)])));
+
+    // A directed triangle and a transitive triangle share their size only.
+    let cyclic = directed(3, &[(0, 1), (1, 2), (2, 0)]);
+    let transitive = directed(3, &[(0, 1), (1, 2), (0, 2)]);
+    assert!(!cyclic.is_isomorphic(&transitive));
+
+    let with_loop = directed(3, &[(0, 0), (0, 1), (1, 2)]);
+    assert!(with_loop.is_isomorphic(&directed(3, &[(2, 2), (2, 0), (0, 1)])));
+    assert!(!with_loop.is_isomorphic(&directed(3, &[(1, 1), (0, 1), (1, 2)])));
+}
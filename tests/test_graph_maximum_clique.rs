@@ -0,0 +1,115 @@
+//! Tests for the maximum clique search on undirected graphs.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::{CSR2D, SortedVec, SymmetricCSR2D},
+    prelude::*,
+    traits::{GraphMaximumClique, VocabularyBuilder, algorithms::randomized_graphs::XorShift64},
+};
+
+/// Helper to build an undirected graph.
+fn build_undi_graph(node_count: usize, edges: Vec<(usize, usize)>) -> UndiGraph<usize> {
+    let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+        .expected_number_of_symbols(node_count)
+        .symbols((0..node_count).enumerate())
+        .build()
+        .unwrap();
+    let edge_count = edges.len();
+    let edges: SymmetricCSR2D<CSR2D<usize, usize, usize>> = UndiEdgesBuilder::default()
+        .expected_number_of_edges(edge_count)
+        .expected_shape(node_count)
+        .edges(edges.into_iter())
+        .build()
+        .unwrap();
+    UndiGraph::from((nodes, edges))
+}
+
+/// Returns random distinct edges `(u, v)` with `u < v`, each pair being
+/// drawn with the given probability in thousandths.
+fn random_edges(rng: &mut XorShift64, order: usize, per_mille: u64) -> Vec<(usize, usize)> {
+    let mut edges = Vec::new();
+    for u in 0..order {
+        for v in (u + 1)..order {
+            if rng.next().unwrap() % 1000 < per_mille {
+                edges.push((u, v));
+            }
+        }
+    }
+    edges
+}
+
+/// Asserts that the nodes are sorted and pairwise adjacent.
+fn assert_clique(graph: &UndiGraph<usize>, clique: &[usize]) {
+    assert!(clique.windows(2).all(|pair| pair[0] < pair[1]));
+    for (position, &u) in clique.iter().enumerate() {
+        for &v in &clique[position + 1..] {
+            assert!(
+                graph.neighbors(u).any(|neighbor| neighbor == v),
+                "{u} and {v} are not adjacent"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_graph_maximum_clique_against_brute_force() {
+    let order = 12;
+    for seed in 0..60 {
+        let mut rng = XorShift64::from(XorShift64::normalize_seed(seed));
+        let edges = random_edges(&mut rng, order, 100 + 10 * seed);
+        let graph = build_undi_graph(order, edges.clone());
+        let mut adjacency = vec![0_u32; order];
+        for &(u, v) in &edges {
+            adjacency[u] |= 1 << v;
+            adjacency[v] |= 1 << u;
+        }
+        let clique_number = (1..1_u32 << order)
+            .filter(|&mask| {
+                (0..order)
+                    .filter(|&node| mask & (1 << node) != 0)
+                    .all(|node| mask & !(1 << node) & !adjacency[node] == 0)
+            })
+            .map(u32::count_ones)
+            .max()
+            .unwrap();
+
+        let clique = graph.maximum_clique_of_graph();
+        assert_clique(&graph, &clique);
+        assert_eq!(clique.len(), usize::try_from(clique_number).unwrap(), "seed {seed}");
+        assert_eq!(graph.clique_number(), clique.len());
+    }
+}
+
+#[test]
+fn test_graph_maximum_clique_with_planted_clique() {
+    let order = 2000;
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(42));
+    let mut edges = random_edges(&mut rng, order, 5);
+    let planted: Vec<usize> = (0..12).map(|index| 7 + 163 * index).collect();
+    for (position, &u) in planted.iter().enumerate() {
+        for &v in &planted[position + 1..] {
+            edges.push((u, v));
+        }
+    }
+    edges.sort_unstable();
+    edges.dedup();
+    let graph = build_undi_graph(order, edges);
+
+    let clique = graph.maximum_clique_of_graph();
+    assert_clique(&graph, &clique);
+    assert_eq!(clique, planted);
+}
+
+#[test]
+fn test_graph_maximum_clique_of_small_graphs() {
+    let empty = build_undi_graph(0, vec![]);
+    assert!(empty.maximum_clique_of_graph().is_empty());
+    assert_eq!(empty.clique_number(), 0);
+
+    // Self-loops do not make a node adjacent to itself in a clique.
+    let loops = build_undi_graph(3, vec![(0, 0), (1, 1), (2, 2)]);
+    assert_eq!(loops.clique_number(), 1);
+
+    let triangle_with_tail = build_undi_graph(5, vec![(0, 1), (1, 2), (1, 3), (2, 3), (3, 4)]);
+    assert_eq!(triangle_with_tail.maximum_clique_of_graph(), vec![1, 2, 3]);
+}
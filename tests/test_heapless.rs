@@ -0,0 +1,136 @@
+//! Tests for the fixed-capacity matrices and searches of the `heapless`
+//! feature.
+#![cfg(all(feature = "std", feature = "heapless"))]
+
+use geometric_traits::{
+    errors::RankSelectError,
+    impls::MutabilityError,
+    prelude::*,
+    traits::algorithms::randomized_graphs::{
+        SparseCostMatrixConfig, erdos_renyi_gnp, random_sparse_cost_matrix,
+    },
+};
+
+type TestHeaplessCSR2D = HeaplessCSR2D<usize, usize, usize, 16, 256>;
+type TestHeaplessValuedCSR2D = HeaplessValuedCSR2D<usize, usize, usize, f64, 20, 400>;
+
+#[test]
+fn test_heapless_bfs_matches_pairwise_bfs() {
+    for seed in 1..=10 {
+        let graph = erdos_renyi_gnp(seed, 16, 0.15);
+        let mut heapless = TestHeaplessCSR2D::with_sparse_shape((16, 16));
+        for coordinates in SparseMatrix::sparse_coordinates(&graph) {
+            MatrixMut::add(&mut heapless, coordinates).unwrap();
+        }
+        assert_eq!(heapless.validate(), Ok(()));
+        assert_eq!(heapless.number_of_defined_values(), graph.number_of_defined_values());
+        assert_eq!(heapless.number_of_non_empty_rows(), graph.number_of_non_empty_rows());
+        for row in 0..16 {
+            assert!(heapless.sparse_row(row).eq(graph.sparse_row(row)), "seed {seed}");
+        }
+
+        let expected = graph.pairwise_bfs();
+        for source in 0..16 {
+            let distances = heapless.heapless_bfs::<16>(source).unwrap();
+            for (destination, &distance) in distances.iter().enumerate() {
+                assert_eq!(distance, expected.value((source, destination)), "seed {seed}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_heapless_dijkstra_matches_pairwise_dijkstra() {
+    for seed in 1..=10 {
+        let costs = random_sparse_cost_matrix(seed, &SparseCostMatrixConfig::new(20, 20, 0.2));
+        let mut heapless = TestHeaplessValuedCSR2D::with_sparse_shape((20, 20));
+        for ((row, column), value) in
+            SparseMatrix::sparse_coordinates(&costs).zip(costs.sparse_values())
+        {
+            MatrixMut::add(&mut heapless, (row, column, value)).unwrap();
+        }
+        assert_eq!(heapless.validate(), Ok(()));
+        for row in 0..20 {
+            assert!(heapless.sparse_row_values(row).eq(costs.sparse_row_values(row)));
+        }
+
+        let expected = costs.pairwise_dijkstra().unwrap();
+        for source in 0..20 {
+            let distances = heapless.heapless_dijkstra::<20>(source).unwrap();
+            for (destination, &distance) in distances.iter().enumerate() {
+                assert_eq!(distance, expected.value((source, destination)), "seed {seed}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_heapless_csr2d_reports_exhausted_capacities() {
+    let mut matrix: HeaplessCSR2D<u8, u8, u8, 2, 3> = SparseMatrixMut::with_sparse_shape((4, 4));
+    MatrixMut::add(&mut matrix, (0, 0)).unwrap();
+    MatrixMut::add(&mut matrix, (0, 2)).unwrap();
+    assert_eq!(
+        MatrixMut::add(&mut matrix, (0, 1)),
+        Err(MutabilityError::UnorderedCoordinate((0, 1)))
+    );
+    assert_eq!(
+        MatrixMut::add(&mut matrix, (2, 0)),
+        Err(MutabilityError::CapacityExceeded("row ends"))
+    );
+    MatrixMut::add(&mut matrix, (1, 3)).unwrap();
+    assert_eq!(
+        MatrixMut::add(&mut matrix, (1, 4)),
+        Err(MutabilityError::CapacityExceeded("column indices"))
+    );
+
+    // The failed insertions leave the matrix untouched.
+    assert_eq!(matrix.validate(), Ok(()));
+    assert_eq!(matrix.number_of_defined_values(), 3);
+    assert_eq!(matrix.shape(), vec![4, 4]);
+    assert_eq!(matrix.empty_row_indices().collect::<Vec<_>>(), vec![2, 3]);
+    assert_eq!(
+        SparseMatrix::sparse_coordinates(&matrix).collect::<Vec<_>>(),
+        vec![(0, 0), (0, 2), (1, 3)]
+    );
+    assert_eq!(matrix.rank_row(4), 3);
+    assert_eq!(matrix.select_row(2), 1);
+    assert_eq!(matrix.try_rank(1, 3), Ok(2));
+    assert_eq!(matrix.try_rank(3, 3), Err(RankSelectError::MissingEntry { row: 3, column: 3 }));
+}
+
+#[test]
+fn test_heapless_searches_reject_invalid_inputs() {
+    let mut graph: HeaplessValuedCSR2D<u8, u8, u8, f64, 3, 3> =
+        SparseMatrixMut::with_sparse_shape((3, 3));
+    MatrixMut::add(&mut graph, (0, 1, 1.0)).unwrap();
+    MatrixMut::add(&mut graph, (1, 2, -1.0)).unwrap();
+
+    assert_eq!(
+        graph.heapless_bfs::<2>(0),
+        Err(HeaplessSearchError::CapacityExceeded { number_of_nodes: 3, capacity: 2 })
+    );
+    assert_eq!(
+        graph.heapless_bfs::<3>(3),
+        Err(HeaplessSearchError::SourceOutOfBounds { source_id: 3, number_of_nodes: 3 })
+    );
+    assert_eq!(graph.heapless_bfs::<3>(0).unwrap().as_slice(), &[Some(0), Some(1), Some(2)]);
+    assert_eq!(
+        graph.heapless_dijkstra::<3>(0),
+        Err(HeaplessSearchError::NegativeWeight { source_id: 1, destination_id: 2 })
+    );
+    assert_eq!(graph.heapless_dijkstra::<3>(2).unwrap().as_slice(), &[None, None, Some(0.0)]);
+
+    MatrixMut::increase_shape(&mut graph, (3, 4)).unwrap();
+    assert_eq!(
+        graph.heapless_bfs::<4>(0),
+        Err(HeaplessSearchError::NonSquareMatrix { rows: 3, columns: 4 })
+    );
+}
+
+#[test]
+fn test_default_heapless_valued_csr2d_is_empty() {
+    let matrix = TestHeaplessValuedCSR2D::default();
+    assert_eq!(matrix.validate(), Ok(()));
+    assert!(matrix.is_empty());
+    assert_eq!(matrix.max_sparse_value(), None);
+}
@@ -0,0 +1,228 @@
+//! Tests for the `HierarchicalClustering` trait and its dendrogram cuts.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::{SymmetricCSR2D, ValuedCSR2D},
+    prelude::*,
+    traits::{
+        DendrogramCut, HierarchicalClustering, HierarchicalClusteringError, Linkage,
+        algorithms::randomized_graphs::XorShift64,
+    },
+};
+
+type Distances = SymmetricCSR2D<ValuedCSR2D<usize, usize, usize, f64>>;
+
+fn distances(order: usize, entries: Vec<(usize, usize, f64)>) -> Distances {
+    SymmetricCSR2D::from_sorted_upper_triangular_entries(order, entries).unwrap()
+}
+
+/// Random sparse distances with distinct values, so that the merge order is
+/// unambiguous.
+#[allow(clippy::cast_precision_loss)]
+fn random_distances(seed: u64, order: usize, density: u64) -> Distances {
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(seed));
+    let mut entries = Vec::new();
+    for source in 0..order {
+        for destination in source + 1..order {
+            if rng.next().unwrap() % 100 < density {
+                let noise = (rng.next().unwrap() % 1_000_000) as f64 / 1_000_000.0;
+                entries.push((source, destination, 1.0 + noise + entries.len() as f64 * 1e-9));
+            }
+        }
+    }
+    distances(order, entries)
+}
+
+/// Naive agglomerative clustering recomputing the linkage distances between
+/// all clusters from the item distances, returning the merge heights and the
+/// partitions after every merge.
+#[allow(clippy::cast_precision_loss)]
+fn naive_clustering(matrix: &Distances, linkage: Linkage) -> (Vec<f64>, Vec<Vec<usize>>) {
+    let order = matrix.order();
+    let distance = |left: usize, right: usize| {
+        matrix
+            .sparse_row(left)
+            .zip(matrix.sparse_row_values(left))
+            .find(|&(column, _)| column == right)
+            .map(|(_, distance)| distance)
+    };
+    let mut clusters: Vec<Vec<usize>> = (0..order).map(|item| vec![item]).collect();
+    let mut heights = Vec::new();
+    let mut partitions = Vec::new();
+    loop {
+        let mut best: Option<(f64, usize, usize)> = None;
+        for left in 0..clusters.len() {
+            for right in left + 1..clusters.len() {
+                let pairs: Vec<Option<f64>> = clusters[left]
+                    .iter()
+                    .flat_map(|&a| clusters[right].iter().map(move |&b| distance(a, b)))
+                    .collect();
+                let known: Vec<f64> = pairs.iter().flatten().copied().collect();
+                let linkage_distance = match linkage {
+                    Linkage::Single => known.iter().copied().reduce(f64::min),
+                    Linkage::Complete if known.len() == pairs.len() => {
+                        known.iter().copied().reduce(f64::max)
+                    }
+                    Linkage::Average if known.len() == pairs.len() => {
+                        Some(known.iter().sum::<f64>() / known.len() as f64)
+                    }
+                    _ => None,
+                };
+                if let Some(linkage_distance) = linkage_distance {
+                    if best.is_none_or(|(best, _, _)| linkage_distance < best) {
+                        best = Some((linkage_distance, left, right));
+                    }
+                }
+            }
+        }
+        let Some((height, left, right)) = best else {
+            break;
+        };
+        let right_items = clusters.remove(right);
+        clusters[left].extend(right_items);
+        heights.push(height);
+        let mut partition = vec![0; order];
+        let mut representatives: Vec<usize> =
+            clusters.iter().map(|cluster| *cluster.iter().min().unwrap()).collect();
+        representatives.sort_unstable();
+        for cluster in &clusters {
+            let label = representatives.binary_search(cluster.iter().min().unwrap()).unwrap();
+            for &item in cluster {
+                partition[item] = label;
+            }
+        }
+        partitions.push(partition);
+    }
+    (heights, partitions)
+}
+
+#[test]
+fn test_hierarchical_clustering_matches_naive_clustering() {
+    for seed in 1..=12 {
+        let matrix = random_distances(seed, 14, if seed % 2 == 0 { 100 } else { 35 });
+        for linkage in [Linkage::Single, Linkage::Complete, Linkage::Average] {
+            let dendrogram = matrix.hierarchical_clustering(linkage).unwrap();
+            let (heights, partitions) = naive_clustering(&matrix, linkage);
+            assert_eq!(dendrogram.number_of_items(), 14);
+            assert_eq!(dendrogram.merges().len(), heights.len(), "{linkage:?} with seed {seed}");
+            for (index, (merge, height)) in dendrogram.merges().iter().zip(&heights).enumerate() {
+                assert!((merge.height() - height).abs() < 1e-9, "{linkage:?} with seed {seed}");
+                assert!(merge.left() < merge.right());
+                assert!(merge.right() < 14 + index);
+                assert_eq!(
+                    dendrogram.cut::<usize>(DendrogramCut::Clusters(13 - index)).unwrap(),
+                    partitions[index]
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_dendrogram_merges_and_sizes() {
+    let matrix = distances(
+        5,
+        vec![
+            (0, 1, 1.0),
+            (0, 2, 4.0),
+            (0, 3, 8.0),
+            (1, 2, 2.0),
+            (1, 3, 6.0),
+            (2, 3, 3.0),
+            (3, 4, 10.0),
+        ],
+    );
+    let merges = |linkage| {
+        matrix
+            .hierarchical_clustering(linkage)
+            .unwrap()
+            .merges()
+            .iter()
+            .map(|merge| (merge.left(), merge.right(), merge.height(), merge.size()))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        merges(Linkage::Single),
+        vec![(0, 1, 1.0, 2), (2, 5, 2.0, 3), (3, 6, 3.0, 4), (4, 7, 10.0, 5)]
+    );
+    assert_eq!(merges(Linkage::Complete), vec![(0, 1, 1.0, 2), (2, 3, 3.0, 2), (5, 6, 8.0, 4)]);
+    assert_eq!(merges(Linkage::Average), vec![(0, 1, 1.0, 2), (2, 3, 3.0, 2), (5, 6, 5.0, 4)]);
+}
+
+#[test]
+fn test_dendrogram_cuts() {
+    let matrix = distances(6, vec![(0, 1, 1.0), (1, 2, 2.0), (3, 4, 1.5), (4, 5, 4.0)]);
+    let dendrogram = matrix.hierarchical_clustering(Linkage::Single).unwrap();
+    let cut = |cut| dendrogram.cut::<usize>(cut).unwrap();
+
+    assert_eq!(cut(DendrogramCut::Height(0.5)), vec![0, 1, 2, 3, 4, 5]);
+    assert_eq!(cut(DendrogramCut::Height(1.0)), vec![0, 0, 1, 2, 3, 4]);
+    assert_eq!(cut(DendrogramCut::Height(2.0)), vec![0, 0, 0, 1, 1, 2]);
+    assert_eq!(cut(DendrogramCut::Height(f64::INFINITY)), vec![0, 0, 0, 1, 1, 1]);
+    assert_eq!(cut(DendrogramCut::Height(f64::NAN)), vec![0, 1, 2, 3, 4, 5]);
+
+    assert_eq!(cut(DendrogramCut::Clusters(6)), vec![0, 1, 2, 3, 4, 5]);
+    assert_eq!(cut(DendrogramCut::Clusters(10)), vec![0, 1, 2, 3, 4, 5]);
+    assert_eq!(cut(DendrogramCut::Clusters(3)), vec![0, 0, 0, 1, 1, 2]);
+    // The two components are never merged.
+    assert_eq!(cut(DendrogramCut::Clusters(1)), vec![0, 0, 0, 1, 1, 1]);
+    assert_eq!(cut(DendrogramCut::Clusters(0)), vec![0, 0, 0, 1, 1, 1]);
+}
+
+#[test]
+fn test_dendrogram_cut_marker_overflow() {
+    let matrix = distances(300, Vec::new());
+    let dendrogram = matrix.hierarchical_clustering(Linkage::Average).unwrap();
+    assert!(dendrogram.merges().is_empty());
+    assert_eq!(
+        dendrogram.cut::<u8>(DendrogramCut::Clusters(1)),
+        Err(HierarchicalClusteringError::TooManyClusters)
+    );
+    assert_eq!(dendrogram.cut::<u16>(DendrogramCut::Clusters(1)).unwrap()[299], 299);
+}
+
+#[test]
+fn test_hierarchical_clustering_degenerate_inputs() {
+    let empty = distances(0, Vec::new());
+    let dendrogram = empty.hierarchical_clustering(Linkage::Single).unwrap();
+    assert_eq!(dendrogram.number_of_items(), 0);
+    assert!(dendrogram.cut::<usize>(DendrogramCut::Clusters(1)).unwrap().is_empty());
+
+    // Diagonal entries are ignored and null distances are merged first.
+    let matrix = distances(3, vec![(0, 0, 7.0), (0, 2, 0.0), (1, 2, 1.0)]);
+    let dendrogram = matrix.hierarchical_clustering(Linkage::Complete).unwrap();
+    assert!(dendrogram.merges()[0].height().abs() < f64::EPSILON);
+    assert_eq!(dendrogram.cut::<usize>(DendrogramCut::Height(0.0)).unwrap(), vec![0, 1, 0]);
+    assert_eq!(dendrogram.merges().len(), 1);
+}
+
+#[test]
+fn test_hierarchical_clustering_errors() {
+    let mut rectangular: ValuedCSR2D<usize, usize, usize, f64> =
+        SparseMatrixMut::with_sparse_shaped_capacity((2, 3), 1);
+    MatrixMut::add(&mut rectangular, (0, 1, 1.0)).unwrap();
+    assert_eq!(
+        rectangular.hierarchical_clustering(Linkage::Single),
+        Err(HierarchicalClusteringError::NonSquareMatrix { rows: 2, columns: 3 })
+    );
+
+    let mut asymmetric: ValuedCSR2D<usize, usize, usize, f64> =
+        SparseMatrixMut::with_sparse_shaped_capacity((2, 2), 1);
+    MatrixMut::add(&mut asymmetric, (0, 1, 1.0)).unwrap();
+    assert_eq!(
+        asymmetric.hierarchical_clustering(Linkage::Single),
+        Err(HierarchicalClusteringError::NonSymmetricDistance { source_id: 0, destination_id: 1 })
+    );
+
+    let negative = distances(2, vec![(0, 1, -1.0)]);
+    assert_eq!(
+        negative.hierarchical_clustering(Linkage::Average),
+        Err(HierarchicalClusteringError::NegativeDistance { source_id: 0, destination_id: 1 })
+    );
+
+    let infinite = distances(2, vec![(0, 1, f64::INFINITY)]);
+    assert_eq!(
+        infinite.hierarchical_clustering(Linkage::Average),
+        Err(HierarchicalClusteringError::NonFiniteDistance { source_id: 0, destination_id: 1 })
+    );
+}
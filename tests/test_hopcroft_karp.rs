@@ -4,7 +4,10 @@
 use geometric_traits::{
     impls::CSR2D,
     prelude::*,
-    traits::{EdgesBuilder, HopcroftKarp},
+    traits::{
+        EdgesBuilder, HallViolator, HopcroftKarp,
+        algorithms::randomized_graphs::{SparseCostMatrixConfig, random_sparse_cost_matrix},
+    },
 };
 
 #[test]
@@ -121,3 +124,53 @@ fn test_hopcroft_karp_rectangular_tall() {
     // Can only match 2 rows (limited by columns)
     assert_eq!(assignment.len(), 2);
 }
+
+#[test]
+fn test_hall_violator_of_rectangular_tall() {
+    // Rows 0, 1, 2 and 3 share the two columns.
+    let edges: CSR2D<usize, usize, usize> =
+        GenericEdgesBuilder::<_, CSR2D<usize, usize, usize>>::default()
+            .expected_number_of_edges(5)
+            .expected_shape((4, 2))
+            .edges(vec![(0, 0), (1, 0), (1, 1), (2, 1), (3, 0)].into_iter())
+            .build()
+            .unwrap();
+
+    let violator = edges.hall_violator().unwrap().unwrap();
+    assert_eq!(violator.neighborhood, vec![0, 1]);
+    assert_eq!(violator.rows.len(), 3);
+    assert!(violator.rows.contains(&2));
+
+    let wide: CSR2D<usize, usize, usize> =
+        GenericEdgesBuilder::<_, CSR2D<usize, usize, usize>>::default()
+            .expected_number_of_edges(2)
+            .expected_shape((2, 4))
+            .edges(vec![(0, 1), (1, 3)].into_iter())
+            .build()
+            .unwrap();
+    assert_eq!(wide.hall_violator(), Ok(None));
+}
+
+#[test]
+fn test_hall_violator_exists_iff_rows_cannot_all_be_assigned() {
+    let mut number_of_violators = 0;
+    for seed in 1..=60 {
+        let matrix = random_sparse_cost_matrix(seed, &SparseCostMatrixConfig::new(10, 10, 0.12));
+        let assignment = matrix.hopcroft_karp().unwrap();
+        let Some(HallViolator { rows, neighborhood }) = matrix.hall_violator().unwrap() else {
+            assert_eq!(assignment.len(), matrix.number_of_rows());
+            continue;
+        };
+        number_of_violators += 1;
+        assert!(assignment.len() < matrix.number_of_rows());
+        assert_eq!(neighborhood.len() + 1, rows.len());
+
+        let mut adjacent: Vec<usize> =
+            rows.iter().flat_map(|&row| matrix.sparse_row(row)).collect();
+        adjacent.sort_unstable();
+        adjacent.dedup();
+        assert_eq!(adjacent, neighborhood);
+        assert!(matrix.lapmod(1e6).is_err());
+    }
+    assert!(number_of_violators > 0);
+}
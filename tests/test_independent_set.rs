@@ -0,0 +1,156 @@
+//! Tests for the independent set heuristics on undirected graphs.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::{CSR2D, SortedVec, SymmetricCSR2D},
+    prelude::*,
+    traits::{IndependentSet, VocabularyBuilder, algorithms::randomized_graphs::XorShift64},
+};
+
+/// Helper to build an undirected graph.
+fn build_undi_graph(node_count: usize, edges: Vec<(usize, usize)>) -> UndiGraph<usize> {
+    let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+        .expected_number_of_symbols(node_count)
+        .symbols((0..node_count).enumerate())
+        .build()
+        .unwrap();
+    let edge_count = edges.len();
+    let edges: SymmetricCSR2D<CSR2D<usize, usize, usize>> = UndiEdgesBuilder::default()
+        .expected_number_of_edges(edge_count)
+        .expected_shape(node_count)
+        .edges(edges.into_iter())
+        .build()
+        .unwrap();
+    UndiGraph::from((nodes, edges))
+}
+
+/// Returns random distinct edges `(u, v)` with `u < v`, each pair being
+/// drawn with the given probability in thousandths.
+fn random_edges(rng: &mut XorShift64, order: usize, per_mille: u64) -> Vec<(usize, usize)> {
+    let mut edges = Vec::new();
+    for u in 0..order {
+        for v in (u + 1)..order {
+            if rng.next().unwrap() % 1000 < per_mille {
+                edges.push((u, v));
+            }
+        }
+    }
+    edges
+}
+
+/// Asserts that the sorted nodes are independent and that no other node can
+/// be added to them.
+fn assert_maximal_independent_set(graph: &UndiGraph<usize>, nodes: &[usize]) {
+    assert!(nodes.windows(2).all(|pair| pair[0] < pair[1]));
+    for &node in nodes {
+        assert!(
+            graph.neighbors(node).all(|neighbor| nodes.binary_search(&neighbor).is_err()),
+            "{node} has a neighbour in the set"
+        );
+    }
+    for node in graph.node_ids() {
+        if nodes.binary_search(&node).is_err() && graph.neighbors(node).all(|n| n != node) {
+            assert!(
+                graph.neighbors(node).any(|neighbor| nodes.binary_search(&neighbor).is_ok()),
+                "{node} could be added to the set"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_independent_set_against_brute_force() {
+    let order = 14;
+    for seed in 0..60 {
+        let mut rng = XorShift64::from(XorShift64::normalize_seed(seed));
+        let edges = random_edges(&mut rng, order, 50 + 10 * seed);
+        let graph = build_undi_graph(order, edges.clone());
+        let mut adjacency = vec![0_u32; order];
+        for &(u, v) in &edges {
+            adjacency[u] |= 1 << v;
+            adjacency[v] |= 1 << u;
+        }
+        let independence_number = (0..1_u32 << order)
+            .filter(|&mask| {
+                (0..order)
+                    .filter(|&node| mask & (1 << node) != 0)
+                    .all(|node| mask & adjacency[node] == 0)
+            })
+            .map(u32::count_ones)
+            .max()
+            .unwrap();
+        let independence_number = usize::try_from(independence_number).unwrap();
+
+        let greedy = graph.greedy_independent_set();
+        let improved = graph.independent_set();
+        for result in [&greedy, &improved] {
+            assert_maximal_independent_set(&graph, result.nodes());
+            assert_eq!(result.size(), result.nodes().len());
+            assert!(
+                f64::from(u32::try_from(result.size()).unwrap()) + 1e-9 >= result.lower_bound()
+            );
+            assert!(result.size() <= independence_number, "seed {seed}");
+            assert!(independence_number <= result.upper_bound(), "seed {seed}");
+        }
+        assert!(improved.size() >= greedy.size(), "seed {seed}");
+
+        // No (1, 2)-swap is left in the improved set.
+        let mask: u32 = improved.nodes().iter().map(|&node| 1 << node).sum();
+        for &removed in improved.nodes() {
+            let remaining = mask & !(1 << removed);
+            let free: Vec<usize> = (0..order)
+                .filter(|&node| remaining & ((1 << node) | adjacency[node]) == 0)
+                .collect();
+            for (position, &u) in free.iter().enumerate() {
+                for &v in &free[position + 1..] {
+                    assert!(adjacency[u] & (1 << v) != 0, "seed {seed}: {removed} -> {u}, {v}");
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_independent_set_improves_greedy_solution() {
+    // The greedy heuristic takes node 1, of smallest degree, which removes
+    // nodes 0 and 5, and then node 2: swapping node 1 for the two
+    // non-adjacent nodes 0 and 5 improves the set.
+    let graph =
+        build_undi_graph(6, vec![(0, 1), (0, 3), (0, 4), (1, 5), (2, 3), (2, 4), (3, 4), (4, 5)]);
+    let greedy = graph.greedy_independent_set();
+    assert_eq!(greedy.nodes(), &[1, 2]);
+    let improved = graph.independent_set();
+    assert_eq!(improved.nodes(), &[0, 2, 5]);
+    assert_maximal_independent_set(&graph, improved.nodes());
+    assert_eq!(improved.upper_bound(), 3);
+}
+
+#[test]
+fn test_independent_set_of_special_graphs() {
+    let empty = build_undi_graph(0, vec![]);
+    let result = empty.independent_set();
+    assert!(result.nodes().is_empty());
+    assert_eq!(result.upper_bound(), 0);
+
+    // Nodes with a self-loop are excluded from the set.
+    let loops = build_undi_graph(4, vec![(0, 0), (0, 1), (2, 2)]);
+    let result = loops.independent_set();
+    assert_eq!(result.nodes(), &[1, 3]);
+    assert_eq!(result.upper_bound(), 2);
+
+    // The leaves of a star are independent.
+    let star = build_undi_graph(6, (1..6).map(|leaf| (0, leaf)).collect());
+    let result = star.independent_set();
+    assert_eq!(result.nodes(), &[1, 2, 3, 4, 5]);
+    assert_eq!(result.upper_bound(), 5);
+
+    // A large sparse graph, on which the complement would be dense.
+    let order = 5000;
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(7));
+    let edges = random_edges(&mut rng, order, 1);
+    let graph = build_undi_graph(order, edges);
+    let result = graph.independent_set();
+    assert_maximal_independent_set(&graph, result.nodes());
+    assert!(result.size() <= result.upper_bound());
+    assert!(f64::from(u32::try_from(result.size()).unwrap()) >= result.lower_bound());
+}
@@ -0,0 +1,125 @@
+//! Tests for the re-encoding of CSR matrices with narrower index types.
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::random_dag;
+use geometric_traits::{
+    impls::{
+        CSR2D, Compacted, IndexWidth, IndexWidthError, SquareCSR2D, SymmetricCSR2D, ValuedCSR2D,
+    },
+    prelude::*,
+    traits::algorithms::randomized_graphs::erdos_renyi_gnp,
+};
+use num_traits::AsPrimitive;
+
+type Wide = CSR2D<usize, usize, usize>;
+
+#[test]
+fn test_index_width_boundaries() {
+    assert_eq!(IndexWidth::fitting(0), IndexWidth::U8);
+    assert_eq!(IndexWidth::fitting(255), IndexWidth::U8);
+    assert_eq!(IndexWidth::fitting(256), IndexWidth::U16);
+    assert_eq!(IndexWidth::fitting(65_535), IndexWidth::U16);
+    assert_eq!(IndexWidth::fitting(65_536), IndexWidth::U32);
+    assert_eq!(IndexWidth::fitting(usize::MAX), IndexWidth::Usize);
+    assert!(IndexWidth::U8.bytes() < IndexWidth::U16.bytes());
+    assert!(IndexWidth::U32.bytes() <= IndexWidth::Usize.bytes());
+}
+
+#[test]
+fn test_compact_csr2d_round_trip() {
+    for (rows, columns, expected) in
+        [(3, 255, IndexWidth::U8), (256, 4, IndexWidth::U16), (10, 70_000, IndexWidth::U32)]
+    {
+        let mut matrix: Wide = SparseMatrixMut::with_sparse_shape((rows, columns));
+        MatrixMut::add(&mut matrix, (0, columns - 1)).unwrap();
+        MatrixMut::add(&mut matrix, (rows - 1, 0)).unwrap();
+        assert_eq!(matrix.index_width(), expected);
+
+        let compact = matrix.compact();
+        assert_eq!(compact.index_width(), expected);
+        let coordinates: Vec<(usize, usize)> = match &compact {
+            Compacted::U8(narrow) => {
+                SparseMatrix::sparse_coordinates(narrow)
+                    .map(|(r, c)| (r.into(), c.into()))
+                    .collect()
+            }
+            Compacted::U16(narrow) => {
+                SparseMatrix::sparse_coordinates(narrow)
+                    .map(|(r, c)| (r.into(), c.into()))
+                    .collect()
+            }
+            Compacted::U32(narrow) => {
+                SparseMatrix::sparse_coordinates(narrow).map(|(r, c)| (r.as_(), c.as_())).collect()
+            }
+            Compacted::Usize(narrow) => SparseMatrix::sparse_coordinates(narrow).collect(),
+        };
+        assert_eq!(coordinates, SparseMatrix::sparse_coordinates(&matrix).collect::<Vec<_>>());
+
+        let back: Wide = match compact {
+            Compacted::U16(narrow) => narrow.try_reindex().unwrap(),
+            Compacted::U8(narrow) => narrow.try_reindex().unwrap(),
+            Compacted::U32(narrow) => narrow.try_reindex().unwrap(),
+            Compacted::Usize(narrow) => narrow,
+        };
+        assert!(back == matrix);
+    }
+}
+
+#[test]
+fn test_try_reindex_errors() {
+    let mut matrix: Wide = SparseMatrixMut::with_sparse_shape((300, 3));
+    for row in 0..300 {
+        MatrixMut::add(&mut matrix, (row, row % 3)).unwrap();
+    }
+    assert_eq!(
+        matrix.try_reindex::<u8, u16, u8>(),
+        Err(IndexWidthError::SparseIndex { number_of_values: 300 })
+    );
+    assert_eq!(
+        matrix.try_reindex::<u16, u8, u8>(),
+        Err(IndexWidthError::RowIndex { number_of_rows: 300 })
+    );
+    let narrow: CSR2D<u16, u16, u8> = matrix.try_reindex().unwrap();
+    assert_eq!(narrow.number_of_columns(), 3);
+    assert_eq!(narrow.number_of_non_empty_rows(), 300);
+}
+
+#[test]
+fn test_compact_valued_csr2d_keeps_values() {
+    let costs: ValuedCSR2D<usize, usize, usize, f64> =
+        ValuedCSR2D::try_from([[1.0, 0.0, 7.5], [0.5, 3.0, 2.0]]).unwrap();
+    let Compacted::U8(compact) = costs.compact() else { panic!("expected u8 indices") };
+    assert_eq!(compact.number_of_rows(), 2_u8);
+    for row in 0..2_u8 {
+        assert_eq!(
+            compact.sparse_row_values(row).collect::<Vec<_>>(),
+            costs.sparse_row_values(usize::from(row)).collect::<Vec<_>>()
+        );
+    }
+}
+
+#[test]
+fn test_compact_wrappers() {
+    let dag: SquareCSR2D<Wide> = random_dag(3, 300, 0.05);
+    let Compacted::U16(compact) = dag.compact() else { panic!("expected u16 indices") };
+    assert_eq!(compact.order(), 300_u16);
+    assert_eq!(compact.number_of_defined_diagonal_values(), 0);
+    let back: SquareCSR2D<Wide> = compact.try_reindex().unwrap();
+    assert!(back == dag);
+
+    let graph: SymmetricCSR2D<Wide> = erdos_renyi_gnp(5, 120, 0.05);
+    assert_eq!(graph.index_width(), IndexWidth::fitting(graph.number_of_defined_values()));
+    let narrow: SymmetricCSR2D<CSR2D<u32, u8, u8>> = graph.try_reindex().unwrap();
+    assert_eq!(
+        narrow.number_of_defined_values(),
+        u32::try_from(graph.number_of_defined_values()).unwrap()
+    );
+    for row in 0..120_u8 {
+        assert_eq!(
+            narrow.sparse_row(row).map(usize::from).collect::<Vec<_>>(),
+            graph.sparse_row(usize::from(row)).collect::<Vec<_>>()
+        );
+    }
+}
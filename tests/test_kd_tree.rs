@@ -0,0 +1,107 @@
+//! Tests for the `KdTree` spatial index.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    spatial::{KdTree, Neighbor},
+    traits::algorithms::randomized_graphs::XorShift64,
+};
+
+#[allow(clippy::cast_precision_loss)]
+fn random_points<const D: usize>(seed: u64, n: usize, resolution: u64) -> Vec<[f64; D]> {
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(seed));
+    (0..n)
+        .map(|_| core::array::from_fn(|_| (rng.next().unwrap() % resolution) as f64 / 10.0))
+        .collect()
+}
+
+fn distance<const D: usize>(left: &[f64; D], right: &[f64; D]) -> f64 {
+    left.iter().zip(right).map(|(left, right)| (left - right) * (left - right)).sum::<f64>().sqrt()
+}
+
+/// Returns the positions of all points sorted as the queries report them.
+fn brute_force_order<const D: usize>(points: &[[f64; D]], query: &[f64; D]) -> Vec<(usize, f64)> {
+    let mut sorted: Vec<(usize, f64)> =
+        points.iter().enumerate().map(|(index, point)| (index, distance(query, point))).collect();
+    sorted.sort_by(|left, right| left.1.total_cmp(&right.1).then(left.0.cmp(&right.0)));
+    sorted
+}
+
+fn indices(neighbors: &[Neighbor]) -> Vec<usize> {
+    neighbors.iter().map(Neighbor::index).collect()
+}
+
+fn check_against_brute_force<const D: usize>(points: &[[f64; D]], queries: &[[f64; D]]) {
+    let tree: KdTree<D, ()> = points.iter().map(|&point| (point, ())).collect();
+    assert_eq!(tree.len(), points.len());
+    for query in queries {
+        let expected = brute_force_order(points, query);
+        for k in [1, 2, 7, 50, points.len() + 3] {
+            let found = tree.nearest_neighbors(query, k);
+            let wanted: Vec<usize> = expected.iter().take(k).map(|&(index, _)| index).collect();
+            assert_eq!(indices(&found), wanted, "k = {k}");
+            for (neighbor, &(_, distance)) in found.iter().zip(&expected) {
+                assert!((neighbor.distance() - distance).abs() < f64::EPSILON);
+            }
+        }
+        for radius in [0.0, 0.5, 2.0, 10.0] {
+            let found = tree.within_radius(query, radius);
+            let wanted: Vec<usize> = expected
+                .iter()
+                .filter(|&&(_, distance)| distance <= radius)
+                .map(|&(index, _)| index)
+                .collect();
+            assert_eq!(indices(&found), wanted, "radius = {radius}");
+        }
+    }
+}
+
+#[test]
+fn test_kd_tree_matches_brute_force_in_two_dimensions() {
+    // A coarse resolution produces many duplicate coordinates and ties.
+    let points = random_points::<2>(1, 500, 100);
+    let queries = random_points::<2>(2, 30, 120);
+    check_against_brute_force(&points, &queries);
+}
+
+#[test]
+fn test_kd_tree_matches_brute_force_in_three_dimensions() {
+    let points = random_points::<3>(3, 400, 1000);
+    let mut queries = random_points::<3>(4, 20, 1000);
+    // Querying stored points must report them at distance zero first.
+    queries.extend(points.iter().take(5));
+    check_against_brute_force(&points, &queries);
+}
+
+#[test]
+fn test_kd_tree_on_skewed_scales() {
+    // One dimension spans thousands of units and the other a handful.
+    let points: Vec<[f64; 2]> = random_points::<2>(5, 300, 50)
+        .into_iter()
+        .enumerate()
+        .map(|(index, [x, y])| [x * 1000.0 + f64::from(u32::try_from(index).unwrap()), y / 10.0])
+        .collect();
+    let queries = random_points::<2>(6, 10, 50000);
+    check_against_brute_force(&points, &queries);
+}
+
+#[test]
+fn test_kd_tree_degenerate_inputs() {
+    let empty: KdTree<3, u8> = KdTree::new([]);
+    assert!(empty.is_empty());
+    assert!(empty.nearest_neighbors(&[0.0; 3], 4).is_empty());
+    assert!(empty.within_radius(&[0.0; 3], 1.0).is_empty());
+
+    let tree = KdTree::new([([1.0], 'a'), ([1.0], 'b'), ([-2.0], 'c')]);
+    assert!(tree.nearest_neighbors(&[0.0], 0).is_empty());
+    assert!(tree.within_radius(&[0.0], -1.0).is_empty());
+    assert_eq!(indices(&tree.nearest_neighbors(&[1.0], 2)), vec![0, 1]);
+    assert_eq!(indices(&tree.within_radius(&[0.0], 2.0)), vec![0, 1, 2]);
+    assert_eq!(*tree.value(2), 'c');
+    assert!((tree.point(2)[0] + 2.0).abs() < f64::EPSILON);
+}
+
+#[test]
+#[should_panic(expected = "must not be NaN")]
+fn test_kd_tree_rejects_nan_coordinates() {
+    let _ = KdTree::new([([0.0, f64::NAN], ())]);
+}
@@ -0,0 +1,184 @@
+//! Tests for the construction of k-nearest-neighbour graphs.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::ValuedCSR2D,
+    prelude::*,
+    spatial::{Euclidean, KnnSymmetrize, Manhattan, Metric, knn_graph, mutual_knn_graph},
+    traits::algorithms::randomized_graphs::XorShift64,
+};
+
+#[allow(clippy::cast_precision_loss)]
+fn random_points(seed: u64, n: usize, resolution: u64) -> Vec<[f64; 2]> {
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(seed));
+    (0..n)
+        .map(|_| core::array::from_fn(|_| (rng.next().unwrap() % resolution) as f64 / 10.0))
+        .collect()
+}
+
+/// Returns the `k` nearest other points of every point, sorted by position.
+fn brute_force<P, M: Metric<P>>(points: &[P], k: usize, metric: &M) -> Vec<Vec<(usize, f64)>> {
+    (0..points.len())
+        .map(|source| {
+            let mut others: Vec<(usize, f64)> = (0..points.len())
+                .filter(|&other| other != source)
+                .map(|other| (other, metric.distance(&points[source], &points[other])))
+                .collect();
+            others.sort_by(|left, right| left.1.total_cmp(&right.1).then(left.0.cmp(&right.0)));
+            others.truncate(k);
+            others.sort_by_key(|&(other, _)| other);
+            others
+        })
+        .collect()
+}
+
+fn weighted_edges<M>(graph: &M) -> Vec<(usize, usize, f64)>
+where
+    M: SparseValuedMatrix2D<RowIndex = usize, ColumnIndex = usize, Value = f64>,
+{
+    SparseMatrix::sparse_coordinates(graph)
+        .zip(graph.sparse_values())
+        .map(|((row, column), distance)| (row, column, distance))
+        .collect()
+}
+
+#[test]
+fn test_knn_graph_matches_brute_force() {
+    // A coarse resolution produces coincident points and tied distances.
+    let points = random_points(1, 300, 50);
+    for k in [0, 1, 5, 12] {
+        let graph = knn_graph(&points, k, &Euclidean);
+        let expected: Vec<(usize, usize, f64)> = brute_force(&points, k, &Euclidean)
+            .into_iter()
+            .enumerate()
+            .flat_map(|(source, neighborhood)| {
+                neighborhood.into_iter().map(move |(other, distance)| (source, other, distance))
+            })
+            .collect();
+        assert_eq!(weighted_edges(&graph), expected, "k = {k}");
+        assert_eq!(graph.number_of_rows(), points.len());
+        assert_eq!(graph.number_of_columns(), points.len());
+    }
+}
+
+#[test]
+fn test_mutual_knn_graph_keeps_reciprocated_edges() {
+    // The Manhattan distance is exactly symmetric in floating point, so both
+    // directions of a mutual edge carry the same weight.
+    let points = random_points(2, 250, 80);
+    for k in [1, 4, 10] {
+        let directed = knn_graph(&points, k, &Manhattan);
+        let mutual = mutual_knn_graph(&points, k, &Manhattan);
+        let expected: Vec<(usize, usize, f64)> = weighted_edges(&directed)
+            .into_iter()
+            .filter(|&(source, destination, _)| directed.has_entry(destination, source))
+            .collect();
+        assert_eq!(weighted_edges(&mutual), expected, "k = {k}");
+        assert!(mutual.row_indices().all(|row| mutual.number_of_defined_values_in_row(row) <= k));
+    }
+}
+
+#[test]
+fn test_knn_graph_degenerate_inputs() {
+    let empty: [[f64; 2]; 0] = [];
+    assert_eq!(knn_graph(&empty, 3, &Euclidean).number_of_defined_values(), 0);
+    assert_eq!(mutual_knn_graph(&empty, 3, &Euclidean).order(), 0);
+
+    // With fewer than `k` other points, every point links to all the others,
+    // and coincident points are neighbours at null distance.
+    let points = [[0.0], [0.0], [2.0]];
+    assert_eq!(
+        weighted_edges(&knn_graph(&points, 5, &Euclidean)),
+        vec![(0, 1, 0.0), (0, 2, 2.0), (1, 0, 0.0), (1, 2, 2.0), (2, 0, 2.0), (2, 1, 2.0)]
+    );
+    assert_eq!(
+        weighted_edges(&mutual_knn_graph(&points, 1, &Euclidean)),
+        vec![(0, 1, 0.0), (1, 0, 0.0)]
+    );
+    assert_eq!(knn_graph(&points, 0, &Euclidean).number_of_defined_values(), 0);
+}
+
+#[test]
+fn test_knn_symmetrize_policies() {
+    let points = random_points(3, 200, 60);
+    for k in [1, 3, 8] {
+        let directed = knn_graph(&points, k, &Manhattan);
+        let weight = |source: usize, destination: usize| {
+            directed
+                .has_entry(source, destination)
+                .then(|| Manhattan.distance(&points[source], &points[destination]))
+        };
+
+        let mutual = KnnSymmetrize::Mutual.symmetrize(&directed);
+        assert_eq!(
+            weighted_edges(&mutual),
+            weighted_edges(&mutual_knn_graph(&points, k, &Manhattan))
+        );
+
+        let union = weighted_edges(&KnnSymmetrize::Union.symmetrize(&directed));
+        let average = weighted_edges(&KnnSymmetrize::Average.symmetrize(&directed));
+        let mut expected_union = Vec::new();
+        let mut expected_average = Vec::new();
+        for source in 0..points.len() {
+            for destination in 0..points.len() {
+                let (forward, backward) =
+                    (weight(source, destination), weight(destination, source));
+                if let Some(distance) = forward.or(backward) {
+                    expected_union.push((source, destination, distance));
+                    expected_average.push((
+                        source,
+                        destination,
+                        f64::midpoint(forward.unwrap_or(0.0), backward.unwrap_or(0.0)),
+                    ));
+                }
+            }
+        }
+        assert_eq!(union, expected_union, "k = {k}");
+        assert_eq!(average, expected_average, "k = {k}");
+    }
+}
+
+#[test]
+fn test_knn_symmetrize_asymmetric_weights() {
+    // Directed weights, with a self-loop kept as it is.
+    let directed: ValuedCSR2D<usize, usize, usize, f64> = GenericEdgesBuilder::<
+        _,
+        ValuedCSR2D<usize, usize, usize, f64>,
+    >::default()
+    .expected_number_of_edges(5)
+    .expected_shape((3, 3))
+    .edges(vec![(0, 1, 1.0), (0, 2, 4.0), (1, 0, 3.0), (1, 1, 5.0), (2, 1, 2.0)].into_iter())
+    .build()
+    .unwrap();
+    assert_eq!(
+        weighted_edges(&KnnSymmetrize::Mutual.symmetrize(&directed)),
+        vec![(0, 1, 1.0), (1, 0, 1.0), (1, 1, 5.0)]
+    );
+    assert_eq!(
+        weighted_edges(&KnnSymmetrize::Union.symmetrize(&directed)),
+        vec![
+            (0, 1, 1.0),
+            (0, 2, 4.0),
+            (1, 0, 1.0),
+            (1, 1, 5.0),
+            (1, 2, 2.0),
+            (2, 0, 4.0),
+            (2, 1, 2.0)
+        ]
+    );
+    assert_eq!(
+        weighted_edges(&KnnSymmetrize::Average.symmetrize(&directed)),
+        vec![
+            (0, 1, 2.0),
+            (0, 2, 2.0),
+            (1, 0, 2.0),
+            (1, 1, 5.0),
+            (1, 2, 1.0),
+            (2, 0, 2.0),
+            (2, 1, 1.0)
+        ]
+    );
+
+    let empty: ValuedCSR2D<usize, usize, usize, f64> = knn_graph(&[[0.0; 2]; 0], 2, &Euclidean);
+    assert_eq!(KnnSymmetrize::Union.symmetrize(&empty).order(), 0);
+}
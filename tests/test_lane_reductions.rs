@@ -0,0 +1,101 @@
+//! Tests for the slice reductions accelerated by the `simd` feature, which
+//! must match the scalar scans exactly.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::{SymmetricCSR2D, ValuedCSR2D},
+    prelude::*,
+    traits::algorithms::randomized_graphs::{
+        CostDistribution, Feasibility, SparseCostMatrixConfig, XorShift64,
+        random_sparse_cost_matrix,
+    },
+};
+
+type Costs = ValuedCSR2D<usize, usize, usize, f64>;
+
+fn scalar_max<T: TotalOrd + Copy>(values: &[T]) -> Option<T> {
+    values.iter().copied().max_by(TotalOrd::total_cmp)
+}
+
+#[test]
+fn test_total_max_matches_scalar_scan() {
+    let specials = [f64::NAN, -f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 0.0, -0.0, f64::MAX];
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(11));
+    for length in 0..40 {
+        for _ in 0..20 {
+            let values: Vec<f64> = (0..length)
+                .map(|_| {
+                    let draw = rng.next().unwrap();
+                    if draw % 5 == 0 {
+                        specials[usize::try_from(draw / 5).unwrap() % specials.len()]
+                    } else {
+                        f64::from_bits(draw)
+                    }
+                })
+                .collect();
+            let expected = scalar_max(&values).map(f64::to_bits);
+            assert_eq!(f64::total_max(&values).map(f64::to_bits), expected, "{values:?}");
+
+            #[allow(clippy::cast_possible_truncation)]
+            let narrow: Vec<f32> = values.iter().map(|&value| value as f32).collect();
+            assert_eq!(
+                f32::total_max(&narrow).map(f32::to_bits),
+                scalar_max(&narrow).map(f32::to_bits)
+            );
+
+            #[allow(clippy::cast_possible_wrap)]
+            let integers: Vec<i64> = values.iter().map(|value| value.to_bits() as i64).collect();
+            assert_eq!(i64::total_max(&integers), scalar_max(&integers));
+        }
+    }
+}
+
+#[test]
+fn test_max_sparse_value_of_csr_matrices() {
+    let costs: Costs = ValuedCSR2D::try_from([[1.0, -0.0, 7.5], [0.0, 7.5, 2.0]]).unwrap();
+    assert_eq!(costs.max_sparse_value(), Some(7.5));
+    assert_eq!(costs.max_sparse_value(), costs.sparse_values().max_by(f64::total_cmp));
+
+    let symmetric: SymmetricCSR2D<ValuedCSR2D<usize, usize, usize, u32>> =
+        SymmetricCSR2D::from_sorted_upper_triangular_entries(
+            3,
+            vec![(0, 0, 3), (0, 1, 9), (1, 2, 4)],
+        )
+        .unwrap();
+    assert_eq!(symmetric.max_sparse_value(), Some(9));
+
+    let empty: Costs = SparseMatrixMut::with_sparse_shaped_capacity((3, 3), 0);
+    assert_eq!(empty.max_sparse_value(), None);
+}
+
+#[test]
+fn test_lapmod_with_long_rows_is_optimal() {
+    for seed in 1..=20 {
+        let mut config = SparseCostMatrixConfig::new(40, 40, 0.4);
+        config.feasibility = Feasibility::Assignment;
+        if seed % 2 == 0 {
+            // Few distinct costs, so that the row minima are often tied.
+            config.costs = CostDistribution::Grid { levels: 4, scale: 1.0 };
+        }
+        let costs = random_sparse_cost_matrix(seed, &config);
+        let maximum = costs.max_sparse_value().unwrap();
+
+        let cost_of = |assignment: &[(usize, usize)]| -> f64 {
+            assignment
+                .iter()
+                .map(|&(row, column)| {
+                    costs
+                        .sparse_row(row)
+                        .zip(costs.sparse_row_values(row))
+                        .find_map(|(other, cost)| (other == column).then_some(cost))
+                        .unwrap()
+                })
+                .sum()
+        };
+        let lapmod = costs.lapmod(maximum * 2.1).unwrap();
+        let reference = costs.sparse_hungarian(maximum * 4.2, maximum * 8.4).unwrap();
+        assert_eq!(lapmod.len(), 40, "seed {seed}");
+        assert_eq!(reference.len(), 40, "seed {seed}");
+        assert!((cost_of(&lapmod) - cost_of(&reference)).abs() < 1e-9, "seed {seed}");
+    }
+}
@@ -0,0 +1,110 @@
+//! Tests for the preflight diagnostics of the inputs of the LAP algorithms.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::ValuedCSR2D,
+    prelude::*,
+    traits::{
+        LAPError, LapPreflight, MAXIMUM_STABLE_DYNAMIC_RANGE,
+        algorithms::randomized_graphs::{SparseCostMatrixConfig, random_sparse_cost_matrix},
+    },
+};
+
+type TestValuedCSR = ValuedCSR2D<usize, usize, usize, f64>;
+
+fn build_matrix(shape: (usize, usize), entries: &[(usize, usize, f64)]) -> TestValuedCSR {
+    let mut matrix: TestValuedCSR = SparseMatrixMut::with_sparse_shape(shape);
+    for &entry in entries {
+        MatrixMut::add(&mut matrix, entry).unwrap();
+    }
+    matrix
+}
+
+#[test]
+fn test_check_agrees_with_lapmod_on_random_matrices() {
+    let mut number_of_infeasible = 0;
+    for seed in 1..=40 {
+        for density in [0.05, 0.15, 0.5] {
+            let matrix =
+                random_sparse_cost_matrix(seed, &SparseCostMatrixConfig::new(8, 8, density));
+            let report = matrix.preflight(1e6);
+            assert_eq!(report.check().is_ok(), matrix.lapmod(1e6).is_ok());
+            if let Err(error) = report.check() {
+                assert_eq!(error, LAPError::InfeasibleAssignment);
+                assert_eq!(matrix.lapmod(1e6), Err(error));
+                number_of_infeasible += 1;
+            }
+            assert_eq!(report.maximum_matching_size, Some(matrix.hopcroft_karp().unwrap().len()));
+            assert!(report.is_numerically_stable());
+        }
+    }
+    assert!(number_of_infeasible > 0);
+}
+
+#[test]
+fn test_report_lists_empty_rows_and_columns() {
+    let matrix = build_matrix((4, 3), &[(0, 0, 1.0), (0, 2, 2.0), (2, 2, 3.0)]);
+    let report = matrix.preflight(10.0);
+    assert_eq!((report.number_of_rows, report.number_of_columns), (4, 3));
+    assert!(!report.is_square());
+    assert_eq!(report.empty_rows, vec![1, 3]);
+    assert_eq!(report.empty_columns, vec![1]);
+    assert_eq!(report.maximum_matching_size, Some(2));
+    assert!(!report.has_perfect_matching());
+    assert_eq!(report.check(), Err(LAPError::NonSquareMatrix));
+
+    let empty = TestValuedCSR::default().preflight(1.0);
+    assert!(empty.empty_rows.is_empty() && empty.empty_columns.is_empty());
+    assert!(empty.has_perfect_matching());
+    assert_eq!(empty.check(), Ok(()));
+    assert_eq!(TestValuedCSR::default().lapmod(1.0), Ok(Vec::new()));
+}
+
+#[test]
+fn test_check_reports_invalid_values_and_costs() {
+    let diagonal = |value: f64| build_matrix((2, 2), &[(0, 0, 1.0), (1, 1, value)]);
+
+    for (value, error) in [
+        (f64::NAN, LAPError::NonFiniteValues),
+        (0.0, LAPError::ZeroValues),
+        (-1.0, LAPError::NegativeValues),
+        (10.0, LAPError::ValueTooLarge),
+    ] {
+        let matrix = diagonal(value);
+        assert_eq!(matrix.preflight(10.0).check(), Err(error.clone()));
+        assert_eq!(matrix.lapmod(10.0), Err(error));
+    }
+
+    let report = diagonal(12.0).preflight(10.0);
+    assert_eq!(report.number_of_values_exceeding_max_cost, 1);
+    assert_eq!(report.statistics.maximum, Some(12.0));
+
+    for (max_cost, error) in
+        [(f64::INFINITY, LAPError::MaximalCostNotFinite), (0.0, LAPError::MaximalCostNotPositive)]
+    {
+        let report = diagonal(2.0).preflight(max_cost);
+        assert_eq!(report.cost_error, Some(error.clone()));
+        assert_eq!(report.check(), Err(error));
+    }
+
+    let integers: ValuedCSR2D<usize, usize, usize, i32> =
+        ValuedCSR2D::try_from([[1, 2], [3, 4]]).unwrap();
+    assert_eq!(integers.preflight(10).check(), Err(LAPError::NonFractionalValueTypeUnsupported));
+}
+
+#[test]
+fn test_numerical_stability_depends_on_the_dynamic_range() {
+    let stable = build_matrix((2, 2), &[(0, 0, 1.0), (1, 1, MAXIMUM_STABLE_DYNAMIC_RANGE)]);
+    let report = stable.preflight(f64::MAX);
+    assert_eq!(report.dynamic_range(), Some(MAXIMUM_STABLE_DYNAMIC_RANGE));
+    assert!(report.is_numerically_stable());
+
+    let unstable = build_matrix((2, 2), &[(0, 0, 1e-10), (1, 1, 1e10)]);
+    let report = unstable.preflight(f64::MAX);
+    assert!(!report.is_numerically_stable());
+    assert_eq!(report.check(), Ok(()));
+
+    let subnormal = build_matrix((1, 1), &[(0, 0, f64::MIN_POSITIVE / 2.0)]);
+    assert!(!subnormal.preflight(1.0).is_numerically_stable());
+    assert!(build_matrix((1, 1), &[(0, 0, -1.0)]).preflight(1.0).is_numerically_stable());
+}
@@ -0,0 +1,138 @@
+//! Tests for the combinatorial and normalized Laplacians of weighted
+//! undirected graphs.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    errors::GeometricError,
+    impls::{SymmetricCSR2D, ValuedCSR2D},
+    prelude::*,
+    traits::{Laplacian, LaplacianError, algorithms::randomized_graphs::XorShift64},
+};
+
+type Weights = SymmetricCSR2D<ValuedCSR2D<usize, usize, usize, f64>>;
+
+fn weights(order: usize, entries: Vec<(usize, usize, f64)>) -> Weights {
+    SymmetricCSR2D::from_sorted_upper_triangular_entries(order, entries).unwrap()
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn random_weights(seed: u64, order: usize) -> Weights {
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(seed));
+    let mut entries = Vec::new();
+    for source in 0..order {
+        for destination in source..order {
+            if rng.next().unwrap() % 100 < 30 {
+                entries.push((
+                    source,
+                    destination,
+                    (1 + rng.next().unwrap() % 1000) as f64 / 100.0,
+                ));
+            }
+        }
+    }
+    weights(order, entries)
+}
+
+/// Returns the dense version of the sparse matrix.
+fn dense(matrix: &ValuedCSR2D<usize, usize, usize, f64>) -> Vec<Vec<f64>> {
+    let mut dense = vec![vec![0.0; matrix.number_of_columns()]; matrix.number_of_rows()];
+    for row in matrix.row_indices() {
+        for (column, value) in matrix.sparse_row(row).zip(matrix.sparse_row_values(row)) {
+            dense[row][column] = value;
+        }
+    }
+    dense
+}
+
+fn dense_adjacency(matrix: &Weights, order: usize) -> Vec<Vec<f64>> {
+    let mut dense = vec![vec![0.0; order]; order];
+    for (row, values) in dense.iter_mut().enumerate() {
+        for (column, value) in matrix.sparse_row(row).zip(matrix.sparse_row_values(row)) {
+            values[column] = value;
+        }
+    }
+    dense
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_laplacians_match_their_dense_definitions() {
+    for seed in 1..=20 {
+        let order = 9;
+        let matrix = random_weights(seed, order);
+        let adjacency = dense_adjacency(&matrix, order);
+        let degrees: Vec<f64> = adjacency.iter().map(|row| row.iter().sum()).collect();
+
+        let laplacian = dense(&matrix.laplacian().unwrap());
+        let normalized = dense(&matrix.normalized_laplacian().unwrap());
+        for row in 0..order {
+            assert!(laplacian[row].iter().sum::<f64>().abs() < 1e-9);
+            for column in 0..order {
+                let expected =
+                    if row == column { degrees[row] } else { 0.0 } - adjacency[row][column];
+                assert!((laplacian[row][column] - expected).abs() < 1e-12);
+                assert_eq!(laplacian[row][column], laplacian[column][row]);
+
+                let expected = if degrees[row] > 0.0 && degrees[column] > 0.0 {
+                    expected / (degrees[row] * degrees[column]).sqrt()
+                } else {
+                    0.0
+                };
+                assert!((normalized[row][column] - expected).abs() < 1e-12);
+            }
+        }
+    }
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_laplacians_of_isolated_nodes_and_self_loops() {
+    // Node 0 has a self-loop and an edge, node 1 has only a self-loop and
+    // node 2 is isolated.
+    let matrix = weights(4, vec![(0, 0, 1.0), (0, 3, 3.0), (1, 1, 2.0)]);
+
+    let laplacian = matrix.laplacian().unwrap();
+    assert_eq!(
+        SparseMatrix::sparse_coordinates(&laplacian).collect::<Vec<_>>(),
+        vec![(0, 0), (0, 3), (3, 0), (3, 3)]
+    );
+    assert_eq!(laplacian.sparse_row_values(0).collect::<Vec<_>>(), vec![3.0, -3.0]);
+
+    let normalized = matrix.normalized_laplacian().unwrap();
+    assert_eq!(normalized.sparse_row(1).count(), 0);
+    assert_eq!(normalized.sparse_row(2).count(), 0);
+    assert_eq!(normalized.sparse_row_values(0).next(), Some(1.0 - 1.0 / 4.0));
+    let row: Vec<f64> = normalized.sparse_row_values(3).collect();
+    assert!((row[0] + 3.0 / 12.0_f64.sqrt()).abs() < 1e-12);
+    assert_eq!(row[1], 1.0);
+
+    let empty = weights(0, Vec::new());
+    assert_eq!(empty.laplacian().unwrap().number_of_rows(), 0);
+}
+
+#[test]
+fn test_invalid_adjacency_matrices_are_rejected() {
+    let mut rectangular: ValuedCSR2D<usize, usize, usize, f64> =
+        SparseMatrixMut::with_sparse_shape((2, 3));
+    MatrixMut::add(&mut rectangular, (0, 1, 1.0)).unwrap();
+    assert_eq!(
+        rectangular.laplacian(),
+        Err(LaplacianError::NonSquareMatrix { rows: 2, columns: 3 })
+    );
+
+    let mut directed: ValuedCSR2D<usize, usize, usize, f64> =
+        SparseMatrixMut::with_sparse_shape((2, 2));
+    MatrixMut::add(&mut directed, (0, 1, 1.0)).unwrap();
+    MatrixMut::add(&mut directed, (1, 0, 2.0)).unwrap();
+    assert_eq!(
+        directed.normalized_laplacian(),
+        Err(LaplacianError::NonSymmetricWeight { source_id: 0, destination_id: 1 })
+    );
+
+    assert_eq!(
+        weights(2, vec![(0, 1, -1.0)]).laplacian(),
+        Err(LaplacianError::NegativeWeight { source_id: 0, destination_id: 1 })
+    );
+    let error: GeometricError = weights(2, vec![(0, 1, f64::NAN)]).laplacian().unwrap_err().into();
+    assert_eq!(error.to_string(), "Found a non-finite weight on (0, 1).");
+}
@@ -14,9 +14,16 @@ use std::{
 use geometric_traits::{
     impls::ValuedCSR2D,
     prelude::{
-        HopcroftKarp, LAPError, LAPMOD, MatrixMut, SparseLAPJV, SparseMatrixMut, SparseValuedMatrix,
+        HopcroftKarp, LAPError, LAPMOD, LapmodState, MatrixMut, SparseLAPJV, SparseMatrixMut,
+        SparseValuedMatrix,
+    },
+    traits::{
+        Matrix2D, SizedSparseMatrix, SparseMatrix2D, SparseValuedMatrix2D,
+        algorithms::randomized_graphs::{
+            CostDistribution, Feasibility, SparseCostMatrixConfig, XorShift64,
+            random_sparse_cost_matrix,
+        },
     },
-    traits::{Matrix2D, algorithms::randomized_graphs::XorShift64},
 };
 use num_traits::{AsPrimitive, ToPrimitive};
 
@@ -434,7 +441,8 @@ fn test_lapmod_matrix_dimensions() {
 // ---------------------------------------------------------------------------
 
 // ---------------------------------------------------------------------------
-// Helper: build the same sparse matrix as the lapmod benchmark does
+// Helper: the generator the lapmod benchmark used to rely on, kept verbatim
+// so that the regression tests below keep exercising the same matrices
 // ---------------------------------------------------------------------------
 
 fn sparse_valued_matrix_usize(
@@ -791,3 +799,160 @@ fn test_lapmod_stress_correctness_vs_sparse_lapjv() {
         }
     }
 }
+
+// ---------------------------------------------------------------------------
+// Warm start
+// ---------------------------------------------------------------------------
+
+fn assignment_cost(
+    csr: &ValuedCSR2D<usize, usize, usize, f64>,
+    assignment: &[(usize, usize)],
+) -> f64 {
+    assignment
+        .iter()
+        .map(|&(row, column)| {
+            csr.sparse_row(row)
+                .zip(csr.sparse_row_values(row))
+                .find_map(|(other, value)| (other == column).then_some(value))
+                .expect("assigned entries are sparse entries")
+        })
+        .sum()
+}
+
+/// Returns the matrix with every cost multiplied by a random factor in
+/// [1 - noise, 1 + noise).
+fn perturbed(
+    rng: &mut XorShift64,
+    csr: &ValuedCSR2D<usize, usize, usize, f64>,
+    noise: f64,
+) -> ValuedCSR2D<usize, usize, usize, f64> {
+    let mut perturbed: ValuedCSR2D<usize, usize, usize, f64> =
+        SparseMatrixMut::with_sparse_shaped_capacity(
+            (csr.number_of_rows(), csr.number_of_columns()),
+            csr.number_of_defined_values(),
+        );
+    for row in csr.row_indices() {
+        for (column, value) in csr.sparse_row(row).zip(csr.sparse_row_values(row)) {
+            let uniform = f64::from(u32::try_from(random_index(rng, 1 << 20)).unwrap())
+                / f64::from(1_u32 << 20);
+            perturbed.add((row, column, value * (1.0 + noise * (2.0 * uniform - 1.0)))).unwrap();
+        }
+    }
+    perturbed
+}
+
+#[test]
+fn test_lapmod_warm_start_from_empty_state_is_lapmod() {
+    for seed in 1..=10 {
+        let mut config = SparseCostMatrixConfig::new(30, 30, 0.2);
+        config.feasibility = Feasibility::Assignment;
+        let csr = random_sparse_cost_matrix(seed, &config);
+        let max_cost = csr.max_sparse_value().unwrap() * 2.0;
+        let (assignment, state) = csr.lapmod_warm_start(&LapmodState::default(), max_cost).unwrap();
+        assert_eq!(assignment, csr.lapmod(max_cost).unwrap());
+        assert_eq!(state.column_duals().len(), 30);
+    }
+
+    let empty: ValuedCSR2D<u8, u8, u8, f64> = ValuedCSR2D::with_sparse_shaped_capacity((0, 0), 0);
+    let (assignment, state) = empty.lapmod_warm_start(&LapmodState::default(), 1.0).unwrap();
+    assert!(assignment.is_empty());
+    assert!(state.column_duals().is_empty());
+}
+
+#[test]
+fn test_lapmod_warm_start_sequence_is_optimal() {
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(31));
+    for seed in 1..=5 {
+        for costs in [CostDistribution::default(), CostDistribution::Grid { levels: 4, scale: 1.0 }]
+        {
+            let config = SparseCostMatrixConfig {
+                costs,
+                feasibility: Feasibility::Assignment,
+                ..SparseCostMatrixConfig::new(60, 60, 0.1)
+            };
+            let mut csr = random_sparse_cost_matrix(seed, &config);
+            let max_cost = csr.max_sparse_value().unwrap() * 4.0;
+            let mut state = LapmodState::default();
+            for step in 0..10 {
+                let (assignment, next) = csr.lapmod_warm_start(&state, max_cost).unwrap();
+                let cold = csr.lapmod(max_cost).unwrap();
+                assert_eq!(assignment.len(), 60);
+                let (warm_cost, cold_cost) =
+                    (assignment_cost(&csr, &assignment), assignment_cost(&csr, &cold));
+                assert!(
+                    (warm_cost - cold_cost).abs() <= 1e-9 * cold_cost,
+                    "seed {seed}, step {step}: {warm_cost} != {cold_cost}"
+                );
+                // Restarting from the duals of the same problem.
+                let (restarted, _) = csr.lapmod_warm_start(&next, max_cost).unwrap();
+                let restarted_cost = assignment_cost(&csr, &restarted);
+                assert!((restarted_cost - cold_cost).abs() <= 1e-9 * cold_cost);
+                state = next;
+                csr = perturbed(&mut rng, &csr, 0.05);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_lapmod_warm_start_errors() {
+    let csr: ValuedCSR2D<u8, u8, u8, f64> =
+        ValuedCSR2D::try_from([[1.0, 2.0], [3.0, 1.0]]).unwrap();
+    let (_, state) = csr.lapmod_warm_start(&LapmodState::default(), 10.0).unwrap();
+    let larger: ValuedCSR2D<u8, u8, u8, f64> =
+        ValuedCSR2D::try_from([[1.0, 2.0, 3.0], [3.0, 1.0, 2.0], [2.0, 3.0, 1.0]]).unwrap();
+    assert_eq!(
+        larger.lapmod_warm_start(&state, 10.0),
+        Err(LAPError::WarmStartStateMismatch { expected: 3, actual: 2 })
+    );
+    // The costs are validated as in a cold start.
+    assert_eq!(csr.lapmod_warm_start(&state, 2.5), Err(LAPError::ValueTooLarge));
+    assert_eq!(csr.lapmod_warm_start(&state, f64::INFINITY), Err(LAPError::MaximalCostNotFinite));
+
+    let mut disconnected: ValuedCSR2D<u8, u8, u8, f64> =
+        SparseMatrixMut::with_sparse_shaped_capacity((2, 2), 2);
+    disconnected.add((0, 0, 1.0)).unwrap();
+    disconnected.add((0, 1, 1.0)).unwrap();
+    assert_eq!(disconnected.lapmod_warm_start(&state, 10.0), Err(LAPError::InfeasibleAssignment));
+}
+
+#[test]
+fn test_lapmod_warm_start_from_any_state_matches_cold_start() {
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(57));
+    for seed in 1..=300 {
+        let n = 5 + random_index(&mut rng, 40);
+        let density = [0.05, 0.1, 0.3][random_index(&mut rng, 3)];
+        let config = SparseCostMatrixConfig {
+            feasibility: Feasibility::RowsAndColumns,
+            ..SparseCostMatrixConfig::new(n, n, density)
+        };
+        let csr = random_sparse_cost_matrix(seed, &config);
+        let unrelated = random_sparse_cost_matrix(seed + 10_000, &config);
+        let nearby = perturbed(&mut rng, &csr, 0.025);
+        let max_cost = [&csr, &unrelated, &nearby]
+            .into_iter()
+            .filter_map(SparseValuedMatrix::max_sparse_value)
+            .fold(0.0, f64::max)
+            * 1.1;
+        let cold = csr.lapmod(max_cost);
+
+        for source in [&unrelated, &nearby] {
+            let Ok((_, state)) = source.lapmod_warm_start(&LapmodState::default(), max_cost) else {
+                continue;
+            };
+            let warm = csr.lapmod_warm_start(&state, max_cost).map(|(assignment, _)| assignment);
+            match (&cold, &warm) {
+                (Ok(cold), Ok(warm)) => {
+                    let (cold_cost, warm_cost) =
+                        (assignment_cost(&csr, cold), assignment_cost(&csr, warm));
+                    assert!(
+                        (warm_cost - cold_cost).abs() <= 1e-9 * cold_cost.abs().max(1.0),
+                        "seed {seed}: {warm_cost} != {cold_cost}"
+                    );
+                }
+                (Err(cold), Err(warm)) => assert_eq!(cold, warm, "seed {seed}"),
+                _ => panic!("seed {seed}: cold {cold:?} but warm {warm:?}"),
+            }
+        }
+    }
+}
@@ -0,0 +1,154 @@
+//! Tests for the force-directed layouts of undirected graphs.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::{CSR2D, SortedVec, SymmetricCSR2D},
+    prelude::*,
+    traits::{EdgesBuilder, ForceModel, Layout, LayoutConfig, LayoutError, VocabularyBuilder},
+};
+
+fn undirected(order: usize, edges: &[(usize, usize)]) -> UndiGraph<usize> {
+    let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+        .expected_number_of_symbols(order)
+        .symbols((0..order).enumerate())
+        .build()
+        .unwrap();
+    let mut edges: Vec<(usize, usize)> =
+        edges.iter().map(|&(left, right)| (left.min(right), left.max(right))).collect();
+    edges.sort_unstable();
+    let edges: SymmetricCSR2D<CSR2D<usize, usize, usize>> = UndiEdgesBuilder::default()
+        .expected_number_of_edges(edges.len())
+        .expected_shape(order)
+        .edges(edges.into_iter())
+        .build()
+        .unwrap();
+    UndiGraph::from((nodes, edges))
+}
+
+/// Returns the edges of a grid of the provided side.
+fn grid_edges(side: usize) -> Vec<(usize, usize)> {
+    let mut edges = Vec::new();
+    for row in 0..side {
+        for column in 0..side {
+            let node = row * side + column;
+            if column + 1 < side {
+                edges.push((node, node + 1));
+            }
+            if row + 1 < side {
+                edges.push((node, node + side));
+            }
+        }
+    }
+    edges
+}
+
+fn distance(left: [f64; 2], right: [f64; 2]) -> f64 {
+    (left[0] - right[0]).hypot(left[1] - right[1])
+}
+
+/// Returns the mean length of the edges and the mean distance between all
+/// pairs of nodes.
+#[allow(clippy::cast_precision_loss)]
+fn mean_lengths(positions: &[[f64; 2]], edges: &[(usize, usize)]) -> (f64, f64) {
+    let edge_length = edges
+        .iter()
+        .map(|&(source, destination)| distance(positions[source], positions[destination]))
+        .sum::<f64>()
+        / edges.len() as f64;
+    let mut total = 0.0;
+    let mut pairs = 0usize;
+    for source in 0..positions.len() {
+        for destination in source + 1..positions.len() {
+            total += distance(positions[source], positions[destination]);
+            pairs += 1;
+        }
+    }
+    (edge_length, total / pairs as f64)
+}
+
+#[test]
+fn test_layout_is_deterministic_and_seeded() {
+    let edges = grid_edges(6);
+    let grid = undirected(36, &edges);
+    let config = LayoutConfig::default();
+    let positions = grid.layout(&config).unwrap();
+    assert_eq!(positions.len(), 36);
+    assert!(positions.iter().flatten().copied().all(f64::is_finite));
+    assert_eq!(positions, grid.layout(&config).unwrap());
+    assert_ne!(positions, grid.layout(&LayoutConfig { seed: 7, ..config }).unwrap());
+
+    // Without iterations, the nodes stay at their random initial positions
+    // within the square of area V centred on the origin.
+    let initial = grid.layout(&LayoutConfig { iterations: 0, ..config }).unwrap();
+    assert!(initial.iter().flatten().all(|coordinate| coordinate.abs() <= 3.0));
+}
+
+#[test]
+fn test_layout_places_neighbours_close() {
+    let edges = grid_edges(8);
+    let grid = undirected(64, &edges);
+    let models =
+        [ForceModel::FruchtermanReingold, ForceModel::ForceAtlas2 { scaling: 2.0, gravity: 1.0 }];
+    for model in models {
+        for theta in [0.0, 0.5, 1.0] {
+            let config = LayoutConfig { model, theta, ..LayoutConfig::default() };
+            let positions = grid.layout(&config).unwrap();
+            let initial = grid.layout(&LayoutConfig { iterations: 0, ..config }).unwrap();
+            let (edge_length, pair_distance) = mean_lengths(&positions, &edges);
+            let (initial_edge_length, initial_pair_distance) = mean_lengths(&initial, &edges);
+            assert!(
+                edge_length / pair_distance < 0.5 * initial_edge_length / initial_pair_distance,
+                "{model:?}, theta = {theta}: {edge_length} / {pair_distance}"
+            );
+            // The opposite corners of the grid end up farther apart than its
+            // adjacent nodes.
+            assert!(distance(positions[0], positions[63]) > 4.0 * edge_length);
+        }
+    }
+}
+
+#[test]
+fn test_layout_gravity_keeps_components_together() {
+    // Two disjoint triangles and an isolated node.
+    let edges = [(0, 1), (1, 2), (0, 2), (3, 4), (4, 5), (3, 5)];
+    let graph = undirected(7, &edges);
+    let spread = |gravity: f64| {
+        let config = LayoutConfig {
+            model: ForceModel::ForceAtlas2 { scaling: 1.0, gravity },
+            iterations: 500,
+            ..LayoutConfig::default()
+        };
+        let positions = graph.layout(&config).unwrap();
+        positions.iter().map(|&position| position[0].hypot(position[1])).fold(0.0, f64::max)
+    };
+    assert!(spread(5.0) < spread(0.0));
+
+    // The edges of each triangle end up shorter than the distances between
+    // the triangles.
+    let positions = graph.layout(&LayoutConfig::default()).unwrap();
+    let (edge_length, _) = mean_lengths(&positions, &edges);
+    assert!(distance(positions[0], positions[3]) > edge_length);
+}
+
+#[test]
+fn test_layout_degenerate_graphs_and_errors() {
+    let empty = undirected(0, &[]);
+    assert!(empty.layout(&LayoutConfig::default()).unwrap().is_empty());
+    let single = undirected(1, &[(0, 0)]);
+    let positions = single.layout(&LayoutConfig::default()).unwrap();
+    assert_eq!(positions.len(), 1);
+    assert!(positions[0].iter().copied().all(f64::is_finite));
+
+    let graph = undirected(3, &[(0, 1), (1, 2)]);
+    for theta in [-1.0, f64::NAN, f64::INFINITY] {
+        let config = LayoutConfig { theta, ..LayoutConfig::default() };
+        assert_eq!(graph.layout(&config), Err(LayoutError::InvalidTheta));
+    }
+    for (scaling, gravity) in [(0.0, 1.0), (1.0, -1.0), (f64::NAN, 1.0)] {
+        let config = LayoutConfig {
+            model: ForceModel::ForceAtlas2 { scaling, gravity },
+            ..LayoutConfig::default()
+        };
+        assert_eq!(graph.layout(&config), Err(LayoutError::InvalidForceAtlas2Parameters));
+    }
+}
@@ -0,0 +1,149 @@
+//! Integration tests for neighbourhood-based link prediction scores.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::{CSR2D, SortedVec, SymmetricCSR2D},
+    prelude::*,
+    traits::{
+        LinkPrediction, LinkPredictionScore, VocabularyBuilder,
+        algorithms::randomized_graphs::{erdos_renyi_gnp, star_graph},
+    },
+};
+
+const SCORES: [LinkPredictionScore; 3] = [
+    LinkPredictionScore::CommonNeighbors,
+    LinkPredictionScore::AdamicAdar,
+    LinkPredictionScore::ResourceAllocation,
+];
+
+fn wrap_undigraph(matrix: SymmetricCSR2D<CSR2D<usize, usize, usize>>) -> UndiGraph<usize> {
+    let order = matrix.order();
+    let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+        .expected_number_of_symbols(order)
+        .symbols((0..order).enumerate())
+        .build()
+        .unwrap();
+    UndiGraph::from((nodes, matrix))
+}
+
+/// Scores a pair by enumerating every node as a potential common neighbour.
+#[allow(clippy::cast_precision_loss)]
+fn brute_force_score(
+    graph: &UndiGraph<usize>,
+    score: LinkPredictionScore,
+    source: usize,
+    destination: usize,
+) -> f64 {
+    graph
+        .node_ids()
+        .filter(|&common| {
+            common != source
+                && common != destination
+                && graph.neighbors(source).any(|neighbor| neighbor == common)
+                && graph.neighbors(destination).any(|neighbor| neighbor == common)
+        })
+        .map(|common| {
+            let degree = graph.neighbors(common).filter(|&neighbor| neighbor != common).count();
+            match score {
+                LinkPredictionScore::CommonNeighbors => 1.0,
+                LinkPredictionScore::AdamicAdar => 1.0 / (degree as f64).ln(),
+                LinkPredictionScore::ResourceAllocation => 1.0 / degree as f64,
+            }
+        })
+        .sum()
+}
+
+#[test]
+fn test_predict_links_matches_brute_force() {
+    let graph = wrap_undigraph(erdos_renyi_gnp(9, 60, 0.08));
+    for score in SCORES {
+        let predicted = graph.predict_links(score, usize::MAX);
+
+        let mut expected = Vec::new();
+        for source in 0..60 {
+            for destination in source + 1..60 {
+                let value = brute_force_score(&graph, score, source, destination);
+                if !graph.neighbors(source).any(|neighbor| neighbor == destination) && value > 0.0 {
+                    expected.push((source, destination, value));
+                }
+            }
+        }
+        expected.sort_by(|left, right| right.2.total_cmp(&left.2));
+
+        assert!(!predicted.is_empty());
+        assert_eq!(predicted.len(), expected.len(), "{score:?}");
+        for (left, right) in predicted.iter().zip(&expected) {
+            assert!((left.2 - right.2).abs() < 1e-12, "{score:?}: {left:?} vs {right:?}");
+        }
+        assert!(predicted.windows(2).all(|pair| {
+            pair[0]
+                .2
+                .total_cmp(&pair[1].2)
+                .then((pair[1].0, pair[1].1).cmp(&(pair[0].0, pair[0].1)))
+                == core::cmp::Ordering::Greater
+        }));
+    }
+}
+
+#[test]
+fn test_predict_links_respects_budget() {
+    let graph = wrap_undigraph(erdos_renyi_gnp(4, 50, 0.1));
+    let all = graph.predict_links(LinkPredictionScore::AdamicAdar, usize::MAX);
+    let top = graph.predict_links(LinkPredictionScore::AdamicAdar, 10);
+    assert_eq!(top.as_slice(), &all[..10]);
+    assert!(graph.predict_links(LinkPredictionScore::AdamicAdar, 0).is_empty());
+}
+
+#[test]
+fn test_score_links_matches_brute_force() {
+    let graph = wrap_undigraph(erdos_renyi_gnp(21, 40, 0.15));
+    let candidates: Vec<(usize, usize)> = (0..40)
+        .flat_map(|source| (0..40).map(move |destination| (source, destination)))
+        .filter(|(source, destination)| source != destination)
+        .collect();
+    for score in SCORES {
+        let scored = graph.score_links(score, candidates.iter().copied());
+        assert_eq!(scored.len(), candidates.len());
+        for &(source, destination, value) in &scored {
+            let expected = brute_force_score(&graph, score, source, destination);
+            assert!((value - expected).abs() < 1e-12, "{score:?} ({source}, {destination})");
+        }
+        assert!(scored.windows(2).all(|pair| pair[0].2 >= pair[1].2));
+    }
+}
+
+#[test]
+fn test_score_links_keeps_candidate_order_on_ties() {
+    // All leaves of a star share the centre only.
+    let graph = wrap_undigraph(star_graph(6));
+    let scored =
+        graph.score_links(LinkPredictionScore::ResourceAllocation, [(4, 5), (0, 1), (2, 3)]);
+    assert_eq!(scored, vec![(4, 5, 0.2), (2, 3, 0.2), (0, 1, 0.0)]);
+
+    // The shared centre has five neighbours.
+    let adamic_adar = graph.score_links(LinkPredictionScore::AdamicAdar, [(1, 2)]);
+    assert!((adamic_adar[0].2 - 1.0 / 5.0_f64.ln()).abs() < 1e-12);
+}
+
+#[test]
+fn test_link_prediction_ignores_self_loops() {
+    let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+        .expected_number_of_symbols(3)
+        .symbols((0..3).enumerate())
+        .build()
+        .unwrap();
+    let edges: SymmetricCSR2D<_> = UndiEdgesBuilder::default()
+        .expected_number_of_edges(4)
+        .expected_shape(3)
+        .edges([(0, 0), (0, 1), (1, 1), (1, 2)].into_iter())
+        .build()
+        .unwrap();
+    let graph = UndiGraph::from((nodes, edges));
+    for score in SCORES {
+        let predicted = graph.predict_links(score, usize::MAX);
+        assert_eq!(predicted.len(), 1);
+        assert_eq!((predicted[0].0, predicted[0].1), (0, 2));
+    }
+    // Node 1 has the two neighbours 0 and 2 once its self-loop is ignored.
+    assert_eq!(graph.predict_links(LinkPredictionScore::ResourceAllocation, 1), vec![(0, 2, 0.5)]);
+}
@@ -0,0 +1,73 @@
+//! Tests for the community graphs kept by `Louvain::louvain_with_level_graphs`.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::ValuedCSR2D,
+    prelude::*,
+    traits::{LouvainConfig, Quotient},
+};
+
+type WeightedMatrix = ValuedCSR2D<usize, usize, usize, f64>;
+
+/// A ring of sixteen triangles, whose consecutive triangles are joined by a
+/// single edge, so that the triangles are merged over several levels.
+fn ring_of_triangles() -> WeightedMatrix {
+    let mut edges = Vec::new();
+    for triangle in 0..16 {
+        let first = 3 * triangle;
+        for (source, destination) in [
+            (first, first + 1),
+            (first, first + 2),
+            (first + 1, first + 2),
+            (first + 2, (first + 3) % 48),
+        ] {
+            edges.push((source, destination, 1.0));
+            edges.push((destination, source, 1.0));
+        }
+    }
+    edges.sort_unstable_by_key(|&(source, destination, _)| (source, destination));
+
+    GenericEdgesBuilder::<_, WeightedMatrix>::default()
+        .expected_number_of_edges(edges.len())
+        .expected_shape((48, 48))
+        .edges(edges.into_iter())
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_louvain_does_not_keep_level_graphs() {
+    let graph = ring_of_triangles();
+    let result = Louvain::<usize>::louvain(&graph, &LouvainConfig::default()).unwrap();
+    assert!(result.levels().iter().all(|level| level.graph().is_none()));
+}
+
+#[test]
+fn test_louvain_with_level_graphs_matches_louvain() {
+    let graph = ring_of_triangles();
+    let config = LouvainConfig::default();
+    let expected = Louvain::<usize>::louvain(&graph, &config).unwrap();
+    let result = Louvain::<usize>::louvain_with_level_graphs(&graph, &config).unwrap();
+
+    assert_eq!(result.final_partition(), expected.final_partition());
+    assert_eq!(result.final_modularity().to_bits(), expected.final_modularity().to_bits());
+    assert_eq!(result.levels().len(), expected.levels().len());
+    assert!(result.levels().len() > 1);
+}
+
+#[test]
+fn test_louvain_level_graphs_are_the_quotients_of_the_partitions() {
+    let graph = ring_of_triangles();
+    let result =
+        Louvain::<usize>::louvain_with_level_graphs(&graph, &LouvainConfig::default()).unwrap();
+
+    for level in result.levels() {
+        let level_graph = level.graph().unwrap();
+        let (quotient, communities) = graph.quotient(level.partition()).unwrap();
+        assert_eq!(communities, (0..communities.len()).collect::<Vec<_>>());
+        assert_eq!(*level_graph, quotient);
+        // The weight of the graph is preserved at every level.
+        let total: f64 = level_graph.sparse_values().sum();
+        assert!((total - 128.0).abs() < 1e-9);
+    }
+}
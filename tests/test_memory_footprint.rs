@@ -0,0 +1,73 @@
+//! Tests for the memory usage reports and the release of spare capacity.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::{CSR2D, SortedVec, SquareCSR2D, SymmetricCSR2D, ValuedCSR2D},
+    prelude::*,
+    traits::algorithms::randomized_graphs::erdos_renyi_gnp,
+};
+
+fn buffer_names(usage: &MemoryUsage) -> Vec<&'static str> {
+    usage.buffers().iter().map(|buffer| buffer.name).collect()
+}
+
+#[test]
+fn test_buffer_usage_of_vec() {
+    let mut buffer: Vec<u32> = Vec::with_capacity(10);
+    buffer.extend([1, 2, 3]);
+    let usage = BufferUsage::of_vec("elements", &buffer);
+    assert_eq!(usage.used_bytes, 12);
+    assert_eq!(usage.allocated_bytes, 40);
+    assert_eq!(usage.spare_bytes(), 28);
+
+    let report = buffer.memory_usage();
+    assert_eq!(report.buffers(), &[usage]);
+    MemoryFootprint::shrink_to_fit(&mut buffer);
+    assert_eq!(buffer.memory_usage().spare_bytes(), 0);
+}
+
+#[test]
+fn test_overestimated_valued_csr2d_is_shrunk() {
+    let mut matrix: ValuedCSR2D<u32, u16, u16, f64> =
+        SparseMatrixMut::with_sparse_shaped_capacity((100, 100), 10_000);
+    for row in 0..100_u16 {
+        MatrixMut::add(&mut matrix, (row, (row * 7) % 100, f64::from(row))).unwrap();
+    }
+
+    let before = matrix.memory_usage();
+    assert_eq!(buffer_names(&before), ["offsets", "column_indices", "values"]);
+    assert_eq!(before.used_bytes(), 101 * 4 + 100 * 2 + 100 * 8);
+    assert!(before.allocated_bytes() >= 10_000 * (2 + 8));
+    assert_eq!(before.spare_bytes(), before.allocated_bytes() - before.used_bytes());
+
+    let expected = matrix.clone();
+    matrix.shrink_to_fit();
+    let after = matrix.memory_usage();
+    assert_eq!(after.used_bytes(), before.used_bytes());
+    assert_eq!(after.spare_bytes(), 0);
+    assert!(matrix == expected);
+}
+
+#[test]
+fn test_memory_usage_of_wrappers_and_graphs() {
+    let dag: SquareCSR2D<CSR2D<usize, usize, usize>> =
+        SparseMatrixMut::with_sparse_shaped_capacity(5, 50);
+    assert_eq!(buffer_names(&dag.memory_usage()), ["offsets", "column_indices"]);
+    assert!(dag.memory_usage().spare_bytes() > 0);
+
+    let edges: SymmetricCSR2D<CSR2D<usize, usize, usize>> = erdos_renyi_gnp(3, 40, 0.2);
+    let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+        .expected_number_of_symbols(40)
+        .symbols((0..40).enumerate())
+        .build()
+        .unwrap();
+    let mut graph: UndiGraph<usize> = UndiGraph::from((nodes, edges.clone()));
+    let usage = graph.memory_usage();
+    assert_eq!(buffer_names(&usage), ["elements", "offsets", "column_indices"]);
+    assert_eq!(usage.used_bytes(), 40 * size_of::<usize>() + edges.memory_usage().used_bytes());
+
+    graph.shrink_to_fit();
+    assert_eq!(graph.memory_usage().spare_bytes(), 0);
+    assert_eq!(graph.memory_usage().used_bytes(), usage.used_bytes());
+    assert_eq!(0_usize.memory_usage(), MemoryUsage::default());
+}
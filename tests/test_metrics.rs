@@ -0,0 +1,84 @@
+//! Tests for the operation counters reported with the `metrics` feature.
+#![cfg(all(feature = "std", feature = "metrics"))]
+
+use geometric_traits::{
+    impls::ValuedCSR2D,
+    prelude::*,
+    traits::{
+        LouvainConfig,
+        algorithms::randomized_graphs::{
+            Feasibility, SparseCostMatrixConfig, random_sparse_cost_matrix,
+        },
+    },
+};
+
+type TestValuedCSR2D = ValuedCSR2D<usize, usize, usize, f64>;
+
+fn build(shape: usize, edges: Vec<(usize, usize, f64)>) -> TestValuedCSR2D {
+    GenericEdgesBuilder::<_, TestValuedCSR2D>::default()
+        .expected_number_of_edges(edges.len())
+        .expected_shape((shape, shape))
+        .edges(edges.into_iter())
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_lapmod_metrics_match_the_plain_solver() {
+    for seed in 1..=10 {
+        let mut config = SparseCostMatrixConfig::new(40, 40, 0.2);
+        config.feasibility = Feasibility::Assignment;
+        let costs = random_sparse_cost_matrix(seed, &config);
+        let max_cost = costs.max_sparse_value().unwrap() * 2.0;
+
+        let (assignment, metrics) = costs.lapmod_with_metrics(max_cost).unwrap();
+        assert_eq!(assignment, costs.lapmod(max_cost).unwrap(), "seed {seed}");
+        assert!(metrics.augmentations <= 40, "seed {seed}");
+        assert!(metrics.row_reductions > 0, "seed {seed}");
+        if metrics.augmentations == 0 {
+            assert_eq!(metrics.scanned_columns, 0, "seed {seed}");
+        }
+        assert_eq!(costs.lapmod_with_metrics(max_cost).unwrap().1, metrics);
+    }
+
+    let empty = TestValuedCSR2D::default();
+    assert_eq!(empty.lapmod_with_metrics(1.0).unwrap(), (Vec::new(), LapmodMetrics::default()));
+}
+
+#[test]
+fn test_dijkstra_metrics_count_settled_nodes_and_relaxations() {
+    let graph = build(4, vec![(0, 1, 2.0), (0, 2, 10.0), (1, 2, 3.0), (2, 3, 4.0)]);
+    let (distances, metrics) = graph.pairwise_dijkstra_with_metrics().unwrap();
+    assert_eq!(distances, graph.pairwise_dijkstra().unwrap());
+
+    // Nodes reachable from each source: 4, 3, 2 and 1.
+    assert_eq!(metrics.settled_nodes, 10);
+    // The distance from 0 to 2 is lowered twice, its entry at 10 going stale.
+    assert_eq!(metrics.relaxations, 7);
+    assert_eq!(metrics.stale_entries, 1);
+}
+
+#[test]
+fn test_louvain_metrics_sum_the_moves_of_every_level() {
+    let graph = build(
+        4,
+        vec![
+            (0, 0, 0.1),
+            (0, 1, 1.0),
+            (1, 0, 1.0),
+            (1, 2, 0.1),
+            (2, 1, 0.1),
+            (2, 3, 1.0),
+            (3, 2, 1.0),
+            (3, 3, 0.1),
+        ],
+    );
+    let result = Louvain::<usize>::louvain(&graph, &LouvainConfig::default()).unwrap();
+    let metrics = result.metrics();
+    assert_eq!(
+        metrics.moved_nodes,
+        result.levels().iter().map(LouvainLevel::moved_nodes).sum::<usize>()
+    );
+    assert!(metrics.local_passes >= result.levels().len());
+    assert!(metrics.visited_nodes >= 4);
+}
@@ -0,0 +1,102 @@
+//! Tests for the minimum vertex-disjoint path cover of directed acyclic
+//! graphs.
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::random_dag;
+use geometric_traits::{
+    errors::GeometricError,
+    impls::{CSR2D, SquareCSR2D},
+    prelude::*,
+    traits::{EdgesBuilder, KahnError, MinimumPathCover, MinimumPathCoverError},
+};
+
+type TestDigraph = SquareCSR2D<CSR2D<usize, usize, usize>>;
+
+fn build_digraph(order: usize, edges: Vec<(usize, usize)>) -> TestDigraph {
+    DiEdgesBuilder::default()
+        .expected_number_of_edges(edges.len())
+        .expected_shape(order)
+        .edges(edges.into_iter())
+        .build()
+        .unwrap()
+}
+
+/// Returns the largest number of edges that can be selected with at most one
+/// selected edge leaving and entering each node, by exhaustive search.
+fn brute_force_maximum_chaining(digraph: &TestDigraph) -> usize {
+    let edges: Vec<(usize, usize)> = SparseMatrix::sparse_coordinates(digraph).collect();
+    assert!(edges.len() <= 16);
+    (0_u32..1 << edges.len())
+        .filter(|mask| {
+            let mut has_successor = vec![false; digraph.order()];
+            let mut has_predecessor = vec![false; digraph.order()];
+            edges.iter().enumerate().filter(|(index, _)| mask & (1 << index) != 0).all(
+                |(_, &(source, destination))| {
+                    !std::mem::replace(&mut has_successor[source], true)
+                        && !std::mem::replace(&mut has_predecessor[destination], true)
+                },
+            )
+        })
+        .map(|mask| mask.count_ones() as usize)
+        .max()
+        .unwrap_or(0)
+}
+
+fn assert_is_path_cover(digraph: &TestDigraph, paths: &[Vec<usize>]) {
+    let mut covered = vec![false; digraph.order()];
+    for path in paths {
+        assert!(!path.is_empty());
+        for &node in path {
+            assert!(!std::mem::replace(&mut covered[node], true), "node {node} covered twice");
+        }
+        for pair in path.windows(2) {
+            assert!(digraph.has_entry(pair[0], pair[1]), "missing edge {pair:?}");
+        }
+    }
+    assert!(covered.into_iter().all(|is_covered| is_covered));
+    assert!(paths.windows(2).all(|pair| pair[0][0] < pair[1][0]));
+}
+
+#[test]
+fn test_minimum_path_cover_of_random_dags() {
+    for seed in 1..=30 {
+        let digraph = random_dag(seed, 7, 0.3);
+        if digraph.number_of_defined_values() > 16 {
+            continue;
+        }
+        let paths = digraph.minimum_path_cover().unwrap();
+        assert_is_path_cover(&digraph, &paths);
+        assert_eq!(paths.len(), digraph.order() - brute_force_maximum_chaining(&digraph));
+    }
+    for seed in 1..=5 {
+        let digraph = random_dag(seed, 60, 0.05);
+        assert_is_path_cover(&digraph, &digraph.minimum_path_cover().unwrap());
+    }
+}
+
+#[test]
+fn test_minimum_path_cover_of_simple_dags() {
+    // A single chain is covered by one path, whatever its shortcuts.
+    let chain = build_digraph(4, vec![(0, 1), (0, 2), (1, 2), (2, 3)]);
+    assert_eq!(chain.minimum_path_cover().unwrap(), vec![vec![0, 1, 2, 3]]);
+
+    // The leaves of a star cannot share a path.
+    let star = build_digraph(4, vec![(0, 1), (0, 2), (0, 3)]);
+    assert_eq!(star.minimum_path_cover().unwrap().len(), 3);
+
+    let isolated = build_digraph(3, vec![]);
+    assert_eq!(isolated.minimum_path_cover().unwrap(), vec![vec![0], vec![1], vec![2]]);
+    assert!(build_digraph(0, vec![]).minimum_path_cover().unwrap().is_empty());
+}
+
+#[test]
+fn test_minimum_path_cover_rejects_cycles() {
+    let cycle = build_digraph(3, vec![(0, 1), (1, 2), (2, 0)]);
+    assert_eq!(cycle.minimum_path_cover(), Err(MinimumPathCoverError::Kahn(KahnError::Cycle)));
+
+    let self_loop = build_digraph(2, vec![(0, 1), (1, 1)]);
+    let error: GeometricError = self_loop.minimum_path_cover().unwrap_err().into();
+    assert_eq!(error.to_string(), "The graph contains a cycle.");
+}
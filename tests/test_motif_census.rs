@@ -0,0 +1,314 @@
+//! Tests for the motif and triad censuses.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::{CSR2D, SortedVec, SquareCSR2D, SymmetricCSR2D},
+    prelude::*,
+    traits::{
+        EdgesBuilder, MotifCensus, MotifCensusError, Triad, TriadCensus, UndirectedMotif,
+        VocabularyBuilder,
+        algorithms::randomized_graphs::{XorShift64, erdos_renyi_gnp},
+    },
+};
+
+fn undirected(order: usize, edges: &[(usize, usize)]) -> UndiGraph<usize> {
+    let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+        .expected_number_of_symbols(order)
+        .symbols((0..order).enumerate())
+        .build()
+        .unwrap();
+    let mut edges: Vec<(usize, usize)> =
+        edges.iter().map(|&(left, right)| (left.min(right), left.max(right))).collect();
+    edges.sort_unstable();
+    let edges: SymmetricCSR2D<CSR2D<usize, usize, usize>> = UndiEdgesBuilder::default()
+        .expected_number_of_edges(edges.len())
+        .expected_shape(order)
+        .edges(edges.into_iter())
+        .build()
+        .unwrap();
+    UndiGraph::from((nodes, edges))
+}
+
+fn directed(order: usize, edges: &[(usize, usize)]) -> DiGraph<usize> {
+    let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+        .expected_number_of_symbols(order)
+        .symbols((0..order).enumerate())
+        .build()
+        .unwrap();
+    let mut edges = edges.to_vec();
+    edges.sort_unstable();
+    let edges: SquareCSR2D<_> = DiEdgesBuilder::default()
+        .expected_number_of_edges(edges.len())
+        .expected_shape(order)
+        .edges(edges.into_iter())
+        .build()
+        .unwrap();
+    DiGraph::from((nodes, edges))
+}
+
+/// Returns the class of the subgraph induced by the provided nodes, if it is
+/// connected.
+fn classify(adjacent: &[Vec<bool>], subset: &[usize]) -> Option<UndirectedMotif> {
+    let degrees: Vec<usize> = subset
+        .iter()
+        .map(|&node| subset.iter().filter(|&&other| adjacent[node][other]).count())
+        .collect();
+    let mut reached = vec![subset[0]];
+    let mut index = 0;
+    while index < reached.len() {
+        let node = reached[index];
+        index += 1;
+        for &other in subset {
+            if adjacent[node][other] && !reached.contains(&other) {
+                reached.push(other);
+            }
+        }
+    }
+    if reached.len() < subset.len() {
+        return None;
+    }
+    let edges = degrees.iter().sum::<usize>() / 2;
+    let largest_degree = degrees.iter().copied().max().unwrap();
+    Some(match (subset.len(), edges, largest_degree) {
+        (3, 2, _) => UndirectedMotif::OpenTriad,
+        (3, 3, _) => UndirectedMotif::Triangle,
+        (4, 3, 2) => UndirectedMotif::Path,
+        (4, 3, 3) => UndirectedMotif::Star,
+        (4, 4, 2) => UndirectedMotif::Cycle,
+        (4, 4, 3) => UndirectedMotif::Paw,
+        (4, 5, _) => UndirectedMotif::Diamond,
+        (4, 6, _) => UndirectedMotif::Clique,
+        _ => unreachable!(),
+    })
+}
+
+#[test]
+#[allow(clippy::cast_precision_loss, clippy::float_cmp)]
+fn test_motif_census_matches_brute_force() {
+    for seed in 1..=6 {
+        let order = 14;
+        let matrix = erdos_renyi_gnp(seed, order, 0.3);
+        let edges: Vec<(usize, usize)> = SparseMatrix::sparse_coordinates(&matrix)
+            .filter(|&(left, right)| left < right)
+            .collect();
+        let graph = undirected(order, &edges);
+        let mut adjacent = vec![vec![false; order]; order];
+        for &(left, right) in &edges {
+            adjacent[left][right] = true;
+            adjacent[right][left] = true;
+        }
+
+        let mut expected = [0_usize; 8];
+        for a in 0..order {
+            for b in a + 1..order {
+                for c in b + 1..order {
+                    if let Some(motif) = classify(&adjacent, &[a, b, c]) {
+                        expected[motif as usize] += 1;
+                    }
+                    for d in c + 1..order {
+                        if let Some(motif) = classify(&adjacent, &[a, b, c, d]) {
+                            expected[motif as usize] += 1;
+                        }
+                    }
+                }
+            }
+        }
+
+        let census = graph.motif_census(3).unwrap();
+        assert!(!census.is_sampled());
+        assert_eq!(census.iter().count(), 2);
+        for motif in UndirectedMotif::THREE_NODES {
+            assert_eq!(census.count(motif), expected[motif as usize] as f64, "seed {seed}");
+        }
+        let census = graph.motif_census(4).unwrap();
+        assert_eq!(census.iter().count(), 6);
+        assert_eq!(census.count(UndirectedMotif::Triangle), 0.0);
+        for motif in UndirectedMotif::FOUR_NODES {
+            assert_eq!(census.count(motif), expected[motif as usize] as f64, "seed {seed}");
+        }
+    }
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_motif_census_of_special_graphs() {
+    // Self-loops are ignored.
+    let graph = undirected(5, &[(0, 0), (0, 1), (0, 2), (0, 3), (0, 4), (1, 2)]);
+    let census = graph.motif_census(3).unwrap();
+    assert_eq!(census.count(UndirectedMotif::Triangle), 1.0);
+    assert_eq!(census.count(UndirectedMotif::OpenTriad), 5.0);
+    let census = graph.motif_census(4).unwrap();
+    assert_eq!(census.count(UndirectedMotif::Star), 2.0);
+    assert_eq!(census.count(UndirectedMotif::Paw), 2.0);
+    assert_eq!(census.total(), 4.0);
+
+    let clique = undirected(
+        5,
+        &[(0, 1), (0, 2), (0, 3), (0, 4), (1, 2), (1, 3), (1, 4), (2, 3), (2, 4), (3, 4)],
+    );
+    assert_eq!(clique.motif_census(3).unwrap().count(UndirectedMotif::Triangle), 10.0);
+    assert_eq!(clique.motif_census(4).unwrap().count(UndirectedMotif::Clique), 5.0);
+
+    let empty = undirected(3, &[]);
+    assert_eq!(empty.motif_census(3).unwrap().total(), 0.0);
+
+    assert_eq!(graph.motif_census(2), Err(MotifCensusError::UnsupportedSize(2)));
+    assert_eq!(graph.motif_census(5), Err(MotifCensusError::UnsupportedSize(5)));
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_sampled_motif_census() {
+    let matrix = erdos_renyi_gnp(7, 60, 0.1);
+    let edges: Vec<(usize, usize)> =
+        SparseMatrix::sparse_coordinates(&matrix).filter(|&(left, right)| left < right).collect();
+    let graph = undirected(60, &edges);
+
+    // Without pruning the sampling is the exact census.
+    let exact = graph.motif_census(4).unwrap();
+    let sampled = graph.sampled_motif_census(4, &[1.0; 4], 3).unwrap();
+    assert!(sampled.is_sampled());
+    for (motif, count) in exact.iter() {
+        assert_eq!(sampled.count(motif), count);
+    }
+
+    let probabilities = [1.0, 1.0, 0.8, 0.5];
+    let sampled = graph.sampled_motif_census(4, &probabilities, 3).unwrap();
+    assert_eq!(sampled, graph.sampled_motif_census(4, &probabilities, 3).unwrap());
+    assert_ne!(sampled, graph.sampled_motif_census(4, &probabilities, 4).unwrap());
+
+    // The mean of many estimates approaches the exact count.
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(9));
+    let runs = 200;
+    let mut total = 0.0;
+    for _ in 0..runs {
+        let seed = rng.next().unwrap();
+        total += graph.sampled_motif_census(4, &probabilities, seed).unwrap().total();
+    }
+    let mean = total / f64::from(runs);
+    assert!((mean - exact.total()).abs() < 0.05 * exact.total(), "{mean} {}", exact.total());
+
+    assert_eq!(
+        graph.sampled_motif_census(3, &[1.0, 0.5], 1),
+        Err(MotifCensusError::ProbabilityCountMismatch { expected: 3, actual: 2 })
+    );
+    assert_eq!(
+        graph.sampled_motif_census(3, &[1.0, 0.0, 1.0], 1),
+        Err(MotifCensusError::InvalidProbability { level: 1 })
+    );
+    assert_eq!(
+        graph.sampled_motif_census(3, &[1.0, 1.0, f64::NAN], 1),
+        Err(MotifCensusError::InvalidProbability { level: 2 })
+    );
+}
+
+/// Returns the sorted out- and in-degrees of the triad of the provided
+/// nodes, which identify its class.
+fn signature(adjacent: &[Vec<bool>], triad: [usize; 3]) -> Vec<(usize, usize)> {
+    let mut degrees: Vec<(usize, usize)> = triad
+        .iter()
+        .map(|&node| {
+            let out = triad.iter().filter(|&&other| adjacent[node][other]).count();
+            let into = triad.iter().filter(|&&other| adjacent[other][node]).count();
+            (out, into)
+        })
+        .collect();
+    degrees.sort_unstable();
+    degrees
+}
+
+/// Canonical triads on the nodes 0, 1 and 2, labelled A, B and C.
+fn canonical_triads() -> Vec<(Triad, Vec<(usize, usize)>)> {
+    vec![
+        (Triad::T003, vec![]),
+        (Triad::T012, vec![(0, 1)]),
+        (Triad::T102, vec![(0, 1), (1, 0)]),
+        (Triad::T021D, vec![(1, 0), (1, 2)]),
+        (Triad::T021U, vec![(0, 1), (2, 1)]),
+        (Triad::T021C, vec![(0, 1), (1, 2)]),
+        (Triad::T111D, vec![(0, 1), (1, 0), (2, 1)]),
+        (Triad::T111U, vec![(0, 1), (1, 0), (1, 2)]),
+        (Triad::T030T, vec![(0, 1), (2, 1), (0, 2)]),
+        (Triad::T030C, vec![(0, 1), (1, 2), (2, 0)]),
+        (Triad::T201, vec![(0, 1), (1, 0), (1, 2), (2, 1)]),
+        (Triad::T120D, vec![(1, 0), (1, 2), (0, 2), (2, 0)]),
+        (Triad::T120U, vec![(0, 1), (2, 1), (0, 2), (2, 0)]),
+        (Triad::T120C, vec![(0, 1), (1, 2), (0, 2), (2, 0)]),
+        (Triad::T210, vec![(0, 1), (1, 2), (2, 1), (0, 2), (2, 0)]),
+        (Triad::T300, vec![(0, 1), (1, 0), (1, 2), (2, 1), (0, 2), (2, 0)]),
+    ]
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_triad_census_of_canonical_triads() {
+    for (triad, edges) in canonical_triads() {
+        // Every relabelling of the triad belongs to the same class.
+        for permutation in [[0, 1, 2], [0, 2, 1], [1, 0, 2], [1, 2, 0], [2, 0, 1], [2, 1, 0]] {
+            let edges: Vec<(usize, usize)> = edges
+                .iter()
+                .map(|&(source, destination)| (permutation[source], permutation[destination]))
+                .collect();
+            let census = directed(3, &edges).triad_census();
+            assert_eq!(census.count(triad), 1.0, "{triad:?} {permutation:?}");
+            assert_eq!(census.total(), 1.0);
+        }
+    }
+}
+
+#[test]
+#[allow(clippy::cast_precision_loss, clippy::float_cmp)]
+fn test_triad_census_matches_brute_force() {
+    let classes: Vec<(Triad, Vec<(usize, usize)>)> = canonical_triads()
+        .into_iter()
+        .map(|(triad, edges)| {
+            let mut adjacent = vec![vec![false; 3]; 3];
+            for (source, destination) in edges {
+                adjacent[source][destination] = true;
+            }
+            (triad, signature(&adjacent, [0, 1, 2]))
+        })
+        .collect();
+
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(17));
+    for order in [0, 1, 2, 3, 8, 15] {
+        let mut adjacent = vec![vec![false; order]; order];
+        let mut edges = Vec::new();
+        for (source, row) in adjacent.iter_mut().enumerate() {
+            for (destination, edge) in row.iter_mut().enumerate() {
+                if rng.next().unwrap() % 4 == 0 {
+                    edges.push((source, destination));
+                    *edge = source != destination;
+                }
+            }
+        }
+        let census = directed(order, &edges).triad_census();
+
+        let mut expected = [0_usize; 16];
+        for a in 0..order {
+            for b in a + 1..order {
+                for c in b + 1..order {
+                    let triad_signature = signature(&adjacent, [a, b, c]);
+                    let (triad, _) =
+                        classes.iter().find(|(_, class)| *class == triad_signature).unwrap();
+                    expected[*triad as usize] += 1;
+                }
+            }
+        }
+        for (triad, count) in census.iter() {
+            assert_eq!(count, expected[triad as usize] as f64, "order {order}, {triad:?}");
+        }
+    }
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_triad_census_of_undirected_graphs() {
+    let graph = undirected(5, &[(0, 1), (1, 2), (0, 2), (2, 3)]);
+    let census = graph.triad_census();
+    assert_eq!(census.count(Triad::T300), 1.0);
+    assert_eq!(census.count(Triad::T201), 2.0);
+    assert_eq!(census.count(Triad::T102), 5.0);
+    assert_eq!(census.count(Triad::T003), 2.0);
+    assert_eq!(census.total(), 10.0);
+}
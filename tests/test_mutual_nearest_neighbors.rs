@@ -0,0 +1,91 @@
+//! Tests for the `MutualNearestNeighbors` trait over sparse cost matrices.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::ValuedCSR2D,
+    prelude::*,
+    traits::algorithms::randomized_graphs::{
+        CostDistribution, SparseCostMatrixConfig, random_sparse_cost_matrix,
+    },
+};
+
+type Costs = ValuedCSR2D<usize, usize, usize, f64>;
+
+/// Returns the pairs whose cost is the smallest of both their row and their
+/// column, with ties broken towards the smaller index, by scanning the whole
+/// matrix for every entry.
+fn naive_mutual_nearest_neighbors(costs: &Costs) -> Vec<(usize, usize)> {
+    let entries: Vec<(usize, usize, f64)> = costs
+        .row_indices()
+        .flat_map(|row| {
+            costs.sparse_row(row).zip(costs.sparse_row_values(row)).map(move |(c, v)| (row, c, v))
+        })
+        .collect();
+    let best = |candidates: Vec<(usize, f64)>| {
+        candidates
+            .into_iter()
+            .min_by(|left, right| left.1.total_cmp(&right.1).then(left.0.cmp(&right.0)))
+            .map(|(index, _)| index)
+    };
+    entries
+        .iter()
+        .filter(|&&(row, column, _)| {
+            best(entries.iter().filter(|e| e.0 == row).map(|e| (e.1, e.2)).collect())
+                == Some(column)
+                && best(entries.iter().filter(|e| e.1 == column).map(|e| (e.0, e.2)).collect())
+                    == Some(row)
+        })
+        .map(|&(row, column, _)| (row, column))
+        .collect()
+}
+
+#[test]
+fn test_mutual_nearest_neighbors_matches_naive_scan() {
+    for seed in 1..=20 {
+        let mut config = SparseCostMatrixConfig::new(25, 35, 0.15);
+        if seed % 2 == 0 {
+            // Few distinct costs, so that ties are frequent.
+            config.costs = CostDistribution::Grid { levels: 3, scale: 1.0 };
+        }
+        let costs = random_sparse_cost_matrix(seed, &config);
+        let pairs = costs.mutual_nearest_neighbors();
+        assert_eq!(pairs, naive_mutual_nearest_neighbors(&costs), "seed {seed}");
+
+        // The pairs form a matching.
+        let mut columns: Vec<usize> = pairs.iter().map(|&(_, column)| column).collect();
+        columns.sort_unstable();
+        columns.dedup();
+        assert_eq!(columns.len(), pairs.len());
+    }
+}
+
+#[test]
+fn test_mutual_nearest_neighbors_resolves_ties_towards_smaller_indices() {
+    let costs: Costs = ValuedCSR2D::try_from([[1.0, 1.0], [1.0, 1.0]]).unwrap();
+    assert_eq!(costs.mutual_nearest_neighbors(), vec![(0, 0)]);
+
+    let costs: Costs = ValuedCSR2D::try_from([[2.0, 1.0, 1.0], [5.0, 3.0, 1.0]]).unwrap();
+    // Column 2 is tied between both rows and prefers row 0, which itself
+    // prefers column 1, so row 1 is left without partner.
+    assert_eq!(costs.mutual_nearest_neighbors(), vec![(0, 1)]);
+}
+
+#[test]
+fn test_mutual_nearest_neighbors_of_sparse_and_degenerate_matrices() {
+    let mut costs: Costs = SparseMatrixMut::with_sparse_shaped_capacity((4, 5), 3);
+    for entry in [(0, 3, 2.0), (2, 3, 1.0), (3, 4, 7.0)] {
+        MatrixMut::add(&mut costs, entry).unwrap();
+    }
+    // Row 1 and columns 0 to 2 have no entry, so they have no partner.
+    assert_eq!(costs.mutual_nearest_neighbors(), vec![(2, 3), (3, 4)]);
+
+    let empty: Costs = SparseMatrixMut::with_sparse_shaped_capacity((3, 3), 0);
+    assert!(empty.mutual_nearest_neighbors().is_empty());
+
+    // NaN costs are larger than any other cost.
+    let costs: Costs = ValuedCSR2D::try_from([[f64::NAN, 4.0], [3.0, f64::NAN]]).unwrap();
+    assert_eq!(costs.mutual_nearest_neighbors(), vec![(0, 1), (1, 0)]);
+
+    let costs: ValuedCSR2D<u8, u8, u8, u32> = ValuedCSR2D::try_from([[3, 1], [2, 5]]).unwrap();
+    assert_eq!(costs.mutual_nearest_neighbors(), vec![(0, 1), (1, 0)]);
+}
@@ -0,0 +1,69 @@
+//! Tests for the conversions between the matrices of this crate and the
+//! `nalgebra` dense matrices.
+#![cfg(feature = "nalgebra")]
+
+use geometric_traits::{impls::VecMatrix2D, prelude::*};
+use nalgebra::DMatrix;
+
+type TestValuedCSR2D = ValuedCSR2D<usize, usize, usize, f64>;
+
+fn sample_valued() -> TestValuedCSR2D {
+    let mut matrix: TestValuedCSR2D = SparseMatrixMut::with_sparse_shape((3, 4));
+    MatrixMut::add(&mut matrix, (0, 1, 1.5)).unwrap();
+    MatrixMut::add(&mut matrix, (0, 3, -1.0)).unwrap();
+    MatrixMut::add(&mut matrix, (2, 0, 4.0)).unwrap();
+    matrix
+}
+
+#[test]
+fn test_valued_csr2d_to_dmatrix_uses_fill_value() {
+    let dense = sample_valued().to_dmatrix(0.0);
+    assert_eq!(
+        dense,
+        DMatrix::from_row_slice(
+            3,
+            4,
+            &[0.0, 1.5, 0.0, -1.0, 0.0, 0.0, 0.0, 0.0, 4.0, 0.0, 0.0, 0.0]
+        )
+    );
+
+    let dense = sample_valued().to_dmatrix(f64::NAN);
+    assert!(dense[(1, 2)].is_nan());
+}
+
+#[test]
+fn test_valued_csr2d_from_dmatrix_round_trip() {
+    let matrix = sample_valued();
+    let restored = TestValuedCSR2D::try_from_dmatrix(&matrix.to_dmatrix(0.0), &0.0).unwrap();
+    assert_eq!(restored, matrix);
+
+    let restored =
+        TestValuedCSR2D::try_from_dmatrix(&matrix.to_dmatrix(f64::INFINITY), &f64::INFINITY)
+            .unwrap();
+    assert_eq!(restored, matrix);
+}
+
+#[test]
+fn test_valued_csr2d_try_from_dmatrix_stores_every_entry() {
+    let dense = DMatrix::from_row_slice(2, 2, &[1.0, 0.0, 0.0, 2.0]);
+    let matrix = TestValuedCSR2D::try_from(dense).unwrap();
+    assert_eq!(matrix, TestValuedCSR2D::try_from([[1.0, 0.0], [0.0, 2.0]]).unwrap());
+    assert_eq!(matrix.number_of_defined_values(), 4);
+}
+
+#[test]
+fn test_valued_csr2d_from_dmatrix_rejects_overflowing_shape() {
+    let dense = DMatrix::from_element(300, 1, 1_u32);
+    assert!(ValuedCSR2D::<u16, u8, u8, u32>::try_from(dense.clone()).is_err());
+    assert!(ValuedCSR2D::<u16, u8, u8, u32>::try_from_dmatrix(&dense, &1).is_err());
+}
+
+#[test]
+fn test_vec_matrix2d_dmatrix_round_trip() {
+    let matrix = VecMatrix2D::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    let dense: DMatrix<i32> = matrix.clone().into();
+    assert_eq!(dense, matrix.to_dmatrix());
+    assert_eq!(dense[(0, 2)], 3);
+    assert_eq!(dense[(1, 1)], 5);
+    assert_eq!(VecMatrix2D::from(dense), matrix);
+}
@@ -0,0 +1,389 @@
+//! Integration tests for similarities between the sparse rows of a matrix.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::{CSR2D, SymmetricCSR2D, ValuedCSR2D},
+    prelude::*,
+    traits::{
+        CosineSimilarity, DiceSimilarity, JaccardSimilarity, MatrixMut, MinHashSignatures,
+        OverlapSimilarity, ScalarSimilarity, SparseMatrix, SparseMatrixMut, TopKSimilarity,
+        algorithms::randomized_graphs::{
+            SparseCostMatrixConfig, XorShift64, complete_graph, erdos_renyi_gnp,
+            random_sparse_cost_matrix, star_graph,
+        },
+    },
+};
+
+/// Returns the entries of a similarity matrix.
+fn entries(similarities: &ValuedCSR2D<usize, usize, usize, f64>) -> Vec<(usize, usize, f64)> {
+    SparseMatrix::sparse_coordinates(similarities)
+        .zip(similarities.sparse_values())
+        .map(|((row, column), value)| (row, column, value))
+        .collect()
+}
+
+/// Ranks by brute force every other row sharing a column with `item`, as
+/// `top_k_similar` should.
+fn brute_force_top_k<S, M>(similarity: &S, matrix: &M, item: usize, k: usize) -> Vec<(usize, f64)>
+where
+    S: ScalarSimilarity<usize, usize, Similarity = f64>,
+    M: SparseMatrix2D<RowIndex = usize, ColumnIndex = usize>,
+{
+    let mut scored: Vec<(usize, f64)> = (0..matrix.number_of_rows())
+        .filter(|&other| {
+            other != item && matrix.sparse_row(item).any(|column| matrix.has_entry(other, column))
+        })
+        .map(|other| (other, similarity.similarity(&item, &other)))
+        .filter(|&(_, score)| score > 0.0)
+        .collect();
+    scored.sort_by(|left, right| right.1.total_cmp(&left.1).then(left.0.cmp(&right.0)));
+    scored.truncate(k);
+    scored
+}
+
+#[test]
+fn test_jaccard_similarity_of_complete_graph() {
+    // Two nodes of K5 share three neighbours out of five.
+    let graph: SymmetricCSR2D<CSR2D<usize, usize, usize>> = complete_graph(5);
+    let jaccard = JaccardSimilarity::new(&graph);
+    assert!((jaccard.similarity(&0, &1) - 3.0 / 5.0).abs() < f64::EPSILON);
+    assert!((jaccard.similarity(&2, &2) - 1.0).abs() < f64::EPSILON);
+
+    let similarities = jaccard.pairwise_similarities(0.0);
+    assert_eq!(similarities.number_of_defined_values(), 5 * 4);
+    assert!(entries(&similarities).iter().all(|&(_, _, value)| (value - 0.6).abs() < 1e-12));
+    assert_eq!(jaccard.pairwise_similarities(0.7).number_of_defined_values(), 0);
+}
+
+#[test]
+fn test_jaccard_similarity_of_star_leaves() {
+    // All leaves share the centre, which shares nothing with them.
+    let graph = star_graph(6);
+    let jaccard = JaccardSimilarity::new(&graph);
+    assert!((jaccard.similarity(&1, &5) - 1.0).abs() < f64::EPSILON);
+    assert!(jaccard.similarity(&0, &3).abs() < f64::EPSILON);
+
+    let similarities = jaccard.pairwise_similarities(0.5);
+    assert_eq!(similarities.number_of_defined_values(), 5 * 4);
+    assert!(
+        SparseMatrix::sparse_coordinates(&similarities).all(|(row, column)| row > 0 && column > 0)
+    );
+}
+
+#[test]
+fn test_jaccard_pairwise_matches_scalar_similarity() {
+    let graph = erdos_renyi_gnp(17, 80, 0.08);
+    let jaccard = JaccardSimilarity::new(&graph);
+    let threshold = 0.1;
+    let similarities = jaccard.pairwise_similarities(threshold);
+
+    let mut expected = Vec::new();
+    for left in 0..80 {
+        for right in 0..80 {
+            let similarity = jaccard.similarity(&left, &right);
+            if left != right && similarity > 0.0 && similarity >= threshold {
+                expected.push((left, right, similarity));
+            }
+        }
+    }
+    assert!(!expected.is_empty());
+    assert_eq!(entries(&similarities), expected);
+}
+
+#[test]
+fn test_jaccard_similarity_of_rectangular_rows() {
+    let matrix = random_sparse_cost_matrix(5, &SparseCostMatrixConfig::new(30, 50, 0.1));
+    let jaccard = JaccardSimilarity::new(&matrix);
+    let similarities = jaccard.pairwise_similarities(0.0);
+    assert_eq!(similarities.number_of_rows(), 30);
+    assert_eq!(similarities.number_of_columns(), 30);
+    for (left, right, similarity) in entries(&similarities) {
+        assert!((similarity - jaccard.similarity(&left, &right)).abs() < f64::EPSILON);
+        assert!((similarity - jaccard.similarity(&right, &left)).abs() < f64::EPSILON);
+    }
+}
+
+#[test]
+fn test_cosine_similarity_of_binary_rows_matches_set_cosine() {
+    // On 0/1 rows, the cosine reduces to |A∩B| / sqrt(|A||B|).
+    let graph = erdos_renyi_gnp(3, 40, 0.2);
+    let mut binary: ValuedCSR2D<usize, usize, usize, f64> =
+        SparseMatrixMut::with_sparse_shaped_capacity((40, 40), graph.number_of_defined_values());
+    for (row, column) in SparseMatrix::sparse_coordinates(&graph) {
+        MatrixMut::add(&mut binary, (row, column, 1.0)).unwrap();
+    }
+    let cosine = CosineSimilarity::new(&binary);
+    let jaccard = JaccardSimilarity::new(&graph);
+    for left in 0..40 {
+        for right in 0..40 {
+            let shared =
+                graph.sparse_row(left).filter(|column| graph.has_entry(right, *column)).count();
+            let expected = cosine_similarity(
+                shared,
+                graph.number_of_defined_values_in_row(left),
+                graph.number_of_defined_values_in_row(right),
+            );
+            if graph.number_of_defined_values_in_row(left) > 0
+                && graph.number_of_defined_values_in_row(right) > 0
+            {
+                assert!((cosine.similarity(&left, &right) - expected).abs() < 1e-12);
+            }
+        }
+    }
+    // Both batched similarities see the same candidate pairs.
+    let cosine_pairs: Vec<(usize, usize)> =
+        SparseMatrix::sparse_coordinates(&cosine.pairwise_similarities(0.0)).collect();
+    let jaccard_pairs: Vec<(usize, usize)> =
+        SparseMatrix::sparse_coordinates(&jaccard.pairwise_similarities(0.0)).collect();
+    assert_eq!(cosine_pairs, jaccard_pairs);
+}
+
+#[test]
+fn test_cosine_pairwise_matches_scalar_similarity() {
+    let matrix = random_sparse_cost_matrix(11, &SparseCostMatrixConfig::new(60, 25, 0.15));
+    let cosine = CosineSimilarity::new(&matrix);
+    let threshold = 0.3;
+    let similarities = entries(&cosine.pairwise_similarities(threshold));
+    assert!(!similarities.is_empty());
+
+    let mut expected = Vec::new();
+    for left in 0..60 {
+        for right in 0..60 {
+            let shares_a_column =
+                matrix.sparse_row(left).any(|column| matrix.has_entry(right, column));
+            let similarity = cosine.similarity(&left, &right);
+            if left != right && shares_a_column && similarity >= threshold {
+                expected.push((left, right, similarity));
+            }
+        }
+    }
+    assert_eq!(similarities.len(), expected.len());
+    for ((row, column, value), (expected_row, expected_column, expected_value)) in
+        similarities.into_iter().zip(expected)
+    {
+        assert_eq!((row, column), (expected_row, expected_column));
+        assert!((value - expected_value).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn test_cosine_similarity_is_scale_invariant_and_handles_null_rows() {
+    let mut matrix: ValuedCSR2D<usize, usize, usize, f64> =
+        SparseMatrixMut::with_sparse_shaped_capacity((4, 3), 7);
+    for entry in [
+        (0, 0, 1.0),
+        (0, 2, 3.0),
+        (1, 0, 10.0),
+        (1, 2, 30.0),
+        (2, 0, 1.0),
+        (2, 1, -1.0),
+        (3, 1, 0.0),
+    ] {
+        MatrixMut::add(&mut matrix, entry).unwrap();
+    }
+    let cosine = CosineSimilarity::new(&matrix);
+    assert!((cosine.similarity(&0, &1) - 1.0).abs() < 1e-12);
+    assert!((cosine.similarity(&0, &2) - 1.0 / (10.0_f64.sqrt() * 2.0_f64.sqrt())).abs() < 1e-12);
+    // Row 3 only holds an explicit zero.
+    assert!(cosine.similarity(&3, &3).abs() < f64::EPSILON);
+    assert!(cosine.similarity(&2, &3).abs() < f64::EPSILON);
+
+    let similarities = cosine.pairwise_similarities(0.2);
+    assert_eq!(
+        SparseMatrix::sparse_coordinates(&similarities).collect::<Vec<_>>(),
+        vec![(0, 1), (0, 2), (1, 0), (1, 2), (2, 0), (2, 1)]
+    );
+}
+
+#[test]
+fn test_jaccard_top_k_matches_brute_force() {
+    let graph: SymmetricCSR2D<CSR2D<usize, usize, usize>> = erdos_renyi_gnp(17, 80, 0.06);
+    let jaccard = JaccardSimilarity::new(&graph);
+    for item in 0..80 {
+        for k in [0, 1, 5, usize::MAX] {
+            assert_eq!(
+                jaccard.top_k_similar(&item, k),
+                brute_force_top_k(&jaccard, &graph, item, k),
+                "item {item}, k {k}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_cosine_top_k_matches_brute_force_on_weighted_graph() {
+    let graph: SymmetricCSR2D<CSR2D<usize, usize, usize>> = erdos_renyi_gnp(23, 60, 0.08);
+    #[allow(clippy::cast_precision_loss)]
+    let entries: Vec<(usize, usize, f64)> = SparseMatrix::sparse_coordinates(&graph)
+        .filter(|&(row, column)| row <= column)
+        .map(|(row, column)| (row, column, ((row * 7 + column) % 9) as f64 - 2.0))
+        .collect();
+    let weighted: SymmetricCSR2D<ValuedCSR2D<usize, usize, usize, f64>> =
+        SymmetricCSR2D::from_sorted_upper_triangular_entries(60, entries).unwrap();
+    let cosine = CosineSimilarity::new(&weighted);
+    for item in 0..60 {
+        for k in [1, 3, usize::MAX] {
+            assert_eq!(
+                cosine.top_k_similar(&item, k),
+                brute_force_top_k(&cosine, &weighted, item, k),
+                "item {item}, k {k}"
+            );
+        }
+    }
+}
+
+/// Builds 60 random rows of 30 columns out of 2000, followed by 20 near
+/// duplicates of the first rows with three of their columns replaced.
+fn planted_near_duplicates() -> ValuedCSR2D<usize, usize, usize, f64> {
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(7));
+    let mut draw = || usize::try_from(rng.next().unwrap() % 2000).unwrap();
+    let mut rows: Vec<Vec<usize>> = Vec::new();
+    for _ in 0..60 {
+        let mut row: Vec<usize> = Vec::new();
+        while row.len() < 30 {
+            let column = draw();
+            if !row.contains(&column) {
+                row.push(column);
+            }
+        }
+        rows.push(row);
+    }
+    for original in 0..20 {
+        let mut row = rows[original].clone();
+        row.truncate(27);
+        while row.len() < 30 {
+            let column = draw();
+            if !row.contains(&column) && !rows[original].contains(&column) {
+                row.push(column);
+            }
+        }
+        rows.push(row);
+    }
+    let mut matrix: ValuedCSR2D<usize, usize, usize, f64> =
+        SparseMatrixMut::with_sparse_shaped_capacity((rows.len(), 2000), rows.len() * 30);
+    for (row_index, mut row) in rows.into_iter().enumerate() {
+        row.sort_unstable();
+        for column in row {
+            MatrixMut::add(&mut matrix, (row_index, column, 1.0)).unwrap();
+        }
+    }
+    matrix
+}
+
+#[test]
+fn test_minhash_estimates_jaccard_similarity() {
+    let matrix = planted_near_duplicates();
+    let jaccard = JaccardSimilarity::new(&matrix);
+    let signatures = MinHashSignatures::new(&matrix, 256, 3);
+    assert_eq!(signatures.signature_length(), 256);
+    for original in 0..20 {
+        // Near duplicates share 27 columns out of 33.
+        let exact = jaccard.similarity(&original, &(original + 60));
+        assert!((exact - 27.0 / 33.0).abs() < 1e-12);
+        let estimate = signatures.similarity(&original, &(original + 60));
+        assert!((estimate - exact).abs() < 0.15, "{estimate} vs {exact}");
+    }
+    assert!((signatures.similarity(&5, &5) - 1.0).abs() < f64::EPSILON);
+    assert!(signatures.similarity(&0, &1) < 0.1);
+}
+
+#[test]
+fn test_minhash_banding_retrieves_planted_near_duplicates() {
+    let matrix = planted_near_duplicates();
+    let signatures = MinHashSignatures::new(&matrix, 128, 11);
+    let threshold = 0.6;
+    let similarities = entries(&signatures.approximate_pairwise_similarities(32, threshold));
+    for original in 0..20 {
+        assert!(
+            similarities.iter().any(|&(left, right, _)| (left, right) == (original, original + 60))
+        );
+        assert!(
+            similarities.iter().any(|&(left, right, _)| (left, right) == (original + 60, original))
+        );
+    }
+    for &(left, right, similarity) in &similarities {
+        assert_ne!(left, right);
+        assert!(similarity >= threshold);
+        assert!((similarity - signatures.similarity(&left, &right)).abs() < f64::EPSILON);
+    }
+    assert_eq!(similarities.len(), 40);
+}
+
+#[test]
+fn test_minhash_is_seeded_and_skips_empty_rows() {
+    let matrix = random_sparse_cost_matrix(13, &SparseCostMatrixConfig::new(40, 30, 0.05));
+    let signatures = MinHashSignatures::new(&matrix, 64, 1);
+    assert_eq!(signatures, MinHashSignatures::new(&matrix, 64, 1));
+    assert_ne!(signatures, MinHashSignatures::new(&matrix, 64, 2));
+
+    // Two empty rows agree everywhere but are never candidates.
+    let empty: ValuedCSR2D<usize, usize, usize, f64> =
+        SparseMatrixMut::with_sparse_shaped_capacity((3, 5), 0);
+    let signatures = MinHashSignatures::new(&empty, 8, 0);
+    assert!((signatures.similarity(&0, &1) - 1.0).abs() < f64::EPSILON);
+    assert!(signatures.approximate_pairwise_similarities(8, 0.0).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "must divide the signature length")]
+fn test_minhash_bands_must_divide_signature_length() {
+    let matrix = random_sparse_cost_matrix(1, &SparseCostMatrixConfig::new(5, 5, 0.5));
+    let _ = MinHashSignatures::new(&matrix, 10, 0).approximate_pairwise_similarities(3, 0.5);
+}
+
+#[test]
+fn test_dice_and_overlap_pairwise_match_scalar_similarity() {
+    let matrix = random_sparse_cost_matrix(31, &SparseCostMatrixConfig::new(50, 40, 0.1));
+    let jaccard = JaccardSimilarity::new(&matrix);
+    let dice = DiceSimilarity::new(&matrix);
+    let overlap = OverlapSimilarity::new(&matrix);
+    for threshold in [0.0, 0.25, 0.5] {
+        for (similarities, scalar) in [
+            (
+                entries(&dice.pairwise_similarities(threshold)),
+                &dice as &dyn ScalarSimilarity<usize, usize, Similarity = f64>,
+            ),
+            (entries(&overlap.pairwise_similarities(threshold)), &overlap),
+        ] {
+            let mut expected = Vec::new();
+            for left in 0..50 {
+                for right in 0..50 {
+                    let shares_a_column =
+                        matrix.sparse_row(left).any(|column| matrix.has_entry(right, column));
+                    let similarity = scalar.similarity(&left, &right);
+                    if left != right && shares_a_column && similarity >= threshold {
+                        expected.push((left, right, similarity));
+                    }
+                }
+            }
+            assert_eq!(similarities.len(), expected.len());
+            for (found, wanted) in similarities.into_iter().zip(expected) {
+                assert_eq!((found.0, found.1), (wanted.0, wanted.1));
+                assert!((found.2 - wanted.2).abs() < 1e-12);
+            }
+        }
+    }
+    // Dice is a monotone transformation of Jaccard, and overlap bounds both.
+    for left in 0..50 {
+        for right in 0..50 {
+            let j = jaccard.similarity(&left, &right);
+            let d = dice.similarity(&left, &right);
+            assert!((d - 2.0 * j / (1.0 + j)).abs() < 1e-12);
+            assert!(overlap.similarity(&left, &right) >= d - 1e-12);
+        }
+    }
+}
+
+#[test]
+fn test_overlap_similarity_of_contained_rows() {
+    // The leaves of a star share the centre as their only neighbour, which
+    // the centre itself does not neighbour.
+    let graph: SymmetricCSR2D<CSR2D<usize, usize, usize>> = star_graph(6);
+    let overlap = OverlapSimilarity::new(&graph);
+    let dice = DiceSimilarity::new(&graph);
+    assert!((overlap.similarity(&1, &2) - 1.0).abs() < f64::EPSILON);
+    assert!((dice.similarity(&1, &2) - 1.0).abs() < f64::EPSILON);
+    assert!(overlap.similarity(&0, &1).abs() < f64::EPSILON);
+    assert_eq!(overlap.top_k_similar(&1, 2), vec![(2, 1.0), (3, 1.0)]);
+    assert_eq!(dice.top_k_similar(&0, 3), Vec::new());
+}
@@ -0,0 +1,183 @@
+//! Tests for the vertex cover and dominating set approximations.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::{CSR2D, SortedVec, SymmetricCSR2D},
+    prelude::*,
+    traits::{
+        DominatingSet, NodeCoverError, VertexCover, VocabularyBuilder,
+        algorithms::randomized_graphs::XorShift64,
+    },
+};
+
+/// Helper to build an undirected graph.
+fn build_undi_graph(node_count: usize, edges: Vec<(usize, usize)>) -> UndiGraph<usize> {
+    let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+        .expected_number_of_symbols(node_count)
+        .symbols((0..node_count).enumerate())
+        .build()
+        .unwrap();
+    let edge_count = edges.len();
+    let edges: SymmetricCSR2D<CSR2D<usize, usize, usize>> = UndiEdgesBuilder::default()
+        .expected_number_of_edges(edge_count)
+        .expected_shape(node_count)
+        .edges(edges.into_iter())
+        .build()
+        .unwrap();
+    UndiGraph::from((nodes, edges))
+}
+
+/// Returns a random graph, without self-loops, and random node weights.
+fn random_instance(seed: u64, order: usize) -> (Vec<(usize, usize)>, Vec<f64>) {
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(seed));
+    let mut random = |bound: usize| usize::try_from(rng.next().unwrap()).unwrap() % bound;
+    let mut edges: Vec<(usize, usize)> = (0..order + random(2 * order))
+        .map(|_| (random(order), random(order)))
+        .filter(|&(u, v)| u != v)
+        .map(|(u, v)| (u.min(v), u.max(v)))
+        .collect();
+    edges.sort_unstable();
+    edges.dedup();
+    let weights = (0..order).map(|_| f64::from(u32::try_from(random(6)).unwrap())).collect();
+    (edges, weights)
+}
+
+/// Returns the smallest total weight of the masks satisfying the predicate.
+fn optimal_weight(order: usize, weights: &[f64], is_valid: impl Fn(u32) -> bool) -> f64 {
+    (0..1_u32 << order)
+        .filter(|&mask| is_valid(mask))
+        .map(|mask| {
+            (0..order).filter(|&node| mask & (1 << node) != 0).map(|node| weights[node]).sum()
+        })
+        .fold(f64::INFINITY, f64::min)
+}
+
+/// Returns the mask of the nodes.
+fn mask_of(nodes: &[usize]) -> u32 {
+    nodes.iter().map(|&node| 1 << node).sum()
+}
+
+#[test]
+fn test_vertex_cover_on_random_graphs() {
+    let order = 9;
+    for seed in 0..100 {
+        let (edges, weights) = random_instance(seed, order);
+        let graph = build_undi_graph(order, edges.clone());
+        let covers = |mask: u32| edges.iter().all(|&(u, v)| mask & (1 << u | 1 << v) != 0);
+        let optimum = optimal_weight(order, &weights, covers);
+
+        for (cover, weights) in [
+            (graph.weighted_vertex_cover(&weights).unwrap(), weights.clone()),
+            (graph.vertex_cover(), vec![1.0; order]),
+        ] {
+            let nodes = cover.nodes();
+            assert!(nodes.windows(2).all(|pair| pair[0] < pair[1]));
+            let mask = mask_of(nodes);
+            assert!(covers(mask), "seed {seed}");
+            // The cover is minimal.
+            for &node in nodes {
+                assert!(!covers(mask & !(1 << node)), "seed {seed}");
+            }
+            let weight: f64 = nodes.iter().map(|&node| weights[node]).sum();
+            assert!((cover.weight() - weight).abs() < 1e-9);
+            assert!((cover.guarantee() - 2.0).abs() < f64::EPSILON);
+            assert!(cover.weight() <= 2.0 * cover.lower_bound() + 1e-9, "seed {seed}");
+            assert!(cover.approximation_ratio() <= cover.guarantee() + 1e-9);
+        }
+        let cover = graph.weighted_vertex_cover(&weights).unwrap();
+        assert!(cover.lower_bound() <= optimum + 1e-9, "seed {seed}");
+        assert!(cover.weight() <= 2.0 * optimum + 1e-9, "seed {seed}");
+    }
+}
+
+#[test]
+fn test_dominating_set_on_random_graphs() {
+    let order = 9;
+    for seed in 0..100 {
+        let (edges, weights) = random_instance(seed, order);
+        let graph = build_undi_graph(order, edges.clone());
+        let mut closed_neighborhoods: Vec<u32> = (0..order).map(|node| 1 << node).collect();
+        for &(u, v) in &edges {
+            closed_neighborhoods[u] |= 1 << v;
+            closed_neighborhoods[v] |= 1 << u;
+        }
+        let dominates =
+            |mask: u32| closed_neighborhoods.iter().all(|&neighborhood| neighborhood & mask != 0);
+        let optimum = optimal_weight(order, &weights, dominates);
+        let max_closed_degree = closed_neighborhoods
+            .iter()
+            .map(|neighborhood| neighborhood.count_ones())
+            .max()
+            .unwrap();
+        let harmonic: f64 = (1..=max_closed_degree).map(|count| 1.0 / f64::from(count)).sum();
+
+        for (set, weights) in [
+            (graph.weighted_dominating_set(&weights).unwrap(), weights.clone()),
+            (graph.dominating_set(), vec![1.0; order]),
+        ] {
+            let nodes = set.nodes();
+            assert!(nodes.windows(2).all(|pair| pair[0] < pair[1]));
+            let mask = mask_of(nodes);
+            assert!(dominates(mask), "seed {seed}");
+            for &node in nodes {
+                assert!(!dominates(mask & !(1 << node)), "seed {seed}");
+            }
+            let weight: f64 = nodes.iter().map(|&node| weights[node]).sum();
+            assert!((set.weight() - weight).abs() < 1e-9);
+            assert!((set.guarantee() - harmonic).abs() < 1e-12);
+            assert!(set.approximation_ratio() <= set.guarantee() + 1e-9, "seed {seed}");
+        }
+        let set = graph.weighted_dominating_set(&weights).unwrap();
+        assert!(set.lower_bound() <= optimum + 1e-9, "seed {seed}");
+        assert!(set.weight() <= harmonic * optimum + 1e-9, "seed {seed}");
+    }
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_node_covers_of_small_graphs() {
+    let empty = build_undi_graph(0, vec![]);
+    for cover in [empty.vertex_cover(), empty.dominating_set()] {
+        assert!(cover.nodes().is_empty());
+        assert_eq!(cover.weight(), 0.0);
+        assert_eq!(cover.approximation_ratio(), 1.0);
+    }
+
+    // A self-loop forces its node in the vertex cover, and isolated nodes
+    // dominate themselves.
+    let graph = build_undi_graph(4, vec![(0, 0), (1, 2)]);
+    let cover = graph.weighted_vertex_cover(&[5.0, 2.0, 3.0, 1.0]).unwrap();
+    assert_eq!(cover.nodes(), &[0, 1]);
+    assert_eq!(cover.weight(), 7.0);
+    assert_eq!(cover.lower_bound(), 7.0);
+    let set = graph.weighted_dominating_set(&[5.0, 2.0, 3.0, 1.0]).unwrap();
+    assert_eq!(set.nodes(), &[0, 1, 3]);
+    assert_eq!(set.weight(), 8.0);
+
+    // Zero-weight nodes come for free.
+    let path = build_undi_graph(3, vec![(0, 1), (1, 2)]);
+    let cover = path.weighted_vertex_cover(&[0.0, 4.0, 0.0]).unwrap();
+    assert_eq!(cover.nodes(), &[0, 2]);
+    assert_eq!(cover.weight(), 0.0);
+    assert_eq!(cover.approximation_ratio(), 1.0);
+    let set = path.weighted_dominating_set(&[1.0, 4.0, 1.0]).unwrap();
+    assert_eq!(set.nodes(), &[0, 2]);
+    assert_eq!(path.dominating_set().nodes(), &[1]);
+}
+
+#[test]
+fn test_node_cover_errors() {
+    let graph = build_undi_graph(3, vec![(0, 1), (1, 2)]);
+    assert_eq!(
+        graph.weighted_vertex_cover(&[1.0, 1.0]),
+        Err(NodeCoverError::WeightsLengthMismatch { expected: 3, found: 2 })
+    );
+    assert_eq!(
+        graph.weighted_dominating_set(&[1.0, -1.0, 1.0]),
+        Err(NodeCoverError::InvalidWeight { node_id: 1 })
+    );
+    assert_eq!(
+        graph.weighted_vertex_cover(&[1.0, 1.0, f64::INFINITY]),
+        Err(NodeCoverError::InvalidWeight { node_id: 2 })
+    );
+}
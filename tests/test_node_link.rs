@@ -0,0 +1,181 @@
+//! Tests for the JSON node-link format.
+#![cfg(feature = "json")]
+
+use geometric_traits::{
+    io::{DelimitedEdgeListReader, NodeLinkError, NodeLinkJson},
+    prelude::*,
+};
+use serde_json::{Value, json};
+
+fn node(graph: &impl MonopartiteGraph<NodeSymbol = String, NodeId = usize>, symbol: &str) -> usize {
+    graph.nodes_vocabulary().invert(&symbol.to_string()).unwrap()
+}
+
+fn to_value<G: NodeLinkJson>(graph: &G) -> Value {
+    let mut bytes = Vec::new();
+    graph.to_node_link_json(&mut bytes).unwrap();
+    serde_json::from_slice(&bytes).unwrap()
+}
+
+#[test]
+fn test_weighted_digraph_round_trip() {
+    let graph = DelimitedEdgeListReader::csv()
+        .weight_column(2)
+        .read_weighted_digraph("b,a,0.5\na,c,2\nc,b,-1.25\n".as_bytes())
+        .unwrap();
+
+    let value = to_value(&graph);
+    assert_eq!(
+        value,
+        json!({
+            "directed": true,
+            "multigraph": false,
+            "graph": {},
+            "nodes": [{"id": "a"}, {"id": "b"}, {"id": "c"}],
+            "links": [
+                {"source": "a", "target": "c", "weight": 2.0},
+                {"source": "b", "target": "a", "weight": 0.5},
+                {"source": "c", "target": "b", "weight": -1.25},
+            ],
+        })
+    );
+
+    let restored = WeightedDiGraph::<String>::from_node_link_json(
+        serde_json::to_vec(&value).unwrap().as_slice(),
+    )
+    .unwrap();
+    assert_eq!(restored, graph);
+}
+
+#[test]
+fn test_undigraph_lists_each_edge_once() {
+    let document = json!({
+        "directed": false,
+        "multigraph": false,
+        "graph": {"name": "ignored"},
+        "nodes": [{"id": "x", "color": "red"}, {"id": "y"}, {"id": "isolated"}],
+        "edges": [{"source": "y", "target": "x"}, {"source": "y", "target": "z"}],
+    });
+    let graph = UndiGraph::<String>::from_node_link_json(document.to_string().as_bytes()).unwrap();
+
+    assert_eq!(graph.number_of_nodes(), 4);
+    assert!(graph.has_successor(node(&graph, "x"), node(&graph, "y")));
+    assert!(graph.has_successor(node(&graph, "z"), node(&graph, "y")));
+    assert!(!graph.has_successor(node(&graph, "isolated"), node(&graph, "x")));
+
+    let value = to_value(&graph);
+    assert_eq!(value["links"].as_array().unwrap().len(), 2);
+    assert_eq!(value["nodes"].as_array().unwrap().len(), 4);
+    let restored = UndiGraph::<String>::from_node_link_json(value.to_string().as_bytes()).unwrap();
+    assert_eq!(restored, graph);
+}
+
+#[test]
+fn test_digraph_with_integer_identifiers() {
+    let document = r#"{"nodes": [{"id": 10}, {"id": 3}], "links": [{"source": 10, "target": 3}]}"#;
+    let graph = DiGraph::<u64>::from_node_link_json(document.as_bytes()).unwrap();
+    assert_eq!(graph.nodes_vocabulary().iter().copied().collect::<Vec<_>>(), vec![3, 10]);
+    assert!(graph.has_successor(1, 0));
+    assert!(!graph.has_successor(0, 1));
+    assert_eq!(to_value(&graph)["links"], json!([{"source": 10, "target": 3}]));
+}
+
+#[test]
+fn test_invalid_documents_are_rejected() {
+    let read = |document: Value| {
+        WeightedDiGraph::<String>::from_node_link_json(document.to_string().as_bytes())
+    };
+
+    assert_eq!(
+        read(json!({"directed": false, "nodes": [], "links": []})),
+        Err(NodeLinkError::DirectedMismatch { expected: true })
+    );
+    assert_eq!(
+        read(json!({"multigraph": true, "nodes": [], "links": []})),
+        Err(NodeLinkError::Multigraph)
+    );
+    assert_eq!(
+        read(json!({"nodes": [], "links": [
+            {"source": "a", "target": "b", "weight": 1.0},
+            {"source": "b", "target": "a"},
+        ]})),
+        Err(NodeLinkError::MissingWeight { link: 1 })
+    );
+    assert_eq!(
+        read(json!({"nodes": [], "links": [
+            {"source": "a", "target": "b", "weight": 1.0},
+            {"source": "b", "target": "c", "weight": 2.0},
+            {"source": "a", "target": "b", "weight": 3.0},
+        ]})),
+        Err(NodeLinkError::DuplicatedEdge { link: 2 })
+    );
+    assert!(matches!(read(json!({"links": []})), Err(NodeLinkError::Json { .. })));
+    assert!(matches!(
+        WeightedDiGraph::<String>::from_node_link_json(&b"{\"nodes\": ["[..]),
+        Err(NodeLinkError::Json { .. })
+    ));
+
+    let undirected = json!({"directed": false, "nodes": [], "links": [
+        {"source": "a", "target": "b"},
+        {"source": "b", "target": "a"},
+    ]});
+    assert_eq!(
+        UndiGraph::<String>::from_node_link_json(undirected.to_string().as_bytes()),
+        Err(NodeLinkError::DuplicatedEdge { link: 1 })
+    );
+}
+
+#[test]
+fn test_escaped_symbols_round_trip() {
+    let document = json!({
+        "directed": true,
+        "graph": {"nested": [[{"deep": null}], -1.5e3, true, "\u{1}"]},
+        "nodes": [{"id": "quote \" and \\ slash"}, {"id": "tab\tline\nbell\u{7}"}],
+        "links": [{"source": "quote \" and \\ slash", "target": "é 🧪", "weight": 1e-3}],
+    });
+    let graph =
+        WeightedDiGraph::<String>::from_node_link_json(document.to_string().as_bytes()).unwrap();
+    assert_eq!(graph.number_of_nodes(), 3);
+    assert!(graph.has_successor(node(&graph, "quote \" and \\ slash"), node(&graph, "é 🧪")));
+
+    let value = to_value(&graph);
+    assert_eq!(value["nodes"][1], json!({"id": "tab\tline\nbell\u{7}"}));
+    assert_eq!(value["links"][0]["weight"], json!(1e-3));
+    let restored =
+        WeightedDiGraph::<String>::from_node_link_json(value.to_string().as_bytes()).unwrap();
+    assert_eq!(restored, graph);
+
+    let surrogates = r#"{"nodes": [{"id": "\ud83e\uddea"}, {"id": "\u00e9"}]}"#;
+    let graph = DiGraph::<String>::from_node_link_json(surrogates.as_bytes()).unwrap();
+    assert_eq!(graph.nodes_vocabulary().iter().collect::<Vec<_>>(), vec!["é", "🧪"]);
+}
+
+#[test]
+fn test_malformed_json_is_rejected() {
+    for document in [
+        r#"{"nodes": []} trailing"#,
+        r#"{"nodes": [], "nodes": []}"#,
+        r#"{"nodes": [{"id": "a"}], "links": [{"source": "a"}]}"#,
+        r#"{"nodes": [{"id": 1}]}"#,
+        r#"{"nodes": [{"id": "\ud83e"}]}"#,
+        r#"{"nodes": [{"id": "a"}],}"#,
+        r#"{"nodes": [], "graph": 01}"#,
+    ] {
+        assert!(
+            matches!(
+                DiGraph::<String>::from_node_link_json(document.as_bytes()),
+                Err(NodeLinkError::Json { .. })
+            ),
+            "{document}"
+        );
+    }
+    let deep = format!(r#"{{"nodes": [], "graph": {}{}}}"#, "[".repeat(200), "]".repeat(200));
+    assert!(matches!(
+        DiGraph::<String>::from_node_link_json(deep.as_bytes()),
+        Err(NodeLinkError::Json { .. })
+    ));
+    assert!(matches!(
+        DiGraph::<u8>::from_node_link_json(r#"{"nodes": [{"id": 256}]}"#.as_bytes()),
+        Err(NodeLinkError::Json { .. })
+    ));
+}
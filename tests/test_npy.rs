@@ -0,0 +1,162 @@
+//! Tests for the NumPy `.npy` and `.npz` writers.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::VecMatrix2D,
+    io::{write_csr_npz, write_csr_pattern_npz, write_dense_npy, write_npy_vector},
+    prelude::*,
+};
+
+/// Splits a `.npy` array into its header dictionary and its data.
+fn parse_npy(bytes: &[u8]) -> (&str, &[u8]) {
+    assert_eq!(&bytes[..6], b"\x93NUMPY");
+    assert_eq!(&bytes[6..8], &[1, 0]);
+    let header_length = usize::from(u16::from_le_bytes([bytes[8], bytes[9]]));
+    let data_start = 10 + header_length;
+    assert_eq!(data_start % 64, 0, "The data must be 64-byte aligned");
+    let header = std::str::from_utf8(&bytes[10..data_start]).unwrap();
+    assert!(header.ends_with('\n'));
+    (header.trim_end(), &bytes[data_start..])
+}
+
+/// Returns the members of an uncompressed ZIP archive, in order.
+fn parse_stored_zip(bytes: &[u8]) -> Vec<(String, &[u8])> {
+    let u16_at = |at: usize| usize::from(u16::from_le_bytes([bytes[at], bytes[at + 1]]));
+    let u32_at = |at: usize| {
+        usize::try_from(u32::from_le_bytes(bytes[at..at + 4].try_into().unwrap())).unwrap()
+    };
+
+    let mut members = Vec::new();
+    let mut offset = 0;
+    while bytes[offset..].starts_with(b"PK\x03\x04") {
+        assert_eq!(u16_at(offset + 8), 0, "Members must be stored uncompressed");
+        let size = u32_at(offset + 18);
+        assert_eq!(size, u32_at(offset + 22));
+        let name_length = u16_at(offset + 26);
+        let name_start = offset + 30;
+        let content_start = name_start + name_length;
+        let name = std::str::from_utf8(&bytes[name_start..content_start]).unwrap();
+        members.push((name.to_string(), &bytes[content_start..content_start + size]));
+        offset = content_start + size;
+    }
+
+    let end = bytes.len() - 22;
+    assert!(bytes[end..].starts_with(b"PK\x05\x06"));
+    assert_eq!(u16_at(end + 10), members.len());
+    assert_eq!(u32_at(end + 16), offset, "The central directory follows the members");
+    assert_eq!(u32_at(end + 12), end - offset);
+    members
+}
+
+fn member<'a>(members: &'a [(String, &'a [u8])], name: &str) -> &'a [u8] {
+    members.iter().find(|(member, _)| member == name).unwrap().1
+}
+
+fn le_values<const N: usize>(data: &[u8]) -> Vec<[u8; N]> {
+    data.chunks_exact(N).map(|chunk| chunk.try_into().unwrap()).collect()
+}
+
+#[test]
+fn test_vector_npy_for_partitions_and_centralities() {
+    let mut bytes = Vec::new();
+    write_npy_vector(&[2_u32, 0, 1], &mut bytes).unwrap();
+    let (header, data) = parse_npy(&bytes);
+    assert_eq!(header, "{'descr': '<u4', 'fortran_order': False, 'shape': (3,), }");
+    assert_eq!(
+        le_values::<4>(data),
+        vec![2_u32.to_le_bytes(), 0_u32.to_le_bytes(), 1_u32.to_le_bytes()]
+    );
+
+    let mut bytes = Vec::new();
+    write_npy_vector(&[0.25_f64, -1.0], &mut bytes).unwrap();
+    let (header, data) = parse_npy(&bytes);
+    assert_eq!(header, "{'descr': '<f8', 'fortran_order': False, 'shape': (2,), }");
+    assert_eq!(le_values::<8>(data), vec![0.25_f64.to_le_bytes(), (-1.0_f64).to_le_bytes()]);
+
+    let mut bytes = Vec::new();
+    write_npy_vector::<usize, _>(&[], &mut bytes).unwrap();
+    let (header, data) = parse_npy(&bytes);
+    assert_eq!(header, "{'descr': '<u8', 'fortran_order': False, 'shape': (0,), }");
+    assert!(data.is_empty());
+}
+
+#[test]
+fn test_dense_npy_is_row_major() {
+    let matrix = VecMatrix2D::new(2, 3, vec![1_i16, 2, 3, 4, 5, 6]);
+    let mut bytes = Vec::new();
+    write_dense_npy(&matrix, &mut bytes).unwrap();
+    let (header, data) = parse_npy(&bytes);
+    assert_eq!(header, "{'descr': '<i2', 'fortran_order': False, 'shape': (2, 3), }");
+    assert_eq!(
+        le_values::<2>(data),
+        [1_i16, 2, 3, 4, 5, 6].iter().map(|value| value.to_le_bytes()).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_csr_npz_follows_scipy_layout() {
+    let mut matrix: ValuedCSR2D<u32, u16, u16, f32> = SparseMatrixMut::with_sparse_shape((4, 3));
+    MatrixMut::add(&mut matrix, (0, 1, 1.5)).unwrap();
+    MatrixMut::add(&mut matrix, (0, 2, 2.5)).unwrap();
+    MatrixMut::add(&mut matrix, (2, 0, -1.0)).unwrap();
+
+    let mut bytes = Vec::new();
+    write_csr_npz(&matrix, &mut bytes).unwrap();
+    let members = parse_stored_zip(&bytes);
+    assert_eq!(
+        members.iter().map(|(name, _)| name.as_str()).collect::<Vec<_>>(),
+        ["indices.npy", "indptr.npy", "format.npy", "shape.npy", "data.npy"]
+    );
+
+    let (header, data) = parse_npy(member(&members, "indptr.npy"));
+    assert_eq!(header, "{'descr': '<u4', 'fortran_order': False, 'shape': (5,), }");
+    // The trailing empty row is padded with the number of values.
+    assert_eq!(
+        le_values::<4>(data),
+        [0_u32, 2, 2, 3, 3].iter().map(|value| value.to_le_bytes()).collect::<Vec<_>>()
+    );
+
+    let (header, data) = parse_npy(member(&members, "indices.npy"));
+    assert_eq!(header, "{'descr': '<u2', 'fortran_order': False, 'shape': (3,), }");
+    assert_eq!(
+        le_values::<2>(data),
+        [1_u16, 2, 0].iter().map(|value| value.to_le_bytes()).collect::<Vec<_>>()
+    );
+
+    let (header, data) = parse_npy(member(&members, "data.npy"));
+    assert_eq!(header, "{'descr': '<f4', 'fortran_order': False, 'shape': (3,), }");
+    assert_eq!(
+        le_values::<4>(data),
+        [1.5_f32, 2.5, -1.0].iter().map(|value| value.to_le_bytes()).collect::<Vec<_>>()
+    );
+
+    let (header, data) = parse_npy(member(&members, "shape.npy"));
+    assert_eq!(header, "{'descr': '<u8', 'fortran_order': False, 'shape': (2,), }");
+    assert_eq!(le_values::<8>(data), vec![4_u64.to_le_bytes(), 3_u64.to_le_bytes()]);
+
+    let (header, data) = parse_npy(member(&members, "format.npy"));
+    assert_eq!(header, "{'descr': '|S3', 'fortran_order': False, 'shape': (), }");
+    assert_eq!(data, b"csr");
+}
+
+#[test]
+fn test_csr_pattern_npz_stores_boolean_data() {
+    let mut matrix: CSR2D<usize, usize, usize> = SparseMatrixMut::with_sparse_shape((2, 2));
+    MatrixMut::add(&mut matrix, (0, 0)).unwrap();
+    MatrixMut::add(&mut matrix, (1, 0)).unwrap();
+    MatrixMut::add(&mut matrix, (1, 1)).unwrap();
+
+    let mut bytes = Vec::new();
+    write_csr_pattern_npz(&matrix, &mut bytes).unwrap();
+    let members = parse_stored_zip(&bytes);
+
+    let (header, data) = parse_npy(member(&members, "data.npy"));
+    assert_eq!(header, "{'descr': '|b1', 'fortran_order': False, 'shape': (3,), }");
+    assert_eq!(data, &[1, 1, 1]);
+
+    let (_, data) = parse_npy(member(&members, "indptr.npy"));
+    assert_eq!(
+        le_values::<8>(data),
+        [0_u64, 1, 3].iter().map(|value| value.to_le_bytes()).collect::<Vec<_>>()
+    );
+}
@@ -25,3 +25,21 @@ fn test_padded_empty_shape_and_last_sparse_coordinates() {
     assert!(SparseMatrix::is_empty(&padded));
     assert_eq!(SparseMatrix::last_sparse_coordinates(&padded), None);
 }
+
+#[test]
+fn test_padded_checked_get() {
+    let mut csr: ValuedCSR2D<u8, u8, u8, u8> = ValuedCSR2D::with_sparse_shaped_capacity((3, 2), 2);
+    geometric_traits::traits::MatrixMut::add(&mut csr, (0, 1, 7)).expect("Failed to add value");
+    geometric_traits::traits::MatrixMut::add(&mut csr, (2, 0, 9)).expect("Failed to add value");
+    let padded = PaddedMatrix2D::new(&csr, |(row, column): (u8, u8)| 10 * row + column).unwrap();
+
+    for row in 0..3 {
+        for column in 0..3 {
+            assert_eq!(padded.checked_get((row, column)), Some(padded.value((row, column))));
+        }
+    }
+    assert_eq!(padded.checked_get((0, 1)), Some(7));
+    assert_eq!(padded.checked_get((1, 2)), Some(12));
+    assert_eq!(padded.checked_get((3, 0)), None);
+    assert_eq!(padded.checked_get((0, 3)), None);
+}
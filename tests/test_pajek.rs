@@ -0,0 +1,140 @@
+//! Tests for the Pajek `.net` reader and writers.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    io::{
+        PajekReadError, PajekReader, PajekWriteError, write_pajek_digraph, write_pajek_undigraph,
+        write_pajek_weighted_digraph,
+    },
+    prelude::*,
+};
+
+fn node(graph: &impl MonopartiteGraph<NodeSymbol = String, NodeId = usize>, symbol: &str) -> usize {
+    graph.nodes_vocabulary().invert(&symbol.to_string()).unwrap()
+}
+
+#[test]
+fn test_weighted_digraph_round_trip() {
+    let network = "\
+% exported by an older tool
+*Network metabolites
+*Vertices 4
+1 \"L-alanine\" 0.1 0.2 0.5 ic Red
+2 \"glycine (zwitterion)\"
+4 pyruvate
+*Arcs
+1 2 0.1
+2 1 -3.5e-3
+*Edges
+2 4 7
+*Arcslist
+3 1 4
+";
+    let graph = PajekReader::default().read_weighted_digraph(network.as_bytes()).unwrap();
+    assert_eq!(
+        graph.nodes_vocabulary().iter().map(String::as_str).collect::<Vec<_>>(),
+        ["3", "L-alanine", "glycine (zwitterion)", "pyruvate"]
+    );
+    assert_eq!(graph.number_of_edges(), 6);
+    let glycine = node(&graph, "glycine (zwitterion)");
+    let pyruvate = node(&graph, "pyruvate");
+    assert_eq!(graph.successor_weights(pyruvate).collect::<Vec<f64>>(), vec![7.0]);
+    assert_eq!(graph.successor_weights(glycine).collect::<Vec<f64>>(), vec![-3.5e-3, 7.0]);
+    assert_eq!(graph.successors(node(&graph, "3")).collect::<Vec<_>>(), vec![1, 3]);
+
+    let mut written = Vec::new();
+    write_pajek_weighted_digraph(&graph, &mut written).unwrap();
+    let restored = PajekReader::default().read_weighted_digraph(written.as_slice()).unwrap();
+    assert_eq!(restored, graph);
+}
+
+#[test]
+fn test_undigraph_round_trip() {
+    let network = "*vertices 3\r\n1 \"a\"\r\n2 \"b\"\r\n3 \"c\"\r\n*edges\r\n2 1\r\n3 3\r\n";
+    let graph = PajekReader::default().read_undigraph(network.as_bytes()).unwrap();
+    assert!(graph.has_successor(0, 1));
+    assert!(graph.has_successor(1, 0));
+    assert!(graph.has_successor(2, 2));
+
+    let mut written = Vec::new();
+    write_pajek_undigraph(&graph, &mut written).unwrap();
+    assert_eq!(
+        String::from_utf8(written.clone()).unwrap(),
+        "*Vertices 3\n1 \"a\"\n2 \"b\"\n3 \"c\"\n*Edges\n1 2\n3 3\n"
+    );
+    assert_eq!(PajekReader::default().read_undigraph(written.as_slice()).unwrap(), graph);
+
+    let digraph = PajekReader::default().read_digraph(network.as_bytes()).unwrap();
+    assert_eq!(digraph.number_of_edges(), 3);
+    let mut written = Vec::new();
+    write_pajek_digraph(&digraph, &mut written).unwrap();
+    assert_eq!(PajekReader::default().read_digraph(written.as_slice()).unwrap(), digraph);
+}
+
+#[test]
+fn test_invalid_networks_are_rejected() {
+    let read = |network: &str| PajekReader::default().read_digraph(network.as_bytes());
+
+    assert_eq!(read("*Arcs\n1 2\n"), Err(PajekReadError::MissingVertices { line: 1 }));
+    assert_eq!(read("*Vertices n\n"), Err(PajekReadError::InvalidVertexCount { line: 1 }));
+    assert_eq!(
+        read("*Vertices 2\n*Matrix\n"),
+        Err(PajekReadError::UnsupportedSection { line: 2, section: "*Matrix".to_string() })
+    );
+    assert_eq!(
+        read("*Vertices 2\n*Arcs\n1 3\n"),
+        Err(PajekReadError::InvalidVertex { line: 3, vertex: "3".to_string() })
+    );
+    assert_eq!(
+        read("*Vertices 2\n1 \"a\"\n1 \"b\"\n"),
+        Err(PajekReadError::RepeatedVertex { line: 3, vertex: 1 })
+    );
+    assert_eq!(
+        read("*Vertices 2\n1 \"a\"\n2 \"a\"\n"),
+        Err(PajekReadError::DuplicatedLabel { line: 3, label: "a".to_string() })
+    );
+    assert_eq!(read("*Vertices 2\n1 \"a\n"), Err(PajekReadError::UnterminatedLabel { line: 2 }));
+    assert_eq!(read("*Vertices 2\n*Arcs\n1\n"), Err(PajekReadError::MissingVertex { line: 3 }));
+    assert_eq!(
+        read("*Vertices 2\n*Arcs\n1 2 heavy\n"),
+        Err(PajekReadError::InvalidWeight { line: 3, value: "heavy".to_string() })
+    );
+    assert_eq!(
+        read("*Vertices 2\n*Arcs\n1 2\n*Edges\n1 2\n"),
+        Err(PajekReadError::DuplicatedEdge {
+            line: 5,
+            source_symbol: "1".to_string(),
+            destination_symbol: "2".to_string(),
+        })
+    );
+    assert!(
+        PajekReader::default()
+            .ignore_duplicates()
+            .read_digraph("*Vertices 2\n*Arcs\n1 2\n*Edges\n1 2\n".as_bytes())
+            .is_ok()
+    );
+    assert_eq!(
+        PajekReader::default().read_undigraph("*Vertices 2\n*Arcs\n1 2\n".as_bytes()),
+        Err(PajekReadError::ArcInUndirectedGraph { line: 3 })
+    );
+}
+
+#[test]
+fn test_unrepresentable_labels_are_rejected() {
+    let nodes: SortedVec<String> = GenericVocabularyBuilder::default()
+        .expected_number_of_symbols(1)
+        .symbols(["say \"hi\"".to_string()].into_iter().enumerate())
+        .build()
+        .unwrap();
+    let edges: SquareCSR2D<CSR2D<usize, usize, usize>> = DiEdgesBuilder::default()
+        .expected_number_of_edges(0)
+        .expected_shape(1)
+        .edges(core::iter::empty())
+        .build()
+        .unwrap();
+    let graph: DiGraph<String> = DiGraph::from((nodes, edges));
+    assert!(matches!(
+        write_pajek_digraph(&graph, Vec::new()),
+        Err(PajekWriteError::UnrepresentableLabel { node: 0, .. })
+    ));
+}
@@ -0,0 +1,183 @@
+//! Tests that the `par_*` methods of the `parallel` feature return the same
+//! results as their sequential counterparts, whatever the number of threads
+//! they run on.
+#![cfg(feature = "parallel")]
+
+mod common;
+
+use std::fmt::Write;
+
+use common::random_dag;
+use geometric_traits::{
+    impls::{CSR2D, SortedVec, SquareCSR2D, SymmetricCSR2D},
+    io::read_edge_list,
+    prelude::*,
+    traits::algorithms::randomized_graphs::{
+        CostDistribution, Feasibility, SparseCostMatrixConfig, XorShift64, erdos_renyi_gnp,
+        random_sparse_cost_matrix,
+    },
+};
+
+const THREADS: [usize; 3] = [1, 3, 8];
+
+/// Runs the closure on thread pools of every size in [`THREADS`] and checks
+/// that all of them return the same result.
+fn assert_same_on_every_pool<T, F>(run: F)
+where
+    T: PartialEq + core::fmt::Debug + Send,
+    F: Fn() -> T + Send + Sync,
+{
+    let results: Vec<T> = THREADS
+        .iter()
+        .map(|&threads| {
+            rayon::ThreadPoolBuilder::new().num_threads(threads).build().unwrap().install(&run)
+        })
+        .collect();
+    for (threads, result) in THREADS.iter().zip(&results).skip(1) {
+        assert_eq!(result, &results[0], "{threads} threads");
+    }
+}
+
+fn wrap_undi(graph: SymmetricCSR2D<CSR2D<usize, usize, usize>>) -> UndiGraph<usize> {
+    let n = graph.order();
+    let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+        .expected_number_of_symbols(n)
+        .symbols((0..n).enumerate())
+        .build()
+        .unwrap();
+    UndiGraph::from((nodes, graph))
+}
+
+#[test]
+fn test_parallel_transpose_and_pairwise_bfs() {
+    for seed in 1..=5 {
+        let dag: SquareCSR2D<CSR2D<usize, usize, usize>> = random_dag(seed, 120, 0.05);
+        assert_same_on_every_pool(|| dag.par_transpose());
+        assert_same_on_every_pool(|| dag.par_pairwise_bfs());
+        assert!(dag.par_transpose() == dag.transpose());
+        assert_eq!(dag.par_pairwise_bfs(), dag.pairwise_bfs());
+
+        let transposed = dag.par_transpose();
+        for (row, column) in SparseMatrix::sparse_coordinates(&dag) {
+            assert!(transposed.has_entry(column, row));
+        }
+        assert_eq!(transposed.number_of_defined_values(), dag.number_of_defined_values());
+    }
+}
+
+#[test]
+fn test_parallel_blocked_transpose() {
+    // Large enough for the transpose to bucket the entries by tiles of rows.
+    let dag: SquareCSR2D<CSR2D<usize, usize, usize>> = random_dag(9, 600, 0.5);
+    assert!(dag.number_of_defined_values() > 1 << 16);
+    assert_same_on_every_pool(|| dag.par_transpose());
+    assert!(dag.par_transpose() == dag.transpose());
+    assert!(dag.par_transpose().par_transpose() == dag);
+}
+
+#[test]
+fn test_parallel_pairwise_dijkstra() {
+    for seed in 1..=5 {
+        let costs = random_sparse_cost_matrix(seed, &SparseCostMatrixConfig::new(80, 80, 0.08));
+        assert_same_on_every_pool(|| costs.par_pairwise_dijkstra().unwrap());
+        assert_same_on_every_pool(|| costs.par_johnson_all_pairs_shortest_paths().unwrap());
+        assert_eq!(costs.par_pairwise_dijkstra(), costs.pairwise_dijkstra());
+        assert_eq!(
+            costs.par_johnson_all_pairs_shortest_paths(),
+            costs.johnson_all_pairs_shortest_paths()
+        );
+    }
+}
+
+#[test]
+fn test_parallel_centralities() {
+    for seed in 1..=5 {
+        let graph = wrap_undi(erdos_renyi_gnp(seed, 150, 0.03));
+        for (normalized, endpoints) in [(true, false), (false, true)] {
+            let scorer = BetweennessCentralityScorerBuilder::default()
+                .normalized(normalized)
+                .endpoints(endpoints)
+                .build();
+            assert_same_on_every_pool(|| scorer.par_score_nodes(&graph));
+            assert_eq!(scorer.par_score_nodes(&graph), scorer.score_nodes(&graph));
+        }
+        let scorer = ClosenessCentralityScorerBuilder::default().build();
+        assert_same_on_every_pool(|| scorer.par_score_nodes(&graph));
+        assert_eq!(scorer.par_score_nodes(&graph), scorer.score_nodes(&graph));
+    }
+}
+
+#[test]
+fn test_parallel_edge_list_indexing() {
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(7));
+    let mut text = String::new();
+    let mut seen = std::collections::HashSet::new();
+    while seen.len() < 2000 {
+        let source = rng.next().unwrap() % 300;
+        let destination = rng.next().unwrap() % 300;
+        if seen.insert((source, destination)) {
+            writeln!(text, "n{source}\tn{destination}\t{}", seen.len()).unwrap();
+        }
+    }
+    assert_same_on_every_pool(|| read_edge_list(text.as_bytes()).unwrap());
+}
+
+#[test]
+fn test_parallel_lapmod() {
+    for seed in 1..=5 {
+        for costs in [CostDistribution::default(), CostDistribution::Grid { levels: 3, scale: 1.0 }]
+        {
+            let config = SparseCostMatrixConfig {
+                costs,
+                feasibility: Feasibility::Assignment,
+                ..SparseCostMatrixConfig::new(300, 300, 0.03)
+            };
+            let matrix = random_sparse_cost_matrix(seed, &config);
+            let max_cost = matrix.max_sparse_value().unwrap() * 2.0;
+            assert_same_on_every_pool(|| matrix.par_lapmod(max_cost));
+            assert_eq!(matrix.par_lapmod(max_cost), matrix.lapmod(max_cost));
+        }
+    }
+
+    // Invalid costs and rows without entries are reported as sequentially.
+    let mut config = SparseCostMatrixConfig::new(200, 200, 0.05);
+    config.feasibility = Feasibility::None;
+    let matrix = random_sparse_cost_matrix(3, &config);
+    assert_same_on_every_pool(|| matrix.par_lapmod(5.0));
+    assert_eq!(matrix.par_lapmod(5.0), matrix.lapmod(5.0));
+    assert_eq!(matrix.par_lapmod(100.0), matrix.lapmod(100.0));
+    config.density = 0.002;
+    let matrix = random_sparse_cost_matrix(3, &config);
+    assert_eq!(matrix.par_lapmod(100.0), Err(LAPError::InfeasibleAssignment));
+}
+
+#[test]
+fn test_parallel_sparse_lapjv() {
+    for seed in 1..=5 {
+        for costs in [CostDistribution::default(), CostDistribution::Grid { levels: 3, scale: 1.0 }]
+        {
+            let config =
+                SparseCostMatrixConfig { costs, ..SparseCostMatrixConfig::new(120, 100, 0.05) };
+            let matrix = random_sparse_cost_matrix(seed, &config);
+            let padding_cost = matrix.max_sparse_value().unwrap() * 2.0;
+            let max_cost = padding_cost * 2.0;
+            assert_same_on_every_pool(|| matrix.par_sparse_lapjv(padding_cost, max_cost));
+            assert_eq!(
+                matrix.par_sparse_lapjv(padding_cost, max_cost),
+                matrix.sparse_lapjv(padding_cost, max_cost)
+            );
+        }
+    }
+
+    // Invalid costs are reported as sequentially.
+    let matrix = random_sparse_cost_matrix(3, &SparseCostMatrixConfig::new(80, 80, 0.05));
+    let max_sparse_value = matrix.max_sparse_value().unwrap();
+    assert_eq!(
+        matrix.par_sparse_lapjv(max_sparse_value, max_sparse_value * 2.0),
+        Err(LAPError::PaddingCostTooSmall)
+    );
+    assert_eq!(
+        matrix.par_sparse_lapjv(max_sparse_value * 2.0, max_sparse_value),
+        matrix.sparse_lapjv(max_sparse_value * 2.0, max_sparse_value)
+    );
+}
@@ -0,0 +1,162 @@
+//! Tests for the approximate personalized PageRank and its node similarity.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::{CSR2D, SortedVec, SymmetricCSR2D},
+    prelude::*,
+    traits::{
+        PersonalizedPageRank, PersonalizedPageRankSimilarity, ScalarSimilarity, TopKSimilarity,
+        VocabularyBuilder,
+        algorithms::randomized_graphs::{barabasi_albert, erdos_renyi_gnp},
+    },
+};
+
+fn wrap_undigraph(matrix: SymmetricCSR2D<CSR2D<usize, usize, usize>>) -> UndiGraph<usize> {
+    let order = matrix.order();
+    let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+        .expected_number_of_symbols(order)
+        .symbols((0..order).enumerate())
+        .build()
+        .unwrap();
+    UndiGraph::from((nodes, matrix))
+}
+
+/// Computes the personalized PageRank of `source` by power iteration.
+#[allow(clippy::cast_precision_loss)]
+fn exact_personalized_pagerank(graph: &UndiGraph<usize>, source: usize, alpha: f64) -> Vec<f64> {
+    let number_of_nodes = graph.number_of_nodes();
+    let mut scores = vec![0.0; number_of_nodes];
+    scores[source] = 1.0;
+    for _ in 0..2_000 {
+        let mut next = vec![0.0; number_of_nodes];
+        next[source] += 1.0 - alpha;
+        for node in graph.node_ids() {
+            let degree = graph.degree(node);
+            if degree == 0 {
+                next[source] += alpha * scores[node];
+                continue;
+            }
+            for neighbor in graph.neighbors(node) {
+                next[neighbor] += alpha * scores[node] / degree as f64;
+            }
+        }
+        scores = next;
+    }
+    // The walk can never leave an isolated source.
+    if graph.degree(source) == 0 {
+        scores = vec![0.0; number_of_nodes];
+        scores[source] = 1.0;
+    }
+    scores
+}
+
+#[test]
+#[allow(clippy::cast_precision_loss)]
+fn test_push_approximation_is_within_degree_tolerance() {
+    let graph = wrap_undigraph(erdos_renyi_gnp(3, 40, 0.1));
+    let epsilon = 1e-4;
+    for alpha in [0.5, 0.85] {
+        for source in [0, 7, 23] {
+            let exact = exact_personalized_pagerank(&graph, source, alpha);
+            let mut approximate = vec![0.0; 40];
+            for (node, score) in graph.approximate_personalized_pagerank(source, alpha, epsilon) {
+                approximate[node] = score;
+            }
+            for node in 0..40 {
+                let tolerance = epsilon * graph.degree(node).max(1) as f64;
+                assert!(approximate[node] <= exact[node] + 1e-9, "overestimated {node}");
+                assert!(
+                    exact[node] - approximate[node] <= tolerance + 1e-9,
+                    "alpha {alpha}, source {source}, node {node}: {} vs {}",
+                    approximate[node],
+                    exact[node]
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_push_entries_are_sorted_positive_and_sum_to_at_most_one() {
+    let graph = wrap_undigraph(barabasi_albert(5, 200, 2));
+    let scores = graph.approximate_personalized_pagerank(17, 0.85, 1e-5);
+    assert!(scores.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    assert!(scores.iter().all(|&(_, score)| score > 0.0));
+    let total: f64 = scores.iter().map(|&(_, score)| score).sum();
+    assert!(total <= 1.0 + 1e-12 && total > 0.95, "total {total}");
+}
+
+#[test]
+fn test_isolated_source_keeps_all_the_mass() {
+    let graph = wrap_undigraph(
+        UndiEdgesBuilder::default()
+            .expected_number_of_edges(1)
+            .expected_shape(3)
+            .edges([(0, 1)].into_iter())
+            .build()
+            .unwrap(),
+    );
+    assert_eq!(graph.approximate_personalized_pagerank(2, 0.85, 1e-6), vec![(2, 1.0)]);
+    let similarity = PersonalizedPageRankSimilarity::new(&graph);
+    assert!((similarity.similarity(&2, &2) - 1.0).abs() < f64::EPSILON);
+    assert!(similarity.similarity(&0, &2).abs() < f64::EPSILON);
+}
+
+#[test]
+#[allow(clippy::cast_precision_loss)]
+fn test_symmetrized_similarity_is_symmetric_and_degree_balanced() {
+    let graph = wrap_undigraph(erdos_renyi_gnp(11, 20, 0.2));
+    let directed = PersonalizedPageRankSimilarity::new(&graph).epsilon(1e-8);
+    let symmetric = PersonalizedPageRankSimilarity::new(&graph).epsilon(1e-8).symmetric(true);
+    for left in 0..20 {
+        for right in 0..20 {
+            let forward = directed.similarity(&left, &right);
+            let backward = directed.similarity(&right, &left);
+            assert!(
+                (symmetric.similarity(&left, &right) - f64::midpoint(forward, backward)).abs()
+                    < 1e-12
+            );
+            assert!(
+                (symmetric.similarity(&left, &right) - symmetric.similarity(&right, &left)).abs()
+                    < 1e-12
+            );
+            // On undirected graphs, ppr_u(v) / deg(v) = ppr_v(u) / deg(u).
+            let left_degree = graph.degree(left) as f64;
+            let right_degree = graph.degree(right) as f64;
+            if left_degree > 0.0 && right_degree > 0.0 {
+                assert!((forward / right_degree - backward / left_degree).abs() < 1e-5);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_top_k_matches_scalar_ranking() {
+    let graph = wrap_undigraph(erdos_renyi_gnp(29, 40, 0.06));
+    for symmetric in [false, true] {
+        let similarity = PersonalizedPageRankSimilarity::new(&graph).symmetric(symmetric);
+        for item in [0, 9, 31] {
+            let mut expected: Vec<(usize, f64)> = (0..40)
+                .filter(|&other| other != item)
+                .map(|other| (other, similarity.similarity(&item, &other)))
+                .filter(|&(_, score)| score > 0.0)
+                .collect();
+            expected.sort_by(|left, right| right.1.total_cmp(&left.1).then(left.0.cmp(&right.0)));
+            for k in [0, 1, 4, usize::MAX] {
+                let top = similarity.top_k_similar(&item, k);
+                assert_eq!(top.len(), expected.len().min(k));
+                for (found, wanted) in top.iter().zip(&expected) {
+                    assert_eq!(found.0, wanted.0);
+                    assert!((found.1 - wanted.1).abs() < 1e-12);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "alpha must be in [0, 1)")]
+fn test_invalid_alpha_panics() {
+    let graph = wrap_undigraph(erdos_renyi_gnp(1, 5, 0.5));
+    let _ = PersonalizedPageRankSimilarity::new(&graph).alpha(1.0);
+}
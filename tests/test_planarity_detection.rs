@@ -8,9 +8,12 @@ use geometric_traits::{
     errors::{MonopartiteError, monopartite_graph_error::algorithms::MonopartiteAlgorithmError},
     impls::{CSR2D, SortedVec, SymmetricCSR2D},
     prelude::*,
-    traits::{EdgesBuilder, PlanarityDetection, PlanarityError, VocabularyBuilder},
+    traits::{
+        EdgesBuilder, KuratowskiKind, PlanarityCertificate, PlanarityDetection, PlanarityError,
+        VocabularyBuilder,
+    },
 };
-use planarity_fixture::{PlanarityFixtureCase, build_undigraph, semantic_cases};
+use planarity_fixture::{PlanarityFixtureCase, build_undigraph, normalize_edge, semantic_cases};
 
 #[test]
 fn test_planarity_matches_semantic_cases() {
@@ -255,3 +258,140 @@ fn test_planarity_rejects_k5_subdivision_corpus_regression() {
 
     assert!(!graph.is_planar().unwrap(), "planarity should reject {} ({})", case.name, case.family);
 }
+
+/// Checks that the certificate of the case is an embedding satisfying Euler's
+/// formula on every component, or a minimal non-planar subgraph subdividing
+/// `K_5` or `K_{3,3}`.
+fn assert_valid_certificate(case: &PlanarityFixtureCase) {
+    let graph = build_undigraph(case);
+    let mut edges: Vec<[usize; 2]> = case.edges.iter().copied().map(normalize_edge).collect();
+    edges.sort_unstable();
+    match graph.planarity_certificate().unwrap() {
+        PlanarityCertificate::Planar(embedding) => {
+            assert!(case.is_planar, "{} was embedded", case.name);
+            assert_eq!(embedding.number_of_nodes(), case.node_count);
+            let mut darts: Vec<[usize; 2]> = (0..case.node_count)
+                .flat_map(|node| {
+                    embedding.rotation(node).iter().map(move |&neighbor| [node, neighbor])
+                })
+                .collect();
+            darts.sort_unstable();
+            let mut expected_darts: Vec<[usize; 2]> =
+                edges.iter().flat_map(|&[left, right]| [[left, right], [right, left]]).collect();
+            expected_darts.sort_unstable();
+            assert_eq!(darts, expected_darts, "{} rotations", case.name);
+
+            // Every face lies in one component, and each component with an
+            // edge satisfies V - E + F = 2.
+            let components = component_labels(case.node_count, &edges);
+            let mut euler = vec![0isize; case.node_count];
+            for node in 0..case.node_count {
+                if !embedding.rotation(node).is_empty() {
+                    euler[components[node]] += 1;
+                }
+            }
+            for &[left, _] in &edges {
+                euler[components[left]] -= 1;
+            }
+            for face in embedding.faces() {
+                euler[components[face[0]]] += 1;
+            }
+            for node in 0..case.node_count {
+                if components[node] == node && !embedding.rotation(node).is_empty() {
+                    assert_eq!(euler[node], 2, "{} violates Euler's formula", case.name);
+                }
+            }
+        }
+        PlanarityCertificate::NonPlanar(witness) => {
+            assert!(!case.is_planar, "{} was rejected", case.name);
+            let witness_edges: Vec<[usize; 2]> =
+                witness.edges().iter().map(|&(left, right)| [left, right]).collect();
+            assert!(witness_edges.iter().all(|edge| edges.binary_search(edge).is_ok()));
+            let is_planar = |edges: &[[usize; 2]]| {
+                planarity_fixture::build_undigraph(&PlanarityFixtureCase {
+                    edges: edges.to_vec(),
+                    ..case.clone()
+                })
+                .is_planar()
+                .unwrap()
+            };
+            assert!(!is_planar(&witness_edges), "{} witness is planar", case.name);
+            for index in 0..witness_edges.len() {
+                let mut smaller = witness_edges.clone();
+                smaller.remove(index);
+                assert!(is_planar(&smaller), "{} witness is not minimal", case.name);
+            }
+
+            let mut degrees = vec![0usize; case.node_count];
+            for &[left, right] in &witness_edges {
+                degrees[left] += 1;
+                degrees[right] += 1;
+            }
+            let branch_nodes: Vec<usize> =
+                (0..case.node_count).filter(|&node| degrees[node] >= 3).collect();
+            assert_eq!(witness.branch_nodes(), branch_nodes);
+            let (expected_count, expected_degree) = match witness.kind() {
+                KuratowskiKind::K5 => (5, 4),
+                KuratowskiKind::K33 => (6, 3),
+            };
+            assert_eq!(branch_nodes.len(), expected_count, "{}", case.name);
+            assert!(branch_nodes.iter().all(|&node| degrees[node] == expected_degree));
+            assert!(degrees.iter().all(|&degree| degree == 0 || degree == 2 || degree >= 3));
+        }
+    }
+}
+
+/// Returns the smallest node of the component of every node.
+fn component_labels(node_count: usize, edges: &[[usize; 2]]) -> Vec<usize> {
+    let mut labels: Vec<usize> = (0..node_count).collect();
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for &[left, right] in edges {
+            let label = labels[left].min(labels[right]);
+            for node in [left, right] {
+                if labels[node] != label {
+                    labels[node] = label;
+                    changed = true;
+                }
+            }
+        }
+    }
+    labels
+}
+
+#[test]
+fn test_planarity_certificates_of_semantic_cases() {
+    for case in semantic_cases() {
+        assert_valid_certificate(&case);
+    }
+}
+
+#[test]
+fn test_planarity_certificates_of_corpus_regressions() {
+    let k33 = regression_case(
+        "k33_subdivision_136470",
+        "k33_subdivision",
+        8,
+        &[[0, 3], [0, 4], [0, 6], [1, 4], [1, 5], [1, 7], [2, 3], [2, 4], [2, 5], [3, 7], [5, 6]],
+        false,
+    );
+    let wheel_with_chord = regression_case(
+        "k4_subdivision_287588",
+        "k4_subdivision",
+        9,
+        &[[0, 2], [0, 3], [0, 7], [1, 5], [1, 7], [1, 8], [2, 5], [2, 6], [3, 4], [3, 8], [4, 6]],
+        true,
+    );
+    let mut k5_with_extras = regression_case(
+        "k5_plus_pendants",
+        "k5_subdivision",
+        8,
+        &[[0, 1], [0, 2], [0, 3], [0, 4], [1, 2], [1, 3], [1, 4], [2, 3], [2, 4], [3, 4]],
+        false,
+    );
+    k5_with_extras.edges.extend([[4, 5], [5, 6], [0, 6], [6, 7]]);
+    for case in [k33, wheel_with_chord, k5_with_extras] {
+        assert_valid_certificate(&case);
+    }
+}
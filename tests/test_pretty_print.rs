@@ -0,0 +1,91 @@
+//! Tests for the dense grid and adjacency list renderings of matrices and
+//! graphs.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::{CSR2D, SortedVec, SquareCSR2D, SymmetricCSR2D, ValuedCSR2D},
+    prelude::*,
+    traits::{DISPLAY_LIMIT, EdgesBuilder},
+};
+
+type TestCSR = CSR2D<usize, usize, usize>;
+
+fn build_csr(shape: (usize, usize), entries: &[(usize, usize)]) -> TestCSR {
+    let mut matrix: TestCSR = SparseMatrixMut::with_sparse_shape(shape);
+    for &entry in entries {
+        MatrixMut::add(&mut matrix, entry).unwrap();
+    }
+    matrix
+}
+
+#[test]
+fn test_dense_string_truncates_rows_and_columns() {
+    let matrix = build_csr((3, 4), &[(0, 0), (0, 3), (1, 2), (2, 1)]);
+    assert_eq!(matrix.to_dense_string(3, 4), "1 · · 1\n· · 1 ·\n· 1 · ·");
+    assert_eq!(matrix.to_dense_string(2, 3), "1 · · …\n· · 1 …\n⋮");
+    assert_eq!(matrix.to_dense_string(3, 4), matrix.to_string());
+
+    assert_eq!(TestCSR::default().to_dense_string(4, 4), "");
+    assert_eq!(build_csr((2, 2), &[]).to_dense_string(2, 2), "· ·\n· ·");
+}
+
+#[test]
+fn test_display_is_limited_to_the_display_limit() {
+    let size = DISPLAY_LIMIT + 4;
+    let diagonal: Vec<(usize, usize)> = (0..size).map(|index| (index, index)).collect();
+    let matrix = build_csr((size, size), &diagonal);
+    let rendered = matrix.to_string();
+    let lines: Vec<&str> = rendered.lines().collect();
+
+    assert_eq!(lines.len(), DISPLAY_LIMIT + 1);
+    assert_eq!(lines.last(), Some(&"⋮"));
+    assert!(lines[..DISPLAY_LIMIT].iter().all(|line| line.ends_with(" …")));
+
+    let square: SquareCSR2D<TestCSR> = SquareCSR2D::default();
+    assert_eq!(square.to_string(), "");
+}
+
+#[test]
+fn test_dense_values_string_aligns_cells() {
+    let mut matrix: ValuedCSR2D<usize, usize, usize, i32> =
+        SparseMatrixMut::with_sparse_shape((2, 3));
+    for entry in [(0, 0, -100), (0, 2, 5), (1, 1, 7)] {
+        MatrixMut::add(&mut matrix, entry).unwrap();
+    }
+    assert_eq!(matrix.to_dense_values_string(2, 3), "-100    ·    5\n   ·    7    ·");
+    assert_eq!(matrix.to_dense_values_string(2, 1), "-100 …\n   · …");
+    assert_eq!(matrix.to_string(), matrix.to_dense_values_string(2, 3));
+}
+
+#[test]
+fn test_wrappers_render_their_underlying_matrix() {
+    let edges: SymmetricCSR2D<TestCSR> = UndiEdgesBuilder::default()
+        .expected_number_of_edges(2)
+        .expected_shape(3)
+        .edges(vec![(0, 1), (1, 2)].into_iter())
+        .build()
+        .unwrap();
+    assert_eq!(edges.to_string(), "· 1 ·\n1 · 1\n· 1 ·");
+    assert_eq!(edges.to_string(), edges.to_dense_string(3, 3));
+}
+
+#[test]
+fn test_graph_display_renders_adjacency_lists() {
+    let nodes: SortedVec<&str> = GenericVocabularyBuilder::default()
+        .expected_number_of_symbols(3)
+        .symbols(["x", "y", "z"].into_iter().enumerate())
+        .build()
+        .unwrap();
+    let edges: SquareCSR2D<TestCSR> = DiEdgesBuilder::default()
+        .expected_number_of_edges(3)
+        .expected_shape(3)
+        .edges(vec![(0, 0), (0, 2), (2, 1)].into_iter())
+        .build()
+        .unwrap();
+    let graph: GenericGraph<SortedVec<&str>, SquareCSR2D<TestCSR>> =
+        GenericGraph::from((nodes, edges));
+
+    assert_eq!(graph.to_string(), "x: x, z\ny:\nz: y");
+    assert_eq!(graph.to_adjacency_string(2), "x: x, z\ny:\n⋮");
+    assert_eq!(graph.to_adjacency_string(0), "⋮");
+}
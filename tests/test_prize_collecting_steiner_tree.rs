@@ -0,0 +1,193 @@
+//! Tests for the Goemans-Williamson prize-collecting Steiner tree heuristic.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::ValuedCSR2D,
+    prelude::*,
+    traits::{
+        EdgesBuilder, PrizeCollectingSteinerTree, PrizeCollectingSteinerTreeError,
+        algorithms::randomized_graphs::XorShift64,
+    },
+};
+
+type TestValCSR = ValuedCSR2D<usize, usize, usize, f64>;
+
+/// Builds a square matrix from undirected edges, stored in both directions.
+fn build_undirected(order: usize, edges: &[(usize, usize, f64)]) -> TestValCSR {
+    let mut entries: Vec<(usize, usize, f64)> =
+        edges.iter().flat_map(|&(u, v, cost)| [(u, v, cost), (v, u, cost)]).collect();
+    entries.sort_unstable_by_key(|&(source, destination, _)| (source, destination));
+    entries.dedup_by_key(|&mut (source, destination, _)| (source, destination));
+    GenericEdgesBuilder::<_, TestValCSR>::default()
+        .expected_number_of_edges(entries.len())
+        .expected_shape((order, order))
+        .edges(entries.into_iter())
+        .build()
+        .unwrap()
+}
+
+/// Returns the cost of a minimum spanning tree of the nodes selected by the
+/// mask, or `None` if they are not connected.
+fn spanning_tree_cost(order: usize, edges: &[(usize, usize, f64)], mask: u32) -> Option<f64> {
+    let mut edges: Vec<(usize, usize, f64)> = edges
+        .iter()
+        .copied()
+        .filter(|&(u, v, _)| u != v && mask & (1 << u) != 0 && mask & (1 << v) != 0)
+        .collect();
+    edges.sort_unstable_by(|left, right| left.2.total_cmp(&right.2));
+    let mut component: Vec<usize> = (0..order).collect();
+    let mut cost = 0.0;
+    let mut joined = 1;
+    for (u, v, edge_cost) in edges {
+        let (left, right) = (component[u], component[v]);
+        if left != right {
+            for label in &mut component {
+                if *label == right {
+                    *label = left;
+                }
+            }
+            cost += edge_cost;
+            joined += 1;
+        }
+    }
+    (joined == mask.count_ones()).then_some(cost)
+}
+
+/// Returns the largest net worth of a connected subgraph, by enumeration.
+fn optimal_net_worth(order: usize, edges: &[(usize, usize, f64)], prizes: &[f64]) -> f64 {
+    (1..1_u32 << order)
+        .filter_map(|mask| {
+            let cost = spanning_tree_cost(order, edges, mask)?;
+            let prize: f64 =
+                (0..order).filter(|&node| mask & (1 << node) != 0).map(|node| prizes[node]).sum();
+            Some(prize - cost)
+        })
+        .fold(0.0, f64::max)
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_prize_collecting_steiner_tree_on_random_graphs() {
+    let order = 10;
+    for seed in 0..200 {
+        let mut rng = XorShift64::from(XorShift64::normalize_seed(seed));
+        let mut random = |bound: u64| rng.next().unwrap() % bound;
+        let mut edges: Vec<(usize, usize, f64)> = (0..18)
+            .map(|_| {
+                let u = usize::try_from(random(order as u64)).unwrap();
+                let v = usize::try_from(random(order as u64)).unwrap();
+                (u.min(v), u.max(v), f64::from(u32::try_from(random(8)).unwrap()))
+            })
+            .collect();
+        edges.sort_unstable_by_key(|&(u, v, _)| (u, v));
+        edges.dedup_by_key(|&mut (u, v, _)| (u, v));
+        let prizes: Vec<f64> =
+            (0..order).map(|_| f64::from(u32::try_from(random(10)).unwrap())).collect();
+        let graph = build_undirected(order, &edges);
+
+        let subgraph = graph.prize_collecting_steiner_tree(&prizes).unwrap();
+        let nodes = subgraph.nodes();
+        assert!(nodes.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(subgraph.prize(), nodes.iter().map(|&node| prizes[node]).sum::<f64>());
+        assert_eq!(subgraph.cost(), subgraph.edges().iter().map(|edge| edge.2).sum::<f64>());
+        assert_eq!(subgraph.net_worth(), subgraph.prize() - subgraph.cost());
+
+        // The edges belong to the graph and form a tree spanning the nodes.
+        for &(u, v, cost) in subgraph.edges() {
+            assert!(u < v);
+            assert_eq!(graph.sparse_value_at(u, v), Some(cost));
+            assert!(nodes.binary_search(&u).is_ok() && nodes.binary_search(&v).is_ok());
+        }
+        if !nodes.is_empty() {
+            assert_eq!(subgraph.edges().len() + 1, nodes.len(), "seed {seed}");
+            let mask: u32 = nodes.iter().map(|&node| 1 << node).sum();
+            assert!(spanning_tree_cost(order, subgraph.edges(), mask).is_some());
+        }
+
+        let best_single = prizes.iter().copied().fold(0.0, f64::max);
+        let optimum = optimal_net_worth(order, &edges, &prizes);
+        assert!(subgraph.net_worth() >= best_single, "seed {seed}");
+        assert!(subgraph.net_worth() <= optimum, "seed {seed}");
+
+        // The Goemans-Williamson guarantee on the cost plus missed prizes.
+        let total: f64 = prizes.iter().sum();
+        assert!(total - subgraph.net_worth() <= 2.0 * (total - optimum), "seed {seed}");
+    }
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_prize_collecting_steiner_tree_prunes_unprofitable_branches() {
+    // A hub paying for two leaves, a leaf too far to be worth its prize, and
+    // a separate richer component joined by an expensive edge.
+    let graph = build_undirected(
+        7,
+        &[(0, 1, 1.0), (0, 2, 1.0), (0, 3, 4.0), (3, 4, 10.0), (4, 5, 0.5), (5, 6, 0.5)],
+    );
+    let subgraph =
+        graph.prize_collecting_steiner_tree(&[0.0, 3.0, 3.0, 2.0, 2.0, 2.0, 2.0]).unwrap();
+    assert_eq!(subgraph.nodes(), &[4, 5, 6]);
+    assert_eq!(subgraph.edges(), &[(4, 5, 0.5), (5, 6, 0.5)]);
+    assert_eq!(subgraph.net_worth(), 5.0);
+
+    let empty = graph.prize_collecting_steiner_tree(&[0.0; 7]).unwrap();
+    assert!(empty.nodes().is_empty());
+    assert!(empty.edges().is_empty());
+    assert_eq!(empty.net_worth(), 0.0);
+
+    let single = graph.prize_collecting_steiner_tree(&[0.0, 0.0, 0.0, 7.0, 0.0, 0.0, 0.0]).unwrap();
+    assert_eq!(single.nodes(), &[3]);
+    assert_eq!(single.net_worth(), 7.0);
+}
+
+#[test]
+fn test_prize_collecting_steiner_tree_errors() {
+    let graph = build_undirected(3, &[(0, 1, 1.0), (1, 2, 2.0)]);
+    assert_eq!(
+        graph.prize_collecting_steiner_tree(&[1.0, 1.0]),
+        Err(PrizeCollectingSteinerTreeError::PrizesLengthMismatch { expected: 3, found: 2 })
+    );
+    assert_eq!(
+        graph.prize_collecting_steiner_tree(&[1.0, -1.0, 1.0]),
+        Err(PrizeCollectingSteinerTreeError::InvalidPrize { node_id: 1 })
+    );
+    assert_eq!(
+        graph.prize_collecting_steiner_tree(&[1.0, 1.0, f64::NAN]),
+        Err(PrizeCollectingSteinerTreeError::InvalidPrize { node_id: 2 })
+    );
+
+    let negative = build_undirected(2, &[(0, 1, -1.0)]);
+    assert_eq!(
+        negative.prize_collecting_steiner_tree(&[1.0, 1.0]),
+        Err(PrizeCollectingSteinerTreeError::NegativeWeight { source_id: 0, destination_id: 1 })
+    );
+    let infinite = build_undirected(2, &[(0, 1, f64::INFINITY)]);
+    assert_eq!(
+        infinite.prize_collecting_steiner_tree(&[1.0, 1.0]),
+        Err(PrizeCollectingSteinerTreeError::NonFiniteWeight { source_id: 0, destination_id: 1 })
+    );
+
+    let directed = GenericEdgesBuilder::<_, TestValCSR>::default()
+        .expected_number_of_edges(1)
+        .expected_shape((2, 2))
+        .edges(vec![(0, 1, 1.0)].into_iter())
+        .build()
+        .unwrap();
+    assert_eq!(
+        directed.prize_collecting_steiner_tree(&[1.0, 1.0]),
+        Err(PrizeCollectingSteinerTreeError::NonSymmetricWeight {
+            source_id: 0,
+            destination_id: 1
+        })
+    );
+    let rectangular = GenericEdgesBuilder::<_, TestValCSR>::default()
+        .expected_number_of_edges(1)
+        .expected_shape((2, 3))
+        .edges(vec![(0, 1, 1.0)].into_iter())
+        .build()
+        .unwrap();
+    assert_eq!(
+        rectangular.prize_collecting_steiner_tree(&[1.0, 1.0]),
+        Err(PrizeCollectingSteinerTreeError::NonSquareMatrix { rows: 2, columns: 3 })
+    );
+}
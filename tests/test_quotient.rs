@@ -0,0 +1,147 @@
+//! Tests for the quotient of weighted undirected graphs by a partition.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::ValuedCSR2D,
+    prelude::*,
+    traits::{LouvainConfig, ModularityError, Quotient, algorithms::randomized_graphs::XorShift64},
+};
+
+type WeightedMatrix = ValuedCSR2D<usize, usize, usize, f64>;
+
+/// Builds the weighted matrix holding each undirected edge in both
+/// directions.
+fn build_symmetric_matrix(order: usize, edges: &[(usize, usize, f64)]) -> WeightedMatrix {
+    let mut entries: Vec<(usize, usize, f64)> =
+        edges
+            .iter()
+            .flat_map(|&(u, v, weight)| {
+                if u == v { vec![(u, v, weight)] } else { vec![(u, v, weight), (v, u, weight)] }
+            })
+            .collect();
+    entries.sort_unstable_by_key(|&(u, v, _)| (u, v));
+    GenericEdgesBuilder::<_, WeightedMatrix>::default()
+        .expected_number_of_edges(entries.len())
+        .expected_shape((order, order))
+        .edges(entries.into_iter())
+        .build()
+        .unwrap()
+}
+
+/// Returns the dense rows of the matrix.
+fn to_dense(matrix: &WeightedMatrix, order: usize) -> Vec<Vec<f64>> {
+    let mut dense = vec![vec![0.0; order]; order];
+    for (row, dense_row) in dense.iter_mut().enumerate() {
+        for (column, weight) in matrix.sparse_row(row).zip(matrix.sparse_row_values(row)) {
+            dense_row[column] += weight;
+        }
+    }
+    dense
+}
+
+/// Asserts that the dense matrices are equal up to rounding.
+fn assert_close(left: &[Vec<f64>], right: &[Vec<f64>]) {
+    assert_eq!(left.len(), right.len());
+    for (left_row, right_row) in left.iter().zip(right) {
+        for (left_value, right_value) in left_row.iter().zip(right_row) {
+            assert!((left_value - right_value).abs() < 1e-9, "{left_value} != {right_value}");
+        }
+    }
+}
+
+#[test]
+fn test_quotient_against_dense_aggregation() {
+    let order = 20;
+    for seed in 0..30 {
+        let mut rng = XorShift64::from(XorShift64::normalize_seed(seed));
+        let mut edges = Vec::new();
+        for u in 0..order {
+            for v in u..order {
+                if rng.next().unwrap() % 100 < 20 {
+                    let weight = f64::from(u32::try_from(rng.next().unwrap() % 9 + 1).unwrap());
+                    edges.push((u, v, weight));
+                }
+            }
+        }
+        let matrix = build_symmetric_matrix(order, &edges);
+        let partition: Vec<u16> =
+            (0..order).map(|_| u16::try_from(rng.next().unwrap() % 5).unwrap() * 100).collect();
+
+        let (quotient, communities) = matrix.quotient(&partition).unwrap();
+        let mut expected_communities = partition.clone();
+        expected_communities.sort_unstable();
+        expected_communities.dedup();
+        assert_eq!(communities, expected_communities);
+
+        let number_of_communities = communities.len();
+        assert_eq!(quotient.number_of_rows(), number_of_communities);
+        assert_eq!(quotient.number_of_columns(), number_of_communities);
+        let super_node =
+            |node: usize| communities.iter().position(|&c| c == partition[node]).unwrap();
+        let dense = to_dense(&matrix, order);
+        let mut expected = vec![vec![0.0; number_of_communities]; number_of_communities];
+        for (u, row) in dense.iter().enumerate() {
+            for (v, weight) in row.iter().enumerate() {
+                expected[super_node(u)][super_node(v)] += weight;
+            }
+        }
+        assert_close(&to_dense(&quotient, number_of_communities), &expected);
+        // Only the pairs of communities with some weight are stored.
+        let stored: usize = expected.iter().flatten().filter(|weight| **weight > 0.0).count();
+        assert_eq!(quotient.number_of_defined_values(), stored);
+    }
+}
+
+#[test]
+fn test_quotient_by_singletons_is_the_graph() {
+    let matrix = build_symmetric_matrix(4, &[(0, 0, 0.5), (0, 1, 1.0), (1, 3, 2.0), (2, 3, 3.0)]);
+    let (quotient, communities) = matrix.quotient(&[0_usize, 1, 2, 3]).unwrap();
+    assert_eq!(communities, vec![0, 1, 2, 3]);
+    assert_eq!(quotient, matrix);
+
+    // A single community holds the whole weight on its self-loop.
+    let (quotient, communities) = matrix.quotient(&[1_u8; 4]).unwrap();
+    assert_eq!(communities, vec![1]);
+    assert_eq!(quotient.sparse_row_values(0).collect::<Vec<_>>(), vec![12.5]);
+}
+
+#[test]
+fn test_quotient_of_louvain_partition_preserves_weight() {
+    // Two triangles joined by a single light edge.
+    let matrix = build_symmetric_matrix(
+        6,
+        &[
+            (0, 1, 1.0),
+            (0, 2, 1.0),
+            (1, 2, 1.0),
+            (2, 3, 0.1),
+            (3, 4, 1.0),
+            (3, 5, 1.0),
+            (4, 5, 1.0),
+        ],
+    );
+    let result = Louvain::<usize>::louvain(&matrix, &LouvainConfig::default()).unwrap();
+    let (quotient, communities) = matrix.quotient(result.final_partition()).unwrap();
+    assert_eq!(communities, vec![0, 1]);
+    assert_close(&to_dense(&quotient, 2), &[vec![6.0, 0.1], vec![0.1, 6.0]]);
+}
+
+#[test]
+fn test_quotient_errors() {
+    let matrix = build_symmetric_matrix(3, &[(0, 1, 1.0), (1, 2, 1.0)]);
+    assert_eq!(
+        matrix.quotient(&[0_usize, 1]),
+        Err(ModularityError::PartitionLengthMismatch { expected: 3, found: 2 })
+    );
+
+    let directed: WeightedMatrix = GenericEdgesBuilder::<_, WeightedMatrix>::default()
+        .expected_number_of_edges(1)
+        .expected_shape((2, 2))
+        .edges(vec![(0, 1, 1.0)].into_iter())
+        .build()
+        .unwrap();
+    assert!(matches!(
+        directed.quotient(&[0_usize, 0]),
+        Err(ModularityError::NonSymmetricEdge { source_id: 0, destination_id: 1 })
+    ));
+}
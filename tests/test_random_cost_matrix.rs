@@ -0,0 +1,127 @@
+//! Tests for the random sparse cost-matrix generator.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::ValuedCSR2D,
+    prelude::*,
+    traits::algorithms::randomized_graphs::{
+        CostDistribution, Feasibility, SparseCostMatrixConfig, random_sparse_cost_matrix,
+    },
+};
+
+type Costs = ValuedCSR2D<usize, usize, usize, f64>;
+
+fn generate(seed: u64, config: SparseCostMatrixConfig) -> Costs {
+    random_sparse_cost_matrix(seed, &config)
+}
+
+fn entries(costs: &Costs) -> Vec<(usize, usize, f64)> {
+    SparseMatrix::sparse_coordinates(costs)
+        .zip(costs.sparse_values())
+        .map(|((row, column), cost)| (row, column, cost))
+        .collect()
+}
+
+#[test]
+fn test_shape_density_and_determinism() {
+    let config = SparseCostMatrixConfig {
+        feasibility: Feasibility::None,
+        ..SparseCostMatrixConfig::new(100, 300, 0.05)
+    };
+    let costs = generate(7, config);
+    assert_eq!(costs.number_of_rows(), 100);
+    assert_eq!(costs.number_of_columns(), 300);
+    // 1500 cells drawn with replacement out of 30000.
+    let values = costs.number_of_defined_values();
+    assert!((1400..=1500).contains(&values), "{values}");
+    assert_eq!(entries(&costs), entries(&generate(7, config)));
+    assert_ne!(entries(&costs), entries(&generate(8, config)));
+}
+
+#[test]
+fn test_cost_distributions() {
+    let uniform = SparseCostMatrixConfig {
+        costs: CostDistribution::Uniform { low: -2.0, high: 3.0 },
+        ..SparseCostMatrixConfig::new(50, 50, 0.2)
+    };
+    let costs: Vec<f64> = generate(1, uniform).sparse_values().collect();
+    assert!(costs.iter().all(|cost| (-2.0..3.0).contains(cost)));
+    assert!(costs.iter().any(|&cost| cost < -1.0) && costs.iter().any(|&cost| cost > 2.0));
+
+    let grid = SparseCostMatrixConfig {
+        costs: CostDistribution::Grid { levels: 4, scale: 2.0 },
+        ..SparseCostMatrixConfig::new(50, 50, 0.2)
+    };
+    let mut costs: Vec<f64> = generate(1, grid).sparse_values().collect();
+    costs.sort_by(f64::total_cmp);
+    costs.dedup();
+    assert_eq!(costs, vec![0.5, 1.0, 1.5, 2.0]);
+
+    let default: Vec<f64> =
+        generate(1, SparseCostMatrixConfig::new(50, 50, 0.2)).sparse_values().collect();
+    assert!(default.iter().all(|&cost| (0.01..=9.99).contains(&cost)));
+}
+
+#[test]
+fn test_feasibility() {
+    for (rows, columns) in [(40, 40), (20, 60), (60, 20)] {
+        let sparse = SparseCostMatrixConfig::new(rows, columns, 0.0);
+
+        let costs = generate(3, sparse);
+        assert!((0..rows).all(|row| costs.sparse_row(row).count() >= 1));
+
+        let costs = generate(
+            3,
+            SparseCostMatrixConfig { feasibility: Feasibility::RowsAndColumns, ..sparse },
+        );
+        let mut covered_columns = vec![false; columns];
+        for (row, column) in SparseMatrix::sparse_coordinates(&costs) {
+            covered_columns[column] = true;
+            assert!(row < rows);
+        }
+        assert!((0..rows).all(|row| costs.sparse_row(row).count() >= 1));
+        assert!(covered_columns.into_iter().all(|covered| covered));
+
+        let costs =
+            generate(3, SparseCostMatrixConfig { feasibility: Feasibility::Assignment, ..sparse });
+        assert_eq!(costs.number_of_defined_values(), rows.min(columns));
+        assert_eq!(costs.hopcroft_karp().unwrap().len(), rows.min(columns));
+
+        let costs =
+            generate(3, SparseCostMatrixConfig { feasibility: Feasibility::None, ..sparse });
+        assert_eq!(costs.number_of_defined_values(), 0);
+    }
+
+    let square = SparseCostMatrixConfig {
+        feasibility: Feasibility::Assignment,
+        ..SparseCostMatrixConfig::new(200, 200, 0.02)
+    };
+    for seed in 1..=5 {
+        assert_eq!(generate(seed, square).lapmod(1000.0).unwrap().len(), 200);
+    }
+}
+
+#[test]
+fn test_degenerate_shapes_and_densities() {
+    assert_eq!(generate(1, SparseCostMatrixConfig::new(0, 5, 1.0)).number_of_defined_values(), 0);
+    assert_eq!(generate(1, SparseCostMatrixConfig::new(5, 0, 1.0)).number_of_rows(), 5);
+    let none = |density| {
+        SparseCostMatrixConfig {
+            feasibility: Feasibility::None,
+            ..SparseCostMatrixConfig::new(10, 10, density)
+        }
+    };
+    assert_eq!(generate(1, none(f64::NAN)).number_of_defined_values(), 0);
+    assert_eq!(generate(1, none(-1.0)).number_of_defined_values(), 0);
+    assert!(generate(1, none(7.0)).number_of_defined_values() <= 100);
+}
+
+#[test]
+#[should_panic(expected = "low < high")]
+fn test_invalid_uniform_costs() {
+    let config = SparseCostMatrixConfig {
+        costs: CostDistribution::Uniform { low: 1.0, high: 1.0 },
+        ..SparseCostMatrixConfig::new(2, 2, 0.5)
+    };
+    let _ = generate(1, config);
+}
@@ -0,0 +1,152 @@
+//! Integration tests for uniform random spanning trees via Wilson's algorithm.
+#![cfg(feature = "std")]
+
+use std::collections::HashMap;
+
+use geometric_traits::{
+    impls::{CSR2D, SortedVec, SquareCSR2D, SymmetricCSR2D},
+    prelude::*,
+    traits::{
+        RandomSpanningTree, RandomSpanningTreeError, SparseMatrix, VocabularyBuilder,
+        algorithms::randomized_graphs::{complete_graph, cycle_graph, grid_graph},
+    },
+};
+
+fn wrap_undigraph(matrix: SymmetricCSR2D<CSR2D<usize, usize, usize>>) -> UndiGraph<usize> {
+    let order = matrix.order();
+    let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+        .expected_number_of_symbols(order)
+        .symbols((0..order).enumerate())
+        .build()
+        .unwrap();
+    UndiGraph::from((nodes, matrix))
+}
+
+fn tree_edges(tree: &SquareCSR2D<CSR2D<usize, usize, usize>>) -> Vec<(usize, usize)> {
+    SparseMatrix::sparse_coordinates(tree)
+        .filter(|&(source, destination)| source < destination)
+        .collect()
+}
+
+/// Returns the representative of a node in a union-find forest.
+fn find(components: &mut [usize], node: usize) -> usize {
+    if components[node] != node {
+        components[node] = find(components, components[node]);
+    }
+    components[node]
+}
+
+/// Asserts that the tree spans the graph using only edges of the graph.
+fn assert_spanning_tree(graph: &UndiGraph<usize>, tree: &SquareCSR2D<CSR2D<usize, usize, usize>>) {
+    let n = graph.number_of_nodes();
+    assert_eq!(tree.order(), n);
+    let edges = tree_edges(tree);
+    assert_eq!(edges.len(), n.saturating_sub(1));
+    assert_eq!(tree.number_of_defined_values(), 2 * edges.len());
+    for &(source, destination) in &edges {
+        assert!(tree.has_entry(destination, source));
+        assert!(graph.neighbors(source).any(|neighbor| neighbor == destination));
+    }
+    let mut components: Vec<usize> = (0..n).collect();
+    for (source, destination) in edges {
+        let (left, right) = (find(&mut components, source), find(&mut components, destination));
+        assert_ne!(left, right, "the tree has a cycle");
+        components[left] = right;
+    }
+}
+
+#[test]
+fn test_random_spanning_tree_of_grid() {
+    let graph = wrap_undigraph(grid_graph(12, 9));
+    for seed in 0..10 {
+        let tree = graph.random_spanning_tree(seed).unwrap();
+        assert_spanning_tree(&graph, &tree);
+    }
+}
+
+#[test]
+fn test_random_spanning_tree_is_deterministic() {
+    let graph = wrap_undigraph(complete_graph(30));
+    assert_eq!(graph.random_spanning_tree(7).unwrap(), graph.random_spanning_tree(7).unwrap());
+    assert_ne!(graph.random_spanning_tree(7).unwrap(), graph.random_spanning_tree(8).unwrap());
+}
+
+#[test]
+fn test_random_spanning_tree_small_graphs() {
+    let empty = wrap_undigraph(complete_graph(0));
+    assert_eq!(empty.random_spanning_tree(1).unwrap().order(), 0);
+
+    let singleton = wrap_undigraph(complete_graph(1));
+    let tree = singleton.random_spanning_tree(1).unwrap();
+    assert_eq!(tree.order(), 1);
+    assert_eq!(tree.number_of_defined_values(), 0);
+
+    let tree_graph = wrap_undigraph(grid_graph(1, 6));
+    let tree = tree_graph.random_spanning_tree(1).unwrap();
+    assert_eq!(tree_edges(&tree), vec![(0, 1), (1, 2), (2, 3), (3, 4), (4, 5)]);
+}
+
+#[test]
+fn test_random_spanning_tree_ignores_self_loops() {
+    let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+        .expected_number_of_symbols(3)
+        .symbols((0..3).enumerate())
+        .build()
+        .unwrap();
+    let edges: SymmetricCSR2D<_> = UndiEdgesBuilder::default()
+        .expected_number_of_edges(4)
+        .expected_shape(3)
+        .edges([(0, 0), (0, 1), (1, 1), (1, 2)].into_iter())
+        .build()
+        .unwrap();
+    let graph = UndiGraph::from((nodes, edges));
+    let tree = graph.random_spanning_tree(3).unwrap();
+    assert_eq!(tree_edges(&tree), vec![(0, 1), (1, 2)]);
+}
+
+#[test]
+fn test_random_spanning_tree_rejects_disconnected_graphs() {
+    let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+        .expected_number_of_symbols(4)
+        .symbols((0..4).enumerate())
+        .build()
+        .unwrap();
+    let edges: SymmetricCSR2D<_> = UndiEdgesBuilder::default()
+        .expected_number_of_edges(2)
+        .expected_shape(4)
+        .edges([(0, 1), (2, 3)].into_iter())
+        .build()
+        .unwrap();
+    let graph = UndiGraph::from((nodes, edges));
+    assert_eq!(graph.random_spanning_tree(3), Err(RandomSpanningTreeError::DisconnectedGraph));
+}
+
+#[test]
+fn test_random_spanning_tree_is_uniform_on_k4() {
+    // K4 has 4^(4-2) = 16 spanning trees, each expected 1000 times.
+    let graph = wrap_undigraph(complete_graph(4));
+    let mut counts: HashMap<Vec<(usize, usize)>, usize> = HashMap::new();
+    for seed in 0..16_000 {
+        *counts.entry(tree_edges(&graph.random_spanning_tree(seed).unwrap())).or_default() += 1;
+    }
+    assert_eq!(counts.len(), 16);
+    for (tree, count) in counts {
+        assert!((850..=1150).contains(&count), "{tree:?} sampled {count} times");
+    }
+}
+
+#[test]
+fn test_random_spanning_tree_of_cycle_drops_one_edge_uniformly() {
+    let n = 5;
+    let graph = wrap_undigraph(cycle_graph(n));
+    let mut counts = vec![0usize; n];
+    for seed in 0..5_000 {
+        let edges = tree_edges(&graph.random_spanning_tree(seed).unwrap());
+        let cycle_edges = (0..n).map(|i| (i.min((i + 1) % n), i.max((i + 1) % n)));
+        let dropped: Vec<usize> =
+            cycle_edges.enumerate().filter(|(_, e)| !edges.contains(e)).map(|(i, _)| i).collect();
+        assert_eq!(dropped.len(), 1);
+        counts[dropped[0]] += 1;
+    }
+    assert!(counts.iter().all(|&count| (850..=1150).contains(&count)), "{counts:?}");
+}
@@ -3,8 +3,8 @@
 
 use geometric_traits::{
     impls::{CSR2D, SquareCSR2D},
-    prelude::{GenericGraph, MonopartiteGraph, MonoplexGraph, RandomizedDAG},
-    traits::{CycleDetection, randomized_graphs::XorShift64},
+    prelude::{GenericGraph, MonopartiteGraph, MonoplexGraph, RandomizedDAG, SparseMatrix2D},
+    traits::{CycleDetection, Kahn, randomized_graphs::XorShift64},
 };
 
 type SimpleDiGraph = GenericGraph<usize, SquareCSR2D<CSR2D<usize, usize, usize>>>;
@@ -170,3 +170,52 @@ fn test_randomized_dag_seed_zero_is_deterministic() {
     assert_eq!(graph1.number_of_nodes(), graph2.number_of_nodes());
     assert_eq!(graph1.number_of_edges(), graph2.number_of_edges());
 }
+
+#[test]
+fn test_randomized_gnp_dag_is_acyclic() {
+    for seed in 1..20 {
+        let dag = SimpleDiGraph::randomized_gnp_dag(seed, 60, 0.2);
+        let order = dag.edges().kahn().expect("random DAGs must be acyclic");
+        assert_eq!(order.len(), 60);
+    }
+}
+
+#[test]
+fn test_randomized_gnp_dag_complete_is_a_tournament() {
+    let dag = SimpleDiGraph::randomized_gnp_dag(7, 12, 1.0);
+    let edges = dag.edges();
+    assert_eq!(dag.number_of_edges(), 12 * 11 / 2);
+    assert!(edges.kahn().is_ok());
+    for u in 0..12 {
+        for v in edges.sparse_row(u) {
+            assert!(!edges.sparse_row(v).any(|w| w == u), "edge {u}-{v} is doubled");
+        }
+    }
+}
+
+#[test]
+fn test_randomized_gnp_dag_degenerate_inputs() {
+    assert_eq!(SimpleDiGraph::randomized_gnp_dag(1, 0, 0.5).number_of_nodes(), 0);
+    assert_eq!(SimpleDiGraph::randomized_gnp_dag(1, 1, 0.5).number_of_edges(), 0);
+    assert_eq!(SimpleDiGraph::randomized_gnp_dag(1, 10, 0.0).number_of_edges(), 0);
+    assert_eq!(SimpleDiGraph::randomized_gnp_dag(1, 10, f64::NAN).number_of_edges(), 0);
+}
+
+#[test]
+fn test_randomized_gnp_dag_deterministic_and_edge_count() {
+    assert!(
+        SimpleDiGraph::randomized_gnp_dag(42, 200, 0.05).edges()
+            == SimpleDiGraph::randomized_gnp_dag(42, 200, 0.05).edges()
+    );
+    // Expected edges: 0.05 * 200 * 199 / 2 = 995.
+    let edges = SimpleDiGraph::randomized_gnp_dag(42, 200, 0.05).number_of_edges();
+    assert!((850..=1150).contains(&edges), "edges={edges}");
+}
+
+#[test]
+fn test_randomized_gnp_dag_large() {
+    let n = 100_000;
+    let dag = SimpleDiGraph::randomized_gnp_dag(3, n, 0.000_04);
+    assert_eq!(dag.number_of_nodes(), n);
+    assert_eq!(dag.edges().kahn().unwrap().len(), n);
+}
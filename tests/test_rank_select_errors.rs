@@ -0,0 +1,66 @@
+//! Tests for the fallible `try_rank` and `try_select` queries.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    errors::{GeometricError, RankSelectError},
+    prelude::*,
+    traits::algorithms::randomized_graphs::{
+        SparseCostMatrixConfig, erdos_renyi_gnp, random_sparse_cost_matrix,
+    },
+};
+
+fn assert_fallible_queries_agree<M>(matrix: &M)
+where
+    M: SizedSparseMatrix2D<RowIndex = usize, ColumnIndex = usize, SparseIndex = usize>,
+{
+    let number_of_rows = matrix.number_of_rows();
+    for row in 0..=number_of_rows {
+        for column in 0..=matrix.number_of_columns() {
+            let result = matrix.try_rank(row, column);
+            if row == number_of_rows {
+                assert_eq!(result, Err(RankSelectError::RowOutOfBounds { row, number_of_rows }));
+            } else if matrix.has_entry(row, column) {
+                let rank = matrix.rank(&(row, column));
+                assert_eq!(result, Ok(rank));
+                assert_eq!(matrix.try_select(rank), Ok((row, column)));
+            } else {
+                assert_eq!(result, Err(RankSelectError::MissingEntry { row, column }));
+            }
+        }
+    }
+    let number_of_defined_values = matrix.number_of_defined_values();
+    assert_eq!(
+        matrix.try_select(number_of_defined_values),
+        Err(RankSelectError::SparseIndexOutOfBounds {
+            sparse_index: number_of_defined_values,
+            number_of_defined_values
+        })
+    );
+}
+
+#[test]
+fn test_fallible_queries_match_panicking_ones() {
+    for seed in 1..=4 {
+        assert_fallible_queries_agree(&erdos_renyi_gnp(seed, 12, 0.3));
+        assert_fallible_queries_agree(&random_sparse_cost_matrix(
+            seed,
+            &SparseCostMatrixConfig::new(9, 14, 0.3),
+        ));
+    }
+    assert_fallible_queries_agree(&CSR2D::<usize, usize, usize>::default());
+}
+
+#[test]
+fn test_sparse_value_at_does_not_panic_on_untrusted_coordinates() {
+    let matrix = random_sparse_cost_matrix(3, &SparseCostMatrixConfig::new(4, 6, 0.5));
+    assert_eq!(matrix.sparse_value_at(4, 0), None);
+    assert_eq!(matrix.sparse_value_at(usize::MAX, usize::MAX), None);
+    for (row, entries) in matrix.rows_with_values() {
+        for (column, value) in entries {
+            assert_eq!(matrix.sparse_value_at(row, column), Some(value));
+        }
+    }
+
+    let error: GeometricError = matrix.try_rank(7, 0).unwrap_err().into();
+    assert_eq!(error.to_string(), "The row 7 is out of bounds for a matrix with 4 rows.");
+}
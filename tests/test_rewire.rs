@@ -0,0 +1,152 @@
+//! Tests for the degree-preserving rewiring of undirected and directed graphs.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::{CSR2D, SortedVec, SquareCSR2D, SymmetricCSR2D},
+    prelude::*,
+    traits::{
+        DirectedRewire, EdgesBuilder, Rewire, RewireError, VocabularyBuilder,
+        algorithms::randomized_graphs::{XorShift64, barabasi_albert, complete_graph},
+    },
+};
+
+fn undirected(order: usize, edges: &[(usize, usize)]) -> UndiGraph<usize> {
+    let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+        .expected_number_of_symbols(order)
+        .symbols((0..order).enumerate())
+        .build()
+        .unwrap();
+    let mut edges: Vec<(usize, usize)> =
+        edges.iter().map(|&(left, right)| (left.min(right), left.max(right))).collect();
+    edges.sort_unstable();
+    let edges: SymmetricCSR2D<CSR2D<usize, usize, usize>> = UndiEdgesBuilder::default()
+        .expected_number_of_edges(edges.len())
+        .expected_shape(order)
+        .edges(edges.into_iter())
+        .build()
+        .unwrap();
+    UndiGraph::from((nodes, edges))
+}
+
+fn directed(order: usize, edges: &[(usize, usize)]) -> DiGraph<usize> {
+    let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+        .expected_number_of_symbols(order)
+        .symbols((0..order).enumerate())
+        .build()
+        .unwrap();
+    let mut edges = edges.to_vec();
+    edges.sort_unstable();
+    let edges: SquareCSR2D<_> = DiEdgesBuilder::default()
+        .expected_number_of_edges(edges.len())
+        .expected_shape(order)
+        .edges(edges.into_iter())
+        .build()
+        .unwrap();
+    DiGraph::from((nodes, edges))
+}
+
+/// Returns the out-degrees and in-degrees of the edges.
+fn degrees(order: usize, edges: &[(usize, usize)]) -> (Vec<usize>, Vec<usize>) {
+    let mut out_degrees = vec![0; order];
+    let mut in_degrees = vec![0; order];
+    for &(source, destination) in edges {
+        out_degrees[source] += 1;
+        in_degrees[destination] += 1;
+    }
+    (out_degrees, in_degrees)
+}
+
+#[test]
+fn test_rewire_preserves_degrees() {
+    for seed in 1..=5 {
+        let order = 40;
+        let matrix = barabasi_albert(seed, order, 3);
+        let edges: Vec<(usize, usize)> = SparseMatrix::sparse_coordinates(&matrix).collect();
+        let graph = undirected(
+            order,
+            &edges.iter().copied().filter(|&(left, right)| left < right).collect::<Vec<_>>(),
+        );
+
+        let rewired = graph.rewire(10 * edges.len() / 2, seed).unwrap();
+        let rewired_edges: Vec<(usize, usize)> =
+            SparseMatrix::sparse_coordinates(&rewired).collect();
+        assert_eq!(degrees(order, &rewired_edges), degrees(order, &edges));
+        assert!(rewired_edges.iter().all(|&(left, right)| left != right));
+        assert!(rewired_edges.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(rewired_edges.iter().all(|&(left, right)| rewired.has_entry(right, left)));
+        assert_ne!(rewired_edges, edges, "seed {seed}");
+
+        assert_eq!(graph.rewire(100, seed).unwrap(), graph.rewire(100, seed).unwrap());
+        assert_ne!(graph.rewire(100, seed).unwrap(), graph.rewire(100, seed + 1).unwrap());
+    }
+}
+
+#[test]
+fn test_rewire_directed_preserves_degrees() {
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(31));
+    for seed in 1..=5 {
+        let order = 30;
+        let mut edges = Vec::new();
+        for source in 0..order {
+            for destination in 0..order {
+                if source != destination && rng.next().unwrap() % 8 == 0 {
+                    edges.push((source, destination));
+                }
+            }
+        }
+        let graph = directed(order, &edges);
+
+        let rewired = graph.rewire_directed(10 * edges.len(), seed).unwrap();
+        let rewired_edges: Vec<(usize, usize)> =
+            SparseMatrix::sparse_coordinates(&rewired).collect();
+        assert_eq!(degrees(order, &rewired_edges), degrees(order, &edges));
+        assert!(rewired_edges.iter().all(|&(source, destination)| source != destination));
+        assert!(rewired_edges.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_ne!(rewired_edges, edges, "seed {seed}");
+        assert_eq!(rewired, graph.rewire_directed(10 * edges.len(), seed).unwrap());
+    }
+}
+
+#[test]
+fn test_rewire_special_graphs() {
+    // Without swaps, the graph is copied as it is, self-loops included.
+    let looped = undirected(5, &[(0, 0), (0, 1), (2, 3), (3, 4)]);
+    let copy = looped.rewire(0, 1).unwrap();
+    let copied_edges: Vec<(usize, usize)> = SparseMatrix::sparse_coordinates(&copy).collect();
+    assert_eq!(copied_edges, vec![(0, 0), (0, 1), (1, 0), (2, 3), (3, 2), (3, 4), (4, 3)]);
+
+    // The self-loops stay in place while the other edges are swapped.
+    let rewired = looped.rewire(1, 3).unwrap();
+    assert!(rewired.has_entry(0, 0));
+    assert_eq!(SparseMatrix::sparse_coordinates(&rewired).count(), 7);
+
+    // Two disjoint directed edges can only be swapped into each other.
+    let pair = directed(4, &[(0, 1), (2, 3)]);
+    let swapped = pair.rewire_directed(1, 5).unwrap();
+    let swapped_edges: Vec<(usize, usize)> = SparseMatrix::sparse_coordinates(&swapped).collect();
+    assert_eq!(swapped_edges, vec![(0, 3), (2, 1)]);
+}
+
+#[test]
+fn test_rewire_errors() {
+    let single = undirected(3, &[(0, 0), (1, 2)]);
+    assert_eq!(single.rewire(1, 1), Err(RewireError::TooFewEdges { edges: 1 }));
+    assert!(single.rewire(0, 1).is_ok());
+
+    // A complete graph admits no swap at all.
+    let matrix = complete_graph(5);
+    let edges: Vec<(usize, usize)> =
+        SparseMatrix::sparse_coordinates(&matrix).filter(|&(left, right)| left < right).collect();
+    let complete = undirected(5, &edges);
+    assert_eq!(
+        complete.rewire(2, 1),
+        Err(RewireError::SwapAttemptsExceeded { requested: 2, performed: 0, attempts: 200 })
+    );
+
+    // A directed triangle cannot be swapped without creating a self-loop.
+    let triangle = directed(3, &[(0, 1), (1, 2), (2, 0)]);
+    assert_eq!(
+        triangle.rewire_directed(1, 1),
+        Err(RewireError::SwapAttemptsExceeded { requested: 1, performed: 0, attempts: 100 })
+    );
+}
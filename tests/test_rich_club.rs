@@ -0,0 +1,118 @@
+//! Tests for the rich-club coefficients of undirected graphs.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::{CSR2D, SortedVec, SymmetricCSR2D},
+    prelude::*,
+    traits::{
+        EdgesBuilder, RichClub, RichClubConfig, RichClubError, VocabularyBuilder,
+        algorithms::randomized_graphs::{barabasi_albert, erdos_renyi_gnp},
+    },
+};
+
+fn undirected(order: usize, edges: &[(usize, usize)]) -> UndiGraph<usize> {
+    let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+        .expected_number_of_symbols(order)
+        .symbols((0..order).enumerate())
+        .build()
+        .unwrap();
+    let mut edges: Vec<(usize, usize)> =
+        edges.iter().map(|&(left, right)| (left.min(right), left.max(right))).collect();
+    edges.sort_unstable();
+    let edges: SymmetricCSR2D<CSR2D<usize, usize, usize>> = UndiEdgesBuilder::default()
+        .expected_number_of_edges(edges.len())
+        .expected_shape(order)
+        .edges(edges.into_iter())
+        .build()
+        .unwrap();
+    UndiGraph::from((nodes, edges))
+}
+
+fn from_matrix(
+    order: usize,
+    matrix: &SymmetricCSR2D<CSR2D<usize, usize, usize>>,
+) -> UndiGraph<usize> {
+    let edges: Vec<(usize, usize)> =
+        SparseMatrix::sparse_coordinates(matrix).filter(|&(left, right)| left < right).collect();
+    undirected(order, &edges)
+}
+
+#[test]
+#[allow(clippy::cast_precision_loss)]
+fn test_rich_club_matches_the_definition() {
+    for seed in 1..=10 {
+        let order = 40;
+        let matrix = barabasi_albert(seed, order, 2);
+        let edges: Vec<(usize, usize)> = SparseMatrix::sparse_coordinates(&matrix)
+            .filter(|&(left, right)| left < right)
+            .collect();
+        let graph = undirected(order, &edges);
+        let mut degrees = vec![0_usize; order];
+        for &(left, right) in &edges {
+            degrees[left] += 1;
+            degrees[right] += 1;
+        }
+        let maximal_degree = degrees.iter().copied().max().unwrap();
+
+        let curve = graph.rich_club_curve();
+        assert_eq!(curve.len(), maximal_degree);
+        for k in 0..=maximal_degree + 1 {
+            let members = degrees.iter().filter(|&&degree| degree > k).count() as f64;
+            let club_edges = edges
+                .iter()
+                .filter(|&&(left, right)| degrees[left] > k && degrees[right] > k)
+                .count() as f64;
+            let expected = (members >= 2.0).then(|| 2.0 * club_edges / (members * (members - 1.0)));
+            assert_eq!(graph.rich_club(k), expected, "seed {seed}, k {k}");
+            assert_eq!(curve.get(k).copied().flatten(), expected, "seed {seed}, k {k}");
+        }
+    }
+}
+
+#[test]
+fn test_rich_club_of_special_graphs() {
+    // Self-loops are ignored.
+    let graph = undirected(4, &[(0, 0), (0, 1), (1, 2), (2, 3), (0, 3)]);
+    assert_eq!(graph.rich_club_curve(), vec![Some(4.0 / 6.0), Some(4.0 / 6.0)]);
+    assert_eq!(graph.rich_club(2), None);
+
+    let empty = undirected(3, &[]);
+    assert!(empty.rich_club_curve().is_empty());
+    assert_eq!(empty.rich_club(0), None);
+
+    // The hub of a star is alone in its club.
+    let star = undirected(5, &[(0, 1), (0, 2), (0, 3), (0, 4)]);
+    assert_eq!(star.rich_club_curve(), vec![Some(0.4), None, None, None]);
+}
+
+#[test]
+fn test_normalized_rich_club() {
+    // Every random graph with the degrees of a perfect matching is one.
+    let matching = undirected(8, &[(0, 5), (1, 2), (3, 7), (4, 6)]);
+    let normalized = matching.normalized_rich_club_curve(&RichClubConfig::default()).unwrap();
+    assert_eq!(normalized.len(), 1);
+    assert!((normalized[0].unwrap() - 1.0).abs() < 1e-12);
+
+    let graph = from_matrix(60, &erdos_renyi_gnp(3, 60, 0.1));
+    let config = RichClubConfig { samples: 5, seed: 11 };
+    let normalized = graph.normalized_rich_club_curve(&config).unwrap();
+    assert_eq!(normalized, graph.normalized_rich_club_curve(&config).unwrap());
+    assert_eq!(normalized.len(), graph.rich_club_curve().len());
+    for (coefficient, ratio) in graph.rich_club_curve().into_iter().zip(&normalized) {
+        if coefficient.is_none() {
+            assert_eq!(*ratio, None);
+        }
+    }
+    // Erasing the self-loops and repeated edges of the pairings only removes
+    // edges from the random graphs.
+    assert!(normalized[0].unwrap() >= 1.0);
+    assert_ne!(
+        normalized,
+        graph.normalized_rich_club_curve(&RichClubConfig { seed: 12, ..config }).unwrap()
+    );
+
+    assert_eq!(
+        graph.normalized_rich_club_curve(&RichClubConfig { samples: 0, seed: 1 }),
+        Err(RichClubError::ZeroSamples)
+    );
+}
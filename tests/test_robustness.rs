@@ -0,0 +1,225 @@
+//! Tests for the robustness profiles of undirected graphs.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::{CSR2D, SortedVec, SymmetricCSR2D},
+    prelude::*,
+    traits::{
+        EdgesBuilder, RemovalOrder, Robustness, RobustnessError, VocabularyBuilder,
+        algorithms::randomized_graphs::{XorShift64, barabasi_albert, erdos_renyi_gnp},
+    },
+};
+
+fn undirected(order: usize, edges: &[(usize, usize)]) -> UndiGraph<usize> {
+    let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+        .expected_number_of_symbols(order)
+        .symbols((0..order).enumerate())
+        .build()
+        .unwrap();
+    let mut edges: Vec<(usize, usize)> =
+        edges.iter().map(|&(left, right)| (left.min(right), left.max(right))).collect();
+    edges.sort_unstable();
+    let edges: SymmetricCSR2D<CSR2D<usize, usize, usize>> = UndiEdgesBuilder::default()
+        .expected_number_of_edges(edges.len())
+        .expected_shape(order)
+        .edges(edges.into_iter())
+        .build()
+        .unwrap();
+    UndiGraph::from((nodes, edges))
+}
+
+fn adjacency(order: usize, edges: &[(usize, usize)]) -> Vec<Vec<usize>> {
+    let mut neighbors = vec![Vec::new(); order];
+    for &(left, right) in edges {
+        if left != right {
+            neighbors[left].push(right);
+            neighbors[right].push(left);
+        }
+    }
+    neighbors
+}
+
+/// Returns the size of the largest connected component of the nodes not
+/// removed.
+fn largest_component(neighbors: &[Vec<usize>], removed: &[usize]) -> usize {
+    let mut visited = vec![false; neighbors.len()];
+    for &node in removed {
+        visited[node] = true;
+    }
+    let mut largest = 0;
+    for start in 0..neighbors.len() {
+        if std::mem::replace(&mut visited[start], true) {
+            continue;
+        }
+        let mut component = vec![start];
+        let mut position = 0;
+        while let Some(&node) = component.get(position) {
+            position += 1;
+            for &neighbor in &neighbors[node] {
+                if !std::mem::replace(&mut visited[neighbor], true) {
+                    component.push(neighbor);
+                }
+            }
+        }
+        largest = largest.max(component.len());
+    }
+    largest
+}
+
+/// Returns the distances and the numbers of shortest paths from the source.
+fn shortest_paths(neighbors: &[Vec<usize>], source: usize) -> (Vec<usize>, Vec<f64>) {
+    let mut distances = vec![usize::MAX; neighbors.len()];
+    let mut counts = vec![0.0; neighbors.len()];
+    distances[source] = 0;
+    counts[source] = 1.0;
+    let mut queue = vec![source];
+    let mut position = 0;
+    while let Some(&node) = queue.get(position) {
+        position += 1;
+        for &neighbor in &neighbors[node] {
+            if distances[neighbor] == usize::MAX {
+                distances[neighbor] = distances[node] + 1;
+                queue.push(neighbor);
+            }
+            if distances[neighbor] == distances[node] + 1 {
+                counts[neighbor] += counts[node];
+            }
+        }
+    }
+    (distances, counts)
+}
+
+#[test]
+#[allow(clippy::cast_precision_loss)]
+fn test_robustness_profile_matches_brute_force() {
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(23));
+    for seed in 1..=6 {
+        let order = 30;
+        let matrix = erdos_renyi_gnp(seed, order, 0.08);
+        let edges: Vec<(usize, usize)> = SparseMatrix::sparse_coordinates(&matrix)
+            .filter(|&(left, right)| left < right)
+            .collect();
+        let graph = undirected(order, &edges);
+        let neighbors = adjacency(order, &edges);
+
+        let mut nodes: Vec<usize> = (0..order).collect();
+        for i in (1..order).rev() {
+            nodes.swap(i, usize::try_from(rng.next().unwrap() % (i as u64 + 1)).unwrap());
+        }
+        for removed in [order, order / 2, 0] {
+            let custom = RemovalOrder::Custom(nodes[..removed].to_vec());
+            let profile = graph.robustness_profile(&custom).unwrap();
+            assert_eq!(profile.len(), removed + 1);
+            for (step, fraction) in profile.iter().enumerate() {
+                let expected = largest_component(&neighbors, &nodes[..step]) as f64;
+                assert!((fraction - expected / order as f64).abs() < 1e-12, "seed {seed}");
+            }
+        }
+    }
+}
+
+#[test]
+#[allow(clippy::cast_precision_loss)]
+fn test_robustness_profile_targeted_orders() {
+    for seed in 1..=5 {
+        let order = 30;
+        let matrix = barabasi_albert(seed, order, 2);
+        let edges: Vec<(usize, usize)> = SparseMatrix::sparse_coordinates(&matrix)
+            .filter(|&(left, right)| left < right)
+            .collect();
+        let graph = undirected(order, &edges);
+        let neighbors = adjacency(order, &edges);
+
+        let mut by_degree: Vec<usize> = (0..order).collect();
+        by_degree.sort_by_key(|&node| std::cmp::Reverse(neighbors[node].len()));
+        assert_eq!(
+            graph.robustness_profile(&RemovalOrder::Degree).unwrap(),
+            graph.robustness_profile(&RemovalOrder::Custom(by_degree)).unwrap()
+        );
+
+        // The betweenness of each node from the pairs of nodes around it.
+        let paths: Vec<(Vec<usize>, Vec<f64>)> =
+            (0..order).map(|source| shortest_paths(&neighbors, source)).collect();
+        let betweenness: Vec<f64> = (0..order)
+            .map(|node| {
+                let mut score = 0.0;
+                for source in 0..order {
+                    for target in source + 1..order {
+                        let (distances, counts) = &paths[source];
+                        let (node_distances, node_counts) = &paths[node];
+                        if node != source
+                            && node != target
+                            && distances[target] != usize::MAX
+                            && distances[node] + node_distances[target] == distances[target]
+                        {
+                            score += counts[node] * node_counts[target] / counts[target];
+                        }
+                    }
+                }
+                (score * 1e9).round() / 1e9
+            })
+            .collect();
+        let mut by_betweenness: Vec<usize> = (0..order).collect();
+        by_betweenness.sort_by(|&left, &right| betweenness[right].total_cmp(&betweenness[left]));
+        assert_eq!(
+            graph.robustness_profile(&RemovalOrder::Betweenness).unwrap(),
+            graph.robustness_profile(&RemovalOrder::Custom(by_betweenness)).unwrap()
+        );
+
+        // Targeted attacks break scale-free graphs faster than failures.
+        let area = |profile: Vec<f64>| profile.iter().sum::<f64>();
+        let random = area(graph.robustness_profile(&RemovalOrder::Random { seed }).unwrap());
+        assert!(area(graph.robustness_profile(&RemovalOrder::Degree).unwrap()) < random);
+    }
+}
+
+#[test]
+fn test_robustness_profile_of_special_graphs() {
+    // The middle of a path carries the most shortest paths.
+    let path = undirected(5, &[(0, 1), (1, 2), (2, 3), (3, 4)]);
+    assert_eq!(
+        path.robustness_profile(&RemovalOrder::Betweenness).unwrap(),
+        vec![1.0, 0.4, 0.4, 0.2, 0.2, 0.0]
+    );
+
+    // Self-loops are ignored.
+    let looped = undirected(3, &[(0, 0), (1, 2)]);
+    assert_eq!(
+        looped.robustness_profile(&RemovalOrder::Degree).unwrap(),
+        looped.robustness_profile(&RemovalOrder::Custom(vec![1, 2, 0])).unwrap()
+    );
+    assert_eq!(looped.robustness_profile(&RemovalOrder::Custom(vec![])).unwrap(), vec![2.0 / 3.0]);
+
+    let empty = undirected(0, &[]);
+    assert_eq!(empty.robustness_profile(&RemovalOrder::Random { seed: 1 }).unwrap(), vec![0.0]);
+}
+
+#[test]
+fn test_robustness_profile_random_order() {
+    let matrix = erdos_renyi_gnp(4, 50, 0.1);
+    let edges: Vec<(usize, usize)> =
+        SparseMatrix::sparse_coordinates(&matrix).filter(|&(left, right)| left < right).collect();
+    let graph = undirected(50, &edges);
+    let full = graph.robustness_profile(&RemovalOrder::Custom(vec![])).unwrap()[0];
+
+    let profile = graph.robustness_profile(&RemovalOrder::Random { seed: 8 }).unwrap();
+    assert_eq!(profile, graph.robustness_profile(&RemovalOrder::Random { seed: 8 }).unwrap());
+    assert_ne!(profile, graph.robustness_profile(&RemovalOrder::Random { seed: 9 }).unwrap());
+    assert_eq!(profile.len(), 51);
+    assert!((profile[0] - full).abs() < f64::EPSILON);
+    assert!(profile[50].abs() < f64::EPSILON);
+    assert!(profile.windows(2).all(|pair| pair[1] <= pair[0]));
+}
+
+#[test]
+fn test_robustness_profile_errors() {
+    let graph = undirected(3, &[(0, 1), (1, 2)]);
+    assert_eq!(
+        graph.robustness_profile(&RemovalOrder::Custom(vec![0, 3])),
+        Err(RobustnessError::UnknownNode { node: 3, number_of_nodes: 3 })
+    );
+    assert_eq!(
+        graph.robustness_profile(&RemovalOrder::Custom(vec![1, 0, 1])),
+        Err(RobustnessError::DuplicateNode(1))
+    );
+}
@@ -0,0 +1,74 @@
+//! Tests for the `rows_with_values` iterator of sparse valued matrices.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::{PaddedMatrix2D, ValuedCSR2D},
+    prelude::*,
+    traits::algorithms::randomized_graphs::{SparseCostMatrixConfig, random_sparse_cost_matrix},
+};
+
+type Entries = Vec<(usize, Vec<(usize, f64)>)>;
+
+fn manual_rows<M>(matrix: &M) -> Entries
+where
+    M: SparseValuedMatrix2D<RowIndex = usize, ColumnIndex = usize, Value = f64>,
+{
+    matrix
+        .row_indices()
+        .map(|row| (row, matrix.sparse_row(row).zip(matrix.sparse_row_values(row)).collect()))
+        .collect()
+}
+
+fn rows_with_values<M>(matrix: &M) -> Entries
+where
+    M: SparseValuedMatrix2D<RowIndex = usize, ColumnIndex = usize, Value = f64>,
+{
+    matrix.rows_with_values().map(|(row, entries)| (row, entries.collect())).collect()
+}
+
+#[test]
+fn test_rows_with_values_matches_manual_zip() {
+    for seed in 1..=5 {
+        let matrix = random_sparse_cost_matrix(seed, &SparseCostMatrixConfig::new(12, 17, 0.3));
+        assert_eq!(rows_with_values(&matrix), manual_rows(&matrix));
+
+        let padded = PaddedMatrix2D::new(&matrix, |_: (usize, usize)| 1000.0).unwrap();
+        assert_eq!(rows_with_values(&padded), manual_rows(&padded));
+    }
+    assert!(rows_with_values(&ValuedCSR2D::<usize, usize, usize, f64>::default()).is_empty());
+}
+
+#[test]
+fn test_rows_with_values_iterates_from_both_ends() {
+    let matrix = random_sparse_cost_matrix(7, &SparseCostMatrixConfig::new(10, 10, 0.4));
+    let forward = rows_with_values(&matrix);
+
+    let backward: Entries = matrix
+        .rows_with_values()
+        .rev()
+        .map(|(row, entries)| (row, entries.rev().collect::<Vec<_>>()))
+        .collect();
+    assert_eq!(backward.len(), forward.len());
+    for ((row, entries), (backward_row, mut backward_entries)) in
+        forward.iter().zip(backward.into_iter().rev())
+    {
+        backward_entries.reverse();
+        assert_eq!((*row, entries), (backward_row, &backward_entries));
+    }
+
+    // Alternating between the two ends meets in the middle of the row.
+    for (row, mut entries) in matrix.rows_with_values() {
+        let expected = &forward[row].1;
+        let mut collected = Vec::new();
+        let mut collected_back = Vec::new();
+        while let Some(entry) = entries.next() {
+            collected.push(entry);
+            match entries.next_back() {
+                Some(entry) => collected_back.push(entry),
+                None => break,
+            }
+        }
+        collected.extend(collected_back.into_iter().rev());
+        assert_eq!(&collected, expected);
+    }
+}
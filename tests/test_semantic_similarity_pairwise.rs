@@ -0,0 +1,117 @@
+//! Tests for the batched all-pairs `Resnik` and `Lin` similarity matrices.
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::random_dag;
+use geometric_traits::{
+    impls::{CSR2D, SortedVec, SquareCSR2D, ValuedCSR2D},
+    prelude::*,
+    traits::{
+        Lin, Resnik, ScalarSimilarity, VocabularyBuilder, algorithms::randomized_graphs::XorShift64,
+    },
+};
+
+fn wrap_digraph(matrix: SquareCSR2D<CSR2D<usize, usize, usize>>) -> DiGraph<usize> {
+    let order = matrix.order();
+    let nodes: SortedVec<usize> = GenericVocabularyBuilder::default()
+        .expected_number_of_symbols(order)
+        .symbols((0..order).enumerate())
+        .build()
+        .unwrap();
+    DiGraph::from((nodes, matrix))
+}
+
+fn random_occurrences(seed: u64, number_of_nodes: usize) -> Vec<usize> {
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(seed));
+    (0..number_of_nodes).map(|_| usize::try_from(rng.next().unwrap() % 5).unwrap() + 1).collect()
+}
+
+/// Checks that the sparse matrix holds exactly the pairs of distinct nodes
+/// whose scalar similarity is at least the threshold, with the same value.
+fn assert_matches_scalar<S>(
+    similarity: &S,
+    matrix: &ValuedCSR2D<usize, usize, usize, f64>,
+    number_of_nodes: usize,
+    threshold: f64,
+) where
+    S: ScalarSimilarity<usize, usize, Similarity = f64>,
+{
+    let emitted: Vec<((usize, usize), f64)> =
+        SparseMatrix::sparse_coordinates(matrix).zip(matrix.sparse_values()).collect();
+    for &((left, right), value) in &emitted {
+        assert_ne!(left, right);
+        let expected = similarity.similarity(&left, &right);
+        assert!((value - expected).abs() < 1e-9, "({left}, {right}): {value} vs {expected}");
+        assert!(value >= threshold);
+    }
+    let mut emitted_pairs = emitted.iter().map(|&(pair, _)| pair);
+    for left in 0..number_of_nodes {
+        for right in 0..number_of_nodes {
+            if left != right && similarity.similarity(&left, &right) >= threshold {
+                assert!(
+                    emitted_pairs.any(|pair| pair == (left, right)),
+                    "missing pair ({left}, {right})"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_resnik_pairwise_matches_scalar_on_random_dags() {
+    for seed in 0..10 {
+        let graph = wrap_digraph(random_dag(seed, 30, 0.1));
+        let resnik = graph.resnik(&random_occurrences(seed, 30)).unwrap();
+        for threshold in [f64::EPSILON, 0.5, 1.5] {
+            let matrix = resnik.pairwise_similarities(threshold);
+            assert_matches_scalar(&resnik, &matrix, 30, threshold);
+        }
+    }
+}
+
+#[test]
+fn test_lin_pairwise_matches_scalar_on_random_dags() {
+    for seed in 0..10 {
+        let graph = wrap_digraph(random_dag(seed, 30, 0.1));
+        let lin = graph.lin(&random_occurrences(seed, 30)).unwrap();
+        for threshold in [f64::EPSILON, 0.3, 0.7] {
+            let matrix = lin.pairwise_similarities(threshold);
+            assert_matches_scalar(&lin, &matrix, 30, threshold);
+        }
+    }
+}
+
+#[test]
+fn test_pairwise_similarities_are_symmetric() {
+    let graph = wrap_digraph(random_dag(42, 60, 0.05));
+    let lin = graph.lin(&random_occurrences(42, 60)).unwrap();
+    let matrix = lin.pairwise_similarities(0.0);
+    let entries: Vec<((usize, usize), f64)> =
+        SparseMatrix::sparse_coordinates(&matrix).zip(matrix.sparse_values()).collect();
+    assert!(!entries.is_empty());
+    for &((left, right), value) in &entries {
+        let mirrored = entries.iter().find(|&&(pair, _)| pair == (right, left)).unwrap();
+        assert!((mirrored.1 - value).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn test_pairwise_similarities_skip_pairs_without_common_ancestor() {
+    // Two disjoint chains: 0 -> 1 -> 2 and 3 -> 4.
+    let graph = wrap_digraph(
+        DiEdgesBuilder::default()
+            .expected_number_of_edges(3)
+            .expected_shape(5)
+            .edges([(0, 1), (1, 2), (3, 4)].into_iter())
+            .build()
+            .unwrap(),
+    );
+    let resnik = graph.resnik(&[1, 1, 1, 1, 1]).unwrap();
+    let matrix = resnik.pairwise_similarities(0.0);
+    assert_eq!(
+        SparseMatrix::sparse_coordinates(&matrix).collect::<Vec<_>>(),
+        vec![(0, 1), (0, 2), (1, 0), (1, 2), (2, 0), (2, 1), (3, 4), (4, 3)]
+    );
+    assert!(resnik.pairwise_similarities(f64::INFINITY).is_empty());
+}
@@ -0,0 +1,206 @@
+//! Tests for the `serde` support of the CSR matrices and the named graphs.
+#![cfg(all(feature = "serde", feature = "std"))]
+
+use geometric_traits::{
+    impls::{CSR2D, SortedVec, SquareCSR2D, SymmetricCSR2D, UpperTriangularCSR2D, ValuedCSR2D},
+    prelude::*,
+    traits::{EdgesBuilder, VocabularyBuilder},
+};
+use serde::{Serialize, de::DeserializeOwned};
+
+type TestCSR2D = CSR2D<usize, usize, usize>;
+
+fn round_trip<T: Serialize + DeserializeOwned + PartialEq + core::fmt::Debug>(value: &T) {
+    let json = serde_json::to_string(value).unwrap();
+    let restored: T = serde_json::from_str(&json).unwrap();
+    assert_eq!(&restored, value, "round trip through {json} changed the value");
+}
+
+fn sample_csr() -> TestCSR2D {
+    let mut csr: TestCSR2D = SparseMatrixMut::with_sparse_shape((4, 5));
+    MatrixMut::add(&mut csr, (0, 1)).unwrap();
+    MatrixMut::add(&mut csr, (0, 4)).unwrap();
+    MatrixMut::add(&mut csr, (2, 0)).unwrap();
+    csr
+}
+
+fn sample_nodes() -> SortedVec<String> {
+    GenericVocabularyBuilder::default()
+        .expected_number_of_symbols(3)
+        .symbols(["a", "b", "c"].into_iter().map(str::to_string).enumerate())
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_csr2d_round_trip_preserves_structure() {
+    let csr = sample_csr();
+    round_trip(&csr);
+
+    let json = serde_json::to_string(&csr).unwrap();
+    let restored: TestCSR2D = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.number_of_rows(), 4);
+    assert_eq!(restored.number_of_non_empty_rows(), 2);
+    assert_eq!(restored.sparse_row(0).collect::<Vec<_>>(), vec![1, 4]);
+
+    round_trip(&TestCSR2D::default());
+}
+
+#[test]
+fn test_csr2d_rejects_corrupted_input() {
+    let cases = [
+        // Offsets must start at zero.
+        r#"{"number_of_rows":1,"number_of_columns":2,"offsets":[1,1],"column_indices":[0]}"#,
+        // Offsets must not be empty.
+        r#"{"number_of_rows":0,"number_of_columns":0,"offsets":[],"column_indices":[]}"#,
+        // More offsets than rows.
+        r#"{"number_of_rows":1,"number_of_columns":2,"offsets":[0,1,2],"column_indices":[0,1]}"#,
+        // Last offset does not match the number of columns.
+        r#"{"number_of_rows":1,"number_of_columns":2,"offsets":[0,1],"column_indices":[0,1]}"#,
+        // Decreasing offsets.
+        r#"{"number_of_rows":2,"number_of_columns":2,"offsets":[0,2,1],"column_indices":[0]}"#,
+        // Unsorted columns within a row.
+        r#"{"number_of_rows":1,"number_of_columns":3,"offsets":[0,2],"column_indices":[2,1]}"#,
+        // Duplicated columns within a row.
+        r#"{"number_of_rows":1,"number_of_columns":3,"offsets":[0,2],"column_indices":[1,1]}"#,
+        // Column out of bounds.
+        r#"{"number_of_rows":1,"number_of_columns":2,"offsets":[0,1],"column_indices":[2]}"#,
+    ];
+    for case in cases {
+        assert!(serde_json::from_str::<TestCSR2D>(case).is_err(), "accepted corrupted {case}");
+    }
+}
+
+#[test]
+fn test_csr2d_round_trip_with_narrow_indices() {
+    let json = serde_json::to_string(&{
+        let mut csr: CSR2D<u16, u8, u8> = SparseMatrixMut::with_sparse_shape((255, 2));
+        for row in 0..255_u8 {
+            MatrixMut::add(&mut csr, (row, 0)).unwrap();
+        }
+        csr
+    })
+    .unwrap();
+    assert!(serde_json::from_str::<CSR2D<u16, u8, u8>>(&json).is_ok());
+}
+
+#[test]
+fn test_valued_csr2d_round_trip_and_length_check() {
+    let valued: ValuedCSR2D<usize, usize, usize, f64> =
+        ValuedCSR2D::try_from([[1.0, 0.5], [0.25, 2.0]]).unwrap();
+    round_trip(&valued);
+
+    let mut json: serde_json::Value = serde_json::to_value(&valued).unwrap();
+    json["values"].as_array_mut().unwrap().pop();
+    assert!(serde_json::from_value::<ValuedCSR2D<usize, usize, usize, f64>>(json).is_err());
+}
+
+#[test]
+fn test_square_csr2d_recomputes_diagonal_and_checks_shape() {
+    let mut square: SquareCSR2D<TestCSR2D> = SparseMatrixMut::with_sparse_shape(3);
+    MatrixMut::add(&mut square, (0, 0)).unwrap();
+    MatrixMut::add(&mut square, (0, 2)).unwrap();
+    MatrixMut::add(&mut square, (2, 2)).unwrap();
+    round_trip(&square);
+
+    let restored: SquareCSR2D<TestCSR2D> =
+        serde_json::from_str(&serde_json::to_string(&square).unwrap()).unwrap();
+    assert_eq!(restored.number_of_defined_diagonal_values(), 2);
+
+    let rectangular = format!(r#"{{"matrix":{}}}"#, serde_json::to_string(&sample_csr()).unwrap());
+    assert!(serde_json::from_str::<SquareCSR2D<TestCSR2D>>(&rectangular).is_err());
+}
+
+#[test]
+fn test_upper_triangular_and_symmetric_validation() {
+    let mut lower: SquareCSR2D<TestCSR2D> = SparseMatrixMut::with_sparse_shape(2);
+    MatrixMut::add(&mut lower, (1, 0)).unwrap();
+    let lower = serde_json::to_string(&lower).unwrap();
+    assert!(serde_json::from_str::<UpperTriangularCSR2D<TestCSR2D>>(&lower).is_err());
+    assert!(serde_json::from_str::<SymmetricCSR2D<TestCSR2D>>(&lower).is_err());
+
+    let mut upper: UpperTriangularCSR2D<TestCSR2D> = SparseMatrixMut::with_sparse_shape(3);
+    MatrixMut::add(&mut upper, (0, 1)).unwrap();
+    MatrixMut::add(&mut upper, (1, 2)).unwrap();
+    round_trip(&upper);
+
+    let symmetric: SymmetricCSR2D<TestCSR2D> = UndiEdgesBuilder::default()
+        .expected_number_of_edges(2)
+        .expected_shape(3)
+        .edges([(0, 1), (1, 2)].into_iter())
+        .build()
+        .unwrap();
+    round_trip(&symmetric);
+}
+
+#[test]
+fn test_sorted_vec_round_trip_and_rejects_unsorted() {
+    round_trip(&sample_nodes());
+    assert!(serde_json::from_str::<SortedVec<u32>>("[3, 1, 2]").is_err());
+}
+
+#[test]
+fn test_named_graphs_round_trip() {
+    let edges: SquareCSR2D<TestCSR2D> = DiEdgesBuilder::default()
+        .expected_number_of_edges(2)
+        .expected_shape(3)
+        .edges([(0, 1), (2, 0)].into_iter())
+        .build()
+        .unwrap();
+    let digraph: DiGraph<String> = DiGraph::from((sample_nodes(), edges.clone()));
+    round_trip(&digraph);
+
+    let dibigraph: DiBiGraph<String> =
+        DiBiGraph::from((sample_nodes(), GenericBiMatrix2D::new(edges)));
+    let restored: DiBiGraph<String> =
+        serde_json::from_str(&serde_json::to_string(&dibigraph).unwrap()).unwrap();
+    assert_eq!(restored, dibigraph);
+    assert!(restored.has_predecessor(0, 2));
+
+    let undigraph: UndiGraph<String> = UndiGraph::from((
+        sample_nodes(),
+        UndiEdgesBuilder::default()
+            .expected_number_of_edges(1)
+            .expected_shape(3)
+            .edges([(0, 2)].into_iter())
+            .build()
+            .unwrap(),
+    ));
+    round_trip(&undigraph);
+
+    let weighted: WeightedDiGraph<String> = WeightedDiGraph::from((
+        sample_nodes(),
+        GenericEdgesBuilder::<_, ValuedCSR2D<usize, usize, usize, f64>>::default()
+            .expected_number_of_edges(1)
+            .expected_shape((3, 3))
+            .edges([(1, 2, 0.5)].into_iter())
+            .build()
+            .unwrap(),
+    ));
+    round_trip(&weighted);
+
+    let left: SortedVec<u8> = SortedVec::try_from(vec![1, 2]).unwrap();
+    let right: SortedVec<char> = SortedVec::try_from(vec!['x', 'y', 'z']).unwrap();
+    let mut bipartite_edges: ValuedCSR2D<usize, usize, usize, f64> =
+        SparseMatrixMut::with_sparse_shape((2, 3));
+    MatrixMut::add(&mut bipartite_edges, (1, 2, 3.0)).unwrap();
+    let bigraph: WeightedBiGraph<u8, char> =
+        WeightedBiGraph::try_from((left, right, bipartite_edges)).unwrap();
+    round_trip(&bigraph);
+}
+
+#[test]
+fn test_graph_rejects_edges_beyond_vocabulary() {
+    let edges: SquareCSR2D<TestCSR2D> = DiEdgesBuilder::default()
+        .expected_number_of_edges(1)
+        .expected_shape(4)
+        .edges([(0, 3)].into_iter())
+        .build()
+        .unwrap();
+    let json = format!(
+        r#"{{"nodes":{},"edges":{}}}"#,
+        serde_json::to_string(&sample_nodes()).unwrap(),
+        serde_json::to_string(&edges).unwrap()
+    );
+    assert!(serde_json::from_str::<DiGraph<String>>(&json).is_err());
+}
@@ -70,7 +70,7 @@ fn test_sparse_index_alias_matches_try_rank_on_valued_csr() {
             .unwrap();
 
     for (row, column) in [(0, 1), (1, 0), (1, 2), (0, 0), (0, 2)] {
-        assert_eq!(matrix.sparse_index(row, column), matrix.try_rank(row, column));
+        assert_eq!(matrix.sparse_index(row, column), matrix.try_rank(row, column).ok());
     }
 }
 
@@ -80,10 +80,10 @@ fn test_sparse_index_alias_matches_try_rank_on_wrappers() {
     let symmetric = build_valued_symmetric(3, &[(0, 1, 5), (1, 2, 7)]);
 
     for (row, column) in [(0, 0), (0, 2), (1, 1), (2, 2)] {
-        assert_eq!(square.sparse_index(row, column), square.try_rank(row, column));
+        assert_eq!(square.sparse_index(row, column), square.try_rank(row, column).ok());
     }
 
     for (row, column) in [(0, 1), (1, 0), (1, 2), (2, 1), (2, 2)] {
-        assert_eq!(symmetric.sparse_index(row, column), symmetric.try_rank(row, column));
+        assert_eq!(symmetric.sparse_index(row, column), symmetric.try_rank(row, column).ok());
     }
 }
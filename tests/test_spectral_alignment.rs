@@ -0,0 +1,114 @@
+//! Tests for the alignment of the peaks of two mass spectra.
+#![cfg(feature = "std")]
+
+use geometric_traits::spatial::{Peak, SpectralAligner};
+use rand::{Rng, SeedableRng, rngs::SmallRng};
+
+fn assert_close(left: f64, right: f64) {
+    assert!((left - right).abs() <= 1e-9, "{left} != {right}");
+}
+
+/// Returns the largest sum over the matched pairs of one plus their
+/// normalized intensity product, by exhaustive search.
+fn brute_force_objective(left: &[Peak], right: &[Peak], tolerance: f64) -> f64 {
+    fn search(
+        row: usize,
+        used: &mut Vec<bool>,
+        left: &[Peak],
+        right: &[Peak],
+        tolerance: f64,
+        largest_product: f64,
+    ) -> f64 {
+        if row == left.len() {
+            return 0.0;
+        }
+        let mut best = search(row + 1, used, left, right, tolerance, largest_product);
+        for column in 0..right.len() {
+            if !used[column] && (left[row].0 - right[column].0).abs() <= tolerance {
+                used[column] = true;
+                let gain = 1.0 + left[row].1 * right[column].1 / largest_product;
+                best =
+                    best.max(gain + search(row + 1, used, left, right, tolerance, largest_product));
+                used[column] = false;
+            }
+        }
+        best
+    }
+    let largest_product = left.iter().map(|peak| peak.1).fold(0.0, f64::max)
+        * right.iter().map(|peak| peak.1).fold(0.0, f64::max);
+    search(0, &mut vec![false; right.len()], left, right, tolerance, largest_product)
+}
+
+#[test]
+fn test_alignment_is_optimal_on_random_spectra() {
+    let mut rng = SmallRng::seed_from_u64(42);
+    for _ in 0..200 {
+        let mut random_spectrum = |size: usize| -> Vec<Peak> {
+            (0..size).map(|_| (rng.gen_range(100.0..101.0), rng.gen_range(1.0..10.0))).collect()
+        };
+        let left = random_spectrum(5);
+        let right = random_spectrum(4);
+        let alignment = SpectralAligner::new(0.3).align(&left, &right).unwrap();
+
+        let largest_product = left.iter().map(|peak| peak.1).fold(0.0, f64::max)
+            * right.iter().map(|peak| peak.1).fold(0.0, f64::max);
+        let objective: f64 = alignment
+            .matches()
+            .iter()
+            .map(|&(row, column)| {
+                assert!((left[row].0 - right[column].0).abs() <= 0.3);
+                1.0 + left[row].1 * right[column].1 / largest_product
+            })
+            .sum();
+        assert!((objective - brute_force_objective(&left, &right, 0.3)).abs() < 1e-6);
+        assert!(alignment.matches().windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert!((0.0..=1.0 + 1e-12).contains(&alignment.score()));
+    }
+}
+
+#[test]
+fn test_identical_spectra_have_unit_score() {
+    let spectrum = [(50.0, 3.0), (75.5, 10.0), (120.25, 1.0), (300.0, 7.0)];
+    let alignment = SpectralAligner::new(0.01).align(&spectrum, &spectrum).unwrap();
+    assert_eq!(alignment.matches(), [(0, 0), (1, 1), (2, 2), (3, 3)]);
+    assert_eq!(alignment.number_of_matches(), 4);
+    assert_close(alignment.score(), 1.0);
+
+    let weighted = SpectralAligner::new(0.01).with_powers(1.0, 0.5);
+    assert_close(weighted.align(&spectrum, &spectrum).unwrap().score(), 1.0);
+}
+
+#[test]
+fn test_each_peak_is_matched_at_most_once() {
+    // Both left peaks are within the tolerance of the single right peak, and
+    // the most intense one is matched.
+    let left = [(100.0, 2.0), (100.02, 8.0)];
+    let right = [(100.01, 5.0)];
+    let alignment = SpectralAligner::new(0.05).align(&left, &right).unwrap();
+    assert_eq!(alignment.matches(), [(1, 0)]);
+    assert_close(alignment.score(), 40.0 / (68.0_f64.sqrt() * 5.0));
+
+    // Weighting by the mass-to-charge ratio only makes the peaks equivalent
+    // up to their ratio, and the heavier one is matched.
+    let alignment = SpectralAligner::new(0.05).with_powers(1.0, 0.0).align(&left, &right).unwrap();
+    assert_eq!(alignment.matches(), [(1, 0)]);
+}
+
+#[test]
+fn test_degenerate_spectra_are_not_matched() {
+    let spectrum = [(100.0, 1.0), (200.0, 2.0)];
+    let aligner = SpectralAligner::new(0.1);
+
+    let alignment = aligner.align(&spectrum, &[]).unwrap();
+    assert!(alignment.matches().is_empty());
+    assert_close(alignment.score(), 0.0);
+    assert!(aligner.align(&[], &[]).unwrap().matches().is_empty());
+
+    // Peaks without intensity, or outside the tolerance, are never matched.
+    let alignment = aligner.align(&spectrum, &[(100.0, 0.0), (200.5, 2.0)]).unwrap();
+    assert!(alignment.matches().is_empty());
+    assert_close(alignment.score(), 0.0);
+    let alignment = aligner.align(&spectrum, &[(100.0, f64::NAN), (200.05, 2.0)]).unwrap();
+    assert_eq!(alignment.matches(), [(1, 1)]);
+    assert_close(alignment.score(), 4.0 / (5.0_f64.sqrt() * 2.0));
+}
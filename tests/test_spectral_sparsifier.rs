@@ -0,0 +1,112 @@
+//! Tests for the spectral sparsification of weighted undirected graphs.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::{SymmetricCSR2D, ValuedCSR2D},
+    prelude::*,
+    traits::{
+        EffectiveResistanceError, LaplacianError, SpectralSparsifier, SpectralSparsifierError,
+        algorithms::randomized_graphs::XorShift64,
+    },
+};
+
+type Weights = SymmetricCSR2D<ValuedCSR2D<usize, usize, usize, f64>>;
+
+fn complete_weights(order: usize) -> Weights {
+    let entries: Vec<(usize, usize, f64)> = (0..order)
+        .flat_map(|source| (source + 1..order).map(move |destination| (source, destination, 1.0)))
+        .collect();
+    SymmetricCSR2D::from_sorted_upper_triangular_entries(order, entries).unwrap()
+}
+
+/// Returns the Laplacian quadratic form xᵀLx of the graph.
+fn quadratic_form(matrix: &Weights, vector: &[f64]) -> f64 {
+    matrix
+        .row_indices()
+        .flat_map(|row| {
+            matrix
+                .sparse_row(row)
+                .zip(matrix.sparse_row_values(row))
+                .filter(move |&(column, _)| column > row)
+                .map(move |(column, weight)| weight * (vector[row] - vector[column]).powi(2))
+        })
+        .sum()
+}
+
+#[test]
+#[allow(clippy::cast_precision_loss)]
+fn test_sparsifier_preserves_the_quadratic_form() {
+    let graph = complete_weights(200);
+    let sparsifier = graph.sparsify(1.0, 42).unwrap();
+    assert_eq!(sparsifier.order(), 200);
+    assert!(sparsifier.number_of_defined_values() < graph.number_of_defined_values() / 2);
+    assert!(
+        SparseMatrix::sparse_coordinates(&sparsifier)
+            .all(|(row, column)| graph.has_entry(row, column) && row != column)
+    );
+
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(7));
+    for _ in 0..20 {
+        // Random cuts and random vectors.
+        let cut: Vec<f64> = (0..200).map(|_| (rng.next().unwrap() % 2) as f64).collect();
+        let vector: Vec<f64> = (0..200).map(|_| (rng.next().unwrap() % 1000) as f64).collect();
+        for vector in [cut, vector] {
+            let ratio = quadratic_form(&sparsifier, &vector) / quadratic_form(&graph, &vector);
+            assert!((ratio - 1.0).abs() < 0.2, "{ratio}");
+        }
+    }
+}
+
+#[test]
+fn test_sparsifier_keeps_critical_edges() {
+    // Two cliques joined by a bridge, and a pendant path with a self-loop.
+    let mut entries = Vec::new();
+    for offset in [0, 30] {
+        for source in offset..offset + 30 {
+            for destination in source + 1..offset + 30 {
+                entries.push((source, destination, 2.0));
+            }
+        }
+    }
+    entries.push((29, 30, 0.5));
+    entries.push((59, 60, 3.0));
+    entries.push((60, 60, 1.0));
+    entries.push((60, 61, 1.5));
+    entries.sort_unstable_by_key(|&(source, destination, _)| (source, destination));
+    let graph: Weights = SymmetricCSR2D::from_sorted_upper_triangular_entries(62, entries).unwrap();
+
+    let sparsifier = graph.sparsify(1.0, 3).unwrap();
+    assert!(sparsifier.number_of_defined_values() < graph.number_of_defined_values());
+    for (source, destination, weight) in [(29, 30, 0.5), (59, 60, 3.0), (60, 61, 1.5)] {
+        assert_eq!(sparsifier.sparse_value_at(source, destination), Some(weight));
+        assert_eq!(sparsifier.sparse_value_at(destination, source), Some(weight));
+    }
+    assert_eq!(sparsifier.sparse_value_at(60, 60), None);
+
+    // The sampling is deterministic for a given seed.
+    assert_eq!(sparsifier, graph.sparsify(1.0, 3).unwrap());
+}
+
+#[test]
+fn test_invalid_sparsifications_are_rejected() {
+    let graph = complete_weights(4);
+    for epsilon in [0.0, -0.5, 1.5, f64::NAN] {
+        assert!(matches!(
+            graph.sparsify(epsilon, 1),
+            Err(SpectralSparsifierError::InvalidEpsilon(_))
+        ));
+    }
+
+    let mut directed: ValuedCSR2D<usize, usize, usize, f64> =
+        SparseMatrixMut::with_sparse_shape((2, 2));
+    MatrixMut::add(&mut directed, (0, 1, 1.0)).unwrap();
+    assert_eq!(
+        directed.sparsify(0.5, 1),
+        Err(SpectralSparsifierError::EffectiveResistance(EffectiveResistanceError::Laplacian(
+            LaplacianError::NonSymmetricWeight { source_id: 0, destination_id: 1 }
+        )))
+    );
+
+    let empty = complete_weights(0);
+    assert_eq!(empty.sparsify(0.5, 1).unwrap().number_of_defined_values(), 0);
+}
@@ -0,0 +1,70 @@
+//! Tests for the conversions between `ValuedCSR2D` and the `sprs` matrices.
+#![cfg(feature = "sprs")]
+
+use geometric_traits::{impls::SprsConversionError, prelude::*};
+use sprs::{CsMat, CsMatI, TriMat};
+
+type TestValuedCSR2D = ValuedCSR2D<usize, usize, usize, f64>;
+
+fn sample_csmat() -> CsMat<f64> {
+    let mut triplets = TriMat::new((4, 3));
+    triplets.add_triplet(0, 2, 1.5);
+    triplets.add_triplet(0, 0, 0.5);
+    triplets.add_triplet(2, 1, -2.0);
+    triplets.to_csr()
+}
+
+#[test]
+fn test_csr_conversion_moves_the_buffers() {
+    let csmat = sample_csmat();
+    let values_pointer = csmat.data().as_ptr();
+
+    let matrix = TestValuedCSR2D::try_from(csmat).unwrap();
+    assert_eq!(matrix.values_ref().as_ptr(), values_pointer);
+    assert_eq!(matrix.number_of_rows(), 4);
+    assert_eq!(matrix.number_of_columns(), 3);
+    assert_eq!(matrix.sparse_row_entries_slice(0), (&[0, 2][..], &[0.5, 1.5][..]));
+    assert_eq!(matrix.sparse_row_entries_slice(2), (&[1][..], &[-2.0][..]));
+    assert_eq!(matrix.number_of_non_empty_rows(), 2);
+}
+
+#[test]
+fn test_csc_conversion_matches_csr_conversion() {
+    let csr = TestValuedCSR2D::try_from(sample_csmat()).unwrap();
+    let csc = TestValuedCSR2D::try_from(sample_csmat().to_csc()).unwrap();
+    assert_eq!(csc, csr);
+}
+
+#[test]
+fn test_round_trip_keeps_trailing_empty_rows() {
+    let mut matrix: ValuedCSR2D<u32, u16, u32, i64> = SparseMatrixMut::with_sparse_shape((5, 4));
+    MatrixMut::add(&mut matrix, (0, 3, 7)).unwrap();
+    MatrixMut::add(&mut matrix, (1, 0, -1)).unwrap();
+
+    let csmat: CsMatI<i64, u32, u32> = matrix.clone().into();
+    assert_eq!(csmat.shape(), (5, 4));
+    assert_eq!(csmat.indptr().raw_storage(), &[0, 1, 2, 2, 2, 2]);
+    assert_eq!(csmat.get(0, 3), Some(&7));
+    assert_eq!(csmat.get(1, 0), Some(&-1));
+
+    let restored = ValuedCSR2D::<u32, u16, u32, i64>::try_from(csmat).unwrap();
+    assert_eq!(restored.number_of_rows(), 5);
+    assert_eq!(restored.sparse_row_entries_slice(1), (&[0][..], &[-1][..]));
+}
+
+#[test]
+fn test_non_zero_index_pointer_offset_is_normalized() {
+    let csmat = CsMat::new((2, 2), vec![3, 4, 5], vec![1, 0], vec![1.0, 2.0]);
+    let matrix = TestValuedCSR2D::try_from(csmat).unwrap();
+    assert_eq!(matrix.sparse_row_entries_slice(0), (&[1][..], &[1.0][..]));
+    assert_eq!(matrix.sparse_row_entries_slice(1), (&[0][..], &[2.0][..]));
+}
+
+#[test]
+fn test_shape_overflow_is_rejected() {
+    let csmat: CsMatI<f64, u16, u16> = CsMatI::zero((300, 2));
+    assert_eq!(
+        ValuedCSR2D::<u16, u8, u16, f64>::try_from(csmat),
+        Err(SprsConversionError::RowsOverflow(300))
+    );
+}
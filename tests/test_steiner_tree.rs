@@ -0,0 +1,181 @@
+//! Tests for the Kou-Markowsky-Berman Steiner tree heuristic.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::ValuedCSR2D,
+    prelude::*,
+    traits::{
+        DijkstraError, EdgesBuilder, SteinerTree, SteinerTreeError,
+        algorithms::randomized_graphs::XorShift64,
+    },
+};
+
+type TestIntValCSR = ValuedCSR2D<usize, usize, usize, i32>;
+
+/// Builds a square matrix from undirected edges, stored in both directions.
+fn build_undirected(order: usize, edges: &[(usize, usize, i32)]) -> TestIntValCSR {
+    let mut entries: Vec<(usize, usize, i32)> =
+        edges.iter().flat_map(|&(u, v, weight)| [(u, v, weight), (v, u, weight)]).collect();
+    entries.sort_unstable_by_key(|&(source, destination, _)| (source, destination));
+    entries.dedup_by_key(|&mut (source, destination, _)| (source, destination));
+    GenericEdgesBuilder::<_, TestIntValCSR>::default()
+        .expected_number_of_edges(entries.len())
+        .expected_shape((order, order))
+        .edges(entries.into_iter())
+        .build()
+        .unwrap()
+}
+
+/// Returns the weight of a minimum spanning tree of the nodes selected by
+/// the mask, or `None` if they are not connected.
+fn spanning_tree_weight(order: usize, edges: &[(usize, usize, i32)], mask: u32) -> Option<i32> {
+    let mut edges: Vec<(usize, usize, i32)> = edges
+        .iter()
+        .copied()
+        .filter(|&(u, v, _)| u != v && mask & (1 << u) != 0 && mask & (1 << v) != 0)
+        .collect();
+    edges.sort_unstable_by_key(|&(_, _, weight)| weight);
+    let mut component: Vec<usize> = (0..order).collect();
+    let mut weight = 0;
+    let mut joined = 1;
+    for (u, v, edge_weight) in edges {
+        let (left, right) = (component[u], component[v]);
+        if left != right {
+            for label in &mut component {
+                if *label == right {
+                    *label = left;
+                }
+            }
+            weight += edge_weight;
+            joined += 1;
+        }
+    }
+    (joined == mask.count_ones()).then_some(weight)
+}
+
+/// Returns the weight of a minimum Steiner tree by enumerating the sets of
+/// Steiner nodes.
+fn optimal_weight(order: usize, edges: &[(usize, usize, i32)], terminals: &[usize]) -> i32 {
+    let terminal_mask: u32 = terminals.iter().map(|&terminal| 1 << terminal).sum();
+    (0..1_u32 << order)
+        .filter(|mask| mask & terminal_mask == terminal_mask)
+        .filter_map(|mask| spanning_tree_weight(order, edges, mask))
+        .min()
+        .unwrap()
+}
+
+#[test]
+fn test_steiner_tree_is_a_two_approximation() {
+    let order = 9;
+    for seed in 0..200 {
+        let mut rng = XorShift64::from(XorShift64::normalize_seed(seed));
+        let mut random = |bound: u64| usize::try_from(rng.next().unwrap() % bound).unwrap();
+        // A random spanning tree keeps the graph connected.
+        let mut edges: Vec<(usize, usize, i32)> = (1..order)
+            .map(|node| (random(node as u64), node, 1 + i32::try_from(random(9)).unwrap()))
+            .collect();
+        for _ in 0..10 {
+            let (u, v) = (random(order as u64), random(order as u64));
+            edges.push((u.min(v), u.max(v), i32::try_from(random(10)).unwrap()));
+        }
+        edges.sort_unstable_by_key(|&(u, v, _)| (u, v));
+        edges.dedup_by_key(|&mut (u, v, _)| (u, v));
+        let graph = build_undirected(order, &edges);
+        let mut terminals: Vec<usize> = (0..=random(5)).map(|_| random(order as u64)).collect();
+        terminals.sort_unstable();
+        terminals.dedup();
+
+        let tree = graph.steiner_tree(&terminals).unwrap();
+        let optimum = optimal_weight(order, &edges, &terminals);
+        assert!(tree.total_weight() >= optimum, "seed {seed}");
+        assert!(tree.total_weight() <= 2 * optimum, "seed {seed}");
+        assert_eq!(
+            tree.total_weight(),
+            tree.edges().iter().map(|&(_, _, weight)| weight).sum::<i32>()
+        );
+
+        // The edges belong to the graph and form a tree spanning its nodes,
+        // whose leaves are all terminals.
+        for &(u, v, weight) in tree.edges() {
+            assert!(u < v);
+            assert_eq!(graph.sparse_value_at(u, v), Some(weight));
+        }
+        let nodes = tree.nodes();
+        if terminals.len() <= 1 {
+            assert!(tree.edges().is_empty());
+            continue;
+        }
+        assert_eq!(tree.edges().len() + 1, nodes.len(), "seed {seed}");
+        let node_mask: u32 = nodes.iter().map(|&node| 1 << node).sum();
+        assert!(spanning_tree_weight(order, tree.edges(), node_mask).is_some());
+        for terminal in &terminals {
+            assert!(nodes.contains(terminal));
+        }
+        for node in nodes {
+            let degree = tree.edges().iter().filter(|&&(u, v, _)| u == node || v == node).count();
+            assert!(degree > 1 || terminals.contains(&node), "seed {seed}: leaf {node}");
+        }
+    }
+}
+
+#[test]
+fn test_steiner_tree_prunes_and_ignores_repeated_terminals() {
+    // The shortest paths 0-1-2 and 0-1-3 share the edge (0, 1), while the
+    // dangling nodes 4 and 5 never enter the tree.
+    let graph = build_undirected(
+        6,
+        &[(0, 1, 1), (1, 2, 1), (1, 3, 1), (0, 2, 5), (2, 3, 3), (3, 4, 1), (4, 5, 1)],
+    );
+    let tree = graph.steiner_tree(&[3, 0, 2, 0]).unwrap();
+    assert_eq!(tree.edges(), &[(0, 1, 1), (1, 2, 1), (1, 3, 1)]);
+    assert_eq!(tree.total_weight(), 3);
+    assert_eq!(tree.nodes(), vec![0, 1, 2, 3]);
+
+    let single = graph.steiner_tree(&[4]).unwrap();
+    assert!(single.edges().is_empty());
+    assert_eq!(single.total_weight(), 0);
+    assert!(graph.steiner_tree(&[]).unwrap().nodes().is_empty());
+}
+
+#[test]
+fn test_steiner_tree_errors() {
+    let graph = build_undirected(4, &[(0, 1, 1), (2, 3, 1)]);
+    assert_eq!(
+        graph.steiner_tree(&[0, 2]),
+        Err(SteinerTreeError::DisconnectedTerminals { source_id: 0, destination_id: 2 })
+    );
+    assert_eq!(
+        graph.steiner_tree(&[0, 4]),
+        Err(SteinerTreeError::UnknownTerminal { terminal_id: 4, number_of_nodes: 4 })
+    );
+
+    let negative = build_undirected(3, &[(0, 1, 1), (1, 2, -1)]);
+    assert_eq!(
+        negative.steiner_tree(&[0, 2]),
+        Err(SteinerTreeError::Dijkstra(DijkstraError::NegativeWeight {
+            source_id: 1,
+            destination_id: 2
+        }))
+    );
+
+    let directed = GenericEdgesBuilder::<_, TestIntValCSR>::default()
+        .expected_number_of_edges(1)
+        .expected_shape((2, 2))
+        .edges(vec![(0, 1, 1)].into_iter())
+        .build()
+        .unwrap();
+    assert_eq!(
+        directed.steiner_tree(&[0, 1]),
+        Err(SteinerTreeError::NonSymmetricWeight { source_id: 0, destination_id: 1 })
+    );
+    let rectangular = GenericEdgesBuilder::<_, TestIntValCSR>::default()
+        .expected_number_of_edges(1)
+        .expected_shape((2, 3))
+        .edges(vec![(0, 1, 1)].into_iter())
+        .build()
+        .unwrap();
+    assert_eq!(
+        rectangular.steiner_tree(&[0]),
+        Err(SteinerTreeError::NonSquareMatrix { rows: 2, columns: 3 })
+    );
+}
@@ -0,0 +1,229 @@
+//! Tests for the two-stage `Tracker` linking detections into tracks.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    spatial::{Detection, Tracker, Tracking},
+    traits::{LAPError, algorithms::randomized_graphs::XorShift64},
+};
+
+fn squared_displacement(left: &[f64; 2], right: &[f64; 2]) -> f64 {
+    (left[0] - right[0]).powi(2) + (left[1] - right[1]).powi(2)
+}
+
+/// Simulated detections of particles moving by small random steps, some of
+/// them missed, listed in shuffled order within every frame, together with
+/// the particle of every detection.
+#[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn simulate(
+    seed: u64,
+    particles: usize,
+    number_of_frames: usize,
+) -> (Vec<Vec<[f64; 2]>>, Vec<Vec<usize>>) {
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(seed));
+    let mut uniform = move || (rng.next().unwrap() % 10000) as f64 / 10000.0;
+    let mut positions: Vec<[f64; 2]> = (0..particles)
+        .map(|particle| [(particle % 5) as f64 * 10.0, (particle / 5) as f64 * 10.0])
+        .collect();
+    let mut missed_previously = vec![false; particles];
+    let mut frames = Vec::new();
+    let mut identities = Vec::new();
+    for frame in 0..number_of_frames {
+        let mut detections: Vec<([f64; 2], usize)> = Vec::new();
+        for (particle, position) in positions.iter_mut().enumerate() {
+            position[0] += (uniform() - 0.5) * 0.6;
+            position[1] += (uniform() - 0.5) * 0.6;
+            // Detections are missed at most one frame in a row, and never in
+            // the first and last frames.
+            let missed = frame > 0
+                && frame + 1 < number_of_frames
+                && !missed_previously[particle]
+                && uniform() < 0.15;
+            missed_previously[particle] = missed;
+            if !missed {
+                detections.push((*position, particle));
+            }
+        }
+        for position in (1..detections.len()).rev() {
+            let other = (uniform() * (position + 1) as f64) as usize;
+            detections.swap(position, other.min(position));
+        }
+        frames.push(detections.iter().map(|&(position, _)| position).collect());
+        identities.push(detections.iter().map(|&(_, particle)| particle).collect());
+    }
+    (frames, identities)
+}
+
+/// Checks that every detection belongs to exactly one track, and that links
+/// go forward in time.
+fn check_partition<F: AsRef<[[f64; 2]]>>(tracking: &Tracking, frames: &[F]) {
+    let mut seen: Vec<Vec<bool>> =
+        frames.iter().map(|frame| vec![false; frame.as_ref().len()]).collect();
+    for track in tracking.tracks() {
+        assert_eq!(track.link_costs().len() + 1, track.detections().len());
+        assert!(track.detections().windows(2).all(|pair| pair[0].0 < pair[1].0));
+        for &(frame, index) in track.detections() {
+            assert!(!seen[frame][index], "Detection {:?} is in two tracks.", (frame, index));
+            seen[frame][index] = true;
+        }
+    }
+    assert!(seen.iter().flatten().all(|&seen| seen));
+    assert!(
+        tracking.tracks().windows(2).all(|pair| pair[0].detections()[0] < pair[1].detections()[0])
+    );
+}
+
+#[test]
+fn test_tracker_recovers_simulated_tracks_with_gap_closing() {
+    let (frames, identities) = simulate(1, 15, 25);
+    let tracking = Tracker::new(squared_displacement, 4.0)
+        .with_gap_closing(1, |left: &[f64; 2], right: &[f64; 2], frames: usize| {
+            #[allow(clippy::cast_precision_loss)]
+            let frames = frames as f64;
+            squared_displacement(left, right) / frames
+        })
+        .track(&frames)
+        .unwrap();
+    check_partition(&tracking, &frames);
+    assert!(tracking.merges().is_empty());
+    assert!(tracking.splits().is_empty());
+
+    assert_eq!(tracking.tracks().len(), 15);
+    for track in tracking.tracks() {
+        let (first_frame, first_index) = track.detections()[0];
+        let particle = identities[first_frame][first_index];
+        assert_eq!(first_frame, 0);
+        assert_eq!(track.detections().last().unwrap().0, frames.len() - 1);
+        assert!(
+            track.detections().iter().all(|&(frame, index)| identities[frame][index] == particle)
+        );
+        for (pair, &cost) in track.detections().windows(2).zip(track.link_costs()) {
+            let (left, right) = (&frames[pair[0].0][pair[0].1], &frames[pair[1].0][pair[1].1]);
+            #[allow(clippy::cast_precision_loss)]
+            let expected = squared_displacement(left, right) / (pair[1].0 - pair[0].0) as f64;
+            assert!((cost - expected).abs() < f64::EPSILON);
+        }
+        assert!((track.cost() - track.link_costs().iter().sum::<f64>()).abs() < f64::EPSILON);
+    }
+}
+
+#[test]
+fn test_tracker_without_gap_closing_splits_tracks_at_missed_detections() {
+    let (frames, _) = simulate(2, 10, 20);
+    let missed: usize = 10 * frames.len() - frames.iter().map(Vec::len).sum::<usize>();
+    assert!(missed > 0);
+    let tracking = Tracker::new(squared_displacement, 4.0).track(&frames).unwrap();
+    check_partition(&tracking, &frames);
+    // Every missed detection cuts a track in two.
+    assert_eq!(tracking.tracks().len(), 10 + missed);
+    assert!(
+        tracking
+            .tracks()
+            .iter()
+            .all(|track| { track.detections().windows(2).all(|pair| pair[0].0 + 1 == pair[1].0) })
+    );
+}
+
+#[test]
+fn test_tracker_merges_and_splits_segments() {
+    // Two particles meet in frames 2 and 3, where a single detection is
+    // seen, and separate again in frame 4.
+    let frames: Vec<Vec<[f64; 2]>> = vec![
+        vec![[0.0, 0.0], [0.0, 1.0]],
+        vec![[1.0, 0.0], [1.0, 1.0]],
+        vec![[2.0, 0.5]],
+        vec![[3.0, 0.5]],
+        vec![[4.0, 1.0], [4.0, 0.0]],
+    ];
+    let tracking = Tracker::new(squared_displacement, 4.0)
+        .with_merging(squared_displacement)
+        .with_splitting(squared_displacement)
+        .track(&frames)
+        .unwrap();
+    check_partition(&tracking, &frames);
+    assert_eq!(tracking.tracks().len(), 3);
+
+    let track_of = |detection: Detection| {
+        tracking.tracks().iter().position(|track| track.detections().contains(&detection)).unwrap()
+    };
+    assert_eq!(tracking.merges().len(), 1);
+    let merge = tracking.merges()[0];
+    assert_eq!(merge.detection(), (2, 0));
+    assert_eq!(merge.other_track(), track_of((2, 0)));
+    assert_eq!(tracking.tracks()[merge.track()].detections().last().unwrap().0, 1);
+    assert!((merge.cost() - 1.25).abs() < f64::EPSILON);
+
+    assert_eq!(tracking.splits().len(), 1);
+    let split = tracking.splits()[0];
+    assert_eq!(split.detection(), (3, 0));
+    assert_eq!(split.other_track(), track_of((3, 0)));
+    assert_eq!(tracking.tracks()[split.track()].detections()[0].0, 4);
+    assert!((split.cost() - 1.25).abs() < f64::EPSILON);
+}
+
+#[test]
+fn test_tracker_respects_maximal_costs() {
+    let frames: Vec<Vec<[f64; 2]>> =
+        vec![vec![[0.0, 0.0]], vec![[1.0, 0.0]], vec![], vec![[4.0, 0.0]], vec![[4.0, 0.0]]];
+    let tracks = |tracking: &Tracking| -> Vec<Vec<Detection>> {
+        tracking.tracks().iter().map(|track| track.detections().to_vec()).collect()
+    };
+
+    // Links costing the maximal cost are not made, whereas null costs are.
+    let tracking = Tracker::new(squared_displacement, 1.0).track(&frames).unwrap();
+    assert_eq!(tracks(&tracking), vec![vec![(0, 0)], vec![(1, 0)], vec![(3, 0), (4, 0)]]);
+    assert_eq!(tracking.tracks()[2].link_costs(), [0.0]);
+
+    // Gap closings below their own maximal cost are made, including over
+    // consecutive frames.
+    let gap_closing_cost = |left: &[f64; 2], right: &[f64; 2], _| squared_displacement(left, right);
+    let tracking = Tracker::new(squared_displacement, 1.0)
+        .with_gap_closing(3, gap_closing_cost)
+        .with_max_closing_cost(9.5)
+        .track(&frames)
+        .unwrap();
+    assert_eq!(tracks(&tracking), vec![vec![(0, 0), (1, 0), (3, 0), (4, 0)]]);
+    assert_eq!(tracking.tracks()[0].link_costs(), [1.0, 9.0, 0.0]);
+
+    let tracking = Tracker::new(squared_displacement, 1.0)
+        .with_gap_closing(3, gap_closing_cost)
+        .with_max_closing_cost(9.0)
+        .track(&frames)
+        .unwrap();
+    assert_eq!(tracks(&tracking), vec![vec![(0, 0), (1, 0)], vec![(3, 0), (4, 0)]]);
+
+    // Gaps longer than the maximal gap are never closed.
+    let tracking = Tracker::new(squared_displacement, 1.0)
+        .with_gap_closing(0, gap_closing_cost)
+        .with_max_closing_cost(100.0)
+        .track(&frames)
+        .unwrap();
+    assert_eq!(tracks(&tracking), vec![vec![(0, 0), (1, 0)], vec![(3, 0), (4, 0)]]);
+}
+
+#[test]
+fn test_tracker_degenerate_inputs() {
+    let no_frames: Vec<Vec<[f64; 2]>> = Vec::new();
+    let tracking = Tracker::new(squared_displacement, 1.0).track(&no_frames).unwrap();
+    assert!(tracking.tracks().is_empty());
+
+    let empty_frames: Vec<Vec<[f64; 2]>> = vec![Vec::new(), Vec::new()];
+    let tracking = Tracker::new(squared_displacement, 1.0)
+        .with_merging(squared_displacement)
+        .with_splitting(squared_displacement)
+        .track(&empty_frames)
+        .unwrap();
+    assert!(tracking.tracks().is_empty());
+
+    let frames = vec![vec![[0.0, 0.0]], vec![[0.5, 0.0]]];
+    assert_eq!(
+        Tracker::new(squared_displacement, f64::NAN).track(&frames),
+        Err(LAPError::PaddingValueNotFinite)
+    );
+    assert_eq!(
+        Tracker::new(squared_displacement, 1.0)
+            .with_max_closing_cost(-1.0)
+            .with_merging(squared_displacement)
+            .track(&frames),
+        Err(LAPError::PaddingValueNotPositive)
+    );
+}
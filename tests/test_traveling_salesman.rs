@@ -0,0 +1,164 @@
+//! Tests for the nearest-neighbour and 2-opt traveling salesman heuristics.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::VecMatrix2D,
+    prelude::*,
+    traits::{
+        TravelingSalesman, TravelingSalesmanError, algorithms::randomized_graphs::XorShift64,
+    },
+};
+
+/// Returns the Euclidean distance matrix of random points of the unit square.
+fn random_points(seed: u64, order: usize) -> VecMatrix2D<f64> {
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(seed));
+    let mut coordinate = || f64::from(u32::try_from(rng.next().unwrap() % 1000).unwrap()) / 1000.0;
+    let points: Vec<(f64, f64)> = (0..order).map(|_| (coordinate(), coordinate())).collect();
+    let distances = points
+        .iter()
+        .flat_map(|&(x, y)| points.iter().map(move |&(u, v)| (x - u).hypot(y - v)))
+        .collect();
+    VecMatrix2D::new(order, order, distances)
+}
+
+/// Returns the length of the closed tour.
+fn length(distances: &VecMatrix2D<f64>, nodes: &[usize]) -> f64 {
+    nodes
+        .iter()
+        .zip(nodes.iter().cycle().skip(1))
+        .map(|(&source, &destination)| distances.value((source, destination)))
+        .sum()
+}
+
+/// Returns the length of the shortest tour, by enumerating the permutations
+/// of the nodes after the first one.
+fn optimal_length(distances: &VecMatrix2D<f64>, order: usize) -> f64 {
+    fn search(
+        distances: &VecMatrix2D<f64>,
+        nodes: &mut Vec<usize>,
+        remaining: &mut Vec<usize>,
+        best: &mut f64,
+    ) {
+        if remaining.is_empty() {
+            *best = best.min(length(distances, nodes));
+            return;
+        }
+        for index in 0..remaining.len() {
+            let node = remaining.remove(index);
+            nodes.push(node);
+            search(distances, nodes, remaining, best);
+            nodes.pop();
+            remaining.insert(index, node);
+        }
+    }
+    let mut best = f64::INFINITY;
+    search(distances, &mut vec![0], &mut (1..order).collect(), &mut best);
+    best
+}
+
+#[test]
+fn test_tours_on_random_points() {
+    for seed in 0..40 {
+        for order in [3, 5, 8] {
+            let distances = random_points(seed, order);
+            let greedy = distances.nearest_neighbor_tour().unwrap();
+            let improved = distances.traveling_salesman_tour().unwrap();
+            for tour in [&greedy, &improved] {
+                assert_eq!(tour.nodes()[0], 0);
+                let mut sorted = tour.nodes().to_vec();
+                sorted.sort_unstable();
+                assert_eq!(sorted, (0..order).collect::<Vec<_>>());
+                assert!((tour.length() - length(&distances, tour.nodes())).abs() < 1e-9);
+            }
+
+            let optimum = optimal_length(&distances, order);
+            assert!(improved.length() <= greedy.length() + 1e-12, "seed {seed}");
+            assert!(improved.length() >= optimum - 1e-9, "seed {seed}");
+
+            // No 2-opt move shortens the improved tour.
+            let nodes = improved.nodes();
+            for first in 0..order {
+                for second in (first + 2)..order {
+                    let (a, b) = (nodes[first], nodes[first + 1]);
+                    let (c, d) = (nodes[second], nodes[(second + 1) % order]);
+                    if d == a {
+                        continue;
+                    }
+                    let removed = distances.value((a, b)) + distances.value((c, d));
+                    let added = distances.value((a, c)) + distances.value((b, d));
+                    assert!(added >= removed - 1e-9, "seed {seed}, order {order}");
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_two_opt_repairs_the_greedy_tour() {
+    // The greedy tour visits (2, 1) between (2, 0) and (0, 0), a detour that
+    // 2-opt moves repair.
+    let points = [(5.0, 3.0), (2.0, 1.0), (4.0, 0.0), (2.0, 0.0), (0.0, 0.0)];
+    let distances = VecMatrix2D::new(
+        5,
+        5,
+        points
+            .iter()
+            .flat_map(|&(x, y): &(f64, f64)| {
+                points.iter().map(move |&(u, v): &(f64, f64)| (x - u).hypot(y - v))
+            })
+            .collect(),
+    );
+    let greedy = distances.nearest_neighbor_tour().unwrap();
+    assert_eq!(greedy.nodes(), &[0, 2, 3, 1, 4]);
+    let improved = distances.traveling_salesman_tour().unwrap();
+    let optimum = optimal_length(&distances, 5);
+    assert!(greedy.length() > optimum + 1.0);
+    assert!((improved.length() - optimum).abs() < 1e-9);
+}
+
+#[test]
+fn test_degenerate_tours() {
+    let empty: VecMatrix2D<f64> = VecMatrix2D::new(0, 0, vec![]);
+    let tour = empty.traveling_salesman_tour().unwrap();
+    assert!(tour.nodes().is_empty());
+    assert!(tour.length().abs() < f64::EPSILON);
+
+    // The diagonal is ignored.
+    let single = VecMatrix2D::new(1, 1, vec![f64::NAN]);
+    let tour = single.traveling_salesman_tour().unwrap();
+    assert_eq!(tour.nodes(), &[0]);
+    assert!(tour.length().abs() < f64::EPSILON);
+
+    let pair = VecMatrix2D::new(2, 2, vec![0.0, 2.5, 2.5, 0.0]);
+    let tour = pair.nearest_neighbor_tour().unwrap();
+    assert_eq!(tour.nodes(), &[0, 1]);
+    assert!((tour.length() - 5.0).abs() < f64::EPSILON);
+
+    let integers = VecMatrix2D::new(3, 3, vec![0, 1, 2, 1, 0, 3, 2, 3, 0]);
+    let tour = integers.traveling_salesman_tour().unwrap();
+    assert!((tour.length() - 6.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn test_traveling_salesman_errors() {
+    let rectangular = VecMatrix2D::new(2, 3, vec![0.0; 6]);
+    assert_eq!(
+        rectangular.traveling_salesman_tour(),
+        Err(TravelingSalesmanError::NonSquareMatrix { rows: 2, columns: 3 })
+    );
+    let negative = VecMatrix2D::new(2, 2, vec![0.0, -1.0, -1.0, 0.0]);
+    assert_eq!(
+        negative.traveling_salesman_tour(),
+        Err(TravelingSalesmanError::NegativeDistance { row: 0, column: 1 })
+    );
+    let infinite = VecMatrix2D::new(2, 2, vec![0.0, 1.0, f64::INFINITY, 0.0]);
+    assert_eq!(
+        infinite.nearest_neighbor_tour(),
+        Err(TravelingSalesmanError::NonFiniteValue { row: 1, column: 0 })
+    );
+    let asymmetric = VecMatrix2D::new(2, 2, vec![0.0, 1.0, 2.0, 0.0]);
+    assert_eq!(
+        asymmetric.traveling_salesman_tour(),
+        Err(TravelingSalesmanError::NonSymmetricMatrix { row: 0, column: 1 })
+    );
+}
@@ -0,0 +1,90 @@
+//! Tests for the accessors skipping the bounds checks of sparse matrices.
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::random_dag;
+use geometric_traits::{
+    impls::{CSR2D, CSR2DSlice, SquareCSR2D, SymmetricCSR2D},
+    prelude::*,
+    traits::algorithms::randomized_graphs::{
+        SparseCostMatrixConfig, erdos_renyi_gnp, random_sparse_cost_matrix,
+    },
+};
+
+type TestCSR2D = CSR2D<usize, usize, usize>;
+
+fn assert_unchecked_matches<M>(matrix: &M)
+where
+    M: SizedSparseMatrix2D<RowIndex = usize, ColumnIndex = usize, SparseIndex = usize>,
+{
+    for row in matrix.row_indices() {
+        assert_eq!(
+            unsafe { matrix.sparse_row_unchecked(row) }.collect::<Vec<_>>(),
+            matrix.sparse_row(row).collect::<Vec<_>>()
+        );
+    }
+    for row in 0..=matrix.number_of_rows() {
+        assert_eq!(matrix.rank_row_unchecked(row), matrix.rank_row(row));
+    }
+    for sparse_index in 0..matrix.number_of_defined_values() {
+        assert_eq!(
+            unsafe { matrix.select_column_unchecked(sparse_index) },
+            matrix.select_column(sparse_index)
+        );
+    }
+}
+
+#[test]
+fn test_unchecked_accessors_of_random_valued_matrices() {
+    for seed in 1..=10 {
+        let matrix = random_sparse_cost_matrix(seed, &SparseCostMatrixConfig::new(25, 40, 0.2));
+        assert_unchecked_matches(&matrix);
+        let reference = &matrix;
+        for row in matrix.row_indices() {
+            assert_eq!(
+                unsafe { SparseMatrix2D::sparse_row_unchecked(&reference, row) }
+                    .collect::<Vec<_>>(),
+                matrix.sparse_row(row).collect::<Vec<_>>()
+            );
+            assert_eq!(
+                unsafe { SparseValuedMatrix2D::sparse_row_values_unchecked(&reference, row) }
+                    .collect::<Vec<_>>(),
+                matrix.sparse_row_values(row).collect::<Vec<_>>()
+            );
+        }
+
+        let view = matrix.as_slice();
+        assert_unchecked_matches(&view);
+        for row in view.row_indices() {
+            assert_eq!(
+                unsafe { view.sparse_row_values_unchecked(row) }.collect::<Vec<_>>(),
+                matrix.sparse_row_values(row).collect::<Vec<_>>()
+            );
+        }
+    }
+}
+
+#[test]
+fn test_unchecked_accessors_with_implicit_trailing_rows() {
+    let mut matrix: TestCSR2D = SparseMatrixMut::with_sparse_shape((6, 4));
+    MatrixMut::add(&mut matrix, (0, 3)).unwrap();
+    MatrixMut::add(&mut matrix, (1, 0)).unwrap();
+    MatrixMut::add(&mut matrix, (1, 2)).unwrap();
+    assert_unchecked_matches(&matrix);
+    assert_eq!(unsafe { matrix.sparse_row_unchecked(5) }.count(), 0);
+
+    let offsets = [0_usize, 1, 3];
+    let columns = [3_usize, 0, 2];
+    let view = CSR2DSlice::from_raw_parts(&offsets[..], &columns[..], (6, 4)).unwrap();
+    assert_unchecked_matches(&view);
+    assert_eq!(view.rank_row_unchecked(6), 3);
+}
+
+#[test]
+fn test_unchecked_accessors_of_wrappers() {
+    let dag: SquareCSR2D<TestCSR2D> = random_dag(7, 50, 0.1);
+    assert_unchecked_matches(&dag);
+    let graph: SymmetricCSR2D<TestCSR2D> = erdos_renyi_gnp(7, 50, 0.1);
+    assert_unchecked_matches(&graph);
+}
@@ -0,0 +1,78 @@
+//! Tests for the checks of the structural invariants of matrices and graphs.
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::random_dag;
+use geometric_traits::{
+    errors::InvariantError,
+    impls::{CSR2D, CSR2DSlice, SortedVec, SquareCSR2D, SymmetricCSR2D, UpperTriangularCSR2D},
+    prelude::*,
+    traits::algorithms::randomized_graphs::{
+        SparseCostMatrixConfig, erdos_renyi_gnp, random_sparse_cost_matrix,
+    },
+};
+
+type TestCSR2D = CSR2D<usize, usize, usize>;
+
+fn nodes(number_of_nodes: usize) -> SortedVec<usize> {
+    GenericVocabularyBuilder::default()
+        .expected_number_of_symbols(number_of_nodes)
+        .symbols((0..number_of_nodes).enumerate())
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_matrices_built_by_the_crate_are_valid() {
+    for seed in 1..=5 {
+        let costs = random_sparse_cost_matrix(seed, &SparseCostMatrixConfig::new(20, 30, 0.2));
+        assert_eq!(costs.validate(), Ok(()));
+        assert_eq!(costs.as_slice().validate(), Ok(()));
+        assert_eq!(costs.as_slice().structure().validate(), Ok(()));
+
+        let dag: SquareCSR2D<TestCSR2D> = random_dag(seed, 30, 0.1);
+        assert_eq!(dag.validate(), Ok(()));
+        let graph: SymmetricCSR2D<TestCSR2D> = erdos_renyi_gnp(seed, 30, 0.1);
+        assert_eq!(graph.validate(), Ok(()));
+    }
+
+    let mut upper: UpperTriangularCSR2D<TestCSR2D> = SparseMatrixMut::with_sparse_shape(4);
+    MatrixMut::add(&mut upper, (0, 3)).unwrap();
+    MatrixMut::add(&mut upper, (2, 2)).unwrap();
+    assert_eq!(upper.validate(), Ok(()));
+    assert_eq!(TestCSR2D::default().validate(), Ok(()));
+}
+
+#[test]
+fn test_views_over_trailing_empty_rows_are_valid() {
+    let offsets = [0_u32, 2, 3];
+    let columns = [0_u16, 4, 1];
+    let view = CSR2DSlice::from_raw_parts(&offsets[..], &columns[..], (7_u16, 5_u16)).unwrap();
+    assert_eq!(view.validate(), Ok(()));
+    assert_eq!(CSR2D::from(view).validate(), Ok(()));
+}
+
+#[test]
+fn test_graphs_referring_to_missing_nodes_are_invalid() {
+    let edges: SymmetricCSR2D<TestCSR2D> = erdos_renyi_gnp(11, 20, 0.2);
+    let graph: UndiGraph<usize> = UndiGraph::from((nodes(20), edges.clone()));
+    assert_eq!(graph.validate(), Ok(()));
+
+    let truncated: UndiGraph<usize> = UndiGraph::from((nodes(15), edges));
+    assert_eq!(
+        truncated.validate(),
+        Err(InvariantError::MissingNodes { referenced: 20, number_of_nodes: 15 })
+    );
+
+    let mut edges: TestCSR2D = SparseMatrixMut::with_sparse_shape((3, 6));
+    MatrixMut::add(&mut edges, (2, 5)).unwrap();
+    let bigraph: BiGraph<usize, usize> =
+        BiGraph::try_from((nodes(3), nodes(6), edges.clone())).unwrap();
+    assert_eq!(bigraph.validate(), Ok(()));
+    let bigraph: BiGraph<usize, usize> = BiGraph::try_from((nodes(3), nodes(4), edges)).unwrap();
+    assert_eq!(
+        bigraph.validate(),
+        Err(InvariantError::MissingNodes { referenced: 6, number_of_nodes: 4 })
+    );
+}
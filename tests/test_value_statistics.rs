@@ -0,0 +1,138 @@
+//! Tests for the distribution statistics of the values of sparse valued
+//! matrices.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    impls::{PaddedMatrix2D, ValuedCSR2D},
+    prelude::*,
+    traits::{
+        LogHistogramBin, QUANTILE_PROBABILITIES, ValueStatistics,
+        algorithms::randomized_graphs::{SparseCostMatrixConfig, random_sparse_cost_matrix},
+    },
+};
+
+fn build_matrix(entries: &[(usize, usize, f64)]) -> ValuedCSR2D<usize, usize, usize, f64> {
+    let mut matrix: ValuedCSR2D<usize, usize, usize, f64> =
+        SparseMatrixMut::with_sparse_shape((4, 4));
+    for &entry in entries {
+        MatrixMut::add(&mut matrix, entry).unwrap();
+    }
+    matrix
+}
+
+fn assert_close(left: f64, right: f64) {
+    assert!((left - right).abs() <= 1e-9 * right.abs().max(1.0), "{left} != {right}");
+}
+
+#[test]
+#[allow(clippy::cast_precision_loss)]
+fn test_statistics_match_two_pass_computation() {
+    for seed in 1..=5 {
+        let matrix = random_sparse_cost_matrix(seed, &SparseCostMatrixConfig::new(15, 20, 0.4));
+        let statistics = matrix.value_statistics();
+        let mut values: Vec<f64> = matrix.sparse_values().collect();
+        values.sort_unstable_by(f64::total_cmp);
+
+        let count = values.len() as f64;
+        let mean = values.iter().sum::<f64>() / count;
+        let variance = values.iter().map(|value| (value - mean).powi(2)).sum::<f64>() / count;
+
+        assert_eq!(statistics.number_of_values, values.len());
+        assert_eq!(statistics.number_of_non_finite_values, 0);
+        assert_eq!(statistics.minimum, values.first().copied());
+        assert_eq!(statistics.maximum, values.last().copied());
+        assert_eq!(statistics.minimum, matrix.min_sparse_value());
+        assert_close(statistics.mean.unwrap(), mean);
+        assert_close(statistics.variance.unwrap(), variance);
+
+        let quantiles = statistics.quantiles.unwrap();
+        assert!(quantiles.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert!(quantiles[0] >= values[0] && quantiles[4] <= values[values.len() - 1]);
+        assert_eq!(statistics.histogram.iter().map(|bin| bin.count).sum::<usize>(), values.len());
+        for bin in &statistics.histogram {
+            let in_bin = values
+                .iter()
+                .filter(|value| (bin.lower_bound()..bin.upper_bound()).contains(*value))
+                .count();
+            assert_eq!(in_bin, bin.count);
+        }
+
+        // Padding only adds imputed values, which are included in the summary.
+        let padded = PaddedMatrix2D::new(&matrix, |_: (usize, usize)| 1000.0).unwrap();
+        let padded_statistics = padded.value_statistics();
+        assert_eq!(padded_statistics.number_of_values, 20 * 20);
+        assert_eq!(padded_statistics.maximum, Some(1000.0));
+    }
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_quantiles_interpolate_between_ranks() {
+    let values = [3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0, 5.0];
+    let statistics = ValueStatistics::from_values(values);
+    assert_eq!(QUANTILE_PROBABILITIES, [0.05, 0.25, 0.5, 0.75, 0.95]);
+    // Sorted values: 1, 1, 2, 3, 4, 5, 5, 6, 9.
+    let quantiles = statistics.quantiles.unwrap();
+    assert_eq!(quantiles[..4], [1.0, 2.0, 4.0, 5.0]);
+    assert_close(quantiles[4], 7.8);
+    assert_eq!(statistics.median(), Some(4.0));
+
+    let single = ValueStatistics::from_values([7_u8]);
+    assert_eq!(single.quantiles, Some([7.0; 5]));
+    assert_eq!(single.variance, Some(0.0));
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_special_values_are_counted_separately() {
+    let matrix = build_matrix(&[
+        (0, 0, f64::NAN),
+        (0, 1, -8.0),
+        (1, 0, 0.0),
+        (1, 3, f64::INFINITY),
+        (2, 2, 0.25),
+        (3, 1, f64::MIN_POSITIVE / 4.0),
+    ]);
+    let statistics = matrix.value_statistics();
+    assert_eq!(statistics.number_of_values, 6);
+    assert_eq!(statistics.number_of_non_finite_values, 2);
+    assert_eq!(statistics.number_of_zero_values, 1);
+    assert_eq!(statistics.number_of_negative_values, 1);
+    assert_eq!(statistics.minimum, Some(-8.0));
+    assert_eq!(statistics.maximum, Some(0.25));
+    assert_eq!(statistics.minimum_positive, Some(f64::MIN_POSITIVE / 4.0));
+    assert_eq!(
+        statistics.histogram,
+        vec![
+            LogHistogramBin { exponent: -1023, count: 1 },
+            LogHistogramBin { exponent: -2, count: 1 },
+            LogHistogramBin { exponent: 3, count: 1 },
+        ]
+    );
+    assert_eq!(statistics.histogram[0].lower_bound(), 0.0);
+    assert_eq!(statistics.histogram[0].upper_bound(), f64::MIN_POSITIVE);
+    assert_eq!(statistics.histogram[2].lower_bound(), 8.0);
+    assert_eq!(LogHistogramBin { exponent: 1023, count: 1 }.upper_bound(), f64::INFINITY);
+}
+
+#[test]
+fn test_statistics_of_matrices_without_finite_values() {
+    let empty = ValuedCSR2D::<usize, usize, usize, f64>::default().value_statistics();
+    assert_eq!(empty.number_of_values, 0);
+    assert_eq!(
+        (empty.minimum, empty.mean, empty.variance, empty.quantiles),
+        (None, None, None, None)
+    );
+    assert!(empty.histogram.is_empty());
+    assert_eq!(empty.positive_dynamic_range(), None);
+
+    let non_finite =
+        build_matrix(&[(0, 0, f64::NAN), (2, 1, f64::NEG_INFINITY)]).value_statistics();
+    assert_eq!(non_finite.number_of_non_finite_values, 2);
+    assert_eq!(non_finite.median(), None);
+
+    let negative = build_matrix(&[(0, 0, -1.0), (1, 1, -2.0)]).value_statistics();
+    assert_eq!(negative.minimum_positive, None);
+    assert_eq!(negative.positive_dynamic_range(), None);
+    assert_eq!(negative.mean, Some(-1.5));
+}
@@ -219,3 +219,79 @@ fn test_try_from_dense_array_all_nonzero() {
     assert!((values[2] - 3.0).abs() < f64::EPSILON);
     assert!((values[3] - 4.0).abs() < f64::EPSILON);
 }
+
+// ============================================================================
+// TryFrom optional rows and try_from_rows
+// ============================================================================
+
+#[test]
+fn test_try_from_optional_rows() {
+    let rows = vec![vec![Some(1.0), None, Some(3.0)], vec![], vec![None, Some(5.0)]];
+
+    let m: ValuedCSR2D<usize, usize, usize, f64> = ValuedCSR2D::try_from(rows).unwrap();
+
+    assert_eq!(m.number_of_rows(), 3);
+    assert_eq!(m.number_of_columns(), 3);
+    assert_eq!(m.number_of_defined_values(), 3);
+    assert_eq!(
+        SparseMatrix::sparse_coordinates(&m).collect::<Vec<_>>(),
+        vec![(0, 0), (0, 2), (2, 1)]
+    );
+    assert_eq!(m.sparse_values().collect::<Vec<_>>(), vec![1.0, 3.0, 5.0]);
+
+    let dense: ValuedCSR2D<usize, usize, usize, f64> =
+        ValuedCSR2D::try_from([[1.0, 2.0], [3.0, 4.0]]).unwrap();
+    let optional: ValuedCSR2D<usize, usize, usize, f64> =
+        ValuedCSR2D::try_from(vec![vec![Some(1.0), Some(2.0)], vec![Some(3.0), Some(4.0)]])
+            .unwrap();
+    assert_eq!(dense, optional);
+
+    let empty: ValuedCSR2D<usize, usize, usize, f64> =
+        ValuedCSR2D::try_from(Vec::<Vec<Option<f64>>>::new()).unwrap();
+    assert_eq!(empty, ValuedCSR2D::default());
+}
+
+#[test]
+fn test_try_from_optional_rows_overflowing_indices() {
+    let rows = vec![vec![None; 300]];
+    assert_eq!(
+        ValuedCSR2D::<u8, u8, u8, f64>::try_from(rows),
+        Err(MutabilityError::MaxedOutColumnIndex)
+    );
+}
+
+#[test]
+fn test_try_from_rows_sorts_entries() {
+    let m: ValuedCSR2D<usize, usize, usize, f64> = ValuedCSR2D::try_from_rows(vec![
+        vec![(4, 4.0), (0, 0.5), (2, 2.0)],
+        vec![],
+        vec![(1, 1.0)],
+        vec![],
+    ])
+    .unwrap();
+
+    assert_eq!(m.number_of_rows(), 4);
+    assert_eq!(m.number_of_columns(), 5);
+    assert_eq!(m.sparse_row(0).collect::<Vec<_>>(), vec![0, 2, 4]);
+    assert_eq!(m.sparse_row_values(0).collect::<Vec<_>>(), vec![0.5, 2.0, 4.0]);
+    assert_eq!(m.sparse_row(2).collect::<Vec<_>>(), vec![1]);
+    assert_eq!(m.sparse_row(3).count(), 0);
+
+    let rebuilt: ValuedCSR2D<usize, usize, usize, f64> = ValuedCSR2D::try_from_rows(
+        m.rows_with_values().map(|(_, entries)| entries.rev().collect::<Vec<_>>()),
+    )
+    .unwrap();
+    assert_eq!(rebuilt, m);
+}
+
+#[test]
+fn test_try_from_rows_rejects_duplicates() {
+    let result = ValuedCSR2D::<usize, usize, usize, f64>::try_from_rows(vec![
+        vec![(0, 1.0)],
+        vec![(3, 1.0), (1, 2.0), (3, 3.0)],
+    ]);
+    assert_eq!(result, Err(MutabilityError::DuplicatedEntry((1, 3))));
+
+    let result = ValuedCSR2D::<u8, u8, u8, f64>::try_from_rows((0..=256).map(|_| Vec::new()));
+    assert_eq!(result, Err(MutabilityError::MaxedOutRowIndex));
+}
@@ -2,6 +2,7 @@
 #![cfg(feature = "alloc")]
 
 use geometric_traits::{
+    errors::RankSelectError,
     impls::{SquareCSR2D, SymmetricCSR2D, ValuedCSR2D},
     prelude::*,
     traits::MatrixMut,
@@ -94,10 +95,10 @@ fn test_square_valued_diagonal_count() {
 #[test]
 fn test_square_valued_try_rank() {
     let sq = build_valued_square(3, &[(0, 0, 10), (0, 2, 20), (1, 1, 30)]);
-    assert_eq!(sq.try_rank(0, 0), Some(0));
-    assert_eq!(sq.try_rank(0, 2), Some(1));
-    assert_eq!(sq.try_rank(1, 1), Some(2));
-    assert_eq!(sq.try_rank(2, 2), None);
+    assert_eq!(sq.try_rank(0, 0), Ok(0));
+    assert_eq!(sq.try_rank(0, 2), Ok(1));
+    assert_eq!(sq.try_rank(1, 1), Ok(2));
+    assert_eq!(sq.try_rank(2, 2), Err(RankSelectError::MissingEntry { row: 2, column: 2 }));
 }
 
 #[test]
@@ -199,10 +200,10 @@ fn test_symmetric_valued_empty() {
 #[test]
 fn test_symmetric_valued_try_rank() {
     let sym = build_valued_symmetric(3, &[(0, 1, 10), (1, 2, 20)]);
-    assert_eq!(sym.try_rank(0, 1), Some(0));
-    assert_eq!(sym.try_rank(1, 0), Some(1));
-    assert_eq!(sym.try_rank(1, 2), Some(2));
-    assert_eq!(sym.try_rank(2, 0), None);
+    assert_eq!(sym.try_rank(0, 1), Ok(0));
+    assert_eq!(sym.try_rank(1, 0), Ok(1));
+    assert_eq!(sym.try_rank(1, 2), Ok(2));
+    assert_eq!(sym.try_rank(2, 0), Err(RankSelectError::MissingEntry { row: 2, column: 0 }));
 }
 
 #[test]
@@ -90,3 +90,25 @@ fn test_lapjv_hungarian_agreement() {
 fn test_new_mismatched_dimensions() {
     let _ = VecMatrix2D::new(2, 3, vec![1; 5]);
 }
+
+#[test]
+fn test_index_and_checked_get() {
+    let m = VecMatrix2D::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    for row in 0..2 {
+        for column in 0..3 {
+            assert_eq!(m[(row, column)], m.value((row, column)));
+            assert_eq!(m.checked_get((row, column)), Some(&m.value((row, column))));
+        }
+    }
+    // The flat offset of (0, 3) is in bounds, but the column is not.
+    assert_eq!(m.checked_get((0, 3)), None);
+    assert_eq!(m.checked_get((2, 0)), None);
+    assert_eq!(VecMatrix2D::<i32>::new(0, 0, vec![]).checked_get((0, 0)), None);
+}
+
+#[test]
+#[should_panic(expected = "The coordinates (0, 3) are out of the bounds of a 2x3 matrix")]
+fn test_index_out_of_bounds_panics() {
+    let m = VecMatrix2D::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    let _ = m[(0, 3)];
+}
@@ -0,0 +1,129 @@
+//! Tests for the `VpTree` spatial index and the provided metrics.
+#![cfg(feature = "std")]
+
+use geometric_traits::{
+    spatial::{Angular, Chebyshev, Euclidean, KdTree, Manhattan, Metric, Neighbor, VpTree},
+    traits::algorithms::randomized_graphs::XorShift64,
+};
+
+#[allow(clippy::cast_precision_loss)]
+fn random_points(seed: u64, n: usize, dimensions: usize, resolution: u64) -> Vec<Vec<f64>> {
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(seed));
+    (0..n)
+        .map(|_| {
+            (0..dimensions).map(|_| (rng.next().unwrap() % resolution) as f64 / 10.0).collect()
+        })
+        .collect()
+}
+
+/// Returns the positions of all points sorted as the queries report them.
+fn brute_force_order<P, M: Metric<P>>(points: &[P], query: &P, metric: &M) -> Vec<(usize, f64)> {
+    let mut sorted: Vec<(usize, f64)> = points
+        .iter()
+        .enumerate()
+        .map(|(index, point)| (index, metric.distance(query, point)))
+        .collect();
+    sorted.sort_by(|left, right| left.1.total_cmp(&right.1).then(left.0.cmp(&right.0)));
+    sorted
+}
+
+fn indices(neighbors: &[Neighbor]) -> Vec<usize> {
+    neighbors.iter().map(Neighbor::index).collect()
+}
+
+fn check_against_brute_force<P: Clone, M: Metric<P> + Clone>(
+    points: &[P],
+    queries: &[P],
+    metric: &M,
+    radii: &[f64],
+) {
+    let tree = VpTree::new(points.to_vec(), metric.clone());
+    assert_eq!(tree.len(), points.len());
+    for query in queries {
+        let expected = brute_force_order(points, query, metric);
+        for k in [1, 2, 7, 50, points.len() + 3] {
+            let found = tree.nearest_neighbors(query, k);
+            let wanted: Vec<usize> = expected.iter().take(k).map(|&(index, _)| index).collect();
+            assert_eq!(indices(&found), wanted, "k = {k}");
+            for (neighbor, &(_, distance)) in found.iter().zip(&expected) {
+                assert!((neighbor.distance() - distance).abs() < f64::EPSILON);
+            }
+        }
+        for &radius in radii {
+            let found = tree.within_radius(query, radius);
+            let wanted: Vec<usize> = expected
+                .iter()
+                .filter(|&&(_, distance)| distance <= radius)
+                .map(|&(index, _)| index)
+                .collect();
+            assert_eq!(indices(&found), wanted, "radius = {radius}");
+        }
+    }
+}
+
+#[test]
+fn test_vp_tree_matches_brute_force_for_coordinate_metrics() {
+    let points = random_points(1, 400, 3, 200);
+    let mut queries = random_points(2, 20, 3, 220);
+    queries.extend(points.iter().take(3).cloned());
+    let radii = [0.0, 0.5, 2.0, 10.0];
+    check_against_brute_force(&points, &queries, &Euclidean, &radii);
+    check_against_brute_force(&points, &queries, &Manhattan, &radii);
+    check_against_brute_force(&points, &queries, &Chebyshev, &radii);
+}
+
+#[test]
+fn test_vp_tree_matches_brute_force_for_angular_distance() {
+    let points = random_points(3, 300, 8, 1000);
+    let queries = random_points(4, 20, 8, 1000);
+    check_against_brute_force(&points, &queries, &Angular, &[0.0, 0.05, 0.1, 0.3]);
+}
+
+#[test]
+fn test_vp_tree_matches_brute_force_for_a_custom_metric() {
+    // Hamming distance between bit strings takes few values, so most
+    // distances are tied.
+    let mut rng = XorShift64::from(XorShift64::normalize_seed(5));
+    let points: Vec<u64> = (0..500).map(|_| rng.next().unwrap() & 0xFFF).collect();
+    let queries: Vec<u64> = (0..20).map(|_| rng.next().unwrap() & 0xFFF).collect();
+    let hamming = |left: &u64, right: &u64| f64::from((left ^ right).count_ones());
+    check_against_brute_force(&points, &queries, &hamming, &[0.0, 1.0, 3.0, 6.0]);
+}
+
+#[test]
+fn test_vp_tree_agrees_with_kd_tree() {
+    let points = random_points(6, 300, 2, 100);
+    let queries = random_points(7, 15, 2, 120);
+    let kd_tree: KdTree<2, ()> = points.iter().map(|point| ([point[0], point[1]], ())).collect();
+    let vp_tree = VpTree::new(points, Euclidean);
+    for query in queries {
+        let array = [query[0], query[1]];
+        assert_eq!(vp_tree.nearest_neighbors(&query, 10), kd_tree.nearest_neighbors(&array, 10));
+        assert_eq!(vp_tree.within_radius(&query, 1.5), kd_tree.within_radius(&array, 1.5));
+    }
+}
+
+#[test]
+fn test_vp_tree_degenerate_inputs() {
+    let empty: VpTree<Vec<f64>, Euclidean> = VpTree::new(Vec::new(), Euclidean);
+    assert!(empty.is_empty());
+    assert!(empty.nearest_neighbors(&vec![0.0], 4).is_empty());
+    assert!(empty.within_radius(&vec![0.0], 1.0).is_empty());
+
+    let tree = VpTree::new([[1.0], [1.0], [-2.0]], Manhattan);
+    assert!(tree.nearest_neighbors(&[0.0], 0).is_empty());
+    assert!(tree.within_radius(&[0.0], -1.0).is_empty());
+    assert_eq!(indices(&tree.nearest_neighbors(&[1.0], 2)), vec![0, 1]);
+    assert_eq!(indices(&tree.within_radius(&[0.0], 2.0)), vec![0, 1, 2]);
+    assert!((tree.point(2)[0] + 2.0).abs() < f64::EPSILON);
+    assert_eq!(*tree.metric(), Manhattan);
+}
+
+#[test]
+fn test_angular_distance_of_null_vectors() {
+    let null = [0.0, 0.0];
+    assert!(Angular.distance(&null, &null).abs() < f64::EPSILON);
+    assert!((Angular.distance(&null, &[1.0, 2.0]) - 0.5).abs() < f64::EPSILON);
+    assert!((Angular.distance(&[1.0, 0.0], &[-3.0, 0.0]) - 1.0).abs() < f64::EPSILON);
+    assert!((Angular.distance(&[1.0, 0.0], &[0.0, 0.5]) - 0.5).abs() < f64::EPSILON);
+}
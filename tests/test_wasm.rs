@@ -0,0 +1,58 @@
+//! Tests for the `wasm-bindgen` wrappers, exercised through their native
+//! entry points.
+#![cfg(feature = "wasm")]
+
+use geometric_traits::wasm::WasmSparseMatrix;
+
+#[test]
+fn test_lapmod_and_jaqaman() {
+    let costs = WasmSparseMatrix::try_from_coordinates(
+        2,
+        2,
+        &[1, 0, 1, 0],
+        &[1, 1, 0, 0],
+        &[8.0, 1.0, 2.0, 4.0],
+    )
+    .unwrap();
+    assert_eq!(costs.number_of_rows(), 2);
+    assert_eq!(costs.number_of_columns(), 2);
+    assert_eq!(costs.number_of_values(), 4);
+    assert_eq!(costs.try_lapmod(100.0).unwrap(), vec![1, 0]);
+
+    let rectangular =
+        WasmSparseMatrix::try_from_coordinates(3, 2, &[0, 1, 1], &[0, 0, 1], &[1.0, 2.0, 3.0])
+            .unwrap();
+    assert_eq!(rectangular.try_jaqaman(10.0, 100.0).unwrap(), vec![0, 1, -1]);
+
+    let infeasible =
+        WasmSparseMatrix::try_from_coordinates(2, 2, &[0, 1], &[0, 0], &[1.0, 1.0]).unwrap();
+    assert!(infeasible.try_lapmod(100.0).is_err());
+}
+
+#[test]
+fn test_louvain() {
+    let graph = WasmSparseMatrix::try_undirected(
+        6,
+        &[0, 0, 1, 2, 3, 3, 4],
+        &[1, 2, 2, 3, 4, 5, 5],
+        &[1.0, 1.0, 1.0, 0.1, 1.0, 1.0, 1.0],
+    )
+    .unwrap();
+    assert_eq!(graph.number_of_values(), 14);
+    let partition = graph.try_louvain(1.0, 7).unwrap();
+    assert_eq!(partition.partition[0], partition.partition[1]);
+    assert_eq!(partition.partition[0], partition.partition[2]);
+    assert_eq!(partition.partition[3], partition.partition[4]);
+    assert_eq!(partition.partition[3], partition.partition[5]);
+    assert_ne!(partition.partition[0], partition.partition[3]);
+    assert!(partition.modularity > 0.4);
+}
+
+#[test]
+fn test_invalid_coordinates() {
+    assert!(WasmSparseMatrix::try_from_coordinates(2, 2, &[0], &[0, 1], &[1.0]).is_err());
+    assert!(WasmSparseMatrix::try_from_coordinates(2, 2, &[2], &[0], &[1.0]).is_err());
+    assert!(WasmSparseMatrix::try_from_coordinates(2, 2, &[0, 0], &[1, 1], &[1.0, 2.0]).is_err());
+    assert!(WasmSparseMatrix::try_undirected(2, &[0, 1], &[1, 0], &[1.0, 1.0]).is_err());
+    assert!(WasmSparseMatrix::try_from_coordinates(1, u32::MAX, &[], &[], &[]).is_err());
+}
@@ -7,7 +7,7 @@ use geometric_traits::{
     prelude::*,
     traits::{
         Edges, EdgesBuilder, MonoplexGraph, SparseValuedMatrix2D, VocabularyBuilder,
-        WeisfeilerLehmanColoring,
+        WeisfeilerLehmanColoring, WlHash,
     },
 };
 
@@ -285,3 +285,71 @@ fn test_wl_coloring_with_seed_gives_stable_dense_classes_for_non_contiguous_equa
     assert_eq!(colors, [1, 0, 1, 0, 1]);
     assert_dense_classes(&colors);
 }
+
+#[test]
+fn test_wl_hashes_are_invariant_under_relabeling() {
+    let first = build_undirected_graph(6, &[(0, 1), (1, 2), (2, 3), (3, 0), (3, 4), (4, 5)]);
+    // The same graph after swapping the nodes 0 and 5, and 1 and 3.
+    let second = build_undirected_graph(6, &[(5, 3), (3, 2), (2, 1), (1, 5), (1, 4), (4, 0)]);
+
+    for iterations in 0..4 {
+        assert_eq!(first.wl_hash(iterations), second.wl_hash(iterations));
+        let mut first_hashes = first.wl_node_hashes(iterations);
+        let second_hashes = second.wl_node_hashes(iterations);
+        first_hashes.swap(0, 5);
+        first_hashes.swap(1, 3);
+        assert_eq!(first_hashes, second_hashes);
+    }
+
+    let labels = [1, 2, 3, 4, 5, 6];
+    let permuted_labels = [6, 4, 3, 2, 5, 1];
+    assert_eq!(
+        first.wl_hash_with_labels(&labels, 3),
+        second.wl_hash_with_labels(&permuted_labels, 3)
+    );
+    assert_ne!(first.wl_hash_with_labels(&labels, 3), second.wl_hash_with_labels(&labels, 3));
+}
+
+#[test]
+fn test_wl_hashes_distinguish_neighborhoods_by_depth() {
+    let path = build_undirected_graph(5, &[(0, 1), (1, 2), (2, 3), (3, 4)]);
+
+    // Without refinement every node keeps the hash of the uniform label.
+    let initial = path.wl_node_hashes(0);
+    assert!(initial.iter().all(|&hash| hash == initial[0]));
+
+    // One round separates the endpoints by degree, a second one separates the
+    // center from its neighbors.
+    let one_round = path.wl_node_hashes(1);
+    assert_eq!(one_round[0], one_round[4]);
+    assert_ne!(one_round[0], one_round[1]);
+    assert_eq!(one_round[1], one_round[2]);
+    let two_rounds = path.wl_node_hashes(2);
+    assert_eq!(two_rounds[1], two_rounds[3]);
+    assert_ne!(two_rounds[1], two_rounds[2]);
+
+    let star = build_undirected_graph(5, &[(0, 1), (0, 2), (0, 3), (0, 4)]);
+    assert_eq!(path.wl_hash(0), star.wl_hash(0));
+    assert_ne!(path.wl_hash(1), star.wl_hash(1));
+    assert_ne!(path.wl_hash(1), path.wl_hash(2));
+    assert_ne!(path.wl_hash(0), build_undirected_graph(4, &[]).wl_hash(0));
+}
+
+#[test]
+fn test_wl_hash_collides_on_regular_graphs_of_equal_degree() {
+    // A hexagon and two triangles are both 2-regular on six nodes, which
+    // Weisfeiler-Lehman refinement cannot tell apart.
+    let hexagon = build_undirected_graph(6, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0)]);
+    let triangles = build_undirected_graph(6, &[(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)]);
+    assert_eq!(hexagon.wl_hash(5), triangles.wl_hash(5));
+    assert_ne!(
+        hexagon.wl_hash_with_labels(&[1, 0, 0, 0, 0, 0], 5),
+        triangles.wl_hash_with_labels(&[1, 0, 0, 0, 0, 0], 5)
+    );
+}
+
+#[test]
+#[should_panic(expected = "initial labels must contain exactly one entry per dense node id")]
+fn test_wl_hash_rejects_initial_labels_of_wrong_length() {
+    let _ = build_undirected_graph(3, &[(0, 1)]).wl_hash_with_labels(&[0, 0], 1);
+}